@@ -0,0 +1,130 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use reflex::cache::SubstitutionCache;
+use reflex::core::{create_record, evaluate, ExpressionFactory, HeapAllocator, StateCache};
+use reflex_js::{
+    builtins::JsBuiltins,
+    stdlib::{create_accessor_term, Accessor},
+};
+use reflex_lang::allocator::DefaultAllocator;
+use reflex_lang::{CachedSharedTerm, SharedTermFactory};
+
+criterion_group!(benches, deep_property_chain_benchmark);
+criterion_main!(benches);
+
+type T = CachedSharedTerm<JsBuiltins>;
+
+fn deep_property_chain_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Deep property chain");
+
+    for depth in (10..=100).step_by(10) {
+        group.bench_with_input(
+            BenchmarkId::new("Runtime lookup (baseline)", depth),
+            &depth,
+            |b, depth| {
+                b.iter_batched(
+                    || {
+                        let factory = SharedTermFactory::<JsBuiltins>::default();
+                        let allocator = DefaultAllocator::default();
+                        let root = generate_nested_record(*depth, &factory, &allocator);
+                        (root, factory, allocator)
+                    },
+                    |(root, factory, allocator)| {
+                        let expression = generate_property_chain(
+                            root,
+                            *depth,
+                            |target, key| {
+                                factory.create_application_term(
+                                    factory.create_builtin_term(Accessor),
+                                    allocator.create_pair(target, key),
+                                )
+                            },
+                            &factory,
+                            &allocator,
+                        );
+                        let state = StateCache::default();
+                        let mut cache = SubstitutionCache::new();
+                        evaluate(&expression, &state, &factory, &allocator, &mut cache)
+                    },
+                    criterion::BatchSize::PerIteration,
+                );
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("Compile-time lookup", depth),
+            &depth,
+            |b, depth| {
+                b.iter_batched(
+                    || {
+                        let factory = SharedTermFactory::<JsBuiltins>::default();
+                        let allocator = DefaultAllocator::default();
+                        let root = generate_nested_record(*depth, &factory, &allocator);
+                        (root, factory, allocator)
+                    },
+                    |(root, factory, allocator)| {
+                        let expression = generate_property_chain(
+                            root,
+                            *depth,
+                            |target, key| create_accessor_term(target, key, &factory, &allocator),
+                            &factory,
+                            &allocator,
+                        );
+                        let state = StateCache::default();
+                        let mut cache = SubstitutionCache::new();
+                        evaluate(&expression, &state, &factory, &allocator, &mut cache)
+                    },
+                    criterion::BatchSize::PerIteration,
+                );
+            },
+        );
+    }
+
+    group.finish();
+}
+
+/// Generate a nested record of the given depth, in which each level contains a handful of sibling fields alongside the
+/// `child` field, and the innermost level contains a single `value` field
+fn generate_nested_record(
+    depth: usize,
+    factory: &SharedTermFactory<JsBuiltins>,
+    allocator: &DefaultAllocator<T>,
+) -> T {
+    let child_key = factory.create_string_term(allocator.create_static_string("child"));
+    let value_key = factory.create_string_term(allocator.create_static_string("value"));
+    let sibling_keys = ["foo", "bar", "baz"]
+        .into_iter()
+        .map(|key| factory.create_string_term(allocator.create_static_string(key)))
+        .collect::<Vec<_>>();
+    let leaf = create_record(
+        [(value_key, factory.create_int_term(3))],
+        factory,
+        allocator,
+    );
+    (0..depth).fold(leaf, |child, _| {
+        create_record(
+            sibling_keys
+                .iter()
+                .cloned()
+                .map(|key| (key, factory.create_nil_term()))
+                .chain([(child_key.clone(), child)]),
+            factory,
+            allocator,
+        )
+    })
+}
+
+/// Generate an expression equivalent to `root.child.child.(...).child.value` using the given accessor constructor
+fn generate_property_chain(
+    root: T,
+    depth: usize,
+    accessor: impl Fn(T, T) -> T,
+    factory: &SharedTermFactory<JsBuiltins>,
+    allocator: &DefaultAllocator<T>,
+) -> T {
+    let child_key = factory.create_string_term(allocator.create_static_string("child"));
+    let value_key = factory.create_string_term(allocator.create_static_string("value"));
+    let target = (0..depth).fold(root, |target, _| accessor(target, child_key.clone()));
+    accessor(target, value_key)
+}
@@ -0,0 +1,183 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::io::{self, Write};
+
+use reflex_handlers::hyper::{
+    body, client::connect::Connect, header, Body, Client, Method, Request, Uri,
+};
+use reflex_json::JsonValue;
+use reflex_utils::json::json_object;
+use tokio::io::{AsyncBufReadExt, BufReader};
+
+/// Run a REPL that evaluates queries against the live graph root of a running server, via the server's admin endpoint
+///
+/// Each line of input is interpreted as either a complete GraphQL operation, or a selection set to be queried against
+/// the graph root (e.g. `user(id: 3) { name }`). The admin endpoint only accepts GraphQL operations, rather than
+/// arbitrary expressions. Operations are evaluated by the server against its current effect state. Unless the server has
+/// been configured to allow mutations, the admin endpoint only accepts query operations.
+///
+/// Requests are authenticated using the provided bearer token, which must match the admin token configured on the server.
+pub async fn run<C>(url: &Uri, token: &str, client: &Client<C, Body>) -> io::Result<()>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    let mut stdout = io::stdout();
+    let mut stderr = io::stderr();
+
+    let read_only = fetch_admin_endpoint_metadata(url, token, client)
+        .await
+        .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+    writeln!(
+        stdout,
+        "Connected to {}{}",
+        url,
+        if read_only { " (read-only)" } else { "" }
+    )?;
+
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+    loop {
+        write!(stdout, "> ")?;
+        stdout.flush()?;
+
+        let input = match lines.next_line().await? {
+            Some(input) => input,
+            None => break,
+        };
+
+        if input == "exit" {
+            break;
+        }
+        if input.trim().is_empty() {
+            continue;
+        }
+
+        match execute_admin_query(url, token, client, &create_admin_query(&input)).await {
+            Ok(response) => {
+                if let Some(JsonValue::Array(errors)) = response.get("errors") {
+                    for error in errors {
+                        match error.get("message") {
+                            Some(JsonValue::String(message)) => {
+                                writeln!(stderr, "Error: {}", message)
+                            }
+                            _ => writeln!(stderr, "Error: {}", error),
+                        }?;
+                    }
+                }
+                match response.get("data") {
+                    Some(JsonValue::Null) | None => Ok(()),
+                    Some(data) => writeln!(stdout, "{:#}", data),
+                }
+            }
+            Err(err) => writeln!(stderr, "{}", err),
+        }?;
+    }
+    Ok(())
+}
+
+/// Convert a line of REPL input into a GraphQL query document
+///
+/// Input that does not already represent a complete GraphQL operation is treated as an anonymous query selection set.
+pub fn create_admin_query(input: &str) -> String {
+    let input = input.trim();
+    let keyword = input
+        .split(|char: char| char.is_whitespace() || char == '{' || char == '(')
+        .next()
+        .unwrap_or_default();
+    if input.starts_with('{') || matches!(keyword, "query" | "mutation" | "subscription") {
+        String::from(input)
+    } else {
+        format!("{{ {} }}", input)
+    }
+}
+
+async fn fetch_admin_endpoint_metadata<C>(
+    url: &Uri,
+    token: &str,
+    client: &Client<C, Body>,
+) -> Result<bool, String>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(url.clone())
+        .header(header::AUTHORIZATION, format!("Bearer {}", token))
+        .body(Body::empty())
+        .map_err(|err| format!("Invalid admin endpoint URL: {}", err))?;
+    let response = send_request(client, request)
+        .await
+        .map_err(|err| format!("Failed to connect to admin endpoint {}: {}", url, err))?;
+    match response.get("readOnly") {
+        Some(JsonValue::Bool(read_only)) => Ok(*read_only),
+        _ => Err(format!("Invalid admin endpoint: {}", url)),
+    }
+}
+
+async fn execute_admin_query<C>(
+    url: &Uri,
+    token: &str,
+    client: &Client<C, Body>,
+    query: &str,
+) -> Result<JsonValue, String>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    let payload = json_object([(
+        String::from("query"),
+        JsonValue::String(String::from(query)),
+    )]);
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(url.clone())
+        .header(header::AUTHORIZATION, format!("Bearer {}", token))
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(payload.to_string()))
+        .map_err(|err| format!("Invalid admin endpoint URL: {}", err))?;
+    send_request(client, request).await
+}
+
+async fn send_request<C>(
+    client: &Client<C, Body>,
+    request: Request<Body>,
+) -> Result<JsonValue, String>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    let response = client
+        .request(request)
+        .await
+        .map_err(|err| format!("{}", err))?;
+    let status = response.status();
+    let body = body::to_bytes(response.into_body())
+        .await
+        .map_err(|err| format!("{}", err))?;
+    let body = String::from_utf8_lossy(&body);
+    // Error responses are typically GraphQL error payloads, however they may also be plain text messages
+    match reflex_json::deserialize(&body) {
+        Ok(response) => Ok(response),
+        Err(_) if !status.is_success() => Err(format!("HTTP error {}: {}", status, body)),
+        Err(err) => Err(format!("Invalid response: {}", err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn admin_query_input() {
+        assert_eq!(create_admin_query("foo"), "{ foo }");
+        assert_eq!(
+            create_admin_query("  user(id: 3) { name }\n"),
+            "{ user(id: 3) { name } }"
+        );
+        assert_eq!(create_admin_query("queryCount"), "{ queryCount }");
+        assert_eq!(create_admin_query("{ foo }"), "{ foo }");
+        assert_eq!(create_admin_query("query Foo { foo }"), "query Foo { foo }");
+        assert_eq!(
+            create_admin_query("mutation { reset }"),
+            "mutation { reset }"
+        );
+    }
+}
@@ -4,16 +4,18 @@
 // SPDX-FileContributor: Chris Campbell <c.campbell@mwam.com> https://github.com/c-campbell-mwam
 // SPDX-FileContributor: Jordan Hall <j.hall@mwam.com> https://github.com/j-hall-mwam
 use std::{
+    io::{IsTerminal, Write},
     iter::{empty, once},
     marker::PhantomData,
     ops::Deref,
     path::{Path, PathBuf},
     pin::Pin,
+    str::FromStr,
     time::Duration,
 };
 
 use anyhow::{anyhow, Context, Result};
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use futures::{Future, Stream, StreamExt};
 use metrics::SharedString;
 use pin_project::pin_project;
@@ -23,8 +25,16 @@ use reflex::{
         Applicable, ArgType, ConditionType, Expression, ExpressionFactory, HeapAllocator,
         Reducible, Rewritable, StateCache,
     },
+    sandbox::SandboxPolicies,
+};
+use reflex_cli::{
+    attach,
+    builtins::CliBuiltins,
+    format_signal_result,
+    render::{render_term, RenderOptions},
+    repl,
+    sandbox::{EnvPermission, SandboxPermissions},
 };
-use reflex_cli::{builtins::CliBuiltins, format_signal_result, repl};
 use reflex_dispatcher::{
     Action, Actor, ActorEvents, AsyncScheduler, Handler, HandlerContext, Matcher, MessageData,
     Named, ProcessId, Redispatcher, SchedulerMode, SchedulerTransition, SerializableAction,
@@ -41,7 +51,7 @@ use reflex_engine::{
 };
 use reflex_grpc::{
     action::*,
-    actor::{GrpcHandler, GrpcHandlerAction, GrpcHandlerMetricNames},
+    actor::{GrpcHandler, GrpcHandlerAction, GrpcHandlerMetricNames, EFFECT_TYPE_GRPC},
     load_grpc_services,
     task::{GrpcHandlerConnectionTaskAction, GrpcHandlerConnectionTaskFactory, GrpcHandlerTask},
     DefaultGrpcConfig, GrpcConfig,
@@ -52,16 +62,21 @@ use reflex_handlers::{
             FetchHandlerActions, FetchHandlerConnectionErrorAction, FetchHandlerFetchCompleteAction,
         },
         graphql::*,
+        plugin::{PluginHandlerActions, PluginHandlerResponseAction},
         timeout::{TimeoutHandlerActions, TimeoutHandlerTimeoutAction},
         timestamp::{TimestampHandlerActions, TimestampHandlerUpdateAction},
     },
-    actor::{HandlerAction, HandlerActor, HandlerActorBuiltin, HandlerTask},
+    actor::{
+        fetch::EFFECT_TYPE_FETCH, graphql::EFFECT_TYPE_GRAPHQL, HandlerAction, HandlerActor,
+        HandlerActorBuiltin, HandlerTask,
+    },
     default_handler_actors, hyper,
     task::{
         fetch::FetchHandlerTaskFactory,
         graphql::{
             GraphQlHandlerHttpFetchTaskFactory, GraphQlHandlerWebSocketConnectionTaskFactory,
         },
+        plugin::PluginHandlerTaskFactory,
         timeout::TimeoutHandlerTaskFactory,
         timestamp::TimestampHandlerTaskFactory,
         DefaultHandlersTaskAction, DefaultHandlersTaskFactory,
@@ -69,7 +84,7 @@ use reflex_handlers::{
     utils::tls::{create_https_client, hyper_rustls},
     DefaultHandlerMetricNames,
 };
-use reflex_json::{JsonMap, JsonValue};
+use reflex_json::{stream_json_chunks, JsonMap, JsonValue};
 use reflex_lang::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
 use reflex_macros::{blanket_trait, task_factory_enum, Matcher, Named};
 use reflex_parser::{create_parser, syntax::js::default_js_loaders, Syntax};
@@ -83,6 +98,7 @@ use reflex_runtime::{
         },
         RuntimeAction, RuntimeActor, RuntimeMetricNames,
     },
+    migration::StateTokenAliases,
     runtime_actors,
     task::{
         evaluate_handler::EffectThrottleTaskFactory, RuntimeTask, RuntimeTaskAction,
@@ -111,7 +127,10 @@ const RUNTIME_BYTES: &'static [u8] = include_bytes!("../../../reflex-wasm/build/
 
 /// Reflex runtime evaluator
 #[derive(Parser)]
+#[clap(args_conflicts_with_subcommands = true)]
 struct Args {
+    #[clap(subcommand)]
+    command: Option<Command>,
     /// Optional entry point module to evaluate (defaults to REPL)
     input_path: Option<PathBuf>,
     /// Entry point module syntax (defaults to inferring based on entry point module file extension)
@@ -153,14 +172,69 @@ struct Args {
     /// Compile constructor arguments as lazily-evaluated expressions
     #[clap(long)]
     lazy_constructors: bool,
+    /// Compiler options, specified as either a JSON object or a comma-separated list of key=value pairs (any
+    /// individual compiler flags take precedence)
+    #[clap(long)]
+    compiler_options: Option<CompilerOptions>,
     /// Wrap compiled lambdas in argument memoization wrappers
     #[clap(long)]
     memoize_lambdas: bool,
+    /// Emit error signals for constructor calls whose arguments do not match the constructor keys
+    /// (only valid for REPL mode, as WASM entry points always reject mismatched constructor arguments)
+    #[clap(long)]
+    validate_constructors: bool,
     /// Dump heap snapshots for any queries that return error results
     #[clap(long)]
     dump_heap_snapshot: Option<WasmHeapDumpMode>,
+    /// Output format for evaluation results (valid options are "display" and "json-lines", defaulting to "display")
+    #[clap(long)]
+    output: Option<OutputFormat>,
+    /// Restrict the entry point module to the permissions granted via the --allow-* flags, prompting for any
+    /// additional permissions when running in an interactive terminal
+    #[clap(long)]
+    sandbox: bool,
+    /// Allow network effects (HTTP fetch, GraphQL and gRPC requests) when running in sandbox mode
+    #[clap(long, requires = "sandbox")]
+    allow_net: bool,
+    /// Expose environment variables when running in sandbox mode (optionally restricted to a comma-separated list of
+    /// variable names, e.g. --allow-env=HOME,USER)
+    #[clap(long, requires = "sandbox", require_equals = true, value_delimiter = ',', num_args = 0..)]
+    allow_env: Option<Option<Vec<String>>>,
+    /// Deny any permissions not granted via the --allow-* flags without prompting when running in sandbox mode
+    #[clap(long, requires = "sandbox")]
+    no_prompt: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Connect to the admin endpoint of a running Reflex server and evaluate GraphQL queries against its live graph root
+    /// (authenticated via the bearer token provided in the REFLEX_ADMIN_TOKEN environment variable)
+    Attach {
+        /// URL of the server admin endpoint
+        url: hyper::Uri,
+    },
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+enum OutputFormat {
+    #[default]
+    Display,
+    JsonLines,
+}
+impl FromStr for OutputFormat {
+    type Err = anyhow::Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "display" => Ok(Self::Display),
+            "json-lines" | "jsonl" => Ok(Self::JsonLines),
+            _ => Err(anyhow!("Unrecognized output format: {}", input)),
+        }
+    }
 }
 
+/// Number of list items to serialize at a time when streaming results in JSON lines format
+const JSON_LINES_CHUNK_SIZE: usize = 1000;
+
 #[tokio::main]
 pub async fn main() -> Result<()> {
     type TBuiltin = CliBuiltins;
@@ -176,11 +250,48 @@ pub async fn main() -> Result<()> {
         CliActorFactory<T, TFactory, TAllocator, TConnect, TReconnect, TGrpcConfig, TMetricLabels>;
     type TInstrumentation = NoopTokioSchedulerInstrumentation<TAction, TTask>;
     let args = Args::parse();
+    if let Some(Command::Attach { url }) = args.command.as_ref() {
+        let token = std::env::var("REFLEX_ADMIN_TOKEN").with_context(|| {
+            String::from(
+                "Attaching to a server requires the REFLEX_ADMIN_TOKEN environment variable",
+            )
+        })?;
+        let https_client: hyper::Client<TConnect> = create_https_client(None)?;
+        return attach::run(url, &token, &https_client)
+            .await
+            .with_context(|| "Failed to attach to server");
+    }
     let unoptimized = args.unoptimized;
+    let output_format = args.output.unwrap_or_default();
     let dump_heap_snapshot = args.dump_heap_snapshot;
     let effect_throttle = args.effect_throttle_ms.map(Duration::from_millis);
     let input_path = &args.input_path;
-    let factory: TFactory = SharedTermFactory::<TBuiltin>::default();
+    let sandbox_permissions = if args.sandbox {
+        let permissions = SandboxPermissions {
+            net: args.allow_net,
+            env: match &args.allow_env {
+                None => EnvPermission::None,
+                Some(Some(names)) if !names.is_empty() => {
+                    EnvPermission::Only(names.iter().cloned().collect())
+                }
+                Some(_) => EnvPermission::All,
+            },
+        };
+        // Permissions are only prompted for when evaluating an entry point module, as the REPL does not handle effects
+        let is_interactive = std::io::stdin().is_terminal() && std::io::stderr().is_terminal();
+        let permissions = if is_interactive && !args.no_prompt && input_path.is_some() {
+            permissions
+                .prompt(std::io::stdin().lock(), std::io::stderr())
+                .with_context(|| "Failed to prompt for sandbox permissions")?
+        } else {
+            permissions
+        };
+        Some(permissions)
+    } else {
+        None
+    };
+    let factory: TFactory = SharedTermFactory::<TBuiltin>::default()
+        .with_constructor_validation(args.validate_constructors);
     let allocator: TAllocator = DefaultAllocator::default();
     let https_client: hyper::Client<TConnect> = create_https_client(None)?;
     let grpc_services = load_grpc_services(args.grpc_service.iter())
@@ -197,7 +308,7 @@ pub async fn main() -> Result<()> {
         let defaults = WasmCompilerOptions::default();
         WasmCompilerOptions {
             compiler: {
-                let defaults = CompilerOptions::default();
+                let defaults = args.compiler_options.unwrap_or_default();
                 CompilerOptions {
                     lazy_record_values: match args.lazy_record_values {
                         true => ArgType::Lazy,
@@ -258,6 +369,11 @@ pub async fn main() -> Result<()> {
             repl::run(parser, &state, &factory, &allocator, &mut cache)?;
         }
         Some(input_path) => {
+            if args.validate_constructors {
+                return Err(anyhow!(
+                    "Constructor validation is only supported in REPL mode"
+                ));
+            }
             let syntax = match args.syntax {
                 Some(syntax) => Ok(syntax),
                 None => {
@@ -300,7 +416,10 @@ pub async fn main() -> Result<()> {
                     parse_and_compile_module(
                         [&entry_point],
                         default_js_loaders(empty(), &factory, &allocator),
-                        std::env::vars(),
+                        match &sandbox_permissions {
+                            Some(permissions) => permissions.filter_env_vars(std::env::vars()),
+                            None => std::env::vars().collect(),
+                        },
                         RUNTIME_BYTES,
                         &factory,
                         &allocator,
@@ -335,6 +454,14 @@ pub async fn main() -> Result<()> {
                         factory.clone(),
                         allocator.clone(),
                         effect_throttle,
+                        None,
+                        match &sandbox_permissions {
+                            Some(permissions) => {
+                                SandboxPolicies::from(permissions.sandbox_policy())
+                            }
+                            None => SandboxPolicies::default(),
+                        },
+                        StateTokenAliases::default(),
                         RuntimeMetricNames::default(),
                         main_pid,
                     )
@@ -384,6 +511,23 @@ pub async fn main() -> Result<()> {
                 ))))
                 .map(|actor| (builder.generate_pid(), actor))
                 .collect::<Vec<_>>();
+                // Handlers for effect types that have not been granted are never registered, ensuring that the
+                // sandbox cannot be bypassed by effects that are dispatched without going through the graph root
+                let actors = match &sandbox_permissions {
+                    Some(permissions) => {
+                        permissions.filter_handlers(actors, |(_, actor)| match actor {
+                            CliActor::Handler(HandlerActor::FetchHandler(_)) => {
+                                Some(EFFECT_TYPE_FETCH)
+                            }
+                            CliActor::Handler(HandlerActor::GraphQlHandler(_)) => {
+                                Some(EFFECT_TYPE_GRAPHQL)
+                            }
+                            CliActor::Grpc(_) => Some(EFFECT_TYPE_GRPC),
+                            _ => None,
+                        })
+                    }
+                    None => actors,
+                };
                 let actor_pids = actors.iter().map(|(pid, _)| *pid);
                 builder.worker(main_pid, CliActor::Main(Redispatcher::new(actor_pids)));
                 for (pid, actor) in actors {
@@ -413,11 +557,30 @@ pub async fn main() -> Result<()> {
             .await
             .unwrap();
             while let Some(value) = results_stream.next().await {
-                let output = match factory.match_signal_term(&value) {
-                    None => format!("{}", value),
-                    Some(signal) => format_signal_result(signal),
-                };
-                println!("{}{}", clear_escape_sequence(), output);
+                match output_format {
+                    OutputFormat::Display => {
+                        let output = match factory.match_signal_term(&value) {
+                            None => render_term(&value, &RenderOptions::default(), &factory),
+                            Some(signal) => format_signal_result(signal, &factory),
+                        };
+                        println!("{}{}", clear_escape_sequence(), output);
+                    }
+                    OutputFormat::JsonLines => match factory.match_signal_term(&value) {
+                        Some(signal) => eprintln!("{}", format_signal_result(signal, &factory)),
+                        None => {
+                            let mut stdout = std::io::stdout().lock();
+                            for chunk in stream_json_chunks(&value, JSON_LINES_CHUNK_SIZE, &factory)
+                            {
+                                match chunk {
+                                    Ok(chunk) => stdout
+                                        .write_all(chunk.to_json_lines().as_bytes())
+                                        .with_context(|| "Failed to write output")?,
+                                    Err(err) => eprintln!("{}", err),
+                                }
+                            }
+                        }
+                    },
+                }
             }
         }
     }
@@ -561,6 +724,7 @@ enum CliActions<T: Expression> {
     WasmInterpreter(BytecodeInterpreterActions<T>),
     FetchHandler(FetchHandlerActions),
     GraphQlHandler(GraphQlHandlerActions),
+    PluginHandler(PluginHandlerActions),
     TimeoutHandler(TimeoutHandlerActions),
     TimestampHandler(TimestampHandlerActions),
     GrpcHandler(GrpcHandlerActions),
@@ -572,6 +736,7 @@ impl<T: Expression> Named for CliActions<T> {
             Self::WasmInterpreter(action) => action.name(),
             Self::FetchHandler(action) => action.name(),
             Self::GraphQlHandler(action) => action.name(),
+            Self::PluginHandler(action) => action.name(),
             Self::TimeoutHandler(action) => action.name(),
             Self::TimestampHandler(action) => action.name(),
             Self::GrpcHandler(action) => action.name(),
@@ -586,6 +751,7 @@ impl<T: Expression> SerializableAction for CliActions<T> {
             Self::WasmInterpreter(action) => action.to_json(),
             Self::FetchHandler(action) => action.to_json(),
             Self::GraphQlHandler(action) => action.to_json(),
+            Self::PluginHandler(action) => action.to_json(),
             Self::TimeoutHandler(action) => action.to_json(),
             Self::TimestampHandler(action) => action.to_json(),
             Self::GrpcHandler(action) => action.to_json(),
@@ -1638,6 +1804,32 @@ where
     }
 }
 
+impl<T, TFactory, TAllocator, TConnect, TReconnect, TGrpcConfig, TMetricLabels>
+    From<PluginHandlerTaskFactory>
+    for CliActorFactory<T, TFactory, TAllocator, TConnect, TReconnect, TGrpcConfig, TMetricLabels>
+where
+    T: AsyncExpression + Rewritable<T> + Reducible<T> + Applicable<T>,
+    T::String: Send,
+    T::Builtin: Send,
+    T::Signal: Send,
+    T::SignalList: Send,
+    T::StructPrototype: Send,
+    T::ExpressionList: Send,
+    TFactory: AsyncExpressionFactory<T> + Default,
+    TAllocator: AsyncHeapAllocator<T> + Default,
+    T::Builtin: HandlerActorBuiltin + Into<reflex_wasm::stdlib::Stdlib>,
+    TConnect: hyper::client::connect::Connect + Clone + Send + Sync + 'static,
+    TReconnect: ReconnectTimeout + Send + Clone + 'static,
+    TGrpcConfig: GrpcConfig + Send + Clone + 'static,
+    TMetricLabels: BytecodeInterpreterMetricLabels + Send + 'static,
+{
+    fn from(value: PluginHandlerTaskFactory) -> Self {
+        Self::from(CliTaskFactory::DefaultHandlers(
+            DefaultHandlersTaskFactory::from(value),
+        ))
+    }
+}
+
 impl<T, TFactory, TAllocator, TConnect, TReconnect, TGrpcConfig, TMetricLabels>
     From<TimeoutHandlerTaskFactory>
     for CliActorFactory<T, TFactory, TAllocator, TConnect, TReconnect, TGrpcConfig, TMetricLabels>
@@ -1802,6 +1994,28 @@ impl<'a, T: Expression> From<&'a CliActions<T>> for Option<&'a GraphQlHandlerAct
     }
 }
 
+impl<T: Expression> From<PluginHandlerActions> for CliActions<T> {
+    fn from(value: PluginHandlerActions) -> Self {
+        Self::PluginHandler(value)
+    }
+}
+impl<T: Expression> From<CliActions<T>> for Option<PluginHandlerActions> {
+    fn from(value: CliActions<T>) -> Self {
+        match value {
+            CliActions::PluginHandler(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+impl<'a, T: Expression> From<&'a CliActions<T>> for Option<&'a PluginHandlerActions> {
+    fn from(value: &'a CliActions<T>) -> Self {
+        match value {
+            CliActions::PluginHandler(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
 impl<T: Expression> From<TimeoutHandlerActions> for CliActions<T> {
     fn from(value: TimeoutHandlerActions) -> Self {
         Self::TimeoutHandler(value)
@@ -1948,6 +2162,22 @@ impl<'a, T: Expression> From<&'a CliActions<T>> for Option<&'a EffectThrottleEmi
     }
 }
 
+impl<T: Expression> From<EffectSchemaDriftAction<T>> for CliActions<T> {
+    fn from(value: EffectSchemaDriftAction<T>) -> Self {
+        EffectActions::from(value).into()
+    }
+}
+impl<T: Expression> From<CliActions<T>> for Option<EffectSchemaDriftAction<T>> {
+    fn from(value: CliActions<T>) -> Self {
+        Option::<EffectActions<T>>::from(value).and_then(|value| value.into())
+    }
+}
+impl<'a, T: Expression> From<&'a CliActions<T>> for Option<&'a EffectSchemaDriftAction<T>> {
+    fn from(value: &'a CliActions<T>) -> Self {
+        Option::<&'a EffectActions<T>>::from(value).and_then(|value| value.into())
+    }
+}
+
 impl<T: Expression> From<EvaluateActions<T>> for CliActions<T> {
     fn from(value: EvaluateActions<T>) -> Self {
         RuntimeActions::from(value).into()
@@ -2336,6 +2566,22 @@ impl<'a, T: Expression> From<&'a CliActions<T>>
     }
 }
 
+impl<T: Expression> From<PluginHandlerResponseAction> for CliActions<T> {
+    fn from(value: PluginHandlerResponseAction) -> Self {
+        PluginHandlerActions::from(value).into()
+    }
+}
+impl<T: Expression> From<CliActions<T>> for Option<PluginHandlerResponseAction> {
+    fn from(value: CliActions<T>) -> Self {
+        Option::<PluginHandlerActions>::from(value).and_then(|value| value.into())
+    }
+}
+impl<'a, T: Expression> From<&'a CliActions<T>> for Option<&'a PluginHandlerResponseAction> {
+    fn from(value: &'a CliActions<T>) -> Self {
+        Option::<&'a PluginHandlerActions>::from(value).and_then(|value| value.into())
+    }
+}
+
 impl<T: Expression> From<TimeoutHandlerTimeoutAction> for CliActions<T> {
     fn from(value: TimeoutHandlerTimeoutAction) -> Self {
         TimeoutHandlerActions::from(value).into()
@@ -0,0 +1,143 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{collections::HashSet, io::Write, iter::once, path::PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use clap::Parser;
+use reflex::env::inject_env_vars;
+use reflex_cli::test_runner::{
+    builtins::TestRunnerBuiltins,
+    collect_test_cases,
+    coverage::format_coverage_report,
+    import_test,
+    report::{format_test_report, TestReportFormat},
+    run_test_case_with_coverage, EffectFixtures, TestOutcome, TestResult, TestSuiteResult,
+    TEST_MODULE_IMPORT_PATH,
+};
+use reflex_js::{coverage::CoverageInstrumentation, create_js_env, parse_module};
+use reflex_lang::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
+use reflex_parser::{
+    cache::NoopModuleCache,
+    loader::ImportMap,
+    remote::RemoteModuleOptions,
+    syntax::js::{create_js_module_loader, default_js_loaders},
+};
+
+/// Reflex test runner
+#[derive(Parser)]
+struct Args {
+    /// Paths of JavaScript test modules to run
+    #[clap(required = true)]
+    input_paths: Vec<PathBuf>,
+    /// Path to JSON file containing mocked effect results
+    #[clap(long)]
+    fixtures: Option<PathBuf>,
+    /// Output format for test results (valid options are "tap" and "junit", defaulting to "tap")
+    #[clap(long)]
+    format: Option<TestReportFormat>,
+    /// Path to output file (defaults to stdout)
+    #[clap(long)]
+    output: Option<PathBuf>,
+    /// Path to output file for a report of which source functions and branches were evaluated during the test run
+    #[clap(long)]
+    coverage: Option<PathBuf>,
+}
+
+pub fn main() -> Result<()> {
+    type TBuiltin = TestRunnerBuiltins;
+    type T = CachedSharedTerm<TBuiltin>;
+    type TFactory = SharedTermFactory<TBuiltin>;
+    type TAllocator = DefaultAllocator<T>;
+    let args = Args::parse();
+    let factory: TFactory = SharedTermFactory::<TBuiltin>::default();
+    let allocator: TAllocator = DefaultAllocator::default();
+    let fixtures = match &args.fixtures {
+        Some(path) => EffectFixtures::load(path).map_err(|err| anyhow!("{}", err)),
+        None => Ok(EffectFixtures::default()),
+    }?;
+    let coverage_instrumentation = args
+        .coverage
+        .as_ref()
+        .map(|_| CoverageInstrumentation::default());
+    let env = {
+        let env = create_js_env(&factory, &allocator);
+        match &coverage_instrumentation {
+            Some(instrumentation) => env.with_coverage(instrumentation.clone()),
+            None => env,
+        }
+    };
+    let loader = create_js_module_loader(
+        env.clone(),
+        ImportMap::default(),
+        RemoteModuleOptions::default(),
+        NoopModuleCache::default(),
+        default_js_loaders(
+            once((
+                String::from(TEST_MODULE_IMPORT_PATH),
+                import_test(&factory, &allocator),
+            )),
+            &factory,
+            &allocator,
+        ),
+        &factory,
+        &allocator,
+    );
+    let mut coverage = HashSet::new();
+    let suites = args
+        .input_paths
+        .iter()
+        .map(|input_path| {
+            let name = format!("{}", input_path.display());
+            let test_cases = std::fs::read_to_string(input_path)
+                .map_err(|err| format!("Failed to load test module: {}", err))
+                .and_then(|source| {
+                    parse_module(&source, &env, input_path, &loader, &factory, &allocator)
+                })
+                .map(|module| inject_env_vars(module, std::env::vars(), &factory, &allocator))
+                .and_then(|module| {
+                    collect_test_cases(&module, &mut coverage, &factory, &allocator)
+                });
+            let results = match test_cases {
+                Ok(test_cases) => test_cases
+                    .into_iter()
+                    .map(|(name, test)| TestResult {
+                        name,
+                        outcome: run_test_case_with_coverage(
+                            &test,
+                            &fixtures,
+                            &mut coverage,
+                            &factory,
+                            &allocator,
+                        ),
+                    })
+                    .collect(),
+                // Report module-level failures as a single failed test within the suite
+                Err(err) => vec![TestResult {
+                    name: String::from("<module>"),
+                    outcome: TestOutcome::Failed(err),
+                }],
+            };
+            TestSuiteResult { name, results }
+        })
+        .collect::<Vec<_>>();
+    let report = format_test_report(args.format.unwrap_or_default(), &suites);
+    match &args.output {
+        Some(path) => std::fs::write(path, report),
+        None => std::io::stdout().write_all(report.as_bytes()),
+    }
+    .with_context(|| "Failed to write test report")?;
+    if let (Some(path), Some(instrumentation)) = (&args.coverage, &coverage_instrumentation) {
+        let report = format_coverage_report(&instrumentation.probes(), &coverage);
+        std::fs::write(path, report).with_context(|| "Failed to write coverage report")?;
+    }
+    let num_failures = suites
+        .iter()
+        .map(|suite| suite.num_failures())
+        .sum::<usize>();
+    if num_failures > 0 {
+        Err(anyhow!("{} tests failed", num_failures))
+    } else {
+        Ok(())
+    }
+}
@@ -117,6 +117,18 @@ impl Builtin for CliBuiltins {
             CliBuiltins::GraphQl(term) => term.should_parallelize(args),
         }
     }
+    fn apply_static<T: Expression<Builtin = Self>>(
+        &self,
+        args: &[T],
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+    ) -> Option<T> {
+        match self {
+            CliBuiltins::Stdlib(term) => term.apply_static(args, factory, allocator),
+            CliBuiltins::Js(term) => term.apply_static(args, factory, allocator),
+            _ => None,
+        }
+    }
 }
 impl std::fmt::Display for CliBuiltins {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -214,11 +226,41 @@ impl From<stdlib::Contains> for CliBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::DateAdd> for CliBuiltins {
+    fn from(value: stdlib::DateAdd) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateDiff> for CliBuiltins {
+    fn from(value: stdlib::DateDiff) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateOffset> for CliBuiltins {
+    fn from(value: stdlib::DateOffset) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateTruncate> for CliBuiltins {
+    fn from(value: stdlib::DateTruncate) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateWithOffset> for CliBuiltins {
+    fn from(value: stdlib::DateWithOffset) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Divide> for CliBuiltins {
     fn from(value: stdlib::Divide) -> Self {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Duration> for CliBuiltins {
+    fn from(value: stdlib::Duration) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Effect> for CliBuiltins {
     fn from(value: stdlib::Effect) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -239,6 +281,11 @@ impl From<stdlib::Equal> for CliBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Eval> for CliBuiltins {
+    fn from(value: stdlib::Eval) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Filter> for CliBuiltins {
     fn from(value: stdlib::Filter) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -329,11 +376,26 @@ impl From<stdlib::Map> for CliBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Match> for CliBuiltins {
+    fn from(value: stdlib::Match) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Max> for CliBuiltins {
     fn from(value: stdlib::Max) -> Self {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Memo> for CliBuiltins {
+    fn from(value: stdlib::Memo) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::MemoStore> for CliBuiltins {
+    fn from(value: stdlib::MemoStore) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Merge> for CliBuiltins {
     fn from(value: stdlib::Merge) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -374,6 +436,11 @@ impl From<stdlib::PushFront> for CliBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Quote> for CliBuiltins {
+    fn from(value: stdlib::Quote) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Raise> for CliBuiltins {
     fn from(value: stdlib::Raise) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -419,6 +486,11 @@ impl From<stdlib::ResolveList> for CliBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Reveal> for CliBuiltins {
+    fn from(value: stdlib::Reveal) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Round> for CliBuiltins {
     fn from(value: stdlib::Round) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -434,6 +506,21 @@ impl From<stdlib::Slice> for CliBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Sort> for CliBuiltins {
+    fn from(value: stdlib::Sort) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::SortBy> for CliBuiltins {
+    fn from(value: stdlib::SortBy) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::SortMerge> for CliBuiltins {
+    fn from(value: stdlib::SortMerge) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Split> for CliBuiltins {
     fn from(value: stdlib::Split) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -449,6 +536,11 @@ impl From<stdlib::Subtract> for CliBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Template> for CliBuiltins {
+    fn from(value: stdlib::Template) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Unzip> for CliBuiltins {
     fn from(value: stdlib::Unzip) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -475,6 +567,11 @@ impl From<reflex_json::stdlib::JsonSerialize> for CliBuiltins {
         Self::from(reflex_json::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_json::stdlib::JsonSerializeCanonical> for CliBuiltins {
+    fn from(value: reflex_json::stdlib::JsonSerializeCanonical) -> Self {
+        Self::from(reflex_json::stdlib::Stdlib::from(value))
+    }
+}
 
 impl From<reflex_js::stdlib::Accessor> for CliBuiltins {
     fn from(value: reflex_js::stdlib::Accessor) -> Self {
@@ -516,6 +613,11 @@ impl From<reflex_js::stdlib::LogArgs> for CliBuiltins {
         Self::from(reflex_js::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_js::stdlib::LogScope> for CliBuiltins {
+    fn from(value: reflex_js::stdlib::LogScope) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_js::stdlib::ParseDate> for CliBuiltins {
     fn from(value: reflex_js::stdlib::ParseDate) -> Self {
         Self::from(reflex_js::stdlib::Stdlib::from(value))
@@ -531,6 +633,16 @@ impl From<reflex_js::stdlib::ParseInt> for CliBuiltins {
         Self::from(reflex_js::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_js::stdlib::ScopedLog> for CliBuiltins {
+    fn from(value: reflex_js::stdlib::ScopedLog) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_js::stdlib::ScopedLogArgs> for CliBuiltins {
+    fn from(value: reflex_js::stdlib::ScopedLogArgs) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_js::stdlib::Throw> for CliBuiltins {
     fn from(value: reflex_js::stdlib::Throw) -> Self {
         Self::from(reflex_js::stdlib::Stdlib::from(value))
@@ -593,6 +705,26 @@ impl From<reflex_handlers::stdlib::DecrementVariable> for CliBuiltins {
         Self::from(reflex_handlers::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_handlers::stdlib::Paginate> for CliBuiltins {
+    fn from(value: reflex_handlers::stdlib::Paginate) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::Throttle> for CliBuiltins {
+    fn from(value: reflex_handlers::stdlib::Throttle) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::Debounce> for CliBuiltins {
+    fn from(value: reflex_handlers::stdlib::Debounce) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::Sample> for CliBuiltins {
+    fn from(value: reflex_handlers::stdlib::Sample) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
 
 impl From<reflex_graphql::stdlib::CollectQueryListItems> for CliBuiltins {
     fn from(value: reflex_graphql::stdlib::CollectQueryListItems) -> Self {
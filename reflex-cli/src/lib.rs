@@ -4,34 +4,50 @@
 // SPDX-FileContributor: Chris Campbell <c.campbell@mwam.com> https://github.com/c-campbell-mwam
 // SPDX-FileContributor: Jordan Hall <j.hall@mwam.com> https://github.com/j-hall-mwam
 use reflex::core::{
-    ConditionListType, ConditionType, Expression, RefType, SignalTermType, SignalType,
+    ConditionListType, ConditionType, Expression, ExpressionFactory, RefType, SignalTermType,
+    SignalType,
 };
 
+use crate::render::{render_term, RenderOptions};
+
+pub mod attach;
 pub mod builtins;
+pub mod render;
 pub mod repl;
+pub mod sandbox;
+pub mod stdlib;
+pub mod test_runner;
 
 pub fn format_signal_result<T: Expression<SignalTerm = V>, V: SignalTermType<T>>(
     result: &V,
+    factory: &impl ExpressionFactory<T>,
 ) -> String {
     result
         .signals()
         .as_deref()
         .iter()
-        .map(|signal| format_signal(signal.as_deref()))
+        .map(|signal| format_signal(signal.as_deref(), factory))
         .collect::<Vec<_>>()
         .join("\n")
 }
 
-fn format_signal<T: Expression<Signal = V>, V: ConditionType<T>>(signal: &V) -> String {
+fn format_signal<T: Expression<Signal = V>, V: ConditionType<T>>(
+    signal: &V,
+    factory: &impl ExpressionFactory<T>,
+) -> String {
+    let options = RenderOptions::default();
     match signal.signal_type() {
         SignalType::Error { payload } => {
-            format!("Error: {payload}")
+            format!("Error: {}", render_term(&payload, &options, factory))
         }
         SignalType::Custom {
             effect_type,
             payload,
             ..
-        } => format!("<{effect_type}> {payload}",),
+        } => format!(
+            "<{effect_type}> {}",
+            render_term(&payload, &options, factory)
+        ),
         SignalType::Pending => String::from("<pending>"),
     }
 }
@@ -0,0 +1,270 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::{
+    core::{
+        Expression, ExpressionFactory, ExpressionListType, HashmapTermType, HashsetTermType,
+        ListTermType, RecordTermType, RefType, StructPrototypeType,
+    },
+    hash::HashId,
+};
+
+/// Options controlling the layout of pretty-printed terms
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct RenderOptions {
+    /// Number of spaces to indent each level of nesting
+    pub indent: usize,
+    /// Maximum nesting depth before collection contents are elided
+    pub max_depth: Option<usize>,
+    /// Maximum number of entries to display for each collection before the remainder are elided
+    pub max_items: Option<usize>,
+}
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            indent: 2,
+            max_depth: Some(8),
+            max_items: Some(100),
+        }
+    }
+}
+
+/// Render a term over multiple lines, expanding records, lists, hashmaps and hashsets
+pub fn render_term<T: Expression>(
+    value: &T,
+    options: &RenderOptions,
+    factory: &impl ExpressionFactory<T>,
+) -> String {
+    let mut output = String::new();
+    render_term_inner(value, 0, &mut Vec::new(), options, factory, &mut output);
+    output
+}
+
+enum RenderedCollection {
+    Record,
+    List,
+    Hashmap,
+    Hashset,
+}
+impl RenderedCollection {
+    fn delimiters(&self) -> (&'static str, &'static str) {
+        match self {
+            Self::Record => ("{", "}"),
+            Self::List => ("[", "]"),
+            Self::Hashmap => ("HashMap(", ")"),
+            Self::Hashset => ("HashSet(", ")"),
+        }
+    }
+    fn item_label(&self) -> &'static str {
+        match self {
+            Self::Record | Self::Hashmap => "entries",
+            Self::List | Self::Hashset => "items",
+        }
+    }
+}
+
+enum RenderedEntry<T> {
+    Keyed(T, &'static str, T),
+    Value(T),
+}
+
+fn render_term_inner<T: Expression>(
+    value: &T,
+    depth: usize,
+    ancestors: &mut Vec<HashId>,
+    options: &RenderOptions,
+    factory: &impl ExpressionFactory<T>,
+    output: &mut String,
+) {
+    let (collection, num_entries, entries): (_, _, Box<dyn Iterator<Item = RenderedEntry<T>>>) =
+        if let Some(term) = factory.match_record_term(value) {
+            let keys = term
+                .prototype()
+                .as_deref()
+                .keys()
+                .as_deref()
+                .iter()
+                .map(|key| key.as_deref().clone())
+                .collect::<Vec<_>>();
+            let values = term
+                .values()
+                .as_deref()
+                .iter()
+                .map(|value| value.as_deref().clone())
+                .collect::<Vec<_>>();
+            (
+                RenderedCollection::Record,
+                keys.len(),
+                Box::new(
+                    keys.into_iter()
+                        .zip(values)
+                        .map(|(key, value)| RenderedEntry::Keyed(key, ": ", value)),
+                ),
+            )
+        } else if let Some(term) = factory.match_list_term(value) {
+            let items = term
+                .items()
+                .as_deref()
+                .iter()
+                .map(|item| item.as_deref().clone())
+                .collect::<Vec<_>>();
+            (
+                RenderedCollection::List,
+                items.len(),
+                Box::new(items.into_iter().map(RenderedEntry::Value)),
+            )
+        } else if let Some(term) = factory.match_hashmap_term(value) {
+            let entries = term
+                .keys()
+                .map(|key| key.as_deref().clone())
+                .zip(term.values().map(|value| value.as_deref().clone()))
+                .collect::<Vec<_>>();
+            (
+                RenderedCollection::Hashmap,
+                entries.len(),
+                Box::new(
+                    entries
+                        .into_iter()
+                        .map(|(key, value)| RenderedEntry::Keyed(key, " => ", value)),
+                ),
+            )
+        } else if let Some(term) = factory.match_hashset_term(value) {
+            let values = term
+                .values()
+                .map(|value| value.as_deref().clone())
+                .collect::<Vec<_>>();
+            (
+                RenderedCollection::Hashset,
+                values.len(),
+                Box::new(values.into_iter().map(RenderedEntry::Value)),
+            )
+        } else {
+            output.push_str(&format!("{}", value));
+            return;
+        };
+    let (open, close) = collection.delimiters();
+    if num_entries == 0 {
+        output.push_str(open);
+        output.push_str(close);
+        return;
+    }
+    if ancestors.contains(&value.id()) {
+        output.push_str("<cycle>");
+        return;
+    }
+    if options
+        .max_depth
+        .map(|max_depth| depth >= max_depth)
+        .unwrap_or(false)
+    {
+        output.push_str(open);
+        output.push_str("...");
+        output.push_str(close);
+        return;
+    }
+    let max_items = options.max_items.unwrap_or(num_entries);
+    let child_indent = " ".repeat((depth + 1) * options.indent);
+    ancestors.push(value.id());
+    output.push_str(open);
+    output.push('\n');
+    for entry in entries.take(max_items) {
+        output.push_str(&child_indent);
+        match entry {
+            RenderedEntry::Keyed(key, separator, value) => {
+                render_term_inner(&key, depth + 1, ancestors, options, factory, output);
+                output.push_str(separator);
+                render_term_inner(&value, depth + 1, ancestors, options, factory, output);
+            }
+            RenderedEntry::Value(value) => {
+                render_term_inner(&value, depth + 1, ancestors, options, factory, output);
+            }
+        }
+        output.push_str(",\n");
+    }
+    if num_entries > max_items {
+        output.push_str(&child_indent);
+        output.push_str(&format!(
+            "...{} more {}\n",
+            num_entries - max_items,
+            collection.item_label()
+        ));
+    }
+    ancestors.pop();
+    output.push_str(&" ".repeat(depth * options.indent));
+    output.push_str(close);
+}
+
+#[cfg(test)]
+mod tests {
+    use reflex_lang::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
+    use reflex_stdlib::Stdlib;
+
+    use reflex::core::{create_record, HeapAllocator};
+
+    use super::*;
+
+    #[test]
+    fn nested_collections() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::<CachedSharedTerm<Stdlib>>::default();
+        let value = create_record(
+            [
+                (
+                    factory.create_string_term(allocator.create_static_string("foo")),
+                    factory.create_int_term(3),
+                ),
+                (
+                    factory.create_string_term(allocator.create_static_string("bar")),
+                    factory.create_list_term(allocator.create_pair(
+                        factory.create_int_term(4),
+                        factory.create_list_term(allocator.create_empty_list()),
+                    )),
+                ),
+            ],
+            &factory,
+            &allocator,
+        );
+        assert_eq!(
+            render_term(&value, &RenderOptions::default(), &factory),
+            [
+                "{",
+                "  \"foo\": 3,",
+                "  \"bar\": [",
+                "    4,",
+                "    [],",
+                "  ],",
+                "}",
+            ]
+            .join("\n"),
+        );
+        assert_eq!(
+            render_term(
+                &factory.create_int_term(3),
+                &RenderOptions::default(),
+                &factory
+            ),
+            "3",
+        );
+    }
+
+    #[test]
+    fn truncation() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::<CachedSharedTerm<Stdlib>>::default();
+        let value = factory.create_list_term(allocator.create_list([
+            factory.create_int_term(1),
+            factory.create_list_term(allocator.create_unit_list(factory.create_int_term(2))),
+            factory.create_int_term(3),
+            factory.create_int_term(4),
+        ]));
+        let options = RenderOptions {
+            indent: 2,
+            max_depth: Some(1),
+            max_items: Some(2),
+        };
+        assert_eq!(
+            render_term(&value, &options, &factory),
+            ["[", "  1,", "  [...],", "  ...2 more items", "]"].join("\n"),
+        );
+    }
+}
@@ -9,7 +9,10 @@ use reflex::core::{
 };
 use reflex_parser::SyntaxParser;
 
-use crate::format_signal_result;
+use crate::{
+    format_signal_result,
+    render::{render_term, RenderOptions},
+};
 
 pub fn run<T: Expression + Rewritable<T> + Reducible<T> + Evaluate<T>>(
     parser: impl SyntaxParser<T>,
@@ -59,9 +62,9 @@ pub fn eval<T: Expression + Evaluate<T>>(
         .unwrap_or_else(|| EvaluationResult::new(expression.clone(), DependencyList::empty()))
         .into_parts();
     let output = if let Some(result) = factory.match_signal_term(&result) {
-        format_signal_result(result)
+        format_signal_result(result, factory)
     } else {
-        format!("{}", result)
+        render_term(&result, &RenderOptions::default(), factory)
     };
     (output, dependencies)
 }
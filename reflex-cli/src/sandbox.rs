@@ -0,0 +1,250 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    collections::HashSet,
+    io::{self, BufRead, Write},
+};
+
+use reflex::sandbox::SandboxPolicy;
+use reflex_grpc::actor::EFFECT_TYPE_GRPC;
+use reflex_handlers::actor::{fetch::EFFECT_TYPE_FETCH, graphql::EFFECT_TYPE_GRAPHQL};
+
+/// Effect types that require network access
+pub const NET_EFFECT_TYPES: [&str; 3] = [EFFECT_TYPE_FETCH, EFFECT_TYPE_GRAPHQL, EFFECT_TYPE_GRPC];
+
+/// Permissions granted to scripts that are evaluated in sandbox mode
+///
+/// Any effect that requires a permission that has not been granted is resolved with an error signal rather than being
+/// handled, and any environment variables that have not been granted are hidden from the script.
+#[derive(PartialEq, Eq, Clone, Default, Debug)]
+pub struct SandboxPermissions {
+    /// Whether network effects (HTTP fetch, GraphQL and gRPC requests) are permitted
+    pub net: bool,
+    pub env: EnvPermission,
+}
+
+/// Set of environment variables exposed to a sandboxed script
+#[derive(PartialEq, Eq, Clone, Default, Debug)]
+pub enum EnvPermission {
+    #[default]
+    None,
+    Only(HashSet<String>),
+    All,
+}
+
+impl EnvPermission {
+    pub fn permits(&self, name: &str) -> bool {
+        match self {
+            Self::None => false,
+            Self::Only(names) => names.contains(name),
+            Self::All => true,
+        }
+    }
+}
+
+impl SandboxPermissions {
+    /// Create a sandbox policy that forbids any effect types which require permissions that have not been granted
+    pub fn sandbox_policy(&self) -> SandboxPolicy {
+        if self.net {
+            SandboxPolicy::default()
+        } else {
+            NET_EFFECT_TYPES
+                .into_iter()
+                .fold(SandboxPolicy::default(), |policy, effect_type| {
+                    policy.deny_effect_type(effect_type)
+                })
+        }
+    }
+    /// Remove any effect handlers whose effect types require permissions that have not been granted
+    ///
+    /// The effect type of each handler is determined by the provided callback, where handlers that are not associated
+    /// with a specific effect type are always retained.
+    pub fn filter_handlers<THandler>(
+        &self,
+        handlers: impl IntoIterator<Item = THandler>,
+        get_effect_type: impl Fn(&THandler) -> Option<&'static str>,
+    ) -> Vec<THandler> {
+        let policy = self.sandbox_policy();
+        handlers
+            .into_iter()
+            .filter(|handler| match get_effect_type(handler) {
+                Some(effect_type) => policy.permits_effect_type(effect_type),
+                None => true,
+            })
+            .collect()
+    }
+    /// Remove any environment variables that have not been granted
+    pub fn filter_env_vars(
+        &self,
+        env_vars: impl IntoIterator<Item = (String, String)>,
+    ) -> Vec<(String, String)> {
+        env_vars
+            .into_iter()
+            .filter(|(name, _)| self.env.permits(name))
+            .collect()
+    }
+    /// Interactively ask the user whether to grant any permissions that have not already been granted
+    ///
+    /// Any prompt that is not explicitly accepted (including on reaching the end of the input) is treated as a denial.
+    pub fn prompt(mut self, mut input: impl BufRead, mut output: impl Write) -> io::Result<Self> {
+        if !self.net {
+            self.net = confirm(
+                "Allow network access (HTTP fetch, GraphQL and gRPC requests)?",
+                &mut input,
+                &mut output,
+            )?;
+        }
+        if !matches!(self.env, EnvPermission::All) {
+            let granted = confirm(
+                "Allow access to all environment variables?",
+                &mut input,
+                &mut output,
+            )?;
+            if granted {
+                self.env = EnvPermission::All;
+            }
+        }
+        Ok(self)
+    }
+}
+
+fn confirm(message: &str, input: &mut impl BufRead, output: &mut impl Write) -> io::Result<bool> {
+    write!(output, "{} [y/N] ", message)?;
+    output.flush()?;
+    let mut response = String::new();
+    input.read_line(&mut response)?;
+    Ok(matches!(
+        response.trim().to_lowercase().as_str(),
+        "y" | "yes"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use reflex::{
+        core::{ExpressionFactory, HeapAllocator, SignalType},
+        sandbox::SandboxViolation,
+    };
+    use reflex_handlers::actor::fetch::create_fetch_effect_type;
+    use reflex_lang::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
+    use reflex_stdlib::Stdlib;
+
+    use super::*;
+
+    #[test]
+    fn sandbox_permissions() {
+        let permissions = SandboxPermissions::default();
+        let policy = permissions.sandbox_policy();
+        assert!(!policy.permits_effect_type(EFFECT_TYPE_FETCH));
+        assert!(!policy.permits_effect_type(EFFECT_TYPE_GRAPHQL));
+        assert!(!policy.permits_effect_type(EFFECT_TYPE_GRPC));
+        assert!(policy.permits_effect_type("reflex::timeout"));
+        assert_eq!(
+            permissions.filter_env_vars([(String::from("FOO"), String::from("foo"))]),
+            Vec::new(),
+        );
+
+        let permissions = SandboxPermissions {
+            net: true,
+            env: EnvPermission::Only(HashSet::from([String::from("FOO")])),
+        };
+        assert!(permissions.sandbox_policy().is_unrestricted());
+        assert_eq!(
+            permissions.filter_env_vars([
+                (String::from("FOO"), String::from("foo")),
+                (String::from("BAR"), String::from("bar")),
+            ]),
+            vec![(String::from("FOO"), String::from("foo"))],
+        );
+    }
+
+    #[test]
+    fn denied_network_effects() {
+        type T = CachedSharedTerm<Stdlib>;
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::<T>::default();
+        let effect = allocator.create_signal(SignalType::Custom {
+            effect_type: create_fetch_effect_type(&factory, &allocator),
+            payload: factory.create_nil_term(),
+            token: factory.create_nil_term(),
+        });
+        assert_eq!(
+            SandboxPermissions::default()
+                .sandbox_policy()
+                .validate_effect::<T>(&effect, &factory),
+            Err(SandboxViolation::EffectType(String::from(
+                EFFECT_TYPE_FETCH
+            ))),
+        );
+        assert_eq!(
+            SandboxPermissions {
+                net: true,
+                env: EnvPermission::None,
+            }
+            .sandbox_policy()
+            .validate_effect::<T>(&effect, &factory),
+            Ok(()),
+        );
+    }
+
+    #[test]
+    fn filter_handlers() {
+        let handlers = [
+            EFFECT_TYPE_FETCH,
+            EFFECT_TYPE_GRAPHQL,
+            EFFECT_TYPE_GRPC,
+            "reflex::timeout",
+            "main",
+        ];
+        let get_effect_type = |handler: &&'static str| match *handler {
+            "main" => None,
+            effect_type => Some(effect_type),
+        };
+        assert_eq!(
+            SandboxPermissions::default().filter_handlers(handlers, get_effect_type),
+            vec!["reflex::timeout", "main"],
+        );
+        assert_eq!(
+            SandboxPermissions {
+                net: true,
+                env: EnvPermission::None,
+            }
+            .filter_handlers(handlers, get_effect_type),
+            Vec::from(handlers),
+        );
+    }
+
+    #[test]
+    fn prompt_permissions() {
+        let mut output = Vec::new();
+        let permissions = SandboxPermissions::default()
+            .prompt("y\nn\n".as_bytes(), &mut output)
+            .unwrap();
+        assert_eq!(
+            permissions,
+            SandboxPermissions {
+                net: true,
+                env: EnvPermission::None,
+            },
+        );
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Allow network access (HTTP fetch, GraphQL and gRPC requests)? [y/N] Allow access to all environment variables? [y/N] ",
+        );
+
+        let mut output = Vec::new();
+        let permissions = SandboxPermissions {
+            net: true,
+            env: EnvPermission::Only(HashSet::from([String::from("FOO")])),
+        }
+        .prompt("yes\n".as_bytes(), &mut output)
+        .unwrap();
+        assert_eq!(permissions.env, EnvPermission::All);
+
+        let permissions = SandboxPermissions::default()
+            .prompt("".as_bytes(), Vec::new())
+            .unwrap();
+        assert_eq!(permissions, SandboxPermissions::default());
+    }
+}
@@ -0,0 +1,76 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::ops::Deref;
+
+use reflex::core::{
+    create_error_expression, uuid, Applicable, ArgType, Arity, BooleanTermType, EvaluationCache,
+    Expression, ExpressionFactory, FunctionArity, HeapAllocator, RefType, StringTermType,
+    StringValue, Uid, Uuid,
+};
+
+pub struct Assert;
+impl Assert {
+    pub const UUID: Uuid = uuid!("d47dcf9e-9cd6-451e-aca9-e7e21ac570d2");
+    const ARITY: FunctionArity<1, 1> = FunctionArity {
+        required: [ArgType::Strict],
+        optional: [ArgType::Strict],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for Assert {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for Assert {
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let condition = args.next().unwrap();
+        let message = args.next();
+        match factory.match_boolean_term(&condition) {
+            Some(term) if term.value() => Ok(factory.create_boolean_term(true)),
+            Some(_) => Ok(create_assertion_error(
+                message.as_ref(),
+                String::from("Expected condition to be true"),
+                factory,
+                allocator,
+            )),
+            None => Err(format!("Expected Boolean, received {}", condition)),
+        }
+    }
+}
+
+pub(crate) fn create_assertion_error<T: Expression>(
+    message: Option<&T>,
+    reason: String,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T {
+    let message = message
+        .and_then(|message| factory.match_string_term(message))
+        .map(|message| String::from(message.value().as_deref().as_str().deref()));
+    let message = match message {
+        Some(message) => format!("Assertion failed: {}: {}", message, reason),
+        None => format!("Assertion failed: {}", reason),
+    };
+    create_error_expression(
+        factory.create_string_term(allocator.create_string(message)),
+        factory,
+        allocator,
+    )
+}
@@ -0,0 +1,72 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{
+    uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
+    FloatTermType, FunctionArity, HeapAllocator, IntTermType, Uid, Uuid,
+};
+
+use crate::stdlib::assert::create_assertion_error;
+
+pub struct AssertEqual;
+impl AssertEqual {
+    pub const UUID: Uuid = uuid!("74c67b2c-194f-486c-b70c-d730b1093a93");
+    const ARITY: FunctionArity<2, 1> = FunctionArity {
+        required: [ArgType::Strict, ArgType::Strict],
+        optional: [ArgType::Strict],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for AssertEqual {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for AssertEqual {
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let actual = args.next().unwrap();
+        let expected = args.next().unwrap();
+        let message = args.next();
+        if is_equal_value(&actual, &expected, factory) {
+            Ok(factory.create_boolean_term(true))
+        } else {
+            Ok(create_assertion_error(
+                message.as_ref(),
+                format!("Expected {}, received {}", expected, actual),
+                factory,
+                allocator,
+            ))
+        }
+    }
+}
+
+fn is_equal_value<T: Expression>(left: &T, right: &T, factory: &impl ExpressionFactory<T>) -> bool {
+    // Numeric values are compared by value, to allow integers to be compared against equivalent floats
+    let left_value = factory
+        .match_int_term(left)
+        .map(|term| term.value() as f64)
+        .or_else(|| factory.match_float_term(left).map(|term| term.value()));
+    let right_value = factory
+        .match_int_term(right)
+        .map(|term| term.value() as f64)
+        .or_else(|| factory.match_float_term(right).map(|term| term.value()));
+    match (left_value, right_value) {
+        (Some(left), Some(right)) => left == right,
+        _ => left.id() == right.id(),
+    }
+}
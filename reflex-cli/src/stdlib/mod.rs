@@ -0,0 +1,100 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::convert::TryFrom;
+
+use reflex::core::{
+    Applicable, Arity, EvaluationCache, Expression, ExpressionFactory, HeapAllocator, Uid, Uuid,
+};
+
+pub mod assert;
+pub mod assert_equal;
+pub mod test_case;
+
+pub use assert::*;
+pub use assert_equal::*;
+pub use test_case::*;
+
+#[derive(Hash, Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Stdlib {
+    Assert,
+    AssertEqual,
+    TestCase,
+}
+impl Stdlib {
+    pub fn entries() -> impl Iterator<Item = Self> {
+        [Self::Assert, Self::AssertEqual, Self::TestCase].into_iter()
+    }
+}
+impl TryFrom<Uuid> for Stdlib {
+    type Error = ();
+    fn try_from(uuid: Uuid) -> Result<Self, Self::Error> {
+        match uuid {
+            Assert::UUID => Ok(Self::Assert),
+            AssertEqual::UUID => Ok(Self::AssertEqual),
+            TestCase::UUID => Ok(Self::TestCase),
+            _ => Err(()),
+        }
+    }
+}
+impl Uid for Stdlib {
+    fn uid(&self) -> Uuid {
+        match self {
+            Self::Assert => Uid::uid(&Assert {}),
+            Self::AssertEqual => Uid::uid(&AssertEqual {}),
+            Self::TestCase => Uid::uid(&TestCase {}),
+        }
+    }
+}
+impl Stdlib {
+    pub fn arity(&self) -> Arity {
+        match self {
+            Self::Assert => Assert::arity(),
+            Self::AssertEqual => AssertEqual::arity(),
+            Self::TestCase => TestCase::arity(),
+        }
+    }
+    pub fn apply<T: Expression>(
+        &self,
+        args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        match self {
+            Self::Assert => Applicable::<T>::apply(&Assert {}, args, factory, allocator, cache),
+            Self::AssertEqual => {
+                Applicable::<T>::apply(&AssertEqual {}, args, factory, allocator, cache)
+            }
+            Self::TestCase => Applicable::<T>::apply(&TestCase {}, args, factory, allocator, cache),
+        }
+    }
+    pub fn should_parallelize<T: Expression>(&self, args: &[T]) -> bool {
+        match self {
+            Self::Assert => Applicable::<T>::should_parallelize(&Assert {}, args),
+            Self::AssertEqual => Applicable::<T>::should_parallelize(&AssertEqual {}, args),
+            Self::TestCase => Applicable::<T>::should_parallelize(&TestCase {}, args),
+        }
+    }
+}
+impl std::fmt::Display for Stdlib {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<test:{:?}>", self)
+    }
+}
+
+impl From<Assert> for Stdlib {
+    fn from(_value: Assert) -> Self {
+        Self::Assert
+    }
+}
+impl From<AssertEqual> for Stdlib {
+    fn from(_value: AssertEqual) -> Self {
+        Self::AssertEqual
+    }
+}
+impl From<TestCase> for Stdlib {
+    fn from(_value: TestCase) -> Self {
+        Self::TestCase
+    }
+}
@@ -0,0 +1,68 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{
+    uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
+    FunctionArity, HeapAllocator, LambdaTermType, Uid, Uuid,
+};
+
+/// Define a named test case, where the test body is a zero-argument function that is invoked by the test runner
+pub struct TestCase;
+impl TestCase {
+    pub const UUID: Uuid = uuid!("d18355b3-677a-4b4a-91a5-8dc6900c54fa");
+    const ARITY: FunctionArity<2, 0> = FunctionArity {
+        required: [ArgType::Strict, ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for TestCase {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for TestCase {
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let name = args.next().unwrap();
+        let body = args.next().unwrap();
+        let is_valid_body = factory
+            .match_lambda_term(&body)
+            .map(|term| term.num_args() == 0)
+            .unwrap_or(false);
+        match (factory.match_string_term(&name), is_valid_body) {
+            (Some(_), true) => Ok(factory.create_record_term(
+                test_case_prototype(factory, allocator),
+                allocator.create_pair(name, body),
+            )),
+            _ => Err(format!(
+                "Expected (String, <function:0>), received ({}, {})",
+                name, body
+            )),
+        }
+    }
+}
+
+pub(crate) fn test_case_prototype<T: Expression>(
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T::StructPrototype {
+    allocator.create_struct_prototype(allocator.create_pair(
+        factory.create_string_term(allocator.create_static_string("name")),
+        factory.create_string_term(allocator.create_static_string("test")),
+    ))
+}
@@ -0,0 +1,762 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+// SPDX-FileContributor: Chris Campbell <c.campbell@mwam.com> https://github.com/c-campbell-mwam
+use std::convert::{TryFrom, TryInto};
+
+use reflex::core::{
+    Applicable, Arity, Builtin, EvaluationCache, Expression, ExpressionFactory, HeapAllocator, Uid,
+    Uuid,
+};
+use reflex_stdlib::stdlib;
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum TestRunnerBuiltins {
+    Stdlib(stdlib::Stdlib),
+    Json(reflex_json::stdlib::Stdlib),
+    Js(reflex_js::stdlib::Stdlib),
+    Lisp(reflex_lisp::stdlib::Stdlib),
+    Handlers(reflex_handlers::stdlib::Stdlib),
+    GraphQl(reflex_graphql::stdlib::Stdlib),
+    Test(crate::stdlib::Stdlib),
+}
+impl From<stdlib::Stdlib> for TestRunnerBuiltins {
+    fn from(target: stdlib::Stdlib) -> Self {
+        TestRunnerBuiltins::Stdlib(target)
+    }
+}
+impl From<reflex_json::stdlib::Stdlib> for TestRunnerBuiltins {
+    fn from(target: reflex_json::stdlib::Stdlib) -> Self {
+        TestRunnerBuiltins::Json(target)
+    }
+}
+impl From<reflex_js::stdlib::Stdlib> for TestRunnerBuiltins {
+    fn from(target: reflex_js::stdlib::Stdlib) -> Self {
+        TestRunnerBuiltins::Js(target)
+    }
+}
+impl From<reflex_lisp::stdlib::Stdlib> for TestRunnerBuiltins {
+    fn from(target: reflex_lisp::stdlib::Stdlib) -> Self {
+        TestRunnerBuiltins::Lisp(target)
+    }
+}
+impl From<reflex_handlers::stdlib::Stdlib> for TestRunnerBuiltins {
+    fn from(target: reflex_handlers::stdlib::Stdlib) -> Self {
+        TestRunnerBuiltins::Handlers(target)
+    }
+}
+impl From<reflex_graphql::stdlib::Stdlib> for TestRunnerBuiltins {
+    fn from(target: reflex_graphql::stdlib::Stdlib) -> Self {
+        TestRunnerBuiltins::GraphQl(target)
+    }
+}
+impl From<crate::stdlib::Stdlib> for TestRunnerBuiltins {
+    fn from(target: crate::stdlib::Stdlib) -> Self {
+        TestRunnerBuiltins::Test(target)
+    }
+}
+impl Uid for TestRunnerBuiltins {
+    fn uid(&self) -> reflex::core::Uuid {
+        match self {
+            TestRunnerBuiltins::Stdlib(term) => term.uid(),
+            TestRunnerBuiltins::Json(term) => term.uid(),
+            TestRunnerBuiltins::Js(term) => term.uid(),
+            TestRunnerBuiltins::Lisp(term) => term.uid(),
+            TestRunnerBuiltins::Handlers(term) => term.uid(),
+            TestRunnerBuiltins::GraphQl(term) => term.uid(),
+            TestRunnerBuiltins::Test(term) => term.uid(),
+        }
+    }
+}
+impl TryFrom<Uuid> for TestRunnerBuiltins {
+    type Error = ();
+    fn try_from(value: Uuid) -> Result<Self, Self::Error> {
+        TryInto::<stdlib::Stdlib>::try_into(value)
+            .map(Self::Stdlib)
+            .or_else(|_| TryInto::<reflex_json::stdlib::Stdlib>::try_into(value).map(Self::Json))
+            .or_else(|_| TryInto::<reflex_js::stdlib::Stdlib>::try_into(value).map(Self::Js))
+            .or_else(|_| TryInto::<reflex_lisp::stdlib::Stdlib>::try_into(value).map(Self::Lisp))
+            .or_else(|_| {
+                TryInto::<reflex_handlers::stdlib::Stdlib>::try_into(value).map(Self::Handlers)
+            })
+            .or_else(|_| {
+                TryInto::<reflex_graphql::stdlib::Stdlib>::try_into(value).map(Self::GraphQl)
+            })
+            .or_else(|_| TryInto::<crate::stdlib::Stdlib>::try_into(value).map(Self::Test))
+    }
+}
+impl Builtin for TestRunnerBuiltins {
+    fn arity(&self) -> Arity {
+        match self {
+            TestRunnerBuiltins::Stdlib(term) => term.arity(),
+            TestRunnerBuiltins::Json(term) => term.arity(),
+            TestRunnerBuiltins::Js(term) => term.arity(),
+            TestRunnerBuiltins::Lisp(term) => term.arity(),
+            TestRunnerBuiltins::Handlers(term) => term.arity(),
+            TestRunnerBuiltins::GraphQl(term) => term.arity(),
+            TestRunnerBuiltins::Test(term) => term.arity(),
+        }
+    }
+    fn apply<T: Expression<Builtin = Self> + Applicable<T>>(
+        &self,
+        args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        match self {
+            TestRunnerBuiltins::Stdlib(term) => term.apply(args, factory, allocator, cache),
+            TestRunnerBuiltins::Json(term) => term.apply(args, factory, allocator, cache),
+            TestRunnerBuiltins::Js(term) => term.apply(args, factory, allocator, cache),
+            TestRunnerBuiltins::Lisp(term) => term.apply(args, factory, allocator, cache),
+            TestRunnerBuiltins::Handlers(term) => term.apply(args, factory, allocator, cache),
+            TestRunnerBuiltins::GraphQl(term) => term.apply(args, factory, allocator, cache),
+            TestRunnerBuiltins::Test(term) => term.apply(args, factory, allocator, cache),
+        }
+    }
+    fn should_parallelize<T: Expression<Builtin = Self> + Applicable<T>>(
+        &self,
+        args: &[T],
+    ) -> bool {
+        match self {
+            TestRunnerBuiltins::Stdlib(term) => term.should_parallelize(args),
+            TestRunnerBuiltins::Json(term) => term.should_parallelize(args),
+            TestRunnerBuiltins::Js(term) => term.should_parallelize(args),
+            TestRunnerBuiltins::Lisp(term) => term.should_parallelize(args),
+            TestRunnerBuiltins::Handlers(term) => term.should_parallelize(args),
+            TestRunnerBuiltins::GraphQl(term) => term.should_parallelize(args),
+            TestRunnerBuiltins::Test(term) => term.should_parallelize(args),
+        }
+    }
+    fn apply_static<T: Expression<Builtin = Self>>(
+        &self,
+        args: &[T],
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+    ) -> Option<T> {
+        match self {
+            TestRunnerBuiltins::Stdlib(term) => term.apply_static(args, factory, allocator),
+            TestRunnerBuiltins::Js(term) => term.apply_static(args, factory, allocator),
+            _ => None,
+        }
+    }
+}
+impl std::fmt::Display for TestRunnerBuiltins {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stdlib(target) => std::fmt::Display::fmt(target, f),
+            Self::Json(target) => std::fmt::Display::fmt(target, f),
+            Self::Js(target) => std::fmt::Display::fmt(target, f),
+            Self::Lisp(target) => std::fmt::Display::fmt(target, f),
+            Self::Handlers(target) => std::fmt::Display::fmt(target, f),
+            Self::GraphQl(target) => std::fmt::Display::fmt(target, f),
+            Self::Test(target) => std::fmt::Display::fmt(target, f),
+        }
+    }
+}
+
+impl From<stdlib::Abs> for TestRunnerBuiltins {
+    fn from(value: stdlib::Abs) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Add> for TestRunnerBuiltins {
+    fn from(value: stdlib::Add) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::And> for TestRunnerBuiltins {
+    fn from(value: stdlib::And) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Apply> for TestRunnerBuiltins {
+    fn from(value: stdlib::Apply) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Ceil> for TestRunnerBuiltins {
+    fn from(value: stdlib::Ceil) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Chain> for TestRunnerBuiltins {
+    fn from(value: stdlib::Chain) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::CollectConstructor> for TestRunnerBuiltins {
+    fn from(value: stdlib::CollectConstructor) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::CollectHashMap> for TestRunnerBuiltins {
+    fn from(value: stdlib::CollectHashMap) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::CollectHashSet> for TestRunnerBuiltins {
+    fn from(value: stdlib::CollectHashSet) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::CollectList> for TestRunnerBuiltins {
+    fn from(value: stdlib::CollectList) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::CollectRecord> for TestRunnerBuiltins {
+    fn from(value: stdlib::CollectRecord) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::CollectSignal> for TestRunnerBuiltins {
+    fn from(value: stdlib::CollectSignal) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::CollectString> for TestRunnerBuiltins {
+    fn from(value: stdlib::CollectString) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Contains> for TestRunnerBuiltins {
+    fn from(value: stdlib::Contains) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateAdd> for TestRunnerBuiltins {
+    fn from(value: stdlib::DateAdd) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateDiff> for TestRunnerBuiltins {
+    fn from(value: stdlib::DateDiff) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateOffset> for TestRunnerBuiltins {
+    fn from(value: stdlib::DateOffset) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateTruncate> for TestRunnerBuiltins {
+    fn from(value: stdlib::DateTruncate) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateWithOffset> for TestRunnerBuiltins {
+    fn from(value: stdlib::DateWithOffset) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Divide> for TestRunnerBuiltins {
+    fn from(value: stdlib::Divide) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Duration> for TestRunnerBuiltins {
+    fn from(value: stdlib::Duration) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Effect> for TestRunnerBuiltins {
+    fn from(value: stdlib::Effect) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::EndsWith> for TestRunnerBuiltins {
+    fn from(value: stdlib::EndsWith) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Eq> for TestRunnerBuiltins {
+    fn from(value: stdlib::Eq) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Equal> for TestRunnerBuiltins {
+    fn from(value: stdlib::Equal) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Eval> for TestRunnerBuiltins {
+    fn from(value: stdlib::Eval) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Filter> for TestRunnerBuiltins {
+    fn from(value: stdlib::Filter) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Flatten> for TestRunnerBuiltins {
+    fn from(value: stdlib::Flatten) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Floor> for TestRunnerBuiltins {
+    fn from(value: stdlib::Floor) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Fold> for TestRunnerBuiltins {
+    fn from(value: stdlib::Fold) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Get> for TestRunnerBuiltins {
+    fn from(value: stdlib::Get) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Gt> for TestRunnerBuiltins {
+    fn from(value: stdlib::Gt) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Gte> for TestRunnerBuiltins {
+    fn from(value: stdlib::Gte) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Hash> for TestRunnerBuiltins {
+    fn from(value: stdlib::Hash) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::If> for TestRunnerBuiltins {
+    fn from(value: stdlib::If) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::IfError> for TestRunnerBuiltins {
+    fn from(value: stdlib::IfError) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::IfPending> for TestRunnerBuiltins {
+    fn from(value: stdlib::IfPending) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Insert> for TestRunnerBuiltins {
+    fn from(value: stdlib::Insert) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Intersperse> for TestRunnerBuiltins {
+    fn from(value: stdlib::Intersperse) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Keys> for TestRunnerBuiltins {
+    fn from(value: stdlib::Keys) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Length> for TestRunnerBuiltins {
+    fn from(value: stdlib::Length) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Lt> for TestRunnerBuiltins {
+    fn from(value: stdlib::Lt) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Lte> for TestRunnerBuiltins {
+    fn from(value: stdlib::Lte) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Map> for TestRunnerBuiltins {
+    fn from(value: stdlib::Map) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Match> for TestRunnerBuiltins {
+    fn from(value: stdlib::Match) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Max> for TestRunnerBuiltins {
+    fn from(value: stdlib::Max) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Memo> for TestRunnerBuiltins {
+    fn from(value: stdlib::Memo) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::MemoStore> for TestRunnerBuiltins {
+    fn from(value: stdlib::MemoStore) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Merge> for TestRunnerBuiltins {
+    fn from(value: stdlib::Merge) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Min> for TestRunnerBuiltins {
+    fn from(value: stdlib::Min) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Multiply> for TestRunnerBuiltins {
+    fn from(value: stdlib::Multiply) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Not> for TestRunnerBuiltins {
+    fn from(value: stdlib::Not) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Or> for TestRunnerBuiltins {
+    fn from(value: stdlib::Or) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Pow> for TestRunnerBuiltins {
+    fn from(value: stdlib::Pow) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Push> for TestRunnerBuiltins {
+    fn from(value: stdlib::Push) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::PushFront> for TestRunnerBuiltins {
+    fn from(value: stdlib::PushFront) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Quote> for TestRunnerBuiltins {
+    fn from(value: stdlib::Quote) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Raise> for TestRunnerBuiltins {
+    fn from(value: stdlib::Raise) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Remainder> for TestRunnerBuiltins {
+    fn from(value: stdlib::Remainder) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Replace> for TestRunnerBuiltins {
+    fn from(value: stdlib::Replace) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::ResolveArgs> for TestRunnerBuiltins {
+    fn from(value: stdlib::ResolveArgs) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::ResolveDeep> for TestRunnerBuiltins {
+    fn from(value: stdlib::ResolveDeep) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::ResolveHashMap> for TestRunnerBuiltins {
+    fn from(value: stdlib::ResolveHashMap) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::ResolveHashSet> for TestRunnerBuiltins {
+    fn from(value: stdlib::ResolveHashSet) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::ResolveRecord> for TestRunnerBuiltins {
+    fn from(value: stdlib::ResolveRecord) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::ResolveList> for TestRunnerBuiltins {
+    fn from(value: stdlib::ResolveList) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Reveal> for TestRunnerBuiltins {
+    fn from(value: stdlib::Reveal) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Round> for TestRunnerBuiltins {
+    fn from(value: stdlib::Round) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Sequence> for TestRunnerBuiltins {
+    fn from(value: stdlib::Sequence) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Slice> for TestRunnerBuiltins {
+    fn from(value: stdlib::Slice) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Sort> for TestRunnerBuiltins {
+    fn from(value: stdlib::Sort) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::SortBy> for TestRunnerBuiltins {
+    fn from(value: stdlib::SortBy) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::SortMerge> for TestRunnerBuiltins {
+    fn from(value: stdlib::SortMerge) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Split> for TestRunnerBuiltins {
+    fn from(value: stdlib::Split) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::StartsWith> for TestRunnerBuiltins {
+    fn from(value: stdlib::StartsWith) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Subtract> for TestRunnerBuiltins {
+    fn from(value: stdlib::Subtract) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Template> for TestRunnerBuiltins {
+    fn from(value: stdlib::Template) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Unzip> for TestRunnerBuiltins {
+    fn from(value: stdlib::Unzip) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Values> for TestRunnerBuiltins {
+    fn from(value: stdlib::Values) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::Zip> for TestRunnerBuiltins {
+    fn from(value: stdlib::Zip) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+
+impl From<reflex_json::stdlib::JsonDeserialize> for TestRunnerBuiltins {
+    fn from(value: reflex_json::stdlib::JsonDeserialize) -> Self {
+        Self::from(reflex_json::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_json::stdlib::JsonSerialize> for TestRunnerBuiltins {
+    fn from(value: reflex_json::stdlib::JsonSerialize) -> Self {
+        Self::from(reflex_json::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_json::stdlib::JsonSerializeCanonical> for TestRunnerBuiltins {
+    fn from(value: reflex_json::stdlib::JsonSerializeCanonical) -> Self {
+        Self::from(reflex_json::stdlib::Stdlib::from(value))
+    }
+}
+
+impl From<reflex_js::stdlib::Accessor> for TestRunnerBuiltins {
+    fn from(value: reflex_js::stdlib::Accessor) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_js::stdlib::Construct> for TestRunnerBuiltins {
+    fn from(value: reflex_js::stdlib::Construct) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_js::stdlib::EncodeUriComponent> for TestRunnerBuiltins {
+    fn from(value: reflex_js::stdlib::EncodeUriComponent) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_js::stdlib::FormatErrorMessage> for TestRunnerBuiltins {
+    fn from(value: reflex_js::stdlib::FormatErrorMessage) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_js::stdlib::IsFinite> for TestRunnerBuiltins {
+    fn from(value: reflex_js::stdlib::IsFinite) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_js::stdlib::IsTruthy> for TestRunnerBuiltins {
+    fn from(value: reflex_js::stdlib::IsTruthy) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_js::stdlib::Log> for TestRunnerBuiltins {
+    fn from(value: reflex_js::stdlib::Log) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_js::stdlib::LogArgs> for TestRunnerBuiltins {
+    fn from(value: reflex_js::stdlib::LogArgs) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_js::stdlib::LogScope> for TestRunnerBuiltins {
+    fn from(value: reflex_js::stdlib::LogScope) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_js::stdlib::ParseDate> for TestRunnerBuiltins {
+    fn from(value: reflex_js::stdlib::ParseDate) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_js::stdlib::ParseFloat> for TestRunnerBuiltins {
+    fn from(value: reflex_js::stdlib::ParseFloat) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_js::stdlib::ParseInt> for TestRunnerBuiltins {
+    fn from(value: reflex_js::stdlib::ParseInt) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_js::stdlib::ScopedLog> for TestRunnerBuiltins {
+    fn from(value: reflex_js::stdlib::ScopedLog) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_js::stdlib::ScopedLogArgs> for TestRunnerBuiltins {
+    fn from(value: reflex_js::stdlib::ScopedLogArgs) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_js::stdlib::Throw> for TestRunnerBuiltins {
+    fn from(value: reflex_js::stdlib::Throw) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_js::stdlib::ToString> for TestRunnerBuiltins {
+    fn from(value: reflex_js::stdlib::ToString) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+
+impl From<reflex_lisp::stdlib::Car> for TestRunnerBuiltins {
+    fn from(value: reflex_lisp::stdlib::Car) -> Self {
+        Self::from(reflex_lisp::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_lisp::stdlib::Cdr> for TestRunnerBuiltins {
+    fn from(value: reflex_lisp::stdlib::Cdr) -> Self {
+        Self::from(reflex_lisp::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_lisp::stdlib::Cons> for TestRunnerBuiltins {
+    fn from(value: reflex_lisp::stdlib::Cons) -> Self {
+        Self::from(reflex_lisp::stdlib::Stdlib::from(value))
+    }
+}
+
+impl From<reflex_handlers::stdlib::ResolveLoaderResults> for TestRunnerBuiltins {
+    fn from(value: reflex_handlers::stdlib::ResolveLoaderResults) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::Scan> for TestRunnerBuiltins {
+    fn from(value: reflex_handlers::stdlib::Scan) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::ToRequest> for TestRunnerBuiltins {
+    fn from(value: reflex_handlers::stdlib::ToRequest) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::GetVariable> for TestRunnerBuiltins {
+    fn from(value: reflex_handlers::stdlib::GetVariable) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::SetVariable> for TestRunnerBuiltins {
+    fn from(value: reflex_handlers::stdlib::SetVariable) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::IncrementVariable> for TestRunnerBuiltins {
+    fn from(value: reflex_handlers::stdlib::IncrementVariable) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::DecrementVariable> for TestRunnerBuiltins {
+    fn from(value: reflex_handlers::stdlib::DecrementVariable) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::Paginate> for TestRunnerBuiltins {
+    fn from(value: reflex_handlers::stdlib::Paginate) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::Throttle> for TestRunnerBuiltins {
+    fn from(value: reflex_handlers::stdlib::Throttle) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::Debounce> for TestRunnerBuiltins {
+    fn from(value: reflex_handlers::stdlib::Debounce) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::Sample> for TestRunnerBuiltins {
+    fn from(value: reflex_handlers::stdlib::Sample) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+
+impl From<reflex_graphql::stdlib::CollectQueryListItems> for TestRunnerBuiltins {
+    fn from(value: reflex_graphql::stdlib::CollectQueryListItems) -> Self {
+        Self::from(reflex_graphql::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_graphql::stdlib::DynamicQueryBranch> for TestRunnerBuiltins {
+    fn from(value: reflex_graphql::stdlib::DynamicQueryBranch) -> Self {
+        Self::from(reflex_graphql::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_graphql::stdlib::FlattenDeep> for TestRunnerBuiltins {
+    fn from(value: reflex_graphql::stdlib::FlattenDeep) -> Self {
+        Self::from(reflex_graphql::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_graphql::stdlib::GraphQlResolver> for TestRunnerBuiltins {
+    fn from(value: reflex_graphql::stdlib::GraphQlResolver) -> Self {
+        Self::from(reflex_graphql::stdlib::Stdlib::from(value))
+    }
+}
+impl From<crate::stdlib::Assert> for TestRunnerBuiltins {
+    fn from(value: crate::stdlib::Assert) -> Self {
+        Self::from(crate::stdlib::Stdlib::from(value))
+    }
+}
+impl From<crate::stdlib::AssertEqual> for TestRunnerBuiltins {
+    fn from(value: crate::stdlib::AssertEqual) -> Self {
+        Self::from(crate::stdlib::Stdlib::from(value))
+    }
+}
+impl From<crate::stdlib::TestCase> for TestRunnerBuiltins {
+    fn from(value: crate::stdlib::TestCase) -> Self {
+        Self::from(crate::stdlib::Stdlib::from(value))
+    }
+}
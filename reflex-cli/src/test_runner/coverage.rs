@@ -0,0 +1,104 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{collections::HashSet, fmt::Write};
+
+use reflex_js::coverage::{CoverageProbe, CoverageProbeKind, SourceLocation};
+
+/// Format a human-readable summary of which instrumented functions and branches were evaluated, listing the source
+/// locations of any probes that were never reached
+///
+/// Modules imported by several test suites are instrumented once per suite, so probes are combined by source location
+/// (a location is considered covered if any of its probes were evaluated).
+pub fn format_coverage_report(probes: &[CoverageProbe], covered: &HashSet<usize>) -> String {
+    let locations = probes.iter().fold(
+        Vec::<(CoverageProbeKind, &SourceLocation, bool)>::new(),
+        |mut results, probe| {
+            let is_covered = covered.contains(&probe.id);
+            match results
+                .iter_mut()
+                .find(|(kind, location, _)| *kind == probe.kind && *location == &probe.location)
+            {
+                Some((_, _, existing)) => *existing = *existing || is_covered,
+                None => results.push((probe.kind, &probe.location, is_covered)),
+            }
+            results
+        },
+    );
+    let mut output = String::new();
+    for (label, kind) in [
+        ("Functions", CoverageProbeKind::Function),
+        ("Branches", CoverageProbeKind::Branch),
+    ] {
+        let (num_covered, num_total) = locations
+            .iter()
+            .filter(|(probe_kind, _, _)| *probe_kind == kind)
+            .fold((0, 0), |(num_covered, num_total), (_, _, is_covered)| {
+                (num_covered + usize::from(*is_covered), num_total + 1)
+            });
+        let _ = writeln!(
+            output,
+            "{}: {}/{} ({:.1}%)",
+            label,
+            num_covered,
+            num_total,
+            format_percentage(num_covered, num_total)
+        );
+    }
+    let uncovered = locations
+        .iter()
+        .filter(|(_, _, is_covered)| !is_covered)
+        .collect::<Vec<_>>();
+    if !uncovered.is_empty() {
+        let _ = writeln!(output, "Not evaluated:");
+        for (kind, location, _) in uncovered {
+            let _ = writeln!(output, "  {} {}", location, kind);
+        }
+    }
+    output
+}
+
+fn format_percentage(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        100.0
+    } else {
+        (numerator as f64 / denominator as f64) * 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn coverage_report() {
+        let create_probe = |id: usize, kind: CoverageProbeKind, line: usize| CoverageProbe {
+            id,
+            kind,
+            location: SourceLocation {
+                path: Some(PathBuf::from("lib.js")),
+                line,
+                column: 1,
+            },
+        };
+        let probes = [
+            create_probe(0, CoverageProbeKind::Function, 1),
+            create_probe(1, CoverageProbeKind::Branch, 2),
+            create_probe(2, CoverageProbeKind::Branch, 3),
+            create_probe(3, CoverageProbeKind::Function, 1),
+        ];
+        assert_eq!(
+            format_coverage_report(&probes, &HashSet::from([0, 1])),
+            [
+                "Functions: 1/1 (100.0%)",
+                "Branches: 1/2 (50.0%)",
+                "Not evaluated:",
+                "  lib.js:3:1 branch",
+                "",
+            ]
+            .join("\n"),
+        );
+    }
+}
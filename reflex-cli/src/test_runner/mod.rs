@@ -0,0 +1,631 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{collections::HashSet, ops::Deref, path::Path};
+
+use reflex::{
+    cache::SubstitutionCache,
+    core::{
+        create_record, evaluate, ConditionListType, ConditionType, DynamicState, Evaluate,
+        Expression, ExpressionFactory, ExpressionListType, HeapAllocator, ListTermType,
+        RecordTermType, Reducible, RefType, Rewritable, SignalTermType, SignalType, StateCache,
+        StringTermType, StringValue, StructPrototypeType,
+    },
+};
+use reflex_js::coverage::{CoverageInstrumentation, COVERAGE_EFFECT_TYPE};
+use reflex_json::JsonValue;
+use reflex_stdlib::ResolveDeep;
+
+use crate::{
+    format_signal_result,
+    stdlib::{Assert, AssertEqual, TestCase},
+};
+
+pub mod builtins;
+pub mod coverage;
+pub mod report;
+
+/// Import path of the module that exposes the test assertion builtins to JavaScript test modules
+pub const TEST_MODULE_IMPORT_PATH: &str = "reflex::test";
+
+pub fn import_test<T: Expression>(
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T
+where
+    T::Builtin: From<Assert> + From<AssertEqual> + From<TestCase>,
+{
+    create_record(
+        [
+            (
+                factory.create_string_term(allocator.create_static_string("assert")),
+                factory.create_builtin_term(Assert),
+            ),
+            (
+                factory.create_string_term(allocator.create_static_string("assertEqual")),
+                factory.create_builtin_term(AssertEqual),
+            ),
+            (
+                factory.create_string_term(allocator.create_static_string("test")),
+                factory.create_builtin_term(TestCase),
+            ),
+        ],
+        factory,
+        allocator,
+    )
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum TestOutcome {
+    Passed,
+    Failed(String),
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct TestResult {
+    pub name: String,
+    pub outcome: TestOutcome,
+}
+
+impl TestResult {
+    pub fn is_passed(&self) -> bool {
+        matches!(&self.outcome, TestOutcome::Passed)
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct TestSuiteResult {
+    pub name: String,
+    pub results: Vec<TestResult>,
+}
+
+impl TestSuiteResult {
+    pub fn num_failures(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|result| !result.is_passed())
+            .count()
+    }
+}
+
+/// Mocked result for effects emitted during the course of a test
+///
+/// Fixtures are matched against effects according to the effect type, and optionally the effect payload (if no
+/// payload is specified, the fixture will match any effect of the given type).
+#[derive(PartialEq, Clone, Debug)]
+pub struct EffectFixture {
+    pub effect_type: String,
+    pub payload: Option<JsonValue>,
+    pub value: JsonValue,
+}
+
+/// Set of mocked effect results, defined in JSON format:
+///
+/// ```json
+/// [
+///   { "type": "reflex::fetch", "payload": ["https://example.com/", "GET", {}, null], "value": [200, "{}"] },
+///   { "type": "reflex::timestamp", "value": 0 }
+/// ]
+/// ```
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct EffectFixtures {
+    fixtures: Vec<EffectFixture>,
+}
+
+impl EffectFixtures {
+    pub fn new(fixtures: impl IntoIterator<Item = EffectFixture>) -> Self {
+        Self {
+            fixtures: fixtures.into_iter().collect(),
+        }
+    }
+    /// Load a set of effect fixtures from a JSON file
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|err| format!("Failed to load fixtures {}: {}", path.display(), err))?;
+        Self::parse(&source).map_err(|err| format!("Invalid fixtures {}: {}", path.display(), err))
+    }
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let value = reflex_json::deserialize(source)?;
+        let fixtures = match value {
+            JsonValue::Array(items) => Ok(items),
+            _ => Err(String::from("Expected fixtures to be an array")),
+        }?;
+        let fixtures = fixtures
+            .into_iter()
+            .enumerate()
+            .map(|(index, fixture)| match fixture {
+                JsonValue::Object(mut fields) => {
+                    let effect_type = match fields.remove("type") {
+                        Some(JsonValue::String(effect_type)) => Ok(effect_type),
+                        _ => Err(format!(
+                            "Invalid fixture at index {}: expected \"type\" field to be a string",
+                            index
+                        )),
+                    }?;
+                    let value = fields.remove("value").ok_or_else(|| {
+                        format!(
+                            "Invalid fixture at index {}: missing \"value\" field",
+                            index
+                        )
+                    })?;
+                    Ok(EffectFixture {
+                        effect_type,
+                        payload: fields.remove("payload"),
+                        value,
+                    })
+                }
+                _ => Err(format!(
+                    "Invalid fixture at index {}: expected object",
+                    index
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { fixtures })
+    }
+    pub fn is_empty(&self) -> bool {
+        self.fixtures.is_empty()
+    }
+    fn resolve<T: Expression>(
+        &self,
+        effect_type: &T,
+        payload: &T,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+    ) -> Option<Result<T, String>> {
+        let effect_type = factory.match_string_term(effect_type)?;
+        let effect_type = effect_type.value();
+        let effect_type = effect_type.as_deref().as_str();
+        let payload = reflex_json::sanitize(payload).ok();
+        self.fixtures
+            .iter()
+            .find(|fixture| {
+                fixture.effect_type.as_str() == effect_type.deref()
+                    && match &fixture.payload {
+                        None => true,
+                        Some(expected) => payload.as_ref() == Some(expected),
+                    }
+            })
+            .map(|fixture| reflex_json::hydrate(fixture.value.clone(), factory, allocator))
+    }
+}
+
+/// Extract the named test cases from a test module's root expression
+///
+/// The module may export a single test case, a list of test cases, or a record whose values are test cases (nested
+/// collections are flattened, with record keys used as name prefixes).
+pub fn collect_test_cases<T: Expression + Rewritable<T> + Reducible<T> + Evaluate<T>>(
+    module: &T,
+    coverage: &mut HashSet<usize>,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> Result<Vec<(String, T)>, String>
+where
+    T::Builtin: From<ResolveDeep>,
+{
+    let expression = factory.create_application_term(
+        factory.create_builtin_term(ResolveDeep),
+        allocator.create_unit_list(module.clone()),
+    );
+    let mut state = StateCache::default();
+    let result = loop {
+        let mut cache = SubstitutionCache::new();
+        let (result, _) =
+            evaluate(&expression, &state, factory, allocator, &mut cache).into_parts();
+        let signal = match factory.match_signal_term(&result) {
+            None => break result,
+            Some(signal) => signal,
+        };
+        let mut updates = Vec::new();
+        for condition in signal.signals().as_deref().iter() {
+            let condition = condition.as_deref();
+            match condition.signal_type() {
+                SignalType::Custom {
+                    effect_type,
+                    payload,
+                    ..
+                } if is_coverage_effect(&effect_type, factory) && !state.has(&condition.id()) => {
+                    updates.push((
+                        condition.id(),
+                        resolve_coverage_effect(&payload, coverage, factory),
+                    ));
+                }
+                _ => return Err(format_signal_result(signal, factory)),
+            }
+        }
+        for (state_token, value) in updates {
+            state.set(state_token, value);
+        }
+    };
+    let mut results = Vec::new();
+    collect_test_cases_inner(&result, None, factory, allocator, &mut results)?;
+    Ok(results)
+}
+
+fn collect_test_cases_inner<T: Expression>(
+    value: &T,
+    prefix: Option<&str>,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+    results: &mut Vec<(String, T)>,
+) -> Result<(), String> {
+    if let Some((name, test)) = match_test_case(value, factory, allocator) {
+        let name = match prefix {
+            Some(prefix) => format!("{} > {}", prefix, name),
+            None => name,
+        };
+        results.push((name, test));
+        Ok(())
+    } else if let Some(term) = factory.match_list_term(value) {
+        term.items().as_deref().iter().try_for_each(|item| {
+            collect_test_cases_inner(item.as_deref(), prefix, factory, allocator, results)
+        })
+    } else if let Some(term) = factory.match_record_term(value) {
+        term.prototype()
+            .as_deref()
+            .keys()
+            .as_deref()
+            .iter()
+            .zip(term.values().as_deref().iter())
+            .try_for_each(|(key, value)| {
+                let key = match factory.match_string_term(key.as_deref()) {
+                    Some(key) => String::from(key.value().as_deref().as_str().deref()),
+                    None => format!("{}", key.as_deref()),
+                };
+                let key = match prefix {
+                    Some(prefix) => format!("{} > {}", prefix, key),
+                    None => key,
+                };
+                collect_test_cases_inner(
+                    value.as_deref(),
+                    Some(key.as_str()),
+                    factory,
+                    allocator,
+                    results,
+                )
+            })
+    } else {
+        Err(format!(
+            "Expected test case or collection of test cases, received {}",
+            value
+        ))
+    }
+}
+
+fn match_test_case<T: Expression>(
+    value: &T,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> Option<(String, T)> {
+    let term = factory.match_record_term(value)?;
+    if term.prototype().as_deref().keys().as_deref().len() != 2 {
+        return None;
+    }
+    let name = term.get(&factory.create_string_term(allocator.create_static_string("name")))?;
+    let test = term.get(&factory.create_string_term(allocator.create_static_string("test")))?;
+    let name = factory
+        .match_string_term(name.as_deref())
+        .map(|name| String::from(name.value().as_deref().as_str().deref()))?;
+    let test = test.as_deref();
+    factory.match_lambda_term(test)?;
+    Some((name, test.clone()))
+}
+
+/// Invoke the given test function, resolving any effects encountered along the way via the provided fixtures
+pub fn run_test_case<T: Expression + Rewritable<T> + Reducible<T> + Evaluate<T>>(
+    test: &T,
+    fixtures: &EffectFixtures,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> TestOutcome
+where
+    T::Builtin: From<ResolveDeep>,
+{
+    run_test_case_with_coverage(test, fixtures, &mut HashSet::new(), factory, allocator)
+}
+
+/// Invoke the given test function, recording the IDs of any coverage probes encountered during evaluation
+pub fn run_test_case_with_coverage<T: Expression + Rewritable<T> + Reducible<T> + Evaluate<T>>(
+    test: &T,
+    fixtures: &EffectFixtures,
+    coverage: &mut HashSet<usize>,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> TestOutcome
+where
+    T::Builtin: From<ResolveDeep>,
+{
+    let expression = factory.create_application_term(
+        factory.create_builtin_term(ResolveDeep),
+        allocator.create_unit_list(
+            factory.create_application_term(test.clone(), allocator.create_empty_list()),
+        ),
+    );
+    let mut state = StateCache::default();
+    loop {
+        // The test body is only expanded during evaluation, so the root expression does not declare any state
+        // dependencies that would allow cached results to be invalidated when the state is updated: use a fresh
+        // cache for each evaluation pass to ensure the latest fixture values are observed
+        let mut cache = SubstitutionCache::new();
+        let (result, _) =
+            evaluate(&expression, &state, factory, allocator, &mut cache).into_parts();
+        let signal = match factory.match_signal_term(&result) {
+            None => return TestOutcome::Passed,
+            Some(signal) => signal,
+        };
+        let mut updates = Vec::new();
+        for condition in signal.signals().as_deref().iter() {
+            let condition = condition.as_deref();
+            match condition.signal_type() {
+                SignalType::Custom {
+                    effect_type,
+                    payload,
+                    ..
+                } if is_coverage_effect(&effect_type, factory) && !state.has(&condition.id()) => {
+                    updates.push((
+                        condition.id(),
+                        resolve_coverage_effect(&payload, coverage, factory),
+                    ));
+                }
+                SignalType::Custom {
+                    effect_type,
+                    payload,
+                    ..
+                } if !state.has(&condition.id()) => {
+                    match fixtures.resolve(&effect_type, &payload, factory, allocator) {
+                        Some(Ok(value)) => updates.push((condition.id(), value)),
+                        Some(Err(err)) => {
+                            return TestOutcome::Failed(format!(
+                                "Invalid fixture for effect <{}> {}: {}",
+                                effect_type, payload, err
+                            ))
+                        }
+                        None => {
+                            return TestOutcome::Failed(format!(
+                                "Unhandled effect: <{}> {}",
+                                effect_type, payload
+                            ))
+                        }
+                    }
+                }
+                _ => return TestOutcome::Failed(format_signal_result(signal, factory)),
+            }
+        }
+        for (state_token, value) in updates {
+            state.set(state_token, value);
+        }
+    }
+}
+
+fn is_coverage_effect<T: Expression>(effect_type: &T, factory: &impl ExpressionFactory<T>) -> bool {
+    match factory.match_string_term(effect_type) {
+        Some(effect_type) => {
+            effect_type.value().as_deref().as_str().deref() == COVERAGE_EFFECT_TYPE
+        }
+        None => false,
+    }
+}
+
+fn resolve_coverage_effect<T: Expression>(
+    payload: &T,
+    coverage: &mut HashSet<usize>,
+    factory: &impl ExpressionFactory<T>,
+) -> T {
+    // Coverage probes are resolved immediately, recording the probe as having been evaluated
+    if let Some(probe_id) = CoverageInstrumentation::parse_effect_payload(payload, factory) {
+        coverage.insert(probe_id);
+    }
+    factory.create_boolean_term(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use reflex::core::ExpressionFactory;
+    use reflex_js::coverage::CoverageProbeKind;
+    use reflex_lang::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
+    use reflex_stdlib::{Add, Effect};
+
+    use super::{builtins::TestRunnerBuiltins, *};
+
+    type T = CachedSharedTerm<TestRunnerBuiltins>;
+
+    #[test]
+    fn assertions() {
+        let factory = SharedTermFactory::<TestRunnerBuiltins>::default();
+        let allocator = DefaultAllocator::<T>::default();
+        let fixtures = EffectFixtures::default();
+        let create_test = |body: T| factory.create_lambda_term(0, body);
+        let sum = factory.create_application_term(
+            factory.create_builtin_term(Add),
+            allocator.create_pair(factory.create_int_term(1), factory.create_int_term(2)),
+        );
+        let test = create_test(factory.create_application_term(
+            factory.create_builtin_term(AssertEqual),
+            allocator.create_pair(sum.clone(), factory.create_int_term(3)),
+        ));
+        assert_eq!(
+            run_test_case(&test, &fixtures, &factory, &allocator),
+            TestOutcome::Passed,
+        );
+        let test = create_test(factory.create_application_term(
+            factory.create_builtin_term(AssertEqual),
+            allocator.create_triple(
+                sum,
+                factory.create_int_term(4),
+                factory.create_string_term(allocator.create_static_string("sum")),
+            ),
+        ));
+        assert_eq!(
+            run_test_case(&test, &fixtures, &factory, &allocator),
+            TestOutcome::Failed(String::from(
+                "Error: \"Assertion failed: sum: Expected 4, received 3\""
+            )),
+        );
+        let test = create_test(factory.create_application_term(
+            factory.create_builtin_term(Assert),
+            allocator.create_unit_list(factory.create_boolean_term(false)),
+        ));
+        assert_eq!(
+            run_test_case(&test, &fixtures, &factory, &allocator),
+            TestOutcome::Failed(String::from(
+                "Error: \"Assertion failed: Expected condition to be true\""
+            )),
+        );
+    }
+
+    #[test]
+    fn effect_fixtures() {
+        let factory = SharedTermFactory::<TestRunnerBuiltins>::default();
+        let allocator = DefaultAllocator::<T>::default();
+        let fixtures = EffectFixtures::parse(
+            r#"[
+                { "type": "foo", "payload": [3], "value": 4 },
+                { "type": "bar", "value": 5 }
+            ]"#,
+        )
+        .unwrap();
+        let create_effect = |effect_type: &'static str, payload: T| {
+            factory.create_application_term(
+                factory.create_builtin_term(Effect),
+                allocator.create_triple(
+                    factory.create_string_term(allocator.create_static_string(effect_type)),
+                    payload,
+                    factory.create_nil_term(),
+                ),
+            )
+        };
+        let test = factory.create_lambda_term(
+            0,
+            factory.create_application_term(
+                factory.create_builtin_term(AssertEqual),
+                allocator.create_pair(
+                    factory.create_application_term(
+                        factory.create_builtin_term(Add),
+                        allocator.create_pair(
+                            create_effect(
+                                "foo",
+                                factory.create_list_term(
+                                    allocator.create_unit_list(factory.create_int_term(3)),
+                                ),
+                            ),
+                            create_effect("bar", factory.create_nil_term()),
+                        ),
+                    ),
+                    factory.create_int_term(9),
+                ),
+            ),
+        );
+        assert_eq!(
+            run_test_case(&test, &fixtures, &factory, &allocator),
+            TestOutcome::Passed,
+        );
+        let test = factory.create_lambda_term(
+            0,
+            create_effect(
+                "foo",
+                factory.create_list_term(allocator.create_unit_list(factory.create_int_term(4))),
+            ),
+        );
+        assert!(matches!(
+            run_test_case(&test, &fixtures, &factory, &allocator),
+            TestOutcome::Failed(message) if message.starts_with("Unhandled effect: <\"foo\">"),
+        ));
+    }
+
+    #[test]
+    fn test_case_discovery() {
+        let factory = SharedTermFactory::<TestRunnerBuiltins>::default();
+        let allocator = DefaultAllocator::<T>::default();
+        let mut coverage = HashSet::new();
+        let create_test_case = |name: &'static str| {
+            factory.create_application_term(
+                factory.create_builtin_term(TestCase),
+                allocator.create_pair(
+                    factory.create_string_term(allocator.create_static_string(name)),
+                    factory.create_lambda_term(0, factory.create_boolean_term(true)),
+                ),
+            )
+        };
+        let module = create_record(
+            [
+                (
+                    factory.create_string_term(allocator.create_static_string("math")),
+                    factory.create_list_term(
+                        allocator.create_pair(create_test_case("add"), create_test_case("sub")),
+                    ),
+                ),
+                (
+                    factory.create_string_term(allocator.create_static_string("other")),
+                    create_test_case("foo"),
+                ),
+            ],
+            &factory,
+            &allocator,
+        );
+        let test_cases = collect_test_cases(&module, &mut coverage, &factory, &allocator).unwrap();
+        assert_eq!(
+            test_cases
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["math > add", "math > sub", "other > foo"],
+        );
+        assert!(collect_test_cases(
+            &factory.create_int_term(3),
+            &mut coverage,
+            &factory,
+            &allocator
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn coverage_instrumentation() {
+        let factory = SharedTermFactory::<TestRunnerBuiltins>::default();
+        let allocator = DefaultAllocator::<T>::default();
+        let fixtures = EffectFixtures::default();
+        let instrumentation = CoverageInstrumentation::default();
+        let env =
+            reflex_js::create_js_env(&factory, &allocator).with_coverage(instrumentation.clone());
+        let expression = reflex_js::parse(
+            "const classify = (value) => (value > 0 ? 'positive' : 'negative');\nclassify(3);",
+            &env,
+            &factory,
+            &allocator,
+        )
+        .unwrap();
+        let mut coverage = HashSet::new();
+        let test = factory.create_lambda_term(0, expression);
+        assert_eq!(
+            run_test_case_with_coverage(&test, &fixtures, &mut coverage, &factory, &allocator),
+            TestOutcome::Passed,
+        );
+        let probes = instrumentation.probes();
+        assert_eq!(
+            probes
+                .iter()
+                .map(|probe| (
+                    probe.kind,
+                    format!("{}", probe.location),
+                    coverage.contains(&probe.id)
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    CoverageProbeKind::Branch,
+                    String::from("<anonymous>:1:42"),
+                    true
+                ),
+                (
+                    CoverageProbeKind::Branch,
+                    String::from("<anonymous>:1:55"),
+                    false
+                ),
+                (
+                    CoverageProbeKind::Function,
+                    String::from("<anonymous>:1:18"),
+                    true
+                ),
+            ],
+        );
+    }
+}
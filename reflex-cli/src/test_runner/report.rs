@@ -0,0 +1,187 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{fmt::Write, str::FromStr};
+
+use crate::test_runner::{TestOutcome, TestSuiteResult};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum TestReportFormat {
+    /// Test Anything Protocol (version 13)
+    #[default]
+    Tap,
+    /// JUnit XML report
+    JUnit,
+}
+
+impl FromStr for TestReportFormat {
+    type Err = anyhow::Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "tap" => Ok(Self::Tap),
+            "junit" | "xml" => Ok(Self::JUnit),
+            _ => Err(anyhow::anyhow!("Unrecognized report format: {}", input)),
+        }
+    }
+}
+
+pub fn format_test_report(format: TestReportFormat, suites: &[TestSuiteResult]) -> String {
+    match format {
+        TestReportFormat::Tap => format_tap_report(suites),
+        TestReportFormat::JUnit => format_junit_report(suites),
+    }
+}
+
+pub fn format_tap_report(suites: &[TestSuiteResult]) -> String {
+    let num_tests = suites
+        .iter()
+        .map(|suite| suite.results.len())
+        .sum::<usize>();
+    let mut output = String::new();
+    let _ = writeln!(output, "TAP version 13");
+    let _ = writeln!(output, "1..{}", num_tests);
+    let results = suites
+        .iter()
+        .flat_map(|suite| suite.results.iter().map(move |result| (suite, result)));
+    for (index, (suite, result)) in results.enumerate() {
+        let name = format!("{}: {}", suite.name, result.name).replace('#', "\\#");
+        match &result.outcome {
+            TestOutcome::Passed => {
+                let _ = writeln!(output, "ok {} - {}", index + 1, name);
+            }
+            TestOutcome::Failed(message) => {
+                let _ = writeln!(output, "not ok {} - {}", index + 1, name);
+                let _ = writeln!(output, "  ---");
+                let _ = writeln!(output, "  message: {:?}", message);
+                let _ = writeln!(output, "  ...");
+            }
+        }
+    }
+    output
+}
+
+pub fn format_junit_report(suites: &[TestSuiteResult]) -> String {
+    let num_tests = suites
+        .iter()
+        .map(|suite| suite.results.len())
+        .sum::<usize>();
+    let num_failures = suites
+        .iter()
+        .map(|suite| suite.num_failures())
+        .sum::<usize>();
+    let mut output = String::new();
+    let _ = writeln!(output, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>");
+    let _ = writeln!(
+        output,
+        "<testsuites tests=\"{}\" failures=\"{}\">",
+        num_tests, num_failures
+    );
+    for suite in suites {
+        let suite_name = escape_xml(&suite.name);
+        let _ = writeln!(
+            output,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\">",
+            suite_name,
+            suite.results.len(),
+            suite.num_failures()
+        );
+        for result in suite.results.iter() {
+            let name = escape_xml(&result.name);
+            match &result.outcome {
+                TestOutcome::Passed => {
+                    let _ = writeln!(
+                        output,
+                        "    <testcase name=\"{}\" classname=\"{}\"/>",
+                        name, suite_name
+                    );
+                }
+                TestOutcome::Failed(message) => {
+                    let _ = writeln!(
+                        output,
+                        "    <testcase name=\"{}\" classname=\"{}\">",
+                        name, suite_name
+                    );
+                    let _ = writeln!(
+                        output,
+                        "      <failure message=\"{}\"/>",
+                        escape_xml(message)
+                    );
+                    let _ = writeln!(output, "    </testcase>");
+                }
+            }
+        }
+        let _ = writeln!(output, "  </testsuite>");
+    }
+    let _ = writeln!(output, "</testsuites>");
+    output
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_runner::TestResult;
+
+    use super::*;
+
+    fn create_suites() -> Vec<TestSuiteResult> {
+        vec![TestSuiteResult {
+            name: String::from("math.test.js"),
+            results: vec![
+                TestResult {
+                    name: String::from("add"),
+                    outcome: TestOutcome::Passed,
+                },
+                TestResult {
+                    name: String::from("compare"),
+                    outcome: TestOutcome::Failed(String::from("Expected 1 < 2")),
+                },
+            ],
+        }]
+    }
+
+    #[test]
+    fn tap_report() {
+        assert_eq!(
+            format_tap_report(&create_suites()),
+            [
+                "TAP version 13",
+                "1..2",
+                "ok 1 - math.test.js: add",
+                "not ok 2 - math.test.js: compare",
+                "  ---",
+                "  message: \"Expected 1 < 2\"",
+                "  ...",
+                "",
+            ]
+            .join("\n"),
+        );
+    }
+
+    #[test]
+    fn junit_report() {
+        assert_eq!(
+            format_junit_report(&create_suites()),
+            [
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>",
+                "<testsuites tests=\"2\" failures=\"1\">",
+                "  <testsuite name=\"math.test.js\" tests=\"2\" failures=\"1\">",
+                "    <testcase name=\"add\" classname=\"math.test.js\"/>",
+                "    <testcase name=\"compare\" classname=\"math.test.js\">",
+                "      <failure message=\"Expected 1 &lt; 2\"/>",
+                "    </testcase>",
+                "  </testsuite>",
+                "</testsuites>",
+                "",
+            ]
+            .join("\n"),
+        );
+    }
+}
@@ -38,6 +38,66 @@ pub trait Named {
     fn name(&self) -> &'static str;
 }
 
+/// Static description of the structure of an action type, used to decode serialized actions (e.g. session recordings)
+/// without compiling against the action type definitions
+pub trait ActionSchema {
+    fn schema() -> ActionSchemaNode;
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ActionSchemaNode {
+    /// Enum that wraps one of several nested action types (serialized as an externally-tagged enum)
+    Group {
+        name: String,
+        variants: Vec<ActionSchemaVariant>,
+    },
+    /// Concrete action type (serialized as an object containing the action fields)
+    Action {
+        name: String,
+        fields: Vec<ActionSchemaField>,
+    },
+}
+impl ActionSchemaNode {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Group { name, .. } => name,
+            Self::Action { name, .. } => name,
+        }
+    }
+    /// Iterate over all the concrete action types within the schema, along with the variant path used to reach them
+    pub fn actions(&self) -> Vec<(Vec<&str>, &ActionSchemaNode)> {
+        let mut results = Vec::new();
+        let mut queue = vec![(Vec::new(), self)];
+        while let Some((path, node)) = queue.pop() {
+            match node {
+                Self::Action { .. } => results.push((path, node)),
+                Self::Group { variants, .. } => {
+                    queue.extend(variants.iter().rev().map(|variant| {
+                        let mut variant_path = path.clone();
+                        variant_path.push(variant.name.as_str());
+                        (variant_path, &variant.schema)
+                    }));
+                }
+            }
+        }
+        results
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct ActionSchemaVariant {
+    pub name: String,
+    pub schema: ActionSchemaNode,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct ActionSchemaField {
+    pub name: String,
+    /// Rust type name of the field (informational only)
+    pub type_name: String,
+}
+
 pub trait SerializableAction: Named {
     fn to_json(&self) -> SerializedAction;
 }
@@ -2,13 +2,18 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 use std::{
-    collections::{hash_map::Entry, HashMap, VecDeque},
+    borrow::Cow,
+    collections::{hash_map::Entry, BTreeMap, HashMap, VecDeque},
     iter::once,
     marker::PhantomData,
     sync::Arc,
+    time::Instant,
 };
 
-use metrics::{describe_gauge, describe_histogram, SharedString, Unit};
+use metrics::{
+    describe_counter, describe_gauge, describe_histogram, histogram, increment_counter,
+    SharedString, Unit,
+};
 use reflex::{
     core::{
         ConditionListType, ConditionType, EvaluationResult, Expression, ExpressionFactory,
@@ -49,6 +54,9 @@ use crate::{
 // TODO: Allow tweaking bytecode interpreter GC trigger
 const MAX_UPDATES_WITHOUT_GC: usize = 3;
 
+const SHADOW_SAMPLE_RESOLUTION: u64 = 10000;
+const SHADOW_METRIC_NAME_SUFFIX: &str = "_shadow";
+
 #[derive(Clone, Copy, Debug)]
 pub struct WasmInterpreterMetricNames {
     pub query_worker_compile_duration: &'static str,
@@ -57,6 +65,8 @@ pub struct WasmInterpreterMetricNames {
     pub query_worker_state_dependency_count: &'static str,
     pub query_worker_evaluation_cache_entry_count: &'static str,
     pub query_worker_evaluation_cache_deep_size: &'static str,
+    pub query_worker_shadow_result_count: &'static str,
+    pub query_worker_shadow_latency_delta: &'static str,
 }
 impl WasmInterpreterMetricNames {
     pub fn init(self) -> Self {
@@ -90,6 +100,16 @@ impl WasmInterpreterMetricNames {
             Unit::Count,
             "A full count of the number of graph nodes in all entries in the query worker evaluation cache"
         );
+        describe_counter!(
+            self.query_worker_shadow_result_count,
+            Unit::Count,
+            "Total number of shadow evaluation results compared against the corresponding primary results"
+        );
+        describe_histogram!(
+            self.query_worker_shadow_latency_delta,
+            Unit::Seconds,
+            "Difference between shadow and primary evaluation completion times (seconds)"
+        );
         self
     }
 }
@@ -102,10 +122,23 @@ impl Default for WasmInterpreterMetricNames {
             query_worker_state_dependency_count: "query_worker_state_dependency_count",
             query_worker_evaluation_cache_deep_size: "query_worker_evaluation_cache_deep_size",
             query_worker_evaluation_cache_entry_count: "query_worker_evaluation_cache_entry_count",
+            query_worker_shadow_result_count: "query_worker_shadow_result_count",
+            query_worker_shadow_latency_delta: "query_worker_shadow_latency_delta",
         }
     }
 }
 
+/// Secondary compiled module used to evaluate a sample of queries alongside the primary module
+///
+/// Shadow results are compared against the primary results and reported via metrics and logs, but are never
+/// served, allowing compiler or runtime upgrades to be validated against live traffic.
+#[derive(Clone, Debug)]
+pub struct WasmShadowEvaluation {
+    pub program: WasmProgram,
+    /// Fraction of queries to evaluate on the shadow module (between 0.0 and 1.0)
+    pub sample_rate: f64,
+}
+
 #[derive(Named, Clone)]
 pub struct WasmInterpreter<T, TFactory, TAllocator, TMetricLabels>
 where
@@ -122,6 +155,7 @@ where
     get_worker_metric_labels: TMetricLabels,
     main_pid: ProcessId,
     dump_heap_snapshot: Option<WasmHeapDumpMode>,
+    shadow_sample_rate: Option<f64>,
     _expression: PhantomData<T>,
 }
 impl<T, TFactory, TAllocator, TMetricLabels> WasmInterpreter<T, TFactory, TAllocator, TMetricLabels>
@@ -150,9 +184,35 @@ where
             get_worker_metric_labels,
             main_pid,
             dump_heap_snapshot,
+            shadow_sample_rate: None,
             _expression: Default::default(),
         }
     }
+    /// Evaluate a sample of queries without serving the results, instead comparing them against the results
+    /// emitted by the primary interpreter
+    pub fn with_shadow_mode(self, sample_rate: f64) -> Self {
+        Self {
+            shadow_sample_rate: Some(sample_rate),
+            ..self
+        }
+    }
+    fn worker_metric_name(&self, metric_name: &'static str) -> Cow<'static, str> {
+        match self.shadow_sample_rate {
+            None => Cow::Borrowed(metric_name),
+            // Report shadow worker timings separately to avoid skewing the primary worker metrics
+            Some(_) => Cow::Owned(format!("{metric_name}{SHADOW_METRIC_NAME_SUFFIX}")),
+        }
+    }
+    fn is_sampled(&self, cache_key: &T::Signal) -> bool {
+        match self.shadow_sample_rate {
+            None => true,
+            // Derive the sample from the query hash to ensure consistent sampling across repeated subscriptions
+            Some(sample_rate) => {
+                ((cache_key.id() % SHADOW_SAMPLE_RESOLUTION) as f64)
+                    < sample_rate * (SHADOW_SAMPLE_RESOLUTION as f64)
+            }
+        }
+    }
 }
 pub struct WasmInterpreterState<T: Expression> {
     // TODO: Use newtypes for state hashmap keys
@@ -204,6 +264,21 @@ struct WasmInterpreterWorkerState<T: Expression> {
     invalidation_strategy: QueryInvalidationStrategy,
     updates_since_gc: usize,
     metrics: BytecodeWorkerStatistics,
+    /// Pending shadow evaluation comparisons, keyed by state index (only used in shadow mode)
+    shadow_comparisons: BTreeMap<Option<MessageOffset>, ShadowComparison<T>>,
+}
+
+struct ShadowComparison<T: Expression> {
+    primary: Option<(EvaluationResult<T>, Instant)>,
+    shadow: Option<(EvaluationResult<T>, Instant)>,
+}
+impl<T: Expression> Default for ShadowComparison<T> {
+    fn default() -> Self {
+        Self {
+            primary: None,
+            shadow: None,
+        }
+    }
 }
 
 enum WasmInterpreterWorkerStatus<T: Expression> {
@@ -319,6 +394,7 @@ dispatcher!({
         Inbox(EvaluateStopAction<T>),
         Inbox(BytecodeInterpreterResultAction<T>),
         Inbox(BytecodeInterpreterGcCompleteAction<T>),
+        Inbox(EvaluateResultAction<T>),
 
         Outbox(EvaluateResultAction<T>),
         Outbox(BytecodeInterpreterInitAction<T>),
@@ -449,6 +525,26 @@ dispatcher!({
         ) -> Option<SchedulerTransition<TAction, TTask>> {
             self.handle_gc_complete_action(state, action, metadata, context)
         }
+
+        fn accept(&self, _action: &EvaluateResultAction<T>) -> bool {
+            self.shadow_sample_rate.is_some()
+        }
+        fn schedule(
+            &self,
+            _action: &EvaluateResultAction<T>,
+            _state: &Self::State,
+        ) -> Option<SchedulerMode> {
+            Some(SchedulerMode::Async)
+        }
+        fn handle(
+            &self,
+            state: &mut Self::State,
+            action: &EvaluateResultAction<T>,
+            metadata: &MessageData,
+            context: &mut impl HandlerContext,
+        ) -> Option<SchedulerTransition<TAction, TTask>> {
+            self.handle_evaluate_result(state, action, metadata, context)
+        }
     }
 });
 
@@ -499,6 +595,9 @@ where
             evaluation_mode,
             invalidation_strategy,
         } = action;
+        if !self.is_sampled(cache_key) {
+            return None;
+        }
         let worker_id = cache_key.id();
         let actions = match state.workers.entry(worker_id) {
             Entry::Occupied(_) => None,
@@ -515,6 +614,7 @@ where
                     invalidation_strategy: *invalidation_strategy,
                     updates_since_gc: 0,
                     metrics: Default::default(),
+                    shadow_comparisons: Default::default(),
                 });
                 Some(SchedulerTransition::new([
                     SchedulerCommand::Task(
@@ -528,18 +628,14 @@ where
                             evaluation_mode: *evaluation_mode,
                             wasm_module: self.program.clone(),
                             metric_names: WasmWorkerMetricNames {
-                                query_worker_compile_duration: self
-                                    .metric_names
-                                    .query_worker_compile_duration
-                                    .into(),
-                                query_worker_evaluate_duration: self
-                                    .metric_names
-                                    .query_worker_evaluate_duration
-                                    .into(),
+                                query_worker_compile_duration: self.worker_metric_name(
+                                    self.metric_names.query_worker_compile_duration,
+                                ),
+                                query_worker_evaluate_duration: self.worker_metric_name(
+                                    self.metric_names.query_worker_evaluate_duration,
+                                ),
                                 query_worker_gc_duration: self
-                                    .metric_names
-                                    .query_worker_gc_duration
-                                    .into(),
+                                    .worker_metric_name(self.metric_names.query_worker_gc_duration),
                             },
                             caller_pid: current_pid,
                             dump_heap_snapshot: self.dump_heap_snapshot,
@@ -659,11 +755,37 @@ where
             worker_pid,
         ))))
     }
+    fn handle_evaluate_result<TAction, TTask>(
+        &self,
+        state: &mut WasmInterpreterState<T>,
+        action: &EvaluateResultAction<T>,
+        metadata: &MessageData,
+        _context: &mut impl HandlerContext,
+    ) -> Option<SchedulerTransition<TAction, TTask>>
+    where
+        TAction: Action,
+        TTask: TaskFactory<TAction, TTask>,
+    {
+        let EvaluateResultAction {
+            cache_key,
+            state_index,
+            result,
+        } = action;
+        let worker_id = cache_key.id();
+        let worker_state = state.workers.get_mut(&worker_id)?;
+        let comparison = worker_state
+            .shadow_comparisons
+            .entry(*state_index)
+            .or_default();
+        comparison.primary = Some((result.clone(), metadata.timestamp));
+        self.report_shadow_comparison(worker_state, *state_index);
+        None
+    }
     fn wasm_bytecode_interpreter_result<TAction, TTask>(
         &self,
         state: &mut WasmInterpreterState<T>,
         action: &BytecodeInterpreterResultAction<T>,
-        _metadata: &MessageData,
+        metadata: &MessageData,
         _context: &mut impl HandlerContext,
     ) -> Option<SchedulerTransition<TAction, TTask>>
     where
@@ -717,8 +839,8 @@ where
                 None
             }
         };
-        Some(SchedulerTransition::new(
-            once(SchedulerCommand::Send(
+        let result_action = match self.shadow_sample_rate {
+            None => Some(SchedulerCommand::Send(
                 self.main_pid,
                 EvaluateResultAction {
                     cache_key: cache_key.clone(),
@@ -726,18 +848,84 @@ where
                     result: result.clone(),
                 }
                 .into(),
-            ))
-            .chain(gc_action.map(|action| {
-                let worker_pid = worker_state.pid;
-                SchedulerCommand::Send(worker_pid, action.into())
-            }))
-            .chain(queued_evaluation.map(|(action, remaining_queue)| {
-                let worker_pid = worker_state.pid;
-                worker_state.status = WasmInterpreterWorkerStatus::Working(remaining_queue);
-                SchedulerCommand::Send(worker_pid, action.into())
-            })),
+            )),
+            Some(_) => {
+                // Shadow results are never served, only compared against the primary result
+                let comparison = worker_state
+                    .shadow_comparisons
+                    .entry(*state_index)
+                    .or_default();
+                comparison.shadow = Some((result.clone(), metadata.timestamp));
+                self.report_shadow_comparison(worker_state, *state_index);
+                None
+            }
+        };
+        Some(SchedulerTransition::new(
+            result_action
+                .into_iter()
+                .chain(gc_action.map(|action| {
+                    let worker_pid = worker_state.pid;
+                    SchedulerCommand::Send(worker_pid, action.into())
+                }))
+                .chain(queued_evaluation.map(|(action, remaining_queue)| {
+                    let worker_pid = worker_state.pid;
+                    worker_state.status = WasmInterpreterWorkerStatus::Working(remaining_queue);
+                    SchedulerCommand::Send(worker_pid, action.into())
+                })),
         ))
     }
+    fn report_shadow_comparison(
+        &self,
+        worker_state: &mut WasmInterpreterWorkerState<T>,
+        state_index: Option<MessageOffset>,
+    ) -> Option<()> {
+        let comparison = worker_state.shadow_comparisons.get(&state_index)?;
+        let (primary_result, primary_timestamp) = comparison.primary.as_ref()?;
+        let (shadow_result, shadow_timestamp) = comparison.shadow.as_ref()?;
+        let is_match = primary_result.result().id() == shadow_result.result().id();
+        let latency_delta = if shadow_timestamp >= primary_timestamp {
+            shadow_timestamp
+                .duration_since(*primary_timestamp)
+                .as_secs_f64()
+        } else {
+            -primary_timestamp
+                .duration_since(*shadow_timestamp)
+                .as_secs_f64()
+        };
+        let worker_labels = self
+            .get_worker_metric_labels
+            .labels(worker_state.label.as_str());
+        let result_labels = worker_labels
+            .iter()
+            .cloned()
+            .chain(once((
+                SharedString::from("result"),
+                SharedString::from(if is_match { "match" } else { "mismatch" }),
+            )))
+            .collect::<Vec<_>>();
+        increment_counter!(
+            self.metric_names.query_worker_shadow_result_count,
+            &result_labels
+        );
+        histogram!(
+            self.metric_names.query_worker_shadow_latency_delta,
+            latency_delta,
+            &worker_labels
+        );
+        if !is_match {
+            println!(
+                "[{}] Shadow evaluation result mismatch: expected {}, received {}",
+                worker_state.label,
+                primary_result.result(),
+                shadow_result.result(),
+            );
+        }
+        // Discard the completed comparison along with any earlier comparisons that have been superseded
+        let remaining_comparisons = worker_state.shadow_comparisons.split_off(&state_index);
+        worker_state.shadow_comparisons = remaining_comparisons;
+        worker_state.shadow_comparisons.remove(&state_index);
+        Some(())
+    }
     fn update_worker_cache_metrics(
         &self,
         state: &mut WasmInterpreterState<T>,
@@ -748,6 +936,10 @@ where
         let worker_id = cache_key.id();
         let worker_state = state.workers.get_mut(&worker_id)?;
         worker_state.metrics = statistics;
+        // Shadow workers are excluded from the worker cache metrics, which reflect the primary workers
+        if self.shadow_sample_rate.is_some() {
+            return Some(());
+        }
         let worker_state = state.workers.get(&worker_id)?;
         // Determine the metric labels to be applied to this worker
         let worker_metrics = state.grouped_worker_metrics.get(&worker_state.label)?;
@@ -809,3 +1001,126 @@ fn is_unresolved_effect<T: Expression<Signal = V>, V: ConditionType<T>>(effect:
         SignalType::Pending | SignalType::Custom { .. } => true,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use reflex::core::{DependencyList, HeapAllocator};
+    use reflex_lang::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
+    use reflex_stdlib::Stdlib;
+
+    use super::*;
+
+    type T = CachedSharedTerm<Stdlib>;
+    type TFactory = SharedTermFactory<Stdlib>;
+    type TAllocator = DefaultAllocator<T>;
+    type TMetricLabels = fn(&str) -> Vec<(SharedString, SharedString)>;
+
+    #[test]
+    fn shadow_sampling() {
+        let factory = TFactory::default();
+        let allocator = TAllocator::default();
+        let cache_keys = (0..1000)
+            .map(|index| create_cache_key(index, &factory, &allocator))
+            .collect::<Vec<_>>();
+        let count_sampled =
+            |interpreter: &WasmInterpreter<T, TFactory, TAllocator, TMetricLabels>| {
+                cache_keys
+                    .iter()
+                    .filter(|cache_key| interpreter.is_sampled(cache_key))
+                    .count()
+            };
+        let interpreter = create_interpreter(&factory, &allocator);
+        assert_eq!(count_sampled(&interpreter), 1000);
+        let interpreter = create_interpreter(&factory, &allocator).with_shadow_mode(1.0);
+        assert_eq!(count_sampled(&interpreter), 1000);
+        let interpreter = create_interpreter(&factory, &allocator).with_shadow_mode(0.0);
+        assert_eq!(count_sampled(&interpreter), 0);
+        let interpreter = create_interpreter(&factory, &allocator).with_shadow_mode(0.5);
+        let num_sampled = count_sampled(&interpreter);
+        assert!(num_sampled > 400 && num_sampled < 600);
+    }
+
+    #[test]
+    fn shadow_comparisons() {
+        let factory = TFactory::default();
+        let allocator = TAllocator::default();
+        let interpreter = create_interpreter(&factory, &allocator).with_shadow_mode(1.0);
+        let mut worker_state = WasmInterpreterWorkerState::<T> {
+            pid: ProcessId::from(1),
+            label: String::from("foo"),
+            state_index: None,
+            status: WasmInterpreterWorkerStatus::Idle,
+            invalidation_strategy: QueryInvalidationStrategy::default(),
+            updates_since_gc: 0,
+            metrics: Default::default(),
+            shadow_comparisons: Default::default(),
+        };
+        let timestamp = Instant::now();
+        let create_result = |value: i64| {
+            EvaluationResult::new(factory.create_int_term(value), DependencyList::empty())
+        };
+        for state_index in [
+            None,
+            Some(MessageOffset::from(1)),
+            Some(MessageOffset::from(2)),
+        ] {
+            worker_state
+                .shadow_comparisons
+                .entry(state_index)
+                .or_default()
+                .primary = Some((create_result(3), timestamp));
+        }
+
+        // Comparisons are retained until both results have been received
+        assert!(interpreter
+            .report_shadow_comparison(&mut worker_state, Some(MessageOffset::from(1)))
+            .is_none());
+        assert_eq!(worker_state.shadow_comparisons.len(), 3);
+
+        // Completed comparisons are discarded along with any superseded comparisons
+        worker_state
+            .shadow_comparisons
+            .entry(Some(MessageOffset::from(1)))
+            .or_default()
+            .shadow = Some((create_result(4), timestamp));
+        assert!(interpreter
+            .report_shadow_comparison(&mut worker_state, Some(MessageOffset::from(1)))
+            .is_some());
+        assert_eq!(
+            worker_state
+                .shadow_comparisons
+                .keys()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![Some(MessageOffset::from(2))],
+        );
+    }
+
+    fn create_interpreter(
+        factory: &TFactory,
+        allocator: &TAllocator,
+    ) -> WasmInterpreter<T, TFactory, TAllocator, TMetricLabels> {
+        WasmInterpreter::new(
+            WasmProgram::from_wasm(Vec::new()),
+            "",
+            *factory,
+            allocator.clone(),
+            WasmInterpreterMetricNames::default(),
+            |_| Vec::new(),
+            ProcessId::from(0),
+            None,
+        )
+    }
+
+    fn create_cache_key(
+        index: i64,
+        factory: &TFactory,
+        allocator: &TAllocator,
+    ) -> <T as Expression>::Signal {
+        allocator.create_signal(SignalType::Custom {
+            effect_type: factory.create_string_term(allocator.create_static_string("foo")),
+            payload: factory.create_int_term(index),
+            token: factory.create_nil_term(),
+        })
+    }
+}
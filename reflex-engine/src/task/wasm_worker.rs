@@ -45,8 +45,9 @@ use reflex_utils::{
 };
 use reflex_wasm::{
     allocator::{Arena, ArenaAllocator, ArenaMut, VecAllocator},
-    cache::{EvaluationCache, EvaluationCacheBucket},
+    cache::{EvaluationCache, EvaluationCacheBucket, EFFECT_TYPE_CACHE},
     factory::WasmTermFactory,
+    heap_snapshot::encode_heap_snapshot,
     interpreter::{InterpreterError, UnboundEvaluationResult, WasmInterpreter, WasmProgram},
     serialize::SerializerState,
     term_type::{
@@ -60,8 +61,6 @@ use serde::{Deserialize, Serialize};
 
 use crate::task::bytecode_worker::BytecodeWorkerAction;
 
-const EFFECT_TYPE_CACHE: &str = "reflex::cache";
-
 /// Criteria governing whether to dump the state of the heap at the point of evaluation
 #[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct WasmHeapDumpMode {
@@ -1786,7 +1785,7 @@ where
                                         worker_state.evaluation_cache_initial_cell,
                                     ),
                                 }
-                                bytes
+                                encode_heap_snapshot(&bytes)
                             };
                             let output_filename = format!(
                                 "{}_{}_{}_{}.bin",
@@ -0,0 +1,128 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex_json::{JsonMap, JsonValue};
+
+/// Set of error payload fields that are promoted into the `extensions` object of GraphQL error responses
+///
+/// Error signals whose payload is a record (e.g. `{ message: "…", code: "NOT_FOUND", retryable: false }`) are serialized
+/// as-is by default. Any configured fields present on the payload are moved into the GraphQL error `extensions` field,
+/// merging with any existing `extensions` object on the payload, so that clients can rely on machine-readable metadata
+/// being exposed in a consistent location regardless of how the error was raised.
+#[derive(Hash, Eq, PartialEq, Default, Clone, Debug)]
+pub struct GraphQlErrorExtensions {
+    fields: Vec<String>,
+}
+impl GraphQlErrorExtensions {
+    pub fn new(fields: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            fields: fields.into_iter().map(|field| field.into()).collect(),
+        }
+    }
+    pub fn fields(&self) -> impl Iterator<Item = &str> {
+        self.fields.iter().map(|field| field.as_str())
+    }
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+    /// Move any configured fields of the provided serialized error object into its `extensions` field
+    pub fn apply(&self, error: JsonValue) -> JsonValue {
+        match error {
+            JsonValue::Object(mut error) if !self.is_empty() => {
+                let promoted_fields = self
+                    .fields
+                    .iter()
+                    .filter_map(|field| error.remove(field).map(|value| (field.clone(), value)))
+                    .collect::<Vec<_>>();
+                if promoted_fields.is_empty() {
+                    return JsonValue::Object(error);
+                }
+                // The GraphQL spec requires extensions to be a map, so any invalid existing value is discarded
+                let mut extensions = match error.remove("extensions") {
+                    Some(JsonValue::Object(extensions)) => extensions,
+                    _ => JsonMap::new(),
+                };
+                extensions.extend(promoted_fields);
+                error.insert(String::from("extensions"), JsonValue::Object(extensions));
+                JsonValue::Object(error)
+            }
+            error => error,
+        }
+    }
+}
+impl std::fmt::Display for GraphQlErrorExtensions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.fields.join(","))
+    }
+}
+impl std::str::FromStr for GraphQlErrorExtensions {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields = s
+            .split(',')
+            .map(|field| field.trim())
+            .filter(|field| !field.is_empty())
+            .map(|field| match field {
+                "message" | "extensions" => {
+                    Err(format!("Invalid GraphQL error extension field: {}", field))
+                }
+                _ => Ok(field),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::new(fields))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reflex_json::json;
+
+    use super::*;
+
+    #[test]
+    fn promote_error_fields() {
+        let extensions = GraphQlErrorExtensions::new(["code", "retryable"]);
+        assert_eq!(
+            extensions.apply(json!({ "message": "foo", "code": "NOT_FOUND", "retryable": false })),
+            json!({ "message": "foo", "extensions": { "code": "NOT_FOUND", "retryable": false } }),
+        );
+        assert_eq!(
+            extensions.apply(json!({
+                "message": "foo",
+                "code": "NOT_FOUND",
+                "extensions": { "correlationId": "bar" },
+            })),
+            json!({
+                "message": "foo",
+                "extensions": { "correlationId": "bar", "code": "NOT_FOUND" },
+            }),
+        );
+        assert_eq!(
+            extensions.apply(json!({ "message": "foo", "path": ["bar"] })),
+            json!({ "message": "foo", "path": ["bar"] }),
+        );
+        assert_eq!(extensions.apply(json!("foo")), json!("foo"));
+        assert_eq!(
+            GraphQlErrorExtensions::default()
+                .apply(json!({ "message": "foo", "code": "NOT_FOUND" })),
+            json!({ "message": "foo", "code": "NOT_FOUND" }),
+        );
+    }
+
+    #[test]
+    fn parse_error_extensions() {
+        assert_eq!(
+            "code, retryable".parse::<GraphQlErrorExtensions>(),
+            Ok(GraphQlErrorExtensions::new(["code", "retryable"])),
+        );
+        assert_eq!(
+            "".parse::<GraphQlErrorExtensions>(),
+            Ok(GraphQlErrorExtensions::default()),
+        );
+        assert!("code,message".parse::<GraphQlErrorExtensions>().is_err());
+        assert_eq!(
+            format!("{}", GraphQlErrorExtensions::new(["code", "retryable"])),
+            "code,retryable",
+        );
+    }
+}
@@ -7,9 +7,9 @@ use std::{borrow::Cow, collections::HashMap, iter::once};
 
 use reflex::core::{
     create_record, Builtin, ConditionListType, ConditionType, Expression, ExpressionFactory,
-    HeapAllocator, RefType, SignalTermType, SignalType,
+    FloatFormat, HeapAllocator, RefType, SignalTermType, SignalType,
 };
-use reflex_json::{sanitize, JsonMap, JsonValue};
+use reflex_json::{format_json_floats, sanitize, JsonMap, JsonValue};
 use reflex_stdlib::{Apply, CollectList, Get};
 use reflex_utils::json::json_object;
 use serde::{Deserialize, Serialize};
@@ -21,15 +21,19 @@ use crate::ast::{
 };
 
 pub mod ast;
+pub mod errors;
 pub mod imports;
 pub mod operation;
 pub mod stdlib;
 pub mod subscriptions;
 pub mod transform;
+pub mod truncate;
 pub mod validate;
 
+pub use errors::GraphQlErrorExtensions;
 pub use graphql_parser;
 pub use operation::{graphql_variables_are_equal, GraphQlOperation};
+pub use truncate::GraphQlResultSizeLimit;
 
 pub trait GraphQlParserBuiltin:
     Builtin + From<Apply> + From<CollectList> + From<DynamicQueryBranch> + From<FlattenDeep> + From<Get>
@@ -408,6 +412,8 @@ pub fn parse_graphql_operation_type(
 
 pub fn serialize_graphql_result_payload<T: Expression>(
     result: &T,
+    float_format: FloatFormat,
+    error_extensions: &GraphQlErrorExtensions,
     factory: &impl ExpressionFactory<T>,
 ) -> Result<JsonValue, Vec<JsonValue>> {
     match factory.match_signal_term(result) {
@@ -420,10 +426,38 @@ pub fn serialize_graphql_result_payload<T: Expression>(
                 )]
             } else {
                 errors
+                    .into_iter()
+                    .map(|error| error_extensions.apply(error))
+                    .collect()
             })
         }
         None => match reflex_json::sanitize(result) {
-            Ok(result) => Ok(result),
+            Ok(result) => Ok(format_json_floats(result, float_format)),
+            Err(message) => Err(vec![create_json_error_object(message, None)]),
+        },
+    }
+}
+
+/// Serialize the provided evaluation result as a GraphQL result payload, truncating the payload to fit within the
+/// provided size limit
+///
+/// The size limit is enforced while the result is being serialized (see [`GraphQlResultSizeLimit::serialize`]). If the
+/// payload was truncated, a warning is returned alongside the payload for use with
+/// [`create_graphql_truncated_success_response`].
+pub fn serialize_graphql_truncated_result_payload<T: Expression>(
+    result: &T,
+    float_format: FloatFormat,
+    error_extensions: &GraphQlErrorExtensions,
+    size_limit: &GraphQlResultSizeLimit,
+    factory: &impl ExpressionFactory<T>,
+) -> Result<(JsonValue, Option<JsonValue>), Vec<JsonValue>> {
+    match factory.match_signal_term(result) {
+        Some(_) => {
+            serialize_graphql_result_payload(result, float_format, error_extensions, factory)
+                .map(|payload| (payload, None))
+        }
+        None => match size_limit.serialize(result, factory) {
+            Ok((payload, warning)) => Ok((format_json_floats(payload, float_format), warning)),
             Err(message) => Err(vec![create_json_error_object(message, None)]),
         },
     }
@@ -440,6 +474,28 @@ pub fn create_graphql_success_response(result: JsonValue) -> JsonValue {
     json_object(once((String::from("data"), result)))
 }
 
+/// Create a GraphQL success response for a result payload that may have been truncated to fit within a size limit
+///
+/// Truncated responses include the provided warning within the `warnings` field of the response `extensions` object.
+pub fn create_graphql_truncated_success_response(
+    result: JsonValue,
+    truncation_warning: Option<JsonValue>,
+) -> JsonValue {
+    match truncation_warning {
+        None => create_graphql_success_response(result),
+        Some(warning) => json_object([
+            (String::from("data"), result),
+            (
+                String::from("extensions"),
+                json_object(once((
+                    String::from("warnings"),
+                    JsonValue::Array(vec![warning]),
+                ))),
+            ),
+        ]),
+    }
+}
+
 pub fn create_graphql_error_response(errors: impl IntoIterator<Item = JsonValue>) -> JsonValue {
     json_object(once((
         String::from("errors"),
@@ -1200,11 +1256,41 @@ mod tests {
             Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
         }
     }
+    impl From<reflex_stdlib::stdlib::DateAdd> for GraphQlTestBuiltins {
+        fn from(value: reflex_stdlib::stdlib::DateAdd) -> Self {
+            Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<reflex_stdlib::stdlib::DateDiff> for GraphQlTestBuiltins {
+        fn from(value: reflex_stdlib::stdlib::DateDiff) -> Self {
+            Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<reflex_stdlib::stdlib::DateOffset> for GraphQlTestBuiltins {
+        fn from(value: reflex_stdlib::stdlib::DateOffset) -> Self {
+            Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<reflex_stdlib::stdlib::DateTruncate> for GraphQlTestBuiltins {
+        fn from(value: reflex_stdlib::stdlib::DateTruncate) -> Self {
+            Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<reflex_stdlib::stdlib::DateWithOffset> for GraphQlTestBuiltins {
+        fn from(value: reflex_stdlib::stdlib::DateWithOffset) -> Self {
+            Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+        }
+    }
     impl From<reflex_stdlib::stdlib::Divide> for GraphQlTestBuiltins {
         fn from(value: reflex_stdlib::stdlib::Divide) -> Self {
             Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
         }
     }
+    impl From<reflex_stdlib::stdlib::Duration> for GraphQlTestBuiltins {
+        fn from(value: reflex_stdlib::stdlib::Duration) -> Self {
+            Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+        }
+    }
     impl From<reflex_stdlib::stdlib::Effect> for GraphQlTestBuiltins {
         fn from(value: reflex_stdlib::stdlib::Effect) -> Self {
             Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -1225,6 +1311,11 @@ mod tests {
             Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
         }
     }
+    impl From<reflex_stdlib::stdlib::Eval> for GraphQlTestBuiltins {
+        fn from(value: reflex_stdlib::stdlib::Eval) -> Self {
+            Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+        }
+    }
     impl From<reflex_stdlib::stdlib::Filter> for GraphQlTestBuiltins {
         fn from(value: reflex_stdlib::stdlib::Filter) -> Self {
             Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -1315,11 +1406,26 @@ mod tests {
             Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
         }
     }
+    impl From<reflex_stdlib::stdlib::Match> for GraphQlTestBuiltins {
+        fn from(value: reflex_stdlib::stdlib::Match) -> Self {
+            Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+        }
+    }
     impl From<reflex_stdlib::stdlib::Max> for GraphQlTestBuiltins {
         fn from(value: reflex_stdlib::stdlib::Max) -> Self {
             Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
         }
     }
+    impl From<reflex_stdlib::stdlib::Memo> for GraphQlTestBuiltins {
+        fn from(value: reflex_stdlib::stdlib::Memo) -> Self {
+            Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<reflex_stdlib::stdlib::MemoStore> for GraphQlTestBuiltins {
+        fn from(value: reflex_stdlib::stdlib::MemoStore) -> Self {
+            Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+        }
+    }
     impl From<reflex_stdlib::stdlib::Merge> for GraphQlTestBuiltins {
         fn from(value: reflex_stdlib::stdlib::Merge) -> Self {
             Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -1360,6 +1466,11 @@ mod tests {
             Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
         }
     }
+    impl From<reflex_stdlib::stdlib::Quote> for GraphQlTestBuiltins {
+        fn from(value: reflex_stdlib::stdlib::Quote) -> Self {
+            Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+        }
+    }
     impl From<reflex_stdlib::stdlib::Raise> for GraphQlTestBuiltins {
         fn from(value: reflex_stdlib::stdlib::Raise) -> Self {
             Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -1405,6 +1516,11 @@ mod tests {
             Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
         }
     }
+    impl From<reflex_stdlib::stdlib::Reveal> for GraphQlTestBuiltins {
+        fn from(value: reflex_stdlib::stdlib::Reveal) -> Self {
+            Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+        }
+    }
     impl From<reflex_stdlib::stdlib::Round> for GraphQlTestBuiltins {
         fn from(value: reflex_stdlib::stdlib::Round) -> Self {
             Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -1420,6 +1536,21 @@ mod tests {
             Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
         }
     }
+    impl From<reflex_stdlib::stdlib::Sort> for GraphQlTestBuiltins {
+        fn from(value: reflex_stdlib::stdlib::Sort) -> Self {
+            Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<reflex_stdlib::stdlib::SortBy> for GraphQlTestBuiltins {
+        fn from(value: reflex_stdlib::stdlib::SortBy) -> Self {
+            Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<reflex_stdlib::stdlib::SortMerge> for GraphQlTestBuiltins {
+        fn from(value: reflex_stdlib::stdlib::SortMerge) -> Self {
+            Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+        }
+    }
     impl From<reflex_stdlib::stdlib::Split> for GraphQlTestBuiltins {
         fn from(value: reflex_stdlib::stdlib::Split) -> Self {
             Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -1435,6 +1566,11 @@ mod tests {
             Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
         }
     }
+    impl From<reflex_stdlib::stdlib::Template> for GraphQlTestBuiltins {
+        fn from(value: reflex_stdlib::stdlib::Template) -> Self {
+            Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+        }
+    }
     impl From<reflex_stdlib::stdlib::Unzip> for GraphQlTestBuiltins {
         fn from(value: reflex_stdlib::stdlib::Unzip) -> Self {
             Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -0,0 +1,424 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::convert::Infallible;
+
+use reflex::core::{
+    Expression, ExpressionFactory, ExpressionListType, ListTermType, RecordTermType, RefType,
+    StructPrototypeType,
+};
+use reflex_json::{sanitize, JsonMap, JsonValue};
+use reflex_utils::json::json_object;
+
+/// Field name used to mark lists and records whose contents have been truncated
+///
+/// Truncated lists are terminated with an additional `{ "__truncated": <count> }` item, and truncated records are given
+/// an additional `"__truncated": <count>` field, where `<count>` is the number of omitted items or fields.
+pub const GRAPHQL_TRUNCATION_MARKER: &str = "__truncated";
+
+/// Error code reported in the response extensions warning when a result has been truncated
+pub const GRAPHQL_TRUNCATION_WARNING_CODE: &str = "RESULT_TRUNCATED";
+
+/// Maximum serialized size of the result payload emitted for a single GraphQL operation
+///
+/// Result payloads that exceed the limit are truncated to fit within the budget rather than failing the whole response.
+/// The result is traversed depth-first, retaining the longest prefix of list items and record fields that fits within
+/// the limit and replacing the remainder with explicit truncation markers (see [`GRAPHQL_TRUNCATION_MARKER`]). Scalar
+/// values are never split: a string or number that does not fit within the remaining budget is omitted entirely.
+///
+/// The size of the inserted truncation markers is not counted towards the budget, so the serialized output can exceed the
+/// limit by a small number of bytes per truncated container.
+#[derive(Hash, Eq, PartialEq, Default, Clone, Copy, Debug)]
+pub struct GraphQlResultSizeLimit {
+    max_size: Option<usize>,
+}
+impl GraphQlResultSizeLimit {
+    pub fn new(max_size: usize) -> Self {
+        Self {
+            max_size: Some(max_size),
+        }
+    }
+    pub fn max_size(&self) -> Option<usize> {
+        self.max_size
+    }
+    pub fn is_unlimited(&self) -> bool {
+        self.max_size.is_none()
+    }
+    /// Serialize the provided result as JSON, truncating the serialized payload to fit within the size limit
+    ///
+    /// The size limit is enforced during serialization: list items and record fields are only serialized while there
+    /// is budget remaining, so the JSON representation of any omitted portion of the result is never constructed.
+    ///
+    /// If the payload was truncated, a warning object is returned alongside the truncated payload, suitable for
+    /// inclusion in the `warnings` field of the GraphQL response `extensions` object.
+    pub fn serialize<T: Expression>(
+        &self,
+        result: &T,
+        factory: &impl ExpressionFactory<T>,
+    ) -> Result<(JsonValue, Option<JsonValue>), String> {
+        let max_size = match self.max_size {
+            Some(max_size) => max_size,
+            None => return sanitize(result).map(|payload| (payload, None)),
+        };
+        let mut budget = max_size;
+        truncate_expression(result, factory, &mut budget)
+            .map(|truncated| truncated.into_payload(max_size))
+    }
+    /// Truncate the provided serialized result payload to fit within the size limit
+    ///
+    /// If the payload was truncated, a warning object is returned alongside the truncated payload, suitable for
+    /// inclusion in the `warnings` field of the GraphQL response `extensions` object.
+    pub fn apply(&self, payload: JsonValue) -> (JsonValue, Option<JsonValue>) {
+        let max_size = match self.max_size {
+            Some(max_size) => max_size,
+            None => return (payload, None),
+        };
+        let mut budget = max_size;
+        match truncate_json_value(payload, &mut budget) {
+            Ok(truncated) => truncated.into_payload(max_size),
+            Err(err) => match err {},
+        }
+    }
+}
+impl std::fmt::Display for GraphQlResultSizeLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.max_size {
+            Some(max_size) => write!(f, "{}", max_size),
+            None => write!(f, "unlimited"),
+        }
+    }
+}
+impl std::str::FromStr for GraphQlResultSizeLimit {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "unlimited" => Ok(Self::default()),
+            value => value
+                .parse::<usize>()
+                .map(Self::new)
+                .map_err(|_| format!("Invalid GraphQL result size limit: {}", s)),
+        }
+    }
+}
+
+enum Truncated {
+    Complete(JsonValue),
+    Partial(JsonValue),
+    Omitted,
+}
+impl Truncated {
+    fn into_payload(self, max_size: usize) -> (JsonValue, Option<JsonValue>) {
+        match self {
+            Self::Complete(payload) => (payload, None),
+            Self::Partial(payload) => (payload, Some(create_truncation_warning(max_size))),
+            Self::Omitted => (JsonValue::Null, Some(create_truncation_warning(max_size))),
+        }
+    }
+}
+
+fn create_truncation_warning(max_size: usize) -> JsonValue {
+    json_object([
+        (
+            String::from("message"),
+            JsonValue::String(format!(
+                "Result exceeded maximum size of {} bytes and has been truncated",
+                max_size
+            )),
+        ),
+        (
+            String::from("code"),
+            JsonValue::String(String::from(GRAPHQL_TRUNCATION_WARNING_CODE)),
+        ),
+        (String::from("maxSize"), JsonValue::from(max_size)),
+    ])
+}
+
+fn truncate_expression<T: Expression>(
+    value: &T,
+    factory: &impl ExpressionFactory<T>,
+    budget: &mut usize,
+) -> Result<Truncated, String> {
+    if let Some(term) = factory.match_list_term(value) {
+        let items = term.items();
+        let items = items.as_deref();
+        truncate_array(items.iter(), budget, |item, budget| {
+            truncate_expression(item.as_deref(), factory, budget)
+        })
+    } else if let Some(term) = factory.match_record_term(value) {
+        let prototype = term.prototype();
+        let keys = prototype.as_deref().keys();
+        let values = term.values();
+        let fields = keys
+            .as_deref()
+            .iter()
+            .zip(values.as_deref().iter())
+            .map(|(key, value)| match key.as_deref().to_json()? {
+                JsonValue::String(key) => Ok((key, value)),
+                key => Err(format!("Invalid JSON object key: {}", key)),
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        truncate_object(fields.into_iter(), budget, |value, budget| {
+            truncate_expression(value.as_deref(), factory, budget)
+        })
+    } else {
+        truncate_json_value(value.to_json()?, budget).or_else(|err| match err {})
+    }
+}
+
+fn truncate_json_value(value: JsonValue, budget: &mut usize) -> Result<Truncated, Infallible> {
+    match value {
+        JsonValue::Array(items) => truncate_array(items.into_iter(), budget, truncate_json_value),
+        JsonValue::Object(fields) => {
+            truncate_object(fields.into_iter(), budget, truncate_json_value)
+        }
+        value => {
+            let size = serialized_scalar_size(&value);
+            Ok(if consume_budget(budget, size) {
+                Truncated::Complete(value)
+            } else {
+                Truncated::Omitted
+            })
+        }
+    }
+}
+
+fn truncate_array<V, E>(
+    items: impl ExactSizeIterator<Item = V>,
+    budget: &mut usize,
+    mut truncate_item: impl FnMut(V, &mut usize) -> Result<Truncated, E>,
+) -> Result<Truncated, E> {
+    if !consume_budget(budget, 2) {
+        return Ok(Truncated::Omitted);
+    }
+    let num_items = items.len();
+    let mut is_partial = false;
+    let mut retained_items = Vec::new();
+    for (index, item) in items.enumerate() {
+        if index > 0 && !consume_budget(budget, 1) {
+            break;
+        }
+        match truncate_item(item, budget)? {
+            Truncated::Complete(item) => retained_items.push(item),
+            Truncated::Partial(item) => {
+                is_partial = true;
+                retained_items.push(item);
+            }
+            Truncated::Omitted => break,
+        }
+    }
+    let num_omitted = num_items - retained_items.len();
+    if num_omitted > 0 {
+        retained_items.push(json_object([(
+            String::from(GRAPHQL_TRUNCATION_MARKER),
+            JsonValue::from(num_omitted),
+        )]));
+    }
+    Ok(if is_partial || num_omitted > 0 {
+        Truncated::Partial(JsonValue::Array(retained_items))
+    } else {
+        Truncated::Complete(JsonValue::Array(retained_items))
+    })
+}
+
+fn truncate_object<V, E>(
+    fields: impl ExactSizeIterator<Item = (String, V)>,
+    budget: &mut usize,
+    mut truncate_value: impl FnMut(V, &mut usize) -> Result<Truncated, E>,
+) -> Result<Truncated, E> {
+    if !consume_budget(budget, 2) {
+        return Ok(Truncated::Omitted);
+    }
+    let num_fields = fields.len();
+    let mut is_partial = false;
+    let mut retained_fields = JsonMap::new();
+    for (index, (key, value)) in fields.enumerate() {
+        let separator_size = if index > 0 { 1 } else { 0 };
+        if !consume_budget(budget, separator_size + serialized_string_size(&key) + 1) {
+            break;
+        }
+        match truncate_value(value, budget)? {
+            Truncated::Complete(value) => {
+                retained_fields.insert(key, value);
+            }
+            Truncated::Partial(value) => {
+                is_partial = true;
+                retained_fields.insert(key, value);
+            }
+            Truncated::Omitted => break,
+        }
+    }
+    let num_omitted = num_fields - retained_fields.len();
+    if num_omitted > 0 {
+        retained_fields.insert(
+            String::from(GRAPHQL_TRUNCATION_MARKER),
+            JsonValue::from(num_omitted),
+        );
+    }
+    Ok(if is_partial || num_omitted > 0 {
+        Truncated::Partial(JsonValue::Object(retained_fields))
+    } else {
+        Truncated::Complete(JsonValue::Object(retained_fields))
+    })
+}
+
+/// Deduct the given size from the remaining budget, exhausting the budget if the size cannot be accommodated
+///
+/// Exhausting the budget on the first overflow ensures that only a contiguous prefix of the payload is retained, so that
+/// list indices within the truncated payload match those of the original payload.
+fn consume_budget(budget: &mut usize, size: usize) -> bool {
+    if size <= *budget {
+        *budget -= size;
+        true
+    } else {
+        *budget = 0;
+        false
+    }
+}
+
+fn serialized_scalar_size(value: &JsonValue) -> usize {
+    match value {
+        JsonValue::String(value) => serialized_string_size(value),
+        value => value.to_string().len(),
+    }
+}
+
+fn serialized_string_size(value: &str) -> usize {
+    2 + value
+        .chars()
+        .map(|char| match char {
+            '"' | '\\' | '\u{0008}' | '\u{000C}' | '\n' | '\r' | '\t' => 2,
+            '\u{0000}'..='\u{001F}' => 6,
+            char => char.len_utf8(),
+        })
+        .sum::<usize>()
+}
+
+#[cfg(test)]
+mod tests {
+    use reflex::core::HeapAllocator;
+    use reflex_json::json;
+    use reflex_lang::{allocator::DefaultAllocator, SharedTermFactory};
+    use reflex_stdlib::Stdlib;
+
+    use super::*;
+
+    #[test]
+    fn truncate_result_payload() {
+        let payload = json!({ "bar": "baz", "foo": [1, 2, 3, 4, 5], "qux": true });
+        assert_eq!(
+            GraphQlResultSizeLimit::default().apply(payload.clone()),
+            (payload.clone(), None),
+        );
+        let serialized_size = payload.to_string().len();
+        assert_eq!(
+            GraphQlResultSizeLimit::new(serialized_size).apply(payload.clone()),
+            (payload.clone(), None),
+        );
+        let (truncated, warning) = GraphQlResultSizeLimit::new(24).apply(payload.clone());
+        assert_eq!(
+            truncated,
+            json!({ "bar": "baz", "foo": [1, { "__truncated": 4 }], "__truncated": 1 }),
+        );
+        assert_eq!(
+            warning,
+            Some(json!({
+                "message": "Result exceeded maximum size of 24 bytes and has been truncated",
+                "code": "RESULT_TRUNCATED",
+                "maxSize": 24,
+            })),
+        );
+        assert_eq!(
+            GraphQlResultSizeLimit::new(8)
+                .apply(json!(["foo", "bar"]))
+                .0,
+            json!(["foo", { "__truncated": 1 }]),
+        );
+        assert_eq!(
+            GraphQlResultSizeLimit::new(4).apply(json!("foobar")).0,
+            JsonValue::Null,
+        );
+        assert_eq!(
+            GraphQlResultSizeLimit::new(1).apply(json!([1, 2])).0,
+            JsonValue::Null,
+        );
+    }
+
+    #[test]
+    fn serialize_truncated_result() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let result = factory.create_record_term(
+            allocator.create_struct_prototype(allocator.create_list([
+                factory.create_string_term(allocator.create_static_string("bar")),
+                factory.create_string_term(allocator.create_static_string("foo")),
+                factory.create_string_term(allocator.create_static_string("qux")),
+            ])),
+            allocator.create_unsized_list([
+                factory.create_string_term(allocator.create_static_string("baz")),
+                factory.create_list_term(
+                    allocator.create_list((1..=5).map(|value| factory.create_int_term(value))),
+                ),
+                factory.create_boolean_term(true),
+            ]),
+        );
+        let payload = json!({ "bar": "baz", "foo": [1, 2, 3, 4, 5], "qux": true });
+        assert_eq!(
+            GraphQlResultSizeLimit::default().serialize(&result, &factory),
+            Ok((payload.clone(), None)),
+        );
+        assert_eq!(
+            GraphQlResultSizeLimit::new(payload.to_string().len()).serialize(&result, &factory),
+            Ok((payload, None)),
+        );
+        assert_eq!(
+            GraphQlResultSizeLimit::new(24).serialize(&result, &factory),
+            Ok(GraphQlResultSizeLimit::new(24).apply(json!({
+                "bar": "baz",
+                "foo": [1, 2, 3, 4, 5],
+                "qux": true,
+            }))),
+        );
+        // Items beyond the size limit are never serialized
+        let result = factory.create_list_term(allocator.create_list([
+            factory.create_int_term(1),
+            factory.create_lambda_term(0, factory.create_nil_term()),
+        ]));
+        assert!(GraphQlResultSizeLimit::default()
+            .serialize(&result, &factory)
+            .is_err());
+        assert_eq!(
+            GraphQlResultSizeLimit::new(3)
+                .serialize(&result, &factory)
+                .map(|(payload, _)| payload),
+            Ok(json!([1, { "__truncated": 1 }])),
+        );
+    }
+
+    #[test]
+    fn serialized_size() {
+        for value in [
+            json!("foo"),
+            json!("\"quoted\" \\ \n\t\u{0001} ünïcödé"),
+            json!(3.142),
+            json!(-42),
+            json!(true),
+            json!(null),
+        ] {
+            assert_eq!(serialized_scalar_size(&value), value.to_string().len());
+        }
+    }
+
+    #[test]
+    fn parse_result_size_limit() {
+        assert_eq!(
+            "1024".parse::<GraphQlResultSizeLimit>(),
+            Ok(GraphQlResultSizeLimit::new(1024)),
+        );
+        assert_eq!(
+            "unlimited".parse::<GraphQlResultSizeLimit>(),
+            Ok(GraphQlResultSizeLimit::default()),
+        );
+        assert!("-1".parse::<GraphQlResultSizeLimit>().is_err());
+        assert_eq!(format!("{}", GraphQlResultSizeLimit::new(1024)), "1024");
+    }
+}
@@ -6,7 +6,7 @@ use std::collections::HashMap;
 use hyper::{header::HeaderName, http::HeaderValue, HeaderMap};
 use reflex_dispatcher::{Action, Named, SerializableAction, SerializedAction};
 use reflex_json::json;
-use reflex_macros::Named;
+use reflex_macros::{ActionSchema, Named};
 use reflex_protobuf::Bytes;
 use reflex_utils::serialize::bytes as serialize_bytes;
 use serde::{Deserialize, Serialize};
@@ -109,7 +109,7 @@ impl From<GrpcMetadata> for MetadataMap {
     }
 }
 
-#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub enum GrpcHandlerActions {
     ConnectSuccess(GrpcHandlerConnectSuccessAction),
     ConnectError(GrpcHandlerConnectErrorAction),
@@ -351,7 +351,7 @@ impl<'a> From<&'a GrpcHandlerActions> for Option<&'a GrpcHandlerConnectionTermin
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GrpcHandlerConnectSuccessAction {
     pub connection_id: Uuid,
     pub url: String,
@@ -369,7 +369,7 @@ impl SerializableAction for GrpcHandlerConnectSuccessAction {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GrpcHandlerConnectErrorAction {
     pub connection_id: Uuid,
     pub url: String,
@@ -389,7 +389,7 @@ impl SerializableAction for GrpcHandlerConnectErrorAction {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GrpcHandlerRequestStartAction {
     pub connection_id: Uuid,
     pub url: String,
@@ -431,7 +431,7 @@ impl SerializableAction for GrpcHandlerRequestStartAction {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GrpcHandlerRequestStopAction {
     pub connection_id: Uuid,
     pub url: String,
@@ -468,7 +468,7 @@ impl SerializableAction for GrpcHandlerRequestStopAction {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GrpcHandlerSuccessResponseAction {
     pub connection_id: Uuid,
     pub operation_id: Uuid,
@@ -492,7 +492,7 @@ impl SerializableAction for GrpcHandlerSuccessResponseAction {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GrpcHandlerErrorResponseAction {
     pub connection_id: Uuid,
     pub url: String,
@@ -531,7 +531,7 @@ impl SerializableAction for GrpcHandlerErrorResponseAction {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GrpcHandlerTransportErrorAction {
     pub connection_id: Uuid,
     pub url: String,
@@ -570,7 +570,7 @@ impl SerializableAction for GrpcHandlerTransportErrorAction {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GrpcHandlerAbortRequestAction {
     pub connection_id: Uuid,
     pub operation_id: Uuid,
@@ -591,7 +591,7 @@ impl SerializableAction for GrpcHandlerAbortRequestAction {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GrpcHandlerConnectionTerminateAction {
     pub connection_id: Uuid,
     pub url: String,
@@ -6,12 +6,12 @@ use http::StatusCode;
 use reflex::core::Uuid;
 use reflex_dispatcher::{Action, Named, SerializableAction, SerializedAction};
 use reflex_json::JsonValue;
-use reflex_macros::Named;
+use reflex_macros::{ActionSchema, Named};
 use serde::{Deserialize, Serialize};
 
 use crate::utils::serialize::SerializedBytes;
 
-#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub enum FetchHandlerActions {
     FetchComplete(FetchHandlerFetchCompleteAction),
     ConnectionError(FetchHandlerConnectionErrorAction),
@@ -78,7 +78,7 @@ impl<'a> From<&'a FetchHandlerActions> for Option<&'a FetchHandlerConnectionErro
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug)]
 pub struct FetchHandlerFetchCompleteAction {
     pub operation_id: Uuid,
     pub url: String,
@@ -155,7 +155,7 @@ impl From<SerializedFetchHandlerFetchCompleteAction> for FetchHandlerFetchComple
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct FetchHandlerConnectionErrorAction {
     pub operation_id: Uuid,
     pub url: String,
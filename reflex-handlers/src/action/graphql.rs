@@ -10,13 +10,13 @@ use reflex_graphql::subscriptions::{
     GraphQlSubscriptionClientMessage, GraphQlSubscriptionServerMessage,
 };
 use reflex_json::{JsonMap, JsonValue};
-use reflex_macros::Named;
+use reflex_macros::{ActionSchema, Named};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::utils::serialize::SerializedBytes;
 
-#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub enum GraphQlHandlerActions {
     HttpFetchComplete(GraphQlHandlerHttpFetchCompleteAction),
     HttpConnectionError(GraphQlHandlerHttpConnectionErrorAction),
@@ -218,7 +218,7 @@ impl<'a> From<&'a GraphQlHandlerActions>
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug)]
 pub struct GraphQlHandlerHttpFetchCompleteAction {
     pub operation_id: Uuid,
     pub url: String,
@@ -299,7 +299,7 @@ impl From<SerializedGraphQlHandlerHttpFetchCompleteAction>
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GraphQlHandlerHttpConnectionErrorAction {
     pub operation_id: Uuid,
     pub url: String,
@@ -319,7 +319,7 @@ impl SerializableAction for GraphQlHandlerHttpConnectionErrorAction {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GraphQlHandlerWebSocketConnectSuccessAction {
     pub connection_id: Uuid,
     pub url: String,
@@ -337,7 +337,7 @@ impl SerializableAction for GraphQlHandlerWebSocketConnectSuccessAction {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GraphQlHandlerWebSocketClientMessageAction {
     pub connection_id: Uuid,
     pub message: GraphQlSubscriptionClientMessage,
@@ -355,7 +355,7 @@ impl SerializableAction for GraphQlHandlerWebSocketClientMessageAction {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GraphQlHandlerWebSocketServerMessageAction {
     pub connection_id: Uuid,
     pub message: Arc<GraphQlSubscriptionServerMessage>,
@@ -396,7 +396,7 @@ impl SerializableAction for GraphQlHandlerWebSocketServerMessageAction {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GraphQlHandlerWebSocketConnectionTerminateAction {
     pub connection_id: Uuid,
     pub url: String,
@@ -414,7 +414,7 @@ impl SerializableAction for GraphQlHandlerWebSocketConnectionTerminateAction {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GraphQlHandlerWebSocketConnectionErrorAction {
     pub connection_id: Uuid,
     pub url: String,
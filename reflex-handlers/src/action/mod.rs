@@ -3,5 +3,6 @@
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 pub mod fetch;
 pub mod graphql;
+pub mod plugin;
 pub mod timeout;
 pub mod timestamp;
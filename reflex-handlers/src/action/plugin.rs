@@ -0,0 +1,68 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::Uuid;
+use reflex_dispatcher::{Action, Named, SerializableAction, SerializedAction};
+use reflex_json::JsonValue;
+use reflex_macros::{ActionSchema, Named};
+use serde::{Deserialize, Serialize};
+
+#[derive(ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub enum PluginHandlerActions {
+    Response(PluginHandlerResponseAction),
+}
+impl Named for PluginHandlerActions {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Response(action) => action.name(),
+        }
+    }
+}
+impl Action for PluginHandlerActions {}
+impl SerializableAction for PluginHandlerActions {
+    fn to_json(&self) -> SerializedAction {
+        match self {
+            Self::Response(action) => action.to_json(),
+        }
+    }
+}
+
+impl From<PluginHandlerResponseAction> for PluginHandlerActions {
+    fn from(value: PluginHandlerResponseAction) -> Self {
+        Self::Response(value)
+    }
+}
+impl From<PluginHandlerActions> for Option<PluginHandlerResponseAction> {
+    fn from(value: PluginHandlerActions) -> Self {
+        match value {
+            PluginHandlerActions::Response(value) => Some(value),
+        }
+    }
+}
+impl<'a> From<&'a PluginHandlerActions> for Option<&'a PluginHandlerResponseAction> {
+    fn from(value: &'a PluginHandlerActions) -> Self {
+        match value {
+            PluginHandlerActions::Response(value) => Some(value),
+        }
+    }
+}
+
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct PluginHandlerResponseAction {
+    pub operation_id: Uuid,
+    pub effect_type: String,
+    pub result: Result<JsonValue, JsonValue>,
+}
+impl Action for PluginHandlerResponseAction {}
+impl SerializableAction for PluginHandlerResponseAction {
+    fn to_json(&self) -> SerializedAction {
+        SerializedAction::from_iter([
+            (
+                "operation_id",
+                JsonValue::from(self.operation_id.to_string()),
+            ),
+            ("effect_type", JsonValue::from(self.effect_type.clone())),
+            ("success", JsonValue::from(self.result.is_ok())),
+        ])
+    }
+}
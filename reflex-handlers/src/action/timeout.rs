@@ -4,10 +4,10 @@
 use reflex::core::Uuid;
 use reflex_dispatcher::{Action, Named, SerializableAction, SerializedAction};
 use reflex_json::JsonValue;
-use reflex_macros::Named;
+use reflex_macros::{ActionSchema, Named};
 use serde::{Deserialize, Serialize};
 
-#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub enum TimeoutHandlerActions {
     Timeout(TimeoutHandlerTimeoutAction),
 }
@@ -47,7 +47,7 @@ impl<'a> From<&'a TimeoutHandlerActions> for Option<&'a TimeoutHandlerTimeoutAct
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct TimeoutHandlerTimeoutAction {
     pub operation_id: Uuid,
 }
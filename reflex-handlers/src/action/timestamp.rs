@@ -6,12 +6,12 @@ use std::time::SystemTime;
 use reflex::core::Uuid;
 use reflex_dispatcher::{Action, Named, SerializableAction, SerializedAction};
 use reflex_json::JsonValue;
-use reflex_macros::Named;
+use reflex_macros::{ActionSchema, Named};
 use serde::{Deserialize, Serialize};
 
 use crate::utils::timestamp::get_timestamp_millis;
 
-#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub enum TimestampHandlerActions {
     Update(TimestampHandlerUpdateAction),
 }
@@ -51,7 +51,7 @@ impl<'a> From<&'a TimestampHandlerActions> for Option<&'a TimestampHandlerUpdate
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct TimestampHandlerUpdateAction {
     pub operation_id: Uuid,
     pub timestamp: SystemTime,
@@ -17,9 +17,9 @@ use metrics::{
     decrement_gauge, describe_counter, describe_gauge, increment_counter, increment_gauge, Unit,
 };
 use reflex::core::{
-    ConditionType, Expression, ExpressionFactory, ExpressionListType, HeapAllocator, ListTermType,
-    RecordTermType, RefType, SignalType, StateToken, StringTermType, StringValue,
-    StructPrototypeType, Uuid,
+    BytesTermType, ConditionType, Expression, ExpressionFactory, ExpressionListType, HeapAllocator,
+    ListTermType, RecordTermType, RefType, SensitiveTermType, SignalType, StateToken,
+    StringTermType, StringValue, StructPrototypeType, Uuid,
 };
 use reflex_dispatcher::{
     Action, ActorEvents, HandlerContext, MessageData, NoopDisposeCallback, ProcessId,
@@ -444,13 +444,14 @@ where
         let task_pid = state.unsubscribe_fetch_task(&effect, &self.metric_names)?;
         let factory = &self.factory;
         let allocator = &self.allocator;
-        let result = match String::from_utf8(body.into_iter().copied().collect()) {
-            Ok(body) => factory.create_list_term(allocator.create_pair(
-                factory.create_int_term(status_code.as_u16().into()),
-                factory.create_string_term(allocator.create_string(body)),
-            )),
-            Err(err) => create_error_expression(format!("{}", err), factory, allocator),
+        // Response bodies that are not valid UTF-8 are returned as raw binary data
+        let body = match String::from_utf8(body.into_iter().copied().collect()) {
+            Ok(body) => factory.create_string_term(allocator.create_string(body)),
+            Err(err) => factory.create_bytes_term(err.as_bytes()),
         };
+        let result = factory.create_list_term(
+            allocator.create_pair(factory.create_int_term(status_code.as_u16().into()), body),
+        );
         Some(SchedulerTransition::new([
             SchedulerCommand::Kill(task_pid),
             SchedulerCommand::Send(
@@ -543,7 +544,7 @@ fn parse_fetch_effect_args<T: Expression>(
     let url = parse_string_arg(&url, factory);
     let method = parse_string_arg(&method, factory);
     let headers = parse_key_values_arg(&headers, factory);
-    let body = parse_optional_string_arg(&body, factory);
+    let body = parse_optional_body_arg(&body, factory);
     match (method, url, headers, body) {
         (Some(method), Some(url), Some(headers), Some(body)) => {
             let headers = format_request_headers(headers)?;
@@ -551,7 +552,7 @@ fn parse_fetch_effect_args<T: Expression>(
                 method,
                 url,
                 headers,
-                body: body.map(Bytes::from),
+                body,
             })
         }
         _ => Err(format!(
@@ -561,17 +562,23 @@ fn parse_fetch_effect_args<T: Expression>(
 }
 
 fn format_request_headers(
-    headers: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>,
+    headers: impl IntoIterator<Item = (impl Into<String>, impl Into<String>, bool)>,
 ) -> Result<Vec<(HeaderName, HeaderValue)>, String> {
     headers
         .into_iter()
-        .map(|(key, value)| {
+        .map(|(key, value, is_sensitive)| {
             let key: String = key.into();
             let value: String = value.into();
             let key = HeaderName::from_str(key.as_str())
                 .map_err(|_| format!("Invalid fetch header name: {}", key))?;
-            let value = HeaderValue::from_str(value.as_str())
-                .map_err(|_| format!("Invalid value for fetch header {}: {}", key, value))?;
+            let mut value = HeaderValue::from_str(value.as_str()).map_err(|_| {
+                if is_sensitive {
+                    format!("Invalid value for fetch header {}", key)
+                } else {
+                    format!("Invalid value for fetch header {}: {}", key, value)
+                }
+            })?;
+            value.set_sensitive(is_sensitive);
             Ok((key, value))
         })
         .collect::<Result<Vec<_>, _>>()
@@ -587,6 +594,16 @@ fn parse_string_arg<T: Expression>(
     }
 }
 
+fn parse_optional_body_arg<T: Expression>(
+    value: &T,
+    factory: &impl ExpressionFactory<T>,
+) -> Option<Option<Bytes>> {
+    match factory.match_bytes_term(value) {
+        Some(term) => Some(Some(Bytes::copy_from_slice(term.value().deref()))),
+        _ => parse_optional_string_arg(value, factory).map(|value| value.map(Bytes::from)),
+    }
+}
+
 fn parse_optional_string_arg<T: Expression>(
     value: &T,
     factory: &impl ExpressionFactory<T>,
@@ -600,10 +617,12 @@ fn parse_optional_string_arg<T: Expression>(
     }
 }
 
+/// Parse a record of string values, returning a flag alongside each entry that indicates whether the value was
+/// provided as a sensitive term (the fetch handler is permitted to unwrap sensitive values in order to send them)
 fn parse_key_values_arg<T: Expression>(
     value: &T,
     factory: &impl ExpressionFactory<T>,
-) -> Option<Vec<(String, String)>> {
+) -> Option<Vec<(String, String, bool)>> {
     if let Some(value) = factory.match_record_term(value) {
         value
             .prototype()
@@ -613,13 +632,19 @@ fn parse_key_values_arg<T: Expression>(
             .iter()
             .zip(value.values().as_deref().iter())
             .map(|(key, value)| {
+                let value = value.as_deref();
+                let (value, is_sensitive) = match factory.match_sensitive_term(value) {
+                    Some(term) => (term.value().as_deref().clone(), true),
+                    None => (value.clone(), false),
+                };
                 match (
                     factory.match_string_term(key.as_deref()),
-                    factory.match_string_term(value.as_deref()),
+                    factory.match_string_term(&value),
                 ) {
                     (Some(key), Some(value)) => Some((
                         String::from(key.value().as_deref().as_str().deref()),
                         String::from(value.value().as_deref().as_str().deref()),
+                        is_sensitive,
                     )),
                     _ => None,
                 }
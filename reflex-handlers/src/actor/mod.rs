@@ -14,15 +14,20 @@ use reflex_runtime::{AsyncExpression, AsyncExpressionFactory, AsyncHeapAllocator
 use reflex_utils::reconnect::ReconnectTimeout;
 
 use crate::task::{
-    fetch::FetchHandlerTask, graphql::GraphQlHandlerTask, timeout::TimeoutHandlerTask,
-    timestamp::TimestampHandlerTask,
+    fetch::FetchHandlerTask, graphql::GraphQlHandlerTask, plugin::PluginHandlerTask,
+    timeout::TimeoutHandlerTask, timestamp::TimestampHandlerTask,
 };
 
 use self::{
     fetch::{FetchHandler, FetchHandlerAction, FetchHandlerState},
     graphql::{GraphQlHandler, GraphQlHandlerAction, GraphQlHandlerState},
     loader::{LoaderHandler, LoaderHandlerAction, LoaderHandlerState},
+    plugin::{PluginHandler, PluginHandlerAction, PluginHandlerState},
+    rate_limit::{
+        RateLimitHandler, RateLimitHandlerAction, RateLimitHandlerBuiltin, RateLimitHandlerState,
+    },
     scan::{ScanHandler, ScanHandlerAction, ScanHandlerBuiltin, ScanHandlerState},
+    secret::{SecretHandler, SecretHandlerAction, SecretHandlerState},
     timeout::{TimeoutHandler, TimeoutHandlerAction, TimeoutHandlerState},
     timestamp::{TimestampHandler, TimestampHandlerAction, TimestampHandlerState},
     variable::{VariableHandler, VariableHandlerAction, VariableHandlerState},
@@ -31,13 +36,16 @@ use self::{
 pub mod fetch;
 pub mod graphql;
 pub mod loader;
+pub mod plugin;
+pub mod rate_limit;
 pub mod scan;
+pub mod secret;
 pub mod timeout;
 pub mod timestamp;
 pub mod variable;
 
 blanket_trait!(
-    pub trait HandlerActorBuiltin: ScanHandlerBuiltin {}
+    pub trait HandlerActorBuiltin: RateLimitHandlerBuiltin + ScanHandlerBuiltin {}
 );
 
 blanket_trait!(
@@ -45,7 +53,10 @@ blanket_trait!(
         FetchHandlerAction<T>
         + GraphQlHandlerAction<T>
         + LoaderHandlerAction<T>
+        + PluginHandlerAction<T>
+        + RateLimitHandlerAction<T>
         + ScanHandlerAction<T>
+        + SecretHandlerAction<T>
         + TimeoutHandlerAction<T>
         + TimestampHandlerAction<T>
         + VariableHandlerAction<T>
@@ -57,6 +68,7 @@ blanket_trait!(
     pub trait HandlerTask<TConnect>:
         FetchHandlerTask<TConnect>
         + GraphQlHandlerTask<TConnect>
+        + PluginHandlerTask
         + TimeoutHandlerTask
         + TimestampHandlerTask
     where
@@ -84,7 +96,10 @@ where
     FetchHandler(FetchHandler<T, TFactory, TAllocator, TConnect>),
     GraphQlHandler(GraphQlHandler<T, TFactory, TAllocator, TConnect, TReconnect>),
     LoaderHandler(LoaderHandler<T, TFactory, TAllocator>),
+    PluginHandler(PluginHandler<T, TFactory, TAllocator>),
+    RateLimitHandler(RateLimitHandler<T, TFactory, TAllocator>),
     ScanHandler(ScanHandler<T, TFactory, TAllocator>),
+    SecretHandler(SecretHandler<T, TFactory, TAllocator>),
     TimeoutHandler(TimeoutHandler<T, TFactory, TAllocator>),
     TimestampHandler(TimestampHandler<T, TFactory, TAllocator>),
     VariableHandler(VariableHandler<T, TFactory, TAllocator>),
@@ -110,7 +125,10 @@ where
             Self::FetchHandler(inner) => inner.name(),
             Self::GraphQlHandler(inner) => inner.name(),
             Self::LoaderHandler(inner) => inner.name(),
+            Self::PluginHandler(inner) => inner.name(),
+            Self::RateLimitHandler(inner) => inner.name(),
             Self::ScanHandler(inner) => inner.name(),
+            Self::SecretHandler(inner) => inner.name(),
             Self::TimeoutHandler(inner) => inner.name(),
             Self::TimestampHandler(inner) => inner.name(),
             Self::VariableHandler(inner) => inner.name(),
@@ -166,12 +184,25 @@ where
                     <LoaderHandler<T, TFactory, TAllocator> as Actor<TAction, TTask>>::init(actor),
                 )
             }
+            Self::PluginHandler(actor) => {
+                HandlerActorState::PluginHandler(
+                    <PluginHandler<T, TFactory, TAllocator> as Actor<TAction, TTask>>::init(actor),
+                )
+            }
+            Self::RateLimitHandler(actor) => HandlerActorState::RateLimitHandler(
+                <RateLimitHandler<T, TFactory, TAllocator> as Actor<TAction, TTask>>::init(actor),
+            ),
             Self::ScanHandler(actor) => {
                 HandlerActorState::ScanHandler(<ScanHandler<T, TFactory, TAllocator> as Actor<
                     TAction,
                     TTask,
                 >>::init(actor))
             }
+            Self::SecretHandler(actor) => {
+                HandlerActorState::SecretHandler(
+                    <SecretHandler<T, TFactory, TAllocator> as Actor<TAction, TTask>>::init(actor),
+                )
+            }
             Self::TimeoutHandler(actor) => {
                 HandlerActorState::TimeoutHandler(
                     <TimeoutHandler<T, TFactory, TAllocator> as Actor<TAction, TTask>>::init(actor),
@@ -227,6 +258,26 @@ where
                     dispose.map(HandlerActorDispose::LoaderHandler),
                 )
             }),
+            Self::PluginHandler(actor) => <PluginHandler<T, TFactory, TAllocator> as Actor<
+                TAction,
+                TTask,
+            >>::events(actor, inbox)
+            .map(|(events, dispose)| {
+                (
+                    HandlerActorEvents::PluginHandler(events),
+                    dispose.map(HandlerActorDispose::PluginHandler),
+                )
+            }),
+            Self::RateLimitHandler(actor) => <RateLimitHandler<T, TFactory, TAllocator> as Actor<
+                TAction,
+                TTask,
+            >>::events(actor, inbox)
+            .map(|(events, dispose)| {
+                (
+                    HandlerActorEvents::RateLimitHandler(events),
+                    dispose.map(HandlerActorDispose::RateLimitHandler),
+                )
+            }),
             Self::ScanHandler(actor) => <ScanHandler<T, TFactory, TAllocator> as Actor<
                 TAction,
                 TTask,
@@ -237,6 +288,16 @@ where
                     dispose.map(HandlerActorDispose::ScanHandler),
                 )
             }),
+            Self::SecretHandler(actor) => <SecretHandler<T, TFactory, TAllocator> as Actor<
+                TAction,
+                TTask,
+            >>::events(actor, inbox)
+            .map(|(events, dispose)| {
+                (
+                    HandlerActorEvents::SecretHandler(events),
+                    dispose.map(HandlerActorDispose::SecretHandler),
+                )
+            }),
             Self::TimeoutHandler(actor) => <TimeoutHandler<T, TFactory, TAllocator> as Actor<
                 TAction,
                 TTask,
@@ -299,7 +360,10 @@ pub enum HandlerActorState<T: Expression> {
     FetchHandler(FetchHandlerState<T>),
     GraphQlHandler(GraphQlHandlerState<T>),
     LoaderHandler(LoaderHandlerState<T>),
+    PluginHandler(PluginHandlerState<T>),
+    RateLimitHandler(RateLimitHandlerState<T>),
     ScanHandler(ScanHandlerState<T>),
+    SecretHandler(SecretHandlerState),
     TimeoutHandler(TimeoutHandlerState<T>),
     TimestampHandler(TimestampHandlerState<T>),
     VariableHandler(VariableHandlerState<T>),
@@ -338,9 +402,18 @@ where
     LoaderHandler(
         #[pin] <LoaderHandler<T, TFactory, TAllocator> as Actor<TAction, TTask>>::Events<TInbox>,
     ),
+    PluginHandler(
+        #[pin] <PluginHandler<T, TFactory, TAllocator> as Actor<TAction, TTask>>::Events<TInbox>,
+    ),
+    RateLimitHandler(
+        #[pin] <RateLimitHandler<T, TFactory, TAllocator> as Actor<TAction, TTask>>::Events<TInbox>,
+    ),
     ScanHandler(
         #[pin] <ScanHandler<T, TFactory, TAllocator> as Actor<TAction, TTask>>::Events<TInbox>,
     ),
+    SecretHandler(
+        #[pin] <SecretHandler<T, TFactory, TAllocator> as Actor<TAction, TTask>>::Events<TInbox>,
+    ),
     TimeoutHandler(
         #[pin] <TimeoutHandler<T, TFactory, TAllocator> as Actor<TAction, TTask>>::Events<TInbox>,
     ),
@@ -379,7 +452,10 @@ where
             HandlerActorEventsVariant::FetchHandler(inner) => inner.poll_next(cx),
             HandlerActorEventsVariant::GraphQlHandler(inner) => inner.poll_next(cx),
             HandlerActorEventsVariant::LoaderHandler(inner) => inner.poll_next(cx),
+            HandlerActorEventsVariant::PluginHandler(inner) => inner.poll_next(cx),
+            HandlerActorEventsVariant::RateLimitHandler(inner) => inner.poll_next(cx),
             HandlerActorEventsVariant::ScanHandler(inner) => inner.poll_next(cx),
+            HandlerActorEventsVariant::SecretHandler(inner) => inner.poll_next(cx),
             HandlerActorEventsVariant::TimeoutHandler(inner) => inner.poll_next(cx),
             HandlerActorEventsVariant::TimestampHandler(inner) => inner.poll_next(cx),
             HandlerActorEventsVariant::VariableHandler(inner) => inner.poll_next(cx),
@@ -390,7 +466,10 @@ where
             Self::FetchHandler(inner) => inner.size_hint(),
             Self::GraphQlHandler(inner) => inner.size_hint(),
             Self::LoaderHandler(inner) => inner.size_hint(),
+            Self::PluginHandler(inner) => inner.size_hint(),
+            Self::RateLimitHandler(inner) => inner.size_hint(),
             Self::ScanHandler(inner) => inner.size_hint(),
+            Self::SecretHandler(inner) => inner.size_hint(),
             Self::TimeoutHandler(inner) => inner.size_hint(),
             Self::TimestampHandler(inner) => inner.size_hint(),
             Self::VariableHandler(inner) => inner.size_hint(),
@@ -429,7 +508,16 @@ where
     LoaderHandler(
         #[pin] <LoaderHandler<T, TFactory, TAllocator> as Actor<TAction, TTask>>::Dispose,
     ),
+    PluginHandler(
+        #[pin] <PluginHandler<T, TFactory, TAllocator> as Actor<TAction, TTask>>::Dispose,
+    ),
+    RateLimitHandler(
+        #[pin] <RateLimitHandler<T, TFactory, TAllocator> as Actor<TAction, TTask>>::Dispose,
+    ),
     ScanHandler(#[pin] <ScanHandler<T, TFactory, TAllocator> as Actor<TAction, TTask>>::Dispose),
+    SecretHandler(
+        #[pin] <SecretHandler<T, TFactory, TAllocator> as Actor<TAction, TTask>>::Dispose,
+    ),
     TimeoutHandler(
         #[pin] <TimeoutHandler<T, TFactory, TAllocator> as Actor<TAction, TTask>>::Dispose,
     ),
@@ -467,7 +555,10 @@ where
             HandlerActorDisposeVariant::FetchHandler(inner) => inner.poll(cx),
             HandlerActorDisposeVariant::GraphQlHandler(inner) => inner.poll(cx),
             HandlerActorDisposeVariant::LoaderHandler(inner) => inner.poll(cx),
+            HandlerActorDisposeVariant::PluginHandler(inner) => inner.poll(cx),
+            HandlerActorDisposeVariant::RateLimitHandler(inner) => inner.poll(cx),
             HandlerActorDisposeVariant::ScanHandler(inner) => inner.poll(cx),
+            HandlerActorDisposeVariant::SecretHandler(inner) => inner.poll(cx),
             HandlerActorDisposeVariant::TimeoutHandler(inner) => inner.poll(cx),
             HandlerActorDisposeVariant::TimestampHandler(inner) => inner.poll(cx),
             HandlerActorDisposeVariant::VariableHandler(inner) => inner.poll(cx),
@@ -512,10 +603,24 @@ where
                 TAction,
                 SchedulerTransition<TAction, TTask>,
             >>::accept(inner, message),
+            Self::PluginHandler(inner) => <PluginHandler<T, TFactory, TAllocator> as Worker<
+                TAction,
+                SchedulerTransition<TAction, TTask>,
+            >>::accept(inner, message),
+            Self::RateLimitHandler(inner) => {
+                <RateLimitHandler<T, TFactory, TAllocator> as Worker<
+                    TAction,
+                    SchedulerTransition<TAction, TTask>,
+                >>::accept(inner, message)
+            }
             Self::ScanHandler(inner) => <ScanHandler<T, TFactory, TAllocator> as Worker<
                 TAction,
                 SchedulerTransition<TAction, TTask>,
             >>::accept(inner, message),
+            Self::SecretHandler(inner) => <SecretHandler<T, TFactory, TAllocator> as Worker<
+                TAction,
+                SchedulerTransition<TAction, TTask>,
+            >>::accept(inner, message),
             Self::TimeoutHandler(inner) => <TimeoutHandler<T, TFactory, TAllocator> as Worker<
                 TAction,
                 SchedulerTransition<TAction, TTask>,
@@ -552,12 +657,30 @@ where
                     SchedulerTransition<TAction, TTask>,
                 >>::schedule(actor, message, state)
             }
+            (Self::PluginHandler(actor), HandlerActorState::PluginHandler(state)) => {
+                <PluginHandler<T, TFactory, TAllocator> as Worker<
+                    TAction,
+                    SchedulerTransition<TAction, TTask>,
+                >>::schedule(actor, message, state)
+            }
+            (Self::RateLimitHandler(actor), HandlerActorState::RateLimitHandler(state)) => {
+                <RateLimitHandler<T, TFactory, TAllocator> as Worker<
+                    TAction,
+                    SchedulerTransition<TAction, TTask>,
+                >>::schedule(actor, message, state)
+            }
             (Self::ScanHandler(actor), HandlerActorState::ScanHandler(state)) => {
                 <ScanHandler<T, TFactory, TAllocator> as Worker<
                     TAction,
                     SchedulerTransition<TAction, TTask>,
                 >>::schedule(actor, message, state)
             }
+            (Self::SecretHandler(actor), HandlerActorState::SecretHandler(state)) => {
+                <SecretHandler<T, TFactory, TAllocator> as Worker<
+                    TAction,
+                    SchedulerTransition<TAction, TTask>,
+                >>::schedule(actor, message, state)
+            }
             (Self::TimeoutHandler(actor), HandlerActorState::TimeoutHandler(state)) => {
                 <TimeoutHandler<T, TFactory, TAllocator> as Worker<
                     TAction,
@@ -627,12 +750,30 @@ where
                     SchedulerTransition<TAction, TTask>,
                 >>::handle(inner, state, action, metadata, context)
             }
+            (Self::PluginHandler(inner), HandlerActorState::PluginHandler(state)) => {
+                <PluginHandler<T, TFactory, TAllocator> as Handler<
+                    TAction,
+                    SchedulerTransition<TAction, TTask>,
+                >>::handle(inner, state, action, metadata, context)
+            }
+            (Self::RateLimitHandler(inner), HandlerActorState::RateLimitHandler(state)) => {
+                <RateLimitHandler<T, TFactory, TAllocator> as Handler<
+                    TAction,
+                    SchedulerTransition<TAction, TTask>,
+                >>::handle(inner, state, action, metadata, context)
+            }
             (Self::ScanHandler(inner), HandlerActorState::ScanHandler(state)) => {
                 <ScanHandler<T, TFactory, TAllocator> as Handler<
                     TAction,
                     SchedulerTransition<TAction, TTask>,
                 >>::handle(inner, state, action, metadata, context)
             }
+            (Self::SecretHandler(inner), HandlerActorState::SecretHandler(state)) => {
+                <SecretHandler<T, TFactory, TAllocator> as Handler<
+                    TAction,
+                    SchedulerTransition<TAction, TTask>,
+                >>::handle(inner, state, action, metadata, context)
+            }
             (Self::TimeoutHandler(inner), HandlerActorState::TimeoutHandler(state)) => {
                 <TimeoutHandler<T, TFactory, TAllocator> as Handler<
                     TAction,
@@ -0,0 +1,413 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    iter::once,
+    marker::PhantomData,
+    ops::Deref,
+};
+
+use reflex::{
+    core::{
+        ConditionType, Expression, ExpressionFactory, HeapAllocator, RefType, SignalType,
+        StateToken, StringTermType, StringValue, Uuid,
+    },
+    hash::IntMap,
+};
+use reflex_dispatcher::{
+    Action, ActorEvents, HandlerContext, MessageData, NoopDisposeCallback, ProcessId,
+    SchedulerCommand, SchedulerMode, SchedulerTransition, TaskFactory, TaskInbox,
+};
+use reflex_json::JsonValue;
+use reflex_macros::{dispatcher, Named};
+use reflex_runtime::{
+    action::effect::{
+        EffectEmitAction, EffectSubscribeAction, EffectUnsubscribeAction, EffectUpdateBatch,
+    },
+    AsyncExpression, AsyncExpressionFactory, AsyncHeapAllocator,
+};
+
+use crate::{
+    action::plugin::PluginHandlerResponseAction,
+    task::plugin::{PluginHandlerTask, PluginHandlerTaskFactory},
+};
+
+pub use crate::utils::plugin::{
+    EffectPlugin, EffectPluginDefinition, DEFAULT_PLUGIN_TIMEOUT, PLUGIN_EXPORT_ALLOC,
+    PLUGIN_EXPORT_FREE, PLUGIN_EXPORT_HANDLE, PLUGIN_EXPORT_MANIFEST, PLUGIN_EXPORT_MEMORY,
+};
+
+#[derive(Named, Clone)]
+pub struct PluginHandler<T, TFactory, TAllocator>
+where
+    T: AsyncExpression,
+    TFactory: AsyncExpressionFactory<T>,
+    TAllocator: AsyncHeapAllocator<T>,
+{
+    plugin: EffectPlugin,
+    factory: TFactory,
+    allocator: TAllocator,
+    main_pid: ProcessId,
+    _expression: PhantomData<T>,
+}
+impl<T, TFactory, TAllocator> PluginHandler<T, TFactory, TAllocator>
+where
+    T: AsyncExpression,
+    TFactory: AsyncExpressionFactory<T>,
+    TAllocator: AsyncHeapAllocator<T>,
+{
+    pub fn new(
+        plugin: EffectPlugin,
+        factory: TFactory,
+        allocator: TAllocator,
+        main_pid: ProcessId,
+    ) -> Self {
+        Self {
+            plugin,
+            factory,
+            allocator,
+            main_pid,
+            _expression: Default::default(),
+        }
+    }
+    fn is_plugin_effect_type(&self, effect_type: &T) -> bool {
+        self.factory
+            .match_string_term(effect_type)
+            .map(|effect_type| {
+                self.plugin
+                    .handles_effect_type(effect_type.value().as_deref().as_str().deref())
+            })
+            .unwrap_or(false)
+    }
+}
+
+pub struct PluginHandlerState<T: Expression> {
+    active_operations: IntMap<StateToken, (Uuid, ProcessId)>,
+    operation_effect_mappings: HashMap<Uuid, (T, T::Signal)>,
+}
+impl<T: Expression> Default for PluginHandlerState<T> {
+    fn default() -> Self {
+        Self {
+            active_operations: Default::default(),
+            operation_effect_mappings: Default::default(),
+        }
+    }
+}
+impl<T: Expression> PluginHandlerState<T> {
+    fn subscribe_plugin_task(
+        &mut self,
+        effect_type: &T,
+        effect: &T::Signal,
+        plugin: &EffectPlugin,
+        effect_type_name: &str,
+        payload: JsonValue,
+        context: &mut impl HandlerContext,
+    ) -> Option<(ProcessId, PluginHandlerTaskFactory)> {
+        let entry = match self.active_operations.entry(effect.id()) {
+            Entry::Occupied(_) => None,
+            Entry::Vacant(entry) => Some(entry),
+        }?;
+        let operation_id = Uuid::new_v4();
+        let task_pid = context.generate_pid();
+        let task = PluginHandlerTaskFactory {
+            operation_id,
+            plugin: plugin.clone(),
+            effect_type: String::from(effect_type_name),
+            payload,
+            caller_pid: context.pid(),
+        };
+        self.operation_effect_mappings
+            .insert(operation_id, (effect_type.clone(), effect.clone()));
+        entry.insert((operation_id, task_pid));
+        Some((task_pid, task))
+    }
+    fn unsubscribe_plugin_task(&mut self, effect: &T::Signal) -> Option<ProcessId> {
+        let (operation_id, task_pid) = self.active_operations.remove(&effect.id())?;
+        let _ = self.operation_effect_mappings.remove(&operation_id)?;
+        Some(task_pid)
+    }
+}
+
+dispatcher!({
+    pub enum PluginHandlerAction<T: Expression> {
+        Inbox(EffectSubscribeAction<T>),
+        Inbox(EffectUnsubscribeAction<T>),
+        Inbox(PluginHandlerResponseAction),
+
+        Outbox(EffectEmitAction<T>),
+    }
+
+    impl<T, TFactory, TAllocator, TAction, TTask> Dispatcher<TAction, TTask>
+        for PluginHandler<T, TFactory, TAllocator>
+    where
+        T: AsyncExpression,
+        TFactory: AsyncExpressionFactory<T>,
+        TAllocator: AsyncHeapAllocator<T>,
+        TAction: Action,
+        TTask: TaskFactory<TAction, TTask> + PluginHandlerTask,
+    {
+        type State = PluginHandlerState<T>;
+        type Events<TInbox: TaskInbox<TAction>> = TInbox;
+        type Dispose = NoopDisposeCallback;
+
+        fn init(&self) -> Self::State {
+            Default::default()
+        }
+        fn events<TInbox: TaskInbox<TAction>>(
+            &self,
+            inbox: TInbox,
+        ) -> ActorEvents<TInbox, Self::Events<TInbox>, Self::Dispose> {
+            ActorEvents::Sync(inbox)
+        }
+
+        fn accept(&self, action: &EffectSubscribeAction<T>) -> bool {
+            self.is_plugin_effect_type(&action.effect_type)
+        }
+        fn schedule(
+            &self,
+            _action: &EffectSubscribeAction<T>,
+            _state: &Self::State,
+        ) -> Option<SchedulerMode> {
+            Some(SchedulerMode::Async)
+        }
+        fn handle(
+            &self,
+            state: &mut Self::State,
+            action: &EffectSubscribeAction<T>,
+            metadata: &MessageData,
+            context: &mut impl HandlerContext,
+        ) -> Option<SchedulerTransition<TAction, TTask>> {
+            self.handle_effect_subscribe(state, action, metadata, context)
+        }
+
+        fn accept(&self, action: &EffectUnsubscribeAction<T>) -> bool {
+            self.is_plugin_effect_type(&action.effect_type)
+        }
+        fn schedule(
+            &self,
+            _action: &EffectUnsubscribeAction<T>,
+            _state: &Self::State,
+        ) -> Option<SchedulerMode> {
+            Some(SchedulerMode::Async)
+        }
+        fn handle(
+            &self,
+            state: &mut Self::State,
+            action: &EffectUnsubscribeAction<T>,
+            metadata: &MessageData,
+            context: &mut impl HandlerContext,
+        ) -> Option<SchedulerTransition<TAction, TTask>> {
+            self.handle_effect_unsubscribe(state, action, metadata, context)
+        }
+
+        fn accept(&self, _action: &PluginHandlerResponseAction) -> bool {
+            true
+        }
+        fn schedule(
+            &self,
+            _action: &PluginHandlerResponseAction,
+            _state: &Self::State,
+        ) -> Option<SchedulerMode> {
+            Some(SchedulerMode::Async)
+        }
+        fn handle(
+            &self,
+            state: &mut Self::State,
+            action: &PluginHandlerResponseAction,
+            metadata: &MessageData,
+            context: &mut impl HandlerContext,
+        ) -> Option<SchedulerTransition<TAction, TTask>> {
+            self.handle_plugin_handler_response(state, action, metadata, context)
+        }
+    }
+});
+
+impl<T, TFactory, TAllocator> PluginHandler<T, TFactory, TAllocator>
+where
+    T: AsyncExpression,
+    TFactory: AsyncExpressionFactory<T>,
+    TAllocator: AsyncHeapAllocator<T>,
+{
+    fn handle_effect_subscribe<TAction, TTask>(
+        &self,
+        state: &mut PluginHandlerState<T>,
+        action: &EffectSubscribeAction<T>,
+        _metadata: &MessageData,
+        context: &mut impl HandlerContext,
+    ) -> Option<SchedulerTransition<TAction, TTask>>
+    where
+        TAction: Action + From<EffectEmitAction<T>>,
+        TTask: TaskFactory<TAction, TTask> + From<PluginHandlerTaskFactory>,
+    {
+        let EffectSubscribeAction {
+            effect_type,
+            effects,
+        } = action;
+        let effect_type_name = self.factory.match_string_term(effect_type)?;
+        let effect_type_name = effect_type_name.value();
+        let effect_type_name = effect_type_name.as_deref().as_str();
+        let effect_type_name = effect_type_name.deref();
+        if !self.plugin.handles_effect_type(effect_type_name) {
+            return None;
+        }
+        let (initial_values, tasks): (Vec<_>, Vec<_>) = effects
+            .iter()
+            .filter_map(
+                |effect| match parse_plugin_effect_args::<T>(effect, effect_type_name) {
+                    Ok(payload) => {
+                        let (task_pid, task) = state.subscribe_plugin_task(
+                            effect_type,
+                            effect,
+                            &self.plugin,
+                            effect_type_name,
+                            payload,
+                            context,
+                        )?;
+                        let initial_value =
+                            create_pending_expression(&self.factory, &self.allocator);
+                        Some((
+                            (effect.clone(), initial_value),
+                            Some(SchedulerCommand::Task(task_pid, task.into())),
+                        ))
+                    }
+                    Err(err) => Some((
+                        (
+                            effect.clone(),
+                            create_error_expression(
+                                self.factory
+                                    .create_string_term(self.allocator.create_string(err)),
+                                &self.factory,
+                                &self.allocator,
+                            ),
+                        ),
+                        None,
+                    )),
+                },
+            )
+            .unzip();
+        let initial_values_action = if initial_values.is_empty() {
+            None
+        } else {
+            Some(SchedulerCommand::Send(
+                self.main_pid,
+                EffectEmitAction {
+                    effect_types: vec![EffectUpdateBatch {
+                        effect_type: effect_type.clone(),
+                        updates: initial_values,
+                    }],
+                }
+                .into(),
+            ))
+        };
+        Some(SchedulerTransition::new(
+            initial_values_action
+                .into_iter()
+                .chain(tasks.into_iter().flatten()),
+        ))
+    }
+    fn handle_effect_unsubscribe<TAction, TTask>(
+        &self,
+        state: &mut PluginHandlerState<T>,
+        action: &EffectUnsubscribeAction<T>,
+        _metadata: &MessageData,
+        _context: &mut impl HandlerContext,
+    ) -> Option<SchedulerTransition<TAction, TTask>>
+    where
+        TAction: Action,
+        TTask: TaskFactory<TAction, TTask>,
+    {
+        let EffectUnsubscribeAction {
+            effect_type,
+            effects,
+        } = action;
+        if !self.is_plugin_effect_type(effect_type) {
+            return None;
+        }
+        let active_pids = effects
+            .iter()
+            .filter_map(|effect| state.unsubscribe_plugin_task(effect));
+        Some(SchedulerTransition::new(
+            active_pids.map(SchedulerCommand::Kill),
+        ))
+    }
+    fn handle_plugin_handler_response<TAction, TTask>(
+        &self,
+        state: &mut PluginHandlerState<T>,
+        action: &PluginHandlerResponseAction,
+        _metadata: &MessageData,
+        _context: &mut impl HandlerContext,
+    ) -> Option<SchedulerTransition<TAction, TTask>>
+    where
+        TAction: Action + From<EffectEmitAction<T>>,
+        TTask: TaskFactory<TAction, TTask>,
+    {
+        let PluginHandlerResponseAction {
+            operation_id,
+            result,
+            ..
+        } = action;
+        let (effect_type, effect) = state.operation_effect_mappings.remove(operation_id)?;
+        let _ = state.active_operations.remove(&effect.id());
+        let value = match result {
+            Ok(value) => reflex_json::hydrate(value.clone(), &self.factory, &self.allocator)
+                .unwrap_or_else(|err| {
+                    create_error_expression(
+                        self.factory
+                            .create_string_term(self.allocator.create_string(err)),
+                        &self.factory,
+                        &self.allocator,
+                    )
+                }),
+            Err(payload) => create_error_expression(
+                reflex_json::hydrate(payload.clone(), &self.factory, &self.allocator)
+                    .unwrap_or_else(|err| {
+                        self.factory
+                            .create_string_term(self.allocator.create_string(err))
+                    }),
+                &self.factory,
+                &self.allocator,
+            ),
+        };
+        Some(SchedulerTransition::new(once(SchedulerCommand::Send(
+            self.main_pid,
+            EffectEmitAction {
+                effect_types: vec![EffectUpdateBatch {
+                    effect_type,
+                    updates: vec![(effect, value)],
+                }],
+            }
+            .into(),
+        ))))
+    }
+}
+
+fn parse_plugin_effect_args<T: Expression>(
+    effect: &T::Signal,
+    effect_type_name: &str,
+) -> Result<JsonValue, String> {
+    match effect.signal_type() {
+        SignalType::Custom { payload, .. } => reflex_json::sanitize(&payload)
+            .map_err(|err| format!("Invalid {effect_type_name} payload: {err}")),
+        _ => Err(format!("Invalid {effect_type_name} signal")),
+    }
+}
+
+fn create_pending_expression<T: Expression>(
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T {
+    factory.create_signal_term(
+        allocator.create_signal_list(once(allocator.create_signal(SignalType::Pending))),
+    )
+}
+
+fn create_error_expression<T: Expression>(
+    payload: T,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T {
+    factory.create_signal_term(
+        allocator.create_signal_list(once(allocator.create_signal(SignalType::Error { payload }))),
+    )
+}
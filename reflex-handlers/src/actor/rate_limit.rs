@@ -0,0 +1,1019 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    iter::once,
+    marker::PhantomData,
+    ops::Deref,
+    time::{Duration, SystemTime},
+};
+
+use reflex::core::{
+    ConditionListType, ConditionType, Expression, ExpressionFactory, ExpressionListType,
+    FloatTermType, HeapAllocator, IntTermType, IntValue, ListTermType, RefType, SignalTermType,
+    SignalType, StateToken, StringTermType, StringValue,
+};
+use reflex_dispatcher::{
+    Action, ActorEvents, HandlerContext, MessageData, NoopDisposeCallback, ProcessId,
+    SchedulerCommand, SchedulerMode, SchedulerTransition, TaskFactory, TaskInbox,
+};
+use reflex_macros::{blanket_trait, dispatcher, Named};
+use reflex_runtime::{
+    action::effect::{
+        EffectEmitAction, EffectSubscribeAction, EffectUnsubscribeAction, EffectUpdateBatch,
+    },
+    actor::evaluate_handler::{
+        create_evaluate_effect, create_evaluate_effect_type, is_evaluate_effect_type,
+        parse_evaluate_effect_result,
+    },
+    AsyncExpression, AsyncExpressionFactory, AsyncHeapAllocator, QueryEvaluationMode,
+    QueryInvalidationStrategy,
+};
+use reflex_stdlib::ResolveDeep;
+use reflex_utils::clock::Clock;
+
+use crate::actor::timeout::{create_timeout_effect_type, is_timeout_effect_type};
+
+blanket_trait!(
+    pub trait RateLimitHandlerBuiltin: From<ResolveDeep> {}
+);
+
+pub const EFFECT_TYPE_THROTTLE: &'static str = "reflex::throttle";
+pub const EFFECT_TYPE_DEBOUNCE: &'static str = "reflex::debounce";
+pub const EFFECT_TYPE_SAMPLE: &'static str = "reflex::sample";
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum RateLimitEffectType {
+    /// Emit the latest input value at most once per interval
+    Throttle,
+    /// Emit the latest input value once the input has stopped emitting for the given interval
+    Debounce,
+    /// Emit the latest input value whenever the trigger expression emits
+    Sample,
+}
+impl RateLimitEffectType {
+    fn parse<T: Expression>(effect_type: &T, factory: &impl ExpressionFactory<T>) -> Option<Self> {
+        let effect_type = factory.match_string_term(effect_type)?;
+        match effect_type.value().as_deref().as_str().deref() {
+            EFFECT_TYPE_THROTTLE => Some(Self::Throttle),
+            EFFECT_TYPE_DEBOUNCE => Some(Self::Debounce),
+            EFFECT_TYPE_SAMPLE => Some(Self::Sample),
+            _ => None,
+        }
+    }
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Throttle => EFFECT_TYPE_THROTTLE,
+            Self::Debounce => EFFECT_TYPE_DEBOUNCE,
+            Self::Sample => EFFECT_TYPE_SAMPLE,
+        }
+    }
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Throttle => "throttle",
+            Self::Debounce => "debounce",
+            Self::Sample => "sample",
+        }
+    }
+}
+
+pub fn is_rate_limit_effect_type<T: Expression>(
+    effect_type: &T,
+    factory: &impl ExpressionFactory<T>,
+) -> bool {
+    RateLimitEffectType::parse(effect_type, factory).is_some()
+}
+
+fn create_rate_limit_effect_type<T: Expression>(
+    effect_type: RateLimitEffectType,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T {
+    factory.create_string_term(allocator.create_static_string(effect_type.name()))
+}
+
+/// Handles the [`EFFECT_TYPE_THROTTLE`], [`EFFECT_TYPE_DEBOUNCE`] and [`EFFECT_TYPE_SAMPLE`] effect types, which
+/// control how often the result of an input expression is propagated to its subscribers.
+///
+/// The input expression is evaluated as a standalone query, and any delayed results are scheduled by subscribing to
+/// [`reflex::timeout`](crate::actor::timeout::EFFECT_TYPE_TIMEOUT) effects.
+#[derive(Named, Clone)]
+pub struct RateLimitHandler<T, TFactory, TAllocator>
+where
+    T: AsyncExpression,
+    TFactory: AsyncExpressionFactory<T>,
+    TAllocator: AsyncHeapAllocator<T>,
+{
+    factory: TFactory,
+    allocator: TAllocator,
+    main_pid: ProcessId,
+    clock: Clock,
+    _expression: PhantomData<T>,
+}
+impl<T, TFactory, TAllocator> RateLimitHandler<T, TFactory, TAllocator>
+where
+    T: AsyncExpression,
+    TFactory: AsyncExpressionFactory<T>,
+    TAllocator: AsyncHeapAllocator<T>,
+{
+    pub fn new(factory: TFactory, allocator: TAllocator, main_pid: ProcessId) -> Self {
+        Self {
+            factory,
+            allocator,
+            main_pid,
+            clock: Clock::default(),
+            _expression: Default::default(),
+        }
+    }
+    /// Measure rate-limit intervals according to the given clock rather than the host system clock
+    pub fn with_clock(self, clock: Clock) -> Self {
+        Self { clock, ..self }
+    }
+}
+
+pub struct RateLimitHandlerState<T: Expression> {
+    effect_state: HashMap<StateToken, RateLimitEffectState<T>>,
+    /// Maps the child input, trigger and timer effect IDs to the parent rate-limited effect ID
+    effect_mappings: HashMap<StateToken, StateToken>,
+}
+impl<T: Expression> Default for RateLimitHandlerState<T> {
+    fn default() -> Self {
+        Self {
+            effect_state: Default::default(),
+            effect_mappings: Default::default(),
+        }
+    }
+}
+
+struct RateLimitEffectState<T: Expression> {
+    effect: T::Signal,
+    effect_type: RateLimitEffectType,
+    interval: Duration,
+    input_effect: T::Signal,
+    trigger_effect: Option<T::Signal>,
+    /// Latest input value that has not yet been emitted (for sampled effects, this retains the latest input value)
+    latest_value: Option<T>,
+    last_emitted: Option<SystemTime>,
+    timer_effect: Option<T::Signal>,
+    num_timers: usize,
+}
+
+dispatcher!({
+    pub enum RateLimitHandlerAction<T: Expression> {
+        Inbox(EffectSubscribeAction<T>),
+        Inbox(EffectEmitAction<T>),
+        Inbox(EffectUnsubscribeAction<T>),
+
+        Outbox(EffectSubscribeAction<T>),
+        Outbox(EffectEmitAction<T>),
+        Outbox(EffectUnsubscribeAction<T>),
+    }
+
+    impl<T, TFactory, TAllocator, TAction, TTask> Dispatcher<TAction, TTask>
+        for RateLimitHandler<T, TFactory, TAllocator>
+    where
+        T: AsyncExpression,
+        TFactory: AsyncExpressionFactory<T>,
+        TAllocator: AsyncHeapAllocator<T>,
+        T::Builtin: RateLimitHandlerBuiltin,
+        TAction: Action,
+        TTask: TaskFactory<TAction, TTask>,
+    {
+        type State = RateLimitHandlerState<T>;
+        type Events<TInbox: TaskInbox<TAction>> = TInbox;
+        type Dispose = NoopDisposeCallback;
+
+        fn init(&self) -> Self::State {
+            Default::default()
+        }
+        fn events<TInbox: TaskInbox<TAction>>(
+            &self,
+            inbox: TInbox,
+        ) -> ActorEvents<TInbox, Self::Events<TInbox>, Self::Dispose> {
+            ActorEvents::Sync(inbox)
+        }
+
+        fn accept(&self, action: &EffectSubscribeAction<T>) -> bool {
+            is_rate_limit_effect_type(&action.effect_type, &self.factory)
+        }
+        fn schedule(
+            &self,
+            _action: &EffectSubscribeAction<T>,
+            _state: &Self::State,
+        ) -> Option<SchedulerMode> {
+            Some(SchedulerMode::Async)
+        }
+        fn handle(
+            &self,
+            state: &mut Self::State,
+            action: &EffectSubscribeAction<T>,
+            metadata: &MessageData,
+            context: &mut impl HandlerContext,
+        ) -> Option<SchedulerTransition<TAction, TTask>> {
+            self.handle_effect_subscribe(state, action, metadata, context)
+        }
+
+        fn accept(&self, action: &EffectUnsubscribeAction<T>) -> bool {
+            is_rate_limit_effect_type(&action.effect_type, &self.factory)
+        }
+        fn schedule(
+            &self,
+            _action: &EffectUnsubscribeAction<T>,
+            _state: &Self::State,
+        ) -> Option<SchedulerMode> {
+            Some(SchedulerMode::Async)
+        }
+        fn handle(
+            &self,
+            state: &mut Self::State,
+            action: &EffectUnsubscribeAction<T>,
+            metadata: &MessageData,
+            context: &mut impl HandlerContext,
+        ) -> Option<SchedulerTransition<TAction, TTask>> {
+            self.handle_effect_unsubscribe(state, action, metadata, context)
+        }
+
+        fn accept(&self, _action: &EffectEmitAction<T>) -> bool {
+            true
+        }
+        fn schedule(
+            &self,
+            _action: &EffectEmitAction<T>,
+            _state: &Self::State,
+        ) -> Option<SchedulerMode> {
+            Some(SchedulerMode::Async)
+        }
+        fn handle(
+            &self,
+            state: &mut Self::State,
+            action: &EffectEmitAction<T>,
+            metadata: &MessageData,
+            context: &mut impl HandlerContext,
+        ) -> Option<SchedulerTransition<TAction, TTask>> {
+            self.handle_effect_emit(state, action, metadata, context)
+        }
+    }
+});
+
+impl<T, TFactory, TAllocator> RateLimitHandler<T, TFactory, TAllocator>
+where
+    T: AsyncExpression,
+    TFactory: AsyncExpressionFactory<T>,
+    TAllocator: AsyncHeapAllocator<T>,
+    T::Builtin: RateLimitHandlerBuiltin,
+{
+    fn handle_effect_subscribe<TAction, TTask>(
+        &self,
+        state: &mut RateLimitHandlerState<T>,
+        action: &EffectSubscribeAction<T>,
+        _metadata: &MessageData,
+        _context: &mut impl HandlerContext,
+    ) -> Option<SchedulerTransition<TAction, TTask>>
+    where
+        TAction: Action + From<EffectSubscribeAction<T>> + From<EffectEmitAction<T>>,
+        TTask: TaskFactory<TAction, TTask>,
+    {
+        let EffectSubscribeAction {
+            effect_type,
+            effects,
+        } = action;
+        let effect_type = RateLimitEffectType::parse(effect_type, &self.factory)?;
+        let (initial_values, child_effects): (Vec<_>, Vec<_>) = effects
+            .iter()
+            .filter_map(|effect| {
+                match parse_rate_limit_effect_args(effect, effect_type, &self.factory) {
+                    Ok(args) => {
+                        let child_effects =
+                            self.subscribe_rate_limit_effect(state, effect, effect_type, args)?;
+                        Some((
+                            (
+                                effect.clone(),
+                                create_pending_expression(&self.factory, &self.allocator),
+                            ),
+                            child_effects,
+                        ))
+                    }
+                    Err(err) => Some((
+                        (
+                            effect.clone(),
+                            create_error_expression(err, &self.factory, &self.allocator),
+                        ),
+                        Vec::new(),
+                    )),
+                }
+            })
+            .unzip();
+        let initial_values_action = if initial_values.is_empty() {
+            None
+        } else {
+            Some(SchedulerCommand::Send(
+                self.main_pid,
+                EffectEmitAction {
+                    effect_types: vec![EffectUpdateBatch {
+                        effect_type: create_rate_limit_effect_type(
+                            effect_type,
+                            &self.factory,
+                            &self.allocator,
+                        ),
+                        updates: initial_values,
+                    }],
+                }
+                .into(),
+            ))
+        };
+        let child_effects = child_effects.into_iter().flatten().collect::<Vec<_>>();
+        let subscribe_action = if child_effects.is_empty() {
+            None
+        } else {
+            Some(SchedulerCommand::Send(
+                self.main_pid,
+                EffectSubscribeAction {
+                    effect_type: create_evaluate_effect_type(&self.factory, &self.allocator),
+                    effects: child_effects,
+                }
+                .into(),
+            ))
+        };
+        Some(SchedulerTransition::new(
+            initial_values_action.into_iter().chain(subscribe_action),
+        ))
+    }
+    fn handle_effect_unsubscribe<TAction, TTask>(
+        &self,
+        state: &mut RateLimitHandlerState<T>,
+        action: &EffectUnsubscribeAction<T>,
+        _metadata: &MessageData,
+        _context: &mut impl HandlerContext,
+    ) -> Option<SchedulerTransition<TAction, TTask>>
+    where
+        TAction: Action + From<EffectUnsubscribeAction<T>>,
+        TTask: TaskFactory<TAction, TTask>,
+    {
+        let EffectUnsubscribeAction {
+            effect_type,
+            effects,
+        } = action;
+        if !is_rate_limit_effect_type(effect_type, &self.factory) {
+            return None;
+        }
+        let (child_effects, timer_effects): (Vec<_>, Vec<_>) = effects
+            .iter()
+            .filter_map(|effect| {
+                let effect_state = state.effect_state.remove(&effect.id())?;
+                let RateLimitEffectState {
+                    input_effect,
+                    trigger_effect,
+                    timer_effect,
+                    ..
+                } = effect_state;
+                let child_effects = once(input_effect).chain(trigger_effect).collect::<Vec<_>>();
+                for child_effect in child_effects.iter().chain(timer_effect.iter()) {
+                    state.effect_mappings.remove(&child_effect.id());
+                }
+                Some((child_effects, timer_effect))
+            })
+            .unzip();
+        let child_effects = child_effects.into_iter().flatten().collect::<Vec<_>>();
+        let timer_effects = timer_effects.into_iter().flatten().collect::<Vec<_>>();
+        let unsubscribe_action = if child_effects.is_empty() {
+            None
+        } else {
+            Some(SchedulerCommand::Send(
+                self.main_pid,
+                EffectUnsubscribeAction {
+                    effect_type: create_evaluate_effect_type(&self.factory, &self.allocator),
+                    effects: child_effects,
+                }
+                .into(),
+            ))
+        };
+        let unsubscribe_timers_action = if timer_effects.is_empty() {
+            None
+        } else {
+            Some(SchedulerCommand::Send(
+                self.main_pid,
+                EffectUnsubscribeAction {
+                    effect_type: create_timeout_effect_type(&self.factory, &self.allocator),
+                    effects: timer_effects,
+                }
+                .into(),
+            ))
+        };
+        Some(SchedulerTransition::new(
+            unsubscribe_action
+                .into_iter()
+                .chain(unsubscribe_timers_action),
+        ))
+    }
+    fn handle_effect_emit<TAction, TTask>(
+        &self,
+        state: &mut RateLimitHandlerState<T>,
+        action: &EffectEmitAction<T>,
+        _metadata: &MessageData,
+        _context: &mut impl HandlerContext,
+    ) -> Option<SchedulerTransition<TAction, TTask>>
+    where
+        TAction: Action
+            + From<EffectSubscribeAction<T>>
+            + From<EffectUnsubscribeAction<T>>
+            + From<EffectEmitAction<T>>,
+        TTask: TaskFactory<TAction, TTask>,
+    {
+        let EffectEmitAction {
+            effect_types: updates,
+        } = action;
+        if state.effect_state.is_empty() {
+            return None;
+        }
+        let now = self.clock.now();
+        let mut results = Vec::new();
+        let mut subscribed_timers = Vec::new();
+        let mut unsubscribed_timers = Vec::new();
+        let updates = updates
+            .iter()
+            .filter(|batch| {
+                is_evaluate_effect_type(&batch.effect_type, &self.factory)
+                    || is_timeout_effect_type(&batch.effect_type, &self.factory)
+            })
+            .flat_map(|batch| batch.updates.iter());
+        for (updated_effect, update) in updates {
+            let Some(parent_id) = state.effect_mappings.get(&updated_effect.id()).copied() else {
+                continue;
+            };
+            let Some(effect_state) = state.effect_state.get_mut(&parent_id) else {
+                continue;
+            };
+            let updated_state_token = updated_effect.id();
+            if updated_state_token == effect_state.input_effect.id() {
+                let Some(result) = parse_evaluate_effect_result(update, &self.factory) else {
+                    continue;
+                };
+                let (value, _) = result.into_parts();
+                // Ignore interim pending results, so that the first resolved value is not delayed unnecessarily
+                if is_pending_expression(&value, &self.factory) {
+                    continue;
+                }
+                match effect_state.effect_type {
+                    RateLimitEffectType::Throttle => {
+                        let elapsed = effect_state
+                            .last_emitted
+                            // Treat the interval as having just started if the clock has moved backwards
+                            .map(|last_emitted| {
+                                now.duration_since(last_emitted).unwrap_or_default()
+                            });
+                        match elapsed {
+                            Some(elapsed) if elapsed < effect_state.interval => {
+                                // Defer the latest value until the current throttle interval has elapsed
+                                effect_state.latest_value.replace(value);
+                                if effect_state.timer_effect.is_none() {
+                                    let timer_effect = self.create_timer_effect(
+                                        effect_state,
+                                        effect_state.interval - elapsed,
+                                    );
+                                    state.effect_mappings.insert(timer_effect.id(), parent_id);
+                                    subscribed_timers.push(timer_effect);
+                                }
+                            }
+                            _ => {
+                                effect_state.last_emitted.replace(now);
+                                results.push((
+                                    effect_state.effect_type,
+                                    effect_state.effect.clone(),
+                                    value,
+                                ));
+                            }
+                        }
+                    }
+                    RateLimitEffectType::Debounce => {
+                        // Restart the quiet period whenever a new value arrives
+                        effect_state.latest_value.replace(value);
+                        if let Some(existing) = effect_state.timer_effect.take() {
+                            state.effect_mappings.remove(&existing.id());
+                            unsubscribed_timers.push(existing);
+                        }
+                        let timer_effect =
+                            self.create_timer_effect(effect_state, effect_state.interval);
+                        state.effect_mappings.insert(timer_effect.id(), parent_id);
+                        subscribed_timers.push(timer_effect);
+                    }
+                    RateLimitEffectType::Sample => {
+                        effect_state.latest_value.replace(value);
+                    }
+                }
+            } else if effect_state
+                .trigger_effect
+                .as_ref()
+                .map(|trigger_effect| trigger_effect.id() == updated_state_token)
+                .unwrap_or(false)
+            {
+                if let Some(value) = effect_state.latest_value.clone() {
+                    results.push((effect_state.effect_type, effect_state.effect.clone(), value));
+                }
+            } else if effect_state
+                .timer_effect
+                .as_ref()
+                .map(|timer_effect| timer_effect.id() == updated_state_token)
+                .unwrap_or(false)
+            {
+                // Ignore the initial pending value emitted when subscribing to the timer
+                if is_pending_expression(update, &self.factory) {
+                    continue;
+                }
+                if let Some(timer_effect) = effect_state.timer_effect.take() {
+                    state.effect_mappings.remove(&timer_effect.id());
+                    unsubscribed_timers.push(timer_effect);
+                }
+                if let Some(value) = effect_state.latest_value.take() {
+                    effect_state.last_emitted.replace(now);
+                    results.push((effect_state.effect_type, effect_state.effect.clone(), value));
+                }
+            }
+        }
+        let emit_action = if results.is_empty() {
+            None
+        } else {
+            let effect_types = [
+                RateLimitEffectType::Throttle,
+                RateLimitEffectType::Debounce,
+                RateLimitEffectType::Sample,
+            ]
+            .into_iter()
+            .filter_map(|effect_type| {
+                let updates = results
+                    .iter()
+                    .filter(|(result_type, _, _)| *result_type == effect_type)
+                    .map(|(_, effect, value)| (effect.clone(), value.clone()))
+                    .collect::<Vec<_>>();
+                if updates.is_empty() {
+                    None
+                } else {
+                    Some(EffectUpdateBatch {
+                        effect_type: create_rate_limit_effect_type(
+                            effect_type,
+                            &self.factory,
+                            &self.allocator,
+                        ),
+                        updates,
+                    })
+                }
+            })
+            .collect::<Vec<_>>();
+            Some(SchedulerCommand::Send(
+                self.main_pid,
+                EffectEmitAction { effect_types }.into(),
+            ))
+        };
+        let unsubscribe_timers_action = if unsubscribed_timers.is_empty() {
+            None
+        } else {
+            Some(SchedulerCommand::Send(
+                self.main_pid,
+                EffectUnsubscribeAction {
+                    effect_type: create_timeout_effect_type(&self.factory, &self.allocator),
+                    effects: unsubscribed_timers,
+                }
+                .into(),
+            ))
+        };
+        let subscribe_timers_action = if subscribed_timers.is_empty() {
+            None
+        } else {
+            Some(SchedulerCommand::Send(
+                self.main_pid,
+                EffectSubscribeAction {
+                    effect_type: create_timeout_effect_type(&self.factory, &self.allocator),
+                    effects: subscribed_timers,
+                }
+                .into(),
+            ))
+        };
+        Some(SchedulerTransition::new(
+            emit_action
+                .into_iter()
+                .chain(unsubscribe_timers_action)
+                .chain(subscribe_timers_action),
+        ))
+    }
+    fn subscribe_rate_limit_effect(
+        &self,
+        state: &mut RateLimitHandlerState<T>,
+        effect: &T::Signal,
+        effect_type: RateLimitEffectType,
+        args: RateLimitEffectArgs<T>,
+    ) -> Option<Vec<T::Signal>> {
+        let entry = match state.effect_state.entry(effect.id()) {
+            Entry::Occupied(_) => None,
+            Entry::Vacant(entry) => Some(entry),
+        }?;
+        let RateLimitEffectArgs {
+            input,
+            interval,
+            trigger,
+        } = args;
+        let input_effect = self.create_query_effect(effect, effect_type, "input", input);
+        let trigger_effect = trigger
+            .map(|trigger| self.create_query_effect(effect, effect_type, "trigger", trigger));
+        let child_effects = once(input_effect.clone())
+            .chain(trigger_effect.clone())
+            .collect::<Vec<_>>();
+        entry.insert(RateLimitEffectState {
+            effect: effect.clone(),
+            effect_type,
+            interval,
+            input_effect,
+            trigger_effect,
+            latest_value: None,
+            last_emitted: None,
+            timer_effect: None,
+            num_timers: 0,
+        });
+        for child_effect in child_effects.iter() {
+            state.effect_mappings.insert(child_effect.id(), effect.id());
+        }
+        Some(child_effects)
+    }
+    fn create_query_effect(
+        &self,
+        effect: &T::Signal,
+        effect_type: RateLimitEffectType,
+        name: &str,
+        factory: T,
+    ) -> T::Signal {
+        create_evaluate_effect(
+            format!("{} [{} {}]", effect.id(), effect_type.label(), name),
+            self.factory.create_application_term(
+                self.factory.create_builtin_term(ResolveDeep),
+                self.allocator.create_unit_list(
+                    self.factory
+                        .create_application_term(factory, self.allocator.create_empty_list()),
+                ),
+            ),
+            QueryEvaluationMode::Standalone,
+            QueryInvalidationStrategy::Exact,
+            &self.factory,
+            &self.allocator,
+        )
+    }
+    fn create_timer_effect(
+        &self,
+        effect_state: &mut RateLimitEffectState<T>,
+        duration: Duration,
+    ) -> T::Signal {
+        // Each timer is assigned a unique token to prevent it from being deduplicated with any other active timers
+        effect_state.num_timers += 1;
+        let timer_effect = self.allocator.create_signal(SignalType::Custom {
+            effect_type: create_timeout_effect_type(&self.factory, &self.allocator),
+            payload: self.factory.create_list_term(
+                self.allocator.create_unit_list(
+                    self.factory
+                        .create_int_term(duration.as_millis().max(1) as IntValue),
+                ),
+            ),
+            token: self.factory.create_list_term(
+                self.allocator.create_pair(
+                    self.factory
+                        .create_int_term(effect_state.effect.id() as IntValue),
+                    self.factory
+                        .create_int_term(effect_state.num_timers as IntValue),
+                ),
+            ),
+        });
+        effect_state.timer_effect.replace(timer_effect.clone());
+        timer_effect
+    }
+}
+
+struct RateLimitEffectArgs<T: Expression> {
+    input: T,
+    interval: Duration,
+    trigger: Option<T>,
+}
+
+fn parse_rate_limit_effect_args<T: Expression>(
+    effect: &T::Signal,
+    effect_type: RateLimitEffectType,
+    factory: &impl ExpressionFactory<T>,
+) -> Result<RateLimitEffectArgs<T>, String> {
+    let name = effect_type.name();
+    let payload = match effect.signal_type() {
+        SignalType::Custom { payload, .. } => Ok(payload),
+        _ => Err(format!("Invalid {name} signal: {effect}")),
+    }?;
+    let args = factory
+        .match_list_term(&payload)
+        .filter(|args| args.items().as_deref().len() == 2)
+        .ok_or_else(|| {
+            format!("Invalid {name} signal: Expected 2 arguments, received {payload}")
+        })?;
+    let args = args.items();
+    let mut args = args.as_deref().iter().map(|item| item.as_deref().clone());
+    let input = args.next().unwrap();
+    let arg = args.next().unwrap();
+    match effect_type {
+        RateLimitEffectType::Throttle | RateLimitEffectType::Debounce => {
+            match parse_duration_millis_arg(&arg, factory) {
+                Some(interval) => Ok(RateLimitEffectArgs {
+                    input,
+                    interval,
+                    trigger: None,
+                }),
+                None => Err(format!(
+                    "Invalid {name} interval: Expected non-negative number, received {arg}"
+                )),
+            }
+        }
+        RateLimitEffectType::Sample => Ok(RateLimitEffectArgs {
+            input,
+            interval: Duration::ZERO,
+            trigger: Some(arg),
+        }),
+    }
+}
+
+fn parse_duration_millis_arg<T: Expression>(
+    value: &T,
+    factory: &impl ExpressionFactory<T>,
+) -> Option<Duration> {
+    match factory.match_int_term(value) {
+        Some(term) => {
+            let value = term.value();
+            if value >= 0 {
+                Some(Duration::from_millis(value as u64))
+            } else {
+                None
+            }
+        }
+        _ => match factory.match_float_term(value) {
+            Some(term) => {
+                let value = term.value();
+                if value >= 0.0 {
+                    Some(Duration::from_millis(value.trunc() as u64))
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        },
+    }
+}
+
+fn is_pending_expression<T: Expression>(value: &T, factory: &impl ExpressionFactory<T>) -> bool {
+    factory
+        .match_signal_term(value)
+        .map(|term| {
+            term.signals()
+                .as_deref()
+                .iter()
+                .all(|signal| matches!(signal.as_deref().signal_type(), SignalType::Pending))
+        })
+        .unwrap_or(false)
+}
+
+fn create_pending_expression<T: Expression>(
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T {
+    factory.create_signal_term(
+        allocator.create_signal_list(once(allocator.create_signal(SignalType::Pending))),
+    )
+}
+
+fn create_error_expression<T: Expression>(
+    message: String,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T {
+    factory.create_signal_term(allocator.create_signal_list(once(allocator.create_signal(
+        SignalType::Error {
+            payload: factory.create_string_term(allocator.create_string(message)),
+        },
+    ))))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Instant, UNIX_EPOCH};
+
+    use reflex_dispatcher::MessageOffset;
+    use reflex_lang::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
+    use reflex_runtime::{action::RuntimeActions, task::RuntimeTaskFactory};
+    use reflex_stdlib::Stdlib;
+    use reflex_utils::clock::SimulationClock;
+
+    use super::*;
+
+    type T = CachedSharedTerm<Stdlib>;
+    type TFactory = SharedTermFactory<Stdlib>;
+    type TAllocator = DefaultAllocator<T>;
+    type TAction = RuntimeActions<T>;
+    type TTask = RuntimeTaskFactory;
+
+    #[test]
+    fn throttle_simulation_clock() {
+        let factory = TFactory::default();
+        let allocator = TAllocator::default();
+        let main_pid = ProcessId::from(0);
+        let clock = SimulationClock::new(UNIX_EPOCH + Duration::from_secs(1000));
+        let handler = RateLimitHandler::new(factory, allocator.clone(), main_pid)
+            .with_clock(Clock::from(clock.clone()));
+        let mut state = RateLimitHandlerState::default();
+        let effect_type =
+            create_rate_limit_effect_type(RateLimitEffectType::Throttle, &factory, &allocator);
+        let evaluate_effect_type = create_evaluate_effect_type(&factory, &allocator);
+        let timeout_effect_type = create_timeout_effect_type(&factory, &allocator);
+        let effect = allocator.create_signal(SignalType::Custom {
+            effect_type: effect_type.clone(),
+            payload: factory.create_list_term(allocator.create_pair(
+                factory.create_lambda_term(0, factory.create_int_term(0)),
+                factory.create_int_term(100),
+            )),
+            token: factory.create_nil_term(),
+        });
+        let actions = collect_actions(handler.handle_effect_subscribe::<TAction, TTask>(
+            &mut state,
+            &EffectSubscribeAction {
+                effect_type: effect_type.clone(),
+                effects: vec![effect.clone()],
+            },
+            &message_data(),
+            &mut DummyContext,
+        ));
+        let input_effect = get_subscribed_effects(&actions, &evaluate_effect_type)
+            .into_iter()
+            .next()
+            .unwrap();
+        let mut emit = |effect_type: &T, updated_effect: &<T as Expression>::Signal, value: T| {
+            collect_actions(handler.handle_effect_emit::<TAction, TTask>(
+                &mut state,
+                &EffectEmitAction {
+                    effect_types: vec![EffectUpdateBatch {
+                        effect_type: effect_type.clone(),
+                        updates: vec![(updated_effect.clone(), value)],
+                    }],
+                },
+                &message_data(),
+                &mut DummyContext,
+            ))
+        };
+        let create_input_result = |value: T| {
+            factory.create_list_term(allocator.create_pair(
+                value,
+                factory.create_list_term(allocator.create_empty_list()),
+            ))
+        };
+
+        // The first input value is emitted immediately
+        let actions = emit(
+            &evaluate_effect_type,
+            &input_effect,
+            create_input_result(factory.create_int_term(1)),
+        );
+        assert_eq!(
+            get_emitted_values(&actions, &effect_type),
+            vec![(effect.clone(), factory.create_int_term(1))],
+        );
+        assert!(get_subscribed_effects(&actions, &timeout_effect_type).is_empty());
+
+        // Values received within the throttle interval are deferred until the remainder of the interval has elapsed
+        clock.advance(Duration::from_millis(40));
+        let actions = emit(
+            &evaluate_effect_type,
+            &input_effect,
+            create_input_result(factory.create_int_term(2)),
+        );
+        assert!(get_emitted_values(&actions, &effect_type).is_empty());
+        let timers = get_subscribed_effects(&actions, &timeout_effect_type);
+        assert_eq!(get_timer_durations(&timers, &factory), vec![60]);
+
+        // The deferred value is emitted when the timer fires
+        clock.advance(Duration::from_millis(60));
+        let actions = emit(&timeout_effect_type, &timers[0], factory.create_nil_term());
+        assert_eq!(
+            get_emitted_values(&actions, &effect_type),
+            vec![(effect.clone(), factory.create_int_term(2))],
+        );
+        assert_eq!(
+            get_unsubscribed_effects(&actions, &timeout_effect_type),
+            timers
+        );
+
+        // The throttle interval restarts from the simulated time at which the deferred value was emitted
+        clock.advance(Duration::from_millis(30));
+        let actions = emit(
+            &evaluate_effect_type,
+            &input_effect,
+            create_input_result(factory.create_int_term(3)),
+        );
+        assert!(get_emitted_values(&actions, &effect_type).is_empty());
+        let timers = get_subscribed_effects(&actions, &timeout_effect_type);
+        assert_eq!(get_timer_durations(&timers, &factory), vec![70]);
+        clock.advance(Duration::from_millis(200));
+        let actions = emit(&timeout_effect_type, &timers[0], factory.create_nil_term());
+        assert_eq!(
+            get_emitted_values(&actions, &effect_type),
+            vec![(effect.clone(), factory.create_int_term(3))],
+        );
+
+        // Values received after the throttle interval has elapsed are emitted immediately
+        clock.advance(Duration::from_millis(150));
+        let actions = emit(
+            &evaluate_effect_type,
+            &input_effect,
+            create_input_result(factory.create_int_term(4)),
+        );
+        assert_eq!(
+            get_emitted_values(&actions, &effect_type),
+            vec![(effect.clone(), factory.create_int_term(4))],
+        );
+        assert!(get_subscribed_effects(&actions, &timeout_effect_type).is_empty());
+    }
+
+    fn collect_actions(transition: Option<SchedulerTransition<TAction, TTask>>) -> Vec<TAction> {
+        transition
+            .into_iter()
+            .flatten()
+            .filter_map(|command| match command {
+                SchedulerCommand::Send(_, action) => Some(action),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn get_subscribed_effects(
+        actions: &[TAction],
+        effect_type: &T,
+    ) -> Vec<<T as Expression>::Signal> {
+        actions
+            .iter()
+            .filter_map(Option::<&EffectSubscribeAction<T>>::from)
+            .filter(|action| action.effect_type == *effect_type)
+            .flat_map(|action| action.effects.iter().cloned())
+            .collect()
+    }
+
+    fn get_unsubscribed_effects(
+        actions: &[TAction],
+        effect_type: &T,
+    ) -> Vec<<T as Expression>::Signal> {
+        actions
+            .iter()
+            .filter_map(Option::<&EffectUnsubscribeAction<T>>::from)
+            .filter(|action| action.effect_type == *effect_type)
+            .flat_map(|action| action.effects.iter().cloned())
+            .collect()
+    }
+
+    fn get_emitted_values(
+        actions: &[TAction],
+        effect_type: &T,
+    ) -> Vec<(<T as Expression>::Signal, T)> {
+        actions
+            .iter()
+            .filter_map(Option::<&EffectEmitAction<T>>::from)
+            .flat_map(|action| action.effect_types.iter())
+            .filter(|batch| batch.effect_type == *effect_type)
+            .flat_map(|batch| batch.updates.iter().cloned())
+            .collect()
+    }
+
+    fn get_timer_durations(
+        timers: &[<T as Expression>::Signal],
+        factory: &TFactory,
+    ) -> Vec<IntValue> {
+        timers
+            .iter()
+            .filter_map(|timer| match timer.signal_type() {
+                SignalType::Custom { payload, .. } => {
+                    factory.match_list_term(&payload).and_then(|args| {
+                        args.items().as_deref().get(0).and_then(|duration| {
+                            factory
+                                .match_int_term(duration.as_deref())
+                                .map(|term| term.value())
+                        })
+                    })
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn message_data() -> MessageData {
+        MessageData {
+            offset: MessageOffset::from(0),
+            parent: None,
+            timestamp: Instant::now(),
+        }
+    }
+
+    struct DummyContext;
+    impl HandlerContext for DummyContext {
+        fn pid(&self) -> ProcessId {
+            ProcessId::from(0)
+        }
+
+        fn generate_pid(&mut self) -> ProcessId {
+            ProcessId::from(0)
+        }
+    }
+}
@@ -0,0 +1,360 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{collections::HashMap, iter::once, marker::PhantomData, ops::Deref, sync::Arc};
+
+use reflex::core::{
+    ConditionType, Expression, ExpressionFactory, ExpressionListType, HeapAllocator, ListTermType,
+    RefType, SignalType, StringTermType, StringValue,
+};
+use reflex_dispatcher::{
+    Action, ActorEvents, HandlerContext, MessageData, NoopDisposeCallback, ProcessId,
+    SchedulerCommand, SchedulerMode, SchedulerTransition, TaskFactory, TaskInbox,
+};
+use reflex_macros::{dispatcher, Named};
+use reflex_runtime::{
+    action::effect::{EffectEmitAction, EffectSubscribeAction, EffectUpdateBatch},
+    AsyncExpression, AsyncExpressionFactory, AsyncHeapAllocator,
+};
+
+pub const EFFECT_TYPE_SECRET: &'static str = "reflex::secret";
+
+/// Prefix of the environment variables that are exposed as secrets by [`env_secrets`]
+pub const SECRET_ENV_VAR_PREFIX: &'static str = "REFLEX_SECRET_";
+
+pub fn is_secret_effect_type<T: Expression>(
+    effect_type: &T,
+    factory: &impl ExpressionFactory<T>,
+) -> bool {
+    factory
+        .match_string_term(effect_type)
+        .map(|effect_type| effect_type.value().as_deref().as_str().deref() == EFFECT_TYPE_SECRET)
+        .unwrap_or(false)
+}
+
+pub fn create_secret_effect_type<T: Expression>(
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T {
+    factory.create_string_term(allocator.create_static_string(EFFECT_TYPE_SECRET))
+}
+
+/// Collect secrets from all environment variables named `REFLEX_SECRET_<name>`, keyed by `<name>`
+pub fn env_secrets() -> impl Iterator<Item = (String, String)> {
+    std::env::vars().filter_map(|(key, value)| {
+        key.strip_prefix(SECRET_ENV_VAR_PREFIX)
+            .filter(|name| !name.is_empty())
+            .map(|name| (String::from(name), value))
+    })
+}
+
+#[derive(Named, Clone)]
+pub struct SecretHandler<T, TFactory, TAllocator>
+where
+    T: AsyncExpression,
+    TFactory: AsyncExpressionFactory<T>,
+    TAllocator: AsyncHeapAllocator<T>,
+{
+    factory: TFactory,
+    allocator: TAllocator,
+    main_pid: ProcessId,
+    secrets: Arc<HashMap<String, String>>,
+    _expression: PhantomData<T>,
+}
+impl<T, TFactory, TAllocator> SecretHandler<T, TFactory, TAllocator>
+where
+    T: AsyncExpression,
+    TFactory: AsyncExpressionFactory<T>,
+    TAllocator: AsyncHeapAllocator<T>,
+{
+    pub fn new(factory: TFactory, allocator: TAllocator, main_pid: ProcessId) -> Self {
+        Self {
+            factory,
+            allocator,
+            main_pid,
+            secrets: Default::default(),
+            _expression: Default::default(),
+        }
+    }
+    /// Expose the given named secrets to the running application, replacing any previously-configured secrets
+    pub fn with_secrets(self, secrets: impl IntoIterator<Item = (String, String)>) -> Self {
+        Self {
+            secrets: Arc::new(secrets.into_iter().collect()),
+            ..self
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct SecretHandlerState;
+
+dispatcher!({
+    pub enum SecretHandlerAction<T: Expression> {
+        Inbox(EffectSubscribeAction<T>),
+
+        Outbox(EffectEmitAction<T>),
+    }
+
+    impl<T, TFactory, TAllocator, TAction, TTask> Dispatcher<TAction, TTask>
+        for SecretHandler<T, TFactory, TAllocator>
+    where
+        T: AsyncExpression,
+        TFactory: AsyncExpressionFactory<T>,
+        TAllocator: AsyncHeapAllocator<T>,
+        TAction: Action,
+        TTask: TaskFactory<TAction, TTask>,
+    {
+        type State = SecretHandlerState;
+        type Events<TInbox: TaskInbox<TAction>> = TInbox;
+        type Dispose = NoopDisposeCallback;
+
+        fn init(&self) -> Self::State {
+            Default::default()
+        }
+        fn events<TInbox: TaskInbox<TAction>>(
+            &self,
+            inbox: TInbox,
+        ) -> ActorEvents<TInbox, Self::Events<TInbox>, Self::Dispose> {
+            ActorEvents::Sync(inbox)
+        }
+
+        fn accept(&self, action: &EffectSubscribeAction<T>) -> bool {
+            is_secret_effect_type(&action.effect_type, &self.factory)
+        }
+        fn schedule(
+            &self,
+            _action: &EffectSubscribeAction<T>,
+            _state: &Self::State,
+        ) -> Option<SchedulerMode> {
+            Some(SchedulerMode::Async)
+        }
+        fn handle(
+            &self,
+            state: &mut Self::State,
+            action: &EffectSubscribeAction<T>,
+            metadata: &MessageData,
+            context: &mut impl HandlerContext,
+        ) -> Option<SchedulerTransition<TAction, TTask>> {
+            self.handle_effect_subscribe(state, action, metadata, context)
+        }
+    }
+});
+
+impl<T, TFactory, TAllocator> SecretHandler<T, TFactory, TAllocator>
+where
+    T: AsyncExpression,
+    TFactory: AsyncExpressionFactory<T>,
+    TAllocator: AsyncHeapAllocator<T>,
+{
+    fn handle_effect_subscribe<TAction, TTask>(
+        &self,
+        _state: &mut SecretHandlerState,
+        action: &EffectSubscribeAction<T>,
+        _metadata: &MessageData,
+        _context: &mut impl HandlerContext,
+    ) -> Option<SchedulerTransition<TAction, TTask>>
+    where
+        TAction: Action + From<EffectEmitAction<T>>,
+        TTask: TaskFactory<TAction, TTask>,
+    {
+        let EffectSubscribeAction {
+            effect_type,
+            effects,
+        } = action;
+        if !is_secret_effect_type(effect_type, &self.factory) {
+            return None;
+        }
+        let updates = effects
+            .iter()
+            .map(|effect| {
+                let result = match parse_secret_effect_args(effect, &self.factory) {
+                    Ok(name) => match self.secrets.get(&name) {
+                        // Secret values are wrapped in a sensitive term to prevent them leaking into logs or snapshots
+                        Some(value) => self.factory.create_sensitive_term(
+                            self.factory
+                                .create_string_term(self.allocator.create_string(value.clone())),
+                        ),
+                        None => create_error_expression(
+                            format!("Secret not found: {name}"),
+                            &self.factory,
+                            &self.allocator,
+                        ),
+                    },
+                    Err(err) => create_error_expression(err, &self.factory, &self.allocator),
+                };
+                (effect.clone(), result)
+            })
+            .collect::<Vec<_>>();
+        if updates.is_empty() {
+            return None;
+        }
+        Some(SchedulerTransition::new(once(SchedulerCommand::Send(
+            self.main_pid,
+            EffectEmitAction {
+                effect_types: vec![EffectUpdateBatch {
+                    effect_type: create_secret_effect_type(&self.factory, &self.allocator),
+                    updates,
+                }],
+            }
+            .into(),
+        ))))
+    }
+}
+
+fn parse_secret_effect_args<T: Expression>(
+    effect: &T::Signal,
+    factory: &impl ExpressionFactory<T>,
+) -> Result<String, String> {
+    let payload = match effect.signal_type() {
+        SignalType::Custom { payload, .. } => Ok(payload),
+        _ => Err(format!("Invalid {EFFECT_TYPE_SECRET} signal: {effect}")),
+    }?;
+    let args = factory
+        .match_list_term(&payload)
+        .filter(|args| args.items().as_deref().len() == 1)
+        .ok_or_else(|| {
+            format!("Invalid {EFFECT_TYPE_SECRET} signal: Expected 1 argument, received {payload}",)
+        })?;
+    let args = args.items();
+    let mut args = args.as_deref().iter().map(|item| item.as_deref().clone());
+    let name = args.next().unwrap();
+    match factory.match_string_term(&name) {
+        Some(name) => Ok(String::from(name.value().as_deref().as_str().deref())),
+        None => Err(format!(
+            "Invalid {EFFECT_TYPE_SECRET} signal arguments: {payload}",
+        )),
+    }
+}
+
+fn create_error_expression<T: Expression>(
+    message: String,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T {
+    factory.create_signal_term(allocator.create_signal_list(once(allocator.create_signal(
+        SignalType::Error {
+            payload: factory.create_string_term(allocator.create_string(message)),
+        },
+    ))))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use reflex::core::SensitiveTermType;
+    use reflex_dispatcher::MessageOffset;
+    use reflex_lang::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
+    use reflex_runtime::{action::RuntimeActions, task::RuntimeTaskFactory};
+    use reflex_stdlib::Stdlib;
+
+    use super::*;
+
+    type T = CachedSharedTerm<Stdlib>;
+    type TFactory = SharedTermFactory<Stdlib>;
+    type TAllocator = DefaultAllocator<T>;
+    type TAction = RuntimeActions<T>;
+    type TTask = RuntimeTaskFactory;
+
+    #[test]
+    fn secret_values() {
+        let factory = TFactory::default();
+        let allocator = TAllocator::default();
+        let main_pid = ProcessId::from(0);
+        let handler = SecretHandler::new(factory, allocator.clone(), main_pid)
+            .with_secrets([(String::from("API_KEY"), String::from("hunter2"))]);
+        let mut state = SecretHandlerState::default();
+        let effect_type = create_secret_effect_type(&factory, &allocator);
+        let create_effect = |name: &'static str| {
+            allocator.create_signal(SignalType::Custom {
+                effect_type: effect_type.clone(),
+                payload: factory.create_list_term(allocator.create_unit_list(
+                    factory.create_string_term(allocator.create_static_string(name)),
+                )),
+                token: factory.create_nil_term(),
+            })
+        };
+        let existing = create_effect("API_KEY");
+        let missing = create_effect("UNKNOWN");
+        let actions = collect_actions(handler.handle_effect_subscribe::<TAction, TTask>(
+            &mut state,
+            &EffectSubscribeAction {
+                effect_type: effect_type.clone(),
+                effects: vec![existing.clone(), missing.clone()],
+            },
+            &message_data(),
+            &mut DummyContext,
+        ));
+        let values = get_emitted_values(&actions, &effect_type);
+        assert_eq!(
+            values,
+            vec![
+                (
+                    existing.clone(),
+                    factory.create_sensitive_term(
+                        factory.create_string_term(allocator.create_static_string("hunter2")),
+                    ),
+                ),
+                (
+                    missing.clone(),
+                    create_error_expression(
+                        String::from("Secret not found: UNKNOWN"),
+                        &factory,
+                        &allocator,
+                    ),
+                ),
+            ],
+        );
+        // The secret value is wrapped in a sensitive term, so that it is redacted when formatted
+        let (_, value) = &values[0];
+        let sensitive = factory.match_sensitive_term(value).unwrap();
+        assert_eq!(
+            sensitive.value().as_deref(),
+            &factory.create_string_term(allocator.create_static_string("hunter2")),
+        );
+        assert!(!format!("{}", value).contains("hunter2"));
+    }
+
+    fn collect_actions(transition: Option<SchedulerTransition<TAction, TTask>>) -> Vec<TAction> {
+        transition
+            .into_iter()
+            .flatten()
+            .filter_map(|command| match command {
+                SchedulerCommand::Send(_, action) => Some(action),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn get_emitted_values(
+        actions: &[TAction],
+        effect_type: &T,
+    ) -> Vec<(<T as Expression>::Signal, T)> {
+        actions
+            .iter()
+            .filter_map(Option::<&EffectEmitAction<T>>::from)
+            .flat_map(|action| action.effect_types.iter())
+            .filter(|batch| batch.effect_type == *effect_type)
+            .flat_map(|batch| batch.updates.iter().cloned())
+            .collect()
+    }
+
+    fn message_data() -> MessageData {
+        MessageData {
+            offset: MessageOffset::from(0),
+            parent: None,
+            timestamp: Instant::now(),
+        }
+    }
+
+    struct DummyContext;
+    impl HandlerContext for DummyContext {
+        fn pid(&self) -> ProcessId {
+            ProcessId::from(0)
+        }
+
+        fn generate_pid(&mut self) -> ProcessId {
+            ProcessId::from(0)
+        }
+    }
+}
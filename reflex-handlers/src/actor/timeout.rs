@@ -29,6 +29,7 @@ use reflex_runtime::{
     },
     AsyncExpression, AsyncExpressionFactory, AsyncHeapAllocator,
 };
+use reflex_utils::clock::Clock;
 
 use crate::{
     action::timeout::TimeoutHandlerTimeoutAction,
@@ -64,6 +65,7 @@ where
     factory: TFactory,
     allocator: TAllocator,
     main_pid: ProcessId,
+    clock: Clock,
     _expression: PhantomData<T>,
 }
 impl<T, TFactory, TAllocator> TimeoutHandler<T, TFactory, TAllocator>
@@ -77,9 +79,14 @@ where
             factory,
             allocator,
             main_pid,
+            clock: Clock::default(),
             _expression: Default::default(),
         }
     }
+    /// Schedule timeout updates according to the given clock rather than the host system clock
+    pub fn with_clock(self, clock: Clock) -> Self {
+        Self { clock, ..self }
+    }
 }
 
 pub struct TimeoutHandlerState<T: Expression> {
@@ -99,6 +106,7 @@ impl<T: Expression> TimeoutHandlerState<T> {
         &mut self,
         effect: &T::Signal,
         duration: Duration,
+        clock: &Clock,
         context: &mut impl HandlerContext,
     ) -> Option<(ProcessId, TimeoutHandlerTaskFactory)> {
         let entry = match self.active_operations.entry(effect.id()) {
@@ -106,7 +114,7 @@ impl<T: Expression> TimeoutHandlerState<T> {
             Entry::Vacant(entry) => Some(entry),
         }?;
         let operation_id = Uuid::new_v4();
-        let (task_pid, task) = create_timeout_task(operation_id, duration, context);
+        let (task_pid, task) = create_timeout_task(operation_id, duration, clock, context);
         self.operation_effect_mappings
             .insert(operation_id, effect.clone());
         entry.insert((operation_id, task_pid));
@@ -244,7 +252,12 @@ where
                     Ok(duration) => match duration {
                         None => Some(((effect.clone(), self.factory.create_nil_term()), None)),
                         Some(duration) => {
-                            match state.subscribe_timeout_task(effect, duration, context) {
+                            match state.subscribe_timeout_task(
+                                effect,
+                                duration,
+                                &self.clock,
+                                context,
+                            ) {
                                 None => None,
                                 Some((task_pid, task)) => {
                                     let initial_value =
@@ -345,6 +358,7 @@ where
 fn create_timeout_task(
     operation_id: Uuid,
     duration: Duration,
+    clock: &Clock,
     context: &mut impl HandlerContext,
 ) -> (ProcessId, TimeoutHandlerTaskFactory) {
     let task_pid = context.generate_pid();
@@ -353,6 +367,7 @@ fn create_timeout_task(
         operation_id,
         duration,
         caller_pid: current_pid,
+        clock: clock.clone(),
     };
     (task_pid, task)
 }
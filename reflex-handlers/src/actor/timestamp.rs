@@ -7,7 +7,7 @@ use std::{
     iter::once,
     marker::PhantomData,
     ops::Deref,
-    time::{Duration, SystemTime},
+    time::Duration,
 };
 
 use reflex::core::{
@@ -25,6 +25,7 @@ use reflex_runtime::{
     },
     AsyncExpression, AsyncExpressionFactory, AsyncHeapAllocator,
 };
+use reflex_utils::clock::Clock;
 
 use crate::{
     action::timestamp::TimestampHandlerUpdateAction,
@@ -61,6 +62,7 @@ where
     factory: TFactory,
     allocator: TAllocator,
     main_pid: ProcessId,
+    clock: Clock,
     _expression: PhantomData<T>,
 }
 impl<T, TFactory, TAllocator> TimestampHandler<T, TFactory, TAllocator>
@@ -74,9 +76,14 @@ where
             factory,
             allocator,
             main_pid,
+            clock: Clock::default(),
             _expression: Default::default(),
         }
     }
+    /// Schedule timestamp updates according to the given clock rather than the host system clock
+    pub fn with_clock(self, clock: Clock) -> Self {
+        Self { clock, ..self }
+    }
 }
 
 pub struct TimestampHandlerState<T: Expression> {
@@ -96,6 +103,7 @@ impl<T: Expression> TimestampHandlerState<T> {
         &mut self,
         effect: &T::Signal,
         duration: Duration,
+        clock: &Clock,
         context: &mut impl HandlerContext,
     ) -> Option<(ProcessId, TimestampHandlerTaskFactory)> {
         let entry = match self.active_operations.entry(effect.id()) {
@@ -103,7 +111,7 @@ impl<T: Expression> TimestampHandlerState<T> {
             Entry::Vacant(entry) => Some(entry),
         }?;
         let operation_id = Uuid::new_v4();
-        let (task_pid, task) = create_timestamp_task(operation_id, duration, context);
+        let (task_pid, task) = create_timestamp_task(operation_id, duration, clock, context);
         self.operation_effect_mappings
             .insert(operation_id, effect.clone());
         entry.insert((operation_id, task_pid));
@@ -239,11 +247,13 @@ where
             .filter_map(
                 |effect| match parse_timestamp_effect_args(effect, &self.factory) {
                     Ok(interval) => {
-                        match state.subscribe_timestamp_task(effect, interval, context) {
+                        match state.subscribe_timestamp_task(effect, interval, &self.clock, context)
+                        {
                             None => None,
                             Some((task_pid, task)) => {
                                 let initial_value = self.factory.create_timestamp_term(
-                                    get_timestamp_millis(SystemTime::now()) as i64,
+                                    get_timestamp_millis(self.clock.now()) as i64,
+                                    0,
                                 );
                                 Some((
                                     (effect.clone(), initial_value),
@@ -325,7 +335,7 @@ where
         let effect = state.operation_effect_mappings.get(operation_id)?;
         let result = self
             .factory
-            .create_timestamp_term(get_timestamp_millis(*timestamp) as i64);
+            .create_timestamp_term(get_timestamp_millis(*timestamp) as i64, 0);
         Some(SchedulerTransition::new(once(SchedulerCommand::Send(
             self.main_pid,
             EffectEmitAction {
@@ -342,6 +352,7 @@ where
 fn create_timestamp_task(
     operation_id: Uuid,
     interval: Duration,
+    clock: &Clock,
     context: &mut impl HandlerContext,
 ) -> (ProcessId, TimestampHandlerTaskFactory) {
     let task_pid = context.generate_pid();
@@ -350,6 +361,7 @@ fn create_timestamp_task(
         operation_id,
         interval,
         caller_pid: current_pid,
+        clock: clock.clone(),
     };
     (task_pid, task)
 }
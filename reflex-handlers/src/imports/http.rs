@@ -10,6 +10,7 @@ use crate::{actor::fetch::EFFECT_TYPE_FETCH, stdlib::to_request::request_prototy
 blanket_trait!(
     pub trait HttpImportBuiltin:
         Builtin
+        + From<crate::stdlib::Paginate>
         + From<crate::stdlib::ToRequest>
         + From<reflex_json::stdlib::JsonDeserialize>
         + From<stdlib::Apply>
@@ -45,6 +46,10 @@ where
                 factory.create_string_term(allocator.create_static_string("Request")),
                 import_http_request(factory, allocator),
             ),
+            (
+                factory.create_string_term(allocator.create_static_string("paginate")),
+                factory.create_builtin_term(crate::stdlib::Paginate),
+            ),
         ],
         factory,
         allocator,
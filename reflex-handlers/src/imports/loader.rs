@@ -1,8 +1,6 @@
 // SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
-use std::iter::once;
-
 use reflex::core::{create_record, Builtin, Expression, ExpressionFactory, HeapAllocator};
 use reflex_macros::blanket_trait;
 use reflex_stdlib::stdlib;
@@ -28,102 +26,577 @@ where
     T::Builtin: LoaderImportBuiltin,
 {
     create_record(
-        once((
-            factory.create_string_term(allocator.create_static_string("default")),
-            factory.create_lambda_term(
-                2,
-                create_record(
-                    [
-                        (
-                            factory.create_string_term(allocator.create_static_string("load")),
-                            factory.create_lambda_term(
-                                1,
+        [
+            (
+                factory.create_string_term(allocator.create_static_string("default")),
+                create_loader_function(factory, allocator),
+            ),
+            (
+                factory.create_string_term(allocator.create_static_string("batchLoad")),
+                create_batch_load_function(factory, allocator),
+            ),
+        ],
+        factory,
+        allocator,
+    )
+}
+
+/// Create a loader factory that combines all keys loaded via the returned `load` / `loadMany` methods within a single
+/// evaluation pass into a single invocation of the provided batch loader function.
+fn create_loader_function<T: Expression>(
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T
+where
+    T::Builtin: LoaderImportBuiltin,
+{
+    factory.create_lambda_term(
+        2,
+        create_record(
+            [
+                (
+                    factory.create_string_term(allocator.create_static_string("load")),
+                    factory.create_lambda_term(
+                        1,
+                        factory.create_application_term(
+                            factory.create_builtin_term(stdlib::Effect),
+                            allocator.create_triple(
+                                factory.create_string_term(
+                                    allocator.create_static_string(EFFECT_TYPE_LOADER),
+                                ),
                                 factory.create_application_term(
-                                    factory.create_builtin_term(stdlib::Effect),
+                                    factory.create_builtin_term(stdlib::CollectList),
                                     allocator.create_triple(
-                                        factory.create_string_term(
-                                            allocator.create_static_string(EFFECT_TYPE_LOADER),
-                                        ),
-                                        factory.create_application_term(
-                                            factory.create_builtin_term(stdlib::CollectList),
-                                            allocator.create_triple(
-                                                factory.create_variable_term(2),
-                                                factory.create_partial_application_term(
-                                                    factory.create_lambda_term(
-                                                        2,
+                                        factory.create_variable_term(2),
+                                        factory.create_partial_application_term(
+                                            factory.create_lambda_term(
+                                                2,
+                                                factory.create_application_term(
+                                                    factory.create_builtin_term(
+                                                        crate::stdlib::ResolveLoaderResults,
+                                                    ),
+                                                    allocator.create_pair(
+                                                        factory.create_variable_term(0),
                                                         factory.create_application_term(
-                                                            factory.create_builtin_term(
-                                                                crate::stdlib::ResolveLoaderResults,
-                                                            ),
-                                                            allocator.create_pair(
+                                                            factory.create_variable_term(1),
+                                                            allocator.create_unit_list(
                                                                 factory.create_variable_term(0),
-                                                                factory.create_application_term(
-                                                                    factory.create_variable_term(1),
-                                                                    allocator.create_unit_list(
-                                                                        factory
-                                                                            .create_variable_term(
-                                                                                0,
-                                                                            ),
-                                                                    ),
-                                                                ),
                                                             ),
                                                         ),
                                                     ),
-                                                    allocator.create_unit_list(
-                                                        factory.create_variable_term(1),
-                                                    ),
                                                 ),
-                                                factory.create_variable_term(0),
                                             ),
+                                            allocator
+                                                .create_unit_list(factory.create_variable_term(1)),
                                         ),
-                                        factory.create_nil_term(),
+                                        factory.create_variable_term(0),
                                     ),
                                 ),
+                                factory.create_nil_term(),
                             ),
                         ),
-                        (
-                            factory.create_string_term(allocator.create_static_string("loadMany")),
+                    ),
+                ),
+                (
+                    factory.create_string_term(allocator.create_static_string("loadMany")),
+                    factory.create_lambda_term(
+                        1,
+                        factory.create_application_term(
+                            factory.create_builtin_term(stdlib::Map),
+                            allocator.create_pair(
+                                factory.create_variable_term(0),
+                                factory.create_lambda_term(
+                                    1,
+                                    factory.create_application_term(
+                                        factory.create_builtin_term(stdlib::Effect),
+                                        allocator.create_triple(
+                                            factory.create_string_term(
+                                                allocator.create_static_string(EFFECT_TYPE_LOADER),
+                                            ),
+                                            factory.create_application_term(
+                                                factory.create_builtin_term(stdlib::CollectList),
+                                                allocator.create_triple(
+                                                    factory.create_variable_term(3),
+                                                    factory.create_variable_term(2),
+                                                    factory.create_variable_term(0),
+                                                ),
+                                            ),
+                                            factory.create_nil_term(),
+                                        ),
+                                    ),
+                                ),
+                            ),
+                        ),
+                    ),
+                ),
+            ],
+            factory,
+            allocator,
+        ),
+    )
+}
+
+/// Create a function that loads the given key via a batched upstream effect of the given type.
+///
+/// All keys requested for the same effect type within a single evaluation pass are combined into a single upstream
+/// effect whose payload is the list of requested keys, and whose result (either a list of values corresponding 1:1 with
+/// the requested keys, or a hashmap keyed by the requested keys) is distributed back to the individual callers.
+fn create_batch_load_function<T: Expression>(
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T
+where
+    T::Builtin: LoaderImportBuiltin,
+{
+    factory.create_lambda_term(
+        2,
+        factory.create_application_term(
+            factory.create_builtin_term(stdlib::Effect),
+            allocator.create_triple(
+                factory.create_string_term(allocator.create_static_string(EFFECT_TYPE_LOADER)),
+                factory.create_application_term(
+                    factory.create_builtin_term(stdlib::CollectList),
+                    allocator.create_triple(
+                        factory.create_variable_term(1),
+                        factory.create_partial_application_term(
                             factory.create_lambda_term(
-                                1,
+                                2,
                                 factory.create_application_term(
-                                    factory.create_builtin_term(stdlib::Map),
+                                    factory
+                                        .create_builtin_term(crate::stdlib::ResolveLoaderResults),
                                     allocator.create_pair(
                                         factory.create_variable_term(0),
-                                        factory.create_lambda_term(
-                                            1,
-                                            factory.create_application_term(
-                                                factory.create_builtin_term(stdlib::Effect),
-                                                allocator.create_triple(
-                                                    factory.create_string_term(
-                                                        allocator.create_static_string(
-                                                            EFFECT_TYPE_LOADER,
-                                                        ),
-                                                    ),
-                                                    factory.create_application_term(
-                                                        factory.create_builtin_term(
-                                                            stdlib::CollectList,
-                                                        ),
-                                                        allocator.create_triple(
-                                                            factory.create_variable_term(3),
-                                                            factory.create_variable_term(2),
-                                                            factory.create_variable_term(0),
-                                                        ),
-                                                    ),
-                                                    factory.create_nil_term(),
-                                                ),
+                                        factory.create_application_term(
+                                            factory.create_builtin_term(stdlib::Effect),
+                                            allocator.create_triple(
+                                                factory.create_variable_term(1),
+                                                factory.create_variable_term(0),
+                                                factory.create_nil_term(),
                                             ),
                                         ),
                                     ),
                                 ),
                             ),
+                            allocator.create_unit_list(factory.create_variable_term(1)),
                         ),
-                    ],
-                    factory,
-                    allocator,
+                        factory.create_variable_term(0),
+                    ),
                 ),
+                factory.create_nil_term(),
             ),
-        )),
-        factory,
-        allocator,
+        ),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use std::convert::{TryFrom, TryInto};
+
+    use reflex::{
+        cache::SubstitutionCache,
+        core::{
+            evaluate, Applicable, Arity, Builtin, ConditionListType, ConditionType,
+            EvaluationCache, ExpressionListType, ListTermType, RefType, SignalTermType, SignalType,
+            StateCache, Uid, Uuid,
+        },
+    };
+    use reflex_lang::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
+
+    use super::*;
+
+    type T = CachedSharedTerm<LoaderTestBuiltins>;
+    type TFactory = SharedTermFactory<LoaderTestBuiltins>;
+    type TAllocator = DefaultAllocator<T>;
+
+    #[test]
+    fn batch_load_coalesces_keys() {
+        let factory = TFactory::default();
+        let allocator = TAllocator::default();
+        let batch_load = create_batch_load_function(&factory, &allocator);
+        let key_a = factory.create_string_term(allocator.create_static_string("a"));
+        let key_b = factory.create_string_term(allocator.create_static_string("b"));
+        let users_a = get_list_items(
+            &get_effect_payload(&load_key(
+                &batch_load,
+                "fetchUsers",
+                key_a.clone(),
+                &factory,
+                &allocator,
+            )),
+            &factory,
+        );
+        let users_b = get_list_items(
+            &get_effect_payload(&load_key(
+                &batch_load,
+                "fetchUsers",
+                key_b.clone(),
+                &factory,
+                &allocator,
+            )),
+            &factory,
+        );
+        let posts_a = get_list_items(
+            &get_effect_payload(&load_key(
+                &batch_load,
+                "fetchPosts",
+                key_a.clone(),
+                &factory,
+                &allocator,
+            )),
+            &factory,
+        );
+        // Loader effects are emitted as (name, loader, key) triples, named after the upstream effect type
+        assert_eq!(
+            users_a[0],
+            factory.create_string_term(allocator.create_static_string("fetchUsers")),
+        );
+        assert_eq!(users_a[2], key_a);
+        assert_eq!(users_b[2], key_b);
+        // Keys requested for the same upstream effect type share a loader, so are combined into a single batch
+        assert_eq!(users_a[1], users_b[1]);
+        // Keys requested for different upstream effect types are batched independently
+        assert_ne!(users_a[1], posts_a[1]);
+    }
+
+    #[test]
+    fn batch_load_list_results() {
+        let factory = TFactory::default();
+        let allocator = TAllocator::default();
+        let (keys, upstream_effect, expression) = create_batch(&factory, &allocator);
+        assert_eq!(get_effect_payload(&upstream_effect), keys);
+        let mut state = StateCache::default();
+        state.set(
+            upstream_effect.id(),
+            factory.create_list_term(
+                allocator.create_pair(factory.create_int_term(1), factory.create_int_term(2)),
+            ),
+        );
+        let result = evaluate(
+            &expression,
+            &state,
+            &factory,
+            &allocator,
+            &mut SubstitutionCache::new(),
+        );
+        assert_eq!(
+            result.result(),
+            &factory.create_list_term(
+                allocator.create_pair(factory.create_int_term(1), factory.create_int_term(2)),
+            ),
+        );
+    }
+
+    #[test]
+    fn batch_load_hashmap_results() {
+        let factory = TFactory::default();
+        let allocator = TAllocator::default();
+        let (_keys, upstream_effect, expression) = create_batch(&factory, &allocator);
+        let mut state = StateCache::default();
+        state.set(
+            upstream_effect.id(),
+            factory.create_hashmap_term([
+                (
+                    factory.create_string_term(allocator.create_static_string("b")),
+                    factory.create_int_term(2),
+                ),
+                (
+                    factory.create_string_term(allocator.create_static_string("a")),
+                    factory.create_int_term(1),
+                ),
+            ]),
+        );
+        let result = evaluate(
+            &expression,
+            &state,
+            &factory,
+            &allocator,
+            &mut SubstitutionCache::new(),
+        );
+        // Hashmap results are reordered to correspond 1:1 with the requested keys
+        assert_eq!(
+            result.result(),
+            &factory.create_list_term(
+                allocator.create_pair(factory.create_int_term(1), factory.create_int_term(2)),
+            ),
+        );
+        let mut state = StateCache::default();
+        state.set(
+            upstream_effect.id(),
+            factory.create_hashmap_term([(
+                factory.create_string_term(allocator.create_static_string("a")),
+                factory.create_int_term(1),
+            )]),
+        );
+        let result = evaluate(
+            &expression,
+            &state,
+            &factory,
+            &allocator,
+            &mut SubstitutionCache::new(),
+        );
+        // Missing keys result in an error rather than a partial result
+        assert!(matches!(
+            get_single_effect(result.result(), &factory).signal_type(),
+            SignalType::Error { .. }
+        ));
+    }
+
+    /// Create a batch for the keys `a` and `b`, returning the list of keys, the upstream effect that is emitted by the
+    /// loader, and the loader invocation expression
+    fn create_batch(
+        factory: &TFactory,
+        allocator: &TAllocator,
+    ) -> (T, <T as Expression>::Signal, T) {
+        let batch_load = create_batch_load_function(factory, allocator);
+        let loader = get_list_items(
+            &get_effect_payload(&load_key(
+                &batch_load,
+                "fetchUsers",
+                factory.create_string_term(allocator.create_static_string("a")),
+                factory,
+                allocator,
+            )),
+            factory,
+        )[1]
+        .clone();
+        let keys = factory.create_list_term(allocator.create_pair(
+            factory.create_string_term(allocator.create_static_string("a")),
+            factory.create_string_term(allocator.create_static_string("b")),
+        ));
+        let expression =
+            factory.create_application_term(loader, allocator.create_unit_list(keys.clone()));
+        let result = evaluate(
+            &expression,
+            &StateCache::default(),
+            factory,
+            allocator,
+            &mut SubstitutionCache::new(),
+        );
+        let upstream_effect = get_single_effect(result.result(), factory);
+        match upstream_effect.signal_type() {
+            SignalType::Custom { effect_type, .. } => assert_eq!(
+                effect_type,
+                factory.create_string_term(allocator.create_static_string("fetchUsers")),
+            ),
+            _ => panic!("Expected custom effect, received {}", upstream_effect),
+        }
+        (keys, upstream_effect, expression)
+    }
+
+    fn load_key(
+        batch_load: &T,
+        effect_type: &'static str,
+        key: T,
+        factory: &TFactory,
+        allocator: &TAllocator,
+    ) -> <T as Expression>::Signal {
+        let expression = factory.create_application_term(
+            batch_load.clone(),
+            allocator.create_pair(
+                factory.create_string_term(allocator.create_static_string(effect_type)),
+                key,
+            ),
+        );
+        let result = evaluate(
+            &expression,
+            &StateCache::default(),
+            factory,
+            allocator,
+            &mut SubstitutionCache::new(),
+        );
+        get_single_effect(result.result(), factory)
+    }
+
+    fn get_single_effect(value: &T, factory: &TFactory) -> <T as Expression>::Signal {
+        factory
+            .match_signal_term(value)
+            .and_then(|term| {
+                term.signals()
+                    .as_deref()
+                    .iter()
+                    .next()
+                    .map(|effect| effect.as_deref().clone())
+            })
+            .unwrap_or_else(|| panic!("Expected signal, received {}", value))
+    }
+
+    fn get_effect_payload(effect: &<T as Expression>::Signal) -> T {
+        match effect.signal_type() {
+            SignalType::Custom { payload, .. } => payload,
+            _ => panic!("Expected custom effect, received {}", effect),
+        }
+    }
+
+    fn get_list_items(value: &T, factory: &TFactory) -> Vec<T> {
+        factory
+            .match_list_term(value)
+            .map(|term| {
+                term.items()
+                    .as_deref()
+                    .iter()
+                    .map(|item| item.as_deref().clone())
+                    .collect()
+            })
+            .unwrap_or_else(|| panic!("Expected list, received {}", value))
+    }
+
+    #[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+    enum LoaderTestBuiltins {
+        Stdlib(stdlib::Stdlib),
+        Handlers(crate::stdlib::Stdlib),
+    }
+    impl From<stdlib::Stdlib> for LoaderTestBuiltins {
+        fn from(target: stdlib::Stdlib) -> Self {
+            LoaderTestBuiltins::Stdlib(target)
+        }
+    }
+    impl From<crate::stdlib::Stdlib> for LoaderTestBuiltins {
+        fn from(target: crate::stdlib::Stdlib) -> Self {
+            LoaderTestBuiltins::Handlers(target)
+        }
+    }
+    impl Uid for LoaderTestBuiltins {
+        fn uid(&self) -> Uuid {
+            match self {
+                LoaderTestBuiltins::Stdlib(term) => term.uid(),
+                LoaderTestBuiltins::Handlers(term) => term.uid(),
+            }
+        }
+    }
+    impl TryFrom<Uuid> for LoaderTestBuiltins {
+        type Error = ();
+        fn try_from(value: Uuid) -> Result<Self, Self::Error> {
+            TryInto::<stdlib::Stdlib>::try_into(value)
+                .map(Self::Stdlib)
+                .or_else(|_| TryInto::<crate::stdlib::Stdlib>::try_into(value).map(Self::Handlers))
+        }
+    }
+    impl Builtin for LoaderTestBuiltins {
+        fn arity(&self) -> Arity {
+            match self {
+                LoaderTestBuiltins::Stdlib(term) => term.arity(),
+                LoaderTestBuiltins::Handlers(term) => term.arity(),
+            }
+        }
+        fn should_parallelize<T: Expression<Builtin = Self> + Applicable<T>>(
+            &self,
+            args: &[T],
+        ) -> bool {
+            match self {
+                LoaderTestBuiltins::Stdlib(term) => term.should_parallelize(args),
+                LoaderTestBuiltins::Handlers(term) => term.should_parallelize(args),
+            }
+        }
+        fn apply<T: Expression<Builtin = Self> + Applicable<T>>(
+            &self,
+            args: impl ExactSizeIterator<Item = T>,
+            factory: &impl ExpressionFactory<T>,
+            allocator: &impl HeapAllocator<T>,
+            cache: &mut impl EvaluationCache<T>,
+        ) -> Result<T, String> {
+            match self {
+                LoaderTestBuiltins::Stdlib(term) => term.apply(args, factory, allocator, cache),
+                LoaderTestBuiltins::Handlers(term) => term.apply(args, factory, allocator, cache),
+            }
+        }
+    }
+    impl std::fmt::Display for LoaderTestBuiltins {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Self::Stdlib(target) => std::fmt::Display::fmt(target, f),
+                Self::Handlers(target) => std::fmt::Display::fmt(target, f),
+            }
+        }
+    }
+
+    impl From<stdlib::Apply> for LoaderTestBuiltins {
+        fn from(value: stdlib::Apply) -> Self {
+            Self::from(stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<stdlib::CollectHashMap> for LoaderTestBuiltins {
+        fn from(value: stdlib::CollectHashMap) -> Self {
+            Self::from(stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<stdlib::CollectHashSet> for LoaderTestBuiltins {
+        fn from(value: stdlib::CollectHashSet) -> Self {
+            Self::from(stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<stdlib::CollectList> for LoaderTestBuiltins {
+        fn from(value: stdlib::CollectList) -> Self {
+            Self::from(stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<stdlib::Effect> for LoaderTestBuiltins {
+        fn from(value: stdlib::Effect) -> Self {
+            Self::from(stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<stdlib::Flatten> for LoaderTestBuiltins {
+        fn from(value: stdlib::Flatten) -> Self {
+            Self::from(stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<stdlib::Get> for LoaderTestBuiltins {
+        fn from(value: stdlib::Get) -> Self {
+            Self::from(stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<stdlib::If> for LoaderTestBuiltins {
+        fn from(value: stdlib::If) -> Self {
+            Self::from(stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<stdlib::Map> for LoaderTestBuiltins {
+        fn from(value: stdlib::Map) -> Self {
+            Self::from(stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<stdlib::MemoStore> for LoaderTestBuiltins {
+        fn from(value: stdlib::MemoStore) -> Self {
+            Self::from(stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<stdlib::ResolveDeep> for LoaderTestBuiltins {
+        fn from(value: stdlib::ResolveDeep) -> Self {
+            Self::from(stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<stdlib::ResolveList> for LoaderTestBuiltins {
+        fn from(value: stdlib::ResolveList) -> Self {
+            Self::from(stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<stdlib::Sequence> for LoaderTestBuiltins {
+        fn from(value: stdlib::Sequence) -> Self {
+            Self::from(stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<stdlib::Sort> for LoaderTestBuiltins {
+        fn from(value: stdlib::Sort) -> Self {
+            Self::from(stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<stdlib::SortBy> for LoaderTestBuiltins {
+        fn from(value: stdlib::SortBy) -> Self {
+            Self::from(stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<stdlib::SortMerge> for LoaderTestBuiltins {
+        fn from(value: stdlib::SortMerge) -> Self {
+            Self::from(stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<stdlib::Unzip> for LoaderTestBuiltins {
+        fn from(value: stdlib::Unzip) -> Self {
+            Self::from(stdlib::Stdlib::from(value))
+        }
+    }
+    impl From<crate::stdlib::ResolveLoaderResults> for LoaderTestBuiltins {
+        fn from(value: crate::stdlib::ResolveLoaderResults) -> Self {
+            Self::from(crate::stdlib::Stdlib::from(value))
+        }
+    }
+}
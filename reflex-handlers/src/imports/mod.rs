@@ -8,6 +8,7 @@ use crate::imports::{
     http::{import_http, HttpImportBuiltin},
     invalidation::{import_invalidation, InvalidationImportBuiltin},
     loader::{import_loader, LoaderImportBuiltin},
+    secret::{import_secret, SecretImportBuiltin},
     state::{import_state, StateImportBuiltin},
     time::{import_time, TimeImportBuiltin},
 };
@@ -15,6 +16,7 @@ use crate::imports::{
 pub mod http;
 pub mod invalidation;
 pub mod loader;
+pub mod secret;
 pub mod state;
 pub mod time;
 
@@ -24,6 +26,7 @@ blanket_trait!(
         + HttpImportBuiltin
         + InvalidationImportBuiltin
         + LoaderImportBuiltin
+        + SecretImportBuiltin
         + StateImportBuiltin
         + TimeImportBuiltin
     {
@@ -50,6 +53,10 @@ where
             String::from("reflex::loader"),
             import_loader(factory, allocator),
         ),
+        (
+            String::from("reflex::secret"),
+            import_secret(factory, allocator),
+        ),
         (
             String::from("reflex::state"),
             import_state(factory, allocator),
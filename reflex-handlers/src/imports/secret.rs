@@ -0,0 +1,46 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{create_record, Builtin, Expression, ExpressionFactory, HeapAllocator};
+use reflex_macros::blanket_trait;
+use reflex_stdlib::stdlib;
+
+use crate::actor::secret::EFFECT_TYPE_SECRET;
+
+blanket_trait!(
+    pub trait SecretImportBuiltin:
+        Builtin + From<stdlib::CollectList> + From<stdlib::Effect>
+    {
+    }
+);
+
+pub fn import_secret<T: Expression>(
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T
+where
+    T::Builtin: SecretImportBuiltin,
+{
+    create_record(
+        [(
+            factory.create_string_term(allocator.create_static_string("get")),
+            factory.create_lambda_term(
+                1,
+                factory.create_application_term(
+                    factory.create_builtin_term(stdlib::Effect),
+                    allocator.create_triple(
+                        factory
+                            .create_string_term(allocator.create_static_string(EFFECT_TYPE_SECRET)),
+                        factory.create_application_term(
+                            factory.create_builtin_term(stdlib::CollectList),
+                            allocator.create_unit_list(factory.create_variable_term(0)),
+                        ),
+                        factory.create_nil_term(),
+                    ),
+                ),
+            ),
+        )],
+        factory,
+        allocator,
+    )
+}
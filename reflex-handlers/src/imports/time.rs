@@ -9,7 +9,13 @@ use crate::actor::{timeout::EFFECT_TYPE_TIMEOUT, timestamp::EFFECT_TYPE_TIMESTAM
 
 blanket_trait!(
     pub trait TimeImportBuiltin:
-        Builtin + From<stdlib::CollectList> + From<stdlib::Effect> + From<stdlib::Get>
+        Builtin
+        + From<crate::stdlib::Debounce>
+        + From<crate::stdlib::Sample>
+        + From<crate::stdlib::Throttle>
+        + From<stdlib::CollectList>
+        + From<stdlib::Effect>
+        + From<stdlib::Get>
     {
     }
 );
@@ -69,6 +75,18 @@ where
                     ),
                 ),
             ),
+            (
+                factory.create_string_term(allocator.create_static_string("throttle")),
+                factory.create_builtin_term(crate::stdlib::Throttle),
+            ),
+            (
+                factory.create_string_term(allocator.create_static_string("debounce")),
+                factory.create_builtin_term(crate::stdlib::Debounce),
+            ),
+            (
+                factory.create_string_term(allocator.create_static_string("sample")),
+                factory.create_builtin_term(crate::stdlib::Sample),
+            ),
         ],
         factory,
         allocator,
@@ -7,14 +7,16 @@ use hyper::Body;
 use reflex::core::{Applicable, Expression};
 use reflex_dispatcher::{Action, ProcessId, TaskFactory};
 use reflex_runtime::{AsyncExpression, AsyncExpressionFactory, AsyncHeapAllocator};
-use reflex_utils::reconnect::ReconnectTimeout;
+use reflex_utils::{clock::Clock, reconnect::ReconnectTimeout};
 
 use crate::{
     actor::{
         fetch::{FetchHandler, FetchHandlerAction, FetchHandlerMetricNames},
         graphql::{GraphQlHandler, GraphQlHandlerAction, GraphQlHandlerMetricNames},
         loader::{LoaderHandler, LoaderHandlerAction, LoaderHandlerMetricNames},
+        rate_limit::{RateLimitHandler, RateLimitHandlerAction},
         scan::{ScanHandler, ScanHandlerAction, ScanHandlerMetricNames},
+        secret::{env_secrets, SecretHandler, SecretHandlerAction},
         timeout::{TimeoutHandler, TimeoutHandlerAction},
         timestamp::{TimestampHandler, TimestampHandlerAction},
         variable::{VariableHandler, VariableHandlerAction},
@@ -41,7 +43,9 @@ blanket_trait!(
         FetchHandlerAction<T>
         + GraphQlHandlerAction<T>
         + LoaderHandlerAction<T>
+        + RateLimitHandlerAction<T>
         + ScanHandlerAction<T>
+        + SecretHandlerAction<T>
         + TimeoutHandlerAction<T>
         + TimestampHandlerAction<T>
         + VariableHandlerAction<T>
@@ -85,6 +89,51 @@ pub fn default_handler_actors<TAction, TTask, T, TFactory, TAllocator, TConnect,
     metric_names: DefaultHandlerMetricNames,
     main_pid: ProcessId,
 ) -> impl IntoIterator<Item = HandlerActor<T, TFactory, TAllocator, TConnect, TReconnect>>
+where
+    T: AsyncExpression + Applicable<T>,
+    T::String: Send,
+    T::Builtin: Send,
+    T::Signal: Send,
+    T::SignalList: Send,
+    T::StructPrototype: Send,
+    T::ExpressionList: Send,
+    TFactory: AsyncExpressionFactory<T>,
+    TAllocator: AsyncHeapAllocator<T>,
+    T::Builtin: HandlerActorBuiltin,
+    TConnect: hyper::client::connect::Connect + Clone + Send + Sync + 'static,
+    TReconnect: ReconnectTimeout + Send + Clone + 'static,
+    TAction: Action + DefaultHandlerAction<T> + Send + 'static,
+    TTask: TaskFactory<TAction, TTask> + DefaultHandlerTask<TConnect>,
+{
+    default_handler_actors_with_clock(
+        https_client,
+        factory,
+        allocator,
+        reconnect_timeout,
+        metric_names,
+        main_pid,
+        Clock::default(),
+    )
+}
+
+/// Create the default set of effect handlers, with time-based effects scheduled according to the given clock
+pub fn default_handler_actors_with_clock<
+    TAction,
+    TTask,
+    T,
+    TFactory,
+    TAllocator,
+    TConnect,
+    TReconnect,
+>(
+    https_client: hyper::Client<TConnect, Body>,
+    factory: &TFactory,
+    allocator: &TAllocator,
+    reconnect_timeout: TReconnect,
+    metric_names: DefaultHandlerMetricNames,
+    main_pid: ProcessId,
+    clock: Clock,
+) -> impl IntoIterator<Item = HandlerActor<T, TFactory, TAllocator, TConnect, TReconnect>>
 where
     T: AsyncExpression + Applicable<T>,
     T::String: Send,
@@ -123,22 +172,27 @@ where
             metric_names.loader_handler,
             main_pid,
         )),
+        HandlerActor::RateLimitHandler(
+            RateLimitHandler::new(factory.clone(), allocator.clone(), main_pid)
+                .with_clock(clock.clone()),
+        ),
         HandlerActor::ScanHandler(ScanHandler::new(
             factory.clone(),
             allocator.clone(),
             metric_names.scan_handler,
             main_pid,
         )),
-        HandlerActor::TimeoutHandler(TimeoutHandler::new(
-            factory.clone(),
-            allocator.clone(),
-            main_pid,
-        )),
-        HandlerActor::TimestampHandler(TimestampHandler::new(
-            factory.clone(),
-            allocator.clone(),
-            main_pid,
-        )),
+        HandlerActor::SecretHandler(
+            SecretHandler::new(factory.clone(), allocator.clone(), main_pid)
+                .with_secrets(env_secrets()),
+        ),
+        HandlerActor::TimeoutHandler(
+            TimeoutHandler::new(factory.clone(), allocator.clone(), main_pid)
+                .with_clock(clock.clone()),
+        ),
+        HandlerActor::TimestampHandler(
+            TimestampHandler::new(factory.clone(), allocator.clone(), main_pid).with_clock(clock),
+        ),
         HandlerActor::VariableHandler(VariableHandler::new(
             factory.clone(),
             allocator.clone(),
@@ -10,13 +10,21 @@ use reflex::core::{
     Applicable, Arity, EvaluationCache, Expression, ExpressionFactory, HeapAllocator, Uid, Uuid,
 };
 
+pub mod debounce;
+pub mod paginate;
 pub mod resolve_loader_results;
+pub mod sample;
 pub mod scan;
+pub mod throttle;
 pub mod to_request;
 pub mod variable;
 
+pub use debounce::*;
+pub use paginate::*;
 pub use resolve_loader_results::*;
+pub use sample::*;
 pub use scan::*;
+pub use throttle::*;
 pub use to_request::*;
 pub use variable::*;
 
@@ -29,6 +37,10 @@ pub enum Stdlib {
     SetVariable,
     IncrementVariable,
     DecrementVariable,
+    Paginate,
+    Throttle,
+    Debounce,
+    Sample,
 }
 impl Stdlib {
     pub fn entries() -> impl Iterator<Item = Self> {
@@ -46,6 +58,10 @@ impl TryFrom<Uuid> for Stdlib {
             SetVariable::UUID => Ok(Self::SetVariable),
             IncrementVariable::UUID => Ok(Self::IncrementVariable),
             DecrementVariable::UUID => Ok(Self::DecrementVariable),
+            Paginate::UUID => Ok(Self::Paginate),
+            Throttle::UUID => Ok(Self::Throttle),
+            Debounce::UUID => Ok(Self::Debounce),
+            Sample::UUID => Ok(Self::Sample),
             _ => Err(()),
         }
     }
@@ -60,6 +76,10 @@ impl Uid for Stdlib {
             Self::SetVariable => Uid::uid(&SetVariable {}),
             Self::IncrementVariable => Uid::uid(&IncrementVariable {}),
             Self::DecrementVariable => Uid::uid(&DecrementVariable {}),
+            Self::Paginate => Uid::uid(&Paginate {}),
+            Self::Throttle => Uid::uid(&Throttle {}),
+            Self::Debounce => Uid::uid(&Debounce {}),
+            Self::Sample => Uid::uid(&Sample {}),
         }
     }
 }
@@ -73,6 +93,10 @@ impl Stdlib {
             Self::SetVariable => SetVariable::arity(),
             Self::IncrementVariable => IncrementVariable::arity(),
             Self::DecrementVariable => DecrementVariable::arity(),
+            Self::Paginate => Paginate::arity(),
+            Self::Throttle => Throttle::arity(),
+            Self::Debounce => Debounce::arity(),
+            Self::Sample => Sample::arity(),
         }
     }
     pub fn apply<T: Expression>(
@@ -105,6 +129,10 @@ impl Stdlib {
             Self::DecrementVariable => {
                 Applicable::<T>::apply(&DecrementVariable {}, args, factory, allocator, cache)
             }
+            Self::Paginate => Applicable::<T>::apply(&Paginate {}, args, factory, allocator, cache),
+            Self::Throttle => Applicable::<T>::apply(&Throttle {}, args, factory, allocator, cache),
+            Self::Debounce => Applicable::<T>::apply(&Debounce {}, args, factory, allocator, cache),
+            Self::Sample => Applicable::<T>::apply(&Sample {}, args, factory, allocator, cache),
         }
     }
     pub fn should_parallelize<T: Expression>(&self, args: &[T]) -> bool
@@ -125,6 +153,10 @@ impl Stdlib {
             Self::DecrementVariable => {
                 Applicable::<T>::should_parallelize(&IncrementVariable {}, args)
             }
+            Self::Paginate => Applicable::<T>::should_parallelize(&Paginate {}, args),
+            Self::Throttle => Applicable::<T>::should_parallelize(&Throttle {}, args),
+            Self::Debounce => Applicable::<T>::should_parallelize(&Debounce {}, args),
+            Self::Sample => Applicable::<T>::should_parallelize(&Sample {}, args),
         }
     }
 }
@@ -169,3 +201,23 @@ impl From<DecrementVariable> for Stdlib {
         Self::DecrementVariable
     }
 }
+impl From<Paginate> for Stdlib {
+    fn from(_value: Paginate) -> Self {
+        Self::Paginate
+    }
+}
+impl From<Throttle> for Stdlib {
+    fn from(_value: Throttle) -> Self {
+        Self::Throttle
+    }
+}
+impl From<Debounce> for Stdlib {
+    fn from(_value: Debounce) -> Self {
+        Self::Debounce
+    }
+}
+impl From<Sample> for Stdlib {
+    fn from(_value: Sample) -> Self {
+        Self::Sample
+    }
+}
@@ -0,0 +1,104 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{
+    create_record, uuid, Applicable, ArgType, Arity, EvaluationCache, Expression,
+    ExpressionFactory, FunctionArity, HeapAllocator, Uid, Uuid,
+};
+
+use crate::stdlib::Stdlib;
+
+/// Lazily-evaluated sequence of pages retrieved from a cursor-based paginated source
+///
+/// The `fetch_page` argument is a function that takes a cursor and returns a record containing an `items` field (the
+/// contents of the current page) and a `cursor` field (the cursor for the subsequent page, or `null` if this is the final
+/// page). The result is a record containing the `items` of the current page, alongside a `next` field containing the
+/// remainder of the sequence (or `null` if there are no further pages).
+///
+/// The `next` field is only evaluated when accessed, so successive fetch effects are only emitted once a consumer pulls
+/// past the end of the page it is currently iterating over.
+pub struct Paginate;
+impl Paginate {
+    pub const UUID: Uuid = uuid!("3c1f1b5e-24c8-4b71-9a5e-7f32c9d4e8a1");
+    const ARITY: FunctionArity<2, 0> = FunctionArity {
+        required: [ArgType::Strict, ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for Paginate {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for Paginate
+where
+    T::Builtin: From<Stdlib> + From<reflex_stdlib::Stdlib>,
+{
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let mut args = args.into_iter();
+        let fetch_page = args.next().unwrap();
+        let cursor = args.next().unwrap();
+        let page =
+            factory.create_application_term(fetch_page.clone(), allocator.create_unit_list(cursor));
+        let next_cursor = factory.create_application_term(
+            factory.create_builtin_term(reflex_stdlib::Stdlib::Get),
+            allocator.create_pair(
+                page.clone(),
+                factory.create_string_term(allocator.create_static_string("cursor")),
+            ),
+        );
+        Ok(create_record(
+            [
+                (
+                    factory.create_string_term(allocator.create_static_string("items")),
+                    factory.create_application_term(
+                        factory.create_builtin_term(reflex_stdlib::Stdlib::Get),
+                        allocator.create_pair(
+                            page,
+                            factory.create_string_term(allocator.create_static_string("items")),
+                        ),
+                    ),
+                ),
+                (
+                    factory.create_string_term(allocator.create_static_string("next")),
+                    factory.create_application_term(
+                        factory.create_builtin_term(reflex_stdlib::Stdlib::If),
+                        allocator.create_triple(
+                            factory.create_application_term(
+                                factory.create_builtin_term(reflex_stdlib::Stdlib::Eq),
+                                allocator
+                                    .create_pair(next_cursor.clone(), factory.create_nil_term()),
+                            ),
+                            factory.create_lambda_term(0, factory.create_nil_term()),
+                            factory.create_lambda_term(
+                                0,
+                                factory.create_application_term(
+                                    factory.create_builtin_term(Stdlib::Paginate),
+                                    allocator.create_pair(fetch_page, next_cursor),
+                                ),
+                            ),
+                        ),
+                    ),
+                ),
+            ],
+            factory,
+            allocator,
+        ))
+    }
+}
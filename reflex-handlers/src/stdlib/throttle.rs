@@ -0,0 +1,62 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{
+    uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
+    FunctionArity, HeapAllocator, SignalType, Uid, Uuid,
+};
+
+use crate::actor::rate_limit::EFFECT_TYPE_THROTTLE;
+
+pub struct Throttle;
+impl Throttle {
+    pub const UUID: Uuid = uuid!("91e20aab-b4f9-4049-b314-a195494dc438");
+    const ARITY: FunctionArity<2, 0> = FunctionArity {
+        required: [ArgType::Strict, ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for Throttle {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for Throttle {
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let mut args = args.into_iter();
+        let input = args.next().unwrap();
+        let interval = args.next().unwrap();
+        if !is_pure_expression(&input) {
+            Err(format!("Throttle input must be a pure expression"))
+        } else {
+            Ok(factory.create_effect_term(
+                allocator.create_signal(SignalType::Custom {
+                    effect_type: factory
+                        .create_string_term(allocator.create_static_string(EFFECT_TYPE_THROTTLE)),
+                    payload: factory.create_list_term(allocator.create_pair(input, interval)),
+                    token: factory.create_nil_term(),
+                }),
+            ))
+        }
+    }
+}
+
+fn is_pure_expression<T: Expression>(expression: &T) -> bool {
+    expression.capture_depth() == 0 && !expression.has_dynamic_dependencies(true)
+}
@@ -7,6 +7,7 @@ use reflex_macros::{blanket_trait, task_factory_enum, Matcher};
 
 pub mod fetch;
 pub mod graphql;
+pub mod plugin;
 pub mod timeout;
 pub mod timestamp;
 
@@ -16,6 +17,7 @@ use crate::task::graphql::{
 use crate::task::{
     fetch::{FetchHandlerTask, FetchHandlerTaskAction, FetchHandlerTaskFactory},
     graphql::{GraphQlHandlerTask, GraphQlHandlerTaskAction, GraphQlHandlerTaskFactory},
+    plugin::{PluginHandlerTask, PluginHandlerTaskAction, PluginHandlerTaskFactory},
     timeout::{TimeoutHandlerTask, TimeoutHandlerTaskAction, TimeoutHandlerTaskFactory},
     timestamp::{TimestampHandlerTask, TimestampHandlerTaskAction, TimestampHandlerTaskFactory},
 };
@@ -25,6 +27,7 @@ blanket_trait!(
         Action
         + FetchHandlerTaskAction
         + GraphQlHandlerTaskAction
+        + PluginHandlerTaskAction
         + TimeoutHandlerTaskAction
         + TimestampHandlerTaskAction
     {
@@ -35,6 +38,7 @@ blanket_trait!(
     pub trait DefaultHandlersTask<TConnect>:
         FetchHandlerTask<TConnect>
         + GraphQlHandlerTask<TConnect>
+        + PluginHandlerTask
         + TimeoutHandlerTask
         + TimestampHandlerTask
     where
@@ -51,6 +55,7 @@ task_factory_enum!({
     {
         Fetch(FetchHandlerTaskFactory<TConnect>),
         GraphQl(GraphQlHandlerTaskFactory<TConnect>),
+        Plugin(PluginHandlerTaskFactory),
         Timeout(TimeoutHandlerTaskFactory),
         Timestamp(TimestampHandlerTaskFactory),
     }
@@ -0,0 +1,154 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use futures::{FutureExt, Stream};
+use reflex::core::Uuid;
+use reflex_dispatcher::{
+    Action, ActorEvents, BoxedActionStream, HandlerContext, MessageData, NoopDisposeCallback,
+    ProcessId, SchedulerCommand, SchedulerMode, SchedulerTransition, TaskFactory, TaskInbox,
+};
+use reflex_json::JsonValue;
+use reflex_macros::{dispatcher, Named};
+
+use crate::{action::plugin::PluginHandlerResponseAction, utils::plugin::EffectPlugin};
+
+pub trait PluginHandlerTask: From<PluginHandlerTaskFactory> {}
+impl<_Self> PluginHandlerTask for _Self where Self: From<PluginHandlerTaskFactory> {}
+
+// TODO: Implement Serialize/Deserialize traits for PluginHandlerTaskFactory
+#[derive(Named, Clone)]
+pub struct PluginHandlerTaskFactory {
+    pub operation_id: Uuid,
+    pub plugin: EffectPlugin,
+    pub effect_type: String,
+    pub payload: JsonValue,
+    pub caller_pid: ProcessId,
+}
+impl<TAction, TTask> TaskFactory<TAction, TTask> for PluginHandlerTaskFactory
+where
+    TAction: Action + PluginHandlerTaskAction + Send + 'static,
+    TTask: TaskFactory<TAction, TTask>,
+{
+    type Actor = PluginHandlerTaskActor;
+    fn create(self) -> Self::Actor {
+        let Self {
+            operation_id,
+            plugin,
+            effect_type,
+            payload,
+            caller_pid,
+        } = self;
+        PluginHandlerTaskActor {
+            operation_id,
+            plugin,
+            effect_type,
+            payload,
+            caller_pid,
+        }
+    }
+}
+
+#[derive(Named, Clone)]
+pub struct PluginHandlerTaskActor {
+    operation_id: Uuid,
+    plugin: EffectPlugin,
+    effect_type: String,
+    payload: JsonValue,
+    caller_pid: ProcessId,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Default)]
+pub struct PluginHandlerTaskActorState;
+
+dispatcher!({
+    pub enum PluginHandlerTaskAction {
+        Inbox(PluginHandlerResponseAction),
+
+        Outbox(PluginHandlerResponseAction),
+    }
+
+    impl<TAction, TTask> Dispatcher<TAction, TTask> for PluginHandlerTaskActor
+    where
+        TAction: Action + 'static,
+        TTask: TaskFactory<TAction, TTask>,
+    {
+        type State = PluginHandlerTaskActorState;
+        type Events<TInbox: TaskInbox<TAction>> = BoxedActionStream<TInbox::Message>;
+        type Dispose = NoopDisposeCallback;
+
+        fn init(&self) -> Self::State {
+            Default::default()
+        }
+        fn events<TInbox: TaskInbox<TAction>>(
+            &self,
+            inbox: TInbox,
+        ) -> ActorEvents<TInbox, Self::Events<TInbox>, Self::Dispose> {
+            ActorEvents::Async(Box::pin(self.events(inbox)), None)
+        }
+
+        fn accept(&self, _action: &PluginHandlerResponseAction) -> bool {
+            true
+        }
+        fn schedule(
+            &self,
+            _action: &PluginHandlerResponseAction,
+            _state: &Self::State,
+        ) -> Option<SchedulerMode> {
+            Some(SchedulerMode::Async)
+        }
+        fn handle(
+            &self,
+            state: &mut Self::State,
+            action: &PluginHandlerResponseAction,
+            metadata: &MessageData,
+            context: &mut impl HandlerContext,
+        ) -> Option<SchedulerTransition<TAction, TTask>> {
+            self.handle_plugin_handler_response(state, action, metadata, context)
+        }
+    }
+});
+
+impl PluginHandlerTaskActor {
+    fn events<TInbox, TAction>(&self, _inbox: TInbox) -> impl Stream<Item = TInbox::Message>
+    where
+        TInbox: TaskInbox<TAction>,
+        TAction: Action + From<PluginHandlerResponseAction>,
+    {
+        let operation_id = self.operation_id;
+        let effect_type = self.effect_type.clone();
+        // Plugin calls block the current thread until the call has completed or timed out, so are invoked on a
+        // dedicated blocking thread to avoid stalling the scheduler
+        tokio::task::spawn_blocking({
+            let plugin = self.plugin.clone();
+            let effect_type = self.effect_type.clone();
+            let payload = self.payload.clone();
+            move || plugin.invoke(&effect_type, &payload)
+        })
+        .map(move |result| {
+            let result = result.unwrap_or_else(|err| Err(JsonValue::from(format!("{}", err))));
+            TAction::from(PluginHandlerResponseAction {
+                operation_id,
+                effect_type,
+                result,
+            })
+        })
+        .map(|action| TInbox::Message::from(action))
+        .into_stream()
+    }
+    fn handle_plugin_handler_response<TAction, TTask>(
+        &self,
+        _state: &mut PluginHandlerTaskActorState,
+        _action: &PluginHandlerResponseAction,
+        _metadata: &MessageData,
+        context: &mut impl HandlerContext,
+    ) -> Option<SchedulerTransition<TAction, TTask>>
+    where
+        TAction: Action + From<PluginHandlerResponseAction>,
+        TTask: TaskFactory<TAction, TTask>,
+    {
+        Some(SchedulerTransition::new([
+            SchedulerCommand::Kill(context.pid()),
+            SchedulerCommand::Forward(self.caller_pid),
+        ]))
+    }
+}
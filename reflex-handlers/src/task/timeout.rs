@@ -10,6 +10,7 @@ use reflex_dispatcher::{
     ProcessId, SchedulerCommand, SchedulerMode, SchedulerTransition, TaskFactory, TaskInbox,
 };
 use reflex_macros::{dispatcher, Named};
+use reflex_utils::clock::Clock;
 use serde::{Deserialize, Serialize};
 
 use crate::action::timeout::TimeoutHandlerTimeoutAction;
@@ -22,6 +23,8 @@ pub struct TimeoutHandlerTaskFactory {
     pub operation_id: Uuid,
     pub duration: Duration,
     pub caller_pid: ProcessId,
+    #[serde(skip)]
+    pub clock: Clock,
 }
 impl<TAction, TTask> TaskFactory<TAction, TTask> for TimeoutHandlerTaskFactory
 where
@@ -34,11 +37,13 @@ where
             operation_id,
             duration,
             caller_pid,
+            clock,
         } = self;
         TimeoutHandlerTaskActor {
             operation_id,
             duration,
             caller_pid,
+            clock,
         }
     }
 }
@@ -48,6 +53,7 @@ pub struct TimeoutHandlerTaskActor {
     operation_id: Uuid,
     duration: Duration,
     caller_pid: ProcessId,
+    clock: Clock,
 }
 
 #[derive(PartialEq, Eq, Clone, Copy, Default)]
@@ -109,11 +115,13 @@ impl TimeoutHandlerTaskActor {
     {
         let duration = self.duration;
         let operation_id = self.operation_id;
-        inbox
-            .sleep(duration)
-            .map(move |_| TAction::from(TimeoutHandlerTimeoutAction { operation_id }))
-            .map(|action| TInbox::Message::from(action))
-            .into_stream()
+        match &self.clock {
+            Clock::System => inbox.sleep(duration).left_future(),
+            Clock::Simulation(clock) => clock.sleep(duration).right_future(),
+        }
+        .map(move |_| TAction::from(TimeoutHandlerTimeoutAction { operation_id }))
+        .map(|action| TInbox::Message::from(action))
+        .into_stream()
     }
     fn handle_timeout_handler_timeout<TAction, TTask>(
         &self,
@@ -13,6 +13,7 @@ use reflex_dispatcher::{
     ProcessId, SchedulerCommand, SchedulerMode, SchedulerTransition, TaskFactory, TaskInbox,
 };
 use reflex_macros::{dispatcher, Named};
+use reflex_utils::clock::Clock;
 use serde::{Deserialize, Serialize};
 
 use crate::action::timestamp::TimestampHandlerUpdateAction;
@@ -25,6 +26,8 @@ pub struct TimestampHandlerTaskFactory {
     pub operation_id: Uuid,
     pub interval: Duration,
     pub caller_pid: ProcessId,
+    #[serde(skip)]
+    pub clock: Clock,
 }
 impl<TAction, TTask> TaskFactory<TAction, TTask> for TimestampHandlerTaskFactory
 where
@@ -37,11 +40,13 @@ where
             operation_id,
             interval,
             caller_pid,
+            clock,
         } = self;
         TimestampHandlerTaskActor {
             operation_id,
             interval,
             caller_pid,
+            clock,
         }
     }
 }
@@ -51,6 +56,7 @@ pub struct TimestampHandlerTaskActor {
     operation_id: Uuid,
     interval: Duration,
     caller_pid: ProcessId,
+    clock: Clock,
 }
 
 #[derive(PartialEq, Eq, Clone, Copy, Default)]
@@ -112,17 +118,37 @@ impl TimestampHandlerTaskActor {
     {
         let interval = self.interval;
         let operation_id = self.operation_id;
-        let now = Instant::now();
-        let first_update = now.checked_add(interval).unwrap_or(now);
-        inbox
-            .interval(first_update, interval)
-            .map(move |_| {
-                TAction::from(TimestampHandlerUpdateAction {
-                    operation_id,
-                    timestamp: SystemTime::now(),
+        match &self.clock {
+            Clock::System => {
+                let now = Instant::now();
+                let first_update = now.checked_add(interval).unwrap_or(now);
+                inbox
+                    .interval(first_update, interval)
+                    .map(|_| SystemTime::now())
+                    .left_stream()
+            }
+            // Simulated updates are emitted with the scheduled timestamp of each interval tick, ensuring that updates
+            // remain deterministic regardless of how far the clock is advanced at a time
+            Clock::Simulation(clock) => {
+                let clock = clock.clone();
+                let first_update = clock.now() + interval;
+                futures::stream::unfold(first_update, move |timestamp| {
+                    let sleep = clock.sleep_until(timestamp);
+                    async move {
+                        sleep.await;
+                        Some((timestamp, timestamp + interval))
+                    }
                 })
+                .right_stream()
+            }
+        }
+        .map(move |timestamp| {
+            TAction::from(TimestampHandlerUpdateAction {
+                operation_id,
+                timestamp,
             })
-            .map(|action| TInbox::Message::from(action))
+        })
+        .map(|action| TInbox::Message::from(action))
     }
     fn handle_update_action<TAction, TTask>(
         &self,
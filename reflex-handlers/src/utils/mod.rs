@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 pub mod fetch;
+pub mod plugin;
 pub mod serialize;
 pub mod timestamp;
 pub mod tls;
@@ -0,0 +1,457 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use reflex_json::JsonValue;
+use serde::Deserialize;
+use wasmtime::{Config, Engine, Instance, Memory, Module, Store, TypedFunc};
+
+/// Name of the plugin export used to allocate a buffer of the given size within the plugin's linear memory
+pub const PLUGIN_EXPORT_ALLOC: &str = "reflex_plugin_alloc";
+/// Name of the plugin export used to release a buffer of the given pointer and size within the plugin's linear memory
+pub const PLUGIN_EXPORT_FREE: &str = "reflex_plugin_free";
+/// Name of the plugin export that returns the plugin manifest as a packed `(pointer << 32) | length` JSON string
+pub const PLUGIN_EXPORT_MANIFEST: &str = "reflex_plugin_manifest";
+/// Name of the plugin export that handles an effect, given the effect type and the JSON-serialized effect payload,
+/// returning a packed `(pointer << 32) | length` JSON string of the form `{"value": ...}` or `{"error": ...}`
+pub const PLUGIN_EXPORT_HANDLE: &str = "reflex_plugin_handle";
+/// Name of the linear memory exported by the plugin
+pub const PLUGIN_EXPORT_MEMORY: &str = "memory";
+
+/// Maximum duration of a single plugin call before the plugin is interrupted
+pub const DEFAULT_PLUGIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Effect type definition declared within a plugin manifest
+#[derive(Clone, Debug, Deserialize)]
+pub struct EffectPluginDefinition {
+    #[serde(rename = "type")]
+    pub effect_type: String,
+}
+
+#[derive(Deserialize)]
+struct EffectPluginManifest {
+    effects: Vec<EffectPluginDefinition>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum EffectPluginResponse {
+    Value(JsonValue),
+    Error(JsonValue),
+}
+
+/// Effect handler implemented as a sandboxed WebAssembly module
+///
+/// Plugin modules must not declare any imports, and must export a linear memory along with the allocation, manifest
+/// and handler functions described by the `PLUGIN_EXPORT_*` constants. All values are exchanged as UTF-8 JSON strings
+/// within the plugin's linear memory. Strings returned by the plugin are released via the plugin's `free` export once
+/// they have been read.
+#[derive(Clone)]
+pub struct EffectPlugin {
+    name: String,
+    effects: Vec<EffectPluginDefinition>,
+    timeout: Duration,
+    instance: Arc<Mutex<EffectPluginInstance>>,
+}
+
+struct EffectPluginInstance {
+    store: Store<()>,
+    memory: Memory,
+    alloc: TypedFunc<u32, u32>,
+    free: TypedFunc<(u32, u32), ()>,
+    handle: TypedFunc<(u32, u32, u32, u32), u64>,
+}
+
+impl EffectPlugin {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)
+            .map_err(|err| format!("Unable to load plugin {}: {}", path.display(), err))?;
+        Self::from_bytes(format!("{}", path.display()), &bytes)
+    }
+    pub fn from_bytes(name: impl Into<String>, bytes: &[u8]) -> Result<Self, String> {
+        let name = name.into();
+        let mut config = Config::new();
+        // Allow long-running plugin calls to be interrupted once their deadline has elapsed
+        config.epoch_interruption(true);
+        let engine = Engine::new(&config)
+            .map_err(|err| format!("Unable to create plugin engine {name}: {err}"))?;
+        let module = Module::new(&engine, bytes)
+            .map_err(|err| format!("Invalid plugin module {name}: {err}"))?;
+        let mut store = Store::new(&engine, ());
+        let instance = call_with_deadline(&mut store, DEFAULT_PLUGIN_TIMEOUT, |store| {
+            Instance::new(store, &module, &[])
+        })
+        .map_err(|err| format!("Unable to instantiate plugin {name}: {err}"))?;
+        let memory = instance
+            .get_memory(&mut store, PLUGIN_EXPORT_MEMORY)
+            .ok_or_else(|| format!("Plugin {name} does not export \"{PLUGIN_EXPORT_MEMORY}\""))?;
+        let alloc = instance
+            .get_typed_func::<u32, u32>(&mut store, PLUGIN_EXPORT_ALLOC)
+            .map_err(|err| {
+                format!("Invalid plugin export {name} \"{PLUGIN_EXPORT_ALLOC}\": {err}")
+            })?;
+        let free = instance
+            .get_typed_func::<(u32, u32), ()>(&mut store, PLUGIN_EXPORT_FREE)
+            .map_err(|err| {
+                format!("Invalid plugin export {name} \"{PLUGIN_EXPORT_FREE}\": {err}")
+            })?;
+        let manifest = instance
+            .get_typed_func::<(), u64>(&mut store, PLUGIN_EXPORT_MANIFEST)
+            .map_err(|err| {
+                format!("Invalid plugin export {name} \"{PLUGIN_EXPORT_MANIFEST}\": {err}")
+            })?;
+        let handle = instance
+            .get_typed_func::<(u32, u32, u32, u32), u64>(&mut store, PLUGIN_EXPORT_HANDLE)
+            .map_err(|err| {
+                format!("Invalid plugin export {name} \"{PLUGIN_EXPORT_HANDLE}\": {err}")
+            })?;
+        let mut instance = EffectPluginInstance {
+            store,
+            memory,
+            alloc,
+            free,
+            handle,
+        };
+        let manifest = call_with_deadline(&mut instance.store, DEFAULT_PLUGIN_TIMEOUT, |store| {
+            manifest.call(store, ())
+        })
+        .map_err(|err| format!("{err}"))
+        .and_then(|result| instance.take_string(result))
+        .map_err(|err| format!("Unable to load plugin manifest {name}: {err}"))
+        .and_then(|manifest| {
+            serde_json::from_str::<EffectPluginManifest>(&manifest)
+                .map_err(|err| format!("Invalid plugin manifest {name}: {err}"))
+        })?;
+        Ok(Self {
+            name,
+            effects: manifest.effects,
+            timeout: DEFAULT_PLUGIN_TIMEOUT,
+            instance: Arc::new(Mutex::new(instance)),
+        })
+    }
+    /// Interrupt any plugin call that has not completed within the given duration, including time spent waiting for
+    /// preceding calls to the same plugin to complete
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        Self { timeout, ..self }
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn effects(&self) -> &[EffectPluginDefinition] {
+        &self.effects
+    }
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+    pub fn handles_effect_type(&self, effect_type: &str) -> bool {
+        self.effects
+            .iter()
+            .any(|definition| definition.effect_type == effect_type)
+    }
+    /// Invoke the plugin handler for the given effect, returning either the resolved value or the error payload
+    ///
+    /// This blocks the current thread until the plugin call has completed or timed out, so should not be invoked from
+    /// within an actor thread.
+    pub fn invoke(&self, effect_type: &str, payload: &JsonValue) -> Result<JsonValue, JsonValue> {
+        let deadline = Instant::now() + self.timeout;
+        let mut instance = self
+            .instance
+            .lock()
+            .map_err(|_| JsonValue::from(format!("Plugin {} is unavailable", self.name)))?;
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        if timeout.is_zero() {
+            return Err(JsonValue::from(self.timeout_message(effect_type)));
+        }
+        instance
+            .handle(effect_type, &payload.to_string(), timeout)
+            .map_err(|err| match err {
+                EffectPluginCallError::Timeout => self.timeout_message(effect_type),
+                EffectPluginCallError::Error(err) => {
+                    format!("Plugin {} failed to handle {effect_type}: {err}", self.name)
+                }
+            })
+            .and_then(|response| {
+                serde_json::from_str::<EffectPluginResponse>(&response)
+                    .map_err(|err| format!("Invalid plugin {} response: {err}", self.name))
+            })
+            .map_err(JsonValue::from)
+            .and_then(|response| match response {
+                EffectPluginResponse::Value(value) => Ok(value),
+                EffectPluginResponse::Error(payload) => Err(payload),
+            })
+    }
+    fn timeout_message(&self, effect_type: &str) -> String {
+        format!(
+            "Plugin {} timed out after {}ms while handling {effect_type}",
+            self.name,
+            self.timeout.as_millis()
+        )
+    }
+}
+
+enum EffectPluginCallError {
+    Timeout,
+    Error(String),
+}
+impl std::fmt::Display for EffectPluginCallError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Timeout => write!(f, "Timed out"),
+            Self::Error(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl EffectPluginInstance {
+    fn handle(
+        &mut self,
+        effect_type: &str,
+        payload: &str,
+        timeout: Duration,
+    ) -> Result<String, EffectPluginCallError> {
+        let (effect_type_pointer, effect_type_length) = self
+            .write_string(effect_type)
+            .map_err(EffectPluginCallError::Error)?;
+        let result = self
+            .write_string(payload)
+            .map_err(EffectPluginCallError::Error)
+            .and_then(|(payload_pointer, payload_length)| {
+                let EffectPluginInstance { store, handle, .. } = &mut *self;
+                let result = call_with_deadline(store, timeout, |store| {
+                    handle.call(
+                        store,
+                        (
+                            effect_type_pointer,
+                            effect_type_length,
+                            payload_pointer,
+                            payload_length,
+                        ),
+                    )
+                });
+                // Release the input buffers regardless of whether the plugin call succeeded
+                let freed = self
+                    .free_buffer(payload_pointer, payload_length)
+                    .map_err(EffectPluginCallError::Error);
+                result.and_then(|result| freed.map(|_| result))
+            });
+        let freed = self
+            .free_buffer(effect_type_pointer, effect_type_length)
+            .map_err(EffectPluginCallError::Error);
+        let result = result.and_then(|result| freed.map(|_| result))?;
+        self.take_string(result)
+            .map_err(EffectPluginCallError::Error)
+    }
+    fn write_string(&mut self, value: &str) -> Result<(u32, u32), String> {
+        let length = value.len() as u32;
+        let pointer = self
+            .alloc
+            .call(&mut self.store, length)
+            .map_err(|err| format!("{err}"))?;
+        self.memory
+            .write(&mut self.store, pointer as usize, value.as_bytes())
+            .map_err(|err| format!("{err}"))?;
+        Ok((pointer, length))
+    }
+    /// Read a packed string that was allocated by the plugin, releasing the underlying buffer once it has been read
+    fn take_string(&mut self, packed: u64) -> Result<String, String> {
+        let pointer = (packed >> 32) as u32;
+        let length = (packed & 0xFFFFFFFF) as u32;
+        let value = self.read_string(pointer, length);
+        let freed = self.free_buffer(pointer, length);
+        value.and_then(|value| freed.map(|_| value))
+    }
+    fn read_string(&self, pointer: u32, length: u32) -> Result<String, String> {
+        let (pointer, length) = (pointer as usize, length as usize);
+        let bytes = self
+            .memory
+            .data(&self.store)
+            .get(pointer..(pointer + length))
+            .ok_or_else(|| String::from("Plugin returned out-of-bounds string"))?;
+        String::from_utf8(bytes.into()).map_err(|err| format!("{err}"))
+    }
+    fn free_buffer(&mut self, pointer: u32, length: u32) -> Result<(), String> {
+        self.free
+            .call(&mut self.store, (pointer, length))
+            .map_err(|err| format!("{err}"))
+    }
+}
+
+/// Invoke the given plugin call, trapping the plugin if the call has not completed within the given duration
+fn call_with_deadline<V>(
+    store: &mut Store<()>,
+    timeout: Duration,
+    call: impl FnOnce(&mut Store<()>) -> wasmtime::Result<V>,
+) -> Result<V, EffectPluginCallError> {
+    // Each call is granted a single epoch tick, which is only ever triggered by the deadline timer for this call
+    store.set_epoch_deadline(1);
+    let expired = Arc::new(AtomicBool::new(false));
+    let (cancel, cancelled) = mpsc::channel::<()>();
+    std::thread::spawn({
+        let engine = store.engine().clone();
+        let expired = expired.clone();
+        move || {
+            if let Err(RecvTimeoutError::Timeout) = cancelled.recv_timeout(timeout) {
+                expired.store(true, Ordering::SeqCst);
+                engine.increment_epoch();
+            }
+        }
+    });
+    let result = call(store);
+    // Dropping the cancellation channel stops the deadline timer if it has not already fired
+    drop(cancel);
+    result.map_err(|err| {
+        if expired.load(Ordering::SeqCst) {
+            EffectPluginCallError::Timeout
+        } else {
+            EffectPluginCallError::Error(format!("{err}"))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE_PLUGIN: &str = r#"
+        (module
+          (memory (export "memory") 1)
+          (global $next (mut i32) (i32.const 1024))
+          (global $live (mut i32) (i32.const 0))
+          (data (i32.const 0) "{\"effects\":[{\"type\":\"test::echo\"},{\"type\":\"test::trap\"},{\"type\":\"test::loop\"},{\"type\":\"test::live\"}]}")
+          (data (i32.const 256) "{\"value\":")
+          (data (i32.const 272) "{\"value\":true}")
+          (data (i32.const 288) "{\"value\":false}")
+          (func $alloc (export "reflex_plugin_alloc") (param $length i32) (result i32)
+            (local $pointer i32)
+            (local.set $pointer (global.get $next))
+            (global.set $next (i32.add (global.get $next) (local.get $length)))
+            (global.set $live (i32.add (global.get $live) (local.get $length)))
+            (local.get $pointer))
+          (func (export "reflex_plugin_free") (param $pointer i32) (param $length i32)
+            (global.set $live (i32.sub (global.get $live) (local.get $length))))
+          (func $pack (param $pointer i32) (param $length i32) (result i64)
+            (i64.or
+              (i64.shl (i64.extend_i32_u (local.get $pointer)) (i64.const 32))
+              (i64.extend_i32_u (local.get $length))))
+          (func $copy (param $source i32) (param $length i32) (result i64)
+            (local $pointer i32)
+            (memory.copy
+              (local.tee $pointer (call $alloc (local.get $length)))
+              (local.get $source)
+              (local.get $length))
+            (call $pack (local.get $pointer) (local.get $length)))
+          (func (export "reflex_plugin_manifest") (result i64)
+            (call $copy (i32.const 0) (i32.const 101)))
+          (func (export "reflex_plugin_handle")
+            (param $type i32) (param $type_length i32) (param $payload i32) (param $payload_length i32)
+            (result i64)
+            (local $selector i32)
+            (local $pointer i32)
+            ;; Dispatch on the second character of the effect type name
+            (local.set $selector (i32.load8_u offset=7 (local.get $type)))
+            ;; test::trap
+            (if (i32.eq (local.get $selector) (i32.const 114))
+              (then (unreachable)))
+            ;; test::loop
+            (if (i32.eq (local.get $selector) (i32.const 111))
+              (then (loop $spin (br $spin))))
+            ;; test::live: determine whether all buffers other than the current arguments have been freed
+            (if (i32.eq (local.get $selector) (i32.const 105))
+              (then
+                (return
+                  (if (result i64)
+                    (i32.eq
+                      (global.get $live)
+                      (i32.add (local.get $type_length) (local.get $payload_length)))
+                    (then (call $copy (i32.const 272) (i32.const 14)))
+                    (else (call $copy (i32.const 288) (i32.const 15)))))))
+            ;; test::echo
+            (local.set $pointer (call $alloc (i32.add (local.get $payload_length) (i32.const 10))))
+            (memory.copy (local.get $pointer) (i32.const 256) (i32.const 9))
+            (memory.copy
+              (i32.add (local.get $pointer) (i32.const 9))
+              (local.get $payload)
+              (local.get $payload_length))
+            (i32.store8
+              (i32.add (local.get $pointer) (i32.add (local.get $payload_length) (i32.const 9)))
+              (i32.const 125))
+            (call $pack (local.get $pointer) (i32.add (local.get $payload_length) (i32.const 10)))))
+    "#;
+
+    fn load_fixture_plugin() -> EffectPlugin {
+        EffectPlugin::from_bytes("fixture", FIXTURE_PLUGIN.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn plugin_manifest() {
+        let plugin = load_fixture_plugin();
+        assert!(plugin.handles_effect_type("test::echo"));
+        assert!(plugin.handles_effect_type("test::trap"));
+        assert!(!plugin.handles_effect_type("test::unknown"));
+        assert_eq!(plugin.timeout(), DEFAULT_PLUGIN_TIMEOUT);
+    }
+
+    #[test]
+    fn plugin_success() {
+        let plugin = load_fixture_plugin();
+        let payload = serde_json::json!(["foo", 3, { "bar": true }]);
+        assert_eq!(plugin.invoke("test::echo", &payload), Ok(payload.clone()));
+        assert_eq!(plugin.invoke("test::echo", &payload), Ok(payload));
+        assert_eq!(
+            plugin.invoke("test::live", &JsonValue::Null),
+            Ok(JsonValue::Bool(true)),
+        );
+    }
+
+    #[test]
+    fn plugin_trap() {
+        let plugin = load_fixture_plugin();
+        let result = plugin.invoke("test::trap", &JsonValue::Null);
+        assert!(matches!(
+            result,
+            Err(JsonValue::String(message)) if message.starts_with("Plugin fixture failed to handle test::trap:"),
+        ));
+        // The plugin remains usable after a trap, and the buffers allocated for the failed call are released
+        assert_eq!(
+            plugin.invoke("test::echo", &JsonValue::from(3)),
+            Ok(JsonValue::from(3)),
+        );
+        assert_eq!(
+            plugin.invoke("test::live", &JsonValue::Null),
+            Ok(JsonValue::Bool(true)),
+        );
+    }
+
+    #[test]
+    fn plugin_timeout() {
+        let plugin = load_fixture_plugin().with_timeout(Duration::from_millis(50));
+        let start = Instant::now();
+        let result = plugin.invoke("test::loop", &JsonValue::Null);
+        assert!(start.elapsed() < Duration::from_secs(10));
+        assert_eq!(
+            result,
+            Err(JsonValue::from(
+                "Plugin fixture timed out after 50ms while handling test::loop"
+            )),
+        );
+        // Subsequent calls are granted a fresh deadline
+        assert_eq!(
+            plugin.invoke("test::echo", &JsonValue::from("foo")),
+            Ok(JsonValue::from("foo")),
+        );
+        assert_eq!(
+            plugin.invoke("test::live", &JsonValue::Null),
+            Ok(JsonValue::Bool(true)),
+        );
+    }
+}
@@ -14,8 +14,9 @@ use std::{
 use reflex::{
     cache::SubstitutionCache,
     core::{
-        Applicable, Expression, ExpressionFactory, HeapAllocator, InstructionPointer, IntValue,
-        Reducible, Rewritable, StackOffset, SymbolId, TimestampValue, Uuid,
+        Applicable, DurationValue, Expression, ExpressionFactory, HeapAllocator,
+        InstructionPointer, IntValue, Reducible, Rewritable, StackOffset, SymbolId, TimestampValue,
+        TimezoneOffset, Uuid,
     },
     hash::{hash_object, FnvHashMap, FnvHasher, HashId, IntMap},
 };
@@ -146,6 +147,16 @@ pub enum Instruction {
     },
     PushTimestamp {
         millis: TimestampValue,
+        offset: TimezoneOffset,
+    },
+    PushDuration {
+        millis: DurationValue,
+    },
+    PushBytes {
+        value: Vec<u8>,
+    },
+    PushRegex {
+        pattern: String,
     },
     PushFunction {
         target: InstructionPointer,
@@ -190,6 +201,7 @@ pub enum Instruction {
         num_args: usize,
     },
     ConstructLazyResult,
+    ConstructSensitive,
     ConstructConstructor {
         num_fields: usize,
     },
@@ -0,0 +1,70 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use reflex::{
+    core::{EvaluationResult, Expression, InstructionPointer},
+    hash::HashId,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::interpreter::stack::CallStackSnapshot;
+
+/// Shared flag used to request that an in-flight evaluation be suspended
+///
+/// Evaluations started via [`execute_resumable`](crate::execute_resumable) check the flag between instructions, and
+/// return a [`Continuation`] as soon as the flag has been set.
+#[derive(Clone, Default, Debug)]
+pub struct SuspendSignal(Arc<AtomicBool>);
+impl SuspendSignal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn suspend(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+    pub fn reset(&self) {
+        self.0.store(false, Ordering::Relaxed);
+    }
+    pub fn is_suspended(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Outcome of a resumable evaluation
+#[derive(Debug)]
+pub enum ExecutionOutcome<T: Expression> {
+    Complete(EvaluationResult<T>),
+    Suspended(Continuation<T>),
+}
+
+/// Serializable snapshot of a suspended evaluation, containing the interpreter operand stack and call stack along with
+/// all the heap values reachable from them
+///
+/// A continuation can only be resumed against the same compiled program that produced it, and the same state that
+/// was active when evaluation was suspended.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))]
+pub struct Continuation<T: Expression> {
+    pub(crate) program_hash: HashId,
+    pub(crate) cache_key: HashId,
+    pub(crate) entry_point: InstructionPointer,
+    pub(crate) state_id: usize,
+    pub(crate) variable_stack: Vec<T>,
+    pub(crate) call_stack: CallStackSnapshot<T>,
+}
+impl<T: Expression> Continuation<T> {
+    pub fn cache_key(&self) -> HashId {
+        self.cache_key
+    }
+    pub fn entry_point(&self) -> InstructionPointer {
+        self.entry_point
+    }
+    pub fn state_id(&self) -> usize {
+        self.state_id
+    }
+}
@@ -0,0 +1,114 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use reflex::core::{Builtin, Uuid};
+
+/// Per-builtin invocation metrics collected over the course of one or more interpreter evaluations
+#[derive(Default)]
+pub struct BuiltinInstrumentation {
+    entries: Mutex<HashMap<Uuid, BuiltinCallMetrics>>,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct BuiltinCallMetrics {
+    /// Display name of the builtin
+    pub name: String,
+    /// Number of times the builtin was applied
+    pub num_calls: usize,
+    /// Cumulative time spent within the builtin implementation
+    pub total_duration: Duration,
+}
+
+impl BuiltinCallMetrics {
+    pub fn average_duration(&self) -> Duration {
+        match u32::try_from(self.num_calls) {
+            Ok(0) => Duration::ZERO,
+            Ok(num_calls) => self.total_duration / num_calls,
+            Err(_) => {
+                Duration::from_secs_f64(self.total_duration.as_secs_f64() / (self.num_calls as f64))
+            }
+        }
+    }
+}
+
+impl BuiltinInstrumentation {
+    /// Invoke the given builtin implementation, recording the invocation against the builtin
+    pub(crate) fn measure<V>(&self, builtin: &impl Builtin, f: impl FnOnce() -> V) -> V {
+        let start_time = Instant::now();
+        let result = f();
+        let duration = start_time.elapsed();
+        self.record(builtin, duration);
+        result
+    }
+    fn record(&self, builtin: &impl Builtin, duration: Duration) {
+        let mut entries = match self.entries.lock() {
+            Ok(entries) => entries,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let entry = entries
+            .entry(builtin.uid())
+            .or_insert_with(|| BuiltinCallMetrics {
+                name: format!("{}", builtin),
+                num_calls: 0,
+                total_duration: Duration::ZERO,
+            });
+        entry.num_calls += 1;
+        entry.total_duration += duration;
+    }
+    /// Retrieve the metrics collected so far, ordered by cumulative time spent within each builtin (descending)
+    pub fn metrics(&self) -> Vec<BuiltinCallMetrics> {
+        let entries = match self.entries.lock() {
+            Ok(entries) => entries,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let mut metrics = entries.values().cloned().collect::<Vec<_>>();
+        metrics.sort_by(|a, b| {
+            b.total_duration
+                .cmp(&a.total_duration)
+                .then_with(|| b.num_calls.cmp(&a.num_calls))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        metrics
+    }
+    /// Discard all metrics collected so far
+    pub fn reset(&self) {
+        match self.entries.lock() {
+            Ok(mut entries) => entries.clear(),
+            Err(poisoned) => poisoned.into_inner().clear(),
+        }
+    }
+}
+
+impl std::fmt::Display for BuiltinInstrumentation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let metrics = self.metrics();
+        let name_width = metrics
+            .iter()
+            .map(|entry| entry.name.len())
+            .max()
+            .unwrap_or(0)
+            .max("Builtin".len());
+        writeln!(
+            f,
+            "{:<name_width$} {:>10} {:>14} {:>14}",
+            "Builtin", "Calls", "Total (µs)", "Average (µs)"
+        )?;
+        for entry in metrics.iter() {
+            writeln!(
+                f,
+                "{:<name_width$} {:>10} {:>14} {:>14}",
+                entry.name,
+                entry.num_calls,
+                entry.total_duration.as_micros(),
+                entry.average_duration().as_micros(),
+            )?;
+        }
+        Ok(())
+    }
+}
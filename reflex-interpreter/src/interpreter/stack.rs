@@ -6,9 +6,13 @@ use std::collections::HashSet;
 use tracing::trace_span;
 
 use reflex::{
-    core::{Arity, DependencyList, Expression, InstructionPointer, StackOffset, StateToken},
+    core::{
+        Arity, DependencyList, Expression, ExpressionListType, HeapAllocator, InstructionPointer,
+        RefType, StackOffset, StateToken,
+    },
     hash::HashId,
 };
+use serde::{Deserialize, Serialize};
 
 use crate::compiler::{Instruction, Program};
 
@@ -417,6 +421,171 @@ impl<'src, T: Expression> CallStack<'src, T> {
     }
 }
 
+/// Serializable copy of the contents of a call stack, allowing the call stack to be reconstructed at a later point
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))]
+pub(crate) struct CallStackSnapshot<T: Expression> {
+    program_counter: InstructionPointer,
+    state_dependencies: DependencyList,
+    subexpressions: Vec<HashId>,
+    entries: Vec<StackEntrySnapshot<T>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))]
+struct StackEntrySnapshot<T: Expression> {
+    hash: Option<HashId>,
+    context: StackFrameSnapshot<T>,
+    parent_state_dependencies: DependencyList,
+    parent_subexpressions: Vec<HashId>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))]
+enum StackFrameSnapshot<T: Expression> {
+    Expression {
+        address: InstructionPointer,
+    },
+    ApplicationTarget {
+        args: Vec<T>,
+    },
+    ApplicationArg,
+    // The function arity is not serializable, so is recomputed from the target when the snapshot is restored
+    ApplicationArgList {
+        target: T,
+        caller_address: InstructionPointer,
+        resume_address: InstructionPointer,
+        args: Vec<T>,
+        results: Vec<T>,
+    },
+    Function {
+        target_address: InstructionPointer,
+        caller_address: InstructionPointer,
+        resume_address: InstructionPointer,
+    },
+}
+
+impl<'src, T: Expression> CallStack<'src, T> {
+    pub(crate) fn snapshot(&self) -> CallStackSnapshot<T> {
+        CallStackSnapshot {
+            program_counter: self.program_counter,
+            state_dependencies: self.state_dependencies.clone(),
+            subexpressions: self.subexpressions.clone(),
+            entries: self
+                .call_stack
+                .iter()
+                .map(|entry| StackEntrySnapshot {
+                    hash: entry.hash,
+                    context: match &entry.context {
+                        StackFrame::Expression { address } => {
+                            StackFrameSnapshot::Expression { address: *address }
+                        }
+                        StackFrame::ApplicationTarget { args } => {
+                            StackFrameSnapshot::ApplicationTarget {
+                                args: args.iter().map(|arg| arg.as_deref().clone()).collect(),
+                            }
+                        }
+                        StackFrame::ApplicationArg => StackFrameSnapshot::ApplicationArg,
+                        StackFrame::ApplicationArgList {
+                            target,
+                            arity: _,
+                            caller_address,
+                            resume_address,
+                            args,
+                            results,
+                        } => StackFrameSnapshot::ApplicationArgList {
+                            target: target.clone(),
+                            caller_address: *caller_address,
+                            resume_address: *resume_address,
+                            args: args.clone(),
+                            results: results.clone(),
+                        },
+                        StackFrame::Function {
+                            target_address,
+                            caller_address,
+                            resume_address,
+                        } => StackFrameSnapshot::Function {
+                            target_address: *target_address,
+                            caller_address: *caller_address,
+                            resume_address: *resume_address,
+                        },
+                    },
+                    parent_state_dependencies: entry.parent_state_dependencies.clone(),
+                    parent_subexpressions: entry.parent_subexpressions.clone(),
+                })
+                .collect(),
+        }
+    }
+    pub(crate) fn restore(
+        program: &'src Program,
+        snapshot: CallStackSnapshot<T>,
+        call_stack_size: Option<usize>,
+        get_arity: impl Fn(&T) -> Result<Arity, String>,
+        allocator: &impl HeapAllocator<T>,
+    ) -> Result<Self, String> {
+        let CallStackSnapshot {
+            program_counter,
+            state_dependencies,
+            subexpressions,
+            entries,
+        } = snapshot;
+        let mut call_stack = create_stack(call_stack_size);
+        for entry in entries {
+            let StackEntrySnapshot {
+                hash,
+                context,
+                parent_state_dependencies,
+                parent_subexpressions,
+            } = entry;
+            let context = match context {
+                StackFrameSnapshot::Expression { address } => StackFrame::Expression { address },
+                StackFrameSnapshot::ApplicationTarget { args } => StackFrame::ApplicationTarget {
+                    args: allocator.create_list(args),
+                },
+                StackFrameSnapshot::ApplicationArg => StackFrame::ApplicationArg,
+                StackFrameSnapshot::ApplicationArgList {
+                    target,
+                    caller_address,
+                    resume_address,
+                    args,
+                    results,
+                } => StackFrame::ApplicationArgList {
+                    arity: get_arity(&target)?,
+                    target,
+                    caller_address,
+                    resume_address,
+                    args,
+                    results,
+                },
+                StackFrameSnapshot::Function {
+                    target_address,
+                    caller_address,
+                    resume_address,
+                } => StackFrame::Function {
+                    target_address,
+                    caller_address,
+                    resume_address,
+                },
+            };
+            call_stack.push(StackEntry {
+                hash,
+                context,
+                parent_state_dependencies,
+                parent_subexpressions,
+                span: hash.map(|hash| trace_span!("stack_frame_enter", hash).entered()),
+            });
+        }
+        Ok(Self {
+            call_stack_size,
+            program,
+            program_counter,
+            state_dependencies,
+            subexpressions,
+            call_stack,
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct VariableStack<T: Expression> {
     variable_stack: Vec<T>,
@@ -475,6 +644,14 @@ impl<T: Expression> VariableStack<T> {
     pub fn slice(&self, count: usize) -> &[T] {
         &self.variable_stack[(self.variable_stack.len() - count)..]
     }
+    pub(crate) fn restore(values: Vec<T>, variable_stack_size: Option<usize>) -> Self {
+        let mut variable_stack = create_stack(variable_stack_size);
+        variable_stack.extend(values);
+        Self {
+            variable_stack,
+            variable_stack_size,
+        }
+    }
     pub fn clone_shallow(&self, depth: StackOffset) -> Self {
         Self {
             variable_stack: self.variable_stack[self.variable_stack.len() - depth..]
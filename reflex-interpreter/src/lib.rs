@@ -14,6 +14,8 @@ pub use interpreter::cache::{
     DefaultInterpreterCache, GcMetrics, InterpreterCache, InterpreterCacheEntry, LocalCacheEntries,
     MutableInterpreterCache,
 };
+pub use interpreter::continuation::{Continuation, ExecutionOutcome, SuspendSignal};
+pub use interpreter::instrumentation::{BuiltinCallMetrics, BuiltinInstrumentation};
 pub use interpreter::stack::{CallStack, VariableStack};
 
 use reflex::core::{
@@ -26,19 +28,21 @@ use reflex::{
     cache::NoopCache,
     core::{
         get_combined_short_circuit_signal, get_num_short_circuit_signals, get_short_circuit_signal,
-        validate_function_application_arity, Applicable, ArgType, Arity, DependencyList,
-        DynamicState, EvaluationResult, Expression, ExpressionFactory, ExpressionListType,
-        HeapAllocator, Reducible, Rewritable,
+        validate_function_application_arity, Applicable, ArgType, Arity, BuiltinTermType,
+        DependencyList, DynamicState, EvaluationResult, Expression, ExpressionFactory,
+        ExpressionListType, HeapAllocator, Reducible, Rewritable,
     },
     hash::HashId,
 };
 use serde::{Deserialize, Serialize};
 
-use crate::compiler::{CompiledProgram, Instruction, Program};
+use crate::compiler::{hash_compiled_program, CompiledProgram, Instruction, Program};
 
 pub mod compiler;
 mod interpreter {
     pub(crate) mod cache;
+    pub(crate) mod continuation;
+    pub(crate) mod instrumentation;
     pub(crate) mod stack;
 }
 pub(crate) mod term;
@@ -101,6 +105,7 @@ fn evaluate_data_section<'a, T: Expression + Rewritable<T> + Reducible<T> + Appl
     factory: &impl ExpressionFactory<T>,
     allocator: &impl HeapAllocator<T>,
     options: &InterpreterOptions,
+    instrumentation: Option<&BuiltinInstrumentation>,
 ) -> Result<Vec<T>, String> {
     value_factories
         .into_iter()
@@ -113,6 +118,7 @@ fn evaluate_data_section<'a, T: Expression + Rewritable<T> + Reducible<T> + Appl
                 factory,
                 allocator,
                 options,
+                instrumentation,
             )?;
             static_data.push(value);
             Ok(static_data)
@@ -127,6 +133,7 @@ fn evaluate_static_program<T: Expression + Rewritable<T> + Reducible<T> + Applic
     factory: &impl ExpressionFactory<T>,
     allocator: &impl HeapAllocator<T>,
     options: &InterpreterOptions,
+    instrumentation: Option<&BuiltinInstrumentation>,
 ) -> Result<T, String> {
     let mut stack = VariableStack::new(options.variable_stack_size);
     let mut call_stack = CallStack::new(program, entry_point, options.call_stack_size);
@@ -142,6 +149,7 @@ fn evaluate_static_program<T: Expression + Rewritable<T> + Reducible<T> + Applic
         &mut DefaultInterpreterCache::default(),
         &mut MultithreadedCacheEntries::default(),
         options,
+        instrumentation,
     )
 }
 
@@ -156,6 +164,166 @@ pub fn execute<T: Expression + Rewritable<T> + Reducible<T> + Applicable<T>>(
     options: &InterpreterOptions,
     cache: &impl InterpreterCache<T>,
 ) -> Result<(EvaluationResult<T>, LocalCacheEntries<T>), String> {
+    execute_program(
+        cache_key,
+        program,
+        entry_point,
+        state_id,
+        state,
+        factory,
+        allocator,
+        options,
+        cache,
+        None,
+    )
+}
+
+/// Execute the given program, recording the number of invocations and cumulative time spent within each builtin
+///
+/// Metrics are accumulated into the provided instrumentation table, which can be inspected once evaluation is complete
+/// (the same table can be reused across multiple evaluations to aggregate metrics across all of them).
+pub fn execute_instrumented<T: Expression + Rewritable<T> + Reducible<T> + Applicable<T>>(
+    cache_key: HashId,
+    program: &CompiledProgram,
+    entry_point: InstructionPointer,
+    state_id: usize,
+    state: &impl DynamicState<T>,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+    options: &InterpreterOptions,
+    cache: &impl InterpreterCache<T>,
+    instrumentation: &BuiltinInstrumentation,
+) -> Result<(EvaluationResult<T>, LocalCacheEntries<T>), String> {
+    execute_program(
+        cache_key,
+        program,
+        entry_point,
+        state_id,
+        state,
+        factory,
+        allocator,
+        options,
+        cache,
+        Some(instrumentation),
+    )
+}
+
+/// Execute the given program, suspending evaluation at the next instruction boundary once the provided signal has been
+/// triggered
+///
+/// Suspended evaluations return a serializable [`Continuation`] which can later be resumed via [`resume`] (e.g. in a
+/// new process after a deployment). Any cache entries that were generated before the evaluation was suspended are
+/// returned alongside the continuation.
+pub fn execute_resumable<T: Expression + Rewritable<T> + Reducible<T> + Applicable<T>>(
+    cache_key: HashId,
+    program: &CompiledProgram,
+    entry_point: InstructionPointer,
+    state_id: usize,
+    state: &impl DynamicState<T>,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+    options: &InterpreterOptions,
+    cache: &impl InterpreterCache<T>,
+    signal: &SuspendSignal,
+) -> Result<(ExecutionOutcome<T>, LocalCacheEntries<T>), String> {
+    run_program(
+        cache_key,
+        program,
+        entry_point,
+        state_id,
+        state,
+        factory,
+        allocator,
+        options,
+        cache,
+        None,
+        None,
+        Some(signal),
+    )
+}
+
+/// Resume a previously-suspended evaluation
+///
+/// The continuation must be resumed against the same compiled program that produced it, and the provided state must
+/// match the state that was active when evaluation was suspended.
+pub fn resume<T: Expression + Rewritable<T> + Reducible<T> + Applicable<T>>(
+    continuation: Continuation<T>,
+    program: &CompiledProgram,
+    state: &impl DynamicState<T>,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+    options: &InterpreterOptions,
+    cache: &impl InterpreterCache<T>,
+    signal: &SuspendSignal,
+) -> Result<(ExecutionOutcome<T>, LocalCacheEntries<T>), String> {
+    if hash_compiled_program(program, &continuation.entry_point) != continuation.program_hash {
+        return Err(String::from(
+            "Unable to resume evaluation: continuation was created by a different program",
+        ));
+    }
+    run_program(
+        continuation.cache_key,
+        program,
+        continuation.entry_point,
+        continuation.state_id,
+        state,
+        factory,
+        allocator,
+        options,
+        cache,
+        Some(continuation),
+        None,
+        Some(signal),
+    )
+}
+
+fn execute_program<T: Expression + Rewritable<T> + Reducible<T> + Applicable<T>>(
+    cache_key: HashId,
+    program: &CompiledProgram,
+    entry_point: InstructionPointer,
+    state_id: usize,
+    state: &impl DynamicState<T>,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+    options: &InterpreterOptions,
+    cache: &impl InterpreterCache<T>,
+    instrumentation: Option<&BuiltinInstrumentation>,
+) -> Result<(EvaluationResult<T>, LocalCacheEntries<T>), String> {
+    match run_program(
+        cache_key,
+        program,
+        entry_point,
+        state_id,
+        state,
+        factory,
+        allocator,
+        options,
+        cache,
+        None,
+        instrumentation,
+        None,
+    )? {
+        (ExecutionOutcome::Complete(result), cache_entries) => Ok((result, cache_entries)),
+        (ExecutionOutcome::Suspended(_), _) => {
+            Err(String::from("Non-resumable evaluation was suspended"))
+        }
+    }
+}
+
+fn run_program<T: Expression + Rewritable<T> + Reducible<T> + Applicable<T>>(
+    cache_key: HashId,
+    program: &CompiledProgram,
+    entry_point: InstructionPointer,
+    state_id: usize,
+    state: &impl DynamicState<T>,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+    options: &InterpreterOptions,
+    cache: &impl InterpreterCache<T>,
+    continuation: Option<Continuation<T>>,
+    instrumentation: Option<&BuiltinInstrumentation>,
+    signal: Option<&SuspendSignal>,
+) -> Result<(ExecutionOutcome<T>, LocalCacheEntries<T>), String> {
     let CompiledProgram {
         instructions,
         data_section,
@@ -172,24 +340,41 @@ pub fn execute<T: Expression + Rewritable<T> + Reducible<T> + Applicable<T>>(
         factory,
         allocator,
         options,
+        instrumentation,
     )?;
 
     std::mem::drop(execution_span);
 
-    match instructions.get(entry_point) {
-        Some(&Instruction::Function { required_args, .. }) if required_args == 0 => Ok(()),
-        Some(instruction) => Err(format!(
-            "Invalid entry point function: {:x} {:?}",
-            entry_point, instruction
-        )),
-        _ => Err(format!("Invalid entry point address: {:x}", entry_point)),
-    }?;
+    let (mut stack, mut call_stack) = match continuation {
+        None => {
+            match instructions.get(entry_point) {
+                Some(&Instruction::Function { required_args, .. }) if required_args == 0 => Ok(()),
+                Some(instruction) => Err(format!(
+                    "Invalid entry point function: {:x} {:?}",
+                    entry_point, instruction
+                )),
+                _ => Err(format!("Invalid entry point address: {:x}", entry_point)),
+            }?;
+            (
+                VariableStack::new(options.variable_stack_size),
+                CallStack::new(instructions, entry_point, options.call_stack_size),
+            )
+        }
+        Some(continuation) => (
+            VariableStack::restore(continuation.variable_stack, options.variable_stack_size),
+            CallStack::restore(
+                instructions,
+                continuation.call_stack,
+                options.call_stack_size,
+                get_function_arity,
+                allocator,
+            )?,
+        ),
+    };
     let execution_span = info_span!("interpreter::execute");
     let execution_span = execution_span.enter();
-    let mut stack = VariableStack::new(options.variable_stack_size);
-    let mut call_stack = CallStack::new(instructions, entry_point, options.call_stack_size);
     let mut evaluation_cache_entries = MultithreadedCacheEntries::new(state);
-    let result = evaluate_program_loop(
+    let result = evaluate_suspendable_program_loop(
         state_id,
         state,
         &mut stack,
@@ -201,13 +386,26 @@ pub fn execute<T: Expression + Rewritable<T> + Reducible<T> + Applicable<T>>(
         cache,
         &mut evaluation_cache_entries,
         options,
+        instrumentation,
+        signal,
     );
 
     std::mem::drop(execution_span);
     let mut cache_entries = evaluation_cache_entries.into_entries();
     match result {
         Err(error) => Err(error),
-        Ok(value) => {
+        Ok(None) => {
+            let continuation = Continuation {
+                program_hash: hash_compiled_program(program, &entry_point),
+                cache_key,
+                entry_point,
+                state_id,
+                variable_stack: stack.values().to_vec(),
+                call_stack: call_stack.snapshot(),
+            };
+            Ok((ExecutionOutcome::Suspended(continuation), cache_entries))
+        }
+        Ok(Some(value)) => {
             // FIXME: Unwind call frame / variable stack on short-circuit signals
             // debug_assert!(
             //     call_stack.call_stack_depth() == 0,
@@ -228,7 +426,7 @@ pub fn execute<T: Expression + Rewritable<T> + Reducible<T> + Applicable<T>>(
                 state,
                 subexpressions,
             ));
-            Ok((result, cache_entries))
+            Ok((ExecutionOutcome::Complete(result), cache_entries))
         }
     }
 }
@@ -245,8 +443,54 @@ fn evaluate_program_loop<'a, T: Expression + Rewritable<T> + Reducible<T> + Appl
     cache: &impl InterpreterCache<T>,
     cache_entries: &mut MultithreadedCacheEntries<'a, T>,
     options: &InterpreterOptions,
+    instrumentation: Option<&BuiltinInstrumentation>,
 ) -> Result<T, String> {
+    evaluate_suspendable_program_loop(
+        state_id,
+        state,
+        stack,
+        call_stack,
+        static_data,
+        function_lookup,
+        factory,
+        allocator,
+        cache,
+        cache_entries,
+        options,
+        instrumentation,
+        None,
+    )
+    .and_then(|result| result.ok_or_else(|| String::from("Evaluation was suspended")))
+}
+
+/// Run the main interpreter loop, returning `None` if evaluation was suspended before a result could be determined
+///
+/// The suspend signal is only checked between instructions, after at least one instruction has been evaluated (this
+/// ensures that progress is made even if an evaluation is repeatedly suspended and resumed).
+fn evaluate_suspendable_program_loop<
+    'a,
+    T: Expression + Rewritable<T> + Reducible<T> + Applicable<T>,
+>(
+    state_id: usize,
+    state: &impl DynamicState<T>,
+    stack: &mut VariableStack<T>,
+    call_stack: &mut CallStack<T>,
+    static_data: &[T],
+    function_lookup: Option<&Program>,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+    cache: &impl InterpreterCache<T>,
+    cache_entries: &mut MultithreadedCacheEntries<'a, T>,
+    options: &InterpreterOptions,
+    instrumentation: Option<&BuiltinInstrumentation>,
+    signal: Option<&SuspendSignal>,
+) -> Result<Option<T>, String> {
+    let mut is_initial_instruction = true;
     loop {
+        if !is_initial_instruction && signal.map(SuspendSignal::is_suspended).unwrap_or(false) {
+            return Ok(None);
+        }
+        is_initial_instruction = false;
         let result = match call_stack.lookup_instruction(call_stack.program_counter()) {
             None => Err(format!(
                 "Invalid program instruction offset: {:x}",
@@ -270,6 +514,7 @@ fn evaluate_program_loop<'a, T: Expression + Rewritable<T> + Reducible<T> + Appl
                     allocator,
                     cache,
                     cache_entries,
+                    instrumentation,
                 )
             }
         };
@@ -359,7 +604,7 @@ fn evaluate_program_loop<'a, T: Expression + Rewritable<T> + Reducible<T> + Appl
                             {
                                 call_stack.add_state_dependencies(dependencies);
                                 call_stack.add_subexpressions(subexpressions);
-                                return Ok(stack.pop().unwrap());
+                                return Ok(Some(stack.pop().unwrap()));
                             } else if let Some((
                                 target,
                                 arity,
@@ -402,6 +647,7 @@ fn evaluate_program_loop<'a, T: Expression + Rewritable<T> + Reducible<T> + Appl
                                         results.into_iter(),
                                         factory,
                                         allocator,
+                                        instrumentation,
                                     )?;
                                     stack.push(result);
                                     resume_address
@@ -461,7 +707,13 @@ fn evaluate_program_loop<'a, T: Expression + Rewritable<T> + Reducible<T> + Appl
                                 let is_resuming_arg_evaluation = resume_address == caller_address
                                     && call_stack.peek_application_arg_list_stack().is_some();
                                 if is_resuming_arg_evaluation {
-                                    process_next_list_item(stack, call_stack, factory, allocator)?;
+                                    process_next_list_item(
+                                        stack,
+                                        call_stack,
+                                        factory,
+                                        allocator,
+                                        instrumentation,
+                                    )?;
                                     continue;
                                 } else {
                                     call_stack.update_program_counter(resume_address);
@@ -526,7 +778,13 @@ fn evaluate_program_loop<'a, T: Expression + Rewritable<T> + Reducible<T> + Appl
                             let is_resuming_arg_evaluation = resume_address == caller_address
                                 && call_stack.peek_application_arg_list_stack().is_some();
                             if is_resuming_arg_evaluation {
-                                process_next_list_item(stack, call_stack, factory, allocator)?;
+                                process_next_list_item(
+                                    stack,
+                                    call_stack,
+                                    factory,
+                                    allocator,
+                                    instrumentation,
+                                )?;
                                 continue;
                             }
 
@@ -534,7 +792,7 @@ fn evaluate_program_loop<'a, T: Expression + Rewritable<T> + Reducible<T> + Appl
                             continue;
                         }
                         None => match stack.pop() {
-                            Some(value) => return Ok(value),
+                            Some(value) => return Ok(Some(value)),
                             None => return Err(String::from("Program did not return a result")),
                         },
                     },
@@ -555,6 +813,7 @@ fn evaluate_instruction<'a, T: Expression + Rewritable<T> + Reducible<T> + Appli
     allocator: &impl HeapAllocator<T>,
     cache: &impl InterpreterCache<T>,
     cache_entries: &mut MultithreadedCacheEntries<'a, T>,
+    instrumentation: Option<&BuiltinInstrumentation>,
 ) -> Result<(ExecutionResult<T>, DependencyList), String> {
     match instruction {
         Instruction::LoadStaticData { offset } => {
@@ -609,9 +868,24 @@ fn evaluate_instruction<'a, T: Expression + Rewritable<T> + Reducible<T> + Appli
             stack.push(factory.create_symbol_term(*value));
             Ok((ExecutionResult::Advance, DependencyList::empty()))
         }
-        Instruction::PushTimestamp { millis } => {
+        Instruction::PushTimestamp { millis, offset } => {
             trace!(instruction = "Instruction::PushTimestamp");
-            stack.push(factory.create_timestamp_term(*millis));
+            stack.push(factory.create_timestamp_term(*millis, *offset));
+            Ok((ExecutionResult::Advance, DependencyList::empty()))
+        }
+        Instruction::PushDuration { millis } => {
+            trace!(instruction = "Instruction::PushDuration");
+            stack.push(factory.create_duration_term(*millis));
+            Ok((ExecutionResult::Advance, DependencyList::empty()))
+        }
+        Instruction::PushBytes { value } => {
+            trace!(instruction = "Instruction::PushBytes");
+            stack.push(factory.create_bytes_term(value));
+            Ok((ExecutionResult::Advance, DependencyList::empty()))
+        }
+        Instruction::PushRegex { pattern } => {
+            trace!(instruction = "Instruction::PushRegex");
+            stack.push(factory.create_regex_term(allocator.create_string(pattern.as_str())));
             Ok((ExecutionResult::Advance, DependencyList::empty()))
         }
         Instruction::PushFunction { target, hash } => {
@@ -826,7 +1100,13 @@ fn evaluate_instruction<'a, T: Expression + Rewritable<T> + Reducible<T> + Appli
                         Ok((ExecutionResult::Advance, DependencyList::empty()))
                     } else {
                         let args = stack.pop_multiple(num_args);
-                        let result = apply_function(&target, args.into_iter(), factory, allocator)?;
+                        let result = apply_function(
+                            &target,
+                            args.into_iter(),
+                            factory,
+                            allocator,
+                            instrumentation,
+                        )?;
                         stack.push(result);
                         Ok((ExecutionResult::Advance, DependencyList::empty()))
                     }
@@ -919,6 +1199,18 @@ fn evaluate_instruction<'a, T: Expression + Rewritable<T> + Reducible<T> + Appli
                 }
             }
         }
+        Instruction::ConstructSensitive => {
+            trace!(instruction = "Instruction::ConstructSensitive");
+            match stack.pop() {
+                Some(value) => {
+                    stack.push(factory.create_sensitive_term(value));
+                    Ok((ExecutionResult::Advance, DependencyList::empty()))
+                }
+                None => Err(String::from(
+                    "Unable to create sensitive value: insufficient arguments on stack",
+                )),
+            }
+        }
         Instruction::ConstructConstructor { num_fields } => {
             trace!(instruction = "Instruction::ConstructConstructor");
             let num_fields = *num_fields;
@@ -1163,6 +1455,7 @@ fn evaluate_expression<T: Expression + Applicable<T>>(
                             WithExactSizeIterator::new(num_combined_args, combined_args),
                             factory,
                             allocator,
+                            instrumentation,
                         );
                         let result = result?;
                         stack.push(result);
@@ -1236,6 +1529,7 @@ fn apply_function<T: Expression + Applicable<T>>(
     args: impl ExactSizeIterator<Item = T>,
     factory: &impl ExpressionFactory<T>,
     allocator: &impl HeapAllocator<T>,
+    instrumentation: Option<&BuiltinInstrumentation>,
 ) -> Result<T, String> {
     let arity = get_function_arity(target)?;
     let num_args = validate_function_application_arity(target, &arity, args.len())?;
@@ -1249,7 +1543,16 @@ fn apply_function<T: Expression + Applicable<T>>(
             .chain((0..num_unspecified_optional_args).map(|_| factory.create_nil_term())),
     );
     // TODO: distinguish between type errors vs runtime errors, and wrap runtime errors as signals
-    target.apply(args, factory, allocator, &mut NoopCache::default())
+    match instrumentation.and_then(|instrumentation| {
+        factory
+            .match_builtin_term(target)
+            .map(|term| (instrumentation, term.target()))
+    }) {
+        Some((instrumentation, builtin)) => instrumentation.measure(&builtin, || {
+            target.apply(args, factory, allocator, &mut NoopCache::default())
+        }),
+        None => target.apply(args, factory, allocator, &mut NoopCache::default()),
+    }
 }
 
 fn process_next_list_item<T: Expression + Applicable<T>>(
@@ -1257,6 +1560,7 @@ fn process_next_list_item<T: Expression + Applicable<T>>(
     call_stack: &mut CallStack<T>,
     factory: &impl ExpressionFactory<T>,
     allocator: &impl HeapAllocator<T>,
+    instrumentation: Option<&BuiltinInstrumentation>,
 ) -> Result<(), String> {
     let (
         target,
@@ -1306,7 +1610,13 @@ fn process_next_list_item<T: Expression + Applicable<T>>(
             stack.push(signal);
             call_stack.update_program_counter(resume_address);
         } else {
-            let result = apply_function(&target, results.into_iter(), factory, allocator)?;
+            let result = apply_function(
+                &target,
+                results.into_iter(),
+                factory,
+                allocator,
+                instrumentation,
+            )?;
             stack.push(result);
             call_stack.update_program_counter(resume_address);
         }
@@ -1529,6 +1839,7 @@ fn resolve_parallel_args<
     cache: &(impl InterpreterCache<T> + Sync),
     cache_entries: &mut MultithreadedCacheEntries<'a, T>,
     options: &InterpreterOptions,
+    instrumentation: Option<&BuiltinInstrumentation>,
 ) -> Result<Vec<T>, String>
 where
     T::ExpressionList: Sync,
@@ -1557,6 +1868,7 @@ where
                 cache,
                 cache_entries,
                 options,
+                instrumentation,
             );
         } else {
             return Ok(args);
@@ -1610,6 +1922,7 @@ where
                             cache,
                             &mut local_cache_entries,
                             options,
+                            instrumentation,
                         );
                         (
                             result,
@@ -1681,6 +1994,7 @@ fn resolve_arg_within_new_stack<
     cache: &impl InterpreterCache<T>,
     cache_entries: &mut MultithreadedCacheEntries<'a, T>,
     options: &InterpreterOptions,
+    instrumentation: Option<&BuiltinInstrumentation>,
 ) -> (Result<T, String>, DependencyList, Vec<HashId>) {
     let capture_depth = arg.capture_depth();
     let mut new_stack = stack.clone_shallow(capture_depth);
@@ -1704,6 +2018,7 @@ fn resolve_arg_within_new_stack<
         cache,
         cache_entries,
         options,
+        instrumentation,
     );
     // FIXME: Unwind call frame / variable stack on short-circuit signals
     // debug_assert_eq!(
@@ -1724,7 +2039,10 @@ fn resolve_arg_within_new_stack<
 
 #[cfg(test)]
 mod tests {
-    use reflex::core::{DependencyList, SignalType, StateCache, Uid};
+    use reflex::core::{
+        create_checked_float_term, create_checked_int_term, create_error_expression,
+        DependencyList, FloatValue, IntValue, NumericSemantics, SignalType, StateCache, Uid,
+    };
     use reflex_lang::{allocator::DefaultAllocator, term::*, SharedTermFactory};
     use reflex_lisp::{parse, LispBuiltins};
     use reflex_stdlib::{Add, And, CollectList, If, Stdlib};
@@ -1844,6 +2162,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn instrumented_builtins() {
+        let factory = SharedTermFactory::<LispBuiltins>::default();
+        let allocator = DefaultAllocator::default();
+        let mut cache = DefaultInterpreterCache::default();
+        let expression = parse("(+ (+ 1 2) 3)", &factory, &allocator).unwrap();
+        let compiler = Compiler::new(CompilerOptions::unoptimized(), None);
+        let program = compiler
+            .compile(&expression, CompilerMode::Function, &factory, &allocator)
+            .unwrap();
+        let state = StateCache::default();
+        let state_id = 0;
+        let entry_point = InstructionPointer::default();
+        let cache_key = hash_compiled_program(&program, &entry_point);
+        let instrumentation = BuiltinInstrumentation::default();
+        let (result, _) = execute_instrumented(
+            cache_key,
+            &program,
+            entry_point,
+            state_id,
+            &state,
+            &factory,
+            &allocator,
+            &InterpreterOptions::default(),
+            &mut cache,
+            &instrumentation,
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            EvaluationResult::new(factory.create_int_term(1 + 2 + 3), DependencyList::empty()),
+        );
+        let metrics = instrumentation.metrics();
+        assert_eq!(metrics.len(), 1);
+        assert_eq!(
+            metrics[0].name,
+            format!("{}", LispBuiltins::from(Stdlib::Add))
+        );
+        assert_eq!(metrics[0].num_calls, 2);
+        instrumentation.reset();
+        assert!(instrumentation.metrics().is_empty());
+    }
+
     #[test]
     fn compiled_tail_calls() {
         let factory = SharedTermFactory::<LispBuiltins>::default();
@@ -2200,6 +2561,294 @@ mod tests {
         );
     }
 
+    #[test]
+    fn numeric_semantics() {
+        use NumericSemantics::{Error, Saturate, Wrap};
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let int = |value: IntValue| factory.create_int_term(value);
+        let float = |value: FloatValue| factory.create_float_term(value);
+        let int_error = create_checked_int_term(None, &factory, &allocator);
+        let float_error = create_checked_float_term(None, &factory, &allocator);
+        for (numeric_semantics, builtin, (left, right), expected) in [
+            (
+                Wrap,
+                Stdlib::Add,
+                (int(IntValue::MAX), int(1)),
+                int(IntValue::MIN),
+            ),
+            (
+                Saturate,
+                Stdlib::Add,
+                (int(IntValue::MAX), int(1)),
+                int(IntValue::MAX),
+            ),
+            (
+                Error,
+                Stdlib::Add,
+                (int(IntValue::MAX), int(1)),
+                int_error.clone(),
+            ),
+            (Error, Stdlib::Add, (int(3), int(4)), int(7)),
+            (
+                Wrap,
+                Stdlib::Subtract,
+                (int(IntValue::MIN), int(1)),
+                int(IntValue::MAX),
+            ),
+            (
+                Saturate,
+                Stdlib::Subtract,
+                (int(IntValue::MIN), int(1)),
+                int(IntValue::MIN),
+            ),
+            (
+                Error,
+                Stdlib::Subtract,
+                (int(IntValue::MIN), int(1)),
+                int_error.clone(),
+            ),
+            (
+                Wrap,
+                Stdlib::Multiply,
+                (int(IntValue::MAX), int(2)),
+                int(-2),
+            ),
+            (
+                Saturate,
+                Stdlib::Multiply,
+                (int(IntValue::MIN), int(2)),
+                int(IntValue::MIN),
+            ),
+            (
+                Error,
+                Stdlib::Multiply,
+                (int(IntValue::MAX), int(2)),
+                int_error.clone(),
+            ),
+            (
+                Wrap,
+                Stdlib::Divide,
+                (int(IntValue::MIN), int(-1)),
+                int(IntValue::MIN),
+            ),
+            (
+                Saturate,
+                Stdlib::Divide,
+                (int(IntValue::MIN), int(-1)),
+                int(IntValue::MAX),
+            ),
+            (
+                Error,
+                Stdlib::Divide,
+                (int(IntValue::MIN), int(-1)),
+                int_error.clone(),
+            ),
+            (
+                Wrap,
+                Stdlib::Add,
+                (float(FloatValue::MAX), float(FloatValue::MAX)),
+                float(FloatValue::INFINITY),
+            ),
+            (
+                Saturate,
+                Stdlib::Add,
+                (float(FloatValue::MAX), float(FloatValue::MAX)),
+                float(FloatValue::MAX),
+            ),
+            (
+                Error,
+                Stdlib::Add,
+                (float(FloatValue::MAX), float(FloatValue::MAX)),
+                float_error.clone(),
+            ),
+            (Error, Stdlib::Add, (float(1.5), float(2.0)), float(3.5)),
+            (
+                Wrap,
+                Stdlib::Remainder,
+                (int(IntValue::MIN), int(-1)),
+                int(0),
+            ),
+            (
+                Saturate,
+                Stdlib::Remainder,
+                (int(IntValue::MIN), int(-1)),
+                int(0),
+            ),
+            (
+                Error,
+                Stdlib::Remainder,
+                (int(IntValue::MIN), int(-1)),
+                int(0),
+            ),
+            (
+                Saturate,
+                Stdlib::Remainder,
+                (float(FloatValue::INFINITY), float(2.0)),
+                float_error.clone(),
+            ),
+            (
+                Error,
+                Stdlib::Remainder,
+                (float(FloatValue::INFINITY), float(2.0)),
+                float_error.clone(),
+            ),
+            (Error, Stdlib::Remainder, (float(5.5), int(2)), float(1.5)),
+            (Wrap, Stdlib::Pow, (int(2), int(63)), int(IntValue::MIN)),
+            (Saturate, Stdlib::Pow, (int(2), int(63)), int(IntValue::MAX)),
+            (
+                Saturate,
+                Stdlib::Pow,
+                (int(-2), int(63)),
+                int(IntValue::MIN),
+            ),
+            (Error, Stdlib::Pow, (int(2), int(63)), int_error.clone()),
+            (Error, Stdlib::Pow, (int(3), int(4)), int(81)),
+            (
+                Error,
+                Stdlib::Pow,
+                (int(2), int(4294967296)),
+                int_error.clone(),
+            ),
+            (
+                Saturate,
+                Stdlib::Pow,
+                (int(-2), int(4294967297)),
+                int(IntValue::MIN),
+            ),
+            (Error, Stdlib::Pow, (int(-1), int(4294967297)), int(-1)),
+            (Error, Stdlib::Pow, (int(2), int(-2)), float(0.25)),
+            (Error, Stdlib::Pow, (int(2), int(-4294967295)), float(0.0)),
+            (Error, Stdlib::Pow, (int(-1), int(-4294967295)), float(-1.0)),
+            (
+                Error,
+                Stdlib::Pow,
+                (float(2.0), int(4294967297)),
+                float_error.clone(),
+            ),
+            (
+                Wrap,
+                Stdlib::Pow,
+                (float(10.0), float(400.0)),
+                float(FloatValue::INFINITY),
+            ),
+            (
+                Saturate,
+                Stdlib::Pow,
+                (float(10.0), float(400.0)),
+                float(FloatValue::MAX),
+            ),
+            (
+                Error,
+                Stdlib::Pow,
+                (float(10.0), float(400.0)),
+                float_error.clone(),
+            ),
+        ] {
+            let factory = factory.with_numeric_semantics(numeric_semantics);
+            let mut cache = DefaultInterpreterCache::default();
+            let expression = factory.create_application_term(
+                factory.create_builtin_term(builtin),
+                allocator.create_pair(left, right),
+            );
+            let program = Compiler::new(CompilerOptions::unoptimized(), None)
+                .compile(&expression, CompilerMode::Function, &factory, &allocator)
+                .unwrap();
+            let state = StateCache::default();
+            let entry_point = InstructionPointer::default();
+            let cache_key = hash_compiled_program(&program, &entry_point);
+            let state_id = 0;
+            let (result, _) = execute(
+                cache_key,
+                &program,
+                InstructionPointer::default(),
+                state_id,
+                &state,
+                &factory,
+                &allocator,
+                &InterpreterOptions::default(),
+                &mut cache,
+            )
+            .unwrap();
+            assert_eq!(
+                result,
+                EvaluationResult::new(expected, DependencyList::empty()),
+            );
+        }
+    }
+
+    #[test]
+    fn constructor_validation() {
+        let factory = SharedTermFactory::<Stdlib>::default().with_constructor_validation(true);
+        let allocator = DefaultAllocator::default();
+        let prototype = allocator.create_struct_prototype(allocator.create_pair(
+            factory.create_string_term(allocator.create_static_string("foo")),
+            factory.create_string_term(allocator.create_static_string("bar")),
+        ));
+        for (args, expected) in [
+            (
+                allocator.create_pair(factory.create_int_term(3), factory.create_int_term(4)),
+                factory.create_record_term(
+                    prototype.clone(),
+                    allocator.create_pair(factory.create_int_term(3), factory.create_int_term(4)),
+                ),
+            ),
+            (
+                allocator.create_unit_list(factory.create_int_term(3)),
+                create_error_expression(
+                    factory.create_string_term(allocator.create_static_string(
+                        "Invalid constructor call: <constructor:{\"foo\",\"bar\"}>: Missing keys: \"bar\"",
+                    )),
+                    &factory,
+                    &allocator,
+                ),
+            ),
+            (
+                allocator.create_triple(
+                    factory.create_int_term(3),
+                    factory.create_int_term(4),
+                    factory.create_int_term(5),
+                ),
+                create_error_expression(
+                    factory.create_string_term(allocator.create_static_string(
+                        "Invalid constructor call: <constructor:{\"foo\",\"bar\"}>: Expected 2 arguments, received 3",
+                    )),
+                    &factory,
+                    &allocator,
+                ),
+            ),
+        ] {
+            let mut cache = DefaultInterpreterCache::default();
+            let expression = factory.create_application_term(
+                factory.create_constructor_term(prototype.clone()),
+                args,
+            );
+            let program = Compiler::new(CompilerOptions::unoptimized(), None)
+                .compile(&expression, CompilerMode::Function, &factory, &allocator)
+                .unwrap();
+            let state = StateCache::default();
+            let entry_point = InstructionPointer::default();
+            let cache_key = hash_compiled_program(&program, &entry_point);
+            let state_id = 0;
+            let (result, _) = execute(
+                cache_key,
+                &program,
+                InstructionPointer::default(),
+                state_id,
+                &state,
+                &factory,
+                &allocator,
+                &InterpreterOptions::default(),
+                &mut cache,
+            )
+            .unwrap();
+            assert_eq!(
+                result,
+                EvaluationResult::new(expected, DependencyList::empty()),
+            );
+        }
+    }
+
     #[test]
     fn chained_functions() {
         let factory = SharedTermFactory::<LispBuiltins>::default();
@@ -2409,4 +3058,107 @@ mod tests {
             EvaluationResult::new(factory.create_int_term(3), DependencyList::empty(),),
         );
     }
+
+    #[test]
+    fn resumable_evaluation() {
+        let factory = SharedTermFactory::<LispBuiltins>::default();
+        let allocator = DefaultAllocator::default();
+        let expression = parse(
+            "((lambda (foo bar) (+ (+ foo bar) (+ bar foo))) 3 4)",
+            &factory,
+            &allocator,
+        )
+        .unwrap();
+        let program = Compiler::new(CompilerOptions::unoptimized(), None)
+            .compile(&expression, CompilerMode::Function, &factory, &allocator)
+            .unwrap();
+
+        let entry_point = InstructionPointer::default();
+        let cache_key = hash_compiled_program(&program, &entry_point);
+        let state = StateCache::default();
+        let state_id = 0;
+        let options = InterpreterOptions::default();
+        let cache = DefaultInterpreterCache::default();
+        let signal = SuspendSignal::new();
+        signal.suspend();
+
+        let (mut outcome, _) = execute_resumable(
+            cache_key,
+            &program,
+            entry_point,
+            state_id,
+            &state,
+            &factory,
+            &allocator,
+            &options,
+            &cache,
+            &signal,
+        )
+        .unwrap();
+        let mut num_suspensions = 0;
+        let result = loop {
+            match outcome {
+                ExecutionOutcome::Complete(result) => break result,
+                ExecutionOutcome::Suspended(continuation) => {
+                    num_suspensions += 1;
+                    let serialized = serde_json::to_string(&continuation).unwrap();
+                    let continuation = serde_json::from_str(&serialized).unwrap();
+                    let (next_outcome, _) = resume(
+                        continuation,
+                        &program,
+                        &state,
+                        &factory,
+                        &allocator,
+                        &options,
+                        &cache,
+                        &signal,
+                    )
+                    .unwrap();
+                    outcome = next_outcome;
+                }
+            }
+        };
+        assert!(num_suspensions > 1);
+        assert_eq!(
+            result,
+            EvaluationResult::new(factory.create_int_term(14), DependencyList::empty()),
+        );
+
+        let (outcome, _) = execute_resumable(
+            cache_key,
+            &program,
+            entry_point,
+            state_id,
+            &state,
+            &factory,
+            &allocator,
+            &options,
+            &cache,
+            &signal,
+        )
+        .unwrap();
+        let continuation = match outcome {
+            ExecutionOutcome::Suspended(continuation) => continuation,
+            ExecutionOutcome::Complete(_) => panic!("Expected suspended evaluation"),
+        };
+        let other_program = Compiler::new(CompilerOptions::unoptimized(), None)
+            .compile(
+                &factory.create_int_term(3),
+                CompilerMode::Function,
+                &factory,
+                &allocator,
+            )
+            .unwrap();
+        assert!(resume(
+            continuation,
+            &other_program,
+            &state,
+            &factory,
+            &allocator,
+            &options,
+            &cache,
+            &signal,
+        )
+        .is_err());
+    }
 }
@@ -8,24 +8,25 @@ use reflex::{
     cache::NoopCache,
     core::{
         Applicable, ApplicationTermType, ArgType, Arity, BooleanTermType, Builtin, BuiltinTermType,
-        CompiledFunctionTermType, ConditionListType, ConditionType, ConstructorTermType,
-        EffectTermType, Expression, ExpressionFactory, ExpressionListType, FloatTermType,
-        GraphNode, HashmapTermType, HashsetTermType, HeapAllocator, InstructionPointer,
-        IntTermType, LambdaTermType, LazyResultTermType, LetTermType, ListTermType,
-        PartialApplicationTermType, RecordTermType, RecursiveTermType, Reducible, RefType,
-        Rewritable, SignalTermType, SignalType, StackOffset, StringTermType, StringValue,
-        StructPrototypeType, Substitutions, SymbolTermType, TimestampTermType, Uid,
-        VariableTermType,
+        BytesTermType, CompiledFunctionTermType, ConditionListType, ConditionType,
+        ConstructorTermType, DurationTermType, EffectTermType, Expression, ExpressionFactory,
+        ExpressionListType, FloatTermType, GraphNode, HashmapTermType, HashsetTermType,
+        HeapAllocator, InstructionPointer, IntTermType, LambdaTermType, LazyResultTermType,
+        LetTermType, ListTermType, PartialApplicationTermType, RecordTermType, RecursiveTermType,
+        Reducible, RefType, RegexTermType, Rewritable, SensitiveTermType, SignalTermType,
+        SignalType, StackOffset, StringTermType, StringValue, StructPrototypeType, Substitutions,
+        SymbolTermType, TimestampTermType, Uid, VariableTermType,
     },
     hash::{hash_object, HashId},
 };
 use reflex_lang::{
     expression::{CachedExpression, SharedExpression},
     term::{
-        ApplicationTerm, BooleanTerm, BuiltinTerm, CompiledFunctionTerm, ConstructorTerm,
-        EffectTerm, FloatTerm, HashMapTerm, HashSetTerm, IntTerm, LambdaTerm, LazyResultTerm,
-        LetTerm, ListTerm, NilTerm, PartialApplicationTerm, RecordTerm, RecursiveTerm, SignalTerm,
-        StringTerm, SymbolTerm, Term, TimestampTerm, VariableTerm,
+        ApplicationTerm, BooleanTerm, BuiltinTerm, BytesTerm, CompiledFunctionTerm,
+        ConstructorTerm, DurationTerm, EffectTerm, FloatTerm, HashMapTerm, HashSetTerm, IntTerm,
+        LambdaTerm, LazyResultTerm, LetTerm, ListTerm, NilTerm, PartialApplicationTerm, RecordTerm,
+        RecursiveTerm, RegexTerm, SensitiveTerm, SignalTerm, StringTerm, SymbolTerm, Term,
+        TimestampTerm, VariableTerm,
     },
     CachedSharedTerm,
 };
@@ -121,6 +122,10 @@ where
             Self::String(term) => term.should_intern(eager),
             Self::Symbol(term) => term.should_intern(eager),
             Self::Timestamp(term) => term.should_intern(eager),
+            Self::Duration(term) => term.should_intern(eager),
+            Self::Bytes(term) => term.should_intern(eager),
+            Self::Regex(term) => term.should_intern(eager),
+            Self::Sensitive(term) => term.should_intern(eager),
             Self::Variable(term) => term.should_intern(eager),
             Self::Effect(term) => term.should_intern(eager),
             Self::Let(term) => term.should_intern(eager),
@@ -164,6 +169,12 @@ where
             Self::Timestamp(term) => {
                 term.compile(eager, stack_offset, factory, allocator, compiler)
             }
+            Self::Duration(term) => term.compile(eager, stack_offset, factory, allocator, compiler),
+            Self::Bytes(term) => term.compile(eager, stack_offset, factory, allocator, compiler),
+            Self::Regex(term) => term.compile(eager, stack_offset, factory, allocator, compiler),
+            Self::Sensitive(term) => {
+                term.compile(eager, stack_offset, factory, allocator, compiler)
+            }
             Self::Variable(term) => term.compile(eager, stack_offset, factory, allocator, compiler),
             Self::Effect(term) => term.compile(eager, stack_offset, factory, allocator, compiler),
             Self::Let(term) => term.compile(eager, stack_offset, factory, allocator, compiler),
@@ -947,6 +958,7 @@ impl<T: Expression + Compile<T>> Compile<T> for TimestampTerm {
     ) -> Result<Program, String> {
         Ok(Program::new(once(Instruction::PushTimestamp {
             millis: self.millis(),
+            offset: self.offset(),
         })))
     }
 }
@@ -957,6 +969,94 @@ impl Internable for TimestampTerm {
     }
 }
 
+impl<T: Expression + Compile<T>> Compile<T> for DurationTerm {
+    fn compile(
+        &self,
+        _eager: Eagerness,
+        _stack_offset: StackOffset,
+        _factory: &impl ExpressionFactory<T>,
+        _allocator: &impl HeapAllocator<T>,
+        _compiler: &mut Compiler,
+    ) -> Result<Program, String> {
+        Ok(Program::new(once(Instruction::PushDuration {
+            millis: self.millis(),
+        })))
+    }
+}
+
+impl Internable for DurationTerm {
+    fn should_intern(&self, _eager: Eagerness) -> bool {
+        true
+    }
+}
+
+impl<T: Expression + Compile<T>> Compile<T> for BytesTerm {
+    fn compile(
+        &self,
+        _eager: Eagerness,
+        _stack_offset: StackOffset,
+        _factory: &impl ExpressionFactory<T>,
+        _allocator: &impl HeapAllocator<T>,
+        _compiler: &mut Compiler,
+    ) -> Result<Program, String> {
+        Ok(Program::new(once(Instruction::PushBytes {
+            value: Vec::from(self.value()),
+        })))
+    }
+}
+
+impl Internable for BytesTerm {
+    fn should_intern(&self, _eager: Eagerness) -> bool {
+        true
+    }
+}
+
+impl<T: Expression + Compile<T>> Compile<T> for RegexTerm<T> {
+    fn compile(
+        &self,
+        _eager: Eagerness,
+        _stack_offset: StackOffset,
+        _factory: &impl ExpressionFactory<T>,
+        _allocator: &impl HeapAllocator<T>,
+        _compiler: &mut Compiler,
+    ) -> Result<Program, String> {
+        let pattern = self.pattern();
+        let pattern = pattern.as_deref();
+        Ok(Program::new(once(Instruction::PushRegex {
+            pattern: Into::<String>::into(pattern.as_str()),
+        })))
+    }
+}
+
+impl<T: Expression> Internable for RegexTerm<T> {
+    fn should_intern(&self, _eager: Eagerness) -> bool {
+        true
+    }
+}
+
+impl<T: Expression + Compile<T>> Compile<T> for SensitiveTerm<T> {
+    fn compile(
+        &self,
+        eager: Eagerness,
+        stack_offset: StackOffset,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        compiler: &mut Compiler,
+    ) -> Result<Program, String> {
+        let value = self.value();
+        let value = value.as_deref();
+        let mut result = compiler.compile_term(value, eager, stack_offset, factory, allocator)?;
+        result.push(Instruction::ConstructSensitive);
+        Ok(result)
+    }
+}
+
+impl<T: Expression> Internable for SensitiveTerm<T> {
+    fn should_intern(&self, _eager: Eagerness) -> bool {
+        true
+    }
+}
+
 impl<T: Expression + Compile<T>> Compile<T> for VariableTerm {
     fn compile(
         &self,
@@ -82,6 +82,18 @@ impl Builtin for JsBuiltins {
             JsBuiltins::Js(term) => term.should_parallelize(args),
         }
     }
+    fn apply_static<T: Expression<Builtin = Self>>(
+        &self,
+        args: &[T],
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+    ) -> Option<T> {
+        match self {
+            JsBuiltins::Stdlib(term) => term.apply_static(args, factory, allocator),
+            JsBuiltins::Js(term) => term.apply_static(args, factory, allocator),
+            _ => None,
+        }
+    }
 }
 impl std::fmt::Display for JsBuiltins {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -172,11 +184,41 @@ impl From<stdlib::Contains> for JsBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::DateAdd> for JsBuiltins {
+    fn from(value: stdlib::DateAdd) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateDiff> for JsBuiltins {
+    fn from(value: stdlib::DateDiff) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateOffset> for JsBuiltins {
+    fn from(value: stdlib::DateOffset) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateTruncate> for JsBuiltins {
+    fn from(value: stdlib::DateTruncate) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateWithOffset> for JsBuiltins {
+    fn from(value: stdlib::DateWithOffset) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Divide> for JsBuiltins {
     fn from(value: stdlib::Divide) -> Self {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Duration> for JsBuiltins {
+    fn from(value: stdlib::Duration) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Effect> for JsBuiltins {
     fn from(value: stdlib::Effect) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -197,6 +239,11 @@ impl From<stdlib::Equal> for JsBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Eval> for JsBuiltins {
+    fn from(value: stdlib::Eval) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Filter> for JsBuiltins {
     fn from(value: stdlib::Filter) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -282,11 +329,26 @@ impl From<stdlib::Map> for JsBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Match> for JsBuiltins {
+    fn from(value: stdlib::Match) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Max> for JsBuiltins {
     fn from(value: stdlib::Max) -> Self {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Memo> for JsBuiltins {
+    fn from(value: stdlib::Memo) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::MemoStore> for JsBuiltins {
+    fn from(value: stdlib::MemoStore) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Merge> for JsBuiltins {
     fn from(value: stdlib::Merge) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -327,6 +389,11 @@ impl From<stdlib::PushFront> for JsBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Quote> for JsBuiltins {
+    fn from(value: stdlib::Quote) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Raise> for JsBuiltins {
     fn from(value: stdlib::Raise) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -377,6 +444,11 @@ impl From<stdlib::ResolveRecord> for JsBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Reveal> for JsBuiltins {
+    fn from(value: stdlib::Reveal) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Round> for JsBuiltins {
     fn from(value: stdlib::Round) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -392,6 +464,21 @@ impl From<stdlib::Slice> for JsBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Sort> for JsBuiltins {
+    fn from(value: stdlib::Sort) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::SortBy> for JsBuiltins {
+    fn from(value: stdlib::SortBy) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::SortMerge> for JsBuiltins {
+    fn from(value: stdlib::SortMerge) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Split> for JsBuiltins {
     fn from(value: stdlib::Split) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -407,6 +494,11 @@ impl From<stdlib::Subtract> for JsBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Template> for JsBuiltins {
+    fn from(value: stdlib::Template) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Unzip> for JsBuiltins {
     fn from(value: stdlib::Unzip) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -433,6 +525,11 @@ impl From<reflex_json::stdlib::JsonSerialize> for JsBuiltins {
         Self::from(reflex_json::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_json::stdlib::JsonSerializeCanonical> for JsBuiltins {
+    fn from(value: reflex_json::stdlib::JsonSerializeCanonical) -> Self {
+        Self::from(reflex_json::stdlib::Stdlib::from(value))
+    }
+}
 
 impl From<reflex_js::stdlib::Accessor> for JsBuiltins {
     fn from(value: reflex_js::stdlib::Accessor) -> Self {
@@ -479,6 +576,11 @@ impl From<reflex_js::stdlib::LogArgs> for JsBuiltins {
         Self::from(reflex_js::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_js::stdlib::LogScope> for JsBuiltins {
+    fn from(value: reflex_js::stdlib::LogScope) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_js::stdlib::ParseFloat> for JsBuiltins {
     fn from(value: reflex_js::stdlib::ParseFloat) -> Self {
         Self::from(reflex_js::stdlib::Stdlib::from(value))
@@ -489,6 +591,16 @@ impl From<reflex_js::stdlib::ParseInt> for JsBuiltins {
         Self::from(reflex_js::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_js::stdlib::ScopedLog> for JsBuiltins {
+    fn from(value: reflex_js::stdlib::ScopedLog) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_js::stdlib::ScopedLogArgs> for JsBuiltins {
+    fn from(value: reflex_js::stdlib::ScopedLogArgs) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_js::stdlib::Throw> for JsBuiltins {
     fn from(value: reflex_js::stdlib::Throw) -> Self {
         Self::from(reflex_js::stdlib::Stdlib::from(value))
@@ -0,0 +1,97 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::sync::{Arc, Mutex};
+
+use reflex::core::{Expression, ExpressionFactory, HeapAllocator, IntTermType, IntValue};
+use reflex_stdlib::{Effect, If};
+
+pub use crate::source::SourceLocation;
+
+pub const COVERAGE_EFFECT_TYPE: &str = "reflex::coverage";
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum CoverageProbeKind {
+    Function,
+    Branch,
+}
+
+impl std::fmt::Display for CoverageProbeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Function => write!(f, "function"),
+            Self::Branch => write!(f, "branch"),
+        }
+    }
+}
+
+/// Instrumented source location whose evaluation is reported via a coverage effect
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct CoverageProbe {
+    pub id: usize,
+    pub kind: CoverageProbeKind,
+    pub location: SourceLocation,
+}
+
+/// Shared registry of coverage probes inserted into the parsed program.
+///
+/// When enabled for a parser environment, every function body and conditional branch is wrapped in a conditional
+/// expression whose condition is a [`COVERAGE_EFFECT_TYPE`] effect with the probe ID as its payload. The host is
+/// responsible for resolving these effects to `true` (recording the probe as covered), after which evaluation
+/// continues as normal.
+#[derive(Clone, Debug, Default)]
+pub struct CoverageInstrumentation {
+    state: Arc<Mutex<CoverageInstrumentationState>>,
+}
+
+#[derive(Debug, Default)]
+struct CoverageInstrumentationState {
+    probes: Vec<CoverageProbe>,
+}
+
+impl CoverageInstrumentation {
+    /// Retrieve all the probes that have been registered so far
+    pub fn probes(&self) -> Vec<CoverageProbe> {
+        self.state.lock().unwrap().probes.clone()
+    }
+    /// Extract the probe ID from the payload of a coverage effect
+    pub fn parse_effect_payload<T: Expression>(
+        payload: &T,
+        factory: &impl ExpressionFactory<T>,
+    ) -> Option<usize> {
+        let value = factory.match_int_term(payload)?.value();
+        usize::try_from(value).ok()
+    }
+    pub(crate) fn instrument<T: Expression>(
+        &self,
+        kind: CoverageProbeKind,
+        location: SourceLocation,
+        expression: T,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+    ) -> T
+    where
+        T::Builtin: From<Effect> + From<If>,
+    {
+        let id = {
+            let mut state = self.state.lock().unwrap();
+            let id = state.probes.len();
+            state.probes.push(CoverageProbe { id, kind, location });
+            id
+        };
+        let probe = factory.create_application_term(
+            factory.create_builtin_term(Effect),
+            allocator.create_triple(
+                factory.create_string_term(allocator.create_static_string(COVERAGE_EFFECT_TYPE)),
+                factory.create_int_term(id as IntValue),
+                factory.create_nil_term(),
+            ),
+        );
+        // Both branches are identical: the conditional is only used to block on the probe effect
+        let body = factory.create_lambda_term(0, expression);
+        factory.create_application_term(
+            factory.create_builtin_term(If),
+            allocator.create_triple(probe, body.clone(), body),
+        )
+    }
+}
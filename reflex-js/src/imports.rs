@@ -29,11 +29,18 @@ pub trait JsImportsBuiltin:
     + From<CollectSignal>
     + From<CollectString>
     + From<Contains>
+    + From<DateAdd>
+    + From<DateDiff>
+    + From<DateOffset>
+    + From<DateTruncate>
+    + From<DateWithOffset>
     + From<Divide>
+    + From<Duration>
     + From<Effect>
     + From<EndsWith>
     + From<Eq>
     + From<Equal>
+    + From<Eval>
     + From<Filter>
     + From<Flatten>
     + From<Floor>
@@ -50,10 +57,13 @@ pub trait JsImportsBuiltin:
     + From<Keys>
     + From<Length>
     + From<Log>
+    + From<LogScope>
     + From<Lt>
     + From<Lte>
     + From<Map>
+    + From<Match>
     + From<Max>
+    + From<Memo>
     + From<Merge>
     + From<Min>
     + From<Multiply>
@@ -62,6 +72,7 @@ pub trait JsImportsBuiltin:
     + From<Pow>
     + From<Push>
     + From<PushFront>
+    + From<Quote>
     + From<Raise>
     + From<Remainder>
     + From<Replace>
@@ -71,12 +82,16 @@ pub trait JsImportsBuiltin:
     + From<ResolveHashSet>
     + From<ResolveList>
     + From<ResolveRecord>
+    + From<Reveal>
     + From<Round>
     + From<Sequence>
     + From<Slice>
+    + From<Sort>
+    + From<SortBy>
     + From<Split>
     + From<StartsWith>
     + From<Subtract>
+    + From<Template>
     + From<Unzip>
     + From<Values>
     + From<Zip>
@@ -98,11 +113,18 @@ impl<T> JsImportsBuiltin for T where
         + From<CollectSignal>
         + From<CollectString>
         + From<Contains>
+        + From<DateAdd>
+        + From<DateDiff>
+        + From<DateOffset>
+        + From<DateTruncate>
+        + From<DateWithOffset>
         + From<Divide>
+        + From<Duration>
         + From<Effect>
         + From<EndsWith>
         + From<Eq>
         + From<Equal>
+        + From<Eval>
         + From<Filter>
         + From<Flatten>
         + From<Floor>
@@ -119,10 +141,13 @@ impl<T> JsImportsBuiltin for T where
         + From<Keys>
         + From<Length>
         + From<Log>
+        + From<LogScope>
         + From<Lt>
         + From<Lte>
         + From<Map>
+        + From<Match>
         + From<Max>
+        + From<Memo>
         + From<Merge>
         + From<Min>
         + From<Multiply>
@@ -131,6 +156,7 @@ impl<T> JsImportsBuiltin for T where
         + From<Pow>
         + From<Push>
         + From<PushFront>
+        + From<Quote>
         + From<Raise>
         + From<Remainder>
         + From<Replace>
@@ -140,12 +166,16 @@ impl<T> JsImportsBuiltin for T where
         + From<ResolveHashSet>
         + From<ResolveList>
         + From<ResolveRecord>
+        + From<Reveal>
         + From<Round>
         + From<Sequence>
         + From<Slice>
+        + From<Sort>
+        + From<SortBy>
         + From<Split>
         + From<StartsWith>
         + From<Subtract>
+        + From<Template>
         + From<Unzip>
         + From<Values>
         + From<Zip>
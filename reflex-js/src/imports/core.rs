@@ -24,11 +24,18 @@ where
         + From<CollectSignal>
         + From<CollectString>
         + From<Contains>
+        + From<DateAdd>
+        + From<DateDiff>
+        + From<DateOffset>
+        + From<DateTruncate>
+        + From<DateWithOffset>
         + From<Divide>
+        + From<Duration>
         + From<Effect>
         + From<EndsWith>
         + From<Eq>
         + From<Equal>
+        + From<Eval>
         + From<Filter>
         + From<Flatten>
         + From<Floor>
@@ -47,7 +54,9 @@ where
         + From<Lt>
         + From<Lte>
         + From<Map>
+        + From<Match>
         + From<Max>
+        + From<Memo>
         + From<Merge>
         + From<Min>
         + From<Multiply>
@@ -56,6 +65,7 @@ where
         + From<Pow>
         + From<Push>
         + From<PushFront>
+        + From<Quote>
         + From<Raise>
         + From<Remainder>
         + From<Replace>
@@ -65,12 +75,16 @@ where
         + From<ResolveHashSet>
         + From<ResolveRecord>
         + From<ResolveList>
+        + From<Reveal>
         + From<Round>
         + From<Sequence>
         + From<Slice>
+        + From<Sort>
+        + From<SortBy>
         + From<Split>
         + From<StartsWith>
         + From<Subtract>
+        + From<Template>
         + From<Unzip>
         + From<Values>
         + From<Zip>,
@@ -133,10 +147,34 @@ where
                 factory.create_string_term(allocator.create_static_string("contains")),
                 factory.create_builtin_term(Contains),
             ),
+            (
+                factory.create_string_term(allocator.create_static_string("dateAdd")),
+                factory.create_builtin_term(DateAdd),
+            ),
+            (
+                factory.create_string_term(allocator.create_static_string("dateDiff")),
+                factory.create_builtin_term(DateDiff),
+            ),
+            (
+                factory.create_string_term(allocator.create_static_string("dateOffset")),
+                factory.create_builtin_term(DateOffset),
+            ),
+            (
+                factory.create_string_term(allocator.create_static_string("dateTruncate")),
+                factory.create_builtin_term(DateTruncate),
+            ),
+            (
+                factory.create_string_term(allocator.create_static_string("dateWithOffset")),
+                factory.create_builtin_term(DateWithOffset),
+            ),
             (
                 factory.create_string_term(allocator.create_static_string("divide")),
                 factory.create_builtin_term(Divide),
             ),
+            (
+                factory.create_string_term(allocator.create_static_string("duration")),
+                factory.create_builtin_term(Duration),
+            ),
             (
                 factory.create_string_term(allocator.create_static_string("effect")),
                 factory.create_builtin_term(Effect),
@@ -153,6 +191,19 @@ where
                 factory.create_string_term(allocator.create_static_string("equal")),
                 factory.create_builtin_term(Equal),
             ),
+            (
+                factory.create_string_term(allocator.create_static_string("eval")),
+                factory.create_lambda_term(
+                    1,
+                    factory.create_application_term(
+                        factory.create_builtin_term(Eval),
+                        allocator.create_unit_list(factory.create_application_term(
+                            factory.create_builtin_term(ResolveDeep),
+                            allocator.create_unit_list(factory.create_variable_term(0)),
+                        )),
+                    ),
+                ),
+            ),
             (
                 factory.create_string_term(allocator.create_static_string("filter")),
                 factory.create_builtin_term(Filter),
@@ -249,10 +300,27 @@ where
                 factory.create_string_term(allocator.create_static_string("map")),
                 factory.create_builtin_term(Map),
             ),
+            (
+                factory.create_string_term(allocator.create_static_string("match")),
+                factory.create_builtin_term(Match),
+            ),
             (
                 factory.create_string_term(allocator.create_static_string("max")),
                 factory.create_builtin_term(Max),
             ),
+            (
+                factory.create_string_term(allocator.create_static_string("memo")),
+                factory.create_lambda_term(
+                    2,
+                    factory.create_partial_application_term(
+                        factory.create_builtin_term(Memo),
+                        allocator.create_pair(
+                            factory.create_variable_term(1),
+                            factory.create_variable_term(0),
+                        ),
+                    ),
+                ),
+            ),
             (
                 factory.create_string_term(allocator.create_static_string("merge")),
                 factory.create_builtin_term(Merge),
@@ -285,6 +353,10 @@ where
                 factory.create_string_term(allocator.create_static_string("pushFront")),
                 factory.create_builtin_term(PushFront),
             ),
+            (
+                factory.create_string_term(allocator.create_static_string("quote")),
+                factory.create_builtin_term(Quote),
+            ),
             (
                 factory.create_string_term(allocator.create_static_string("raise")),
                 factory.create_builtin_term(Raise),
@@ -321,6 +393,10 @@ where
                 factory.create_string_term(allocator.create_static_string("resolveList")),
                 factory.create_builtin_term(ResolveList),
             ),
+            (
+                factory.create_string_term(allocator.create_static_string("reveal")),
+                factory.create_builtin_term(Reveal),
+            ),
             (
                 factory.create_string_term(allocator.create_static_string("round")),
                 factory.create_builtin_term(Round),
@@ -333,6 +409,14 @@ where
                 factory.create_string_term(allocator.create_static_string("slice")),
                 factory.create_builtin_term(Slice),
             ),
+            (
+                factory.create_string_term(allocator.create_static_string("sort")),
+                factory.create_builtin_term(Sort),
+            ),
+            (
+                factory.create_string_term(allocator.create_static_string("sortBy")),
+                factory.create_builtin_term(SortBy),
+            ),
             (
                 factory.create_string_term(allocator.create_static_string("split")),
                 factory.create_builtin_term(Split),
@@ -345,6 +429,25 @@ where
                 factory.create_string_term(allocator.create_static_string("subtract")),
                 factory.create_builtin_term(Subtract),
             ),
+            (
+                factory.create_string_term(allocator.create_static_string("template")),
+                factory.create_lambda_term(
+                    2,
+                    factory.create_application_term(
+                        factory.create_builtin_term(Template),
+                        allocator.create_pair(
+                            factory.create_application_term(
+                                factory.create_builtin_term(ResolveDeep),
+                                allocator.create_unit_list(factory.create_variable_term(1)),
+                            ),
+                            factory.create_application_term(
+                                factory.create_builtin_term(ResolveDeep),
+                                allocator.create_unit_list(factory.create_variable_term(0)),
+                            ),
+                        ),
+                    ),
+                ),
+            ),
             (
                 factory.create_string_term(allocator.create_static_string("unzip")),
                 factory.create_builtin_term(Unzip),
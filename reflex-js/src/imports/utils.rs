@@ -5,14 +5,14 @@ use std::iter::once;
 
 use reflex::core::{create_record, Expression, ExpressionFactory, HeapAllocator, SignalType};
 
-use crate::stdlib::Log;
+use crate::stdlib::{Log, LogScope};
 
 pub fn import_utils<T: Expression>(
     factory: &impl ExpressionFactory<T>,
     allocator: &impl HeapAllocator<T>,
 ) -> T
 where
-    T::Builtin: From<Log>,
+    T::Builtin: From<Log> + From<LogScope>,
 {
     create_record(
         vec![
@@ -27,6 +27,10 @@ where
                 factory.create_string_term(allocator.create_static_string("log")),
                 factory.create_builtin_term(Log),
             ),
+            (
+                factory.create_string_term(allocator.create_static_string("logScope")),
+                factory.create_builtin_term(LogScope),
+            ),
             (
                 factory.create_string_term(allocator.create_static_string("pending")),
                 factory.create_lambda_term(
@@ -3,18 +3,24 @@
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 use std::collections::HashMap;
 
-use reflex::core::Expression;
+use reflex::{core::Expression, source_map::SourceMetadataRecorder};
 
+use crate::{coverage::CoverageInstrumentation, source::SourceStack};
+
+pub mod coverage;
 mod loader;
 pub use loader::{
-    compose_module_loaders, create_js_env, create_module_loader, static_module_loader,
+    compose_module_loaders, create_default_module_export, create_js_env, create_module_loader,
+    static_module_loader, JavaScriptModuleLoader,
 };
 pub mod parser;
+mod source;
 pub use parser::{parse, parse_module, JsParserBuiltin};
 
 pub mod builtins;
 pub mod globals;
 pub mod imports;
+pub mod log_sink;
 pub use globals::builtin_globals;
 pub use imports::builtin_imports;
 
@@ -23,13 +29,34 @@ pub mod stdlib;
 #[derive(Clone, Debug)]
 pub struct Env<T: Expression> {
     globals: HashMap<&'static str, T>,
+    coverage: Option<CoverageInstrumentation>,
+    source_metadata: Option<SourceMetadataRecorder>,
+    sources: SourceStack,
 }
 impl<T: Expression> Env<T> {
     pub fn new() -> Self {
         Self {
             globals: HashMap::new(),
+            coverage: None,
+            source_metadata: None,
+            sources: SourceStack::default(),
         }
     }
+    pub fn with_coverage(mut self, instrumentation: CoverageInstrumentation) -> Self {
+        self.coverage = Some(instrumentation);
+        self
+    }
+    pub fn coverage(&self) -> Option<&CoverageInstrumentation> {
+        self.coverage.as_ref()
+    }
+    /// Record the source location and declared name of each parsed function into the provided recorder
+    pub fn with_source_metadata(mut self, recorder: SourceMetadataRecorder) -> Self {
+        self.source_metadata = Some(recorder);
+        self
+    }
+    pub fn source_metadata(&self) -> Option<&SourceMetadataRecorder> {
+        self.source_metadata.as_ref()
+    }
     pub fn with_globals(mut self, values: impl IntoIterator<Item = (&'static str, T)>) -> Self {
         self.globals.extend(values);
         self
@@ -41,4 +68,7 @@ impl<T: Expression> Env<T> {
     pub fn global(&self, name: &str) -> Option<T> {
         self.globals.get(name).cloned()
     }
+    pub(crate) fn sources(&self) -> &SourceStack {
+        &self.sources
+    }
 }
@@ -12,8 +12,8 @@ use derivative::Derivative;
 use reflex::{
     core::{Expression, ExpressionFactory, HeapAllocator, ModuleLoader},
     loader::{
-        get_module_filesystem_path, ChainedModuleLoader, ErrorFallbackModuleLoader,
-        RecursiveModuleLoader, StaticModuleLoader,
+        get_module_filesystem_path, CachedModuleLoader, ChainedModuleLoader,
+        ErrorFallbackModuleLoader, RecursiveModuleLoader, StaticModuleLoader,
     },
 };
 
@@ -36,10 +36,11 @@ where
     let factory = factory.clone();
     let allocator = allocator.clone();
     RecursiveModuleLoader::new(move |loader| {
-        ChainedModuleLoader::new(
+        // Share a single instance of each module across all its importers
+        CachedModuleLoader::new(ChainedModuleLoader::new(
             JavaScriptModuleLoader::new(env, loader, factory, allocator),
             ChainedModuleLoader::new(custom_loader, ErrorFallbackModuleLoader::default()),
-        )
+        ))
     })
 }
 
@@ -67,7 +68,7 @@ pub fn static_module_loader<T: Expression + 'static>(
         bound = "TLoader: std::fmt::Debug, TFactory: std::fmt::Debug, TAllocator: std::fmt::Debug"
     )
 )]
-struct JavaScriptModuleLoader<
+pub struct JavaScriptModuleLoader<
     T: Expression,
     TLoader: ModuleLoader<Output = T>,
     TFactory: ExpressionFactory<T>,
@@ -150,3 +151,78 @@ pub fn create_default_module_export<T: Expression>(
         allocator.create_unit_list(value),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use reflex::core::ExpressionFactory;
+    use reflex_lang::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
+
+    use crate::builtins::JsBuiltins;
+
+    use super::*;
+
+    type TestTerm = CachedSharedTerm<JsBuiltins>;
+
+    /// Loader that resolves `.txt` imports to an integer, keeping track of how many times it was invoked
+    struct CountingModuleLoader {
+        factory: SharedTermFactory<JsBuiltins>,
+        num_loads: Rc<Cell<usize>>,
+    }
+
+    impl ModuleLoader for CountingModuleLoader {
+        type Output = TestTerm;
+        fn load(
+            &self,
+            import_path: &str,
+            _current_path: &Path,
+        ) -> Option<Result<Self::Output, String>> {
+            if !import_path.ends_with(".txt") {
+                return None;
+            }
+            self.num_loads.set(self.num_loads.get() + 1);
+            Some(Ok(self.factory.create_int_term(3)))
+        }
+    }
+
+    #[test]
+    fn shared_module_instances() {
+        let factory = SharedTermFactory::<JsBuiltins>::default();
+        let allocator = DefaultAllocator::default();
+        let directory = std::env::temp_dir().join(format!(
+            "reflex-js-shared-module-instances-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(directory.join("bar")).unwrap();
+        fs::write(
+            directory.join("foo.js"),
+            "import value from './value.txt'; export default value;",
+        )
+        .unwrap();
+        fs::write(
+            directory.join("bar").join("baz.js"),
+            "import value from '../value.txt'; export default value;",
+        )
+        .unwrap();
+        let entry_path = directory.join("index.js");
+        let num_loads = Rc::new(Cell::new(0));
+        let loader = create_module_loader(
+            create_js_env(&factory, &allocator),
+            CountingModuleLoader {
+                factory,
+                num_loads: Rc::clone(&num_loads),
+            },
+            &factory,
+            &allocator,
+        );
+        let foo = loader.load("./foo.js", &entry_path);
+        let baz = loader.load("./bar/baz.js", &entry_path);
+        assert!(matches!(foo, Some(Ok(_))));
+        assert!(matches!(baz, Some(Ok(_))));
+        assert_eq!(num_loads.get(), 1);
+        assert_eq!(loader.load("./foo.js", &entry_path), foo);
+        let _ = fs::remove_dir_all(&directory);
+    }
+}
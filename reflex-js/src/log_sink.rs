@@ -0,0 +1,302 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    num::NonZeroU32,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Arc, Mutex, RwLock},
+    time::{Duration, Instant},
+};
+
+/// Log entry emitted by the `Log` family of builtins
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct LogRecord {
+    /// Monotonically increasing sequence number, assigned in emission order
+    pub sequence: u64,
+    /// Scope labels applied to the log entry, outermost first
+    pub labels: Vec<String>,
+    pub message: String,
+}
+impl std::fmt::Display for LogRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.labels.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "[{}] {}", self.labels.join(" "), self.message)
+        }
+    }
+}
+
+/// Host destination for log entries emitted during evaluation
+///
+/// Records are passed to the sink one at a time in sequence order, so implementations do not need to perform any
+/// additional synchronization in order to prevent concurrent evaluations from interleaving their output.
+pub trait LogSink: Send + Sync {
+    fn write(&self, record: &LogRecord);
+}
+
+#[derive(Default, Clone, Copy, Debug)]
+pub struct StdoutLogSink;
+impl LogSink for StdoutLogSink {
+    fn write(&self, record: &LogRecord) {
+        let mut stdout = std::io::stdout().lock();
+        let _ = writeln!(stdout, "{}", record);
+    }
+}
+
+#[derive(Default, Clone, Copy, Debug)]
+pub struct TracingLogSink;
+impl LogSink for TracingLogSink {
+    fn write(&self, record: &LogRecord) {
+        let LogRecord {
+            sequence,
+            labels,
+            message,
+        } = record;
+        tracing::info!(
+            target: "reflex::log",
+            sequence = sequence,
+            labels = %labels.join(" "),
+            "{}",
+            message
+        );
+    }
+}
+
+pub struct WriterLogSink<W: Write + Send> {
+    writer: Mutex<W>,
+}
+impl<W: Write + Send> WriterLogSink<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: Mutex::new(writer),
+        }
+    }
+}
+impl<W: Write + Send> LogSink for WriterLogSink<W> {
+    fn write(&self, record: &LogRecord) {
+        if let Ok(mut writer) = self.writer.lock() {
+            let _ = writeln!(writer, "{}", record).and_then(|_| writer.flush());
+        }
+    }
+}
+
+pub type FileLogSink = WriterLogSink<BufWriter<File>>;
+impl FileLogSink {
+    pub fn create(path: &Path) -> std::io::Result<Self> {
+        File::create(path).map(|file| Self::new(BufWriter::new(file)))
+    }
+}
+
+/// Sequences log records and enforces the configured rate limit before forwarding them to the underlying sink
+pub struct LogDispatcher {
+    sink: Arc<dyn LogSink>,
+    max_rate: Option<NonZeroU32>,
+    state: Mutex<LogDispatcherState>,
+}
+struct LogDispatcherState {
+    sequence: u64,
+    window_start: Option<Instant>,
+    window_count: u32,
+    suppressed: u64,
+}
+impl LogDispatcher {
+    const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(1);
+    /// Create a dispatcher that forwards at most `max_rate` records per second to the provided sink
+    pub fn new(sink: impl LogSink + 'static, max_rate: Option<NonZeroU32>) -> Self {
+        Self {
+            sink: Arc::new(sink),
+            max_rate,
+            state: Mutex::new(LogDispatcherState {
+                sequence: 0,
+                window_start: None,
+                window_count: 0,
+                suppressed: 0,
+            }),
+        }
+    }
+    pub fn emit(&self, labels: Vec<String>, message: String) {
+        self.emit_at(labels, message, Instant::now())
+    }
+    fn emit_at(&self, labels: Vec<String>, message: String, now: Instant) {
+        // The state lock is held while writing to the sink to ensure records are written in sequence order
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(_) => return,
+        };
+        if let Some(max_rate) = self.max_rate {
+            let is_new_window = state
+                .window_start
+                .map(|window_start| now.duration_since(window_start) >= Self::RATE_LIMIT_WINDOW)
+                .unwrap_or(true);
+            if is_new_window {
+                state.window_start = Some(now);
+                state.window_count = 0;
+                if state.suppressed > 0 {
+                    let suppressed = std::mem::take(&mut state.suppressed);
+                    self.write(
+                        &mut state,
+                        Vec::new(),
+                        format!("{} log entries suppressed by rate limit", suppressed),
+                    );
+                }
+            }
+            if state.window_count >= max_rate.get() {
+                state.suppressed += 1;
+                return;
+            }
+            state.window_count += 1;
+        }
+        self.write(&mut state, labels, message);
+    }
+    fn write(&self, state: &mut LogDispatcherState, labels: Vec<String>, message: String) {
+        let sequence = state.sequence;
+        state.sequence += 1;
+        self.sink.write(&LogRecord {
+            sequence,
+            labels,
+            message,
+        });
+    }
+}
+impl Default for LogDispatcher {
+    fn default() -> Self {
+        Self::new(StdoutLogSink, None)
+    }
+}
+
+/// Host log sink selection, parsed from `"stdout"`, `"tracing"` or `"file:<path>"`
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum LogSinkConfig {
+    Stdout,
+    Tracing,
+    File(PathBuf),
+}
+impl FromStr for LogSinkConfig {
+    type Err = String;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "stdout" => Ok(Self::Stdout),
+            "tracing" => Ok(Self::Tracing),
+            _ => match input.strip_prefix("file:") {
+                Some(path) if !path.is_empty() => Ok(Self::File(PathBuf::from(path))),
+                _ => Err(format!("Unrecognized log sink: {}", input)),
+            },
+        }
+    }
+}
+impl LogSinkConfig {
+    /// Install the configured sink as the process-wide log sink
+    pub fn install(&self, max_rate: Option<NonZeroU32>) -> std::io::Result<()> {
+        match self {
+            Self::Stdout => set_log_sink(StdoutLogSink, max_rate),
+            Self::Tracing => set_log_sink(TracingLogSink, max_rate),
+            Self::File(path) => set_log_sink(FileLogSink::create(path)?, max_rate),
+        }
+        Ok(())
+    }
+}
+
+static LOG_DISPATCHER: RwLock<Option<Arc<LogDispatcher>>> = RwLock::new(None);
+
+/// Replace the process-wide log sink used by the `Log` family of builtins (defaults to stdout with no rate limit)
+pub fn set_log_sink(sink: impl LogSink + 'static, max_rate: Option<NonZeroU32>) {
+    if let Ok(mut dispatcher) = LOG_DISPATCHER.write() {
+        *dispatcher = Some(Arc::new(LogDispatcher::new(sink, max_rate)));
+    }
+}
+
+pub(crate) fn emit_log(labels: Vec<String>, message: String) {
+    let existing = LOG_DISPATCHER
+        .read()
+        .ok()
+        .and_then(|dispatcher| dispatcher.clone());
+    let dispatcher = existing.or_else(|| {
+        LOG_DISPATCHER.write().ok().map(|mut dispatcher| {
+            dispatcher
+                .get_or_insert_with(|| Arc::new(LogDispatcher::default()))
+                .clone()
+        })
+    });
+    if let Some(dispatcher) = dispatcher {
+        dispatcher.emit(labels, message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default, Clone)]
+    struct CollectingLogSink(Arc<Mutex<Vec<LogRecord>>>);
+    impl LogSink for CollectingLogSink {
+        fn write(&self, record: &LogRecord) {
+            self.0.lock().unwrap().push(record.clone());
+        }
+    }
+
+    #[test]
+    fn sequences_records() {
+        let sink = CollectingLogSink::default();
+        let dispatcher = LogDispatcher::new(sink.clone(), None);
+        dispatcher.emit(Vec::new(), String::from("foo"));
+        dispatcher.emit(
+            vec![String::from("a"), String::from("b")],
+            String::from("bar"),
+        );
+        let records = sink.0.lock().unwrap().clone();
+        assert_eq!(
+            records,
+            vec![
+                LogRecord {
+                    sequence: 0,
+                    labels: Vec::new(),
+                    message: String::from("foo"),
+                },
+                LogRecord {
+                    sequence: 1,
+                    labels: vec![String::from("a"), String::from("b")],
+                    message: String::from("bar"),
+                },
+            ]
+        );
+        assert_eq!(
+            records
+                .iter()
+                .map(|record| format!("{}", record))
+                .collect::<Vec<_>>(),
+            vec![String::from("foo"), String::from("[a b] bar")],
+        );
+    }
+
+    #[test]
+    fn rate_limits_records() {
+        let sink = CollectingLogSink::default();
+        let dispatcher = LogDispatcher::new(sink.clone(), NonZeroU32::new(2));
+        let start = Instant::now();
+        for index in 0..5 {
+            dispatcher.emit_at(Vec::new(), format!("{}", index), start);
+        }
+        dispatcher.emit_at(
+            Vec::new(),
+            String::from("5"),
+            start + LogDispatcher::RATE_LIMIT_WINDOW,
+        );
+        let records = sink.0.lock().unwrap().clone();
+        assert_eq!(
+            records
+                .iter()
+                .map(|record| (record.sequence, record.message.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (0, "0"),
+                (1, "1"),
+                (2, "3 log entries suppressed by rate limit"),
+                (3, "5"),
+            ],
+        );
+    }
+}
@@ -4,36 +4,49 @@
 // SPDX-FileContributor: Chris Campbell <c.campbell@mwam.com> https://github.com/c-campbell-mwam
 // SPDX-FileContributor: Jordan Hall <j.hall@mwam.com> https://github.com/j-hall-mwam
 use std::{
+    collections::HashSet,
     iter::{empty, once},
     ops::Deref,
     path::Path,
 };
 
-use reflex::core::{
-    as_integer, create_record, Builtin, Expression, ExpressionFactory, FloatTermType,
-    HeapAllocator, IntTermType, IntValue, ModuleLoader, RefType, StringTermType, StringValue,
+use reflex::{
+    core::{
+        as_integer, create_record, validate_builtin_application_arity, Builtin, BuiltinTermType,
+        Expression, ExpressionFactory, FloatTermType, HeapAllocator, IntTermType, IntValue,
+        ModuleLoader, RefType, StringTermType, StringValue,
+    },
+    regex::compile_regex,
+    source_map::SourceMetadata,
 };
 use reflex_stdlib::{
     Add, Apply, Chain, CollectHashMap, CollectHashSet, CollectList, CollectString, Contains,
-    Divide, Eq, Flatten, Get, Gt, Gte, If, IfError, Lt, Lte, Merge, Multiply, Not, Pow, Push,
-    PushFront, Remainder, ResolveDeep, ResolveHashMap, ResolveList, Subtract,
+    Divide, Effect, Eq, Flatten, Get, Gt, Gte, If, IfError, Lt, Lte, Merge, Multiply, Not, Pow,
+    Push, PushFront, Remainder, ResolveDeep, ResolveHashMap, ResolveList, Subtract,
 };
-use swc_common::{source_map::Pos, sync::Lrc, FileName, SourceMap, Span, Spanned};
+use swc_common::{source_map::Pos, sync::Lrc, BytePos, FileName, SourceMap, Span, Spanned};
 use swc_ecma_ast::{
     ArrayLit, ArrowExpr, BinExpr, BinaryOp, BindingIdent, BlockStmt, BlockStmtOrExpr, Bool,
     CallExpr, Callee, CondExpr, Decl, EsVersion, Expr, ExprOrSpread, ExprStmt, Ident, ImportDecl,
     ImportSpecifier, Lit, MemberExpr, MemberProp, Module, ModuleDecl, ModuleExportName, ModuleItem,
-    NewExpr, Null, Number, ObjectLit, ObjectPatProp, Pat, Prop, PropName, PropOrSpread, Stmt, Str,
-    TaggedTpl, Tpl, TplElement, UnaryExpr, UnaryOp, VarDeclKind, VarDeclarator,
+    NewExpr, Null, Number, ObjectLit, ObjectPatProp, Pat, Prop, PropName, PropOrSpread, Regex,
+    Stmt, Str, TaggedTpl, Tpl, TplElement, UnaryExpr, UnaryOp, VarDeclKind, VarDeclarator,
 };
 use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax};
 
 use crate::{
+    coverage::CoverageProbeKind,
     globals::{global_aggregate_error, global_map},
-    stdlib::{Accessor, Construct, FormatErrorMessage, IsTruthy, Throw, ToString},
+    stdlib::{
+        create_accessor_term, Accessor, Construct, FormatErrorMessage, IsTruthy, Throw, ToString,
+    },
     Env,
 };
 
+mod tree_shaking;
+
+use self::tree_shaking::prune_unused_declarations;
+
 pub type ParserResult<T> = Result<T, ParserError>;
 pub type ParserError = String;
 
@@ -50,6 +63,7 @@ pub trait JsParserBuiltin:
     + From<Construct>
     + From<Contains>
     + From<Divide>
+    + From<Effect>
     + From<Eq>
     + From<Flatten>
     + From<FormatErrorMessage>
@@ -89,6 +103,7 @@ impl<T> JsParserBuiltin for T where
         + From<Construct>
         + From<Contains>
         + From<Divide>
+        + From<Effect>
         + From<Eq>
         + From<Flatten>
         + From<FormatErrorMessage>
@@ -175,8 +190,10 @@ pub fn parse<T: Expression>(
 where
     T::Builtin: JsParserBuiltin,
 {
-    let program = parse_ast(input, None)?;
-    parse_script_contents(program.body.into_iter(), env, factory, allocator)
+    let (program, start_pos) = parse_ast(input, None)?;
+    with_source(env, None, input, start_pos, || {
+        parse_script_contents(program.body.into_iter(), env, factory, allocator)
+    })
 }
 
 pub fn parse_module<T: Expression>(
@@ -190,15 +207,81 @@ pub fn parse_module<T: Expression>(
 where
     T::Builtin: JsParserBuiltin,
 {
-    let program = parse_ast(input, Some(path))?;
-    parse_module_contents(
-        program.body.into_iter(),
-        env,
-        path,
-        loader,
-        factory,
-        allocator,
-    )
+    let (program, start_pos) = parse_ast(input, Some(path))?;
+    with_source(env, Some(path), input, start_pos, || {
+        parse_module_contents(
+            program.body.into_iter(),
+            env,
+            path,
+            loader,
+            factory,
+            allocator,
+        )
+    })
+}
+
+fn with_source<T: Expression, V>(
+    env: &Env<T>,
+    path: Option<&Path>,
+    input: &str,
+    start_pos: BytePos,
+    callback: impl FnOnce() -> V,
+) -> V {
+    env.sources().enter_source(path, input, start_pos);
+    let result = callback();
+    env.sources().exit_source();
+    result
+}
+
+fn instrument_coverage<T: Expression>(
+    kind: CoverageProbeKind,
+    span: Span,
+    expression: T,
+    env: &Env<T>,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T
+where
+    T::Builtin: JsParserBuiltin,
+{
+    match env.coverage() {
+        None => expression,
+        Some(coverage) => coverage.instrument(
+            kind,
+            env.sources().locate(span.lo),
+            expression,
+            factory,
+            allocator,
+        ),
+    }
+}
+
+fn record_source_metadata<T: Expression>(
+    expression: &T,
+    name: Option<&str>,
+    span: Option<Span>,
+    env: &Env<T>,
+) {
+    if let Some(recorder) = env.source_metadata() {
+        recorder.record(
+            expression,
+            SourceMetadata {
+                name: name.map(String::from),
+                location: span.map(|span| env.sources().locate(span.lo)),
+            },
+        )
+    }
+}
+
+fn record_function_name<T: Expression>(
+    value: &T,
+    name: &str,
+    env: &Env<T>,
+    factory: &impl ExpressionFactory<T>,
+) {
+    if factory.match_lambda_term(value).is_some() {
+        record_source_metadata(value, Some(name), None, env)
+    }
 }
 
 fn format_source_error(location: Span, message: &str, source_map: &SourceMap) -> String {
@@ -233,7 +316,7 @@ fn format_source_error(location: Span, message: &str, source_map: &SourceMap) ->
     format!("{}: {}", location, message)
 }
 
-fn parse_ast(input: &str, path: Option<&Path>) -> ParserResult<Module> {
+fn parse_ast(input: &str, path: Option<&Path>) -> ParserResult<(Module, BytePos)> {
     let source_map: Lrc<SourceMap> = Default::default();
     let source = source_map.new_source_file(
         match path {
@@ -260,6 +343,7 @@ fn parse_ast(input: &str, path: Option<&Path>) -> ParserResult<Module> {
 
     parser
         .parse_module()
+        .map(|module| (module, source.start_pos))
         .map_err(|err| format_source_error(err.span(), &err.into_kind().msg(), &source_map))
 }
 
@@ -297,17 +381,15 @@ where
     T::Builtin: JsParserBuiltin,
 {
     let num_statements = program.len();
-    let (body, import_bindings) = program.into_iter().fold(
+    let (body, imports) = program.into_iter().fold(
         Ok((Vec::with_capacity(num_statements), Vec::new())),
         |results, node| {
-            let (mut body, mut import_bindings) = results?;
+            let (mut body, mut imports) = results?;
             match node {
                 ModuleItem::ModuleDecl(node) => match node {
                     ModuleDecl::Import(node) => {
-                        let bindings =
-                            parse_module_import(&node, path, loader, factory, allocator)?;
-                        import_bindings.extend(bindings);
-                        Ok((body, import_bindings))
+                        imports.push(node);
+                        Ok((body, imports))
                     }
                     ModuleDecl::ExportDecl(node) => Err(err_unimplemented(node)),
                     ModuleDecl::ExportNamed(node) => Err(err_unimplemented(node)),
@@ -317,18 +399,29 @@ where
                             span: node.span,
                             expr: node.expr,
                         }));
-                        Ok((body, import_bindings))
+                        Ok((body, imports))
                     }
                     ModuleDecl::ExportAll(_) => Err(err_unimplemented(node)),
                     _ => Err(err_unimplemented(node)),
                 },
                 ModuleItem::Stmt(node) => {
                     body.push(node);
-                    Ok((body, import_bindings))
+                    Ok((body, imports))
                 }
             }
         },
     )?;
+    // Prune any declarations and imports that are not reachable from the default export before constructing the
+    // module expression
+    let (body, references) = prune_unused_declarations(body);
+    let import_bindings = imports
+        .iter()
+        .map(|node| {
+            parse_module_import(node, references.as_ref(), path, loader, factory, allocator)
+        })
+        .collect::<ParserResult<Vec<_>>>()?
+        .into_iter()
+        .flatten();
     let (import_keys, import_initializers): (Vec<_>, Vec<_>) = import_bindings.into_iter().unzip();
     let scope = LexicalScope::from(import_keys.into_iter().map(Some));
     match parse_block(&body, &scope, &env, factory, allocator)? {
@@ -343,6 +436,7 @@ where
 
 fn parse_module_import<T: Expression>(
     node: &ImportDecl,
+    references: Option<&HashSet<String>>,
     path: &Path,
     loader: &impl ModuleLoader<Output = T>,
     factory: &impl ExpressionFactory<T>,
@@ -364,11 +458,21 @@ where
     Ok(node
         .specifiers
         .iter()
+        .filter(|specifier| {
+            let local = match specifier {
+                ImportSpecifier::Default(node) => &node.local,
+                ImportSpecifier::Namespace(node) => &node.local,
+                ImportSpecifier::Named(node) => &node.local,
+            };
+            references
+                .map(|references| references.contains(parse_identifier(local)))
+                .unwrap_or(true)
+        })
         .map(|specifier| {
             let (identifier, value) = match specifier {
                 ImportSpecifier::Default(node) => {
                     let identifier = parse_identifier(&node.local);
-                    let value = get_static_field(module.clone(), "default", factory, allocator);
+                    let value = get_module_export(&module, "default", factory, allocator);
                     (identifier, value)
                 }
                 ImportSpecifier::Namespace(node) => {
@@ -386,8 +490,7 @@ where
                             ModuleExportName::Str(name) => parse_string(name),
                         })
                         .unwrap_or_else(|| String::from(identifier));
-                    let value =
-                        get_static_field(module.clone(), &imported_field, factory, allocator);
+                    let value = get_module_export(&module, &imported_field, factory, allocator);
                     (identifier, value)
                 }
             };
@@ -396,6 +499,20 @@ where
         .collect())
 }
 
+fn get_module_export<T: Expression>(
+    module: &T,
+    field: &str,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T
+where
+    T::Builtin: JsParserBuiltin,
+{
+    // Resolve the export directly if the module is a static record, avoiding retaining the entire module record
+    let key = factory.create_string_term(allocator.create_string(field));
+    create_accessor_term(module.clone(), key, factory, allocator)
+}
+
 fn parse_block<'a, T: Expression>(
     body: impl IntoIterator<Item = &'a Stmt>,
     scope: &LexicalScope,
@@ -477,10 +594,24 @@ where
                 }
                 Stmt::If(node) => {
                     let condition = parse_expression(&node.test, scope, env, factory, allocator)?;
-                    let consequent = parse_if_branch(&node.cons, scope, env, factory, allocator)?;
+                    let consequent = instrument_coverage(
+                        CoverageProbeKind::Branch,
+                        node.cons.span(),
+                        parse_if_branch(&node.cons, scope, env, factory, allocator)?,
+                        env,
+                        factory,
+                        allocator,
+                    );
                     match &node.alt {
                         Some(node) => {
-                            let alternate = parse_if_branch(&node, scope, env, factory, allocator)?;
+                            let alternate = instrument_coverage(
+                                CoverageProbeKind::Branch,
+                                node.span(),
+                                parse_if_branch(&node, scope, env, factory, allocator)?,
+                                env,
+                                factory,
+                                allocator,
+                            );
                             let expression = create_if_expression(
                                 condition, consequent, alternate, factory, allocator,
                             );
@@ -490,9 +621,16 @@ where
                             )
                         }
                         None => {
-                            let alternate = parse_branch(
-                                &statement, remaining, scope, env, factory, allocator,
-                            )?;
+                            let alternate = instrument_coverage(
+                                CoverageProbeKind::Branch,
+                                node.span,
+                                parse_branch(
+                                    &statement, remaining, scope, env, factory, allocator,
+                                )?,
+                                env,
+                                factory,
+                                allocator,
+                            );
                             let result = create_if_expression(
                                 condition, consequent, alternate, factory, allocator,
                             );
@@ -596,6 +734,7 @@ where
     match &node.name {
         Pat::Ident(node) => {
             let identifier = parse_identifier(&node.id);
+            record_function_name(&value, identifier, env, factory);
             Ok((
                 vec![value],
                 Some(scope.create_child(once(Some(String::from(identifier))))),
@@ -951,6 +1090,7 @@ fn parse_literal<T: Expression>(
         Lit::Bool(node) => parse_boolean_literal(node, factory),
         Lit::Num(node) => parse_number_literal(node, factory),
         Lit::Str(node) => parse_string_literal(node, factory, allocator),
+        Lit::Regex(node) => parse_regex_literal(node, factory, allocator),
         _ => Err(err_unimplemented(node)),
     }
 }
@@ -985,6 +1125,36 @@ fn parse_string_literal<T: Expression>(
     Ok(factory.create_string_term(allocator.create_string(value)))
 }
 
+fn parse_regex_literal<T: Expression>(
+    node: &Regex,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> ParserResult<T> {
+    // Flags that affect matching semantics are translated into an inline flag group, while flags that would require
+    // stateful matching are rejected
+    let flags = node
+        .flags
+        .chars()
+        .filter_map(|flag| match flag {
+            'i' | 'm' | 's' => Some(Ok(flag)),
+            'u' => None,
+            _ => Some(Err(err(
+                &format!("Unsupported regular expression flag: {}", flag),
+                node,
+            ))),
+        })
+        .collect::<ParserResult<String>>()?;
+    let pattern = if flags.is_empty() {
+        String::from(&*node.exp)
+    } else {
+        format!("(?{}){}", flags, &*node.exp)
+    };
+    match compile_regex(&pattern) {
+        Ok(_) => Ok(factory.create_regex_term(allocator.create_string(pattern))),
+        Err(message) => Err(err(&message, node)),
+    }
+}
+
 fn parse_string(node: &Str) -> String {
     parse_escaped_string(&node.value)
 }
@@ -1104,6 +1274,7 @@ where
                             let key = parse_prop_name(&prop.key, scope, env, factory, allocator)?;
                             let value =
                                 parse_expression(&prop.value, scope, env, factory, allocator)?;
+                            record_function_name(&value, &key, env, factory);
                             elements.push(ObjectLiteralField::Property(key, value));
                             Ok(elements)
                         }
@@ -1719,8 +1890,22 @@ where
     T::Builtin: JsParserBuiltin,
 {
     let condition = parse_expression(&node.test, scope, env, factory, allocator)?;
-    let consequent = parse_expression(&node.cons, scope, env, factory, allocator)?;
-    let alternate = parse_expression(&node.alt, scope, env, factory, allocator)?;
+    let consequent = instrument_coverage(
+        CoverageProbeKind::Branch,
+        node.cons.span(),
+        parse_expression(&node.cons, scope, env, factory, allocator)?,
+        env,
+        factory,
+        allocator,
+    );
+    let alternate = instrument_coverage(
+        CoverageProbeKind::Branch,
+        node.alt.span(),
+        parse_expression(&node.alt, scope, env, factory, allocator)?,
+        env,
+        factory,
+        allocator,
+    );
     Ok(create_if_expression(
         condition, consequent, alternate, factory, allocator,
     ))
@@ -1894,15 +2079,26 @@ where
         }?;
         match body {
             None => Err(err("Missing function return statement", node)),
-            Some(body) => Ok(factory.create_lambda_term(
-                num_args,
-                initializers
-                    .into_iter()
-                    .rev()
-                    .fold(body, |body, initializer| {
-                        factory.create_let_term(initializer, body)
-                    }),
-            )),
+            Some(body) => {
+                let function = factory.create_lambda_term(
+                    num_args,
+                    instrument_coverage(
+                        CoverageProbeKind::Function,
+                        node.span,
+                        initializers
+                            .into_iter()
+                            .rev()
+                            .fold(body, |body, initializer| {
+                                factory.create_let_term(initializer, body)
+                            }),
+                        env,
+                        factory,
+                        allocator,
+                    ),
+                );
+                record_source_metadata(&function, None, Some(node.span), env);
+                Ok(function)
+            }
         }
     }
 }
@@ -1925,7 +2121,8 @@ where
         MemberProp::Computed(key) => parse_expression(&key.expr, scope, env, factory, allocator),
         MemberProp::PrivateName(_) => Err(err_unimplemented(&node.prop)),
     }?;
-    Ok(get_dynamic_field(target, field, factory, allocator))
+    // String-literal property accesses on static records are resolved at compile time
+    Ok(create_accessor_term(target, field, factory, allocator))
 }
 
 fn get_static_field<T: Expression>(
@@ -1999,6 +2196,11 @@ where
             allocator.create_pair(target, spread),
         ))
     } else {
+        if let Some(term) = factory.match_builtin_term(&target) {
+            validate_builtin_application_arity(&term.target(), args.len()).map_err(|message| {
+                format!("{}: {}", env.sources().locate(node.span.lo), message)
+            })?;
+        }
         Ok(factory.create_application_term(target, allocator.create_list(args)))
     }
 }
@@ -2157,11 +2359,13 @@ mod tests {
     use reflex::{
         cache::SubstitutionCache,
         core::{
-            create_record, evaluate, ConditionListType, ConditionType, DependencyList,
-            EvaluationResult, Expression, ExpressionFactory, HeapAllocator, InstructionPointer,
-            RecordTermType, SignalTermType, SignalType, StateCache, StringValue,
+            create_error_expression, create_record, evaluate, ConditionListType, ConditionType,
+            DependencyList, EvaluationResult, Expression, ExpressionFactory, HeapAllocator,
+            InstructionPointer, NumericSemantics, RecordTermType, SignalTermType, SignalType,
+            StateCache, StringValue,
         },
         env::inject_env_vars,
+        source_map::SourceMetadataRecorder,
     };
     use reflex_interpreter::{
         compiler::{hash_compiled_program, Compiler, CompilerMode, CompilerOptions},
@@ -2170,6 +2374,8 @@ mod tests {
     use reflex_lang::{allocator::DefaultAllocator, SharedTermFactory};
     use reflex_stdlib::CollectList;
 
+    use crate::stdlib::IsFinite;
+
     use super::*;
 
     fn get_combined_errors<T: Expression>(
@@ -2309,6 +2515,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn regex_literals() {
+        let factory = SharedTermFactory::<JsBuiltins>::default();
+        let allocator = DefaultAllocator::default();
+        let env = Env::new();
+        assert_eq!(
+            parse("/fo+(\\d)/", &env, &factory, &allocator),
+            Ok(factory.create_regex_term(allocator.create_static_string("fo+(\\d)"))),
+        );
+        assert_eq!(
+            parse("/foo/iu", &env, &factory, &allocator),
+            Ok(factory.create_regex_term(allocator.create_static_string("(?i)foo"))),
+        );
+        assert!(parse("/foo/g", &env, &factory, &allocator).is_err());
+        assert!(parse("/(?<=foo)bar/", &env, &factory, &allocator).is_err());
+        let evaluate_source = |source: &str| {
+            evaluate(
+                &parse(source, &env, &factory, &allocator).unwrap(),
+                &StateCache::default(),
+                &factory,
+                &allocator,
+                &mut SubstitutionCache::new(),
+            )
+        };
+        assert_eq!(
+            evaluate_source("'foo123bar'.match(/([a-z]+)(\\d+)(x)?/)"),
+            EvaluationResult::new(
+                factory.create_list_term(allocator.create_list([
+                    factory.create_string_term(allocator.create_static_string("foo123")),
+                    factory.create_string_term(allocator.create_static_string("foo")),
+                    factory.create_string_term(allocator.create_static_string("123")),
+                    factory.create_nil_term(),
+                ])),
+                DependencyList::empty(),
+            ),
+        );
+        assert_eq!(
+            evaluate_source("'foo123bar'.match(/baz/)"),
+            EvaluationResult::new(factory.create_nil_term(), DependencyList::empty()),
+        );
+        assert_eq!(
+            evaluate_source("'foo123bar'.replace(/(\\d)(\\d)/, '$2$1')"),
+            EvaluationResult::new(
+                factory.create_string_term(allocator.create_static_string("foo213bar")),
+                DependencyList::empty(),
+            ),
+        );
+    }
+
     #[test]
     fn numeric_literals() {
         let factory = SharedTermFactory::<JsBuiltins>::default();
@@ -2720,6 +2975,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn object_property_access() {
+        let factory = SharedTermFactory::<JsBuiltins>::default();
+        let allocator = DefaultAllocator::default();
+        let env = Env::new();
+        assert_eq!(
+            parse(
+                "({ foo: 3, bar: { baz: 4 } }).bar.baz",
+                &env,
+                &factory,
+                &allocator
+            ),
+            Ok(factory.create_float_term(4.0)),
+        );
+        assert_eq!(
+            parse("({ foo: 3, bar: 4 })[\"foo\"]", &env, &factory, &allocator),
+            Ok(factory.create_float_term(3.0)),
+        );
+        assert_eq!(
+            parse("({ foo: 3 }).bar", &env, &factory, &allocator),
+            Ok(factory.create_application_term(
+                factory.create_builtin_term(Accessor),
+                allocator.create_pair(
+                    create_record(
+                        [(
+                            factory.create_string_term(allocator.create_static_string("foo")),
+                            factory.create_float_term(3.0),
+                        )],
+                        &factory,
+                        &allocator,
+                    ),
+                    factory.create_string_term(allocator.create_static_string("bar")),
+                ),
+            )),
+        );
+        let expression = parse(
+            "const value = { foo: 3, bar: { baz: 4 } }; value.bar.baz",
+            &env,
+            &factory,
+            &allocator,
+        )
+        .unwrap();
+        let result = evaluate(
+            &expression,
+            &StateCache::default(),
+            &factory,
+            &allocator,
+            &mut SubstitutionCache::new(),
+        );
+        assert_eq!(
+            result,
+            EvaluationResult::new(factory.create_float_term(4.0), DependencyList::empty())
+        );
+    }
+
     #[test]
     fn object_spread() {
         let factory = SharedTermFactory::<JsBuiltins>::default();
@@ -3106,6 +3416,90 @@ mod tests {
         );
     }
 
+    #[test]
+    fn module_tree_shaking() {
+        let factory = SharedTermFactory::<JsBuiltins>::default();
+        let allocator = DefaultAllocator::default();
+        let env = Env::new();
+        let loader = static_module_loader(vec![(
+            String::from("foo"),
+            create_record(
+                [
+                    (
+                        factory.create_string_term(allocator.create_static_string("foo")),
+                        factory.create_float_term(3.0),
+                    ),
+                    (
+                        factory.create_string_term(allocator.create_static_string("bar")),
+                        factory.create_float_term(4.0),
+                    ),
+                ],
+                &factory,
+                &allocator,
+            ),
+        )]);
+        let path = Path::new("./foo.js");
+        assert_eq!(
+            parse_module(
+                "
+                import { foo, bar } from 'foo';
+                const unused = bar;
+                const first = foo;
+                const { second } = { second: first };
+                const unusedDestructured = [unused];
+                export default second;
+                ",
+                &env,
+                &path,
+                &loader,
+                &factory,
+                &allocator
+            ),
+            Ok(factory.create_let_term(
+                factory.create_float_term(3.0),
+                factory.create_let_term(
+                    factory.create_variable_term(0),
+                    factory.create_let_term(
+                        factory.create_application_term(
+                            factory.create_builtin_term(Accessor),
+                            allocator.create_pair(
+                                create_record(
+                                    [(
+                                        factory.create_string_term(
+                                            allocator.create_static_string("second"),
+                                        ),
+                                        factory.create_variable_term(0),
+                                    )],
+                                    &factory,
+                                    &allocator,
+                                ),
+                                factory
+                                    .create_string_term(allocator.create_static_string("second")),
+                            ),
+                        ),
+                        factory.create_variable_term(0),
+                    ),
+                ),
+            )),
+        );
+        assert_eq!(
+            parse_module(
+                "
+                const foo = 3;
+                export default foo;
+                const bar = 4;
+                ",
+                &env,
+                &path,
+                &loader,
+                &factory,
+                &allocator
+            )
+            .is_err(),
+            true,
+        );
+    }
+
     #[test]
     fn variable_declarations() {
         let factory = SharedTermFactory::<JsBuiltins>::default();
@@ -5315,6 +5709,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn invalid_builtin_applications() {
+        let factory = SharedTermFactory::<JsBuiltins>::default();
+        let allocator = DefaultAllocator::default();
+        let env = Env::new().with_global("isFinite", factory.create_builtin_term(IsFinite));
+        assert_eq!(
+            parse("\n  isFinite()", &env, &factory, &allocator),
+            Err(String::from(
+                "<anonymous>:2:3: <js:IsFinite>: Expected 1 argument, received 0 (signature: <js:IsFinite>(strict))"
+            )),
+        );
+        assert_eq!(
+            parse("isFinite(3, 4)", &env, &factory, &allocator),
+            Ok(factory.create_application_term(
+                factory.create_builtin_term(IsFinite),
+                allocator.create_pair(
+                    factory.create_float_term(3.0),
+                    factory.create_float_term(4.0),
+                ),
+            )),
+        );
+    }
+
     #[test]
     fn function_arg_spreading() {
         let factory = SharedTermFactory::<JsBuiltins>::default();
@@ -5436,6 +5853,325 @@ mod tests {
         );
     }
 
+    #[test]
+    fn scoped_log_expressions() {
+        let factory = SharedTermFactory::<JsBuiltins>::default();
+        let allocator = DefaultAllocator::default();
+        let env = Env::new();
+        let path = Path::new("./foo.js");
+        let loader = static_module_loader(builtin_imports(&factory, &allocator));
+        let expression = parse_module(
+            "
+            import { log, logScope } from 'reflex::utils';
+            export default logScope('foo', () => log(3, 'bar') + logScope('baz', () => log(4)));
+        ",
+            &env,
+            path,
+            &loader,
+            &factory,
+            &allocator,
+        )
+        .unwrap();
+        let result = evaluate(
+            &expression,
+            &StateCache::default(),
+            &factory,
+            &allocator,
+            &mut SubstitutionCache::new(),
+        );
+        assert_eq!(
+            result,
+            EvaluationResult::new(factory.create_float_term(7.0), DependencyList::empty()),
+        );
+    }
+
+    #[test]
+    fn date_arithmetic() {
+        let factory = SharedTermFactory::<JsBuiltins>::default();
+        let allocator = DefaultAllocator::default();
+        let env = Env::new().with_globals(builtin_globals(&factory, &allocator));
+        let path = Path::new("./foo.js");
+        let loader = static_module_loader(builtin_imports(&factory, &allocator));
+        for (source, expected) in [
+            (
+                "dateDiff(end, start)",
+                factory.create_duration_term(90 * 60 * 1000),
+            ),
+            (
+                "dateTruncate(end, 'day', '-08:00')",
+                factory.create_timestamp_term(1677830400000, 0),
+            ),
+            (
+                "dateTruncate(end, 'hour', 'Z')",
+                factory.create_timestamp_term(1677909600000, 0),
+            ),
+            ("gt(end, start)", factory.create_boolean_term(true)),
+            (
+                "gt(duration(1000), dateDiff(end, start))",
+                factory.create_boolean_term(false),
+            ),
+        ] {
+            let expression = parse_module(
+                &format!(
+                    "
+                    import {{ dateAdd, dateDiff, dateTruncate, duration, gt }} from 'reflex::core';
+                    const start = new Date('2023-03-04T05:06:07.089Z');
+                    const end = dateAdd(start, duration(90 * 60 * 1000));
+                    export default {};
+                    ",
+                    source
+                ),
+                &env,
+                path,
+                &loader,
+                &factory,
+                &allocator,
+            )
+            .unwrap();
+            let result = evaluate(
+                &expression,
+                &StateCache::default(),
+                &factory,
+                &allocator,
+                &mut SubstitutionCache::new(),
+            );
+            assert_eq!(
+                result,
+                EvaluationResult::new(expected, DependencyList::empty())
+            );
+        }
+    }
+
+    #[test]
+    fn date_arithmetic_errors() {
+        let factory = SharedTermFactory::<JsBuiltins>::default()
+            .with_numeric_semantics(NumericSemantics::Error);
+        let allocator = DefaultAllocator::default();
+        let env = Env::new().with_globals(builtin_globals(&factory, &allocator));
+        let path = Path::new("./foo.js");
+        let loader = static_module_loader(builtin_imports(&factory, &allocator));
+        let error = |message: &str| {
+            create_error_expression(
+                factory.create_string_term(allocator.create_string(message)),
+                &factory,
+                &allocator,
+            )
+        };
+        for (source, expected) in [
+            ("duration(1500.9)", factory.create_duration_term(1500)),
+            ("duration(-1500.9)", factory.create_duration_term(-1500)),
+            (
+                "duration(1e400)",
+                error("<stdlib:Duration>: Invalid Duration constructor: Expected Int or Float milliseconds, received inf"),
+            ),
+            (
+                "duration('foo')",
+                error("<stdlib:Duration>: Invalid Duration constructor: Expected Int or Float milliseconds, received \"foo\""),
+            ),
+            (
+                "dateAdd(start, 3)",
+                error("<stdlib:DateAdd>: Expected (Timestamp, Duration), received (<2023-03-04T05:06:07.089Z>, 3.0)"),
+            ),
+            (
+                "dateAdd(dateAdd(start, duration(9e18)), duration(9e18))",
+                error("<stdlib:DateAdd>: Timestamp overflow: 9000001677906367089 + 9000000000000000000ms"),
+            ),
+            (
+                "dateDiff(dateAdd(start, duration(9e18)), dateAdd(start, duration(-9e18)))",
+                error("<stdlib:DateDiff>: Duration overflow: 9000001677906367089 - -8999998322093632911"),
+            ),
+            (
+                "dateTruncate(start, 'week', 'Z')",
+                error("<stdlib:DateTruncate>: Invalid truncation unit: Expected \"day\" or \"hour\", received \"week\""),
+            ),
+            (
+                "dateTruncate(start, 'day', 'Europe/London')",
+                error("<stdlib:DateTruncate>: Invalid UTC offset: Expected \"Z\", \"UTC\" or \"±HH:MM\", received \"Europe/London\""),
+            ),
+            (
+                "dateTruncate(start, 'day', '+24:00')",
+                error("<stdlib:DateTruncate>: Invalid UTC offset: Expected \"Z\", \"UTC\" or \"±HH:MM\", received \"+24:00\""),
+            ),
+        ] {
+            let expression = parse_module(
+                &format!(
+                    "
+                    import {{ dateAdd, dateDiff, dateTruncate, duration }} from 'reflex::core';
+                    const start = new Date('2023-03-04T05:06:07.089Z');
+                    export default {};
+                    ",
+                    source
+                ),
+                &env,
+                path,
+                &loader,
+                &factory,
+                &allocator,
+            )
+            .unwrap();
+            let result = evaluate(
+                &expression,
+                &StateCache::default(),
+                &factory,
+                &allocator,
+                &mut SubstitutionCache::new(),
+            );
+            assert_eq!(
+                result,
+                EvaluationResult::new(expected, DependencyList::empty()),
+                "{}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn sorting() {
+        let factory = SharedTermFactory::<JsBuiltins>::default();
+        let allocator = DefaultAllocator::default();
+        let env = Env::new().with_globals(builtin_globals(&factory, &allocator));
+        let path = Path::new("./foo.js");
+        let loader = static_module_loader(builtin_imports(&factory, &allocator));
+        let floats = |values: &[f64]| {
+            factory.create_list_term(
+                allocator.create_list(values.iter().map(|value| factory.create_float_term(*value))),
+            )
+        };
+        let strings = |values: &[&str]| {
+            factory.create_list_term(
+                allocator.create_list(
+                    values
+                        .iter()
+                        .map(|value| factory.create_string_term(allocator.create_string(*value))),
+                ),
+            )
+        };
+        for (source, expected) in [
+            ("sort([])", floats(&[])),
+            (
+                "sort([3, 1, 4, 1, 5, 9, 2, 6])",
+                floats(&[1.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 9.0]),
+            ),
+            (
+                "sort(['foo', 'bar', 'baz'])",
+                strings(&["bar", "baz", "foo"]),
+            ),
+            (
+                "sort([2, 1].map((value) => value * 2))",
+                floats(&[2.0, 4.0]),
+            ),
+            (
+                "sortBy([3, 1, 4, 1, 5], (a, b) => b - a)",
+                floats(&[5.0, 4.0, 3.0, 1.0, 1.0]),
+            ),
+            (
+                "sortBy([21, 13, 11, 22, 12, 31], (a, b) => (a % 10) - (b % 10))",
+                floats(&[21.0, 11.0, 31.0, 22.0, 12.0, 13.0]),
+            ),
+            (
+                "sortBy(['ccc', 'a', 'bb', 'dd'], (a, b) => a.length - b.length)",
+                strings(&["a", "bb", "dd", "ccc"]),
+            ),
+        ] {
+            let expression = parse_module(
+                &format!(
+                    "
+                    import {{ sort, sortBy }} from 'reflex::core';
+                    export default {};
+                    ",
+                    source
+                ),
+                &env,
+                path,
+                &loader,
+                &factory,
+                &allocator,
+            )
+            .unwrap();
+            let result = evaluate(
+                &expression,
+                &StateCache::default(),
+                &factory,
+                &allocator,
+                &mut SubstitutionCache::new(),
+            );
+            assert_eq!(
+                result,
+                EvaluationResult::new(expected, DependencyList::empty()),
+                "{}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn date_offsets() {
+        let factory = SharedTermFactory::<JsBuiltins>::default();
+        let allocator = DefaultAllocator::default();
+        let env = Env::new().with_globals(builtin_globals(&factory, &allocator));
+        let path = Path::new("./foo.js");
+        let loader = static_module_loader(builtin_imports(&factory, &allocator));
+        for (source, expected) in [
+            ("start", factory.create_timestamp_term(1677906367089, 0)),
+            ("zoned", factory.create_timestamp_term(1677899167089, 120)),
+            (
+                "dateOffset(start)",
+                factory.create_string_term(allocator.create_static_string("Z")),
+            ),
+            (
+                "dateOffset(zoned)",
+                factory.create_string_term(allocator.create_static_string("+02:00")),
+            ),
+            (
+                "dateOffset(dateWithOffset(start, '-05:30'))",
+                factory.create_string_term(allocator.create_static_string("-05:30")),
+            ),
+            (
+                "dateWithOffset(zoned, 'UTC')",
+                factory.create_timestamp_term(1677899167089, 0),
+            ),
+            (
+                "dateAdd(zoned, duration(1000))",
+                factory.create_timestamp_term(1677899168089, 120),
+            ),
+            (
+                "dateDiff(zoned, dateWithOffset(zoned, 'Z'))",
+                factory.create_duration_term(0),
+            ),
+        ] {
+            let expression = parse_module(
+                &format!(
+                    "
+                    import {{ dateAdd, dateDiff, dateOffset, dateWithOffset, duration }} from 'reflex::core';
+                    const start = new Date('2023-03-04T05:06:07.089Z');
+                    const zoned = new Date('2023-03-04T05:06:07.089+02:00');
+                    export default {};
+                    ",
+                    source
+                ),
+                &env,
+                path,
+                &loader,
+                &factory,
+                &allocator,
+            )
+            .unwrap();
+            let result = evaluate(
+                &expression,
+                &StateCache::default(),
+                &factory,
+                &allocator,
+                &mut SubstitutionCache::new(),
+            );
+            assert_eq!(
+                result,
+                EvaluationResult::new(expected, DependencyList::empty()),
+                "{}",
+                source
+            );
+        }
+    }
+
     #[test]
     fn import_scoping() {
         let factory = SharedTermFactory::<JsBuiltins>::default();
@@ -5498,6 +6234,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn number_parsing_options() {
+        let factory = SharedTermFactory::<JsBuiltins>::default();
+        let allocator = DefaultAllocator::default();
+        let env = Env::new().with_globals(builtin_globals(&factory, &allocator));
+        let expression = parse(
+            "[
+                parseInt('ff', 16),
+                parseInt('12px', 10),
+                parseInt(' 12 ', null, { strict: true }),
+                isFinite(parseInt('12px', 10, { strict: true })),
+                parseFloat('3.5abc'),
+                isFinite(parseFloat('3.5abc', { strict: true })),
+            ]",
+            &env,
+            &factory,
+            &allocator,
+        )
+        .unwrap();
+        let result = evaluate(
+            &expression,
+            &StateCache::default(),
+            &factory,
+            &allocator,
+            &mut SubstitutionCache::new(),
+        );
+        assert_eq!(
+            result,
+            EvaluationResult::new(
+                factory.create_list_term(allocator.create_list([
+                    factory.create_int_term(255),
+                    factory.create_int_term(12),
+                    factory.create_int_term(12),
+                    factory.create_boolean_term(false),
+                    factory.create_float_term(3.5),
+                    factory.create_boolean_term(false),
+                ])),
+                DependencyList::empty(),
+            ),
+        );
+    }
+
     #[test]
     fn js_interpreted() {
         let factory = SharedTermFactory::<JsBuiltins>::default();
@@ -5558,4 +6336,29 @@ mod tests {
             ),
         );
     }
+
+    #[test]
+    fn source_metadata() {
+        let factory = SharedTermFactory::<JsBuiltins>::default();
+        let allocator = DefaultAllocator::default();
+        let recorder = SourceMetadataRecorder::default();
+        let env = Env::new().with_source_metadata(recorder.clone());
+        let input = "const double = (value) => value * 2;
+const utils = { triple: (value) => value * 3 };
+utils.triple(double(3))";
+        parse(input, &env, &factory, &allocator).unwrap();
+        let mut entries = recorder
+            .table()
+            .iter()
+            .map(|(_, metadata)| format!("{}", metadata))
+            .collect::<Vec<_>>();
+        entries.sort();
+        assert_eq!(
+            entries,
+            vec![
+                String::from("double at <anonymous>:1:16"),
+                String::from("triple at <anonymous>:2:25"),
+            ],
+        );
+    }
 }
@@ -0,0 +1,299 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::collections::HashSet;
+
+use swc_ecma_ast::{
+    BlockStmtOrExpr, Callee, Decl, Expr, ExprOrSpread, MemberProp, ObjectPatProp, Pat, Prop,
+    PropName, PropOrSpread, Stmt, VarDeclKind, VarDeclarator,
+};
+
+/// Remove any top-level module declarations that are not referenced (either directly or transitively) by the module's
+/// default export
+///
+/// Returns the pruned list of module body statements, along with the set of identifiers that are referenced by the
+/// remaining statements (this allows unreferenced import bindings to be omitted). If any of the module statements
+/// contain syntax that cannot be analyzed, the module body is returned unmodified and no identifier set is returned,
+/// indicating that all bindings must be retained.
+///
+/// The analysis is conservative: identifiers are matched by name without regard for shadowing, so a declaration will
+/// be retained if any identifier of the same name is referenced anywhere within the retained statements.
+pub(super) fn prune_unused_declarations(body: Vec<Stmt>) -> (Vec<Stmt>, Option<HashSet<String>>) {
+    // Declarations that follow the final non-declaration statement are retained as-is (these would otherwise be
+    // reported as unreachable code, so they must not be silently removed)
+    let num_prunable_statements = body
+        .iter()
+        .rposition(|statement| !is_const_declaration(statement))
+        .unwrap_or(body.len());
+    let mut references = HashSet::new();
+    for statement in body[num_prunable_statements..].iter() {
+        if collect_statement_references(statement, &mut references).is_none() {
+            return (body, None);
+        }
+    }
+    let mut retained = Vec::with_capacity(body.len());
+    for statement in body[..num_prunable_statements].iter().rev() {
+        match statement {
+            Stmt::Decl(Decl::Var(node)) if node.kind == VarDeclKind::Const => {
+                let mut declarators = Vec::with_capacity(node.decls.len());
+                for declarator in node.decls.iter().rev() {
+                    let bindings = collect_pattern_bindings(&declarator.name);
+                    let is_referenced = match &bindings {
+                        Some(bindings) => bindings
+                            .iter()
+                            .any(|identifier| references.contains(identifier)),
+                        None => true,
+                    };
+                    if !is_referenced {
+                        continue;
+                    }
+                    // Any references to these identifiers within the initializer refer to an outer binding
+                    for identifier in bindings.into_iter().flatten() {
+                        references.remove(&identifier);
+                    }
+                    if collect_declarator_references(declarator, &mut references).is_none() {
+                        return (body, None);
+                    }
+                    declarators.push(declarator.clone());
+                }
+                if !declarators.is_empty() {
+                    declarators.reverse();
+                    let mut node = node.clone();
+                    node.decls = declarators;
+                    retained.push(Stmt::Decl(Decl::Var(node)));
+                }
+            }
+            _ => {
+                if collect_statement_references(statement, &mut references).is_none() {
+                    return (body, None);
+                }
+                retained.push(statement.clone());
+            }
+        }
+    }
+    retained.reverse();
+    retained.extend(body[num_prunable_statements..].iter().cloned());
+    (retained, Some(references))
+}
+
+fn is_const_declaration(node: &Stmt) -> bool {
+    match node {
+        Stmt::Decl(Decl::Var(node)) => node.kind == VarDeclKind::Const,
+        _ => false,
+    }
+}
+
+fn collect_pattern_bindings(node: &Pat) -> Option<Vec<String>> {
+    let mut results = Vec::new();
+    collect_pattern_binding_identifiers(node, &mut results)?;
+    Some(results)
+}
+
+fn collect_pattern_binding_identifiers(node: &Pat, results: &mut Vec<String>) -> Option<()> {
+    match node {
+        Pat::Ident(node) => {
+            results.push(String::from(&*node.id.sym));
+            Some(())
+        }
+        Pat::Object(node) => node.props.iter().try_for_each(|prop| match prop {
+            ObjectPatProp::KeyValue(prop) => {
+                collect_pattern_binding_identifiers(&prop.value, results)
+            }
+            ObjectPatProp::Assign(prop) => {
+                results.push(String::from(&*prop.key.sym));
+                Some(())
+            }
+            ObjectPatProp::Rest(prop) => collect_pattern_binding_identifiers(&prop.arg, results),
+        }),
+        Pat::Array(node) => node
+            .elems
+            .iter()
+            .flatten()
+            .try_for_each(|node| collect_pattern_binding_identifiers(node, results)),
+        Pat::Rest(node) => collect_pattern_binding_identifiers(&node.arg, results),
+        Pat::Assign(node) => collect_pattern_binding_identifiers(&node.left, results),
+        _ => None,
+    }
+}
+
+fn collect_declarator_references(
+    node: &VarDeclarator,
+    results: &mut HashSet<String>,
+) -> Option<()> {
+    collect_pattern_references(&node.name, results)?;
+    match &node.init {
+        Some(init) => collect_expression_references(init, results),
+        None => Some(()),
+    }
+}
+
+fn collect_statements_references<'a>(
+    body: impl IntoIterator<Item = &'a Stmt>,
+    results: &mut HashSet<String>,
+) -> Option<()> {
+    body.into_iter()
+        .try_for_each(|node| collect_statement_references(node, results))
+}
+
+fn collect_statement_references(node: &Stmt, results: &mut HashSet<String>) -> Option<()> {
+    match node {
+        Stmt::Decl(Decl::Var(node)) => node
+            .decls
+            .iter()
+            .try_for_each(|node| collect_declarator_references(node, results)),
+        Stmt::Expr(node) => collect_expression_references(&node.expr, results),
+        Stmt::Return(node) => match &node.arg {
+            Some(arg) => collect_expression_references(arg, results),
+            None => Some(()),
+        },
+        Stmt::Throw(node) => collect_expression_references(&node.arg, results),
+        Stmt::If(node) => {
+            collect_expression_references(&node.test, results)?;
+            collect_statement_references(&node.cons, results)?;
+            match &node.alt {
+                Some(alt) => collect_statement_references(alt, results),
+                None => Some(()),
+            }
+        }
+        Stmt::Try(node) => {
+            collect_statements_references(&node.block.stmts, results)?;
+            if let Some(handler) = &node.handler {
+                if let Some(param) = &handler.param {
+                    collect_pattern_references(param, results)?;
+                }
+                collect_statements_references(&handler.body.stmts, results)?;
+            }
+            match &node.finalizer {
+                Some(finalizer) => collect_statements_references(&finalizer.stmts, results),
+                None => Some(()),
+            }
+        }
+        Stmt::Block(node) => collect_statements_references(&node.stmts, results),
+        Stmt::Empty(_) => Some(()),
+        _ => None,
+    }
+}
+
+fn collect_pattern_references(node: &Pat, results: &mut HashSet<String>) -> Option<()> {
+    match node {
+        Pat::Ident(_) => Some(()),
+        Pat::Object(node) => node.props.iter().try_for_each(|prop| match prop {
+            ObjectPatProp::KeyValue(prop) => {
+                collect_prop_name_references(&prop.key, results)?;
+                collect_pattern_references(&prop.value, results)
+            }
+            ObjectPatProp::Assign(prop) => match &prop.value {
+                Some(value) => collect_expression_references(value, results),
+                None => Some(()),
+            },
+            ObjectPatProp::Rest(prop) => collect_pattern_references(&prop.arg, results),
+        }),
+        Pat::Array(node) => node
+            .elems
+            .iter()
+            .flatten()
+            .try_for_each(|node| collect_pattern_references(node, results)),
+        Pat::Rest(node) => collect_pattern_references(&node.arg, results),
+        Pat::Assign(node) => {
+            collect_pattern_references(&node.left, results)?;
+            collect_expression_references(&node.right, results)
+        }
+        _ => None,
+    }
+}
+
+fn collect_prop_name_references(node: &PropName, results: &mut HashSet<String>) -> Option<()> {
+    match node {
+        PropName::Computed(key) => collect_expression_references(&key.expr, results),
+        _ => Some(()),
+    }
+}
+
+fn collect_args_references<'a>(
+    args: impl IntoIterator<Item = &'a ExprOrSpread>,
+    results: &mut HashSet<String>,
+) -> Option<()> {
+    args.into_iter()
+        .try_for_each(|arg| collect_expression_references(&arg.expr, results))
+}
+
+fn collect_expression_references(node: &Expr, results: &mut HashSet<String>) -> Option<()> {
+    match node {
+        Expr::Paren(node) => collect_expression_references(&node.expr, results),
+        Expr::Ident(node) => {
+            results.insert(String::from(&*node.sym));
+            Some(())
+        }
+        Expr::Lit(_) => Some(()),
+        Expr::Tpl(node) => node
+            .exprs
+            .iter()
+            .try_for_each(|node| collect_expression_references(node, results)),
+        Expr::TaggedTpl(node) => {
+            collect_expression_references(&node.tag, results)?;
+            node.tpl
+                .exprs
+                .iter()
+                .try_for_each(|node| collect_expression_references(node, results))
+        }
+        Expr::Unary(node) => collect_expression_references(&node.arg, results),
+        Expr::Bin(node) => {
+            collect_expression_references(&node.left, results)?;
+            collect_expression_references(&node.right, results)
+        }
+        Expr::Cond(node) => {
+            collect_expression_references(&node.test, results)?;
+            collect_expression_references(&node.cons, results)?;
+            collect_expression_references(&node.alt, results)
+        }
+        Expr::Arrow(node) => {
+            node.params
+                .iter()
+                .try_for_each(|node| collect_pattern_references(node, results))?;
+            match &node.body {
+                BlockStmtOrExpr::Expr(body) => collect_expression_references(body, results),
+                BlockStmtOrExpr::BlockStmt(body) => {
+                    collect_statements_references(&body.stmts, results)
+                }
+            }
+        }
+        Expr::Member(node) => {
+            collect_expression_references(&node.obj, results)?;
+            match &node.prop {
+                MemberProp::Computed(key) => collect_expression_references(&key.expr, results),
+                MemberProp::Ident(_) | MemberProp::PrivateName(_) => Some(()),
+            }
+        }
+        Expr::Call(node) => {
+            match &node.callee {
+                Callee::Expr(callee) => collect_expression_references(callee, results),
+                _ => None,
+            }?;
+            collect_args_references(&node.args, results)
+        }
+        Expr::New(node) => {
+            collect_expression_references(&node.callee, results)?;
+            collect_args_references(node.args.iter().flatten(), results)
+        }
+        Expr::Object(node) => node.props.iter().try_for_each(|prop| match prop {
+            PropOrSpread::Spread(node) => collect_expression_references(&node.expr, results),
+            PropOrSpread::Prop(prop) => match &**prop {
+                Prop::KeyValue(prop) => {
+                    collect_prop_name_references(&prop.key, results)?;
+                    collect_expression_references(&prop.value, results)
+                }
+                Prop::Shorthand(prop) => {
+                    results.insert(String::from(&*prop.sym));
+                    Some(())
+                }
+                _ => None,
+            },
+        }),
+        Expr::Array(node) => node
+            .elems
+            .iter()
+            .flatten()
+            .try_for_each(|node| collect_expression_references(&node.expr, results)),
+        _ => None,
+    }
+}
@@ -0,0 +1,75 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+pub use reflex::source_map::SourceLocation;
+use swc_common::BytePos;
+
+/// Stack of source files currently being parsed, used to resolve AST byte positions to human-readable locations
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SourceStack {
+    sources: Arc<Mutex<Vec<SourceFile>>>,
+}
+
+impl SourceStack {
+    pub(crate) fn enter_source(&self, path: Option<&Path>, input: &str, start_pos: BytePos) {
+        self.sources
+            .lock()
+            .unwrap()
+            .push(SourceFile::new(path, input, start_pos));
+    }
+    pub(crate) fn exit_source(&self) {
+        self.sources.lock().unwrap().pop();
+    }
+    pub(crate) fn locate(&self, position: BytePos) -> SourceLocation {
+        match self.sources.lock().unwrap().last() {
+            Some(source) => source.locate(position),
+            None => SourceLocation {
+                path: None,
+                line: 0,
+                column: 0,
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+struct SourceFile {
+    path: Option<PathBuf>,
+    start_pos: BytePos,
+    line_offsets: Vec<usize>,
+}
+
+impl SourceFile {
+    fn new(path: Option<&Path>, input: &str, start_pos: BytePos) -> Self {
+        Self {
+            path: path.map(|path| path.to_path_buf()),
+            start_pos,
+            line_offsets: std::iter::once(0)
+                .chain(
+                    input
+                        .bytes()
+                        .enumerate()
+                        .filter(|(_, byte)| *byte == b'\n')
+                        .map(|(offset, _)| offset + 1),
+                )
+                .collect(),
+        }
+    }
+    fn locate(&self, position: BytePos) -> SourceLocation {
+        let offset = position.0.saturating_sub(self.start_pos.0) as usize;
+        let line = self
+            .line_offsets
+            .partition_point(|line_offset| *line_offset <= offset)
+            .max(1);
+        SourceLocation {
+            path: self.path.clone(),
+            line,
+            column: offset - self.line_offsets[line - 1] + 1,
+        }
+    }
+}
@@ -2,9 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 // SPDX-FileContributor: Chris Campbell <c.campbell@mwam.com> https://github.com/c-campbell-mwam
-use reflex::core::{
-    Applicable, Arity, Builtin, EvaluationCache, Expression, ExpressionFactory, HeapAllocator, Uid,
-    Uuid,
+use reflex::{
+    cache::NoopCache,
+    core::{
+        Applicable, Arity, Builtin, EvaluationCache, Expression, ExpressionFactory, HeapAllocator,
+        Uid, Uuid,
+    },
 };
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
@@ -21,6 +24,7 @@ pub use log::*;
 pub use parse_date::*;
 pub use parse_float::*;
 pub use parse_int::*;
+pub use parse_number::*;
 pub use throw::*;
 pub use to_string::*;
 
@@ -34,6 +38,7 @@ mod log;
 mod parse_date;
 mod parse_float;
 mod parse_int;
+mod parse_number;
 mod throw;
 mod to_string;
 
@@ -50,9 +55,12 @@ pub enum Stdlib {
     IsTruthy,
     Log,
     LogArgs,
+    LogScope,
     ParseDate,
     ParseFloat,
     ParseInt,
+    ScopedLog,
+    ScopedLogArgs,
     Throw,
     ToString,
 }
@@ -72,9 +80,12 @@ impl Uid for Stdlib {
             Self::IsTruthy => Uid::uid(&IsTruthy {}),
             Self::Log => Uid::uid(&Log {}),
             Self::LogArgs => Uid::uid(&LogArgs {}),
+            Self::LogScope => Uid::uid(&LogScope {}),
             Self::ParseDate => Uid::uid(&ParseDate {}),
             Self::ParseFloat => Uid::uid(&ParseFloat {}),
             Self::ParseInt => Uid::uid(&ParseInt {}),
+            Self::ScopedLog => Uid::uid(&ScopedLog {}),
+            Self::ScopedLogArgs => Uid::uid(&ScopedLogArgs {}),
             Self::Throw => Uid::uid(&Throw {}),
             Self::ToString => Uid::uid(&ToString {}),
         }
@@ -92,9 +103,12 @@ impl TryFrom<Uuid> for Stdlib {
             IsTruthy::UUID => Ok(Self::IsTruthy),
             Log::UUID => Ok(Self::Log),
             LogArgs::UUID => Ok(Self::LogArgs),
+            LogScope::UUID => Ok(Self::LogScope),
             ParseDate::UUID => Ok(Self::ParseDate),
             ParseFloat::UUID => Ok(Self::ParseFloat),
             ParseInt::UUID => Ok(Self::ParseInt),
+            ScopedLog::UUID => Ok(Self::ScopedLog),
+            ScopedLogArgs::UUID => Ok(Self::ScopedLogArgs),
             Throw::UUID => Ok(Self::Throw),
             ToString::UUID => Ok(Self::ToString),
             _ => Err(()),
@@ -112,9 +126,12 @@ impl Stdlib {
             Self::IsTruthy => IsTruthy::arity(),
             Self::Log => Log::arity(),
             Self::LogArgs => LogArgs::arity(),
+            Self::LogScope => LogScope::arity(),
             Self::ParseDate => ParseDate::arity(),
             Self::ParseFloat => ParseFloat::arity(),
             Self::ParseInt => ParseInt::arity(),
+            Self::ScopedLog => ScopedLog::arity(),
+            Self::ScopedLogArgs => ScopedLogArgs::arity(),
             Self::Throw => Throw::arity(),
             Self::ToString => ToString::arity(),
         }
@@ -136,9 +153,12 @@ impl Stdlib {
             Self::IsTruthy => Applicable::<T>::should_parallelize(&IsTruthy, args),
             Self::Log => Applicable::<T>::should_parallelize(&Log, args),
             Self::LogArgs => Applicable::<T>::should_parallelize(&LogArgs, args),
+            Self::LogScope => Applicable::<T>::should_parallelize(&LogScope, args),
             Self::ParseDate => Applicable::<T>::should_parallelize(&ParseDate, args),
             Self::ParseFloat => Applicable::<T>::should_parallelize(&ParseFloat, args),
             Self::ParseInt => Applicable::<T>::should_parallelize(&ParseInt, args),
+            Self::ScopedLog => Applicable::<T>::should_parallelize(&ScopedLog, args),
+            Self::ScopedLogArgs => Applicable::<T>::should_parallelize(&ScopedLogArgs, args),
             Self::Throw => Applicable::<T>::should_parallelize(&Throw, args),
             Self::ToString => Applicable::<T>::should_parallelize(&ToString, args),
         }
@@ -166,15 +186,42 @@ impl Stdlib {
             Self::IsTruthy => Applicable::<T>::apply(&IsTruthy, args, factory, allocator, cache),
             Self::Log => Applicable::<T>::apply(&Log, args, factory, allocator, cache),
             Self::LogArgs => Applicable::<T>::apply(&LogArgs, args, factory, allocator, cache),
+            Self::LogScope => Applicable::<T>::apply(&LogScope, args, factory, allocator, cache),
             Self::ParseDate => Applicable::<T>::apply(&ParseDate, args, factory, allocator, cache),
             Self::ParseFloat => {
                 Applicable::<T>::apply(&ParseFloat, args, factory, allocator, cache)
             }
             Self::ParseInt => Applicable::<T>::apply(&ParseInt, args, factory, allocator, cache),
+            Self::ScopedLog => Applicable::<T>::apply(&ScopedLog, args, factory, allocator, cache),
+            Self::ScopedLogArgs => {
+                Applicable::<T>::apply(&ScopedLogArgs, args, factory, allocator, cache)
+            }
             Self::Throw => Applicable::<T>::apply(&Throw, args, factory, allocator, cache),
             Self::ToString => Applicable::<T>::apply(&ToString, args, factory, allocator, cache),
         }
     }
+    pub fn apply_static<T: Expression>(
+        &self,
+        args: &[T],
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+    ) -> Option<T> {
+        match self {
+            // Conditional expressions are wrapped in truthiness checks, so these must be applied statically in order
+            // to determine statically-known branch conditions
+            Self::IsTruthy if args.len() == 1 && args.iter().all(|arg| arg.is_atomic()) => {
+                Applicable::<T>::apply(
+                    &IsTruthy,
+                    args.iter().cloned(),
+                    factory,
+                    allocator,
+                    &mut NoopCache::default(),
+                )
+                .ok()
+            }
+            _ => None,
+        }
+    }
 }
 impl std::fmt::Display for Stdlib {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -222,6 +269,11 @@ impl From<LogArgs> for Stdlib {
         Self::LogArgs
     }
 }
+impl From<LogScope> for Stdlib {
+    fn from(_value: LogScope) -> Self {
+        Self::LogScope
+    }
+}
 impl From<ParseDate> for Stdlib {
     fn from(_value: ParseDate) -> Self {
         Self::ParseDate
@@ -237,6 +289,16 @@ impl From<ParseInt> for Stdlib {
         Self::ParseInt
     }
 }
+impl From<ScopedLog> for Stdlib {
+    fn from(_value: ScopedLog) -> Self {
+        Self::ScopedLog
+    }
+}
+impl From<ScopedLogArgs> for Stdlib {
+    fn from(_value: ScopedLogArgs) -> Self {
+        Self::ScopedLogArgs
+    }
+}
 impl From<Throw> for Stdlib {
     fn from(_value: Throw) -> Self {
         Self::Throw
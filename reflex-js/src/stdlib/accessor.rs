@@ -25,6 +25,7 @@ pub trait AccessorBuiltin:
     + From<stdlib::Keys>
     + From<stdlib::Length>
     + From<stdlib::Map>
+    + From<stdlib::Match>
     + From<stdlib::Multiply>
     + From<crate::stdlib::ParseInt>
     + From<stdlib::Push>
@@ -55,6 +56,7 @@ impl<T> AccessorBuiltin for T where
         + From<stdlib::Keys>
         + From<stdlib::Length>
         + From<stdlib::Map>
+        + From<stdlib::Match>
         + From<stdlib::Multiply>
         + From<crate::stdlib::ParseInt>
         + From<stdlib::Push>
@@ -131,6 +133,37 @@ where
     }
 }
 
+/// Create an accessor expression for the given target and key
+///
+/// String-literal keys on static record targets (e.g. `({ foo: 3 }).foo`, or a property chain on an imported module
+/// namespace) are resolved to the corresponding field value when the expression is created, so that the field lookup
+/// is performed once at compile time rather than on every evaluation. Any other combination of target and key is
+/// deferred to the runtime [`Accessor`] builtin.
+pub fn create_accessor_term<T: Expression>(
+    target: T,
+    key: T,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T
+where
+    T::Builtin: From<Accessor>,
+{
+    let static_value = if factory.match_string_term(&key).is_some() {
+        factory
+            .match_record_term(&target)
+            .and_then(|term| term.get(&key).map(|value| value.as_deref().clone()))
+    } else {
+        None
+    };
+    match static_value {
+        Some(value) => value,
+        None => factory.create_application_term(
+            factory.create_builtin_term(Accessor),
+            allocator.create_pair(target, key),
+        ),
+    }
+}
+
 fn get_record_property<T: Expression, TFactory: ExpressionFactory<T>>(
     term: &T::RecordTerm,
     _target: &T,
@@ -208,6 +241,7 @@ fn get_string_property<T: Expression, TFactory: ExpressionFactory<T>>(
 where
     T::Builtin: From<stdlib::EndsWith>
         + From<stdlib::Length>
+        + From<stdlib::Match>
         + From<stdlib::Replace>
         + From<stdlib::Slice>
         + From<stdlib::Split>
@@ -458,6 +492,7 @@ fn get_string_field<T: Expression, TFactory: ExpressionFactory<T>>(
 where
     T::Builtin: From<stdlib::EndsWith>
         + From<stdlib::Length>
+        + From<stdlib::Match>
         + From<stdlib::Replace>
         + From<stdlib::Slice>
         + From<stdlib::Split>
@@ -472,6 +507,10 @@ where
             factory.create_builtin_term(stdlib::Length),
             allocator.create_unit_list(target.clone()),
         )),
+        "match" => Some(factory.create_partial_application_term(
+            factory.create_builtin_term(stdlib::Match),
+            allocator.create_unit_list(target.clone()),
+        )),
         "replace" => Some(factory.create_partial_application_term(
             factory.create_builtin_term(stdlib::Replace),
             allocator.create_unit_list(target.clone()),
@@ -636,3 +675,110 @@ fn get_string_char(value: &str, index: usize) -> String {
         .map(String::from)
         .unwrap_or_default()
 }
+
+#[cfg(test)]
+mod tests {
+    use reflex::{
+        cache::SubstitutionCache,
+        core::{create_record, evaluate, DependencyList, EvaluationResult, StateCache},
+    };
+    use reflex_lang::{allocator::DefaultAllocator, SharedTermFactory};
+
+    use crate::builtins::JsBuiltins;
+
+    use super::*;
+
+    #[test]
+    fn static_record_fields() {
+        let factory = SharedTermFactory::<JsBuiltins>::default();
+        let allocator = DefaultAllocator::default();
+        let foo = factory.create_string_term(allocator.create_static_string("foo"));
+        let bar = factory.create_string_term(allocator.create_static_string("bar"));
+        let target = create_record(
+            [
+                (foo.clone(), factory.create_int_term(3)),
+                (
+                    bar.clone(),
+                    create_record(
+                        [(foo.clone(), factory.create_int_term(4))],
+                        &factory,
+                        &allocator,
+                    ),
+                ),
+            ],
+            &factory,
+            &allocator,
+        );
+        assert_eq!(
+            create_accessor_term(target.clone(), foo.clone(), &factory, &allocator),
+            factory.create_int_term(3),
+        );
+        assert_eq!(
+            create_accessor_term(
+                create_accessor_term(target.clone(), bar.clone(), &factory, &allocator),
+                foo.clone(),
+                &factory,
+                &allocator,
+            ),
+            factory.create_int_term(4),
+        );
+        let missing = factory.create_string_term(allocator.create_static_string("baz"));
+        assert_eq!(
+            create_accessor_term(target.clone(), missing.clone(), &factory, &allocator),
+            factory.create_application_term(
+                factory.create_builtin_term(Accessor),
+                allocator.create_pair(target.clone(), missing),
+            ),
+        );
+    }
+
+    #[test]
+    fn dynamic_accessors() {
+        let factory = SharedTermFactory::<JsBuiltins>::default();
+        let allocator = DefaultAllocator::default();
+        let foo = factory.create_string_term(allocator.create_static_string("foo"));
+        let length = factory.create_string_term(allocator.create_static_string("length"));
+        let target = factory.create_variable_term(0);
+        assert_eq!(
+            create_accessor_term(target.clone(), foo.clone(), &factory, &allocator),
+            factory.create_application_term(
+                factory.create_builtin_term(Accessor),
+                allocator.create_pair(target.clone(), foo.clone()),
+            ),
+        );
+        let target = factory.create_list_term(
+            allocator.create_list([factory.create_int_term(3), factory.create_int_term(4)]),
+        );
+        let expression = create_accessor_term(target.clone(), length.clone(), &factory, &allocator);
+        assert_eq!(
+            expression,
+            factory.create_application_term(
+                factory.create_builtin_term(Accessor),
+                allocator.create_pair(target.clone(), length),
+            ),
+        );
+        assert_eq!(
+            evaluate(
+                &expression,
+                &StateCache::default(),
+                &factory,
+                &allocator,
+                &mut SubstitutionCache::new(),
+            ),
+            EvaluationResult::new(factory.create_int_term(2), DependencyList::empty()),
+        );
+        let target = create_record(
+            [(factory.create_int_term(0), factory.create_int_term(3))],
+            &factory,
+            &allocator,
+        );
+        let index = factory.create_int_term(0);
+        assert_eq!(
+            create_accessor_term(target.clone(), index.clone(), &factory, &allocator),
+            factory.create_application_term(
+                factory.create_builtin_term(Accessor),
+                allocator.create_pair(target, index),
+            ),
+        );
+    }
+}
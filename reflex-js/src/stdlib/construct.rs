@@ -3,7 +3,8 @@
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 // SPDX-FileContributor: Chris Campbell <c.campbell@mwam.com> https://github.com/c-campbell-mwam
 use reflex::core::{
-    parse_record_values, uuid, Applicable, ArgType, Arity, ConstructorTermType, EvaluationCache,
+    create_error_expression, parse_record_values, uuid, validate_record_keys,
+    validate_record_length, Applicable, ArgType, Arity, ConstructorTermType, EvaluationCache,
     Expression, ExpressionFactory, ExpressionListType, FunctionArity, HeapAllocator, ListTermType,
     RecordTermType, RefType, StructPrototypeType, Uid, Uuid,
 };
@@ -44,6 +45,25 @@ impl<T: Expression> Applicable<T> for Construct {
         if let Some(constructor) = factory.match_constructor_term(&target) {
             let prototype = constructor.prototype();
             let properties = args.next().unwrap();
+            if factory.validate_constructors() {
+                let validation_result = if let Some(term) = factory.match_record_term(&properties) {
+                    validate_record_keys::<T>(prototype.as_deref(), term)
+                } else if let Some(term) = factory.match_list_term(&properties) {
+                    validate_record_length::<T>(prototype.as_deref(), term.items().as_deref().len())
+                } else {
+                    Ok(())
+                };
+                if let Err(err) = validation_result {
+                    return Ok(create_error_expression(
+                        factory.create_string_term(allocator.create_string(format!(
+                            "Invalid constructor call: {}: {}",
+                            target, err
+                        ))),
+                        factory,
+                        allocator,
+                    ));
+                }
+            }
             let result = if let Some(term) = factory.match_record_term(&properties) {
                 let parsed_values =
                     parse_record_values(prototype.as_deref(), &properties, factory, allocator);
@@ -1,17 +1,27 @@
 // SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
-use std::iter::once;
+use std::{iter::once, ops::Deref};
 
 use reflex::core::{
-    uuid, Applicable, ArgType, Arity, Builtin, EvaluationCache, Expression, ExpressionFactory,
-    FunctionArity, HeapAllocator, Uid, Uuid,
+    transform_expression_list, uuid, Applicable, ApplicationTermType, ArgType, Arity, Builtin,
+    BuiltinTermType, EvaluationCache, Expression, ExpressionFactory, ExpressionListType,
+    FunctionArity, HeapAllocator, LambdaTermType, LetTermType, ListTermType,
+    PartialApplicationTermType, RecordTermType, RefType, StringTermType, StringValue, Uid, Uuid,
 };
 use reflex_json::stringify;
 use reflex_stdlib::ResolveDeep;
 
-pub trait LogBuiltin: Builtin + From<LogArgs> + From<ResolveDeep> {}
-impl<T> LogBuiltin for T where T: Builtin + From<LogArgs> + From<ResolveDeep> {}
+use crate::log_sink::emit_log;
+
+pub trait LogBuiltin:
+    Builtin + From<LogArgs> + From<ResolveDeep> + From<ScopedLog> + From<ScopedLogArgs>
+{
+}
+impl<T> LogBuiltin for T where
+    T: Builtin + From<LogArgs> + From<ResolveDeep> + From<ScopedLog> + From<ScopedLogArgs>
+{
+}
 
 pub struct Log;
 impl Log {
@@ -93,17 +103,294 @@ impl<T: Expression> Applicable<T> for LogArgs {
     ) -> Result<T, String> {
         let mut args = args.into_iter();
         let expression = args.next().unwrap();
-        println!(
-            "{}",
+        emit_log(
+            Vec::new(),
             once(stringify_value(&expression))
                 .chain(args.map(|value| stringify_value(&value)))
                 .collect::<Vec<_>>()
-                .join(" ")
+                .join(" "),
         );
         Ok(expression)
     }
 }
 
+/// Evaluate the provided zero-argument function body, labelling any log emissions within the function body
+///
+/// Any references to the `Log` builtin within the lexical subtree of the function body are replaced with a scoped log
+/// function that prefixes its output with the scope label. Nested scopes accumulate their labels, outermost first.
+pub struct LogScope;
+impl LogScope {
+    pub const UUID: Uuid = uuid!("1f0b3a4e-6c2d-4f5a-9e8b-7d6c5b4a3f21");
+    const ARITY: FunctionArity<2, 0> = FunctionArity {
+        required: [ArgType::Strict, ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for LogScope {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for LogScope
+where
+    T::Builtin: LogBuiltin,
+{
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let label = args.next().unwrap();
+        let body = args.next().unwrap();
+        let body = scope_log_calls(&body, &label, factory, allocator).unwrap_or(body);
+        Ok(factory.create_application_term(body, allocator.create_empty_list()))
+    }
+}
+
+pub struct ScopedLog;
+impl ScopedLog {
+    pub const UUID: Uuid = uuid!("8a4d2c6e-3b1f-4e7a-a9c5-2f6e8d0b4c73");
+    const ARITY: FunctionArity<2, 0> = FunctionArity {
+        required: [ArgType::Strict, ArgType::Eager],
+        optional: [],
+        variadic: Some(ArgType::Eager),
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for ScopedLog {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for ScopedLog
+where
+    T::Builtin: LogBuiltin,
+{
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let labels = args.next().unwrap();
+        Ok(factory.create_application_term(
+            factory.create_builtin_term(ScopedLogArgs),
+            allocator.create_list(
+                once(labels)
+                    .chain(args.map(|arg| {
+                        factory.create_application_term(
+                            factory.create_builtin_term(ResolveDeep),
+                            allocator.create_unit_list(arg),
+                        )
+                    }))
+                    .collect::<Vec<_>>(),
+            ),
+        ))
+    }
+}
+
+pub struct ScopedLogArgs;
+impl ScopedLogArgs {
+    pub const UUID: Uuid = uuid!("c5e7f9a1-4d2b-4c8e-b6a3-9e1d7f5c2b84");
+    const ARITY: FunctionArity<2, 0> = FunctionArity {
+        required: [ArgType::Strict, ArgType::Eager],
+        optional: [],
+        variadic: Some(ArgType::Eager),
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for ScopedLogArgs {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for ScopedLogArgs {
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        _allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let labels = args.next().unwrap();
+        let expression = args.next().unwrap();
+        let labels = match factory.match_list_term(&labels) {
+            Some(term) => term
+                .items()
+                .as_deref()
+                .iter()
+                .map(|label| stringify_label(label.as_deref(), factory))
+                .collect::<Vec<_>>(),
+            None => vec![stringify_label(&labels, factory)],
+        };
+        emit_log(
+            labels,
+            once(stringify_value(&expression))
+                .chain(args.map(|value| stringify_value(&value)))
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+        Ok(expression)
+    }
+}
+
+fn scope_log_calls<T: Expression>(
+    expression: &T,
+    label: &T,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> Option<T>
+where
+    T::Builtin: LogBuiltin,
+{
+    if is_builtin_term(expression, Log::UUID, factory) {
+        Some(factory.create_partial_application_term(
+            factory.create_builtin_term(ScopedLog),
+            allocator.create_unit_list(
+                factory.create_list_term(allocator.create_unit_list(label.clone())),
+            ),
+        ))
+    } else if let Some(term) = factory.match_partial_application_term(expression) {
+        let target = term.target();
+        let target = target.as_deref();
+        let args = term.args();
+        let args = args.as_deref();
+        // Nested scopes append their label to the labels of the enclosing scope
+        let existing_labels = if is_builtin_term(target, ScopedLog::UUID, factory) {
+            args.iter().next().map(|arg| arg.as_deref().clone())
+        } else {
+            None
+        };
+        if let Some(existing_labels) = existing_labels
+            .as_ref()
+            .and_then(|labels| factory.match_list_term(labels))
+        {
+            let labels = existing_labels
+                .items()
+                .as_deref()
+                .iter()
+                .map(|label| label.as_deref().clone())
+                .chain(once(label.clone()))
+                .collect::<Vec<_>>();
+            return Some(
+                factory.create_partial_application_term(
+                    target.clone(),
+                    allocator.create_list(
+                        once(factory.create_list_term(allocator.create_list(labels)))
+                            .chain(args.iter().skip(1).map(|arg| arg.as_deref().clone()))
+                            .collect::<Vec<_>>(),
+                    ),
+                ),
+            );
+        }
+        let scoped_target = scope_log_calls(target, label, factory, allocator);
+        let scoped_args = transform_expression_list(args, allocator, |arg| {
+            scope_log_calls(arg, label, factory, allocator)
+        });
+        if scoped_target.is_none() && scoped_args.is_none() {
+            return None;
+        }
+        Some(factory.create_partial_application_term(
+            scoped_target.unwrap_or_else(|| target.clone()),
+            scoped_args.unwrap_or_else(|| args.clone()),
+        ))
+    } else if let Some(term) = factory.match_application_term(expression) {
+        let target = term.target();
+        let target = target.as_deref();
+        let args = term.args();
+        let args = args.as_deref();
+        let scoped_target = scope_log_calls(target, label, factory, allocator);
+        let scoped_args = transform_expression_list(args, allocator, |arg| {
+            scope_log_calls(arg, label, factory, allocator)
+        });
+        if scoped_target.is_none() && scoped_args.is_none() {
+            return None;
+        }
+        Some(factory.create_application_term(
+            scoped_target.unwrap_or_else(|| target.clone()),
+            scoped_args.unwrap_or_else(|| args.clone()),
+        ))
+    } else if let Some(term) = factory.match_let_term(expression) {
+        let initializer = term.initializer();
+        let initializer = initializer.as_deref();
+        let body = term.body();
+        let body = body.as_deref();
+        let scoped_initializer = scope_log_calls(initializer, label, factory, allocator);
+        let scoped_body = scope_log_calls(body, label, factory, allocator);
+        if scoped_initializer.is_none() && scoped_body.is_none() {
+            return None;
+        }
+        Some(factory.create_let_term(
+            scoped_initializer.unwrap_or_else(|| initializer.clone()),
+            scoped_body.unwrap_or_else(|| body.clone()),
+        ))
+    } else if let Some(term) = factory.match_lambda_term(expression) {
+        scope_log_calls(term.body().as_deref(), label, factory, allocator)
+            .map(|body| factory.create_lambda_term(term.num_args(), body))
+    } else if let Some(term) = factory.match_list_term(expression) {
+        transform_expression_list(term.items().as_deref(), allocator, |item| {
+            scope_log_calls(item, label, factory, allocator)
+        })
+        .map(|items| factory.create_list_term(items))
+    } else if let Some(term) = factory.match_record_term(expression) {
+        transform_expression_list(term.values().as_deref(), allocator, |value| {
+            scope_log_calls(value, label, factory, allocator)
+        })
+        .map(|values| {
+            factory.create_record_term(allocator.clone_struct_prototype(term.prototype()), values)
+        })
+    } else {
+        None
+    }
+}
+
+fn is_builtin_term<T: Expression>(
+    expression: &T,
+    uid: Uuid,
+    factory: &impl ExpressionFactory<T>,
+) -> bool {
+    factory
+        .match_builtin_term(expression)
+        .map(|term| term.target().uid() == uid)
+        .unwrap_or(false)
+}
+
+fn stringify_label<T: Expression>(label: &T, factory: &impl ExpressionFactory<T>) -> String {
+    match factory.match_string_term(label) {
+        Some(term) => String::from(term.value().as_deref().as_str().deref()),
+        None => stringify_value(label),
+    }
+}
+
 fn stringify_value<T: Expression>(expression: &T) -> String {
     match stringify(expression) {
         Ok(result) => {
@@ -7,7 +7,7 @@ use chrono::{DateTime, NaiveDateTime};
 use reflex::core::{
     uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
     FloatTermType, FunctionArity, HeapAllocator, IntTermType, RefType, StringTermType, StringValue,
-    TimestampValue, Uid, Uuid,
+    TimestampValue, TimezoneOffset, Uid, Uuid,
 };
 
 pub struct ParseDate;
@@ -46,17 +46,17 @@ impl<T: Expression> Applicable<T> for ParseDate {
         if let Some(_) = factory.match_timestamp_term(&value) {
             Ok(value)
         } else {
-            let millis = if let Some(term) = factory.match_int_term(&value) {
-                Some(term.value())
+            let timestamp = if let Some(term) = factory.match_int_term(&value) {
+                Some((term.value(), 0))
             } else if let Some(term) = factory.match_float_term(&value) {
-                Some(term.value().trunc() as i64)
+                Some((term.value().trunc() as i64, 0))
             } else if let Some(term) = factory.match_string_term(&value) {
                 parse_string_timestamp(term.value().as_deref().as_str().deref())
             } else {
                 None
             };
-            if let Some(millis) = millis {
-                Ok(factory.create_timestamp_term(millis))
+            if let Some((millis, offset)) = timestamp {
+                Ok(factory.create_timestamp_term(millis, offset))
             } else {
                 Err(format!(
                     "Invalid Date constructor: Expected Int or Float or ISO-8601 String, received {}",
@@ -67,15 +67,19 @@ impl<T: Expression> Applicable<T> for ParseDate {
     }
 }
 
-fn parse_string_timestamp(timestamp: &str) -> Option<TimestampValue> {
+/// Parse an ISO-8601 timestamp, retaining any explicit UTC offset (timestamps without an offset are assumed to be UTC)
+fn parse_string_timestamp(timestamp: &str) -> Option<(TimestampValue, TimezoneOffset)> {
     None.or_else(|| {
-        DateTime::parse_from_rfc3339(timestamp)
-            .ok()
-            .map(|date| date.timestamp_millis())
+        DateTime::parse_from_rfc3339(timestamp).ok().map(|date| {
+            (
+                date.timestamp_millis(),
+                date.offset().local_minus_utc() / 60,
+            )
+        })
     })
     .or_else(|| {
         NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%dT%H:%M:%S%.f")
             .ok()
-            .map(|date| date.timestamp_millis())
+            .map(|date| (date.timestamp_millis(), 0))
     })
 }
@@ -1,17 +1,27 @@
 // SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::ops::Deref;
+
 use reflex::core::{
     uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
-    FunctionArity, HeapAllocator, IntTermType, Uid, Uuid,
+    FunctionArity, HeapAllocator, IntTermType, RefType, StringTermType, StringValue, Uid, Uuid,
 };
 
+use crate::stdlib::{parse_js_float, parse_number_options_arg};
+
+/// Convert the given value to a float, following the semantics of the JavaScript `parseFloat()` global function when
+/// parsing strings
+///
+/// Strings that do not contain a valid decimal number prefix are converted to `NaN`. When parsing strings, an optional
+/// options record argument can enable strict parsing via its `strict` field (where inputs with trailing non-whitespace
+/// characters are converted to `NaN`).
 pub struct ParseFloat;
 impl ParseFloat {
     pub const UUID: Uuid = uuid!("55e1f68f-e20f-4fab-a6f3-3e38b3dbd6ad");
-    const ARITY: FunctionArity<1, 0> = FunctionArity {
+    const ARITY: FunctionArity<1, 1> = FunctionArity {
         required: [ArgType::Strict],
-        optional: [],
+        optional: [ArgType::Strict],
         variadic: None,
     };
     pub fn arity() -> Arity {
@@ -34,20 +44,27 @@ impl<T: Expression> Applicable<T> for ParseFloat {
         &self,
         args: impl ExactSizeIterator<Item = T>,
         factory: &impl ExpressionFactory<T>,
-        _allocator: &impl HeapAllocator<T>,
+        allocator: &impl HeapAllocator<T>,
         _cache: &mut impl EvaluationCache<T>,
     ) -> Result<T, String> {
         let mut args = args.into_iter();
         let value = args.next().unwrap();
-        match factory.match_float_term(&value) {
-            Some(_) => Ok(value),
-            _ => match factory.match_int_term(&value) {
-                Some(term) => Ok(factory.create_float_term(term.value() as f64)),
-                _ => Err(format!(
-                    "Invalid float conversion: Expected Int or Float, received {}",
-                    value,
-                )),
-            },
+        let options = args.next();
+        if factory.match_float_term(&value).is_some() {
+            Ok(value)
+        } else if let Some(term) = factory.match_int_term(&value) {
+            Ok(factory.create_float_term(term.value() as f64))
+        } else if let Some(term) = factory.match_string_term(&value) {
+            let options = parse_number_options_arg(options.as_ref(), factory, allocator)?;
+            Ok(factory.create_float_term(parse_js_float(
+                term.value().as_deref().as_str().deref(),
+                options,
+            )))
+        } else {
+            Err(format!(
+                "Invalid float conversion: Expected Int or Float or String, received {}",
+                value,
+            ))
         }
     }
 }
@@ -1,17 +1,28 @@
 // SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::ops::Deref;
+
 use reflex::core::{
     uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
-    FloatTermType, FunctionArity, HeapAllocator, IntValue, TimestampTermType, Uid, Uuid,
+    FloatTermType, FunctionArity, HeapAllocator, IntValue, RefType, StringTermType, StringValue,
+    TimestampTermType, Uid, Uuid,
 };
 
+use crate::stdlib::{js_number_to_int, parse_js_int, parse_number_options_arg, parse_radix_arg};
+
+/// Convert the given value to an integer, following the semantics of the JavaScript `parseInt()` global function when
+/// parsing strings
+///
+/// Strings that do not contain a valid integer prefix are converted to `NaN`. When parsing strings, an optional radix
+/// argument selects the numeric base (as per `parseInt()`), and an optional options record argument can enable strict
+/// parsing via its `strict` field (where inputs with trailing non-whitespace characters are converted to `NaN`).
 pub struct ParseInt;
 impl ParseInt {
     pub const UUID: Uuid = uuid!("62e9e98a-6f04-46d6-b97e-60aa5bf505e4");
-    const ARITY: FunctionArity<1, 0> = FunctionArity {
+    const ARITY: FunctionArity<1, 2> = FunctionArity {
         required: [ArgType::Strict],
-        optional: [],
+        optional: [ArgType::Strict, ArgType::Strict],
         variadic: None,
     };
     pub fn arity() -> Arity {
@@ -34,20 +45,30 @@ impl<T: Expression> Applicable<T> for ParseInt {
         &self,
         args: impl ExactSizeIterator<Item = T>,
         factory: &impl ExpressionFactory<T>,
-        _allocator: &impl HeapAllocator<T>,
+        allocator: &impl HeapAllocator<T>,
         _cache: &mut impl EvaluationCache<T>,
     ) -> Result<T, String> {
         let mut args = args.into_iter();
         let value = args.next().unwrap();
+        let radix = args.next();
+        let options = args.next();
         if let Some(_) = factory.match_int_term(&value) {
             Ok(value)
         } else if let Some(term) = factory.match_float_term(&value) {
             Ok(factory.create_int_term(term.value() as IntValue))
         } else if let Some(term) = factory.match_timestamp_term(&value) {
             Ok(factory.create_int_term(term.millis() as IntValue))
+        } else if let Some(term) = factory.match_string_term(&value) {
+            let radix = parse_radix_arg(radix.as_ref(), factory)?;
+            let options = parse_number_options_arg(options.as_ref(), factory, allocator)?;
+            let value = parse_js_int(term.value().as_deref().as_str().deref(), radix, options);
+            match js_number_to_int(value) {
+                Some(value) => Ok(factory.create_int_term(value)),
+                None => Ok(factory.create_float_term(value)),
+            }
         } else {
             Err(format!(
-                "Invalid integer conversion: Expected Float or Int or Date, received {}",
+                "Invalid integer conversion: Expected Float or Int or Date or String, received {}",
                 value,
             ))
         }
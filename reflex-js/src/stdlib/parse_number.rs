@@ -0,0 +1,402 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{
+    BooleanTermType, Expression, ExpressionFactory, FloatTermType, FloatValue, HeapAllocator,
+    IntTermType, IntValue, RecordTermType, RefType,
+};
+
+/// Options that control how number strings are parsed
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct NumberParseOptions {
+    /// Reject inputs that contain trailing characters after the parsed number (trailing whitespace is still permitted)
+    ///
+    /// By default, parsing follows JavaScript semantics where the longest valid prefix of the input is parsed and any
+    /// remaining characters are ignored (e.g. `parseInt("12px")` returns `12`).
+    pub strict: bool,
+}
+
+impl NumberParseOptions {
+    pub fn strict() -> Self {
+        Self { strict: true }
+    }
+}
+
+/// Parse an integer string according to the semantics of the JavaScript `parseInt()` global function
+///
+/// Leading whitespace is ignored, an optional sign is permitted, and a `0x`/`0X` prefix selects base 16 when no radix
+/// is specified (or when the radix is 16). A radix of `None` or `Some(0)` is treated as base 10. Invalid radixes and
+/// inputs with no leading digits return `NaN`.
+pub fn parse_js_int(input: &str, radix: Option<u32>, options: NumberParseOptions) -> FloatValue {
+    let input = input.trim_start_matches(is_js_whitespace);
+    let (sign, input) = parse_sign(input);
+    let (radix, strip_prefix) = match radix.unwrap_or(0) {
+        0 => (10, true),
+        16 => (16, true),
+        radix if (2..=36).contains(&radix) => (radix, false),
+        _ => return FloatValue::NAN,
+    };
+    let (radix, input) = match input
+        .strip_prefix("0x")
+        .or_else(|| input.strip_prefix("0X"))
+    {
+        Some(remaining) if strip_prefix => (16, remaining),
+        _ => (radix, input),
+    };
+    let num_digits = input
+        .find(|char: char| !char.is_digit(radix))
+        .unwrap_or(input.len());
+    let (digits, remaining) = input.split_at(num_digits);
+    if digits.is_empty() || (options.strict && !is_trailing_whitespace(remaining)) {
+        return FloatValue::NAN;
+    }
+    let value = if radix == 10 {
+        // Delegate to the standard library parser to ensure large decimal values are correctly rounded
+        digits.parse::<FloatValue>().unwrap_or(FloatValue::NAN)
+    } else {
+        let mut value: FloatValue = 0.0;
+        for char in digits.chars() {
+            value = value * (radix as FloatValue) + (char.to_digit(radix).unwrap() as FloatValue);
+        }
+        value
+    };
+    sign * value
+}
+
+/// Parse a decimal number string according to the semantics of the JavaScript `parseFloat()` global function
+///
+/// Leading whitespace is ignored, and the longest prefix of the input that forms a valid decimal literal (optionally
+/// signed, with optional fractional and exponent parts, or the literal `Infinity`) is parsed. Inputs with no valid
+/// prefix return `NaN`.
+pub fn parse_js_float(input: &str, options: NumberParseOptions) -> FloatValue {
+    let input = input.trim_start_matches(is_js_whitespace);
+    let (sign, input) = parse_sign(input);
+    let (value, remaining) = if let Some(remaining) = input.strip_prefix("Infinity") {
+        (FloatValue::INFINITY, remaining)
+    } else {
+        let length = decimal_literal_length(input);
+        if length == 0 {
+            return FloatValue::NAN;
+        }
+        let (literal, remaining) = input.split_at(length);
+        (
+            literal.parse::<FloatValue>().unwrap_or(FloatValue::NAN),
+            remaining,
+        )
+    };
+    if options.strict && !is_trailing_whitespace(remaining) {
+        return FloatValue::NAN;
+    }
+    sign * value
+}
+
+/// Parse the optional radix argument passed to the `ParseInt` builtin
+///
+/// Omitted and `null` radixes (as well as non-finite numbers) fall back to the default radix detection, while numeric
+/// radixes are truncated towards zero as per JavaScript semantics (radixes outside the valid range result in `NaN`).
+pub(crate) fn parse_radix_arg<T: Expression>(
+    value: Option<&T>,
+    factory: &impl ExpressionFactory<T>,
+) -> Result<Option<u32>, String> {
+    let value = match value {
+        None => return Ok(None),
+        Some(value) => value,
+    };
+    if factory.match_nil_term(value).is_some() {
+        Ok(None)
+    } else if let Some(term) = factory.match_int_term(value) {
+        Ok(Some(parse_radix_value(term.value())))
+    } else if let Some(term) = factory.match_float_term(value) {
+        let value = term.value();
+        if value.is_finite() {
+            Ok(Some(parse_radix_value(value.trunc() as IntValue)))
+        } else {
+            Ok(None)
+        }
+    } else {
+        Err(format!(
+            "Invalid radix: Expected Int or Float or null, received {}",
+            value
+        ))
+    }
+}
+
+fn parse_radix_value(value: IntValue) -> u32 {
+    // Out-of-range values are mapped to an invalid radix rather than being wrapped into the valid range
+    u32::try_from(value).unwrap_or(u32::MAX)
+}
+
+/// Parse the optional options record argument passed to the `ParseInt` and `ParseFloat` builtins
+///
+/// Omitted and `null` options fall back to the default options, and omitted record fields fall back to their defaults.
+pub(crate) fn parse_number_options_arg<T: Expression>(
+    value: Option<&T>,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> Result<NumberParseOptions, String> {
+    let value = match value {
+        None => return Ok(NumberParseOptions::default()),
+        Some(value) => value,
+    };
+    if factory.match_nil_term(value).is_some() {
+        Ok(NumberParseOptions::default())
+    } else if let Some(term) = factory.match_record_term(value) {
+        let strict =
+            match term.get(&factory.create_string_term(allocator.create_static_string("strict"))) {
+                None => false,
+                Some(strict) => {
+                    let strict = strict.as_deref();
+                    if factory.match_nil_term(strict).is_some() {
+                        false
+                    } else if let Some(strict) = factory.match_boolean_term(strict) {
+                        strict.value()
+                    } else {
+                        return Err(format!(
+                            "Invalid strict option: Expected Boolean, received {}",
+                            strict
+                        ));
+                    }
+                }
+            };
+        Ok(NumberParseOptions { strict })
+    } else {
+        Err(format!(
+            "Invalid parse options: Expected Record or null, received {}",
+            value
+        ))
+    }
+}
+
+/// Convert a parsed JavaScript number to an integer value, if it can be represented exactly
+pub fn js_number_to_int(value: FloatValue) -> Option<IntValue> {
+    // Integers beyond the safe integer range cannot be represented exactly as JavaScript numbers
+    const MAX_SAFE_INTEGER: FloatValue = 9007199254740991.0;
+    if value.is_finite() && value.trunc() == value && value.abs() <= MAX_SAFE_INTEGER {
+        Some(value as IntValue)
+    } else {
+        None
+    }
+}
+
+/// Determine whether a character is classed as whitespace by the JavaScript `StrWhiteSpaceChar` grammar production
+fn is_js_whitespace(char: char) -> bool {
+    matches!(
+        char,
+        // WhiteSpace
+        '\u{0009}' | '\u{000B}' | '\u{000C}' | '\u{0020}' | '\u{00A0}' | '\u{FEFF}'
+        // LineTerminator
+        | '\u{000A}' | '\u{000D}' | '\u{2028}' | '\u{2029}'
+        // Unicode space separators
+        | '\u{1680}' | '\u{2000}'..='\u{200A}' | '\u{202F}' | '\u{205F}' | '\u{3000}'
+    )
+}
+
+fn is_trailing_whitespace(input: &str) -> bool {
+    input.chars().all(is_js_whitespace)
+}
+
+fn parse_sign(input: &str) -> (FloatValue, &str) {
+    if let Some(remaining) = input.strip_prefix('-') {
+        (-1.0, remaining)
+    } else if let Some(remaining) = input.strip_prefix('+') {
+        (1.0, remaining)
+    } else {
+        (1.0, input)
+    }
+}
+
+/// Determine the length of the longest prefix of the input that forms an unsigned decimal literal
+fn decimal_literal_length(input: &str) -> usize {
+    let bytes = input.as_bytes();
+    let integer_digits = count_digits(bytes);
+    let mut length = integer_digits;
+    let fraction_digits = match bytes.get(length) {
+        Some(b'.') => {
+            let fraction_digits = count_digits(&bytes[(length + 1)..]);
+            if integer_digits > 0 || fraction_digits > 0 {
+                length += 1 + fraction_digits;
+            }
+            fraction_digits
+        }
+        _ => 0,
+    };
+    if integer_digits == 0 && fraction_digits == 0 {
+        return 0;
+    }
+    if let Some(b'e' | b'E') = bytes.get(length) {
+        let sign_length = match bytes.get(length + 1) {
+            Some(b'+' | b'-') => 1,
+            _ => 0,
+        };
+        let exponent_digits = count_digits(&bytes[(length + 1 + sign_length)..]);
+        if exponent_digits > 0 {
+            length += 1 + sign_length + exponent_digits;
+        }
+    }
+    length
+}
+
+fn count_digits(bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_number_eq(actual: FloatValue, expected: FloatValue) {
+        if expected.is_nan() {
+            assert!(actual.is_nan(), "Expected NaN, received {}", actual);
+        } else {
+            assert_eq!(actual, expected);
+            assert_eq!(actual.is_sign_negative(), expected.is_sign_negative());
+        }
+    }
+
+    #[test]
+    fn parse_int_conformance() {
+        let cases: &[(&str, Option<u32>, FloatValue)] = &[
+            ("0", None, 0.0),
+            ("3", None, 3.0),
+            ("-3", None, -3.0),
+            ("+3", None, 3.0),
+            ("-0", None, -0.0),
+            ("  42", None, 42.0),
+            (
+                "\t\n\r\u{000B}\u{000C}\u{00A0}\u{FEFF}\u{2028}\u{3000}42",
+                None,
+                42.0,
+            ),
+            ("42  ", None, 42.0),
+            ("12px", None, 12.0),
+            ("3.142", None, 3.0),
+            ("1e3", None, 1.0),
+            ("007", None, 7.0),
+            ("0x1F", None, 31.0),
+            ("0X1f", None, 31.0),
+            ("-0x1F", None, -31.0),
+            ("0x1F", Some(16), 31.0),
+            ("0x1F", Some(10), 0.0),
+            ("0x", None, FloatValue::NAN),
+            ("0b101", None, 0.0),
+            ("101", Some(2), 5.0),
+            ("1012", Some(2), 5.0),
+            ("777", Some(8), 511.0),
+            ("zz", Some(36), 1295.0),
+            ("ZZ", Some(36), 1295.0),
+            ("42", Some(0), 42.0),
+            ("42", Some(1), FloatValue::NAN),
+            ("42", Some(37), FloatValue::NAN),
+            ("", None, FloatValue::NAN),
+            ("   ", None, FloatValue::NAN),
+            ("-", None, FloatValue::NAN),
+            ("- 3", None, FloatValue::NAN),
+            ("abc", None, FloatValue::NAN),
+            ("Infinity", None, FloatValue::NAN),
+            ("\u{0085}1", None, FloatValue::NAN),
+            ("900719925474099267", None, 900719925474099300.0),
+            ("1_000", None, 1.0),
+        ];
+        for (input, radix, expected) in cases.iter().copied() {
+            assert_number_eq(
+                parse_js_int(input, radix, NumberParseOptions::default()),
+                expected,
+            );
+        }
+    }
+
+    #[test]
+    fn parse_int_strict() {
+        let cases: &[(&str, Option<u32>, FloatValue)] = &[
+            ("42", None, 42.0),
+            ("  -42  ", None, -42.0),
+            ("0x1F", None, 31.0),
+            ("12px", None, FloatValue::NAN),
+            ("3.142", None, FloatValue::NAN),
+            ("1012", Some(2), FloatValue::NAN),
+            ("", None, FloatValue::NAN),
+        ];
+        for (input, radix, expected) in cases.iter().copied() {
+            assert_number_eq(
+                parse_js_int(input, radix, NumberParseOptions::strict()),
+                expected,
+            );
+        }
+    }
+
+    #[test]
+    fn parse_float_conformance() {
+        let cases: &[(&str, FloatValue)] = &[
+            ("0", 0.0),
+            ("3.142", 3.142),
+            ("-3.142", -3.142),
+            ("+3.142", 3.142),
+            ("-0", -0.0),
+            ("  3.142", 3.142),
+            ("\u{00A0}\u{FEFF}3.142", 3.142),
+            ("3.142  ", 3.142),
+            ("3.142abc", 3.142),
+            ("3.", 3.0),
+            (".5", 0.5),
+            ("-.5", -0.5),
+            ("1e3", 1000.0),
+            ("1E3", 1000.0),
+            ("1e+3", 1000.0),
+            ("1.5e-3", 0.0015),
+            ("1e", 1.0),
+            ("1e+", 1.0),
+            ("1.2.3", 1.2),
+            ("0x1F", 0.0),
+            ("Infinity", FloatValue::INFINITY),
+            ("-Infinity", FloatValue::NEG_INFINITY),
+            ("Infinityx", FloatValue::INFINITY),
+            ("infinity", FloatValue::NAN),
+            ("inf", FloatValue::NAN),
+            ("NaN", FloatValue::NAN),
+            ("", FloatValue::NAN),
+            (".", FloatValue::NAN),
+            ("-.", FloatValue::NAN),
+            ("e3", FloatValue::NAN),
+            ("1_000", 1.0),
+            ("1e400", FloatValue::INFINITY),
+        ];
+        for (input, expected) in cases.iter().copied() {
+            assert_number_eq(
+                parse_js_float(input, NumberParseOptions::default()),
+                expected,
+            );
+        }
+    }
+
+    #[test]
+    fn parse_float_strict() {
+        let cases: &[(&str, FloatValue)] = &[
+            ("3.142", 3.142),
+            ("  -1.5e3\n", -1500.0),
+            ("Infinity", FloatValue::INFINITY),
+            ("3.142abc", FloatValue::NAN),
+            ("1e", FloatValue::NAN),
+            ("Infinityx", FloatValue::NAN),
+        ];
+        for (input, expected) in cases.iter().copied() {
+            assert_number_eq(
+                parse_js_float(input, NumberParseOptions::strict()),
+                expected,
+            );
+        }
+    }
+
+    #[test]
+    fn number_to_int() {
+        assert_eq!(js_number_to_int(3.0), Some(3));
+        assert_eq!(js_number_to_int(-0.0), Some(0));
+        assert_eq!(js_number_to_int(3.5), None);
+        assert_eq!(js_number_to_int(FloatValue::NAN), None);
+        assert_eq!(js_number_to_int(FloatValue::INFINITY), None);
+        assert_eq!(js_number_to_int(9007199254740991.0), Some(9007199254740991));
+        assert_eq!(js_number_to_int(9007199254740992.0), None);
+    }
+}
@@ -5,9 +5,9 @@
 use std::ops::Deref;
 
 use reflex::core::{
-    uuid, Applicable, ArgType, Arity, BooleanTermType, EvaluationCache, Expression,
-    ExpressionFactory, FloatTermType, FunctionArity, HeapAllocator, IntTermType, RefType,
-    StringTermType, StringValue, SymbolTermType, Uid, Uuid,
+    format_float, uuid, Applicable, ArgType, Arity, BooleanTermType, EvaluationCache, Expression,
+    ExpressionFactory, FloatFormat, FloatTermType, FunctionArity, HeapAllocator, IntTermType,
+    RefType, StringTermType, StringValue, SymbolTermType, Uid, Uuid,
 };
 
 pub struct ToString;
@@ -64,7 +64,7 @@ pub fn format_value<T: Expression>(
     } else if let Some(term) = factory.match_int_term(value) {
         Some(format!("{}", term.value()))
     } else if let Some(term) = factory.match_float_term(value) {
-        Some(format!("{}", term.value()))
+        Some(format_float(term.value(), FloatFormat::default()))
     } else if let Some(term) = factory.match_string_term(value) {
         Some(String::from(term.value().as_deref().as_str().deref()))
     } else if let Some(term) = factory.match_symbol_term(value) {
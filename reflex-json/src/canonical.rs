@@ -0,0 +1,275 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::fmt::Write;
+
+use reflex::core::Expression;
+use serde_json::{Number, Value};
+
+/// Serialize an expression into canonical JSON, suitable for computing stable content hashes and signatures.
+///
+/// Canonical output follows the JSON Canonicalization Scheme (RFC 8785): object keys are sorted by their UTF-16 code
+/// units, all numbers (including integers) are formatted as IEEE 754 doubles according to the ECMAScript
+/// `Number.prototype.toString()` algorithm, and no insignificant whitespace is emitted. The output does not depend on
+/// the host locale or on record field order.
+pub fn stringify_canonical<T: Expression>(value: &T) -> Result<String, String> {
+    value
+        .to_json()
+        .and_then(|value| serialize_canonical(&value))
+        .map_err(|err| format!("JSON serialization failed: {}", err))
+}
+
+/// Serialize a JSON value into canonical JSON (see [`stringify_canonical`])
+pub fn serialize_canonical(value: &Value) -> Result<String, String> {
+    let mut output = String::new();
+    write_canonical_value(value, &mut output)?;
+    Ok(output)
+}
+
+/// Format a number according to the canonical JSON number serialization rules (see [`stringify_canonical`])
+pub fn serialize_canonical_number(value: f64) -> Result<String, String> {
+    let mut output = String::new();
+    write_canonical_float(value, &mut output)?;
+    Ok(output)
+}
+
+fn write_canonical_value(value: &Value, output: &mut String) -> Result<(), String> {
+    match value {
+        Value::Null => output.push_str("null"),
+        Value::Bool(value) => output.push_str(if *value { "true" } else { "false" }),
+        Value::Number(value) => write_canonical_number(value, output)?,
+        Value::String(value) => write_canonical_string(value, output),
+        Value::Array(items) => {
+            output.push('[');
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+                write_canonical_value(item, output)?;
+            }
+            output.push(']');
+        }
+        Value::Object(entries) => {
+            let mut entries = entries.iter().collect::<Vec<_>>();
+            entries.sort_by(|(left, _), (right, _)| left.encode_utf16().cmp(right.encode_utf16()));
+            output.push('{');
+            for (index, (key, value)) in entries.into_iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+                write_canonical_string(key, output);
+                output.push(':');
+                write_canonical_value(value, output)?;
+            }
+            output.push('}');
+        }
+    }
+    Ok(())
+}
+
+fn write_canonical_string(value: &str, output: &mut String) {
+    output.push('"');
+    for char in value.chars() {
+        match char {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\u{08}' => output.push_str("\\b"),
+            '\u{0C}' => output.push_str("\\f"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            char if char < '\u{20}' => {
+                let _ = write!(output, "\\u{:04x}", char as u32);
+            }
+            char => output.push(char),
+        }
+    }
+    output.push('"');
+}
+
+fn write_canonical_number(value: &Number, output: &mut String) -> Result<(), String> {
+    // Integers are serialized via their nearest double representation, so values beyond 2^53 are rounded
+    match value.as_f64() {
+        Some(value) => write_canonical_float(value, output),
+        None => Err(format!("Invalid JSON number: {}", value)),
+    }
+}
+
+fn write_canonical_float(value: f64, output: &mut String) -> Result<(), String> {
+    if !value.is_finite() {
+        return Err(format!("Unable to serialize non-finite number: {}", value));
+    }
+    if value == 0.0 {
+        output.push('0');
+        return Ok(());
+    }
+    if value < 0.0 {
+        output.push('-');
+    }
+    // The exponential formatter yields the shortest digit sequence that uniquely identifies the value
+    let exponential = format!("{:e}", value.abs());
+    let (mantissa, exponent) = exponential
+        .split_once('e')
+        .ok_or_else(|| format!("Invalid float formatting: {}", exponential))?;
+    let digits = mantissa.replace('.', "");
+    let exponent = exponent
+        .parse::<i32>()
+        .map_err(|_| format!("Invalid float formatting: {}", exponential))?;
+    let num_digits = digits.len() as i32;
+    // Position of the decimal point relative to the start of the digit sequence
+    let point = exponent + 1;
+    if num_digits <= point && point <= 21 {
+        output.push_str(&digits);
+        output.push_str(&"0".repeat((point - num_digits) as usize));
+    } else if 0 < point && point <= 21 {
+        output.push_str(&digits[..point as usize]);
+        output.push('.');
+        output.push_str(&digits[point as usize..]);
+    } else if -6 < point && point <= 0 {
+        output.push_str("0.");
+        output.push_str(&"0".repeat(-point as usize));
+        output.push_str(&digits);
+    } else {
+        output.push_str(&digits[..1]);
+        if num_digits > 1 {
+            output.push('.');
+            output.push_str(&digits[1..]);
+        }
+        let _ = write!(
+            output,
+            "e{}{}",
+            if exponent < 0 { '-' } else { '+' },
+            exponent.abs()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use reflex::core::{create_record, ExpressionFactory, HeapAllocator};
+    use reflex_lang::{allocator::DefaultAllocator, SharedTermFactory};
+    use reflex_stdlib::Stdlib;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn canonical_primitives() {
+        assert_eq!(serialize_canonical(&json!(null)), Ok(String::from("null")));
+        assert_eq!(serialize_canonical(&json!(true)), Ok(String::from("true")));
+        assert_eq!(
+            serialize_canonical(&json!(false)),
+            Ok(String::from("false"))
+        );
+        assert_eq!(serialize_canonical(&json!(0)), Ok(String::from("0")));
+        assert_eq!(serialize_canonical(&json!(-3)), Ok(String::from("-3")));
+        assert_eq!(
+            serialize_canonical(&json!(u64::MAX)),
+            Ok(String::from("18446744073709552000"))
+        );
+        assert_eq!(
+            serialize_canonical(&json!(9007199254740993i64)),
+            Ok(String::from("9007199254740992"))
+        );
+        assert_eq!(
+            serialize_canonical(&json!(i64::MIN)),
+            Ok(String::from("-9223372036854776000"))
+        );
+        assert_eq!(
+            serialize_canonical(&json!("foo \"bar\" \\ \n\t\u{1f} é 😀")),
+            Ok(String::from("\"foo \\\"bar\\\" \\\\ \\n\\t\\u001f é 😀\"")),
+        );
+    }
+
+    #[test]
+    fn canonical_floats() {
+        for (value, expected) in [
+            (0.0, "0"),
+            (-0.0, "0"),
+            (1.0, "1"),
+            (-1.0, "-1"),
+            (3.25, "3.25"),
+            (0.1, "0.1"),
+            (123.456, "123.456"),
+            (1e20, "100000000000000000000"),
+            (1e21, "1e+21"),
+            (1.5e300, "1.5e+300"),
+            (0.000001, "0.000001"),
+            (0.0000001, "1e-7"),
+            (-1.25e-10, "-1.25e-10"),
+            (5e-324, "5e-324"),
+            (f64::MAX, "1.7976931348623157e+308"),
+        ] {
+            let mut output = String::new();
+            write_canonical_float(value, &mut output).unwrap();
+            assert_eq!(output, expected);
+        }
+        assert!(write_canonical_float(f64::NAN, &mut String::new()).is_err());
+        assert!(write_canonical_float(f64::INFINITY, &mut String::new()).is_err());
+    }
+
+    #[test]
+    fn canonical_collections() {
+        assert_eq!(
+            serialize_canonical(&json!({
+                "foo": [3, 1.5, { "b": null, "a": true }],
+                "bar": {},
+                "baz": [],
+            })),
+            Ok(String::from(
+                "{\"bar\":{},\"baz\":[],\"foo\":[3,1.5,{\"a\":true,\"b\":null}]}"
+            )),
+        );
+        // Keys are ordered by UTF-16 code units rather than by Unicode code points
+        assert_eq!(
+            serialize_canonical(&json!({ "\u{e000}": 1, "😀": 2, "a": 3 })),
+            Ok(String::from("{\"a\":3,\"😀\":2,\"\u{e000}\":1}")),
+        );
+    }
+
+    #[test]
+    fn canonical_expressions() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let left = create_record(
+            [
+                (
+                    factory.create_string_term(allocator.create_static_string("foo")),
+                    factory.create_float_term(1.0),
+                ),
+                (
+                    factory.create_string_term(allocator.create_static_string("bar")),
+                    factory.create_int_term(2),
+                ),
+            ],
+            &factory,
+            &allocator,
+        );
+        let right = create_record(
+            [
+                (
+                    factory.create_string_term(allocator.create_static_string("bar")),
+                    factory.create_int_term(2),
+                ),
+                (
+                    factory.create_string_term(allocator.create_static_string("foo")),
+                    factory.create_float_term(1.0),
+                ),
+            ],
+            &factory,
+            &allocator,
+        );
+        assert_eq!(
+            stringify_canonical(&left),
+            Ok(String::from("{\"bar\":2,\"foo\":1}"))
+        );
+        assert_eq!(stringify_canonical(&left), stringify_canonical(&right));
+        assert_eq!(
+            stringify_canonical(&factory.create_symbol_term(3)),
+            Err(String::from(
+                "JSON serialization failed: Unable to serialize term: <symbol:0x0000000000000003>"
+            )),
+        );
+    }
+}
@@ -2,12 +2,18 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 // SPDX-FileContributor: Chris Campbell <c.campbell@mwam.com> https://github.com/c-campbell-mwam
-use reflex::core::{create_record, Expression, ExpressionFactory, HeapAllocator};
+use reflex::core::{
+    create_record, format_float, Expression, ExpressionFactory, FloatFormat, HeapAllocator,
+};
 use serde_json::{Map, Value};
 
+mod canonical;
 pub mod stdlib;
+mod stream;
 
+pub use canonical::{serialize_canonical, serialize_canonical_number, stringify_canonical};
 pub use serde_json::{json, Map as JsonMap, Number as JsonNumber, Value as JsonValue};
+pub use stream::{stream_json_chunks, JsonChunk, JsonChunkStream};
 
 pub fn json_array(items: impl IntoIterator<Item = JsonValue>) -> JsonValue {
     JsonValue::Array(items.into_iter().collect())
@@ -36,6 +42,37 @@ pub fn sanitize<'a, T: Expression>(value: &T) -> Result<JsonValue, String> {
     value.to_json()
 }
 
+/// Round all float values within the provided JSON value to the precision specified by the given formatting strategy
+///
+/// Each float is replaced by the value obtained by parsing its formatted representation, ensuring that serialized
+/// output agrees with [`format_float`]. Integer values are left unchanged.
+pub fn format_json_floats(value: JsonValue, format: FloatFormat) -> JsonValue {
+    match format {
+        FloatFormat::Shortest => value,
+        FloatFormat::Fixed(_) => match value {
+            Value::Number(number) if number.is_f64() => number
+                .as_f64()
+                .and_then(|value| format_float(value, format).parse().ok())
+                .and_then(JsonNumber::from_f64)
+                .map(Value::Number)
+                .unwrap_or(Value::Number(number)),
+            Value::Array(items) => Value::Array(
+                items
+                    .into_iter()
+                    .map(|item| format_json_floats(item, format))
+                    .collect(),
+            ),
+            Value::Object(entries) => Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, format_json_floats(value, format)))
+                    .collect(),
+            ),
+            value => value,
+        },
+    }
+}
+
 pub fn hydrate<T: Expression>(
     value: Value,
     factory: &impl ExpressionFactory<T>,
@@ -95,13 +132,32 @@ fn hydrate_object<T: Expression>(
 mod tests {
     use std::iter::empty;
 
-    use crate::{hydrate, JsonValue};
-    use reflex::core::{create_record, ExpressionFactory, HeapAllocator, SerializeJson};
+    use crate::{format_json_floats, hydrate, json, JsonValue};
+    use reflex::core::{
+        create_record, ExpressionFactory, FloatFormat, HeapAllocator, SerializeJson,
+    };
     use reflex_lang::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
     use reflex_stdlib::Stdlib;
 
     use super::{parse, stringify};
 
+    #[test]
+    fn format_floats() {
+        let value = json!({ "foo": [3, 1.23456, -0.0049], "bar": { "baz": 1.0, "qux": 1e21 } });
+        assert_eq!(
+            format_json_floats(value.clone(), FloatFormat::Shortest),
+            value
+        );
+        assert_eq!(
+            format_json_floats(value, FloatFormat::Fixed(2)),
+            json!({ "foo": [3, 1.23, 0.0], "bar": { "baz": 1.0, "qux": 1e21 } }),
+        );
+        assert_eq!(
+            format_json_floats(json!([1.5, 2.5, -1.005]), FloatFormat::Fixed(0)).to_string(),
+            "[2.0,2.0,-1.0]",
+        );
+    }
+
     #[test]
     fn stringify_primitives() {
         let factory = SharedTermFactory::<Stdlib>::default();
@@ -13,14 +13,17 @@ use reflex::core::{
 
 pub use json_deserialize::*;
 pub use json_serialize::*;
+pub use json_serialize_canonical::*;
 
 mod json_deserialize;
 mod json_serialize;
+mod json_serialize_canonical;
 
 #[derive(Hash, Eq, PartialEq, Clone, Copy, Debug, Serialize, Deserialize, EnumIter)]
 pub enum Stdlib {
     JsonDeserialize,
     JsonSerialize,
+    JsonSerializeCanonical,
 }
 impl Stdlib {
     pub fn entries() -> impl Iterator<Item = Self> {
@@ -33,6 +36,7 @@ impl TryFrom<Uuid> for Stdlib {
         match uuid {
             JsonDeserialize::UUID => Ok(Self::JsonDeserialize),
             JsonSerialize::UUID => Ok(Self::JsonSerialize),
+            JsonSerializeCanonical::UUID => Ok(Self::JsonSerializeCanonical),
             _ => Err(()),
         }
     }
@@ -42,6 +46,7 @@ impl Uid for Stdlib {
         match self {
             Self::JsonDeserialize => Uid::uid(&JsonDeserialize {}),
             Self::JsonSerialize => Uid::uid(&JsonSerialize {}),
+            Self::JsonSerializeCanonical => Uid::uid(&JsonSerializeCanonical {}),
         }
     }
 }
@@ -50,6 +55,7 @@ impl Stdlib {
         match self {
             Self::JsonDeserialize => JsonDeserialize::arity(),
             Self::JsonSerialize => JsonSerialize::arity(),
+            Self::JsonSerializeCanonical => JsonSerializeCanonical::arity(),
         }
     }
     pub fn apply<T: Expression>(
@@ -69,6 +75,9 @@ impl Stdlib {
             Self::JsonSerialize => {
                 Applicable::<T>::apply(&JsonSerialize {}, args, factory, allocator, cache)
             }
+            Self::JsonSerializeCanonical => {
+                Applicable::<T>::apply(&JsonSerializeCanonical {}, args, factory, allocator, cache)
+            }
         }
     }
     pub fn should_parallelize<T: Expression>(&self, args: &[T]) -> bool
@@ -78,6 +87,9 @@ impl Stdlib {
         match self {
             Self::JsonDeserialize => Applicable::<T>::should_parallelize(&JsonDeserialize {}, args),
             Self::JsonSerialize => Applicable::<T>::should_parallelize(&JsonSerialize {}, args),
+            Self::JsonSerializeCanonical => {
+                Applicable::<T>::should_parallelize(&JsonSerializeCanonical {}, args)
+            }
         }
     }
 }
@@ -114,3 +126,8 @@ impl From<JsonSerialize> for Stdlib {
         Self::JsonSerialize
     }
 }
+impl From<JsonSerializeCanonical> for Stdlib {
+    fn from(_value: JsonSerializeCanonical) -> Self {
+        Self::JsonSerializeCanonical
+    }
+}
@@ -0,0 +1,178 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{Expression, ExpressionFactory, ExpressionListType, ListTermType, RefType};
+
+use crate::JsonValue;
+
+/// Contiguous batch of serialized result items, as emitted by a [`JsonChunkStream`]
+#[derive(PartialEq, Clone, Debug)]
+pub struct JsonChunk {
+    /// Index of the first chunk item within the overall result
+    pub offset: usize,
+    pub items: Vec<JsonValue>,
+}
+
+impl JsonChunk {
+    /// Serialize the chunk items as newline-delimited JSON, with each item on its own line
+    pub fn to_json_lines(&self) -> String {
+        self.items
+            .iter()
+            .map(|item| format!("{}\n", item))
+            .collect::<String>()
+    }
+}
+
+/// Pull-based stream of JSON-serialized chunks of an evaluation result
+///
+/// List results are serialized lazily in batches of up to `chunk_size` items, only converting each batch once the
+/// consumer pulls the next chunk, which avoids materializing the JSON representation of the entire list up-front.
+/// Any other result is emitted as a single chunk containing one item.
+///
+/// The stream ends after the first serialization error.
+pub struct JsonChunkStream<'a, T: Expression, TFactory: ExpressionFactory<T>> {
+    value: T,
+    factory: &'a TFactory,
+    chunk_size: usize,
+    offset: usize,
+    is_complete: bool,
+}
+
+impl<'a, T: Expression, TFactory: ExpressionFactory<T>> JsonChunkStream<'a, T, TFactory> {
+    pub fn new(value: T, chunk_size: usize, factory: &'a TFactory) -> Self {
+        Self {
+            value,
+            factory,
+            chunk_size: chunk_size.max(1),
+            offset: 0,
+            is_complete: false,
+        }
+    }
+}
+
+impl<'a, T: Expression, TFactory: ExpressionFactory<T>> Iterator
+    for JsonChunkStream<'a, T, TFactory>
+{
+    type Item = Result<JsonChunk, String>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_complete {
+            return None;
+        }
+        let offset = self.offset;
+        let result = match self.factory.match_list_term(&self.value) {
+            None => {
+                self.is_complete = true;
+                self.value.to_json().map(|value| vec![value])
+            }
+            Some(list) => {
+                let items = list.items();
+                let items = items.as_deref();
+                let end_offset = (offset + self.chunk_size).min(items.len());
+                if offset >= end_offset {
+                    self.is_complete = true;
+                    return None;
+                }
+                self.offset = end_offset;
+                (offset..end_offset)
+                    .filter_map(|index| items.get(index))
+                    .map(|item| item.as_deref().to_json())
+                    .collect::<Result<Vec<_>, _>>()
+            }
+        };
+        Some(
+            result
+                .map(|items| JsonChunk { offset, items })
+                .map_err(|err| {
+                    self.is_complete = true;
+                    format!("JSON serialization failed: {}", err)
+                }),
+        )
+    }
+}
+
+/// Serialize the provided evaluation result as a pull-based stream of independently-serialized chunks
+pub fn stream_json_chunks<'a, T: Expression, TFactory: ExpressionFactory<T>>(
+    value: &T,
+    chunk_size: usize,
+    factory: &'a TFactory,
+) -> JsonChunkStream<'a, T, TFactory> {
+    JsonChunkStream::new(value.clone(), chunk_size, factory)
+}
+
+#[cfg(test)]
+mod tests {
+    use reflex::core::{ExpressionFactory, HeapAllocator};
+    use reflex_lang::{allocator::DefaultAllocator, SharedTermFactory};
+    use reflex_stdlib::Stdlib;
+
+    use super::*;
+    use crate::json;
+
+    #[test]
+    fn stream_list_chunks() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let value = factory.create_list_term(
+            allocator.create_list((0..5i32).map(|value| factory.create_int_term(value.into()))),
+        );
+        assert_eq!(
+            stream_json_chunks(&value, 2, &factory).collect::<Vec<_>>(),
+            vec![
+                Ok(JsonChunk {
+                    offset: 0,
+                    items: vec![json!(0), json!(1)],
+                }),
+                Ok(JsonChunk {
+                    offset: 2,
+                    items: vec![json!(2), json!(3)],
+                }),
+                Ok(JsonChunk {
+                    offset: 4,
+                    items: vec![json!(4)],
+                }),
+            ],
+        );
+        assert_eq!(
+            stream_json_chunks(&value, 3, &factory)
+                .map(|chunk| chunk.map(|chunk| chunk.to_json_lines()))
+                .collect::<Result<String, _>>(),
+            Ok(String::from("0\n1\n2\n3\n4\n")),
+        );
+        let empty = factory.create_list_term(allocator.create_empty_list());
+        assert_eq!(stream_json_chunks(&empty, 2, &factory).next(), None);
+    }
+
+    #[test]
+    fn stream_non_list_values() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let value = factory.create_string_term(String::from("foo"));
+        assert_eq!(
+            stream_json_chunks(&value, 2, &factory).collect::<Vec<_>>(),
+            vec![Ok(JsonChunk {
+                offset: 0,
+                items: vec![json!("foo")],
+            })],
+        );
+    }
+
+    #[test]
+    fn stream_serialization_errors() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let value = factory.create_list_term(allocator.create_list([
+            factory.create_int_term(3),
+            factory.create_symbol_term(4),
+            factory.create_int_term(5),
+        ]));
+        let mut chunks = stream_json_chunks(&value, 1, &factory);
+        assert_eq!(
+            chunks.next(),
+            Some(Ok(JsonChunk {
+                offset: 0,
+                items: vec![json!(3)],
+            })),
+        );
+        assert!(matches!(chunks.next(), Some(Err(_))));
+        assert_eq!(chunks.next(), None);
+    }
+}
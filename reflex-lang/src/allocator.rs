@@ -83,6 +83,27 @@ impl<
     {
         expressions.as_deref().clone()
     }
+    fn slice_list<'a>(
+        &self,
+        expressions: T::ExpressionListRef<'a>,
+        offset: usize,
+        length: usize,
+    ) -> T::ExpressionList
+    where
+        Self: 'a,
+    {
+        expressions.as_deref().slice(offset, length)
+    }
+    fn concat_lists<'a>(
+        &self,
+        left: T::ExpressionListRef<'a>,
+        right: T::ExpressionListRef<'a>,
+    ) -> T::ExpressionList
+    where
+        Self: 'a,
+    {
+        left.as_deref().concat(right.as_deref())
+    }
     fn create_triple(&self, first: T, second: T, third: T) -> T::ExpressionList {
         self.sized_iterator_list([first, second, third])
     }
@@ -7,10 +7,11 @@ use std::{collections::HashSet, marker::PhantomData};
 
 use reflex::{
     core::{
-        Applicable, Arity, Builtin, CompoundNode, DependencyList, DynamicState, Evaluate,
+        Applicable, Arity, Builtin, CompoundNode, DependencyList, DurationValue, DynamicState, Evaluate,
         EvaluationCache, EvaluationResult, Expression, ExpressionFactory, FloatValue, GraphNode,
-        HeapAllocator, InstructionPointer, IntValue, NodeId, Reducible, Rewritable, SerializeJson,
-        StackOffset, Substitutions, SymbolId, TimestampValue,
+        HeapAllocator, InstructionPointer, IntValue, NodeId, NumericSemantics, Reducible,
+        Rewritable, SerializeJson, StackOffset, Substitutions, SymbolId, TimestampValue,
+        TimezoneOffset,
     },
     hash::HashId,
 };
@@ -22,20 +23,37 @@ use crate::{ExpressionList, Signal, SignalList, StructPrototype};
 use super::{
     expression::{CachedExpression, SharedExpression},
     term::*,
+    traversal,
 };
 
 #[derive(Clone, Copy, Serialize, Deserialize)]
 pub struct SharedTermFactory<TBuiltin: Builtin> {
+    numeric_semantics: NumericSemantics,
+    validate_constructors: bool,
     _builtin: PhantomData<TBuiltin>,
 }
 impl<TBuiltin: Builtin> Default for SharedTermFactory<TBuiltin> {
     fn default() -> Self {
         Self {
+            numeric_semantics: NumericSemantics::default(),
+            validate_constructors: false,
             _builtin: PhantomData,
         }
     }
 }
 impl<TBuiltin: Builtin> SharedTermFactory<TBuiltin> {
+    pub fn with_numeric_semantics(self, numeric_semantics: NumericSemantics) -> Self {
+        Self {
+            numeric_semantics,
+            ..self
+        }
+    }
+    pub fn with_constructor_validation(self, validate_constructors: bool) -> Self {
+        Self {
+            validate_constructors,
+            ..self
+        }
+    }
     fn create_expression(
         &self,
         value: Term<CachedSharedTerm<TBuiltin>>,
@@ -67,8 +85,30 @@ impl<TBuiltin: Builtin> ExpressionFactory<CachedSharedTerm<TBuiltin>>
     fn create_symbol_term(&self, id: SymbolId) -> CachedSharedTerm<TBuiltin> {
         self.create_expression(Term::Symbol(SymbolTerm::new(id)))
     }
-    fn create_timestamp_term(&self, millis: TimestampValue) -> CachedSharedTerm<TBuiltin> {
-        self.create_expression(Term::Timestamp(TimestampTerm::new(millis)))
+    fn create_timestamp_term(
+        &self,
+        millis: TimestampValue,
+        offset: TimezoneOffset,
+    ) -> CachedSharedTerm<TBuiltin> {
+        self.create_expression(Term::Timestamp(TimestampTerm::new(millis, offset)))
+    }
+    fn create_duration_term(&self, millis: DurationValue) -> CachedSharedTerm<TBuiltin> {
+        self.create_expression(Term::Duration(DurationTerm::new(millis)))
+    }
+    fn create_bytes_term(&self, value: &[u8]) -> CachedSharedTerm<TBuiltin> {
+        self.create_expression(Term::Bytes(BytesTerm::new(value)))
+    }
+    fn create_regex_term(
+        &self,
+        pattern: <CachedSharedTerm<TBuiltin> as Expression>::String,
+    ) -> CachedSharedTerm<TBuiltin> {
+        self.create_expression(Term::Regex(RegexTerm::new(pattern)))
+    }
+    fn create_sensitive_term(
+        &self,
+        value: CachedSharedTerm<TBuiltin>,
+    ) -> CachedSharedTerm<TBuiltin> {
+        self.create_expression(Term::Sensitive(SensitiveTerm::new(value)))
     }
     fn create_variable_term(&self, offset: StackOffset) -> CachedSharedTerm<TBuiltin> {
         self.create_expression(Term::Variable(VariableTerm::new(offset)))
@@ -152,7 +192,9 @@ impl<TBuiltin: Builtin> ExpressionFactory<CachedSharedTerm<TBuiltin>>
         &self,
         prototype: StructPrototype<CachedSharedTerm<TBuiltin>>,
     ) -> CachedSharedTerm<TBuiltin> {
-        self.create_expression(Term::Constructor(ConstructorTerm::new(prototype)))
+        self.create_expression(Term::Constructor(
+            ConstructorTerm::new(prototype).with_validation(self.validate_constructors),
+        ))
     }
     fn create_list_term(
         &self,
@@ -244,6 +286,42 @@ impl<TBuiltin: Builtin> ExpressionFactory<CachedSharedTerm<TBuiltin>>
             _ => None,
         }
     }
+    fn match_duration_term<'a>(
+        &self,
+        expression: &'a CachedSharedTerm<TBuiltin>,
+    ) -> Option<&'a DurationTerm> {
+        match expression.inner_term() {
+            Term::Duration(term) => Some(term),
+            _ => None,
+        }
+    }
+    fn match_bytes_term<'a>(
+        &self,
+        expression: &'a CachedSharedTerm<TBuiltin>,
+    ) -> Option<&'a BytesTerm> {
+        match expression.inner_term() {
+            Term::Bytes(term) => Some(term),
+            _ => None,
+        }
+    }
+    fn match_regex_term<'a>(
+        &self,
+        expression: &'a CachedSharedTerm<TBuiltin>,
+    ) -> Option<&'a RegexTerm<CachedSharedTerm<TBuiltin>>> {
+        match expression.inner_term() {
+            Term::Regex(term) => Some(term),
+            _ => None,
+        }
+    }
+    fn match_sensitive_term<'a>(
+        &self,
+        expression: &'a CachedSharedTerm<TBuiltin>,
+    ) -> Option<&'a SensitiveTerm<CachedSharedTerm<TBuiltin>>> {
+        match expression.inner_term() {
+            Term::Sensitive(term) => Some(term),
+            _ => None,
+        }
+    }
     fn match_symbol_term<'a>(
         &self,
         expression: &'a CachedSharedTerm<TBuiltin>,
@@ -397,6 +475,12 @@ impl<TBuiltin: Builtin> ExpressionFactory<CachedSharedTerm<TBuiltin>>
             _ => None,
         }
     }
+    fn numeric_semantics(&self) -> NumericSemantics {
+        self.numeric_semantics
+    }
+    fn validate_constructors(&self) -> bool {
+        self.validate_constructors
+    }
 }
 
 #[derive(PartialEq, Eq, Hash, Clone)]
@@ -414,7 +498,7 @@ impl<TBuiltin: Builtin> CachedSharedTerm<TBuiltin> {
     pub fn value(&self) -> &SharedExpression<CachedExpression<Term<Self>>> {
         &self.value
     }
-    fn inner_term(&self) -> &Term<Self> {
+    pub(crate) fn inner_term(&self) -> &Term<Self> {
         self.value.value().value()
     }
 }
@@ -432,6 +516,10 @@ impl<TBuiltin: Builtin> Expression for CachedSharedTerm<TBuiltin> {
     type StringTerm = StringTerm<Self>;
     type SymbolTerm = SymbolTerm;
     type TimestampTerm = TimestampTerm;
+    type DurationTerm = DurationTerm;
+    type BytesTerm = BytesTerm;
+    type RegexTerm = RegexTerm<Self>;
+    type SensitiveTerm = SensitiveTerm<Self>;
     type VariableTerm = VariableTerm;
     type EffectTerm = EffectTerm<Self>;
     type LetTerm = LetTerm<Self>;
@@ -491,7 +579,7 @@ impl<TBuiltin: Builtin> GraphNode for CachedSharedTerm<TBuiltin> {
         self.value.capture_depth()
     }
     fn free_variables(&self) -> HashSet<StackOffset> {
-        self.value.free_variables()
+        traversal::free_variables(self)
     }
     fn count_variable_usages(&self, offset: StackOffset) -> usize {
         self.value.count_variable_usages(offset)
@@ -610,7 +698,7 @@ impl<TBuiltin: Builtin> std::fmt::Debug for CachedSharedTerm<TBuiltin> {
 }
 impl<TBuiltin: Builtin> std::fmt::Display for CachedSharedTerm<TBuiltin> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(&self.value, f)
+        traversal::fmt_expression(self, f)
     }
 }
 impl<TBuiltin: Builtin> SerializeJson for CachedSharedTerm<TBuiltin> {
@@ -5,6 +5,8 @@
 use std::{
     collections::HashSet,
     hash::{Hash, Hasher},
+    iter::{empty, once},
+    sync::{Arc, OnceLock},
 };
 
 use reflex::{
@@ -21,104 +23,271 @@ pub mod allocator;
 pub mod expression;
 mod factory;
 pub mod term;
+mod traversal;
 
 pub use self::factory::*;
 
-#[derive(Eq, PartialEq, Clone, Debug)]
+/// Maximum number of backing segments retained by a concatenated list before its items are copied into a single
+/// contiguous segment (this bounds the cost of indexed access into concatenated lists)
+const MAX_EXPRESSION_LIST_SEGMENTS: usize = 8;
+
+/// List of expressions, stored as a sequence of windows onto shared backing storage
+///
+/// Slicing or concatenating existing lists reuses the backing storage of the source lists rather than copying their
+/// items, so windowing operations over large lists only allocate in proportion to the number of backing segments.
+#[derive(Clone)]
 pub struct ExpressionList<T: Expression> {
     id: HashId,
-    items: Vec<T>,
+    len: usize,
+    /// Initial window onto the backing storage of the list items
+    head: ExpressionListSegment<T>,
+    /// Subsequent windows onto the backing storage of concatenated lists (empty for contiguous lists)
+    tail: Vec<ExpressionListSegment<T>>,
+    /// Lazily-allocated contiguous copy of the list items (only populated for multi-segment lists)
+    contiguous: OnceLock<Arc<[T]>>,
+}
+#[derive(Clone)]
+struct ExpressionListSegment<T> {
+    items: Arc<[T]>,
+    offset: usize,
+    length: usize,
+}
+impl<T> ExpressionListSegment<T> {
+    fn as_slice(&self) -> &[T] {
+        &self.items[self.offset..(self.offset + self.length)]
+    }
+    fn slice(&self, offset: usize, length: usize) -> Self {
+        Self {
+            items: Arc::clone(&self.items),
+            offset: self.offset + offset,
+            length,
+        }
+    }
 }
 impl<T: Expression> std::hash::Hash for ExpressionList<T> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.id.hash(state);
     }
 }
+impl<T: Expression> PartialEq for ExpressionList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.len == other.len && self.items().eq(other.items())
+    }
+}
+impl<T: Expression> Eq for ExpressionList<T> {}
+impl<T: Expression> std::fmt::Debug for ExpressionList<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExpressionList")
+            .field("id", &self.id)
+            .field("items", &self.items().collect::<Vec<_>>())
+            .finish()
+    }
+}
 impl<T: Expression> ExpressionList<T> {
     pub fn new(items: impl IntoIterator<Item = T>) -> Self {
-        let items = items.into_iter().collect::<Vec<_>>();
-        Self {
-            id: hash_object(&items.iter().map(|val| val.id()).collect::<Vec<_>>()),
-            items,
-        }
+        let items = items.into_iter().collect::<Arc<[T]>>();
+        Self::from_segments(
+            ExpressionListSegment {
+                offset: 0,
+                length: items.len(),
+                items,
+            },
+            Vec::new(),
+        )
+    }
+    fn from_segments(head: ExpressionListSegment<T>, tail: Vec<ExpressionListSegment<T>>) -> Self {
+        let len = once(&head)
+            .chain(tail.iter())
+            .map(|segment| segment.length)
+            .sum::<usize>();
+        let mut list = Self {
+            id: 0,
+            len,
+            head,
+            tail,
+            contiguous: OnceLock::new(),
+        };
+        list.id = hash_expression_list_items(list.len, list.items());
+        list
     }
     pub fn get(&self, index: usize) -> Option<&T> {
-        self.items.get(index)
+        let mut offset = index;
+        for segment in self.segments() {
+            if offset < segment.length {
+                return segment.as_slice().get(offset);
+            }
+            offset -= segment.length;
+        }
+        None
     }
     pub fn len(&self) -> usize {
-        self.items.len()
+        self.len
     }
     pub fn is_empty(&self) -> bool {
-        self.items.is_empty()
+        self.len == 0
     }
+    pub fn items(&self) -> ExpressionListIter<'_, T> {
+        ExpressionListIter {
+            current: self.head.as_slice().iter(),
+            remaining_segments: self.tail.iter(),
+            remaining: self.len,
+        }
+    }
+    /// Retrieve the list items as a contiguous slice
+    ///
+    /// Lists backed by a single segment return a view onto their backing storage; lists backed by multiple segments
+    /// copy their items into a contiguous buffer on first access, which is retained for subsequent calls.
     pub fn as_slice(&self) -> &[T] {
-        self.items.as_slice()
+        if self.tail.is_empty() {
+            self.head.as_slice()
+        } else {
+            self.contiguous
+                .get_or_init(|| self.items().cloned().collect())
+        }
     }
     pub fn into_values(self) -> Vec<T> {
-        self.items
+        self.items().cloned().collect()
+    }
+    /// Create a list containing a window of this list's items, sharing this list's backing storage
+    ///
+    /// Out-of-range bounds are clamped to the length of the list.
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        let offset = offset.min(self.len);
+        let length = length.min(self.len - offset);
+        let (mut segments, _, _) = self.segments().fold(
+            (Vec::new(), offset, length),
+            |(mut segments, skip, take), segment| {
+                if take > 0 && skip < segment.length {
+                    let segment_length = (segment.length - skip).min(take);
+                    segments.push(segment.slice(skip, segment_length));
+                    (segments, 0, take - segment_length)
+                } else {
+                    (segments, skip.saturating_sub(segment.length), take)
+                }
+            },
+        );
+        if segments.is_empty() {
+            Self::new(empty())
+        } else {
+            let head = segments.remove(0);
+            Self::from_segments(head, segments)
+        }
+    }
+    /// Create a list containing the items of this list followed by the items of the other list, sharing the backing
+    /// storage of both lists
+    ///
+    /// If the combined list would exceed the maximum number of backing segments, the items are copied into a new
+    /// contiguous list instead.
+    pub fn concat(&self, other: &Self) -> Self {
+        if other.is_empty() {
+            self.clone()
+        } else if self.is_empty() {
+            other.clone()
+        } else if self.tail.len() + other.tail.len() + 2 > MAX_EXPRESSION_LIST_SEGMENTS {
+            Self::new(self.items().chain(other.items()).cloned())
+        } else {
+            Self::from_segments(
+                self.head.clone(),
+                self.tail.iter().chain(other.segments()).cloned().collect(),
+            )
+        }
+    }
+    fn segments(&self) -> impl Iterator<Item = &ExpressionListSegment<T>> {
+        once(&self.head).chain(self.tail.iter())
     }
 }
 
+fn hash_expression_list_items<'a, T: Expression + 'a>(
+    len: usize,
+    items: impl IntoIterator<Item = &'a T>,
+) -> HashId {
+    // Hash the sequence of item IDs without collecting them into an intermediate buffer
+    let mut hasher = FnvHasher::default();
+    hasher.write_usize(len);
+    for item in items {
+        hasher.write_u64(item.id());
+    }
+    hasher.finish()
+}
+
+/// Iterator over the items of an [`ExpressionList`], traversing each backing segment in turn
+pub struct ExpressionListIter<'a, T> {
+    current: std::slice::Iter<'a, T>,
+    remaining_segments: std::slice::Iter<'a, ExpressionListSegment<T>>,
+    remaining: usize,
+}
+impl<'a, T> Iterator for ExpressionListIter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.current.next() {
+                self.remaining -= 1;
+                return Some(item);
+            }
+            self.current = self.remaining_segments.next()?.as_slice().iter();
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+impl<'a, T> ExactSizeIterator for ExpressionListIter<'a, T> {}
+
 impl<T: Expression> ExpressionListType<T> for ExpressionList<T> {
-    type Iterator<'a> = IntoRefTypeIterator<T, T::ExpressionRef<'a>, std::slice::Iter<'a, T>> where T: 'a, Self: 'a;
+    type Iterator<'a> = IntoRefTypeIterator<T, T::ExpressionRef<'a>, ExpressionListIter<'a, T>> where T: 'a, Self: 'a;
     fn id(&self) -> HashId {
         self.id
     }
     fn len(&self) -> usize {
-        self.items.len()
+        self.len
     }
     fn get<'a>(&'a self, index: usize) -> Option<T::ExpressionRef<'a>>
     where
         T: 'a,
     {
-        self.items.get(index).map(|item| item.into())
+        ExpressionList::get(self, index).map(|item| item.into())
     }
     fn iter<'a>(&'a self) -> Self::Iterator<'a>
     where
         T: 'a,
     {
-        IntoRefTypeIterator::new(self.items.iter())
+        IntoRefTypeIterator::new(self.items())
     }
 }
 impl<T: Expression> GraphNode for ExpressionList<T> {
     fn size(&self) -> usize {
-        self.items.len()
+        self.len
     }
     fn capture_depth(&self) -> StackOffset {
-        self.items
-            .iter()
+        self.items()
             .map(|term| term.capture_depth())
             .max()
             .unwrap_or_default()
     }
     fn free_variables(&self) -> HashSet<StackOffset> {
-        self.items.iter().fold(HashSet::new(), |mut results, term| {
+        self.items().fold(HashSet::new(), |mut results, term| {
             results.extend(term.free_variables());
             results
         })
     }
     fn count_variable_usages(&self, offset: StackOffset) -> usize {
-        self.items.iter().fold(0, |results, term| {
+        self.items().fold(0, |results, term| {
             results + term.count_variable_usages(offset)
         })
     }
     fn dynamic_dependencies(&self, deep: bool) -> DependencyList {
-        self.items
-            .iter()
-            .fold(DependencyList::empty(), |acc, term| {
-                acc.union(term.dynamic_dependencies(deep))
-            })
+        self.items().fold(DependencyList::empty(), |acc, term| {
+            acc.union(term.dynamic_dependencies(deep))
+        })
     }
     fn has_dynamic_dependencies(&self, deep: bool) -> bool {
-        self.items
-            .iter()
-            .any(|term| term.has_dynamic_dependencies(deep))
+        self.items().any(|term| term.has_dynamic_dependencies(deep))
     }
     fn is_static(&self) -> bool {
         true
     }
     fn is_atomic(&self) -> bool {
-        self.items.iter().all(|item| item.is_atomic())
+        self.items().all(|item| item.is_atomic())
     }
     fn is_complex(&self) -> bool {
         true
@@ -129,8 +298,7 @@ impl<T: Expression> std::fmt::Display for ExpressionList<T> {
         write!(
             f,
             "[{}]",
-            self.items
-                .iter()
+            self.items()
                 .map(|value| format!("{}", value))
                 .collect::<Vec<_>>()
                 .join(",")
@@ -163,8 +331,7 @@ where
 struct SerializedExpressionList<T: Expression>(Vec<T>);
 impl<'a, T: Expression> Into<SerializedExpressionList<T>> for &'a ExpressionList<T> {
     fn into(self) -> SerializedExpressionList<T> {
-        let ExpressionList { items, .. } = self.clone();
-        SerializedExpressionList(items)
+        SerializedExpressionList(self.items().cloned().collect())
     }
 }
 impl<T: Expression> Into<ExpressionList<T>> for SerializedExpressionList<T> {
@@ -412,3 +579,156 @@ impl<T: Expression> std::fmt::Display for StructPrototype<T> {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use reflex::core::{ExpressionFactory, ExpressionListType};
+    use reflex_stdlib::Stdlib;
+
+    use crate::{CachedSharedTerm, SharedTermFactory};
+
+    use super::*;
+
+    type T = CachedSharedTerm<Stdlib>;
+
+    fn create_list(factory: &SharedTermFactory<Stdlib>, values: &[i64]) -> ExpressionList<T> {
+        ExpressionList::new(values.iter().map(|value| factory.create_int_term(*value)))
+    }
+
+    #[test]
+    fn expression_list_slice() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let list = create_list(&factory, &[0, 1, 2, 3, 4]);
+        let slice = list.slice(1, 3);
+        assert_eq!(slice, create_list(&factory, &[1, 2, 3]));
+        assert_eq!(slice.id(), create_list(&factory, &[1, 2, 3]).id());
+        assert_eq!(slice.get(2), Some(&factory.create_int_term(3)));
+        assert_eq!(slice.get(3), None);
+        assert!(Arc::ptr_eq(&slice.head.items, &list.head.items));
+        assert_eq!(list.slice(3, 10), create_list(&factory, &[3, 4]));
+        assert_eq!(list.slice(10, 10), create_list(&factory, &[]));
+    }
+
+    #[test]
+    fn expression_list_concat() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let left = create_list(&factory, &[0, 1, 2]);
+        let right = create_list(&factory, &[3, 4]);
+        let combined = left.concat(&right);
+        let expected = create_list(&factory, &[0, 1, 2, 3, 4]);
+        assert_eq!(combined, expected);
+        assert_eq!(combined.id(), expected.id());
+        assert_eq!(combined.len(), 5);
+        assert_eq!(combined.get(3), Some(&factory.create_int_term(3)));
+        assert_eq!(combined.items().len(), 5);
+        assert!(Arc::ptr_eq(&combined.head.items, &left.head.items));
+        assert!(Arc::ptr_eq(&combined.tail[0].items, &right.head.items));
+        assert_eq!(combined.slice(2, 2), create_list(&factory, &[2, 3]));
+        assert_eq!(combined.slice(2, 2).tail.len(), 1);
+        assert_eq!(format!("{}", combined), format!("{}", expected));
+        let repeated = (0..(MAX_EXPRESSION_LIST_SEGMENTS as i64 * 2))
+            .fold(create_list(&factory, &[]), |list, value| {
+                list.concat(&create_list(&factory, &[value]))
+            });
+        assert_eq!(repeated.len(), MAX_EXPRESSION_LIST_SEGMENTS * 2);
+        assert!(repeated.tail.len() < MAX_EXPRESSION_LIST_SEGMENTS);
+        assert_eq!(
+            repeated.into_values(),
+            (0..(MAX_EXPRESSION_LIST_SEGMENTS as i64 * 2))
+                .map(|value| factory.create_int_term(value))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn expression_list_slice_shares_storage() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let list = create_list(&factory, &[0, 1, 2, 3, 4, 5]);
+        let slice = list.slice(1, 4);
+        let nested = slice.slice(1, 2);
+        assert_eq!(nested, create_list(&factory, &[2, 3]));
+        assert!(Arc::ptr_eq(&nested.head.items, &list.head.items));
+        assert_eq!(nested.head.offset, 2);
+        assert_eq!(nested.head.length, 2);
+        let combined = list.slice(0, 2).concat(&list.slice(4, 2));
+        assert_eq!(combined, create_list(&factory, &[0, 1, 4, 5]));
+        assert!(Arc::ptr_eq(&combined.head.items, &list.head.items));
+        assert!(Arc::ptr_eq(&combined.tail[0].items, &list.head.items));
+        let window = combined.slice(1, 2);
+        assert_eq!(window, create_list(&factory, &[1, 4]));
+        assert!(window
+            .segments()
+            .all(|segment| Arc::ptr_eq(&segment.items, &list.head.items)));
+    }
+
+    #[test]
+    fn expression_list_slice_clamps_bounds() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let list = create_list(&factory, &[0, 1, 2]);
+        assert_eq!(list.slice(0, 3), list);
+        assert_eq!(list.slice(0, usize::MAX), list);
+        assert_eq!(list.slice(1, usize::MAX), create_list(&factory, &[1, 2]));
+        assert_eq!(list.slice(3, 1), create_list(&factory, &[]));
+        assert_eq!(
+            list.slice(usize::MAX, usize::MAX),
+            create_list(&factory, &[])
+        );
+        assert_eq!(list.slice(1, 0), create_list(&factory, &[]));
+        assert_eq!(list.slice(1, 0).id(), create_list(&factory, &[]).id());
+        let combined = list.concat(&create_list(&factory, &[3, 4]));
+        assert_eq!(combined.slice(2, 100), create_list(&factory, &[2, 3, 4]));
+        assert_eq!(combined.slice(5, 1), create_list(&factory, &[]));
+        assert_eq!(combined.slice(4, 100).tail.len(), 0);
+    }
+
+    #[test]
+    fn expression_list_concat_flattens_segments() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let items = (0..(MAX_EXPRESSION_LIST_SEGMENTS as i64 + 1))
+            .map(|value| create_list(&factory, &[value]))
+            .collect::<Vec<_>>();
+        let (last, initial) = items.split_last().unwrap();
+        let segmented = initial
+            .iter()
+            .fold(create_list(&factory, &[]), |list, item| list.concat(item));
+        assert_eq!(segmented.tail.len() + 1, MAX_EXPRESSION_LIST_SEGMENTS);
+        assert!(segmented
+            .segments()
+            .zip(initial.iter())
+            .all(|(segment, item)| Arc::ptr_eq(&segment.items, &item.head.items)));
+        let flattened = segmented.concat(last);
+        assert!(flattened.tail.is_empty());
+        assert_eq!(flattened.head.length, MAX_EXPRESSION_LIST_SEGMENTS + 1);
+        assert!(!Arc::ptr_eq(&flattened.head.items, &initial[0].head.items));
+        let expected = create_list(
+            &factory,
+            &(0..(MAX_EXPRESSION_LIST_SEGMENTS as i64 + 1)).collect::<Vec<_>>(),
+        );
+        assert_eq!(flattened, expected);
+        assert_eq!(flattened.id(), expected.id());
+        assert_eq!(
+            segmented.concat(&create_list(&factory, &[])).tail.len(),
+            segmented.tail.len()
+        );
+    }
+
+    #[test]
+    fn expression_list_as_slice() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let list = create_list(&factory, &[0, 1, 2, 3]);
+        assert_eq!(
+            list.as_slice(),
+            create_list(&factory, &[0, 1, 2, 3]).into_values()
+        );
+        assert!(std::ptr::eq(
+            list.slice(1, 2).as_slice(),
+            &list.as_slice()[1..3]
+        ));
+        let combined = list.slice(2, 2).concat(&list.slice(0, 2));
+        assert_eq!(
+            combined.as_slice(),
+            create_list(&factory, &[2, 3, 0, 1]).into_values()
+        );
+        assert!(std::ptr::eq(combined.as_slice(), combined.as_slice()));
+    }
+}
@@ -14,8 +14,10 @@ use crate::{ExpressionList, Signal, SignalList, StructPrototype};
 mod application;
 mod boolean;
 mod builtin;
+mod bytes;
 mod compiled;
 mod constructor;
+mod duration;
 mod effect;
 mod float;
 mod hashmap;
@@ -29,6 +31,8 @@ mod nil;
 mod partial;
 mod record;
 mod recursive;
+mod regex;
+mod sensitive;
 mod signal;
 mod string;
 mod symbol;
@@ -38,8 +42,10 @@ mod variable;
 pub use application::*;
 pub use boolean::*;
 pub use builtin::*;
+pub use bytes::*;
 pub use compiled::*;
 pub use constructor::*;
+pub use duration::*;
 pub use effect::*;
 pub use float::*;
 pub use hashmap::*;
@@ -53,6 +59,8 @@ pub use partial::*;
 pub use r#let::*;
 pub use record::*;
 pub use recursive::*;
+pub use regex::*;
+pub use sensitive::*;
 pub use signal::*;
 pub use string::*;
 pub use symbol::*;
@@ -85,6 +93,10 @@ where
     String(StringTerm<T>),
     Symbol(SymbolTerm),
     Timestamp(TimestampTerm),
+    Duration(DurationTerm),
+    Bytes(BytesTerm),
+    Regex(RegexTerm<T>),
+    Sensitive(SensitiveTerm<T>),
     Variable(VariableTerm),
     Effect(EffectTerm<T>),
     Let(LetTerm<T>),
@@ -121,6 +133,10 @@ where
     type StringTerm = StringTerm<Self>;
     type SymbolTerm = SymbolTerm;
     type TimestampTerm = TimestampTerm;
+    type DurationTerm = DurationTerm;
+    type BytesTerm = BytesTerm;
+    type RegexTerm = RegexTerm<Self>;
+    type SensitiveTerm = SensitiveTerm<Self>;
     type VariableTerm = VariableTerm;
     type EffectTerm = EffectTerm<Self>;
     type LetTerm = LetTerm<Self>;
@@ -189,6 +205,10 @@ where
             Self::String(term) => term.size(),
             Self::Symbol(term) => term.size(),
             Self::Timestamp(term) => term.size(),
+            Self::Duration(term) => term.size(),
+            Self::Bytes(term) => term.size(),
+            Self::Regex(term) => term.size(),
+            Self::Sensitive(term) => term.size(),
             Self::Variable(term) => term.size(),
             Self::Effect(term) => term.size(),
             Self::Let(term) => term.size(),
@@ -216,6 +236,10 @@ where
             Self::String(term) => term.capture_depth(),
             Self::Symbol(term) => term.capture_depth(),
             Self::Timestamp(term) => term.capture_depth(),
+            Self::Duration(term) => term.capture_depth(),
+            Self::Bytes(term) => term.capture_depth(),
+            Self::Regex(term) => term.capture_depth(),
+            Self::Sensitive(term) => term.capture_depth(),
             Self::Variable(term) => term.capture_depth(),
             Self::Effect(term) => term.capture_depth(),
             Self::Let(term) => term.capture_depth(),
@@ -243,6 +267,10 @@ where
             Self::String(term) => term.free_variables(),
             Self::Symbol(term) => term.free_variables(),
             Self::Timestamp(term) => term.free_variables(),
+            Self::Duration(term) => term.free_variables(),
+            Self::Bytes(term) => term.free_variables(),
+            Self::Regex(term) => term.free_variables(),
+            Self::Sensitive(term) => term.free_variables(),
             Self::Variable(term) => term.free_variables(),
             Self::Effect(term) => term.free_variables(),
             Self::Let(term) => term.free_variables(),
@@ -270,6 +298,10 @@ where
             Self::String(term) => term.count_variable_usages(offset),
             Self::Symbol(term) => term.count_variable_usages(offset),
             Self::Timestamp(term) => term.count_variable_usages(offset),
+            Self::Duration(term) => term.count_variable_usages(offset),
+            Self::Bytes(term) => term.count_variable_usages(offset),
+            Self::Regex(term) => term.count_variable_usages(offset),
+            Self::Sensitive(term) => term.count_variable_usages(offset),
             Self::Variable(term) => term.count_variable_usages(offset),
             Self::Effect(term) => term.count_variable_usages(offset),
             Self::Let(term) => term.count_variable_usages(offset),
@@ -297,6 +329,10 @@ where
             Self::String(term) => term.dynamic_dependencies(deep),
             Self::Symbol(term) => term.dynamic_dependencies(deep),
             Self::Timestamp(term) => term.dynamic_dependencies(deep),
+            Self::Duration(term) => term.dynamic_dependencies(deep),
+            Self::Bytes(term) => term.dynamic_dependencies(deep),
+            Self::Regex(term) => term.dynamic_dependencies(deep),
+            Self::Sensitive(term) => term.dynamic_dependencies(deep),
             Self::Variable(term) => term.dynamic_dependencies(deep),
             Self::Effect(term) => term.dynamic_dependencies(deep),
             Self::Let(term) => term.dynamic_dependencies(deep),
@@ -324,6 +360,10 @@ where
             Self::String(term) => term.has_dynamic_dependencies(deep),
             Self::Symbol(term) => term.has_dynamic_dependencies(deep),
             Self::Timestamp(term) => term.has_dynamic_dependencies(deep),
+            Self::Duration(term) => term.has_dynamic_dependencies(deep),
+            Self::Bytes(term) => term.has_dynamic_dependencies(deep),
+            Self::Regex(term) => term.has_dynamic_dependencies(deep),
+            Self::Sensitive(term) => term.has_dynamic_dependencies(deep),
             Self::Variable(term) => term.has_dynamic_dependencies(deep),
             Self::Effect(term) => term.has_dynamic_dependencies(deep),
             Self::Let(term) => term.has_dynamic_dependencies(deep),
@@ -351,6 +391,10 @@ where
             Self::String(term) => term.is_static(),
             Self::Symbol(term) => term.is_static(),
             Self::Timestamp(term) => term.is_static(),
+            Self::Duration(term) => term.is_static(),
+            Self::Bytes(term) => term.is_static(),
+            Self::Regex(term) => term.is_static(),
+            Self::Sensitive(term) => term.is_static(),
             Self::Variable(term) => term.is_static(),
             Self::Effect(term) => term.is_static(),
             Self::Let(term) => term.is_static(),
@@ -378,6 +422,10 @@ where
             Self::String(term) => term.is_atomic(),
             Self::Symbol(term) => term.is_atomic(),
             Self::Timestamp(term) => term.is_atomic(),
+            Self::Duration(term) => term.is_atomic(),
+            Self::Bytes(term) => term.is_atomic(),
+            Self::Regex(term) => term.is_atomic(),
+            Self::Sensitive(term) => term.is_atomic(),
             Self::Variable(term) => term.is_atomic(),
             Self::Effect(term) => term.is_atomic(),
             Self::Let(term) => term.is_atomic(),
@@ -405,6 +453,10 @@ where
             Self::String(term) => term.is_complex(),
             Self::Symbol(term) => term.is_complex(),
             Self::Timestamp(term) => term.is_complex(),
+            Self::Duration(term) => term.is_complex(),
+            Self::Bytes(term) => term.is_complex(),
+            Self::Regex(term) => term.is_complex(),
+            Self::Sensitive(term) => term.is_complex(),
             Self::Variable(term) => term.is_complex(),
             Self::Effect(term) => term.is_complex(),
             Self::Let(term) => term.is_complex(),
@@ -712,6 +764,10 @@ where
             Self::String(term) => std::fmt::Display::fmt(term, f),
             Self::Symbol(term) => std::fmt::Display::fmt(term, f),
             Self::Timestamp(term) => std::fmt::Display::fmt(term, f),
+            Self::Duration(term) => std::fmt::Display::fmt(term, f),
+            Self::Bytes(term) => std::fmt::Display::fmt(term, f),
+            Self::Regex(term) => std::fmt::Display::fmt(term, f),
+            Self::Sensitive(term) => std::fmt::Display::fmt(term, f),
             Self::Variable(term) => std::fmt::Display::fmt(term, f),
             Self::Effect(term) => std::fmt::Display::fmt(term, f),
             Self::Let(term) => std::fmt::Display::fmt(term, f),
@@ -745,6 +801,10 @@ where
             Self::String(term) => term.to_json(),
             Self::Symbol(term) => term.to_json(),
             Self::Timestamp(term) => term.to_json(),
+            Self::Duration(term) => term.to_json(),
+            Self::Bytes(term) => term.to_json(),
+            Self::Regex(term) => term.to_json(),
+            Self::Sensitive(term) => term.to_json(),
             Self::Variable(term) => term.to_json(),
             Self::Effect(term) => term.to_json(),
             Self::Let(term) => term.to_json(),
@@ -773,6 +833,10 @@ where
             (Self::String(term), Self::String(other)) => term.patch(other),
             (Self::Symbol(term), Self::Symbol(other)) => term.patch(other),
             (Self::Timestamp(term), Self::Timestamp(other)) => term.patch(other),
+            (Self::Duration(term), Self::Duration(other)) => term.patch(other),
+            (Self::Bytes(term), Self::Bytes(other)) => term.patch(other),
+            (Self::Regex(term), Self::Regex(other)) => term.patch(other),
+            (Self::Sensitive(term), Self::Sensitive(other)) => term.patch(other),
             (Self::Variable(term), Self::Variable(other)) => term.patch(other),
             (Self::Effect(term), Self::Effect(other)) => term.patch(other),
             (Self::Let(term), Self::Let(other)) => term.patch(other),
@@ -644,7 +644,7 @@ mod tests {
     use crate::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
     use reflex::{
         cache::SubstitutionCache,
-        core::{ExpressionFactory, HeapAllocator, Rewritable},
+        core::{create_error_expression, ExpressionFactory, HeapAllocator, Rewritable},
     };
     use reflex_stdlib::{Add, Get, Stdlib, Subtract};
 
@@ -1018,4 +1018,69 @@ mod tests {
         let result = expression.normalize(&factory, &allocator, &mut SubstitutionCache::new());
         assert_eq!(result, Some(factory.create_boolean_term(true)));
     }
+
+    #[test]
+    fn validate_constructor_application() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::<CachedSharedTerm<Stdlib>>::default();
+        let prototype = allocator.create_struct_prototype(allocator.create_pair(
+            factory.create_string_term(allocator.create_static_string("foo")),
+            factory.create_string_term(allocator.create_static_string("bar")),
+        ));
+        let expression = factory.create_application_term(
+            factory.create_constructor_term(prototype.clone()),
+            allocator.create_unit_list(factory.create_int_term(3)),
+        );
+        let result = expression.normalize(&factory, &allocator, &mut SubstitutionCache::new());
+        assert_eq!(
+            result,
+            Some(factory.create_record_term(
+                prototype.clone(),
+                allocator.create_pair(factory.create_int_term(3), factory.create_nil_term()),
+            ))
+        );
+        let factory = SharedTermFactory::<Stdlib>::default().with_constructor_validation(true);
+        let expression = factory.create_application_term(
+            factory.create_constructor_term(prototype.clone()),
+            allocator.create_unit_list(factory.create_int_term(3)),
+        );
+        let result = expression.normalize(&factory, &allocator, &mut SubstitutionCache::new());
+        assert_eq!(
+            result,
+            Some(create_error_expression(
+                factory.create_string_term(allocator.create_static_string("Invalid constructor call: <constructor:{\"foo\",\"bar\"}>: Missing keys: \"bar\"")),
+                &factory,
+                &allocator,
+            ))
+        );
+        let expression = factory.create_application_term(
+            factory.create_constructor_term(prototype.clone()),
+            allocator.create_triple(
+                factory.create_int_term(3),
+                factory.create_int_term(4),
+                factory.create_int_term(5),
+            ),
+        );
+        let result = expression.normalize(&factory, &allocator, &mut SubstitutionCache::new());
+        assert_eq!(
+            result,
+            Some(create_error_expression(
+                factory.create_string_term(allocator.create_static_string("Invalid constructor call: <constructor:{\"foo\",\"bar\"}>: Expected 2 arguments, received 3")),
+                &factory,
+                &allocator,
+            ))
+        );
+        let expression = factory.create_application_term(
+            factory.create_constructor_term(prototype.clone()),
+            allocator.create_pair(factory.create_int_term(3), factory.create_int_term(4)),
+        );
+        let result = expression.normalize(&factory, &allocator, &mut SubstitutionCache::new());
+        assert_eq!(
+            result,
+            Some(factory.create_record_term(
+                prototype,
+                allocator.create_pair(factory.create_int_term(3), factory.create_int_term(4)),
+            ))
+        );
+    }
 }
@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::collections::HashSet;
+
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+use reflex::core::{BytesTermType, DependencyList, GraphNode, SerializeJson, StackOffset};
+
+#[derive(PartialEq, Eq, Clone, Serialize, Deserialize, Hash)]
+pub struct BytesTerm {
+    value: Vec<u8>,
+}
+impl BytesTerm {
+    pub fn new(value: &[u8]) -> Self {
+        Self {
+            value: Vec::from(value),
+        }
+    }
+}
+impl BytesTermType for BytesTerm {
+    type BytesRef<'a> = &'a [u8]
+    where
+        Self: 'a;
+    fn value<'a>(&'a self) -> Self::BytesRef<'a> {
+        self.value.as_slice()
+    }
+}
+impl GraphNode for BytesTerm {
+    fn size(&self) -> usize {
+        1
+    }
+    fn capture_depth(&self) -> StackOffset {
+        0
+    }
+    fn free_variables(&self) -> HashSet<StackOffset> {
+        HashSet::new()
+    }
+    fn count_variable_usages(&self, _offset: StackOffset) -> usize {
+        0
+    }
+    fn dynamic_dependencies(&self, _deep: bool) -> DependencyList {
+        DependencyList::empty()
+    }
+    fn has_dynamic_dependencies(&self, _deep: bool) -> bool {
+        false
+    }
+    fn is_static(&self) -> bool {
+        true
+    }
+    fn is_atomic(&self) -> bool {
+        true
+    }
+    fn is_complex(&self) -> bool {
+        false
+    }
+}
+
+impl std::fmt::Display for BytesTerm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            base64::engine::general_purpose::STANDARD.encode(&self.value)
+        )
+    }
+}
+impl std::fmt::Debug for BytesTerm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+impl SerializeJson for BytesTerm {
+    fn to_json(&self) -> Result<JsonValue, String> {
+        Ok(JsonValue::String(
+            base64::engine::general_purpose::STANDARD.encode(&self.value),
+        ))
+    }
+    fn patch(&self, target: &Self) -> Result<Option<JsonValue>, String> {
+        if self.value == target.value {
+            Ok(None)
+        } else {
+            target.to_json().map(Some)
+        }
+    }
+}
@@ -9,25 +9,41 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 
 use reflex::core::{
-    Applicable, Arity, ConstructorTermType, DependencyList, EvaluationCache, Expression,
-    ExpressionFactory, ExpressionListType, GraphNode, HeapAllocator, RefType, SerializeJson,
-    StackOffset, StructPrototypeType,
+    create_error_expression, validate_record_length, Applicable, Arity, ConstructorTermType,
+    DependencyList, EvaluationCache, Expression, ExpressionFactory, ExpressionListType, GraphNode,
+    HeapAllocator, RefType, SerializeJson, StackOffset, StructPrototypeType,
 };
 
 #[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub struct ConstructorTerm<T: Expression> {
     prototype: T::StructPrototype,
+    #[serde(default)]
+    validate: bool,
 }
 
 impl<T: Expression> std::hash::Hash for ConstructorTerm<T> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.prototype.keys().as_deref().id().hash(state);
+        if self.validate {
+            self.validate.hash(state);
+        }
     }
 }
 
 impl<T: Expression> ConstructorTerm<T> {
     pub fn new(prototype: T::StructPrototype) -> Self {
-        Self { prototype }
+        Self {
+            prototype,
+            validate: false,
+        }
+    }
+    /// Emit an error when the constructor is applied to the wrong number of arguments, rather than
+    /// padding missing fields with null values and discarding extraneous arguments
+    pub fn with_validation(self, validate: bool) -> Self {
+        Self { validate, ..self }
+    }
+    pub fn validate(&self) -> bool {
+        self.validate
     }
 }
 impl<T: Expression> ConstructorTermType<T> for ConstructorTerm<T> {
@@ -70,11 +86,16 @@ impl<T: Expression> GraphNode for ConstructorTerm<T> {
 }
 impl<T: Expression> Applicable<T> for ConstructorTerm<T> {
     fn arity(&self) -> Option<Arity> {
-        Some(Arity::lazy(
-            0,
-            self.prototype().as_deref().keys().as_deref().len(),
-            false,
-        ))
+        if self.validate {
+            // Accept any number of arguments so that the full argument list can be checked on application
+            Some(Arity::lazy(0, 0, true))
+        } else {
+            Some(Arity::lazy(
+                0,
+                self.prototype().as_deref().keys().as_deref().len(),
+                false,
+            ))
+        }
     }
     fn apply(
         &self,
@@ -83,6 +104,18 @@ impl<T: Expression> Applicable<T> for ConstructorTerm<T> {
         allocator: &impl HeapAllocator<T>,
         _cache: &mut impl EvaluationCache<T>,
     ) -> Result<T, String> {
+        if self.validate {
+            if let Err(err) = validate_record_length::<T>(&self.prototype, args.len()) {
+                return Ok(create_error_expression(
+                    factory.create_string_term(
+                        allocator
+                            .create_string(format!("Invalid constructor call: {}: {}", self, err)),
+                    ),
+                    factory,
+                    allocator,
+                ));
+            }
+        }
         Ok(factory.create_record_term(
             allocator.clone_struct_prototype((&self.prototype).into()),
             allocator.create_list(args),
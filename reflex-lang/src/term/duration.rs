@@ -0,0 +1,74 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+use reflex::core::{
+    DependencyList, DurationTermType, DurationValue, GraphNode, SerializeJson, StackOffset,
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Hash)]
+pub struct DurationTerm {
+    millis: DurationValue,
+}
+impl DurationTerm {
+    pub fn new(millis: DurationValue) -> Self {
+        Self { millis }
+    }
+}
+impl DurationTermType for DurationTerm {
+    fn millis(&self) -> DurationValue {
+        self.millis
+    }
+}
+impl GraphNode for DurationTerm {
+    fn size(&self) -> usize {
+        1
+    }
+    fn capture_depth(&self) -> StackOffset {
+        0
+    }
+    fn free_variables(&self) -> HashSet<StackOffset> {
+        HashSet::new()
+    }
+    fn count_variable_usages(&self, _offset: StackOffset) -> usize {
+        0
+    }
+    fn dynamic_dependencies(&self, _deep: bool) -> DependencyList {
+        DependencyList::empty()
+    }
+    fn has_dynamic_dependencies(&self, _deep: bool) -> bool {
+        false
+    }
+    fn is_static(&self) -> bool {
+        true
+    }
+    fn is_atomic(&self) -> bool {
+        true
+    }
+    fn is_complex(&self) -> bool {
+        false
+    }
+}
+
+impl std::fmt::Display for DurationTerm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<{}ms>", self.millis)
+    }
+}
+impl std::fmt::Debug for DurationTerm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+impl SerializeJson for DurationTerm {
+    fn to_json(&self) -> Result<JsonValue, String> {
+        Ok(JsonValue::Number(self.millis.into()))
+    }
+    fn patch(&self, target: &Self) -> Result<Option<JsonValue>, String> {
+        target.to_json().map(Some)
+    }
+}
@@ -9,7 +9,8 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 
 use reflex::core::{
-    as_integer, DependencyList, FloatTermType, FloatValue, GraphNode, SerializeJson, StackOffset,
+    format_float, DependencyList, FloatFormat, FloatTermType, FloatValue, GraphNode, SerializeJson,
+    StackOffset,
 };
 
 #[derive(PartialEq, Clone, Copy, Serialize, Deserialize)]
@@ -64,9 +65,10 @@ impl GraphNode for FloatTerm {
 
 impl std::fmt::Display for FloatTerm {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match as_integer(self.value) {
-            Some(value) => write!(f, "{}.0", value),
-            None => write!(f, "{}", self.value),
+        if self.value.is_finite() {
+            write!(f, "{}", format_float(self.value, FloatFormat::default()))
+        } else {
+            write!(f, "{}", self.value)
         }
     }
 }
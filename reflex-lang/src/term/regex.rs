@@ -0,0 +1,121 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{collections::HashSet, hash::Hash, ops::Deref, sync::Arc};
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value as JsonValue;
+
+use reflex::{
+    core::{
+        DependencyList, Expression, GraphNode, Regex, RegexTermType, SerializeJson, StackOffset,
+        StringValue,
+    },
+    regex::compile_regex,
+};
+
+#[derive(Clone, Serialize)]
+pub struct RegexTerm<T: Expression> {
+    pattern: T::String,
+    /// Compiled pattern, computed once when the term is created
+    #[serde(skip_serializing)]
+    compiled: Result<Arc<Regex>, String>,
+}
+
+impl<T: Expression> RegexTerm<T> {
+    pub fn new(pattern: T::String) -> Self {
+        let compiled = compile_regex(pattern.as_str().deref());
+        Self { pattern, compiled }
+    }
+}
+impl<T: Expression> PartialEq for RegexTerm<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+    }
+}
+impl<T: Expression> Eq for RegexTerm<T> {}
+impl<T: Expression> Hash for RegexTerm<T>
+where
+    T::String: Hash,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.pattern.hash(state);
+    }
+}
+impl<'de, T: Expression> Deserialize<'de> for RegexTerm<T>
+where
+    T::String: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct SerializedRegexTerm<S> {
+            pattern: S,
+        }
+        let SerializedRegexTerm { pattern } = SerializedRegexTerm::deserialize(deserializer)?;
+        Ok(Self::new(pattern))
+    }
+}
+impl<T: Expression> RegexTermType<T> for RegexTerm<T> {
+    fn pattern<'a>(&'a self) -> T::StringRef<'a>
+    where
+        T::String: 'a,
+        T: 'a,
+        Self: 'a,
+    {
+        (&self.pattern).into()
+    }
+    fn compiled(&self) -> Result<Arc<Regex>, String> {
+        self.compiled.clone()
+    }
+}
+impl<T: Expression> GraphNode for RegexTerm<T> {
+    fn size(&self) -> usize {
+        1
+    }
+    fn capture_depth(&self) -> StackOffset {
+        0
+    }
+    fn free_variables(&self) -> HashSet<StackOffset> {
+        HashSet::new()
+    }
+    fn count_variable_usages(&self, _offset: StackOffset) -> usize {
+        0
+    }
+    fn dynamic_dependencies(&self, _deep: bool) -> DependencyList {
+        DependencyList::empty()
+    }
+    fn has_dynamic_dependencies(&self, _deep: bool) -> bool {
+        false
+    }
+    fn is_static(&self) -> bool {
+        true
+    }
+    fn is_atomic(&self) -> bool {
+        true
+    }
+    fn is_complex(&self) -> bool {
+        false
+    }
+}
+
+impl<T: Expression> std::fmt::Display for RegexTerm<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "/{}/", self.pattern.as_str().deref())
+    }
+}
+impl<T: Expression> std::fmt::Debug for RegexTerm<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+impl<T: Expression> SerializeJson for RegexTerm<T> {
+    fn to_json(&self) -> Result<JsonValue, String> {
+        Err(format!("Unable to serialize term: {}", self))
+    }
+    fn patch(&self, target: &Self) -> Result<Option<JsonValue>, String> {
+        Err(format!(
+            "Unable to create patch for terms: {}, {}",
+            self, target
+        ))
+    }
+}
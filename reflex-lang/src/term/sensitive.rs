@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+use reflex::core::{
+    DependencyList, Expression, GraphNode, SensitiveTermType, SerializeJson, StackOffset,
+};
+
+/// Placeholder emitted in place of the underlying value whenever a sensitive term is formatted or serialized
+pub const SENSITIVE_TERM_PLACEHOLDER: &str = "***";
+
+#[derive(Eq, PartialEq, Clone, Serialize, Deserialize)]
+pub struct SensitiveTerm<T: Expression> {
+    value: T,
+}
+
+impl<T: Expression> std::hash::Hash for SensitiveTerm<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.value.id().hash(state);
+    }
+}
+
+impl<T: Expression> SensitiveTerm<T> {
+    pub fn new(value: T) -> Self {
+        Self { value }
+    }
+}
+impl<T: Expression> SensitiveTermType<T> for SensitiveTerm<T> {
+    fn value<'a>(&'a self) -> T::ExpressionRef<'a>
+    where
+        T: 'a,
+    {
+        (&self.value).into()
+    }
+}
+impl<T: Expression> GraphNode for SensitiveTerm<T> {
+    // The wrapped value is opaque to the rest of the graph, so the sensitive term behaves as a static leaf node
+    fn size(&self) -> usize {
+        1
+    }
+    fn capture_depth(&self) -> StackOffset {
+        0
+    }
+    fn free_variables(&self) -> HashSet<StackOffset> {
+        HashSet::new()
+    }
+    fn count_variable_usages(&self, _offset: StackOffset) -> usize {
+        0
+    }
+    fn dynamic_dependencies(&self, _deep: bool) -> DependencyList {
+        DependencyList::empty()
+    }
+    fn has_dynamic_dependencies(&self, _deep: bool) -> bool {
+        false
+    }
+    fn is_static(&self) -> bool {
+        true
+    }
+    fn is_atomic(&self) -> bool {
+        true
+    }
+    fn is_complex(&self) -> bool {
+        false
+    }
+}
+
+impl<T: Expression> std::fmt::Display for SensitiveTerm<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SENSITIVE_TERM_PLACEHOLDER)
+    }
+}
+impl<T: Expression> std::fmt::Debug for SensitiveTerm<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+impl<T: Expression> SerializeJson for SensitiveTerm<T> {
+    fn to_json(&self) -> Result<JsonValue, String> {
+        Ok(JsonValue::String(String::from(SENSITIVE_TERM_PLACEHOLDER)))
+    }
+    fn patch(&self, target: &Self) -> Result<Option<JsonValue>, String> {
+        if self.value == target.value {
+            Ok(None)
+        } else {
+            target.to_json().map(Some)
+        }
+    }
+}
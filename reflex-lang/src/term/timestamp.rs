@@ -3,27 +3,32 @@
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 use std::collections::HashSet;
 
-use chrono::{DateTime, NaiveDateTime, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 
 use reflex::core::{
     DependencyList, GraphNode, SerializeJson, StackOffset, TimestampTermType, TimestampValue,
+    TimezoneOffset,
 };
 
 #[derive(PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Hash)]
 pub struct TimestampTerm {
     millis: TimestampValue,
+    offset: TimezoneOffset,
 }
 impl TimestampTerm {
-    pub fn new(millis: TimestampValue) -> Self {
-        Self { millis }
+    pub fn new(millis: TimestampValue, offset: TimezoneOffset) -> Self {
+        Self { millis, offset }
     }
 }
 impl TimestampTermType for TimestampTerm {
     fn millis(&self) -> TimestampValue {
         self.millis
     }
+    fn offset(&self) -> TimezoneOffset {
+        self.offset
+    }
 }
 impl GraphNode for TimestampTerm {
     fn size(&self) -> usize {
@@ -57,7 +62,7 @@ impl GraphNode for TimestampTerm {
 
 impl std::fmt::Display for TimestampTerm {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "<{}>", format_datetime_utc(self.millis))
+        write!(f, "<{}>", format_datetime(self.millis, self.offset))
     }
 }
 impl std::fmt::Debug for TimestampTerm {
@@ -69,7 +74,7 @@ impl SerializeJson for TimestampTerm {
     fn to_json(&self) -> Result<JsonValue, String> {
         Ok(JsonValue::String(format!(
             "{}",
-            format_datetime_utc(self.millis)
+            format_datetime(self.millis, self.offset)
         )))
     }
     fn patch(&self, target: &Self) -> Result<Option<JsonValue>, String> {
@@ -77,10 +82,46 @@ impl SerializeJson for TimestampTerm {
     }
 }
 
-pub fn format_datetime_utc(millis: TimestampValue) -> impl std::fmt::Display {
+/// Format the timestamp as an ISO-8601 string, expressed as local time at the given UTC offset
+///
+/// Zero offsets are written with the `Z` suffix, all other offsets are written as `±HH:MM`
+pub fn format_datetime(millis: TimestampValue, offset: TimezoneOffset) -> impl std::fmt::Display {
     let datetime: DateTime<Utc> = DateTime::from_utc(
         NaiveDateTime::from_timestamp_millis(millis).unwrap_or_default(),
         Utc,
     );
-    datetime.format("%Y-%m-%dT%H:%M:%S%.3fZ")
+    match FixedOffset::east_opt(offset * 60).filter(|_| offset != 0) {
+        Some(timezone) => datetime
+            .with_timezone(&timezone)
+            .format("%Y-%m-%dT%H:%M:%S%.3f%:z")
+            .to_string(),
+        None => datetime.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn timestamp_offsets() {
+        assert_eq!(
+            TimestampTerm::new(1677906367089, 0).to_json(),
+            Ok(json!("2023-03-04T05:06:07.089Z")),
+        );
+        assert_eq!(
+            TimestampTerm::new(1677899167089, 120).to_json(),
+            Ok(json!("2023-03-04T05:06:07.089+02:00")),
+        );
+        assert_eq!(
+            TimestampTerm::new(1677906367089, -330).to_json(),
+            Ok(json!("2023-03-03T23:36:07.089-05:30")),
+        );
+        assert_eq!(
+            format!("{}", TimestampTerm::new(1677899167089, 120)),
+            "<2023-03-04T05:06:07.089+02:00>",
+        );
+    }
 }
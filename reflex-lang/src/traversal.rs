@@ -0,0 +1,284 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{collections::HashSet, iter::once};
+
+use reflex::core::{
+    ApplicationTermType, Builtin, CompoundNode, GraphNode, LambdaTermType, LazyResultTermType,
+    LetTermType, ListTermType, PartialApplicationTermType, RecordTermType, RecursiveTermType,
+    StackOffset, StructPrototypeType, VariableTermType,
+};
+use reflex_utils::WorkStack;
+
+use crate::{term::Term, CachedSharedTerm};
+
+/// Determine the set of variables referenced by the provided expression that are not bound within the expression itself,
+/// using an explicit work stack rather than recursing through the expression tree
+pub(crate) fn free_variables<TBuiltin: Builtin>(
+    expression: &CachedSharedTerm<TBuiltin>,
+) -> HashSet<StackOffset> {
+    let mut results = HashSet::new();
+    // Each work item consists of a subexpression, combined with the number of variables bound by its ancestors
+    let mut stack = WorkStack::new((expression, 0));
+    while let Some((expression, depth)) = stack.pop() {
+        // Skip over any branches that do not reference variables bound outside the root expression
+        // (capture depth is cached for each expression so this does not require traversing the branch)
+        if expression.capture_depth() <= depth {
+            continue;
+        }
+        match expression.inner_term() {
+            Term::Variable(term) => {
+                let offset = term.offset();
+                if offset >= depth {
+                    results.insert(offset - depth);
+                }
+            }
+            Term::Let(term) => {
+                stack.extend([(term.initializer(), depth), (term.body(), depth + 1)]);
+            }
+            Term::Lambda(term) => {
+                stack.push((term.body(), depth + term.num_args()));
+            }
+            Term::LazyResult(term) => stack.extend(term.children().map(|child| (child, depth))),
+            Term::Application(term) => stack.extend(term.children().map(|child| (child, depth))),
+            Term::PartialApplication(term) => {
+                stack.extend(term.children().map(|child| (child, depth)))
+            }
+            Term::Recursive(term) => stack.extend(term.children().map(|child| (child, depth))),
+            Term::Record(term) => stack.extend(term.children().map(|child| (child, depth))),
+            Term::List(term) => stack.extend(term.children().map(|child| (child, depth))),
+            Term::HashMap(term) => stack.extend(term.children().map(|child| (child, depth))),
+            Term::HashSet(term) => stack.extend(term.children().map(|child| (child, depth))),
+            _ => {}
+        }
+    }
+    results
+}
+
+enum DisplayFragment<'a, T> {
+    Literal(&'static str),
+    Text(String),
+    Expression(&'a T),
+}
+
+/// Format the provided expression using an explicit work stack rather than recursing through the expression tree
+///
+/// Compound terms are expanded into a sequence of output fragments which are pushed onto the work stack, with any
+/// remaining terms delegating to their own `Display` implementations.
+pub(crate) fn fmt_expression<TBuiltin: Builtin>(
+    expression: &CachedSharedTerm<TBuiltin>,
+    f: &mut std::fmt::Formatter<'_>,
+) -> std::fmt::Result {
+    let max_displayed_items = 100;
+    let mut stack = WorkStack::new(DisplayFragment::Expression(expression));
+    while let Some(fragment) = stack.pop() {
+        let expression = match fragment {
+            DisplayFragment::Literal(value) => {
+                f.write_str(value)?;
+                continue;
+            }
+            DisplayFragment::Text(value) => {
+                f.write_str(&value)?;
+                continue;
+            }
+            DisplayFragment::Expression(expression) => expression,
+        };
+        match expression.inner_term() {
+            Term::Let(term) => stack.extend([
+                DisplayFragment::Literal("<let:"),
+                DisplayFragment::Expression(term.initializer()),
+                DisplayFragment::Literal(":"),
+                DisplayFragment::Expression(term.body()),
+                DisplayFragment::Literal(">"),
+            ]),
+            Term::LazyResult(term) => stack.extend([
+                DisplayFragment::Literal("<lazy:"),
+                DisplayFragment::Expression(term.value()),
+                DisplayFragment::Literal(">"),
+            ]),
+            Term::Recursive(term) => stack.extend([
+                DisplayFragment::Literal("<recursive:"),
+                DisplayFragment::Expression(term.factory()),
+                DisplayFragment::Literal(">"),
+            ]),
+            Term::PartialApplication(term) => stack.extend([
+                DisplayFragment::Text(format!("<partial:{}:", term.args().len())),
+                DisplayFragment::Expression(term.target()),
+                DisplayFragment::Literal(">"),
+            ]),
+            Term::Application(term) => stack.extend(
+                [
+                    DisplayFragment::Literal("<apply:"),
+                    DisplayFragment::Expression(term.target()),
+                    DisplayFragment::Literal(":("),
+                ]
+                .into_iter()
+                .chain(join_fragments(
+                    term.args()
+                        .items()
+                        .map(|arg| vec![DisplayFragment::Expression(arg)]),
+                    ", ",
+                ))
+                .chain(once(DisplayFragment::Literal(")>"))),
+            ),
+            Term::List(term) => {
+                let items = term.items();
+                let num_items = items.len();
+                let displayed_items = if num_items <= max_displayed_items {
+                    items
+                        .items()
+                        .map(DisplayFragment::Expression)
+                        .collect::<Vec<_>>()
+                } else {
+                    items
+                        .items()
+                        .take(max_displayed_items - 1)
+                        .map(DisplayFragment::Expression)
+                        .chain(once(DisplayFragment::Text(format!(
+                            "...{} more items",
+                            num_items - (max_displayed_items - 1)
+                        ))))
+                        .collect::<Vec<_>>()
+                };
+                stack.extend(
+                    once(DisplayFragment::Literal("["))
+                        .chain(join_fragments(
+                            displayed_items.into_iter().map(|item| vec![item]),
+                            ", ",
+                        ))
+                        .chain(once(DisplayFragment::Literal("]"))),
+                )
+            }
+            Term::Record(term) => {
+                let keys = term.prototype().keys();
+                if keys.is_empty() {
+                    f.write_str("{}")?;
+                } else {
+                    stack.extend(
+                        once(DisplayFragment::Literal("{ "))
+                            .chain(join_fragments(
+                                keys.items().zip(term.values().items()).map(|(key, value)| {
+                                    vec![
+                                        DisplayFragment::Expression(key),
+                                        DisplayFragment::Literal(": "),
+                                        DisplayFragment::Expression(value),
+                                    ]
+                                }),
+                                ", ",
+                            ))
+                            .chain(once(DisplayFragment::Literal(" }"))),
+                    )
+                }
+            }
+            term => std::fmt::Display::fmt(term, f)?,
+        }
+    }
+    Ok(())
+}
+
+fn join_fragments<'a, T: 'a>(
+    items: impl IntoIterator<Item = Vec<DisplayFragment<'a, T>>>,
+    separator: &'static str,
+) -> impl Iterator<Item = DisplayFragment<'a, T>> {
+    items
+        .into_iter()
+        .enumerate()
+        .flat_map(move |(index, item)| {
+            (if index == 0 {
+                None
+            } else {
+                Some(DisplayFragment::Literal(separator))
+            })
+            .into_iter()
+            .chain(item)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use reflex::core::{ExpressionFactory, GraphNode, HeapAllocator};
+    use reflex_stdlib::{Add, Stdlib};
+
+    use crate::{allocator::DefaultAllocator, SharedTermFactory};
+
+    #[test]
+    fn free_variables() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let expression = factory.create_let_term(
+            factory.create_variable_term(4),
+            factory.create_lambda_term(
+                2,
+                factory.create_application_term(
+                    factory.create_builtin_term(Add),
+                    allocator.create_list([
+                        factory.create_variable_term(0),
+                        factory.create_variable_term(2),
+                        factory.create_variable_term(5),
+                        factory.create_record_term(
+                            allocator.create_struct_prototype(allocator.create_unit_list(
+                                factory.create_string_term(allocator.create_static_string("foo")),
+                            )),
+                            allocator.create_unit_list(factory.create_variable_term(7)),
+                        ),
+                    ]),
+                ),
+            ),
+        );
+        assert_eq!(expression.free_variables(), [4, 2, 4].into_iter().collect(),);
+        assert_eq!(expression.capture_depth(), 5);
+    }
+
+    #[test]
+    fn display() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let expression = factory.create_let_term(
+            factory.create_int_term(3),
+            factory.create_application_term(
+                factory.create_builtin_term(Add),
+                allocator.create_pair(
+                    factory.create_variable_term(0),
+                    factory.create_record_term(
+                        allocator.create_struct_prototype(allocator.create_pair(
+                            factory.create_string_term(allocator.create_static_string("foo")),
+                            factory.create_string_term(allocator.create_static_string("bar")),
+                        )),
+                        allocator.create_pair(
+                            factory.create_list_term(allocator.create_empty_list()),
+                            factory.create_list_term(allocator.create_pair(
+                                factory.create_int_term(4),
+                                factory.create_nil_term(),
+                            )),
+                        ),
+                    ),
+                ),
+            ),
+        );
+        assert_eq!(
+            format!("{}", expression),
+            format!("{}", expression.value().value().value()),
+        );
+        assert_eq!(
+            format!("{}", expression),
+            "<let:3:<apply:<stdlib:Add>:(<variable:0>, { \"foo\": [], \"bar\": [4, null] })>>",
+        );
+    }
+
+    #[test]
+    fn deeply_nested_expressions() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let depth = 100_000;
+        let expression = (0..depth).fold(factory.create_variable_term(depth + 3), |body, _| {
+            factory.create_let_term(factory.create_int_term(3), body)
+        });
+        assert_eq!(expression.free_variables(), [3].into_iter().collect());
+        assert_eq!(expression.capture_depth(), 4);
+        let formatted = format!("{}", expression);
+        assert!(formatted.starts_with("<let:3:<let:3:"));
+        assert!(formatted.ends_with(&format!("<variable:{}>{}", depth + 3, ">".repeat(depth))));
+        // Dropping the expression would trigger a recursive destructor call for each nested term, which is outside the
+        // scope of this test
+        std::mem::forget(expression);
+    }
+}
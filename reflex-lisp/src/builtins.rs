@@ -68,6 +68,17 @@ impl Builtin for LispBuiltins {
             LispBuiltins::Lisp(term) => term.should_parallelize(args),
         }
     }
+    fn apply_static<T: Expression<Builtin = Self>>(
+        &self,
+        args: &[T],
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+    ) -> Option<T> {
+        match self {
+            LispBuiltins::Stdlib(term) => term.apply_static(args, factory, allocator),
+            _ => None,
+        }
+    }
 }
 impl std::fmt::Display for LispBuiltins {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -156,11 +167,41 @@ impl From<stdlib::Contains> for LispBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::DateAdd> for LispBuiltins {
+    fn from(value: stdlib::DateAdd) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateDiff> for LispBuiltins {
+    fn from(value: stdlib::DateDiff) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateOffset> for LispBuiltins {
+    fn from(value: stdlib::DateOffset) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateTruncate> for LispBuiltins {
+    fn from(value: stdlib::DateTruncate) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateWithOffset> for LispBuiltins {
+    fn from(value: stdlib::DateWithOffset) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Divide> for LispBuiltins {
     fn from(value: stdlib::Divide) -> Self {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Duration> for LispBuiltins {
+    fn from(value: stdlib::Duration) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Effect> for LispBuiltins {
     fn from(value: stdlib::Effect) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -181,6 +222,11 @@ impl From<stdlib::Equal> for LispBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Eval> for LispBuiltins {
+    fn from(value: stdlib::Eval) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Filter> for LispBuiltins {
     fn from(value: stdlib::Filter) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -266,11 +312,26 @@ impl From<stdlib::Map> for LispBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Match> for LispBuiltins {
+    fn from(value: stdlib::Match) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Max> for LispBuiltins {
     fn from(value: stdlib::Max) -> Self {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Memo> for LispBuiltins {
+    fn from(value: stdlib::Memo) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::MemoStore> for LispBuiltins {
+    fn from(value: stdlib::MemoStore) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Merge> for LispBuiltins {
     fn from(value: stdlib::Merge) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -311,6 +372,11 @@ impl From<stdlib::PushFront> for LispBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Quote> for LispBuiltins {
+    fn from(value: stdlib::Quote) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Raise> for LispBuiltins {
     fn from(value: stdlib::Raise) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -361,6 +427,11 @@ impl From<stdlib::ResolveRecord> for LispBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Reveal> for LispBuiltins {
+    fn from(value: stdlib::Reveal) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Round> for LispBuiltins {
     fn from(value: stdlib::Round) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -376,6 +447,21 @@ impl From<stdlib::Slice> for LispBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Sort> for LispBuiltins {
+    fn from(value: stdlib::Sort) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::SortBy> for LispBuiltins {
+    fn from(value: stdlib::SortBy) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::SortMerge> for LispBuiltins {
+    fn from(value: stdlib::SortMerge) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Split> for LispBuiltins {
     fn from(value: stdlib::Split) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -391,6 +477,11 @@ impl From<stdlib::Subtract> for LispBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Template> for LispBuiltins {
+    fn from(value: stdlib::Template) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Unzip> for LispBuiltins {
     fn from(value: stdlib::Unzip) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -9,8 +9,9 @@ use std::{
 use reflex::{
     cache::SubstitutionCache,
     core::{
-        Builtin, EvaluationCache, Expression, ExpressionFactory, ExpressionListType, HeapAllocator,
-        IntValue, RefType, Rewritable, Substitutions, SymbolId,
+        validate_builtin_application_arity, Builtin, BuiltinTermType, EvaluationCache, Expression,
+        ExpressionFactory, ExpressionListType, HeapAllocator, IntValue, RefType, Rewritable,
+        Substitutions, SymbolId,
     },
 };
 use reflex_stdlib::stdlib;
@@ -413,7 +414,7 @@ where
 }
 
 fn parse_function_application<'src, T: Expression + Rewritable<T>>(
-    _input: &SyntaxDatum<'src>,
+    input: &SyntaxDatum<'src>,
     target: &SyntaxDatum<'src>,
     args: &[SyntaxDatum<'src>],
     scope: &LexicalScope<'src>,
@@ -425,15 +426,20 @@ fn parse_function_application<'src, T: Expression + Rewritable<T>>(
 where
     T::Builtin: LispParserBuiltin,
 {
+    let target = parse_expression(
+        target,
+        scope,
+        symbol_cache,
+        evaluation_cache,
+        factory,
+        allocator,
+    )?;
+    if let Some(term) = factory.match_builtin_term(&target) {
+        validate_builtin_application_arity(&term.target(), args.len())
+            .map_err(|message| ParserError::new(message, input))?;
+    }
     Ok(factory.create_application_term(
-        parse_expression(
-            target,
-            scope,
-            symbol_cache,
-            evaluation_cache,
-            factory,
-            allocator,
-        )?,
+        target,
         parse_function_arguments(
             args,
             scope,
@@ -2389,6 +2395,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn invalid_builtin_applications() {
+        let factory = SharedTermFactory::<LispBuiltins>::default();
+        let allocator = DefaultAllocator::default();
+        assert_eq!(
+            parse("(+ 1)", &factory, &allocator).unwrap_err().message(),
+            "<stdlib:Add>: Expected 2 arguments, received 1 (signature: <stdlib:Add>(strict, strict))",
+        );
+        assert_eq!(parse("(+ 1 2 3)", &factory, &allocator).is_ok(), true);
+    }
+
     #[test]
     fn nested_expressions() {
         let state = StateCache::default();
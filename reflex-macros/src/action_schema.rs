@@ -0,0 +1,120 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use crate::utils;
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::{punctuated::Punctuated, spanned::Spanned, token::Comma, Data, DeriveInput, Fields};
+
+pub fn execute(input: TokenStream) -> TokenStream {
+    syn::parse(input)
+        .and_then(|ast: DeriveInput| {
+            let name = &ast.ident;
+            let generic_params = &ast.generics.params;
+            let generic_args =
+                utils::create_generic_arguments_for_params(ast.generics.params.iter())
+                    .collect::<Punctuated<_, Comma>>();
+            let where_clause = &ast.generics.where_clause;
+            let body = match &ast.data {
+                Data::Struct(data) => parse_struct_schema(name, &data.fields),
+                Data::Enum(data) => parse_enum_schema(name, data.variants.iter()),
+                Data::Union(_) => Err(syn::Error::new(
+                    ast.span(),
+                    "ActionSchema cannot be derived for union types",
+                )),
+            }?;
+            Ok(TokenStream::from(quote! {
+                impl<#generic_params> ::reflex_dispatcher::ActionSchema for #name<#generic_args>
+                    #where_clause
+                {
+                    fn schema() -> ::reflex_dispatcher::ActionSchemaNode {
+                        #body
+                    }
+                }
+            }))
+        })
+        .unwrap_or_else(|err| err.to_compile_error().into())
+}
+
+fn parse_struct_schema(name: &syn::Ident, fields: &Fields) -> syn::Result<TokenStream2> {
+    let fields = match fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .filter_map(|field| {
+                field.ident.as_ref().map(|field_name| {
+                    let type_name = format_type_name(&field.ty);
+                    quote! {
+                        ::reflex_dispatcher::ActionSchemaField {
+                            name: String::from(stringify!(#field_name)),
+                            type_name: String::from(#type_name),
+                        }
+                    }
+                })
+            })
+            .collect::<Vec<_>>(),
+        Fields::Unit => Vec::new(),
+        Fields::Unnamed(fields) => {
+            return Err(syn::Error::new(
+                fields.span(),
+                "ActionSchema cannot be derived for tuple structs",
+            ))
+        }
+    };
+    Ok(quote! {
+        ::reflex_dispatcher::ActionSchemaNode::Action {
+            name: String::from(stringify!(#name)),
+            fields: vec![#(#fields),*],
+        }
+    })
+}
+
+fn parse_enum_schema<'a>(
+    name: &syn::Ident,
+    variants: impl IntoIterator<Item = &'a syn::Variant>,
+) -> syn::Result<TokenStream2> {
+    let variants = variants
+        .into_iter()
+        .map(|variant| {
+            let variant_name = &variant.ident;
+            let inner_type = match &variant.fields {
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                    fields.unnamed.first().map(|field| &field.ty)
+                }
+                _ => None,
+            }
+            .ok_or_else(|| {
+                syn::Error::new(
+                    variant.span(),
+                    "ActionSchema enum variants must contain a single unnamed action field",
+                )
+            })?;
+            Ok(quote! {
+                ::reflex_dispatcher::ActionSchemaVariant {
+                    name: String::from(stringify!(#variant_name)),
+                    schema: <#inner_type as ::reflex_dispatcher::ActionSchema>::schema(),
+                }
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        ::reflex_dispatcher::ActionSchemaNode::Group {
+            name: String::from(stringify!(#name)),
+            variants: vec![#(#variants),*],
+        }
+    })
+}
+
+fn format_type_name(ty: &syn::Type) -> String {
+    ty.to_token_stream()
+        .to_string()
+        .replace(" < ", "<")
+        .replace("< ", "<")
+        .replace(" <", "<")
+        .replace(" >", ">")
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+}
@@ -6,6 +6,7 @@
 use pointer_iter::pointer_iter;
 use proc_macro::TokenStream;
 
+mod action_schema;
 mod blanket_trait;
 mod dispatcher;
 mod matcher;
@@ -29,6 +30,11 @@ pub fn named_matcher(input: TokenStream) -> TokenStream {
     named::execute(input)
 }
 
+#[proc_macro_derive(ActionSchema)]
+pub fn derive_action_schema(input: TokenStream) -> TokenStream {
+    action_schema::execute(input)
+}
+
 #[proc_macro]
 pub fn dispatcher(input: TokenStream) -> TokenStream {
     dispatcher::execute(input)
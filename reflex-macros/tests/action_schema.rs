@@ -0,0 +1,69 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex_dispatcher::{ActionSchema, ActionSchemaField, ActionSchemaNode, ActionSchemaVariant};
+use reflex_macros::ActionSchema;
+
+#[test]
+fn basic_usage() {
+    #[derive(ActionSchema)]
+    #[allow(dead_code)]
+    struct Foo {
+        value: Option<Vec<u32>>,
+    }
+    #[derive(ActionSchema)]
+    struct Bar;
+    #[derive(ActionSchema)]
+    #[allow(dead_code)]
+    enum Actions {
+        Foo(Foo),
+        Bar(Bar),
+    }
+    assert_eq!(
+        <Actions as ActionSchema>::schema(),
+        ActionSchemaNode::Group {
+            name: String::from("Actions"),
+            variants: vec![
+                ActionSchemaVariant {
+                    name: String::from("Foo"),
+                    schema: ActionSchemaNode::Action {
+                        name: String::from("Foo"),
+                        fields: vec![ActionSchemaField {
+                            name: String::from("value"),
+                            type_name: String::from("Option<Vec<u32>>"),
+                        }],
+                    },
+                },
+                ActionSchemaVariant {
+                    name: String::from("Bar"),
+                    schema: ActionSchemaNode::Action {
+                        name: String::from("Bar"),
+                        fields: Vec::new(),
+                    },
+                },
+            ],
+        }
+    );
+}
+
+#[test]
+fn generics() {
+    #[derive(ActionSchema)]
+    #[allow(dead_code)]
+    struct Foo<T: Clone> {
+        value: T,
+    }
+    #[derive(ActionSchema)]
+    #[allow(dead_code)]
+    enum Actions<T>
+    where
+        T: Clone,
+    {
+        Foo(Foo<T>),
+    }
+    let schema = <Actions<bool> as ActionSchema>::schema();
+    let actions = schema.actions();
+    assert_eq!(actions.len(), 1);
+    assert_eq!(actions[0].0, vec!["Foo"]);
+    assert_eq!(actions[0].1.name(), "Foo");
+}
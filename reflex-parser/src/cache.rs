@@ -0,0 +1,432 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fs,
+    iter::once,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+use derivative::Derivative;
+use reflex::{
+    core::{Expression, ModuleLoader},
+    loader::{get_module_filesystem_path, normalize_module_path},
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Version identifier incorporated into all cache keys, ensuring that cached modules are invalidated whenever the parser
+/// implementation changes
+const CACHE_VERSION: &str = concat!(env!("CARGO_PKG_NAME"), "@", env!("CARGO_PKG_VERSION"));
+
+/// Source file that was read while parsing a cached module
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct ModuleDependency {
+    pub path: PathBuf,
+    pub hash: String,
+}
+
+impl ModuleDependency {
+    fn is_valid(&self) -> bool {
+        fs::read(&self.path)
+            .map(|contents| hash_module_contents(&contents) == self.hash)
+            .unwrap_or(false)
+    }
+}
+
+/// Parsed module output, along with all the source files (including transitive imports) that contributed to it
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct CachedModule<T> {
+    pub value: T,
+    pub dependencies: Vec<ModuleDependency>,
+}
+
+/// Storage for parsed module outputs, keyed by the content hash of the module source
+pub trait ModuleCache<T: Expression> {
+    fn retrieve(&self, key: &str) -> Option<CachedModule<T>>;
+    fn store(&self, key: &str, entry: &CachedModule<T>);
+}
+
+/// Module cache that never stores any entries
+#[derive(Derivative)]
+#[derivative(
+    Default(bound = ""),
+    Clone(bound = ""),
+    Copy(bound = ""),
+    Debug(bound = "")
+)]
+pub struct NoopModuleCache<T: Expression> {
+    _expression: PhantomData<T>,
+}
+
+impl<T: Expression> ModuleCache<T> for NoopModuleCache<T> {
+    fn retrieve(&self, _key: &str) -> Option<CachedModule<T>> {
+        None
+    }
+    fn store(&self, _key: &str, _entry: &CachedModule<T>) {}
+}
+
+/// Module cache that persists entries to the given directory, serialized in MessagePack binary format
+///
+/// Any failures when reading or writing cache entries are treated as cache misses.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct FileSystemModuleCache<T: Expression> {
+    directory: PathBuf,
+    _expression: PhantomData<T>,
+}
+
+impl<T: Expression> FileSystemModuleCache<T> {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            _expression: PhantomData,
+        }
+    }
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.directory.join(format!("{}.mp", key))
+    }
+}
+
+impl<T: Expression> ModuleCache<T> for FileSystemModuleCache<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    fn retrieve(&self, key: &str) -> Option<CachedModule<T>> {
+        let bytes = fs::read(self.entry_path(key)).ok()?;
+        rmp_serde::from_slice(&bytes).ok()
+    }
+    fn store(&self, key: &str, entry: &CachedModule<T>) {
+        let bytes = match rmp_serde::to_vec(entry) {
+            Ok(bytes) => bytes,
+            Err(_) => return,
+        };
+        if fs::create_dir_all(&self.directory).is_err() {
+            return;
+        }
+        // Write to a temporary file before renaming, to avoid leaving partially-written entries if interrupted
+        let entry_path = self.entry_path(key);
+        let temp_path = entry_path.with_extension(format!("{}.tmp", std::process::id()));
+        if fs::write(&temp_path, bytes).is_ok() && fs::rename(&temp_path, &entry_path).is_err() {
+            let _ = fs::remove_file(&temp_path);
+        }
+    }
+}
+
+/// Compute the content hash used to identify a module source file
+pub fn hash_module_contents(contents: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(contents))
+}
+
+fn get_module_cache_key(path: &Path, contents: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(CACHE_VERSION.as_bytes());
+    hasher.update([0]);
+    // The module path determines how relative imports are resolved, so must be incorporated into the cache key
+    hasher.update(path.to_string_lossy().as_bytes());
+    hasher.update([0]);
+    hasher.update(contents);
+    format!("{:x}", hasher.finalize())
+}
+
+/// Module loader that caches the output of the inner loader for any modules whose filesystem path has the given file
+/// extension, skipping the inner loader entirely for modules whose source (and the source of all their transitive
+/// dependencies) is unchanged since the output was cached
+///
+/// Each cacheable module is loaded at most once per loader session: subsequent imports of the same module (from any
+/// importer) share the module instance produced by the first import, ensuring that any effects within the module's
+/// top-level bindings are only evaluated once.
+///
+/// For dependencies to be tracked correctly, this loader must wrap the recursive loader that is used to load any nested
+/// imports.
+#[derive(Derivative)]
+#[derivative(Debug(bound = "TCache: std::fmt::Debug, TLoader: std::fmt::Debug"))]
+pub struct CachingModuleLoader<
+    T: Expression,
+    TCache: ModuleCache<T>,
+    TLoader: ModuleLoader<Output = T>,
+> {
+    extension: &'static str,
+    cache: TCache,
+    inner: TLoader,
+    /// Dependencies collected for each of the currently-loading cacheable modules
+    dependencies: RefCell<Vec<Vec<ModuleDependency>>>,
+    /// Modules that have already been loaded during this loader session, keyed by module cache key
+    loaded: RefCell<HashMap<String, CachedModule<T>>>,
+    _expression: PhantomData<T>,
+}
+
+impl<T: Expression, TCache: ModuleCache<T>, TLoader: ModuleLoader<Output = T>>
+    CachingModuleLoader<T, TCache, TLoader>
+{
+    pub fn new(extension: &'static str, cache: TCache, inner: TLoader) -> Self {
+        Self {
+            extension,
+            cache,
+            inner,
+            dependencies: RefCell::new(Vec::new()),
+            loaded: RefCell::new(HashMap::new()),
+            _expression: PhantomData,
+        }
+    }
+    fn record_dependencies(&self, dependencies: impl IntoIterator<Item = ModuleDependency>) {
+        if let Some(collector) = self.dependencies.borrow_mut().last_mut() {
+            collector.extend(dependencies);
+        }
+    }
+}
+
+impl<T: Expression, TCache: ModuleCache<T>, TLoader: ModuleLoader<Output = T>> ModuleLoader
+    for CachingModuleLoader<T, TCache, TLoader>
+{
+    type Output = T;
+    fn load(&self, import_path: &str, current_path: &Path) -> Option<Result<Self::Output, String>> {
+        let is_cacheable = import_path.ends_with(self.extension);
+        // Dependencies only need to be tracked if they were imported from within a cacheable module
+        if !is_cacheable && self.dependencies.borrow().is_empty() {
+            return self.inner.load(import_path, current_path);
+        }
+        let target_path =
+            normalize_module_path(&get_module_filesystem_path(import_path, current_path));
+        let contents = match target_path.is_file() {
+            true => fs::read(&target_path).ok(),
+            false => None,
+        };
+        let contents = match contents {
+            None => return self.inner.load(import_path, current_path),
+            Some(contents) => contents,
+        };
+        let dependency = ModuleDependency {
+            path: target_path.clone(),
+            hash: hash_module_contents(&contents),
+        };
+        if !is_cacheable {
+            let result = self.inner.load(import_path, current_path);
+            if result.is_some() {
+                self.record_dependencies([dependency]);
+            }
+            return result;
+        }
+        let key = get_module_cache_key(&target_path, &contents);
+        let loaded = self.loaded.borrow().get(&key).cloned();
+        if let Some(entry) = loaded {
+            self.record_dependencies(once(dependency).chain(entry.dependencies));
+            return Some(Ok(entry.value));
+        }
+        if let Some(entry) = self.cache.retrieve(&key) {
+            if entry
+                .dependencies
+                .iter()
+                .all(|dependency| dependency.is_valid())
+            {
+                self.loaded.borrow_mut().insert(key, entry.clone());
+                self.record_dependencies(once(dependency).chain(entry.dependencies));
+                return Some(Ok(entry.value));
+            }
+        }
+        self.dependencies.borrow_mut().push(Vec::new());
+        let result = self.inner.load(import_path, current_path);
+        let mut dependencies = self.dependencies.borrow_mut().pop().unwrap_or_default();
+        dependencies.sort_by(|a, b| a.path.cmp(&b.path));
+        dependencies.dedup();
+        if let Some(Ok(value)) = &result {
+            let entry = CachedModule {
+                value: value.clone(),
+                dependencies,
+            };
+            self.cache.store(&key, &entry);
+            self.loaded.borrow_mut().insert(key, entry.clone());
+            self.record_dependencies(once(dependency).chain(entry.dependencies));
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use reflex::{
+        core::{ExpressionFactory, HeapAllocator, IntTermType},
+        loader::{BoxedModuleLoader, RecursiveModuleLoader},
+    };
+    use reflex_lang::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
+    use reflex_stdlib::Stdlib;
+
+    use super::*;
+
+    type TestTerm = CachedSharedTerm<Stdlib>;
+
+    #[derive(Default, Clone)]
+    struct InMemoryModuleCache {
+        entries: Rc<RefCell<HashMap<String, CachedModule<TestTerm>>>>,
+    }
+
+    impl ModuleCache<TestTerm> for InMemoryModuleCache {
+        fn retrieve(&self, key: &str) -> Option<CachedModule<TestTerm>> {
+            self.entries.borrow().get(key).cloned()
+        }
+        fn store(&self, key: &str, entry: &CachedModule<TestTerm>) {
+            self.entries
+                .borrow_mut()
+                .insert(String::from(key), entry.clone());
+        }
+    }
+
+    /// Loader that sums the integer on the first line of the target file with the values of any imports listed on
+    /// subsequent lines, keeping track of how many times it was invoked
+    struct SummingModuleLoader {
+        loader: BoxedModuleLoader<TestTerm>,
+        factory: SharedTermFactory<Stdlib>,
+        num_loads: Rc<Cell<usize>>,
+    }
+
+    impl ModuleLoader for SummingModuleLoader {
+        type Output = TestTerm;
+        fn load(
+            &self,
+            import_path: &str,
+            current_path: &Path,
+        ) -> Option<Result<Self::Output, String>> {
+            self.num_loads.set(self.num_loads.get() + 1);
+            let target_path = get_module_filesystem_path(import_path, current_path);
+            let source = fs::read_to_string(&target_path).ok()?;
+            let mut lines = source.lines();
+            let mut value = lines.next()?.trim().parse::<i64>().ok()?;
+            for import in lines {
+                match self.loader.load(import.trim(), &target_path)? {
+                    Ok(imported) => value += self.factory.match_int_term(&imported)?.value(),
+                    Err(err) => return Some(Err(err)),
+                }
+            }
+            Some(Ok(self.factory.create_int_term(value)))
+        }
+    }
+
+    fn create_loader(
+        cache: &(impl ModuleCache<TestTerm> + Clone + 'static),
+        num_loads: &Rc<Cell<usize>>,
+    ) -> RecursiveModuleLoader<TestTerm> {
+        let cache = cache.clone();
+        let num_loads = num_loads.clone();
+        RecursiveModuleLoader::new(move |loader| {
+            CachingModuleLoader::new(
+                ".js",
+                cache,
+                SummingModuleLoader {
+                    loader,
+                    factory: SharedTermFactory::<Stdlib>::default(),
+                    num_loads,
+                },
+            )
+        })
+    }
+
+    #[test]
+    fn cached_modules() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let directory =
+            std::env::temp_dir().join(format!("reflex-parser-module-cache-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join("foo.js"), "1\n./bar.js").unwrap();
+        fs::write(directory.join("bar.js"), "2\n./baz.txt").unwrap();
+        fs::write(directory.join("baz.txt"), "3").unwrap();
+        let entry_path = directory.join("index.js");
+        let cache = InMemoryModuleCache::default();
+        let num_loads = Rc::new(Cell::new(0));
+
+        let loader = create_loader(&cache, &num_loads);
+        assert_eq!(
+            loader.load("./foo.js", &entry_path),
+            Some(Ok(factory.create_int_term(6))),
+        );
+        assert_eq!(num_loads.get(), 3);
+        assert_eq!(cache.entries.borrow().len(), 2);
+
+        // Unchanged modules are loaded from the cache
+        num_loads.set(0);
+        let loader = create_loader(&cache, &num_loads);
+        assert_eq!(
+            loader.load("./foo.js", &entry_path),
+            Some(Ok(factory.create_int_term(6))),
+        );
+        assert_eq!(num_loads.get(), 0);
+
+        // Modules are reloaded if a transitive dependency has changed
+        fs::write(directory.join("baz.txt"), "4").unwrap();
+        num_loads.set(0);
+        let loader = create_loader(&cache, &num_loads);
+        assert_eq!(
+            loader.load("./foo.js", &entry_path),
+            Some(Ok(factory.create_int_term(7))),
+        );
+        assert_eq!(num_loads.get(), 3);
+
+        // Non-file imports are passed through to the inner loader
+        assert_eq!(loader.load("reflex::core", &entry_path), None);
+
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn shared_module_instances() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let directory = std::env::temp_dir().join(format!(
+            "reflex-parser-shared-module-instances-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(directory.join("qux")).unwrap();
+        fs::write(directory.join("foo.js"), "1\n./bar.js\n./qux/quux.js").unwrap();
+        fs::write(directory.join("bar.js"), "2").unwrap();
+        fs::write(directory.join("qux").join("quux.js"), "3\n../bar.js").unwrap();
+        let entry_path = directory.join("index.js");
+        let cache = NoopModuleCache::<TestTerm>::default();
+        let num_loads = Rc::new(Cell::new(0));
+
+        // Modules imported from multiple importers are only loaded once per loader session
+        let loader = create_loader(&cache, &num_loads);
+        assert_eq!(
+            loader.load("./foo.js", &entry_path),
+            Some(Ok(factory.create_int_term(8))),
+        );
+        assert_eq!(num_loads.get(), 3);
+        assert_eq!(
+            loader.load("./qux/../bar.js", &entry_path),
+            Some(Ok(factory.create_int_term(2))),
+        );
+        assert_eq!(num_loads.get(), 3);
+
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn file_system_module_cache() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let directory = std::env::temp_dir().join(format!(
+            "reflex-parser-file-system-module-cache-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&directory);
+        let cache = FileSystemModuleCache::<TestTerm>::new(&directory);
+        let entry = CachedModule {
+            value: factory.create_list_term(
+                allocator.create_pair(factory.create_int_term(3), factory.create_variable_term(0)),
+            ),
+            dependencies: vec![ModuleDependency {
+                path: PathBuf::from("/foo.js"),
+                hash: hash_module_contents(b"foo"),
+            }],
+        };
+        assert_eq!(cache.retrieve("foo"), None);
+        cache.store("foo", &entry);
+        assert_eq!(cache.retrieve("foo"), Some(entry));
+        assert_eq!(cache.retrieve("bar"), None);
+        let _ = fs::remove_dir_all(&directory);
+    }
+}
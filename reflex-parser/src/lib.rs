@@ -3,11 +3,14 @@
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 use std::{ffi::OsStr, marker::PhantomData, path::Path, str::FromStr};
 
+use cache::NoopModuleCache;
+use loader::ImportMap;
 use reflex::{
     core::{
         Builtin, Expression, ExpressionFactory, HeapAllocator, ModuleLoader, Reducible, Rewritable,
     },
     env::inject_env_vars,
+    source_map::SourceMetadataRecorder,
 };
 use reflex_graphql::imports::GraphQlImportsBuiltin;
 use reflex_grpc::loader::GrpcLoaderBuiltin;
@@ -15,6 +18,7 @@ use reflex_handlers::imports::HandlerImportsBuiltin;
 use reflex_js::{globals::JsGlobalsBuiltin, imports::JsImportsBuiltin, JsParserBuiltin};
 use reflex_lisp::LispParserBuiltin;
 use reflex_macros::blanket_trait;
+use remote::RemoteModuleOptions;
 use syntax::{
     js::{
         create_js_module_parser, create_js_script_parser, JavaScriptModuleParser,
@@ -24,6 +28,9 @@ use syntax::{
     sexpr::{create_sexpr_parser, LispParser},
 };
 
+pub mod cache;
+pub mod loader;
+pub mod remote;
 pub mod syntax {
     pub mod js;
     pub mod json;
@@ -98,18 +105,58 @@ pub fn create_parser<
     factory: &TFactory,
     allocator: &TAllocator,
 ) -> impl SyntaxParser<T>
+where
+    T::Builtin: ParserBuiltin,
+    // TODO: Remove unnecessary trait bounds
+    T: Rewritable<T> + Reducible<T>,
+{
+    create_parser_with_source_metadata(
+        syntax,
+        entry_path,
+        module_loader,
+        env_vars,
+        None,
+        factory,
+        allocator,
+    )
+}
+
+/// Create a parser that records the source location and declared name of each parsed function into the provided
+/// recorder (source metadata is currently only produced for JavaScript sources)
+pub fn create_parser_with_source_metadata<
+    T: Expression + 'static,
+    TFactory: ExpressionFactory<T> + Clone + 'static,
+    TAllocator: HeapAllocator<T> + Clone + 'static,
+>(
+    syntax: Syntax,
+    entry_path: Option<&Path>,
+    module_loader: impl ModuleLoader<Output = T> + 'static,
+    env_vars: impl IntoIterator<Item = (String, String)>,
+    source_metadata: Option<SourceMetadataRecorder>,
+    factory: &TFactory,
+    allocator: &TAllocator,
+) -> impl SyntaxParser<T>
 where
     T::Builtin: ParserBuiltin,
     // TODO: Remove unnecessary trait bounds
     T: Rewritable<T> + Reducible<T>,
 {
     let parser = match (syntax, entry_path) {
-        (Syntax::JavaScript, None) => {
-            PolyglotSyntaxParser::JavaScriptScript(create_js_script_parser(factory, allocator))
-        }
-        (Syntax::JavaScript, Some(entry_path)) => PolyglotSyntaxParser::JavaScriptModule(
-            create_js_module_parser(entry_path, module_loader, factory, allocator),
+        (Syntax::JavaScript, None) => PolyglotSyntaxParser::JavaScriptScript(
+            create_js_script_parser(source_metadata, factory, allocator),
         ),
+        (Syntax::JavaScript, Some(entry_path)) => {
+            PolyglotSyntaxParser::JavaScriptModule(create_js_module_parser(
+                entry_path,
+                ImportMap::default(),
+                RemoteModuleOptions::default(),
+                NoopModuleCache::default(),
+                module_loader,
+                source_metadata,
+                factory,
+                allocator,
+            ))
+        }
         (Syntax::Json, _) => PolyglotSyntaxParser::Json(create_json_parser(factory, allocator)),
         (Syntax::Lisp, _) => PolyglotSyntaxParser::Lisp(create_sexpr_parser(factory, allocator)),
     };
@@ -0,0 +1,378 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{collections::HashMap, marker::PhantomData, path::Path, rc::Rc};
+
+use derivative::Derivative;
+use reflex::core::{Expression, ModuleLoader};
+
+/// Provider responsible for creating the contents of a synthetic module that does not exist on the filesystem
+pub trait VirtualModuleProvider<T: Expression> {
+    fn create_module(&self) -> Result<T, String>;
+}
+
+impl<T: Expression, F> VirtualModuleProvider<T> for F
+where
+    F: Fn() -> Result<T, String>,
+{
+    fn create_module(&self) -> Result<T, String> {
+        self()
+    }
+}
+
+/// Registry of virtual module providers, keyed by import specifier (e.g. `reflex:env`, `app:config`)
+#[derive(Derivative)]
+#[derivative(Default(bound = ""), Clone(bound = ""))]
+pub struct VirtualModuleRegistry<T: Expression> {
+    providers: HashMap<String, Rc<dyn VirtualModuleProvider<T>>>,
+}
+
+impl<T: Expression> VirtualModuleRegistry<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn with_module(
+        mut self,
+        specifier: impl Into<String>,
+        provider: impl VirtualModuleProvider<T> + 'static,
+    ) -> Self {
+        self.register(specifier, provider);
+        self
+    }
+    pub fn with_static_module(self, specifier: impl Into<String>, value: T) -> Self
+    where
+        T: 'static,
+    {
+        self.with_module(specifier, move || Ok(value.clone()))
+    }
+    pub fn register(
+        &mut self,
+        specifier: impl Into<String>,
+        provider: impl VirtualModuleProvider<T> + 'static,
+    ) -> Option<Rc<dyn VirtualModuleProvider<T>>> {
+        self.providers.insert(specifier.into(), Rc::new(provider))
+    }
+    pub fn contains(&self, specifier: &str) -> bool {
+        self.providers.contains_key(specifier)
+    }
+}
+
+impl<T: Expression> std::fmt::Debug for VirtualModuleRegistry<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VirtualModuleRegistry")
+            .field("providers", &self.providers.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<T: Expression> ModuleLoader for VirtualModuleRegistry<T> {
+    type Output = T;
+    fn load(
+        &self,
+        import_path: &str,
+        _current_path: &Path,
+    ) -> Option<Result<Self::Output, String>> {
+        self.providers
+            .get(import_path)
+            .map(|provider| provider.create_module())
+    }
+}
+
+/// Mapping from import specifiers to module locations, allowing imports to be redirected without modifying the
+/// importing source files
+///
+/// Import maps are defined in JSON format, following the same structure as browser import maps:
+///
+/// ```json
+/// {
+///   "imports": {
+///     "app:config": "./config/production.json",
+///     "lodash": "./vendor/lodash.js",
+///     "utils/": "./src/utils/",
+///     "env": "reflex:env"
+///   },
+///   "integrity": {
+///     "https://example.com/lib/utils.js": "sha384-oqVuAfXRKap7fdgcCY5uykM6+R9GqQ8K/uxy9rx7HNQlGYl1kPzQho1wx4JwY8wC"
+///   }
+/// }
+/// ```
+///
+/// Specifiers ending with a trailing slash match any import specifier with that prefix (the longest matching prefix
+/// takes precedence over shorter prefixes, and exact matches take precedence over prefix matches). Relative targets are
+/// resolved relative to the location of the import map file; any other targets are treated as bare import specifiers,
+/// allowing imports to be redirected to builtin or virtual modules.
+///
+/// Remote module URLs can be pinned to a specific subresource integrity hash via the `integrity` field, causing the
+/// module to fail to load if the fetched contents do not match the expected hash.
+#[derive(PartialEq, Eq, Clone, Default, Debug)]
+pub struct ImportMap {
+    imports: HashMap<String, String>,
+    integrity: HashMap<String, String>,
+}
+
+impl ImportMap {
+    pub fn new(imports: impl IntoIterator<Item = (String, String)>) -> Self {
+        Self {
+            imports: imports.into_iter().collect(),
+            integrity: Default::default(),
+        }
+    }
+    /// Load an import map from a JSON configuration file
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|err| format!("Failed to load import map {}: {}", path.display(), err))?;
+        Self::parse(&source, path.parent().unwrap_or_else(|| Path::new("")))
+            .map_err(|err| format!("Invalid import map {}: {}", path.display(), err))
+    }
+    /// Parse an import map from JSON source, resolving relative targets against the given base directory
+    pub fn parse(source: &str, base_path: &Path) -> Result<Self, String> {
+        let value =
+            serde_json::from_str::<serde_json::Value>(source).map_err(|err| format!("{}", err))?;
+        let (imports, integrity) = match value {
+            serde_json::Value::Object(mut fields) => Ok((
+                parse_import_map_section(&mut fields, "imports")?,
+                parse_import_map_section(&mut fields, "integrity")?,
+            )),
+            _ => Err(String::from("Expected import map to be an object")),
+        }?;
+        let imports = imports
+            .into_iter()
+            .map(|(specifier, target)| match target {
+                serde_json::Value::String(target) => {
+                    if specifier.ends_with('/') && !target.ends_with('/') {
+                        Err(format!(
+                            "Invalid target for import prefix \"{}\": expected trailing slash, received \"{}\"",
+                            specifier, target
+                        ))
+                    } else {
+                        Ok((specifier, resolve_import_map_target(target, base_path)))
+                    }
+                }
+                _ => Err(format!(
+                    "Invalid target for import \"{}\": expected string",
+                    specifier
+                )),
+            })
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        let integrity = integrity
+            .into_iter()
+            .map(|(url, hash)| match hash {
+                serde_json::Value::String(hash) => Ok((url, hash)),
+                _ => Err(format!(
+                    "Invalid integrity hash for \"{}\": expected string",
+                    url
+                )),
+            })
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        Ok(Self { imports, integrity })
+    }
+    pub fn is_empty(&self) -> bool {
+        self.imports.is_empty() && self.integrity.is_empty()
+    }
+    pub fn insert(&mut self, specifier: impl Into<String>, target: impl Into<String>) {
+        self.imports.insert(specifier.into(), target.into());
+    }
+    pub fn insert_integrity(&mut self, url: impl Into<String>, hash: impl Into<String>) {
+        self.integrity.insert(url.into(), hash.into());
+    }
+    /// Retrieve the subresource integrity hash that the given remote module URL is pinned to, if any
+    pub fn integrity(&self, url: &str) -> Option<&str> {
+        self.integrity.get(url).map(|hash| hash.as_str())
+    }
+    /// Determine the target location for the given import specifier, if the specifier is remapped by this import map
+    pub fn resolve(&self, specifier: &str) -> Option<String> {
+        if let Some(target) = self.imports.get(specifier) {
+            return Some(target.clone());
+        }
+        self.imports
+            .iter()
+            .filter(|(prefix, _)| prefix.ends_with('/') && specifier.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(prefix, target)| format!("{}{}", target, &specifier[prefix.len()..]))
+    }
+}
+
+fn parse_import_map_section(
+    fields: &mut serde_json::Map<String, serde_json::Value>,
+    key: &str,
+) -> Result<serde_json::Map<String, serde_json::Value>, String> {
+    match fields.remove(key) {
+        None => Ok(Default::default()),
+        Some(serde_json::Value::Object(entries)) => Ok(entries),
+        Some(_) => Err(format!("Expected \"{}\" field to be an object", key)),
+    }
+}
+
+fn resolve_import_map_target(target: String, base_path: &Path) -> String {
+    if target.starts_with("./") || target.starts_with("../") {
+        base_path.join(target).to_string_lossy().into_owned()
+    } else {
+        target
+    }
+}
+
+/// Module loader that rewrites import specifiers according to the provided [`ImportMap`] before delegating to the
+/// inner loader
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TLoader: Clone"),
+    Debug(bound = "TLoader: std::fmt::Debug")
+)]
+pub struct ImportMapModuleLoader<T: Expression, TLoader: ModuleLoader<Output = T>> {
+    import_map: ImportMap,
+    inner: TLoader,
+    _expression: PhantomData<T>,
+}
+
+impl<T: Expression, TLoader: ModuleLoader<Output = T>> ImportMapModuleLoader<T, TLoader> {
+    pub fn new(import_map: ImportMap, inner: TLoader) -> Self {
+        Self {
+            import_map,
+            inner,
+            _expression: PhantomData,
+        }
+    }
+}
+
+impl<T: Expression, TLoader: ModuleLoader<Output = T>> ModuleLoader
+    for ImportMapModuleLoader<T, TLoader>
+{
+    type Output = T;
+    fn load(&self, import_path: &str, current_path: &Path) -> Option<Result<Self::Output, String>> {
+        match self.import_map.resolve(import_path) {
+            None => self.inner.load(import_path, current_path),
+            Some(target_path) => Some(
+                match self.inner.load(&target_path, current_path) {
+                    Some(result) => result,
+                    None => Err(format!("Unable to load mapped module: {}", target_path)),
+                }
+                .map_err(|err| format!("{} (mapped to {})", err, target_path)),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reflex::core::ExpressionFactory;
+    use reflex_lang::SharedTermFactory;
+    use reflex_stdlib::Stdlib;
+
+    use super::*;
+
+    #[test]
+    fn import_map_resolution() {
+        let import_map = ImportMap::parse(
+            r#"{
+                "imports": {
+                    "app:config": "./config/production.json",
+                    "env": "reflex:env",
+                    "utils/": "./src/utils/",
+                    "utils/math/": "../math/",
+                    "utils/math/index.js": "./math.js"
+                }
+            }"#,
+            Path::new("/app"),
+        )
+        .unwrap();
+        assert_eq!(
+            import_map.resolve("app:config"),
+            Some(String::from("/app/./config/production.json")),
+        );
+        assert_eq!(import_map.resolve("env"), Some(String::from("reflex:env")));
+        assert_eq!(
+            import_map.resolve("utils/strings.js"),
+            Some(String::from("/app/./src/utils/strings.js")),
+        );
+        assert_eq!(
+            import_map.resolve("utils/math/add.js"),
+            Some(String::from("/app/../math/add.js")),
+        );
+        assert_eq!(
+            import_map.resolve("utils/math/index.js"),
+            Some(String::from("/app/./math.js")),
+        );
+        assert_eq!(import_map.resolve("./foo.js"), None);
+        assert_eq!(import_map.resolve("utils"), None);
+    }
+
+    #[test]
+    fn invalid_import_maps() {
+        assert!(ImportMap::parse("[]", Path::new("/app")).is_err());
+        assert!(ImportMap::parse(r#"{ "imports": [] }"#, Path::new("/app")).is_err());
+        assert!(ImportMap::parse(r#"{ "imports": { "foo": 3 } }"#, Path::new("/app")).is_err());
+        assert!(
+            ImportMap::parse(r#"{ "imports": { "foo/": "./foo" } }"#, Path::new("/app")).is_err()
+        );
+        assert!(ImportMap::parse(r#"{ "integrity": [] }"#, Path::new("/app")).is_err());
+        assert!(ImportMap::parse(
+            r#"{ "integrity": { "https://example.com/foo.js": 3 } }"#,
+            Path::new("/app")
+        )
+        .is_err());
+        assert_eq!(
+            ImportMap::parse("{}", Path::new("/app")),
+            Ok(ImportMap::default())
+        );
+    }
+
+    #[test]
+    fn import_map_integrity() {
+        let import_map = ImportMap::parse(
+            r#"{
+                "imports": {
+                    "utils": "https://example.com/utils.js"
+                },
+                "integrity": {
+                    "https://example.com/utils.js": "sha256-47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU="
+                }
+            }"#,
+            Path::new("/app"),
+        )
+        .unwrap();
+        assert_eq!(
+            import_map.resolve("utils"),
+            Some(String::from("https://example.com/utils.js")),
+        );
+        assert_eq!(
+            import_map.integrity("https://example.com/utils.js"),
+            Some("sha256-47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU="),
+        );
+        assert_eq!(import_map.integrity("https://example.com/other.js"), None);
+    }
+
+    #[test]
+    fn virtual_module_providers() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let registry = VirtualModuleRegistry::new()
+            .with_static_module("app:config", factory.create_int_term(3))
+            .with_module("reflex:env", move || Ok(factory.create_int_term(4)))
+            .with_module("app:broken", || Err(String::from("Broken module")));
+        let loader = ImportMapModuleLoader::new(
+            ImportMap::new([
+                (String::from("config"), String::from("app:config")),
+                (String::from("missing"), String::from("app:missing")),
+            ]),
+            registry,
+        );
+        let path = Path::new("/app/index.js");
+        assert_eq!(
+            loader.load("app:config", path),
+            Some(Ok(factory.create_int_term(3))),
+        );
+        assert_eq!(
+            loader.load("config", path),
+            Some(Ok(factory.create_int_term(3))),
+        );
+        assert_eq!(
+            loader.load("reflex:env", path),
+            Some(Ok(factory.create_int_term(4))),
+        );
+        assert_eq!(
+            loader.load("app:broken", path),
+            Some(Err(String::from("Broken module"))),
+        );
+        assert!(matches!(loader.load("missing", path), Some(Err(_))));
+        assert_eq!(loader.load("app:unknown", path), None);
+    }
+}
@@ -0,0 +1,587 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    fs,
+    future::Future,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use base64::Engine;
+use derivative::Derivative;
+use futures::{executor::block_on, future::LocalBoxFuture};
+use reflex::core::{Expression, ExpressionFactory, HeapAllocator, ModuleLoader};
+use reflex_js::{create_default_module_export, parse_module, Env, JsParserBuiltin};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use url::Url;
+
+use crate::{cache::hash_module_contents, loader::ImportMap};
+
+/// Transport responsible for retrieving the raw contents of remote modules
+///
+/// Fetches are driven to completion on the parsing thread, so implementations must not rely on being polled from within
+/// any particular async runtime.
+pub trait RemoteModuleFetcher {
+    fn fetch<'a>(&'a self, url: &'a Url) -> LocalBoxFuture<'a, Result<Vec<u8>, String>>;
+}
+
+impl<F, TFuture> RemoteModuleFetcher for F
+where
+    F: Fn(Url) -> TFuture,
+    TFuture: Future<Output = Result<Vec<u8>, String>> + 'static,
+{
+    fn fetch<'a>(&'a self, url: &'a Url) -> LocalBoxFuture<'a, Result<Vec<u8>, String>> {
+        Box::pin(self(url.clone()))
+    }
+}
+
+/// Configuration determining how `https://` module imports are loaded
+///
+/// Remote imports are disabled by default. When a cache directory is provided, fetched module contents are persisted
+/// to the cache directory and subsequently loaded from there without refetching, allowing programs to be parsed offline
+/// once all their remote dependencies have been cached.
+#[derive(Clone, Default)]
+pub struct RemoteModuleOptions {
+    fetcher: Option<Rc<dyn RemoteModuleFetcher>>,
+    cache_directory: Option<PathBuf>,
+}
+
+impl RemoteModuleOptions {
+    pub fn new(fetcher: impl RemoteModuleFetcher + 'static) -> Self {
+        Self {
+            fetcher: Some(Rc::new(fetcher)),
+            cache_directory: None,
+        }
+    }
+    /// Load remote modules exclusively from the given cache directory, without fetching any uncached modules
+    pub fn offline(cache_directory: impl Into<PathBuf>) -> Self {
+        Self {
+            fetcher: None,
+            cache_directory: Some(cache_directory.into()),
+        }
+    }
+    pub fn with_cache_directory(self, cache_directory: impl Into<PathBuf>) -> Self {
+        Self {
+            cache_directory: Some(cache_directory.into()),
+            ..self
+        }
+    }
+    pub fn is_enabled(&self) -> bool {
+        self.fetcher.is_some() || self.cache_directory.is_some()
+    }
+    fn cache_entry_path(&self, url: &Url) -> Option<PathBuf> {
+        self.cache_directory.as_ref().map(|directory| {
+            directory.join(format!(
+                "{}.js",
+                hash_module_contents(url.as_str().as_bytes())
+            ))
+        })
+    }
+    fn retrieve_cached(&self, url: &Url) -> Option<Vec<u8>> {
+        fs::read(self.cache_entry_path(url)?).ok()
+    }
+    fn store_cached(&self, url: &Url, contents: &[u8]) {
+        let entry_path = match self.cache_entry_path(url) {
+            Some(entry_path) => entry_path,
+            None => return,
+        };
+        if let Some(directory) = entry_path.parent() {
+            if fs::create_dir_all(directory).is_err() {
+                return;
+            }
+        }
+        // Write to a temporary file before renaming, to avoid leaving partially-written entries if interrupted
+        let temp_path = entry_path.with_extension(format!("{}.tmp", std::process::id()));
+        if fs::write(&temp_path, contents).is_ok() && fs::rename(&temp_path, &entry_path).is_err() {
+            let _ = fs::remove_file(&temp_path);
+        }
+    }
+}
+
+impl std::fmt::Debug for RemoteModuleOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RemoteModuleOptions")
+            .field("fetcher", &self.fetcher.is_some())
+            .field("cache_directory", &self.cache_directory)
+            .finish()
+    }
+}
+
+/// Module loader that loads JavaScript modules imported via `https://` URLs, as well as any relative imports from
+/// within those modules
+///
+/// Module contents are verified against any subresource integrity hashes pinned in the provided [`ImportMap`] before
+/// being parsed. Remote modules that have not been pinned are loaded without verification.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TLoader: Clone, TFactory: Clone, TAllocator: Clone"),
+    Debug(
+        bound = "TLoader: std::fmt::Debug, TFactory: std::fmt::Debug, TAllocator: std::fmt::Debug"
+    )
+)]
+pub struct RemoteModuleLoader<
+    T: Expression,
+    TLoader: ModuleLoader<Output = T>,
+    TFactory: ExpressionFactory<T>,
+    TAllocator: HeapAllocator<T>,
+> {
+    options: RemoteModuleOptions,
+    import_map: ImportMap,
+    env: Env<T>,
+    loader: TLoader,
+    factory: TFactory,
+    allocator: TAllocator,
+    _expression: PhantomData<T>,
+}
+
+impl<
+        T: Expression,
+        TLoader: ModuleLoader<Output = T>,
+        TFactory: ExpressionFactory<T>,
+        TAllocator: HeapAllocator<T>,
+    > RemoteModuleLoader<T, TLoader, TFactory, TAllocator>
+{
+    pub fn new(
+        options: RemoteModuleOptions,
+        import_map: ImportMap,
+        env: Env<T>,
+        loader: TLoader,
+        factory: TFactory,
+        allocator: TAllocator,
+    ) -> Self {
+        Self {
+            options,
+            import_map,
+            env,
+            loader,
+            factory,
+            allocator,
+            _expression: PhantomData,
+        }
+    }
+    fn retrieve_source(&self, url: &Url) -> Result<String, String> {
+        let integrity = self.import_map.integrity(url.as_str());
+        // Cached entries that no longer match the pinned hash are ignored, allowing pinned versions to be upgraded
+        let cached = self
+            .options
+            .retrieve_cached(url)
+            .filter(|contents| match integrity {
+                Some(integrity) => verify_integrity(contents, integrity).is_ok(),
+                None => true,
+            });
+        let contents = match cached {
+            Some(contents) => contents,
+            None => {
+                let fetcher = self.options.fetcher.as_ref().ok_or_else(|| {
+                    format!("Remote module is not available in offline cache: {}", url)
+                })?;
+                let contents = block_on(fetcher.fetch(url))
+                    .map_err(|err| format!("Failed to fetch remote module {}: {}", url, err))?;
+                if let Some(integrity) = integrity {
+                    verify_integrity(&contents, integrity).map_err(|err| {
+                        format!("Integrity check failed for remote module {}: {}", url, err)
+                    })?;
+                }
+                self.options.store_cached(url, &contents);
+                contents
+            }
+        };
+        String::from_utf8(contents)
+            .map_err(|_| format!("Remote module contains invalid UTF-8: {}", url))
+    }
+}
+
+impl<
+        T: Expression,
+        TLoader: ModuleLoader<Output = T>,
+        TFactory: ExpressionFactory<T>,
+        TAllocator: HeapAllocator<T>,
+    > ModuleLoader for RemoteModuleLoader<T, TLoader, TFactory, TAllocator>
+where
+    T::Builtin: JsParserBuiltin,
+{
+    type Output = T;
+    fn load(&self, import_path: &str, current_path: &Path) -> Option<Result<Self::Output, String>> {
+        let url = match resolve_remote_module_url(import_path, current_path)? {
+            Ok(url) => url,
+            Err(err) => return Some(Err(err)),
+        };
+        if !self.options.is_enabled() {
+            return Some(Err(format!("Remote module imports are disabled: {}", url)));
+        }
+        Some(self.retrieve_source(&url).and_then(|source| {
+            parse_module(
+                &source,
+                &self.env,
+                Path::new(url.as_str()),
+                &self.loader,
+                &self.factory,
+                &self.allocator,
+            )
+            .map(|result| create_default_module_export(result, &self.factory, &self.allocator))
+        }))
+    }
+}
+
+/// Determine the remote URL referenced by the given import, taking into account relative imports from within remote
+/// modules (returns `None` if the import does not refer to a remote module)
+fn resolve_remote_module_url(
+    import_path: &str,
+    current_path: &Path,
+) -> Option<Result<Url, String>> {
+    if import_path.starts_with("https://") {
+        return Some(
+            Url::parse(import_path)
+                .map_err(|err| format!("Invalid remote module URL {}: {}", import_path, err)),
+        );
+    }
+    if import_path.starts_with("http://") {
+        return Some(Err(format!(
+            "Insecure remote module URL (expected https://): {}",
+            import_path
+        )));
+    }
+    let current_url = current_path
+        .to_str()
+        .filter(|current_path| current_path.starts_with("https://"))?;
+    if !(import_path.starts_with("./")
+        || import_path.starts_with("../")
+        || import_path.starts_with('/'))
+    {
+        return None;
+    }
+    Some(
+        Url::parse(current_url)
+            .and_then(|base| base.join(import_path))
+            .map_err(|err| {
+                format!(
+                    "Invalid remote module import {} (imported from {}): {}",
+                    import_path, current_url, err
+                )
+            }),
+    )
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+enum IntegrityAlgorithm {
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl IntegrityAlgorithm {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "sha256" => Some(Self::Sha256),
+            "sha384" => Some(Self::Sha384),
+            "sha512" => Some(Self::Sha512),
+            _ => None,
+        }
+    }
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Sha256 => "sha256",
+            Self::Sha384 => "sha384",
+            Self::Sha512 => "sha512",
+        }
+    }
+    fn digest(&self, contents: &[u8]) -> String {
+        let engine = base64::engine::general_purpose::STANDARD;
+        match self {
+            Self::Sha256 => engine.encode(Sha256::digest(contents)),
+            Self::Sha384 => engine.encode(Sha384::digest(contents)),
+            Self::Sha512 => engine.encode(Sha512::digest(contents)),
+        }
+    }
+}
+
+/// Compute the subresource integrity hash (e.g. `sha384-<base64 digest>`) for the given module contents
+pub fn compute_integrity(contents: &[u8]) -> String {
+    let algorithm = IntegrityAlgorithm::Sha384;
+    format!("{}-{}", algorithm.name(), algorithm.digest(contents))
+}
+
+/// Verify module contents against subresource integrity metadata
+///
+/// Following the W3C Subresource Integrity specification, the metadata can contain multiple whitespace-separated hashes,
+/// in which case the contents must match at least one of the hashes that use the strongest supported algorithm.
+pub fn verify_integrity(contents: &[u8], metadata: &str) -> Result<(), String> {
+    let hashes = metadata
+        .split_whitespace()
+        .filter_map(|token| {
+            // Discard any trailing options (these are reserved for future use by the specification)
+            let token = token.split('?').next()?;
+            let (algorithm, digest) = token.split_once('-')?;
+            Some((IntegrityAlgorithm::parse(algorithm)?, digest))
+        })
+        .collect::<Vec<_>>();
+    let algorithm = hashes
+        .iter()
+        .map(|(algorithm, _)| *algorithm)
+        .max()
+        .ok_or_else(|| format!("Unsupported integrity metadata: \"{}\"", metadata))?;
+    let actual = algorithm.digest(contents);
+    if hashes
+        .iter()
+        .any(|(hash_algorithm, digest)| *hash_algorithm == algorithm && *digest == actual)
+    {
+        Ok(())
+    } else {
+        Err(format!(
+            "Expected \"{}\", received \"{}-{}\"",
+            metadata,
+            algorithm.name(),
+            actual
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, collections::HashMap};
+
+    use reflex::loader::NoopModuleLoader;
+
+    use reflex_js::{builtins::JsBuiltins, create_js_env};
+    use reflex_lang::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
+
+    use crate::{cache::NoopModuleCache, syntax::js::create_js_module_loader};
+
+    use super::*;
+
+    type TestTerm = CachedSharedTerm<JsBuiltins>;
+
+    const INDEX_MODULE: &str = "import value from './lib/value.js';\nexport default value + 1;";
+    const VALUE_MODULE: &str = "export default 3;";
+
+    /// Fetcher that serves modules from an in-memory map, keeping track of how many fetches were made
+    #[derive(Clone)]
+    struct MockFetcher {
+        modules: Rc<HashMap<String, String>>,
+        num_fetches: Rc<Cell<usize>>,
+    }
+
+    impl MockFetcher {
+        fn new(modules: impl IntoIterator<Item = (&'static str, &'static str)>) -> Self {
+            Self {
+                modules: Rc::new(
+                    modules
+                        .into_iter()
+                        .map(|(url, source)| (String::from(url), String::from(source)))
+                        .collect(),
+                ),
+                num_fetches: Default::default(),
+            }
+        }
+    }
+
+    impl RemoteModuleFetcher for MockFetcher {
+        fn fetch<'a>(&'a self, url: &'a Url) -> LocalBoxFuture<'a, Result<Vec<u8>, String>> {
+            Box::pin(async move {
+                self.num_fetches.set(self.num_fetches.get() + 1);
+                self.modules
+                    .get(url.as_str())
+                    .map(|source| source.clone().into_bytes())
+                    .ok_or_else(|| String::from("Not found"))
+            })
+        }
+    }
+
+    fn create_loader(
+        import_map: ImportMap,
+        remote_modules: RemoteModuleOptions,
+    ) -> impl ModuleLoader<Output = TestTerm> {
+        let factory = SharedTermFactory::<JsBuiltins>::default();
+        let allocator = DefaultAllocator::default();
+        create_js_module_loader(
+            create_js_env(&factory, &allocator),
+            import_map,
+            remote_modules,
+            NoopModuleCache::default(),
+            NoopModuleLoader::default(),
+            &factory,
+            &allocator,
+        )
+    }
+
+    fn create_temp_directory(name: &str) -> PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("reflex-parser-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        directory
+    }
+
+    fn load_local_modules(directory: &Path) -> Option<Result<TestTerm, String>> {
+        fs::create_dir_all(directory.join("lib")).unwrap();
+        fs::write(directory.join("index.js"), INDEX_MODULE).unwrap();
+        fs::write(directory.join("lib/value.js"), VALUE_MODULE).unwrap();
+        create_loader(ImportMap::default(), RemoteModuleOptions::default())
+            .load("./index.js", &directory.join("main.js"))
+    }
+
+    #[test]
+    fn remote_modules() {
+        let directory = create_temp_directory("remote-modules");
+        let expected = load_local_modules(&directory);
+        assert!(matches!(expected, Some(Ok(_))));
+        let fetcher = MockFetcher::new([
+            ("https://example.com/index.js", INDEX_MODULE),
+            ("https://example.com/lib/value.js", VALUE_MODULE),
+        ]);
+        let loader = create_loader(
+            ImportMap::default(),
+            RemoteModuleOptions::new(fetcher.clone()),
+        );
+        let path = Path::new("/app/index.js");
+        assert_eq!(loader.load("https://example.com/index.js", path), expected);
+        assert_eq!(fetcher.num_fetches.get(), 2);
+        assert!(matches!(
+            loader.load("https://example.com/missing.js", path),
+            Some(Err(_))
+        ));
+        assert!(matches!(
+            loader.load("http://example.com/index.js", path),
+            Some(Err(_))
+        ));
+        assert_eq!(fetcher.num_fetches.get(), 3);
+
+        // Import maps can redirect bare specifiers to remote modules
+        let mut import_map = ImportMap::default();
+        import_map.insert("app", "https://example.com/index.js");
+        let loader = create_loader(import_map, RemoteModuleOptions::new(fetcher));
+        assert_eq!(loader.load("app", path), expected);
+
+        // Remote modules are disabled unless explicitly enabled
+        let loader = create_loader(ImportMap::default(), RemoteModuleOptions::default());
+        assert!(matches!(
+            loader.load("https://example.com/index.js", path),
+            Some(Err(_))
+        ));
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn remote_module_integrity() {
+        let fetcher = MockFetcher::new([("https://example.com/lib/value.js", VALUE_MODULE)]);
+        let path = Path::new("/app/index.js");
+        let mut import_map = ImportMap::default();
+        import_map.insert_integrity(
+            "https://example.com/lib/value.js",
+            compute_integrity(VALUE_MODULE.as_bytes()),
+        );
+        let loader = create_loader(import_map, RemoteModuleOptions::new(fetcher.clone()));
+        assert!(matches!(
+            loader.load("https://example.com/lib/value.js", path),
+            Some(Ok(_))
+        ));
+        let mut import_map = ImportMap::default();
+        import_map.insert_integrity(
+            "https://example.com/lib/value.js",
+            compute_integrity(b"export default 4;"),
+        );
+        let loader = create_loader(import_map, RemoteModuleOptions::new(fetcher));
+        assert!(matches!(
+            loader.load("https://example.com/lib/value.js", path),
+            Some(Err(_))
+        ));
+    }
+
+    #[test]
+    fn integrity_metadata() {
+        let contents = b"export default 3;";
+        let sha256 = format!(
+            "sha256-{}",
+            IntegrityAlgorithm::Sha256.digest(contents.as_slice())
+        );
+        let sha512 = format!(
+            "sha512-{}",
+            IntegrityAlgorithm::Sha512.digest(contents.as_slice())
+        );
+        assert_eq!(
+            verify_integrity(contents, &compute_integrity(contents)),
+            Ok(())
+        );
+        assert_eq!(verify_integrity(contents, &sha256), Ok(()));
+        assert_eq!(
+            verify_integrity(contents, &format!("{}?foo=bar", sha512)),
+            Ok(())
+        );
+        assert_eq!(
+            verify_integrity(contents, &format!("md5-foo {} sha512-foo", sha512)),
+            Ok(())
+        );
+        // Hashes using weaker algorithms are ignored when a stronger algorithm is present
+        assert!(verify_integrity(contents, &format!("{} sha512-foo", sha256)).is_err());
+        assert!(verify_integrity(b"export default 4;", &sha256).is_err());
+        assert!(verify_integrity(contents, "md5-foo").is_err());
+        assert!(verify_integrity(contents, "").is_err());
+    }
+
+    #[test]
+    fn offline_remote_module_cache() {
+        let directory = create_temp_directory("remote-module-cache");
+        let expected = load_local_modules(&directory.join("local"));
+        let cache_directory = directory.join("cache");
+        let fetcher = MockFetcher::new([
+            ("https://example.com/index.js", INDEX_MODULE),
+            ("https://example.com/lib/value.js", VALUE_MODULE),
+        ]);
+        let path = Path::new("/app/index.js");
+        let loader = create_loader(
+            ImportMap::default(),
+            RemoteModuleOptions::new(fetcher.clone()).with_cache_directory(&cache_directory),
+        );
+        assert_eq!(loader.load("https://example.com/index.js", path), expected);
+        assert_eq!(fetcher.num_fetches.get(), 2);
+
+        // Cached modules are not refetched
+        let loader = create_loader(
+            ImportMap::default(),
+            RemoteModuleOptions::new(fetcher.clone()).with_cache_directory(&cache_directory),
+        );
+        assert_eq!(loader.load("https://example.com/index.js", path), expected);
+        assert_eq!(fetcher.num_fetches.get(), 2);
+
+        // Cached modules can be loaded offline
+        let loader = create_loader(
+            ImportMap::default(),
+            RemoteModuleOptions::offline(&cache_directory),
+        );
+        assert_eq!(loader.load("https://example.com/index.js", path), expected);
+        assert!(matches!(
+            loader.load("https://example.com/missing.js", path),
+            Some(Err(_))
+        ));
+
+        // Cached modules are refetched if they do not match the pinned integrity hash
+        fs::write(
+            RemoteModuleOptions::offline(&cache_directory)
+                .cache_entry_path(&Url::parse("https://example.com/lib/value.js").unwrap())
+                .unwrap(),
+            "export default 4;",
+        )
+        .unwrap();
+        let mut import_map = ImportMap::default();
+        import_map.insert_integrity(
+            "https://example.com/lib/value.js",
+            compute_integrity(VALUE_MODULE.as_bytes()),
+        );
+        let num_refetches = Rc::new(Cell::new(0));
+        let loader = create_loader(
+            import_map,
+            RemoteModuleOptions::new({
+                let num_refetches = num_refetches.clone();
+                move |_url: Url| {
+                    num_refetches.set(num_refetches.get() + 1);
+                    futures::future::ready(Ok(Vec::from(VALUE_MODULE)))
+                }
+            })
+            .with_cache_directory(&cache_directory),
+        );
+        assert_eq!(loader.load("https://example.com/index.js", path), expected);
+        assert_eq!(num_refetches.get(), 1);
+        let _ = fs::remove_dir_all(&directory);
+    }
+}
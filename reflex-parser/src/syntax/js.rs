@@ -2,9 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 // SPDX-FileContributor: Jordan Hall <j.hall@mwam.com> https://github.com/j-hall-mwam
-use std::path::{Path, PathBuf};
+use std::{
+    path::{Path, PathBuf},
+    rc::Rc,
+};
 
-use reflex::core::{Expression, ExpressionFactory, HeapAllocator, ModuleLoader};
+use reflex::{
+    core::{Expression, ExpressionFactory, HeapAllocator, ModuleLoader},
+    loader::{ChainedModuleLoader, ErrorFallbackModuleLoader, RecursiveModuleLoader},
+    source_map::SourceMetadataRecorder,
+};
 use reflex_graphql::imports::{graphql_imports, GraphQlImportsBuiltin};
 use reflex_grpc::loader::{create_grpc_loader, GrpcLoaderBuiltin};
 use reflex_handlers::{
@@ -12,12 +19,17 @@ use reflex_handlers::{
     loader::create_graphql_loader,
 };
 use reflex_js::{
-    builtin_imports, compose_module_loaders, create_js_env, create_module_loader,
-    globals::JsGlobalsBuiltin, imports::JsImportsBuiltin, static_module_loader, Env,
-    JsParserBuiltin,
+    builtin_imports, compose_module_loaders, create_js_env, globals::JsGlobalsBuiltin,
+    imports::JsImportsBuiltin, static_module_loader, Env, JavaScriptModuleLoader, JsParserBuiltin,
 };
 
-use crate::{syntax::json::json_loader, ParserBuiltin, SyntaxParser};
+use crate::{
+    cache::{CachingModuleLoader, ModuleCache},
+    loader::{ImportMap, ImportMapModuleLoader},
+    remote::{RemoteModuleLoader, RemoteModuleOptions},
+    syntax::json::json_loader,
+    ParserBuiltin, SyntaxParser,
+};
 
 pub fn default_js_loaders<T: Expression + 'static>(
     imports: impl IntoIterator<Item = (String, T)>,
@@ -55,18 +67,20 @@ pub fn create_js_script_parser<
     TFactory: ExpressionFactory<T> + Clone + 'static,
     TAllocator: HeapAllocator<T> + Clone + 'static,
 >(
+    source_metadata: Option<SourceMetadataRecorder>,
     factory: &TFactory,
     allocator: &TAllocator,
 ) -> JavaScriptScriptParser<T, TFactory, TAllocator>
 where
     T::Builtin: JsParserBuiltin + JsGlobalsBuiltin,
 {
-    let env = create_js_env(factory, allocator);
+    let env = with_source_metadata(create_js_env(factory, allocator), source_metadata);
     let factory = factory.clone();
     let allocator = allocator.clone();
     JavaScriptScriptParser::new(env, factory, allocator)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_js_module_parser<
     T: Expression + 'static,
     TLoader: ModuleLoader<Output = T> + 'static,
@@ -74,21 +88,95 @@ pub fn create_js_module_parser<
     TAllocator: HeapAllocator<T> + Clone + 'static,
 >(
     path: &Path,
+    import_map: ImportMap,
+    remote_modules: RemoteModuleOptions,
+    module_cache: impl ModuleCache<T> + 'static,
     module_loader: TLoader,
+    source_metadata: Option<SourceMetadataRecorder>,
     factory: &TFactory,
     allocator: &TAllocator,
 ) -> JavaScriptModuleParser<T, impl ModuleLoader<Output = T> + 'static, TFactory, TAllocator>
 where
     T::Builtin: ParserBuiltin,
 {
-    let env = create_js_env(factory, allocator);
-    let loader = create_module_loader(env.clone(), module_loader, factory, allocator);
+    let env = with_source_metadata(create_js_env(factory, allocator), source_metadata);
+    let loader = create_js_module_loader(
+        env.clone(),
+        import_map,
+        remote_modules,
+        module_cache,
+        module_loader,
+        factory,
+        allocator,
+    );
     let factory = factory.clone();
     let allocator = allocator.clone();
     let path = path.to_owned();
     JavaScriptModuleParser::new(path, loader, env, factory, allocator)
 }
 
+fn with_source_metadata<T: Expression>(
+    env: Env<T>,
+    source_metadata: Option<SourceMetadataRecorder>,
+) -> Env<T> {
+    match source_metadata {
+        Some(recorder) => env.with_source_metadata(recorder),
+        None => env,
+    }
+}
+
+/// Create a module loader capable of loading JavaScript source modules in addition to any modules provided by the
+/// custom loader, where all import specifiers (including imports within nested modules) are first remapped according
+/// to the provided import map
+///
+/// Parsed JavaScript source modules are stored in the provided module cache, allowing unchanged modules to be reused
+/// across program loads without needing to be re-parsed.
+///
+/// Imports of `https://` URLs (and relative imports from within remote modules) are loaded according to the provided
+/// remote module options, verified against any integrity hashes pinned in the import map.
+pub fn create_js_module_loader<T: Expression + 'static>(
+    env: Env<T>,
+    import_map: ImportMap,
+    remote_modules: RemoteModuleOptions,
+    module_cache: impl ModuleCache<T> + 'static,
+    custom_loader: impl ModuleLoader<Output = T> + 'static,
+    factory: &(impl ExpressionFactory<T> + Clone + 'static),
+    allocator: &(impl HeapAllocator<T> + Clone + 'static),
+) -> RecursiveModuleLoader<T>
+where
+    T::Builtin: JsParserBuiltin,
+{
+    let factory = factory.clone();
+    let allocator = allocator.clone();
+    RecursiveModuleLoader::new(move |loader| {
+        let loader = Rc::new(loader);
+        ImportMapModuleLoader::new(
+            import_map.clone(),
+            CachingModuleLoader::new(
+                ".js",
+                module_cache,
+                ChainedModuleLoader::new(
+                    RemoteModuleLoader::new(
+                        remote_modules,
+                        import_map,
+                        env.clone(),
+                        Rc::clone(&loader),
+                        factory.clone(),
+                        allocator.clone(),
+                    ),
+                    ChainedModuleLoader::new(
+                        JavaScriptModuleLoader::new(env, loader, factory, allocator),
+                        ChainedModuleLoader::new(
+                            custom_loader,
+                            ErrorFallbackModuleLoader::default(),
+                        ),
+                    ),
+                ),
+            ),
+        )
+    })
+}
+
 pub struct JavaScriptScriptParser<
     T: Expression,
     TFactory: ExpressionFactory<T>,
@@ -7,7 +7,7 @@ use prost_reflect::{DynamicMessage, FieldDescriptor, Kind, MapKey, ReflectMessag
 use reflex::core::{create_record, Expression, ExpressionFactory, HeapAllocator};
 
 use crate::{
-    utils::{count_iterator_items, create_invalid_field_type_error_message, CountedIteratorItems},
+    utils::{count_iterator_items, CountedIteratorItems},
     ProtoTranscoder, TranscodeError,
 };
 
@@ -116,9 +116,7 @@ fn deserialize_field_value<T: Expression>(
         Value::String(value) => {
             Ok(factory.create_string_term(allocator.create_string(value.as_str())))
         }
-        Value::Bytes(_) => Err(TranscodeError::from(
-            create_invalid_field_type_error_message("bytes"),
-        )),
+        Value::Bytes(value) => Ok(factory.create_bytes_term(value.as_ref())),
         Value::EnumNumber(value) => {
             deserialize_enum_field_value(*value, field_type, factory, allocator)
         }
@@ -3,18 +3,19 @@
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 use std::{collections::HashMap, ops::Deref};
 
+use bytes::Bytes;
 use prost_reflect::{
     DynamicMessage, EnumDescriptor, FieldDescriptor, Kind, MapKey, MessageDescriptor,
     OneofDescriptor, Value,
 };
 use reflex::core::{
-    as_integer, BooleanTermType, Expression, ExpressionFactory, ExpressionListType, FloatTermType,
-    FloatValue, HashmapTermType, HeapAllocator, IntTermType, IntValue, ListTermType,
+    as_integer, BooleanTermType, BytesTermType, Expression, ExpressionFactory, ExpressionListType,
+    FloatTermType, FloatValue, HashmapTermType, HeapAllocator, IntTermType, IntValue, ListTermType,
     RecordTermType, RefType, StringTermType, StringValue, StructPrototypeType,
 };
 
 use crate::{
-    utils::{count_iterator_items, create_invalid_field_type_error_message, CountedIteratorItems},
+    utils::{count_iterator_items, CountedIteratorItems},
     ProtoTranscoder, TranscodeError,
 };
 
@@ -297,10 +298,7 @@ fn serialize_simple_field_value<T: Expression>(
                     .map(Value::Message)
             }
         }
-        // TODO: allow encoding byte-array fields
-        Kind::Bytes => Err(TranscodeError::from(
-            create_invalid_field_type_error_message("bytes"),
-        )),
+        Kind::Bytes => parse_bytes_value(value, factory).map(Value::Bytes),
     }
 }
 
@@ -380,6 +378,22 @@ fn parse_float_value<T: Expression>(
     }
 }
 
+fn parse_bytes_value<T: Expression>(
+    value: &T,
+    factory: &impl ExpressionFactory<T>,
+) -> Result<Bytes, TranscodeError> {
+    if factory.match_nil_term(value).is_some() {
+        Ok(Default::default())
+    } else if let Some(term) = factory.match_bytes_term(value) {
+        Ok(Bytes::copy_from_slice(term.value().deref()))
+    } else {
+        Err(TranscodeError::from(format!(
+            "Expected Bytes, received {}",
+            value
+        )))
+    }
+}
+
 fn parse_string_value<T: Expression>(
     value: &T,
     factory: &impl ExpressionFactory<T>,
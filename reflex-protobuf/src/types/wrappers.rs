@@ -3,15 +3,14 @@
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 use std::ops::Deref;
 
+use bytes::Bytes;
 use prost_reflect::{DynamicMessage, MessageDescriptor, Value};
 use reflex::core::{
-    as_integer, BooleanTermType, Expression, ExpressionFactory, FloatTermType, FloatValue,
-    HeapAllocator, IntTermType, IntValue, RefType, StringTermType, StringValue,
+    as_integer, BooleanTermType, BytesTermType, Expression, ExpressionFactory, FloatTermType,
+    FloatValue, HeapAllocator, IntTermType, IntValue, RefType, StringTermType, StringValue,
 };
 
-use crate::{
-    get_optional_message_field, utils::create_invalid_field_type_error_message, CustomType,
-};
+use crate::{get_optional_message_field, CustomType};
 
 pub struct DoubleValueMessage;
 impl DoubleValueMessage {
@@ -427,19 +426,31 @@ impl CustomType for BytesValueMessage {
     }
     fn serialize<T: Expression>(
         &self,
-        _value: &T,
-        _message_type: &MessageDescriptor,
-        _factory: &impl ExpressionFactory<T>,
+        value: &T,
+        message_type: &MessageDescriptor,
+        factory: &impl ExpressionFactory<T>,
     ) -> Result<DynamicMessage, String> {
-        Err(create_invalid_field_type_error_message(self.name()))
+        if let Some(value) = factory.match_bytes_term(value) {
+            Ok(create_value_message_wrapper(
+                message_type,
+                Value::Bytes(Bytes::copy_from_slice(value.value().deref())),
+            ))
+        } else {
+            Err(format!("Expected Bytes, received {}", value))
+        }
     }
     fn deserialize<T: Expression>(
         &self,
-        _message: &DynamicMessage,
-        _factory: &impl ExpressionFactory<T>,
+        message: &DynamicMessage,
+        factory: &impl ExpressionFactory<T>,
         _allocator: &impl HeapAllocator<T>,
     ) -> Result<T, String> {
-        Err(create_invalid_field_type_error_message(self.name()))
+        let value = get_optional_message_field(message, "value")?;
+        match value {
+            None => Ok(factory.create_bytes_term(&[])),
+            Some(Value::Bytes(value)) => Ok(factory.create_bytes_term(value.as_ref())),
+            _ => Err(format!("Expected bytes, received {:?}", value)),
+        }
     }
 }
 
@@ -1141,8 +1152,6 @@ mod tests {
     }
 
     mod bytes_value {
-        use bytes::Bytes;
-
         use super::*;
 
         #[test]
@@ -1156,13 +1165,30 @@ mod tests {
             let serializer = BytesValueMessage;
             let message_type = Bytes::default().descriptor();
             let factory = SharedTermFactory::<Stdlib>::default();
-            let allocator = DefaultAllocator::<CachedSharedTerm<Stdlib>>::default();
-            let value = factory.create_string_term(allocator.create_string("foo"));
-            let result = serializer.serialize(&value, &message_type, &factory);
-            assert_eq!(
-                result,
-                Err(String::from("Unsupported type: google.protobuf.BytesValue")),
-            );
+            {
+                let value = factory.create_bytes_term(&[]);
+                let result = serializer.serialize(&value, &message_type, &factory);
+                assert_eq!(
+                    result,
+                    Ok({
+                        let mut message = DynamicMessage::new(message_type.clone());
+                        message.set_field_by_name("value", Value::Bytes(Bytes::new()));
+                        message
+                    }),
+                );
+            }
+            {
+                let value = factory.create_bytes_term(&[0x66, 0x6f, 0x6f]);
+                let result = serializer.serialize(&value, &message_type, &factory);
+                assert_eq!(
+                    result,
+                    Ok({
+                        let mut message = DynamicMessage::new(message_type.clone());
+                        message.set_field_by_name("value", Value::Bytes(Bytes::from("foo")));
+                        message
+                    }),
+                );
+            }
         }
 
         #[test]
@@ -1177,10 +1203,7 @@ mod tests {
                 message
             };
             let result = serializer.deserialize(&message, &factory, &allocator);
-            assert_eq!(
-                result,
-                Err(String::from("Unsupported type: google.protobuf.BytesValue"))
-            );
+            assert_eq!(result, Ok(factory.create_bytes_term(&[0x66, 0x6f, 0x6f])));
         }
     }
 }
@@ -0,0 +1,527 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{fmt::Write, marker::PhantomData, ops::Deref, time::SystemTime};
+
+use hmac::{Hmac, Mac};
+
+use reflex::{
+    core::{
+        ConditionListType, ConditionType, DependencyList, Expression, ExpressionFactory, RefType,
+        SignalTermType, SignalType, StateToken, StringTermType, StringValue,
+    },
+    hash::IntMap,
+};
+use reflex_json::stringify_canonical;
+use reflex_runtime::{
+    action::{
+        effect::{EffectEmitAction, EffectUpdateBatch},
+        evaluate::{EvaluateResultAction, EvaluateStartAction, EvaluateStopAction},
+    },
+    actor::evaluate_handler::is_evaluate_effect_type,
+};
+use reflex_utils::event::EventSink;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::effect::{EffectLoggerEvent, SerializedEffectLoggerAction};
+
+/// Audit record describing a single emitted effect value
+///
+/// Effect payloads are never included verbatim: only a keyed digest of the payload is recorded, allowing records to be
+/// correlated with one another without exposing potentially sensitive effect arguments.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct EffectAuditEvent {
+    /// Effect type identifier
+    pub effect_type: String,
+    /// Hex-encoded HMAC-SHA256 digest of the canonical JSON serialization of the effect payload
+    pub payload_hash: String,
+    /// Labels of the active queries that depend on the effect
+    pub queries: Vec<String>,
+    /// Time at which the effect was emitted, in milliseconds since the UNIX epoch
+    pub timestamp: u64,
+    /// Outcome of the emitted effect value
+    pub outcome: EffectAuditOutcome,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum EffectAuditOutcome {
+    Success,
+    Error,
+    Pending,
+}
+
+impl EffectAuditOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Error => "error",
+            Self::Pending => "pending",
+        }
+    }
+}
+
+impl std::fmt::Display for EffectAuditOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Event sink that converts effect emissions into [`EffectAuditEvent`] records, attributing each emission to the
+/// currently-active queries that depend on the emitted effect
+pub struct EffectAuditEventSink<T: Expression, TFactory: ExpressionFactory<T>, TInner> {
+    factory: TFactory,
+    redaction_key: Vec<u8>,
+    queries: IntMap<StateToken, EffectAuditQueryState>,
+    inner: TInner,
+    _expression: PhantomData<T>,
+}
+
+struct EffectAuditQueryState {
+    label: String,
+    dependencies: DependencyList,
+}
+
+impl<T: Expression, TFactory: ExpressionFactory<T>, TInner>
+    EffectAuditEventSink<T, TFactory, TInner>
+{
+    /// Create an audit sink whose payload digests are keyed with the provided secret redaction key
+    pub fn new(factory: TFactory, redaction_key: impl Into<Vec<u8>>, inner: TInner) -> Self {
+        Self {
+            factory,
+            redaction_key: redaction_key.into(),
+            queries: Default::default(),
+            inner,
+            _expression: PhantomData,
+        }
+    }
+}
+
+impl<T: Expression, TFactory: ExpressionFactory<T>, TInner>
+    EffectAuditEventSink<T, TFactory, TInner>
+where
+    TInner: EventSink<Event = EffectAuditEvent>,
+{
+    fn handle_evaluate_start_action(&mut self, action: &EvaluateStartAction<T>) {
+        let EvaluateStartAction {
+            cache_key, label, ..
+        } = action;
+        self.queries.insert(
+            cache_key.id(),
+            EffectAuditQueryState {
+                label: label.clone(),
+                dependencies: DependencyList::empty(),
+            },
+        );
+    }
+    fn handle_evaluate_stop_action(&mut self, action: &EvaluateStopAction<T>) {
+        let EvaluateStopAction { cache_key } = action;
+        self.queries.remove(&cache_key.id());
+    }
+    fn handle_evaluate_result_action(&mut self, action: &EvaluateResultAction<T>) {
+        let EvaluateResultAction {
+            cache_key, result, ..
+        } = action;
+        if let Some(query) = self.queries.get_mut(&cache_key.id()) {
+            query.dependencies = result.dependencies().clone();
+        }
+    }
+    fn handle_effect_emit_action(&mut self, action: &EffectEmitAction<T>) {
+        let EffectEmitAction { effect_types } = action;
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as u64)
+            .unwrap_or_default();
+        for batch in effect_types {
+            let EffectUpdateBatch {
+                effect_type,
+                updates,
+            } = batch;
+            if is_evaluate_effect_type(effect_type, &self.factory) {
+                continue;
+            }
+            let effect_type = get_effect_type_name(effect_type, &self.factory);
+            for (effect, value) in updates {
+                let payload_hash = match effect.signal_type() {
+                    SignalType::Custom { payload, .. } => {
+                        get_payload_digest(&payload, &self.redaction_key)
+                    }
+                    SignalType::Error { .. } | SignalType::Pending => continue,
+                };
+                let queries = self
+                    .queries
+                    .values()
+                    .filter(|query| query.dependencies.contains(effect.id()))
+                    .map(|query| query.label.clone())
+                    .collect();
+                self.inner.emit(&EffectAuditEvent {
+                    effect_type: effect_type.clone(),
+                    payload_hash,
+                    queries,
+                    timestamp,
+                    outcome: get_effect_outcome(value, &self.factory),
+                });
+            }
+        }
+    }
+}
+
+impl<T: Expression, TFactory: ExpressionFactory<T>, TInner> EventSink
+    for EffectAuditEventSink<T, TFactory, TInner>
+where
+    TInner: EventSink<Event = EffectAuditEvent>,
+{
+    type Event = EffectLoggerEvent<T>;
+    fn emit(&mut self, event: &Self::Event) {
+        let action = match event {
+            EffectLoggerEvent::Send { action, .. } => action,
+            EffectLoggerEvent::Receive { .. } => return,
+        };
+        match action {
+            SerializedEffectLoggerAction::EvaluateStart(action) => {
+                self.handle_evaluate_start_action(action)
+            }
+            SerializedEffectLoggerAction::EvaluateStop(action) => {
+                self.handle_evaluate_stop_action(action)
+            }
+            SerializedEffectLoggerAction::EvaluateResult(action) => {
+                self.handle_evaluate_result_action(action)
+            }
+            SerializedEffectLoggerAction::EffectEmit(action) => {
+                self.handle_effect_emit_action(action)
+            }
+            SerializedEffectLoggerAction::EffectSubscribe(_)
+            | SerializedEffectLoggerAction::EffectUnsubscribe(_)
+            | SerializedEffectLoggerAction::EvaluateUpdate(_) => {}
+        }
+    }
+}
+
+fn get_payload_digest<T: Expression>(payload: &T, redaction_key: &[u8]) -> String {
+    // Payloads that cannot be represented as JSON fall back to their (similarly deterministic) string representation.
+    // Sensitive terms are redacted by both representations, so secret values never contribute to the digest.
+    let serialized = stringify_canonical(payload).unwrap_or_else(|_| format!("{}", payload));
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(redaction_key).expect("HMAC keys can be of any length");
+    mac.update(serialized.as_bytes());
+    mac.finalize()
+        .into_bytes()
+        .iter()
+        .fold(String::with_capacity(64), |mut output, byte| {
+            let _ = write!(output, "{:02x}", byte);
+            output
+        })
+}
+
+fn get_effect_outcome<T: Expression>(
+    value: &T,
+    factory: &impl ExpressionFactory<T>,
+) -> EffectAuditOutcome {
+    match factory.match_signal_term(value) {
+        None => EffectAuditOutcome::Success,
+        Some(term) => {
+            let is_error =
+                term.signals().as_deref().iter().any(|signal| {
+                    matches!(signal.as_deref().signal_type(), SignalType::Error { .. })
+                });
+            if is_error {
+                EffectAuditOutcome::Error
+            } else {
+                EffectAuditOutcome::Pending
+            }
+        }
+    }
+}
+
+fn get_effect_type_name<T: Expression>(
+    effect_type: &T,
+    factory: &impl ExpressionFactory<T>,
+) -> String {
+    match factory.match_string_term(effect_type) {
+        Some(term) => String::from(term.value().as_deref().as_str().deref()),
+        None => format!("{}", effect_type),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use reflex::core::{EvaluationResult, HeapAllocator};
+    use reflex_lang::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
+    use reflex_runtime::{
+        actor::evaluate_handler::create_evaluate_effect, QueryEvaluationMode,
+        QueryInvalidationStrategy,
+    };
+    use reflex_stdlib::Stdlib;
+
+    use super::*;
+
+    type T = CachedSharedTerm<Stdlib>;
+
+    #[derive(Clone, Default)]
+    struct CollectEventSink(Arc<Mutex<Vec<EffectAuditEvent>>>);
+    impl CollectEventSink {
+        fn events(&self) -> Vec<EffectAuditEvent> {
+            self.0.lock().unwrap().clone()
+        }
+    }
+    impl EventSink for CollectEventSink {
+        type Event = EffectAuditEvent;
+        fn emit(&mut self, event: &Self::Event) {
+            self.0.lock().unwrap().push(event.clone())
+        }
+    }
+
+    fn send(action: SerializedEffectLoggerAction<T>) -> EffectLoggerEvent<T> {
+        EffectLoggerEvent::Send {
+            pid: 0.into(),
+            action,
+            metadata: None,
+        }
+    }
+
+    fn create_fetch_effect(
+        url: &str,
+        factory: &SharedTermFactory<Stdlib>,
+        allocator: &DefaultAllocator<T>,
+    ) -> <T as Expression>::Signal {
+        allocator.create_signal(SignalType::Custom {
+            effect_type: factory
+                .create_string_term(allocator.create_static_string("reflex::fetch")),
+            payload: factory.create_string_term(allocator.create_string(url)),
+            token: factory.create_nil_term(),
+        })
+    }
+
+    fn create_query(
+        label: &str,
+        factory: &SharedTermFactory<Stdlib>,
+        allocator: &DefaultAllocator<T>,
+    ) -> (<T as Expression>::Signal, EvaluateStartAction<T>) {
+        let query = factory.create_string_term(allocator.create_string(label));
+        let cache_key = create_evaluate_effect(
+            String::from(label),
+            query.clone(),
+            QueryEvaluationMode::Query,
+            QueryInvalidationStrategy::default(),
+            factory,
+            allocator,
+        );
+        let action = EvaluateStartAction {
+            cache_key: cache_key.clone(),
+            label: String::from(label),
+            query,
+            evaluation_mode: QueryEvaluationMode::Query,
+            invalidation_strategy: QueryInvalidationStrategy::default(),
+        };
+        (cache_key, action)
+    }
+
+    fn emit_effect_action(
+        effect_type: T,
+        updates: Vec<(<T as Expression>::Signal, T)>,
+    ) -> SerializedEffectLoggerAction<T> {
+        SerializedEffectLoggerAction::EffectEmit(EffectEmitAction {
+            effect_types: vec![EffectUpdateBatch {
+                effect_type,
+                updates,
+            }],
+        })
+    }
+
+    #[test]
+    fn effect_audit_query_attribution() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let output = CollectEventSink::default();
+        let mut sink = EffectAuditEventSink::new(factory, "secret", output.clone());
+        let effect_type = factory.create_string_term(allocator.create_static_string("reflex::fetch"));
+        let effect = create_fetch_effect("https://example.com/", &factory, &allocator);
+        let unrelated_effect = create_fetch_effect("https://example.org/", &factory, &allocator);
+        let (foo_key, foo_start) = create_query("foo", &factory, &allocator);
+        let (bar_key, bar_start) = create_query("bar", &factory, &allocator);
+        sink.emit(&send(SerializedEffectLoggerAction::EvaluateStart(
+            foo_start,
+        )));
+        sink.emit(&send(SerializedEffectLoggerAction::EvaluateStart(
+            bar_start,
+        )));
+        for cache_key in [&foo_key, &bar_key] {
+            sink.emit(&send(SerializedEffectLoggerAction::EvaluateResult(
+                EvaluateResultAction {
+                    cache_key: cache_key.clone(),
+                    state_index: None,
+                    result: EvaluationResult::new(
+                        factory.create_nil_term(),
+                        DependencyList::of(effect.id()),
+                    ),
+                },
+            )));
+        }
+        sink.emit(&send(emit_effect_action(
+            effect_type.clone(),
+            vec![
+                (effect.clone(), factory.create_int_term(3)),
+                (unrelated_effect.clone(), factory.create_int_term(4)),
+            ],
+        )));
+        let events = output.events();
+        assert_eq!(events.len(), 2);
+        let mut queries = events[0].queries.clone();
+        queries.sort();
+        assert_eq!(queries, vec![String::from("bar"), String::from("foo")]);
+        assert_eq!(events[0].effect_type, "reflex::fetch");
+        assert_eq!(events[1].queries, Vec::<String>::new());
+        sink.emit(&send(SerializedEffectLoggerAction::EvaluateStop(
+            EvaluateStopAction { cache_key: foo_key },
+        )));
+        sink.emit(&send(emit_effect_action(
+            effect_type,
+            vec![(effect, factory.create_int_term(5))],
+        )));
+        let events = output.events();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[2].queries, vec![String::from("bar")]);
+    }
+
+    #[test]
+    fn effect_audit_outcomes() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let output = CollectEventSink::default();
+        let mut sink = EffectAuditEventSink::new(factory, "secret", output.clone());
+        let effect_type = factory.create_string_term(allocator.create_static_string("reflex::fetch"));
+        let effect = create_fetch_effect("https://example.com/", &factory, &allocator);
+        let error =
+            factory.create_signal_term(allocator.create_signal_list([allocator.create_signal(
+                SignalType::Error {
+                    payload: factory.create_string_term(allocator.create_static_string("Failed")),
+                },
+            )]));
+        let pending = factory.create_signal_term(
+            allocator.create_signal_list([allocator.create_signal(SignalType::Pending)]),
+        );
+        sink.emit(&send(emit_effect_action(
+            effect_type,
+            vec![
+                (effect.clone(), factory.create_int_term(3)),
+                (effect.clone(), error),
+                (effect, pending),
+            ],
+        )));
+        assert_eq!(
+            output
+                .events()
+                .into_iter()
+                .map(|event| event.outcome)
+                .collect::<Vec<_>>(),
+            vec![
+                EffectAuditOutcome::Success,
+                EffectAuditOutcome::Error,
+                EffectAuditOutcome::Pending,
+            ],
+        );
+    }
+
+    #[test]
+    fn effect_audit_skips_evaluate_effects() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let output = CollectEventSink::default();
+        let mut sink = EffectAuditEventSink::new(factory, "secret", output.clone());
+        let (cache_key, _) = create_query("foo", &factory, &allocator);
+        sink.emit(&send(emit_effect_action(
+            factory.create_string_term(allocator.create_static_string("reflex::core::evaluate")),
+            vec![(cache_key, factory.create_int_term(3))],
+        )));
+        assert_eq!(output.events(), Vec::new());
+    }
+
+    #[test]
+    fn effect_audit_receive_events() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let output = CollectEventSink::default();
+        let mut sink = EffectAuditEventSink::new(factory, "secret", output.clone());
+        let effect_type = factory.create_string_term(allocator.create_static_string("reflex::fetch"));
+        let effect = create_fetch_effect("https://example.com/", &factory, &allocator);
+        sink.emit(&EffectLoggerEvent::Receive {
+            pid: 0.into(),
+            action: emit_effect_action(effect_type, vec![(effect, factory.create_int_term(3))]),
+            metadata: None,
+        });
+        assert_eq!(output.events(), Vec::new());
+    }
+
+    #[test]
+    fn effect_audit_payload_redaction() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let effect_type = factory.create_string_term(allocator.create_static_string("reflex::fetch"));
+        let effect = create_fetch_effect("https://example.com/?token=1234", &factory, &allocator);
+        let other_effect = create_fetch_effect("https://example.com/?token=5678", &factory, &allocator);
+        let emit_effects = |redaction_key: &str| {
+            let output = CollectEventSink::default();
+            let mut sink = EffectAuditEventSink::new(factory, redaction_key, output.clone());
+            sink.emit(&send(emit_effect_action(
+                effect_type.clone(),
+                vec![
+                    (effect.clone(), factory.create_int_term(3)),
+                    (effect.clone(), factory.create_int_term(4)),
+                    (other_effect.clone(), factory.create_int_term(5)),
+                ],
+            )));
+            output
+                .events()
+                .into_iter()
+                .map(|event| event.payload_hash)
+                .collect::<Vec<_>>()
+        };
+        let hashes = emit_effects("secret");
+        assert_eq!(hashes[0].len(), 64);
+        assert!(!hashes[0].contains("1234"));
+        assert_eq!(hashes[0], hashes[1]);
+        assert_ne!(hashes[0], hashes[2]);
+        assert_eq!(emit_effects("secret"), hashes);
+        assert_ne!(emit_effects("other")[0], hashes[0]);
+    }
+
+    #[test]
+    fn effect_audit_sensitive_payload_redaction() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let effect_type =
+            factory.create_string_term(allocator.create_static_string("reflex::fetch"));
+        let url =
+            factory.create_string_term(allocator.create_static_string("https://example.com/"));
+        let create_sensitive_effect = |token: &str| {
+            let token = factory.create_string_term(allocator.create_string(token));
+            allocator.create_signal(SignalType::Custom {
+                effect_type: effect_type.clone(),
+                payload: factory.create_list_term(
+                    allocator.create_pair(url.clone(), factory.create_sensitive_term(token)),
+                ),
+                token: factory.create_nil_term(),
+            })
+        };
+        let effect = create_sensitive_effect("1234");
+        let other_effect = create_sensitive_effect("5678");
+        let output = CollectEventSink::default();
+        let mut sink = EffectAuditEventSink::new(factory, "secret", output.clone());
+        sink.emit(&send(emit_effect_action(
+            effect_type.clone(),
+            vec![
+                (effect, factory.create_int_term(3)),
+                (other_effect, factory.create_int_term(4)),
+            ],
+        )));
+        let hashes = output
+            .events()
+            .into_iter()
+            .map(|event| event.payload_hash)
+            .collect::<Vec<_>>();
+        assert_eq!(hashes.len(), 2);
+        assert_eq!(hashes[0], hashes[1]);
+    }
+}
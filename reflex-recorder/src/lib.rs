@@ -1,7 +1,9 @@
 // SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+pub mod audit;
 pub mod effect;
 pub mod file_recorder;
 pub mod session_playback;
 pub mod session_recorder;
+pub mod session_snapshot;
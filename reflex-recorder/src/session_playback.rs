@@ -4,7 +4,7 @@
 // SPDX-FileContributor: Chris Campbell <c.campbell@mwam.com> https://github.com/c-campbell-mwam
 use std::iter::once;
 
-use reflex_macros::{dispatcher, Named};
+use reflex_macros::{dispatcher, ActionSchema, Named};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value as JsonValue};
 
@@ -14,7 +14,7 @@ use reflex_dispatcher::{
     TaskFactory, TaskInbox,
 };
 
-#[derive(Named, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct SessionPlaybackBeginAction {
     pub num_frames: usize,
 }
@@ -25,7 +25,7 @@ impl SerializableAction for SessionPlaybackBeginAction {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct SessionPlaybackEndAction;
 impl Action for SessionPlaybackEndAction {}
 impl SerializableAction for SessionPlaybackEndAction {
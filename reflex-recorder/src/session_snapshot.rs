@@ -0,0 +1,556 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    collections::hash_map::Entry,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+    time::SystemTime,
+};
+
+use reflex::{
+    core::{
+        ConditionType, Expression, ExpressionFactory, ExpressionListType, HashmapTermType,
+        HashsetTermType, ListTermType, RecordTermType, RefType, StateToken,
+    },
+    hash::IntMap,
+};
+use reflex_runtime::{
+    action::effect::{
+        EffectEmitAction, EffectSubscribeAction, EffectUnsubscribeAction, EffectUpdateBatch,
+    },
+    actor::evaluate_handler::is_evaluate_effect_type,
+};
+use reflex_utils::event::EventSink;
+use serde::{Deserialize, Serialize};
+
+use crate::effect::{EffectLoggerEvent, SerializedEffectLoggerAction};
+
+/// Serializable snapshot of the runtime session state, used to seed the runtime state cache when restarting a server
+///
+/// Only the latest resolved values of actively-subscribed effects are captured: query evaluation caches are tied to
+/// the compiled graph (which is liable to change across upgrades) and are recomputed from the restored effect values,
+/// while effect subscriptions are re-established by the relevant handlers as soon as the queries that depend on them
+/// are re-evaluated (at which point the restored values are superseded by fresh handler emissions).
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "T: Serialize, <T as Expression>::Signal: Serialize",
+    deserialize = "T: Deserialize<'de>, <T as Expression>::Signal: Deserialize<'de>"
+))]
+pub struct RuntimeSessionSnapshot<T: Expression> {
+    /// Time at which the snapshot was taken, in milliseconds since the UNIX epoch
+    pub timestamp: u64,
+    /// Latest effect values, grouped by effect type
+    pub effects: Vec<EffectUpdateBatch<T>>,
+}
+
+impl<T: Expression> RuntimeSessionSnapshot<T> {
+    pub fn len(&self) -> usize {
+        self.effects.iter().map(|batch| batch.updates.len()).sum()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Discard all effect values whose effect type does not satisfy the provided predicate (used to exclude effect
+    /// types whose values cannot safely be served stale while awaiting handler resubscription)
+    pub fn retain_effect_types(&mut self, predicate: impl Fn(&T) -> bool) {
+        self.effects.retain(|batch| predicate(&batch.effect_type));
+    }
+    /// Convert the snapshot into an action that seeds the runtime state cache with the captured effect values
+    pub fn into_emit_action(self) -> Option<EffectEmitAction<T>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(EffectEmitAction {
+                effect_types: self.effects,
+            })
+        }
+    }
+}
+
+/// Event sink that keeps track of the latest values of all actively-subscribed effects, allowing a
+/// [`RuntimeSessionSnapshot`] to be taken at any point during the runtime session
+///
+/// Cloned instances share the same underlying session state.
+///
+/// Effect values that contain sensitive terms are not captured unless explicitly enabled via
+/// [`RuntimeSessionSnapshotEventSink::include_sensitive_values`].
+pub struct RuntimeSessionSnapshotEventSink<T: Expression, TFactory: ExpressionFactory<T>> {
+    factory: TFactory,
+    state: Arc<Mutex<RuntimeSessionState<T>>>,
+    include_sensitive_values: bool,
+    _expression: PhantomData<T>,
+}
+
+impl<T: Expression, TFactory: ExpressionFactory<T> + Clone> Clone
+    for RuntimeSessionSnapshotEventSink<T, TFactory>
+{
+    fn clone(&self) -> Self {
+        Self {
+            factory: self.factory.clone(),
+            state: Arc::clone(&self.state),
+            include_sensitive_values: self.include_sensitive_values,
+            _expression: PhantomData,
+        }
+    }
+}
+
+struct RuntimeSessionState<T: Expression> {
+    effects: IntMap<StateToken, RuntimeSessionEffectState<T>>,
+}
+
+impl<T: Expression> Default for RuntimeSessionState<T> {
+    fn default() -> Self {
+        Self {
+            effects: Default::default(),
+        }
+    }
+}
+
+struct RuntimeSessionEffectState<T: Expression> {
+    effect_type: T,
+    effect: T::Signal,
+    subscription_count: usize,
+    value: Option<T>,
+}
+
+impl<T: Expression, TFactory: ExpressionFactory<T>> RuntimeSessionSnapshotEventSink<T, TFactory> {
+    pub fn new(factory: TFactory) -> Self {
+        Self {
+            factory,
+            state: Default::default(),
+            include_sensitive_values: false,
+            _expression: PhantomData,
+        }
+    }
+    /// Capture effect values that contain sensitive terms (these are omitted by default, as snapshots are liable to be
+    /// persisted outside the running process)
+    pub fn include_sensitive_values(self) -> Self {
+        Self {
+            include_sensitive_values: true,
+            ..self
+        }
+    }
+    /// Capture the latest resolved values of all currently-subscribed effects
+    pub fn snapshot(&self) -> RuntimeSessionSnapshot<T> {
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as u64)
+            .unwrap_or_default();
+        let state = self.state.lock().unwrap();
+        let effects = state
+            .effects
+            .values()
+            .filter_map(|effect_state| {
+                effect_state.value.as_ref().map(|value| {
+                    (
+                        &effect_state.effect_type,
+                        (effect_state.effect.clone(), value.clone()),
+                    )
+                })
+            })
+            .fold(
+                Vec::<EffectUpdateBatch<T>>::new(),
+                |mut batches, (effect_type, update)| {
+                    match batches
+                        .iter_mut()
+                        .find(|batch| batch.effect_type.id() == effect_type.id())
+                    {
+                        Some(batch) => batch.updates.push(update),
+                        None => batches.push(EffectUpdateBatch {
+                            effect_type: effect_type.clone(),
+                            updates: vec![update],
+                        }),
+                    }
+                    batches
+                },
+            );
+        RuntimeSessionSnapshot { timestamp, effects }
+    }
+    fn handle_effect_subscribe_action(&mut self, action: &EffectSubscribeAction<T>) {
+        let EffectSubscribeAction {
+            effect_type,
+            effects,
+        } = action;
+        if is_evaluate_effect_type(effect_type, &self.factory) {
+            return;
+        }
+        let mut state = self.state.lock().unwrap();
+        for effect in effects {
+            match state.effects.entry(effect.id()) {
+                Entry::Occupied(mut entry) => {
+                    entry.get_mut().subscription_count += 1;
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(RuntimeSessionEffectState {
+                        effect_type: effect_type.clone(),
+                        effect: effect.clone(),
+                        subscription_count: 1,
+                        value: None,
+                    });
+                }
+            }
+        }
+    }
+    fn handle_effect_unsubscribe_action(&mut self, action: &EffectUnsubscribeAction<T>) {
+        let EffectUnsubscribeAction { effects, .. } = action;
+        let mut state = self.state.lock().unwrap();
+        for effect in effects {
+            if let Entry::Occupied(mut entry) = state.effects.entry(effect.id()) {
+                let effect_state = entry.get_mut();
+                effect_state.subscription_count = effect_state.subscription_count.saturating_sub(1);
+                if effect_state.subscription_count == 0 {
+                    entry.remove();
+                }
+            }
+        }
+    }
+    fn handle_effect_emit_action(&mut self, action: &EffectEmitAction<T>) {
+        let EffectEmitAction { effect_types } = action;
+        let mut state = self.state.lock().unwrap();
+        for (effect, value) in effect_types.iter().flat_map(|batch| batch.updates.iter()) {
+            if let Some(effect_state) = state.effects.get_mut(&effect.id()) {
+                // Pending and error values are not retained, as these are liable to be transient
+                effect_state.value = if self.factory.match_signal_term(value).is_some() {
+                    None
+                } else if !self.include_sensitive_values
+                    && contains_sensitive_values(value, &self.factory)
+                {
+                    None
+                } else {
+                    Some(value.clone())
+                };
+            }
+        }
+    }
+}
+
+impl<T: Expression, TFactory: ExpressionFactory<T>> EventSink
+    for RuntimeSessionSnapshotEventSink<T, TFactory>
+{
+    type Event = EffectLoggerEvent<T>;
+    fn emit(&mut self, event: &Self::Event) {
+        let action = match event {
+            EffectLoggerEvent::Send { action, .. } => action,
+            EffectLoggerEvent::Receive { .. } => return,
+        };
+        match action {
+            SerializedEffectLoggerAction::EffectSubscribe(action) => {
+                self.handle_effect_subscribe_action(action)
+            }
+            SerializedEffectLoggerAction::EffectUnsubscribe(action) => {
+                self.handle_effect_unsubscribe_action(action)
+            }
+            SerializedEffectLoggerAction::EffectEmit(action) => {
+                self.handle_effect_emit_action(action)
+            }
+            SerializedEffectLoggerAction::EvaluateStart(_)
+            | SerializedEffectLoggerAction::EvaluateStop(_)
+            | SerializedEffectLoggerAction::EvaluateResult(_)
+            | SerializedEffectLoggerAction::EvaluateUpdate(_) => {}
+        }
+    }
+}
+
+fn contains_sensitive_values<T: Expression>(
+    value: &T,
+    factory: &impl ExpressionFactory<T>,
+) -> bool {
+    if factory.match_sensitive_term(value).is_some() {
+        true
+    } else if let Some(term) = factory.match_list_term(value) {
+        term.items()
+            .as_deref()
+            .iter()
+            .any(|item| contains_sensitive_values(item.as_deref(), factory))
+    } else if let Some(term) = factory.match_record_term(value) {
+        term.values()
+            .as_deref()
+            .iter()
+            .any(|item| contains_sensitive_values(item.as_deref(), factory))
+    } else if let Some(term) = factory.match_hashmap_term(value) {
+        term.keys()
+            .chain(term.values())
+            .any(|item| contains_sensitive_values(item.as_deref(), factory))
+    } else if let Some(term) = factory.match_hashset_term(value) {
+        term.values()
+            .any(|item| contains_sensitive_values(item.as_deref(), factory))
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reflex::core::{HeapAllocator, SignalType};
+    use reflex_lang::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
+    use reflex_runtime::{
+        actor::evaluate_handler::{create_evaluate_effect, create_evaluate_effect_type},
+        QueryEvaluationMode, QueryInvalidationStrategy,
+    };
+    use reflex_stdlib::Stdlib;
+
+    use super::*;
+
+    type T = CachedSharedTerm<Stdlib>;
+
+    fn send(action: SerializedEffectLoggerAction<T>) -> EffectLoggerEvent<T> {
+        EffectLoggerEvent::Send {
+            pid: 0.into(),
+            action,
+            metadata: None,
+        }
+    }
+
+    fn create_fetch_effect(
+        url: &str,
+        factory: &SharedTermFactory<Stdlib>,
+        allocator: &DefaultAllocator<T>,
+    ) -> <T as Expression>::Signal {
+        allocator.create_signal(SignalType::Custom {
+            effect_type: factory
+                .create_string_term(allocator.create_static_string("reflex::fetch")),
+            payload: factory.create_string_term(allocator.create_string(url)),
+            token: factory.create_nil_term(),
+        })
+    }
+
+    fn subscribe_action(
+        effect_type: T,
+        effects: Vec<<T as Expression>::Signal>,
+    ) -> SerializedEffectLoggerAction<T> {
+        SerializedEffectLoggerAction::EffectSubscribe(EffectSubscribeAction {
+            effect_type,
+            effects,
+        })
+    }
+
+    fn unsubscribe_action(
+        effect_type: T,
+        effects: Vec<<T as Expression>::Signal>,
+    ) -> SerializedEffectLoggerAction<T> {
+        SerializedEffectLoggerAction::EffectUnsubscribe(EffectUnsubscribeAction {
+            effect_type,
+            effects,
+        })
+    }
+
+    fn emit_effect_action(
+        effect_type: T,
+        updates: Vec<(<T as Expression>::Signal, T)>,
+    ) -> SerializedEffectLoggerAction<T> {
+        SerializedEffectLoggerAction::EffectEmit(EffectEmitAction {
+            effect_types: vec![EffectUpdateBatch {
+                effect_type,
+                updates,
+            }],
+        })
+    }
+
+    #[test]
+    fn session_snapshot_active_effects() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let mut sink = RuntimeSessionSnapshotEventSink::new(factory);
+        let effect_type =
+            factory.create_string_term(allocator.create_static_string("reflex::fetch"));
+        let foo = create_fetch_effect("https://example.com/foo", &factory, &allocator);
+        let bar = create_fetch_effect("https://example.com/bar", &factory, &allocator);
+        let unsubscribed = create_fetch_effect("https://example.com/baz", &factory, &allocator);
+        sink.emit(&send(subscribe_action(
+            effect_type.clone(),
+            vec![foo.clone(), bar.clone()],
+        )));
+        sink.emit(&send(emit_effect_action(
+            effect_type.clone(),
+            vec![
+                (foo.clone(), factory.create_int_term(3)),
+                (bar.clone(), factory.create_int_term(4)),
+                (unsubscribed, factory.create_int_term(5)),
+            ],
+        )));
+        sink.emit(&send(emit_effect_action(
+            effect_type.clone(),
+            vec![(foo.clone(), factory.create_int_term(6))],
+        )));
+        let snapshot = sink.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot.effects.len(), 1);
+        let updates = &snapshot.effects[0].updates;
+        assert!(updates.contains(&(foo.clone(), factory.create_int_term(6))));
+        assert!(updates.contains(&(bar.clone(), factory.create_int_term(4))));
+        sink.emit(&send(unsubscribe_action(effect_type, vec![bar])));
+        let snapshot = sink.clone().snapshot();
+        assert_eq!(
+            snapshot.effects[0].updates,
+            vec![(foo, factory.create_int_term(6))]
+        );
+    }
+
+    #[test]
+    fn session_snapshot_shared_subscriptions() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let mut sink = RuntimeSessionSnapshotEventSink::new(factory);
+        let effect_type =
+            factory.create_string_term(allocator.create_static_string("reflex::fetch"));
+        let effect = create_fetch_effect("https://example.com/", &factory, &allocator);
+        sink.emit(&send(subscribe_action(
+            effect_type.clone(),
+            vec![effect.clone()],
+        )));
+        sink.emit(&send(subscribe_action(
+            effect_type.clone(),
+            vec![effect.clone()],
+        )));
+        sink.emit(&send(emit_effect_action(
+            effect_type.clone(),
+            vec![(effect.clone(), factory.create_int_term(3))],
+        )));
+        sink.emit(&send(unsubscribe_action(
+            effect_type.clone(),
+            vec![effect.clone()],
+        )));
+        assert_eq!(sink.snapshot().len(), 1);
+        sink.emit(&send(unsubscribe_action(effect_type, vec![effect])));
+        assert!(sink.snapshot().is_empty());
+    }
+
+    #[test]
+    fn session_snapshot_skips_transient_values() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let mut sink = RuntimeSessionSnapshotEventSink::new(factory);
+        let effect_type =
+            factory.create_string_term(allocator.create_static_string("reflex::fetch"));
+        let effect = create_fetch_effect("https://example.com/", &factory, &allocator);
+        let error =
+            factory.create_signal_term(allocator.create_signal_list([allocator.create_signal(
+                SignalType::Error {
+                    payload: factory.create_string_term(allocator.create_static_string("Failed")),
+                },
+            )]));
+        sink.emit(&send(subscribe_action(
+            effect_type.clone(),
+            vec![effect.clone()],
+        )));
+        sink.emit(&send(emit_effect_action(
+            effect_type.clone(),
+            vec![(effect.clone(), factory.create_int_term(3))],
+        )));
+        sink.emit(&send(emit_effect_action(
+            effect_type,
+            vec![(effect, error)],
+        )));
+        assert!(sink.snapshot().is_empty());
+        let (cache_key, evaluate_effect_type) = (
+            create_evaluate_effect(
+                String::from("foo"),
+                factory.create_nil_term(),
+                QueryEvaluationMode::Query,
+                QueryInvalidationStrategy::default(),
+                &factory,
+                &allocator,
+            ),
+            create_evaluate_effect_type(&factory, &allocator),
+        );
+        sink.emit(&send(subscribe_action(
+            evaluate_effect_type.clone(),
+            vec![cache_key.clone()],
+        )));
+        sink.emit(&send(emit_effect_action(
+            evaluate_effect_type,
+            vec![(cache_key, factory.create_int_term(3))],
+        )));
+        assert!(sink.snapshot().is_empty());
+    }
+
+    #[test]
+    fn session_snapshot_skips_sensitive_values() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let effect_type =
+            factory.create_string_term(allocator.create_static_string("reflex::fetch"));
+        let effect = create_fetch_effect("https://example.com/", &factory, &allocator);
+        let value = factory.create_list_term(allocator.create_pair(
+            factory.create_int_term(3),
+            factory.create_sensitive_term(
+                factory.create_string_term(allocator.create_static_string("secret")),
+            ),
+        ));
+        let mut sink = RuntimeSessionSnapshotEventSink::new(factory);
+        sink.emit(&send(subscribe_action(
+            effect_type.clone(),
+            vec![effect.clone()],
+        )));
+        sink.emit(&send(emit_effect_action(
+            effect_type.clone(),
+            vec![(effect.clone(), value.clone())],
+        )));
+        assert!(sink.snapshot().is_empty());
+        let mut sink = RuntimeSessionSnapshotEventSink::new(factory).include_sensitive_values();
+        sink.emit(&send(subscribe_action(
+            effect_type.clone(),
+            vec![effect.clone()],
+        )));
+        sink.emit(&send(emit_effect_action(
+            effect_type,
+            vec![(effect, value)],
+        )));
+        assert_eq!(sink.snapshot().len(), 1);
+    }
+
+    #[test]
+    fn session_snapshot_restore() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let mut sink = RuntimeSessionSnapshotEventSink::new(factory);
+        let fetch_type =
+            factory.create_string_term(allocator.create_static_string("reflex::fetch"));
+        let timestamp_type =
+            factory.create_string_term(allocator.create_static_string("reflex::timestamp"));
+        let fetch = create_fetch_effect("https://example.com/", &factory, &allocator);
+        let timestamp = allocator.create_signal(SignalType::Custom {
+            effect_type: timestamp_type.clone(),
+            payload: factory.create_int_term(1000),
+            token: factory.create_nil_term(),
+        });
+        sink.emit(&send(subscribe_action(
+            fetch_type.clone(),
+            vec![fetch.clone()],
+        )));
+        sink.emit(&send(subscribe_action(
+            timestamp_type.clone(),
+            vec![timestamp.clone()],
+        )));
+        sink.emit(&send(emit_effect_action(
+            fetch_type.clone(),
+            vec![(fetch.clone(), factory.create_int_term(3))],
+        )));
+        sink.emit(&send(emit_effect_action(
+            timestamp_type.clone(),
+            vec![(timestamp, factory.create_int_term(4))],
+        )));
+        let serialized = serde_json::to_string(&sink.snapshot()).unwrap();
+        let mut snapshot: RuntimeSessionSnapshot<T> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(snapshot.len(), 2);
+        snapshot.retain_effect_types(|effect_type| *effect_type != timestamp_type);
+        assert_eq!(
+            snapshot.into_emit_action(),
+            Some(EffectEmitAction {
+                effect_types: vec![EffectUpdateBatch {
+                    effect_type: fetch_type,
+                    updates: vec![(fetch, factory.create_int_term(3))],
+                }],
+            }),
+        );
+        assert_eq!(
+            RuntimeSessionSnapshot::<T> {
+                timestamp: 0,
+                effects: Vec::new(),
+            }
+            .into_emit_action(),
+            None,
+        );
+    }
+}
@@ -3,6 +3,7 @@
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 use reflex::core::Expression;
 use reflex_dispatcher::{Action, Named, SerializableAction, SerializedAction};
+use reflex_macros::ActionSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -15,7 +16,7 @@ use self::effect::*;
 use self::evaluate::*;
 use self::query::*;
 
-#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub enum RuntimeActions<T: Expression> {
     #[serde(bound(
         serialize = "<T as Expression>::Signal: Serialize",
@@ -159,6 +160,38 @@ impl<'a, T: Expression> From<&'a RuntimeActions<T>> for Option<&'a EffectEmitAct
     }
 }
 
+impl<T: Expression> From<EffectThrottleEmitAction> for RuntimeActions<T> {
+    fn from(value: EffectThrottleEmitAction) -> Self {
+        EffectActions::from(value).into()
+    }
+}
+impl<T: Expression> From<RuntimeActions<T>> for Option<EffectThrottleEmitAction> {
+    fn from(value: RuntimeActions<T>) -> Self {
+        Option::<EffectActions<T>>::from(value).and_then(|value| value.into())
+    }
+}
+impl<'a, T: Expression> From<&'a RuntimeActions<T>> for Option<&'a EffectThrottleEmitAction> {
+    fn from(value: &'a RuntimeActions<T>) -> Self {
+        Option::<&'a EffectActions<T>>::from(value).and_then(|value| value.into())
+    }
+}
+
+impl<T: Expression> From<EffectSchemaDriftAction<T>> for RuntimeActions<T> {
+    fn from(value: EffectSchemaDriftAction<T>) -> Self {
+        EffectActions::from(value).into()
+    }
+}
+impl<T: Expression> From<RuntimeActions<T>> for Option<EffectSchemaDriftAction<T>> {
+    fn from(value: RuntimeActions<T>) -> Self {
+        Option::<EffectActions<T>>::from(value).and_then(|value| value.into())
+    }
+}
+impl<'a, T: Expression> From<&'a RuntimeActions<T>> for Option<&'a EffectSchemaDriftAction<T>> {
+    fn from(value: &'a RuntimeActions<T>) -> Self {
+        Option::<&'a EffectActions<T>>::from(value).and_then(|value| value.into())
+    }
+}
+
 impl<T: Expression> From<EvaluateStartAction<T>> for RuntimeActions<T> {
     fn from(value: EvaluateStartAction<T>) -> Self {
         EvaluateActions::from(value).into()
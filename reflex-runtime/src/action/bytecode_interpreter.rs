@@ -5,10 +5,10 @@
 use reflex::core::{ConditionType, EvaluationResult, Expression};
 use reflex_dispatcher::{Action, MessageOffset, Named, SerializableAction, SerializedAction};
 use reflex_json::{JsonMap, JsonValue};
-use reflex_macros::Named;
+use reflex_macros::{ActionSchema, Named};
 use serde::{Deserialize, Serialize};
 
-#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub enum BytecodeInterpreterActions<T: Expression> {
     #[serde(bound(
         serialize = "<T as Expression>::Signal: Serialize",
@@ -172,7 +172,7 @@ impl<'a, T: Expression> From<&'a BytecodeInterpreterActions<T>>
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 #[serde(bound(
     serialize = "<T as Expression>::Signal: Serialize",
     deserialize = "<T as Expression>::Signal: Deserialize<'de>"
@@ -187,7 +187,7 @@ impl<T: Expression> SerializableAction for BytecodeInterpreterInitAction<T> {
     }
 }
 
-#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 #[serde(bound(
     serialize = "T: Serialize, <T as Expression>::Signal: Serialize",
     deserialize = "T: Deserialize<'de>, <T as Expression>::Signal: Deserialize<'de>"
@@ -222,7 +222,7 @@ impl<T: Expression> SerializableAction for BytecodeInterpreterEvaluateAction<T>
     }
 }
 
-#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 #[serde(bound(
     serialize = "T: Serialize, <T as Expression>::Signal: Serialize",
     deserialize = "T: Deserialize<'de>, <T as Expression>::Signal: Deserialize<'de>"
@@ -272,7 +272,7 @@ impl<T: Expression> SerializableAction for BytecodeInterpreterResultAction<T> {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 #[serde(bound(
     serialize = "<T as Expression>::Signal: Serialize",
     deserialize = "<T as Expression>::Signal: Deserialize<'de>"
@@ -297,7 +297,7 @@ impl<T: Expression> SerializableAction for BytecodeInterpreterGcAction<T> {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 #[serde(bound(
     serialize = "<T as Expression>::Signal: Serialize",
     deserialize = "<T as Expression>::Signal: Deserialize<'de>"
@@ -4,10 +4,12 @@
 use reflex::core::{ConditionType, Expression, SignalType};
 use reflex_dispatcher::{Action, Named, SerializableAction, SerializedAction};
 use reflex_json::{JsonMap, JsonValue};
-use reflex_macros::Named;
+use reflex_macros::{ActionSchema, Named};
 use serde::{Deserialize, Serialize};
 
-#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+use crate::utils::effect_schema::EffectSchemaDrift;
+
+#[derive(ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub enum EffectActions<T: Expression> {
     #[serde(bound(
         serialize = "<T as Expression>::Signal: Serialize",
@@ -17,6 +19,11 @@ pub enum EffectActions<T: Expression> {
     Unsubscribe(EffectUnsubscribeAction<T>),
     Emit(EffectEmitAction<T>),
     ThrottleEmit(EffectThrottleEmitAction),
+    #[serde(bound(
+        serialize = "<T as Expression>::Signal: Serialize",
+        deserialize = "<T as Expression>::Signal: Deserialize<'de>"
+    ))]
+    SchemaDrift(EffectSchemaDriftAction<T>),
 }
 impl<T: Expression> Named for EffectActions<T> {
     fn name(&self) -> &'static str {
@@ -25,6 +32,7 @@ impl<T: Expression> Named for EffectActions<T> {
             Self::Unsubscribe(action) => action.name(),
             Self::Emit(action) => action.name(),
             Self::ThrottleEmit(action) => action.name(),
+            Self::SchemaDrift(action) => action.name(),
         }
     }
 }
@@ -36,6 +44,7 @@ impl<T: Expression> SerializableAction for EffectActions<T> {
             Self::Unsubscribe(action) => action.to_json(),
             Self::Emit(action) => action.to_json(),
             Self::ThrottleEmit(action) => action.to_json(),
+            Self::SchemaDrift(action) => action.to_json(),
         }
     }
 }
@@ -128,7 +137,29 @@ impl<'a, T: Expression> From<&'a EffectActions<T>> for Option<&'a EffectThrottle
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+impl<T: Expression> From<EffectSchemaDriftAction<T>> for EffectActions<T> {
+    fn from(value: EffectSchemaDriftAction<T>) -> Self {
+        Self::SchemaDrift(value)
+    }
+}
+impl<T: Expression> From<EffectActions<T>> for Option<EffectSchemaDriftAction<T>> {
+    fn from(value: EffectActions<T>) -> Self {
+        match value {
+            EffectActions::SchemaDrift(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+impl<'a, T: Expression> From<&'a EffectActions<T>> for Option<&'a EffectSchemaDriftAction<T>> {
+    fn from(value: &'a EffectActions<T>) -> Self {
+        match value {
+            EffectActions::SchemaDrift(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct EffectSubscribeAction<T: Expression> {
     pub effect_type: T,
     #[serde(bound(
@@ -169,7 +200,7 @@ impl<T: Expression> SerializableAction for EffectSubscribeAction<T> {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct EffectUnsubscribeAction<T: Expression> {
     pub effect_type: T,
     #[serde(bound(
@@ -210,7 +241,7 @@ impl<T: Expression> SerializableAction for EffectUnsubscribeAction<T> {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 #[serde(bound(
     serialize = "T: Serialize, <T as Expression>::Signal: Serialize",
     deserialize = "T: Deserialize<'de>, <T as Expression>::Signal: Deserialize<'de>"
@@ -244,7 +275,7 @@ impl<T: Expression> SerializableAction for EffectEmitAction<T> {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct EffectThrottleEmitAction;
 impl Action for EffectThrottleEmitAction {}
 impl SerializableAction for EffectThrottleEmitAction {
@@ -253,6 +284,35 @@ impl SerializableAction for EffectThrottleEmitAction {
     }
 }
 
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct EffectSchemaDriftAction<T: Expression> {
+    pub effect_type: T,
+    #[serde(bound(
+        serialize = "<T as Expression>::Signal: Serialize",
+        deserialize = "<T as Expression>::Signal: Deserialize<'de>"
+    ))]
+    pub effect: T::Signal,
+    pub drift: Vec<EffectSchemaDrift>,
+}
+impl<T: Expression> Action for EffectSchemaDriftAction<T> {}
+impl<T: Expression> SerializableAction for EffectSchemaDriftAction<T> {
+    fn to_json(&self) -> SerializedAction {
+        SerializedAction::from_iter([
+            ("effect_type", sanitize_expression(&self.effect_type)),
+            ("effect_id", JsonValue::from(self.effect.id())),
+            (
+                "drift",
+                JsonValue::from(
+                    self.drift
+                        .iter()
+                        .map(|drift| JsonValue::String(drift.to_string()))
+                        .collect::<Vec<_>>(),
+                ),
+            ),
+        ])
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 #[serde(bound(
     serialize = "T: Serialize, <T as Expression>::Signal: Serialize",
@@ -4,12 +4,12 @@
 use reflex::core::{ConditionType, EvaluationResult, Expression};
 use reflex_dispatcher::{Action, MessageOffset, Named, SerializableAction, SerializedAction};
 use reflex_json::JsonValue;
-use reflex_macros::Named;
+use reflex_macros::{ActionSchema, Named};
 use serde::{Deserialize, Serialize};
 
 use crate::{QueryEvaluationMode, QueryInvalidationStrategy};
 
-#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 #[serde(bound(
     serialize = "T: Serialize, <T as Expression>::Signal: Serialize",
     deserialize = "T: Deserialize<'de>, <T as Expression>::Signal: Deserialize<'de>"
@@ -130,7 +130,7 @@ impl<'a, T: Expression> From<&'a EvaluateActions<T>> for Option<&'a EvaluateResu
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 #[serde(bound(
     serialize = "T: Serialize, <T as Expression>::Signal: Serialize",
     deserialize = "T: Deserialize<'de>, <T as Expression>::Signal: Deserialize<'de>"
@@ -167,7 +167,7 @@ impl<T: Expression> SerializableAction for EvaluateStartAction<T> {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 #[serde(bound(
     serialize = "T: Serialize, <T as Expression>::Signal: Serialize",
     deserialize = "T: Deserialize<'de>, <T as Expression>::Signal: Deserialize<'de>"
@@ -197,7 +197,7 @@ impl<T: Expression> SerializableAction for EvaluateUpdateAction<T> {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 #[serde(bound(
     serialize = "<T as Expression>::Signal: Serialize",
     deserialize = "<T as Expression>::Signal: Deserialize<'de>"
@@ -211,7 +211,7 @@ impl<T: Expression> SerializableAction for EvaluateStopAction<T> {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 #[serde(bound(
     serialize = "T: Serialize, <T as Expression>::Signal: Serialize",
     deserialize = "T: Deserialize<'de>, <T as Expression>::Signal: Deserialize<'de>"
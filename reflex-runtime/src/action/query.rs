@@ -4,10 +4,10 @@
 use reflex::core::{EvaluationResult, Expression};
 use reflex_dispatcher::{Action, Named, SerializableAction, SerializedAction};
 use reflex_json::JsonValue;
-use reflex_macros::Named;
+use reflex_macros::{ActionSchema, Named};
 use serde::{Deserialize, Serialize};
 
-#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub enum QueryActions<T: Expression> {
     Subscribe(QuerySubscribeAction<T>),
     Unsubscribe(QueryUnsubscribeAction<T>),
@@ -99,7 +99,7 @@ impl<'a, T: Expression> From<&'a QueryActions<T>> for Option<&'a QueryEmitAction
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct QuerySubscribeAction<T: Expression> {
     pub query: T,
     pub label: String,
@@ -114,7 +114,7 @@ impl<T: Expression> SerializableAction for QuerySubscribeAction<T> {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct QueryUnsubscribeAction<T: Expression> {
     pub query: T,
     pub label: String,
@@ -129,7 +129,7 @@ impl<T: Expression> SerializableAction for QueryUnsubscribeAction<T> {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct QueryEmitAction<T: Expression> {
     pub query: T,
     pub result: EvaluationResult<T>,
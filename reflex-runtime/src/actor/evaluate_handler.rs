@@ -17,11 +17,13 @@ use metrics::{
 };
 use reflex::{
     core::{
-        ConditionListType, ConditionType, DependencyList, DynamicState, EvaluationResult,
-        Expression, ExpressionFactory, ExpressionListType, HeapAllocator, IntTermType, IntValue,
-        ListTermType, RefType, SignalTermType, SignalType, StateToken, StringTermType, StringValue,
+        create_error_expression, ConditionListType, ConditionType, DependencyList, DynamicState,
+        EvaluationResult, Expression, ExpressionFactory, ExpressionListType, HeapAllocator,
+        IntTermType, IntValue, ListTermType, RefType, SignalTermType, SignalType, StateToken,
+        StringTermType, StringValue,
     },
     hash::{FnvHasher, HashId, IntMap, IntSet},
+    sandbox::SandboxPolicies,
 };
 use reflex_dispatcher::{
     Action, ActorEvents, HandlerContext, MessageData, MessageOffset, NoopDisposeCallback,
@@ -33,14 +35,19 @@ use reflex_utils::partition_results;
 use crate::{
     action::{
         effect::{
-            EffectEmitAction, EffectSubscribeAction, EffectThrottleEmitAction,
-            EffectUnsubscribeAction, EffectUpdateBatch,
+            EffectEmitAction, EffectSchemaDriftAction, EffectSubscribeAction,
+            EffectThrottleEmitAction, EffectUnsubscribeAction, EffectUpdateBatch,
         },
         evaluate::{
             EvaluateResultAction, EvaluateStartAction, EvaluateStopAction, EvaluateUpdateAction,
         },
     },
+    migration::StateTokenAliases,
     task::evaluate_handler::EffectThrottleTaskFactory,
+    utils::{
+        demand::prune_unreachable_branches,
+        effect_schema::{EffectResultShape, EffectSchemaDriftOptions, EffectSchemaRegistry},
+    },
     QueryEvaluationMode, QueryInvalidationStrategy,
 };
 
@@ -77,6 +84,7 @@ pub struct EvaluateHandlerMetricNames {
     pub error_query_worker_count: &'static str,
     pub blocked_query_worker_count: &'static str,
     pub active_query_worker_cache_entry_count: &'static str,
+    pub effect_schema_drift_count: &'static str,
 }
 impl EvaluateHandlerMetricNames {
     fn init(self) -> Self {
@@ -132,6 +140,11 @@ impl EvaluateHandlerMetricNames {
             Unit::Count,
             "Active query worker cache entry count"
         );
+        describe_counter!(
+            self.effect_schema_drift_count,
+            Unit::Count,
+            "Number of effect results whose shape diverged from the learned result schema"
+        );
         self
     }
 }
@@ -150,6 +163,7 @@ impl Default for EvaluateHandlerMetricNames {
             error_query_worker_count: "error_query_worker_count",
             blocked_query_worker_count: "blocked_query_worker_count",
             active_query_worker_cache_entry_count: "active_query_worker_cache_entry_count",
+            effect_schema_drift_count: "effect_schema_drift_count",
         }
     }
 }
@@ -283,6 +297,9 @@ where
     factory: TFactory,
     allocator: TAllocator,
     throttle: Option<Duration>,
+    schema_drift: Option<EffectSchemaDriftOptions>,
+    sandbox_policies: SandboxPolicies,
+    state_token_aliases: StateTokenAliases<T>,
     metric_names: EvaluateHandlerMetricNames,
     main_pid: ProcessId,
     _expression: PhantomData<T>,
@@ -297,6 +314,9 @@ where
         factory: TFactory,
         allocator: TAllocator,
         throttle: Option<Duration>,
+        schema_drift: Option<EffectSchemaDriftOptions>,
+        sandbox_policies: SandboxPolicies,
+        state_token_aliases: StateTokenAliases<T>,
         metric_names: EvaluateHandlerMetricNames,
         main_pid: ProcessId,
     ) -> Self {
@@ -304,6 +324,9 @@ where
             factory,
             allocator,
             throttle,
+            schema_drift,
+            sandbox_policies,
+            state_token_aliases,
             metric_names: metric_names.init(),
             main_pid,
             _expression: Default::default(),
@@ -319,6 +342,10 @@ pub struct EvaluateHandlerState<T: Expression> {
     immediate_effects: IntSet<StateToken>,
     /// Accumulated set of pending deferred updates, to be applied at the next throttle timeout
     deferred_updates: Option<IntMap<StateToken, (T::Signal, T)>>,
+    /// Mapping from replacement effect state tokens to the legacy state tokens whose cached values they inherit
+    aliased_state_tokens: IntMap<StateToken, StateToken>,
+    /// Result schemas learned from previous effect emissions (only populated when schema drift detection is enabled)
+    effect_schemas: EffectSchemaRegistry,
 }
 impl<T: Expression> Default for EvaluateHandlerState<T> {
     fn default() -> Self {
@@ -327,14 +354,21 @@ impl<T: Expression> Default for EvaluateHandlerState<T> {
             state_cache: Default::default(),
             immediate_effects: Default::default(),
             deferred_updates: Default::default(),
+            aliased_state_tokens: Default::default(),
+            effect_schemas: Default::default(),
         }
     }
 }
 struct WorkerState<T: Expression> {
     subscription_count: usize,
     effect: T::Signal,
+    /// Query label, used to determine which sandbox policy applies to this worker's effects
+    label: String,
     status: WorkerStatus<T>,
     state_values: WorkerStateCache<T>,
+    /// Error values for any effects forbidden by this worker's sandbox policy
+    /// These are resolved locally within the worker, without touching the global state cache shared with other workers
+    denied_effects: IntMap<StateToken, (T::Signal, T)>,
     metric_labels: [(&'static str, String); 1],
 }
 struct GlobalStateCache<T: Expression> {
@@ -457,10 +491,12 @@ impl<T: Expression> EvaluateHandlerState<T> {
                     latest_result: (_, result),
                     ..
                 } => {
-                    let has_invalidated_dependencies = result
-                        .dependencies()
-                        .iter()
-                        .any(|state_token| updated_state_tokens.contains(&state_token));
+                    let denied_effects = &worker.denied_effects;
+                    let has_invalidated_dependencies =
+                        result.dependencies().iter().any(|state_token| {
+                            updated_state_tokens.contains(&state_token)
+                                && !denied_effects.contains_key(&state_token)
+                        });
                     if has_invalidated_dependencies {
                         Some(worker)
                     } else {
@@ -476,6 +512,7 @@ impl<T: Expression> EvaluateHandlerState<T> {
                     worker,
                     WorkerStateUpdateType::DependencyUpdate,
                     &mut self.state_cache,
+                    None,
                     metric_names,
                 )
                 .map(|action| SchedulerCommand::Send(main_pid, TAction::from(action)))
@@ -702,6 +739,7 @@ dispatcher!({
         Outbox(EffectSubscribeAction<T>),
         Outbox(EffectUnsubscribeAction<T>),
         Outbox(EffectEmitAction<T>),
+        Outbox(EffectSchemaDriftAction<T>),
         Outbox(EvaluateStartAction<T>),
         Outbox(EvaluateUpdateAction<T>),
         Outbox(EvaluateStopAction<T>),
@@ -902,14 +940,21 @@ where
                             entry.insert(WorkerState {
                                 subscription_count: 1,
                                 effect: effect.clone(),
+                                label: label.clone(),
                                 status: WorkerStatus::Busy {
                                     previous_result: None,
                                     active_effects: Default::default(),
                                 },
                                 state_values: Default::default(),
+                                denied_effects: Default::default(),
                                 metric_labels,
                             });
                             let cache_key = effect.clone();
+                            // Discard any statically-unreachable branches before evaluation, to avoid subscribing to
+                            // effects that can never be selected for this query
+                            let query =
+                                prune_unreachable_branches(&query, &self.factory, &self.allocator)
+                                    .unwrap_or(query);
                             Some(Ok(SchedulerCommand::Send(
                                 self.main_pid,
                                 EvaluateStartAction {
@@ -1036,6 +1081,13 @@ where
                 .map(|effect| effect.id())
                 .collect::<DependencyList>();
             state.state_cache.gc(&retained_keys, self.metric_names);
+            let combined_state = &state.state_cache.combined_state;
+            state
+                .aliased_state_tokens
+                .retain(|_, legacy_state_token| combined_state.has(legacy_state_token));
+            state
+                .effect_schemas
+                .retain(|state_token| retained_keys.contains(*state_token));
         }
         let has_unsubscribed_workers = !unsubscribed_workers.is_empty();
         if has_unsubscribed_workers {
@@ -1048,7 +1100,7 @@ where
         &self,
         state: &mut EvaluateHandlerState<T>,
         action: &EvaluateResultAction<T>,
-        _metadata: &MessageData,
+        metadata: &MessageData,
         _context: &mut impl HandlerContext,
     ) -> Option<SchedulerTransition<TAction, TTask>>
     where
@@ -1129,6 +1181,48 @@ where
                     .collect::<IntMap<_, _>>()
             })
             .unwrap_or_default();
+        // Any effects forbidden by the sandbox policy are resolved immediately with an error rather than being subscribed
+        // (the error is injected into this worker's local state, so other workers that are permitted to use the same
+        // effect are unaffected)
+        let sandbox_policy = self.sandbox_policies.get(&worker.label);
+        let (added_worker_effects, forbidden_worker_effects) =
+            added_worker_effects.into_iter().fold(
+                (IntMap::default(), IntMap::default()),
+                |(mut permitted_effects, mut forbidden_effects), (effect_id, effect)| {
+                    match sandbox_policy.validate_effect(&effect, &self.factory) {
+                        Ok(_) => {
+                            permitted_effects.insert(effect_id, effect);
+                        }
+                        Err(err) => {
+                            let value = create_error_expression(
+                                self.factory.create_string_term(
+                                    self.allocator.create_string(format!("{}", err)),
+                                ),
+                                &self.factory,
+                                &self.allocator,
+                            );
+                            forbidden_effects.insert(effect_id, (effect, value));
+                        }
+                    }
+                    (permitted_effects, forbidden_effects)
+                },
+            );
+        // Any added effects that replace a legacy effect inherit the legacy effect's cached value (if one exists), to
+        // avoid discarding existing state while effect identities are being migrated
+        let migrated_worker_effects = if state.aliased_state_tokens.is_empty() {
+            Vec::new()
+        } else {
+            let combined_state = &state.state_cache.combined_state;
+            added_worker_effects
+                .iter()
+                .filter(|(effect_id, _)| !combined_state.has(effect_id))
+                .filter_map(|(effect_id, effect)| {
+                    let legacy_state_token = state.aliased_state_tokens.get(effect_id)?;
+                    let value = combined_state.get(legacy_state_token)?;
+                    Some((effect.clone(), value.clone()))
+                })
+                .collect::<Vec<_>>()
+        };
         let removed_worker_effects = previous_worker_effects
             .iter()
             .filter(|(effect_id, _)| !worker_dependencies.contains(**effect_id))
@@ -1156,6 +1250,36 @@ where
                 )
             })
             .collect::<Vec<_>>();
+        let migrated_effect_emit_action = if migrated_worker_effects.is_empty() {
+            None
+        } else {
+            let mut migrated_values = migrated_worker_effects
+                .iter()
+                .map(|(effect, value)| (effect.id(), value.clone()))
+                .collect::<IntMap<_, _>>();
+            Some(SchedulerCommand::Send(
+                self.main_pid,
+                EffectEmitAction {
+                    effect_types: group_effects_by_type(
+                        migrated_worker_effects
+                            .into_iter()
+                            .map(|(effect, _)| effect),
+                    )
+                    .map(|(effect_type, effects)| EffectUpdateBatch {
+                        effect_type,
+                        updates: effects
+                            .into_iter()
+                            .filter_map(|effect| {
+                                let value = migrated_values.remove(&effect.id())?;
+                                Some((effect, value))
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+                }
+                .into(),
+            ))
+        };
         let effect_unsubscribe_actions = group_effects_by_type(unsubscribed_effects)
             .map(|(effect_type, effects)| {
                 SchedulerCommand::Send(
@@ -1181,6 +1305,10 @@ where
                 active_effects
             },
         };
+        worker
+            .denied_effects
+            .retain(|effect_id, _| worker_dependencies.contains(*effect_id));
+        worker.denied_effects.extend(forbidden_worker_effects);
         let reevaluate_action = {
             let reevaluate_action = update_worker_state(
                 worker,
@@ -1191,6 +1319,7 @@ where
                     },
                 },
                 &mut state.state_cache,
+                Some(metadata.offset),
                 self.metric_names,
             )
             .map(|action| SchedulerCommand::Send(self.main_pid, TAction::from(action)));
@@ -1225,6 +1354,7 @@ where
         let actions = effect_emit_action
             .into_iter()
             .chain(reevaluate_action)
+            .chain(migrated_effect_emit_action)
             .chain(effect_subscribe_actions)
             .chain(effect_unsubscribe_actions)
             .collect::<Vec<_>>();
@@ -1239,10 +1369,14 @@ where
         context: &mut impl HandlerContext,
     ) -> Option<SchedulerTransition<TAction, TTask>>
     where
-        TAction: Action + From<EvaluateUpdateAction<T>>,
+        TAction: Action + From<EvaluateUpdateAction<T>> + From<EffectSchemaDriftAction<T>>,
         TTask: TaskFactory<TAction, TTask> + From<EffectThrottleTaskFactory>,
     {
         let EffectEmitAction { effect_types } = action;
+        let drift_actions = match &self.schema_drift {
+            Some(options) => self.detect_effect_schema_drift(state, effect_types, options),
+            None => Vec::new(),
+        };
         let updates = if effect_types.is_empty() {
             Vec::default()
         } else {
@@ -1278,7 +1412,23 @@ where
                 .collect()
         };
         if updates.is_empty() {
-            return None;
+            return if drift_actions.is_empty() {
+                None
+            } else {
+                Some(SchedulerTransition::new(drift_actions))
+            };
+        }
+        if !self.state_token_aliases.is_empty() {
+            for (key, _) in updates.iter() {
+                if let Some(replacement) =
+                    self.state_token_aliases
+                        .migrate(key, &self.factory, &self.allocator)
+                {
+                    state
+                        .aliased_state_tokens
+                        .insert(replacement.id(), key.id());
+                }
+            }
         }
         let (immediate_updates, throttle_operation) = match self.throttle {
             None => (updates, None),
@@ -1352,7 +1502,7 @@ where
                 self.metric_names,
             )
         };
-        match (update_actions, throttle_operation) {
+        let actions = match (update_actions, throttle_operation) {
             (Some(update_actions), Some(throttle_operation)) => {
                 let mut combined_actions = update_actions;
                 combined_actions.push(throttle_operation);
@@ -1363,8 +1513,70 @@ where
                 Some(SchedulerTransition::new([throttle_operation]))
             }
             (None, None) => None,
+        };
+        if drift_actions.is_empty() {
+            actions
+        } else {
+            let drift_actions = SchedulerTransition::new(drift_actions);
+            Some(match actions {
+                Some(actions) => actions.append(drift_actions),
+                None => drift_actions,
+            })
         }
     }
+    fn detect_effect_schema_drift<TAction, TTask>(
+        &self,
+        state: &mut EvaluateHandlerState<T>,
+        effect_types: &[EffectUpdateBatch<T>],
+        options: &EffectSchemaDriftOptions,
+    ) -> Vec<SchedulerCommand<TAction, TTask>>
+    where
+        TAction: Action + From<EffectSchemaDriftAction<T>>,
+        TTask: TaskFactory<TAction, TTask>,
+    {
+        let existing_state = &state.state_cache.combined_state;
+        let effect_schemas = &mut state.effect_schemas;
+        effect_types
+            .iter()
+            .filter(|batch| !is_evaluate_effect_type(&batch.effect_type, &self.factory))
+            .flat_map(|batch| {
+                batch
+                    .updates
+                    .iter()
+                    .map(move |(key, value)| (&batch.effect_type, key, value))
+            })
+            // Signal results (e.g. errors and pending values) do not contribute to the learned result schema
+            .filter(|(_, _, value)| self.factory.match_signal_term(value).is_none())
+            .filter(|(_, key, value)| {
+                existing_state
+                    .get(&key.id())
+                    .map(|existing_value| value.id() != existing_value.id())
+                    .unwrap_or(true)
+            })
+            .filter_map(|(effect_type, key, value)| {
+                let shape = EffectResultShape::infer(value, options);
+                let drift = effect_schemas.observe(key.id(), shape);
+                if drift.is_empty() {
+                    return None;
+                }
+                let metric_labels = get_effect_type_metric_labels(effect_type, &self.factory);
+                counter!(
+                    self.metric_names.effect_schema_drift_count,
+                    1,
+                    &metric_labels
+                );
+                Some(SchedulerCommand::Send(
+                    self.main_pid,
+                    EffectSchemaDriftAction {
+                        effect_type: effect_type.clone(),
+                        effect: key.clone(),
+                        drift,
+                    }
+                    .into(),
+                ))
+            })
+            .collect()
+    }
     fn handle_effect_throttle_emit<TAction, TTask>(
         &self,
         state: &mut EvaluateHandlerState<T>,
@@ -1402,9 +1614,14 @@ fn update_worker_state<T: Expression>(
     worker: &mut WorkerState<T>,
     update_type: WorkerStateUpdateType,
     global_state: &mut GlobalStateCache<T>,
+    local_state_index: Option<MessageOffset>,
     metric_names: EvaluateHandlerMetricNames,
 ) -> Option<EvaluateUpdateAction<T>> {
-    let state_index = global_state.combined_state.state_index()?;
+    // Fall back to the provided local state index if the worker has locally-resolved state but no global state exists
+    let state_index = global_state
+        .combined_state
+        .state_index()
+        .or(local_state_index.filter(|_| !worker.denied_effects.is_empty()))?;
     let worker_dependencies = worker.dependencies()?;
     let state_updates = match update_type {
         WorkerStateUpdateType::FirstResult => {
@@ -1450,6 +1667,22 @@ fn update_worker_state<T: Expression>(
             updated_state_values.collect::<Vec<_>>()
         }
     };
+    // Insert locally-resolved values for any dependencies forbidden by the worker's sandbox policy
+    // (these are not present in the global state cache, so only values that differ from the worker's existing state
+    // will be sent)
+    let denied_state_values = worker.latest_result().map(|result| {
+        let dependencies = result.dependencies();
+        worker
+            .denied_effects
+            .iter()
+            .filter(|(effect_id, _)| dependencies.contains(**effect_id))
+            .map(|(_, (key, value))| (key.clone(), value.clone()))
+            .collect::<Vec<_>>()
+    });
+    let state_updates = state_updates
+        .into_iter()
+        .chain(denied_state_values.into_iter().flatten())
+        .collect::<Vec<_>>();
     let updates = worker
         .update_state_cache(state_index, state_updates)
         .collect::<Vec<_>>();
@@ -1533,3 +1766,492 @@ fn is_unresolved_effect<T: Expression<Signal = V>, V: ConditionType<T>>(effect:
         SignalType::Pending | SignalType::Custom { .. } => true,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use reflex::sandbox::SandboxPolicy;
+    use reflex_lang::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
+    use reflex_macros::{task_factory_enum, Matcher};
+    use reflex_stdlib::Stdlib;
+
+    use crate::{
+        action::RuntimeActions, task::RuntimeTaskAction, utils::effect_schema::EffectSchemaDrift,
+    };
+
+    use super::*;
+
+    type T = CachedSharedTerm<Stdlib>;
+    type TFactory = SharedTermFactory<Stdlib>;
+    type TAllocator = DefaultAllocator<T>;
+    type TAction = RuntimeActions<T>;
+    type TTask = TestTaskFactory;
+
+    task_factory_enum!({
+        #[derive(Matcher, Clone)]
+        enum TestTaskFactory {
+            EvaluateHandler(EffectThrottleTaskFactory),
+        }
+        impl<TAction> TaskFactory<TAction, TestTaskFactory> for TestTaskFactory
+        where
+            TAction: Action + RuntimeTaskAction + Send + 'static,
+        {
+        }
+    });
+
+    #[test]
+    fn forbidden_effects() {
+        let factory = TFactory::default();
+        let allocator = TAllocator::default();
+        let main_pid = ProcessId::from(0);
+        let handler = EvaluateHandler::new(
+            factory,
+            allocator.clone(),
+            None,
+            None,
+            SandboxPolicies::default().with_root(
+                "foo",
+                SandboxPolicy::default().deny_effect_type("reflex::fetch"),
+            ),
+            StateTokenAliases::default(),
+            EvaluateHandlerMetricNames::default(),
+            main_pid,
+        );
+        let mut state = EvaluateHandlerState::default();
+        let permitted_effect = create_effect("reflex::timestamp", &factory, &allocator);
+        let forbidden_effect = create_effect("reflex::fetch", &factory, &allocator);
+        let result = EvaluationResult::new(
+            factory.create_signal_term(
+                allocator.create_signal_list([permitted_effect.clone(), forbidden_effect.clone()]),
+            ),
+            DependencyList::from_iter([permitted_effect.id(), forbidden_effect.id()]),
+        );
+
+        let error_value = create_error_expression(
+            factory.create_string_term(allocator.create_static_string(
+                "Effect type not permitted by sandbox policy: reflex::fetch",
+            )),
+            &factory,
+            &allocator,
+        );
+
+        // Forbidden effects emitted by a restricted query root are resolved immediately with an error
+        let restricted_query = create_evaluate_effect(
+            String::from("foo"),
+            factory.create_nil_term(),
+            QueryEvaluationMode::Query,
+            QueryInvalidationStrategy::default(),
+            &factory,
+            &allocator,
+        );
+        let actions = evaluate_query(
+            &handler,
+            &mut state,
+            &restricted_query,
+            result.clone(),
+            &allocator,
+        );
+        let updates = get_update_actions(&actions);
+        let (subscribed_effects, emitted_effects) = get_effect_actions(actions, &factory);
+        assert_eq!(subscribed_effects, vec![permitted_effect.clone()]);
+        assert!(emitted_effects.is_empty());
+        assert_eq!(
+            updates,
+            vec![(
+                restricted_query.clone(),
+                vec![(forbidden_effect.clone(), error_value.clone())],
+            )],
+        );
+
+        // Unrestricted query roots subscribe to the same effects as normal
+        let unrestricted_query = create_evaluate_effect(
+            String::from("bar"),
+            factory.create_nil_term(),
+            QueryEvaluationMode::Query,
+            QueryInvalidationStrategy::default(),
+            &factory,
+            &allocator,
+        );
+        let actions = evaluate_query(
+            &handler,
+            &mut state,
+            &unrestricted_query,
+            result,
+            &allocator,
+        );
+        let updates = get_update_actions(&actions);
+        let (subscribed_effects, emitted_effects) = get_effect_actions(actions, &factory);
+        assert_eq!(subscribed_effects, vec![forbidden_effect]);
+        assert!(emitted_effects.is_empty());
+        assert!(updates.is_empty());
+    }
+
+    #[test]
+    fn forbidden_effects_shared_across_roots() {
+        let factory = TFactory::default();
+        let allocator = TAllocator::default();
+        let main_pid = ProcessId::from(0);
+        let handler = EvaluateHandler::new(
+            factory,
+            allocator.clone(),
+            None,
+            None,
+            SandboxPolicies::default().with_root(
+                "foo",
+                SandboxPolicy::default().deny_effect_type("reflex::fetch"),
+            ),
+            StateTokenAliases::default(),
+            EvaluateHandlerMetricNames::default(),
+            main_pid,
+        );
+        let mut state = EvaluateHandlerState::default();
+        let effect = create_effect("reflex::fetch", &factory, &allocator);
+        let result = EvaluationResult::new(
+            factory.create_signal_term(allocator.create_signal_list([effect.clone()])),
+            DependencyList::from_iter([effect.id()]),
+        );
+        let error_value = create_error_expression(
+            factory.create_string_term(allocator.create_static_string(
+                "Effect type not permitted by sandbox policy: reflex::fetch",
+            )),
+            &factory,
+            &allocator,
+        );
+        let restricted_query = create_evaluate_effect(
+            String::from("foo"),
+            factory.create_nil_term(),
+            QueryEvaluationMode::Query,
+            QueryInvalidationStrategy::default(),
+            &factory,
+            &allocator,
+        );
+        let unrestricted_query = create_evaluate_effect(
+            String::from("bar"),
+            factory.create_nil_term(),
+            QueryEvaluationMode::Query,
+            QueryInvalidationStrategy::default(),
+            &factory,
+            &allocator,
+        );
+
+        // The permitted root subscribes to the effect, whereas the restricted root receives a local error value
+        let actions = evaluate_query(
+            &handler,
+            &mut state,
+            &unrestricted_query,
+            result.clone(),
+            &allocator,
+        );
+        let (subscribed_effects, _) = get_effect_actions(actions, &factory);
+        assert_eq!(subscribed_effects, vec![effect.clone()]);
+        let actions = evaluate_query(
+            &handler,
+            &mut state,
+            &restricted_query,
+            result.clone(),
+            &allocator,
+        );
+        let updates = get_update_actions(&actions);
+        let (subscribed_effects, emitted_effects) = get_effect_actions(actions, &factory);
+        assert!(subscribed_effects.is_empty());
+        assert!(emitted_effects.is_empty());
+        assert_eq!(
+            updates,
+            vec![(
+                restricted_query.clone(),
+                vec![(effect.clone(), error_value.clone())],
+            )],
+        );
+
+        // Values emitted for the permitted root are only sent to the permitted root
+        let value = factory.create_int_term(3);
+        let actions = handler
+            .handle_effect_emit::<TAction, TTask>(
+                &mut state,
+                &EffectEmitAction {
+                    effect_types: vec![EffectUpdateBatch {
+                        effect_type: factory
+                            .create_string_term(allocator.create_static_string("reflex::fetch")),
+                        updates: vec![(effect.clone(), value.clone())],
+                    }],
+                },
+                &MessageData {
+                    offset: MessageOffset::from(1),
+                    ..message_data()
+                },
+                &mut DummyContext,
+            )
+            .into_iter()
+            .flatten()
+            .filter_map(|command| match command {
+                SchedulerCommand::Send(_, action) => Some(action),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            get_update_actions(&actions),
+            vec![(unrestricted_query.clone(), vec![(effect.clone(), value)])],
+        );
+
+        // Re-evaluating the restricted root retains the local error value without overwriting the global value
+        let actions = handler
+            .handle_evaluate_result::<TAction, TTask>(
+                &mut state,
+                &EvaluateResultAction {
+                    cache_key: restricted_query.clone(),
+                    state_index: None,
+                    result: EvaluationResult::new(
+                        error_value.clone(),
+                        DependencyList::from_iter([effect.id()]),
+                    ),
+                },
+                &message_data(),
+                &mut DummyContext,
+            )
+            .into_iter()
+            .flatten()
+            .filter_map(|command| match command {
+                SchedulerCommand::Send(_, action) => Some(action),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert!(get_update_actions(&actions).is_empty());
+        let (_, emitted_effects) = get_effect_actions(actions, &factory);
+        assert!(emitted_effects.is_empty());
+    }
+
+    #[test]
+    fn aliased_effects() {
+        let factory = TFactory::default();
+        let allocator = TAllocator::default();
+        let main_pid = ProcessId::from(0);
+        let handler = EvaluateHandler::new(
+            factory,
+            allocator.clone(),
+            None,
+            None,
+            SandboxPolicies::default(),
+            StateTokenAliases::default().with_effect_type("reflex::legacy", "reflex::timestamp"),
+            EvaluateHandlerMetricNames::default(),
+            main_pid,
+        );
+        let mut state = EvaluateHandlerState::default();
+        let legacy_effect = create_effect("reflex::legacy", &factory, &allocator);
+        let replacement_effect = create_effect("reflex::timestamp", &factory, &allocator);
+        let unrelated_effect = create_effect("reflex::fetch", &factory, &allocator);
+        handler.handle_effect_emit::<TAction, TTask>(
+            &mut state,
+            &EffectEmitAction {
+                effect_types: vec![EffectUpdateBatch {
+                    effect_type: factory
+                        .create_string_term(allocator.create_static_string("reflex::legacy")),
+                    updates: vec![(legacy_effect, factory.create_int_term(3))],
+                }],
+            },
+            &message_data(),
+            &mut DummyContext,
+        );
+
+        // Replacement effects inherit the cached value of the legacy effect they replace
+        let query = create_evaluate_effect(
+            String::from("foo"),
+            factory.create_nil_term(),
+            QueryEvaluationMode::Query,
+            QueryInvalidationStrategy::default(),
+            &factory,
+            &allocator,
+        );
+        let result = EvaluationResult::new(
+            factory.create_signal_term(
+                allocator
+                    .create_signal_list([replacement_effect.clone(), unrelated_effect.clone()]),
+            ),
+            DependencyList::from_iter([replacement_effect.id(), unrelated_effect.id()]),
+        );
+        let actions = evaluate_query(&handler, &mut state, &query, result, &allocator);
+        let (mut subscribed_effects, emitted_effects) = get_effect_actions(actions, &factory);
+        subscribed_effects.sort_by_key(|effect| effect.id());
+        let mut expected_effects = vec![replacement_effect.clone(), unrelated_effect];
+        expected_effects.sort_by_key(|effect| effect.id());
+        assert_eq!(subscribed_effects, expected_effects);
+        assert_eq!(
+            emitted_effects,
+            vec![(replacement_effect, factory.create_int_term(3))]
+        );
+    }
+
+    #[test]
+    fn effect_schema_drift() {
+        let factory = TFactory::default();
+        let allocator = TAllocator::default();
+        let main_pid = ProcessId::from(0);
+        let handler = EvaluateHandler::new(
+            factory,
+            allocator.clone(),
+            None,
+            Some(EffectSchemaDriftOptions::default()),
+            SandboxPolicies::default(),
+            StateTokenAliases::default(),
+            EvaluateHandlerMetricNames::default(),
+            main_pid,
+        );
+        let mut state = EvaluateHandlerState::default();
+        let effect = create_effect("reflex::fetch", &factory, &allocator);
+        let create_record = |key: &'static str, value: T| {
+            factory.create_record_term(
+                allocator.create_struct_prototype(allocator.create_list([
+                    factory.create_string_term(allocator.create_static_string(key)),
+                ])),
+                allocator.create_unsized_list([value]),
+            )
+        };
+        let mut emit = |value: T| -> Vec<TAction> {
+            handler
+                .handle_effect_emit::<TAction, TTask>(
+                    &mut state,
+                    &EffectEmitAction {
+                        effect_types: vec![EffectUpdateBatch {
+                            effect_type: factory.create_string_term(
+                                allocator.create_static_string("reflex::fetch"),
+                            ),
+                            updates: vec![(effect.clone(), value)],
+                        }],
+                    },
+                    &message_data(),
+                    &mut DummyContext,
+                )
+                .into_iter()
+                .flatten()
+                .filter_map(|command| match command {
+                    SchedulerCommand::Send(_, action) => Some(action),
+                    _ => None,
+                })
+                .filter(|action| Option::<&EffectSchemaDriftAction<T>>::from(action).is_some())
+                .collect()
+        };
+
+        // The first result establishes the learned schema
+        assert!(emit(create_record("foo", factory.create_int_term(3))).is_empty());
+        // Results that match the learned schema are not reported
+        assert!(emit(create_record("foo", factory.create_int_term(4))).is_empty());
+        // Results that diverge from the learned schema are reported
+        let actions = emit(create_record("bar", factory.create_int_term(4)));
+        assert_eq!(actions.len(), 1);
+        let action = Option::<&EffectSchemaDriftAction<T>>::from(&actions[0]).unwrap();
+        assert_eq!(action.effect, effect);
+        assert_eq!(
+            action.drift,
+            vec![EffectSchemaDrift::FieldRemoved {
+                path: String::from("$.foo"),
+            }],
+        );
+        // Signal results do not affect the learned schema
+        assert!(emit(create_error_expression(
+            factory.create_nil_term(),
+            &factory,
+            &allocator
+        ))
+        .is_empty());
+    }
+
+    fn evaluate_query(
+        handler: &EvaluateHandler<T, TFactory, TAllocator>,
+        state: &mut EvaluateHandlerState<T>,
+        query: &<T as Expression>::Signal,
+        result: EvaluationResult<T>,
+        allocator: &TAllocator,
+    ) -> Vec<TAction> {
+        let subscribe_actions = handler.handle_effect_subscribe::<TAction, TTask>(
+            state,
+            &EffectSubscribeAction {
+                effect_type: create_evaluate_effect_type(&handler.factory, allocator),
+                effects: vec![query.clone()],
+            },
+            &message_data(),
+            &mut DummyContext,
+        );
+        let result_actions = handler.handle_evaluate_result::<TAction, TTask>(
+            state,
+            &EvaluateResultAction {
+                cache_key: query.clone(),
+                state_index: None,
+                result,
+            },
+            &message_data(),
+            &mut DummyContext,
+        );
+        subscribe_actions
+            .into_iter()
+            .chain(result_actions)
+            .flatten()
+            .filter_map(|command| match command {
+                SchedulerCommand::Send(_, action) => Some(action),
+                _ => None,
+            })
+            .collect()
+    }
+
+    type EffectActions = (
+        Vec<<T as Expression>::Signal>,
+        Vec<(<T as Expression>::Signal, T)>,
+    );
+
+    type EffectUpdates = (
+        <T as Expression>::Signal,
+        Vec<(<T as Expression>::Signal, T)>,
+    );
+
+    fn get_effect_actions(actions: Vec<TAction>, factory: &TFactory) -> EffectActions {
+        let subscribed_effects = actions
+            .iter()
+            .filter_map(Option::<&EffectSubscribeAction<T>>::from)
+            .flat_map(|action| action.effects.iter().cloned())
+            .collect();
+        let emitted_effects = actions
+            .iter()
+            .filter_map(Option::<&EffectEmitAction<T>>::from)
+            .flat_map(|action| action.effect_types.iter())
+            .filter(|batch| !is_evaluate_effect_type(&batch.effect_type, factory))
+            .flat_map(|batch| batch.updates.iter().cloned())
+            .collect();
+        (subscribed_effects, emitted_effects)
+    }
+
+    fn get_update_actions(actions: &[TAction]) -> Vec<EffectUpdates> {
+        actions
+            .iter()
+            .filter_map(Option::<&EvaluateUpdateAction<T>>::from)
+            .map(|action| (action.cache_key.clone(), action.state_updates.clone()))
+            .collect()
+    }
+
+    fn create_effect(
+        effect_type: &'static str,
+        factory: &TFactory,
+        allocator: &TAllocator,
+    ) -> <T as Expression>::Signal {
+        allocator.create_signal(SignalType::Custom {
+            effect_type: factory.create_string_term(allocator.create_static_string(effect_type)),
+            payload: factory.create_nil_term(),
+            token: factory.create_nil_term(),
+        })
+    }
+
+    fn message_data() -> MessageData {
+        MessageData {
+            offset: MessageOffset::from(0),
+            parent: None,
+            timestamp: Instant::now(),
+        }
+    }
+
+    struct DummyContext;
+    impl HandlerContext for DummyContext {
+        fn pid(&self) -> ProcessId {
+            ProcessId::from(0)
+        }
+
+        fn generate_pid(&mut self) -> ProcessId {
+            ProcessId::from(0)
+        }
+    }
+}
@@ -8,13 +8,19 @@ use actor::{
     evaluate_handler::EvaluateHandler, query_manager::QueryManager, RuntimeActor,
     RuntimeMetricNames,
 };
-use reflex::core::{BooleanTermType, Expression, ExpressionFactory, HeapAllocator};
+use migration::StateTokenAliases;
+use reflex::{
+    core::{BooleanTermType, Expression, ExpressionFactory, HeapAllocator},
+    sandbox::SandboxPolicies,
+};
 use reflex_dispatcher::ProcessId;
 use reflex_macros::blanket_trait;
 use serde::{Deserialize, Serialize};
+use utils::effect_schema::EffectSchemaDriftOptions;
 
 pub mod action;
 pub mod actor;
+pub mod migration;
 pub mod task;
 pub mod utils;
 
@@ -97,6 +103,9 @@ pub fn runtime_actors<T, TFactory, TAllocator>(
     factory: TFactory,
     allocator: TAllocator,
     effect_throttle: Option<Duration>,
+    effect_schema_drift: Option<EffectSchemaDriftOptions>,
+    sandbox_policies: SandboxPolicies,
+    state_token_aliases: StateTokenAliases<T>,
     metric_names: RuntimeMetricNames,
     main_pid: ProcessId,
 ) -> impl IntoIterator<Item = RuntimeActor<T, TFactory, TAllocator>>
@@ -116,6 +125,9 @@ where
             factory,
             allocator,
             effect_throttle,
+            effect_schema_drift,
+            sandbox_policies,
+            state_token_aliases,
             metric_names.evaluate_handler,
             main_pid,
         )),
@@ -0,0 +1,107 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{ops::Deref, sync::Arc};
+
+use reflex::core::{
+    ConditionType, Expression, ExpressionFactory, HeapAllocator, RefType, SignalType,
+    StringTermType, StringValue,
+};
+
+/// Transform a legacy effect payload into the equivalent payload for its replacement effect (returning `None` if the
+/// legacy effect has no equivalent replacement)
+pub type EffectPayloadTransform<T> = Arc<dyn Fn(&T) -> Option<T> + Send + Sync>;
+
+/// Set of aliases that map legacy effect identities onto their replacements
+///
+/// Renaming an effect type or changing the shape of its payload changes the state token of every affected effect,
+/// which would otherwise discard any cached values for those effects. While aliases are registered, a query that
+/// subscribes to a replacement effect is seeded with the latest cached value of the equivalent legacy effect (if one
+/// exists), allowing existing state to be carried over for the duration of a deployment window.
+#[derive(Clone)]
+pub struct StateTokenAliases<T: Expression> {
+    migrations: Vec<EffectMigration<T>>,
+}
+impl<T: Expression> Default for StateTokenAliases<T> {
+    fn default() -> Self {
+        Self {
+            migrations: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct EffectMigration<T: Expression> {
+    source_effect_type: String,
+    target_effect_type: String,
+    transform_payload: Option<EffectPayloadTransform<T>>,
+}
+
+impl<T: Expression> StateTokenAliases<T> {
+    /// Alias all effects of the given legacy effect type to effects of the replacement type with identical payloads
+    pub fn with_effect_type(
+        mut self,
+        source_effect_type: impl Into<String>,
+        target_effect_type: impl Into<String>,
+    ) -> Self {
+        self.migrations.push(EffectMigration {
+            source_effect_type: source_effect_type.into(),
+            target_effect_type: target_effect_type.into(),
+            transform_payload: None,
+        });
+        self
+    }
+    /// Alias all effects of the given legacy effect type to effects of the replacement type, transforming the legacy
+    /// effect payloads into their replacement equivalents
+    pub fn with_migration(
+        mut self,
+        source_effect_type: impl Into<String>,
+        target_effect_type: impl Into<String>,
+        transform_payload: impl Fn(&T) -> Option<T> + Send + Sync + 'static,
+    ) -> Self {
+        self.migrations.push(EffectMigration {
+            source_effect_type: source_effect_type.into(),
+            target_effect_type: target_effect_type.into(),
+            transform_payload: Some(Arc::new(transform_payload)),
+        });
+        self
+    }
+    pub fn is_empty(&self) -> bool {
+        self.migrations.is_empty()
+    }
+    /// Determine the replacement effect for the given legacy effect, if one exists
+    pub fn migrate(
+        &self,
+        effect: &T::Signal,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+    ) -> Option<T::Signal> {
+        let (effect_type, payload, token) = match effect.signal_type() {
+            SignalType::Custom {
+                effect_type,
+                payload,
+                token,
+            } => Some((effect_type, payload, token)),
+            _ => None,
+        }?;
+        let effect_type = factory.match_string_term(&effect_type)?;
+        let effect_type = effect_type.value();
+        let effect_type = effect_type.as_deref().as_str();
+        let migration = self
+            .migrations
+            .iter()
+            .find(|migration| migration.source_effect_type.as_str() == effect_type.deref())?;
+        let payload = match &migration.transform_payload {
+            Some(transform) => transform(&payload)?,
+            None => payload,
+        };
+        Some(allocator.create_signal(SignalType::Custom {
+            effect_type:
+                factory.create_string_term(
+                    allocator.create_string(migration.target_effect_type.clone()),
+                ),
+            payload,
+            token,
+        }))
+    }
+}
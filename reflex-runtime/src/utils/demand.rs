@@ -0,0 +1,402 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{
+    transform_expression_list, ApplicationTermType, Builtin, BuiltinTermType, Expression,
+    ExpressionFactory, ExpressionListType, HeapAllocator, LambdaTermType, LetTermType,
+    ListTermType, RecordTermType, RefType, VariableTermType,
+};
+
+/// Eliminate any conditional branches within the provided query that are statically unreachable
+///
+/// Builtin applications whose arguments can be determined without evaluation (either literal values, variables bound
+/// to literal values, or the results of statically-applied builtins) are applied via [`Builtin::apply_static`]. This
+/// allows conditional expressions whose condition is statically known (e.g. a boolean literal, the negation of a
+/// boolean literal, or the result of matching a literal string against a literal regular expression) to be replaced
+/// with the body of the selected branch, discarding the unselected branch. This ensures that any effects declared
+/// within unreachable branches are never visible to the evaluation of the query, and therefore cannot cause upstream
+/// subscriptions.
+///
+/// Returns `None` if the query contains no statically-unreachable branches.
+pub fn prune_unreachable_branches<T: Expression>(
+    expression: &T,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> Option<T> {
+    prune_expression(expression, &mut Vec::new(), factory, allocator)
+}
+
+/// Statically-known values of the variables currently in scope (innermost binding last)
+type DemandScope<T> = Vec<Option<T>>;
+
+fn prune_expression<T: Expression>(
+    expression: &T,
+    scope: &mut DemandScope<T>,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> Option<T> {
+    if let Some(term) = factory.match_application_term(expression) {
+        let target = term.target();
+        let target = target.as_deref();
+        let args = term.args();
+        let args = args.as_deref();
+        let pruned_args = transform_expression_list(args, allocator, |arg| {
+            prune_expression(arg, scope, factory, allocator)
+        });
+        if let Some(builtin) = factory.match_builtin_term(target) {
+            let static_args = pruned_args
+                .as_ref()
+                .unwrap_or(args)
+                .iter()
+                .map(|arg| {
+                    let arg = arg.as_deref();
+                    get_static_value(arg, scope, factory, allocator).unwrap_or_else(|| arg.clone())
+                })
+                .collect::<Vec<_>>();
+            let selected_branch = builtin
+                .target()
+                .apply_static(&static_args, factory, allocator)
+                .and_then(|result| get_selected_branch(&result, &static_args, factory));
+            if let Some(branch) = selected_branch {
+                return Some(
+                    prune_expression(&branch, scope, factory, allocator).unwrap_or(branch),
+                );
+            }
+        }
+        let pruned_target = prune_expression(target, scope, factory, allocator);
+        if pruned_target.is_none() && pruned_args.is_none() {
+            return None;
+        }
+        Some(factory.create_application_term(
+            pruned_target.unwrap_or_else(|| target.clone()),
+            pruned_args.unwrap_or_else(|| args.clone()),
+        ))
+    } else if let Some(term) = factory.match_let_term(expression) {
+        let initializer = term.initializer();
+        let initializer = initializer.as_deref();
+        let body = term.body();
+        let body = body.as_deref();
+        let pruned_initializer = prune_expression(initializer, scope, factory, allocator);
+        let value = get_static_value(
+            pruned_initializer.as_ref().unwrap_or(initializer),
+            scope,
+            factory,
+            allocator,
+        );
+        scope.push(value);
+        let pruned_body = prune_expression(body, scope, factory, allocator);
+        scope.pop();
+        if pruned_initializer.is_none() && pruned_body.is_none() {
+            return None;
+        }
+        Some(factory.create_let_term(
+            pruned_initializer.unwrap_or_else(|| initializer.clone()),
+            pruned_body.unwrap_or_else(|| body.clone()),
+        ))
+    } else if let Some(term) = factory.match_lambda_term(expression) {
+        let num_args = term.num_args();
+        scope.extend((0..num_args).map(|_| None));
+        let pruned_body = prune_expression(term.body().as_deref(), scope, factory, allocator);
+        scope.truncate(scope.len() - num_args);
+        pruned_body.map(|body| factory.create_lambda_term(num_args, body))
+    } else if let Some(term) = factory.match_list_term(expression) {
+        transform_expression_list(term.items().as_deref(), allocator, |item| {
+            prune_expression(item, scope, factory, allocator)
+        })
+        .map(|items| factory.create_list_term(items))
+    } else if let Some(term) = factory.match_record_term(expression) {
+        transform_expression_list(term.values().as_deref(), allocator, |value| {
+            prune_expression(value, scope, factory, allocator)
+        })
+        .map(|values| {
+            factory.create_record_term(allocator.clone_struct_prototype(term.prototype()), values)
+        })
+    } else {
+        None
+    }
+}
+
+/// Determine the value of the given expression without evaluation, if it is statically known
+fn get_static_value<T: Expression>(
+    expression: &T,
+    scope: &DemandScope<T>,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> Option<T> {
+    if is_literal_value(expression, factory) {
+        Some(expression.clone())
+    } else if let Some(term) = factory.match_variable_term(expression) {
+        // Variables bound outside the query (e.g. within compiled graph roots) are unknown
+        let offset = term.offset();
+        if offset < scope.len() {
+            scope[scope.len() - 1 - offset].clone()
+        } else {
+            None
+        }
+    } else if let Some(term) = factory.match_application_term(expression) {
+        let builtin = factory.match_builtin_term(term.target().as_deref())?;
+        let args = term
+            .args()
+            .as_deref()
+            .iter()
+            .map(|arg| get_static_value(arg.as_deref(), scope, factory, allocator))
+            .collect::<Option<Vec<_>>>()?;
+        builtin
+            .target()
+            .apply_static(&args, factory, allocator)
+            .filter(|result| is_literal_value(result, factory))
+    } else {
+        None
+    }
+}
+
+fn is_literal_value<T: Expression>(expression: &T, factory: &impl ExpressionFactory<T>) -> bool {
+    if let Some(term) = factory.match_list_term(expression) {
+        term.items()
+            .as_deref()
+            .iter()
+            .all(|item| is_literal_value(item.as_deref(), factory))
+    } else {
+        factory.match_nil_term(expression).is_some()
+            || factory.match_boolean_term(expression).is_some()
+            || factory.match_int_term(expression).is_some()
+            || factory.match_float_term(expression).is_some()
+            || factory.match_string_term(expression).is_some()
+            || factory.match_symbol_term(expression).is_some()
+            || factory.match_regex_term(expression).is_some()
+    }
+}
+
+/// Conditional builtins return the selected branch as a thunk application, so determine whether the result of a
+/// statically-applied builtin corresponds to one of its branch arguments (returning the body of the branch if so)
+fn get_selected_branch<T: Expression>(
+    result: &T,
+    args: &[T],
+    factory: &impl ExpressionFactory<T>,
+) -> Option<T> {
+    let term = factory.match_application_term(result)?;
+    let target = term.target();
+    let target = target.as_deref();
+    if term.args().as_deref().len() > 0 || !args.iter().any(|arg| arg.id() == target.id()) {
+        return None;
+    }
+    Some(
+        factory
+            .match_lambda_term(target)
+            .filter(|term| term.num_args() == 0)
+            .map(|term| term.body().as_deref().clone())
+            .unwrap_or_else(|| result.clone()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use reflex::core::{create_record, ExpressionFactory, HeapAllocator};
+    use reflex_lang::{allocator::DefaultAllocator, SharedTermFactory};
+    use reflex_stdlib::Stdlib;
+
+    use super::*;
+
+    #[test]
+    fn prune_static_conditions() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let create_conditional = |condition, consequent, alternate| {
+            factory.create_application_term(
+                factory.create_builtin_term(Stdlib::If),
+                allocator.create_triple(
+                    condition,
+                    factory.create_lambda_term(0, consequent),
+                    factory.create_lambda_term(0, alternate),
+                ),
+            )
+        };
+        let consequent = factory.create_string_term(allocator.create_static_string("foo"));
+        let alternate = factory.create_string_term(allocator.create_static_string("bar"));
+        assert_eq!(
+            prune_unreachable_branches(
+                &create_conditional(
+                    factory.create_boolean_term(true),
+                    consequent.clone(),
+                    alternate.clone(),
+                ),
+                &factory,
+                &allocator,
+            ),
+            Some(consequent.clone()),
+        );
+        assert_eq!(
+            prune_unreachable_branches(
+                &create_conditional(
+                    factory.create_application_term(
+                        factory.create_builtin_term(Stdlib::Not),
+                        allocator.create_unit_list(factory.create_boolean_term(true)),
+                    ),
+                    consequent.clone(),
+                    alternate.clone(),
+                ),
+                &factory,
+                &allocator,
+            ),
+            Some(alternate.clone()),
+        );
+        assert_eq!(
+            prune_unreachable_branches(
+                &factory.create_let_term(
+                    factory.create_boolean_term(false),
+                    create_record(
+                        [(
+                            factory.create_string_term(allocator.create_static_string("value")),
+                            create_conditional(
+                                factory.create_variable_term(0),
+                                consequent.clone(),
+                                alternate.clone(),
+                            ),
+                        )],
+                        &factory,
+                        &allocator,
+                    ),
+                ),
+                &factory,
+                &allocator,
+            ),
+            Some(factory.create_let_term(
+                factory.create_boolean_term(false),
+                create_record(
+                    [(
+                        factory.create_string_term(allocator.create_static_string("value")),
+                        alternate.clone(),
+                    )],
+                    &factory,
+                    &allocator,
+                ),
+            )),
+        );
+    }
+
+    #[test]
+    fn prune_static_match_conditions() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let create_match = |target, pattern| {
+            factory.create_application_term(
+                factory.create_builtin_term(Stdlib::Match),
+                allocator.create_pair(
+                    target,
+                    factory.create_regex_term(allocator.create_static_string(pattern)),
+                ),
+            )
+        };
+        let create_is_unmatched = |target| {
+            factory.create_application_term(
+                factory.create_builtin_term(Stdlib::Eq),
+                allocator.create_pair(target, factory.create_nil_term()),
+            )
+        };
+        let create_conditional = |condition, consequent, alternate| {
+            factory.create_application_term(
+                factory.create_builtin_term(Stdlib::If),
+                allocator.create_triple(
+                    condition,
+                    factory.create_lambda_term(0, consequent),
+                    factory.create_lambda_term(0, alternate),
+                ),
+            )
+        };
+        let consequent = factory.create_string_term(allocator.create_static_string("foo"));
+        let alternate = factory.create_string_term(allocator.create_static_string("bar"));
+        assert_eq!(
+            prune_unreachable_branches(
+                &create_conditional(
+                    create_is_unmatched(create_match(
+                        factory.create_string_term(allocator.create_static_string("foo")),
+                        "^f",
+                    )),
+                    consequent.clone(),
+                    alternate.clone(),
+                ),
+                &factory,
+                &allocator,
+            ),
+            Some(alternate.clone()),
+        );
+        assert_eq!(
+            prune_unreachable_branches(
+                &factory.create_let_term(
+                    create_match(
+                        factory.create_string_term(allocator.create_static_string("bar")),
+                        "^f",
+                    ),
+                    create_conditional(
+                        create_is_unmatched(factory.create_variable_term(0)),
+                        consequent.clone(),
+                        alternate.clone(),
+                    ),
+                ),
+                &factory,
+                &allocator,
+            ),
+            Some(factory.create_let_term(
+                create_match(
+                    factory.create_string_term(allocator.create_static_string("bar")),
+                    "^f",
+                ),
+                consequent.clone(),
+            )),
+        );
+        let expression = factory.create_lambda_term(
+            1,
+            create_conditional(
+                create_is_unmatched(create_match(factory.create_variable_term(0), "^f")),
+                consequent.clone(),
+                alternate.clone(),
+            ),
+        );
+        assert_eq!(
+            prune_unreachable_branches(&expression, &factory, &allocator),
+            None,
+        );
+    }
+
+    #[test]
+    fn preserve_dynamic_conditions() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let expression = factory.create_lambda_term(
+            1,
+            factory.create_application_term(
+                factory.create_builtin_term(Stdlib::If),
+                allocator.create_triple(
+                    factory.create_variable_term(0),
+                    factory.create_lambda_term(
+                        0,
+                        factory.create_string_term(allocator.create_static_string("foo")),
+                    ),
+                    factory.create_lambda_term(
+                        0,
+                        factory.create_string_term(allocator.create_static_string("bar")),
+                    ),
+                ),
+            ),
+        );
+        assert_eq!(
+            prune_unreachable_branches(&expression, &factory, &allocator),
+            None,
+        );
+        assert_eq!(
+            prune_unreachable_branches(
+                &factory.create_application_term(
+                    factory.create_builtin_term(Stdlib::If),
+                    allocator.create_triple(
+                        factory.create_variable_term(0),
+                        factory.create_nil_term(),
+                        factory.create_nil_term(),
+                    ),
+                ),
+                &factory,
+                &allocator,
+            ),
+            None,
+        );
+    }
+}
@@ -0,0 +1,434 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::collections::{hash_map::Entry, BTreeMap};
+
+use reflex::{
+    core::{Expression, StateToken},
+    hash::IntMap,
+};
+use reflex_json::JsonValue;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for detecting changes in the shape of effect results over time
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct EffectSchemaDriftOptions {
+    /// Maximum nesting depth to which result shapes are inferred (more deeply nested values are treated as opaque)
+    pub max_depth: usize,
+    /// Maximum number of items to sample when inferring the item shape of a list
+    pub max_list_items: usize,
+}
+impl Default for EffectSchemaDriftOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: 8,
+            max_list_items: 32,
+        }
+    }
+}
+
+/// Structural shape of an effect result, as inferred from its JSON representation
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EffectResultShape {
+    Nil,
+    Boolean,
+    Number,
+    String,
+    List {
+        /// Combined shape of the list items (`None` if only empty lists have been observed)
+        items: Option<Box<EffectResultShape>>,
+    },
+    Record {
+        fields: BTreeMap<String, EffectResultShape>,
+    },
+    /// Value that has been observed to be either nil or absent, as well as the inner shape
+    Optional {
+        inner: Box<EffectResultShape>,
+    },
+    /// Value that has been observed with multiple incompatible shapes
+    Union {
+        variants: Vec<EffectResultShape>,
+    },
+    /// Value that cannot be represented as JSON, or that exceeds the maximum inference depth
+    Opaque,
+}
+impl EffectResultShape {
+    pub fn infer<T: Expression>(value: &T, options: &EffectSchemaDriftOptions) -> Self {
+        match reflex_json::sanitize(value) {
+            Ok(value) => Self::from_json(&value, options),
+            Err(_) => Self::Opaque,
+        }
+    }
+    pub fn from_json(value: &JsonValue, options: &EffectSchemaDriftOptions) -> Self {
+        Self::from_json_with_depth(value, options, options.max_depth)
+    }
+    fn from_json_with_depth(
+        value: &JsonValue,
+        options: &EffectSchemaDriftOptions,
+        depth: usize,
+    ) -> Self {
+        match value {
+            JsonValue::Null => Self::Nil,
+            JsonValue::Bool(_) => Self::Boolean,
+            JsonValue::Number(_) => Self::Number,
+            JsonValue::String(_) => Self::String,
+            _ if depth == 0 => Self::Opaque,
+            JsonValue::Array(items) => Self::List {
+                items: items
+                    .iter()
+                    .take(options.max_list_items)
+                    .map(|item| Self::from_json_with_depth(item, options, depth - 1))
+                    .reduce(|combined, item| combined.merge(item))
+                    .map(Box::new),
+            },
+            JsonValue::Object(fields) => Self::Record {
+                fields: fields
+                    .iter()
+                    .map(|(key, value)| {
+                        (
+                            key.clone(),
+                            Self::from_json_with_depth(value, options, depth - 1),
+                        )
+                    })
+                    .collect(),
+            },
+        }
+    }
+    /// Combine two shapes into the narrowest shape that accepts both
+    pub fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Nil, Self::Nil) => Self::Nil,
+            (Self::Nil, other) | (other, Self::Nil) => other.into_optional(),
+            (Self::Optional { inner }, other) | (other, Self::Optional { inner }) => {
+                inner.merge(other).into_optional()
+            }
+            (Self::Union { variants }, other) | (other, Self::Union { variants }) => other
+                .into_variants()
+                .fold(Self::Union { variants }, |combined, variant| {
+                    combined.merge_variant(variant)
+                }),
+            (Self::List { items: left }, Self::List { items: right }) => Self::List {
+                items: match (left, right) {
+                    (Some(left), Some(right)) => Some(Box::new(left.merge(*right))),
+                    (left, right) => left.or(right),
+                },
+            },
+            (Self::Record { fields: left }, Self::Record { fields: mut right }) => {
+                let mut fields = left
+                    .into_iter()
+                    .map(|(key, left)| {
+                        let merged = match right.remove(&key) {
+                            Some(right) => left.merge(right),
+                            None => left.into_optional(),
+                        };
+                        (key, merged)
+                    })
+                    .collect::<BTreeMap<_, _>>();
+                fields.extend(
+                    right
+                        .into_iter()
+                        .map(|(key, right)| (key, right.into_optional())),
+                );
+                Self::Record { fields }
+            }
+            (left, right) if left.is_same_kind(&right) => left,
+            (left, right) => Self::Union {
+                variants: vec![left, right],
+            },
+        }
+    }
+    /// Determine how the given observed shape diverges from this learned shape
+    ///
+    /// Additional fields and values that were previously nil are not considered divergent, as these cannot cause
+    /// existing field accesses to fail.
+    pub fn diff(&self, observed: &Self) -> Vec<EffectSchemaDrift> {
+        let mut results = Vec::new();
+        self.diff_at_path(observed, "$", &mut results);
+        results
+    }
+    fn diff_at_path(&self, observed: &Self, path: &str, results: &mut Vec<EffectSchemaDrift>) {
+        match (self, observed) {
+            (Self::Nil, _) => {}
+            (Self::Optional { .. }, Self::Nil) => {}
+            (Self::Optional { inner }, observed) => inner.diff_at_path(observed, path, results),
+            (learned, Self::Optional { inner }) => learned.diff_at_path(inner, path, results),
+            (learned, Self::Union { variants }) => {
+                for variant in variants {
+                    learned.diff_at_path(variant, path, results);
+                }
+            }
+            (Self::Union { variants }, observed) => {
+                match variants
+                    .iter()
+                    .find(|variant| variant.is_same_kind(observed))
+                {
+                    Some(variant) => variant.diff_at_path(observed, path, results),
+                    None => results.push(EffectSchemaDrift::TypeChanged {
+                        path: String::from(path),
+                        expected: self.to_string(),
+                        received: observed.to_string(),
+                    }),
+                }
+            }
+            (Self::List { items: Some(left) }, Self::List { items: Some(right) }) => {
+                left.diff_at_path(right, &format!("{}[]", path), results)
+            }
+            (Self::Record { fields: left }, Self::Record { fields: right }) => {
+                for (key, learned) in left.iter() {
+                    let field_path = format!("{}.{}", path, key);
+                    match right.get(key) {
+                        Some(observed) => learned.diff_at_path(observed, &field_path, results),
+                        None => {
+                            if !matches!(learned, Self::Optional { .. }) {
+                                results.push(EffectSchemaDrift::FieldRemoved { path: field_path })
+                            }
+                        }
+                    }
+                }
+            }
+            (learned, observed) if learned.is_same_kind(observed) => {}
+            (learned, observed) => results.push(EffectSchemaDrift::TypeChanged {
+                path: String::from(path),
+                expected: learned.to_string(),
+                received: observed.to_string(),
+            }),
+        }
+    }
+    fn into_optional(self) -> Self {
+        match self {
+            Self::Nil | Self::Optional { .. } => self,
+            inner => Self::Optional {
+                inner: Box::new(inner),
+            },
+        }
+    }
+    fn into_variants(self) -> impl Iterator<Item = Self> {
+        match self {
+            Self::Union { variants } => variants,
+            other => vec![other],
+        }
+        .into_iter()
+    }
+    fn merge_variant(self, variant: Self) -> Self {
+        let mut variants = self.into_variants().collect::<Vec<_>>();
+        match variants
+            .iter()
+            .position(|existing| existing.is_same_kind(&variant))
+        {
+            Some(index) => {
+                let existing = variants.remove(index);
+                variants.insert(index, existing.merge(variant));
+            }
+            None => variants.push(variant),
+        }
+        Self::Union { variants }
+    }
+    fn is_same_kind(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+impl std::fmt::Display for EffectResultShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Nil => write!(f, "nil"),
+            Self::Boolean => write!(f, "boolean"),
+            Self::Number => write!(f, "number"),
+            Self::String => write!(f, "string"),
+            Self::List { items: None } => write!(f, "list"),
+            Self::List { items: Some(items) } => write!(f, "list<{}>", items),
+            Self::Record { .. } => write!(f, "record"),
+            Self::Optional { inner } => write!(f, "{}?", inner),
+            Self::Union { variants } => write!(
+                f,
+                "{}",
+                variants
+                    .iter()
+                    .map(|variant| variant.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            ),
+            Self::Opaque => write!(f, "opaque"),
+        }
+    }
+}
+
+/// Individual divergence between an observed effect result and the learned schema for that effect
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EffectSchemaDrift {
+    /// Field that was present in all previous results is missing from the latest result
+    FieldRemoved { path: String },
+    /// Value has a different type from the type observed in previous results
+    TypeChanged {
+        path: String,
+        expected: String,
+        received: String,
+    },
+}
+impl EffectSchemaDrift {
+    pub fn path(&self) -> &str {
+        match self {
+            Self::FieldRemoved { path } => path,
+            Self::TypeChanged { path, .. } => path,
+        }
+    }
+}
+impl std::fmt::Display for EffectSchemaDrift {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FieldRemoved { path } => write!(f, "Missing field {}", path),
+            Self::TypeChanged {
+                path,
+                expected,
+                received,
+            } => write!(
+                f,
+                "Type mismatch at {}: expected {}, received {}",
+                path, expected, received
+            ),
+        }
+    }
+}
+
+/// Learned result schemas for the set of active effects
+#[derive(Default, Clone, Debug)]
+pub struct EffectSchemaRegistry {
+    schemas: IntMap<StateToken, EffectResultShape>,
+}
+impl EffectSchemaRegistry {
+    /// Record the shape of the latest result for the given effect, returning any divergences from the learned schema
+    ///
+    /// The learned schema is widened to accept the new shape, ensuring that each divergence is only reported once.
+    pub fn observe(
+        &mut self,
+        state_token: StateToken,
+        shape: EffectResultShape,
+    ) -> Vec<EffectSchemaDrift> {
+        match self.schemas.entry(state_token) {
+            Entry::Vacant(entry) => {
+                entry.insert(shape);
+                Vec::new()
+            }
+            Entry::Occupied(mut entry) => {
+                let drift = entry.get().diff(&shape);
+                if entry.get() != &shape {
+                    let learned = std::mem::replace(entry.get_mut(), EffectResultShape::Nil);
+                    entry.insert(learned.merge(shape));
+                }
+                drift
+            }
+        }
+    }
+    pub fn get(&self, state_token: &StateToken) -> Option<&EffectResultShape> {
+        self.schemas.get(state_token)
+    }
+    pub fn retain(&mut self, mut predicate: impl FnMut(&StateToken) -> bool) {
+        self.schemas.retain(|state_token, _| predicate(state_token));
+    }
+    pub fn len(&self) -> usize {
+        self.schemas.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.schemas.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reflex_json::json;
+
+    use super::*;
+
+    fn infer(value: JsonValue) -> EffectResultShape {
+        EffectResultShape::from_json(&value, &EffectSchemaDriftOptions::default())
+    }
+
+    #[test]
+    fn infer_result_shapes() {
+        assert_eq!(
+            infer(json!({ "id": 3, "tags": ["a", null], "meta": null })),
+            EffectResultShape::Record {
+                fields: BTreeMap::from_iter([
+                    (String::from("id"), EffectResultShape::Number),
+                    (String::from("meta"), EffectResultShape::Nil),
+                    (
+                        String::from("tags"),
+                        EffectResultShape::List {
+                            items: Some(Box::new(EffectResultShape::Optional {
+                                inner: Box::new(EffectResultShape::String),
+                            })),
+                        },
+                    ),
+                ]),
+            },
+        );
+        assert_eq!(
+            EffectResultShape::from_json(
+                &json!([[1]]),
+                &EffectSchemaDriftOptions {
+                    max_depth: 1,
+                    ..Default::default()
+                },
+            ),
+            EffectResultShape::List {
+                items: Some(Box::new(EffectResultShape::Opaque)),
+            },
+        );
+    }
+
+    #[test]
+    fn detect_schema_drift() {
+        let mut registry = EffectSchemaRegistry::default();
+        let state_token: StateToken = 123;
+        assert_eq!(
+            registry.observe(
+                state_token,
+                infer(json!({ "user": { "name": "foo", "age": 3 }, "nickname": null })),
+            ),
+            Vec::new(),
+        );
+        assert_eq!(
+            registry.observe(
+                state_token,
+                infer(
+                    json!({ "user": { "name": "bar", "age": 4 }, "nickname": "baz", "extra": true })
+                ),
+            ),
+            Vec::new(),
+        );
+        assert_eq!(
+            registry.observe(
+                state_token,
+                infer(json!({ "user": { "fullName": "bar", "age": "4" }, "nickname": null })),
+            ),
+            vec![
+                EffectSchemaDrift::TypeChanged {
+                    path: String::from("$.user.age"),
+                    expected: String::from("number"),
+                    received: String::from("string"),
+                },
+                EffectSchemaDrift::FieldRemoved {
+                    path: String::from("$.user.name"),
+                },
+            ],
+        );
+        assert_eq!(
+            registry.observe(
+                state_token,
+                infer(json!({ "user": { "fullName": "bar", "age": "4" }, "nickname": null })),
+            ),
+            Vec::new(),
+        );
+        assert_eq!(
+            registry.observe(state_token, infer(json!(null))),
+            vec![EffectSchemaDrift::TypeChanged {
+                path: String::from("$"),
+                expected: String::from("record"),
+                received: String::from("nil"),
+            }],
+        );
+        registry.retain(|_| false);
+        assert!(registry.is_empty());
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 use std::{
-    collections::{hash_map::Entry, HashMap, VecDeque},
+    collections::{hash_map::Entry, HashMap, HashSet, VecDeque},
     iter::once,
     marker::PhantomData,
     ops::Deref,
@@ -346,6 +346,35 @@ where
     // Asynchronous event stream (used as input to an actor process)
     Task(TokioTask<TAction, TTask>),
 }
+impl<TAction, TTask> TokioProcess<TAction, TTask>
+where
+    TAction: Action,
+    TTask: TaskFactory<TAction, TTask>,
+{
+    fn scope(&self) -> &TokioProcessScope {
+        match self {
+            Self::Worker(instance) => &instance.scope,
+            Self::Task(instance) => &instance.scope,
+        }
+    }
+    fn scope_mut(&mut self) -> &mut TokioProcessScope {
+        match self {
+            Self::Worker(instance) => &mut instance.scope,
+            Self::Task(instance) => &mut instance.scope,
+        }
+    }
+}
+
+/// Ownership relationships between a process and the process that spawned it
+///
+/// Processes are owned by the process whose handler spawned them: when a process is killed, all processes spawned
+/// within its scope are killed along with it (recursively), ensuring that descendant tasks such as timers, sockets and
+/// retries do not outlive the process that requested them.
+#[derive(Default)]
+struct TokioProcessScope {
+    owner: Option<ProcessId>,
+    children: HashSet<ProcessId>,
+}
 
 struct TokioWorkerInstance<TAction, TTask>
 where
@@ -357,6 +386,7 @@ where
     actor: Arc<TTask::Actor>,
     handle: JoinHandle<()>,
     subscribers: HashMap<TokioSubscriberId, TokioSubscriber<TAction>>,
+    scope: TokioProcessScope,
 }
 impl<TAction, TTask> Drop for TokioWorkerInstance<TAction, TTask>
 where
@@ -378,6 +408,7 @@ where
     actor_pid: ProcessId,
     handle: JoinHandle<()>,
     dispose: Option<<TTask::Actor as Actor<TAction, TTask>>::Dispose>,
+    scope: TokioProcessScope,
 }
 impl<TAction, TTask> Drop for TokioTask<TAction, TTask>
 where
@@ -1045,6 +1076,7 @@ where
             tokio::spawn(instrumentation.instrument_task_thread(inbox_task))
         },
         dispose,
+        scope: Default::default(),
     };
     (task_process, actor_process)
 }
@@ -1222,6 +1254,7 @@ where
             tokio::spawn(instrumentation.instrument_worker_thread(task))
         },
         subscribers: Default::default(),
+        scope: Default::default(),
     }
 }
 
@@ -1685,12 +1718,13 @@ where
             TokioCommand::Spawn {
                 pid,
                 factory,
-                caller: _,
+                caller,
             } => {
-                let secondary_worker =
+                let owner_pid = caller.map(|(_, caller_pid)| caller_pid);
+                let (is_spawned, secondary_worker) =
                     if let Entry::Vacant(entry) = processes.write().expect("FIXME").entry(pid) {
                         let actor = factory.create();
-                        let (process, secondary_worker) = spawn_worker_process(
+                        let (mut process, secondary_worker) = spawn_worker_process(
                             actor,
                             pid,
                             64,
@@ -1702,14 +1736,21 @@ where
                             logger.clone(),
                             instrumentation.clone(),
                         );
+                        process.scope_mut().owner = owner_pid;
                         entry.insert(process);
-                        secondary_worker
+                        (true, secondary_worker)
                     } else {
-                        None
+                        (false, None)
                     };
                 if let Some((pid, process)) = secondary_worker {
                     processes.write().expect("FIXME").insert(pid, process);
                 }
+                // Register the spawned process within the scope of the process that spawned it
+                if let Some(owner_pid) = owner_pid.filter(|_| is_spawned) {
+                    if let Some(owner) = processes.write().expect("FIXME").get_mut(&owner_pid) {
+                        owner.scope_mut().children.insert(pid);
+                    }
+                }
             }
             TokioCommand::Send { pid, message } => {
                 let (send_task, subscriptions) =
@@ -1764,12 +1805,33 @@ where
                 }
             }
             TokioCommand::Kill { pid, caller: _ } => {
-                let mut removed_process = processes
+                let mut removed_processes = processes
                     .write()
                     .expect("FIXME")
                     .remove(&pid)
-                    .map(|process| (process, pid));
-                while let Some((process, pid)) = removed_process.take() {
+                    .map(|process| (process, pid))
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                while let Some((process, pid)) = removed_processes.pop() {
+                    {
+                        let mut processes = processes.write().expect("FIXME");
+                        // Detach the process from the scope of its owner
+                        if let Some(owner) = process
+                            .scope()
+                            .owner
+                            .and_then(|owner_pid| processes.get_mut(&owner_pid))
+                        {
+                            owner.scope_mut().children.remove(&pid);
+                        }
+                        // Cancel any descendant processes that were spawned within the scope of this process
+                        removed_processes.extend(process.scope().children.iter().filter_map(
+                            |child_pid| {
+                                processes
+                                    .remove(child_pid)
+                                    .map(|child_process| (child_process, *child_pid))
+                            },
+                        ));
+                    }
                     match process {
                         TokioProcess::Worker(instance) => {
                             let actor = &instance.actor;
@@ -1801,7 +1863,7 @@ where
                             if let Some(actor_process) =
                                 processes.write().expect("FIXME").remove(&actor_pid)
                             {
-                                removed_process.replace((actor_process, actor_pid));
+                                removed_processes.push((actor_process, actor_pid));
                             }
                         }
                     }
@@ -2120,3 +2182,234 @@ where
     ) {
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use futures::stream;
+
+    use super::*;
+
+    enum TestAction {}
+    impl Action for TestAction {}
+
+    enum TestTask {
+        Worker,
+        Task { disposals: Arc<AtomicUsize> },
+    }
+    impl TaskFactory<TestAction, TestTask> for TestTask {
+        type Actor = TestActor;
+        fn create(self) -> Self::Actor {
+            TestActor(self)
+        }
+    }
+
+    struct TestActor(TestTask);
+    impl Actor<TestAction, TestTask> for TestActor {
+        type Events<TInbox: TaskInbox<TestAction>> = stream::Pending<TInbox::Message>;
+        type Dispose = Pin<Box<dyn Future<Output = ()> + Send + Sync>>;
+        fn init(&self) -> Self::State {}
+        fn events<TInbox: TaskInbox<TestAction>>(
+            &self,
+            inbox: TInbox,
+        ) -> ActorEvents<TInbox, Self::Events<TInbox>, Self::Dispose> {
+            match &self.0 {
+                TestTask::Worker => ActorEvents::Sync(inbox),
+                TestTask::Task { disposals } => {
+                    let dispose: Self::Dispose = Box::pin({
+                        let disposals = Arc::clone(disposals);
+                        async move {
+                            increment_atomic_counter(&disposals);
+                        }
+                    });
+                    ActorEvents::Async(stream::pending(), Some(dispose))
+                }
+            }
+        }
+    }
+    impl Worker<TestAction, SchedulerTransition<TestAction, TestTask>> for TestActor {
+        fn accept(&self, _message: &TestAction) -> bool {
+            true
+        }
+        fn schedule(&self, _message: &TestAction, _state: &Self::State) -> Option<SchedulerMode> {
+            Some(SchedulerMode::Sync)
+        }
+    }
+    impl Handler<TestAction, SchedulerTransition<TestAction, TestTask>> for TestActor {
+        type State = ();
+        fn handle(
+            &self,
+            _state: &mut Self::State,
+            _message: &TestAction,
+            _metadata: &MessageData,
+            _context: &mut impl HandlerContext,
+        ) -> Option<SchedulerTransition<TestAction, TestTask>> {
+            None
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    struct NoopTokioSchedulerLogger;
+    impl TokioSchedulerLogger for NoopTokioSchedulerLogger {
+        type Action = TestAction;
+        type Task = TestTask;
+        fn log_scheduler_command(
+            &mut self,
+            _command: &TokioCommand<TestAction, TestTask>,
+            _enqueue_time: AsyncMessageTimestamp,
+        ) {
+        }
+        fn log_worker_message(
+            &mut self,
+            _message: &AsyncMessage<TestAction>,
+            _actor: &TestActor,
+            _pid: ProcessId,
+        ) {
+        }
+        fn log_task_message(&mut self, _message: &AsyncMessage<TestAction>, _pid: ProcessId) {}
+    }
+
+    type TestProcesses = Arc<RwLock<HashMap<ProcessId, TokioProcess<TestAction, TestTask>>>>;
+
+    async fn process_commands(
+        commands: impl IntoIterator<Item = TokioCommand<TestAction, TestTask>>,
+        next_pid: &Arc<AtomicUsize>,
+        processes: &TestProcesses,
+    ) {
+        let (async_tasks, _) = mpsc::channel(1);
+        let (blocking_tasks, _) = mpsc::channel(1);
+        let send_task = process_handler_results(
+            commands
+                .into_iter()
+                .map(|command| (command, AsyncMessageTimestamp::now()))
+                .collect(),
+            next_pid,
+            &Arc::new(AtomicUsize::new(0)),
+            processes,
+            &async_tasks,
+            &blocking_tasks,
+            None,
+            &mut NoopTokioSchedulerLogger,
+            &NoopTokioSchedulerInstrumentation::default(),
+        );
+        if let Some(task) = send_task {
+            task.await;
+        }
+    }
+
+    fn spawn_command(
+        pid: ProcessId,
+        factory: TestTask,
+        owner: Option<ProcessId>,
+    ) -> TokioCommand<TestAction, TestTask> {
+        TokioCommand::Spawn {
+            pid,
+            factory,
+            caller: owner.map(|owner| (MessageOffset::default(), owner)),
+        }
+    }
+
+    fn kill_command(pid: ProcessId) -> TokioCommand<TestAction, TestTask> {
+        TokioCommand::Kill { pid, caller: None }
+    }
+
+    fn get_scope_children(processes: &TestProcesses, pid: ProcessId) -> HashSet<ProcessId> {
+        processes
+            .read()
+            .unwrap()
+            .get(&pid)
+            .map(|process| process.scope().children.clone())
+            .unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn kill_cancels_descendant_processes() {
+        // Offset the generated task actor process IDs from the process IDs allocated by the test
+        let next_pid = Arc::new(AtomicUsize::new(100));
+        let processes = TestProcesses::default();
+        let disposals = Arc::new(AtomicUsize::new(0));
+        let [owner, child, sibling, task, nested_task, unrelated] =
+            [1, 2, 3, 4, 5, 6].map(ProcessId::from);
+
+        process_commands(
+            [
+                spawn_command(owner, TestTask::Worker, None),
+                spawn_command(unrelated, TestTask::Worker, None),
+                spawn_command(child, TestTask::Worker, Some(owner)),
+                spawn_command(sibling, TestTask::Worker, Some(owner)),
+                spawn_command(
+                    task,
+                    TestTask::Task {
+                        disposals: Arc::clone(&disposals),
+                    },
+                    Some(child),
+                ),
+            ],
+            &next_pid,
+            &processes,
+        )
+        .await;
+        // Processes spawned by a task handler are owned by the task actor process
+        let task_actor = match processes.read().unwrap().get(&task) {
+            Some(TokioProcess::Task(instance)) => instance.actor_pid,
+            _ => panic!("Expected task process"),
+        };
+        process_commands(
+            [spawn_command(
+                nested_task,
+                TestTask::Task {
+                    disposals: Arc::clone(&disposals),
+                },
+                Some(task_actor),
+            )],
+            &next_pid,
+            &processes,
+        )
+        .await;
+        let nested_task_actor = match processes.read().unwrap().get(&nested_task) {
+            Some(TokioProcess::Task(instance)) => instance.actor_pid,
+            _ => panic!("Expected task process"),
+        };
+        assert_eq!(
+            get_scope_children(&processes, owner),
+            HashSet::from([child, sibling])
+        );
+        assert_eq!(get_scope_children(&processes, child), HashSet::from([task]));
+        assert_eq!(
+            get_scope_children(&processes, task_actor),
+            HashSet::from([nested_task])
+        );
+
+        // Killing a process detaches it from the scope of its owner
+        process_commands([kill_command(sibling)], &next_pid, &processes).await;
+        assert!(!processes.read().unwrap().contains_key(&sibling));
+        assert_eq!(
+            get_scope_children(&processes, owner),
+            HashSet::from([child])
+        );
+
+        // Killing a process cancels all of its descendants, including the actors of any nested tasks
+        process_commands([kill_command(owner)], &next_pid, &processes).await;
+        for pid in [child, task, task_actor, nested_task, nested_task_actor] {
+            assert!(!processes.read().unwrap().contains_key(&pid));
+        }
+        assert_eq!(
+            processes
+                .read()
+                .unwrap()
+                .keys()
+                .copied()
+                .collect::<HashSet<_>>(),
+            HashSet::from([unrelated])
+        );
+        assert!(get_scope_children(&processes, unrelated).is_empty());
+        // The dispose callbacks of all cancelled tasks are invoked
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while disposals.load(Ordering::SeqCst) < 2 {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("Task dispose callbacks were not invoked");
+        assert_eq!(disposals.load(Ordering::SeqCst), 2);
+    }
+}
@@ -10,9 +10,11 @@ use reflex_handlers::action::{
         FetchHandlerActions, FetchHandlerConnectionErrorAction, FetchHandlerFetchCompleteAction,
     },
     graphql::*,
+    plugin::{PluginHandlerActions, PluginHandlerResponseAction},
     timeout::{TimeoutHandlerActions, TimeoutHandlerTimeoutAction},
     timestamp::{TimestampHandlerActions, TimestampHandlerUpdateAction},
 };
+use reflex_macros::ActionSchema;
 use reflex_runtime::action::{
     bytecode_interpreter::*, effect::*, evaluate::*, query::*, RuntimeActions,
 };
@@ -23,7 +25,7 @@ use crate::server::action::{
     telemetry::*, websocket_server::*,
 };
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(ActionSchema, Clone, Debug, Serialize, Deserialize)]
 pub enum ServerCliAction<T: Expression> {
     #[serde(bound(
         serialize = "<T as Expression>::Signal: Serialize",
@@ -40,6 +42,7 @@ pub enum ServerCliAction<T: Expression> {
     FetchHandler(FetchHandlerActions),
     GraphQlHandler(GraphQlHandlerActions),
     GrpcHandler(GrpcHandlerActions),
+    PluginHandler(PluginHandlerActions),
     TimeoutHandler(TimeoutHandlerActions),
     TimestampHandler(TimestampHandlerActions),
     Init(InitActions),
@@ -58,6 +61,7 @@ impl<T: Expression> Named for ServerCliAction<T> {
             Self::FetchHandler(action) => action.name(),
             Self::GraphQlHandler(action) => action.name(),
             Self::GrpcHandler(action) => action.name(),
+            Self::PluginHandler(action) => action.name(),
             Self::TimeoutHandler(action) => action.name(),
             Self::TimestampHandler(action) => action.name(),
             Self::Init(action) => action.name(),
@@ -79,6 +83,7 @@ impl<T: Expression> SerializableAction for ServerCliAction<T> {
             Self::FetchHandler(action) => action.to_json(),
             Self::GraphQlHandler(action) => action.to_json(),
             Self::GrpcHandler(action) => action.to_json(),
+            Self::PluginHandler(action) => action.to_json(),
             Self::TimeoutHandler(action) => action.to_json(),
             Self::TimestampHandler(action) => action.to_json(),
             Self::Init(action) => action.to_json(),
@@ -330,6 +335,28 @@ impl<'a, T: Expression> From<&'a ServerCliAction<T>> for Option<&'a GrpcHandlerA
     }
 }
 
+impl<T: Expression> From<PluginHandlerActions> for ServerCliAction<T> {
+    fn from(value: PluginHandlerActions) -> Self {
+        Self::PluginHandler(value)
+    }
+}
+impl<T: Expression> From<ServerCliAction<T>> for Option<PluginHandlerActions> {
+    fn from(value: ServerCliAction<T>) -> Self {
+        match value {
+            ServerCliAction::PluginHandler(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+impl<'a, T: Expression> From<&'a ServerCliAction<T>> for Option<&'a PluginHandlerActions> {
+    fn from(value: &'a ServerCliAction<T>) -> Self {
+        match value {
+            ServerCliAction::PluginHandler(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
 impl<T: Expression> From<TimeoutHandlerActions> for ServerCliAction<T> {
     fn from(value: TimeoutHandlerActions) -> Self {
         Self::TimeoutHandler(value)
@@ -476,6 +503,22 @@ impl<'a, T: Expression> From<&'a ServerCliAction<T>> for Option<&'a EffectThrott
     }
 }
 
+impl<T: Expression> From<EffectSchemaDriftAction<T>> for ServerCliAction<T> {
+    fn from(value: EffectSchemaDriftAction<T>) -> Self {
+        EffectActions::from(value).into()
+    }
+}
+impl<T: Expression> From<ServerCliAction<T>> for Option<EffectSchemaDriftAction<T>> {
+    fn from(value: ServerCliAction<T>) -> Self {
+        Option::<EffectActions<T>>::from(value).and_then(|value| value.into())
+    }
+}
+impl<'a, T: Expression> From<&'a ServerCliAction<T>> for Option<&'a EffectSchemaDriftAction<T>> {
+    fn from(value: &'a ServerCliAction<T>) -> Self {
+        Option::<&'a EffectActions<T>>::from(value).and_then(|value| value.into())
+    }
+}
+
 impl<T: Expression> From<EvaluateActions<T>> for ServerCliAction<T> {
     fn from(value: EvaluateActions<T>) -> Self {
         RuntimeActions::from(value).into()
@@ -1056,6 +1099,22 @@ impl<'a, T: Expression> From<&'a ServerCliAction<T>>
     }
 }
 
+impl<T: Expression> From<PluginHandlerResponseAction> for ServerCliAction<T> {
+    fn from(value: PluginHandlerResponseAction) -> Self {
+        PluginHandlerActions::from(value).into()
+    }
+}
+impl<T: Expression> From<ServerCliAction<T>> for Option<PluginHandlerResponseAction> {
+    fn from(value: ServerCliAction<T>) -> Self {
+        Option::<PluginHandlerActions>::from(value).and_then(|value| value.into())
+    }
+}
+impl<'a, T: Expression> From<&'a ServerCliAction<T>> for Option<&'a PluginHandlerResponseAction> {
+    fn from(value: &'a ServerCliAction<T>) -> Self {
+        Option::<&'a PluginHandlerActions>::from(value).and_then(|value| value.into())
+    }
+}
+
 impl<T: Expression> From<TimeoutHandlerTimeoutAction> for ServerCliAction<T> {
     fn from(value: TimeoutHandlerTimeoutAction) -> Self {
         TimeoutHandlerActions::from(value).into()
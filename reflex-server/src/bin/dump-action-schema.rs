@@ -0,0 +1,35 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{fs, path::PathBuf};
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use reflex_dispatcher::ActionSchema;
+use reflex_lang::CachedSharedTerm;
+use reflex_server::{action::ServerCliAction, builtins::ServerBuiltins};
+
+/// Dump the JSON schema describing all actions that can be captured within a server session recording
+#[derive(Parser)]
+struct Args {
+    /// Path to output JSON file (defaults to stdout)
+    #[clap(long)]
+    output: Option<PathBuf>,
+}
+
+pub fn main() -> Result<()> {
+    type TBuiltin = ServerBuiltins;
+    type T = CachedSharedTerm<TBuiltin>;
+    let args = Args::parse();
+    let schema = <ServerCliAction<T> as ActionSchema>::schema();
+    let output =
+        serde_json::to_string_pretty(&schema).context("Failed to serialize action schema")?;
+    match args.output {
+        Some(path) => fs::write(&path, output)
+            .with_context(|| format!("Failed to write action schema to {}", path.display())),
+        None => {
+            println!("{}", output);
+            Ok(())
+        }
+    }
+}
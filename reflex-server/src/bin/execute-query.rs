@@ -14,7 +14,10 @@ use anyhow::{anyhow, Context, Result};
 use clap::Parser;
 use futures::{Future, FutureExt};
 use opentelemetry::trace::noop::NoopTracer;
-use reflex::core::{ArgType, Expression};
+use reflex::{
+    core::{ArgType, Expression},
+    sandbox::SandboxPolicies,
+};
 use reflex_dispatcher::{Action, HandlerContext, TaskFactory};
 use reflex_engine::task::wasm_worker::WasmHeapDumpMode;
 use reflex_graphql::{parse_graphql_schema, GraphQlSchema, NoopGraphQlQueryTransform};
@@ -102,6 +105,9 @@ pub struct Args {
     /// Throttle stateful effect updates
     #[clap(long)]
     effect_throttle_ms: Option<u64>,
+    /// Path to JSON sandbox policy restricting the builtins and effect types available to the graph root (per-root overrides can be specified in a "roots" map keyed by entry point name or query label)
+    #[clap(long)]
+    sandbox_policy: Option<PathBuf>,
     /// Log runtime actions
     #[clap(long)]
     log: Option<Option<LogFormat>>,
@@ -132,6 +138,10 @@ pub struct Args {
     /// Compile constructor arguments as lazily-evaluated expressions
     #[clap(long)]
     lazy_constructors: bool,
+    /// Compiler options, specified as either a JSON object or a comma-separated list of key=value pairs (any
+    /// individual compiler flags take precedence)
+    #[clap(long)]
+    compiler_options: Option<CompilerOptions>,
     /// Wrap compiled lambdas in argument memoization wrappers
     #[clap(long)]
     memoize_lambdas: bool,
@@ -213,6 +223,10 @@ async fn main() -> Result<()> {
     let input_path = &args.input_path;
     let factory: TFactory = SharedTermFactory::<TBuiltin>::default();
     let allocator: TAllocator = DefaultAllocator::default();
+    let sandbox_policies = match &args.sandbox_policy {
+        Some(path) => load_sandbox_policies(path.as_path())?,
+        None => SandboxPolicies::default(),
+    };
     let syntax = match args.syntax {
         Some(syntax) => Ok(syntax),
         None => {
@@ -227,7 +241,7 @@ async fn main() -> Result<()> {
         let defaults = WasmCompilerOptions::default();
         WasmCompilerOptions {
             compiler: {
-                let defaults = CompilerOptions::default();
+                let defaults = args.compiler_options.unwrap_or_default();
                 CompilerOptions {
                     lazy_record_values: match args.lazy_record_values {
                         true => ArgType::Lazy,
@@ -292,7 +306,8 @@ async fn main() -> Result<()> {
                     JavaScriptCompilerRootConfig::from(input_path.to_owned()),
                 ),
             };
-            let entry_point = GraphRootEntryPoint::new(entry_point_name.clone(), root);
+            let entry_point = GraphRootEntryPoint::new(entry_point_name.clone(), root)
+                .with_sandbox_policy(sandbox_policies.get(entry_point_name.as_str()).clone());
             parse_and_compile_module(
                 [&entry_point],
                 default_js_loaders(empty(), &factory, &allocator),
@@ -434,6 +449,7 @@ async fn main() -> Result<()> {
         TokioRuntimeMonitorMetricNames::default(),
         TokioRuntimeThreadPoolFactory::new(tokio::runtime::Handle::current()),
         TokioRuntimeThreadPoolFactory::new(tokio::runtime::Handle::current()),
+        sandbox_policies,
         dump_heap_snapshot,
     )
     .await
@@ -502,6 +518,13 @@ fn load_graphql_schema(path: &Path) -> Result<GraphQlSchema> {
         .with_context(|| format!("Failed to load GraphQL schema: {}", path.to_string_lossy()))
 }
 
+fn load_sandbox_policies(path: &Path) -> Result<SandboxPolicies> {
+    let source = fs::read_to_string(path)
+        .with_context(|| format!("Failed to load sandbox policy: {}", path.to_string_lossy()))?;
+    serde_json::from_str(&source)
+        .with_context(|| format!("Failed to load sandbox policy: {}", path.to_string_lossy()))
+}
+
 fn read_wasm_module(path: &Path) -> Result<Vec<u8>> {
     std::fs::read(path).with_context(|| {
         format!(
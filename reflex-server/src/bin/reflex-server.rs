@@ -6,7 +6,8 @@
 use std::{
     fs,
     iter::{empty, once},
-    net::SocketAddr,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    num::NonZeroU32,
     path::{Path, PathBuf},
     str::FromStr,
     time::Duration,
@@ -17,44 +18,63 @@ use clap::Parser;
 use futures::{Future, FutureExt};
 use metrics_exporter_prometheus::PrometheusBuilder;
 use opentelemetry::trace::noop::NoopTracer;
-use reflex::core::{ArgType, Expression};
+use reflex::{
+    core::{ArgType, Expression, ExpressionFactory, FloatFormat, HeapAllocator},
+    sandbox::SandboxPolicies,
+};
 use reflex_dispatcher::{Action, HandlerContext, TaskFactory};
-use reflex_engine::task::wasm_worker::WasmHeapDumpMode;
-use reflex_graphql::{parse_graphql_schema, GraphQlSchema, NoopGraphQlQueryTransform};
+use reflex_engine::{
+    actor::wasm_interpreter::WasmShadowEvaluation, task::wasm_worker::WasmHeapDumpMode,
+};
+use reflex_graphql::{
+    deserialize_graphql_operation, parse_graphql_query, parse_graphql_schema,
+    GraphQlErrorExtensions, GraphQlOperation, GraphQlOperationPayload, GraphQlResultSizeLimit,
+    GraphQlSchema, NoopGraphQlQueryTransform,
+};
 use reflex_grpc::{
     actor::{GrpcHandler, GrpcHandlerMetricNames},
     load_grpc_services, DefaultGrpcConfig,
 };
 use reflex_handlers::{
+    actor::{
+        plugin::{EffectPlugin, PluginHandler},
+        HandlerActor,
+    },
     default_handler_actors,
     utils::tls::{create_https_client, hyper_rustls},
     DefaultHandlerMetricNames,
 };
+use reflex_js::log_sink::LogSinkConfig;
 use reflex_lang::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
 use reflex_parser::{syntax::js::default_js_loaders, Syntax};
 use reflex_protobuf::types::WellKnownTypesTranscoder;
 use reflex_recorder::{
+    audit::{EffectAuditEvent, EffectAuditEventSink},
     effect::{EffectEventSerializerEventSink, EffectRecorder, SerializedEffectEvent},
     file_recorder::FileRecorder,
     session_recorder::SessionRecorder,
+    session_snapshot::{RuntimeSessionSnapshot, RuntimeSessionSnapshotEventSink},
+};
+use reflex_runtime::{
+    actor::evaluate_handler::EvaluateHandlerAction, utils::effect_schema::EffectSchemaDriftOptions,
 };
-use reflex_runtime::actor::evaluate_handler::EvaluateHandlerAction;
 use reflex_scheduler::threadpool::TokioRuntimeThreadPoolFactory;
 use reflex_server::{
     action::ServerCliAction,
     builtins::ServerBuiltins,
     cli::{
         reflex_server::{
-            cli, GraphQlWebServerMetricLabels, OpenTelemetryConfig, ReflexServerCliOptions,
+            cli, GraphQlWebServerMetricLabels, OpenTelemetryConfig, ReflexServerAdminOptions,
+            ReflexServerCliOptions,
         },
         task::{ServerCliTaskActor, ServerCliTaskFactory},
     },
     generate_session_recording_filename,
     logger::{
-        async_channel::AsyncChannelEventSink, formatted::FormattedActionLogger,
-        formatter::TimestampedLogFormatter, json::JsonActionLogger,
-        messages::DefaultActionFormatter, prometheus::PrometheusLogger, ActionLogger, ChainLogger,
-        EitherLogger, SharedLogger,
+        async_channel::AsyncChannelEventSink, audit::OpenTelemetryAuditEventSink,
+        formatted::FormattedActionLogger, formatter::TimestampedLogFormatter,
+        json::JsonActionLogger, messages::DefaultActionFormatter, prometheus::PrometheusLogger,
+        ActionLogger, ChainLogger, EitherLogger, SharedLogger,
     },
     scheduler_metrics::{
         NoopServerMetricsSchedulerQueueInstrumentation, ServerMetricsInstrumentation,
@@ -66,11 +86,12 @@ use reflex_server::{
             InitSessionRecordingAction,
         },
         utils::EitherTracer,
+        GraphQlServerLatencyBudgets,
     },
     tokio_runtime_metrics_export::TokioRuntimeMonitorMetricNames,
     GraphQlWebServerActorFactory, GraphQlWebServerMetricNames,
 };
-use reflex_utils::{reconnect::FibonacciReconnectTimeout, FileWriterFormat};
+use reflex_utils::{event::ChainEventSink, reconnect::FibonacciReconnectTimeout, FileWriterFormat};
 use reflex_wasm::{
     cli::compile::{
         parse_and_compile_module, CompilerRootConfig, GraphRootEntryPoint,
@@ -78,9 +99,9 @@ use reflex_wasm::{
         ModuleEntryPoint, RuntimeEntryPointSyntax, WasmCompilerOptions, WasmCompilerRuntimeOptions,
     },
     compiler::CompilerOptions,
-    interpreter::WasmProgram,
+    interpreter::{WasmModuleCache, WasmProgram},
 };
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Serialize};
 
 const RUNTIME_BYTES: &'static [u8] = include_bytes!("../../../reflex-wasm/build/runtime.wasm");
 
@@ -104,21 +125,69 @@ struct Args {
     /// Port on which to expose Prometheus HTTP metrics
     #[clap(long)]
     metrics_port: Option<u16>,
+    /// Port on which to expose an admin endpoint for remote REPL clients (see `reflex-cli attach`), authenticated via the bearer token provided in the REFLEX_ADMIN_TOKEN environment variable
+    #[clap(long)]
+    admin_port: Option<u16>,
+    /// Network interface on which to expose the admin endpoint (defaults to the loopback interface)
+    #[clap(long, default_value_t = IpAddr::V4(Ipv4Addr::LOCALHOST))]
+    admin_host: IpAddr,
+    /// Allow the admin endpoint to execute mutations (the admin endpoint is read-only by default)
+    #[clap(long)]
+    admin_allow_mutations: bool,
     /// Paths of compiled gRPC service definition protobufs
     #[clap(long)]
     grpc_service: Vec<PathBuf>,
+    /// Paths of WebAssembly effect handler plugins to load at startup
+    #[clap(long)]
+    plugin: Vec<PathBuf>,
+    /// Paths of GraphQL operations to evaluate at startup and keep subscribed (either GraphQL query documents or JSON operation payloads)
+    #[clap(long)]
+    warmup: Vec<PathBuf>,
     /// Throttle stateful effect updates
     #[clap(long)]
     effect_throttle_ms: Option<u64>,
+    /// Learn the shape of each effect's results and report any subsequent results whose shape diverges from the learned schema
+    #[clap(long)]
+    effect_schema_drift: bool,
+    /// Formatting strategy applied to float values within GraphQL results ("shortest" or "fixed:<precision>")
+    #[clap(long, default_value_t = FloatFormat::default())]
+    float_format: FloatFormat,
+    /// Comma-separated list of error payload fields to expose within the GraphQL error extensions (e.g. "code,retryable")
+    #[clap(long)]
+    graphql_error_extensions: Option<GraphQlErrorExtensions>,
+    /// Maximum serialized size in bytes of each GraphQL operation result, beyond which lists and records are truncated with explicit truncation markers
+    #[clap(long)]
+    graphql_max_result_size: Option<GraphQlResultSizeLimit>,
+    /// Path to JSON sandbox policy restricting the builtins and effect types available to the graph root (per-root overrides can be specified in a "roots" map keyed by entry point name or query label)
+    #[clap(long)]
+    sandbox_policy: Option<PathBuf>,
     /// Log runtime actions
     #[clap(long)]
     log: Option<Option<LogFormat>>,
+    /// Destination for output of the Log builtins ("stdout", "tracing" or "file:<path>", defaulting to stdout)
+    #[clap(long)]
+    log_sink: Option<LogSinkConfig>,
+    /// Maximum number of Log builtin entries to emit per second (entries beyond the limit are dropped)
+    #[clap(long)]
+    log_max_rate: Option<NonZeroU32>,
     /// Path to capture runtime event playback file (defaults to an autogenerated filename)
     #[clap(long)]
     capture_events: Option<Option<PathBuf>>,
     /// If capturing a runtime event playback file, filter events to only capture a certain category of events (valid options are "effect", defaulting to all events)
     #[clap(long)]
     capture_type: Option<EventCaptureType>,
+    /// Path to write a structured audit log of all emitted effects (effect payloads are redacted using the key provided via the AUDIT_LOG_HMAC_KEY environment variable)
+    #[clap(long)]
+    audit_log: Option<PathBuf>,
+    /// Export effect audit records to the configured OpenTelemetry collector
+    #[clap(long)]
+    audit_otlp: bool,
+    /// Path to a runtime session snapshot, used to restore cached effect values at startup (if present) and updated with the latest effect values on shutdown
+    #[clap(long)]
+    session_snapshot: Option<PathBuf>,
+    /// Effect types whose cached values must not be restored from the runtime session snapshot, and must instead await fresh values from their handlers (e.g. "reflex::timestamp")
+    #[clap(long)]
+    session_snapshot_exclude: Vec<String>,
     /// Skip compiler optimizations
     #[clap(long)]
     unoptimized: bool,
@@ -140,18 +209,67 @@ struct Args {
     /// Compile constructor arguments as lazily-evaluated expressions
     #[clap(long)]
     lazy_constructors: bool,
+    /// Compiler options, specified as either a JSON object or a comma-separated list of key=value pairs (any
+    /// individual compiler flags take precedence)
+    #[clap(long)]
+    compiler_options: Option<CompilerOptions>,
     /// Wrap compiled lambdas in argument memoization wrappers
     #[clap(long)]
     memoize_lambdas: bool,
     /// Dump heap snapshots for any queries that return error results
     #[clap(long)]
     dump_heap_snapshot: Option<WasmHeapDumpMode>,
+    /// Path to a directory in which to cache natively-compiled WebAssembly modules, reducing startup latency on
+    /// subsequent launches of an unchanged module
+    #[clap(long)]
+    module_cache: Option<PathBuf>,
+    /// Path to a secondary compiled WebAssembly module on which to evaluate a sample of queries in shadow mode (shadow results are compared against the primary results but never served)
+    #[clap(long)]
+    shadow_module: Option<PathBuf>,
+    /// Fraction of queries to evaluate on the shadow module (between 0.0 and 1.0, defaulting to all queries)
+    #[clap(long)]
+    shadow_sample_rate: Option<f64>,
+    /// Default latency budget for GraphQL operations that do not declare a budget via the @latencyBudget(ms: <ms>) operation directive
+    #[clap(long)]
+    latency_budget_ms: Option<u64>,
+    /// Latency budget for a named GraphQL operation, specified as <operation name>=<ms> (takes precedence over the default latency budget)
+    #[clap(long)]
+    operation_latency_budget: Vec<OperationLatencyBudget>,
+    /// Target proportion of GraphQL results expected to meet their latency budget, used to calculate SLO burn rate metrics (between 0.0 and 1.0, defaulting to 0.99)
+    #[clap(long)]
+    latency_slo_target: Option<f64>,
+    /// Rolling window over which latency SLO burn rate metrics are calculated (defaulting to 300 seconds)
+    #[clap(long)]
+    latency_slo_window_secs: Option<u64>,
 }
-impl Into<ReflexServerCliOptions> for Args {
-    fn into(self) -> ReflexServerCliOptions {
-        ReflexServerCliOptions {
-            address: SocketAddr::from(([0, 0, 0, 0], self.port)),
-        }
+impl TryFrom<Args> for ReflexServerCliOptions {
+    type Error = anyhow::Error;
+    fn try_from(args: Args) -> Result<Self> {
+        let warmup_operations = args
+            .warmup
+            .iter()
+            .map(|path| load_graphql_operation(path.as_path()))
+            .collect::<Result<Vec<_>>>()?;
+        let admin = match args.admin_port {
+            None => None,
+            Some(port) => Some(ReflexServerAdminOptions {
+                address: SocketAddr::new(args.admin_host, port),
+                token: std::env::var("REFLEX_ADMIN_TOKEN")
+                    .ok()
+                    .filter(|token| !token.is_empty())
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "Admin endpoint requires the REFLEX_ADMIN_TOKEN environment variable"
+                        )
+                    })?,
+                allow_mutations: args.admin_allow_mutations,
+            }),
+        };
+        Ok(Self {
+            address: SocketAddr::from(([0, 0, 0, 0], args.port)),
+            warmup_operations,
+            admin,
+        })
     }
 }
 
@@ -169,6 +287,26 @@ impl FromStr for LogFormat {
     }
 }
 
+#[derive(Clone, Debug)]
+struct OperationLatencyBudget {
+    operation_name: String,
+    budget: Duration,
+}
+impl FromStr for OperationLatencyBudget {
+    type Err = anyhow::Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (operation_name, budget) = input
+            .split_once('=')
+            .ok_or_else(|| anyhow!("Invalid operation latency budget: {}", input))?;
+        let budget = u64::from_str(budget.trim())
+            .with_context(|| anyhow!("Invalid operation latency budget: {}", input))?;
+        Ok(Self {
+            operation_name: String::from(operation_name.trim()),
+            budget: Duration::from_millis(budget),
+        })
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 enum EventCaptureType {
     Effect,
@@ -211,9 +349,20 @@ pub async fn main() -> Result<()> {
     >;
 
     let args = Args::parse();
+    if args.log_sink.is_some() || args.log_max_rate.is_some() {
+        args.log_sink
+            .clone()
+            .unwrap_or(LogSinkConfig::Stdout)
+            .install(args.log_max_rate)
+            .context("Failed to initialize log sink")?;
+    }
     let input_path = &args.input_path;
     let factory: TFactory = SharedTermFactory::<TBuiltin>::default();
     let allocator: TAllocator = DefaultAllocator::default();
+    let sandbox_policies = match &args.sandbox_policy {
+        Some(path) => load_sandbox_policies(path.as_path())?,
+        None => SandboxPolicies::default(),
+    };
     let syntax = match args.syntax {
         Some(syntax) => Ok(syntax),
         None => {
@@ -228,7 +377,7 @@ pub async fn main() -> Result<()> {
         let defaults = WasmCompilerOptions::default();
         WasmCompilerOptions {
             compiler: {
-                let defaults = CompilerOptions::default();
+                let defaults = args.compiler_options.unwrap_or_default();
                 CompilerOptions {
                     lazy_record_values: match args.lazy_record_values {
                         true => ArgType::Lazy,
@@ -293,7 +442,8 @@ pub async fn main() -> Result<()> {
                     JavaScriptCompilerRootConfig::from(input_path.to_owned()),
                 ),
             };
-            let entry_point = GraphRootEntryPoint::new(entry_point_name.clone(), root);
+            let entry_point = GraphRootEntryPoint::new(entry_point_name.clone(), root)
+                .with_sandbox_policy(sandbox_policies.get(entry_point_name.as_str()).clone());
             parse_and_compile_module(
                 [&entry_point],
                 default_js_loaders(empty(), &factory, &allocator),
@@ -309,6 +459,10 @@ pub async fn main() -> Result<()> {
             .map(move |module| (module, entry_point_name))
         }
     }?;
+    let wasm_module = match args.module_cache.as_ref() {
+        Some(directory) => wasm_module.with_module_cache(WasmModuleCache::new(directory)),
+        None => wasm_module,
+    };
     let mut logger = args.log.map(|format| match format {
         Some(LogFormat::Json) => {
             EitherLogger::Left(JsonActionLogger::<_, TAction, TTask>::stderr())
@@ -332,6 +486,12 @@ pub async fn main() -> Result<()> {
     let grpc_services = load_grpc_services(args.grpc_service.iter())
         .with_context(|| "Failed to load gRPC service descriptor")?;
     let grpc_config = DefaultGrpcConfig::default();
+    let plugins = args
+        .plugin
+        .iter()
+        .map(|path| EffectPlugin::load(path).map_err(|err| anyhow!(err)))
+        .collect::<Result<Vec<_>>>()
+        .with_context(|| "Failed to load effect handler plugin")?;
     let grpc_max_operations_per_connection =
         match std::env::var("GRPC_MAX_OPERATIONS_PER_CONNECTION") {
             Ok(value) => str::parse::<usize>(&value)
@@ -340,12 +500,26 @@ pub async fn main() -> Result<()> {
             _ => Ok(None),
         }?;
     let dump_heap_snapshot = args.dump_heap_snapshot;
+    let shadow_evaluation = args
+        .shadow_module
+        .as_ref()
+        .map(|path| load_shadow_evaluation(path.as_path(), args.shadow_sample_rate))
+        .transpose()?;
     let schema = if let Some(schema_path) = &args.schema {
         Some(load_graphql_schema(schema_path.as_path())?)
     } else {
         None
     };
     let effect_throttle = args.effect_throttle_ms.map(Duration::from_millis);
+    let effect_schema_drift = if args.effect_schema_drift {
+        Some(EffectSchemaDriftOptions::default())
+    } else {
+        None
+    };
+    let latency_budgets = parse_latency_budgets(&args)?;
+    let float_format = args.float_format;
+    let error_extensions = args.graphql_error_extensions.clone().unwrap_or_default();
+    let result_size_limit = args.graphql_max_result_size.unwrap_or_default();
     let mut logger = {
         let stdout_logger = logger;
         let prometheus_logger = args
@@ -391,8 +565,59 @@ pub async fn main() -> Result<()> {
         .map(|(recorder, task)| (Some(recorder), task))
         .unwrap_or((None, None));
     let _recorder_handle = recorder_task.map(|task| tokio::runtime::Handle::current().spawn(task));
+    let audit_tracer = match (args.audit_otlp, tracer.as_ref()) {
+        (false, _) => Ok(None),
+        (true, Some(tracer)) => Ok(Some(tracer.clone())),
+        (true, None) => Err(anyhow!(
+            "OpenTelemetry audit export requires an OpenTelemetry collector to be configured"
+        )),
+    }?;
+    let audit_key = match (args.audit_log.as_ref(), audit_tracer.as_ref()) {
+        (None, None) => None,
+        _ => Some(std::env::var("AUDIT_LOG_HMAC_KEY").with_context(|| {
+            String::from(
+                "Effect audit logging requires the AUDIT_LOG_HMAC_KEY environment variable",
+            )
+        })?),
+    };
+    let (audit_logger, audit_task) = create_effect_audit_logger::<T, _, TAction, TTask>(
+        args.audit_log.as_deref(),
+        audit_tracer,
+        audit_key,
+        factory,
+    )
+    .map_err(|err| anyhow!("{}", err))
+    .with_context(|| String::from("Unable to create effect audit log"))?;
+    let _audit_handle = audit_task.map(|task| tokio::runtime::Handle::current().spawn(task));
+    let restored_effects = args
+        .session_snapshot
+        .as_deref()
+        .filter(|path| path.exists())
+        .map(load_session_snapshot::<T>)
+        .transpose()?
+        .and_then(|mut snapshot| {
+            let excluded_effect_types = args
+                .session_snapshot_exclude
+                .iter()
+                .map(|effect_type| {
+                    factory.create_string_term(allocator.create_string(effect_type.as_str()))
+                })
+                .collect::<Vec<_>>();
+            snapshot
+                .retain_effect_types(|effect_type| !excluded_effect_types.contains(effect_type));
+            snapshot.into_emit_action()
+        });
+    let session_snapshot = args.session_snapshot.clone().map(|path| {
+        (
+            path,
+            RuntimeSessionSnapshotEventSink::<T, TFactory>::new(factory),
+        )
+    });
+    let session_snapshot_logger = session_snapshot
+        .as_ref()
+        .map(|(_, sink)| EffectRecorder::<T, _, TAction, TTask>::new(sink.clone()));
     let metric_names = ServerSchedulerMetricNames::default();
-    let config: ReflexServerCliOptions = args.into();
+    let config = ReflexServerCliOptions::try_from(args)?;
     log_server_action(
         &mut logger,
         &TAction::from(InitHttpServerAction {
@@ -410,6 +635,7 @@ pub async fn main() -> Result<()> {
                     units: Duration::from_secs(1),
                     max_timeout: Duration::from_secs(30),
                 };
+                let main_pid = context.pid();
                 default_handler_actors::<
                     TAction,
                     TTask,
@@ -427,6 +653,14 @@ pub async fn main() -> Result<()> {
                     context.pid(),
                 )
                 .into_iter()
+                .chain(plugins.into_iter().map(|plugin| {
+                    HandlerActor::PluginHandler(PluginHandler::new(
+                        plugin,
+                        factory.clone(),
+                        allocator.clone(),
+                        main_pid,
+                    ))
+                }))
                 .map(ServerCliTaskActor::Handler)
                 .chain(once(ServerCliTaskActor::Grpc(GrpcHandler::new(
                     grpc_services,
@@ -457,7 +691,13 @@ pub async fn main() -> Result<()> {
                 None => EitherTracer::Left(NoopTracer::default()),
                 Some(tracer) => EitherTracer::Right(tracer),
             },
-            ChainLogger::new(logger, recorder.map(SharedLogger::new)),
+            ChainLogger::new(
+                ChainLogger::new(
+                    ChainLogger::new(logger, recorder.map(SharedLogger::new)),
+                    audit_logger,
+                ),
+                session_snapshot_logger,
+            ),
             ServerMetricsInstrumentation::new(
                 NoopServerMetricsSchedulerQueueInstrumentation::default(),
                 metric_names,
@@ -465,10 +705,83 @@ pub async fn main() -> Result<()> {
             TokioRuntimeThreadPoolFactory::new(tokio::runtime::Handle::current()),
             TokioRuntimeThreadPoolFactory::new(tokio::runtime::Handle::current()),
             effect_throttle,
+            effect_schema_drift,
+            float_format,
+            error_extensions,
+            result_size_limit,
+            sandbox_policies,
+            latency_budgets,
             dump_heap_snapshot,
+            shadow_evaluation,
+            restored_effects,
         )
         .with_context(|| anyhow!("Server startup failed"))?;
-    server.await.with_context(|| anyhow!("Server error"))
+    match session_snapshot {
+        None => server.await.with_context(|| anyhow!("Server error")),
+        Some((output_path, sink)) => {
+            tokio::select! {
+                result = server => result.with_context(|| anyhow!("Server error")),
+                result = shutdown_signal() => {
+                    result.with_context(|| anyhow!("Failed to listen for shutdown signal"))?;
+                    save_session_snapshot(&output_path, &sink.snapshot())
+                }
+            }
+        }
+    }
+}
+
+async fn shutdown_signal() -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        let mut terminate =
+            tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())?;
+        tokio::select! {
+            result = tokio::signal::ctrl_c() => result,
+            _ = terminate.recv() => Ok(()),
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        tokio::signal::ctrl_c().await
+    }
+}
+
+fn load_session_snapshot<T>(path: &Path) -> Result<RuntimeSessionSnapshot<T>>
+where
+    T: Expression + DeserializeOwned,
+    T::Signal: DeserializeOwned,
+{
+    let source = fs::read(path).with_context(|| {
+        format!(
+            "Failed to load runtime session snapshot: {}",
+            path.to_string_lossy()
+        )
+    })?;
+    serde_json::from_slice(&source).with_context(|| {
+        format!(
+            "Failed to parse runtime session snapshot: {}",
+            path.to_string_lossy()
+        )
+    })
+}
+
+fn save_session_snapshot<T>(path: &Path, snapshot: &RuntimeSessionSnapshot<T>) -> Result<()>
+where
+    T: Expression + Serialize,
+    T::Signal: Serialize,
+{
+    // Write to a temporary file before moving into place, to avoid leaving a partially-written snapshot on failure
+    let temp_path = path.with_extension("tmp");
+    serde_json::to_vec(snapshot)
+        .map_err(anyhow::Error::from)
+        .and_then(|output| fs::write(&temp_path, output).map_err(anyhow::Error::from))
+        .and_then(|_| fs::rename(&temp_path, path).map_err(anyhow::Error::from))
+        .with_context(|| {
+            format!(
+                "Failed to save runtime session snapshot: {}",
+                path.to_string_lossy()
+            )
+        })
 }
 
 fn create_effect_recorder<T: Expression, TAction, TTask>(
@@ -526,6 +839,64 @@ where
     }
 }
 
+type EffectAuditLogger<T, TFactory, TAction, TTask> = EffectRecorder<
+    T,
+    SharedLogger<
+        EffectAuditEventSink<
+            T,
+            TFactory,
+            ChainEventSink<
+                Option<AsyncChannelEventSink<EffectAuditEvent>>,
+                Option<OpenTelemetryAuditEventSink<opentelemetry::sdk::trace::Tracer>>,
+            >,
+        >,
+    >,
+    TAction,
+    TTask,
+>;
+
+fn create_effect_audit_logger<T: Expression, TFactory: ExpressionFactory<T>, TAction, TTask>(
+    output_path: Option<&Path>,
+    tracer: Option<opentelemetry::sdk::trace::Tracer>,
+    redaction_key: Option<String>,
+    factory: TFactory,
+) -> Result<
+    (
+        Option<EffectAuditLogger<T, TFactory, TAction, TTask>>,
+        Option<impl Future<Output = ()>>,
+    ),
+    String,
+>
+where
+    TAction: Action + EvaluateHandlerAction<T>,
+    TTask: TaskFactory<TAction, TTask>,
+{
+    let (file_sink, task) = output_path
+        .map(|output_path| {
+            FileRecorder::<EffectAuditEvent>::create(FileWriterFormat::Json, output_path)
+                .map(AsyncChannelEventSink::create)
+        })
+        .transpose()?
+        .map(|(sink, task)| (Some(sink), Some(task)))
+        .unwrap_or((None, None));
+    let otlp_sink = tracer.map(OpenTelemetryAuditEventSink::new);
+    let logger = match (&file_sink, &otlp_sink) {
+        (None, None) => None,
+        _ => {
+            let redaction_key = redaction_key
+                .ok_or_else(|| String::from("Missing effect audit log redaction key"))?;
+            Some(EffectRecorder::<T, _, TAction, TTask>::new(
+                SharedLogger::new(EffectAuditEventSink::new(
+                    factory,
+                    redaction_key,
+                    ChainEventSink::new(file_sink, otlp_sink),
+                )),
+            ))
+        }
+    };
+    Ok((logger, task))
+}
+
 fn log_server_action<TAction: Action>(
     logger: &mut impl ActionLogger<Action = TAction>,
     action: &TAction,
@@ -540,6 +911,103 @@ fn load_graphql_schema(path: &Path) -> Result<GraphQlSchema> {
         .with_context(|| format!("Failed to load GraphQL schema: {}", path.to_string_lossy()))
 }
 
+fn load_graphql_operation(path: &Path) -> Result<GraphQlOperation> {
+    let source = fs::read_to_string(path).with_context(|| {
+        format!(
+            "Failed to load GraphQL operation: {}",
+            path.to_string_lossy()
+        )
+    })?;
+    let GraphQlOperationPayload {
+        query,
+        operation_name,
+        variables,
+        extensions,
+    } = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("json") => deserialize_graphql_operation(&source).map_err(|err| anyhow!(err)),
+        _ => Ok(GraphQlOperationPayload {
+            query: source,
+            operation_name: None,
+            variables: Default::default(),
+            extensions: Default::default(),
+        }),
+    }
+    .with_context(|| {
+        format!(
+            "Failed to load GraphQL operation: {}",
+            path.to_string_lossy()
+        )
+    })?;
+    let query = parse_graphql_query(&query).with_context(|| {
+        format!(
+            "Failed to load GraphQL operation: {}",
+            path.to_string_lossy()
+        )
+    })?;
+    Ok(GraphQlOperation::new(
+        query,
+        operation_name,
+        variables,
+        extensions,
+    ))
+}
+
+fn load_sandbox_policies(path: &Path) -> Result<SandboxPolicies> {
+    let source = fs::read_to_string(path)
+        .with_context(|| format!("Failed to load sandbox policy: {}", path.to_string_lossy()))?;
+    serde_json::from_str(&source)
+        .with_context(|| format!("Failed to load sandbox policy: {}", path.to_string_lossy()))
+}
+
+fn parse_latency_budgets(args: &Args) -> Result<GraphQlServerLatencyBudgets> {
+    let defaults = GraphQlServerLatencyBudgets::default();
+    let slo_target = args.latency_slo_target.unwrap_or(defaults.slo_target);
+    if !(slo_target > 0.0 && slo_target < 1.0) {
+        return Err(anyhow!(
+            "Invalid latency SLO target: {} (expected a value between 0.0 and 1.0)",
+            slo_target
+        ));
+    }
+    Ok(GraphQlServerLatencyBudgets {
+        default_budget: args.latency_budget_ms.map(Duration::from_millis),
+        operation_budgets: args
+            .operation_latency_budget
+            .iter()
+            .map(|entry| (entry.operation_name.clone(), entry.budget))
+            .collect(),
+        slo_target,
+        burn_rate_window: args
+            .latency_slo_window_secs
+            .map(Duration::from_secs)
+            .unwrap_or(defaults.burn_rate_window),
+    })
+}
+
+fn load_shadow_evaluation(path: &Path, sample_rate: Option<f64>) -> Result<WasmShadowEvaluation> {
+    let sample_rate = sample_rate.unwrap_or(1.0);
+    if !(0.0..=1.0).contains(&sample_rate) {
+        return Err(anyhow!(
+            "Invalid shadow sample rate: {} (expected a value between 0.0 and 1.0)",
+            sample_rate
+        ));
+    }
+    let syntax = path.extension().and_then(RuntimeEntryPointSyntax::infer);
+    let program = match syntax {
+        Some(RuntimeEntryPointSyntax::PrecompiledWasm) => {
+            read_wasm_module(path).map(WasmProgram::from_cwasm)
+        }
+        Some(RuntimeEntryPointSyntax::Wasm) => read_wasm_module(path).map(WasmProgram::from_wasm),
+        _ => Err(anyhow!(
+            "Shadow module must be a compiled WebAssembly module: {}",
+            path.to_string_lossy()
+        )),
+    }?;
+    Ok(WasmShadowEvaluation {
+        program,
+        sample_rate,
+    })
+}
+
 fn read_wasm_module(path: &Path) -> Result<Vec<u8>> {
     std::fs::read(path).with_context(|| {
         format!(
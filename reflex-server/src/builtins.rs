@@ -147,6 +147,18 @@ impl Builtin for ServerBuiltins {
             Self::Handlers(term) => term.should_parallelize(args),
         }
     }
+    fn apply_static<T: Expression<Builtin = Self>>(
+        &self,
+        args: &[T],
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+    ) -> Option<T> {
+        match self {
+            Self::Stdlib(term) => term.apply_static(args, factory, allocator),
+            Self::Js(term) => term.apply_static(args, factory, allocator),
+            _ => None,
+        }
+    }
 }
 impl std::fmt::Display for ServerBuiltins {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -231,11 +243,41 @@ impl From<reflex_stdlib::stdlib::Contains> for ServerBuiltins {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_stdlib::stdlib::DateAdd> for ServerBuiltins {
+    fn from(value: reflex_stdlib::stdlib::DateAdd) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_stdlib::stdlib::DateDiff> for ServerBuiltins {
+    fn from(value: reflex_stdlib::stdlib::DateDiff) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_stdlib::stdlib::DateOffset> for ServerBuiltins {
+    fn from(value: reflex_stdlib::stdlib::DateOffset) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_stdlib::stdlib::DateTruncate> for ServerBuiltins {
+    fn from(value: reflex_stdlib::stdlib::DateTruncate) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_stdlib::stdlib::DateWithOffset> for ServerBuiltins {
+    fn from(value: reflex_stdlib::stdlib::DateWithOffset) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_stdlib::stdlib::Divide> for ServerBuiltins {
     fn from(value: reflex_stdlib::stdlib::Divide) -> Self {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_stdlib::stdlib::Duration> for ServerBuiltins {
+    fn from(value: reflex_stdlib::stdlib::Duration) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_stdlib::stdlib::Effect> for ServerBuiltins {
     fn from(value: reflex_stdlib::stdlib::Effect) -> Self {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -256,6 +298,11 @@ impl From<reflex_stdlib::stdlib::Equal> for ServerBuiltins {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_stdlib::stdlib::Eval> for ServerBuiltins {
+    fn from(value: reflex_stdlib::stdlib::Eval) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_stdlib::stdlib::Filter> for ServerBuiltins {
     fn from(value: reflex_stdlib::stdlib::Filter) -> Self {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -346,11 +393,26 @@ impl From<reflex_stdlib::stdlib::Map> for ServerBuiltins {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_stdlib::stdlib::Match> for ServerBuiltins {
+    fn from(value: reflex_stdlib::stdlib::Match) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_stdlib::stdlib::Max> for ServerBuiltins {
     fn from(value: reflex_stdlib::stdlib::Max) -> Self {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_stdlib::stdlib::Memo> for ServerBuiltins {
+    fn from(value: reflex_stdlib::stdlib::Memo) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_stdlib::stdlib::MemoStore> for ServerBuiltins {
+    fn from(value: reflex_stdlib::stdlib::MemoStore) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_stdlib::stdlib::Merge> for ServerBuiltins {
     fn from(value: reflex_stdlib::stdlib::Merge) -> Self {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -391,6 +453,11 @@ impl From<reflex_stdlib::stdlib::PushFront> for ServerBuiltins {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_stdlib::stdlib::Quote> for ServerBuiltins {
+    fn from(value: reflex_stdlib::stdlib::Quote) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_stdlib::stdlib::Raise> for ServerBuiltins {
     fn from(value: reflex_stdlib::stdlib::Raise) -> Self {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -436,6 +503,11 @@ impl From<reflex_stdlib::stdlib::ResolveList> for ServerBuiltins {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_stdlib::stdlib::Reveal> for ServerBuiltins {
+    fn from(value: reflex_stdlib::stdlib::Reveal) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_stdlib::stdlib::Round> for ServerBuiltins {
     fn from(value: reflex_stdlib::stdlib::Round) -> Self {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -451,6 +523,21 @@ impl From<reflex_stdlib::stdlib::Slice> for ServerBuiltins {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_stdlib::stdlib::Sort> for ServerBuiltins {
+    fn from(value: reflex_stdlib::stdlib::Sort) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_stdlib::stdlib::SortBy> for ServerBuiltins {
+    fn from(value: reflex_stdlib::stdlib::SortBy) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_stdlib::stdlib::SortMerge> for ServerBuiltins {
+    fn from(value: reflex_stdlib::stdlib::SortMerge) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_stdlib::stdlib::Split> for ServerBuiltins {
     fn from(value: reflex_stdlib::stdlib::Split) -> Self {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -466,6 +553,11 @@ impl From<reflex_stdlib::stdlib::Subtract> for ServerBuiltins {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_stdlib::stdlib::Template> for ServerBuiltins {
+    fn from(value: reflex_stdlib::stdlib::Template) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_stdlib::stdlib::Unzip> for ServerBuiltins {
     fn from(value: reflex_stdlib::stdlib::Unzip) -> Self {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -492,6 +584,11 @@ impl From<reflex_json::stdlib::JsonSerialize> for ServerBuiltins {
         Self::from(reflex_json::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_json::stdlib::JsonSerializeCanonical> for ServerBuiltins {
+    fn from(value: reflex_json::stdlib::JsonSerializeCanonical) -> Self {
+        Self::from(reflex_json::stdlib::Stdlib::from(value))
+    }
+}
 
 impl From<reflex_js::stdlib::Accessor> for ServerBuiltins {
     fn from(value: reflex_js::stdlib::Accessor) -> Self {
@@ -533,6 +630,11 @@ impl From<reflex_js::stdlib::LogArgs> for ServerBuiltins {
         Self::from(reflex_js::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_js::stdlib::LogScope> for ServerBuiltins {
+    fn from(value: reflex_js::stdlib::LogScope) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_js::stdlib::ParseDate> for ServerBuiltins {
     fn from(value: reflex_js::stdlib::ParseDate) -> Self {
         Self::from(reflex_js::stdlib::Stdlib::from(value))
@@ -548,6 +650,16 @@ impl From<reflex_js::stdlib::ParseInt> for ServerBuiltins {
         Self::from(reflex_js::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_js::stdlib::ScopedLog> for ServerBuiltins {
+    fn from(value: reflex_js::stdlib::ScopedLog) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_js::stdlib::ScopedLogArgs> for ServerBuiltins {
+    fn from(value: reflex_js::stdlib::ScopedLogArgs) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_js::stdlib::Throw> for ServerBuiltins {
     fn from(value: reflex_js::stdlib::Throw) -> Self {
         Self::from(reflex_js::stdlib::Stdlib::from(value))
@@ -631,6 +743,26 @@ impl From<reflex_handlers::stdlib::DecrementVariable> for ServerBuiltins {
         Self::from(reflex_handlers::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_handlers::stdlib::Paginate> for ServerBuiltins {
+    fn from(value: reflex_handlers::stdlib::Paginate) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::Throttle> for ServerBuiltins {
+    fn from(value: reflex_handlers::stdlib::Throttle) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::Debounce> for ServerBuiltins {
+    fn from(value: reflex_handlers::stdlib::Debounce) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::Sample> for ServerBuiltins {
+    fn from(value: reflex_handlers::stdlib::Sample) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
 
 #[cfg(test)]
 mod test {
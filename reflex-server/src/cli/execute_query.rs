@@ -10,13 +10,19 @@ use futures::{future, Future, FutureExt};
 use http::{header, HeaderMap, Request, Response};
 use metrics::SharedString;
 use opentelemetry::trace::{Span, Tracer};
-use reflex::core::{Applicable, Reducible, Rewritable};
+use reflex::{
+    core::{Applicable, FloatFormat, Reducible, Rewritable},
+    sandbox::SandboxPolicies,
+};
 use reflex_dispatcher::{
     Action, Actor, Handler, ProcessId, SchedulerTransition, SerializableAction, TaskFactory,
 };
 use reflex_engine::actor::bytecode_interpreter::BytecodeInterpreterMetricLabels;
 use reflex_engine::task::wasm_worker::WasmHeapDumpMode;
-use reflex_graphql::{GraphQlOperation, GraphQlParserBuiltin, GraphQlSchema};
+use reflex_graphql::{
+    GraphQlErrorExtensions, GraphQlOperation, GraphQlParserBuiltin, GraphQlResultSizeLimit,
+    GraphQlSchema,
+};
 use reflex_json::{json, JsonValue};
 use reflex_runtime::{
     task::RuntimeTask, AsyncExpression, AsyncExpressionFactory, AsyncHeapAllocator,
@@ -30,7 +36,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     logger::SkipRedispatchedActionsLogger,
     server::{
-        GraphQlServerOperationMetricLabels, GraphQlServerQueryLabel,
+        GraphQlServerLatencyBudgets, GraphQlServerOperationMetricLabels, GraphQlServerQueryLabel,
         HttpGraphQlServerQueryMetricLabels, HttpGraphQlServerQueryTransform,
         WebSocketGraphQlServerConnectionMetricLabels, WebSocketGraphQlServerQueryTransform,
     },
@@ -105,6 +111,7 @@ pub async fn cli<
     tokio_runtime_metric_names: TokioRuntimeMonitorMetricNames,
     async_tasks: TAsyncTasks,
     blocking_tasks: TBlockingTasks,
+    sandbox_policies: SandboxPolicies,
     dump_heap_snapshot: Option<WasmHeapDumpMode>,
 ) -> Result<String>
 where
@@ -144,7 +151,7 @@ where
     THttpMetricLabels: HttpGraphQlServerQueryMetricLabels + Send + 'static,
     TConnectionMetricLabels: WebSocketGraphQlServerConnectionMetricLabels + Send + 'static,
     TOperationMetricLabels: GraphQlServerOperationMetricLabels + Send + 'static,
-    TWorkerMetricLabels: BytecodeInterpreterMetricLabels + Send + 'static,
+    TWorkerMetricLabels: BytecodeInterpreterMetricLabels + Clone + Send + 'static,
     TTracer: Tracer + Send + 'static,
     TTracer::Span: Span + Send + Sync + 'static,
     TLogger: TokioSchedulerLogger<Action = TAction, Task = TTask> + Clone + Send + Sync + 'static,
@@ -236,7 +243,14 @@ where
         async_tasks,
         blocking_tasks,
         effect_throttle,
+        None,
+        FloatFormat::default(),
+        GraphQlErrorExtensions::default(),
+        GraphQlResultSizeLimit::default(),
+        sandbox_policies,
+        GraphQlServerLatencyBudgets::default(),
         dump_heap_snapshot,
+        None,
     )
     .map_err(|err| anyhow!(err))
     .context("Failed to initialize server")?;
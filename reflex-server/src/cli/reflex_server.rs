@@ -36,16 +36,27 @@ use nom::{
     sequence::tuple,
     IResult,
 };
-use reflex::core::{Applicable, Expression, Reducible, Rewritable};
+use reflex::{
+    core::{Applicable, Expression, FloatFormat, Reducible, Rewritable},
+    sandbox::SandboxPolicies,
+};
 use reflex_engine::{
-    actor::bytecode_interpreter::BytecodeInterpreterMetricLabels,
+    actor::{
+        bytecode_interpreter::BytecodeInterpreterMetricLabels,
+        wasm_interpreter::WasmShadowEvaluation,
+    },
     task::wasm_worker::{WasmHeapDumpMode, WasmWorkerTask},
 };
-use reflex_graphql::{GraphQlOperation, GraphQlParserBuiltin, GraphQlSchema};
+use reflex_graphql::{
+    GraphQlErrorExtensions, GraphQlOperation, GraphQlParserBuiltin, GraphQlResultSizeLimit,
+    GraphQlSchema,
+};
 use reflex_handlers::utils::tls::{parse_ca_certs, rustls};
 use reflex_json::JsonValue;
 use reflex_runtime::{
-    task::RuntimeTask, AsyncExpression, AsyncExpressionFactory, AsyncHeapAllocator,
+    action::effect::EffectEmitAction, task::RuntimeTask,
+    utils::effect_schema::EffectSchemaDriftOptions, AsyncExpression, AsyncExpressionFactory,
+    AsyncHeapAllocator,
 };
 use reflex_scheduler::tokio::{
     TokioInbox, TokioSchedulerInstrumentation, TokioSchedulerLogger, TokioThreadPoolFactory,
@@ -54,12 +65,15 @@ use reflex_wasm::interpreter::WasmProgram;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    graphql_service,
+    admin_service, graphql_service,
     logger::SkipRedispatchedActionsLogger,
     server::{
         actor::{
             create_grpc_otlp_tracer, create_http_otlp_tracer,
-            graphql_server::{GraphQlServerOperationMetricLabels, GraphQlServerQueryLabel},
+            graphql_server::{
+                GraphQlServerLatencyBudgets, GraphQlServerOperationMetricLabels,
+                GraphQlServerQueryLabel,
+            },
             http_graphql_server::{
                 HttpGraphQlServerQueryMetricLabels, HttpGraphQlServerQueryTransform,
             },
@@ -88,6 +102,20 @@ pub use reflex_js::{
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct ReflexServerCliOptions {
     pub address: SocketAddr,
+    /// Operations to evaluate at startup and keep subscribed for the lifetime of the server
+    pub warmup_operations: Vec<GraphQlOperation>,
+    /// Admin endpoint used by remote REPL clients (disabled if not specified)
+    pub admin: Option<ReflexServerAdminOptions>,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ReflexServerAdminOptions {
+    /// Address on which to expose the admin endpoint
+    pub address: SocketAddr,
+    /// Bearer token that clients must provide in order to access the admin endpoint
+    pub token: String,
+    /// Allow the admin endpoint to execute mutations (the admin endpoint is read-only by default)
+    pub allow_mutations: bool,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -439,7 +467,15 @@ pub fn cli<
     async_tasks: TAsyncTasks,
     blocking_tasks: TBlockingTasks,
     effect_throttle: Option<Duration>,
+    effect_schema_drift: Option<EffectSchemaDriftOptions>,
+    float_format: FloatFormat,
+    error_extensions: GraphQlErrorExtensions,
+    result_size_limit: GraphQlResultSizeLimit,
+    sandbox_policies: SandboxPolicies,
+    latency_budgets: GraphQlServerLatencyBudgets,
     dump_heap_snapshot: Option<WasmHeapDumpMode>,
+    shadow_evaluation: Option<WasmShadowEvaluation>,
+    restored_effects: Option<EffectEmitAction<T>>,
 ) -> Result<impl Future<Output = Result<(), hyper::Error>>>
 where
     T: AsyncExpression + Expression<String = String> + Rewritable<T> + Reducible<T> + Applicable<T>,
@@ -470,7 +506,7 @@ where
     THttpMetricLabels: HttpGraphQlServerQueryMetricLabels + Send + 'static,
     TConnectionMetricLabels: WebSocketGraphQlServerConnectionMetricLabels + Send + 'static,
     TOperationMetricLabels: GraphQlServerOperationMetricLabels + Send + 'static,
-    TWorkerMetricLabels: BytecodeInterpreterMetricLabels + Send + 'static,
+    TWorkerMetricLabels: BytecodeInterpreterMetricLabels + Clone + Send + 'static,
     TTracer: Tracer + Send + 'static,
     TTracer::Span: Span + Send + Sync + 'static,
     TLogger: TokioSchedulerLogger<Action = TAction, Task = TTask> + Clone + Send + Sync + 'static,
@@ -536,22 +572,84 @@ where
         async_tasks,
         blocking_tasks,
         effect_throttle,
+        effect_schema_drift,
+        float_format,
+        error_extensions,
+        result_size_limit,
+        sandbox_policies,
+        latency_budgets,
         dump_heap_snapshot,
+        shadow_evaluation,
     )
     .map_err(|err| anyhow!(err))
     .context("Failed to initialize server")?;
+    let ReflexServerCliOptions {
+        address,
+        warmup_operations,
+        admin,
+    } = args;
+    let restore_task = restored_effects.map(|action| app.restore_effects(action));
+    let warmup_task = if warmup_operations.is_empty() {
+        None
+    } else {
+        Some(app.warm_up(warmup_operations))
+    };
+    if restore_task.is_some() || warmup_task.is_some() {
+        // Restored effect values are dispatched ahead of any warm-up operations to ensure that the warm-up queries are
+        // able to make use of them
+        tokio::spawn(async move {
+            if let Some(task) = restore_task {
+                task.await;
+            }
+            if let Some(task) = warmup_task {
+                task.await;
+            }
+        });
+    }
     let main_pid = app.main_pid();
     let runtime = Arc::new(app);
     let service = make_service_fn({
+        let runtime = Arc::clone(&runtime);
         move |_socket: &AddrStream| {
             let service = graphql_service(Arc::clone(&runtime), main_pid, instrumentation.clone());
             future::ready(Ok::<_, Infallible>(service))
         }
     });
-    let server = Server::try_bind(&args.address)
+    let server = Server::try_bind(&address)
         .with_context(|| "Failed to bind server address")?
         .serve(service);
-    Ok(server)
+    let admin_server = match admin {
+        None => None,
+        Some(ReflexServerAdminOptions {
+            address,
+            token,
+            allow_mutations,
+        }) => {
+            let token = Arc::<str>::from(token);
+            let service = make_service_fn({
+                let runtime = Arc::clone(&runtime);
+                move |_socket: &AddrStream| {
+                    let service = admin_service(
+                        Arc::clone(&runtime),
+                        main_pid,
+                        Arc::clone(&token),
+                        allow_mutations,
+                    );
+                    future::ready(Ok::<_, Infallible>(service))
+                }
+            });
+            let server = Server::try_bind(&address)
+                .with_context(|| "Failed to bind admin server address")?
+                .serve(service);
+            Some(server)
+        }
+    };
+    Ok(async move {
+        match admin_server {
+            None => server.await,
+            Some(admin_server) => future::try_join(server, admin_server).await.map(|_| ()),
+        }
+    })
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -25,6 +25,7 @@ use reflex_handlers::{
         graphql::{
             GraphQlHandlerHttpFetchTaskFactory, GraphQlHandlerWebSocketConnectionTaskFactory,
         },
+        plugin::PluginHandlerTaskFactory,
         timeout::TimeoutHandlerTaskFactory,
         timestamp::TimestampHandlerTaskFactory,
         DefaultHandlersTaskFactory,
@@ -956,6 +957,67 @@ where
     }
 }
 
+impl<
+        T,
+        TFactory,
+        TAllocator,
+        TConnect,
+        TReconnect,
+        TGrpcConfig,
+        TTransformHttp,
+        TTransformWs,
+        TGraphQlQueryLabel,
+        THttpMetricLabels,
+        TConnectionMetricLabels,
+        TWorkerMetricLabels,
+        TOperationMetricLabels,
+        TTracer,
+    > From<PluginHandlerTaskFactory>
+    for ServerCliTaskFactory<
+        T,
+        TFactory,
+        TAllocator,
+        TConnect,
+        TReconnect,
+        TGrpcConfig,
+        TTransformHttp,
+        TTransformWs,
+        TGraphQlQueryLabel,
+        THttpMetricLabels,
+        TConnectionMetricLabels,
+        TWorkerMetricLabels,
+        TOperationMetricLabels,
+        TTracer,
+    >
+where
+    T: AsyncExpression + Rewritable<T> + Reducible<T> + Applicable<T> + Compile<T>,
+    T::String: Send,
+    T::Builtin: Send,
+    T::Signal: Send,
+    T::SignalList: Send,
+    T::StructPrototype: Send,
+    T::ExpressionList: Send,
+    T::Builtin: HandlerActorBuiltin + GraphQlParserBuiltin + Into<reflex_wasm::stdlib::Stdlib>,
+    TFactory: AsyncExpressionFactory<T> + Default,
+    TAllocator: AsyncHeapAllocator<T> + Default,
+    TConnect: hyper::client::connect::Connect + Clone + Send + Sync + 'static,
+    TReconnect: ReconnectTimeout + Send + Clone + 'static,
+    TGrpcConfig: GrpcConfig + Send + 'static,
+    TTransformHttp: HttpGraphQlServerQueryTransform,
+    TTransformWs: WebSocketGraphQlServerQueryTransform,
+    TGraphQlQueryLabel: GraphQlServerQueryLabel,
+    THttpMetricLabels: HttpGraphQlServerQueryMetricLabels,
+    TConnectionMetricLabels: WebSocketGraphQlServerConnectionMetricLabels,
+    TOperationMetricLabels: GraphQlServerOperationMetricLabels,
+    TWorkerMetricLabels: BytecodeInterpreterMetricLabels,
+    TTracer: Tracer,
+    TTracer::Span: Send + Sync + 'static,
+{
+    fn from(value: PluginHandlerTaskFactory) -> Self {
+        Self::ServerTask(ServerTaskFactory::from(value))
+    }
+}
+
 impl<
         T,
         TFactory,
@@ -12,9 +12,15 @@ use actor::ServerActor;
 use http::StatusCode;
 use logger::ActionLogger;
 use opentelemetry::trace::Tracer;
-use reflex::core::{ExpressionFactory, HeapAllocator};
+use reflex::{
+    core::{ExpressionFactory, FloatFormat, HeapAllocator},
+    sandbox::SandboxPolicies,
+};
 use reflex_graphql::GraphQlParserBuiltin;
-use reflex_runtime::{actor::RuntimeMetricNames, runtime_actors, AsyncExpression};
+use reflex_runtime::{
+    actor::RuntimeMetricNames, migration::StateTokenAliases, runtime_actors,
+    utils::effect_schema::EffectSchemaDriftOptions, AsyncExpression,
+};
 use reflex_utils::FileWriterFormat;
 use server::{
     GraphQlServerOperationMetricLabels, GraphQlServerQueryLabel,
@@ -25,9 +31,9 @@ use utils::datetime::format_datetime_utc;
 use crate::server::{
     action::opentelemetry::OpenTelemetryMiddlewareErrorAction,
     ChainedHttpGraphQlServerQueryTransform, ChainedWebSocketGraphQlServerQueryTransform,
-    GraphQlServer, GraphQlServerMetricNames, HttpGraphQlServer, HttpGraphQlServerMetricNames,
-    HttpGraphQlServerQueryTransform, WebSocketGraphQlServer, WebSocketGraphQlServerMetricNames,
-    WebSocketGraphQlServerQueryTransform,
+    GraphQlServer, GraphQlServerLatencyBudgets, GraphQlServerMetricNames, HttpGraphQlServer,
+    HttpGraphQlServerMetricNames, HttpGraphQlServerQueryTransform, WebSocketGraphQlServer,
+    WebSocketGraphQlServerMetricNames, WebSocketGraphQlServerQueryTransform,
 };
 
 pub use ::bytes;
@@ -53,8 +59,8 @@ pub(crate) mod service;
 
 use reflex_dispatcher::{Action, ProcessId};
 use reflex_graphql::{
-    create_json_error_object, validate::ValidateQueryGraphQlTransform, GraphQlOperation,
-    GraphQlQueryTransform, GraphQlSchemaTypes,
+    create_json_error_object, validate::ValidateQueryGraphQlTransform, GraphQlErrorExtensions,
+    GraphQlOperation, GraphQlQueryTransform, GraphQlResultSizeLimit, GraphQlSchemaTypes,
 };
 use reflex_json::JsonValue;
 pub use service::*;
@@ -92,6 +98,12 @@ pub fn server_actors<
     transform_http: TTransformHttp,
     transform_ws: TTransformWs,
     effect_throttle: Option<Duration>,
+    effect_schema_drift: Option<EffectSchemaDriftOptions>,
+    float_format: FloatFormat,
+    error_extensions: GraphQlErrorExtensions,
+    result_size_limit: GraphQlResultSizeLimit,
+    sandbox_policies: SandboxPolicies,
+    latency_budgets: GraphQlServerLatencyBudgets,
     metric_names: ServerMetricNames,
     get_graphql_query_label: TGraphQlQueryLabel,
     get_http_query_metric_labels: THttpMetricLabels,
@@ -133,6 +145,9 @@ where
             factory.clone(),
             allocator.clone(),
             effect_throttle,
+            effect_schema_drift,
+            sandbox_policies,
+            StateTokenAliases::default(),
             metric_names.runtime,
             main_pid,
         )
@@ -146,6 +161,7 @@ where
             metric_names.graphql_server,
             get_graphql_query_label,
             get_operation_metric_labels,
+            latency_budgets,
             tracer,
             main_pid,
         )),
@@ -156,6 +172,9 @@ where
                 left: validate_query_transform.clone(),
                 right: transform_http,
             },
+            float_format,
+            error_extensions.clone(),
+            result_size_limit,
             metric_names.http_graphql_server,
             get_http_query_metric_labels,
             main_pid,
@@ -167,6 +186,9 @@ where
                 left: validate_query_transform,
                 right: transform_ws,
             },
+            float_format,
+            error_extensions,
+            result_size_limit,
             metric_names.websocket_graphql_server,
             get_websocket_connection_metric_labels,
             main_pid,
@@ -0,0 +1,131 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    borrow::Cow,
+    time::{Duration, SystemTime},
+};
+
+use opentelemetry::{
+    trace::{Span, Tracer},
+    Array, KeyValue, Value,
+};
+use reflex_recorder::audit::EffectAuditEvent;
+use reflex_utils::event::EventSink;
+
+pub const OPENTELEMETRY_AUDIT_SPAN_NAME: &str = "reflex::audit::effect";
+
+/// Event sink that exports effect audit records to an OpenTelemetry collector
+///
+/// The OpenTelemetry SDK version used by this crate does not yet support the OTLP logs signal, so each audit record is
+/// exported as a zero-duration span whose attributes contain the audit record fields.
+#[derive(Clone)]
+pub struct OpenTelemetryAuditEventSink<TTracer: Tracer> {
+    tracer: TTracer,
+}
+
+impl<TTracer: Tracer> OpenTelemetryAuditEventSink<TTracer> {
+    pub fn new(tracer: TTracer) -> Self {
+        Self { tracer }
+    }
+}
+
+impl<TTracer: Tracer> EventSink for OpenTelemetryAuditEventSink<TTracer> {
+    type Event = EffectAuditEvent;
+    fn emit(&mut self, event: &Self::Event) {
+        let EffectAuditEvent {
+            effect_type,
+            payload_hash,
+            queries,
+            timestamp,
+            outcome,
+        } = event;
+        let timestamp = SystemTime::UNIX_EPOCH + Duration::from_millis(*timestamp);
+        let mut span = self
+            .tracer
+            .span_builder(OPENTELEMETRY_AUDIT_SPAN_NAME)
+            .with_attributes(vec![
+                KeyValue::new("effect.type", effect_type.clone()),
+                KeyValue::new("effect.payload_hash", payload_hash.clone()),
+                KeyValue::new("effect.outcome", outcome.as_str()),
+                KeyValue::new(
+                    "effect.queries",
+                    Value::Array(Array::String(
+                        queries.iter().cloned().map(Cow::Owned).collect::<Vec<_>>(),
+                    )),
+                ),
+            ])
+            .with_start_time(timestamp)
+            .start(&self.tracer);
+        span.end_with_timestamp(timestamp);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use opentelemetry::{
+        sdk::{
+            export::trace::SpanData,
+            trace::{Span as SdkSpan, SpanProcessor, TracerProvider},
+        },
+        trace::{TraceResult, TracerProvider as _},
+        Context, Key,
+    };
+    use reflex_recorder::audit::EffectAuditOutcome;
+
+    use super::*;
+
+    #[derive(Clone, Default, Debug)]
+    struct CollectSpanProcessor(Arc<Mutex<Vec<SpanData>>>);
+    impl SpanProcessor for CollectSpanProcessor {
+        fn on_start(&self, _span: &mut SdkSpan, _cx: &Context) {}
+        fn on_end(&self, span: SpanData) {
+            self.0.lock().unwrap().push(span)
+        }
+        fn force_flush(&self) -> TraceResult<()> {
+            Ok(())
+        }
+        fn shutdown(&mut self) -> TraceResult<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn opentelemetry_audit_spans() {
+        let processor = CollectSpanProcessor::default();
+        let provider = TracerProvider::builder()
+            .with_span_processor(processor.clone())
+            .build();
+        let mut sink = OpenTelemetryAuditEventSink::new(provider.tracer("reflex"));
+        sink.emit(&EffectAuditEvent {
+            effect_type: String::from("reflex::fetch"),
+            payload_hash: String::from("0123456789abcdef"),
+            queries: vec![String::from("foo"), String::from("bar")],
+            timestamp: 1000,
+            outcome: EffectAuditOutcome::Error,
+        });
+        let spans = processor.0.lock().unwrap();
+        assert_eq!(spans.len(), 1);
+        let span = &spans[0];
+        let timestamp = SystemTime::UNIX_EPOCH + Duration::from_millis(1000);
+        assert_eq!(span.name, OPENTELEMETRY_AUDIT_SPAN_NAME);
+        assert_eq!(span.start_time, timestamp);
+        assert_eq!(span.end_time, timestamp);
+        let attribute = |key: &'static str| span.attributes.get(&Key::new(key)).cloned();
+        assert_eq!(attribute("effect.type"), Some(Value::from("reflex::fetch")));
+        assert_eq!(
+            attribute("effect.payload_hash"),
+            Some(Value::from("0123456789abcdef"))
+        );
+        assert_eq!(attribute("effect.outcome"), Some(Value::from("error")));
+        assert_eq!(
+            attribute("effect.queries"),
+            Some(Value::Array(Array::String(vec![
+                Cow::Borrowed("foo"),
+                Cow::Borrowed("bar"),
+            ])))
+        );
+    }
+}
@@ -14,7 +14,8 @@ use reflex_handlers::action::graphql::{
 use reflex_macros::blanket_trait;
 use reflex_runtime::{
     action::effect::{
-        EffectEmitAction, EffectSubscribeAction, EffectThrottleEmitAction, EffectUnsubscribeAction,
+        EffectEmitAction, EffectSchemaDriftAction, EffectSubscribeAction, EffectThrottleEmitAction,
+        EffectUnsubscribeAction,
     },
     actor::evaluate_handler::is_evaluate_effect_type,
 };
@@ -45,6 +46,7 @@ blanket_trait!(
         + Matcher<EffectUnsubscribeAction<T>>
         + Matcher<EffectEmitAction<T>>
         + Matcher<EffectThrottleEmitAction>
+        + Matcher<EffectSchemaDriftAction<T>>
         + Matcher<GraphQlHandlerWebSocketConnectSuccessAction>
         + Matcher<GraphQlHandlerWebSocketConnectionErrorAction>
         + Matcher<GrpcHandlerConnectSuccessAction>
@@ -161,6 +163,8 @@ where
             Some(DefaultActionFormatWriter::EffectEmit(action))
         } else if let Option::<&EffectThrottleEmitAction>::Some(action) = message.match_type() {
             Some(DefaultActionFormatWriter::EffectThrottleEmit(action))
+        } else if let Option::<&EffectSchemaDriftAction<T>>::Some(action) = message.match_type() {
+            Some(DefaultActionFormatWriter::EffectSchemaDrift(action))
         } else {
             None
         }
@@ -186,6 +190,7 @@ where
     EffectUnsubscribe(&'a EffectUnsubscribeAction<T>),
     EffectEmit(&'a EffectEmitAction<T>),
     EffectThrottleEmit(&'a EffectThrottleEmitAction),
+    EffectSchemaDrift(&'a EffectSchemaDriftAction<T>),
 }
 impl<'a, T> LogWriter for DefaultActionFormatWriter<'a, T>
 where
@@ -208,6 +213,7 @@ where
             Self::EffectUnsubscribe(inner) => inner.write(f),
             Self::EffectEmit(inner) => inner.write(f),
             Self::EffectThrottleEmit(inner) => inner.write(f),
+            Self::EffectSchemaDrift(inner) => inner.write(f),
         }
     }
 }
@@ -363,3 +369,13 @@ impl LogWriter for EffectThrottleEmitAction {
         write!(f, "Emitting throttled effects")
     }
 }
+
+impl<T: Expression> LogWriter for EffectSchemaDriftAction<T> {
+    fn write(&self, f: &mut impl std::io::Write) -> std::io::Result<()> {
+        write!(f, "Effect result schema changed: {}", self.effect_type)?;
+        for drift in self.drift.iter() {
+            write!(f, "\n {}", drift)?;
+        }
+        Ok(())
+    }
+}
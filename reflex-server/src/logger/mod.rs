@@ -14,6 +14,7 @@ use reflex_scheduler::tokio::{
 use reflex_utils::event::EventSink;
 
 pub mod async_channel;
+pub mod audit;
 pub mod formatted;
 pub mod formatter;
 pub mod json;
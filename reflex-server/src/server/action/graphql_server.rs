@@ -7,10 +7,10 @@ use reflex::core::{Expression, Uuid};
 use reflex_dispatcher::{Action, Named, SerializableAction, SerializedAction};
 use reflex_graphql::{GraphQlOperation, GraphQlVariables};
 use reflex_json::JsonValue;
-use reflex_macros::Named;
+use reflex_macros::{ActionSchema, Named};
 use serde::{Deserialize, Serialize};
 
-#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub enum GraphQlServerActions<T: Expression> {
     Subscribe(GraphQlServerSubscribeAction<T>),
     Unsubscribe(GraphQlServerUnsubscribeAction<T>),
@@ -189,7 +189,7 @@ impl<'a, T: Expression> From<&'a GraphQlServerActions<T>>
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GraphQlServerSubscribeAction<T: Expression> {
     pub subscription_id: Uuid,
     pub operation: GraphQlOperation,
@@ -226,7 +226,7 @@ impl<T: Expression> SerializableAction for GraphQlServerSubscribeAction<T> {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GraphQlServerUnsubscribeAction<T: Expression> {
     pub subscription_id: Uuid,
     pub _expression: PhantomData<T>,
@@ -241,7 +241,7 @@ impl<T: Expression> SerializableAction for GraphQlServerUnsubscribeAction<T> {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GraphQlServerModifyAction<T: Expression> {
     pub subscription_id: Uuid,
     pub variables: GraphQlVariables,
@@ -260,7 +260,7 @@ impl<T: Expression> SerializableAction for GraphQlServerModifyAction<T> {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GraphQlServerParseSuccessAction<T: Expression> {
     pub subscription_id: Uuid,
     pub query: T,
@@ -278,7 +278,7 @@ impl<T: Expression> SerializableAction for GraphQlServerParseSuccessAction<T> {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GraphQlServerParseErrorAction<T: Expression> {
     pub subscription_id: Uuid,
     pub message: String,
@@ -317,7 +317,7 @@ impl<T: Expression> SerializableAction for GraphQlServerParseErrorAction<T> {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct GraphQlServerEmitAction<T: Expression> {
     pub subscription_id: Uuid,
     pub result: T,
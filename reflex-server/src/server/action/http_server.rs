@@ -6,7 +6,7 @@ use http::{Request, Response};
 use reflex::core::Uuid;
 use reflex_dispatcher::{Action, Named, SerializableAction, SerializedAction};
 use reflex_json::{JsonMap, JsonValue};
-use reflex_macros::Named;
+use reflex_macros::{ActionSchema, Named};
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -14,7 +14,7 @@ use crate::{
     utils::serialize::{SerializedRequest, SerializedResponse},
 };
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(ActionSchema, Clone, Debug, Serialize, Deserialize)]
 pub enum HttpServerActions {
     Request(HttpServerRequestAction),
     Response(HttpServerResponseAction),
@@ -81,7 +81,7 @@ impl<'a> From<&'a HttpServerActions> for Option<&'a HttpServerResponseAction> {
     }
 }
 
-#[derive(Named, Debug)]
+#[derive(Named, ActionSchema, Debug)]
 pub struct HttpServerRequestAction {
     pub request_id: Uuid,
     pub request: Request<Bytes>,
@@ -181,7 +181,7 @@ impl From<SerializedHttpServerRequestAction> for HttpServerRequestAction {
     }
 }
 
-#[derive(Named, Debug)]
+#[derive(Named, ActionSchema, Debug)]
 pub struct HttpServerResponseAction {
     pub request_id: Uuid,
     pub response: Response<Bytes>,
@@ -5,12 +5,12 @@ use std::{net::SocketAddr, path::PathBuf, time::Duration};
 
 use reflex_dispatcher::{Action, Named, SerializableAction, SerializedAction};
 use reflex_json::{JsonMap, JsonValue};
-use reflex_macros::Named;
+use reflex_macros::{ActionSchema, Named};
 use serde::{Deserialize, Serialize};
 
 use crate::cli::reflex_server::OpenTelemetryConfig;
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(ActionSchema, Clone, Debug, Serialize, Deserialize)]
 pub enum InitActions {
     PrometheusMetrics(InitPrometheusMetricsAction),
     OpenTelemetry(InitOpenTelemetryAction),
@@ -152,7 +152,7 @@ impl<'a> From<&'a InitActions> for Option<&'a InitSessionRecordingAction> {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct InitPrometheusMetricsAction {
     pub address: SocketAddr,
 }
@@ -166,7 +166,7 @@ impl SerializableAction for InitPrometheusMetricsAction {
     }
 }
 
-#[derive(Named, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, Clone, Debug, Serialize, Deserialize)]
 pub struct InitOpenTelemetryAction {
     pub config: OpenTelemetryConfig,
 }
@@ -230,7 +230,7 @@ impl<'a> SerializableAction for InitOpenTelemetryAction {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct InitGraphRootAction {
     pub compiler_duration: Duration,
     pub instruction_count: usize,
@@ -248,7 +248,7 @@ impl SerializableAction for InitGraphRootAction {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct InitHttpServerAction {
     pub address: SocketAddr,
 }
@@ -262,7 +262,7 @@ impl SerializableAction for InitHttpServerAction {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct InitSessionRecordingAction {
     pub output_path: PathBuf,
 }
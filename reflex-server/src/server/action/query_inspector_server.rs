@@ -5,7 +5,7 @@ use bytes::Bytes;
 use http::{Request, Response};
 use reflex_dispatcher::{Action, Named, SerializableAction, SerializedAction};
 use reflex_json::{JsonMap, JsonValue};
-use reflex_macros::Named;
+use reflex_macros::{ActionSchema, Named};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -14,7 +14,7 @@ use crate::{
     utils::serialize::{SerializedRequest, SerializedResponse},
 };
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(ActionSchema, Clone, Debug, Serialize, Deserialize)]
 pub enum QueryInspectorServerActions {
     HttpRequest(QueryInspectorServerHttpRequestAction),
     HttpResponse(QueryInspectorServerHttpResponseAction),
@@ -85,7 +85,7 @@ impl<'a> From<&'a QueryInspectorServerActions>
     }
 }
 
-#[derive(Named, Debug)]
+#[derive(Named, ActionSchema, Debug)]
 pub struct QueryInspectorServerHttpRequestAction {
     pub request_id: Uuid,
     pub request: Request<Bytes>,
@@ -157,7 +157,7 @@ impl From<SerializedQueryInspectorServerHttpRequestAction>
     }
 }
 
-#[derive(Named, Debug)]
+#[derive(Named, ActionSchema, Debug)]
 pub struct QueryInspectorServerHttpResponseAction {
     pub request_id: Uuid,
     pub response: Response<Bytes>,
@@ -5,7 +5,7 @@ use bytes::Bytes;
 use http::{Request, Response};
 use reflex_dispatcher::{Action, Named, SerializableAction, SerializedAction};
 use reflex_json::{JsonMap, JsonValue};
-use reflex_macros::Named;
+use reflex_macros::{ActionSchema, Named};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -14,7 +14,7 @@ use crate::{
     utils::serialize::{SerializedRequest, SerializedResponse},
 };
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(ActionSchema, Clone, Debug, Serialize, Deserialize)]
 pub enum SessionPlaybackServerActions {
     HttpRequest(SessionPlaybackServerHttpRequestAction),
     HttpResponse(SessionPlaybackServerHttpResponseAction),
@@ -85,7 +85,7 @@ impl<'a> From<&'a SessionPlaybackServerActions>
     }
 }
 
-#[derive(Named, Debug)]
+#[derive(Named, ActionSchema, Debug)]
 pub struct SessionPlaybackServerHttpRequestAction {
     pub request_id: Uuid,
     pub request: Request<Bytes>,
@@ -157,7 +157,7 @@ impl From<SerializedSessionPlaybackServerHttpRequestAction>
     }
 }
 
-#[derive(Named, Debug)]
+#[derive(Named, ActionSchema, Debug)]
 pub struct SessionPlaybackServerHttpResponseAction {
     pub request_id: Uuid,
     pub response: Response<Bytes>,
@@ -3,7 +3,7 @@
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 use reflex_dispatcher::{Action, Named, SerializableAction, SerializedAction};
 use reflex_json::{JsonMap, JsonValue};
-use reflex_macros::Named;
+use reflex_macros::{ActionSchema, Named};
 use serde::{Deserialize, Serialize};
 
 use crate::utils::traceparent::Traceparent;
@@ -16,7 +16,7 @@ pub struct TelemetryTransaction {
     pub attributes: Vec<(String, String)>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(ActionSchema, Clone, Debug, Serialize, Deserialize)]
 pub enum TelemetryMiddlewareActions {
     TransactionStart(TelemetryMiddlewareTransactionStartAction),
     TransactionEnd(TelemetryMiddlewareTransactionEndAction),
@@ -87,7 +87,7 @@ impl<'a> From<&'a TelemetryMiddlewareActions>
     }
 }
 
-#[derive(Named, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, Clone, Debug, Serialize, Deserialize)]
 pub struct TelemetryMiddlewareTransactionStartAction {
     pub transactions: Vec<TelemetryTransaction>,
 }
@@ -132,7 +132,7 @@ impl SerializableAction for TelemetryMiddlewareTransactionStartAction {
     }
 }
 
-#[derive(Named, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, Clone, Debug, Serialize, Deserialize)]
 pub struct TelemetryMiddlewareTransactionEndAction {
     pub transaction_ids: Vec<Traceparent>,
 }
@@ -8,12 +8,12 @@ use reflex_graphql::subscriptions::{
     GraphQlSubscriptionClientMessage, GraphQlSubscriptionServerMessage,
 };
 use reflex_json::{JsonMap, JsonValue};
-use reflex_macros::Named;
+use reflex_macros::{ActionSchema, Named};
 use serde::{Deserialize, Serialize};
 
 use crate::{server::utils::clone_http_request_wrapper, utils::serialize::SerializedRequest};
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(ActionSchema, Clone, Debug, Serialize, Deserialize)]
 pub enum WebSocketServerActions {
     Connect(WebSocketServerConnectAction),
     Receive(WebSocketServerReceiveAction),
@@ -153,7 +153,7 @@ impl<'a> From<&'a WebSocketServerActions> for Option<&'a WebSocketServerThrottle
     }
 }
 
-#[derive(Named, Debug)]
+#[derive(Named, ActionSchema, Debug)]
 pub struct WebSocketServerConnectAction {
     pub connection_id: Uuid,
     pub request: Request<()>,
@@ -221,7 +221,7 @@ impl From<SerializedWebSocketServerConnectAction> for WebSocketServerConnectActi
     }
 }
 
-#[derive(Named, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, Clone, Debug, Serialize, Deserialize)]
 pub struct WebSocketServerReceiveAction {
     pub connection_id: Uuid,
     pub message: GraphQlSubscriptionClientMessage,
@@ -239,7 +239,7 @@ impl SerializableAction for WebSocketServerReceiveAction {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct WebSocketServerSendAction {
     pub connection_id: Uuid,
     pub message: GraphQlSubscriptionServerMessage,
@@ -280,7 +280,7 @@ impl SerializableAction for WebSocketServerSendAction {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct WebSocketServerDisconnectAction {
     pub connection_id: Uuid,
 }
@@ -294,7 +294,7 @@ impl SerializableAction for WebSocketServerDisconnectAction {
     }
 }
 
-#[derive(Named, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Named, ActionSchema, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct WebSocketServerThrottleTimeoutAction {
     pub subscription_id: Uuid,
 }
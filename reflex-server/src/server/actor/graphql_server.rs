@@ -4,10 +4,10 @@
 // SPDX-FileContributor: Chris Campbell <c.campbell@mwam.com> https://github.com/c-campbell-mwam
 use std::borrow::Cow;
 use std::collections::hash_map::Entry;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::iter::empty;
 use std::ops::Deref;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 use std::{iter::once, marker::PhantomData, time::Instant};
 
 use metrics::{
@@ -29,7 +29,11 @@ use reflex_dispatcher::{
     Action, ActorEvents, HandlerContext, MessageData, NoopDisposeCallback, ProcessId,
     SchedulerCommand, SchedulerMode, SchedulerTransition, TaskFactory, TaskInbox,
 };
-use reflex_graphql::{graphql_variables_are_equal, GraphQlOperation, GraphQlParserBuiltin};
+use reflex_graphql::{
+    ast::{common::Value as GraphQlValue, query::OperationDefinition},
+    graphql_variables_are_equal, parse_graphql_root_operation, GraphQlOperation,
+    GraphQlParserBuiltin,
+};
 use reflex_handlers::actor::loader::is_loader_effect_type;
 use reflex_handlers::actor::scan::is_scan_effect_type;
 use reflex_handlers::actor::timeout::is_timeout_effect_type;
@@ -65,6 +69,9 @@ pub struct GraphQlServerMetricNames {
     pub graphql_active_query_success_count: &'static str,
     pub graphql_active_query_pending_count: &'static str,
     pub graphql_active_query_error_count: &'static str,
+    pub graphql_latency_budget_met_count: &'static str,
+    pub graphql_latency_budget_exceeded_count: &'static str,
+    pub graphql_latency_budget_burn_rate: &'static str,
 }
 impl GraphQlServerMetricNames {
     fn init(self) -> Self {
@@ -108,6 +115,21 @@ impl GraphQlServerMetricNames {
             Unit::Count,
             "Number of active GraphQL queries that have yet to return results"
         );
+        describe_counter!(
+            self.graphql_latency_budget_met_count,
+            Unit::Count,
+            "Total number of GraphQL results emitted within the operation latency budget"
+        );
+        describe_counter!(
+            self.graphql_latency_budget_exceeded_count,
+            Unit::Count,
+            "Total number of GraphQL results that exceeded the operation latency budget"
+        );
+        describe_gauge!(
+            self.graphql_latency_budget_burn_rate,
+            Unit::Count,
+            "Rate at which the operation latency SLO error budget is being consumed (1.0 exhausts the error budget exactly at the end of the SLO window)"
+        );
         self
     }
 }
@@ -122,10 +144,114 @@ impl Default for GraphQlServerMetricNames {
             graphql_active_query_success_count: "graphql_active_query_success_count",
             graphql_active_query_pending_count: "graphql_active_query_pending_count",
             graphql_active_query_error_count: "graphql_active_query_error_count",
+            graphql_latency_budget_met_count: "graphql_latency_budget_met_count",
+            graphql_latency_budget_exceeded_count: "graphql_latency_budget_exceeded_count",
+            graphql_latency_budget_burn_rate: "graphql_latency_budget_burn_rate",
         }
     }
 }
 
+/// Name of the GraphQL operation directive used to declare a latency budget for an individual operation
+/// (e.g. `query Foo @latencyBudget(ms: 250) { ... }`)
+pub const GRAPHQL_LATENCY_BUDGET_DIRECTIVE: &str = "latencyBudget";
+
+/// Latency budgets within which GraphQL operations are expected to emit results
+///
+/// Each emitted result is timed from the point at which the operation was subscribed (for the initial result) or the
+/// point at which one of its effect dependencies emitted an updated value (for subsequent results), and is recorded
+/// against the operation metric labels as either meeting or exceeding the operation's latency budget.
+#[derive(Clone, Debug)]
+pub struct GraphQlServerLatencyBudgets {
+    /// Latency budget applied to operations that do not declare their own budget
+    pub default_budget: Option<Duration>,
+    /// Latency budgets keyed by GraphQL operation name (budgets declared via the operation directive take precedence)
+    pub operation_budgets: HashMap<String, Duration>,
+    /// Target proportion of results that are expected to meet their latency budget (between 0.0 and 1.0, exclusive)
+    pub slo_target: f64,
+    /// Rolling window over which the SLO burn rate is calculated
+    pub burn_rate_window: Duration,
+}
+impl Default for GraphQlServerLatencyBudgets {
+    fn default() -> Self {
+        Self {
+            default_budget: None,
+            operation_budgets: Default::default(),
+            slo_target: 0.99,
+            burn_rate_window: Duration::from_secs(5 * 60),
+        }
+    }
+}
+impl GraphQlServerLatencyBudgets {
+    pub fn budget(&self, operation: &GraphQlOperation) -> Option<Duration> {
+        parse_latency_budget_directive(operation)
+            .or_else(|| {
+                operation
+                    .operation_name()
+                    .and_then(|operation_name| self.operation_budgets.get(operation_name))
+                    .copied()
+            })
+            .or(self.default_budget)
+    }
+}
+
+fn parse_latency_budget_directive(operation: &GraphQlOperation) -> Option<Duration> {
+    let root_operation =
+        parse_graphql_root_operation(operation.query(), operation.operation_name()).ok()?;
+    let directives = match root_operation {
+        OperationDefinition::Query(query) => &query.directives,
+        OperationDefinition::Mutation(mutation) => &mutation.directives,
+        OperationDefinition::Subscription(subscription) => &subscription.directives,
+        OperationDefinition::SelectionSet(_) => return None,
+    };
+    let directive = directives
+        .iter()
+        .find(|directive| directive.name.as_str() == GRAPHQL_LATENCY_BUDGET_DIRECTIVE)?;
+    let (_, value) = directive
+        .arguments
+        .iter()
+        .find(|(key, _)| key.as_str() == "ms")?;
+    let millis = match value {
+        GraphQlValue::Int(value) => value.as_i64().and_then(|value| u64::try_from(value).ok()),
+        GraphQlValue::Variable(variable_name) => operation
+            .variable(variable_name)
+            .and_then(|value| value.as_u64()),
+        _ => None,
+    }?;
+    Some(Duration::from_millis(millis))
+}
+
+/// Rolling record of latency budget outcomes for a given set of operation metric labels
+#[derive(Default)]
+struct LatencyBudgetWindow {
+    samples: VecDeque<(Instant, bool)>,
+    num_exceeded: usize,
+}
+impl LatencyBudgetWindow {
+    fn record(&mut self, timestamp: Instant, is_within_budget: bool, window: Duration) {
+        self.samples.push_back((timestamp, is_within_budget));
+        if !is_within_budget {
+            self.num_exceeded += 1;
+        }
+        while let Some((sample_timestamp, sample_within_budget)) = self.samples.front().copied() {
+            if timestamp.duration_since(sample_timestamp) <= window {
+                break;
+            }
+            self.samples.pop_front();
+            if !sample_within_budget {
+                self.num_exceeded -= 1;
+            }
+        }
+    }
+    fn burn_rate(&self, slo_target: f64) -> f64 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        let error_rate = (self.num_exceeded as f64) / (self.samples.len() as f64);
+        let error_budget = 1.0 - slo_target;
+        error_rate / error_budget
+    }
+}
+
 #[derive(Default)]
 struct QueryStatusMetrics {
     success: usize,
@@ -207,6 +333,7 @@ where
     metric_names: GraphQlServerMetricNames,
     get_graphql_query_label: TQueryLabel,
     get_operation_metric_labels: TMetricLabels,
+    latency_budgets: GraphQlServerLatencyBudgets,
     tracer: TTracer,
     main_pid: ProcessId,
     _expression: PhantomData<T>,
@@ -229,6 +356,7 @@ where
         metric_names: GraphQlServerMetricNames,
         get_graphql_query_label: TQueryLabel,
         get_operation_metric_labels: TMetricLabels,
+        latency_budgets: GraphQlServerLatencyBudgets,
         tracer: TTracer,
         main_pid: ProcessId,
     ) -> Self {
@@ -238,6 +366,7 @@ where
             metric_names: metric_names.init(),
             get_graphql_query_label,
             get_operation_metric_labels,
+            latency_budgets,
             tracer,
             main_pid,
             _expression: Default::default(),
@@ -373,6 +502,8 @@ pub struct GraphQlServerState<T: Expression, TSpan: Span> {
     /// Mapping from query evaluate effect ID to query label
     // FIXME: remove
     subquery_label_mappings: IntMap<StateToken, String>,
+    /// Recent latency budget outcomes, keyed by operation metric labels
+    latency_budget_windows: HashMap<Vec<(String, String)>, LatencyBudgetWindow>,
 }
 impl<T: Expression, TSpan: Span> Default for GraphQlServerState<T, TSpan> {
     fn default() -> Self {
@@ -381,6 +512,7 @@ impl<T: Expression, TSpan: Span> Default for GraphQlServerState<T, TSpan> {
             subscription_operation_mappings: Default::default(),
             evaluate_effect_mappings: Default::default(),
             subquery_label_mappings: Default::default(),
+            latency_budget_windows: Default::default(),
         }
     }
 }
@@ -391,6 +523,10 @@ struct GraphQlOperationState<T: Expression, TSpan: Span> {
     operation_phase: Option<GraphQlOperationPhase>,
     metric_labels: Vec<(String, String)>,
     start_time: Option<Instant>,
+    /// Maximum expected duration between the operation being subscribed or invalidated and the result being emitted
+    latency_budget: Option<Duration>,
+    /// Time at which an effect dependency was first updated since the latest result was emitted
+    update_start_time: Option<Instant>,
     /// Latest emitted result
     result: Option<EvaluationResult<T>>,
     /// Effects this query currently depends on (determined by the most recent evaluation result), keyed by effect ID
@@ -909,6 +1045,7 @@ where
                 Entry::Vacant(entry) => {
                     let label = self.get_graphql_query_label.label(operation);
                     let metric_labels = self.get_operation_metric_labels.labels(operation);
+                    let latency_budget = self.latency_budgets.budget(operation);
                     let evaluate_effect = create_query_evaluate_effect(
                         label.clone(),
                         query.clone(),
@@ -934,6 +1071,9 @@ where
                         0,
                         &metric_labels
                     );
+                    if latency_budget.is_some() {
+                        self.init_latency_budget_metrics(&metric_labels);
+                    }
                     let operation_phase = GraphQlOperationPhase::Queued;
                     let trace = self.start_transaction(operation, &metric_labels, operation_phase);
                     let subscription_state = GraphQlSubscriptionState {
@@ -953,6 +1093,8 @@ where
                         operation_phase: Some(operation_phase),
                         metric_labels,
                         start_time: Some(Instant::now()),
+                        latency_budget,
+                        update_start_time: None,
                         result: None,
                         active_effects: Default::default(),
                         subscriptions: HashMap::from([(subscription_id, subscription_state)]),
@@ -1214,6 +1356,7 @@ where
                                 self.get_graphql_query_label.label(&updated_operation);
                             let metric_labels =
                                 self.get_operation_metric_labels.labels(&updated_operation);
+                            let latency_budget = self.latency_budgets.budget(&updated_operation);
                             let evaluate_effect = create_query_evaluate_effect(
                                 updated_label.clone(),
                                 updated_query.clone(),
@@ -1225,6 +1368,9 @@ where
                                 1.0,
                                 &metric_labels
                             );
+                            if latency_budget.is_some() {
+                                self.init_latency_budget_metrics(&metric_labels);
+                            }
                             state
                                 .evaluate_effect_mappings
                                 .insert(evaluate_effect.id(), updated_query.clone());
@@ -1255,6 +1401,8 @@ where
                                 operation_phase: Some(operation_phase),
                                 metric_labels,
                                 start_time: Some(Instant::now()),
+                                latency_budget,
+                                update_start_time: None,
                                 result: None,
                                 active_effects: Default::default(),
                                 subscriptions: HashMap::from([(
@@ -1306,6 +1454,26 @@ where
     {
         let QueryEmitAction { query, result } = action;
         let operation_state = state.operations.get_mut(&query.id())?;
+        let duration = operation_state
+            .start_time
+            .take()
+            .map(|start_time| start_time.elapsed());
+        let update_duration = operation_state
+            .update_start_time
+            .take()
+            .map(|start_time| start_time.elapsed());
+        if let (Some(latency_budget), Some(round_trip_duration)) =
+            (operation_state.latency_budget, duration.or(update_duration))
+        {
+            self.record_latency_budget_result(
+                state
+                    .latency_budget_windows
+                    .entry(operation_state.metric_labels.clone())
+                    .or_default(),
+                &operation_state.metric_labels,
+                round_trip_duration <= latency_budget,
+            );
+        }
         let previous_result = operation_state.result.replace(result.clone());
         let is_unchanged = previous_result
             .as_ref()
@@ -1314,10 +1482,6 @@ where
         if is_unchanged {
             return None;
         }
-        let duration = operation_state
-            .start_time
-            .take()
-            .map(|start_time| start_time.elapsed());
         if let Some(duration) = duration {
             histogram!(
                 self.metric_names.graphql_initial_response_duration,
@@ -1641,6 +1805,7 @@ where
         if filtered_effect_types.is_empty() {
             return None;
         }
+        let timestamp = Instant::now();
         for operation_state in state.operations.values_mut() {
             for effect_type in filtered_effect_types.iter().copied() {
                 for (effect, value) in effect_type.updates.iter() {
@@ -1649,6 +1814,11 @@ where
                         effect_state.status = EffectStatus::Emitted {
                             value: value.clone(),
                         };
+                        if operation_state.latency_budget.is_some()
+                            && operation_state.start_time.is_none()
+                        {
+                            operation_state.update_start_time.get_or_insert(timestamp);
+                        }
                         if let Some(state_token) = effect_state.active_span.take() {
                             let active_traces = {
                                 operation_state
@@ -1666,6 +1836,46 @@ where
         }
         None
     }
+    fn init_latency_budget_metrics(&self, metric_labels: &Vec<(String, String)>) {
+        counter!(
+            self.metric_names.graphql_latency_budget_met_count,
+            0,
+            metric_labels
+        );
+        counter!(
+            self.metric_names.graphql_latency_budget_exceeded_count,
+            0,
+            metric_labels
+        );
+    }
+    fn record_latency_budget_result(
+        &self,
+        window: &mut LatencyBudgetWindow,
+        metric_labels: &Vec<(String, String)>,
+        is_within_budget: bool,
+    ) {
+        if is_within_budget {
+            increment_counter!(
+                self.metric_names.graphql_latency_budget_met_count,
+                metric_labels
+            );
+        } else {
+            increment_counter!(
+                self.metric_names.graphql_latency_budget_exceeded_count,
+                metric_labels
+            );
+        }
+        window.record(
+            Instant::now(),
+            is_within_budget,
+            self.latency_budgets.burn_rate_window,
+        );
+        gauge!(
+            self.metric_names.graphql_latency_budget_burn_rate,
+            window.burn_rate(self.latency_budgets.slo_target),
+            metric_labels
+        );
+    }
     fn update_graphql_query_status_metrics<const NUM_DISPOSED: usize>(
         &self,
         state: &GraphQlServerState<T, TTracer::Span>,
@@ -2293,6 +2503,8 @@ mod tests {
                         metric_labels,
                         operation_phase: Default::default(),
                         start_time: None,
+                        latency_budget: None,
+                        update_start_time: None,
                         result,
                         active_effects: Default::default(),
                         subscriptions: [{
@@ -2329,6 +2541,8 @@ mod tests {
                         metric_labels,
                         operation_phase: Default::default(),
                         start_time: None,
+                        latency_budget: None,
+                        update_start_time: None,
                         result,
                         active_effects: Default::default(),
                         subscriptions: [{
@@ -2365,6 +2579,8 @@ mod tests {
                         metric_labels,
                         operation_phase: Default::default(),
                         start_time: None,
+                        latency_budget: None,
+                        update_start_time: None,
                         result,
                         active_effects: Default::default(),
                         subscriptions: [{
@@ -2609,6 +2825,90 @@ mod tests {
         assert_eq!(state.operations.len(), 0);
     }
 
+    #[test]
+    fn warmed_up_operation_remains_subscribed_after_client_unsubscription() {
+        type T = CachedSharedTerm<ServerBuiltins>;
+        type TFactory = SharedTermFactory<ServerBuiltins>;
+        type TAllocator = DefaultAllocator<T>;
+        type TConnect = hyper_rustls::HttpsConnector<hyper::client::HttpConnector>;
+        type TReconnect = NoopReconnectTimeout;
+        type TGrpcConfig = DefaultGrpcConfig;
+        type TTracer = NoopTracer;
+        type TAction = ServerCliAction<T>;
+        type TTask = ServerCliTaskFactory<
+            T,
+            TFactory,
+            TAllocator,
+            TConnect,
+            TReconnect,
+            TGrpcConfig,
+            NoopGraphQlQueryTransform,
+            NoopGraphQlQueryTransform,
+            GraphQlWebServerMetricLabels,
+            GraphQlWebServerMetricLabels,
+            GraphQlWebServerMetricLabels,
+            GraphQlWebServerMetricLabels,
+            GraphQlWebServerMetricLabels,
+            TTracer,
+        >;
+        let (_factory, _allocator, server) = harness(generate_metric_labels_for_operation);
+        let operation = graphql_operation_with_uuid(uuid!("5d4d2a0a-6f0e-4a43-9b0e-3f40c7f4f5a1"));
+        // warm-up subscription issued by the server at startup
+        let warmup_subscription_id = uuid!("1b8f6f0c-8d2e-4d6b-a0a4-5f0f1e0b3c2d");
+        // subsequent client subscription to the same operation
+        let client_subscription_id = uuid!("c6a1b7e4-2f3d-4e5a-9b8c-7d6e5f4a3b2c");
+        let mut state = GraphQlServerState::default();
+        for action in [
+            graphql_subscribe_action(warmup_subscription_id, operation.clone()),
+            graphql_subscribe_action(client_subscription_id, operation.clone()),
+        ] {
+            let _ = Handler::<TAction, SchedulerTransition<TAction, TTask>>::handle(
+                &server,
+                &mut state,
+                &action,
+                &message_data(),
+                &mut DummyContext,
+            );
+        }
+        // both subscriptions share the same warm query
+        assert_eq!(state.operations.len(), 1);
+        assert_eq!(
+            state
+                .operations
+                .values()
+                .next()
+                .unwrap()
+                .subscriptions
+                .keys()
+                .copied()
+                .collect::<HashSet<_>>(),
+            HashSet::from_iter([warmup_subscription_id, client_subscription_id])
+        );
+
+        let unsubscribe_action = graphql_unsubscribe_action(client_subscription_id);
+        let _ = Handler::<TAction, SchedulerTransition<TAction, TTask>>::handle(
+            &server,
+            &mut state,
+            &unsubscribe_action,
+            &message_data(),
+            &mut DummyContext,
+        );
+        // the warm-up subscription keeps the query active
+        assert_eq!(state.operations.len(), 1);
+        assert_eq!(
+            state
+                .operations
+                .values()
+                .next()
+                .unwrap()
+                .subscriptions
+                .keys()
+                .copied()
+                .collect::<HashSet<_>>(),
+            HashSet::from_iter([warmup_subscription_id])
+        );
+    }
+
     #[test]
     fn unsubscription_updates_metrics_for_subscriptions() {
         type T = CachedSharedTerm<ServerBuiltins>;
@@ -2729,6 +3029,8 @@ mod tests {
                     metric_labels,
                     operation_phase: Default::default(),
                     start_time: None,
+                    latency_budget: None,
+                    update_start_time: None,
                     result,
                     active_effects: Default::default(),
                     subscriptions: [{
@@ -2929,6 +3231,70 @@ mod tests {
         });
     }
 
+    #[test]
+    fn latency_budgets() {
+        let latency_budgets = GraphQlServerLatencyBudgets {
+            default_budget: Some(Duration::from_millis(1000)),
+            operation_budgets: HashMap::from([(String::from("Bar"), Duration::from_millis(500))]),
+            ..Default::default()
+        };
+        let create_operation = |query: &str, operation_name: &str, variables: GraphQlVariables| {
+            GraphQlOperation::new(
+                parse_graphql_query(query).unwrap(),
+                Some(String::from(operation_name)),
+                variables,
+                JsonMap::default(),
+            )
+        };
+        assert_eq!(
+            latency_budgets.budget(&create_operation(
+                "query Foo @latencyBudget(ms: 250) { foo }",
+                "Foo",
+                Default::default(),
+            )),
+            Some(Duration::from_millis(250)),
+        );
+        assert_eq!(
+            latency_budgets.budget(&create_operation(
+                "query Foo($budget: Int!) @latencyBudget(ms: $budget) { foo }",
+                "Foo",
+                JsonMap::from_iter([(String::from("budget"), JsonValue::from(100))]),
+            )),
+            Some(Duration::from_millis(100)),
+        );
+        assert_eq!(
+            latency_budgets.budget(&create_operation(
+                "query Bar { foo }",
+                "Bar",
+                Default::default(),
+            )),
+            Some(Duration::from_millis(500)),
+        );
+        assert_eq!(
+            latency_budgets.budget(&create_operation(
+                "query Baz { foo }",
+                "Baz",
+                Default::default(),
+            )),
+            Some(Duration::from_millis(1000)),
+        );
+    }
+
+    #[test]
+    fn latency_budget_burn_rate() {
+        let window_duration = Duration::from_secs(60);
+        let mut window = LatencyBudgetWindow::default();
+        assert_eq!(window.burn_rate(0.9), 0.0);
+        let start_time = Instant::now();
+        window.record(start_time, false, window_duration);
+        for _ in 0..4 {
+            window.record(start_time, true, window_duration);
+        }
+        assert!((window.burn_rate(0.9) - 2.0).abs() < 1e-9);
+        window.record(start_time + Duration::from_secs(61), true, window_duration);
+        assert_eq!(window.burn_rate(0.9), 0.0);
+    }
+
     fn error_metric_name(label: &str) -> String {
         format!("graphql_active_query_error_count{{{}}}", label)
     }
@@ -2967,6 +3333,7 @@ mod tests {
             metric_names,
             GraphQlWebServerMetricLabels,
             get_operation_metric_labels,
+            GraphQlServerLatencyBudgets::default(),
             NoopTracer::default(),
             ProcessId::default(),
         );
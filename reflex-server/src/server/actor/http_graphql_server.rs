@@ -18,7 +18,7 @@ use metrics::{
     decrement_gauge, describe_counter, describe_gauge, increment_counter, increment_gauge, Unit,
 };
 use reflex::{
-    core::{Expression, ExpressionFactory, Uuid},
+    core::{Expression, ExpressionFactory, FloatFormat, Uuid},
     hash::HashId,
 };
 use reflex_dispatcher::{
@@ -26,9 +26,11 @@ use reflex_dispatcher::{
     SchedulerCommand, SchedulerMode, SchedulerTransition, TaskFactory, TaskInbox,
 };
 use reflex_graphql::{
-    create_graphql_error_response, create_graphql_success_response, deserialize_graphql_operation,
-    serialize_graphql_result_payload, validate::validate_graphql_result, GraphQlOperation,
-    GraphQlOperationPayload, GraphQlQuery, GraphQlQueryTransform, GraphQlSchemaTypes,
+    create_graphql_error_response, create_graphql_truncated_success_response,
+    deserialize_graphql_operation, serialize_graphql_result_payload,
+    serialize_graphql_truncated_result_payload, validate::validate_graphql_result,
+    GraphQlErrorExtensions, GraphQlOperation, GraphQlOperationPayload, GraphQlQuery,
+    GraphQlQueryTransform, GraphQlResultSizeLimit, GraphQlSchemaTypes,
 };
 use reflex_json::JsonValue;
 use reflex_macros::{dispatcher, Named};
@@ -246,6 +248,9 @@ where
     schema_types: Option<GraphQlSchemaTypes<'static, String>>,
     factory: TFactory,
     transform: TTransform,
+    float_format: FloatFormat,
+    error_extensions: GraphQlErrorExtensions,
+    result_size_limit: GraphQlResultSizeLimit,
     metric_names: HttpGraphQlServerMetricNames,
     get_query_metric_labels: TQueryMetricLabels,
     main_pid: ProcessId,
@@ -263,6 +268,9 @@ where
         schema_types: Option<GraphQlSchemaTypes<'static, String>>,
         factory: TFactory,
         transform: TTransform,
+        float_format: FloatFormat,
+        error_extensions: GraphQlErrorExtensions,
+        result_size_limit: GraphQlResultSizeLimit,
         metric_names: HttpGraphQlServerMetricNames,
         get_query_metric_labels: TQueryMetricLabels,
         main_pid: ProcessId,
@@ -271,6 +279,9 @@ where
             schema_types,
             factory,
             transform,
+            float_format,
+            error_extensions,
+            result_size_limit,
             metric_names: metric_names.init(),
             get_query_metric_labels,
             main_pid,
@@ -543,18 +554,27 @@ where
                 create_http_response(StatusCode::NOT_MODIFIED, None, None)
             }
             _ => {
-                let payload =
-                    serialize_graphql_result_payload(result, &self.factory).and_then(|payload| {
-                        let query = query.as_ref();
-                        let schema_types = self.schema_types.as_ref();
-                        match (query, schema_types) {
-                            (Some(query), Some(schema_types)) => {
-                                validate_graphql_result(&payload, query, schema_types)
-                                    .map(|_| payload)
-                            }
-                            _ => Ok(payload),
-                        }
-                    });
+                let payload = match (query.as_ref(), self.schema_types.as_ref()) {
+                    // Schema validation requires the complete result payload, so validated results are truncated
+                    // after validation rather than during serialization
+                    (Some(query), Some(schema_types)) => serialize_graphql_result_payload(
+                        result,
+                        self.float_format,
+                        &self.error_extensions,
+                        &self.factory,
+                    )
+                    .and_then(|payload| {
+                        validate_graphql_result(&payload, query, schema_types).map(|_| payload)
+                    })
+                    .map(|payload| self.result_size_limit.apply(payload)),
+                    _ => serialize_graphql_truncated_result_payload(
+                        result,
+                        self.float_format,
+                        &self.error_extensions,
+                        &self.result_size_limit,
+                        &self.factory,
+                    ),
+                };
                 create_json_http_response(
                     StatusCode::OK,
                     create_etag_header(&response_etag)
@@ -569,7 +589,9 @@ where
                             .into(),
                         )),
                     &match payload {
-                        Ok(payload) => create_graphql_success_response(payload),
+                        Ok((payload, truncation_warning)) => {
+                            create_graphql_truncated_success_response(payload, truncation_warning)
+                        }
                         Err(errors) => create_graphql_error_response(errors),
                     },
                 )
@@ -11,24 +11,26 @@ use std::{
 
 use http::{HeaderMap, Request};
 use metrics::{decrement_gauge, describe_gauge, gauge, increment_gauge, Unit};
-use reflex::core::{Expression, ExpressionFactory, Uuid};
+use reflex::core::{Expression, ExpressionFactory, FloatFormat, Uuid};
 use reflex_dispatcher::{
     Action, ActorEvents, HandlerContext, MessageData, NoopDisposeCallback, ProcessId,
     SchedulerCommand, SchedulerMode, SchedulerTransition, TaskFactory, TaskInbox,
 };
 use reflex_graphql::{
-    create_graphql_error_response, create_graphql_success_response, create_json_error_object,
+    create_graphql_error_response, create_graphql_success_response,
+    create_graphql_truncated_success_response, create_json_error_object,
     parse_graphql_operation_type, parse_graphql_query, serialize_graphql_result_payload,
+    serialize_graphql_truncated_result_payload,
     subscriptions::{
         GraphQlSubscriptionClientMessage, GraphQlSubscriptionConnectionInitMessage,
         GraphQlSubscriptionServerMessage, GraphQlSubscriptionStartMessage,
         GraphQlSubscriptionStopMessage, GraphQlSubscriptionUpdateMessage, OperationId,
     },
     validate::validate_graphql_result,
-    GraphQlOperation, GraphQlOperationType, GraphQlQuery, GraphQlQueryTransform,
-    GraphQlSchemaTypes,
+    GraphQlErrorExtensions, GraphQlOperation, GraphQlOperationType, GraphQlQuery, GraphQlQueryTransform,
+    GraphQlResultSizeLimit, GraphQlSchemaTypes,
 };
-use reflex_json::{JsonNumber, JsonValue};
+use reflex_json::{format_json_floats, JsonNumber, JsonValue};
 use reflex_macros::{dispatcher, Named};
 use reflex_utils::json::json_object;
 
@@ -236,6 +238,9 @@ where
     schema_types: Option<GraphQlSchemaTypes<'static, String>>,
     factory: TFactory,
     transform: TTransform,
+    float_format: FloatFormat,
+    error_extensions: GraphQlErrorExtensions,
+    result_size_limit: GraphQlResultSizeLimit,
     metric_names: WebSocketGraphQlServerMetricNames,
     get_connection_metric_labels: TMetricLabels,
     main_pid: ProcessId,
@@ -253,6 +258,9 @@ where
         schema_types: Option<GraphQlSchemaTypes<'static, String>>,
         factory: TFactory,
         transform: TTransform,
+        float_format: FloatFormat,
+        error_extensions: GraphQlErrorExtensions,
+        result_size_limit: GraphQlResultSizeLimit,
         metric_names: WebSocketGraphQlServerMetricNames,
         get_connection_metric_labels: TMetricLabels,
         main_pid: ProcessId,
@@ -261,6 +269,9 @@ where
             schema_types,
             factory,
             transform,
+            float_format,
+            error_extensions,
+            result_size_limit,
             metric_names: metric_names.init(),
             get_connection_metric_labels,
             main_pid,
@@ -1065,6 +1076,9 @@ where
                     subscription.query.as_ref(),
                     self.schema_types.as_ref(),
                     subscription.diff_result.as_mut(),
+                    self.float_format,
+                    &self.error_extensions,
+                    &self.result_size_limit,
                     &self.factory,
                 )?;
                 Some(SchedulerTransition::new(once(SchedulerCommand::Send(
@@ -1136,6 +1150,9 @@ where
             subscription.query.as_ref(),
             self.schema_types.as_ref(),
             subscription.diff_result.as_mut(),
+            self.float_format,
+            &self.error_extensions,
+            &self.result_size_limit,
             &self.factory,
         );
         let update_action = update_message.map(|message| {
@@ -1161,6 +1178,9 @@ fn get_subscription_result_payload<T: Expression>(
     query: Option<&GraphQlQuery>,
     schema_types: Option<&GraphQlSchemaTypes<'static, String>>,
     previous_result: Option<&mut Option<T>>,
+    float_format: FloatFormat,
+    error_extensions: &GraphQlErrorExtensions,
+    result_size_limit: &GraphQlResultSizeLimit,
     factory: &impl ExpressionFactory<T>,
 ) -> Option<GraphQlSubscriptionServerMessage> {
     let previous_result = if let Some(previous_result) = previous_result {
@@ -1168,6 +1188,10 @@ fn get_subscription_result_payload<T: Expression>(
     } else {
         None
     };
+    // Incremental patches are computed from the complete result rather than the truncated payload previously sent to
+    // the client, so diffing is disabled whenever a result size limit is in effect (each update is instead sent as a
+    // full result payload, truncated to fit within the size limit)
+    let previous_result = previous_result.filter(|_| result_size_limit.is_unlimited());
     if let Some(previous_result) = previous_result {
         match previous_result.patch(result) {
             Err(message) => Some(GraphQlSubscriptionServerMessage::Data(
@@ -1177,23 +1201,34 @@ fn get_subscription_result_payload<T: Expression>(
             Ok(None) => None,
             Ok(Some(patch)) => Some(GraphQlSubscriptionServerMessage::Patch(
                 operation_id.clone(),
-                create_graphql_success_response(patch),
+                create_graphql_success_response(format_json_floats(patch, float_format)),
             )),
         }
     } else {
-        let result_payload =
-            serialize_graphql_result_payload(result, factory).and_then(|payload| {
-                match (query, schema_types) {
-                    (Some(query), Some(schema_types)) => {
+        let result_payload = match (query, schema_types) {
+            // Schema validation requires the complete result payload, so validated results are truncated after
+            // validation rather than during serialization
+            (Some(query), Some(schema_types)) => {
+                serialize_graphql_result_payload(result, float_format, error_extensions, factory)
+                    .and_then(|payload| {
                         validate_graphql_result(&payload, query, schema_types).map(|_| payload)
-                    }
-                    _ => Ok(payload),
-                }
-            });
+                    })
+                    .map(|payload| result_size_limit.apply(payload))
+            }
+            _ => serialize_graphql_truncated_result_payload(
+                result,
+                float_format,
+                error_extensions,
+                result_size_limit,
+                factory,
+            ),
+        };
         Some(GraphQlSubscriptionServerMessage::Data(
             operation_id.clone(),
             match result_payload {
-                Ok(result) => create_graphql_success_response(result),
+                Ok((result, truncation_warning)) => {
+                    create_graphql_truncated_success_response(result, truncation_warning)
+                }
                 Err(errors) => create_graphql_error_response(errors),
             },
         ))
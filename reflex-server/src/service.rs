@@ -17,8 +17,12 @@ use hyper_tungstenite::{
     HyperWebsocket, WebSocketStream,
 };
 use opentelemetry::trace::{Span, Tracer};
-use reflex::core::{
-    Applicable, Expression, ExpressionFactory, HeapAllocator, Reducible, Rewritable,
+use reflex::{
+    core::{
+        Applicable, Expression, ExpressionFactory, FloatFormat, HeapAllocator, Reducible,
+        Rewritable,
+    },
+    sandbox::SandboxPolicies,
 };
 use reflex_dispatcher::{
     utils::take_until_final_item::TakeUntilFinalItem, Action, Actor, AsyncScheduler, Handler,
@@ -27,28 +31,33 @@ use reflex_dispatcher::{
 use reflex_engine::{
     actor::{
         bytecode_interpreter::{BytecodeInterpreterAction, BytecodeInterpreterMetricLabels},
-        wasm_interpreter::{WasmInterpreter, WasmInterpreterMetricNames},
+        wasm_interpreter::{WasmInterpreter, WasmInterpreterMetricNames, WasmShadowEvaluation},
     },
     task::wasm_worker::{WasmHeapDumpMode, WasmWorkerTask},
 };
 use reflex_graphql::{
-    create_json_error_object,
+    create_graphql_error_response, create_json_error_object, deserialize_graphql_operation,
+    parse_graphql_operation_type, parse_graphql_query,
     subscriptions::{
         deserialize_graphql_client_message, GraphQlSubscriptionClientMessage,
         GraphQlSubscriptionServerMessage,
     },
     validate::parse_graphql_schema_types,
-    GraphQlParserBuiltin, GraphQlSchema,
+    GraphQlErrorExtensions, GraphQlOperation, GraphQlOperationType, GraphQlParserBuiltin,
+    GraphQlResultSizeLimit, GraphQlSchema,
 };
 use reflex_json::JsonValue;
 use reflex_macros::blanket_trait;
 use reflex_runtime::{
-    task::RuntimeTask, AsyncExpression, AsyncExpressionFactory, AsyncHeapAllocator,
+    action::effect::EffectEmitAction, task::RuntimeTask,
+    utils::effect_schema::EffectSchemaDriftOptions, AsyncExpression, AsyncExpressionFactory,
+    AsyncHeapAllocator,
 };
 use reflex_scheduler::tokio::{
     TokioInbox, TokioScheduler, TokioSchedulerBuilder, TokioSchedulerInstrumentation,
     TokioSchedulerLogger, TokioThreadPoolFactory,
 };
+use reflex_utils::json::json_object;
 use reflex_wasm::interpreter::WasmProgram;
 use uuid::Uuid;
 
@@ -57,6 +66,7 @@ use crate::{
     logger::SkipRedispatchedActionsLogger,
     server::{
         action::{
+            graphql_server::GraphQlServerSubscribeAction,
             http_server::{HttpServerRequestAction, HttpServerResponseAction},
             query_inspector_server::{
                 QueryInspectorServerHttpRequestAction, QueryInspectorServerHttpResponseAction,
@@ -79,7 +89,7 @@ use crate::{
             clone_http_request_wrapper, clone_http_response, clone_http_response_wrapper,
             create_http_response, create_json_http_response, get_cors_headers,
         },
-        GraphQlServerOperationMetricLabels, GraphQlServerQueryLabel,
+        GraphQlServerLatencyBudgets, GraphQlServerOperationMetricLabels, GraphQlServerQueryLabel,
         HttpGraphQlServerQueryMetricLabels, SessionPlaybackServerAction,
         WebSocketGraphQlServerConnectionMetricLabels,
     },
@@ -488,7 +498,14 @@ where
         async_tasks: TAsyncTasks,
         blocking_tasks: TBlockingTasks,
         effect_throttle: Option<Duration>,
+        effect_schema_drift: Option<EffectSchemaDriftOptions>,
+        float_format: FloatFormat,
+        error_extensions: GraphQlErrorExtensions,
+        result_size_limit: GraphQlResultSizeLimit,
+        sandbox_policies: SandboxPolicies,
+        latency_budgets: GraphQlServerLatencyBudgets,
         dump_heap_snapshot: Option<WasmHeapDumpMode>,
+        shadow_evaluation: Option<WasmShadowEvaluation>,
     ) -> Result<Self, String>
     where
         T: AsyncExpression + Rewritable<T> + Reducible<T> + Applicable<T>,
@@ -519,7 +536,7 @@ where
         THttpMetricLabels: HttpGraphQlServerQueryMetricLabels + Send + 'static,
         TConnectionMetricLabels: WebSocketGraphQlServerConnectionMetricLabels + Send + 'static,
         TOperationMetricLabels: GraphQlServerOperationMetricLabels + Send + 'static,
-        TWorkerMetricLabels: BytecodeInterpreterMetricLabels + Send + 'static,
+        TWorkerMetricLabels: BytecodeInterpreterMetricLabels + Clone + Send + 'static,
         TTracer: Tracer + Send + 'static,
         TTracer::Span: Span + Send + Sync + 'static,
         TLogger:
@@ -574,6 +591,12 @@ where
                     transform_http,
                     transform_ws,
                     effect_throttle,
+                    effect_schema_drift,
+                    float_format,
+                    error_extensions,
+                    result_size_limit,
+                    sandbox_policies,
+                    latency_budgets,
                     metric_names.server,
                     get_graphql_query_label,
                     get_http_query_metric_labels,
@@ -585,7 +608,22 @@ where
                 .into_iter()
                 .map(TTask::Actor::from)
             }
-            .chain(
+            .chain({
+                let graph_root_factory_export_name = graph_root_factory_export_name.into();
+                // Evaluate a sample of queries on the shadow module alongside the primary module
+                let shadow_interpreter = shadow_evaluation.map(|shadow_evaluation| {
+                    WasmInterpreter::new(
+                        shadow_evaluation.program,
+                        graph_root_factory_export_name.clone(),
+                        factory.clone(),
+                        allocator.clone(),
+                        metric_names.interpreter,
+                        get_worker_metric_labels.clone(),
+                        main_pid,
+                        None,
+                    )
+                    .with_shadow_mode(shadow_evaluation.sample_rate)
+                });
                 once(WasmInterpreter::new(
                     wasm_module,
                     graph_root_factory_export_name,
@@ -596,8 +634,9 @@ where
                     main_pid,
                     dump_heap_snapshot,
                 ))
-                .map(TTask::Actor::from),
-            )
+                .chain(shadow_interpreter)
+                .map(TTask::Actor::from)
+            })
             .map(|actor| (builder.generate_pid(), actor))
             .collect::<Vec<_>>();
             let workers = core_workers
@@ -642,6 +681,53 @@ where
     {
         handle_graphql_http_request(request, &self.runtime, self.main_pid)
     }
+    /// Subscribe to the provided operations on behalf of the server itself, ensuring that subsequent identical
+    /// operations are served from already-active query results and upstream effect subscriptions
+    pub fn warm_up<T: Expression>(
+        &self,
+        operations: impl IntoIterator<Item = GraphQlOperation>,
+    ) -> impl Future<Output = ()>
+    where
+        TAction: Action + From<GraphQlServerSubscribeAction<T>> + Send + Sync + 'static,
+        TTask: TaskFactory<TAction, TTask> + Send + 'static,
+        TTask::Actor: Send + Sync + 'static,
+        <TTask::Actor as Actor<TAction, TTask>>::Events<TokioInbox<TAction>>: Send + 'static,
+        <TTask::Actor as Actor<TAction, TTask>>::Dispose: Send + Sync + 'static,
+        <TTask::Actor as Handler<TAction, SchedulerTransition<TAction, TTask>>>::State:
+            Send + 'static,
+    {
+        let actions = operations
+            .into_iter()
+            .map(|operation| {
+                TAction::from(GraphQlServerSubscribeAction {
+                    subscription_id: Uuid::new_v4(),
+                    operation,
+                    _expression: Default::default(),
+                })
+            })
+            .collect::<Vec<_>>();
+        pipe_stream(stream::iter(actions), self.runtime.actions(self.main_pid))
+    }
+    /// Seed the runtime state cache with previously-captured effect values on behalf of the server itself, allowing
+    /// queries to be served from restored values while the underlying effects are resubscribed by their handlers
+    pub fn restore_effects<T: Expression>(
+        &self,
+        action: EffectEmitAction<T>,
+    ) -> impl Future<Output = ()>
+    where
+        TAction: Action + From<EffectEmitAction<T>> + Send + Sync + 'static,
+        TTask: TaskFactory<TAction, TTask> + Send + 'static,
+        TTask::Actor: Send + Sync + 'static,
+        <TTask::Actor as Actor<TAction, TTask>>::Events<TokioInbox<TAction>>: Send + 'static,
+        <TTask::Actor as Actor<TAction, TTask>>::Dispose: Send + Sync + 'static,
+        <TTask::Actor as Handler<TAction, SchedulerTransition<TAction, TTask>>>::State:
+            Send + 'static,
+    {
+        pipe_stream(
+            stream::iter(once(TAction::from(action))),
+            self.runtime.actions(self.main_pid),
+        )
+    }
     pub fn main_pid(&self) -> ProcessId {
         self.main_pid
     }
@@ -737,6 +823,67 @@ where
     })
 }
 
+/// Administrative endpoint used by remote REPL clients to interrogate the live graph root of a running server
+///
+/// The endpoint accepts GraphQL operations only: operations are evaluated by the main GraphQL server process against the
+/// live graph root, sharing the server's current effect state (arbitrary expression evaluation is not supported). Unless
+/// mutations are explicitly allowed, the endpoint is read-only and rejects any operations other than queries.
+///
+/// All requests must be authenticated with an `Authorization: Bearer <token>` header matching the provided admin token.
+/// No CORS headers are emitted, so the endpoint cannot be invoked from browser scripts hosted on other origins.
+pub fn admin_service<TAction>(
+    runtime: Arc<impl AsyncScheduler<Action = TAction> + Send + Sync + 'static>,
+    server_pid: ProcessId,
+    token: Arc<str>,
+    allow_mutations: bool,
+) -> impl Service<
+    Request<Body>,
+    Response = Response<Body>,
+    Error = Infallible,
+    Future = impl Future<Output = Result<Response<Body>, Infallible>> + Send,
+>
+where
+    TAction: Action
+        + Matcher<HttpServerResponseAction>
+        + From<HttpServerRequestAction>
+        + From<HttpServerResponseAction>
+        + Send
+        + Sync
+        + 'static,
+{
+    service_fn({
+        move |req: Request<Body>| {
+            let runtime = runtime.clone();
+            let token = token.clone();
+            async move {
+                if !is_authorized_admin_request(&req, &token) {
+                    return Ok(create_http_response(
+                        StatusCode::UNAUTHORIZED,
+                        once((header::WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"))),
+                        None,
+                    ));
+                }
+                let response = match req.method() {
+                    // Allow clients to determine the endpoint capabilities before submitting any operations
+                    &Method::GET => create_json_http_response(
+                        StatusCode::OK,
+                        None,
+                        &json_object([(
+                            String::from("readOnly"),
+                            JsonValue::Bool(!allow_mutations),
+                        )]),
+                    ),
+                    &Method::POST => {
+                        handle_admin_http_request(req, &*runtime, server_pid, allow_mutations).await
+                    }
+                    _ => method_not_allowed(req),
+                };
+                Ok(response)
+            }
+        }
+    })
+}
+
 pub fn query_inspector_service<TAction>(
     runtime: Arc<impl AsyncScheduler<Action = TAction> + Send + Sync>,
     server_pid: ProcessId,
@@ -845,6 +992,86 @@ where
     )
 }
 
+async fn handle_admin_http_request<TAction>(
+    request: Request<Body>,
+    runtime: &impl AsyncScheduler<Action = TAction>,
+    server_pid: ProcessId,
+    allow_mutations: bool,
+) -> Response<Body>
+where
+    TAction: Action
+        + Matcher<HttpServerResponseAction>
+        + From<HttpServerRequestAction>
+        + From<HttpServerResponseAction>
+        + Send
+        + Sync
+        + 'static,
+{
+    let (headers, body) = request.into_parts();
+    let body = match hyper::body::to_bytes(body).await {
+        Ok(body) => body,
+        Err(err) => {
+            return create_http_response(
+                StatusCode::BAD_REQUEST,
+                None,
+                Some(format!("Failed to parse incoming request: {}", err)),
+            )
+        }
+    };
+    if !allow_mutations {
+        if let Err(message) = validate_read_only_operation(&body) {
+            return create_json_http_response(
+                StatusCode::FORBIDDEN,
+                None,
+                &create_graphql_error_response(once(create_json_error_object(message, None))),
+            );
+        }
+    }
+    handle_graphql_http_request(
+        Request::from_parts(headers, Body::from(body)),
+        runtime,
+        server_pid,
+    )
+    .await
+}
+
+fn is_authorized_admin_request<T>(request: &Request<T>, token: &str) -> bool {
+    request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(|value| constant_time_eq(value.trim().as_bytes(), token.as_bytes()))
+        .unwrap_or(false)
+}
+
+/// Compare two byte strings without short-circuiting on the first mismatched byte, to avoid leaking timing information
+fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    left.len() == right.len()
+        && left
+            .iter()
+            .zip(right.iter())
+            .fold(0, |result, (left, right)| result | (left ^ right))
+            == 0
+}
+
+fn validate_read_only_operation(body: &[u8]) -> Result<(), String> {
+    let operation = std::str::from_utf8(body)
+        .map_err(|err| format!("Invalid request body: {}", err))
+        .and_then(deserialize_graphql_operation)?;
+    let query = parse_graphql_query(&operation.query)
+        .map_err(|err| format!("Invalid GraphQL query: {}", err))?;
+    match parse_graphql_operation_type(&query, operation.operation_name.as_deref())
+        .map_err(|err| format!("{}", err))?
+    {
+        GraphQlOperationType::Query => Ok(()),
+        operation_type => Err(format!(
+            "Admin endpoint is read-only: {} operations are not permitted",
+            operation_type
+        )),
+    }
+}
+
 fn handle_graphql_websocket_request<TAction>(
     request: Request<Body>,
     runtime: &impl AsyncScheduler<Action = TAction>,
@@ -1158,3 +1385,38 @@ fn pipe_stream<T>(
 fn ignore_stream_results<T, V>(stream: impl Stream<Item = T>) -> impl Stream<Item = V> {
     stream.flat_map(|_| stream::empty())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn admin_request_authorization() {
+        fn request(authorization: Option<&str>) -> Request<Body> {
+            let builder = Request::builder().method(Method::GET).uri("/");
+            match authorization {
+                Some(value) => builder.header(header::AUTHORIZATION, value),
+                None => builder,
+            }
+            .body(Body::empty())
+            .unwrap()
+        }
+        assert!(is_authorized_admin_request(
+            &request(Some("Bearer secret")),
+            "secret"
+        ));
+        assert!(!is_authorized_admin_request(&request(None), "secret"));
+        assert!(!is_authorized_admin_request(
+            &request(Some("Bearer other")),
+            "secret"
+        ));
+        assert!(!is_authorized_admin_request(
+            &request(Some("Bearer secrets")),
+            "secret"
+        ));
+        assert!(!is_authorized_admin_request(
+            &request(Some("Basic secret")),
+            "secret"
+        ));
+    }
+}
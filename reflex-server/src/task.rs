@@ -8,6 +8,7 @@ use reflex_handlers::task::fetch::FetchHandlerTaskFactory;
 use reflex_handlers::task::graphql::{
     GraphQlHandlerHttpFetchTaskFactory, GraphQlHandlerWebSocketConnectionTaskFactory,
 };
+use reflex_handlers::task::plugin::PluginHandlerTaskFactory;
 use reflex_handlers::task::timeout::TimeoutHandlerTaskFactory;
 use reflex_handlers::task::timestamp::TimestampHandlerTaskFactory;
 use reflex_handlers::task::{
@@ -88,6 +89,15 @@ where
     }
 }
 
+impl<TConnect> From<PluginHandlerTaskFactory> for ServerTaskFactory<TConnect>
+where
+    TConnect: hyper::client::connect::Connect + Clone + Send + Sync + 'static,
+{
+    fn from(value: PluginHandlerTaskFactory) -> Self {
+        DefaultHandlersTaskFactory::from(value).into()
+    }
+}
+
 impl<TConnect> From<TimeoutHandlerTaskFactory> for ServerTaskFactory<TConnect>
 where
     TConnect: hyper::client::connect::Connect + Clone + Send + Sync + 'static,
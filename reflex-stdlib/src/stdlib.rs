@@ -7,9 +7,12 @@ use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
-use reflex::core::{
-    Applicable, Arity, Builtin, EvaluationCache, Expression, ExpressionFactory, HeapAllocator, Uid,
-    Uuid,
+use reflex::{
+    cache::NoopCache,
+    core::{
+        Applicable, Arity, Builtin, EvaluationCache, Expression, ExpressionFactory, HeapAllocator,
+        Uid, Uuid,
+    },
 };
 
 pub use abs::*;
@@ -26,11 +29,18 @@ pub use collect_record::*;
 pub use collect_signal::*;
 pub use collect_string::*;
 pub use contains::*;
+pub use date_add::*;
+pub use date_diff::*;
+pub use date_offset::*;
+pub use date_truncate::*;
+pub use date_with_offset::*;
 pub use divide::*;
+pub use duration::*;
 pub use effect::*;
 pub use ends_with::*;
 pub use eq::*;
 pub use equal::*;
+pub use eval::*;
 pub use filter::*;
 pub use flatten::*;
 pub use floor::*;
@@ -49,6 +59,8 @@ pub use lt::*;
 pub use lte::*;
 pub use map::*;
 pub use max::*;
+pub use memo::*;
+pub use memo_store::*;
 pub use merge::*;
 pub use min::*;
 pub use multiply::*;
@@ -57,7 +69,9 @@ pub use or::*;
 pub use pow::*;
 pub use push::*;
 pub use push_front::*;
+pub use quote::*;
 pub use r#if::*;
+pub use r#match::*;
 pub use raise::*;
 pub use remainder::*;
 pub use replace::*;
@@ -67,12 +81,17 @@ pub use resolve_hashmap::*;
 pub use resolve_hashset::*;
 pub use resolve_list::*;
 pub use resolve_record::*;
+pub use reveal::*;
 pub use round::*;
 pub use sequence::*;
 pub use slice::*;
+pub use sort::*;
+pub use sort_by::*;
+pub use sort_merge::*;
 pub use split::*;
 pub use starts_with::*;
 pub use subtract::*;
+pub use template::*;
 pub use unzip::*;
 pub use values::*;
 pub use zip::*;
@@ -91,11 +110,18 @@ mod collect_record;
 mod collect_signal;
 mod collect_string;
 mod contains;
+mod date_add;
+mod date_diff;
+mod date_offset;
+mod date_truncate;
+mod date_with_offset;
 mod divide;
+mod duration;
 mod effect;
 mod ends_with;
 mod eq;
 mod equal;
+mod eval;
 mod filter;
 mod flatten;
 mod floor;
@@ -114,7 +140,10 @@ mod length;
 mod lt;
 mod lte;
 mod map;
+mod r#match;
 mod max;
+mod memo;
+mod memo_store;
 mod merge;
 mod min;
 mod multiply;
@@ -123,6 +152,7 @@ mod or;
 mod pow;
 mod push;
 mod push_front;
+mod quote;
 mod raise;
 mod remainder;
 mod replace;
@@ -132,12 +162,17 @@ mod resolve_hashmap;
 mod resolve_hashset;
 mod resolve_list;
 mod resolve_record;
+mod reveal;
 mod round;
 mod sequence;
 mod slice;
+mod sort;
+mod sort_by;
+mod sort_merge;
 mod split;
 mod starts_with;
 mod subtract;
+mod template;
 mod unzip;
 mod values;
 mod zip;
@@ -152,9 +187,13 @@ pub trait StdlibBuiltin:
     + From<Get>
     + From<If>
     + From<Map>
+    + From<MemoStore>
     + From<ResolveDeep>
     + From<ResolveList>
     + From<Sequence>
+    + From<Sort>
+    + From<SortBy>
+    + From<SortMerge>
     + From<Unzip>
 {
 }
@@ -168,9 +207,13 @@ impl<T> StdlibBuiltin for T where
         + From<Get>
         + From<If>
         + From<Map>
+        + From<MemoStore>
         + From<ResolveDeep>
         + From<ResolveList>
         + From<Sequence>
+        + From<Sort>
+        + From<SortBy>
+        + From<SortMerge>
         + From<Unzip>
 {
 }
@@ -192,11 +235,18 @@ pub enum Stdlib {
     CollectSignal,
     CollectString,
     Contains,
+    DateAdd,
+    DateDiff,
+    DateOffset,
+    DateTruncate,
+    DateWithOffset,
     Divide,
+    Duration,
     Effect,
     EndsWith,
     Eq,
     Equal,
+    Eval,
     Filter,
     Flatten,
     Floor,
@@ -215,7 +265,10 @@ pub enum Stdlib {
     Lt,
     Lte,
     Map,
+    Match,
     Max,
+    Memo,
+    MemoStore,
     Merge,
     Min,
     Multiply,
@@ -224,6 +277,7 @@ pub enum Stdlib {
     Pow,
     Push,
     PushFront,
+    Quote,
     Raise,
     Remainder,
     Replace,
@@ -233,12 +287,17 @@ pub enum Stdlib {
     ResolveHashSet,
     ResolveRecord,
     ResolveList,
+    Reveal,
     Round,
     Sequence,
     Slice,
+    Sort,
+    SortBy,
+    SortMerge,
     Split,
     StartsWith,
     Subtract,
+    Template,
     Unzip,
     Values,
     Zip,
@@ -266,11 +325,18 @@ impl TryFrom<Uuid> for Stdlib {
             CollectSignal::UUID => Ok(Self::CollectSignal),
             CollectString::UUID => Ok(Self::CollectString),
             Contains::UUID => Ok(Self::Contains),
+            DateAdd::UUID => Ok(Self::DateAdd),
+            DateDiff::UUID => Ok(Self::DateDiff),
+            DateOffset::UUID => Ok(Self::DateOffset),
+            DateTruncate::UUID => Ok(Self::DateTruncate),
+            DateWithOffset::UUID => Ok(Self::DateWithOffset),
             Divide::UUID => Ok(Self::Divide),
+            Duration::UUID => Ok(Self::Duration),
             Effect::UUID => Ok(Self::Effect),
             EndsWith::UUID => Ok(Self::EndsWith),
             Eq::UUID => Ok(Self::Eq),
             Equal::UUID => Ok(Self::Equal),
+            Eval::UUID => Ok(Self::Eval),
             Filter::UUID => Ok(Self::Filter),
             Flatten::UUID => Ok(Self::Flatten),
             Floor::UUID => Ok(Self::Floor),
@@ -289,7 +355,10 @@ impl TryFrom<Uuid> for Stdlib {
             Lt::UUID => Ok(Self::Lt),
             Lte::UUID => Ok(Self::Lte),
             Map::UUID => Ok(Self::Map),
+            Match::UUID => Ok(Self::Match),
             Max::UUID => Ok(Self::Max),
+            Memo::UUID => Ok(Self::Memo),
+            MemoStore::UUID => Ok(Self::MemoStore),
             Merge::UUID => Ok(Self::Merge),
             Min::UUID => Ok(Self::Min),
             Multiply::UUID => Ok(Self::Multiply),
@@ -298,6 +367,7 @@ impl TryFrom<Uuid> for Stdlib {
             Pow::UUID => Ok(Self::Pow),
             Push::UUID => Ok(Self::Push),
             PushFront::UUID => Ok(Self::PushFront),
+            Quote::UUID => Ok(Self::Quote),
             Raise::UUID => Ok(Self::Raise),
             Remainder::UUID => Ok(Self::Remainder),
             Replace::UUID => Ok(Self::Replace),
@@ -307,12 +377,17 @@ impl TryFrom<Uuid> for Stdlib {
             ResolveHashSet::UUID => Ok(Self::ResolveHashSet),
             ResolveRecord::UUID => Ok(Self::ResolveRecord),
             ResolveList::UUID => Ok(Self::ResolveList),
+            Reveal::UUID => Ok(Self::Reveal),
             Round::UUID => Ok(Self::Round),
             Sequence::UUID => Ok(Self::Sequence),
             Slice::UUID => Ok(Self::Slice),
+            Sort::UUID => Ok(Self::Sort),
+            SortBy::UUID => Ok(Self::SortBy),
+            SortMerge::UUID => Ok(Self::SortMerge),
             Split::UUID => Ok(Self::Split),
             StartsWith::UUID => Ok(Self::StartsWith),
             Subtract::UUID => Ok(Self::Subtract),
+            Template::UUID => Ok(Self::Template),
             Unzip::UUID => Ok(Self::Unzip),
             Values::UUID => Ok(Self::Values),
             Zip::UUID => Ok(Self::Zip),
@@ -337,11 +412,18 @@ impl Uid for Stdlib {
             Self::CollectSignal => Uid::uid(&CollectSignal {}),
             Self::CollectString => Uid::uid(&CollectString {}),
             Self::Contains => Uid::uid(&Contains {}),
+            Self::DateAdd => Uid::uid(&DateAdd {}),
+            Self::DateDiff => Uid::uid(&DateDiff {}),
+            Self::DateOffset => Uid::uid(&DateOffset {}),
+            Self::DateTruncate => Uid::uid(&DateTruncate {}),
+            Self::DateWithOffset => Uid::uid(&DateWithOffset {}),
             Self::Divide => Uid::uid(&Divide {}),
+            Self::Duration => Uid::uid(&Duration {}),
             Self::Effect => Uid::uid(&Effect {}),
             Self::EndsWith => Uid::uid(&EndsWith {}),
             Self::Eq => Uid::uid(&Eq {}),
             Self::Equal => Uid::uid(&Equal {}),
+            Self::Eval => Uid::uid(&Eval {}),
             Self::Filter => Uid::uid(&Filter {}),
             Self::Flatten => Uid::uid(&Flatten {}),
             Self::Floor => Uid::uid(&Floor {}),
@@ -360,7 +442,10 @@ impl Uid for Stdlib {
             Self::Lt => Uid::uid(&Lt {}),
             Self::Lte => Uid::uid(&Lte {}),
             Self::Map => Uid::uid(&Map {}),
+            Self::Match => Uid::uid(&Match {}),
             Self::Max => Uid::uid(&Max {}),
+            Self::Memo => Uid::uid(&Memo {}),
+            Self::MemoStore => Uid::uid(&MemoStore {}),
             Self::Merge => Uid::uid(&Merge {}),
             Self::Min => Uid::uid(&Min {}),
             Self::Multiply => Uid::uid(&Multiply {}),
@@ -369,6 +454,7 @@ impl Uid for Stdlib {
             Self::Pow => Uid::uid(&Pow {}),
             Self::Push => Uid::uid(&Push {}),
             Self::PushFront => Uid::uid(&PushFront {}),
+            Self::Quote => Uid::uid(&Quote {}),
             Self::Raise => Uid::uid(&Raise {}),
             Self::Remainder => Uid::uid(&Remainder {}),
             Self::Replace => Uid::uid(&Replace {}),
@@ -378,12 +464,17 @@ impl Uid for Stdlib {
             Self::ResolveHashSet => Uid::uid(&ResolveHashSet {}),
             Self::ResolveRecord => Uid::uid(&ResolveRecord {}),
             Self::ResolveList => Uid::uid(&ResolveList {}),
+            Self::Reveal => Uid::uid(&Reveal {}),
             Self::Round => Uid::uid(&Round {}),
             Self::Sequence => Uid::uid(&Sequence {}),
             Self::Slice => Uid::uid(&Slice {}),
+            Self::Sort => Uid::uid(&Sort {}),
+            Self::SortBy => Uid::uid(&SortBy {}),
+            Self::SortMerge => Uid::uid(&SortMerge {}),
             Self::Split => Uid::uid(&Split {}),
             Self::StartsWith => Uid::uid(&StartsWith {}),
             Self::Subtract => Uid::uid(&Subtract {}),
+            Self::Template => Uid::uid(&Template {}),
             Self::Unzip => Uid::uid(&Unzip {}),
             Self::Values => Uid::uid(&Values {}),
             Self::Zip => Uid::uid(&Zip {}),
@@ -407,11 +498,18 @@ impl Stdlib {
             Self::CollectSignal => CollectSignal::arity(),
             Self::CollectString => CollectString::arity(),
             Self::Contains => Contains::arity(),
+            Self::DateAdd => DateAdd::arity(),
+            Self::DateDiff => DateDiff::arity(),
+            Self::DateOffset => DateOffset::arity(),
+            Self::DateTruncate => DateTruncate::arity(),
+            Self::DateWithOffset => DateWithOffset::arity(),
             Self::Divide => Divide::arity(),
+            Self::Duration => Duration::arity(),
             Self::Effect => Effect::arity(),
             Self::EndsWith => EndsWith::arity(),
             Self::Eq => Eq::arity(),
             Self::Equal => Equal::arity(),
+            Self::Eval => Eval::arity(),
             Self::Filter => Filter::arity(),
             Self::Flatten => Flatten::arity(),
             Self::Floor => Floor::arity(),
@@ -430,7 +528,10 @@ impl Stdlib {
             Self::Lt => Lt::arity(),
             Self::Lte => Lte::arity(),
             Self::Map => Map::arity(),
+            Self::Match => Match::arity(),
             Self::Max => Max::arity(),
+            Self::Memo => Memo::arity(),
+            Self::MemoStore => MemoStore::arity(),
             Self::Merge => Merge::arity(),
             Self::Min => Min::arity(),
             Self::Multiply => Multiply::arity(),
@@ -439,6 +540,7 @@ impl Stdlib {
             Self::Pow => Pow::arity(),
             Self::Push => Push::arity(),
             Self::PushFront => PushFront::arity(),
+            Self::Quote => Quote::arity(),
             Self::Raise => Raise::arity(),
             Self::Remainder => Remainder::arity(),
             Self::Replace => Replace::arity(),
@@ -448,12 +550,17 @@ impl Stdlib {
             Self::ResolveHashSet => ResolveHashSet::arity(),
             Self::ResolveRecord => ResolveRecord::arity(),
             Self::ResolveList => ResolveList::arity(),
+            Self::Reveal => Reveal::arity(),
             Self::Round => Round::arity(),
             Self::Sequence => Sequence::arity(),
             Self::Slice => Slice::arity(),
+            Self::Sort => Sort::arity(),
+            Self::SortBy => SortBy::arity(),
+            Self::SortMerge => SortMerge::arity(),
             Self::Split => Split::arity(),
             Self::StartsWith => StartsWith::arity(),
             Self::Subtract => Subtract::arity(),
+            Self::Template => Template::arity(),
             Self::Unzip => Unzip::arity(),
             Self::Values => Values::arity(),
             Self::Zip => Zip::arity(),
@@ -498,11 +605,24 @@ impl Stdlib {
                 Applicable::<T>::apply(&CollectString, args, factory, allocator, cache)
             }
             Self::Contains => Applicable::<T>::apply(&Contains, args, factory, allocator, cache),
+            Self::DateAdd => Applicable::<T>::apply(&DateAdd, args, factory, allocator, cache),
+            Self::DateDiff => Applicable::<T>::apply(&DateDiff, args, factory, allocator, cache),
+            Self::DateOffset => {
+                Applicable::<T>::apply(&DateOffset, args, factory, allocator, cache)
+            }
+            Self::DateTruncate => {
+                Applicable::<T>::apply(&DateTruncate, args, factory, allocator, cache)
+            }
+            Self::DateWithOffset => {
+                Applicable::<T>::apply(&DateWithOffset, args, factory, allocator, cache)
+            }
             Self::Divide => Applicable::<T>::apply(&Divide, args, factory, allocator, cache),
+            Self::Duration => Applicable::<T>::apply(&Duration, args, factory, allocator, cache),
             Self::Effect => Applicable::<T>::apply(&Effect, args, factory, allocator, cache),
             Self::EndsWith => Applicable::<T>::apply(&EndsWith, args, factory, allocator, cache),
             Self::Eq => Applicable::<T>::apply(&Eq, args, factory, allocator, cache),
             Self::Equal => Applicable::<T>::apply(&Equal, args, factory, allocator, cache),
+            Self::Eval => Applicable::<T>::apply(&Eval, args, factory, allocator, cache),
             Self::Filter => Applicable::<T>::apply(&Filter, args, factory, allocator, cache),
             Self::Flatten => Applicable::<T>::apply(&Flatten, args, factory, allocator, cache),
             Self::Floor => Applicable::<T>::apply(&Floor, args, factory, allocator, cache),
@@ -523,7 +643,10 @@ impl Stdlib {
             Self::Lt => Applicable::<T>::apply(&Lt, args, factory, allocator, cache),
             Self::Lte => Applicable::<T>::apply(&Lte, args, factory, allocator, cache),
             Self::Map => Applicable::<T>::apply(&Map, args, factory, allocator, cache),
+            Self::Match => Applicable::<T>::apply(&Match, args, factory, allocator, cache),
             Self::Max => Applicable::<T>::apply(&Max, args, factory, allocator, cache),
+            Self::Memo => Applicable::<T>::apply(&Memo, args, factory, allocator, cache),
+            Self::MemoStore => Applicable::<T>::apply(&MemoStore, args, factory, allocator, cache),
             Self::Merge => Applicable::<T>::apply(&Merge, args, factory, allocator, cache),
             Self::Min => Applicable::<T>::apply(&Min, args, factory, allocator, cache),
             Self::Multiply => Applicable::<T>::apply(&Multiply, args, factory, allocator, cache),
@@ -532,6 +655,7 @@ impl Stdlib {
             Self::Pow => Applicable::<T>::apply(&Pow, args, factory, allocator, cache),
             Self::Push => Applicable::<T>::apply(&Push, args, factory, allocator, cache),
             Self::PushFront => Applicable::<T>::apply(&PushFront, args, factory, allocator, cache),
+            Self::Quote => Applicable::<T>::apply(&Quote, args, factory, allocator, cache),
             Self::Raise => Applicable::<T>::apply(&Raise, args, factory, allocator, cache),
             Self::Remainder => Applicable::<T>::apply(&Remainder, args, factory, allocator, cache),
             Self::Replace => Applicable::<T>::apply(&Replace, args, factory, allocator, cache),
@@ -553,14 +677,19 @@ impl Stdlib {
             Self::ResolveList => {
                 Applicable::<T>::apply(&ResolveList, args, factory, allocator, cache)
             }
+            Self::Reveal => Applicable::<T>::apply(&Reveal, args, factory, allocator, cache),
             Self::Round => Applicable::<T>::apply(&Round, args, factory, allocator, cache),
             Self::Sequence => Applicable::<T>::apply(&Sequence, args, factory, allocator, cache),
             Self::Slice => Applicable::<T>::apply(&Slice, args, factory, allocator, cache),
+            Self::Sort => Applicable::<T>::apply(&Sort, args, factory, allocator, cache),
+            Self::SortBy => Applicable::<T>::apply(&SortBy, args, factory, allocator, cache),
+            Self::SortMerge => Applicable::<T>::apply(&SortMerge, args, factory, allocator, cache),
             Self::Split => Applicable::<T>::apply(&Split, args, factory, allocator, cache),
             Self::StartsWith => {
                 Applicable::<T>::apply(&StartsWith, args, factory, allocator, cache)
             }
             Self::Subtract => Applicable::<T>::apply(&Subtract, args, factory, allocator, cache),
+            Self::Template => Applicable::<T>::apply(&Template, args, factory, allocator, cache),
             Self::Unzip => Applicable::<T>::apply(&Unzip, args, factory, allocator, cache),
             Self::Values => Applicable::<T>::apply(&Values, args, factory, allocator, cache),
             Self::Zip => Applicable::<T>::apply(&Zip, args, factory, allocator, cache),
@@ -587,11 +716,18 @@ impl Stdlib {
             Self::CollectSignal => Applicable::<T>::should_parallelize(&CollectSignal, args),
             Self::CollectString => Applicable::<T>::should_parallelize(&CollectString, args),
             Self::Contains => Applicable::<T>::should_parallelize(&Contains, args),
+            Self::DateAdd => Applicable::<T>::should_parallelize(&DateAdd, args),
+            Self::DateDiff => Applicable::<T>::should_parallelize(&DateDiff, args),
+            Self::DateOffset => Applicable::<T>::should_parallelize(&DateOffset, args),
+            Self::DateTruncate => Applicable::<T>::should_parallelize(&DateTruncate, args),
+            Self::DateWithOffset => Applicable::<T>::should_parallelize(&DateWithOffset, args),
             Self::Divide => Applicable::<T>::should_parallelize(&Divide, args),
+            Self::Duration => Applicable::<T>::should_parallelize(&Duration, args),
             Self::Effect => Applicable::<T>::should_parallelize(&Effect, args),
             Self::EndsWith => Applicable::<T>::should_parallelize(&EndsWith, args),
             Self::Eq => Applicable::<T>::should_parallelize(&Eq, args),
             Self::Equal => Applicable::<T>::should_parallelize(&Equal, args),
+            Self::Eval => Applicable::<T>::should_parallelize(&Eval, args),
             Self::Filter => Applicable::<T>::should_parallelize(&Filter, args),
             Self::Flatten => Applicable::<T>::should_parallelize(&Flatten, args),
             Self::Floor => Applicable::<T>::should_parallelize(&Floor, args),
@@ -610,7 +746,10 @@ impl Stdlib {
             Self::Lt => Applicable::<T>::should_parallelize(&Lt, args),
             Self::Lte => Applicable::<T>::should_parallelize(&Lte, args),
             Self::Map => Applicable::<T>::should_parallelize(&Map, args),
+            Self::Match => Applicable::<T>::should_parallelize(&Match, args),
             Self::Max => Applicable::<T>::should_parallelize(&Max, args),
+            Self::Memo => Applicable::<T>::should_parallelize(&Memo, args),
+            Self::MemoStore => Applicable::<T>::should_parallelize(&MemoStore, args),
             Self::Merge => Applicable::<T>::should_parallelize(&Merge, args),
             Self::Min => Applicable::<T>::should_parallelize(&Min, args),
             Self::Multiply => Applicable::<T>::should_parallelize(&Multiply, args),
@@ -619,6 +758,7 @@ impl Stdlib {
             Self::Pow => Applicable::<T>::should_parallelize(&Pow, args),
             Self::Push => Applicable::<T>::should_parallelize(&Push, args),
             Self::PushFront => Applicable::<T>::should_parallelize(&PushFront, args),
+            Self::Quote => Applicable::<T>::should_parallelize(&Quote, args),
             Self::Raise => Applicable::<T>::should_parallelize(&Raise, args),
             Self::Remainder => Applicable::<T>::should_parallelize(&Remainder, args),
             Self::Replace => Applicable::<T>::should_parallelize(&Replace, args),
@@ -628,17 +768,58 @@ impl Stdlib {
             Self::ResolveHashSet => Applicable::<T>::should_parallelize(&ResolveHashSet, args),
             Self::ResolveRecord => Applicable::<T>::should_parallelize(&ResolveRecord, args),
             Self::ResolveList => Applicable::<T>::should_parallelize(&ResolveList, args),
+            Self::Reveal => Applicable::<T>::should_parallelize(&Reveal, args),
             Self::Round => Applicable::<T>::should_parallelize(&Round, args),
             Self::Sequence => Applicable::<T>::should_parallelize(&Sequence, args),
             Self::Slice => Applicable::<T>::should_parallelize(&Slice, args),
+            Self::Sort => Applicable::<T>::should_parallelize(&Sort, args),
+            Self::SortBy => Applicable::<T>::should_parallelize(&SortBy, args),
+            Self::SortMerge => Applicable::<T>::should_parallelize(&SortMerge, args),
             Self::Split => Applicable::<T>::should_parallelize(&Split, args),
             Self::StartsWith => Applicable::<T>::should_parallelize(&StartsWith, args),
             Self::Subtract => Applicable::<T>::should_parallelize(&Subtract, args),
+            Self::Template => Applicable::<T>::should_parallelize(&Template, args),
             Self::Unzip => Applicable::<T>::should_parallelize(&Unzip, args),
             Self::Values => Applicable::<T>::should_parallelize(&Values, args),
             Self::Zip => Applicable::<T>::should_parallelize(&Zip, args),
         }
     }
+    pub fn apply_static<T: Expression>(
+        &self,
+        args: &[T],
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+    ) -> Option<T> {
+        // Only builtins whose arguments are all required can be applied statically, to avoid having to resolve default
+        // values for optional arguments
+        let arity = self.arity();
+        if arity.optional().len() > 0
+            || arity.variadic().is_some()
+            || arity.required().len() != args.len()
+        {
+            return None;
+        }
+        // Conditional branches are passed through without being inspected, however all other arguments must be
+        // fully-evaluated values
+        let num_value_args = match self {
+            Self::If => 1,
+            _ => args.len(),
+        };
+        if !args.iter().take(num_value_args).all(|arg| arg.is_atomic()) {
+            return None;
+        }
+        let args = args.iter().cloned();
+        let cache = &mut NoopCache::default();
+        // Only cheap builtins whose result depends solely on the values of their arguments are applied statically
+        match self {
+            Self::Eq => Applicable::<T>::apply(&Eq, args, factory, allocator, cache).ok(),
+            Self::Equal => Applicable::<T>::apply(&Equal, args, factory, allocator, cache).ok(),
+            Self::If => Applicable::<T>::apply(&If, args, factory, allocator, cache).ok(),
+            Self::Match => Applicable::<T>::apply(&Match, args, factory, allocator, cache).ok(),
+            Self::Not => Applicable::<T>::apply(&Not, args, factory, allocator, cache).ok(),
+            _ => None,
+        }
+    }
 }
 impl Builtin for Stdlib {
     fn arity(&self) -> Arity {
@@ -656,6 +837,14 @@ impl Builtin for Stdlib {
     ) -> Result<T, String> {
         self.apply(args, factory, allocator, cache)
     }
+    fn apply_static<T: Expression<Builtin = Self>>(
+        &self,
+        args: &[T],
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+    ) -> Option<T> {
+        self.apply_static(args, factory, allocator)
+    }
 }
 impl std::fmt::Display for Stdlib {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -733,11 +922,41 @@ impl From<Contains> for Stdlib {
         Self::Contains
     }
 }
+impl From<DateAdd> for Stdlib {
+    fn from(_value: DateAdd) -> Self {
+        Self::DateAdd
+    }
+}
+impl From<DateDiff> for Stdlib {
+    fn from(_value: DateDiff) -> Self {
+        Self::DateDiff
+    }
+}
+impl From<DateOffset> for Stdlib {
+    fn from(_value: DateOffset) -> Self {
+        Self::DateOffset
+    }
+}
+impl From<DateTruncate> for Stdlib {
+    fn from(_value: DateTruncate) -> Self {
+        Self::DateTruncate
+    }
+}
+impl From<DateWithOffset> for Stdlib {
+    fn from(_value: DateWithOffset) -> Self {
+        Self::DateWithOffset
+    }
+}
 impl From<Divide> for Stdlib {
     fn from(_value: Divide) -> Self {
         Self::Divide
     }
 }
+impl From<Duration> for Stdlib {
+    fn from(_value: Duration) -> Self {
+        Self::Duration
+    }
+}
 impl From<Effect> for Stdlib {
     fn from(_value: Effect) -> Self {
         Self::Effect
@@ -758,6 +977,11 @@ impl From<Equal> for Stdlib {
         Self::Equal
     }
 }
+impl From<Eval> for Stdlib {
+    fn from(_value: Eval) -> Self {
+        Self::Eval
+    }
+}
 impl From<Filter> for Stdlib {
     fn from(_value: Filter) -> Self {
         Self::Filter
@@ -848,11 +1072,26 @@ impl From<Map> for Stdlib {
         Self::Map
     }
 }
+impl From<Match> for Stdlib {
+    fn from(_value: Match) -> Self {
+        Self::Match
+    }
+}
 impl From<Max> for Stdlib {
     fn from(_value: Max) -> Self {
         Self::Max
     }
 }
+impl From<Memo> for Stdlib {
+    fn from(_value: Memo) -> Self {
+        Self::Memo
+    }
+}
+impl From<MemoStore> for Stdlib {
+    fn from(_value: MemoStore) -> Self {
+        Self::MemoStore
+    }
+}
 impl From<Merge> for Stdlib {
     fn from(_value: Merge) -> Self {
         Self::Merge
@@ -893,6 +1132,11 @@ impl From<PushFront> for Stdlib {
         Self::PushFront
     }
 }
+impl From<Quote> for Stdlib {
+    fn from(_value: Quote) -> Self {
+        Self::Quote
+    }
+}
 impl From<Raise> for Stdlib {
     fn from(_value: Raise) -> Self {
         Self::Raise
@@ -938,6 +1182,11 @@ impl From<ResolveList> for Stdlib {
         Self::ResolveList
     }
 }
+impl From<Reveal> for Stdlib {
+    fn from(_value: Reveal) -> Self {
+        Self::Reveal
+    }
+}
 impl From<Round> for Stdlib {
     fn from(_value: Round) -> Self {
         Self::Round
@@ -953,6 +1202,21 @@ impl From<Slice> for Stdlib {
         Self::Slice
     }
 }
+impl From<Sort> for Stdlib {
+    fn from(_value: Sort) -> Self {
+        Self::Sort
+    }
+}
+impl From<SortBy> for Stdlib {
+    fn from(_value: SortBy) -> Self {
+        Self::SortBy
+    }
+}
+impl From<SortMerge> for Stdlib {
+    fn from(_value: SortMerge) -> Self {
+        Self::SortMerge
+    }
+}
 impl From<Split> for Stdlib {
     fn from(_value: Split) -> Self {
         Self::Split
@@ -968,6 +1232,11 @@ impl From<Subtract> for Stdlib {
         Self::Subtract
     }
 }
+impl From<Template> for Stdlib {
+    fn from(_value: Template) -> Self {
+        Self::Template
+    }
+}
 impl From<Unzip> for Stdlib {
     fn from(_value: Unzip) -> Self {
         Self::Unzip
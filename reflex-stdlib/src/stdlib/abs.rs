@@ -2,8 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 use reflex::core::{
-    uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
-    FloatTermType, FunctionArity, HeapAllocator, IntTermType, Uid, Uuid,
+    create_checked_int_term, uuid, Applicable, ArgType, Arity, EvaluationCache, Expression,
+    ExpressionFactory, FloatTermType, FunctionArity, HeapAllocator, IntTermType, Uid, Uuid,
 };
 
 pub struct Abs;
@@ -34,12 +34,16 @@ impl<T: Expression> Applicable<T> for Abs {
         &self,
         mut args: impl ExactSizeIterator<Item = T>,
         factory: &impl ExpressionFactory<T>,
-        _allocator: &impl HeapAllocator<T>,
+        allocator: &impl HeapAllocator<T>,
         _cache: &mut impl EvaluationCache<T>,
     ) -> Result<T, String> {
         let operand = args.next().unwrap();
         let result = if let Some(operand) = factory.match_int_term(&operand) {
-            Some(factory.create_int_term(operand.value().abs()))
+            Some(create_checked_int_term(
+                factory.numeric_semantics().abs_int(operand.value()),
+                factory,
+                allocator,
+            ))
         } else if let Some(operand) = factory.match_float_term(&operand) {
             Some(factory.create_float_term(operand.value().abs()))
         } else {
@@ -3,7 +3,7 @@
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 use reflex::core::{
     uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
-    ExpressionListType, FunctionArity, HeapAllocator, ListTermType, RefType, Uid, Uuid,
+    FunctionArity, HeapAllocator, ListTermType, Uid, Uuid,
 };
 
 pub struct Chain;
@@ -43,19 +43,7 @@ impl<T: Expression> Applicable<T> for Chain {
             factory.match_list_term(&left),
             factory.match_list_term(&right),
         ) {
-            let left_items = left.items();
-            let right_items = right.items();
-            let left_items = left_items.as_deref();
-            let right_items = right_items.as_deref();
-            Ok(factory.create_list_term(
-                allocator.create_sized_list(
-                    left_items.len() + right_items.len(),
-                    left_items
-                        .iter()
-                        .chain(right_items.iter())
-                        .map(|item| item.as_deref().clone()),
-                ),
-            ))
+            Ok(factory.create_list_term(allocator.concat_lists(left.items(), right.items())))
         } else {
             Err(format!(
                 "Expected (List, List), received ({}, {})",
@@ -0,0 +1,63 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{
+    uuid, Applicable, ArgType, Arity, DurationTermType, EvaluationCache, Expression,
+    ExpressionFactory, FunctionArity, HeapAllocator, TimestampTermType, Uid, Uuid,
+};
+
+pub struct DateAdd;
+impl DateAdd {
+    pub const UUID: Uuid = uuid!("7688ec20-de77-47ef-8e34-40fc02e2e5ec");
+    const ARITY: FunctionArity<2, 0> = FunctionArity {
+        required: [ArgType::Strict, ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for DateAdd {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for DateAdd {
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        _allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let timestamp = args.next().unwrap();
+        let duration = args.next().unwrap();
+        match (
+            factory.match_timestamp_term(&timestamp),
+            factory.match_duration_term(&duration),
+        ) {
+            (Some(timestamp), Some(duration)) => factory
+                .numeric_semantics()
+                .add_int(timestamp.millis(), duration.millis())
+                .map(|millis| factory.create_timestamp_term(millis, timestamp.offset()))
+                .ok_or_else(|| {
+                    format!(
+                        "Timestamp overflow: {} + {}ms",
+                        timestamp.millis(),
+                        duration.millis()
+                    )
+                }),
+            _ => Err(format!(
+                "Expected (Timestamp, Duration), received ({}, {})",
+                timestamp, duration
+            )),
+        }
+    }
+}
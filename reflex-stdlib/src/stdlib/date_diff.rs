@@ -0,0 +1,59 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{
+    uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
+    FunctionArity, HeapAllocator, TimestampTermType, Uid, Uuid,
+};
+
+pub struct DateDiff;
+impl DateDiff {
+    pub const UUID: Uuid = uuid!("d5e384bf-dee5-44e0-8908-61a6921494af");
+    const ARITY: FunctionArity<2, 0> = FunctionArity {
+        required: [ArgType::Strict, ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for DateDiff {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for DateDiff {
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        _allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let left = args.next().unwrap();
+        let right = args.next().unwrap();
+        match (
+            factory.match_timestamp_term(&left),
+            factory.match_timestamp_term(&right),
+        ) {
+            (Some(left), Some(right)) => factory
+                .numeric_semantics()
+                .subtract_int(left.millis(), right.millis())
+                .map(|millis| factory.create_duration_term(millis))
+                .ok_or_else(|| {
+                    format!("Duration overflow: {} - {}", left.millis(), right.millis())
+                }),
+            _ => Err(format!(
+                "Expected (Timestamp, Timestamp), received ({}, {})",
+                left, right
+            )),
+        }
+    }
+}
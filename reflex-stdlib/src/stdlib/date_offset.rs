@@ -0,0 +1,59 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{
+    uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
+    FunctionArity, HeapAllocator, TimestampTermType, TimezoneOffset, Uid, Uuid,
+};
+
+pub struct DateOffset;
+impl DateOffset {
+    pub const UUID: Uuid = uuid!("e13501d7-d525-43cf-80e6-1e68cb21491d");
+    const ARITY: FunctionArity<1, 0> = FunctionArity {
+        required: [ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for DateOffset {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for DateOffset {
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let timestamp = args.next().unwrap();
+        match factory.match_timestamp_term(&timestamp) {
+            Some(timestamp) => Ok(factory.create_string_term(
+                allocator.create_string(format_utc_offset(timestamp.offset())),
+            )),
+            _ => Err(format!("Expected Timestamp, received {}", timestamp)),
+        }
+    }
+}
+
+/// Format a UTC offset as either `Z` (for zero offsets) or `±HH:MM`
+fn format_utc_offset(offset: TimezoneOffset) -> String {
+    if offset == 0 {
+        String::from("Z")
+    } else {
+        let sign = if offset < 0 { '-' } else { '+' };
+        let minutes = offset.unsigned_abs();
+        format!("{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+    }
+}
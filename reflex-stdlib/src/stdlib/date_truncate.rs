@@ -0,0 +1,145 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::ops::Deref;
+
+use reflex::core::{
+    uuid, Applicable, ArgType, Arity, DurationValue, EvaluationCache, Expression,
+    ExpressionFactory, FunctionArity, HeapAllocator, NumericSemantics, RefType, StringTermType,
+    StringValue, TimestampTermType, TimestampValue, Uid, Uuid,
+};
+
+const MILLIS_PER_MINUTE: DurationValue = 60 * 1000;
+const MILLIS_PER_HOUR: DurationValue = 60 * MILLIS_PER_MINUTE;
+const MILLIS_PER_DAY: DurationValue = 24 * MILLIS_PER_HOUR;
+
+pub struct DateTruncate;
+impl DateTruncate {
+    pub const UUID: Uuid = uuid!("7df51501-4deb-4312-adc9-d6f0f34e1340");
+    const ARITY: FunctionArity<3, 0> = FunctionArity {
+        required: [ArgType::Strict, ArgType::Strict, ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for DateTruncate {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for DateTruncate {
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        _allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let timestamp = args.next().unwrap();
+        let unit = args.next().unwrap();
+        let utc_offset = args.next().unwrap();
+        let (timestamp_millis, timestamp_offset, unit_millis, offset_millis) = match (
+            factory.match_timestamp_term(&timestamp),
+            factory.match_string_term(&unit),
+            factory.match_string_term(&utc_offset),
+        ) {
+            (Some(timestamp), Some(unit_term), Some(utc_offset_term)) => {
+                let unit_millis = parse_unit_millis(unit_term.value().as_deref().as_str().deref())
+                    .ok_or_else(|| {
+                        format!(
+                            "Invalid truncation unit: Expected \"day\" or \"hour\", received {}",
+                            unit
+                        )
+                    })?;
+                let offset_millis =
+                    parse_utc_offset_millis(utc_offset_term.value().as_deref().as_str().deref())
+                        .ok_or_else(|| {
+                            format!(
+                        "Invalid UTC offset: Expected \"Z\", \"UTC\" or \"±HH:MM\", received {}",
+                        utc_offset
+                    )
+                        })?;
+                Ok((
+                    timestamp.millis(),
+                    timestamp.offset(),
+                    unit_millis,
+                    offset_millis,
+                ))
+            }
+            _ => Err(format!(
+                "Expected (Timestamp, String, String), received ({}, {}, {})",
+                timestamp, unit, utc_offset
+            )),
+        }?;
+        truncate_timestamp(
+            timestamp_millis,
+            unit_millis,
+            offset_millis,
+            factory.numeric_semantics(),
+        )
+        .map(|millis| factory.create_timestamp_term(millis, timestamp_offset))
+        .ok_or_else(|| format!("Timestamp out of range: {}", timestamp))
+    }
+}
+
+fn parse_unit_millis(unit: &str) -> Option<DurationValue> {
+    match unit {
+        "day" => Some(MILLIS_PER_DAY),
+        "hour" => Some(MILLIS_PER_HOUR),
+        _ => None,
+    }
+}
+
+/// Parse a fixed UTC offset of the form `Z`, `UTC` or `±HH:MM`
+///
+/// Named timezones are not supported, so callers must supply the offset that is in effect at the given timestamp
+pub(crate) fn parse_utc_offset_millis(utc_offset: &str) -> Option<DurationValue> {
+    match utc_offset {
+        "Z" | "UTC" => Some(0),
+        _ => {
+            let (sign, offset) = match utc_offset.as_bytes().first()? {
+                b'+' => Some((1, &utc_offset[1..])),
+                b'-' => Some((-1, &utc_offset[1..])),
+                _ => None,
+            }?;
+            let (hours, minutes) = offset.split_once(':')?;
+            if hours.len() != 2 || minutes.len() != 2 {
+                return None;
+            }
+            let hours = parse_digits(hours).filter(|hours| *hours < 24)?;
+            let minutes = parse_digits(minutes).filter(|minutes| *minutes < 60)?;
+            Some(sign * (hours * MILLIS_PER_HOUR + minutes * MILLIS_PER_MINUTE))
+        }
+    }
+}
+
+fn parse_digits(value: &str) -> Option<DurationValue> {
+    value.bytes().try_fold(0, |result, char| {
+        if char.is_ascii_digit() {
+            Some(result * 10 + DurationValue::from(char - b'0'))
+        } else {
+            None
+        }
+    })
+}
+
+/// Truncate the timestamp to the start of the containing unit, as observed at the given fixed UTC offset
+fn truncate_timestamp(
+    timestamp: TimestampValue,
+    unit: DurationValue,
+    offset: DurationValue,
+    numeric_semantics: NumericSemantics,
+) -> Option<TimestampValue> {
+    let local = numeric_semantics.add_int(timestamp, offset)?;
+    let truncated = numeric_semantics.subtract_int(local, local.rem_euclid(unit))?;
+    numeric_semantics.subtract_int(truncated, offset)
+}
@@ -0,0 +1,73 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::ops::Deref;
+
+use reflex::core::{
+    uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
+    FunctionArity, HeapAllocator, RefType, StringTermType, StringValue, TimestampTermType,
+    TimezoneOffset, Uid, Uuid,
+};
+
+use crate::stdlib::date_truncate::parse_utc_offset_millis;
+
+pub struct DateWithOffset;
+impl DateWithOffset {
+    pub const UUID: Uuid = uuid!("a53333e1-6077-4698-835f-b4ba32c1ed98");
+    const ARITY: FunctionArity<2, 0> = FunctionArity {
+        required: [ArgType::Strict, ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for DateWithOffset {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for DateWithOffset {
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        _allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let timestamp = args.next().unwrap();
+        let utc_offset = args.next().unwrap();
+        match (
+            factory.match_timestamp_term(&timestamp),
+            factory.match_string_term(&utc_offset),
+        ) {
+            (Some(timestamp), Some(utc_offset_term)) => {
+                let offset_millis = parse_utc_offset_millis(
+                    utc_offset_term.value().as_deref().as_str().deref(),
+                )
+                .ok_or_else(|| {
+                    format!(
+                        "Invalid UTC offset: Expected \"Z\", \"UTC\" or \"±HH:MM\", received {}",
+                        utc_offset
+                    )
+                })?;
+                // The instant is left untouched: only the offset at which it is observed changes
+                Ok(factory.create_timestamp_term(
+                    timestamp.millis(),
+                    (offset_millis / (60 * 1000)) as TimezoneOffset,
+                ))
+            }
+            _ => Err(format!(
+                "Expected (Timestamp, String), received ({}, {})",
+                timestamp, utc_offset
+            )),
+        }
+    }
+}
@@ -2,8 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 use reflex::core::{
-    uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
-    FloatTermType, FunctionArity, HeapAllocator, IntTermType, Uid, Uuid,
+    create_checked_float_term, create_checked_int_term, uuid, Applicable, ArgType, Arity,
+    EvaluationCache, Expression, ExpressionFactory, FloatTermType, FunctionArity, HeapAllocator,
+    IntTermType, Uid, Uuid,
 };
 
 pub struct Divide;
@@ -34,11 +35,12 @@ impl<T: Expression> Applicable<T> for Divide {
         &self,
         mut args: impl ExactSizeIterator<Item = T>,
         factory: &impl ExpressionFactory<T>,
-        _allocator: &impl HeapAllocator<T>,
+        allocator: &impl HeapAllocator<T>,
         _cache: &mut impl EvaluationCache<T>,
     ) -> Result<T, String> {
         let left = args.next().unwrap();
         let right = args.next().unwrap();
+        let numeric_semantics = factory.numeric_semantics();
         let result = if let (Some(left), Some(right)) = (
             factory.match_int_term(&left),
             factory.match_int_term(&right),
@@ -50,7 +52,11 @@ impl<T: Expression> Applicable<T> for Divide {
                     right.value()
                 )))
             } else {
-                Some(Ok(factory.create_int_term(left.value() / right.value())))
+                Some(Ok(create_checked_int_term(
+                    numeric_semantics.divide_int(left.value(), right.value()),
+                    factory,
+                    allocator,
+                )))
             }
         } else if let (Some(left), Some(right)) = (
             factory.match_float_term(&left),
@@ -63,7 +69,11 @@ impl<T: Expression> Applicable<T> for Divide {
                     right.value()
                 )))
             } else {
-                Some(Ok(factory.create_float_term(left.value() / right.value())))
+                Some(Ok(create_checked_float_term(
+                    numeric_semantics.float(left.value() / right.value()),
+                    factory,
+                    allocator,
+                )))
             }
         } else if let (Some(left), Some(right)) = (
             factory.match_int_term(&left),
@@ -76,9 +86,11 @@ impl<T: Expression> Applicable<T> for Divide {
                     right.value()
                 )))
             } else {
-                Some(Ok(
-                    factory.create_float_term((left.value() as f64) / right.value())
-                ))
+                Some(Ok(create_checked_float_term(
+                    numeric_semantics.float((left.value() as f64) / right.value()),
+                    factory,
+                    allocator,
+                )))
             }
         } else if let (Some(left), Some(right)) = (
             factory.match_float_term(&left),
@@ -91,9 +103,11 @@ impl<T: Expression> Applicable<T> for Divide {
                     right.value()
                 )))
             } else {
-                Some(Ok(
-                    factory.create_float_term(left.value() / (right.value() as f64))
-                ))
+                Some(Ok(create_checked_float_term(
+                    numeric_semantics.float(left.value() / (right.value() as f64)),
+                    factory,
+                    allocator,
+                )))
             }
         } else {
             None
@@ -0,0 +1,72 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{
+    uuid, Applicable, ArgType, Arity, DurationValue, EvaluationCache, Expression,
+    ExpressionFactory, FloatTermType, FunctionArity, HeapAllocator, IntTermType, Uid, Uuid,
+};
+
+pub struct Duration;
+impl Duration {
+    pub const UUID: Uuid = uuid!("54340bad-1e7f-4faf-b25e-8d03c940cf13");
+    const ARITY: FunctionArity<1, 0> = FunctionArity {
+        required: [ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for Duration {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for Duration {
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        _allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let value = args.next().unwrap();
+        if factory.match_duration_term(&value).is_some() {
+            Ok(value)
+        } else {
+            let millis = if let Some(term) = factory.match_int_term(&value) {
+                Some(term.value())
+            } else if let Some(term) = factory.match_float_term(&value) {
+                parse_float_millis(term.value())
+            } else {
+                None
+            };
+            match millis {
+                Some(millis) => Ok(factory.create_duration_term(millis)),
+                None => Err(format!(
+                    "Invalid Duration constructor: Expected Int or Float milliseconds, received {}",
+                    value
+                )),
+            }
+        }
+    }
+}
+
+fn parse_float_millis(value: f64) -> Option<DurationValue> {
+    let value = value.trunc();
+    if value.is_finite()
+        && value >= (DurationValue::MIN as f64)
+        && value < -(DurationValue::MIN as f64)
+    {
+        Some(value as DurationValue)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,189 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::ops::Deref;
+
+use reflex::core::{
+    as_integer, uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
+    ExpressionListType, FloatTermType, FunctionArity, HeapAllocator, IntTermType, ListTermType,
+    RecordTermType, RefType, StringTermType, StringValue, Uid, Uuid,
+};
+
+use crate::stdlib::quote::QUOTED_TYPE_FIELD;
+
+/// Reconstruct an expression from the data representation produced by the [`Quote`](crate::stdlib::Quote) builtin
+///
+/// The provided data must be fully resolved; the reconstructed expression is returned unevaluated, and will be
+/// evaluated by the caller as per any other builtin result.
+pub struct Eval;
+impl Eval {
+    pub const UUID: Uuid = uuid!("d8f17c5f-d59e-4ce2-8522-bfeb29a8cb75");
+    const ARITY: FunctionArity<1, 0> = FunctionArity {
+        required: [ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for Eval {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for Eval {
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let data = args.next().unwrap();
+        unquote_expression(&data, 0, factory, allocator)
+            .map_err(|err| format!("Invalid quoted expression: {}", err))
+    }
+}
+
+fn unquote_expression<T: Expression>(
+    node: &T,
+    scope_depth: usize,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> Result<T, String> {
+    let node_type = get_quoted_field(node, QUOTED_TYPE_FIELD, factory, allocator)?;
+    let node_type = match factory.match_string_term(&node_type) {
+        Some(term) => Ok(String::from(term.value().as_deref().as_str().deref())),
+        None => Err(format!("Expected node type string, received {}", node_type)),
+    }?;
+    match node_type.as_str() {
+        "Application" => {
+            let target = get_quoted_field(node, "target", factory, allocator)?;
+            let args = get_quoted_field(node, "args", factory, allocator)?;
+            Ok(factory.create_application_term(
+                unquote_expression(&target, scope_depth, factory, allocator)?,
+                allocator.create_list(unquote_expression_list(
+                    &args,
+                    scope_depth,
+                    factory,
+                    allocator,
+                )?),
+            ))
+        }
+        "Lambda" => {
+            let arity = get_quoted_field(node, "arity", factory, allocator)?;
+            let arity = parse_quoted_offset(&arity, factory)?;
+            let body = get_quoted_field(node, "body", factory, allocator)?;
+            Ok(factory.create_lambda_term(
+                arity,
+                unquote_expression(&body, scope_depth + arity, factory, allocator)?,
+            ))
+        }
+        "Let" => {
+            let initializer = get_quoted_field(node, "initializer", factory, allocator)?;
+            let body = get_quoted_field(node, "body", factory, allocator)?;
+            Ok(factory.create_let_term(
+                unquote_expression(&initializer, scope_depth, factory, allocator)?,
+                unquote_expression(&body, scope_depth + 1, factory, allocator)?,
+            ))
+        }
+        "Variable" => {
+            let offset = get_quoted_field(node, "offset", factory, allocator)?;
+            let offset = parse_quoted_offset(&offset, factory)?;
+            // Variables that refer outside the reconstructed expression would otherwise be left dangling
+            if offset >= scope_depth {
+                Err(format!("Unbound variable offset: {}", offset))
+            } else {
+                Ok(factory.create_variable_term(offset))
+            }
+        }
+        "List" => {
+            let items = get_quoted_field(node, "items", factory, allocator)?;
+            let items = unquote_expression_list(&items, scope_depth, factory, allocator)?;
+            Ok(factory.create_list_term(allocator.create_list(items)))
+        }
+        "Record" => {
+            let keys = get_quoted_field(node, "keys", factory, allocator)?;
+            let keys = match factory.match_list_term(&keys) {
+                Some(term) => Ok(term
+                    .items()
+                    .as_deref()
+                    .iter()
+                    .map(|item| item.as_deref().clone())
+                    .collect::<Vec<_>>()),
+                None => Err(format!("Expected list of record keys, received {}", keys)),
+            }?;
+            let values = get_quoted_field(node, "values", factory, allocator)?;
+            let values = unquote_expression_list(&values, scope_depth, factory, allocator)?;
+            if keys.len() != values.len() {
+                Err(format!(
+                    "Expected {} record values, received {}",
+                    keys.len(),
+                    values.len()
+                ))
+            } else {
+                Ok(factory.create_record_term(
+                    allocator.create_struct_prototype(allocator.create_list(keys)),
+                    allocator.create_list(values),
+                ))
+            }
+        }
+        "Value" => get_quoted_field(node, "value", factory, allocator),
+        _ => Err(format!("Unknown node type: {}", node_type)),
+    }
+}
+
+fn unquote_expression_list<T: Expression>(
+    nodes: &T,
+    scope_depth: usize,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> Result<Vec<T>, String> {
+    match factory.match_list_term(nodes) {
+        Some(term) => term
+            .items()
+            .as_deref()
+            .iter()
+            .map(|item| unquote_expression(item.as_deref(), scope_depth, factory, allocator))
+            .collect::<Result<Vec<_>, _>>(),
+        None => Err(format!("Expected list of nodes, received {}", nodes)),
+    }
+}
+
+fn get_quoted_field<T: Expression>(
+    node: &T,
+    field_name: &'static str,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> Result<T, String> {
+    match factory.match_record_term(node) {
+        Some(term) => term
+            .get(&factory.create_string_term(allocator.create_static_string(field_name)))
+            .map(|value| value.as_deref().clone())
+            .ok_or_else(|| format!("Missing \"{}\" field on node {}", field_name, node)),
+        None => Err(format!("Expected node record, received {}", node)),
+    }
+}
+
+fn parse_quoted_offset<T: Expression>(
+    value: &T,
+    factory: &impl ExpressionFactory<T>,
+) -> Result<usize, String> {
+    let offset = match factory.match_int_term(value) {
+        Some(term) => Some(term.value()),
+        None => factory
+            .match_float_term(value)
+            .and_then(|term| as_integer(term.value())),
+    };
+    match offset {
+        Some(offset) if offset >= 0 => Ok(offset as usize),
+        _ => Err(format!("Expected non-negative integer, received {}", value)),
+    }
+}
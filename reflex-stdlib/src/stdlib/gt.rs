@@ -2,8 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 use reflex::core::{
-    uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
-    FloatTermType, FunctionArity, HeapAllocator, IntTermType, Uid, Uuid,
+    uuid, Applicable, ArgType, Arity, DurationTermType, EvaluationCache, Expression,
+    ExpressionFactory, FloatTermType, FunctionArity, HeapAllocator, IntTermType, TimestampTermType,
+    Uid, Uuid,
 };
 
 pub struct Gt;
@@ -59,13 +60,23 @@ impl<T: Expression> Applicable<T> for Gt {
             factory.match_int_term(&right),
         ) {
             Some(factory.create_boolean_term(left.value() > (right.value() as f64)))
+        } else if let (Some(left), Some(right)) = (
+            factory.match_timestamp_term(&left),
+            factory.match_timestamp_term(&right),
+        ) {
+            Some(factory.create_boolean_term(left.millis() > right.millis()))
+        } else if let (Some(left), Some(right)) = (
+            factory.match_duration_term(&left),
+            factory.match_duration_term(&right),
+        ) {
+            Some(factory.create_boolean_term(left.millis() > right.millis()))
         } else {
             None
         };
         match result {
             Some(result) => Ok(result),
             None => Err(format!(
-                "Expected (Int, Int) or (Float, Float), received ({}, {})",
+                "Expected (Int, Int) or (Float, Float) or (Timestamp, Timestamp) or (Duration, Duration), received ({}, {})",
                 left, right
             )),
         }
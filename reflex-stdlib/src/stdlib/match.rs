@@ -0,0 +1,71 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{
+    uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
+    FunctionArity, HeapAllocator, RefType, RegexTermType, StringTermType, StringValue, Uid, Uuid,
+};
+
+pub struct Match;
+impl Match {
+    pub const UUID: Uuid = uuid!("55d83d35-874e-44b1-860e-5464a2368567");
+    const ARITY: FunctionArity<2, 0> = FunctionArity {
+        required: [ArgType::Strict, ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for Match {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for Match {
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let target = args.next().unwrap();
+        let pattern = args.next().unwrap();
+        match (
+            factory.match_string_term(&target),
+            factory.match_regex_term(&pattern),
+        ) {
+            (Some(target), Some(pattern)) => {
+                let regex = pattern.compiled()?;
+                let target = target.value();
+                let target = target.as_deref().as_str();
+                // Returns a list containing the full match followed by each of the capture groups (or nil for
+                // unmatched groups), or nil if the pattern does not match
+                Ok(match regex.captures(&target) {
+                    None => factory.create_nil_term(),
+                    Some(captures) => factory.create_list_term(allocator.create_list(
+                        captures.iter().map(|capture| {
+                            match capture {
+                                Some(capture) => factory
+                                    .create_string_term(allocator.create_string(capture.as_str())),
+                                None => factory.create_nil_term(),
+                            }
+                        }),
+                    )),
+                })
+            }
+            _ => Err(format!(
+                "Expected (String, RegExp), received ({}, {})",
+                target, pattern,
+            )),
+        }
+    }
+}
@@ -0,0 +1,94 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{
+    as_integer, uuid, Applicable, ArgType, Arity, Builtin, EvaluationCache, Expression,
+    ExpressionFactory, FloatTermType, FunctionArity, HeapAllocator, IntTermType, Uid, Uuid,
+};
+
+use crate::stdlib::MemoStore;
+
+/// Apply the target function to the provided arguments, reusing previously-computed results from a bounded memo table
+///
+/// The arguments are `(fn, capacity, ...args)`: a memoized version of a function is created by partially applying the
+/// target function and the memo table capacity, with any subsequent arguments being passed to the target function.
+/// Each target function has its own memo table, separate from the global evaluation cache, which retains up to
+/// `capacity` results before evicting the least recently used entries.
+///
+/// Memoization is only appropriate for pure functions: memoized results are not invalidated when any dynamic state
+/// that was accessed when computing the result is updated. Signal results are never memoized.
+///
+/// Evaluation caches that do not maintain memo tables will apply the target function directly.
+pub struct Memo;
+impl Memo {
+    pub const UUID: Uuid = uuid!("a4fda34b-065e-4678-a9a6-8b3d11f33071");
+    const ARITY: FunctionArity<2, 0> = FunctionArity {
+        required: [ArgType::Strict, ArgType::Strict],
+        optional: [],
+        variadic: Some(ArgType::Strict),
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for Memo {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for Memo
+where
+    T::Builtin: Builtin + From<MemoStore>,
+{
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let target = args.next().unwrap();
+        let capacity = args.next().unwrap();
+        let _ = parse_memo_capacity(&capacity, factory)?;
+        let args = args.collect::<Vec<_>>();
+        let arg_list = factory.create_list_term(allocator.create_list(args.iter().cloned()));
+        match cache.retrieve_memoized(target.id(), arg_list.id()) {
+            Some(result) => Ok(result),
+            // Defer to the store builtin to record the result once the target function application has been evaluated
+            None => Ok(factory.create_application_term(
+                factory.create_builtin_term(MemoStore),
+                allocator.create_list([
+                    target.clone(),
+                    capacity,
+                    arg_list,
+                    factory.create_application_term(target, allocator.create_list(args)),
+                ]),
+            )),
+        }
+    }
+}
+
+pub(crate) fn parse_memo_capacity<T: Expression>(
+    capacity: &T,
+    factory: &impl ExpressionFactory<T>,
+) -> Result<usize, String> {
+    let value = match factory.match_int_term(capacity) {
+        Some(term) => Some(term.value()),
+        None => factory
+            .match_float_term(capacity)
+            .and_then(|term| as_integer(term.value())),
+    };
+    match value {
+        Some(value) if value > 0 => Ok(value as usize),
+        _ => Err(format!(
+            "Expected positive integer memo capacity, received {}",
+            capacity
+        )),
+    }
+}
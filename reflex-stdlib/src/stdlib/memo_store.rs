@@ -0,0 +1,62 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{
+    uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
+    FunctionArity, HeapAllocator, Uid, Uuid,
+};
+
+use crate::stdlib::memo::parse_memo_capacity;
+
+/// Record the evaluated result of a memoized function application in the target function's memo table
+///
+/// This is used internally by the [`Memo`](crate::stdlib::Memo) builtin, and takes the arguments
+/// `(fn, capacity, args, result)`, returning the result unchanged.
+pub struct MemoStore;
+impl MemoStore {
+    pub const UUID: Uuid = uuid!("840d522e-d6ee-4359-9cce-f2c1eff56fd0");
+    const ARITY: FunctionArity<4, 0> = FunctionArity {
+        required: [
+            ArgType::Strict,
+            ArgType::Strict,
+            ArgType::Strict,
+            ArgType::Strict,
+        ],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for MemoStore {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for MemoStore {
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        _allocator: &impl HeapAllocator<T>,
+        cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let target = args.next().unwrap();
+        let capacity = args.next().unwrap();
+        let arg_list = args.next().unwrap();
+        let result = args.next().unwrap();
+        let capacity = parse_memo_capacity(&capacity, factory)?;
+        if factory.match_list_term(&arg_list).is_none() {
+            return Err(format!("Expected List, received {}", arg_list));
+        }
+        cache.store_memoized(target.id(), capacity, arg_list.id(), result.clone());
+        Ok(result)
+    }
+}
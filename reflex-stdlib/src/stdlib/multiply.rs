@@ -2,8 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 use reflex::core::{
-    uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
-    FloatTermType, FunctionArity, HeapAllocator, IntTermType, Uid, Uuid,
+    create_checked_float_term, create_checked_int_term, uuid, Applicable, ArgType, Arity,
+    EvaluationCache, Expression, ExpressionFactory, FloatTermType, FunctionArity, HeapAllocator,
+    IntTermType, Uid, Uuid,
 };
 
 pub struct Multiply;
@@ -34,31 +35,48 @@ impl<T: Expression> Applicable<T> for Multiply {
         &self,
         mut args: impl ExactSizeIterator<Item = T>,
         factory: &impl ExpressionFactory<T>,
-        _allocator: &impl HeapAllocator<T>,
+        allocator: &impl HeapAllocator<T>,
         _cache: &mut impl EvaluationCache<T>,
     ) -> Result<T, String> {
         let left = args.next().unwrap();
         let right = args.next().unwrap();
+        let numeric_semantics = factory.numeric_semantics();
         let result = if let (Some(left), Some(right)) = (
             factory.match_int_term(&left),
             factory.match_int_term(&right),
         ) {
-            Some(factory.create_int_term(left.value() * right.value()))
+            Some(create_checked_int_term(
+                numeric_semantics.multiply_int(left.value(), right.value()),
+                factory,
+                allocator,
+            ))
         } else if let (Some(left), Some(right)) = (
             factory.match_float_term(&left),
             factory.match_float_term(&right),
         ) {
-            Some(factory.create_float_term(left.value() * right.value()))
+            Some(create_checked_float_term(
+                numeric_semantics.float(left.value() * right.value()),
+                factory,
+                allocator,
+            ))
         } else if let (Some(left), Some(right)) = (
             factory.match_int_term(&left),
             factory.match_float_term(&right),
         ) {
-            Some(factory.create_float_term((left.value() as f64) * right.value()))
+            Some(create_checked_float_term(
+                numeric_semantics.float((left.value() as f64) * right.value()),
+                factory,
+                allocator,
+            ))
         } else if let (Some(left), Some(right)) = (
             factory.match_float_term(&left),
             factory.match_int_term(&right),
         ) {
-            Some(factory.create_float_term(left.value() * (right.value() as f64)))
+            Some(create_checked_float_term(
+                numeric_semantics.float(left.value() * (right.value() as f64)),
+                factory,
+                allocator,
+            ))
         } else {
             None
         };
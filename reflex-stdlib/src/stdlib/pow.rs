@@ -3,8 +3,9 @@
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 // SPDX-FileContributor: Chris Campbell <c.campbell@mwam.com> https://github.com/c-campbell-mwam
 use reflex::core::{
-    is_integer, uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
-    FloatTermType, FloatValue, FunctionArity, HeapAllocator, IntTermType, Uid, Uuid,
+    create_checked_float_term, create_checked_int_term, is_integer, uuid, Applicable, ArgType,
+    Arity, EvaluationCache, Expression, ExpressionFactory, FloatTermType, FloatValue,
+    FunctionArity, HeapAllocator, IntTermType, IntValue, Uid, Uuid,
 };
 
 pub struct Pow;
@@ -35,11 +36,12 @@ impl<T: Expression> Applicable<T> for Pow {
         &self,
         mut args: impl ExactSizeIterator<Item = T>,
         factory: &impl ExpressionFactory<T>,
-        _allocator: &impl HeapAllocator<T>,
+        allocator: &impl HeapAllocator<T>,
         _cache: &mut impl EvaluationCache<T>,
     ) -> Result<T, String> {
         let left = args.next().unwrap();
         let right = args.next().unwrap();
+        let numeric_semantics = factory.numeric_semantics();
         let result = if let (Some(left), Some(right)) = (
             factory.match_int_term(&left),
             factory.match_int_term(&right),
@@ -47,9 +49,17 @@ impl<T: Expression> Applicable<T> for Pow {
             let left = left.value();
             let right = right.value();
             Some(Ok(if right < 0 {
-                factory.create_float_term((left as FloatValue).powi(right as i32))
+                create_checked_float_term(
+                    numeric_semantics.float((left as FloatValue).powi(get_float_exponent(right))),
+                    factory,
+                    allocator,
+                )
             } else {
-                factory.create_int_term(left.pow(right as u32))
+                create_checked_int_term(
+                    numeric_semantics.pow_int(left, get_integer_exponent(right)),
+                    factory,
+                    allocator,
+                )
             }))
         } else if let (Some(left), Some(right)) = (
             factory.match_float_term(&left),
@@ -63,7 +73,11 @@ impl<T: Expression> Applicable<T> for Pow {
                     left, right
                 )))
             } else {
-                Some(Ok(factory.create_float_term(left.powf(right))))
+                Some(Ok(create_checked_float_term(
+                    numeric_semantics.float(left.powf(right)),
+                    factory,
+                    allocator,
+                )))
             }
         } else if let (Some(left), Some(right)) = (
             factory.match_int_term(&left),
@@ -77,15 +91,21 @@ impl<T: Expression> Applicable<T> for Pow {
                     left, right
                 )))
             } else {
-                Some(Ok(factory.create_float_term((left as f64).powf(right))))
+                Some(Ok(create_checked_float_term(
+                    numeric_semantics.float((left as f64).powf(right)),
+                    factory,
+                    allocator,
+                )))
             }
         } else if let (Some(left), Some(right)) = (
             factory.match_float_term(&left),
             factory.match_int_term(&right),
         ) {
-            Some(Ok(
-                factory.create_float_term(left.value().powi(right.value() as i32))
-            ))
+            Some(Ok(create_checked_float_term(
+                numeric_semantics.float(left.value().powi(get_float_exponent(right.value()))),
+                factory,
+                allocator,
+            )))
         } else {
             None
         };
@@ -98,3 +118,24 @@ impl<T: Expression> Applicable<T> for Pow {
         }
     }
 }
+
+fn get_integer_exponent(value: IntValue) -> u32 {
+    // Exponents too large to represent will overflow for any base other than 0, 1 or -1, so clamp to the largest
+    // representable exponent while preserving the parity that determines the sign of the result for negative bases
+    u32::try_from(value).unwrap_or(if value % 2 == 0 {
+        u32::MAX - 1
+    } else {
+        u32::MAX
+    })
+}
+
+fn get_float_exponent(value: IntValue) -> i32 {
+    // Exponents outside the 32-bit range will overflow or underflow for any base other than 0, 1 or -1, so clamp to the
+    // nearest representable exponent while preserving the parity that determines the sign of the result
+    i32::try_from(value).unwrap_or(match (value < 0, value % 2 == 0) {
+        (false, true) => i32::MAX - 1,
+        (false, false) => i32::MAX,
+        (true, true) => i32::MIN,
+        (true, false) => i32::MIN + 1,
+    })
+}
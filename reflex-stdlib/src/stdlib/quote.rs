@@ -0,0 +1,211 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{
+    create_record, uuid, Applicable, ApplicationTermType, ArgType, Arity, EvaluationCache,
+    Expression, ExpressionFactory, ExpressionListType, FunctionArity, HeapAllocator,
+    LambdaTermType, LetTermType, ListTermType, RecordTermType, RefType, StructPrototypeType, Uid,
+    Uuid, VariableTermType,
+};
+
+/// Convert an unevaluated expression into an inspectable data representation
+///
+/// Each expression node is represented as a record with a `type` field that determines the remaining fields:
+///
+/// - `{ type: "Application", target: <node>, args: [<node>, ...] }`
+/// - `{ type: "Lambda", arity: <int>, body: <node> }`
+/// - `{ type: "Let", initializer: <node>, body: <node> }`
+/// - `{ type: "Variable", offset: <int> }` (where the offset is a De Bruijn index into the enclosing scopes)
+/// - `{ type: "List", items: [<node>, ...] }`
+/// - `{ type: "Record", keys: [<key>, ...], values: [<node>, ...] }`
+/// - `{ type: "Value", value: <value> }`
+///
+/// Any term that is not one of the structural term types listed above is wrapped as-is in a `Value` node. Note that
+/// compiled evaluation backends can represent closures as opaque compiled functions, in which case the closure will be
+/// quoted as a `Value` node rather than as a `Lambda` node.
+///
+/// The data representation can be converted back into an expression via the [`Eval`](crate::stdlib::Eval) builtin.
+pub struct Quote;
+impl Quote {
+    pub const UUID: Uuid = uuid!("1fe9f134-1280-491f-bc9a-33f827f1d421");
+    const ARITY: FunctionArity<1, 0> = FunctionArity {
+        required: [ArgType::Lazy],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for Quote {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for Quote {
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let expression = args.next().unwrap();
+        Ok(quote_expression(&expression, factory, allocator))
+    }
+}
+
+pub(crate) const QUOTED_TYPE_FIELD: &str = "type";
+
+fn quote_expression<T: Expression>(
+    expression: &T,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T {
+    if let Some(term) = factory.match_application_term(expression) {
+        create_quoted_node(
+            "Application",
+            [
+                (
+                    "target",
+                    quote_expression(term.target().as_deref(), factory, allocator),
+                ),
+                (
+                    "args",
+                    quote_expression_list(
+                        term.args()
+                            .as_deref()
+                            .iter()
+                            .map(|item| item.as_deref().clone()),
+                        factory,
+                        allocator,
+                    ),
+                ),
+            ],
+            factory,
+            allocator,
+        )
+    } else if let Some(term) = factory.match_lambda_term(expression) {
+        create_quoted_node(
+            "Lambda",
+            [
+                ("arity", factory.create_int_term(term.num_args() as i64)),
+                (
+                    "body",
+                    quote_expression(term.body().as_deref(), factory, allocator),
+                ),
+            ],
+            factory,
+            allocator,
+        )
+    } else if let Some(term) = factory.match_let_term(expression) {
+        create_quoted_node(
+            "Let",
+            [
+                (
+                    "initializer",
+                    quote_expression(term.initializer().as_deref(), factory, allocator),
+                ),
+                (
+                    "body",
+                    quote_expression(term.body().as_deref(), factory, allocator),
+                ),
+            ],
+            factory,
+            allocator,
+        )
+    } else if let Some(term) = factory.match_variable_term(expression) {
+        create_quoted_node(
+            "Variable",
+            [("offset", factory.create_int_term(term.offset() as i64))],
+            factory,
+            allocator,
+        )
+    } else if let Some(term) = factory.match_list_term(expression) {
+        create_quoted_node(
+            "List",
+            [(
+                "items",
+                quote_expression_list(
+                    term.items()
+                        .as_deref()
+                        .iter()
+                        .map(|item| item.as_deref().clone()),
+                    factory,
+                    allocator,
+                ),
+            )],
+            factory,
+            allocator,
+        )
+    } else if let Some(term) = factory.match_record_term(expression) {
+        create_quoted_node(
+            "Record",
+            [
+                (
+                    "keys",
+                    factory
+                        .create_list_term(allocator.clone_list(term.prototype().as_deref().keys())),
+                ),
+                (
+                    "values",
+                    quote_expression_list(
+                        term.values()
+                            .as_deref()
+                            .iter()
+                            .map(|item| item.as_deref().clone()),
+                        factory,
+                        allocator,
+                    ),
+                ),
+            ],
+            factory,
+            allocator,
+        )
+    } else {
+        create_quoted_node("Value", [("value", expression.clone())], factory, allocator)
+    }
+}
+
+fn quote_expression_list<T: Expression>(
+    items: impl IntoIterator<Item = T>,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T {
+    factory.create_list_term(
+        allocator.create_list(
+            items
+                .into_iter()
+                .map(|item| quote_expression(&item, factory, allocator))
+                .collect::<Vec<_>>(),
+        ),
+    )
+}
+
+fn create_quoted_node<T: Expression, const N: usize>(
+    node_type: &'static str,
+    fields: [(&'static str, T); N],
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T {
+    create_record(
+        std::iter::once((
+            factory.create_string_term(allocator.create_static_string(QUOTED_TYPE_FIELD)),
+            factory.create_string_term(allocator.create_static_string(node_type)),
+        ))
+        .chain(fields.into_iter().map(|(key, value)| {
+            (
+                factory.create_string_term(allocator.create_static_string(key)),
+                value,
+            )
+        })),
+        factory,
+        allocator,
+    )
+}
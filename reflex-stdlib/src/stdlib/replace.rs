@@ -5,7 +5,7 @@ use std::ops::Deref;
 
 use reflex::core::{
     uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
-    FunctionArity, HeapAllocator, RefType, StringTermType, StringValue, Uid, Uuid,
+    FunctionArity, HeapAllocator, RefType, RegexTermType, StringTermType, StringValue, Uid, Uuid,
 };
 
 pub struct Replace;
@@ -42,20 +42,40 @@ impl<T: Expression> Applicable<T> for Replace {
         let target = args.next().unwrap();
         let pattern = args.next().unwrap();
         let replacement = args.next().unwrap();
-        match (
+        let result = match (
             factory.match_string_term(&target),
-            factory.match_string_term(&pattern),
             factory.match_string_term(&replacement),
         ) {
-            (Some(target), Some(pattern), Some(replacement)) => Ok(factory.create_string_term(
-                allocator.create_string(target.value().as_deref().as_str().replacen(
-                    pattern.value().as_deref().as_str().deref(),
-                    replacement.value().as_deref().as_str().deref(),
-                    1,
-                )),
-            )),
-            _ => Err(format!(
-                "Expected (String, String, String), received ({}, {}, {})",
+            (Some(target), Some(replacement)) => {
+                let target = target.value();
+                let target = target.as_deref().as_str();
+                let replacement = replacement.value();
+                let replacement = replacement.as_deref().as_str();
+                if let Some(pattern) = factory.match_string_term(&pattern) {
+                    Some(Ok(target.replacen(
+                        pattern.value().as_deref().as_str().deref(),
+                        replacement.deref(),
+                        1,
+                    )))
+                } else {
+                    // Regular expression replacements support `$1`-style capture group references
+                    factory.match_regex_term(&pattern).map(|pattern| {
+                        pattern.compiled().map(|regex| {
+                            regex
+                                .replacen(target.deref(), 1, replacement.deref())
+                                .into_owned()
+                        })
+                    })
+                }
+            }
+            _ => None,
+        };
+        match result {
+            Some(result) => {
+                result.map(|value| factory.create_string_term(allocator.create_string(value)))
+            }
+            None => Err(format!(
+                "Expected (String, String, String) or (String, RegExp, String), received ({}, {}, {})",
                 target, pattern, replacement,
             )),
         }
@@ -0,0 +1,50 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{
+    uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
+    FunctionArity, HeapAllocator, RefType, SensitiveTermType, Uid, Uuid,
+};
+
+/// Unwrap the underlying value of a sensitive term (values that are not sensitive are returned unchanged)
+///
+/// This is the only builtin that is permitted to access the contents of a sensitive term: all other builtins treat
+/// sensitive terms as opaque values.
+pub struct Reveal;
+impl Reveal {
+    pub const UUID: Uuid = uuid!("52efee21-4f96-45b4-9e04-de0a32ca185c");
+    const ARITY: FunctionArity<1, 0> = FunctionArity {
+        required: [ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for Reveal {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for Reveal {
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        _allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let target = args.next().unwrap();
+        match factory.match_sensitive_term(&target) {
+            Some(term) => Ok(term.value().as_deref().clone()),
+            None => Ok(target),
+        }
+    }
+}
@@ -3,8 +3,8 @@
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 use reflex::core::{
     as_integer, uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
-    ExpressionListType, FloatTermType, FunctionArity, HeapAllocator, IntTermType, IntValue,
-    ListTermType, RefType, StringTermType, StringValue, Uid, Uuid,
+    FloatTermType, FunctionArity, HeapAllocator, IntTermType, IntValue, ListTermType, RefType,
+    StringTermType, StringValue, Uid, Uuid,
 };
 
 pub struct Slice;
@@ -56,17 +56,11 @@ impl<T: Expression> Applicable<T> for Slice {
         if let (Some(target), Some((start_index, end_index))) =
             (factory.match_list_term(&target), bounds)
         {
-            Ok(factory.create_list_term(
-                allocator.create_list(
-                    target
-                        .items()
-                        .as_deref()
-                        .iter()
-                        .map(|item| item.as_deref().clone())
-                        .skip(start_index)
-                        .take(end_index - start_index),
-                ),
-            ))
+            Ok(factory.create_list_term(allocator.slice_list(
+                target.items(),
+                start_index,
+                end_index - start_index,
+            )))
         } else if let (Some(target), Some((start_index, end_index))) =
             (factory.match_string_term(&target), bounds)
         {
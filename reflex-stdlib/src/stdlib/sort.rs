@@ -0,0 +1,141 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{cmp::Ordering, ops::Deref};
+
+use reflex::core::{
+    uuid, Applicable, ArgType, Arity, Builtin, DurationTermType, EvaluationCache, Expression,
+    ExpressionFactory, ExpressionListType, FloatTermType, FunctionArity, GraphNode, HeapAllocator,
+    IntTermType, ListTermType, RefType, StringTermType, StringValue, TimestampTermType, Uid, Uuid,
+};
+
+use crate::stdlib::ResolveList;
+
+/// Sort the items of a list into ascending order
+///
+/// Numbers, strings, timestamps and durations are supported; all list items must be of a comparable type. The sort is
+/// stable, so items that compare as equal retain their original relative order. See [`SortBy`](crate::stdlib::SortBy)
+/// for sorting with a custom comparator function.
+pub struct Sort;
+impl Sort {
+    pub const UUID: Uuid = uuid!("3307ffc6-6d4e-4a58-9989-5eec2fa3c01b");
+    const ARITY: FunctionArity<1, 0> = FunctionArity {
+        required: [ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for Sort {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for Sort
+where
+    T::Builtin: Builtin + From<ResolveList> + From<Sort>,
+{
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let target = args.next().unwrap();
+        let items = match factory.match_list_term(&target) {
+            Some(list) => list
+                .items()
+                .as_deref()
+                .iter()
+                .map(|item| item.as_deref().clone())
+                .collect::<Vec<_>>(),
+            None => return Err(format!("Expected List, received {}", target)),
+        };
+        if items.len() <= 1 {
+            return Ok(target);
+        }
+        // List items must be fully evaluated before they can be compared
+        if items.iter().any(|item| !item.is_static()) {
+            return Ok(factory.create_application_term(
+                factory.create_builtin_term(Sort),
+                allocator.create_unit_list(factory.create_application_term(
+                    factory.create_builtin_term(ResolveList),
+                    allocator.create_unit_list(target),
+                )),
+            ));
+        }
+        let mut error = None;
+        let mut items = items;
+        items.sort_by(
+            |left, right| match compare_sort_values(left, right, factory) {
+                Some(ordering) => ordering,
+                None => {
+                    if error.is_none() {
+                        error = Some(format!("Unable to compare list items: {}, {}", left, right));
+                    }
+                    Ordering::Equal
+                }
+            },
+        );
+        match error {
+            Some(error) => Err(error),
+            None => Ok(factory.create_list_term(allocator.create_list(items))),
+        }
+    }
+}
+
+fn compare_sort_values<T: Expression>(
+    left: &T,
+    right: &T,
+    factory: &impl ExpressionFactory<T>,
+) -> Option<Ordering> {
+    if let (Some(left), Some(right)) = (factory.match_int_term(left), factory.match_int_term(right))
+    {
+        Some(left.value().cmp(&right.value()))
+    } else if let (Some(left), Some(right)) = (
+        factory.match_float_term(left),
+        factory.match_float_term(right),
+    ) {
+        left.value().partial_cmp(&right.value())
+    } else if let (Some(left), Some(right)) = (
+        factory.match_int_term(left),
+        factory.match_float_term(right),
+    ) {
+        (left.value() as f64).partial_cmp(&right.value())
+    } else if let (Some(left), Some(right)) = (
+        factory.match_float_term(left),
+        factory.match_int_term(right),
+    ) {
+        left.value().partial_cmp(&(right.value() as f64))
+    } else if let (Some(left), Some(right)) = (
+        factory.match_string_term(left),
+        factory.match_string_term(right),
+    ) {
+        let left = left.value();
+        let right = right.value();
+        let left = left.as_deref().as_str();
+        let right = right.as_deref().as_str();
+        Some(left.deref().cmp(right.deref()))
+    } else if let (Some(left), Some(right)) = (
+        factory.match_timestamp_term(left),
+        factory.match_timestamp_term(right),
+    ) {
+        Some(left.millis().cmp(&right.millis()))
+    } else if let (Some(left), Some(right)) = (
+        factory.match_duration_term(left),
+        factory.match_duration_term(right),
+    ) {
+        Some(left.millis().cmp(&right.millis()))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,96 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{
+    uuid, Applicable, ArgType, Arity, Builtin, EvaluationCache, Expression, ExpressionFactory,
+    ExpressionListType, FunctionArity, HeapAllocator, ListTermType, RefType, Uid, Uuid,
+};
+
+use crate::stdlib::SortMerge;
+
+/// Sort the items of a list according to the provided comparator function
+///
+/// The comparator is invoked with a pair of list items and must return a number: negative if the first item should be
+/// sorted before the second item, positive if the first item should be sorted after the second item, or zero if the
+/// items are considered equal. The sort is stable, so items that compare as equal retain their original relative
+/// order.
+///
+/// The list is sorted via a merge sort, where each half of the list is sorted recursively before the two sorted halves
+/// are combined by the [`SortMerge`] builtin.
+pub struct SortBy;
+impl SortBy {
+    pub const UUID: Uuid = uuid!("1fe8732f-6f07-4e87-98e2-958a127355a2");
+    const ARITY: FunctionArity<2, 0> = FunctionArity {
+        required: [ArgType::Strict, ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for SortBy {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for SortBy
+where
+    T::Builtin: Builtin + From<SortBy> + From<SortMerge>,
+{
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let target = args.next().unwrap();
+        let comparator = args.next().unwrap();
+        let items = match factory.match_list_term(&target) {
+            Some(list) => list
+                .items()
+                .as_deref()
+                .iter()
+                .map(|item| item.as_deref().clone())
+                .collect::<Vec<_>>(),
+            None => {
+                return Err(format!(
+                    "Expected (List, <function:2>), received ({}, {})",
+                    target, comparator,
+                ))
+            }
+        };
+        if items.len() <= 1 {
+            return Ok(target);
+        }
+        let (left, right) = items.split_at(items.len() / 2);
+        let sort_half = |items: &[T]| {
+            let list = factory.create_list_term(allocator.create_list(items.iter().cloned()));
+            if items.len() <= 1 {
+                list
+            } else {
+                factory.create_application_term(
+                    factory.create_builtin_term(SortBy),
+                    allocator.create_pair(list, comparator.clone()),
+                )
+            }
+        };
+        Ok(factory.create_application_term(
+            factory.create_builtin_term(SortMerge),
+            allocator.create_list([
+                sort_half(left),
+                sort_half(right),
+                comparator.clone(),
+                factory.create_nil_term(),
+                factory.create_nil_term(),
+            ]),
+        ))
+    }
+}
@@ -0,0 +1,224 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{
+    uuid, Applicable, ArgType, Arity, Builtin, EvaluationCache, Expression, ExpressionFactory,
+    ExpressionListType, FloatTermType, FunctionArity, HeapAllocator, IntTermType, IntValue,
+    ListTermType, RefType, Uid, Uuid,
+};
+
+/// Merge two sorted lists into a single sorted list, according to the provided comparator function
+///
+/// The arguments are `(left, right, comparator, merged, comparison)`. Each application of this builtin consumes a
+/// single item from either the left or the right list based on the result of the previous comparison, before deferring
+/// to a subsequent application that awaits the comparator result for the next pair of items.
+///
+/// Merge progress is tracked as a linked chain of `[previous, item, left_offset, right_offset]` list terms, so that each
+/// step only allocates a constant amount of memory. A `merged` argument of `null` indicates that the merge has not yet
+/// started; once either list has been exhausted, the chain is unwound and combined with the remaining items.
+pub struct SortMerge;
+impl SortMerge {
+    pub const UUID: Uuid = uuid!("682e7eff-895c-4a74-815d-8f8dbc01ae50");
+    const ARITY: FunctionArity<5, 0> = FunctionArity {
+        required: [
+            ArgType::Strict,
+            ArgType::Strict,
+            ArgType::Strict,
+            ArgType::Strict,
+            ArgType::Strict,
+        ],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for SortMerge {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for SortMerge
+where
+    T::Builtin: Builtin + From<SortMerge>,
+{
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let left = args.next().unwrap();
+        let right = args.next().unwrap();
+        let comparator = args.next().unwrap();
+        let merged = args.next().unwrap();
+        let comparison = args.next().unwrap();
+        let (left_items, right_items) = match (
+            collect_list_items(&left, factory),
+            collect_list_items(&right, factory),
+        ) {
+            (Some(left_items), Some(right_items)) => (left_items, right_items),
+            _ => {
+                return Err(format!(
+                    "Expected (List, List, <function:2>), received ({}, {}, {})",
+                    left, right, comparator,
+                ))
+            }
+        };
+        if factory.match_nil_term(&merged).is_some() {
+            if left_items.is_empty() || right_items.is_empty() {
+                return Ok(factory.create_list_term(
+                    allocator.create_list(left_items.into_iter().chain(right_items)),
+                ));
+            }
+            let merged = factory.create_list_term(allocator.create_empty_list());
+            return Ok(create_merge_step(
+                left,
+                right,
+                comparator,
+                merged,
+                (left_items[0].clone(), right_items[0].clone()),
+                factory,
+                allocator,
+            ));
+        }
+        let (left_offset, right_offset) = match parse_merge_offsets(&merged, factory) {
+            Some(offsets) => offsets,
+            None => return Err(format!("Invalid sort merge state: {}", merged)),
+        };
+        let comparison = match parse_comparison_result(&comparison, factory) {
+            Some(value) => value,
+            None => {
+                return Err(format!(
+                    "Expected comparator to return a number, received {}",
+                    comparison
+                ))
+            }
+        };
+        // Prefer the left item unless the comparator orders it after the right item, ensuring the sort is stable
+        let take_left = comparison <= 0.0 || comparison.is_nan();
+        let (item, left_offset, right_offset) =
+            match (left_items.get(left_offset), right_items.get(right_offset)) {
+                (Some(item), Some(_)) if take_left => (item.clone(), left_offset + 1, right_offset),
+                (Some(_), Some(item)) => (item.clone(), left_offset, right_offset + 1),
+                _ => return Err(format!("Invalid sort merge state: {}", merged)),
+            };
+        let merged = factory.create_list_term(allocator.create_list([
+            merged,
+            item,
+            factory.create_int_term(left_offset as IntValue),
+            factory.create_int_term(right_offset as IntValue),
+        ]));
+        if left_offset < left_items.len() && right_offset < right_items.len() {
+            return Ok(create_merge_step(
+                left,
+                right,
+                comparator,
+                merged,
+                (
+                    left_items[left_offset].clone(),
+                    right_items[right_offset].clone(),
+                ),
+                factory,
+                allocator,
+            ));
+        }
+        // Unwind the chain of merged items, appending any items that remain in either list
+        let mut results = Vec::with_capacity(left_items.len() + right_items.len());
+        let mut current = merged;
+        while let Some((previous, item)) = parse_merge_node(&current, factory) {
+            results.push(item);
+            current = previous;
+        }
+        results.reverse();
+        results.extend(left_items.into_iter().skip(left_offset));
+        results.extend(right_items.into_iter().skip(right_offset));
+        Ok(factory.create_list_term(allocator.create_list(results)))
+    }
+}
+
+fn create_merge_step<T: Expression>(
+    left: T,
+    right: T,
+    comparator: T,
+    merged: T,
+    (left_item, right_item): (T, T),
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T
+where
+    T::Builtin: From<SortMerge>,
+{
+    let comparison = factory.create_application_term(
+        comparator.clone(),
+        allocator.create_pair(left_item, right_item),
+    );
+    factory.create_application_term(
+        factory.create_builtin_term(SortMerge),
+        allocator.create_list([left, right, comparator, merged, comparison]),
+    )
+}
+
+fn collect_list_items<T: Expression>(
+    target: &T,
+    factory: &impl ExpressionFactory<T>,
+) -> Option<Vec<T>> {
+    factory.match_list_term(target).map(|list| {
+        list.items()
+            .as_deref()
+            .iter()
+            .map(|item| item.as_deref().clone())
+            .collect()
+    })
+}
+
+fn parse_merge_node<T: Expression>(
+    node: &T,
+    factory: &impl ExpressionFactory<T>,
+) -> Option<(T, T)> {
+    let node = factory.match_list_term(node)?;
+    let items = node.items();
+    let items = items.as_deref();
+    if items.len() != 4 {
+        return None;
+    }
+    let previous = items.get(0)?.as_deref().clone();
+    let item = items.get(1)?.as_deref().clone();
+    Some((previous, item))
+}
+
+fn parse_merge_offsets<T: Expression>(
+    node: &T,
+    factory: &impl ExpressionFactory<T>,
+) -> Option<(usize, usize)> {
+    let node = factory.match_list_term(node)?;
+    let items = node.items();
+    let items = items.as_deref();
+    match items.len() {
+        0 => Some((0, 0)),
+        4 => {
+            let left_offset = factory.match_int_term(items.get(2)?.as_deref())?.value();
+            let right_offset = factory.match_int_term(items.get(3)?.as_deref())?.value();
+            Some((left_offset.max(0) as usize, right_offset.max(0) as usize))
+        }
+        _ => None,
+    }
+}
+
+fn parse_comparison_result<T: Expression>(
+    value: &T,
+    factory: &impl ExpressionFactory<T>,
+) -> Option<f64> {
+    match factory.match_int_term(value) {
+        Some(term) => Some(term.value() as f64),
+        None => factory.match_float_term(value).map(|term| term.value()),
+    }
+}
@@ -3,8 +3,9 @@
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 // SPDX-FileContributor: Chris Campbell <c.campbell@mwam.com> https://github.com/c-campbell-mwam
 use reflex::core::{
-    uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
-    FloatTermType, FunctionArity, HeapAllocator, IntTermType, Uid, Uuid,
+    create_checked_float_term, create_checked_int_term, uuid, Applicable, ArgType, Arity,
+    EvaluationCache, Expression, ExpressionFactory, FloatTermType, FunctionArity, HeapAllocator,
+    IntTermType, Uid, Uuid,
 };
 
 pub struct Subtract;
@@ -35,31 +36,48 @@ impl<T: Expression> Applicable<T> for Subtract {
         &self,
         mut args: impl ExactSizeIterator<Item = T>,
         factory: &impl ExpressionFactory<T>,
-        _allocator: &impl HeapAllocator<T>,
+        allocator: &impl HeapAllocator<T>,
         _cache: &mut impl EvaluationCache<T>,
     ) -> Result<T, String> {
         let left = args.next().unwrap();
         let right = args.next().unwrap();
+        let numeric_semantics = factory.numeric_semantics();
         let result = if let (Some(left), Some(right)) = (
             factory.match_int_term(&left),
             factory.match_int_term(&right),
         ) {
-            Some(factory.create_int_term(left.value() - right.value()))
+            Some(create_checked_int_term(
+                numeric_semantics.subtract_int(left.value(), right.value()),
+                factory,
+                allocator,
+            ))
         } else if let (Some(left), Some(right)) = (
             factory.match_float_term(&left),
             factory.match_float_term(&right),
         ) {
-            Some(factory.create_float_term(left.value() - right.value()))
+            Some(create_checked_float_term(
+                numeric_semantics.float(left.value() - right.value()),
+                factory,
+                allocator,
+            ))
         } else if let (Some(left), Some(right)) = (
             factory.match_int_term(&left),
             factory.match_float_term(&right),
         ) {
-            Some(factory.create_float_term((left.value() as f64) - right.value()))
+            Some(create_checked_float_term(
+                numeric_semantics.float((left.value() as f64) - right.value()),
+                factory,
+                allocator,
+            ))
         } else if let (Some(left), Some(right)) = (
             factory.match_float_term(&left),
             factory.match_int_term(&right),
         ) {
-            Some(factory.create_float_term(left.value() - (right.value() as f64)))
+            Some(create_checked_float_term(
+                numeric_semantics.float(left.value() - (right.value() as f64)),
+                factory,
+                allocator,
+            ))
         } else {
             None
         };
@@ -0,0 +1,180 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::ops::Deref;
+
+use reflex::core::{
+    uuid, Applicable, ArgType, Arity, EvaluationCache, Expression, ExpressionFactory,
+    ExpressionListType, FunctionArity, HeapAllocator, ListTermType, RecordTermType, RefType,
+    StringTermType, StringValue, Uid, Uuid,
+};
+
+use crate::stdlib::quote::QUOTED_TYPE_FIELD;
+
+/// Fill the placeholders within a quoted expression template with the corresponding quoted expressions
+///
+/// Placeholders are represented as `{ type: "Splice", name: <string> }` nodes, and are replaced with the field of the
+/// bindings record with the given name. When a placeholder is an item within a list whose binding is itself a list
+/// (e.g. application arguments, list items or record keys/values), the bound items are spliced into the enclosing list
+/// in place of the placeholder. The contents of quoted `Value` nodes are left untouched.
+///
+/// Both the template and the bindings must be fully resolved. The result can be evaluated via the
+/// [`Eval`](crate::stdlib::Eval) builtin.
+pub struct Template;
+impl Template {
+    pub const UUID: Uuid = uuid!("c34068c5-9a8f-4240-a2a1-0d20b467eac8");
+    const ARITY: FunctionArity<2, 0> = FunctionArity {
+        required: [ArgType::Strict, ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity() -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for Template {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
+impl<T: Expression> Applicable<T> for Template {
+    fn arity(&self) -> Option<Arity> {
+        Some(Self::arity())
+    }
+    fn should_parallelize(&self, _args: &[T]) -> bool {
+        false
+    }
+    fn apply(
+        &self,
+        mut args: impl ExactSizeIterator<Item = T>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        _cache: &mut impl EvaluationCache<T>,
+    ) -> Result<T, String> {
+        let template = args.next().unwrap();
+        let bindings = args.next().unwrap();
+        let bindings = match factory.match_record_term(&bindings) {
+            Some(term) => Ok(term),
+            None => Err(format!(
+                "Expected template bindings record, received {}",
+                bindings
+            )),
+        }?;
+        fill_template(&template, bindings, factory, allocator)
+    }
+}
+
+enum TemplateNode<T> {
+    Value(T),
+    Placeholder(String),
+}
+
+fn fill_template<T: Expression>(
+    template: &T,
+    bindings: &T::RecordTerm,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> Result<T, String> {
+    match parse_template_node(template, factory, allocator) {
+        TemplateNode::Placeholder(name) => get_binding(&name, bindings, factory, allocator),
+        TemplateNode::Value(template) => {
+            if let Some(term) = factory.match_list_term(&template) {
+                let mut items = Vec::with_capacity(term.items().as_deref().len());
+                for item in term.items().as_deref().iter() {
+                    let item = item.as_deref();
+                    match parse_template_node(item, factory, allocator) {
+                        TemplateNode::Placeholder(name) => {
+                            let binding = get_binding(&name, bindings, factory, allocator)?;
+                            // List bindings are spliced into the enclosing list
+                            match factory.match_list_term(&binding) {
+                                Some(binding) => items.extend(
+                                    binding
+                                        .items()
+                                        .as_deref()
+                                        .iter()
+                                        .map(|item| item.as_deref().clone()),
+                                ),
+                                None => items.push(binding),
+                            }
+                        }
+                        TemplateNode::Value(item) => {
+                            items.push(fill_template(&item, bindings, factory, allocator)?)
+                        }
+                    }
+                }
+                Ok(factory.create_list_term(allocator.create_list(items)))
+            } else if let Some(term) = factory.match_record_term(&template) {
+                // The contents of quoted values are opaque, so cannot contain placeholders
+                if is_quoted_node_type(&template, "Value", factory, allocator) {
+                    return Ok(template);
+                }
+                let values = term
+                    .values()
+                    .as_deref()
+                    .iter()
+                    .map(|value| fill_template(value.as_deref(), bindings, factory, allocator))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(factory.create_record_term(
+                    allocator.clone_struct_prototype(term.prototype()),
+                    allocator.create_list(values),
+                ))
+            } else {
+                Ok(template)
+            }
+        }
+    }
+}
+
+fn parse_template_node<T: Expression>(
+    template: &T,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> TemplateNode<T> {
+    let name = if is_quoted_node_type(template, "Splice", factory, allocator) {
+        factory.match_record_term(template).and_then(|term| {
+            term.get(&factory.create_string_term(allocator.create_static_string("name")))
+                .and_then(|value| {
+                    factory
+                        .match_string_term(value.as_deref())
+                        .map(|term| String::from(term.value().as_deref().as_str().deref()))
+                })
+        })
+    } else {
+        None
+    };
+    match name {
+        Some(name) => TemplateNode::Placeholder(name),
+        None => TemplateNode::Value(template.clone()),
+    }
+}
+
+fn is_quoted_node_type<T: Expression>(
+    node: &T,
+    node_type: &'static str,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> bool {
+    factory
+        .match_record_term(node)
+        .and_then(|term| {
+            term.get(&factory.create_string_term(allocator.create_static_string(QUOTED_TYPE_FIELD)))
+                .and_then(|value| {
+                    factory
+                        .match_string_term(value.as_deref())
+                        .map(|term| term.value().as_deref().as_str().deref() == node_type)
+                })
+        })
+        .unwrap_or(false)
+}
+
+fn get_binding<T: Expression>(
+    name: &str,
+    bindings: &T::RecordTerm,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> Result<T, String> {
+    bindings
+        .get(&factory.create_string_term(allocator.create_string(name)))
+        .map(|value| value.as_deref().clone())
+        .ok_or_else(|| format!("Missing template binding: {}", name))
+}
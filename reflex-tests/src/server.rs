@@ -16,10 +16,14 @@ use std::{
 use hyper::{server::conn::AddrStream, service::make_service_fn, Server};
 use reflex::{
     cache::SubstitutionCache,
-    core::{Expression, ExpressionFactory, HeapAllocator, Reducible, Rewritable},
+    core::{Expression, ExpressionFactory, FloatFormat, HeapAllocator, Reducible, Rewritable},
+    sandbox::SandboxPolicies,
 };
 use reflex_dispatcher::HandlerContext;
-use reflex_graphql::{imports::GraphQlImportsBuiltin, NoopGraphQlQueryTransform};
+use reflex_graphql::{
+    imports::GraphQlImportsBuiltin, GraphQlErrorExtensions, GraphQlResultSizeLimit,
+    NoopGraphQlQueryTransform,
+};
 use reflex_grpc::DefaultGrpcConfig;
 use reflex_handlers::actor::graphql::{GraphQlHandler, GraphQlHandlerMetricNames};
 use reflex_handlers::{
@@ -45,6 +49,7 @@ use reflex_server::{
     scheduler_metrics::{
         NoopServerMetricsSchedulerQueueInstrumentation, ServerMetricsInstrumentation,
     },
+    server::GraphQlServerLatencyBudgets,
     GraphQlWebServerActorFactory, WasmHeapDumpEvaluationType, WasmHeapDumpMode,
     WasmHeapDumpResultType,
 };
@@ -172,7 +177,14 @@ pub fn serve_graphql(
         async_tasks,
         blocking_tasks,
         None,
+        None,
+        FloatFormat::default(),
+        GraphQlErrorExtensions::default(),
+        GraphQlResultSizeLimit::default(),
+        SandboxPolicies::default(),
+        GraphQlServerLatencyBudgets::default(),
         dump_heap_snapshot,
+        None,
     )
     .map_err(WasmTestError::Server)?;
     let service = make_service_fn({
@@ -261,6 +273,7 @@ where
         [(&ModuleEntryPoint::from(export_name), graph_factory)],
         RUNTIME_BYTES,
         None,
+        factory.numeric_semantics(),
         &WasmCompilerOptions::default(),
         true,
     )
@@ -3,6 +3,24 @@
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 
 declare module 'reflex::core' {
+  export type QuotedExpression =
+    | {
+        type: 'Application';
+        target: QuotedExpression;
+        args: Array<QuotedExpression>;
+      }
+    | { type: 'Lambda'; arity: number; body: QuotedExpression }
+    | { type: 'Let'; initializer: QuotedExpression; body: QuotedExpression }
+    | { type: 'Variable'; offset: number }
+    | { type: 'List'; items: Array<QuotedExpression> }
+    | {
+        type: 'Record';
+        keys: Array<PropertyKey>;
+        values: Array<QuotedExpression>;
+      }
+    | { type: 'Value'; value: any }
+    | { type: 'Splice'; name: string };
+
   export function abs(value: number): number;
   export function add(left: number, right: number): number;
   export function and(left: boolean, right: () => boolean): boolean;
@@ -46,6 +64,7 @@ declare module 'reflex::core' {
   export function endsWith(target: string, pattern: string): boolean;
   export function eq<T>(left: T, right: T): boolean;
   export function equal(left: number, right: number): boolean;
+  export function eval<T>(expression: QuotedExpression): T;
   export function filter<T>(
     target: Array<T>,
     predicate: (item: T) => boolean,
@@ -88,6 +107,10 @@ declare module 'reflex::core' {
     iteratee: (item: T) => V,
   ): Array<V>;
   export function max(left: number, right: number): number;
+  export function memo<F extends (...args: Array<any>) => any>(
+    fn: F,
+    capacity: number,
+  ): F;
   export function merge<K extends PropertyKey, V>(
     ...args: Array<Record<K, V> | null | undefined>
   ): Record<K, V>;
@@ -98,6 +121,7 @@ declare module 'reflex::core' {
   export function pow(base: number, exponent: number): number;
   export function push<T>(target: Array<T>, value: T): Array<T>;
   export function pushFront<T>(target: Array<T>, value: T): Array<T>;
+  export function quote(expression: any): QuotedExpression;
   export function raise(payload: any): never;
   export function reduce<T, V>(
     target: Array<T>,
@@ -121,6 +145,7 @@ declare module 'reflex::core' {
     target: T,
   ): T;
   export function resolveList<T extends Array<any>>(target: T): T;
+  export function reveal<T>(target: T): T;
   export function round(value: number): number;
   export function sequence<T, V>(target: T, callback: (value: T) => V): V;
   export function slice<T>(
@@ -133,9 +158,20 @@ declare module 'reflex::core' {
     startIndex: number,
     endIndex: number,
   ): string;
+  export function sort<T extends number | string | Date>(
+    target: Array<T>,
+  ): Array<T>;
+  export function sortBy<T>(
+    target: Array<T>,
+    comparator: (left: T, right: T) => number,
+  ): Array<T>;
   export function split(target: string, separator: string): Array<string>;
   export function startsWith(target: string, pattern: string): boolean;
   export function subtract(left: number, right: number): number;
+  export function template(
+    template: QuotedExpression,
+    bindings: Record<string, QuotedExpression | Array<QuotedExpression>>,
+  ): QuotedExpression;
   export function unzip<L, R>(target: Array<[L, R]>): [Array<L>, Array<R>];
   export function values<T>(target: Map<any, T>): Array<T>;
   export function values<T>(target: Set<T>): Array<T>;
@@ -27,4 +27,14 @@ declare module 'reflex::http' {
 
   export function fetch<T>(url: string): Response<T>;
   export function fetch<T>(request: Request): Response<T>;
+
+  export interface Page<T> {
+    items: Array<T>;
+    next: Page<T> | null;
+  }
+
+  export function paginate<T, C>(
+    fetchPage: (cursor: C) => { items: Array<T>; cursor: C | null },
+    cursor: C,
+  ): Page<T>;
 }
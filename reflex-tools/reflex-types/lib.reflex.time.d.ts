@@ -5,4 +5,7 @@
 declare module 'reflex::time' {
   export function timeout(duration: number, token: symbol): null;
   export function now(options: { interval: number }): number;
+  export function throttle<T>(input: () => T, interval: number): T;
+  export function debounce<T>(input: () => T, interval: number): T;
+  export function sample<T>(input: () => T, trigger: () => unknown): T;
 }
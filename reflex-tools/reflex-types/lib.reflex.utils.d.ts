@@ -5,5 +5,6 @@
 declare module 'reflex::utils' {
   export function graph<T>(factory: (value: T) => T): T;
   export function log<T>(value: T, ...args: any[]): T;
+  export function logScope<T>(label: any, body: () => T): T;
   export function pending(): never;
 }
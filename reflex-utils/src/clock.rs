@@ -0,0 +1,123 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    future::Future,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use tokio::sync::watch;
+
+/// Source of the current wall-clock time for time-based effects
+///
+/// Time-based effect handlers use the host system clock by default; swapping in a [`SimulationClock`] allows tests
+/// and session replays to control the passage of time programmatically.
+#[derive(Clone, Debug, Default)]
+pub enum Clock {
+    /// Host system clock
+    #[default]
+    System,
+    /// Virtual clock that only advances when explicitly instructed
+    Simulation(SimulationClock),
+}
+
+impl Clock {
+    pub fn now(&self) -> SystemTime {
+        match self {
+            Self::System => SystemTime::now(),
+            Self::Simulation(clock) => clock.now(),
+        }
+    }
+    pub fn is_simulated(&self) -> bool {
+        matches!(self, Self::Simulation(_))
+    }
+}
+
+impl From<SimulationClock> for Clock {
+    fn from(value: SimulationClock) -> Self {
+        Self::Simulation(value)
+    }
+}
+
+/// Virtual clock whose current time is advanced manually
+///
+/// Clones share the same underlying time, so the clock can be handed to any number of consumers while remaining under
+/// the control of the test harness. Pending sleeps are woken whenever the clock is advanced past their deadline.
+#[derive(Clone, Debug)]
+pub struct SimulationClock {
+    state: Arc<watch::Sender<SystemTime>>,
+}
+
+impl SimulationClock {
+    pub fn new(start_time: SystemTime) -> Self {
+        let (sender, _) = watch::channel(start_time);
+        Self {
+            state: Arc::new(sender),
+        }
+    }
+    pub fn now(&self) -> SystemTime {
+        *self.state.borrow()
+    }
+    /// Move the clock forwards by the given duration
+    pub fn advance(&self, duration: Duration) {
+        self.state.send_modify(|now| *now += duration);
+    }
+    /// Move the clock forwards to the given time (times earlier than the current time are ignored)
+    pub fn advance_to(&self, timestamp: SystemTime) {
+        self.state.send_if_modified(|now| {
+            if timestamp > *now {
+                *now = timestamp;
+                true
+            } else {
+                false
+            }
+        });
+    }
+    /// Wait until the clock has been advanced to the given deadline
+    pub fn sleep_until(&self, deadline: SystemTime) -> impl Future<Output = ()> + Send + 'static {
+        let mut receiver = self.state.subscribe();
+        async move {
+            while *receiver.borrow() < deadline {
+                if receiver.changed().await.is_err() {
+                    // All handles to the clock have been dropped, so the deadline will never be reached
+                    std::future::pending::<()>().await;
+                }
+            }
+        }
+    }
+    /// Wait until the clock has been advanced by the given duration
+    pub fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send + 'static {
+        self.sleep_until(self.now() + duration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use futures::FutureExt;
+
+    use super::*;
+
+    #[test]
+    fn simulation_clock() {
+        let start_time = UNIX_EPOCH + Duration::from_secs(1000);
+        let clock = SimulationClock::new(start_time);
+        assert_eq!(Clock::from(clock.clone()).now(), start_time);
+
+        let mut sleep = Box::pin(clock.sleep(Duration::from_millis(300)));
+        assert_eq!((&mut sleep).now_or_never(), None);
+        clock.advance(Duration::from_millis(200));
+        assert_eq!((&mut sleep).now_or_never(), None);
+        clock.clone().advance(Duration::from_millis(100));
+        assert_eq!((&mut sleep).now_or_never(), Some(()));
+        assert_eq!(clock.now(), start_time + Duration::from_millis(300));
+
+        clock.advance_to(start_time);
+        assert_eq!(clock.now(), start_time + Duration::from_millis(300));
+        clock.advance_to(start_time + Duration::from_secs(5));
+        assert_eq!(clock.now(), start_time + Duration::from_secs(5));
+        assert_eq!(clock.sleep_until(start_time).now_or_never(), Some(()));
+    }
+}
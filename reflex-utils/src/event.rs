@@ -60,6 +60,12 @@ pub struct ChainEventSink<T1, T2> {
     right: T2,
 }
 
+impl<T1, T2> ChainEventSink<T1, T2> {
+    pub fn new(left: T1, right: T2) -> Self {
+        Self { left, right }
+    }
+}
+
 impl<T1, T2, T> EventSink for ChainEventSink<T1, T2>
 where
     T1: EventSink<Event = T>,
@@ -6,6 +6,7 @@ mod file_writer;
 mod iter;
 mod partition_results;
 
+pub mod clock;
 pub mod dag;
 pub mod event;
 pub mod json;
@@ -14,6 +15,7 @@ pub mod serialize;
 pub mod stack;
 pub mod stack_vec;
 pub mod visitor;
+pub mod work_stack;
 
 pub use self::file_writer::*;
 pub use self::iter::*;
@@ -21,3 +23,4 @@ pub use self::partition_results::*;
 pub use self::stack::*;
 pub use self::stack_vec::*;
 pub use self::visitor::*;
+pub use self::work_stack::*;
@@ -0,0 +1,89 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+
+/// Heap-allocated last-in-first-out queue of pending work items, used to replace recursive algorithms with iterative equivalents.
+///
+/// This allows traversing arbitrarily deep data structures without risk of overflowing the call stack.
+///
+/// Items added via [`WorkStack::extend`] are popped in the same order in which they were provided, which allows a
+/// node's children to be enqueued in one step while still being processed in order.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct WorkStack<T> {
+    items: Vec<T>,
+}
+
+impl<T> Default for WorkStack<T> {
+    fn default() -> Self {
+        Self {
+            items: Default::default(),
+        }
+    }
+}
+
+impl<T> WorkStack<T> {
+    pub fn new(item: T) -> Self {
+        Self { items: vec![item] }
+    }
+    pub fn push(&mut self, item: T) {
+        self.items.push(item)
+    }
+    pub fn extend(&mut self, items: impl IntoIterator<Item = T>) {
+        let start_index = self.items.len();
+        self.items.extend(items);
+        // Items are popped from the back of the stack one-by-one, so we need to reverse the newly-added entries
+        // to ensure they are processed in the order in which they were provided
+        self.items[start_index..].reverse();
+    }
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn work_stack() {
+        let mut stack = WorkStack::new(1);
+        stack.extend([2, 3, 4]);
+        assert_eq!(stack.len(), 4);
+        assert_eq!(stack.pop(), Some(2));
+        stack.push(5);
+        stack.extend([6, 7]);
+        assert_eq!(stack.pop(), Some(6));
+        assert_eq!(stack.pop(), Some(7));
+        assert_eq!(stack.pop(), Some(5));
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(4));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn deep_traversal() {
+        struct Node(Option<Box<Node>>);
+        let depth = 100_000;
+        let root = (0..depth).fold(Node(None), |child, _| Node(Some(Box::new(child))));
+        let mut stack = WorkStack::new(&root);
+        let mut count = 0;
+        while let Some(Node(child)) = stack.pop() {
+            count += 1;
+            stack.extend(child.as_deref());
+        }
+        assert_eq!(count, depth + 1);
+        // Dismantle the list iteratively to avoid overflowing the stack when dropping the nested boxes
+        let mut next = root.0;
+        while let Some(node) = next {
+            next = node.0;
+        }
+    }
+}
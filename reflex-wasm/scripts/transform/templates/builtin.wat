@@ -96,7 +96,7 @@
     (local (@concat "$" (@get $arg_name) "::type") i32))
   (@map $arg_name
     (@chain (@get $arg_names) (@get $vararg_names))
-    (local.set (@concat "$" (@get $arg_name) "::type") (call $Term::get_type (local.get (@get $arg_name)))))
+    (local.set (@concat "$" (@get $arg_name) "::type") (call $Term::get_dispatch_type (local.get (@get $arg_name)))))
   (@switch
     (@list
       (@zip $implementation_name $implementation_signature
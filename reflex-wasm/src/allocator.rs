@@ -10,7 +10,15 @@ use std::{
     rc::Rc,
 };
 
-use crate::{hash::TermSize, ArenaPointer};
+use reflex::hash::IntMap;
+
+use crate::{
+    gc::{collect_garbage, compact_arena, ArenaRemapping, GarbageCollectionStats},
+    hash::TermSize,
+    heap_stats::{get_heap_usage_stats, HeapUsageStats},
+    term_type::TermTypeDiscriminants,
+    ArenaPointer, Term, TermHeader,
+};
 
 pub trait Arena {
     type Slice<'a>: Deref<Target = [u8]>
@@ -27,6 +35,20 @@ pub trait Arena {
     where
         Self::Slice<'a>: 'a,
         Self: 'a;
+    /// Compute memory usage statistics for the sequence of terms located between the given offsets
+    ///
+    /// See [`get_heap_usage_stats`] for details.
+    fn usage_stats(
+        &self,
+        start_offset: ArenaPointer,
+        end_offset: ArenaPointer,
+        num_largest_terms: usize,
+    ) -> HeapUsageStats
+    where
+        Self: Sized,
+    {
+        get_heap_usage_stats(self, start_offset, end_offset, num_largest_terms)
+    }
 }
 
 pub trait ArenaMut: Arena {
@@ -37,6 +59,69 @@ pub trait ArenaAllocator: ArenaMut {
     fn allocate<T: TermSize>(&mut self, value: T) -> ArenaPointer;
     fn extend(&mut self, offset: ArenaPointer, size: usize);
     fn shrink(&mut self, offset: ArenaPointer, size: usize);
+    /// Register a newly-allocated string term, returning the location of a previously-interned string term with
+    /// identical contents if one exists
+    ///
+    /// If a match is found and the new term is the most recent allocation, the new allocation is discarded. Allocators
+    /// that do not maintain an interning table return the provided pointer unchanged.
+    fn intern_string(&mut self, pointer: ArenaPointer) -> ArenaPointer {
+        pointer
+    }
+    /// Update the locations of any interned string terms to reflect the outcome of an arena compaction
+    fn remap_interned_strings(&mut self, remapping: &ArenaRemapping) {
+        let _ = remapping;
+    }
+    /// Allocate a value whose allocation will not subsequently be extended, reusing the space occupied by a previously
+    /// released allocation of the same size if the allocator supports it
+    fn allocate_fixed<T: TermSize>(&mut self, value: T) -> ArenaPointer {
+        self.allocate(value)
+    }
+    /// Mark the term at the given location as no longer reachable, allowing its space to be reused by subsequent
+    /// [`ArenaAllocator::allocate_fixed`] allocations if the allocator supports it
+    ///
+    /// The released term MUST NOT be referenced by any other term, and MUST NOT be released more than once.
+    fn release(&mut self, pointer: ArenaPointer) {
+        let _ = pointer;
+    }
+    /// Reclaim the space occupied by any terms located after `start_offset` that are not reachable from the given
+    /// roots, updating the roots in-place to reflect the new locations of the surviving terms
+    ///
+    /// See [`collect_garbage`] for details.
+    fn collect_garbage(
+        &mut self,
+        start_offset: ArenaPointer,
+        roots: &mut [ArenaPointer],
+    ) -> GarbageCollectionStats
+    where
+        Self: Sized,
+    {
+        collect_garbage(self, start_offset, roots)
+    }
+    /// Relocate all terms after the given start offset that are reachable from the provided roots, discarding any
+    /// unreachable terms and returning a table that maps the original term locations to their relocated offsets
+    ///
+    /// See [`compact_arena`] for details.
+    fn compact(
+        &mut self,
+        start_offset: ArenaPointer,
+        roots: impl IntoIterator<Item = ArenaPointer>,
+    ) -> (ArenaRemapping, GarbageCollectionStats)
+    where
+        Self: Sized,
+    {
+        compact_arena(self, start_offset, roots)
+    }
+    /// Compute memory usage statistics for all the terms allocated within the arena
+    fn heap_usage_stats(&self, num_largest_terms: usize) -> HeapUsageStats
+    where
+        Self: Sized,
+    {
+        // Skip over the initial 4-byte allocator offset marker
+        let start_offset = ArenaPointer::from(std::mem::size_of::<u32>() as u32);
+        let end_offset =
+            ArenaPointer::from(self.read_value::<u32, _>(ArenaPointer::from(0), |value| *value));
+        self.usage_stats(start_offset, end_offset, num_largest_terms)
+    }
 }
 
 impl<'slice> Arena for &'slice [u8] {
@@ -129,7 +214,11 @@ fn get_mut_slice_ref<T>(slice: &mut [u8], offset: ArenaPointer) -> &mut T {
     unsafe { std::mem::transmute::<&mut u8, &mut T>(slice.get_unchecked_mut(index)) }
 }
 
-pub struct VecAllocator(Vec<u32>);
+pub struct VecAllocator {
+    data: Vec<u32>,
+    /// Locations of all string terms registered via [`ArenaAllocator::intern_string`], indexed by term hash
+    interned_strings: IntMap<u64, Vec<ArenaPointer>>,
+}
 impl VecAllocator {
     pub fn from_bytes(data: &[u8]) -> Self {
         if data.len() % 4 != 0 {
@@ -142,7 +231,10 @@ impl VecAllocator {
         )
     }
     pub fn from_vec_u32(data: Vec<u32>) -> Self {
-        Self(data)
+        Self {
+            data,
+            interned_strings: Default::default(),
+        }
     }
     pub(crate) fn get_ref<T>(&self, offset: ArenaPointer) -> &T {
         let is_zero_sized_value = std::mem::size_of::<T>() == 0;
@@ -157,7 +249,7 @@ impl VecAllocator {
                 u32::from(self.end_offset())
             );
         }
-        let Self(data) = self;
+        let Self { data, .. } = self;
         let words = data.as_slice();
         let index = (u32::from(offset) / 4) as usize;
         unsafe { std::mem::transmute::<&u32, &T>(words.get_unchecked(index)) }
@@ -175,17 +267,17 @@ impl VecAllocator {
                 u32::from(self.end_offset())
             );
         }
-        let Self(data) = self;
+        let Self { data, .. } = self;
         let words = data.as_mut_slice();
         let index = (u32::from(offset) / 4) as usize;
         unsafe { std::mem::transmute::<&mut u32, &mut T>(words.get_unchecked_mut(index)) }
     }
     pub fn into_inner(self) -> Vec<u32> {
-        let Self(data) = self;
+        let Self { data, .. } = self;
         data
     }
     pub fn into_bytes(self) -> Vec<u8> {
-        let Self(data) = self;
+        let Self { data, .. } = self;
         data.into_iter()
             .flat_map(|word| word.to_le_bytes())
             .collect()
@@ -195,16 +287,16 @@ impl VecAllocator {
         ArenaPointer::from(std::mem::size_of::<u32>() as u32)
     }
     pub fn end_offset(&self) -> ArenaPointer {
-        let Self(data) = self;
+        let Self { data, .. } = self;
         // Read the initial 4-byte allocator offset marker
         ArenaPointer::from(data[0])
     }
     pub fn as_words(&self) -> &[u32] {
-        let Self(data) = self;
+        let Self { data, .. } = self;
         data
     }
     pub fn as_bytes(&self) -> &[u8] {
-        let Self(data) = self;
+        let Self { data, .. } = self;
         unsafe {
             std::slice::from_raw_parts::<u8>(&data[0] as *const u32 as *const u8, data.len() * 4)
         }
@@ -214,13 +306,13 @@ impl VecAllocator {
 impl Default for VecAllocator {
     fn default() -> Self {
         // Start with an initial 4-byte length marker to match the WASM allocator representation
-        Self(vec![0x00000004u32])
+        Self::from_vec_u32(vec![0x00000004u32])
     }
 }
 
 impl Into<Vec<u32>> for VecAllocator {
     fn into(self) -> Vec<u32> {
-        let Self(data) = self;
+        let Self { data, .. } = self;
         data
     }
 }
@@ -280,7 +372,7 @@ impl ArenaAllocator for VecAllocator {
                 u32::from(self.end_offset())
             );
         } else {
-            let Self(data) = self;
+            let Self { data, .. } = self;
             // Ensure all allocations are 32-bit aligned
             let padded_size = pad_to_4_byte_offset(size) as u32;
             // Extend the allocation with zero-filled bytes
@@ -297,15 +389,54 @@ impl ArenaAllocator for VecAllocator {
                 u32::from(self.end_offset())
             );
         } else {
-            let Self(data) = self;
+            let Self { data, .. } = self;
             // Ensure all allocations are 32-bit aligned
             let padded_size = pad_to_4_byte_offset(size) as u32;
             // Truncate the allocation
             data.truncate((u32::from(offset) - padded_size) as usize / 4);
             // Update the length marker
             data[0] -= padded_size;
+            // Discard any interned strings that were located within the truncated region
+            let end_offset = self.end_offset();
+            self.interned_strings.retain(|_, pointers| {
+                pointers.retain(|pointer| *pointer < end_offset);
+                !pointers.is_empty()
+            });
         }
     }
+    fn intern_string(&mut self, pointer: ArenaPointer) -> ArenaPointer {
+        let hash = self.read_value::<u64, _>(Term::get_hash_pointer(pointer), |hash| *hash);
+        let existing = self.interned_strings.get(&hash).and_then(|pointers| {
+            let value = self.get_ref::<Term>(pointer).as_bytes();
+            pointers
+                .iter()
+                .copied()
+                .find(|existing| self.get_ref::<Term>(*existing).as_bytes() == value)
+        });
+        match existing {
+            Some(existing) => {
+                let size = pad_to_4_byte_offset(self.get_ref::<Term>(pointer).size_of());
+                let end_offset = self.end_offset();
+                if pointer.offset(size as u32) == end_offset {
+                    self.shrink(end_offset, size);
+                }
+                existing
+            }
+            None => {
+                self.interned_strings.entry(hash).or_default().push(pointer);
+                pointer
+            }
+        }
+    }
+    fn remap_interned_strings(&mut self, remapping: &ArenaRemapping) {
+        self.interned_strings.retain(|_, pointers| {
+            *pointers = pointers
+                .iter()
+                .filter_map(|pointer| remapping.get(*pointer))
+                .collect();
+            !pointers.is_empty()
+        });
+    }
 }
 
 // TODO: Abstract reference-wrapped arena types into blanket trait implementation
@@ -372,6 +503,12 @@ impl<'heap> ArenaAllocator for &'heap mut VecAllocator {
     fn shrink(&mut self, offset: ArenaPointer, size: usize) {
         self.deref_mut().shrink(offset, size)
     }
+    fn intern_string(&mut self, pointer: ArenaPointer) -> ArenaPointer {
+        self.deref_mut().intern_string(pointer)
+    }
+    fn remap_interned_strings(&mut self, remapping: &ArenaRemapping) {
+        self.deref_mut().remap_interned_strings(remapping)
+    }
 }
 
 impl<'heap> Arena for Rc<VecAllocator> {
@@ -467,6 +604,151 @@ impl<'heap> ArenaAllocator for Rc<RefCell<&'heap mut VecAllocator>> {
     fn shrink(&mut self, offset: ArenaPointer, size: usize) {
         self.deref().borrow_mut().shrink(offset, size)
     }
+    fn intern_string(&mut self, pointer: ArenaPointer) -> ArenaPointer {
+        self.deref().borrow_mut().intern_string(pointer)
+    }
+    fn remap_interned_strings(&mut self, remapping: &ArenaRemapping) {
+        self.deref().borrow_mut().remap_interned_strings(remapping)
+    }
+}
+
+/// Allocator that maintains size-class freelists of released allocations, allowing the space occupied by temporary
+/// terms to be reused without needing a full garbage collection pass
+///
+/// Released blocks are only reused by [`ArenaAllocator::allocate_fixed`] allocations with exactly the same padded size,
+/// ensuring that the arena remains a contiguous sequence of terms. All other allocations are passed through to the
+/// underlying allocator. String interning is disabled, as interned strings could otherwise be released and reused.
+///
+/// An interpreter can opt into freelist allocation by wrapping the instance, e.g. `FreelistAllocator::new(interpreter)`.
+pub struct FreelistAllocator<A: ArenaAllocator> {
+    inner: A,
+    /// Released allocations, indexed by padded allocation size
+    freelists: IntMap<u32, Vec<ArenaPointer>>,
+}
+
+impl<A: ArenaAllocator> FreelistAllocator<A> {
+    pub fn new(inner: A) -> Self {
+        Self {
+            inner,
+            freelists: Default::default(),
+        }
+    }
+    pub fn into_inner(self) -> A {
+        self.inner
+    }
+    /// Total number of bytes occupied by released allocations that are available for reuse
+    pub fn free_bytes(&self) -> usize {
+        self.freelists
+            .iter()
+            .map(|(size, blocks)| (*size as usize) * blocks.len())
+            .sum()
+    }
+}
+
+impl<A: ArenaAllocator> Deref for FreelistAllocator<A> {
+    type Target = A;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<A: ArenaAllocator> DerefMut for FreelistAllocator<A> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<A: ArenaAllocator> Arena for FreelistAllocator<A> {
+    type Slice<'a> = A::Slice<'a>
+        where
+            Self: 'a;
+    fn read_value<T, V>(&self, offset: ArenaPointer, selector: impl FnOnce(&T) -> V) -> V {
+        self.inner.read_value::<T, V>(offset, selector)
+    }
+    fn inner_pointer<T, V>(
+        &self,
+        offset: ArenaPointer,
+        selector: impl FnOnce(&T) -> &V,
+    ) -> ArenaPointer {
+        self.inner.inner_pointer::<T, V>(offset, selector)
+    }
+    fn as_slice<'a>(&'a self, offset: ArenaPointer, length: usize) -> Self::Slice<'a>
+    where
+        Self::Slice<'a>: 'a,
+    {
+        self.inner.as_slice(offset, length)
+    }
+}
+
+impl<A: ArenaAllocator> ArenaMut for FreelistAllocator<A> {
+    fn write<T: Sized>(&mut self, offset: ArenaPointer, value: T) {
+        self.inner.write(offset, value)
+    }
+}
+
+impl<A: ArenaAllocator> ArenaAllocator for FreelistAllocator<A> {
+    fn allocate<T: TermSize>(&mut self, value: T) -> ArenaPointer {
+        self.inner.allocate(value)
+    }
+    fn extend(&mut self, offset: ArenaPointer, size: usize) {
+        self.inner.extend(offset, size)
+    }
+    fn shrink(&mut self, offset: ArenaPointer, size: usize) {
+        self.inner.shrink(offset, size);
+        // Discard any released allocations that were located within the truncated region
+        let end_offset =
+            ArenaPointer::from(self.read_value::<u32, _>(ArenaPointer::from(0), |value| *value));
+        self.freelists.retain(|_, blocks| {
+            blocks.retain(|pointer| *pointer < end_offset);
+            !blocks.is_empty()
+        });
+    }
+    fn allocate_fixed<T: TermSize>(&mut self, value: T) -> ArenaPointer {
+        let size = pad_to_4_byte_offset(value.size_of());
+        // Values whose size exceeds their static size cannot be copied directly into a released block
+        let block = if size <= std::mem::size_of::<T>() {
+            self.freelists
+                .get_mut(&(size as u32))
+                .and_then(|blocks| blocks.pop())
+        } else {
+            None
+        };
+        match block {
+            None => self.inner.allocate(value),
+            Some(pointer) => {
+                // Copy the value into the released block word-by-word, leaving any trailing static padding behind
+                let words = unsafe {
+                    std::slice::from_raw_parts(&value as *const T as *const u32, size / 4)
+                };
+                for (index, word) in words.iter().enumerate() {
+                    self.inner
+                        .write::<u32>(pointer.offset((index * 4) as u32), *word);
+                }
+                pointer
+            }
+        }
+    }
+    fn release(&mut self, pointer: ArenaPointer) {
+        let size = pad_to_4_byte_offset(self.read_value::<Term, _>(pointer, |term| term.size_of()));
+        self.freelists.entry(size as u32).or_default().push(pointer);
+    }
+    fn collect_garbage(
+        &mut self,
+        start_offset: ArenaPointer,
+        roots: &mut [ArenaPointer],
+    ) -> GarbageCollectionStats {
+        // Released allocations are unreachable, so will be reclaimed by the collection
+        self.freelists.clear();
+        collect_garbage(self, start_offset, roots)
+    }
+    fn compact(
+        &mut self,
+        start_offset: ArenaPointer,
+        roots: impl IntoIterator<Item = ArenaPointer>,
+    ) -> (ArenaRemapping, GarbageCollectionStats) {
+        self.freelists.clear();
+        compact_arena(self, start_offset, roots)
+    }
 }
 
 fn pad_to_4_byte_offset(value: usize) -> usize {
@@ -517,3 +799,146 @@ impl<'a, T: TermSize, A: Arena> Iterator for ArenaIterator<'a, T, A> {
         }
     }
 }
+
+impl<'a, A: Arena> ArenaIterator<'a, Term, A> {
+    /// Convert this iterator into one that validates each term header before advancing, recovering from corrupt regions
+    /// by skipping ahead to the next plausible term header
+    pub fn checked(self) -> CheckedArenaIterator<'a, A> {
+        CheckedArenaIterator::new(self.arena, self.next_offset, self.end_offset)
+    }
+}
+
+/// Entry emitted when iterating over the terms contained within an arena in checked mode
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum CheckedArenaEntry {
+    /// Pointer to a term with a valid header
+    Term(ArenaPointer),
+    /// Region of the arena that could not be decoded as a sequence of valid terms
+    Corrupt(CorruptArenaRange),
+}
+
+/// Region of an arena that could not be decoded as a sequence of valid terms
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct CorruptArenaRange {
+    /// Offset of the invalid term header
+    pub start: ArenaPointer,
+    /// Offset of the next plausible term header (or the arena end offset if the remainder of the arena is corrupt)
+    pub end: ArenaPointer,
+    /// Reason the term header at the start of the range was rejected
+    pub reason: ArenaCorruption,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ArenaCorruption {
+    /// Remaining arena contents are too short to contain a term header
+    TruncatedHeader { length: usize },
+    /// Term type discriminant does not correspond to a known term type
+    InvalidTermType { discriminant: u32 },
+    /// Term size is invalid, or the term extends beyond the end of the arena
+    InvalidSize { size: usize },
+}
+
+impl std::fmt::Display for ArenaCorruption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TruncatedHeader { length } => {
+                write!(f, "Truncated term header ({length} bytes remaining)")
+            }
+            Self::InvalidTermType { discriminant } => {
+                write!(f, "Invalid term type discriminant: {discriminant:#010x}")
+            }
+            Self::InvalidSize { size } => write!(f, "Invalid term size: {size} bytes"),
+        }
+    }
+}
+
+/// Arena iterator that validates term boundaries as it goes, rather than assuming the arena contains a perfectly-formed
+/// sequence of back-to-back terms
+///
+/// Whenever an invalid term header is encountered, the iterator scans forward one word at a time until it finds a
+/// plausible term header, and emits the skipped region as a [`CheckedArenaEntry::Corrupt`] entry before resuming.
+pub struct CheckedArenaIterator<'a, A: Arena> {
+    arena: &'a A,
+    next_offset: ArenaPointer,
+    end_offset: ArenaPointer,
+}
+
+impl<'a, A: Arena> CheckedArenaIterator<'a, A> {
+    pub fn new(arena: &'a A, start_offset: ArenaPointer, end_offset: ArenaPointer) -> Self {
+        Self {
+            arena,
+            next_offset: start_offset,
+            end_offset,
+        }
+    }
+    /// Determine the padded size of the term located at the given offset, returning an error if the term header is not
+    /// valid
+    fn validate_term(&self, offset: ArenaPointer) -> Result<usize, ArenaCorruption> {
+        let remaining = (u32::from(self.end_offset) - u32::from(offset)) as usize;
+        let header_size = std::mem::size_of::<TermHeader>();
+        let min_size = header_size + std::mem::size_of::<u32>();
+        if remaining < min_size {
+            return Err(ArenaCorruption::TruncatedHeader { length: remaining });
+        }
+        let discriminant = self
+            .arena
+            .read_value::<u32, _>(offset.offset(header_size as u32), |value| *value);
+        if TermTypeDiscriminants::try_from(discriminant).is_err() {
+            return Err(ArenaCorruption::InvalidTermType { discriminant });
+        }
+        let size = pad_to_4_byte_offset(
+            self.arena
+                .read_value::<Term, _>(offset, |term| term.size_of()),
+        );
+        if size < min_size || size > remaining {
+            return Err(ArenaCorruption::InvalidSize { size });
+        }
+        Ok(size)
+    }
+    /// Determine whether the given offset looks like the start of a genuine term when resynchronizing after corruption
+    ///
+    /// In addition to having a valid header, the candidate term must have a non-zero hash (to avoid zero-filled regions
+    /// being decoded as a sequence of empty terms) and must be followed either by the end of the arena or by another
+    /// valid term header.
+    fn is_plausible_term(&self, offset: ArenaPointer) -> bool {
+        let Ok(size) = self.validate_term(offset) else {
+            return false;
+        };
+        if self.arena.read_value::<Term, _>(offset, |term| term.id()) == 0 {
+            return false;
+        }
+        let next_offset = offset.offset(size as u32);
+        next_offset == self.end_offset || self.validate_term(next_offset).is_ok()
+    }
+}
+
+impl<'a, A: Arena> Iterator for CheckedArenaIterator<'a, A> {
+    type Item = CheckedArenaEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_offset >= self.end_offset {
+            return None;
+        }
+        let current_pointer = self.next_offset;
+        match self.validate_term(current_pointer) {
+            Ok(size) => {
+                self.next_offset = current_pointer.offset(size as u32);
+                Some(CheckedArenaEntry::Term(current_pointer))
+            }
+            Err(reason) => {
+                // Scan forward word-by-word until a plausible term header is found
+                let mut candidate = current_pointer.offset(std::mem::size_of::<u32>() as u32);
+                while candidate < self.end_offset && !self.is_plausible_term(candidate) {
+                    candidate = candidate.offset(std::mem::size_of::<u32>() as u32);
+                }
+                let end = candidate.min(self.end_offset);
+                self.next_offset = end;
+                Some(CheckedArenaEntry::Corrupt(CorruptArenaRange {
+                    start: current_pointer,
+                    end,
+                    reason,
+                }))
+            }
+        }
+    }
+}
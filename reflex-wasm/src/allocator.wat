@@ -125,8 +125,8 @@
               (i32.const 0))
             (else
               ;; Otherwise halve the number of pages requested and try again
-              (local.set $pages (i32.div_u (local.get $pages) (i32.const 2))
-              (br $LOOP)))))
+              (local.set $pages (i32.div_u (local.get $pages) (i32.const 2)))
+              (br $LOOP))))
         (else
           ;; Return the number of pages that were successfully allocated
           (local.get $pages)))))
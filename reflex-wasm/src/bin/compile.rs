@@ -4,18 +4,26 @@
 // SPDX-FileContributor: Jordan Hall <j.hall@mwam.com> https://github.com/j-hall-mwam
 use std::{io::Write, iter::empty, path::PathBuf};
 
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use clap::Parser;
-use reflex::core::ArgType;
+use reflex::{
+    analysis::{GraphMetricsBudget, GraphMetricsConfig},
+    core::{ArgType, NumericSemantics},
+};
 use reflex_lang::{allocator::DefaultAllocator, SharedTermFactory};
 use reflex_parser::syntax::js::default_js_loaders;
 use reflex_wasm::{
     builtins::WasmCompilerBuiltins,
     cli::compile::{
-        parse_and_compile_module, CompilerRootConfig, GraphRootEntryPoint, ModuleEntryPoint,
-        WasmCompilerOptions, WasmCompilerRuntimeOptions,
+        parse_and_analyze_module, parse_and_audit_module, parse_and_compile_module_with_cache,
+        CompilerRootConfig, GraphRootEntryPoint, ModuleEntryPoint, WasmCompilerOptions,
+        WasmCompilerOutputFormat, WasmCompilerRuntimeOptions,
+    },
+    compiler::{
+        cache::CompilerCache,
+        options::{CompilerOptionsOverrides, ModuleCompilerOptions},
+        CompilerLimits, CompilerOptions,
     },
-    compiler::CompilerOptions,
 };
 
 // Reflex WebAssembly compiler
@@ -34,6 +42,12 @@ struct Args {
     /// Whether to skip compile-time evaluation where applicable
     #[arg(long)]
     unoptimized: bool,
+    /// Output an annotated WAT text listing of the compiled functions instead of a WASM binary module
+    #[arg(long)]
+    wat: bool,
+    /// Embed a WASM name section labelling the compiled functions (improves trap backtraces and profiler output)
+    #[arg(long)]
+    debug_info: bool,
     /// Compile array items as lazily-evaluated expressions
     #[arg(long)]
     lazy_list_items: bool,
@@ -52,9 +66,60 @@ struct Args {
     /// Compile constructor arguments as lazily-evaluated expressions
     #[arg(long)]
     lazy_constructors: bool,
+    /// Compiler options, specified as either a JSON object or a comma-separated list of key=value pairs (any
+    /// individual compiler flags take precedence)
+    #[arg(long)]
+    compiler_options: Option<CompilerOptions>,
+    /// Compiler options for functions declared within a given source module or directory, specified as
+    /// `<path>=<options>` where any omitted options are inherited from the global compiler options
+    /// (e.g. `lib/vendor=lazy_list_items=lazy,lazy_record_values=lazy`)
+    #[arg(long)]
+    module_compiler_options: Vec<ModuleCompilerOptions>,
     /// Wrap compiled lambdas in argument memoization wrappers
     #[arg(long)]
     memoize_lambdas: bool,
+    /// Import any standard library builtins that are not implemented by the runtime library as host functions, rather
+    /// than failing to compile (the host must provide an implementation for each imported builtin)
+    #[arg(long)]
+    builtin_fallback: bool,
+    /// List any builtins used by the entry points that are not implemented by the runtime library, without compiling
+    #[arg(long)]
+    audit_builtins: bool,
+    /// Invoke host debugger hooks on entry to each compiled function and before each builtin invocation (the host must
+    /// provide the debugger hook implementations)
+    #[arg(long)]
+    debug_hooks: bool,
+    /// Behavior of arithmetic builtins on integer overflow and non-finite float results (wrap, saturate, error)
+    #[arg(long, default_value_t = NumericSemantics::default())]
+    numeric_semantics: NumericSemantics,
+    /// Print a report of graph complexity metrics for each entry point to stderr
+    #[arg(long)]
+    metrics: bool,
+    /// Fail if any entry point graph contains more than the given number of distinct terms
+    #[arg(long)]
+    max_graph_nodes: Option<usize>,
+    /// Fail if any entry point graph exceeds the given depth
+    #[arg(long)]
+    max_graph_depth: Option<usize>,
+    /// Fail if any entry point graph contains more than the given number of distinct effects
+    #[arg(long)]
+    max_graph_effects: Option<usize>,
+    /// Fail if the estimated heap snapshot size of any entry point graph exceeds the given number of bytes
+    #[arg(long)]
+    max_snapshot_size: Option<usize>,
+    /// Fail if the static values interned into the heap snapshot during compilation exceed the given number of bytes
+    #[arg(long)]
+    max_interned_heap_size: Option<usize>,
+    /// Fail if any individual static value interned into the heap snapshot exceeds the given number of bytes
+    #[arg(long)]
+    max_interned_term_size: Option<usize>,
+    /// Path to compiler cache file used to reuse unchanged functions across compiler invocations (created if missing)
+    #[arg(long)]
+    compiler_cache: Option<PathBuf>,
+    /// Output a self-contained WASI command module whose `_start` function evaluates the given entry point and prints
+    /// the JSON result to stdout (runnable via `wasmtime run` without the Reflex host runtime)
+    #[arg(long, value_name = "EXPORT_NAME")]
+    standalone: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -85,7 +150,8 @@ fn main() -> Result<()> {
     let runtime_path = &args.runtime;
     let entry_points = args.entry_point;
     let unoptimized = args.unoptimized;
-    let factory = SharedTermFactory::<WasmCompilerBuiltins>::default();
+    let factory = SharedTermFactory::<WasmCompilerBuiltins>::default()
+        .with_numeric_semantics(args.numeric_semantics);
     let allocator = DefaultAllocator::default();
 
     // Load the runtime library module
@@ -96,7 +162,7 @@ fn main() -> Result<()> {
         let defaults = WasmCompilerOptions::default();
         WasmCompilerOptions {
             compiler: {
-                let defaults = CompilerOptions::default();
+                let defaults = args.compiler_options.unwrap_or_default();
                 CompilerOptions {
                     lazy_record_values: match args.lazy_record_values {
                         true => ArgType::Lazy,
@@ -122,6 +188,11 @@ fn main() -> Result<()> {
                         true => ArgType::Lazy,
                         false => defaults.lazy_constructors,
                     },
+                    constant_folding: !unoptimized && defaults.constant_folding,
+                    inline_lambda_threshold: match unoptimized {
+                        true => 0,
+                        false => defaults.inline_lambda_threshold,
+                    },
                     ..defaults
                 }
             },
@@ -129,9 +200,25 @@ fn main() -> Result<()> {
                 let defaults = WasmCompilerRuntimeOptions::default();
                 WasmCompilerRuntimeOptions {
                     memoize_lambdas: args.memoize_lambdas,
+                    builtin_fallback: args.builtin_fallback,
+                    debug_hooks: args.debug_hooks,
                     ..defaults
                 }
             },
+            output_format: match args.wat {
+                true => WasmCompilerOutputFormat::Text,
+                false => defaults.output_format,
+            },
+            debug_info: args.debug_info,
+            overrides: CompilerOptionsOverrides {
+                modules: args.module_compiler_options,
+                ..Default::default()
+            },
+            limits: CompilerLimits {
+                max_interned_heap_size: args.max_interned_heap_size,
+                max_interned_term_size: args.max_interned_term_size,
+            },
+            standalone: args.standalone.map(ModuleEntryPoint::from),
             ..defaults
         }
     };
@@ -144,20 +231,95 @@ fn main() -> Result<()> {
         })
         .collect::<Vec<_>>();
 
+    let budget = GraphMetricsBudget {
+        max_nodes: args.max_graph_nodes,
+        max_depth: args.max_graph_depth,
+        max_effects: args.max_graph_effects,
+        max_snapshot_size: args.max_snapshot_size,
+    };
+
+    // Report graph complexity metrics and enforce any configured budgets before compiling
+    if args.metrics || budget != GraphMetricsBudget::default() {
+        let results = parse_and_analyze_module(
+            entry_points.iter(),
+            default_js_loaders(empty(), &factory, &allocator),
+            std::env::vars(),
+            &factory,
+            &allocator,
+            &GraphMetricsConfig::default(),
+        )
+        .with_context(|| "Failed to analyze entry points")?;
+        let mut violations = Vec::new();
+        for (export_name, metrics) in results {
+            if args.metrics {
+                eprintln!("Entry point {}:\n{}", export_name.as_str(), metrics);
+            }
+            violations.extend(
+                budget.validate(&metrics).into_iter().map(|violation| {
+                    format!("Entry point {}: {}", export_name.as_str(), violation)
+                }),
+            );
+        }
+        if !violations.is_empty() {
+            return Err(anyhow!(violations.join("\n")));
+        }
+    }
+
+    // List any builtins that lack a runtime implementation and exit without compiling
+    if args.audit_builtins {
+        let missing_builtins = parse_and_audit_module(
+            entry_points.iter(),
+            default_js_loaders(empty(), &factory, &allocator),
+            std::env::vars(),
+            &runtime_bytes,
+            &factory,
+            &allocator,
+        )
+        .with_context(|| "Failed to audit entry points")?;
+        for builtin in missing_builtins.iter() {
+            println!("{}", builtin.name());
+        }
+        return if missing_builtins.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "{} builtins are not implemented by the runtime library",
+                missing_builtins.len()
+            ))
+        };
+    }
+
+    // Load the compiler cache from a previous compiler invocation if one exists
+    let mut compiler_cache = match args.compiler_cache.as_ref() {
+        Some(path) if path.exists() => {
+            Some(CompilerCache::load(path).with_context(|| "Failed to load compiler cache")?)
+        }
+        Some(_) => Some(CompilerCache::default()),
+        None => None,
+    };
+
     // Parse the input file and compile to WASM
-    let wasm_module = parse_and_compile_module(
+    let wasm_module = parse_and_compile_module_with_cache(
         entry_points.iter(),
         default_js_loaders(empty(), &factory, &allocator),
         std::env::vars(),
         &runtime_bytes,
         &factory,
         &allocator,
+        compiler_cache.as_mut(),
         &compiler_options,
         unoptimized,
     )
     .with_context(|| "Failed to compile WebAssembly module")?;
 
-    // Output compiled WASM module bytes
+    // Save the updated compiler cache for use by subsequent compiler invocations
+    if let (Some(path), Some(compiler_cache)) = (args.compiler_cache.as_ref(), compiler_cache) {
+        compiler_cache
+            .save(path)
+            .with_context(|| "Failed to save compiler cache")?;
+    }
+
+    // Output compiled WASM module bytes (or WAT text listing)
     match args.output {
         Some(name) => std::fs::write(&name, &wasm_module),
         None => std::io::stdout().write(&wasm_module).map(|_| ()),
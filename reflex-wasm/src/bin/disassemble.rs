@@ -0,0 +1,43 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{io::Write, path::PathBuf};
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use reflex_wasm::{cli::disassemble::disassemble_compiler_cache, compiler::cache::CompilerCache};
+
+// Reflex WebAssembly compiler output disassembler
+#[derive(Parser, Debug)]
+#[command(about)]
+struct Args {
+    /// Path to compiler cache file, as generated by the --compiler-cache compiler option
+    input: PathBuf,
+
+    /// Path to output file (defaults to stdout)
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+fn main() -> Result<()> {
+    // Parse CLI args
+    let args = Args::parse();
+    let Args {
+        input: input_path,
+        output: output_path,
+    } = args;
+
+    // Load the compiled functions and heap snapshot
+    let cache =
+        CompilerCache::load(&input_path).with_context(|| "Failed to load compiler cache")?;
+    let disassembly =
+        disassemble_compiler_cache(&cache).with_context(|| "Failed to decode compiler cache")?;
+
+    // Format the decoded instruction listings
+    let output = format!("{}", disassembly);
+    match output_path {
+        Some(name) => std::fs::write(&name, output.as_bytes()),
+        None => std::io::stdout().write(output.as_bytes()).map(|_| ()),
+    }
+    .with_context(|| "Failed to write output file")
+}
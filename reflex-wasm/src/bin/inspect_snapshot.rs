@@ -0,0 +1,109 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{io::Write, path::PathBuf};
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use reflex_wasm::{
+    cli::{
+        compile::parse_source_metadata_section,
+        inspect_snapshot::inspect_heap_snapshot_with_source_metadata,
+    },
+    heap_snapshot::{accept_legacy_heap_snapshot, decode_heap_snapshot_with_migration},
+    serialize::diff_heap_snapshots,
+};
+
+// Reflex WebAssembly heap snapshot inspector
+#[derive(Parser, Debug)]
+#[command(about)]
+struct Args {
+    /// Path to input heap snapshot
+    input: PathBuf,
+
+    /// Path to the compiled WASM module from which the snapshot was captured, used to label terms with the source
+    /// locations embedded by the --debug-info compiler option
+    #[arg(long)]
+    module: Option<PathBuf>,
+
+    /// Path to a baseline heap snapshot to compare against, outputting the terms added and removed since the baseline
+    /// instead of the decoded heap layout
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// Output the decoded heap layout as JSON
+    #[arg(long)]
+    json: bool,
+
+    /// Path to output file (defaults to stdout)
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+fn main() -> Result<()> {
+    // Parse CLI args
+    let args = Args::parse();
+    let Args {
+        input: input_path,
+        module: module_path,
+        baseline: baseline_path,
+        json,
+        output: output_path,
+    } = args;
+
+    // Load and decode the heap snapshot
+    let snapshot_bytes =
+        std::fs::read(&input_path).with_context(|| "Failed to load heap snapshot")?;
+    let snapshot_bytes = decode_heap_snapshot(&snapshot_bytes)?;
+
+    // Compare the heap snapshot against the baseline snapshot if one was provided
+    if let Some(baseline_path) = baseline_path {
+        let baseline_bytes =
+            std::fs::read(&baseline_path).with_context(|| "Failed to load baseline snapshot")?;
+        let baseline_bytes = decode_heap_snapshot(&baseline_bytes)?;
+        let diff = diff_heap_snapshots(&baseline_bytes, &snapshot_bytes)
+            .with_context(|| "Failed to compare heap snapshots")?;
+        let output = if json {
+            serde_json::to_string_pretty(&diff).with_context(|| "Failed to serialize output")?
+        } else {
+            format!("{}", diff)
+        };
+        return write_output(output_path, output);
+    }
+
+    let source_metadata = match module_path {
+        Some(module_path) => {
+            let module_bytes =
+                std::fs::read(&module_path).with_context(|| "Failed to load WASM module")?;
+            parse_source_metadata_section(&module_bytes)
+                .with_context(|| "Failed to parse WASM module source metadata")?
+        }
+        None => None,
+    };
+    let layout =
+        inspect_heap_snapshot_with_source_metadata(&snapshot_bytes, source_metadata.as_ref())
+            .with_context(|| "Failed to decode heap snapshot")?;
+
+    // Format the decoded heap layout
+    let output = if json {
+        serde_json::to_string_pretty(&layout).with_context(|| "Failed to serialize output")?
+    } else {
+        format!("{}", layout)
+    };
+
+    write_output(output_path, output)
+}
+
+fn decode_heap_snapshot(bytes: &[u8]) -> Result<Vec<u8>> {
+    // Legacy headerless snapshots are accepted as-is, as the inspector is able to report any corrupt heap contents
+    decode_heap_snapshot_with_migration(bytes, accept_legacy_heap_snapshot)
+        .with_context(|| "Invalid heap snapshot")
+}
+
+fn write_output(output_path: Option<PathBuf>, output: String) -> Result<()> {
+    match output_path {
+        Some(name) => std::fs::write(&name, output.as_bytes()),
+        None => std::io::stdout().write(output.as_bytes()).map(|_| ()),
+    }
+    .with_context(|| "Failed to write output file")
+}
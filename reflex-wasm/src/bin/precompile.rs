@@ -5,7 +5,7 @@ use std::{io::Write, path::PathBuf};
 
 use anyhow::{Context, Result};
 use clap::Parser;
-use wasmtime::Engine;
+use reflex_wasm::interpreter::WasmEngineOptions;
 
 // Cranelift precompiler tool for WebAssembly modules
 #[derive(Parser, Debug)]
@@ -17,6 +17,10 @@ struct Args {
     /// Path to output file (defaults to stdout)
     #[arg(short, long)]
     output: Option<PathBuf>,
+
+    /// Instrument the compiled module with fuel metering (required for budgeted evaluations)
+    #[arg(long)]
+    fuel_metering: bool,
 }
 
 fn main() -> Result<()> {
@@ -25,11 +29,17 @@ fn main() -> Result<()> {
     let Args {
         input: input_path,
         output: output_path,
+        fuel_metering,
     } = args;
 
     // Load the WASM module
     let wasm_bytes = std::fs::read(&input_path).with_context(|| "Failed to load input module")?;
-    let engine = Engine::default();
+    let engine = WasmEngineOptions {
+        // The fuel limit is determined at instantiation time, so any limit will produce the same compiled output
+        fuel_limit: fuel_metering.then_some(u64::MAX),
+        ..Default::default()
+    }
+    .create_engine()?;
     let output_bytes = engine.precompile_module(&wasm_bytes)?;
 
     // Output precompiled Cranelift output
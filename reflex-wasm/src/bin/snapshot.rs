@@ -5,7 +5,10 @@ use std::{io::Write, path::PathBuf};
 
 use anyhow::{Context, Result};
 use clap::Parser;
-use reflex_wasm::cli::snapshot::{capture_heap_snapshot, inline_heap_snapshot, MemorySnapshot};
+use reflex_wasm::{
+    cli::snapshot::{capture_heap_snapshot, inline_heap_snapshot, MemorySnapshot},
+    heap_snapshot::decode_heap_snapshot,
+};
 
 // Reflex WebAssembly memory snapshot tool
 #[derive(Parser, Debug)]
@@ -49,8 +52,9 @@ fn main() -> Result<()> {
     // Capture the heap snapshot if one was not provided
     let snapshot = match snapshot {
         Some(snapshot_path) => std::fs::read(&snapshot_path)
-            .map(MemorySnapshot::from_bytes)
-            .with_context(|| "Failed to load heap snapshot"),
+            .with_context(|| "Failed to load heap snapshot")
+            .and_then(|bytes| decode_heap_snapshot(&bytes).with_context(|| "Invalid heap snapshot"))
+            .map(MemorySnapshot::from_bytes),
         None => capture_heap_snapshot(&wasm_bytes, &memory_name, inline_globals)
             .with_context(|| "Failed to capture initial heap snapshot"),
     }?;
@@ -7,7 +7,11 @@ use anyhow::{Context, Result};
 use clap::Parser;
 use reflex_wasm::{
     allocator::Arena,
-    interpreter::{WasmInterpreter, WasmProgram},
+    exports::WasmHostImports,
+    interpreter::{
+        WasiOptions, WasiPreopenedDir, WasiStdio, WasmEngineOptions, WasmInterpreter,
+        WasmModuleCache, WasmProgram,
+    },
     ArenaPointer,
 };
 
@@ -29,6 +33,44 @@ struct Args {
     /// Whether to display human-readable output
     #[arg(short, long)]
     formatted: bool,
+
+    /// Path to a directory in which to cache the natively-compiled module, reducing startup latency on subsequent runs
+    #[arg(long)]
+    module_cache: Option<PathBuf>,
+
+    /// Grant the module access to a host directory, specified as either `<HOST_PATH>` or `<HOST_PATH>:<GUEST_PATH>`
+    #[arg(long = "dir", value_parser = parse_preopened_dir)]
+    dirs: Vec<(PathBuf, PathBuf)>,
+
+    /// Expose an environment variable to the module, specified as `<KEY>=<VALUE>`
+    #[arg(long = "env", value_parser = parse_env_var)]
+    env: Vec<(String, String)>,
+
+    /// Source of the module stdin stream (either `inherit`, `null`, or a path to an input file)
+    #[arg(long, default_value = "inherit")]
+    stdin: WasiStdio,
+
+    /// Destination of the module stdout stream (either `inherit`, `null`, or a path to an output file)
+    #[arg(long, default_value = "inherit")]
+    stdout: WasiStdio,
+
+    /// Destination of the module stderr stream (either `inherit`, `null`, or a path to an output file)
+    #[arg(long, default_value = "inherit")]
+    stderr: WasiStdio,
+}
+
+fn parse_preopened_dir(value: &str) -> Result<(PathBuf, PathBuf), String> {
+    match value.split_once(':') {
+        Some((host_path, guest_path)) => Ok((PathBuf::from(host_path), PathBuf::from(guest_path))),
+        None => Ok((PathBuf::from(value), PathBuf::from(value))),
+    }
+}
+
+fn parse_env_var(value: &str) -> Result<(String, String), String> {
+    match value.split_once('=') {
+        Some((key, value)) => Ok((String::from(key), String::from(value))),
+        None => Err(format!("Invalid environment variable: {value}")),
+    }
 }
 
 fn main() -> Result<()> {
@@ -39,7 +81,27 @@ fn main() -> Result<()> {
         entry_point,
         precompiled,
         formatted,
+        module_cache,
+        dirs,
+        env,
+        stdin,
+        stdout,
+        stderr,
     } = args;
+    let wasi_options = WasiOptions {
+        preopened_dirs: dirs
+            .into_iter()
+            .map(|(host_path, guest_path)| WasiPreopenedDir {
+                host_path,
+                guest_path,
+            })
+            .collect(),
+        env,
+        stdin,
+        stdout,
+        stderr,
+        ..Default::default()
+    };
 
     // Load the WASM module
     let wasm_bytes = std::fs::read(&input_path).with_context(|| "Failed to load input module")?;
@@ -48,8 +110,18 @@ fn main() -> Result<()> {
     } else {
         WasmProgram::from_wasm(wasm_bytes)
     };
-    let mut interpreter = WasmInterpreter::instantiate(&wasm_module, "memory")
-        .with_context(|| "Failed to instantiate WebAssembly interpreter")?;
+    let wasm_module = match module_cache {
+        Some(directory) => wasm_module.with_module_cache(WasmModuleCache::new(directory)),
+        None => wasm_module,
+    };
+    let mut interpreter = WasmInterpreter::instantiate_with_wasi_options(
+        &wasm_module,
+        "memory",
+        &WasmEngineOptions::default(),
+        &WasmHostImports::default(),
+        &wasi_options,
+    )
+    .with_context(|| "Failed to instantiate WebAssembly interpreter")?;
     let (result, dependencies) = interpreter
         .call::<(), (u32, u32)>(
             entry_point
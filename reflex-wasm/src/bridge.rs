@@ -0,0 +1,164 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use reflex::core::{Arity, Expression, ExpressionFactory, HeapAllocator};
+
+use crate::{
+    allocator::{Arena, ArenaAllocator},
+    factory::{WasmTermConversionCache, WasmTermFactory},
+    term_type::{ConditionTerm, TypedTerm, WasmExpression},
+    ArenaRef, FunctionIndex,
+};
+
+/// Bidirectional converter between host expressions and arena-allocated WASM terms
+///
+/// Conversion results are memoized across calls, allowing host code to repeatedly inject host-built expressions into the
+/// arena (and inspect WASM evaluation results as host expressions) without re-converting shared subterms. The cache must
+/// be cleared via [`WasmExpressionBridge::clear_cache`] whenever the underlying arena is garbage-collected or reset.
+pub struct WasmExpressionBridge<T, TFactory, TAllocator, A>
+where
+    T: Expression,
+    TFactory: ExpressionFactory<T>,
+    TAllocator: HeapAllocator<T>,
+    A: Arena,
+{
+    wasm_factory: WasmTermFactory<A>,
+    factory: TFactory,
+    allocator: TAllocator,
+    indirect_call_arity: HashMap<FunctionIndex, Arity>,
+    cache: WasmTermConversionCache<T>,
+}
+
+impl<T, TFactory, TAllocator, A> WasmExpressionBridge<T, TFactory, TAllocator, A>
+where
+    T: Expression,
+    TFactory: ExpressionFactory<T>,
+    TAllocator: HeapAllocator<T>,
+    A: Arena + ArenaAllocator,
+    Rc<RefCell<A>>: Arena,
+{
+    pub fn new(
+        arena: Rc<RefCell<A>>,
+        factory: TFactory,
+        allocator: TAllocator,
+        indirect_call_arity: HashMap<FunctionIndex, Arity>,
+    ) -> Self {
+        Self {
+            wasm_factory: WasmTermFactory::from(arena),
+            factory,
+            allocator,
+            indirect_call_arity,
+            cache: Default::default(),
+        }
+    }
+    pub fn import(&mut self, expression: &T) -> Result<WasmExpression<WasmTermFactory<A>>, T>
+    where
+        T::Builtin: Into<crate::stdlib::Stdlib>,
+    {
+        self.wasm_factory
+            .import_with_cache(expression, &self.factory, &mut self.cache)
+    }
+    pub fn import_condition(
+        &mut self,
+        condition: &T::Signal,
+    ) -> Result<ArenaRef<TypedTerm<ConditionTerm>, WasmTermFactory<A>>, T>
+    where
+        T::Builtin: Into<crate::stdlib::Stdlib>,
+    {
+        self.wasm_factory
+            .import_condition_with_cache(condition, &self.factory, &mut self.cache)
+    }
+    pub fn export(
+        &mut self,
+        expression: &WasmExpression<Rc<RefCell<A>>>,
+    ) -> Result<T, WasmExpression<Rc<RefCell<A>>>> {
+        self.wasm_factory.export_with_cache(
+            expression,
+            &self.factory,
+            &self.allocator,
+            &self.indirect_call_arity,
+            &mut self.cache,
+        )
+    }
+    pub fn export_condition(
+        &mut self,
+        condition: &ArenaRef<TypedTerm<ConditionTerm>, Rc<RefCell<A>>>,
+    ) -> Result<T::Signal, WasmExpression<Rc<RefCell<A>>>> {
+        self.wasm_factory.export_condition_with_cache(
+            condition,
+            &self.factory,
+            &self.allocator,
+            &self.indirect_call_arity,
+            &mut self.cache,
+        )
+    }
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reflex::core::{ExpressionListType, ListTermType, RefType};
+    use reflex_lang::{allocator::DefaultAllocator, SharedTermFactory};
+
+    use crate::{allocator::VecAllocator, stdlib::Stdlib};
+
+    use super::*;
+
+    #[test]
+    fn shared_subterms() {
+        let factory = SharedTermFactory::<Stdlib>::default();
+        let allocator = DefaultAllocator::default();
+        let shared = factory.create_list_term(allocator.create_list([
+            factory.create_int_term(3),
+            factory.create_string_term(allocator.create_static_string("foo")),
+        ]));
+        let expression = factory.create_list_term(allocator.create_list([
+            shared.clone(),
+            shared.clone(),
+            factory.create_nil_term(),
+        ]));
+        let mut arena = VecAllocator::default();
+        let arena = Rc::new(RefCell::new(&mut arena));
+        let mut bridge = WasmExpressionBridge::new(
+            Rc::clone(&arena),
+            factory.clone(),
+            allocator.clone(),
+            Default::default(),
+        );
+        let imported = bridge.import(&expression).unwrap();
+        let items = imported
+            .as_list_term()
+            .unwrap()
+            .as_inner()
+            .items()
+            .as_deref()
+            .iter()
+            .collect::<Vec<_>>();
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0], items[1]);
+        assert_ne!(items[0], items[2]);
+        let reimported = bridge.import(&shared).unwrap();
+        assert_eq!(reimported.as_pointer(), items[0]);
+        let exported = bridge
+            .export(&WasmExpression::new(
+                Rc::clone(&arena),
+                imported.as_pointer(),
+            ))
+            .unwrap();
+        assert_eq!(exported, expression);
+        let items = factory
+            .match_list_term(&exported)
+            .unwrap()
+            .items()
+            .as_deref()
+            .iter()
+            .map(|item| item.as_deref().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(items[0], shared);
+        assert_eq!(items[1], shared);
+    }
+}
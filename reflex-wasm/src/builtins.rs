@@ -116,6 +116,18 @@ impl Builtin for WasmCompilerBuiltins {
             WasmCompilerBuiltins::GraphQl(term) => term.should_parallelize(args),
         }
     }
+    fn apply_static<T: Expression<Builtin = Self>>(
+        &self,
+        args: &[T],
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+    ) -> Option<T> {
+        match self {
+            WasmCompilerBuiltins::Stdlib(term) => term.apply_static(args, factory, allocator),
+            WasmCompilerBuiltins::Js(term) => term.apply_static(args, factory, allocator),
+            _ => None,
+        }
+    }
 }
 impl std::fmt::Display for WasmCompilerBuiltins {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -213,11 +225,41 @@ impl From<stdlib::Contains> for WasmCompilerBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::DateAdd> for WasmCompilerBuiltins {
+    fn from(value: stdlib::DateAdd) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateDiff> for WasmCompilerBuiltins {
+    fn from(value: stdlib::DateDiff) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateOffset> for WasmCompilerBuiltins {
+    fn from(value: stdlib::DateOffset) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateTruncate> for WasmCompilerBuiltins {
+    fn from(value: stdlib::DateTruncate) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::DateWithOffset> for WasmCompilerBuiltins {
+    fn from(value: stdlib::DateWithOffset) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Divide> for WasmCompilerBuiltins {
     fn from(value: stdlib::Divide) -> Self {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Duration> for WasmCompilerBuiltins {
+    fn from(value: stdlib::Duration) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Effect> for WasmCompilerBuiltins {
     fn from(value: stdlib::Effect) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -238,6 +280,11 @@ impl From<stdlib::Equal> for WasmCompilerBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Eval> for WasmCompilerBuiltins {
+    fn from(value: stdlib::Eval) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Filter> for WasmCompilerBuiltins {
     fn from(value: stdlib::Filter) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -328,11 +375,26 @@ impl From<stdlib::Map> for WasmCompilerBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Match> for WasmCompilerBuiltins {
+    fn from(value: stdlib::Match) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Max> for WasmCompilerBuiltins {
     fn from(value: stdlib::Max) -> Self {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Memo> for WasmCompilerBuiltins {
+    fn from(value: stdlib::Memo) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::MemoStore> for WasmCompilerBuiltins {
+    fn from(value: stdlib::MemoStore) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Merge> for WasmCompilerBuiltins {
     fn from(value: stdlib::Merge) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -373,6 +435,11 @@ impl From<stdlib::PushFront> for WasmCompilerBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Quote> for WasmCompilerBuiltins {
+    fn from(value: stdlib::Quote) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Raise> for WasmCompilerBuiltins {
     fn from(value: stdlib::Raise) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -418,6 +485,11 @@ impl From<stdlib::ResolveList> for WasmCompilerBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Reveal> for WasmCompilerBuiltins {
+    fn from(value: stdlib::Reveal) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Round> for WasmCompilerBuiltins {
     fn from(value: stdlib::Round) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -433,6 +505,21 @@ impl From<stdlib::Slice> for WasmCompilerBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Sort> for WasmCompilerBuiltins {
+    fn from(value: stdlib::Sort) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::SortBy> for WasmCompilerBuiltins {
+    fn from(value: stdlib::SortBy) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
+impl From<stdlib::SortMerge> for WasmCompilerBuiltins {
+    fn from(value: stdlib::SortMerge) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Split> for WasmCompilerBuiltins {
     fn from(value: stdlib::Split) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -448,6 +535,11 @@ impl From<stdlib::Subtract> for WasmCompilerBuiltins {
         Self::from(stdlib::Stdlib::from(value))
     }
 }
+impl From<stdlib::Template> for WasmCompilerBuiltins {
+    fn from(value: stdlib::Template) -> Self {
+        Self::from(stdlib::Stdlib::from(value))
+    }
+}
 impl From<stdlib::Unzip> for WasmCompilerBuiltins {
     fn from(value: stdlib::Unzip) -> Self {
         Self::from(stdlib::Stdlib::from(value))
@@ -474,6 +566,11 @@ impl From<reflex_json::stdlib::JsonSerialize> for WasmCompilerBuiltins {
         Self::from(reflex_json::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_json::stdlib::JsonSerializeCanonical> for WasmCompilerBuiltins {
+    fn from(value: reflex_json::stdlib::JsonSerializeCanonical) -> Self {
+        Self::from(reflex_json::stdlib::Stdlib::from(value))
+    }
+}
 
 impl From<reflex_js::stdlib::Accessor> for WasmCompilerBuiltins {
     fn from(value: reflex_js::stdlib::Accessor) -> Self {
@@ -515,6 +612,11 @@ impl From<reflex_js::stdlib::LogArgs> for WasmCompilerBuiltins {
         Self::from(reflex_js::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_js::stdlib::LogScope> for WasmCompilerBuiltins {
+    fn from(value: reflex_js::stdlib::LogScope) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_js::stdlib::ParseDate> for WasmCompilerBuiltins {
     fn from(value: reflex_js::stdlib::ParseDate) -> Self {
         Self::from(reflex_js::stdlib::Stdlib::from(value))
@@ -530,6 +632,16 @@ impl From<reflex_js::stdlib::ParseInt> for WasmCompilerBuiltins {
         Self::from(reflex_js::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_js::stdlib::ScopedLog> for WasmCompilerBuiltins {
+    fn from(value: reflex_js::stdlib::ScopedLog) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_js::stdlib::ScopedLogArgs> for WasmCompilerBuiltins {
+    fn from(value: reflex_js::stdlib::ScopedLogArgs) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_js::stdlib::Throw> for WasmCompilerBuiltins {
     fn from(value: reflex_js::stdlib::Throw) -> Self {
         Self::from(reflex_js::stdlib::Stdlib::from(value))
@@ -592,6 +704,26 @@ impl From<reflex_handlers::stdlib::DecrementVariable> for WasmCompilerBuiltins {
         Self::from(reflex_handlers::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_handlers::stdlib::Paginate> for WasmCompilerBuiltins {
+    fn from(value: reflex_handlers::stdlib::Paginate) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::Throttle> for WasmCompilerBuiltins {
+    fn from(value: reflex_handlers::stdlib::Throttle) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::Debounce> for WasmCompilerBuiltins {
+    fn from(value: reflex_handlers::stdlib::Debounce) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::Sample> for WasmCompilerBuiltins {
+    fn from(value: reflex_handlers::stdlib::Sample) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
 
 impl From<reflex_graphql::stdlib::CollectQueryListItems> for WasmCompilerBuiltins {
     fn from(value: reflex_graphql::stdlib::CollectQueryListItems) -> Self {
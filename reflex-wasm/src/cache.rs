@@ -10,6 +10,9 @@ use crate::{
     ArenaPointer, ArenaRef, Array, Term,
 };
 
+/// Effect type used for the condition that marks a dependency on a cached evaluation result
+pub const EFFECT_TYPE_CACHE: &str = "reflex::cache";
+
 #[derive(Clone, Copy, Debug)]
 #[repr(C)]
 pub struct EvaluationCache {
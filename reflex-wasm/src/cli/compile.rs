@@ -16,45 +16,58 @@ use std::{
 use anyhow::Context;
 use derivative::Derivative;
 use reflex::{
+    analysis::{GraphMetrics, GraphMetricsConfig},
     cache::SubstitutionCache,
     core::{
         Arity, Expression, ExpressionFactory, HeapAllocator, LambdaTermType, ModuleLoader,
-        Reducible, Rewritable, Uuid,
+        NumericSemantics, Reducible, Rewritable, Uuid,
     },
+    sandbox::{SandboxPolicy, SandboxViolation},
+    source_map::{SourceMetadata, SourceMetadataRecorder, SourceMetadataTable},
 };
-use reflex_parser::{create_parser, ParserBuiltin, Syntax, SyntaxParser};
+use reflex_parser::{create_parser_with_source_metadata, ParserBuiltin, Syntax, SyntaxParser};
 use reflex_utils::Visitable;
 use strum::IntoEnumIterator;
 use walrus::{
     self,
     ir::{InstrSeqId, Value},
     ActiveData, ActiveDataLocation, DataKind, ElementId, ElementKind, ExportItem, FunctionId,
-    GlobalId, InitExpr, InstrSeqBuilder, LocalId, MemoryId, Module, TableId, ValType,
+    GlobalId, GlobalKind, InitExpr, InstrSeqBuilder, LocalId, MemoryId, Module, TableId, ValType,
 };
 
 use crate::{
     allocator::{Arena, ArenaAllocator, ArenaIterator, VecAllocator},
+    cli::standalone::{add_standalone_entry_point, WasmStandaloneError},
     compiler::{
+        cache::CompilerCache,
         error::TypedStackError,
         instruction::{self, CompiledInstruction},
+        options::{CompilerOptionsError, CompilerOptionsOverrides},
         runtime::{builtin::RuntimeBuiltin, globals::RuntimeGlobal},
-        wasm::generate::{
-            generate_cached_function_wrapper, generate_indirect_function_wrapper,
-            generate_stateful_function, RuntimeBuiltinMappings, RuntimeExportMappings,
-            RuntimeGlobalMappings, RuntimeStdlibMappings, WasmCompiledFunctionMappings,
-            WasmGeneratorError, WasmGeneratorOptions,
+        wasm::{
+            generate::{
+                generate_cached_function_wrapper, generate_indirect_function_wrapper,
+                generate_stateful_function, RuntimeBuiltinMappings, RuntimeExportMappings,
+                RuntimeGlobalMappings, RuntimeStdlibMappings, WasmCompiledFunctionMappings,
+                WasmGeneratorError, WasmGeneratorOptions, WasmInstructionAnnotations,
+            },
+            wat::print_wat_functions,
         },
         CapturingThunk, CompileWasm, CompiledBlock, CompiledFunctionId, CompiledLambda,
-        CompiledThunk, CompilerOptions, CompilerStack, CompilerState, ConstValue, FunctionPointer,
-        ParamsSignature, PureThunk, TypeSignature, ValueType,
+        CompiledThunk, CompilerLimits, CompilerOptions, CompilerStack, CompilerState, ConstValue,
+        FunctionPointer, ParamsSignature, PureThunk, TypeSignature, ValueType,
     },
-    factory::WasmTermFactory,
-    hash::TermHasher,
+    debugger::{DEBUGGER_ENTER_FUNCTION_HOOK, DEBUGGER_HOOK_MODULE},
+    factory::{WasmTermConversionCache, WasmTermFactory},
+    hash::{TermHashState, TermHasher},
     stdlib,
     term_type::{BuiltinTerm, LambdaTerm, TermType, TypedTerm},
     ArenaPointer, ArenaPointerIterator, ArenaRef, FunctionIndex, Term, WASM_PAGE_SIZE,
 };
 
+/// Name of the custom section used to embed source metadata within compiled WASM modules
+pub const SOURCE_METADATA_SECTION_NAME: &str = "reflex.source_metadata";
+
 const CACHED_FUNCTION_TEMPLATE: &'static [u8] =
     include_bytes!("../../templates/cached_function.wasm");
 
@@ -94,6 +107,7 @@ pub trait CompilerEntryPoint<
 {
     fn export_name(&self) -> &ModuleEntryPoint;
     fn root(&self) -> &CompilerRootConfig;
+    fn sandbox_policy(&self) -> &SandboxPolicy;
     fn transform(&self, expression: &T, factory: &TFactory, allocator: &TAllocator) -> Option<T>;
 }
 
@@ -106,6 +120,7 @@ pub struct GraphRootEntryPoint<
 > {
     export_name: ModuleEntryPoint,
     root: CompilerRootConfig,
+    sandbox_policy: SandboxPolicy,
     _expression: PhantomData<T>,
     _factory: PhantomData<TFactory>,
     _allocator: PhantomData<TAllocator>,
@@ -118,11 +133,18 @@ impl<T: Expression, TFactory: ExpressionFactory<T>, TAllocator: HeapAllocator<T>
         Self {
             export_name,
             root,
+            sandbox_policy: SandboxPolicy::default(),
             _expression: PhantomData,
             _factory: PhantomData,
             _allocator: PhantomData,
         }
     }
+    pub fn with_sandbox_policy(self, sandbox_policy: SandboxPolicy) -> Self {
+        Self {
+            sandbox_policy,
+            ..self
+        }
+    }
 }
 
 impl<T: Expression, TFactory: ExpressionFactory<T>, TAllocator: HeapAllocator<T>>
@@ -134,6 +156,9 @@ impl<T: Expression, TFactory: ExpressionFactory<T>, TAllocator: HeapAllocator<T>
     fn root(&self) -> &CompilerRootConfig {
         &self.root
     }
+    fn sandbox_policy(&self) -> &SandboxPolicy {
+        &self.sandbox_policy
+    }
     fn transform(
         &self,
         _expression: &T,
@@ -153,6 +178,7 @@ pub struct ExpressionFactoryEntryPoint<
 > {
     export_name: ModuleEntryPoint,
     root: CompilerRootConfig,
+    sandbox_policy: SandboxPolicy,
     _expression: PhantomData<T>,
     _factory: PhantomData<TFactory>,
     _allocator: PhantomData<TAllocator>,
@@ -165,11 +191,18 @@ impl<T: Expression, TFactory: ExpressionFactory<T>, TAllocator: HeapAllocator<T>
         Self {
             export_name,
             root,
+            sandbox_policy: SandboxPolicy::default(),
             _expression: PhantomData,
             _factory: PhantomData,
             _allocator: PhantomData,
         }
     }
+    pub fn with_sandbox_policy(self, sandbox_policy: SandboxPolicy) -> Self {
+        Self {
+            sandbox_policy,
+            ..self
+        }
+    }
 }
 
 impl<T: Expression, TFactory: ExpressionFactory<T>, TAllocator: HeapAllocator<T>>
@@ -182,6 +215,9 @@ impl<T: Expression, TFactory: ExpressionFactory<T>, TAllocator: HeapAllocator<T>
     fn root(&self) -> &CompilerRootConfig {
         &self.root
     }
+    fn sandbox_policy(&self) -> &SandboxPolicy {
+        &self.sandbox_policy
+    }
     fn transform(&self, expression: &T, factory: &TFactory, _allocator: &TAllocator) -> Option<T> {
         Some(factory.create_lambda_term(0, expression.clone()))
     }
@@ -336,6 +372,9 @@ pub enum WasmCompilerError {
     RuntimeBuiltinNotFound(RuntimeBuiltin),
     StdlibBuiltinNotFound(stdlib::Stdlib),
     GeneratorError(WasmGeneratorError),
+    SandboxError(ModuleEntryPoint, SandboxViolation),
+    InvalidCompilerOptions(PathBuf, CompilerOptionsError),
+    StandaloneError(WasmStandaloneError),
 }
 
 impl std::error::Error for WasmCompilerError {}
@@ -388,6 +427,15 @@ impl std::fmt::Display for WasmCompilerError {
             Self::StdlibBuiltinNotFound(target) => {
                 write!(f, "Standard library function not found: {}", target.name())
             }
+            Self::SandboxError(entry_point, err) => {
+                write!(f, "Invalid entry point {}: {err}", entry_point.as_str())
+            }
+            Self::InvalidCompilerOptions(path, err) => {
+                write!(f, "Invalid compiler options for {}: {err}", path.display())
+            }
+            Self::StandaloneError(err) => {
+                write!(f, "Failed to generate standalone WASI module: {err}")
+            }
         }
     }
 }
@@ -407,12 +455,54 @@ pub fn parse_and_compile_module<
     compiler_options: &WasmCompilerOptions,
     unoptimized: bool,
 ) -> Result<Vec<u8>, WasmCompilerError>
+where
+    T::Builtin: ParserBuiltin + Into<crate::stdlib::Stdlib>,
+    // TODO: Remove unnecessary trait bounds
+    T: Rewritable<T> + Reducible<T>,
+{
+    parse_and_compile_module_with_cache(
+        entry_points,
+        module_loader,
+        env_vars,
+        runtime,
+        factory,
+        allocator,
+        None,
+        compiler_options,
+        unoptimized,
+    )
+}
+
+/// Parse and compile the provided entry points, reusing any unchanged lambdas and thunks from the provided compiler cache
+///
+/// The cache is updated in-place to contain all the functions compiled during this compiler invocation.
+#[allow(clippy::too_many_arguments)]
+pub fn parse_and_compile_module_with_cache<
+    'a,
+    T: Expression + 'static,
+    TFactory: ExpressionFactory<T> + Clone + 'static,
+    TAllocator: HeapAllocator<T> + Clone + 'static,
+>(
+    entry_points: impl IntoIterator<Item = &'a (impl CompilerEntryPoint<T, TFactory, TAllocator> + 'a)>,
+    module_loader: impl ModuleLoader<Output = T> + Clone + 'static,
+    env_vars: impl IntoIterator<Item = (String, String)>,
+    runtime: &[u8],
+    factory: &TFactory,
+    allocator: &TAllocator,
+    compiler_cache: Option<&mut CompilerCache>,
+    compiler_options: &WasmCompilerOptions,
+    unoptimized: bool,
+) -> Result<Vec<u8>, WasmCompilerError>
 where
     T::Builtin: ParserBuiltin + Into<crate::stdlib::Stdlib>,
     // TODO: Remove unnecessary trait bounds
     T: Rewritable<T> + Reducible<T>,
 {
     let env = env_vars.into_iter().collect::<HashMap<_, _>>();
+    // Record the source locations of any user-level functions in order to report the provenance of compiler errors
+    // (source locations are also used to label the compiled output when generating debug info, and to determine which
+    // functions are affected by any module-level option overrides)
+    let source_metadata = Some(SourceMetadataRecorder::default());
     let entry_points = entry_points
         .into_iter()
         .map({
@@ -421,10 +511,17 @@ where
                     entry_point,
                     &env,
                     module_loader.clone(),
+                    source_metadata.clone(),
                     factory,
                     allocator,
                 )
-                .map(|expression| (entry_point.export_name(), expression))
+                .map(|expression| {
+                    (
+                        entry_point.export_name(),
+                        expression,
+                        entry_point.sandbox_policy(),
+                    )
+                })
             }
         })
         .collect::<Result<Vec<_>, _>>()?;
@@ -432,6 +529,8 @@ where
     compile_wasm_module(
         entry_points,
         runtime,
+        source_metadata.map(|recorder| recorder.table()).as_ref(),
+        compiler_cache,
         factory,
         allocator,
         compiler_options,
@@ -439,6 +538,116 @@ where
     )
 }
 
+/// Parse the provided entry points and compute structural metrics for each of the resulting graphs
+pub fn parse_and_analyze_module<
+    'a,
+    T: Expression + Rewritable<T> + Reducible<T> + 'static,
+    TFactory: ExpressionFactory<T> + Clone + 'static,
+    TAllocator: HeapAllocator<T> + Clone + 'static,
+>(
+    entry_points: impl IntoIterator<Item = &'a (impl CompilerEntryPoint<T, TFactory, TAllocator> + 'a)>,
+    module_loader: impl ModuleLoader<Output = T> + Clone + 'static,
+    env_vars: impl IntoIterator<Item = (String, String)>,
+    factory: &TFactory,
+    allocator: &TAllocator,
+    config: &GraphMetricsConfig,
+) -> Result<Vec<(ModuleEntryPoint, GraphMetrics)>, WasmCompilerError>
+where
+    T::Builtin: ParserBuiltin + Into<crate::stdlib::Stdlib>,
+{
+    let env = env_vars.into_iter().collect::<HashMap<_, _>>();
+    entry_points
+        .into_iter()
+        .map(|entry_point| {
+            compile_module_entry_point(
+                entry_point,
+                &env,
+                module_loader.clone(),
+                None,
+                factory,
+                allocator,
+            )
+            .map(|expression| {
+                (
+                    entry_point.export_name().clone(),
+                    config.analyze_expression(&expression, factory),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Parse the provided entry points and list any standard library builtins used by the resulting graphs that are not
+/// implemented by the given runtime library
+///
+/// This allows missing builtins to be identified up front, rather than failing partway through compilation (any missing
+/// builtins can alternatively be provided by the host, see [`WasmCompilerRuntimeOptions::builtin_fallback`]).
+pub fn parse_and_audit_module<
+    'a,
+    T: Expression + Rewritable<T> + Reducible<T> + 'static,
+    TFactory: ExpressionFactory<T> + Clone + 'static,
+    TAllocator: HeapAllocator<T> + Clone + 'static,
+>(
+    entry_points: impl IntoIterator<Item = &'a (impl CompilerEntryPoint<T, TFactory, TAllocator> + 'a)>,
+    module_loader: impl ModuleLoader<Output = T> + Clone + 'static,
+    env_vars: impl IntoIterator<Item = (String, String)>,
+    runtime: &[u8],
+    factory: &TFactory,
+    allocator: &TAllocator,
+) -> Result<Vec<stdlib::Stdlib>, WasmCompilerError>
+where
+    T::Builtin: ParserBuiltin + Into<crate::stdlib::Stdlib>,
+{
+    let env = env_vars.into_iter().collect::<HashMap<_, _>>();
+    let mut arena = VecAllocator::default();
+    {
+        let shared_arena = Rc::new(RefCell::new(&mut arena));
+        let mut conversion_cache = WasmTermConversionCache::default();
+        for entry_point in entry_points {
+            let expression = compile_module_entry_point(
+                entry_point,
+                &env,
+                module_loader.clone(),
+                None,
+                factory,
+                allocator,
+            )?;
+            WasmTermFactory::from(Rc::clone(&shared_arena))
+                .import_with_cache(&expression, factory, &mut conversion_cache)
+                .map_err(|term| anyhow::anyhow!("Failed to compile term: {}", term))
+                .map_err(WasmCompilerError::CompilerError)?;
+        }
+    }
+    audit_runtime_builtins(runtime, collect_stdlib_builtins(&arena))
+}
+
+/// List any of the given standard library builtins that are not implemented by the given runtime library
+pub fn audit_runtime_builtins(
+    runtime_wasm: &[u8],
+    builtins: impl IntoIterator<Item = stdlib::Stdlib>,
+) -> Result<Vec<stdlib::Stdlib>, WasmCompilerError> {
+    let ast = parse_wasm_ast(runtime_wasm)?;
+    let exported_functions = parse_exported_functions(&ast)
+        .map(|(name, function_id)| (String::from(name), function_id))
+        .collect::<HashMap<_, _>>();
+    let mut missing_builtins =
+        get_missing_stdlib_functions(&exported_functions, builtins).collect::<Vec<_>>();
+    missing_builtins.sort_by_key(|builtin| builtin.name());
+    missing_builtins.dedup_by_key(|builtin| builtin.name());
+    Ok(missing_builtins)
+}
+
+/// Collect the standard library builtins referenced by any of the terms allocated within the given arena
+fn collect_stdlib_builtins(arena: &VecAllocator) -> Vec<stdlib::Stdlib> {
+    ArenaIterator::<Term, _>::new(arena, arena.start_offset(), arena.end_offset())
+        .into_arena_refs::<Term, _>(arena)
+        .filter_map(|term| {
+            term.as_builtin_term()
+                .and_then(|term| stdlib::Stdlib::try_from(term.as_inner().target()).ok())
+        })
+        .collect()
+}
+
 fn compile_module_entry_point<
     T: Expression + 'static,
     TFactory: ExpressionFactory<T> + Clone + 'static,
@@ -447,6 +656,7 @@ fn compile_module_entry_point<
     entry_point: &impl CompilerEntryPoint<T, TFactory, TAllocator>,
     env_vars: &HashMap<String, String>,
     module_loader: impl ModuleLoader<Output = T> + 'static,
+    source_metadata: Option<SourceMetadataRecorder>,
     factory: &TFactory,
     allocator: &TAllocator,
 ) -> Result<T, WasmCompilerError>
@@ -465,6 +675,7 @@ where
                 Syntax::Lisp,
                 env_vars,
                 module_loader,
+                source_metadata,
                 factory,
                 allocator,
             )
@@ -475,6 +686,7 @@ where
                 Syntax::Json,
                 env_vars,
                 module_loader,
+                source_metadata,
                 factory,
                 allocator,
             )
@@ -485,6 +697,7 @@ where
                 Syntax::JavaScript,
                 env_vars,
                 module_loader,
+                source_metadata,
                 factory,
                 allocator,
             )
@@ -495,6 +708,14 @@ where
             .transform(&expression, factory, allocator)
             .unwrap_or(expression)
     })
+    .and_then(|expression| {
+        // Reject any forbidden builtins before partial evaluation has a chance to eliminate them
+        entry_point
+            .sandbox_policy()
+            .validate_expression(&expression, factory)
+            .map_err(|err| WasmCompilerError::SandboxError(entry_point.export_name().clone(), err))
+            .map(|_| expression)
+    })
 }
 
 fn compile_generic_module_entry_point<T: Expression + 'static>(
@@ -502,6 +723,7 @@ fn compile_generic_module_entry_point<T: Expression + 'static>(
     syntax: Syntax,
     env_vars: impl IntoIterator<Item = (String, String)>,
     module_loader: impl ModuleLoader<Output = T> + 'static,
+    source_metadata: Option<SourceMetadataRecorder>,
     factory: &(impl ExpressionFactory<T> + Clone + 'static),
     allocator: &(impl HeapAllocator<T> + Clone + 'static),
 ) -> Result<T, WasmCompilerError>
@@ -513,11 +735,12 @@ where
     // Parse the input file into an expression
     let source = std::fs::read_to_string(input_path)
         .map_err(|err| WasmCompilerError::ReadError(input_path.into(), err))?;
-    let parser = create_parser(
+    let parser = create_parser_with_source_metadata(
         syntax,
         Some(input_path),
         module_loader,
         env_vars,
+        source_metadata,
         factory,
         allocator,
     );
@@ -527,9 +750,12 @@ where
     Ok(expression)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn compile_wasm_module<'a, T: Expression + 'static>(
-    entry_points: impl IntoIterator<Item = (&'a ModuleEntryPoint, T)>,
+    entry_points: impl IntoIterator<Item = (&'a ModuleEntryPoint, T, &'a SandboxPolicy)>,
     runtime: &[u8],
+    source_metadata: Option<&SourceMetadataTable>,
+    compiler_cache: Option<&mut CompilerCache>,
     factory: &(impl ExpressionFactory<T> + Clone + 'static),
     allocator: &(impl HeapAllocator<T> + Clone + 'static),
     compiler_options: &WasmCompilerOptions,
@@ -542,10 +768,12 @@ where
 {
     let mut arena = VecAllocator::default();
     let shared_arena = Rc::new(RefCell::new(&mut arena));
+    // Share the conversion cache across entry points so that imported source terms can be located within the arena
+    let mut conversion_cache = WasmTermConversionCache::default();
 
     let entry_point_functions = entry_points
         .into_iter()
-        .map(|(export_name, expression)| {
+        .map(|(export_name, expression, sandbox_policy)| {
             // Abstract any free variables from any internal lambda functions within the expression
             let expression = expression
                 .hoist_free_variables(factory, allocator)
@@ -556,9 +784,14 @@ where
                 .normalize(factory, allocator, &mut SubstitutionCache::new())
                 .unwrap_or(expression);
 
+            // Reject any forbidden effects that have been statically resolved by partial evaluation
+            sandbox_policy
+                .validate_expression(&expression, factory)
+                .map_err(|err| WasmCompilerError::SandboxError(export_name.clone(), err))?;
+
             // Convert the expression into the WASM term representation
             let wasm_term = WasmTermFactory::from(Rc::clone(&shared_arena))
-                .import(&expression, factory)
+                .import_with_cache(&expression, factory, &mut conversion_cache)
                 .map_err(|term| anyhow::anyhow!("Failed to compile term: {}", term))
                 .map_err(WasmCompilerError::CompilerError)?;
 
@@ -584,26 +817,161 @@ where
         })
         .collect::<Result<Vec<_>, _>>()?;
 
+    // Resolve any module-level option overrides to the arena terms that were parsed from the overridden modules
+    let overridden_options = match source_metadata {
+        Some(source_metadata) if !compiler_options.overrides.modules.is_empty() => {
+            let mut overrides = compiler_options.overrides.clone();
+            for (hash, metadata) in source_metadata.iter() {
+                let path = metadata
+                    .location
+                    .as_ref()
+                    .and_then(|location| location.path.as_ref());
+                if let (Some(path), Some(pointer)) = (path, conversion_cache.imported(hash)) {
+                    if let Some(options) = compiler_options
+                        .overrides
+                        .resolve_module(path, &compiler_options.compiler)
+                    {
+                        let options = options.map_err(|err| {
+                            WasmCompilerError::InvalidCompilerOptions(path.clone(), err)
+                        })?;
+                        let term = ArenaRef::<Term, _>::new(Rc::clone(&shared_arena), pointer);
+                        // Explicit subtree overrides take precedence over module-level overrides
+                        overrides
+                            .terms
+                            .entry(term.read_value(|term| term.header.hash))
+                            .or_insert(options);
+                    }
+                }
+            }
+            Some(WasmCompilerOptions {
+                overrides,
+                ..compiler_options.clone()
+            })
+        }
+        _ => None,
+    };
+    let compiler_options = overridden_options.as_ref().unwrap_or(compiler_options);
+
+    // Translate the source metadata for any imported terms into the equivalent arena terms
+    let source_metadata = source_metadata.map(|source_metadata| {
+        source_metadata.iter().fold(
+            WasmSourceMetadata::default(),
+            |mut results, (hash, metadata)| {
+                if let Some(pointer) = conversion_cache.imported(hash) {
+                    let term = ArenaRef::<Term, _>::new(Rc::clone(&shared_arena), pointer);
+                    results.insert(&term, metadata.clone());
+                }
+                results
+            },
+        )
+    });
+
     // Compile the expression into a WASM module
-    compile_module(
+    compile_module_with_cache(
         entry_point_functions,
         runtime,
         None,
+        factory.numeric_semantics(),
+        source_metadata.as_ref(),
+        compiler_cache,
         compiler_options,
         unoptimized,
     )
 }
 
-#[derive(Default, Clone, Copy, Debug)]
+/// Source metadata for the terms within a compiled module, keyed by the hashes of the arena terms
+#[derive(Default, Clone, Debug)]
+pub struct WasmSourceMetadata {
+    terms: SourceMetadataTable,
+    functions: HashMap<CompiledFunctionId, SourceMetadata>,
+}
+
+impl WasmSourceMetadata {
+    pub fn insert<A: Arena + Clone>(&mut self, term: &ArenaRef<Term, A>, metadata: SourceMetadata) {
+        let hash = term.read_value(|term| term.id());
+        // Lambdas are compiled into functions identified by the hash of the lambda term, whereas thunks are compiled
+        // into functions identified by the hash of the deferred term
+        if let Some(lambda_term) = term.as_lambda_term() {
+            self.functions
+                .entry(CompiledFunctionId::from(&lambda_term.as_inner()))
+                .or_insert_with(|| metadata.clone());
+        }
+        self.functions
+            .entry(CompiledFunctionId::from(TermHashState::from(u64::from(
+                hash,
+            ))))
+            .or_insert_with(|| metadata.clone());
+        self.terms.insert(hash, metadata);
+    }
+    pub fn terms(&self) -> &SourceMetadataTable {
+        &self.terms
+    }
+    pub fn function(&self, function_identifier: CompiledFunctionId) -> Option<&SourceMetadata> {
+        self.functions.get(&function_identifier)
+    }
+}
+
+#[derive(Default, Clone, Debug)]
 pub struct WasmCompilerOptions {
     pub compiler: CompilerOptions,
     pub generator: WasmGeneratorOptions,
     pub runtime: WasmCompilerRuntimeOptions,
+    pub output_format: WasmCompilerOutputFormat,
+    /// Embed a WASM name section that labels each compiled function according to the entry point, lambda or thunk
+    /// from which it was compiled, allowing runtime traps and profiler output to be traced back to the source graph
+    ///
+    /// Where source metadata is available, functions are additionally labelled with the user-level symbol names and
+    /// source locations from which they were parsed, and the metadata is embedded in a custom section of the module.
+    pub debug_info: bool,
+    /// Compiler options overrides for specific source modules or subtrees, taking precedence over the base options
+    pub overrides: CompilerOptionsOverrides,
+    /// Limits on the growth of the heap snapshot during compilation
+    pub limits: CompilerLimits,
+    /// Emit a self-contained WASI command module whose `_start` function evaluates the given entry point and prints the
+    /// JSON-serialized result to stdout, allowing the module to be run by a standard WASI host without the Reflex host
+    /// runtime (see [`add_standalone_entry_point`])
+    pub standalone: Option<ModuleEntryPoint>,
 }
 
 #[derive(Default, Clone, Copy, Debug)]
 pub struct WasmCompilerRuntimeOptions {
     pub memoize_lambdas: bool,
+    /// Replace any standard library builtins that are not implemented by the runtime library with host function imports
+    /// (see [`stdlib::BUILTIN_FALLBACK_MODULE`]), rather than failing to compile
+    ///
+    /// The host must provide an implementation for each imported builtin when instantiating the compiled module. Note
+    /// that only direct calls are routed to the host import: indirect calls to a missing builtin will still fail.
+    pub builtin_fallback: bool,
+    /// Invoke host debugger hooks (see [`DEBUGGER_HOOK_MODULE`]) on entry to each compiled function and before each
+    /// runtime builtin invocation, allowing an embedder to set breakpoints and step through evaluation
+    ///
+    /// The host must provide the debugger hook implementations when instantiating the compiled module (see
+    /// [`crate::interpreter::WasmContextBuilder::add_debugger`]).
+    pub debug_hooks: bool,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Copy, Debug)]
+pub enum WasmCompilerOutputFormat {
+    /// WebAssembly binary module
+    #[default]
+    Binary,
+    /// WebAssembly text format (WAT) listing of the compiled functions, annotated with the compiled instructions from
+    /// which each sequence of WASM instructions was generated
+    ///
+    /// The listing reflects the generated output prior to any optimization passes, and omits the runtime library
+    /// functions (this output format is intended for inspecting compiler output rather than for execution).
+    Text,
+}
+
+impl FromStr for WasmCompilerOutputFormat {
+    type Err = anyhow::Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "wasm" | "binary" => Ok(Self::Binary),
+            "wat" | "text" => Ok(Self::Text),
+            _ => Err(anyhow::anyhow!("Unsupported output format: {}", input)),
+        }
+    }
 }
 
 pub fn compile_module<'a>(
@@ -615,6 +983,64 @@ pub fn compile_module<'a>(
     >,
     runtime_wasm: &[u8],
     heap_snapshot: Option<&[u8]>,
+    numeric_semantics: NumericSemantics,
+    options: &WasmCompilerOptions,
+    unoptimized: bool,
+) -> Result<Vec<u8>, WasmCompilerError> {
+    compile_module_with_source_metadata(
+        entry_points,
+        runtime_wasm,
+        heap_snapshot,
+        numeric_semantics,
+        None,
+        options,
+        unoptimized,
+    )
+}
+
+pub fn compile_module_with_source_metadata<'a>(
+    entry_points: impl IntoIterator<
+        Item = (
+            &'a ModuleEntryPoint,
+            ArenaRef<TypedTerm<LambdaTerm>, impl Arena + Clone>,
+        ),
+    >,
+    runtime_wasm: &[u8],
+    heap_snapshot: Option<&[u8]>,
+    numeric_semantics: NumericSemantics,
+    source_metadata: Option<&WasmSourceMetadata>,
+    options: &WasmCompilerOptions,
+    unoptimized: bool,
+) -> Result<Vec<u8>, WasmCompilerError> {
+    compile_module_with_cache(
+        entry_points,
+        runtime_wasm,
+        heap_snapshot,
+        numeric_semantics,
+        source_metadata,
+        None,
+        options,
+        unoptimized,
+    )
+}
+
+/// Compile a module, reusing any unchanged lambdas and thunks from the provided compiler cache
+///
+/// The cache is updated in-place to contain all the functions compiled during this compiler invocation, allowing it to
+/// be saved and provided to subsequent compiler invocations.
+#[allow(clippy::too_many_arguments)]
+pub fn compile_module_with_cache<'a>(
+    entry_points: impl IntoIterator<
+        Item = (
+            &'a ModuleEntryPoint,
+            ArenaRef<TypedTerm<LambdaTerm>, impl Arena + Clone>,
+        ),
+    >,
+    runtime_wasm: &[u8],
+    heap_snapshot: Option<&[u8]>,
+    numeric_semantics: NumericSemantics,
+    source_metadata: Option<&WasmSourceMetadata>,
+    mut compiler_cache: Option<&mut CompilerCache>,
     options: &WasmCompilerOptions,
     unoptimized: bool,
 ) -> Result<Vec<u8>, WasmCompilerError> {
@@ -625,13 +1051,26 @@ pub fn compile_module<'a>(
                 disable_block_params: true,
                 ..options.generator
             },
-            compiler: options.compiler,
-            runtime: options.runtime,
+            ..options.clone()
         })
     } else {
         None
     };
     let options = overridden_options.as_ref().unwrap_or(options);
+    // Text output is annotated with the compiled instructions from which the WASM instructions were generated
+    let overridden_options = match options.output_format {
+        WasmCompilerOutputFormat::Text if !options.generator.annotate_instructions => {
+            Some(WasmCompilerOptions {
+                generator: WasmGeneratorOptions {
+                    annotate_instructions: true,
+                    ..options.generator
+                },
+                ..options.clone()
+            })
+        }
+        _ => None,
+    };
+    let options = overridden_options.as_ref().unwrap_or(options);
 
     let entry_points = entry_points.into_iter().collect::<Vec<_>>();
 
@@ -650,7 +1089,21 @@ pub fn compile_module<'a>(
 
     // Create a new Wasm module based on the runtime bytes
     let mut ast = parse_wasm_ast(runtime_wasm)?;
-    let export_mappings = parse_runtime_exports(&ast)?;
+    let export_mappings = parse_runtime_exports(&mut ast, options.runtime.builtin_fallback)?;
+
+    // Instrument the runtime builtins with debugger hooks if required
+    let debugger_enter_hook = if options.runtime.debug_hooks {
+        Some(add_debugger_hooks(&mut ast))
+    } else {
+        None
+    };
+
+    // Configure the numeric semantics policy used by the runtime arithmetic builtins
+    set_global_value(
+        &mut ast,
+        export_mappings.globals.numeric_semantics,
+        Value::I32(get_numeric_semantics_global_value(numeric_semantics)),
+    );
 
     // Locate the linear memory
     let memory_id = get_linear_memory_id(&ast)?;
@@ -662,12 +1115,34 @@ pub fn compile_module<'a>(
 
     // Initialize the compiler state with the contents of the linear memory snapshot if one was provided,
     // otherwise load the linear memory snapshot from the inlined data sections in the WebAssembly module
-    let mut compiler_state = if let Some(snapshot) = heap_snapshot {
+    let compiler_state = if let Some(snapshot) = heap_snapshot {
         CompilerState::from_heap_snapshot::<Term>(snapshot)
     } else {
         let snapshot = collect_inline_data_snapshot(&ast, memory_id);
         CompilerState::from_heap_snapshot::<Term>(&snapshot)
     };
+    // Apply any compiler options overrides for specific subtrees
+    let compiler_state = compiler_state.with_options_overrides(
+        options
+            .overrides
+            .terms
+            .iter()
+            .map(|(hash, options)| (*hash, *options)),
+    );
+    // Fail compilation if the static values interned into the heap snapshot grow beyond the configured limits
+    let compiler_state = compiler_state.with_limits(options.limits);
+    // Annotate any compiler errors with the source locations of the user-level terms in which they occurred
+    let compiler_state = match source_metadata {
+        Some(source_metadata) => {
+            compiler_state.with_source_metadata(source_metadata.terms().clone())
+        }
+        None => compiler_state,
+    };
+    // Reuse any previously-compiled functions that are present in the compiler cache
+    let mut compiler_state = match compiler_cache.as_deref_mut() {
+        Some(cache) => compiler_state.with_cache(std::mem::take(cache), &options.compiler),
+        None => compiler_state,
+    };
 
     // Compile the entry points, allocating any static expressions into the compiler state linear memory
     // (this will additionally compile all inner lambdas and thunks encountered along the way)
@@ -679,6 +1154,7 @@ pub fn compile_module<'a>(
             if !compiler_state
                 .compiled_lambdas
                 .contains_key(&compiled_function_id)
+                && !compiler_state.restore_cached_lambda(compiled_function_id)
             {
                 let params = (0..lambda_term.num_args())
                     .map(|_| ValueType::HeapPointer)
@@ -718,6 +1194,11 @@ pub fn compile_module<'a>(
         },
     )?;
 
+    // Update the compiler cache with all the functions compiled during this compiler invocation
+    if let Some(cache) = compiler_cache {
+        *cache = compiler_state.to_cache(&options.compiler);
+    }
+
     // Dump the compiler state to get a comprehensive list of all the compiled lambdas and thunks,
     // as well as a heap snapshot containing any interned static terms
     let (mut heap_snapshot, mut compiled_lambdas, compiled_thunks) = compiler_state.into_parts();
@@ -775,6 +1256,33 @@ pub fn compile_module<'a>(
         )
     }?;
 
+    // Determine human-readable names for the compiled functions in case debug info is required
+    let debug_names = if options.debug_info {
+        let thunk_ids = compiled_thunk_targets
+            .values()
+            .map(|(function_identifier, _)| *function_identifier)
+            .collect::<HashSet<_>>();
+        compiled_entry_points
+            .iter()
+            .map(|(function_identifier, export_names)| {
+                let name = get_compiled_function_debug_name(
+                    *function_identifier,
+                    export_names,
+                    thunk_ids.contains(function_identifier),
+                    source_metadata
+                        .and_then(|source_metadata| source_metadata.function(*function_identifier)),
+                );
+                (*function_identifier, name)
+            })
+            .collect::<HashMap<_, _>>()
+    } else {
+        HashMap::default()
+    };
+
+    // Keep track of the generated functions and their annotations in case a text listing is required
+    let mut annotations = WasmInstructionAnnotations::default();
+    let mut generated_functions = Vec::new();
+
     // Emit WASM bytecode for each of the compiled functions in order
     // (this relies on the functions having been topologically sorted so that later functions can reference prior ones)
     let function_ids = compiled_functions.fold(
@@ -792,12 +1300,26 @@ pub fn compile_module<'a>(
                 memory_id,
                 main_function_table_id,
                 &mut function_ids,
+                &mut annotations,
                 &options.generator,
             )
             .map_err(WasmCompilerError::GeneratorError)?;
+            generated_functions.push((compiled_function_id, function_identifier));
+            if let Some(enter_hook_id) = debugger_enter_hook {
+                add_function_entry_debugger_hook(
+                    &mut ast,
+                    compiled_function_id,
+                    function_identifier,
+                    enter_hook_id,
+                );
+            }
+            let debug_name = debug_names.get(&function_identifier);
+            if let Some(name) = debug_name {
+                ast.funcs.get_mut(compiled_function_id).name = Some(name.clone());
+            }
             // Generate a cache wrapper for the compiled function that memoizes previous results for specific combinations of arguments
             let cached_function_id = if options.runtime.memoize_lambdas {
-                generate_cached_function_wrapper(
+                let cached_function_id = generate_cached_function_wrapper(
                     &mut ast,
                     FunctionPointer::Lambda(function_identifier),
                     params,
@@ -808,7 +1330,11 @@ pub fn compile_module<'a>(
                     ),
                 )
                 .with_context(|| "Failed to generate cached function wrapper template")
-                .map_err(WasmCompilerError::TemplateError)?
+                .map_err(WasmCompilerError::TemplateError)?;
+                if let Some(name) = debug_name {
+                    ast.funcs.get_mut(cached_function_id).name = Some(format!("{}::cached", name));
+                }
+                cached_function_id
             } else {
                 compiled_function_id
             };
@@ -823,6 +1349,9 @@ pub fn compile_module<'a>(
                     num_args,
                     &export_mappings,
                 );
+                if let Some(name) = debug_name {
+                    ast.funcs.get_mut(wrapper_id).name = Some(format!("{}::indirect", name));
+                }
                 register_dynamic_function(&mut ast, function_table_initializer_id, wrapper_id)
             }?;
             // Store the cached function wrapper (to be used by later functions) and the indirect call wrapper
@@ -905,6 +1434,42 @@ pub fn compile_module<'a>(
         heap_snapshot,
     );
 
+    if options.output_format == WasmCompilerOutputFormat::Text {
+        let labels = generated_functions
+            .iter()
+            .map(|(_, function_identifier)| format!("Compiled function {}", function_identifier))
+            .collect::<Vec<_>>();
+        let wat = print_wat_functions(
+            &ast,
+            generated_functions
+                .iter()
+                .map(|(function_id, _)| *function_id)
+                .zip(labels.iter().map(|label| label.as_str())),
+            &annotations,
+        );
+        return Ok(wat.into_bytes());
+    }
+
+    // Embed the source metadata within the module to allow tooling to label terms in subsequent heap snapshots
+    if let Some(source_metadata) = source_metadata
+        .map(|source_metadata| source_metadata.terms())
+        .filter(|terms| options.debug_info && !terms.is_empty())
+    {
+        let data = serde_json::to_vec(source_metadata)
+            .with_context(|| "Failed to serialize source metadata")
+            .map_err(WasmCompilerError::CompilerError)?;
+        ast.customs.add(walrus::RawCustomSection {
+            name: String::from(SOURCE_METADATA_SECTION_NAME),
+            data,
+        });
+    }
+
+    // Bundle the runtime into a self-contained WASI command module if required
+    if let Some(entry_point) = options.standalone.as_ref() {
+        add_standalone_entry_point(&mut ast, entry_point.as_str())
+            .map_err(WasmCompilerError::StandaloneError)?;
+    }
+
     // Emit the resulting WASM as bytes
     let wasm_bytes = ast.emit_wasm();
 
@@ -930,6 +1495,29 @@ pub fn compile_module<'a>(
     Ok(wasm_bytes)
 }
 
+fn get_compiled_function_debug_name(
+    function_identifier: CompiledFunctionId,
+    export_names: &[&ModuleEntryPoint],
+    is_thunk: bool,
+    source_metadata: Option<&SourceMetadata>,
+) -> String {
+    if export_names.is_empty() {
+        let prefix = if is_thunk { "thunk" } else { "lambda" };
+        match source_metadata {
+            Some(source_metadata) => {
+                format!("{}{} {}", prefix, function_identifier, source_metadata)
+            }
+            None => format!("{}{}", prefix, function_identifier),
+        }
+    } else {
+        export_names
+            .iter()
+            .map(|export_name| export_name.as_str())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
 fn recompute_invalidated_term_hashes(
     heap_snapshot: &mut [u8],
     invalidated_terms: impl IntoIterator<Item = ArenaPointer>,
@@ -1213,6 +1801,24 @@ pub fn parse_inline_memory_snapshot(wasm_bytes: &[u8]) -> Result<Vec<u8>, WasmCo
     Ok(collect_inline_data_snapshot(&ast, memory_id))
 }
 
+/// Retrieve the source metadata embedded within a module compiled with debug info, if present
+pub fn parse_source_metadata_section(
+    wasm_bytes: &[u8],
+) -> Result<Option<SourceMetadataTable>, WasmCompilerError> {
+    let ast = parse_wasm_ast(wasm_bytes)?;
+    let data = ast
+        .customs
+        .iter()
+        .find(|(_, section)| section.name() == SOURCE_METADATA_SECTION_NAME)
+        .map(|(_, section)| section.data(&Default::default()).into_owned());
+    data.map(|data| {
+        serde_json::from_slice(&data)
+            .with_context(|| "Failed to parse source metadata")
+            .map_err(WasmCompilerError::CompilerError)
+    })
+    .transpose()
+}
+
 fn patch_heap_snapshot_builtin_target_uid(
     heap_snapshot: &mut [u8],
     compiled_function_term: ArenaPointer,
@@ -1387,16 +1993,23 @@ fn register_dynamic_function(
     Ok(function_index)
 }
 
-fn parse_runtime_exports(module: &Module) -> Result<RuntimeExportMappings, WasmCompilerError> {
+fn parse_runtime_exports(
+    module: &mut Module,
+    builtin_fallback: bool,
+) -> Result<RuntimeExportMappings, WasmCompilerError> {
     let globals = parse_exported_globals(module)
         .map(|(name, global_id)| (String::from(name), global_id))
         .collect::<HashMap<_, _>>();
-    let exported_functions = parse_exported_functions(module)
+    let mut exported_functions = parse_exported_functions(module)
         .map(|(name, function_id)| (String::from(name), function_id))
         .collect::<HashMap<_, _>>();
+    if builtin_fallback {
+        add_builtin_fallback_imports(module, &mut exported_functions);
+    }
     Ok(RuntimeExportMappings {
         globals: RuntimeGlobalMappings {
             null_pointer: get_builtin_global(&globals, RuntimeGlobal::NullPointer)?,
+            numeric_semantics: get_builtin_global(&globals, RuntimeGlobal::NumericSemantics)?,
         },
         builtins: RuntimeBuiltinMappings {
             initialize: get_builtin_function(&exported_functions, RuntimeBuiltin::Initialize)?,
@@ -1411,6 +2024,14 @@ fn parse_runtime_exports(module: &Module) -> Result<RuntimeExportMappings, WasmC
                 RuntimeBuiltin::CombineSignals,
             )?,
             is_signal: get_builtin_function(&exported_functions, RuntimeBuiltin::IsSignal)?,
+            allocate_big_int: get_builtin_function(
+                &exported_functions,
+                RuntimeBuiltin::AllocateBigInt,
+            )?,
+            allocate_bytes: get_builtin_function(
+                &exported_functions,
+                RuntimeBuiltin::AllocateBytes,
+            )?,
             allocate_cell: get_builtin_function(&exported_functions, RuntimeBuiltin::AllocateCell)?,
             allocate_hashmap: get_builtin_function(
                 &exported_functions,
@@ -1495,13 +2116,30 @@ fn parse_runtime_exports(module: &Module) -> Result<RuntimeExportMappings, WasmC
                 RuntimeBuiltin::CreatePointer,
             )?,
             create_record: get_builtin_function(&exported_functions, RuntimeBuiltin::CreateRecord)?,
+            create_recursive: get_builtin_function(
+                &exported_functions,
+                RuntimeBuiltin::CreateRecursive,
+            )?,
+            create_regex: get_builtin_function(&exported_functions, RuntimeBuiltin::CreateRegex)?,
+            create_sensitive: get_builtin_function(
+                &exported_functions,
+                RuntimeBuiltin::CreateSensitive,
+            )?,
             create_signal: get_builtin_function(&exported_functions, RuntimeBuiltin::CreateSignal)?,
             create_symbol: get_builtin_function(&exported_functions, RuntimeBuiltin::CreateSymbol)?,
             create_timestamp: get_builtin_function(
                 &exported_functions,
                 RuntimeBuiltin::CreateTimestamp,
             )?,
+            create_duration: get_builtin_function(
+                &exported_functions,
+                RuntimeBuiltin::CreateDuration,
+            )?,
             create_tree: get_builtin_function(&exported_functions, RuntimeBuiltin::CreateTree)?,
+            create_dedupe_iterator: get_builtin_function(
+                &exported_functions,
+                RuntimeBuiltin::CreateDedupeIterator,
+            )?,
             create_empty_iterator: get_builtin_function(
                 &exported_functions,
                 RuntimeBuiltin::CreateEmptyIterator,
@@ -1566,6 +2204,14 @@ fn parse_runtime_exports(module: &Module) -> Result<RuntimeExportMappings, WasmC
                 &exported_functions,
                 RuntimeBuiltin::CreateZipIterator,
             )?,
+            get_big_int_limb_offset: get_builtin_function(
+                &exported_functions,
+                RuntimeBuiltin::GetBigIntLimbOffset,
+            )?,
+            get_bytes_byte_offset: get_builtin_function(
+                &exported_functions,
+                RuntimeBuiltin::GetBytesByteOffset,
+            )?,
             get_boolean_value: get_builtin_function(
                 &exported_functions,
                 RuntimeBuiltin::GetBooleanValue,
@@ -1583,6 +2229,8 @@ fn parse_runtime_exports(module: &Module) -> Result<RuntimeExportMappings, WasmC
                 &exported_functions,
                 RuntimeBuiltin::GetStringCharOffset,
             )?,
+            init_big_int: get_builtin_function(&exported_functions, RuntimeBuiltin::InitBigInt)?,
+            init_bytes: get_builtin_function(&exported_functions, RuntimeBuiltin::InitBytes)?,
             init_hashmap: get_builtin_function(&exported_functions, RuntimeBuiltin::InitHashmap)?,
             init_list: get_builtin_function(&exported_functions, RuntimeBuiltin::InitList)?,
             init_string: get_builtin_function(&exported_functions, RuntimeBuiltin::InitString)?,
@@ -1625,16 +2273,29 @@ fn parse_runtime_exports(module: &Module) -> Result<RuntimeExportMappings, WasmC
             collect_tree: get_stdlib_function(&exported_functions, stdlib::CollectTree.into())?,
             cons: get_stdlib_function(&exported_functions, stdlib::Cons.into())?,
             construct: get_stdlib_function(&exported_functions, stdlib::Construct.into())?,
+            date_add: get_stdlib_function(&exported_functions, stdlib::DateAdd.into())?,
+            date_diff: get_stdlib_function(&exported_functions, stdlib::DateDiff.into())?,
+            date_offset: get_stdlib_function(&exported_functions, stdlib::DateOffset.into())?,
+            date_truncate: get_stdlib_function(&exported_functions, stdlib::DateTruncate.into())?,
+            date_with_offset: get_stdlib_function(
+                &exported_functions,
+                stdlib::DateWithOffset.into(),
+            )?,
+            debounce: get_stdlib_function(&exported_functions, stdlib::Debounce.into())?,
             debug: get_stdlib_function(&exported_functions, stdlib::Debug.into())?,
             decrement_variable: get_stdlib_function(
                 &exported_functions,
                 stdlib::DecrementVariable.into(),
             )?,
+            dedupe: get_stdlib_function(&exported_functions, stdlib::Dedupe.into())?,
+            delete: get_stdlib_function(&exported_functions, stdlib::Delete.into())?,
             divide: get_stdlib_function(&exported_functions, stdlib::Divide.into())?,
+            duration: get_stdlib_function(&exported_functions, stdlib::Duration.into())?,
             effect: get_stdlib_function(&exported_functions, stdlib::Effect.into())?,
             ends_with: get_stdlib_function(&exported_functions, stdlib::EndsWith.into())?,
             eq: get_stdlib_function(&exported_functions, stdlib::Eq.into())?,
             equal: get_stdlib_function(&exported_functions, stdlib::Equal.into())?,
+            eval: get_stdlib_function(&exported_functions, stdlib::Eval.into())?,
             filter: get_stdlib_function(&exported_functions, stdlib::Filter.into())?,
             flatten: get_stdlib_function(&exported_functions, stdlib::Flatten.into())?,
             floor: get_stdlib_function(&exported_functions, stdlib::Floor.into())?,
@@ -1653,6 +2314,14 @@ fn parse_runtime_exports(module: &Module) -> Result<RuntimeExportMappings, WasmC
             gte: get_stdlib_function(&exported_functions, stdlib::Gte.into())?,
             has: get_stdlib_function(&exported_functions, stdlib::Has.into())?,
             hash: get_stdlib_function(&exported_functions, stdlib::Hash.into())?,
+            hashmap_from_iterator: get_stdlib_function(
+                &exported_functions,
+                stdlib::HashmapFromIterator.into(),
+            )?,
+            hashset_from_iterator: get_stdlib_function(
+                &exported_functions,
+                stdlib::HashsetFromIterator.into(),
+            )?,
             identity: get_stdlib_function(&exported_functions, stdlib::Identity.into())?,
             r#if: get_stdlib_function(&exported_functions, stdlib::If.into())?,
             if_error: get_stdlib_function(&exported_functions, stdlib::IfError.into())?,
@@ -1668,15 +2337,20 @@ fn parse_runtime_exports(module: &Module) -> Result<RuntimeExportMappings, WasmC
             keys: get_stdlib_function(&exported_functions, stdlib::Keys.into())?,
             length: get_stdlib_function(&exported_functions, stdlib::Length.into())?,
             log: get_stdlib_function(&exported_functions, stdlib::Log.into())?,
+            log_scope: get_stdlib_function(&exported_functions, stdlib::LogScope.into())?,
             lt: get_stdlib_function(&exported_functions, stdlib::Lt.into())?,
             lte: get_stdlib_function(&exported_functions, stdlib::Lte.into())?,
             map: get_stdlib_function(&exported_functions, stdlib::Map.into())?,
+            r#match: get_stdlib_function(&exported_functions, stdlib::Match.into())?,
             max: get_stdlib_function(&exported_functions, stdlib::Max.into())?,
+            memo: get_stdlib_function(&exported_functions, stdlib::Memo.into())?,
+            memo_store: get_stdlib_function(&exported_functions, stdlib::MemoStore.into())?,
             merge: get_stdlib_function(&exported_functions, stdlib::Merge.into())?,
             min: get_stdlib_function(&exported_functions, stdlib::Min.into())?,
             multiply: get_stdlib_function(&exported_functions, stdlib::Multiply.into())?,
             not: get_stdlib_function(&exported_functions, stdlib::Not.into())?,
             or: get_stdlib_function(&exported_functions, stdlib::Or.into())?,
+            paginate: get_stdlib_function(&exported_functions, stdlib::Paginate.into())?,
             parse_date: get_stdlib_function(&exported_functions, stdlib::ParseDate.into())?,
             parse_float: get_stdlib_function(&exported_functions, stdlib::ParseFloat.into())?,
             parse_int: get_stdlib_function(&exported_functions, stdlib::ParseInt.into())?,
@@ -1684,6 +2358,7 @@ fn parse_runtime_exports(module: &Module) -> Result<RuntimeExportMappings, WasmC
             pow: get_stdlib_function(&exported_functions, stdlib::Pow.into())?,
             push: get_stdlib_function(&exported_functions, stdlib::Push.into())?,
             push_front: get_stdlib_function(&exported_functions, stdlib::PushFront.into())?,
+            quote: get_stdlib_function(&exported_functions, stdlib::Quote.into())?,
             raise: get_stdlib_function(&exported_functions, stdlib::Raise.into())?,
             remainder: get_stdlib_function(&exported_functions, stdlib::Remainder.into())?,
             replace: get_stdlib_function(&exported_functions, stdlib::Replace.into())?,
@@ -1712,18 +2387,30 @@ fn parse_runtime_exports(module: &Module) -> Result<RuntimeExportMappings, WasmC
             )?,
             resolve_record: get_stdlib_function(&exported_functions, stdlib::ResolveRecord.into())?,
             resolve_tree: get_stdlib_function(&exported_functions, stdlib::ResolveTree.into())?,
+            reveal: get_stdlib_function(&exported_functions, stdlib::Reveal.into())?,
             round: get_stdlib_function(&exported_functions, stdlib::Round.into())?,
+            sample: get_stdlib_function(&exported_functions, stdlib::Sample.into())?,
             scan: get_stdlib_function(&exported_functions, stdlib::Scan.into())?,
+            scoped_log: get_stdlib_function(&exported_functions, stdlib::ScopedLog.into())?,
             sequence: get_stdlib_function(&exported_functions, stdlib::Sequence.into())?,
             set: get_stdlib_function(&exported_functions, stdlib::Set.into())?,
             set_variable: get_stdlib_function(&exported_functions, stdlib::SetVariable.into())?,
             skip: get_stdlib_function(&exported_functions, stdlib::Skip.into())?,
             slice: get_stdlib_function(&exported_functions, stdlib::Slice.into())?,
+            sort: get_stdlib_function(&exported_functions, stdlib::Sort.into())?,
+            sort_by: get_stdlib_function(&exported_functions, stdlib::SortBy.into())?,
+            sort_merge: get_stdlib_function(&exported_functions, stdlib::SortMerge.into())?,
             split: get_stdlib_function(&exported_functions, stdlib::Split.into())?,
             starts_with: get_stdlib_function(&exported_functions, stdlib::StartsWith.into())?,
             stringify_json: get_stdlib_function(&exported_functions, stdlib::StringifyJson.into())?,
+            stringify_json_canonical: get_stdlib_function(
+                &exported_functions,
+                stdlib::StringifyJsonCanonical.into(),
+            )?,
             subtract: get_stdlib_function(&exported_functions, stdlib::Subtract.into())?,
             take: get_stdlib_function(&exported_functions, stdlib::Take.into())?,
+            template: get_stdlib_function(&exported_functions, stdlib::Template.into())?,
+            throttle: get_stdlib_function(&exported_functions, stdlib::Throttle.into())?,
             throw: get_stdlib_function(&exported_functions, stdlib::Throw.into())?,
             to_request: get_stdlib_function(&exported_functions, stdlib::ToRequest.into())?,
             to_string: get_stdlib_function(&exported_functions, stdlib::ToString.into())?,
@@ -1735,17 +2422,113 @@ fn parse_runtime_exports(module: &Module) -> Result<RuntimeExportMappings, WasmC
     })
 }
 
-fn get_builtin_global(
-    globals: &HashMap<String, GlobalId>,
-    target: RuntimeGlobal,
-) -> Result<GlobalId, WasmCompilerError> {
-    globals
-        .get(target.name())
-        .copied()
-        .ok_or_else(|| WasmCompilerError::RuntimeGlobalNotFound(target))
+/// Import a host function in place of any standard library builtins that are not implemented by the runtime library
+///
+/// Each host function has the same signature as the runtime implementation would have had, allowing it to be called
+/// directly by the compiled functions.
+fn add_builtin_fallback_imports(
+    module: &mut Module,
+    exported_functions: &mut HashMap<String, FunctionId>,
+) {
+    let missing_builtins = get_missing_stdlib_functions(exported_functions, stdlib::Stdlib::iter())
+        .collect::<Vec<_>>();
+    for builtin in missing_builtins {
+        let params = vec![ValType::I32; builtin.num_params() + 1];
+        let type_id = module.types.add(&params, &[ValType::I32, ValType::I32]);
+        let (function_id, _) =
+            module.add_import_func(stdlib::BUILTIN_FALLBACK_MODULE, builtin.name(), type_id);
+        exported_functions.insert(String::from(builtin.name()), function_id);
+    }
 }
 
-fn get_builtin_function(
+/// Import the host debugger hooks, instrumenting each runtime builtin to invoke its corresponding hook with its arguments
+/// before executing the builtin implementation
+///
+/// Returns the ID of the imported function entry hook, to be invoked by each compiled function.
+fn add_debugger_hooks(module: &mut Module) -> FunctionId {
+    let enter_hook_type_id = module.types.add(&[ValType::I64], &[]);
+    let (enter_hook_id, _) = module.add_import_func(
+        DEBUGGER_HOOK_MODULE,
+        DEBUGGER_ENTER_FUNCTION_HOOK,
+        enter_hook_type_id,
+    );
+    for builtin in stdlib::Stdlib::iter() {
+        // Builtins that are imported from the host (e.g. builtin fallbacks) cannot be instrumented
+        let args = match get_exported_function_id(module, builtin.name())
+            .map(|function_id| (function_id, &module.funcs.get(function_id).kind))
+        {
+            Some((function_id, walrus::FunctionKind::Local(function))) => {
+                Some((function_id, function.args.clone()))
+            }
+            _ => None,
+        };
+        let Some((function_id, args)) = args else {
+            continue;
+        };
+        let hook_type_id = module.types.add(&vec![ValType::I32; args.len()], &[]);
+        let (hook_id, _) =
+            module.add_import_func(DEBUGGER_HOOK_MODULE, builtin.name(), hook_type_id);
+        if let walrus::FunctionKind::Local(function) = &mut module.funcs.get_mut(function_id).kind {
+            let mut body = function.builder_mut().func_body();
+            for (index, arg) in args.iter().enumerate() {
+                body.local_get_at(index, *arg);
+            }
+            body.call_at(args.len(), hook_id);
+        }
+    }
+    enter_hook_id
+}
+
+fn add_function_entry_debugger_hook(
+    module: &mut Module,
+    function_id: FunctionId,
+    function_identifier: CompiledFunctionId,
+    enter_hook_id: FunctionId,
+) {
+    if let walrus::FunctionKind::Local(function) = &mut module.funcs.get_mut(function_id).kind {
+        let hash = u64::from(TermHashState::from(function_identifier));
+        function
+            .builder_mut()
+            .func_body()
+            .const_at(0, Value::I64(hash as i64))
+            .call_at(1, enter_hook_id);
+    }
+}
+
+fn get_missing_stdlib_functions<'a>(
+    exported_functions: &'a HashMap<String, FunctionId>,
+    builtins: impl IntoIterator<Item = stdlib::Stdlib> + 'a,
+) -> impl Iterator<Item = stdlib::Stdlib> + 'a {
+    builtins
+        .into_iter()
+        .filter(|builtin| !exported_functions.contains_key(builtin.name()))
+}
+
+fn get_builtin_global(
+    globals: &HashMap<String, GlobalId>,
+    target: RuntimeGlobal,
+) -> Result<GlobalId, WasmCompilerError> {
+    globals
+        .get(target.name())
+        .copied()
+        .ok_or_else(|| WasmCompilerError::RuntimeGlobalNotFound(target))
+}
+
+fn set_global_value(ast: &mut Module, global_id: GlobalId, value: Value) {
+    let global = ast.globals.get_mut(global_id);
+    global.kind = GlobalKind::Local(InitExpr::Value(value));
+}
+
+fn get_numeric_semantics_global_value(numeric_semantics: NumericSemantics) -> i32 {
+    // Enum values must match the corresponding runtime globals
+    match numeric_semantics {
+        NumericSemantics::Wrap => 0,
+        NumericSemantics::Saturate => 1,
+        NumericSemantics::Error => 2,
+    }
+}
+
+fn get_builtin_function(
     builtins: &HashMap<String, FunctionId>,
     target: RuntimeBuiltin,
 ) -> Result<FunctionId, WasmCompilerError> {
@@ -1878,17 +2661,25 @@ mod tests {
         rc::Rc,
     };
 
-    use reflex::core::{ConditionType, DependencyList};
+    use reflex::{
+        core::{format_float, ConditionType, DependencyList, FloatFormat, SignalType},
+        source_map::SourceLocation,
+    };
+    use reflex_lang::{allocator::DefaultAllocator, SharedTermFactory};
+    use wasi_common::{pipe::WritePipe, I32Exit};
+    use wasmtime_wasi::sync::WasiCtxBuilder;
 
     use crate::{
         allocator::{ArenaAllocator, VecAllocator},
+        builtins::WasmCompilerBuiltins,
+        cli::standalone::STANDALONE_ENTRY_POINT,
         interpreter::{
             mocks::add_import_stubs, InterpreterError, WasmContextBuilder, WasmInterpreter,
         },
-        stdlib::{Add, Stdlib},
+        stdlib::{Add, Multiply, Stdlib, Subtract},
         term_type::{
-            ApplicationTerm, BuiltinTerm, ConditionTerm, IntTerm, ListTerm, TermType,
-            WasmExpression,
+            ApplicationTerm, BuiltinTerm, ConditionTerm, FloatTerm, IntTerm, ListTerm, TermType,
+            VariableTerm, WasmExpression,
         },
         ArenaPointer, ArenaRef, Term,
     };
@@ -1927,6 +2718,7 @@ mod tests {
             [(&ModuleEntryPoint::from("foo"), entry_point)],
             RUNTIME_BYTES,
             None,
+            NumericSemantics::default(),
             &WasmCompilerOptions::default(),
             true,
         )
@@ -1969,6 +2761,770 @@ mod tests {
         assert_eq!(dependencies, DependencyList::empty());
     }
 
+    #[test]
+    fn text_output_format() {
+        let mut arena = VecAllocator::default();
+        let value = arena.allocate(Term::new(TermType::Int(IntTerm::from(5)), &arena));
+        let main_function = arena.allocate(Term::new(
+            TermType::Lambda(LambdaTerm {
+                num_args: 0,
+                body: value,
+            }),
+            &arena,
+        ));
+
+        let arena = Rc::new(RefCell::new(&mut arena));
+        let entry_point = WasmExpression::new(arena.clone(), main_function)
+            .as_lambda_term()
+            .cloned()
+            .unwrap();
+
+        let output = compile_module(
+            [(&ModuleEntryPoint::from("foo"), entry_point)],
+            RUNTIME_BYTES,
+            None,
+            NumericSemantics::default(),
+            &WasmCompilerOptions {
+                output_format: WasmCompilerOutputFormat::Text,
+                ..Default::default()
+            },
+            true,
+        )
+        .unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.starts_with(";; Compiled function <fn:"));
+        assert!(output.contains("(export \"foo\")"));
+        assert!(output.contains(";; Const { value: HeapPointer("));
+        assert!(output.contains("call $Dependencies::new"));
+        assert_eq!(
+            output.chars().filter(|char| *char == '(').count(),
+            output.chars().filter(|char| *char == ')').count(),
+        );
+    }
+
+    #[test]
+    fn standalone_float_output() {
+        let values = [0.1, 1e21, -2.5, 100.0, 1e-7, 123456.789];
+        let mut arena = VecAllocator::default();
+        let items = values.map(|value| {
+            let item = Term::new(TermType::Float(FloatTerm::from(value)), &arena);
+            arena.allocate(item)
+        });
+        let list = ListTerm::allocate(items, &mut arena);
+        let main_function = arena.allocate(Term::new(
+            TermType::Lambda(LambdaTerm {
+                num_args: 0,
+                body: list,
+            }),
+            &arena,
+        ));
+
+        let arena = Rc::new(RefCell::new(&mut arena));
+        let entry_point = WasmExpression::new(arena.clone(), main_function)
+            .as_lambda_term()
+            .cloned()
+            .unwrap();
+
+        let wasm_bytes = compile_module(
+            [(&ModuleEntryPoint::from("foo"), entry_point)],
+            RUNTIME_BYTES,
+            None,
+            NumericSemantics::default(),
+            &WasmCompilerOptions {
+                standalone: Some(ModuleEntryPoint::from("foo")),
+                ..Default::default()
+            },
+            true,
+        )
+        .unwrap();
+
+        let engine = wasmtime::Engine::default();
+        let module = wasmtime::Module::new(&engine, &wasm_bytes).unwrap();
+        let mut linker = wasmtime::Linker::new(&engine);
+        wasmtime_wasi::add_to_linker(&mut linker, |wasi| wasi).unwrap();
+        let stdout = WritePipe::new_in_memory();
+        let wasi = WasiCtxBuilder::new()
+            .stdout(Box::new(stdout.clone()))
+            .build();
+        let mut store = wasmtime::Store::new(&engine, wasi);
+        let instance = linker.instantiate(&mut store, &module).unwrap();
+        let start = instance
+            .get_typed_func::<(), ()>(&mut store, STANDALONE_ENTRY_POINT)
+            .unwrap();
+        let exit_code = match start.call(&mut store, ()) {
+            Ok(()) => 0,
+            Err(err) => err.downcast_ref::<I32Exit>().map(|exit| exit.0).unwrap(),
+        };
+        drop(store);
+        let output = String::from_utf8(stdout.try_into_inner().unwrap().into_inner()).unwrap();
+
+        assert_eq!(exit_code, 0);
+        assert_eq!(
+            output,
+            format!(
+                "[{}]\n",
+                values
+                    .map(|value| format_float(value, FloatFormat::default()))
+                    .join(","),
+            ),
+        );
+        assert_eq!(output, "[0.1,1e21,-2.5,100.0,1e-7,123456.789]\n");
+    }
+
+    #[test]
+    fn debug_info() {
+        let mut arena = VecAllocator::default();
+        let value = arena.allocate(Term::new(TermType::Int(IntTerm::from(5)), &arena));
+        let main_function = arena.allocate(Term::new(
+            TermType::Lambda(LambdaTerm {
+                num_args: 0,
+                body: value,
+            }),
+            &arena,
+        ));
+
+        let arena = Rc::new(RefCell::new(&mut arena));
+        let entry_point = WasmExpression::new(arena.clone(), main_function)
+            .as_lambda_term()
+            .cloned()
+            .unwrap();
+
+        let compile = |debug_info: bool| {
+            let output = compile_module(
+                [(&ModuleEntryPoint::from("foo"), entry_point.clone())],
+                RUNTIME_BYTES,
+                None,
+                NumericSemantics::default(),
+                &WasmCompilerOptions {
+                    debug_info,
+                    ..Default::default()
+                },
+                true,
+            )
+            .unwrap();
+            let module = parse_wasm_ast(&output).unwrap();
+            module
+                .funcs
+                .iter()
+                .filter_map(|function| function.name.clone())
+                .collect::<HashSet<_>>()
+        };
+
+        let function_names = compile(true);
+        assert!(function_names.contains("foo"));
+        assert!(function_names.contains("foo::indirect"));
+        let function_names = compile(false);
+        assert!(!function_names.contains("foo"));
+        assert!(!function_names.contains("foo::indirect"));
+    }
+
+    #[test]
+    fn source_metadata() {
+        let mut arena = VecAllocator::default();
+        let variable = arena.allocate(Term::new(
+            TermType::Variable(VariableTerm { stack_offset: 0 }),
+            &arena,
+        ));
+        let identity_function = arena.allocate(Term::new(
+            TermType::Lambda(LambdaTerm {
+                num_args: 1,
+                body: variable,
+            }),
+            &arena,
+        ));
+        let main_function = arena.allocate(Term::new(
+            TermType::Lambda(LambdaTerm {
+                num_args: 0,
+                body: identity_function,
+            }),
+            &arena,
+        ));
+
+        let arena = Rc::new(RefCell::new(&mut arena));
+        let entry_point = WasmExpression::new(arena.clone(), main_function)
+            .as_lambda_term()
+            .cloned()
+            .unwrap();
+        let identity_term = ArenaRef::<Term, _>::new(arena.clone(), identity_function);
+        let metadata = SourceMetadata {
+            name: Some(String::from("identity")),
+            location: Some(SourceLocation {
+                path: Some(PathBuf::from("index.js")),
+                line: 1,
+                column: 18,
+            }),
+        };
+        let mut source_metadata = WasmSourceMetadata::default();
+        source_metadata.insert(&identity_term, metadata.clone());
+
+        let output = compile_module_with_source_metadata(
+            [(&ModuleEntryPoint::from("foo"), entry_point)],
+            RUNTIME_BYTES,
+            None,
+            NumericSemantics::default(),
+            Some(&source_metadata),
+            &WasmCompilerOptions {
+                debug_info: true,
+                ..Default::default()
+            },
+            true,
+        )
+        .unwrap();
+        let module = parse_wasm_ast(&output).unwrap();
+        assert!(module
+            .funcs
+            .iter()
+            .filter_map(|function| function.name.as_deref())
+            .any(|name| name.ends_with(" identity at index.js:1:18")));
+        let embedded_metadata = parse_source_metadata_section(&output).unwrap().unwrap();
+        assert_eq!(
+            embedded_metadata.get(identity_term.read_value(|term| term.id())),
+            Some(&metadata),
+        );
+    }
+
+    #[test]
+    fn compiler_error_source_metadata() {
+        let mut arena = VecAllocator::default();
+        let variable = arena.allocate(Term::new(
+            TermType::Variable(VariableTerm { stack_offset: 0 }),
+            &arena,
+        ));
+        let binary_function = arena.allocate(Term::new(
+            TermType::Lambda(LambdaTerm {
+                num_args: 2,
+                body: variable,
+            }),
+            &arena,
+        ));
+        let args = ListTerm::allocate([variable], &mut arena);
+        let application = arena.allocate(Term::new(
+            TermType::Application(ApplicationTerm {
+                target: binary_function,
+                args,
+            }),
+            &arena,
+        ));
+        let unary_function = arena.allocate(Term::new(
+            TermType::Lambda(LambdaTerm {
+                num_args: 1,
+                body: application,
+            }),
+            &arena,
+        ));
+        let main_function = arena.allocate(Term::new(
+            TermType::Lambda(LambdaTerm {
+                num_args: 0,
+                body: unary_function,
+            }),
+            &arena,
+        ));
+
+        let arena = Rc::new(RefCell::new(&mut arena));
+        let entry_point = WasmExpression::new(arena.clone(), main_function)
+            .as_lambda_term()
+            .cloned()
+            .unwrap();
+        let mut source_metadata = WasmSourceMetadata::default();
+        source_metadata.insert(
+            &ArenaRef::<Term, _>::new(arena.clone(), unary_function),
+            SourceMetadata {
+                name: Some(String::from("foo")),
+                location: Some(SourceLocation {
+                    path: Some(PathBuf::from("foo.js")),
+                    line: 42,
+                    column: 3,
+                }),
+            },
+        );
+
+        let err = compile_module_with_source_metadata(
+            [(&ModuleEntryPoint::from("foo"), entry_point.clone())],
+            RUNTIME_BYTES,
+            None,
+            NumericSemantics::default(),
+            Some(&source_metadata),
+            &WasmCompilerOptions::default(),
+            true,
+        )
+        .unwrap_err();
+        let message = format!("{}", err);
+        assert!(message.contains("Invalid function invocation"));
+        assert!(message.ends_with("(in foo at foo.js:42:3)"));
+
+        let err = compile_module_with_source_metadata(
+            [(&ModuleEntryPoint::from("foo"), entry_point)],
+            RUNTIME_BYTES,
+            None,
+            NumericSemantics::default(),
+            None,
+            &WasmCompilerOptions::default(),
+            true,
+        )
+        .unwrap_err();
+        let message = format!("{}", err);
+        assert!(message.contains("Invalid function invocation"));
+        assert!(!message.contains("foo.js"));
+    }
+
+    #[test]
+    fn interned_heap_limits() {
+        let mut arena = VecAllocator::default();
+        let value = arena.allocate(Term::new(TermType::Int(IntTerm::from(123456789)), &arena));
+        let main_function = arena.allocate(Term::new(
+            TermType::Lambda(LambdaTerm {
+                num_args: 0,
+                body: value,
+            }),
+            &arena,
+        ));
+
+        let arena = Rc::new(RefCell::new(&mut arena));
+        let entry_point = WasmExpression::new(arena.clone(), main_function)
+            .as_lambda_term()
+            .cloned()
+            .unwrap();
+        let mut source_metadata = WasmSourceMetadata::default();
+        source_metadata.insert(
+            &ArenaRef::<Term, _>::new(arena.clone(), value),
+            SourceMetadata {
+                name: Some(String::from("foo")),
+                location: Some(SourceLocation {
+                    path: Some(PathBuf::from("foo.js")),
+                    line: 42,
+                    column: 3,
+                }),
+            },
+        );
+
+        let compile = |limits: CompilerLimits| {
+            compile_module_with_source_metadata(
+                [(&ModuleEntryPoint::from("foo"), entry_point.clone())],
+                RUNTIME_BYTES,
+                None,
+                NumericSemantics::default(),
+                Some(&source_metadata),
+                &WasmCompilerOptions {
+                    limits,
+                    ..Default::default()
+                },
+                true,
+            )
+        };
+
+        assert!(compile(CompilerLimits {
+            max_interned_heap_size: Some(1024),
+            max_interned_term_size: Some(1024),
+        })
+        .is_ok());
+
+        let message = format!(
+            "{}",
+            compile(CompilerLimits {
+                max_interned_term_size: Some(1),
+                ..Default::default()
+            })
+            .unwrap_err()
+        );
+        assert!(message.contains("Interned term size limit exceeded"));
+        assert!(message.contains("Largest interned terms:"));
+        assert!(message.contains("Int <"));
+        assert!(message.contains("(foo at foo.js:42:3)"));
+
+        let message = format!(
+            "{}",
+            compile(CompilerLimits {
+                max_interned_heap_size: Some(1),
+                ..Default::default()
+            })
+            .unwrap_err()
+        );
+        assert!(message.contains("Interned heap snapshot size limit exceeded"));
+    }
+
+    #[test]
+    fn constant_folding() {
+        fn compile_text_output(left: i64, right: i64, options: CompilerOptions) -> String {
+            let mut arena = VecAllocator::default();
+            let value = {
+                let add = arena.allocate(Term::new(
+                    TermType::Builtin(BuiltinTerm::from(Stdlib::from(Add))),
+                    &arena,
+                ));
+                let multiply = arena.allocate(Term::new(
+                    TermType::Builtin(BuiltinTerm::from(Stdlib::from(Multiply))),
+                    &arena,
+                ));
+                let left = arena.allocate(Term::new(TermType::Int(IntTerm::from(left)), &arena));
+                let right = arena.allocate(Term::new(TermType::Int(IntTerm::from(right)), &arena));
+                let factor = arena.allocate(Term::new(TermType::Int(IntTerm::from(2)), &arena));
+                let args = ListTerm::allocate([left, right], &mut arena);
+                let sum = arena.allocate(Term::new(
+                    TermType::Application(ApplicationTerm { target: add, args }),
+                    &arena,
+                ));
+                let args = ListTerm::allocate([sum, factor], &mut arena);
+                arena.allocate(Term::new(
+                    TermType::Application(ApplicationTerm {
+                        target: multiply,
+                        args,
+                    }),
+                    &arena,
+                ))
+            };
+            let main_function = arena.allocate(Term::new(
+                TermType::Lambda(LambdaTerm {
+                    num_args: 0,
+                    body: value,
+                }),
+                &arena,
+            ));
+
+            let arena = Rc::new(RefCell::new(&mut arena));
+            let entry_point = WasmExpression::new(arena.clone(), main_function)
+                .as_lambda_term()
+                .cloned()
+                .unwrap();
+
+            let output = compile_module(
+                [(&ModuleEntryPoint::from("foo"), entry_point)],
+                RUNTIME_BYTES,
+                None,
+                NumericSemantics::default(),
+                &WasmCompilerOptions {
+                    compiler: options,
+                    output_format: WasmCompilerOutputFormat::Text,
+                    ..Default::default()
+                },
+                true,
+            )
+            .unwrap();
+            String::from_utf8(output).unwrap()
+        }
+
+        let output = compile_text_output(3, 4, CompilerOptions::default());
+        assert!(!output.contains("CallStdlib"));
+        let output = compile_text_output(
+            3,
+            4,
+            CompilerOptions {
+                constant_folding: false,
+                ..Default::default()
+            },
+        );
+        assert!(output.contains("CallStdlib"));
+        let output = compile_text_output(i64::MAX, 1, CompilerOptions::default());
+        assert!(output.contains("CallStdlib"));
+    }
+
+    #[test]
+    fn compiler_options_overrides() {
+        fn compile_text_output(overridden_term: Option<usize>) -> String {
+            let mut arena = VecAllocator::default();
+            let add = arena.allocate(Term::new(
+                TermType::Builtin(BuiltinTerm::from(Stdlib::from(Add))),
+                &arena,
+            ));
+            let multiply = arena.allocate(Term::new(
+                TermType::Builtin(BuiltinTerm::from(Stdlib::from(Multiply))),
+                &arena,
+            ));
+            let left = arena.allocate(Term::new(TermType::Int(IntTerm::from(3)), &arena));
+            let right = arena.allocate(Term::new(TermType::Int(IntTerm::from(4)), &arena));
+            let factor = arena.allocate(Term::new(TermType::Int(IntTerm::from(2)), &arena));
+            let args = ListTerm::allocate([left, right], &mut arena);
+            let sum = arena.allocate(Term::new(
+                TermType::Application(ApplicationTerm { target: add, args }),
+                &arena,
+            ));
+            let args = ListTerm::allocate([sum, factor], &mut arena);
+            let product = arena.allocate(Term::new(
+                TermType::Application(ApplicationTerm {
+                    target: multiply,
+                    args,
+                }),
+                &arena,
+            ));
+            let main_function = arena.allocate(Term::new(
+                TermType::Lambda(LambdaTerm {
+                    num_args: 0,
+                    body: product,
+                }),
+                &arena,
+            ));
+
+            let arena = Rc::new(RefCell::new(&mut arena));
+            let entry_point = WasmExpression::new(arena.clone(), main_function)
+                .as_lambda_term()
+                .cloned()
+                .unwrap();
+            let overridden_term = overridden_term.map(|index| [factor, sum, product][index]);
+            let terms = overridden_term
+                .map(|pointer| {
+                    let term = WasmExpression::new(arena.clone(), pointer);
+                    (
+                        term.read_value(|term| term.header.hash),
+                        CompilerOptions {
+                            constant_folding: false,
+                            ..Default::default()
+                        },
+                    )
+                })
+                .into_iter()
+                .collect();
+
+            let output = compile_module(
+                [(&ModuleEntryPoint::from("foo"), entry_point)],
+                RUNTIME_BYTES,
+                None,
+                NumericSemantics::default(),
+                &WasmCompilerOptions {
+                    output_format: WasmCompilerOutputFormat::Text,
+                    overrides: CompilerOptionsOverrides {
+                        terms,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                true,
+            )
+            .unwrap();
+            String::from_utf8(output).unwrap()
+        }
+
+        assert!(!compile_text_output(None).contains("CallStdlib"));
+        // Overrides for unrelated terms have no effect
+        assert!(!compile_text_output(Some(0)).contains("CallStdlib"));
+        // Overrides apply to nested subtrees, preventing enclosing terms from being folded
+        assert!(compile_text_output(Some(1)).contains("CallStdlib"));
+        assert!(compile_text_output(Some(2)).contains("CallStdlib"));
+    }
+
+    #[test]
+    fn lambda_inlining() {
+        fn compile_lambda_application(
+            options: CompilerOptions,
+            output_format: WasmCompilerOutputFormat,
+        ) -> Vec<u8> {
+            let mut arena = VecAllocator::default();
+            let value = {
+                let subtract = arena.allocate(Term::new(
+                    TermType::Builtin(BuiltinTerm::from(Stdlib::from(Subtract))),
+                    &arena,
+                ));
+                let first = arena.allocate(Term::new(
+                    TermType::Variable(VariableTerm { stack_offset: 1 }),
+                    &arena,
+                ));
+                let second = arena.allocate(Term::new(
+                    TermType::Variable(VariableTerm { stack_offset: 0 }),
+                    &arena,
+                ));
+                let args = ListTerm::allocate([first, second], &mut arena);
+                let body = arena.allocate(Term::new(
+                    TermType::Application(ApplicationTerm {
+                        target: subtract,
+                        args,
+                    }),
+                    &arena,
+                ));
+                let target = arena.allocate(Term::new(
+                    TermType::Lambda(LambdaTerm { num_args: 2, body }),
+                    &arena,
+                ));
+                let left = arena.allocate(Term::new(TermType::Int(IntTerm::from(10)), &arena));
+                let right = arena.allocate(Term::new(TermType::Int(IntTerm::from(3)), &arena));
+                let args = ListTerm::allocate([left, right], &mut arena);
+                arena.allocate(Term::new(
+                    TermType::Application(ApplicationTerm { target, args }),
+                    &arena,
+                ))
+            };
+            let main_function = arena.allocate(Term::new(
+                TermType::Lambda(LambdaTerm {
+                    num_args: 0,
+                    body: value,
+                }),
+                &arena,
+            ));
+
+            let arena = Rc::new(RefCell::new(&mut arena));
+            let entry_point = WasmExpression::new(arena.clone(), main_function)
+                .as_lambda_term()
+                .cloned()
+                .unwrap();
+
+            compile_module(
+                [(&ModuleEntryPoint::from("foo"), entry_point)],
+                RUNTIME_BYTES,
+                None,
+                NumericSemantics::default(),
+                &WasmCompilerOptions {
+                    compiler: options,
+                    output_format,
+                    ..Default::default()
+                },
+                true,
+            )
+            .unwrap()
+        }
+
+        fn evaluate_lambda_application(options: CompilerOptions) -> i64 {
+            let wasm_bytes = compile_lambda_application(options, WasmCompilerOutputFormat::Binary);
+            let mut interpreter = create_mock_wasm_interpreter(&wasm_bytes).unwrap();
+            let interpreter = Rc::new(RefCell::new(&mut interpreter));
+            let result = interpreter
+                .deref()
+                .borrow_mut()
+                .deref_mut()
+                .execute("foo", ArenaPointer::null())
+                .unwrap()
+                .bind(Rc::clone(&interpreter));
+            result.result().as_int_term().unwrap().as_inner().value()
+        }
+
+        let inlined = CompilerOptions::default();
+        let not_inlined = CompilerOptions {
+            inline_lambda_threshold: 0,
+            ..Default::default()
+        };
+        let output = String::from_utf8(compile_lambda_application(
+            inlined,
+            WasmCompilerOutputFormat::Text,
+        ))
+        .unwrap();
+        assert!(!output.contains("CallCompiledFunction"));
+        let output = String::from_utf8(compile_lambda_application(
+            not_inlined,
+            WasmCompilerOutputFormat::Text,
+        ))
+        .unwrap();
+        assert!(output.contains("CallCompiledFunction"));
+        assert_eq!(evaluate_lambda_application(inlined), 7);
+        assert_eq!(evaluate_lambda_application(not_inlined), 7);
+    }
+
+    #[test]
+    fn compiler_cache() {
+        fn compile_lambda_application(
+            left: i64,
+            right: i64,
+            compiler_cache: &mut CompilerCache,
+            options: CompilerOptions,
+        ) -> Vec<u8> {
+            let mut arena = VecAllocator::default();
+            let value = {
+                let subtract = arena.allocate(Term::new(
+                    TermType::Builtin(BuiltinTerm::from(Stdlib::from(Subtract))),
+                    &arena,
+                ));
+                let first = arena.allocate(Term::new(
+                    TermType::Variable(VariableTerm { stack_offset: 1 }),
+                    &arena,
+                ));
+                let second = arena.allocate(Term::new(
+                    TermType::Variable(VariableTerm { stack_offset: 0 }),
+                    &arena,
+                ));
+                let args = ListTerm::allocate([first, second], &mut arena);
+                let body = arena.allocate(Term::new(
+                    TermType::Application(ApplicationTerm {
+                        target: subtract,
+                        args,
+                    }),
+                    &arena,
+                ));
+                let target = arena.allocate(Term::new(
+                    TermType::Lambda(LambdaTerm { num_args: 2, body }),
+                    &arena,
+                ));
+                let left = arena.allocate(Term::new(TermType::Int(IntTerm::from(left)), &arena));
+                let right = arena.allocate(Term::new(TermType::Int(IntTerm::from(right)), &arena));
+                let args = ListTerm::allocate([left, right], &mut arena);
+                arena.allocate(Term::new(
+                    TermType::Application(ApplicationTerm { target, args }),
+                    &arena,
+                ))
+            };
+            let main_function = arena.allocate(Term::new(
+                TermType::Lambda(LambdaTerm {
+                    num_args: 0,
+                    body: value,
+                }),
+                &arena,
+            ));
+
+            let arena = Rc::new(RefCell::new(&mut arena));
+            let entry_point = WasmExpression::new(arena.clone(), main_function)
+                .as_lambda_term()
+                .cloned()
+                .unwrap();
+
+            compile_module_with_cache(
+                [(&ModuleEntryPoint::from("foo"), entry_point)],
+                RUNTIME_BYTES,
+                None,
+                NumericSemantics::default(),
+                None,
+                Some(compiler_cache),
+                &WasmCompilerOptions {
+                    compiler: options,
+                    ..Default::default()
+                },
+                true,
+            )
+            .unwrap()
+        }
+
+        fn evaluate(wasm_bytes: &[u8]) -> i64 {
+            let mut interpreter = create_mock_wasm_interpreter(wasm_bytes).unwrap();
+            let interpreter = Rc::new(RefCell::new(&mut interpreter));
+            let result = interpreter
+                .deref()
+                .borrow_mut()
+                .deref_mut()
+                .execute("foo", ArenaPointer::null())
+                .unwrap()
+                .bind(Rc::clone(&interpreter));
+            result.result().as_int_term().unwrap().as_inner().value()
+        }
+
+        let options = CompilerOptions {
+            inline_lambda_threshold: 0,
+            ..Default::default()
+        };
+        let mut compiler_cache = CompilerCache::default();
+        let wasm_bytes = compile_lambda_application(10, 3, &mut compiler_cache, options);
+        assert_eq!(evaluate(&wasm_bytes), 7);
+        // The cache contains the entry point function and the inner lambda
+        assert_eq!(compiler_cache.len(), 2);
+
+        let mut compiler_cache =
+            CompilerCache::from_bytes(&compiler_cache.to_bytes().unwrap()).unwrap();
+        let cached_wasm_bytes = compile_lambda_application(10, 3, &mut compiler_cache, options);
+        assert_eq!(evaluate(&cached_wasm_bytes), 7);
+        assert_eq!(compiler_cache.len(), 2);
+
+        // Changed functions are recompiled, while the unchanged inner lambda is reused from the cache
+        let wasm_bytes = compile_lambda_application(20, 5, &mut compiler_cache, options);
+        assert_eq!(evaluate(&wasm_bytes), 15);
+        assert_eq!(compiler_cache.len(), 2);
+
+        // Caches created with different compiler options are ignored
+        let wasm_bytes = compile_lambda_application(
+            20,
+            5,
+            &mut compiler_cache,
+            CompilerOptions {
+                inline_lambda_threshold: 0,
+                constant_folding: false,
+                ..Default::default()
+            },
+        );
+        assert_eq!(evaluate(&wasm_bytes), 15);
+    }
+
     #[test]
     fn deeply_nested_applications() {
         let mut arena = VecAllocator::default();
@@ -2013,6 +3569,7 @@ mod tests {
             [(&ModuleEntryPoint::from("foo"), entry_point)],
             RUNTIME_BYTES,
             None,
+            NumericSemantics::default(),
             &WasmCompilerOptions::default(),
             true,
         )
@@ -2055,6 +3612,75 @@ mod tests {
         assert_eq!(result.result(), expected_result);
         assert_eq!(dependencies, DependencyList::empty());
     }
+
+    #[test]
+    fn sandbox_policy() {
+        let factory = SharedTermFactory::<WasmCompilerBuiltins>::default();
+        let allocator = DefaultAllocator::default();
+        let export_name = ModuleEntryPoint::default();
+
+        let expression = factory.create_lambda_term(
+            1,
+            factory.create_application_term(
+                factory.create_builtin_term(reflex_stdlib::stdlib::Add),
+                allocator.create_pair(factory.create_variable_term(0), factory.create_int_term(3)),
+            ),
+        );
+        let policy = SandboxPolicy::default().deny_builtin(reflex_stdlib::stdlib::Add);
+        let result = compile_wasm_module(
+            [(&export_name, expression, &policy)],
+            RUNTIME_BYTES,
+            None,
+            None,
+            &factory,
+            &allocator,
+            &WasmCompilerOptions::default(),
+            true,
+        );
+        assert!(matches!(
+            result,
+            Err(WasmCompilerError::SandboxError(
+                _,
+                SandboxViolation::Builtin(_)
+            ))
+        ));
+
+        let expression = factory.create_effect_term(allocator.create_signal(SignalType::Custom {
+            effect_type: factory.create_string_term(allocator.create_static_string("foo")),
+            payload: factory.create_nil_term(),
+            token: factory.create_nil_term(),
+        }));
+        let policy = SandboxPolicy::default().deny_effect_type("foo");
+        let result = compile_wasm_module(
+            [(&export_name, expression.clone(), &policy)],
+            RUNTIME_BYTES,
+            None,
+            None,
+            &factory,
+            &allocator,
+            &WasmCompilerOptions::default(),
+            true,
+        );
+        match result {
+            Err(WasmCompilerError::SandboxError(entry_point, err)) => {
+                assert_eq!(entry_point, export_name);
+                assert_eq!(err, SandboxViolation::EffectType(String::from("foo")));
+            }
+            result => panic!("Unexpected result: {:?}", result.map(|_| ())),
+        }
+        let policy = SandboxPolicy::default().deny_effect_type("bar");
+        let result = compile_wasm_module(
+            [(&export_name, expression, &policy)],
+            RUNTIME_BYTES,
+            None,
+            None,
+            &factory,
+            &allocator,
+            &WasmCompilerOptions::default(),
+            true,
+        );
+        assert!(result.is_ok());
+    }
 }
 
 fn split_at_separator(separator: char, value: &str) -> Option<(&str, &str)> {
@@ -0,0 +1,189 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::collections::HashMap;
+
+use crate::{
+    cli::inspect_snapshot::{
+        inspect_heap_snapshot, HeapSnapshotInspectionError, HeapSnapshotLayout,
+    },
+    compiler::{
+        cache::CompilerCache,
+        instruction::{self, CompiledInstruction},
+        CapturingThunk, CompiledBlock, CompiledFunctionId, CompiledLambda, CompiledThunk,
+        ConstValue, FunctionPointer,
+    },
+    hash::TermHashState,
+};
+
+/// Decoded listing of the compiled functions and interned heap terms retained within a compiler cache
+pub struct CompilerCacheDisassembly<'a> {
+    heap: HeapSnapshotLayout,
+    compiled_lambdas: Vec<(CompiledFunctionId, &'a CompiledLambda)>,
+    compiled_thunks: Vec<(TermHashState, &'a CompiledThunk)>,
+}
+
+/// Decode the compiled functions contained within a compiler cache, along with the heap terms they reference
+///
+/// Functions are listed in order of function ID to ensure deterministic output when comparing the output of different
+/// compiler invocations.
+pub fn disassemble_compiler_cache(
+    cache: &CompilerCache,
+) -> Result<CompilerCacheDisassembly<'_>, HeapSnapshotInspectionError> {
+    let heap = inspect_heap_snapshot(cache.heap())?;
+    let mut compiled_lambdas = cache
+        .compiled_lambdas()
+        .iter()
+        .map(|(function_id, lambda)| (*function_id, lambda))
+        .collect::<Vec<_>>();
+    compiled_lambdas.sort_by_key(|(function_id, _)| *function_id);
+    let mut compiled_thunks = cache
+        .compiled_thunks()
+        .iter()
+        .map(|(thunk_id, thunk)| (*thunk_id, thunk))
+        .collect::<Vec<_>>();
+    compiled_thunks.sort_by_key(|(thunk_id, _)| CompiledFunctionId::from(*thunk_id));
+    Ok(CompilerCacheDisassembly {
+        heap,
+        compiled_lambdas,
+        compiled_thunks,
+    })
+}
+
+impl<'a> std::fmt::Display for CompilerCacheDisassembly<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let term_types = self
+            .heap
+            .entries
+            .iter()
+            .map(|entry| (entry.offset, entry.term_type.as_str()))
+            .collect::<HashMap<_, _>>();
+        for (function_id, lambda) in self.compiled_lambdas.iter() {
+            let CompiledLambda { params, body } = lambda;
+            writeln!(f, "lambda {function_id} ({params})")?;
+            write_compiled_block(f, body, &term_types, 1)?;
+            writeln!(f)?;
+        }
+        for (thunk_id, thunk) in self.compiled_thunks.iter() {
+            let function_id = CompiledFunctionId::from(*thunk_id);
+            match thunk {
+                CompiledThunk::Pure(_) => writeln!(f, "thunk {function_id} (pure)"),
+                CompiledThunk::Capturing(CapturingThunk { free_variables, .. }) => writeln!(
+                    f,
+                    "thunk {function_id} (captures {})",
+                    free_variables
+                        .iter()
+                        .map(|offset| format!("{offset}"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            }?;
+            write_compiled_block(f, thunk.body(), &term_types, 1)?;
+            writeln!(f)?;
+        }
+        write!(f, "{}", self.heap)
+    }
+}
+
+fn write_compiled_block(
+    f: &mut std::fmt::Formatter<'_>,
+    block: &CompiledBlock,
+    term_types: &HashMap<u32, &str>,
+    depth: usize,
+) -> std::fmt::Result {
+    let indent = "  ".repeat(depth);
+    for instruction in block.iter() {
+        match instruction {
+            CompiledInstruction::Block(instruction::core::Block { block_type, body }) => {
+                writeln!(f, "{indent}Block ({block_type})")?;
+                write_compiled_block(f, body, term_types, depth + 1)?;
+                writeln!(f, "{indent}End")
+            }
+            CompiledInstruction::If(instruction::core::If {
+                block_type,
+                consequent,
+                alternative,
+            }) => {
+                writeln!(f, "{indent}If ({block_type})")?;
+                write_compiled_block(f, consequent, term_types, depth + 1)?;
+                writeln!(f, "{indent}Else")?;
+                write_compiled_block(f, alternative, term_types, depth + 1)?;
+                writeln!(f, "{indent}End")
+            }
+            CompiledInstruction::Const(instruction::core::Const { value }) => {
+                writeln!(f, "{indent}Const {}", format_const_value(value, term_types))
+            }
+            CompiledInstruction::CallStdlib(instruction::runtime::CallStdlib { target }) => {
+                writeln!(f, "{indent}CallStdlib {}", target.name())
+            }
+            CompiledInstruction::CallCompiledFunction(
+                instruction::runtime::CallCompiledFunction { signature, target },
+            ) => writeln!(f, "{indent}CallCompiledFunction {target} ({signature})"),
+            instruction => writeln!(f, "{indent}{instruction}"),
+        }?;
+    }
+    Ok(())
+}
+
+fn format_const_value(value: &ConstValue, term_types: &HashMap<u32, &str>) -> String {
+    match value {
+        ConstValue::HeapPointer(pointer) => {
+            let offset = u32::from(*pointer);
+            match term_types.get(&offset) {
+                Some(term_type) => format!("{offset:#010x} ; {term_type}"),
+                None => format!("{offset:#010x}"),
+            }
+        }
+        ConstValue::FunctionPointer(FunctionPointer::Stdlib(target)) => {
+            format!("&{}", target.name())
+        }
+        ConstValue::FunctionPointer(FunctionPointer::Lambda(target)) => format!("&{target}"),
+        value => format!("{value:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        allocator::{ArenaAllocator, VecAllocator},
+        compiler::{
+            error::TypedStackError, instruction::core::Const, instruction::runtime::CallStdlib,
+            CompiledBlockBuilder, CompilerOptions, CompilerStack, CompilerState, ParamsSignature,
+            ValueType,
+        },
+        stdlib::{Abs, Stdlib},
+        term_type::{IntTerm, TermType},
+        Term,
+    };
+
+    use super::*;
+
+    #[test]
+    fn disassemble_lambda() {
+        let mut allocator = VecAllocator::default();
+        let value = allocator.allocate(Term::new(TermType::Int(IntTerm::from(-3)), &allocator));
+        let mut state = CompilerState::from_heap_snapshot::<Term>(allocator.as_bytes());
+        let body = CompiledBlockBuilder::new(CompilerStack::default())
+            .push(Const {
+                value: ConstValue::HeapPointer(value),
+            })
+            .push(CallStdlib {
+                target: Stdlib::Abs(Abs),
+            })
+            .finish::<TypedStackError>()
+            .unwrap();
+        let function_id = CompiledFunctionId::from(TermHashState::from(123u64));
+        state.compiled_lambdas.insert(
+            function_id,
+            CompiledLambda {
+                params: ParamsSignature::from_iter([ValueType::HeapPointer]),
+                body,
+            },
+        );
+        let cache = state.to_cache(&CompilerOptions::default());
+        let output = format!("{}", disassemble_compiler_cache(&cache).unwrap());
+        assert!(output.contains(&format!("lambda {function_id}")));
+        assert!(output.contains(&format!("  Const {:#010x} ; Int", u32::from(value))));
+        assert!(output.contains("  CallStdlib Stdlib_Abs"));
+    }
+}
@@ -0,0 +1,475 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::collections::{BTreeMap, HashSet};
+
+use reflex::source_map::SourceMetadataTable;
+use reflex_utils::Visitable;
+use serde::Serialize;
+
+use crate::{
+    allocator::{
+        Arena, ArenaCorruption, ArenaIterator, CheckedArenaEntry, CorruptArenaRange, VecAllocator,
+    },
+    hash::TermSize,
+    ArenaPointer, ArenaRef, Term,
+};
+
+#[derive(Debug)]
+pub enum HeapSnapshotInspectionError {
+    InvalidAlignment(usize),
+    InvalidEndOffset { end_offset: u32, length: usize },
+}
+
+impl std::error::Error for HeapSnapshotInspectionError {}
+
+impl std::fmt::Display for HeapSnapshotInspectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidAlignment(length) => {
+                write!(f, "Invalid heap snapshot length: {length} bytes is not 32-bit aligned")
+            }
+            Self::InvalidEndOffset { end_offset, length } => write!(
+                f,
+                "Invalid heap snapshot end offset: {end_offset:#010x} (snapshot length: {length} bytes)"
+            ),
+        }
+    }
+}
+
+/// Decoded summary of the terms allocated within a heap snapshot
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HeapSnapshotLayout {
+    /// Offset of the end of the allocated heap region, as recorded in the snapshot allocator header
+    pub end_offset: u32,
+    /// Terms allocated within the heap, in allocation order
+    pub entries: Vec<HeapSnapshotEntry>,
+    /// Pointers that do not refer to the start of any term within the heap
+    pub invalid_pointers: Vec<HeapSnapshotInvalidPointer>,
+    /// Regions of the heap that could not be decoded as valid terms
+    pub corruption: Vec<HeapSnapshotCorruption>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HeapSnapshotEntry {
+    pub offset: u32,
+    pub term_type: String,
+    pub size: usize,
+    pub hash: String,
+    pub pointers: Vec<u32>,
+    /// User-level symbol name and source location from which the term was parsed, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HeapSnapshotInvalidPointer {
+    pub source: u32,
+    pub target: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HeapSnapshotCorruption {
+    /// Offset of the invalid term header
+    pub start: u32,
+    /// Offset at which decoding resumed
+    pub end: u32,
+    pub reason: HeapSnapshotCorruptionReason,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type")]
+pub enum HeapSnapshotCorruptionReason {
+    InvalidTermType { discriminant: u32 },
+    TruncatedTerm { size: usize },
+}
+
+impl From<CorruptArenaRange> for HeapSnapshotCorruption {
+    fn from(value: CorruptArenaRange) -> Self {
+        let CorruptArenaRange { start, end, reason } = value;
+        Self {
+            start: u32::from(start),
+            end: u32::from(end),
+            reason: match reason {
+                ArenaCorruption::InvalidTermType { discriminant } => {
+                    HeapSnapshotCorruptionReason::InvalidTermType { discriminant }
+                }
+                ArenaCorruption::TruncatedHeader { length: size }
+                | ArenaCorruption::InvalidSize { size } => {
+                    HeapSnapshotCorruptionReason::TruncatedTerm { size }
+                }
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for HeapSnapshotCorruption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self { start, end, reason } = self;
+        match reason {
+            HeapSnapshotCorruptionReason::InvalidTermType { discriminant } => write!(
+                f,
+                "Invalid term type discriminant at {start:#010x}: {discriminant:#010x}"
+            ),
+            HeapSnapshotCorruptionReason::TruncatedTerm { size } => {
+                write!(f, "Invalid term size at {start:#010x}: {size} bytes")
+            }
+        }?;
+        write!(
+            f,
+            " (skipped {} bytes, resumed at {end:#010x})",
+            end - start
+        )
+    }
+}
+
+/// Aggregate allocation statistics for a single term type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HeapSnapshotTypeSummary {
+    pub term_type: String,
+    pub count: usize,
+    pub size: usize,
+}
+
+impl HeapSnapshotLayout {
+    /// Total allocation statistics for each term type, ordered by descending total size
+    pub fn summary(&self) -> Vec<HeapSnapshotTypeSummary> {
+        let mut summary = self
+            .entries
+            .iter()
+            .fold(
+                BTreeMap::<&str, (usize, usize)>::new(),
+                |mut results, entry| {
+                    let (count, size) = results.entry(entry.term_type.as_str()).or_default();
+                    *count += 1;
+                    *size += entry.size;
+                    results
+                },
+            )
+            .into_iter()
+            .map(|(term_type, (count, size))| HeapSnapshotTypeSummary {
+                term_type: String::from(term_type),
+                count,
+                size,
+            })
+            .collect::<Vec<_>>();
+        summary.sort_by_key(|summary| std::cmp::Reverse(summary.size));
+        summary
+    }
+}
+
+/// Decode the terms contained within a heap snapshot, as captured from the linear memory of a runtime WASM module
+///
+/// Decoding proceeds linearly from the start of the heap. Whenever a term header cannot be decoded, the corrupt region is
+/// recorded and decoding resumes from the next plausible term header.
+pub fn inspect_heap_snapshot(
+    bytes: &[u8],
+) -> Result<HeapSnapshotLayout, HeapSnapshotInspectionError> {
+    inspect_heap_snapshot_with_source_metadata(bytes, None)
+}
+
+/// Decode the terms contained within a heap snapshot, labelling any terms that have corresponding entries in the
+/// source metadata embedded within the compiled module
+pub fn inspect_heap_snapshot_with_source_metadata(
+    bytes: &[u8],
+    source_metadata: Option<&SourceMetadataTable>,
+) -> Result<HeapSnapshotLayout, HeapSnapshotInspectionError> {
+    if !bytes.len().is_multiple_of(4) {
+        return Err(HeapSnapshotInspectionError::InvalidAlignment(bytes.len()));
+    }
+    // Pad the heap contents with trailing zero bytes to ensure that decoding a term header located near the end of the
+    // heap never reads beyond the bounds of the underlying buffer
+    let arena = VecAllocator::from_bytes(&{
+        let mut padded_bytes = bytes.to_vec();
+        padded_bytes.resize(
+            bytes.len() + pad_to_4_byte_offset(std::mem::size_of::<Term>()),
+            0,
+        );
+        padded_bytes
+    });
+    let start_offset = arena.start_offset();
+    let end_offset = arena.end_offset();
+    if end_offset < start_offset || (u32::from(end_offset) as usize) > bytes.len() {
+        return Err(HeapSnapshotInspectionError::InvalidEndOffset {
+            end_offset: u32::from(end_offset),
+            length: bytes.len(),
+        });
+    }
+    let mut entries = Vec::new();
+    let mut corruption = Vec::new();
+    for entry in ArenaIterator::<Term, _>::new(&arena, start_offset, end_offset).checked() {
+        let offset = match entry {
+            CheckedArenaEntry::Term(offset) => offset,
+            CheckedArenaEntry::Corrupt(range) => {
+                corruption.push(HeapSnapshotCorruption::from(range));
+                continue;
+            }
+        };
+        let term = ArenaRef::<Term, _>::new(&arena, offset);
+        let pointers = Visitable::<ArenaPointer>::children(&term)
+            .map(|pointer| arena.read_value::<ArenaPointer, _>(pointer, |target| *target))
+            .map(u32::from)
+            .collect();
+        let hash = term.read_value(|term| term.id());
+        entries.push(HeapSnapshotEntry {
+            offset: u32::from(offset),
+            term_type: format!("{:?}", term.read_value(|term| term.type_id())),
+            size: pad_to_4_byte_offset(term.read_value(|term| term.size_of())),
+            hash: format!("{:016x}", hash),
+            pointers,
+            source: source_metadata
+                .and_then(|source_metadata| source_metadata.get(hash))
+                .map(|metadata| format!("{}", metadata)),
+        });
+    }
+    let term_offsets = entries
+        .iter()
+        .map(|entry| entry.offset)
+        .collect::<HashSet<_>>();
+    let invalid_pointers = entries
+        .iter()
+        .flat_map(|entry| {
+            entry
+                .pointers
+                .iter()
+                .copied()
+                .filter(|target| {
+                    let pointer = ArenaPointer::from(*target);
+                    !pointer.is_null() && !pointer.is_uninitialized()
+                })
+                .filter(|target| !term_offsets.contains(target))
+                .map(|target| HeapSnapshotInvalidPointer {
+                    source: entry.offset,
+                    target,
+                })
+        })
+        .collect();
+    Ok(HeapSnapshotLayout {
+        end_offset: u32::from(end_offset),
+        entries,
+        invalid_pointers,
+        corruption,
+    })
+}
+
+impl std::fmt::Display for HeapSnapshotLayout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:<10}  {:>8}  {:<24}  {:<16}  pointers",
+            "offset", "size", "type", "hash"
+        )?;
+        for entry in self.entries.iter() {
+            let line = format!(
+                "{:#010x}  {:>8}  {:<24}  {}  {}{}",
+                entry.offset,
+                entry.size,
+                entry.term_type,
+                entry.hash,
+                entry
+                    .pointers
+                    .iter()
+                    .map(|pointer| format!("{:#010x}", pointer))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                match &entry.source {
+                    Some(source) => format!("  ({})", source),
+                    None => String::new(),
+                }
+            );
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{} terms, {} bytes (heap end offset: {:#010x})",
+            self.entries.len(),
+            self.entries.iter().map(|entry| entry.size).sum::<usize>(),
+            self.end_offset
+        )?;
+        for summary in self.summary() {
+            writeln!(
+                f,
+                "  {:<24}  {:>8} terms  {:>10} bytes",
+                summary.term_type, summary.count, summary.size
+            )?;
+        }
+        for pointer in self.invalid_pointers.iter() {
+            writeln!(
+                f,
+                "Invalid pointer in term {:#010x}: {:#010x}",
+                pointer.source, pointer.target
+            )?;
+        }
+        for corruption in self.corruption.iter() {
+            writeln!(f, "{}", corruption)?;
+        }
+        Ok(())
+    }
+}
+
+fn pad_to_4_byte_offset(value: usize) -> usize {
+    if value == 0 {
+        0
+    } else {
+        (((value - 1) / 4) + 1) * 4
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reflex::source_map::{SourceLocation, SourceMetadata};
+
+    use crate::{
+        allocator::ArenaAllocator,
+        term_type::{IntTerm, ListTerm, TermType},
+        TermHeader,
+    };
+
+    use super::*;
+
+    #[test]
+    fn inspect_snapshot() {
+        let mut allocator = VecAllocator::default();
+        let first_item = allocator.allocate(Term::new(TermType::Int(IntTerm::from(3)), &allocator));
+        let second_item =
+            allocator.allocate(Term::new(TermType::Int(IntTerm::from(4)), &allocator));
+        let list = ListTerm::allocate([first_item, second_item], &mut allocator);
+        let layout = inspect_heap_snapshot(allocator.as_bytes()).unwrap();
+        assert_eq!(layout.end_offset, u32::from(allocator.end_offset()));
+        assert_eq!(
+            layout
+                .entries
+                .iter()
+                .map(|entry| (
+                    entry.offset,
+                    entry.term_type.as_str(),
+                    entry.pointers.clone()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (u32::from(first_item), "Int", vec![]),
+                (u32::from(second_item), "Int", vec![]),
+                (
+                    u32::from(list),
+                    "List",
+                    vec![u32::from(first_item), u32::from(second_item)]
+                ),
+            ],
+        );
+        assert!(layout.invalid_pointers.is_empty());
+        assert!(layout.corruption.is_empty());
+        assert_eq!(
+            layout
+                .summary()
+                .into_iter()
+                .map(|summary| (summary.term_type, summary.count))
+                .collect::<Vec<_>>(),
+            vec![(String::from("Int"), 2), (String::from("List"), 1)],
+        );
+    }
+
+    #[test]
+    fn source_metadata() {
+        let mut allocator = VecAllocator::default();
+        let first_item = allocator.allocate(Term::new(TermType::Int(IntTerm::from(3)), &allocator));
+        let second_item =
+            allocator.allocate(Term::new(TermType::Int(IntTerm::from(4)), &allocator));
+        let source_metadata = [(
+            ArenaRef::<Term, _>::new(&allocator, second_item).read_value(|term| term.id()),
+            SourceMetadata {
+                name: Some(String::from("foo")),
+                location: Some(SourceLocation {
+                    path: None,
+                    line: 1,
+                    column: 7,
+                }),
+            },
+        )]
+        .into_iter()
+        .collect::<SourceMetadataTable>();
+        let layout = inspect_heap_snapshot_with_source_metadata(
+            allocator.as_bytes(),
+            Some(&source_metadata),
+        )
+        .unwrap();
+        assert_eq!(
+            layout
+                .entries
+                .iter()
+                .map(|entry| (entry.offset, entry.source.as_deref()))
+                .collect::<Vec<_>>(),
+            vec![
+                (u32::from(first_item), None),
+                (u32::from(second_item), Some("foo at <anonymous>:1:7")),
+            ],
+        );
+    }
+
+    #[test]
+    fn corrupt_snapshot() {
+        let mut allocator = VecAllocator::default();
+        let first_item = allocator.allocate(Term::new(TermType::Int(IntTerm::from(3)), &allocator));
+        let second_item =
+            allocator.allocate(Term::new(TermType::Int(IntTerm::from(4)), &allocator));
+        let third_item = allocator.allocate(Term::new(TermType::Int(IntTerm::from(5)), &allocator));
+        let mut bytes = allocator.into_bytes();
+        let discriminant_offset =
+            u32::from(second_item) as usize + std::mem::size_of::<TermHeader>();
+        bytes[discriminant_offset..discriminant_offset + 4]
+            .copy_from_slice(&0xDEADu32.to_le_bytes());
+        let layout = inspect_heap_snapshot(&bytes).unwrap();
+        assert_eq!(
+            layout
+                .entries
+                .iter()
+                .map(|entry| entry.offset)
+                .collect::<Vec<_>>(),
+            vec![u32::from(first_item), u32::from(third_item)],
+        );
+        assert_eq!(
+            layout.corruption,
+            vec![HeapSnapshotCorruption {
+                start: u32::from(second_item),
+                end: u32::from(third_item),
+                reason: HeapSnapshotCorruptionReason::InvalidTermType {
+                    discriminant: 0xDEAD
+                },
+            }],
+        );
+    }
+
+    #[test]
+    fn corrupt_term_size() {
+        let mut allocator = VecAllocator::default();
+        let first_item = allocator.allocate(Term::new(TermType::Int(IntTerm::from(3)), &allocator));
+        let list = ListTerm::allocate([first_item], &mut allocator);
+        let second_item =
+            allocator.allocate(Term::new(TermType::Int(IntTerm::from(4)), &allocator));
+        let mut bytes = allocator.into_bytes();
+        // Overwrite the list capacity field with a value that extends beyond the end of the heap
+        let capacity_offset = u32::from(list) as usize
+            + std::mem::size_of::<TermHeader>()
+            + std::mem::size_of::<u32>();
+        bytes[capacity_offset..capacity_offset + 4].copy_from_slice(&0xFFFFu32.to_le_bytes());
+        let layout = inspect_heap_snapshot(&bytes).unwrap();
+        // Resynchronization is heuristic, so the words within the corrupt list term may themselves be decoded as a
+        // plausible term; regardless, decoding must resume in time to recover the term that follows the corrupt region
+        let offsets = layout
+            .entries
+            .iter()
+            .map(|entry| entry.offset)
+            .collect::<Vec<_>>();
+        assert_eq!(offsets.first(), Some(&u32::from(first_item)));
+        assert_eq!(offsets.last(), Some(&u32::from(second_item)));
+        assert!(!offsets.contains(&u32::from(list)));
+        assert!(matches!(
+            layout.corruption.first(),
+            Some(HeapSnapshotCorruption {
+                start,
+                reason: HeapSnapshotCorruptionReason::TruncatedTerm { .. },
+                ..
+            }) if *start == u32::from(list)
+        ));
+    }
+}
@@ -2,5 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 pub mod compile;
+pub mod disassemble;
 pub mod entry_point;
+pub mod inspect_snapshot;
 pub mod snapshot;
+pub mod standalone;
@@ -0,0 +1,324 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::collections::HashMap;
+
+use walrus::{
+    ir::{self, VisitorMut},
+    ExportItem, FunctionBuilder, FunctionId, ImportKind, Module, ValType,
+};
+
+use crate::ArenaPointer;
+
+/// Name of the WASI import module that is provided by standard WASI hosts
+pub const WASI_MODULE: &str = "wasi_snapshot_preview1";
+
+/// Name of the exported command entry point invoked by standard WASI hosts
+pub const STANDALONE_ENTRY_POINT: &str = "_start";
+
+const RUNTIME_INITIALIZER: &str = "_initialize";
+const RUNTIME_PRINT_RESULT: &str = "printResult";
+const WASI_PROC_EXIT: &str = "proc_exit";
+
+/// Host imports for which the runtime exports an equivalent bundled implementation, as (module, name, export) triples
+///
+/// Float formatting is bundled to allow results containing floats to be printed without a host formatter.
+const BUNDLED_HOST_IMPORTS: [(&str, &str, &str); 1] = [("Number", "toString", "formatFloat")];
+
+#[derive(Debug)]
+pub enum WasmStandaloneError {
+    FunctionNotFound(String),
+}
+
+impl std::error::Error for WasmStandaloneError {}
+
+impl std::fmt::Display for WasmStandaloneError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FunctionNotFound(name) => write!(f, "Function definition not found: {name}"),
+        }
+    }
+}
+
+/// Convert a compiled module into a self-contained WASI command module that can be run by a standard WASI host (e.g.
+/// `wasmtime run`) without any Reflex-specific host imports
+///
+/// The generated `_start` function initializes the runtime, evaluates the given entry point with an empty state object,
+/// and writes the JSON-serialized result to stdout. If the result is an error signal, the formatted signal is written to
+/// stderr and the process exits with a non-zero exit code.
+///
+/// Host float formatting imports are redirected to the float formatter bundled within the runtime. Any other host imports
+/// besides WASI imports are replaced with stub implementations that trap when invoked, so any builtins that rely on host
+/// functionality (e.g. date parsing, regular expressions and transcendental math functions) will abort the program if
+/// they are encountered during evaluation.
+pub fn add_standalone_entry_point(
+    module: &mut Module,
+    entry_point: &str,
+) -> Result<FunctionId, WasmStandaloneError> {
+    let initializer_id = get_exported_function_id(module, RUNTIME_INITIALIZER)?;
+    let entry_point_id = get_exported_function_id(module, entry_point)?;
+    let print_result_id = get_exported_function_id(module, RUNTIME_PRINT_RESULT)?;
+    replace_host_imports(module);
+    let proc_exit_id = get_wasi_proc_exit_import(module);
+    let start_function_id = {
+        let mut builder = FunctionBuilder::new(&mut module.types, &[], &[]);
+        builder
+            .func_body()
+            // Initialize the runtime globals
+            .call(initializer_id)
+            // Evaluate the entry point with the empty state object
+            .i32_const(u32::from(ArenaPointer::null()) as i32)
+            .call(entry_point_id)
+            // Discard the result dependencies
+            .drop()
+            // Print the result, returning the process exit code
+            .call(print_result_id)
+            // Exit the process with the corresponding exit code
+            .call(proc_exit_id);
+        builder.finish(Vec::new(), &mut module.funcs)
+    };
+    // Standard WASI hosts treat modules that export an initializer as reactor modules rather than command modules, so
+    // the runtime initializer is only invoked via the command entry point
+    if let Some(export_id) = module
+        .exports
+        .iter()
+        .find(|export| export.name == RUNTIME_INITIALIZER)
+        .map(|export| export.id())
+    {
+        module.exports.delete(export_id);
+    }
+    module
+        .exports
+        .add(STANDALONE_ENTRY_POINT, start_function_id);
+    Ok(start_function_id)
+}
+
+/// Replace all non-WASI function imports with their bundled runtime equivalents where available, or otherwise with
+/// local functions that trap when invoked
+fn replace_host_imports(module: &mut Module) {
+    let host_imports = module
+        .imports
+        .iter()
+        .filter_map(|import| match import.kind {
+            ImportKind::Function(function_id) if import.module != WASI_MODULE => {
+                let bundled_function_id = BUNDLED_HOST_IMPORTS
+                    .iter()
+                    .find(|(module_name, name, _)| {
+                        import.module == *module_name && import.name == *name
+                    })
+                    .and_then(|(_, _, export_name)| {
+                        get_exported_function_id(module, export_name).ok()
+                    });
+                Some((import.id(), function_id, bundled_function_id))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if host_imports.is_empty() {
+        return;
+    }
+    let mut replacements = HashMap::with_capacity(host_imports.len());
+    for (import_id, function_id, bundled_function_id) in host_imports {
+        let replacement_id = match bundled_function_id {
+            Some(bundled_function_id) => bundled_function_id,
+            None => create_trap_stub(module, function_id),
+        };
+        module.imports.delete(import_id);
+        replacements.insert(function_id, replacement_id);
+    }
+    let mut visitor = FunctionIdReplacer(&replacements);
+    for (_, function) in module.funcs.iter_local_mut() {
+        let entry_block = function.entry_block();
+        ir::dfs_pre_order_mut(&mut visitor, function, entry_block);
+    }
+    for element in module.elements.iter_mut() {
+        for member in element.members.iter_mut().flatten() {
+            visitor.visit_function_id_mut(member);
+        }
+    }
+    for export in module.exports.iter_mut() {
+        if let ExportItem::Function(function_id) = &mut export.item {
+            visitor.visit_function_id_mut(function_id);
+        }
+    }
+    for function_id in replacements.into_keys() {
+        module.funcs.delete(function_id);
+    }
+}
+
+fn create_trap_stub(module: &mut Module, function_id: FunctionId) -> FunctionId {
+    let (params, results) = {
+        let function_type = module.types.get(module.funcs.get(function_id).ty());
+        (
+            function_type.params().to_vec(),
+            function_type.results().to_vec(),
+        )
+    };
+    let args = params
+        .iter()
+        .map(|value_type| module.locals.add(*value_type))
+        .collect::<Vec<_>>();
+    let mut builder = FunctionBuilder::new(&mut module.types, &params, &results);
+    builder.func_body().unreachable();
+    let stub_id = builder.finish(args, &mut module.funcs);
+    let name = module.funcs.get(function_id).name.clone();
+    module.funcs.get_mut(stub_id).name = name;
+    stub_id
+}
+
+struct FunctionIdReplacer<'a>(&'a HashMap<FunctionId, FunctionId>);
+
+impl<'a> VisitorMut for FunctionIdReplacer<'a> {
+    fn visit_function_id_mut(&mut self, function: &mut FunctionId) {
+        if let Some(replacement) = self.0.get(function) {
+            *function = *replacement;
+        }
+    }
+}
+
+fn get_wasi_proc_exit_import(module: &mut Module) -> FunctionId {
+    let existing_import = module.imports.iter().find_map(|import| match import.kind {
+        ImportKind::Function(function_id)
+            if import.module == WASI_MODULE && import.name == WASI_PROC_EXIT =>
+        {
+            Some(function_id)
+        }
+        _ => None,
+    });
+    match existing_import {
+        Some(function_id) => function_id,
+        None => {
+            let type_id = module.types.add(&[ValType::I32], &[]);
+            let (function_id, _) = module.add_import_func(WASI_MODULE, WASI_PROC_EXIT, type_id);
+            function_id
+        }
+    }
+}
+
+fn get_exported_function_id(
+    module: &Module,
+    export_name: &str,
+) -> Result<FunctionId, WasmStandaloneError> {
+    module
+        .exports
+        .iter()
+        .find_map(|export| match export.item {
+            ExportItem::Function(function_id) if export.name == export_name => Some(function_id),
+            _ => None,
+        })
+        .ok_or_else(|| WasmStandaloneError::FunctionNotFound(String::from(export_name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use walrus::{ir::Visitor, ModuleConfig};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct FunctionCallCollector(Vec<FunctionId>);
+
+    impl<'a> Visitor<'a> for FunctionCallCollector {
+        fn visit_call(&mut self, instr: &ir::Call) {
+            self.0.push(instr.func);
+        }
+    }
+
+    #[test]
+    fn standalone_entry_point() {
+        let mut module = Module::with_config(ModuleConfig::new());
+        let fd_write_type = module.types.add(&[ValType::I32; 4], &[ValType::I32]);
+        module.add_import_func(WASI_MODULE, "fd_write", fd_write_type);
+        let host_type = module.types.add(&[ValType::F64], &[ValType::F64]);
+        let (host_function_id, _) = module.add_import_func("Math", "sin", host_type);
+        let format_type = module
+            .types
+            .add(&[ValType::F64, ValType::I32], &[ValType::I32]);
+        let (format_import_id, _) = module.add_import_func("Number", "toString", format_type);
+        let format_function_id = {
+            let mut builder = FunctionBuilder::new(
+                &mut module.types,
+                &[ValType::F64, ValType::I32],
+                &[ValType::I32],
+            );
+            let value = module.locals.add(ValType::F64);
+            let offset = module.locals.add(ValType::I32);
+            builder.func_body().i32_const(0);
+            builder.finish(vec![value, offset], &mut module.funcs)
+        };
+        module.exports.add("formatFloat", format_function_id);
+        let initializer_id = {
+            let builder = FunctionBuilder::new(&mut module.types, &[], &[]);
+            builder.finish(Vec::new(), &mut module.funcs)
+        };
+        module.exports.add(RUNTIME_INITIALIZER, initializer_id);
+        let entry_point_id = {
+            let mut builder =
+                FunctionBuilder::new(&mut module.types, &[ValType::I32], &[ValType::I32; 2]);
+            let state = module.locals.add(ValType::I32);
+            builder
+                .func_body()
+                .f64_const(0.0)
+                .call(host_function_id)
+                .drop()
+                .f64_const(0.0)
+                .i32_const(0)
+                .call(format_import_id)
+                .drop()
+                .local_get(state)
+                .local_get(state);
+            builder.finish(vec![state], &mut module.funcs)
+        };
+        module.exports.add("main", entry_point_id);
+        let print_result_id = {
+            let mut builder =
+                FunctionBuilder::new(&mut module.types, &[ValType::I32], &[ValType::I32]);
+            let result = module.locals.add(ValType::I32);
+            builder.func_body().i32_const(0);
+            builder.finish(vec![result], &mut module.funcs)
+        };
+        module.exports.add(RUNTIME_PRINT_RESULT, print_result_id);
+
+        assert!(matches!(
+            add_standalone_entry_point(&mut module, "missing"),
+            Err(WasmStandaloneError::FunctionNotFound(name)) if name == "missing"
+        ));
+
+        add_standalone_entry_point(&mut module, "main").unwrap();
+        let mut imports = module
+            .imports
+            .iter()
+            .map(|import| format!("{}.{}", import.module, import.name))
+            .collect::<Vec<_>>();
+        imports.sort();
+        assert_eq!(
+            imports,
+            vec![
+                format!("{WASI_MODULE}.fd_write"),
+                format!("{WASI_MODULE}.proc_exit"),
+            ]
+        );
+        let mut exports = module
+            .exports
+            .iter()
+            .map(|export| export.name.as_str())
+            .collect::<Vec<_>>();
+        exports.sort();
+        assert_eq!(
+            exports,
+            vec![STANDALONE_ENTRY_POINT, "formatFloat", "main", "printResult"]
+        );
+        let format_function_callers = module
+            .funcs
+            .iter_local()
+            .filter(|(_, function)| {
+                let mut visitor = FunctionCallCollector::default();
+                ir::dfs_in_order(&mut visitor, function, function.entry_block());
+                visitor.0.contains(&format_function_id)
+            })
+            .count();
+        assert_eq!(format_function_callers, 1);
+        let wasm_bytes = module.emit_wasm();
+        assert!(Module::from_buffer(&wasm_bytes).is_ok());
+    }
+}
@@ -0,0 +1,453 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+
+use serde::Deserialize;
+
+use crate::{
+    allocator::VecAllocator,
+    compiler::{
+        instruction::{self, CompiledInstruction},
+        CapturingThunk, CompiledBlock, CompiledFunctionId, CompiledLambda, CompiledThunk,
+        CompilerOptions, CompilerState, ConstValue, FunctionPointer, PureThunk,
+    },
+    hash::TermHashState,
+    serialize::Serialize,
+    ArenaPointer, ArenaRef, Term,
+};
+
+/// Version identifier incorporated into all compiler caches, ensuring that cached functions are discarded whenever the
+/// compiler implementation changes
+const CACHE_VERSION: &str = concat!(env!("CARGO_PKG_NAME"), "@", env!("CARGO_PKG_VERSION"));
+
+/// Compiled lambdas and thunks retained from a previous compiler invocation, keyed by term hash
+///
+/// Providing the cache to a subsequent compiler invocation allows any functions whose term hash is unchanged to be
+/// reused without needing to be recompiled. Compiled function bodies refer to static terms via pointers into the heap
+/// snapshot of the compiler invocation that produced them, so the cache additionally retains a copy of that heap
+/// snapshot: whenever a cached function is reused, any static terms it references are copied into the current heap
+/// snapshot and the function body is rewritten to point to the copied terms.
+#[derive(Default, Clone, Debug, serde::Serialize, Deserialize)]
+pub struct CompilerCache {
+    version: String,
+    /// Debug representation of the compiler options (and any overrides) used to compile the cached functions
+    options: String,
+    heap: Vec<u8>,
+    compiled_lambdas: HashMap<CompiledFunctionId, CompiledLambda>,
+    compiled_thunks: HashMap<TermHashState, CompiledThunk>,
+}
+
+impl CompilerCache {
+    pub fn is_empty(&self) -> bool {
+        self.compiled_lambdas.is_empty() && self.compiled_thunks.is_empty()
+    }
+    /// Number of cached lambda and thunk functions
+    pub fn len(&self) -> usize {
+        self.compiled_lambdas.len() + self.compiled_thunks.len()
+    }
+    /// Heap snapshot containing the static terms referenced by the cached functions
+    pub fn heap(&self) -> &[u8] {
+        &self.heap
+    }
+    pub fn compiled_lambdas(&self) -> &HashMap<CompiledFunctionId, CompiledLambda> {
+        &self.compiled_lambdas
+    }
+    pub fn compiled_thunks(&self) -> &HashMap<TermHashState, CompiledThunk> {
+        &self.compiled_thunks
+    }
+    /// Load a cache that was previously saved to the given path, serialized in MessagePack binary format
+    pub fn load(path: &Path) -> Result<Self, CompilerCacheError> {
+        let bytes = std::fs::read(path).map_err(CompilerCacheError::Io)?;
+        Self::from_bytes(&bytes)
+    }
+    /// Save the cache to the given path, serialized in MessagePack binary format
+    pub fn save(&self, path: &Path) -> Result<(), CompilerCacheError> {
+        let bytes = self.to_bytes()?;
+        // Write to a temporary file before renaming, to avoid leaving a partially-written cache if interrupted
+        let temp_path = path.with_extension(format!("{}.tmp", std::process::id()));
+        std::fs::write(&temp_path, bytes)
+            .and_then(|_| std::fs::rename(&temp_path, path))
+            .map_err(|err| {
+                let _ = std::fs::remove_file(&temp_path);
+                CompilerCacheError::Io(err)
+            })
+    }
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, CompilerCacheError> {
+        rmp_serde::from_slice(bytes).map_err(CompilerCacheError::Decode)
+    }
+    pub fn to_bytes(&self) -> Result<Vec<u8>, CompilerCacheError> {
+        rmp_serde::to_vec(self).map_err(CompilerCacheError::Encode)
+    }
+    fn is_compatible(&self, options: &str) -> bool {
+        self.version == CACHE_VERSION && self.options == options
+    }
+}
+
+#[derive(Debug)]
+pub enum CompilerCacheError {
+    Io(std::io::Error),
+    Encode(rmp_serde::encode::Error),
+    Decode(rmp_serde::decode::Error),
+}
+
+impl std::error::Error for CompilerCacheError {}
+
+impl std::fmt::Display for CompilerCacheError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Failed to access compiler cache: {}", err),
+            Self::Encode(err) => write!(f, "Failed to serialize compiler cache: {}", err),
+            Self::Decode(err) => write!(f, "Invalid compiler cache: {}", err),
+        }
+    }
+}
+
+impl CompilerState {
+    /// Provide a set of previously-compiled functions that can be reused within this compiler invocation
+    ///
+    /// The cache is ignored if it was created by a different compiler version or with different compiler options.
+    pub fn with_cache(self, cache: CompilerCache, options: &CompilerOptions) -> Self {
+        let options = self.format_cache_options(options);
+        Self {
+            cache: if cache.is_compatible(&options) && !cache.is_empty() {
+                Some(LoadedCompilerCache::new(cache))
+            } else {
+                None
+            },
+            ..self
+        }
+    }
+    /// Create a cache containing all the functions that have been compiled so far by this compiler invocation
+    pub fn to_cache(&self, options: &CompilerOptions) -> CompilerCache {
+        CompilerCache {
+            version: String::from(CACHE_VERSION),
+            options: self.format_cache_options(options),
+            heap: self.heap.as_bytes().to_vec(),
+            compiled_lambdas: self.compiled_lambdas.clone(),
+            compiled_thunks: self.compiled_thunks.clone(),
+        }
+    }
+    fn format_cache_options(&self, options: &CompilerOptions) -> String {
+        if self.options_overrides.is_empty() {
+            format!("{:?}", options)
+        } else {
+            // Sort the overrides to ensure a deterministic representation
+            let mut overrides = self
+                .options_overrides
+                .iter()
+                .map(|(hash, options)| (u64::from(*hash), options))
+                .collect::<Vec<_>>();
+            overrides.sort_by_key(|(hash, _)| *hash);
+            format!("{:?} {:?}", options, overrides)
+        }
+    }
+    /// Reuse the cached copy of the given lambda if one exists, returning `false` if the lambda needs to be compiled
+    pub(crate) fn restore_cached_lambda(&mut self, function_id: CompiledFunctionId) -> bool {
+        match self.cache.take() {
+            None => false,
+            Some(mut cache) => {
+                let result = cache.restore_lambda(function_id, self);
+                self.cache = Some(cache);
+                result
+            }
+        }
+    }
+    /// Reuse the cached copy of the given thunk if one exists, returning `false` if the thunk needs to be compiled
+    pub(crate) fn restore_cached_thunk(&mut self, thunk_id: TermHashState) -> bool {
+        match self.cache.take() {
+            None => false,
+            Some(mut cache) => {
+                let result = cache.restore_thunk(thunk_id, self);
+                self.cache = Some(cache);
+                result
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum CachedThunkTerm {
+    Application(TermHashState),
+    CompiledFunction(TermHashState),
+}
+
+pub(crate) struct LoadedCompilerCache {
+    heap: VecAllocator,
+    compiled_lambdas: HashMap<CompiledFunctionId, CompiledLambda>,
+    compiled_thunks: HashMap<TermHashState, CompiledThunk>,
+    /// Lookup table of the cached heap terms allocated for each thunk, used to identify references to cached thunks
+    thunk_terms: HashMap<ArenaPointer, CachedThunkTerm>,
+    /// Mapping from cached heap pointers to the equivalent pointers within the current heap snapshot
+    relocations: HashMap<ArenaPointer, ArenaPointer>,
+    /// Lambdas that are not present in the cache but will be provided separately (e.g. by another linked module)
+    external_lambdas: HashSet<CompiledFunctionId>,
+}
+
+impl LoadedCompilerCache {
+    fn new(cache: CompilerCache) -> Self {
+        let CompilerCache {
+            heap,
+            compiled_lambdas,
+            compiled_thunks,
+            ..
+        } = cache;
+        Self::from_parts(&heap, compiled_lambdas, compiled_thunks)
+    }
+    pub(crate) fn from_parts(
+        heap: &[u8],
+        compiled_lambdas: HashMap<CompiledFunctionId, CompiledLambda>,
+        compiled_thunks: HashMap<TermHashState, CompiledThunk>,
+    ) -> Self {
+        let thunk_terms = compiled_thunks
+            .iter()
+            .flat_map(|(thunk_id, thunk)| {
+                let thunk_id = *thunk_id;
+                let (application_term, compiled_function_term) = match thunk {
+                    CompiledThunk::Pure(PureThunk {
+                        application_term,
+                        compiled_function_term,
+                        ..
+                    }) => (Some(*application_term), *compiled_function_term),
+                    CompiledThunk::Capturing(CapturingThunk {
+                        compiled_function_term,
+                        ..
+                    }) => (None, *compiled_function_term),
+                };
+                application_term
+                    .map(|pointer| (pointer, CachedThunkTerm::Application(thunk_id)))
+                    .into_iter()
+                    .chain([(
+                        compiled_function_term,
+                        CachedThunkTerm::CompiledFunction(thunk_id),
+                    )])
+            })
+            .collect();
+        Self {
+            heap: VecAllocator::from_bytes(heap),
+            compiled_lambdas,
+            compiled_thunks,
+            thunk_terms,
+            relocations: Default::default(),
+            external_lambdas: Default::default(),
+        }
+    }
+    /// Treat references to the given lambdas as resolved, even though they are not present in the cache
+    pub(crate) fn with_external_lambdas(
+        self,
+        external_lambdas: impl IntoIterator<Item = CompiledFunctionId>,
+    ) -> Self {
+        Self {
+            external_lambdas: external_lambdas.into_iter().collect(),
+            ..self
+        }
+    }
+    pub(crate) fn compiled_lambda_ids(&self) -> impl Iterator<Item = CompiledFunctionId> + '_ {
+        self.compiled_lambdas.keys().copied()
+    }
+    pub(crate) fn compiled_thunk_ids(&self) -> impl Iterator<Item = TermHashState> + '_ {
+        self.compiled_thunks.keys().copied()
+    }
+    /// Iterate over the bodies of all the cached lambdas and thunks, keyed by their compiled function ID
+    pub(crate) fn compiled_function_bodies(
+        &self,
+    ) -> impl Iterator<Item = (CompiledFunctionId, &CompiledBlock)> + '_ {
+        self.compiled_lambdas
+            .iter()
+            .map(|(function_id, lambda)| (*function_id, &lambda.body))
+            .chain(
+                self.compiled_thunks
+                    .iter()
+                    .map(|(thunk_id, thunk)| (CompiledFunctionId::from(*thunk_id), thunk.body())),
+            )
+    }
+    pub(crate) fn restore_lambda(
+        &mut self,
+        function_id: CompiledFunctionId,
+        state: &mut CompilerState,
+    ) -> bool {
+        if state.compiled_lambdas.contains_key(&function_id)
+            || self.external_lambdas.contains(&function_id)
+        {
+            return true;
+        }
+        // Note that lambdas are identified by their content hash, so cannot contain cyclic references to themselves
+        let CompiledLambda { params, body } = match self.compiled_lambdas.remove(&function_id) {
+            Some(lambda) => lambda,
+            None => return false,
+        };
+        match self.relocate_block(body, state) {
+            Some(body) => {
+                state
+                    .compiled_lambdas
+                    .insert(function_id, CompiledLambda { params, body });
+                true
+            }
+            None => false,
+        }
+    }
+    pub(crate) fn restore_thunk(
+        &mut self,
+        thunk_id: TermHashState,
+        state: &mut CompilerState,
+    ) -> bool {
+        if state.compiled_thunks.contains_key(&thunk_id) {
+            return true;
+        }
+        let thunk = match self.compiled_thunks.remove(&thunk_id) {
+            Some(thunk) => thunk,
+            None => return false,
+        };
+        // Allocate fresh thunk terms within the current heap snapshot (placeholder terms are never shared across thunks)
+        let thunk = match thunk {
+            CompiledThunk::Pure(PureThunk {
+                thunk_function_body,
+                ..
+            }) => self
+                .relocate_block(thunk_function_body, state)
+                .map(|thunk_function_body| {
+                    let compiled_function_term = state.allocate_compiled_function_placeholder();
+                    CompiledThunk::Pure(PureThunk {
+                        application_term: state
+                            .allocate_thunk_application_term(compiled_function_term),
+                        thunk_function_body,
+                        compiled_function_term,
+                    })
+                }),
+            CompiledThunk::Capturing(CapturingThunk {
+                free_variables,
+                thunk_function_body,
+                ..
+            }) => self
+                .relocate_block(thunk_function_body, state)
+                .map(|thunk_function_body| {
+                    CompiledThunk::Capturing(CapturingThunk {
+                        free_variables,
+                        thunk_function_body,
+                        compiled_function_term: state.allocate_compiled_function_placeholder(),
+                    })
+                }),
+        };
+        match thunk {
+            Some(thunk) => {
+                state.compiled_thunks.insert(thunk_id, thunk);
+                true
+            }
+            None => false,
+        }
+    }
+    fn relocate_block(
+        &mut self,
+        block: CompiledBlock,
+        state: &mut CompilerState,
+    ) -> Option<CompiledBlock> {
+        block
+            .into_iter()
+            .map(|instruction| self.relocate_instruction(instruction, state))
+            .try_fold(CompiledBlock::default(), |mut block, instruction| {
+                block.push(instruction?);
+                Some(block)
+            })
+    }
+    fn relocate_instruction(
+        &mut self,
+        instruction: CompiledInstruction,
+        state: &mut CompilerState,
+    ) -> Option<CompiledInstruction> {
+        Some(match instruction {
+            CompiledInstruction::Const(instruction::core::Const { value }) => {
+                CompiledInstruction::Const(instruction::core::Const {
+                    value: self.relocate_value(value, state)?,
+                })
+            }
+            CompiledInstruction::Block(instruction::core::Block { block_type, body }) => {
+                CompiledInstruction::Block(instruction::core::Block {
+                    block_type,
+                    body: self.relocate_block(body, state)?,
+                })
+            }
+            CompiledInstruction::If(instruction::core::If {
+                block_type,
+                consequent,
+                alternative,
+            }) => CompiledInstruction::If(instruction::core::If {
+                block_type,
+                consequent: self.relocate_block(consequent, state)?,
+                alternative: self.relocate_block(alternative, state)?,
+            }),
+            CompiledInstruction::CallCompiledFunction(instruction) => {
+                if !self.restore_lambda(instruction.target, state) {
+                    return None;
+                }
+                CompiledInstruction::CallCompiledFunction(instruction)
+            }
+            instruction => instruction,
+        })
+    }
+    fn relocate_value(
+        &mut self,
+        value: ConstValue,
+        state: &mut CompilerState,
+    ) -> Option<ConstValue> {
+        match value {
+            ConstValue::HeapPointer(pointer) => self
+                .relocate_heap_pointer(pointer, state)
+                .map(ConstValue::HeapPointer),
+            ConstValue::FunctionPointer(FunctionPointer::Lambda(function_id)) => {
+                self.restore_lambda(function_id, state).then_some(value)
+            }
+            value => Some(value),
+        }
+    }
+    fn relocate_heap_pointer(
+        &mut self,
+        pointer: ArenaPointer,
+        state: &mut CompilerState,
+    ) -> Option<ArenaPointer> {
+        // References to thunk terms are resolved to the equivalent terms allocated for the restored thunk
+        if let Some(thunk_term) = self.thunk_terms.get(&pointer).copied() {
+            let thunk_id = match thunk_term {
+                CachedThunkTerm::Application(thunk_id) => thunk_id,
+                CachedThunkTerm::CompiledFunction(thunk_id) => thunk_id,
+            };
+            if !self.restore_thunk(thunk_id, state) {
+                return None;
+            }
+            return match (thunk_term, state.compiled_thunks.get(&thunk_id)?) {
+                (
+                    CachedThunkTerm::Application(_),
+                    CompiledThunk::Pure(PureThunk {
+                        application_term, ..
+                    }),
+                ) => Some(*application_term),
+                (
+                    CachedThunkTerm::CompiledFunction(_),
+                    CompiledThunk::Pure(PureThunk {
+                        compiled_function_term,
+                        ..
+                    })
+                    | CompiledThunk::Capturing(CapturingThunk {
+                        compiled_function_term,
+                        ..
+                    }),
+                ) => Some(*compiled_function_term),
+                _ => None,
+            };
+        }
+        // Any other static terms are copied into the current heap snapshot
+        if let Some(relocated) = self.relocations.get(&pointer) {
+            return Some(*relocated);
+        }
+        if pointer >= self.heap.end_offset() {
+            return None;
+        }
+        state.serializer_state.next_offset = state.heap.end_offset();
+        let relocated = ArenaRef::<Term, _>::new(&self.heap, pointer)
+            .serialize(&mut state.heap, &mut state.serializer_state);
+        self.relocations.insert(pointer, relocated);
+        Some(relocated)
+    }
+}
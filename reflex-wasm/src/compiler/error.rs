@@ -2,11 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 use derivative::Derivative;
-use reflex::core::{Arity, StackOffset};
+use reflex::{
+    core::{Arity, StackOffset},
+    hash::HashId,
+    source_map::SourceMetadata,
+};
 
 use crate::{
     allocator::Arena,
     compiler::{ParamsSignature, ValueType},
+    term_type::TermTypeDiscriminants,
     ArenaRef, FunctionIndex, Term,
 };
 
@@ -21,6 +26,39 @@ pub enum CompilerError<A: Arena> {
     },
     UnboundVariable(StackOffset),
     StackError(TypedStackError),
+    /// Static values interned into the heap snapshot exceeded the configured compiler limits
+    InternedHeapLimitExceeded(InternedHeapLimitError),
+    /// Error encountered while compiling a term that was parsed from the given user-level source
+    Source {
+        source: SourceMetadata,
+        error: Box<CompilerError<A>>,
+    },
+}
+
+impl<A: Arena> CompilerError<A> {
+    /// Annotate the error with the source provenance of the term being compiled
+    ///
+    /// Errors that have already been annotated are left unchanged, ensuring that the reported provenance refers to the
+    /// innermost user-level term that encloses the underlying error.
+    pub fn with_source(self, source: impl FnOnce() -> Option<SourceMetadata>) -> Self {
+        match self {
+            Self::Source { .. } => self,
+            error => match source() {
+                Some(source) => Self::Source {
+                    source,
+                    error: Box::new(error),
+                },
+                None => error,
+            },
+        }
+    }
+    /// Source provenance of the term that caused the error, if known
+    pub fn source(&self) -> Option<&SourceMetadata> {
+        match self {
+            Self::Source { source, .. } => Some(source),
+            _ => None,
+        }
+    }
 }
 
 impl<A: Arena + Clone> std::fmt::Display for CompilerError<A> {
@@ -46,6 +84,8 @@ impl<A: Arena + Clone> std::fmt::Display for CompilerError<A> {
                 write!(f, "Unbound variable scope offset {scope_offset}")
             }
             Self::StackError(err) => write!(f, "Stack error: {}", err),
+            Self::InternedHeapLimitExceeded(err) => std::fmt::Display::fmt(err, f),
+            Self::Source { source, error } => write!(f, "{error} (in {source})"),
         }
     }
 }
@@ -56,12 +96,108 @@ impl<A: Arena> From<TypedStackError> for CompilerError<A> {
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum InternedHeapLimitKind {
+    /// Combined size of all the terms interned into the heap snapshot
+    HeapSize,
+    /// Size of an individual interned term
+    TermSize,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InternedHeapLimitError {
+    pub kind: InternedHeapLimitKind,
+    /// Configured limit, in bytes
+    pub limit: usize,
+    /// Size that exceeded the limit, in bytes
+    pub size: usize,
+    /// Largest terms interned so far (including the offending term), ordered from largest to smallest
+    pub largest_terms: Vec<InternedTermSummary>,
+}
+
+impl std::fmt::Display for InternedHeapLimitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let _ = match self.kind {
+            InternedHeapLimitKind::HeapSize => write!(
+                f,
+                "Interned heap snapshot size limit exceeded: {} bytes allocated (limit: {} bytes)",
+                self.size, self.limit
+            ),
+            InternedHeapLimitKind::TermSize => write!(
+                f,
+                "Interned term size limit exceeded: {} bytes allocated (limit: {} bytes)",
+                self.size, self.limit
+            ),
+        }?;
+        if !self.largest_terms.is_empty() {
+            let _ = write!(f, "\nLargest interned terms:")?;
+        }
+        self.largest_terms.iter().fold(Ok(()), |result, term| {
+            let _ = result?;
+            write!(f, "\n    {term}")
+        })
+    }
+}
+
+/// Diagnostic summary of a term that was interned into the heap snapshot during compilation
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InternedTermSummary {
+    pub id: HashId,
+    pub term_type: TermTypeDiscriminants,
+    /// Number of heap snapshot bytes allocated when interning the term
+    pub size: usize,
+    /// Source provenance of the term, if it was parsed from user-level source
+    pub source: Option<SourceMetadata>,
+}
+
+impl std::fmt::Display for InternedTermSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let _ = write!(
+            f,
+            "{} bytes: {:?} <{:#016x}>",
+            self.size, self.term_type, self.id
+        )?;
+        match self.source.as_ref() {
+            Some(source) => write!(f, " ({source})"),
+            None => Ok(()),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum TypedStackError {
     InvalidOperandStackValueTypes(InvalidOperandStackValueTypesError),
     InvalidLexicalScopeValueType(InvalidLexicalScopeValueTypeError),
     InvalidBlockResultType(InvalidBlockResultTypeError),
     InvalidBlockReference(InvalidBlockReferenceError),
+    /// Error encountered while compiling the given trail of nested terms
+    Traced(TracedStackError),
+}
+
+impl TypedStackError {
+    /// Annotate the error with the trail of terms that were being compiled when the error occurred
+    ///
+    /// Errors that have already been annotated are left unchanged, ensuring that the reported trace leads to the
+    /// innermost term that encloses the underlying error.
+    pub fn with_trace(self, trace: impl FnOnce() -> Vec<CompilerTraceFrame>) -> Self {
+        match self {
+            Self::Traced(_) => self,
+            error => match trace() {
+                trace if trace.is_empty() => error,
+                trace => Self::Traced(TracedStackError {
+                    trace,
+                    error: Box::new(error),
+                }),
+            },
+        }
+    }
+    /// Trail of terms that were being compiled when the error occurred, ordered from innermost to outermost
+    pub fn trace(&self) -> &[CompilerTraceFrame] {
+        match self {
+            Self::Traced(inner) => &inner.trace,
+            _ => &[],
+        }
+    }
 }
 
 impl std::fmt::Display for TypedStackError {
@@ -71,10 +207,45 @@ impl std::fmt::Display for TypedStackError {
             Self::InvalidLexicalScopeValueType(inner) => std::fmt::Display::fmt(inner, f),
             Self::InvalidBlockResultType(inner) => std::fmt::Display::fmt(inner, f),
             Self::InvalidBlockReference(inner) => std::fmt::Display::fmt(inner, f),
+            Self::Traced(inner) => std::fmt::Display::fmt(inner, f),
+        }
+    }
+}
+
+/// Breadcrumb identifying a term whose compilation was in progress when a compiler error occurred
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CompilerTraceFrame {
+    pub term_type: TermTypeDiscriminants,
+    /// Source provenance of the term, if it was parsed from user-level source
+    pub source: Option<SourceMetadata>,
+}
+
+impl std::fmt::Display for CompilerTraceFrame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.source.as_ref() {
+            Some(source) => write!(f, "{:?} ({})", self.term_type, source),
+            None => write!(f, "{:?}", self.term_type),
         }
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TracedStackError {
+    /// Terms that were being compiled when the error occurred, ordered from innermost to outermost
+    pub trace: Vec<CompilerTraceFrame>,
+    pub error: Box<TypedStackError>,
+}
+
+impl std::fmt::Display for TracedStackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let _ = write!(f, "{}", self.error)?;
+        self.trace.iter().fold(Ok(()), |result, frame| {
+            let _ = result?;
+            write!(f, "\n    at {frame}")
+        })
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct InvalidOperandStackValueTypesError {
     pub expected: ParamsSignature,
@@ -160,3 +331,52 @@ impl std::fmt::Display for InvalidBlockReferenceError {
         write!(f, "Invalid enclosing block depth: {}", self.target_block)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::compiler::{instruction::core::Drop, CompiledBlockBuilder, CompilerStack};
+
+    use super::*;
+
+    #[test]
+    fn traced_stack_errors() {
+        let stack = CompilerStack::default()
+            .enter_term(CompilerTraceFrame {
+                term_type: TermTypeDiscriminants::Let,
+                source: Some(SourceMetadata {
+                    name: Some(String::from("foo")),
+                    location: None,
+                }),
+            })
+            .enter_term(CompilerTraceFrame {
+                term_type: TermTypeDiscriminants::Application,
+                source: None,
+            });
+        let err = CompiledBlockBuilder::new(stack)
+            .push(Drop {
+                value_type: ValueType::HeapPointer,
+            })
+            .finish::<TypedStackError>()
+            .unwrap_err();
+        assert_eq!(
+            err.trace(),
+            [
+                CompilerTraceFrame {
+                    term_type: TermTypeDiscriminants::Application,
+                    source: None,
+                },
+                CompilerTraceFrame {
+                    term_type: TermTypeDiscriminants::Let,
+                    source: Some(SourceMetadata {
+                        name: Some(String::from("foo")),
+                        location: None,
+                    }),
+                },
+            ]
+        );
+        assert_eq!(
+            format!("{err}"),
+            "Invalid values on operand stack: Expected (<pointer>), received ()\n    at Application\n    at Let (foo)",
+        );
+    }
+}
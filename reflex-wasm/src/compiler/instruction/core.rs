@@ -1,6 +1,7 @@
 // SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use serde::{Deserialize, Serialize};
 use walrus::{
     ir::{self, BinaryOp, LoadKind, MemArg, StoreKind, Value},
     Module,
@@ -23,7 +24,7 @@ use crate::{
 };
 
 /// Push a constant value onto the operand stack
-#[derive(PartialEq, Clone, Hash, Debug)]
+#[derive(PartialEq, Clone, Hash, Debug, Serialize, Deserialize)]
 pub struct Const {
     /// Value to push onto the operand stack
     pub value: ConstValue,
@@ -78,7 +79,7 @@ impl GenerateWasm for Const {
 }
 
 /// Duplicate the value at the top of the operand stack
-#[derive(PartialEq, Clone, Hash, Debug)]
+#[derive(PartialEq, Clone, Hash, Debug, Serialize, Deserialize)]
 pub struct Duplicate {
     /// Type of the value being duplicated on the operand stack
     pub value_type: ValueType,
@@ -114,7 +115,7 @@ impl GenerateWasm for Duplicate {
 }
 
 /// Pop the term at the top of the operand stack and discard it
-#[derive(PartialEq, Clone, Hash, Debug)]
+#[derive(PartialEq, Clone, Hash, Debug, Serialize, Deserialize)]
 pub struct Drop {
     /// Type of the value being dropped from the operand stack
     pub value_type: ValueType,
@@ -143,7 +144,7 @@ impl GenerateWasm for Drop {
 }
 
 /// Pop the top item of the operand stack and enter a new lexical scope whose variable is assigned to that value
-#[derive(PartialEq, Clone, Hash, Debug)]
+#[derive(PartialEq, Clone, Hash, Debug, Serialize, Deserialize)]
 pub struct ScopeStart {
     /// Type of the variable declared by the lexical scope
     pub value_type: ValueType,
@@ -179,7 +180,7 @@ impl GenerateWasm for ScopeStart {
 }
 
 /// Pop the latest lexical scope
-#[derive(PartialEq, Clone, Hash, Debug)]
+#[derive(PartialEq, Clone, Hash, Debug, Serialize, Deserialize)]
 pub struct ScopeEnd {
     /// Type of the variable declared by the lexical scope
     /// (this must match the corresponding scope start instruction)
@@ -210,7 +211,7 @@ impl GenerateWasm for ScopeEnd {
 }
 
 /// Push a variable defined in a containing lexical scope onto the operand stack
-#[derive(PartialEq, Clone, Hash, Debug)]
+#[derive(PartialEq, Clone, Hash, Debug, Serialize, Deserialize)]
 pub struct GetScopeValue {
     /// Type of the variable declared by the lexical scope
     /// (this must match the corresponding lexical scope's declaration)
@@ -252,7 +253,7 @@ impl GenerateWasm for GetScopeValue {
 
 /// Enter a new control flow block
 /// (this allows child instructions to break out of the block)
-#[derive(PartialEq, Clone, Hash, Debug)]
+#[derive(PartialEq, Clone, Hash, Debug, Serialize, Deserialize)]
 pub struct Block {
     /// Description of operand stack items to inject into the block, along with the result type of the block
     pub block_type: TypeSignature,
@@ -289,7 +290,7 @@ impl GenerateWasm for Block {
 }
 
 /// Unconditionally break out of the specified control flow block
-#[derive(PartialEq, Clone, Hash, Debug)]
+#[derive(PartialEq, Clone, Hash, Debug, Serialize, Deserialize)]
 pub struct Break {
     /// Index of the control flow block to break out of
     /// (where `0` is the current control flow block, `1` is the immediate parent of the current control flow block, etc)
@@ -338,7 +339,7 @@ impl GenerateWasm for Break {
 }
 
 /// Pop the top item from the operand stack, and if the value is not `0` then break out of the specified control flow block, otherwise continue with the current block
-#[derive(PartialEq, Clone, Hash, Debug)]
+#[derive(PartialEq, Clone, Hash, Debug, Serialize, Deserialize)]
 pub struct ConditionalBreak {
     /// Index of the control flow block to break out of
     /// (where `0` is the current control flow block, `1` is the immediate parent of the current control flow block, etc)
@@ -383,7 +384,7 @@ impl GenerateWasm for ConditionalBreak {
 
 /// Pop the top item of the operand stack, and if the value is not `0` then enter the `consequent` block, otherwise enter the `alternative` block
 /// (note that the `consequent` and `alternative` blocks count towards the target block offset when breaking out of parent control flow blocks)
-#[derive(PartialEq, Clone, Hash, Debug)]
+#[derive(PartialEq, Clone, Hash, Debug, Serialize, Deserialize)]
 pub struct If {
     /// Description of operand stack items to inject into the consequent/alternative blocks, along with the result type of the blocks
     /// (both blocks must have the same type)
@@ -443,7 +444,7 @@ impl GenerateWasm for If {
 }
 
 /// Pop the top three values from the operand stack, and if the top item is not `0`, push the bottom value back onto the stack, otherwise push the middle value onto the stack
-#[derive(PartialEq, Clone, Hash, Debug)]
+#[derive(PartialEq, Clone, Hash, Debug, Serialize, Deserialize)]
 pub struct Select {
     /// Type of the value to select from the stack
     /// (this must match the corresponding value on the operand stack)
@@ -482,7 +483,7 @@ impl GenerateWasm for Select {
 }
 
 /// Pop the top two values from the operand stack, and push a constant `1` if they are equal, or `0` if not
-#[derive(PartialEq, Clone, Hash, Debug)]
+#[derive(PartialEq, Clone, Hash, Debug, Serialize, Deserialize)]
 pub struct Eq {
     /// Type of the values to compare for equality
     /// (this must match the corresponding values on the operand stack)
@@ -525,7 +526,7 @@ impl GenerateWasm for Eq {
 }
 
 /// Pop the top two values from the operand stack, and push a constant `0` if they are equal, or `1` if not
-#[derive(PartialEq, Clone, Hash, Debug)]
+#[derive(PartialEq, Clone, Hash, Debug, Serialize, Deserialize)]
 pub struct Ne {
     /// Type of the values to compare for equality
     /// (this must match the corresponding values on the operand stack)
@@ -568,7 +569,7 @@ impl GenerateWasm for Ne {
 }
 
 /// Pop a target heap pointer address from the operand stack, read the value at that offset within the heap memory and push the value onto the operand stack
-#[derive(PartialEq, Clone, Hash, Debug)]
+#[derive(PartialEq, Clone, Hash, Debug, Serialize, Deserialize)]
 pub struct ReadHeapValue {
     /// Type of the value being read from the heap
     pub value_type: ValueType,
@@ -615,7 +616,7 @@ impl GenerateWasm for ReadHeapValue {
 }
 
 /// Pop a value from the operand stack, then pop the target heap pointer address from the operand stack, then write the value to that offset in the heap memory
-#[derive(PartialEq, Clone, Hash, Debug)]
+#[derive(PartialEq, Clone, Hash, Debug, Serialize, Deserialize)]
 pub struct WriteHeapValue {
     /// Type of the value being written to the heap
     pub value_type: ValueType,
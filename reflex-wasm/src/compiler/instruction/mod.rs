@@ -1,6 +1,7 @@
 // SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use serde::{Deserialize, Serialize};
 use walrus::Module;
 
 use crate::compiler::{
@@ -28,7 +29,7 @@ pub mod runtime;
 ///     the lexical scope. Variables stored in the lexical scopes cannot be mutated (although mutation can be simulated
 ///     by using a SSA-style approach).
 ///   3. The control flow block stack, which stores nested code blocks. Nested instructions can break out of enclosing blocks.
-#[derive(PartialEq, Clone, Hash, Debug)]
+#[derive(PartialEq, Clone, Hash, Debug, Serialize, Deserialize)]
 pub enum CompiledInstruction {
     Const(core::Const),
     Duplicate(core::Duplicate),
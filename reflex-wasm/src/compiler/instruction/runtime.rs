@@ -3,6 +3,7 @@
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 use std::iter::repeat;
 
+use serde::{Deserialize, Serialize};
 use walrus::{ir, Module};
 
 use crate::{
@@ -24,7 +25,7 @@ use crate::{
 };
 
 /// Push a null pointer onto the operand stack
-#[derive(Clone, PartialEq, Hash, Debug)]
+#[derive(Clone, PartialEq, Hash, Debug, Serialize, Deserialize)]
 pub struct NullPointer;
 
 impl TypedCompilerBlock for NullPointer {
@@ -53,7 +54,7 @@ impl GenerateWasm for NullPointer {
 /// Pop the top item of the operand stack and enter a new lexical scope whose variable is assigned to that value
 /// (note that this lexical scope will be marked as a 'variable' scope, allowing its value to be retrieved within any
 /// child scopes by looking up the variable offset on the stack)
-#[derive(PartialEq, Clone, Hash, Debug)]
+#[derive(PartialEq, Clone, Hash, Debug, Serialize, Deserialize)]
 pub struct DeclareVariable {
     /// Type of the variable declared by the lexical scope
     pub value_type: ValueType,
@@ -93,7 +94,7 @@ impl GenerateWasm for DeclareVariable {
 /// and will continue to be used to accumulate dependencies until the lexical scope is ended).
 /// Any dependencies accumulated within the capture scope will be discarded once the scope is ended, so the value must
 /// be retrieved and assigned somewhere in order to retain a record of the dependencies.
-#[derive(PartialEq, Clone, Hash, Debug)]
+#[derive(PartialEq, Clone, Hash, Debug, Serialize, Deserialize)]
 pub struct DeclareDependenciesVariable;
 
 impl TypedCompilerBlock for DeclareDependenciesVariable {
@@ -127,7 +128,7 @@ impl GenerateWasm for DeclareDependenciesVariable {
 }
 
 /// Push a copy of the value of the current dependencies variable onto the operand stack
-#[derive(PartialEq, Clone, Hash, Debug)]
+#[derive(PartialEq, Clone, Hash, Debug, Serialize, Deserialize)]
 pub struct GetDependenciesValue;
 
 impl TypedCompilerBlock for GetDependenciesValue {
@@ -155,7 +156,7 @@ impl GenerateWasm for GetDependenciesValue {
 
 /// Pop a term pointer from the operand stack, look that key up in the global state object,
 /// and push either the corresponding value term reference or a null pointer depending on whether the key exists
-#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub struct LoadStateValue;
 
 impl TypedCompilerBlock for LoadStateValue {
@@ -197,7 +198,7 @@ impl GenerateWasm for LoadStateValue {
 
 /// Invoke an interpreter builtin function, popping the required number of arguments from the operand stack
 /// (arguments are passed to the function in the same order they were added to the operand stack, i.e. not reversed)
-#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub struct CallRuntimeBuiltin {
     /// Runtime function to invoke
     pub target: RuntimeBuiltin,
@@ -234,7 +235,7 @@ impl GenerateWasm for CallRuntimeBuiltin {
 /// Invoke a standard library function known at compile-time, popping the required number of arguments from the operand stack
 /// (arguments are passed to the function in the same order they were added to the operand stack, i.e. not reversed)
 /// If the function has variadic arguments, the final argument is assumed to be a heap pointer to a list term containing the variadic arguments
-#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub struct CallStdlib {
     /// Standard library method to invoke
     pub target: Stdlib,
@@ -287,7 +288,7 @@ impl GenerateWasm for CallStdlib {
 
 /// Invoke a user-defined function known at compile-time, popping the required number of arguments from the operand stack
 /// (arguments are passed to the function in the same order they were added to the operand stack, i.e. not reversed)
-#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub struct CallCompiledFunction {
     /// Type signature of the target function
     pub signature: TypeSignature,
@@ -341,7 +342,7 @@ impl GenerateWasm for CallCompiledFunction {
 /// Pop the argument list term pointer from the operand stack,
 /// then pop the target function index from the operand stack,
 /// then invoke the corresponding function, pushing the result onto the operand stack
-#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub struct CallDynamic {
     /// Type signature of the target function
     pub signature: TypeSignature,
@@ -391,7 +392,7 @@ impl GenerateWasm for CallDynamic {
 }
 
 /// Pop the top item of the operand stack, evaluate it, and push the result onto the operand stack.
-#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub struct Evaluate;
 
 impl TypedCompilerBlock for Evaluate {
@@ -434,7 +435,7 @@ impl GenerateWasm for Evaluate {
 /// Pop the argument list term pointer from the operand stack,
 /// then pop the application target term pointer from the operand stack,
 /// then apply the corresponding target term to the arguments list, pushing the result onto the operand stack
-#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub struct Apply;
 
 impl TypedCompilerBlock for Apply {
@@ -481,7 +482,7 @@ impl GenerateWasm for Apply {
 /// Pop the specified number of term pointers from the top of the operand stack,
 /// and push with a single combined signal term pointer onto top of the operand stack (in the case where one or moreof the operands is a signal)
 /// or a null pointer (in the case where none of the terms is a signal)
-#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub struct CollectSignals {
     /// Number of operand stack values to test for signals
     pub count: usize,
@@ -665,7 +666,7 @@ impl GenerateWasm for CollectSignals {
 
 /// Peek at the term pointer on top of the operand stack, and if it is a signal,
 /// break out of the specified control flow block
-#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub struct BreakOnSignal {
     /// Index of the control flow block to break out of
     /// (where `0` is the current control flow block, `1` is the immediate parent of the current control flow block, etc)
@@ -0,0 +1,183 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::collections::HashSet;
+
+use crate::{
+    allocator::VecAllocator,
+    compiler::{
+        cache::LoadedCompilerCache,
+        instruction::{self, CompiledInstruction},
+        CompiledBlock, CompiledFunctionId, CompilerState, ConstValue, FunctionPointer,
+    },
+    hash::TermHashState,
+    ArenaRef, Term,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum CompilerLinkerError {
+    /// A compiled function refers to a lambda that is not defined by any of the linked modules
+    UnresolvedFunction {
+        source: CompiledFunctionId,
+        target: CompiledFunctionId,
+    },
+    /// A compiled lambda could not be relocated into the combined heap snapshot
+    InvalidLambda(CompiledFunctionId),
+    /// A compiled thunk could not be relocated into the combined heap snapshot
+    InvalidThunk(TermHashState),
+}
+
+impl std::error::Error for CompilerLinkerError {}
+
+impl std::fmt::Display for CompilerLinkerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnresolvedFunction { source, target } => write!(
+                f,
+                "Unresolved function reference to {} within compiled function {}",
+                target, source
+            ),
+            Self::InvalidLambda(function_id) => {
+                write!(f, "Failed to link compiled function {}", function_id)
+            }
+            Self::InvalidThunk(thunk_id) => write!(
+                f,
+                "Failed to link compiled thunk {}",
+                CompiledFunctionId::from(*thunk_id)
+            ),
+        }
+    }
+}
+
+/// Combine the output of multiple compiler invocations into a single compiler state
+///
+/// The first module is used as the base for the combined compiler state: the compiled functions of each subsequent
+/// module are copied into the base module, along with any static heap terms that they reference. Static terms are
+/// interned within the combined heap snapshot, so terms shared across multiple modules are only allocated once.
+///
+/// Compiled lambdas are identified by their content hash, so lambdas that are defined by multiple modules are only
+/// linked once, and functions within one module can call lambdas defined by any of the other linked modules (e.g. an
+/// application module can be compiled separately from a shared library module and the two modules linked together).
+/// Linking fails if any compiled function refers to a lambda that is not defined by any of the linked modules.
+pub fn link_compiler_states(
+    modules: impl IntoIterator<Item = CompilerState>,
+) -> Result<CompilerState, CompilerLinkerError> {
+    let mut modules = modules.into_iter();
+    let mut state = match modules.next() {
+        Some(state) => state,
+        None => {
+            return Ok(CompilerState::from_heap_values(std::iter::empty::<
+                ArenaRef<Term, &VecAllocator>,
+            >()))
+        }
+    };
+    let modules = modules
+        .map(|module| {
+            let CompilerState {
+                heap,
+                compiled_lambdas,
+                compiled_thunks,
+                options_overrides,
+                source_metadata,
+                ..
+            } = module;
+            // Retain any metadata associated with the linked module terms (keyed by term hash, so valid across heaps)
+            state.options_overrides.extend(options_overrides);
+            state.source_metadata.extend(
+                source_metadata
+                    .iter()
+                    .map(|(hash, metadata)| (hash, metadata.clone())),
+            );
+            LoadedCompilerCache::from_parts(heap.as_bytes(), compiled_lambdas, compiled_thunks)
+        })
+        .collect::<Vec<_>>();
+
+    // Determine the full set of lambdas defined across all the linked modules
+    let defined_lambdas = state
+        .compiled_lambdas
+        .keys()
+        .copied()
+        .chain(
+            modules
+                .iter()
+                .flat_map(|module| module.compiled_lambda_ids()),
+        )
+        .collect::<HashSet<_>>();
+
+    // Ensure that all cross-module function references can be resolved before modifying the combined heap
+    let base_function_bodies = state
+        .compiled_lambdas
+        .iter()
+        .map(|(function_id, lambda)| (*function_id, &lambda.body))
+        .chain(
+            state
+                .compiled_thunks
+                .iter()
+                .map(|(thunk_id, thunk)| (CompiledFunctionId::from(*thunk_id), thunk.body())),
+        );
+    for (function_id, body) in base_function_bodies.chain(
+        modules
+            .iter()
+            .flat_map(|module| module.compiled_function_bodies()),
+    ) {
+        validate_function_references(function_id, body, &defined_lambdas)?;
+    }
+
+    // Copy the compiled functions from each module into the combined compiler state, treating any lambdas defined by
+    // the other modules as external references that will be resolved when those modules are linked
+    for module in modules {
+        let local_lambdas = module.compiled_lambda_ids().collect::<HashSet<_>>();
+        let mut module = module.with_external_lambdas(
+            defined_lambdas
+                .iter()
+                .copied()
+                .filter(|function_id| !local_lambdas.contains(function_id)),
+        );
+        for function_id in local_lambdas {
+            if !module.restore_lambda(function_id, &mut state) {
+                return Err(CompilerLinkerError::InvalidLambda(function_id));
+            }
+        }
+        for thunk_id in module.compiled_thunk_ids().collect::<Vec<_>>() {
+            if !module.restore_thunk(thunk_id, &mut state) {
+                return Err(CompilerLinkerError::InvalidThunk(thunk_id));
+            }
+        }
+    }
+    Ok(state)
+}
+
+fn validate_function_references(
+    function_id: CompiledFunctionId,
+    body: &CompiledBlock,
+    defined_lambdas: &HashSet<CompiledFunctionId>,
+) -> Result<(), CompilerLinkerError> {
+    body.iter().try_for_each(|instruction| {
+        let target = match instruction {
+            CompiledInstruction::Block(instruction::core::Block { body, .. }) => {
+                return validate_function_references(function_id, body, defined_lambdas);
+            }
+            CompiledInstruction::If(instruction::core::If {
+                consequent,
+                alternative,
+                ..
+            }) => {
+                validate_function_references(function_id, consequent, defined_lambdas)?;
+                return validate_function_references(function_id, alternative, defined_lambdas);
+            }
+            CompiledInstruction::CallCompiledFunction(instruction) => instruction.target,
+            CompiledInstruction::Const(instruction::core::Const {
+                value: ConstValue::FunctionPointer(FunctionPointer::Lambda(target)),
+            }) => *target,
+            _ => return Ok(()),
+        };
+        if defined_lambdas.contains(&target) {
+            Ok(())
+        } else {
+            Err(CompilerLinkerError::UnresolvedFunction {
+                source: function_id,
+                target,
+            })
+        }
+    })
+}
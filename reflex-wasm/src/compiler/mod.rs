@@ -10,20 +10,24 @@ use std::{
 use reflex::{
     core::{ArgType, Arity, GraphNode, NodeId, StackOffset},
     hash::IntMap,
+    source_map::SourceMetadataTable,
 };
 use reflex_utils::{Stack, Visitable};
 
 use crate::{
     allocator::{Arena, ArenaAllocator, ArenaIterator, VecAllocator},
     compiler::{
+        cache::LoadedCompilerCache,
         error::{
-            CompilerError, InvalidBlockResultTypeError, InvalidLexicalScopeValueTypeError,
+            CompilerError, CompilerTraceFrame, InternedHeapLimitError, InternedHeapLimitKind,
+            InternedTermSummary, InvalidBlockResultTypeError, InvalidLexicalScopeValueTypeError,
             InvalidOperandStackValueTypesError, TypedStackError,
         },
         instruction::CompiledInstruction,
         runtime::builtin::RuntimeBuiltin,
     },
     hash::{TermHashState, TermHasher, TermSize},
+    heap_snapshot::{decode_heap_snapshot_with_migration, HeapSnapshotError},
     serialize::{Serialize, SerializerState},
     stdlib::Stdlib,
     term_type::*,
@@ -34,8 +38,11 @@ use crate::{
     ArenaPointer, ArenaPointerIterator, ArenaRef, Array, Term,
 };
 
+pub mod cache;
 pub mod error;
 pub mod instruction;
+pub mod linker;
+pub mod options;
 pub mod runtime;
 pub mod wasm;
 
@@ -63,7 +70,7 @@ pub trait Internable {
     fn should_intern(&self, eager: ArgType) -> bool;
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash, serde::Serialize, serde::Deserialize)]
 pub struct TypeSignature {
     pub params: ParamsSignature,
     pub results: ParamsSignature,
@@ -84,7 +91,7 @@ impl std::fmt::Display for TypeSignature {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash, serde::Serialize, serde::Deserialize)]
 pub enum ParamsSignature {
     Void,
     Single(ValueType),
@@ -258,7 +265,7 @@ impl<'a> Iterator for ParamsSignatureIter<'a> {
 
 impl<'a> ExactSizeIterator for ParamsSignatureIter<'a> {}
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum ConstValue {
     I32(i32),
     U32(u32),
@@ -308,7 +315,7 @@ impl ConstValue {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum FunctionPointer {
     Stdlib(Stdlib),
     Lambda(CompiledFunctionId),
@@ -324,7 +331,7 @@ impl From<CompiledFunctionId> for FunctionPointer {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum ValueType {
     I32,
     U32,
@@ -359,6 +366,10 @@ pub struct CompilerOptions {
     pub lazy_function_args: bool,
     pub lazy_lambda_args: ArgType,
     pub lazy_constructors: ArgType,
+    /// Evaluate applications of pure arithmetic/comparison builtins to literal arguments at compile time
+    pub constant_folding: bool,
+    /// Inline applications of lambdas whose body term size does not exceed this threshold (zero disables inlining)
+    pub inline_lambda_threshold: usize,
 }
 
 impl Default for CompilerOptions {
@@ -370,10 +381,31 @@ impl Default for CompilerOptions {
             lazy_function_args: false,
             lazy_lambda_args: ArgType::Strict,
             lazy_constructors: ArgType::Strict,
+            constant_folding: true,
+            inline_lambda_threshold: 8,
         }
     }
 }
 
+/// Limits on the growth of the heap snapshot during compilation, guarding against runaway memory usage when compiling
+/// programs that contain enormous literal datasets
+#[derive(Default, Copy, Clone, PartialEq, Eq, Debug)]
+pub struct CompilerLimits {
+    /// Maximum combined size in bytes of the static values interned into the heap snapshot during compilation
+    pub max_interned_heap_size: Option<usize>,
+    /// Maximum size in bytes of any individual static value interned into the heap snapshot
+    pub max_interned_term_size: Option<usize>,
+}
+
+impl CompilerLimits {
+    fn is_enabled(&self) -> bool {
+        self.max_interned_heap_size.is_some() || self.max_interned_term_size.is_some()
+    }
+}
+
+/// Number of interned terms to report when a compiler limit is exceeded
+const MAX_REPORTED_INTERNED_TERMS: usize = 10;
+
 #[derive(Clone, Debug)]
 pub struct CompiledBlockBuilder {
     result: Result<CompiledBlock, TypedStackError>,
@@ -401,7 +433,12 @@ impl CompiledBlockBuilder {
                     (Ok(instructions), updated_stack)
                 })
             })
-            .unwrap_or_else(|err| (Err(err), stack));
+            .unwrap_or_else(|err| {
+                (
+                    Err(err.with_trace(|| stack.trace().cloned().collect())),
+                    stack,
+                )
+            });
         Self { result, stack }
     }
     #[must_use]
@@ -414,7 +451,12 @@ impl CompiledBlockBuilder {
                     (Ok(instructions), updated_stack)
                 })
             })
-            .unwrap_or_else(|err| (Err(err), stack));
+            .unwrap_or_else(|err| {
+                (
+                    Err(err.with_trace(|| stack.trace().cloned().collect())),
+                    stack,
+                )
+            });
         Self { result, stack }
     }
     #[must_use]
@@ -434,7 +476,7 @@ impl CompiledBlockBuilder {
     }
 }
 
-#[derive(Default, PartialEq, Clone, Hash, Debug)]
+#[derive(Default, PartialEq, Clone, Hash, Debug, serde::Serialize, serde::Deserialize)]
 pub struct CompiledBlock {
     instructions: LinkedList<CompiledInstruction>,
 }
@@ -490,14 +532,27 @@ pub struct CompilerState {
     pub(crate) heap: VecAllocator,
     pub(crate) compiled_lambdas: HashMap<CompiledFunctionId, CompiledLambda>,
     pub(crate) compiled_thunks: HashMap<TermHashState, CompiledThunk>,
-}
-
+    pub(crate) cache: Option<LoadedCompilerCache>,
+    /// Compiler options overrides for the subtrees rooted at the terms with the given hashes
+    pub(crate) options_overrides: HashMap<TermHashState, CompilerOptions>,
+    /// Source provenance of user-level terms, used to annotate compiler errors
+    pub(crate) source_metadata: SourceMetadataTable,
+    /// Limits on the growth of the heap snapshot during compilation
+    pub(crate) limits: CompilerLimits,
+    /// Combined size in bytes of the static values interned during compilation (only tracked when limits are enabled)
+    pub(crate) interned_heap_size: usize,
+    /// Largest static values interned during compilation, ordered from largest to smallest (only tracked when limits
+    /// are enabled)
+    pub(crate) largest_interned_terms: Vec<InternedTermSummary>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct CompiledLambda {
     pub params: ParamsSignature,
     pub body: CompiledBlock,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct CompiledFunctionId(TermHashState);
 
 impl<'a, A: Arena> From<&'a ArenaRef<LambdaTerm, A>> for CompiledFunctionId {
@@ -556,15 +611,116 @@ impl CompilerState {
             },
             compiled_lambdas: Default::default(),
             compiled_thunks: Default::default(),
+            cache: None,
+            options_overrides: Default::default(),
+            source_metadata: Default::default(),
+            limits: Default::default(),
+            interned_heap_size: 0,
+            largest_interned_terms: Default::default(),
             heap,
         }
     }
+    /// Initialize the compiler state from a versioned heap snapshot (see [`encode_heap_snapshot`])
+    ///
+    /// Snapshots produced by older format versions are passed to the provided migration (see
+    /// [`decode_heap_snapshot_with_migration`]), and snapshots that are incompatible with the current runtime are
+    /// rejected with an error.
+    ///
+    /// [`encode_heap_snapshot`]: crate::heap_snapshot::encode_heap_snapshot
+    pub fn from_encoded_heap_snapshot<T: TermSize>(
+        bytes: &[u8],
+        migrate: impl FnOnce(u32, Vec<u8>) -> Result<Vec<u8>, HeapSnapshotError>,
+    ) -> Result<Self, HeapSnapshotError>
+    where
+        for<'a> ArenaRef<T, &'a VecAllocator>: NodeId,
+    {
+        decode_heap_snapshot_with_migration(bytes, migrate)
+            .map(|heap| Self::from_heap_snapshot::<T>(&heap))
+    }
     pub fn from_arena<A: Arena + Visitable<ArenaPointer> + Clone>(arena: &A) -> Self {
         Self::from_heap_values(
             Visitable::<ArenaPointer>::children(arena)
                 .map(|pointer| ArenaRef::<Term, _>::new(arena.clone(), pointer)),
         )
     }
+    /// Override the compiler options for the subtrees rooted at the terms with the given hashes
+    ///
+    /// Overrides must be provided before loading a compiler cache, to ensure that cached functions are only reused when
+    /// compiled with the same overrides.
+    pub fn with_options_overrides(
+        self,
+        overrides: impl IntoIterator<Item = (TermHashState, CompilerOptions)>,
+    ) -> Self {
+        Self {
+            options_overrides: overrides.into_iter().collect(),
+            ..self
+        }
+    }
+    /// Annotate any compiler errors with the source metadata of the enclosing user-level terms
+    ///
+    /// Source metadata entries are keyed by the hashes of the corresponding arena terms.
+    pub fn with_source_metadata(self, source_metadata: SourceMetadataTable) -> Self {
+        Self {
+            source_metadata,
+            ..self
+        }
+    }
+    /// Fail compilation if the static values interned into the heap snapshot exceed the given limits
+    pub fn with_limits(self, limits: CompilerLimits) -> Self {
+        Self { limits, ..self }
+    }
+    /// Record the given term as having been interned into the heap snapshot, enforcing any configured limits
+    fn track_interned_term<A: Arena + Clone>(
+        &mut self,
+        term: &ArenaRef<Term, A>,
+        size: usize,
+    ) -> Result<(), CompilerError<A>> {
+        if !self.limits.is_enabled() || size == 0 {
+            return Ok(());
+        }
+        self.interned_heap_size += size;
+        let id = term.read_value(|term| term.id());
+        let summary = InternedTermSummary {
+            id,
+            term_type: term.read_value(|term| term.type_id()),
+            size,
+            source: self.source_metadata.get(id).cloned(),
+        };
+        let index = self
+            .largest_interned_terms
+            .partition_point(|existing| existing.size >= size);
+        if index < MAX_REPORTED_INTERNED_TERMS {
+            self.largest_interned_terms.insert(index, summary);
+            self.largest_interned_terms
+                .truncate(MAX_REPORTED_INTERNED_TERMS);
+        }
+        let exceeded_limit = match self.limits {
+            CompilerLimits {
+                max_interned_term_size: Some(limit),
+                ..
+            } if size > limit => Some((InternedHeapLimitKind::TermSize, limit, size)),
+            CompilerLimits {
+                max_interned_heap_size: Some(limit),
+                ..
+            } if self.interned_heap_size > limit => Some((
+                InternedHeapLimitKind::HeapSize,
+                limit,
+                self.interned_heap_size,
+            )),
+            _ => None,
+        };
+        match exceeded_limit {
+            None => Ok(()),
+            Some((kind, limit, size)) => Err(CompilerError::InternedHeapLimitExceeded(
+                InternedHeapLimitError {
+                    kind,
+                    limit,
+                    size,
+                    largest_terms: self.largest_interned_terms.clone(),
+                },
+            )),
+        }
+    }
     fn from_heap_values<T: Serialize>(values: impl IntoIterator<Item = T>) -> Self {
         let mut destination_arena = VecAllocator::default();
         let next_offset = destination_arena.end_offset();
@@ -577,9 +733,65 @@ impl CompilerState {
             serializer_state,
             compiled_lambdas: Default::default(),
             compiled_thunks: Default::default(),
+            cache: None,
+            options_overrides: Default::default(),
+            source_metadata: Default::default(),
+            limits: Default::default(),
+            interned_heap_size: 0,
+            largest_interned_terms: Default::default(),
             heap: destination_arena,
         }
     }
+    /// Allocate a placeholder builtin term to represent a compiled thunk function
+    ///
+    /// The compiled function ID will be filled in with the actual value by the linker, so the placeholder term is
+    /// deliberately not interned (each compiled thunk must be assigned its own distinct placeholder term).
+    pub(crate) fn allocate_compiled_function_placeholder(&mut self) -> ArenaPointer {
+        self.heap.allocate(Term::new(
+            TermType::Builtin(BuiltinTerm {
+                uid: u32::from(ArenaPointer::null()),
+            }),
+            &self.heap,
+        ))
+    }
+    /// Allocate an application term that invokes the given compiled thunk function with no arguments
+    pub(crate) fn allocate_thunk_application_term(
+        &mut self,
+        compiled_function_term: ArenaPointer,
+    ) -> ArenaPointer {
+        let empty_list = {
+            let empty_list_term = Term::new(
+                TermType::List(ListTerm {
+                    items: Array {
+                        capacity: 0,
+                        length: 0,
+                        items: [],
+                    },
+                }),
+                &self.heap,
+            );
+            match self
+                .serializer_state
+                .allocated_terms
+                .entry(empty_list_term.id())
+            {
+                Entry::Occupied(entry) => *(entry.get()),
+                Entry::Vacant(entry) => {
+                    let heap_pointer = self.heap.allocate(empty_list_term);
+                    entry.insert(heap_pointer);
+                    self.serializer_state.next_offset = self.heap.end_offset();
+                    heap_pointer
+                }
+            }
+        };
+        self.heap.allocate(Term::new(
+            TermType::Application(ApplicationTerm {
+                target: compiled_function_term,
+                args: empty_list,
+            }),
+            &self.heap,
+        ))
+    }
     pub fn into_parts(
         self,
     ) -> (
@@ -611,6 +823,8 @@ pub struct CompilerStack {
     operands: Stack<ValueType>,
     blocks: Stack<ParamsSignature>,
     bindings: CompilerVariableBindings,
+    /// Trail of terms currently being compiled, used to annotate type errors
+    trace: Stack<CompilerTraceFrame>,
 }
 
 impl CompilerStack {
@@ -621,6 +835,7 @@ impl CompilerStack {
             operands: Default::default(),
             blocks: Default::default(),
             bindings: CompilerVariableBindings::from_free_variables(scope_offsets),
+            trace: Default::default(),
         }
     }
     pub fn push_operand(&self, value_type: ValueType) -> Self {
@@ -641,6 +856,7 @@ impl CompilerStack {
             operands: self.operands.append(value_types.iter()),
             blocks: self.blocks.clone(),
             bindings: self.bindings.clone(),
+            trace: self.trace.clone(),
         }
     }
     pub fn pop_operands(&self, value_types: &ParamsSignature) -> Result<Self, TypedStackError> {
@@ -687,6 +903,7 @@ impl CompilerStack {
             operands: updated_operands,
             blocks: self.blocks.clone(),
             bindings: self.bindings.clone(),
+            trace: self.trace.clone(),
         })
     }
     pub fn num_operands(&self) -> usize {
@@ -700,6 +917,7 @@ impl CompilerStack {
             operands: self.operands.clone(),
             blocks: self.blocks.clone(),
             bindings: self.bindings.push_internal(value_type),
+            trace: self.trace.clone(),
         }
     }
     pub fn declare_variable(&self, value_type: ValueType) -> Self {
@@ -707,6 +925,7 @@ impl CompilerStack {
             operands: self.operands.clone(),
             blocks: self.blocks.clone(),
             bindings: self.bindings.push_variable(value_type),
+            trace: self.trace.clone(),
         }
     }
     pub fn lookup_variable(&self, scope_offset: StackOffset) -> Option<usize> {
@@ -737,6 +956,7 @@ impl CompilerStack {
             operands: self.operands.clone(),
             blocks: self.blocks.clone(),
             bindings: updated_bindings,
+            trace: self.trace.clone(),
         })
     }
     pub fn assert_lexical_scope(
@@ -775,6 +995,7 @@ impl CompilerStack {
             operands: inner_stack,
             blocks: updated_blocks,
             bindings: self.bindings.clone(),
+            trace: self.trace.clone(),
         })
     }
     pub fn leave_block(&self, result_type: &ParamsSignature) -> Result<Self, TypedStackError> {
@@ -800,6 +1021,7 @@ impl CompilerStack {
             operands: self.operands.clone(),
             blocks: updated_blocks,
             bindings: self.bindings.clone(),
+            trace: self.trace.clone(),
         })
     }
     pub fn active_block(&self) -> Option<&ParamsSignature> {
@@ -808,6 +1030,28 @@ impl CompilerStack {
     pub fn blocks(&self) -> impl Iterator<Item = &'_ ParamsSignature> + '_ {
         self.blocks.rev().collect::<Vec<_>>().into_iter()
     }
+    /// Record that compilation has entered the given term
+    pub fn enter_term(&self, frame: CompilerTraceFrame) -> Self {
+        Self {
+            operands: self.operands.clone(),
+            blocks: self.blocks.clone(),
+            bindings: self.bindings.clone(),
+            trace: self.trace.push(frame),
+        }
+    }
+    /// Inherit the trail of terms being compiled from the given enclosing stack
+    ///
+    /// This allows errors within separately-compiled function bodies to report the terms that enclose the function.
+    pub fn with_trace(self, parent: &CompilerStack) -> Self {
+        Self {
+            trace: parent.trace.clone(),
+            ..self
+        }
+    }
+    /// Trail of terms currently being compiled, ordered from innermost to outermost
+    pub fn trace(&self) -> impl Iterator<Item = &'_ CompilerTraceFrame> + '_ {
+        self.trace.rev()
+    }
 }
 
 #[derive(Default, Debug)]
@@ -1254,8 +1498,11 @@ pub(crate) fn intern_static_value<A: Arena + Clone>(
     state: &mut CompilerState,
 ) -> CompilerResult<A> {
     // TODO: Avoid need to manually track serializer state heap allocator offset during compiler static interning
-    state.serializer_state.next_offset = state.heap.end_offset();
+    let start_offset = state.heap.end_offset();
+    state.serializer_state.next_offset = start_offset;
     let heap_pointer = Serialize::serialize(value, &mut state.heap, &mut state.serializer_state);
+    let size = u32::from(state.heap.end_offset()) - u32::from(start_offset);
+    state.track_interned_term(value, size as usize)?;
     let block = CompiledBlockBuilder::new(CompilerStack::default());
     let block = block.push(instruction::core::Const {
         value: ConstValue::HeapPointer(heap_pointer),
@@ -1454,6 +1701,8 @@ impl<A: Arena + Clone> CompileWasm<A> for LazyExpression<A> {
         } else {
             // Compile the thunk
             let thunk_id = self.inner.read_value(|term| term.header.hash);
+            // Reuse the cached copy of the thunk if it was compiled by a previous compiler invocation
+            state.restore_cached_thunk(thunk_id);
             let compiled_thunk = match state.compiled_thunks.get(&thunk_id) {
                 // If the provided thunk has already been compiled, return the existing copy
                 Some(compiled_thunk) => compiled_thunk,
@@ -1476,61 +1725,21 @@ impl<A: Arena + Clone> CompileWasm<A> for LazyExpression<A> {
                         ),
                         None => CompilerStack::default(),
                     }
+                    .with_trace(&stack)
                     .enter_block(&TypeSignature {
                         params: ParamsSignature::Void,
                         results: ParamsSignature::Single(ValueType::HeapPointer),
                     })
                     .map_err(CompilerError::StackError)?;
                     let thunk_function_body = self.inner.compile(inner_stack, state, options)?;
-                    // Create a placeholder builtin term to represent the compiled function
-                    let compiled_function_term = state.heap.allocate(Term::new(
-                        TermType::Builtin(BuiltinTerm {
-                            // The compiled function ID will be filled in with the actual value by the linker
-                            uid: u32::from(ArenaPointer::null()),
-                        }),
-                        &state.heap,
-                    ));
+                    let compiled_function_term = state.allocate_compiled_function_placeholder();
                     let thunk = match free_variables {
-                        None => {
-                            let empty_list = {
-                                let empty_list_term = Term::new(
-                                    TermType::List(ListTerm {
-                                        items: Array {
-                                            capacity: 0,
-                                            length: 0,
-                                            items: [],
-                                        },
-                                    }),
-                                    &state.heap,
-                                );
-                                match state
-                                    .serializer_state
-                                    .allocated_terms
-                                    .entry(empty_list_term.id())
-                                {
-                                    Entry::Occupied(entry) => *(entry.get()),
-                                    Entry::Vacant(entry) => {
-                                        let heap_pointer = state.heap.allocate(empty_list_term);
-                                        entry.insert(heap_pointer);
-                                        state.serializer_state.next_offset =
-                                            state.heap.end_offset();
-                                        heap_pointer
-                                    }
-                                }
-                            };
-                            let application_pointer = state.heap.allocate(Term::new(
-                                TermType::Application(ApplicationTerm {
-                                    target: compiled_function_term,
-                                    args: empty_list,
-                                }),
-                                &state.heap,
-                            ));
-                            CompiledThunk::Pure(PureThunk {
-                                application_term: application_pointer,
-                                thunk_function_body,
-                                compiled_function_term,
-                            })
-                        }
+                        None => CompiledThunk::Pure(PureThunk {
+                            application_term: state
+                                .allocate_thunk_application_term(compiled_function_term),
+                            thunk_function_body,
+                            compiled_function_term,
+                        }),
                         Some(bindings) => CompiledThunk::Capturing(CapturingThunk {
                             free_variables: bindings.into_iter().collect(),
                             thunk_function_body,
@@ -1676,13 +1885,22 @@ impl<A: Arena, T: CompileWasm<A>> CompileWasm<A> for BlockWrappedExpression<A, T
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub enum CompiledThunk {
     Pure(PureThunk),
     Capturing(CapturingThunk),
 }
 
-#[derive(Clone, Debug)]
+impl CompiledThunk {
+    pub fn body(&self) -> &CompiledBlock {
+        match self {
+            Self::Pure(thunk) => &thunk.thunk_function_body,
+            Self::Capturing(thunk) => &thunk.thunk_function_body,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct PureThunk {
     /// Bytecode instructions for the compiled thunk
     pub thunk_function_body: CompiledBlock,
@@ -1696,7 +1914,7 @@ pub struct PureThunk {
     pub compiled_function_term: ArenaPointer,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct CapturingThunk {
     /// List of variable scope offsets of any free variables referenced within the thunk
     pub free_variables: Vec<StackOffset>,
@@ -1745,185 +1963,254 @@ impl<A: Arena + Clone> CompileWasm<A> for ArenaRef<Term, A> {
         state: &mut CompilerState,
         options: &CompilerOptions,
     ) -> CompilerResult<A> {
-        if self.should_intern(ArgType::Strict) {
-            let compiled_heap_pointer = intern_static_value(self, state)?;
-            let result = if self.is_static() {
-                Ok(compiled_heap_pointer)
-            } else {
-                let block = CompiledBlockBuilder::new(CompilerStack::default());
-                let block = block.append_block(compiled_heap_pointer);
-                let block = block.push(CompiledInstruction::Evaluate(
-                    instruction::runtime::Evaluate,
-                ));
-                block.finish()
-            };
-            return result;
-        }
-        match self.read_value(|term| term.type_id()) {
-            TermTypeDiscriminants::Application => self
-                .as_typed_term::<ApplicationTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Boolean => self
-                .as_typed_term::<BooleanTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Builtin => self
-                .as_typed_term::<BuiltinTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Cell => self
-                .as_typed_term::<CellTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Condition => self
-                .as_typed_term::<ConditionTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Constructor => self
-                .as_typed_term::<ConstructorTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Effect => self
-                .as_typed_term::<EffectTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Float => self
-                .as_typed_term::<FloatTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Hashmap => self
-                .as_typed_term::<HashmapTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Hashset => self
-                .as_typed_term::<HashsetTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Int => self
-                .as_typed_term::<IntTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Lambda => self
-                .as_typed_term::<LambdaTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::LazyResult => self
-                .as_typed_term::<LazyResultTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Let => self
-                .as_typed_term::<LetTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::List => self
-                .as_typed_term::<ListTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Nil => self
-                .as_typed_term::<NilTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Partial => self
-                .as_typed_term::<PartialTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Pointer => self
-                .as_typed_term::<PointerTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Record => self
-                .as_typed_term::<RecordTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Signal => self
-                .as_typed_term::<SignalTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::String => self
-                .as_typed_term::<StringTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Symbol => self
-                .as_typed_term::<SymbolTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Timestamp => self
-                .as_typed_term::<TimestampTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Tree => self
-                .as_typed_term::<TreeTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::Variable => self
-                .as_typed_term::<VariableTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::EmptyIterator => self
-                .as_typed_term::<EmptyIteratorTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::EvaluateIterator => self
-                .as_typed_term::<EvaluateIteratorTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::FilterIterator => self
-                .as_typed_term::<FilterIteratorTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::FlattenIterator => self
-                .as_typed_term::<FlattenIteratorTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::HashmapKeysIterator => self
-                .as_typed_term::<HashmapKeysIteratorTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::HashmapValuesIterator => self
-                .as_typed_term::<HashmapValuesIteratorTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::IndexedAccessorIterator => self
-                .as_typed_term::<IndexedAccessorIteratorTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::IntegersIterator => self
-                .as_typed_term::<IntegersIteratorTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::IntersperseIterator => self
-                .as_typed_term::<IntersperseIteratorTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::MapIterator => self
-                .as_typed_term::<MapIteratorTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::OnceIterator => self
-                .as_typed_term::<OnceIteratorTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::RangeIterator => self
-                .as_typed_term::<RangeIteratorTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::RepeatIterator => self
-                .as_typed_term::<RepeatIteratorTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::SkipIterator => self
-                .as_typed_term::<SkipIteratorTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::TakeIterator => self
-                .as_typed_term::<TakeIteratorTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-            TermTypeDiscriminants::ZipIterator => self
-                .as_typed_term::<ZipIteratorTerm>()
-                .as_inner()
-                .compile(stack, state, options),
-        }
+        // Compile this subtree with any overridden compiler options (nested subtrees inherit the overridden options)
+        let overridden_options = if state.options_overrides.is_empty() {
+            None
+        } else {
+            let hash = self.read_value(|term| term.header.hash);
+            state.options_overrides.get(&hash).copied()
+        };
+        let options = overridden_options.as_ref().unwrap_or(options);
+        // Record this term in the trail of terms being compiled, allowing type errors to report their enclosing terms
+        let stack = stack.enter_term(CompilerTraceFrame {
+            term_type: self.read_value(|term| term.type_id()),
+            source: state
+                .source_metadata
+                .get(self.read_value(|term| term.id()))
+                .cloned(),
+        });
+        // Annotate any compiler errors with the source provenance of the innermost user-level term
+        compile_term(self, stack, state, options).map_err(|err| {
+            err.with_source(|| {
+                state
+                    .source_metadata
+                    .get(self.read_value(|term| term.id()))
+                    .cloned()
+            })
+        })
+    }
+}
+
+fn compile_term<A: Arena + Clone>(
+    term: &ArenaRef<Term, A>,
+    stack: CompilerStack,
+    state: &mut CompilerState,
+    options: &CompilerOptions,
+) -> CompilerResult<A> {
+    if term.should_intern(ArgType::Strict) {
+        let compiled_heap_pointer = intern_static_value(term, state)?;
+        let result = if term.is_static() {
+            Ok(compiled_heap_pointer)
+        } else {
+            let block = CompiledBlockBuilder::new(CompilerStack::default());
+            let block = block.append_block(compiled_heap_pointer);
+            let block = block.push(CompiledInstruction::Evaluate(
+                instruction::runtime::Evaluate,
+            ));
+            block.finish()
+        };
+        return result;
+    }
+    match term.read_value(|term| term.type_id()) {
+        TermTypeDiscriminants::Application => term
+            .as_typed_term::<ApplicationTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::BigInt => term
+            .as_typed_term::<BigIntTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Boolean => term
+            .as_typed_term::<BooleanTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Builtin => term
+            .as_typed_term::<BuiltinTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Bytes => term
+            .as_typed_term::<BytesTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Cell => term
+            .as_typed_term::<CellTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Condition => term
+            .as_typed_term::<ConditionTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Constructor => term
+            .as_typed_term::<ConstructorTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Duration => term
+            .as_typed_term::<DurationTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Effect => term
+            .as_typed_term::<EffectTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Float => term
+            .as_typed_term::<FloatTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Hashmap => term
+            .as_typed_term::<HashmapTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Hashset => term
+            .as_typed_term::<HashsetTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Int => term
+            .as_typed_term::<IntTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Lambda => term
+            .as_typed_term::<LambdaTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::LazyResult => term
+            .as_typed_term::<LazyResultTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Let => term
+            .as_typed_term::<LetTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::List => term
+            .as_typed_term::<ListTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Nil => term
+            .as_typed_term::<NilTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Partial => term
+            .as_typed_term::<PartialTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Pointer => term
+            .as_typed_term::<PointerTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Record => term
+            .as_typed_term::<RecordTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Recursive => term
+            .as_typed_term::<RecursiveTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Regex => term
+            .as_typed_term::<RegexTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Signal => term
+            .as_typed_term::<SignalTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::String => term
+            .as_typed_term::<StringTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Symbol => term
+            .as_typed_term::<SymbolTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Timestamp => term
+            .as_typed_term::<TimestampTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Tree => term
+            .as_typed_term::<TreeTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Variable => term
+            .as_typed_term::<VariableTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::DedupeIterator => term
+            .as_typed_term::<DedupeIteratorTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::EmptyIterator => term
+            .as_typed_term::<EmptyIteratorTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::EvaluateIterator => term
+            .as_typed_term::<EvaluateIteratorTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::FilterIterator => term
+            .as_typed_term::<FilterIteratorTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::FlattenIterator => term
+            .as_typed_term::<FlattenIteratorTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::HashmapKeysIterator => term
+            .as_typed_term::<HashmapKeysIteratorTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::HashmapValuesIterator => term
+            .as_typed_term::<HashmapValuesIteratorTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::IndexedAccessorIterator => term
+            .as_typed_term::<IndexedAccessorIteratorTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::IntegersIterator => term
+            .as_typed_term::<IntegersIteratorTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::IntersperseIterator => term
+            .as_typed_term::<IntersperseIteratorTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::MapIterator => term
+            .as_typed_term::<MapIteratorTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::OnceIterator => term
+            .as_typed_term::<OnceIteratorTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::RangeIterator => term
+            .as_typed_term::<RangeIteratorTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::RepeatIterator => term
+            .as_typed_term::<RepeatIteratorTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::SkipIterator => term
+            .as_typed_term::<SkipIteratorTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::TakeIterator => term
+            .as_typed_term::<TakeIteratorTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::ZipIterator => term
+            .as_typed_term::<ZipIteratorTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::ListView => term
+            .as_typed_term::<ListViewTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::ListRope => term
+            .as_typed_term::<ListRopeTerm>()
+            .as_inner()
+            .compile(stack, state, options),
+        TermTypeDiscriminants::Sensitive => term
+            .as_typed_term::<SensitiveTerm>()
+            .as_inner()
+            .compile(stack, state, options),
     }
 }
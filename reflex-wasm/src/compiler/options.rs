@@ -0,0 +1,468 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    collections::HashMap,
+    path::{Component, Path, PathBuf},
+    str::FromStr,
+};
+
+use reflex::core::ArgType;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{compiler::CompilerOptions, hash::TermHashState};
+
+#[derive(Debug)]
+pub enum CompilerOptionsError {
+    /// Options could not be parsed from the provided input
+    InvalidSyntax(String),
+    /// Options were parsed successfully but contain an unknown field or an invalid field value
+    InvalidValue(serde_json::Error),
+    /// Options contain an invalid combination of values
+    InvalidCombination(String),
+}
+
+impl std::error::Error for CompilerOptionsError {}
+
+impl std::fmt::Display for CompilerOptionsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidSyntax(message) => write!(f, "Invalid compiler options: {}", message),
+            Self::InvalidValue(err) => write!(f, "Invalid compiler options: {}", err),
+            Self::InvalidCombination(message) => {
+                write!(f, "Invalid compiler options combination: {}", message)
+            }
+        }
+    }
+}
+
+/// Serialized representation of [`CompilerOptions`], where any omitted fields fall back to the default options
+#[derive(Default, Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct CompilerOptionsConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lazy_record_values: Option<ArgTypeConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lazy_list_items: Option<ArgTypeConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lazy_variable_initializers: Option<ArgTypeConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lazy_function_args: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lazy_lambda_args: Option<ArgTypeConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lazy_constructors: Option<ArgTypeConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    constant_folding: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inline_lambda_threshold: Option<usize>,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ArgTypeConfig {
+    Strict,
+    Eager,
+    Lazy,
+}
+
+impl From<ArgTypeConfig> for ArgType {
+    fn from(value: ArgTypeConfig) -> Self {
+        match value {
+            ArgTypeConfig::Strict => ArgType::Strict,
+            ArgTypeConfig::Eager => ArgType::Eager,
+            ArgTypeConfig::Lazy => ArgType::Lazy,
+        }
+    }
+}
+
+impl From<ArgType> for ArgTypeConfig {
+    fn from(value: ArgType) -> Self {
+        match value {
+            ArgType::Strict => ArgTypeConfig::Strict,
+            ArgType::Eager => ArgTypeConfig::Eager,
+            ArgType::Lazy => ArgTypeConfig::Lazy,
+        }
+    }
+}
+
+impl From<CompilerOptionsConfig> for CompilerOptions {
+    fn from(value: CompilerOptionsConfig) -> Self {
+        value.apply(&CompilerOptions::default())
+    }
+}
+
+impl CompilerOptionsConfig {
+    /// Combine the provided options with the fields specified in this config, which take precedence
+    fn apply(&self, options: &CompilerOptions) -> CompilerOptions {
+        let Self {
+            lazy_record_values,
+            lazy_list_items,
+            lazy_variable_initializers,
+            lazy_function_args,
+            lazy_lambda_args,
+            lazy_constructors,
+            constant_folding,
+            inline_lambda_threshold,
+        } = *self;
+        CompilerOptions {
+            lazy_record_values: lazy_record_values
+                .map(ArgType::from)
+                .unwrap_or(options.lazy_record_values),
+            lazy_list_items: lazy_list_items
+                .map(ArgType::from)
+                .unwrap_or(options.lazy_list_items),
+            lazy_variable_initializers: lazy_variable_initializers
+                .map(ArgType::from)
+                .unwrap_or(options.lazy_variable_initializers),
+            lazy_function_args: lazy_function_args.unwrap_or(options.lazy_function_args),
+            lazy_lambda_args: lazy_lambda_args
+                .map(ArgType::from)
+                .unwrap_or(options.lazy_lambda_args),
+            lazy_constructors: lazy_constructors
+                .map(ArgType::from)
+                .unwrap_or(options.lazy_constructors),
+            constant_folding: constant_folding.unwrap_or(options.constant_folding),
+            inline_lambda_threshold: inline_lambda_threshold
+                .unwrap_or(options.inline_lambda_threshold),
+        }
+    }
+    /// Parse a config from either a JSON object or a comma-separated list of `key=value` pairs
+    fn parse(input: &str) -> Result<Self, CompilerOptionsError> {
+        let input = input.trim();
+        let value = if input.starts_with('{') {
+            serde_json::from_str(input)
+                .map_err(|err| CompilerOptionsError::InvalidSyntax(format!("{}", err)))?
+        } else {
+            let fields = input
+                .split(',')
+                .map(|field| field.trim())
+                .filter(|field| !field.is_empty())
+                .map(|field| {
+                    let (key, value) = field.split_once('=').ok_or_else(|| {
+                        CompilerOptionsError::InvalidSyntax(format!(
+                            "Expected key=value pair, received \"{}\"",
+                            field
+                        ))
+                    })?;
+                    // Keys can be provided in kebab-case to match the CLI argument naming convention
+                    let key = key.trim().replace('-', "_");
+                    let value = value.trim();
+                    // Boolean and numeric values are parsed as JSON literals, falling back to string values
+                    let value = serde_json::from_str::<serde_json::Value>(value)
+                        .ok()
+                        .filter(|value| value.is_boolean() || value.is_number())
+                        .unwrap_or_else(|| serde_json::Value::String(String::from(value)));
+                    Ok((key, value))
+                })
+                .collect::<Result<serde_json::Map<_, _>, _>>()?;
+            serde_json::Value::Object(fields)
+        };
+        serde_json::from_value(value).map_err(CompilerOptionsError::InvalidValue)
+    }
+}
+
+impl From<&CompilerOptions> for CompilerOptionsConfig {
+    fn from(value: &CompilerOptions) -> Self {
+        Self {
+            lazy_record_values: Some(value.lazy_record_values.into()),
+            lazy_list_items: Some(value.lazy_list_items.into()),
+            lazy_variable_initializers: Some(value.lazy_variable_initializers.into()),
+            lazy_function_args: Some(value.lazy_function_args),
+            lazy_lambda_args: Some(value.lazy_lambda_args.into()),
+            lazy_constructors: Some(value.lazy_constructors.into()),
+            constant_folding: Some(value.constant_folding),
+            inline_lambda_threshold: Some(value.inline_lambda_threshold),
+        }
+    }
+}
+
+impl CompilerOptions {
+    /// Parse compiler options from a JSON object, where any omitted fields fall back to the default options
+    ///
+    /// Eagerness fields accept one of `"strict"`, `"eager"` or `"lazy"`. The parsed options are validated to ensure that
+    /// they do not contain any invalid combinations of values.
+    pub fn from_json(value: serde_json::Value) -> Result<Self, CompilerOptionsError> {
+        serde_json::from_value::<CompilerOptionsConfig>(value)
+            .map_err(CompilerOptionsError::InvalidValue)
+            .map(CompilerOptions::from)
+            .and_then(|options| options.validate())
+    }
+    /// Serialize the compiler options into a JSON object that can be parsed via [`CompilerOptions::from_json`]
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(CompilerOptionsConfig::from(self)).unwrap_or_default()
+    }
+    /// Ensure that the compiler options do not contain any invalid combinations of values
+    pub fn validate(self) -> Result<Self, CompilerOptionsError> {
+        // Lambda call sites 'upgrade' lazy lambda arguments to be evaluated eagerly unless function arguments are also
+        // lazy, which would silently negate the lazy lambda arguments option
+        if self.lazy_lambda_args.is_lazy() && !self.lazy_function_args {
+            return Err(CompilerOptionsError::InvalidCombination(String::from(
+                "lazy_lambda_args=lazy requires lazy_function_args=true",
+            )));
+        }
+        Ok(self)
+    }
+}
+
+impl Serialize for CompilerOptions {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CompilerOptionsConfig::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CompilerOptions {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        CompilerOptionsConfig::deserialize(deserializer)
+            .map(CompilerOptions::from)
+            .and_then(|options| options.validate().map_err(serde::de::Error::custom))
+    }
+}
+
+impl FromStr for CompilerOptions {
+    type Err = CompilerOptionsError;
+    /// Parse compiler options from either a JSON object or a comma-separated list of `key=value` pairs
+    /// (e.g. `lazy_list_items=lazy,constant_folding=false`)
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        CompilerOptionsConfig::parse(input)
+            .map(CompilerOptions::from)
+            .and_then(|options| options.validate())
+    }
+}
+
+/// Partial set of compiler options, where any omitted fields are inherited from the options being overridden
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CompilerOptionsOverride(CompilerOptionsConfig);
+
+impl CompilerOptionsOverride {
+    /// Combine the provided options with the fields specified in this override, validating the resulting options
+    pub fn apply(
+        &self,
+        options: &CompilerOptions,
+    ) -> Result<CompilerOptions, CompilerOptionsError> {
+        let Self(config) = self;
+        config.apply(options).validate()
+    }
+}
+
+impl FromStr for CompilerOptionsOverride {
+    type Err = CompilerOptionsError;
+    /// Parse an override using the same syntax as [`CompilerOptions`], with any omitted fields left unchanged
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        CompilerOptionsConfig::parse(input).map(Self)
+    }
+}
+
+/// Compiler options override that applies to all the functions declared within a given source module or directory
+#[derive(Clone, Debug)]
+pub struct ModuleCompilerOptions {
+    /// Path of the source module, or of a directory containing source modules
+    pub path: PathBuf,
+    pub options: CompilerOptionsOverride,
+}
+
+impl ModuleCompilerOptions {
+    /// Determine whether the given source module path is covered by this override
+    ///
+    /// Paths are compared component-wise as provided to the module loader, ignoring any `.` components.
+    pub fn matches(&self, path: &Path) -> bool {
+        let mut prefix = normalize_path(&self.path);
+        let mut components = normalize_path(path);
+        loop {
+            match prefix.next() {
+                None => break true,
+                Some(expected) => {
+                    if components.next() != Some(expected) {
+                        break false;
+                    }
+                }
+            }
+        }
+    }
+    /// Number of path components, used to prioritize more specific overrides
+    fn specificity(&self) -> usize {
+        normalize_path(&self.path).count()
+    }
+}
+
+impl FromStr for ModuleCompilerOptions {
+    type Err = CompilerOptionsError;
+    /// Parse an override in the format `<path>=<options>` (e.g. `lib/vendor=lazy_list_items=lazy`)
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.split_once('=') {
+            Some((path, options)) if !path.trim().is_empty() => Ok(Self {
+                path: PathBuf::from(path.trim()),
+                options: CompilerOptionsOverride::from_str(options)?,
+            }),
+            _ => Err(CompilerOptionsError::InvalidSyntax(format!(
+                "Expected <path>=<options>, received \"{}\"",
+                input
+            ))),
+        }
+    }
+}
+
+fn normalize_path(path: &Path) -> impl Iterator<Item = Component<'_>> {
+    path.components()
+        .filter(|component| !matches!(component, Component::CurDir))
+}
+
+/// Compiler options overrides that take precedence over the base compiler options for specific parts of a program
+///
+/// Overrides apply to the whole subtree beneath the overridden term, except where a nested term has its own override.
+#[derive(Default, Clone, Debug)]
+pub struct CompilerOptionsOverrides {
+    /// Overrides for any functions declared within the given source modules (this requires source metadata to be
+    /// recorded when parsing the program, and applies only to functions that survive any compiler transformations
+    /// unchanged)
+    pub modules: Vec<ModuleCompilerOptions>,
+    /// Overrides for the subtrees rooted at the arena terms with the given hashes
+    pub terms: HashMap<TermHashState, CompilerOptions>,
+}
+
+impl CompilerOptionsOverrides {
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty() && self.terms.is_empty()
+    }
+    /// Resolve the options for the given source module path, where the most specific matching module override takes
+    /// precedence over any less specific overrides, and later overrides take precedence over earlier overrides for the
+    /// same path (returns `None` if there are no overrides for the given path)
+    pub fn resolve_module(
+        &self,
+        path: &Path,
+        options: &CompilerOptions,
+    ) -> Option<Result<CompilerOptions, CompilerOptionsError>> {
+        self.modules
+            .iter()
+            .filter(|module| module.matches(path))
+            .max_by_key(|module| module.specificity())
+            .map(|module| module.options.apply(options))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_compiler_options() {
+        let options = CompilerOptions::from_str("{}").unwrap();
+        assert_eq!(options.to_json(), CompilerOptions::default().to_json());
+
+        let options = CompilerOptions::from_str(
+            r#"{ "lazy_list_items": "lazy", "constant_folding": false, "inline_lambda_threshold": 4 }"#,
+        )
+        .unwrap();
+        assert_eq!(options.lazy_list_items, ArgType::Lazy);
+        assert!(!options.constant_folding);
+        assert_eq!(options.inline_lambda_threshold, 4);
+        assert_eq!(
+            options.lazy_record_values,
+            CompilerOptions::default().lazy_record_values
+        );
+
+        let options = CompilerOptions::from_str(
+            "lazy-lambda-args=lazy, lazy_function_args=true,inline_lambda_threshold=0",
+        )
+        .unwrap();
+        assert_eq!(options.lazy_lambda_args, ArgType::Lazy);
+        assert!(options.lazy_function_args);
+        assert_eq!(options.inline_lambda_threshold, 0);
+
+        let options = CompilerOptions::from_str("").unwrap();
+        assert_eq!(options.to_json(), CompilerOptions::default().to_json());
+
+        let options = CompilerOptions::from_json(options.to_json()).unwrap();
+        assert_eq!(options.to_json(), CompilerOptions::default().to_json());
+    }
+
+    #[test]
+    fn invalid_compiler_options() {
+        assert!(matches!(
+            CompilerOptions::from_str("lazy_list_items"),
+            Err(CompilerOptionsError::InvalidSyntax(_))
+        ));
+        assert!(matches!(
+            CompilerOptions::from_str("{ lazy_list_items }"),
+            Err(CompilerOptionsError::InvalidSyntax(_))
+        ));
+        let err = CompilerOptions::from_str("lazy_list_items=sometimes").unwrap_err();
+        assert!(matches!(err, CompilerOptionsError::InvalidValue(_)));
+        assert!(format!("{}", err).contains("expected one of `strict`, `eager`, `lazy`"));
+        let err = CompilerOptions::from_str("lazy_items=lazy").unwrap_err();
+        assert!(matches!(err, CompilerOptionsError::InvalidValue(_)));
+        assert!(format!("{}", err).contains("unknown field `lazy_items`"));
+        assert!(matches!(
+            CompilerOptions::from_str("constant_folding=yes"),
+            Err(CompilerOptionsError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            CompilerOptions::from_str("lazy_lambda_args=lazy"),
+            Err(CompilerOptionsError::InvalidCombination(_))
+        ));
+    }
+
+    #[test]
+    fn compiler_options_overrides() {
+        let base = CompilerOptions {
+            constant_folding: false,
+            ..Default::default()
+        };
+        let options = CompilerOptionsOverride::from_str("lazy_list_items=lazy")
+            .unwrap()
+            .apply(&base)
+            .unwrap();
+        assert_eq!(options.lazy_list_items, ArgType::Lazy);
+        assert!(!options.constant_folding);
+        let options = CompilerOptionsOverride::from_str("")
+            .unwrap()
+            .apply(&base)
+            .unwrap();
+        assert_eq!(options.to_json(), base.to_json());
+        assert!(matches!(
+            CompilerOptionsOverride::from_str("lazy_lambda_args=lazy")
+                .unwrap()
+                .apply(&base),
+            Err(CompilerOptionsError::InvalidCombination(_))
+        ));
+    }
+
+    #[test]
+    fn module_compiler_options() {
+        let overrides = CompilerOptionsOverrides {
+            modules: vec![
+                ModuleCompilerOptions::from_str("./lib=lazy_list_items=lazy").unwrap(),
+                ModuleCompilerOptions::from_str("lib/vendor=lazy_record_values=lazy").unwrap(),
+            ],
+            ..Default::default()
+        };
+        let base = CompilerOptions::default();
+        let options = overrides
+            .resolve_module(Path::new("lib/index.js"), &base)
+            .unwrap()
+            .unwrap();
+        assert_eq!(options.lazy_list_items, ArgType::Lazy);
+        assert_eq!(options.lazy_record_values, base.lazy_record_values);
+        let options = overrides
+            .resolve_module(Path::new("./lib/vendor/index.js"), &base)
+            .unwrap()
+            .unwrap();
+        assert_eq!(options.lazy_list_items, base.lazy_list_items);
+        assert_eq!(options.lazy_record_values, ArgType::Lazy);
+        assert!(overrides
+            .resolve_module(Path::new("library/index.js"), &base)
+            .is_none());
+        assert!(overrides
+            .resolve_module(Path::new("src/lib/index.js"), &base)
+            .is_none());
+        assert!(matches!(
+            ModuleCompilerOptions::from_str("lazy_list_items"),
+            Err(CompilerOptionsError::InvalidSyntax(_))
+        ));
+        assert!(matches!(
+            ModuleCompilerOptions::from_str("=lazy_list_items=lazy"),
+            Err(CompilerOptionsError::InvalidSyntax(_))
+        ));
+    }
+}
@@ -1,11 +1,12 @@
 // SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
 use crate::compiler::{TypeSignature, ValueType};
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, EnumIter)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, EnumIter, Serialize, Deserialize)]
 pub enum RuntimeBuiltin {
     Initialize,
     Evaluate,
@@ -13,6 +14,8 @@ pub enum RuntimeBuiltin {
     CombineDependencies,
     CombineSignals,
     IsSignal,
+    AllocateBigInt,
+    AllocateBytes,
     AllocateCell,
     AllocateHashmap,
     AllocateList,
@@ -40,10 +43,15 @@ pub enum RuntimeBuiltin {
     CreatePartial,
     CreatePointer,
     CreateRecord,
+    CreateRecursive,
+    CreateRegex,
+    CreateSensitive,
     CreateTimestamp,
+    CreateDuration,
     CreateSignal,
     CreateSymbol,
     CreateTree,
+    CreateDedupeIterator,
     CreateEmptyIterator,
     CreateEvaluateIterator,
     CreateFilterIterator,
@@ -60,11 +68,15 @@ pub enum RuntimeBuiltin {
     CreateSkipIterator,
     CreateTakeIterator,
     CreateZipIterator,
+    GetBigIntLimbOffset,
+    GetBytesByteOffset,
     GetBooleanValue,
     GetListItem,
     GetListLength,
     GetStateValue,
     GetStringCharOffset,
+    InitBigInt,
+    InitBytes,
     InitHashmap,
     InitList,
     InitString,
@@ -93,6 +105,12 @@ impl RuntimeBuiltin {
                 ValueType::HeapPointer,
             ),
             RuntimeBuiltin::IsSignal => TypeSignature::new(ValueType::HeapPointer, ValueType::U32),
+            RuntimeBuiltin::AllocateBigInt => {
+                TypeSignature::new((ValueType::U32, ValueType::U32), ValueType::HeapPointer)
+            }
+            RuntimeBuiltin::AllocateBytes => {
+                TypeSignature::new(ValueType::U32, ValueType::HeapPointer)
+            }
             RuntimeBuiltin::AllocateCell => {
                 TypeSignature::new(ValueType::U32, ValueType::HeapPointer)
             }
@@ -178,6 +196,15 @@ impl RuntimeBuiltin {
                 (ValueType::HeapPointer, ValueType::HeapPointer),
                 ValueType::HeapPointer,
             ),
+            RuntimeBuiltin::CreateRecursive => {
+                TypeSignature::new(ValueType::HeapPointer, ValueType::HeapPointer)
+            }
+            RuntimeBuiltin::CreateRegex => {
+                TypeSignature::new(ValueType::HeapPointer, ValueType::HeapPointer)
+            }
+            RuntimeBuiltin::CreateSensitive => {
+                TypeSignature::new(ValueType::HeapPointer, ValueType::HeapPointer)
+            }
             RuntimeBuiltin::CreateSignal => {
                 TypeSignature::new(ValueType::HeapPointer, ValueType::HeapPointer)
             }
@@ -185,12 +212,18 @@ impl RuntimeBuiltin {
                 TypeSignature::new(ValueType::I64, ValueType::HeapPointer)
             }
             RuntimeBuiltin::CreateTimestamp => {
+                TypeSignature::new((ValueType::I64, ValueType::I32), ValueType::HeapPointer)
+            }
+            RuntimeBuiltin::CreateDuration => {
                 TypeSignature::new(ValueType::I64, ValueType::HeapPointer)
             }
             RuntimeBuiltin::CreateTree => TypeSignature::new(
                 (ValueType::HeapPointer, ValueType::HeapPointer),
                 ValueType::HeapPointer,
             ),
+            RuntimeBuiltin::CreateDedupeIterator => {
+                TypeSignature::new(ValueType::HeapPointer, ValueType::HeapPointer)
+            }
             RuntimeBuiltin::CreateEmptyIterator => TypeSignature::new((), ValueType::HeapPointer),
             RuntimeBuiltin::CreateEvaluateIterator => {
                 TypeSignature::new(ValueType::HeapPointer, ValueType::HeapPointer)
@@ -244,6 +277,12 @@ impl RuntimeBuiltin {
                 (ValueType::HeapPointer, ValueType::HeapPointer),
                 ValueType::HeapPointer,
             ),
+            RuntimeBuiltin::GetBigIntLimbOffset => {
+                TypeSignature::new((ValueType::HeapPointer, ValueType::U32), ValueType::U32)
+            }
+            RuntimeBuiltin::GetBytesByteOffset => {
+                TypeSignature::new((ValueType::HeapPointer, ValueType::U32), ValueType::U32)
+            }
             RuntimeBuiltin::GetBooleanValue => {
                 TypeSignature::new(ValueType::HeapPointer, ValueType::U32)
             }
@@ -261,6 +300,12 @@ impl RuntimeBuiltin {
             RuntimeBuiltin::GetStringCharOffset => {
                 TypeSignature::new((ValueType::HeapPointer, ValueType::U32), ValueType::U32)
             }
+            RuntimeBuiltin::InitBigInt => {
+                TypeSignature::new(ValueType::HeapPointer, ValueType::HeapPointer)
+            }
+            RuntimeBuiltin::InitBytes => {
+                TypeSignature::new(ValueType::HeapPointer, ValueType::HeapPointer)
+            }
             RuntimeBuiltin::InitHashmap => {
                 TypeSignature::new(ValueType::HeapPointer, ValueType::HeapPointer)
             }
@@ -305,6 +350,8 @@ impl RuntimeBuiltin {
             RuntimeBuiltin::CombineDependencies => "combineDependencies",
             RuntimeBuiltin::CombineSignals => "combineSignals",
             RuntimeBuiltin::IsSignal => "isSignal",
+            RuntimeBuiltin::AllocateBigInt => "allocateBigInt",
+            RuntimeBuiltin::AllocateBytes => "allocateBytes",
             RuntimeBuiltin::AllocateCell => "allocateCell",
             RuntimeBuiltin::AllocateHashmap => "allocateHashmap",
             RuntimeBuiltin::AllocateList => "allocateList",
@@ -336,10 +383,15 @@ impl RuntimeBuiltin {
             RuntimeBuiltin::CreatePartial => "createPartial",
             RuntimeBuiltin::CreatePointer => "createPointer",
             RuntimeBuiltin::CreateRecord => "createRecord",
+            RuntimeBuiltin::CreateRecursive => "createRecursive",
+            RuntimeBuiltin::CreateRegex => "createRegex",
+            RuntimeBuiltin::CreateSensitive => "createSensitive",
             RuntimeBuiltin::CreateSignal => "createSignal",
             RuntimeBuiltin::CreateSymbol => "createSymbol",
             RuntimeBuiltin::CreateTimestamp => "createTimestamp",
+            RuntimeBuiltin::CreateDuration => "createDuration",
             RuntimeBuiltin::CreateTree => "createTree",
+            RuntimeBuiltin::CreateDedupeIterator => "createDedupeIterator",
             RuntimeBuiltin::CreateEmptyIterator => "createEmptyIterator",
             RuntimeBuiltin::CreateEvaluateIterator => "createEvaluateIterator",
             RuntimeBuiltin::CreateFilterIterator => "createFilterIterator",
@@ -356,11 +408,15 @@ impl RuntimeBuiltin {
             RuntimeBuiltin::CreateSkipIterator => "createSkipIterator",
             RuntimeBuiltin::CreateTakeIterator => "createTakeIterator",
             RuntimeBuiltin::CreateZipIterator => "createZipIterator",
+            RuntimeBuiltin::GetBigIntLimbOffset => "getBigIntLimbOffset",
+            RuntimeBuiltin::GetBytesByteOffset => "getBytesByteOffset",
             RuntimeBuiltin::GetBooleanValue => "getBooleanValue",
             RuntimeBuiltin::GetListItem => "getListItem",
             RuntimeBuiltin::GetListLength => "getListLength",
             RuntimeBuiltin::GetStateValue => "getStateValue",
             RuntimeBuiltin::GetStringCharOffset => "getStringCharOffset",
+            RuntimeBuiltin::InitBigInt => "initBigInt",
+            RuntimeBuiltin::InitBytes => "initBytes",
             RuntimeBuiltin::InitHashmap => "initHashmap",
             RuntimeBuiltin::InitList => "initList",
             RuntimeBuiltin::InitString => "initString",
@@ -4,12 +4,14 @@
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub enum RuntimeGlobal {
     NullPointer,
+    NumericSemantics,
 }
 
 impl RuntimeGlobal {
     pub fn name(self) -> &'static str {
         match self {
             RuntimeGlobal::NullPointer => "NULL",
+            RuntimeGlobal::NumericSemantics => "NUMERIC_SEMANTICS",
         }
     }
 }
@@ -8,8 +8,10 @@ use std::{
 
 use crate::{
     compiler::{
-        instruction::core::Block, runtime::builtin::RuntimeBuiltin, CompiledBlock,
-        CompiledFunctionId, FunctionPointer, ParamsSignature, TypeSignature, ValueType,
+        instruction::{core::Block, CompiledInstruction},
+        runtime::builtin::RuntimeBuiltin,
+        CompiledBlock, CompiledFunctionId, FunctionPointer, ParamsSignature, TypeSignature,
+        ValueType,
     },
     stdlib::Stdlib,
     utils::from_twos_complement_i32,
@@ -39,6 +41,7 @@ pub struct RuntimeExportMappings {
 #[derive(Clone, Copy, Debug)]
 pub struct RuntimeGlobalMappings {
     pub null_pointer: GlobalId,
+    pub numeric_semantics: GlobalId,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -49,6 +52,8 @@ pub struct RuntimeBuiltinMappings {
     pub combine_dependencies: FunctionId,
     pub combine_signals: FunctionId,
     pub is_signal: FunctionId,
+    pub allocate_big_int: FunctionId,
+    pub allocate_bytes: FunctionId,
     pub allocate_cell: FunctionId,
     pub allocate_hashmap: FunctionId,
     pub allocate_list: FunctionId,
@@ -76,10 +81,15 @@ pub struct RuntimeBuiltinMappings {
     pub create_partial: FunctionId,
     pub create_pointer: FunctionId,
     pub create_record: FunctionId,
+    pub create_recursive: FunctionId,
+    pub create_regex: FunctionId,
+    pub create_sensitive: FunctionId,
     pub create_signal: FunctionId,
     pub create_symbol: FunctionId,
     pub create_timestamp: FunctionId,
+    pub create_duration: FunctionId,
     pub create_tree: FunctionId,
+    pub create_dedupe_iterator: FunctionId,
     pub create_empty_iterator: FunctionId,
     pub create_evaluate_iterator: FunctionId,
     pub create_filter_iterator: FunctionId,
@@ -96,11 +106,15 @@ pub struct RuntimeBuiltinMappings {
     pub create_skip_iterator: FunctionId,
     pub create_take_iterator: FunctionId,
     pub create_zip_iterator: FunctionId,
+    pub get_big_int_limb_offset: FunctionId,
+    pub get_bytes_byte_offset: FunctionId,
     pub get_boolean_value: FunctionId,
     pub get_list_item: FunctionId,
     pub get_list_length: FunctionId,
     pub get_state_value: FunctionId,
     pub get_string_char_offset: FunctionId,
+    pub init_big_int: FunctionId,
+    pub init_bytes: FunctionId,
     pub init_hashmap: FunctionId,
     pub init_list: FunctionId,
     pub init_string: FunctionId,
@@ -118,6 +132,8 @@ impl RuntimeBuiltinMappings {
             RuntimeBuiltin::CombineDependencies => self.combine_dependencies,
             RuntimeBuiltin::CombineSignals => self.combine_signals,
             RuntimeBuiltin::IsSignal => self.is_signal,
+            RuntimeBuiltin::AllocateBigInt => self.allocate_big_int,
+            RuntimeBuiltin::AllocateBytes => self.allocate_bytes,
             RuntimeBuiltin::AllocateCell => self.allocate_cell,
             RuntimeBuiltin::AllocateHashmap => self.allocate_hashmap,
             RuntimeBuiltin::AllocateList => self.allocate_list,
@@ -149,10 +165,15 @@ impl RuntimeBuiltinMappings {
             RuntimeBuiltin::CreatePartial => self.create_partial,
             RuntimeBuiltin::CreatePointer => self.create_pointer,
             RuntimeBuiltin::CreateRecord => self.create_record,
+            RuntimeBuiltin::CreateRecursive => self.create_recursive,
+            RuntimeBuiltin::CreateRegex => self.create_regex,
+            RuntimeBuiltin::CreateSensitive => self.create_sensitive,
             RuntimeBuiltin::CreateSignal => self.create_signal,
             RuntimeBuiltin::CreateSymbol => self.create_symbol,
             RuntimeBuiltin::CreateTimestamp => self.create_timestamp,
+            RuntimeBuiltin::CreateDuration => self.create_duration,
             RuntimeBuiltin::CreateTree => self.create_tree,
+            RuntimeBuiltin::CreateDedupeIterator => self.create_dedupe_iterator,
             RuntimeBuiltin::CreateEmptyIterator => self.create_empty_iterator,
             RuntimeBuiltin::CreateEvaluateIterator => self.create_evaluate_iterator,
             RuntimeBuiltin::CreateFilterIterator => self.create_filter_iterator,
@@ -169,11 +190,15 @@ impl RuntimeBuiltinMappings {
             RuntimeBuiltin::CreateSkipIterator => self.create_skip_iterator,
             RuntimeBuiltin::CreateTakeIterator => self.create_take_iterator,
             RuntimeBuiltin::CreateZipIterator => self.create_zip_iterator,
+            RuntimeBuiltin::GetBigIntLimbOffset => self.get_big_int_limb_offset,
+            RuntimeBuiltin::GetBytesByteOffset => self.get_bytes_byte_offset,
             RuntimeBuiltin::GetBooleanValue => self.get_boolean_value,
             RuntimeBuiltin::GetListItem => self.get_list_item,
             RuntimeBuiltin::GetListLength => self.get_list_length,
             RuntimeBuiltin::GetStateValue => self.get_state_value,
             RuntimeBuiltin::GetStringCharOffset => self.get_string_char_offset,
+            RuntimeBuiltin::InitBigInt => self.init_big_int,
+            RuntimeBuiltin::InitBytes => self.init_bytes,
             RuntimeBuiltin::InitHashmap => self.init_hashmap,
             RuntimeBuiltin::InitList => self.init_list,
             RuntimeBuiltin::InitString => self.init_string,
@@ -205,13 +230,23 @@ pub struct RuntimeStdlibMappings {
     pub collect_tree: FunctionId,
     pub cons: FunctionId,
     pub construct: FunctionId,
+    pub date_add: FunctionId,
+    pub date_diff: FunctionId,
+    pub date_offset: FunctionId,
+    pub date_truncate: FunctionId,
+    pub date_with_offset: FunctionId,
+    pub debounce: FunctionId,
     pub debug: FunctionId,
     pub decrement_variable: FunctionId,
+    pub dedupe: FunctionId,
+    pub delete: FunctionId,
     pub divide: FunctionId,
+    pub duration: FunctionId,
     pub effect: FunctionId,
     pub ends_with: FunctionId,
     pub eq: FunctionId,
     pub equal: FunctionId,
+    pub eval: FunctionId,
     pub filter: FunctionId,
     pub flatten: FunctionId,
     pub floor: FunctionId,
@@ -224,6 +259,8 @@ pub struct RuntimeStdlibMappings {
     pub gte: FunctionId,
     pub has: FunctionId,
     pub hash: FunctionId,
+    pub hashmap_from_iterator: FunctionId,
+    pub hashset_from_iterator: FunctionId,
     pub identity: FunctionId,
     pub r#if: FunctionId,
     pub if_error: FunctionId,
@@ -236,15 +273,20 @@ pub struct RuntimeStdlibMappings {
     pub keys: FunctionId,
     pub length: FunctionId,
     pub log: FunctionId,
+    pub log_scope: FunctionId,
     pub lt: FunctionId,
     pub lte: FunctionId,
     pub map: FunctionId,
+    pub r#match: FunctionId,
     pub max: FunctionId,
+    pub memo: FunctionId,
+    pub memo_store: FunctionId,
     pub merge: FunctionId,
     pub min: FunctionId,
     pub multiply: FunctionId,
     pub not: FunctionId,
     pub or: FunctionId,
+    pub paginate: FunctionId,
     pub parse_date: FunctionId,
     pub parse_float: FunctionId,
     pub parse_int: FunctionId,
@@ -252,6 +294,7 @@ pub struct RuntimeStdlibMappings {
     pub pow: FunctionId,
     pub push: FunctionId,
     pub push_front: FunctionId,
+    pub quote: FunctionId,
     pub raise: FunctionId,
     pub remainder: FunctionId,
     pub replace: FunctionId,
@@ -265,18 +308,27 @@ pub struct RuntimeStdlibMappings {
     pub resolve_query_leaf: FunctionId,
     pub resolve_record: FunctionId,
     pub resolve_tree: FunctionId,
+    pub reveal: FunctionId,
     pub round: FunctionId,
+    pub sample: FunctionId,
     pub scan: FunctionId,
+    pub scoped_log: FunctionId,
     pub sequence: FunctionId,
     pub set: FunctionId,
     pub set_variable: FunctionId,
     pub skip: FunctionId,
     pub slice: FunctionId,
+    pub sort: FunctionId,
+    pub sort_by: FunctionId,
+    pub sort_merge: FunctionId,
     pub split: FunctionId,
     pub starts_with: FunctionId,
     pub stringify_json: FunctionId,
+    pub stringify_json_canonical: FunctionId,
     pub subtract: FunctionId,
     pub take: FunctionId,
+    pub template: FunctionId,
+    pub throttle: FunctionId,
     pub throw: FunctionId,
     pub to_request: FunctionId,
     pub to_string: FunctionId,
@@ -308,13 +360,23 @@ impl RuntimeStdlibMappings {
             Stdlib::CollectTree(_) => self.collect_tree,
             Stdlib::Cons(_) => self.cons,
             Stdlib::Construct(_) => self.construct,
+            Stdlib::DateAdd(_) => self.date_add,
+            Stdlib::DateDiff(_) => self.date_diff,
+            Stdlib::DateOffset(_) => self.date_offset,
+            Stdlib::DateTruncate(_) => self.date_truncate,
+            Stdlib::DateWithOffset(_) => self.date_with_offset,
+            Stdlib::Debounce(_) => self.debounce,
             Stdlib::Debug(_) => self.debug,
             Stdlib::DecrementVariable(_) => self.decrement_variable,
+            Stdlib::Dedupe(_) => self.dedupe,
+            Stdlib::Delete(_) => self.delete,
             Stdlib::Divide(_) => self.divide,
+            Stdlib::Duration(_) => self.duration,
             Stdlib::Effect(_) => self.effect,
             Stdlib::EndsWith(_) => self.ends_with,
             Stdlib::Eq(_) => self.eq,
             Stdlib::Equal(_) => self.equal,
+            Stdlib::Eval(_) => self.eval,
             Stdlib::Filter(_) => self.filter,
             Stdlib::Flatten(_) => self.flatten,
             Stdlib::Floor(_) => self.floor,
@@ -327,6 +389,8 @@ impl RuntimeStdlibMappings {
             Stdlib::Gte(_) => self.gte,
             Stdlib::Has(_) => self.has,
             Stdlib::Hash(_) => self.hash,
+            Stdlib::HashmapFromIterator(_) => self.hashmap_from_iterator,
+            Stdlib::HashsetFromIterator(_) => self.hashset_from_iterator,
             Stdlib::Identity(_) => self.identity,
             Stdlib::If(_) => self.r#if,
             Stdlib::IfError(_) => self.if_error,
@@ -339,15 +403,20 @@ impl RuntimeStdlibMappings {
             Stdlib::Keys(_) => self.keys,
             Stdlib::Length(_) => self.length,
             Stdlib::Log(_) => self.log,
+            Stdlib::LogScope(_) => self.log_scope,
             Stdlib::Lt(_) => self.lt,
             Stdlib::Lte(_) => self.lte,
             Stdlib::Map(_) => self.map,
+            Stdlib::Match(_) => self.r#match,
             Stdlib::Max(_) => self.max,
+            Stdlib::Memo(_) => self.memo,
+            Stdlib::MemoStore(_) => self.memo_store,
             Stdlib::Merge(_) => self.merge,
             Stdlib::Min(_) => self.min,
             Stdlib::Multiply(_) => self.multiply,
             Stdlib::Not(_) => self.not,
             Stdlib::Or(_) => self.or,
+            Stdlib::Paginate(_) => self.paginate,
             Stdlib::ParseDate(_) => self.parse_date,
             Stdlib::ParseFloat(_) => self.parse_float,
             Stdlib::ParseInt(_) => self.parse_int,
@@ -355,6 +424,7 @@ impl RuntimeStdlibMappings {
             Stdlib::Pow(_) => self.pow,
             Stdlib::Push(_) => self.push,
             Stdlib::PushFront(_) => self.push_front,
+            Stdlib::Quote(_) => self.quote,
             Stdlib::Raise(_) => self.raise,
             Stdlib::Remainder(_) => self.remainder,
             Stdlib::Replace(_) => self.replace,
@@ -368,18 +438,27 @@ impl RuntimeStdlibMappings {
             Stdlib::ResolveQueryLeaf(_) => self.resolve_query_leaf,
             Stdlib::ResolveRecord(_) => self.resolve_record,
             Stdlib::ResolveTree(_) => self.resolve_tree,
+            Stdlib::Reveal(_) => self.reveal,
             Stdlib::Round(_) => self.round,
+            Stdlib::Sample(_) => self.sample,
             Stdlib::Scan(_) => self.scan,
+            Stdlib::ScopedLog(_) => self.scoped_log,
             Stdlib::Sequence(_) => self.sequence,
             Stdlib::Set(_) => self.set,
             Stdlib::SetVariable(_) => self.set_variable,
             Stdlib::Skip(_) => self.skip,
             Stdlib::Slice(_) => self.slice,
+            Stdlib::Sort(_) => self.sort,
+            Stdlib::SortBy(_) => self.sort_by,
+            Stdlib::SortMerge(_) => self.sort_merge,
             Stdlib::Split(_) => self.split,
             Stdlib::StartsWith(_) => self.starts_with,
             Stdlib::StringifyJson(_) => self.stringify_json,
+            Stdlib::StringifyJsonCanonical(_) => self.stringify_json_canonical,
             Stdlib::Subtract(_) => self.subtract,
             Stdlib::Take(_) => self.take,
+            Stdlib::Template(_) => self.template,
+            Stdlib::Throttle(_) => self.throttle,
             Stdlib::Throw(_) => self.throw,
             Stdlib::ToRequest(_) => self.to_request,
             Stdlib::ToString(_) => self.to_string,
@@ -398,6 +477,9 @@ impl RuntimeStdlibMappings {
 #[derive(Default, Clone, Copy, Debug)]
 pub struct WasmGeneratorOptions {
     pub disable_block_params: bool,
+    /// Attach a human-readable annotation to each generated instruction sequence, indicating the compiled instruction
+    /// from which it was generated (see [`WasmInstructionAnnotations`])
+    pub annotate_instructions: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -451,6 +533,7 @@ pub(crate) fn generate_stateful_function(
     memory_id: MemoryId,
     main_function_table_id: TableId,
     compiled_function_mappings: &WasmCompiledFunctionMappings,
+    annotations: &mut WasmInstructionAnnotations,
     options: &WasmGeneratorOptions,
 ) -> Result<FunctionId, WasmGeneratorError> {
     // Define the function signature
@@ -532,7 +615,13 @@ pub(crate) fn generate_stateful_function(
                 compiled_instructions
             }?;
             // Inject the generated instructions into the function body
-            let _ = assemble_wasm(&mut function_body, Default::default(), instructions)?;
+            let _ = assemble_wasm(
+                &mut function_body,
+                Default::default(),
+                Default::default(),
+                annotations,
+                instructions,
+            )?;
         };
         // Embed the function post-instructions
         function_body
@@ -1366,6 +1455,9 @@ impl Extend<WasmInstruction> for WasmGeneratorOutput {
 pub enum WasmInstruction {
     /// Standard WASM instruction
     Instruction(ir::Instr),
+    /// Human-readable annotation that applies to all subsequent instructions within the current block (this does not
+    /// emit any WASM bytecode)
+    Annotation(String),
     /// Control flow block
     Block {
         block_type: ir::InstrSeqType,
@@ -1396,40 +1488,91 @@ pub enum WasmInstruction {
 
 pub type WasmGeneratorResult = Result<WasmGeneratorOutput, WasmGeneratorError>;
 
+/// Mapping from instruction location IDs to the human-readable annotations attached to the generated instructions
+///
+/// Annotated instructions are assigned a location ID that refers to an entry in this table, allowing annotations to be
+/// recovered from the assembled WASM functions (e.g. when printing the generated functions in WAT text format).
+#[derive(Default, Clone, Debug)]
+pub struct WasmInstructionAnnotations {
+    annotations: Vec<String>,
+}
+
+impl WasmInstructionAnnotations {
+    pub fn insert(&mut self, annotation: String) -> ir::InstrLocId {
+        let index = self.annotations.len();
+        self.annotations.push(annotation);
+        ir::InstrLocId::new(index as u32)
+    }
+    pub fn get(&self, location: ir::InstrLocId) -> Option<&str> {
+        if location.is_default() {
+            None
+        } else {
+            self.annotations
+                .get(location.data() as usize)
+                .map(|annotation| annotation.as_str())
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.annotations.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.annotations.is_empty()
+    }
+}
+
 #[must_use]
 fn assemble_wasm(
     builder: &mut InstrSeqBuilder,
     enclosing_blocks: Stack<ir::InstrSeqId>,
+    location: ir::InstrLocId,
+    annotations: &mut WasmInstructionAnnotations,
     instructions: impl IntoIterator<Item = WasmInstruction>,
 ) -> Result<(), WasmGeneratorError> {
     instructions
         .into_iter()
-        .fold(Ok(()), |result, instruction| {
-            let _ = result?;
+        .fold(Ok(location), |result, instruction| {
+            let location = result?;
             match instruction {
                 WasmInstruction::Instruction(instruction) => {
-                    builder.instr(instruction);
-                    Ok(())
+                    builder.instrs_mut().push((instruction, location));
+                    Ok(location)
                 }
+                WasmInstruction::Annotation(annotation) => Ok(annotations.insert(annotation)),
                 WasmInstruction::Block { block_type, body } => {
                     let block_id = {
                         let mut block_builder = builder.dangling_instr_seq(block_type);
                         let block_id = block_builder.id();
-                        assemble_wasm(&mut block_builder, enclosing_blocks.push(block_id), body)?;
+                        assemble_wasm(
+                            &mut block_builder,
+                            enclosing_blocks.push(block_id),
+                            location,
+                            annotations,
+                            body,
+                        )?;
                         block_id
                     };
-                    builder.instr(ir::Block { seq: block_id });
-                    Ok(())
+                    builder
+                        .instrs_mut()
+                        .push((ir::Block { seq: block_id }.into(), location));
+                    Ok(location)
                 }
                 WasmInstruction::Loop { block_type, body } => {
                     let block_id = {
                         let mut block_builder = builder.dangling_instr_seq(block_type);
                         let block_id = block_builder.id();
-                        assemble_wasm(&mut block_builder, enclosing_blocks.push(block_id), body)?;
+                        assemble_wasm(
+                            &mut block_builder,
+                            enclosing_blocks.push(block_id),
+                            location,
+                            annotations,
+                            body,
+                        )?;
                         block_id
                     };
-                    builder.instr(ir::Loop { seq: block_id });
-                    Ok(())
+                    builder
+                        .instrs_mut()
+                        .push((ir::Loop { seq: block_id }.into(), location));
+                    Ok(location)
                 }
                 WasmInstruction::Break { target_block } => {
                     let block_id = enclosing_blocks
@@ -1438,8 +1581,10 @@ fn assemble_wasm(
                         .skip(target_block)
                         .next()
                         .ok_or_else(|| WasmGeneratorError::InvalidBlockOffset(target_block))?;
-                    builder.instr(ir::Br { block: block_id });
-                    Ok(())
+                    builder
+                        .instrs_mut()
+                        .push((ir::Br { block: block_id }.into(), location));
+                    Ok(location)
                 }
                 WasmInstruction::ConditionalBreak { target_block } => {
                     let block_id = enclosing_blocks
@@ -1448,8 +1593,10 @@ fn assemble_wasm(
                         .skip(target_block)
                         .next()
                         .ok_or_else(|| WasmGeneratorError::InvalidBlockOffset(target_block))?;
-                    builder.instr(ir::BrIf { block: block_id });
-                    Ok(())
+                    builder
+                        .instrs_mut()
+                        .push((ir::BrIf { block: block_id }.into(), location));
+                    Ok(location)
                 }
                 WasmInstruction::IfElse {
                     block_type,
@@ -1460,24 +1607,41 @@ fn assemble_wasm(
                         let body = consequent;
                         let mut block_builder = builder.dangling_instr_seq(block_type);
                         let block_id = block_builder.id();
-                        assemble_wasm(&mut block_builder, enclosing_blocks.push(block_id), body)?;
+                        assemble_wasm(
+                            &mut block_builder,
+                            enclosing_blocks.push(block_id),
+                            location,
+                            annotations,
+                            body,
+                        )?;
                         block_id
                     };
                     let alternative_block_id = {
                         let body = alternative;
                         let mut block_builder = builder.dangling_instr_seq(block_type);
                         let block_id = block_builder.id();
-                        assemble_wasm(&mut block_builder, enclosing_blocks.push(block_id), body)?;
+                        assemble_wasm(
+                            &mut block_builder,
+                            enclosing_blocks.push(block_id),
+                            location,
+                            annotations,
+                            body,
+                        )?;
                         block_id
                     };
-                    builder.instr(ir::IfElse {
-                        consequent: consequent_block_id,
-                        alternative: alternative_block_id,
-                    });
-                    Ok(())
+                    builder.instrs_mut().push((
+                        ir::IfElse {
+                            consequent: consequent_block_id,
+                            alternative: alternative_block_id,
+                        }
+                        .into(),
+                        location,
+                    ));
+                    Ok(location)
                 }
             }
         })
+        .map(|_| ())
 }
 
 pub trait GenerateWasm {
@@ -1498,8 +1662,24 @@ impl GenerateWasm for CompiledBlock {
     ) -> WasmGeneratorResult {
         let mut instructions = WasmGeneratorOutput::default();
         for instruction in self.instructions.iter() {
+            if options.annotate_instructions {
+                instructions.instructions.push(WasmInstruction::Annotation(
+                    format_instruction_annotation(instruction),
+                ));
+            }
             instructions.push_chunk(instruction.emit_wasm(module, bindings, options)?);
         }
         Ok(instructions)
     }
 }
+
+fn format_instruction_annotation(instruction: &CompiledInstruction) -> String {
+    match instruction {
+        // Omit the contents of any child blocks (the child block instructions will be annotated individually)
+        CompiledInstruction::Block(inner) => {
+            format!("Block {{ block_type: {:?} }}", inner.block_type)
+        }
+        CompiledInstruction::If(inner) => format!("If {{ block_type: {:?} }}", inner.block_type),
+        instruction => format!("{}", instruction),
+    }
+}
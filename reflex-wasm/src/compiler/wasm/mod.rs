@@ -7,3 +7,4 @@ pub mod get_locals;
 pub mod import_function;
 pub mod transform;
 pub mod types;
+pub mod wat;
@@ -0,0 +1,455 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::fmt::Write;
+
+use walrus::{
+    ir::{self, InstrLocId, InstrSeqId, InstrSeqType, Value},
+    FunctionId, FunctionKind, LocalFunction, LocalId, Module, ValType,
+};
+
+use super::{generate::WasmInstructionAnnotations, get_locals::WasmLocals};
+
+const INDENT: &str = "  ";
+
+/// Print the provided module functions in WebAssembly text format (WAT)
+///
+/// Each function is preceded by a comment containing the provided function label. Any instructions that were generated
+/// with annotations enabled are preceded by a comment containing the corresponding instruction annotation, allowing the
+/// generated instructions to be mapped back to the compiled instructions from which they originated.
+///
+/// Note that only the specified functions are printed (rather than the full module), so the output is intended for
+/// inspection rather than as input for a WAT assembler.
+pub fn print_wat_functions<'a>(
+    module: &Module,
+    functions: impl IntoIterator<Item = (FunctionId, &'a str)>,
+    annotations: &WasmInstructionAnnotations,
+) -> String {
+    let mut output = String::new();
+    for (function_id, label) in functions {
+        if !output.is_empty() {
+            output.push('\n');
+        }
+        let _ = writeln!(output, ";; {}", label);
+        write_function(&mut output, module, function_id, annotations);
+    }
+    output
+}
+
+fn write_function(
+    output: &mut String,
+    module: &Module,
+    function_id: FunctionId,
+    annotations: &WasmInstructionAnnotations,
+) {
+    let function = module.funcs.get(function_id);
+    let signature = module.types.get(function.ty());
+    let _ = write!(
+        output,
+        "(func {}",
+        format_function_name(module, function_id)
+    );
+    for export in module.exports.iter() {
+        if matches!(export.item, walrus::ExportItem::Function(id) if id == function_id) {
+            let _ = write!(output, " (export {:?})", export.name);
+        }
+    }
+    match &function.kind {
+        FunctionKind::Local(function) => {
+            for (arg_id, arg_type) in function.args.iter().zip(signature.params()) {
+                let _ = write!(
+                    output,
+                    " (param {} {})",
+                    format_local_name(*arg_id),
+                    format_value_type(*arg_type)
+                );
+            }
+            write_results(output, signature.results());
+            output.push('\n');
+            let mut locals = function
+                .get_locals(function)
+                .into_iter()
+                .filter(|local_id| !function.args.contains(local_id))
+                .collect::<Vec<_>>();
+            locals.sort_by_key(|local_id| local_id.index());
+            for local_id in locals {
+                let _ = writeln!(
+                    output,
+                    "{}(local {} {})",
+                    INDENT,
+                    format_local_name(local_id),
+                    format_value_type(module.locals.get(local_id).ty())
+                );
+            }
+            let mut printer = WatFunctionPrinter {
+                output,
+                module,
+                function,
+                annotations,
+                labels: Vec::new(),
+                location: InstrLocId::default(),
+            };
+            printer.write_sequence(function.entry_block(), 1);
+        }
+        _ => {
+            for param_type in signature.params() {
+                let _ = write!(output, " (param {})", format_value_type(*param_type));
+            }
+            write_results(output, signature.results());
+            output.push('\n');
+        }
+    }
+    output.push_str(")\n");
+}
+
+fn write_results(output: &mut String, results: &[ValType]) {
+    for result_type in results {
+        let _ = write!(output, " (result {})", format_value_type(*result_type));
+    }
+}
+
+struct WatFunctionPrinter<'a> {
+    output: &'a mut String,
+    module: &'a Module,
+    function: &'a LocalFunction,
+    annotations: &'a WasmInstructionAnnotations,
+    /// Stack of labels for the enclosing blocks (if/else branches share the same label)
+    labels: Vec<(InstrSeqId, String)>,
+    /// Location ID of the most recently printed instruction, used to determine when to print a new annotation
+    location: InstrLocId,
+}
+
+impl<'a> WatFunctionPrinter<'a> {
+    fn write_sequence(&mut self, seq_id: InstrSeqId, depth: usize) {
+        let block = self.function.block(seq_id);
+        for (instruction, location) in block.instrs.iter() {
+            if let Some(annotation) = self.get_updated_annotation(*location) {
+                let _ = writeln!(self.output, "{};; {}", INDENT.repeat(depth), annotation);
+            }
+            self.location = *location;
+            self.write_instruction(instruction, depth);
+        }
+    }
+    fn get_updated_annotation(&self, location: InstrLocId) -> Option<&'a str> {
+        if location.is_default()
+            || (!self.location.is_default() && self.location.data() == location.data())
+        {
+            None
+        } else {
+            self.annotations.get(location)
+        }
+    }
+    fn write_line(&mut self, depth: usize, value: impl std::fmt::Display) {
+        let _ = writeln!(self.output, "{}{}", INDENT.repeat(depth), value);
+    }
+    fn write_block(&mut self, keyword: &str, seq_ids: &[InstrSeqId], depth: usize) {
+        let label = format!("$block{}", self.labels.len());
+        let block_type = format_block_type(self.module, self.function.block(seq_ids[0]).ty);
+        self.write_line(depth, format!("{} {}{}", keyword, label, block_type));
+        for (index, seq_id) in seq_ids.iter().enumerate() {
+            if index > 0 {
+                self.write_line(depth, "else");
+            }
+            self.labels.push((*seq_id, label.clone()));
+            self.write_sequence(*seq_id, depth + 1);
+            self.labels.pop();
+        }
+        self.write_line(depth, "end");
+    }
+    fn format_label(&self, target: InstrSeqId) -> String {
+        self.labels
+            .iter()
+            .rev()
+            .find(|(seq_id, _)| *seq_id == target)
+            .map(|(_, label)| label.clone())
+            .unwrap_or_else(|| {
+                // Branches that do not target an enclosing block target the function body
+                format!("{}", self.labels.len())
+            })
+    }
+    fn write_instruction(&mut self, instruction: &ir::Instr, depth: usize) {
+        let module = self.module;
+        match instruction {
+            ir::Instr::Block(ir::Block { seq }) => self.write_block("block", &[*seq], depth),
+            ir::Instr::Loop(ir::Loop { seq }) => self.write_block("loop", &[*seq], depth),
+            ir::Instr::IfElse(ir::IfElse {
+                consequent,
+                alternative,
+            }) => self.write_block("if", &[*consequent, *alternative], depth),
+            ir::Instr::Br(ir::Br { block }) => {
+                let label = self.format_label(*block);
+                self.write_line(depth, format!("br {}", label))
+            }
+            ir::Instr::BrIf(ir::BrIf { block }) => {
+                let label = self.format_label(*block);
+                self.write_line(depth, format!("br_if {}", label))
+            }
+            ir::Instr::BrTable(ir::BrTable { blocks, default }) => {
+                let labels = blocks
+                    .iter()
+                    .chain([default])
+                    .map(|block| self.format_label(*block))
+                    .collect::<Vec<_>>();
+                self.write_line(depth, format!("br_table {}", labels.join(" ")))
+            }
+            instruction => {
+                let value = format_instruction(module, instruction);
+                self.write_line(depth, value)
+            }
+        }
+    }
+}
+
+fn format_instruction(module: &Module, instruction: &ir::Instr) -> String {
+    match instruction {
+        ir::Instr::Call(ir::Call { func }) => {
+            format!("call {}", format_function_name(module, *func))
+        }
+        ir::Instr::CallIndirect(ir::CallIndirect { ty, table }) => {
+            let signature = module.types.get(*ty);
+            let mut output = format!("call_indirect {}", table.index());
+            for param_type in signature.params() {
+                let _ = write!(output, " (param {})", format_value_type(*param_type));
+            }
+            write_results(&mut output, signature.results());
+            output
+        }
+        ir::Instr::LocalGet(ir::LocalGet { local }) => {
+            format!("local.get {}", format_local_name(*local))
+        }
+        ir::Instr::LocalSet(ir::LocalSet { local }) => {
+            format!("local.set {}", format_local_name(*local))
+        }
+        ir::Instr::LocalTee(ir::LocalTee { local }) => {
+            format!("local.tee {}", format_local_name(*local))
+        }
+        ir::Instr::GlobalGet(ir::GlobalGet { global }) => {
+            format!("global.get {}", format_global_name(module, *global))
+        }
+        ir::Instr::GlobalSet(ir::GlobalSet { global }) => {
+            format!("global.set {}", format_global_name(module, *global))
+        }
+        ir::Instr::Const(ir::Const { value }) => format_const(*value),
+        ir::Instr::Binop(ir::Binop { op }) => format_operator_name(&format!("{:?}", op)),
+        ir::Instr::Unop(ir::Unop { op }) => format_operator_name(&format!("{:?}", op)),
+        ir::Instr::Select(ir::Select { ty }) => match ty {
+            Some(ty) => format!("select (result {})", format_value_type(*ty)),
+            None => String::from("select"),
+        },
+        ir::Instr::Unreachable(_) => String::from("unreachable"),
+        ir::Instr::Drop(_) => String::from("drop"),
+        ir::Instr::Return(_) => String::from("return"),
+        ir::Instr::MemorySize(_) => String::from("memory.size"),
+        ir::Instr::MemoryGrow(_) => String::from("memory.grow"),
+        ir::Instr::MemoryInit(ir::MemoryInit { data, .. }) => {
+            format!("memory.init {}", data.index())
+        }
+        ir::Instr::DataDrop(ir::DataDrop { data }) => format!("data.drop {}", data.index()),
+        ir::Instr::MemoryCopy(_) => String::from("memory.copy"),
+        ir::Instr::MemoryFill(_) => String::from("memory.fill"),
+        ir::Instr::Load(ir::Load { kind, arg, .. }) => format!(
+            "{}{}",
+            format_load_kind(*kind),
+            format_mem_arg(*arg, kind.width())
+        ),
+        ir::Instr::Store(ir::Store { kind, arg, .. }) => format!(
+            "{}{}",
+            format_store_kind(*kind),
+            format_mem_arg(*arg, kind.width())
+        ),
+        // Any other instructions are not emitted by the code generator, so fall back to the debug representation
+        instruction => format!(";; {:?}", instruction),
+    }
+}
+
+fn format_function_name(module: &Module, function_id: FunctionId) -> String {
+    match module.funcs.get(function_id).name.as_ref() {
+        Some(name) => format!("${}", sanitize_identifier(name)),
+        None => format!("$func{}", function_id.index()),
+    }
+}
+
+fn format_global_name(module: &Module, global_id: walrus::GlobalId) -> String {
+    // Globals are not named within the IR, so fall back to the export name if one exists
+    let export_name = module.exports.iter().find_map(|export| match export.item {
+        walrus::ExportItem::Global(id) if id == global_id => Some(export.name.as_str()),
+        _ => None,
+    });
+    match export_name {
+        Some(name) => format!("${}", sanitize_identifier(name)),
+        None => format!("$global{}", global_id.index()),
+    }
+}
+
+fn format_local_name(local_id: LocalId) -> String {
+    format!("$var{}", local_id.index())
+}
+
+fn sanitize_identifier(name: &str) -> String {
+    name.chars()
+        .map(|char| match char {
+            '0'..='9' | 'a'..='z' | 'A'..='Z' => char,
+            '!' | '#' | '$' | '%' | '&' | '\'' | '*' | '+' | '-' | '.' | '/' | ':' | '<' | '='
+            | '>' | '?' | '@' | '\\' | '^' | '_' | '`' | '|' | '~' => char,
+            _ => '_',
+        })
+        .collect()
+}
+
+fn format_value_type(value_type: ValType) -> &'static str {
+    match value_type {
+        ValType::I32 => "i32",
+        ValType::I64 => "i64",
+        ValType::F32 => "f32",
+        ValType::F64 => "f64",
+        ValType::V128 => "v128",
+        ValType::Externref => "externref",
+        ValType::Funcref => "funcref",
+    }
+}
+
+fn format_block_type(module: &Module, block_type: InstrSeqType) -> String {
+    match block_type {
+        InstrSeqType::Simple(None) => String::new(),
+        InstrSeqType::Simple(Some(result_type)) => {
+            format!(" (result {})", format_value_type(result_type))
+        }
+        InstrSeqType::MultiValue(type_id) => {
+            let signature = module.types.get(type_id);
+            let mut output = String::new();
+            for param_type in signature.params() {
+                let _ = write!(output, " (param {})", format_value_type(*param_type));
+            }
+            write_results(&mut output, signature.results());
+            output
+        }
+    }
+}
+
+fn format_const(value: Value) -> String {
+    match value {
+        Value::I32(value) => format!("i32.const {}", value),
+        Value::I64(value) => format!("i64.const {}", value),
+        Value::F32(value) => format!("f32.const {}", format_float(value as f64)),
+        Value::F64(value) => format!("f64.const {}", format_float(value)),
+        Value::V128(value) => format!("v128.const i64x2 {} {}", value as u64, (value >> 64) as u64),
+    }
+}
+
+fn format_float(value: f64) -> String {
+    if value.is_nan() {
+        String::from("nan")
+    } else if value.is_infinite() {
+        String::from(if value.is_sign_negative() {
+            "-inf"
+        } else {
+            "inf"
+        })
+    } else {
+        format!("{:?}", value)
+    }
+}
+
+fn format_load_kind(kind: ir::LoadKind) -> String {
+    let (value_type, width, extend) = match kind {
+        ir::LoadKind::I32 { atomic } => ("i32", "", atomic.then_some(None)),
+        ir::LoadKind::I64 { atomic } => ("i64", "", atomic.then_some(None)),
+        ir::LoadKind::F32 => ("f32", "", None),
+        ir::LoadKind::F64 => ("f64", "", None),
+        ir::LoadKind::V128 => ("v128", "", None),
+        ir::LoadKind::I32_8 { kind } => ("i32", "8", Some(Some(kind))),
+        ir::LoadKind::I32_16 { kind } => ("i32", "16", Some(Some(kind))),
+        ir::LoadKind::I64_8 { kind } => ("i64", "8", Some(Some(kind))),
+        ir::LoadKind::I64_16 { kind } => ("i64", "16", Some(Some(kind))),
+        ir::LoadKind::I64_32 { kind } => ("i64", "32", Some(Some(kind))),
+    };
+    match extend {
+        None => format!("{}.load{}", value_type, width),
+        Some(None) => format!("{}.atomic.load{}", value_type, width),
+        Some(Some(ir::ExtendedLoad::SignExtend)) => format!("{}.load{}_s", value_type, width),
+        Some(Some(ir::ExtendedLoad::ZeroExtend)) => format!("{}.load{}_u", value_type, width),
+        Some(Some(ir::ExtendedLoad::ZeroExtendAtomic)) => {
+            format!("{}.atomic.load{}_u", value_type, width)
+        }
+    }
+}
+
+fn format_store_kind(kind: ir::StoreKind) -> String {
+    let (value_type, width, atomic) = match kind {
+        ir::StoreKind::I32 { atomic } => ("i32", "", atomic),
+        ir::StoreKind::I64 { atomic } => ("i64", "", atomic),
+        ir::StoreKind::F32 => ("f32", "", false),
+        ir::StoreKind::F64 => ("f64", "", false),
+        ir::StoreKind::V128 => ("v128", "", false),
+        ir::StoreKind::I32_8 { atomic } => ("i32", "8", atomic),
+        ir::StoreKind::I32_16 { atomic } => ("i32", "16", atomic),
+        ir::StoreKind::I64_8 { atomic } => ("i64", "8", atomic),
+        ir::StoreKind::I64_16 { atomic } => ("i64", "16", atomic),
+        ir::StoreKind::I64_32 { atomic } => ("i64", "32", atomic),
+    };
+    if atomic {
+        format!("{}.atomic.store{}", value_type, width)
+    } else {
+        format!("{}.store{}", value_type, width)
+    }
+}
+
+fn format_mem_arg(arg: ir::MemArg, natural_alignment: u32) -> String {
+    let offset = if arg.offset != 0 {
+        format!(" offset={}", arg.offset)
+    } else {
+        String::new()
+    };
+    let align = if arg.align != natural_alignment {
+        format!(" align={}", arg.align)
+    } else {
+        String::new()
+    };
+    format!("{}{}", offset, align)
+}
+
+/// Convert a numeric operator name as represented in the intermediate representation (e.g. `I32TruncSF64`) into the
+/// corresponding WAT instruction name (e.g. `i32.trunc_f64_s`)
+fn format_operator_name(name: &str) -> String {
+    // Strip any immediate operands from the debug representation
+    let name = name.split(' ').next().unwrap_or(name);
+    // Split the name into words, where each word starts with an uppercase character
+    let mut words = Vec::<String>::new();
+    for char in name.chars() {
+        match words.last_mut() {
+            Some(word) if !char.is_ascii_uppercase() => word.push(char),
+            _ => words.push(String::from(char)),
+        }
+    }
+    let mut words = words.into_iter().map(|word| word.to_lowercase());
+    let value_type = words.next().unwrap_or_default();
+    let mut words = words.collect::<Vec<_>>();
+    // Signedness modifiers are placed at the end of the instruction name (e.g. `trunc_s_f64` -> `trunc_f64_s`)
+    if let Some(index) = words
+        .iter()
+        .position(|word| word.as_str() == "s" || word.as_str() == "u")
+    {
+        let signedness = words.remove(index);
+        words.push(signedness);
+    }
+    format!("{}.{}", value_type, words.join("_"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operator_names() {
+        assert_eq!(format_operator_name("I32Add"), "i32.add");
+        assert_eq!(format_operator_name("I32LtU"), "i32.lt_u");
+        assert_eq!(format_operator_name("I64ShrS"), "i64.shr_s");
+        assert_eq!(format_operator_name("F64Copysign"), "f64.copysign");
+        assert_eq!(format_operator_name("I32Eqz"), "i32.eqz");
+        assert_eq!(format_operator_name("I32WrapI64"), "i32.wrap_i64");
+        assert_eq!(format_operator_name("I32TruncSF64"), "i32.trunc_f64_s");
+        assert_eq!(format_operator_name("I64ExtendUI32"), "i64.extend_i32_u");
+        assert_eq!(format_operator_name("I32Extend8S"), "i32.extend8_s");
+        assert_eq!(format_operator_name("F32DemoteF64"), "f32.demote_f64");
+    }
+}
@@ -0,0 +1,250 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use crate::{compiler::CompiledFunctionId, stdlib::Stdlib, ArenaPointer, ArenaRef, Term};
+
+/// Name of the import module containing the debugger hooks invoked by modules compiled with debug hooks enabled
+///
+/// Each compiled function invokes the `enter` hook with its function ID (as an `i64`) before executing its body, and
+/// each runtime builtin invokes the hook named after the builtin (e.g. `Stdlib_Add`) with its argument term pointers
+/// and the evaluation state pointer before executing its implementation.
+pub const DEBUGGER_HOOK_MODULE: &str = "Debugger";
+
+/// Name of the debugger hook invoked on entry to each compiled function
+pub const DEBUGGER_ENTER_FUNCTION_HOOK: &str = "enter";
+
+/// Location at which the debugger should pause evaluation
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum WasmBreakpoint {
+    /// Pause on entry to the compiled function identified by the given term hash
+    Function(CompiledFunctionId),
+    /// Pause before invoking the given standard library builtin
+    Builtin(Stdlib),
+}
+
+impl std::hash::Hash for WasmBreakpoint {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Function(function_id) => {
+                state.write_u8(0);
+                function_id.hash(state);
+            }
+            Self::Builtin(target) => {
+                state.write_u8(1);
+                state.write_u32(u32::from(*target));
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for WasmBreakpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Function(function_id) => write!(f, "{}", function_id),
+            Self::Builtin(target) => write!(f, "{}", target.name()),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum WasmDebuggerEvent<'a> {
+    /// A compiled function is about to be executed
+    EnterFunction { function_id: CompiledFunctionId },
+    /// A standard library builtin is about to be invoked with the given operands
+    ///
+    /// Any variadic arguments are collected into a single list term.
+    CallBuiltin {
+        target: Stdlib,
+        args: &'a [ArenaPointer],
+        state: ArenaPointer,
+    },
+}
+
+impl<'a> WasmDebuggerEvent<'a> {
+    pub fn breakpoint(&self) -> WasmBreakpoint {
+        match self {
+            Self::EnterFunction { function_id } => WasmBreakpoint::Function(*function_id),
+            Self::CallBuiltin { target, .. } => WasmBreakpoint::Builtin(*target),
+        }
+    }
+}
+
+/// Snapshot of the interpreter state at the point where evaluation was paused
+pub struct WasmDebuggerFrame<'a> {
+    pub event: WasmDebuggerEvent<'a>,
+    /// Contents of the interpreter linear memory
+    pub heap: &'a [u8],
+}
+
+impl<'a> WasmDebuggerFrame<'a> {
+    /// Inspect the heap term located at the given pointer
+    pub fn term(&self, pointer: ArenaPointer) -> ArenaRef<Term, &'a [u8]> {
+        ArenaRef::new(self.heap, pointer)
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum WasmDebuggerAction {
+    /// Resume evaluation until the next breakpoint is encountered
+    Continue,
+    /// Resume evaluation, pausing again at the next debugger hook
+    Step,
+    /// Abort the current evaluation with a runtime error
+    Abort,
+}
+
+type WasmDebuggerHandler = Box<dyn FnMut(&WasmDebuggerFrame<'_>) -> WasmDebuggerAction + Send>;
+
+/// Debugger that pauses evaluation of a module compiled with debug hooks enabled, whenever a breakpoint is encountered
+/// (or at every hook when single-stepping)
+///
+/// The debugger is a shared handle: breakpoints can be added and removed from outside the interpreter (e.g. between
+/// evaluations) as well as from within the handler callback.
+#[derive(Clone)]
+pub struct WasmDebugger {
+    state: Arc<Mutex<WasmDebuggerState>>,
+}
+
+struct WasmDebuggerState {
+    breakpoints: HashSet<WasmBreakpoint>,
+    stepping: bool,
+    handler: Option<WasmDebuggerHandler>,
+}
+
+impl WasmDebugger {
+    pub fn new(
+        handler: impl FnMut(&WasmDebuggerFrame<'_>) -> WasmDebuggerAction + Send + 'static,
+    ) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(WasmDebuggerState {
+                breakpoints: Default::default(),
+                stepping: false,
+                handler: Some(Box::new(handler)),
+            })),
+        }
+    }
+    pub fn add_breakpoint(&self, breakpoint: WasmBreakpoint) -> bool {
+        self.lock().breakpoints.insert(breakpoint)
+    }
+    pub fn remove_breakpoint(&self, breakpoint: &WasmBreakpoint) -> bool {
+        self.lock().breakpoints.remove(breakpoint)
+    }
+    pub fn clear_breakpoints(&self) {
+        self.lock().breakpoints.clear()
+    }
+    pub fn breakpoints(&self) -> Vec<WasmBreakpoint> {
+        self.lock().breakpoints.iter().copied().collect()
+    }
+    /// Pause evaluation at the next debugger hook, regardless of whether a breakpoint is registered at that location
+    pub fn step(&self) {
+        self.lock().stepping = true;
+    }
+    pub fn is_stepping(&self) -> bool {
+        self.lock().stepping
+    }
+    pub(crate) fn handle_event(
+        &self,
+        event: WasmDebuggerEvent<'_>,
+        heap: &[u8],
+    ) -> Result<(), anyhow::Error> {
+        let handler = {
+            let mut state = self.lock();
+            if !state.stepping && !state.breakpoints.contains(&event.breakpoint()) {
+                return Ok(());
+            }
+            // Release the lock while the handler is running, to allow the handler to modify the debugger state
+            state.handler.take()
+        };
+        let Some(mut handler) = handler else {
+            // The handler is already running further up the call stack
+            return Ok(());
+        };
+        let action = handler(&WasmDebuggerFrame { event, heap });
+        let mut state = self.lock();
+        state.handler = Some(handler);
+        match action {
+            WasmDebuggerAction::Continue => {
+                state.stepping = false;
+                Ok(())
+            }
+            WasmDebuggerAction::Step => {
+                state.stepping = true;
+                Ok(())
+            }
+            WasmDebuggerAction::Abort => {
+                state.stepping = false;
+                Err(anyhow::anyhow!(
+                    "Evaluation aborted by debugger at {}",
+                    event.breakpoint()
+                ))
+            }
+        }
+    }
+    fn lock(&self) -> MutexGuard<'_, WasmDebuggerState> {
+        match self.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hash::TermHashState, stdlib::Add};
+
+    use super::*;
+
+    #[test]
+    fn breakpoints_and_stepping() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let debugger = WasmDebugger::new({
+            let events = Arc::clone(&events);
+            move |frame| {
+                let mut events = events.lock().unwrap();
+                events.push(frame.event.breakpoint());
+                if events.len() == 1 {
+                    WasmDebuggerAction::Step
+                } else {
+                    WasmDebuggerAction::Continue
+                }
+            }
+        });
+        let function_id = CompiledFunctionId::from(TermHashState::from(123u64));
+        let enter_function = WasmDebuggerEvent::EnterFunction { function_id };
+        let call_builtin = WasmDebuggerEvent::CallBuiltin {
+            target: Stdlib::Add(Add),
+            args: &[ArenaPointer::null(), ArenaPointer::null()],
+            state: ArenaPointer::null(),
+        };
+        debugger.handle_event(call_builtin, &[]).unwrap();
+        assert!(events.lock().unwrap().is_empty());
+        assert!(debugger.add_breakpoint(WasmBreakpoint::Function(function_id)));
+        debugger.handle_event(enter_function, &[]).unwrap();
+        assert!(debugger.is_stepping());
+        debugger.handle_event(call_builtin, &[]).unwrap();
+        assert!(!debugger.is_stepping());
+        debugger.handle_event(call_builtin, &[]).unwrap();
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                WasmBreakpoint::Function(function_id),
+                WasmBreakpoint::Builtin(Stdlib::Add(Add)),
+            ]
+        );
+    }
+
+    #[test]
+    fn abort_evaluation() {
+        let debugger = WasmDebugger::new(|_| WasmDebuggerAction::Abort);
+        debugger.step();
+        let function_id = CompiledFunctionId::from(TermHashState::from(123u64));
+        let result = debugger.handle_event(WasmDebuggerEvent::EnterFunction { function_id }, &[]);
+        assert!(result.is_err());
+        assert!(!debugger.is_stepping());
+    }
+}
@@ -1,16 +1,128 @@
 // SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::sync::Arc;
+
 use chrono::{DateTime, NaiveDateTime};
-use wasi_common::WasiCtx;
-use wasmtime::{AsContext, AsContextMut, Caller, Extern, Memory, StoreContext};
+use reflex::{
+    core::{format_float, FloatFormat},
+    regex::compile_regex,
+};
+use reflex_js::stdlib::{parse_js_float, parse_js_int, NumberParseOptions};
+use reflex_json::serialize_canonical_number;
+use wasmtime::{AsContext, AsContextMut, Caller, Extern, Func, Memory, Store, StoreContext};
 
 use crate::{
-    interpreter::{InterpreterError, WasmContextBuilder},
-    term_type::timestamp::UtcTimestamp,
+    interpreter::{InterpreterError, WasmContextBuilder, WasmHostContext},
+    stdlib::{Stdlib, BUILTIN_FALLBACK_MODULE},
+    term_type::timestamp::ZonedTimestamp,
     ArenaPointer, WASM_PAGE_SIZE,
 };
 
+/// Interface implemented by a custom host function import
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum WasmHostImportDeclaration {
+    /// Host implementation of a standard library builtin that was compiled as a host function import (see
+    /// [`crate::cli::compile::WasmCompilerRuntimeOptions::builtin_fallback`])
+    ///
+    /// The function is invoked with the builtin argument term pointers (where any variadic arguments are collected into
+    /// a single list term) and the evaluation state pointer, and must return the result term pointer and dependencies
+    /// term pointer (which can be null).
+    Builtin(Stdlib),
+    /// Arbitrary host function imported by the compiled module from the given import module
+    Function { module: String, name: String },
+}
+
+impl WasmHostImportDeclaration {
+    pub fn module(&self) -> &str {
+        match self {
+            Self::Builtin(_) => BUILTIN_FALLBACK_MODULE,
+            Self::Function { module, .. } => module.as_str(),
+        }
+    }
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Builtin(target) => target.name(),
+            Self::Function { name, .. } => name.as_str(),
+        }
+    }
+}
+
+type WasmHostFuncFactory = Arc<dyn Fn(&mut Store<WasmHostContext>) -> Func + Send + Sync>;
+
+/// Custom host function to be linked into each interpreter instance
+#[derive(Clone)]
+pub struct WasmHostImport {
+    declaration: WasmHostImportDeclaration,
+    factory: WasmHostFuncFactory,
+}
+
+impl WasmHostImport {
+    /// Declare a host function, where the factory creates the function within the store of each interpreter instance
+    /// (e.g. via [`Func::wrap`] or [`Func::new`])
+    pub fn new(
+        declaration: WasmHostImportDeclaration,
+        factory: impl Fn(&mut Store<WasmHostContext>) -> Func + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            declaration,
+            factory: Arc::new(factory),
+        }
+    }
+    pub fn declaration(&self) -> &WasmHostImportDeclaration {
+        &self.declaration
+    }
+}
+
+impl std::fmt::Debug for WasmHostImport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WasmHostImport")
+            .field("declaration", &self.declaration)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Registry of custom host functions made available to compiled programs, allowing embedders to provide native
+/// capabilities beyond the standard runtime imports
+#[derive(Default, Clone, Debug)]
+pub struct WasmHostImports {
+    imports: Vec<WasmHostImport>,
+}
+
+impl WasmHostImports {
+    pub fn register(&mut self, import: WasmHostImport) -> &mut Self {
+        self.imports.push(import);
+        self
+    }
+    pub fn with_import(mut self, import: WasmHostImport) -> Self {
+        self.register(import);
+        self
+    }
+    pub fn is_empty(&self) -> bool {
+        self.imports.is_empty()
+    }
+    pub fn iter(&self) -> impl Iterator<Item = &WasmHostImport> + '_ {
+        self.imports.iter()
+    }
+}
+
+pub fn add_wasm_host_imports(
+    builder: WasmContextBuilder,
+    imports: &WasmHostImports,
+) -> Result<WasmContextBuilder, InterpreterError> {
+    imports.iter().try_fold(builder, |builder, import| {
+        let factory = |store: &mut Store<WasmHostContext>| (import.factory)(store);
+        match &import.declaration {
+            WasmHostImportDeclaration::Builtin(target) => {
+                builder.add_builtin_func(*target, factory)
+            }
+            WasmHostImportDeclaration::Function { module, name } => {
+                builder.add_func(module, name, factory)
+            }
+        }
+    })
+}
+
 pub fn add_wasm_runtime_imports(
     builder: WasmContextBuilder,
     memory_name: &'static str,
@@ -27,7 +139,7 @@ pub fn add_wasm_runtime_imports(
         .add_import(
             "Date",
             "parse",
-            |mut caller: Caller<'_, WasiCtx>, offset: u32, length: u32| -> i64 {
+            |mut caller: Caller<'_, WasmHostContext>, offset: u32, length: u32| -> i64 {
                 let timestamp = caller
                     .get_export(memory_name)
                     .and_then(|export| match export {
@@ -41,14 +153,38 @@ pub fn add_wasm_runtime_imports(
                             .ok()
                             .and_then(|timestamp| parse_string_timestamp(timestamp))
                     });
-                timestamp.unwrap_or(-1)
+                timestamp.map(|(millis, _)| millis).unwrap_or(-1)
+            },
+        )?
+        .add_import(
+            "Date",
+            "parseOffset",
+            |mut caller: Caller<'_, WasmHostContext>, offset: u32, length: u32| -> i32 {
+                let timestamp = caller
+                    .get_export(memory_name)
+                    .and_then(|export| match export {
+                        Extern::Memory(memory) => Some(memory),
+                        _ => None,
+                    })
+                    .and_then(|memory| {
+                        let slice =
+                            read_linear_memory_slice(&memory, caller.as_context(), offset, length);
+                        std::str::from_utf8(slice)
+                            .ok()
+                            .and_then(parse_string_timestamp)
+                    });
+                timestamp.map(|(_, offset)| offset).unwrap_or(0)
             },
         )?
         .add_import(
             "Date",
             "toISOString",
-            |mut caller: Caller<'_, WasiCtx>, timestamp: i64, dest_pointer: u32| -> u32 {
-                let formatted = format!("{}", UtcTimestamp(timestamp));
+            |mut caller: Caller<'_, WasmHostContext>,
+             timestamp: i64,
+             offset: i32,
+             dest_pointer: u32|
+             -> u32 {
+                let formatted = format!("{}", ZonedTimestamp(timestamp, offset));
                 let formatted_bytes = formatted.as_bytes();
                 match get_linear_memory(&mut caller, memory_name).and_then(|mut memory| {
                     write_linear_memory_bytes(
@@ -71,11 +207,211 @@ pub fn add_wasm_runtime_imports(
                 }
             },
         )?
+        .add_import(
+            "Number",
+            "parseInt",
+            |mut caller: Caller<'_, WasmHostContext>,
+             offset: u32,
+             length: u32,
+             radix: u32,
+             strict: u32|
+             -> f64 {
+                let options = NumberParseOptions {
+                    strict: strict != 0,
+                };
+                get_linear_memory(&mut caller, memory_name)
+                    .and_then(|memory| {
+                        let slice =
+                            read_linear_memory_slice(&memory, caller.as_context(), offset, length);
+                        std::str::from_utf8(slice)
+                            .ok()
+                            .map(|value| parse_js_int(value, Some(radix), options))
+                    })
+                    .unwrap_or(f64::NAN)
+            },
+        )?
+        .add_import(
+            "Number",
+            "parseFloat",
+            |mut caller: Caller<'_, WasmHostContext>,
+             offset: u32,
+             length: u32,
+             strict: u32|
+             -> f64 {
+                let options = NumberParseOptions {
+                    strict: strict != 0,
+                };
+                get_linear_memory(&mut caller, memory_name)
+                    .and_then(|memory| {
+                        let slice =
+                            read_linear_memory_slice(&memory, caller.as_context(), offset, length);
+                        std::str::from_utf8(slice)
+                            .ok()
+                            .map(|value| parse_js_float(value, options))
+                    })
+                    .unwrap_or(f64::NAN)
+            },
+        )?
+        .add_import(
+            "Regex",
+            "captureCount",
+            |mut caller: Caller<'_, WasmHostContext>,
+             pattern_offset: u32,
+             pattern_length: u32|
+             -> i32 {
+                get_linear_memory(&mut caller, memory_name)
+                    .and_then(|memory| {
+                        let pattern = read_linear_memory_slice(
+                            &memory,
+                            caller.as_context(),
+                            pattern_offset,
+                            pattern_length,
+                        );
+                        std::str::from_utf8(pattern)
+                            .ok()
+                            .and_then(|pattern| compile_regex(pattern).ok())
+                    })
+                    .map(|regex| regex.captures_len() as i32)
+                    .unwrap_or(-1)
+            },
+        )?
+        .add_import(
+            "Regex",
+            "capture",
+            |mut caller: Caller<'_, WasmHostContext>,
+             pattern_offset: u32,
+             pattern_length: u32,
+             target_offset: u32,
+             target_length: u32,
+             index: u32|
+             -> i64 {
+                get_linear_memory(&mut caller, memory_name)
+                    .and_then(|memory| {
+                        let pattern = read_linear_memory_slice(
+                            &memory,
+                            caller.as_context(),
+                            pattern_offset,
+                            pattern_length,
+                        );
+                        let target = read_linear_memory_slice(
+                            &memory,
+                            caller.as_context(),
+                            target_offset,
+                            target_length,
+                        );
+                        let regex = std::str::from_utf8(pattern)
+                            .ok()
+                            .and_then(|pattern| compile_regex(pattern).ok())?;
+                        let target = std::str::from_utf8(target).ok()?;
+                        regex
+                            .captures(target)
+                            .and_then(|captures| captures.get(index as usize))
+                            .map(|capture| {
+                                ((capture.start() as i64) << 32) | (capture.end() as i64)
+                            })
+                    })
+                    .unwrap_or(-1)
+            },
+        )?
+        .add_import(
+            "Regex",
+            "replace",
+            |mut caller: Caller<'_, WasmHostContext>,
+             pattern_offset: u32,
+             pattern_length: u32,
+             target_offset: u32,
+             target_length: u32,
+             replacement_offset: u32,
+             replacement_length: u32,
+             dest_pointer: u32|
+             -> u32 {
+                let replaced = get_linear_memory(&mut caller, memory_name).and_then(|memory| {
+                    let pattern = read_linear_memory_slice(
+                        &memory,
+                        caller.as_context(),
+                        pattern_offset,
+                        pattern_length,
+                    );
+                    let target = read_linear_memory_slice(
+                        &memory,
+                        caller.as_context(),
+                        target_offset,
+                        target_length,
+                    );
+                    let replacement = read_linear_memory_slice(
+                        &memory,
+                        caller.as_context(),
+                        replacement_offset,
+                        replacement_length,
+                    );
+                    let regex = std::str::from_utf8(pattern)
+                        .ok()
+                        .and_then(|pattern| compile_regex(pattern).ok())?;
+                    let target = std::str::from_utf8(target).ok()?;
+                    let replacement = std::str::from_utf8(replacement).ok()?;
+                    Some(String::from(regex.replacen(target, 1, replacement)))
+                });
+                let replaced = match replaced {
+                    Some(replaced) => replaced,
+                    None => return u32::from(ArenaPointer::null()),
+                };
+                let replaced_bytes = replaced.as_bytes();
+                match get_linear_memory(&mut caller, memory_name).and_then(|mut memory| {
+                    write_linear_memory_bytes(
+                        &mut memory,
+                        &mut caller,
+                        dest_pointer,
+                        replaced_bytes,
+                    )
+                    .and_then(|_| {
+                        update_linear_memory_allocator_offset(
+                            &mut memory,
+                            &mut caller,
+                            dest_pointer + replaced_bytes.len() as u32,
+                        )
+                    })
+                    .ok()
+                }) {
+                    Some(_) => replaced_bytes.len() as u32,
+                    None => u32::from(ArenaPointer::null()),
+                }
+            },
+        )?
         .add_import(
             "Number",
             "toString",
-            |mut caller: Caller<'_, WasiCtx>, value: f64, dest_pointer: u32| -> u32 {
-                let formatted = format!("{}", value);
+            |mut caller: Caller<'_, WasmHostContext>, value: f64, dest_pointer: u32| -> u32 {
+                let formatted = format_float(value, FloatFormat::default());
+                let formatted_bytes = formatted.as_bytes();
+                match get_linear_memory(&mut caller, memory_name).and_then(|mut memory| {
+                    write_linear_memory_bytes(
+                        &mut memory,
+                        &mut caller,
+                        dest_pointer,
+                        formatted_bytes,
+                    )
+                    .and_then(|_| {
+                        update_linear_memory_allocator_offset(
+                            &mut memory,
+                            &mut caller,
+                            dest_pointer + formatted_bytes.len() as u32,
+                        )
+                    })
+                    .ok()
+                }) {
+                    Some(_) => formatted_bytes.len() as u32,
+                    None => u32::from(ArenaPointer::null()),
+                }
+            },
+        )?
+        .add_import(
+            "Number",
+            "toCanonicalString",
+            |mut caller: Caller<'_, WasmHostContext>, value: f64, dest_pointer: u32| -> u32 {
+                let formatted = match serialize_canonical_number(value) {
+                    Ok(formatted) => formatted,
+                    Err(_) => return u32::from(ArenaPointer::null()),
+                };
                 let formatted_bytes = formatted.as_bytes();
                 match get_linear_memory(&mut caller, memory_name).and_then(|mut memory| {
                     write_linear_memory_bytes(
@@ -132,7 +468,7 @@ pub fn add_wasm_runtime_imports(
         .add_import("Math", "tanh", |value: f64| -> f64 { value.tanh() })
 }
 
-fn get_linear_memory(caller: &mut Caller<WasiCtx>, memory_name: &str) -> Option<Memory> {
+fn get_linear_memory(caller: &mut Caller<WasmHostContext>, memory_name: &str) -> Option<Memory> {
     caller
         .get_export(memory_name)
         .and_then(|export| match export {
@@ -143,7 +479,7 @@ fn get_linear_memory(caller: &mut Caller<WasiCtx>, memory_name: &str) -> Option<
 
 fn write_linear_memory_bytes(
     memory: &mut Memory,
-    caller: &mut Caller<WasiCtx>,
+    caller: &mut Caller<WasmHostContext>,
     dest_pointer: u32,
     bytes: &[u8],
 ) -> Result<(), ()> {
@@ -162,7 +498,7 @@ fn write_linear_memory_bytes(
 
 fn update_linear_memory_allocator_offset(
     memory: &mut Memory,
-    caller: &mut Caller<WasiCtx>,
+    caller: &mut Caller<WasmHostContext>,
     offset: u32,
 ) -> Result<(), ()> {
     // Update the bump allocator offset at heap pointer address 0 to reflect the updated heap size
@@ -208,15 +544,18 @@ fn pad_to_next(value: usize, interval: usize) -> usize {
     1 + (value.saturating_sub(1) / interval)
 }
 
-fn parse_string_timestamp(timestamp: &str) -> Option<i64> {
+fn parse_string_timestamp(timestamp: &str) -> Option<(i64, i32)> {
     None.or_else(|| {
-        DateTime::parse_from_rfc3339(timestamp)
-            .ok()
-            .map(|date| date.timestamp_millis())
+        DateTime::parse_from_rfc3339(timestamp).ok().map(|date| {
+            (
+                date.timestamp_millis(),
+                date.offset().local_minus_utc() / 60,
+            )
+        })
     })
     .or_else(|| {
         NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%dT%H:%M:%S%.f")
             .ok()
-            .map(|date| date.timestamp_millis())
+            .map(|date| (date.timestamp_millis(), 0))
     })
 }
@@ -12,28 +12,29 @@ use std::{
 use reflex::{
     core::{
         ApplicationTermType, Arity, BooleanTermType, BuiltinTermType, CompiledFunctionTermType,
-        ConditionListType, ConditionType, ConstructorTermType, EffectTermType, Expression,
-        ExpressionFactory, ExpressionListType, FloatTermType, FloatValue, HashmapTermType,
-        HashsetTermType, HeapAllocator, InstructionPointer, IntTermType, IntValue, LambdaTermType,
-        LazyResultTermType, LetTermType, ListTermType, PartialApplicationTermType, RecordTermType,
-        RecursiveTermType, RefType, SignalTermType, SignalType, StackOffset, StringTermType,
-        StringValue, StructPrototypeType, SymbolId, SymbolTermType, TimestampTermType,
-        TimestampValue, VariableTermType,
+        ConditionListType, ConditionType, ConstructorTermType, BytesTermType, DurationTermType, DurationValue,
+        EffectTermType, Expression, ExpressionFactory, ExpressionListType, FloatTermType,
+        FloatValue, HashmapTermType, HashsetTermType, HeapAllocator, InstructionPointer,
+        IntTermType, IntValue, LambdaTermType, LazyResultTermType, LetTermType, ListTermType,
+        PartialApplicationTermType, RecordTermType, RecursiveTermType, RefType, RegexTermType, SensitiveTermType, SignalTermType,
+        SignalType, StackOffset, StringTermType, StringValue, StructPrototypeType, SymbolId,
+        SymbolTermType, TimestampTermType, TimestampValue, TimezoneOffset, VariableTermType,
     },
-    hash::HashId,
+    hash::{HashId, IntMap},
 };
 use reflex_utils::WithExactSizeIterator;
 
 use crate::{
     self as reflex_wasm,
     allocator::{Arena, ArenaAllocator, ArenaMut},
+    gc::ArenaRemapping,
     hash::TermSize,
     term_type::{
         ApplicationTerm, BooleanTerm, BuiltinTerm, ConditionTerm, ConstructorTerm, CustomCondition,
-        EffectTerm, ErrorCondition, FloatTerm, HashmapTerm, HashsetTerm, IntTerm, LambdaTerm,
-        LazyResultTerm, LetTerm, ListTerm, NilTerm, PartialTerm, PendingCondition, RecordTerm,
-        SignalTerm, StringTerm, SymbolTerm, TermType, TermTypeDiscriminants, TimestampTerm,
-        TreeTerm, TypedTerm, VariableTerm, WasmExpression,
+        BytesTerm, DurationTerm, EffectTerm, ErrorCondition, FloatTerm, HashmapTerm, HashsetTerm, IntTerm,
+        LambdaTerm, LazyResultTerm, LetTerm, ListTerm, NilTerm, PartialTerm, PendingCondition,
+        RecordTerm, RecursiveTerm, RegexTerm, SensitiveTerm, SignalTerm, StringTerm, SymbolTerm, TermType, TermTypeDiscriminants,
+        TimestampTerm, TreeTerm, TypedTerm, VariableTerm, WasmExpression,
     },
     ArenaPointer, ArenaRef, FunctionIndex, Term,
 };
@@ -43,6 +44,42 @@ pub struct WasmTermFactory<A: Arena> {
     arena: Rc<RefCell<A>>,
 }
 
+/// Memoized conversion results used to preserve structural sharing when converting between host expressions and
+/// arena terms
+///
+/// Cached arena pointers are only valid for as long as the underlying arena is not garbage-collected or reset, so the
+/// cache must be cleared whenever this happens.
+pub struct WasmTermConversionCache<T: Expression> {
+    imported: IntMap<HashId, ArenaPointer>,
+    exported: HashMap<ArenaPointer, T>,
+}
+
+impl<T: Expression> Default for WasmTermConversionCache<T> {
+    fn default() -> Self {
+        Self {
+            imported: Default::default(),
+            exported: Default::default(),
+        }
+    }
+}
+
+impl<T: Expression> WasmTermConversionCache<T> {
+    pub fn len(&self) -> usize {
+        self.imported.len() + self.exported.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.imported.is_empty() && self.exported.is_empty()
+    }
+    pub fn clear(&mut self) {
+        self.imported.clear();
+        self.exported.clear();
+    }
+    /// Retrieve the arena pointer of a previously-imported host expression with the given hash
+    pub fn imported(&self, hash: HashId) -> Option<ArenaPointer> {
+        self.imported.get(&hash).copied()
+    }
+}
+
 impl<A: Arena> From<Rc<RefCell<A>>> for WasmTermFactory<A> {
     fn from(value: Rc<RefCell<A>>) -> Self {
         Self { arena: value }
@@ -59,6 +96,35 @@ where
         expression: &T,
         factory: &impl ExpressionFactory<T>,
     ) -> Result<WasmExpression<Self>, T>
+    where
+        T::Builtin: Into<crate::stdlib::Stdlib>,
+    {
+        self.import_with_cache(expression, factory, &mut WasmTermConversionCache::default())
+    }
+    /// Import the given expression into the arena, reusing any previously-imported subterms from the provided cache
+    pub fn import_with_cache<T: Expression>(
+        &self,
+        expression: &T,
+        factory: &impl ExpressionFactory<T>,
+        cache: &mut WasmTermConversionCache<T>,
+    ) -> Result<WasmExpression<Self>, T>
+    where
+        T::Builtin: Into<crate::stdlib::Stdlib>,
+    {
+        let hash = expression.id();
+        if let Some(pointer) = cache.imported.get(&hash).copied() {
+            return Ok(WasmExpression::new(self.clone(), pointer));
+        }
+        let result = self.import_term(expression, factory, cache)?;
+        cache.imported.insert(hash, result.as_pointer());
+        Ok(result)
+    }
+    fn import_term<T: Expression>(
+        &self,
+        expression: &T,
+        factory: &impl ExpressionFactory<T>,
+        cache: &mut WasmTermConversionCache<T>,
+    ) -> Result<WasmExpression<Self>, T>
     where
         T::Builtin: Into<crate::stdlib::Stdlib>,
     {
@@ -76,52 +142,69 @@ where
         } else if let Some(term) = factory.match_symbol_term(expression) {
             Ok(self.create_symbol_term(term.id()))
         } else if let Some(term) = factory.match_timestamp_term(expression) {
-            Ok(self.create_timestamp_term(term.millis()))
+            Ok(self.create_timestamp_term(term.millis(), term.offset()))
+        } else if let Some(term) = factory.match_duration_term(expression) {
+            Ok(self.create_duration_term(term.millis()))
+        } else if let Some(term) = factory.match_bytes_term(expression) {
+            Ok(self.create_bytes_term(term.value().deref()))
+        } else if let Some(term) = factory.match_regex_term(expression) {
+            let pattern = self.create_string(term.pattern().as_deref().as_str());
+            Ok(self.create_regex_term(pattern))
+        } else if let Some(term) = factory.match_sensitive_term(expression) {
+            let value = self.import_with_cache(term.value().as_deref(), factory, cache)?;
+            Ok(self.create_sensitive_term(value))
         } else if let Some(term) = factory.match_variable_term(expression) {
             Ok(self.create_variable_term(term.offset()))
         } else if let Some(term) = factory.match_effect_term(expression) {
-            let condition = self.import_condition(term.condition().as_deref().deref(), factory)?;
+            let condition = self.import_condition_with_cache(
+                term.condition().as_deref().deref(),
+                factory,
+                cache,
+            )?;
             Ok(self.create_effect_term(condition))
         } else if let Some(term) = factory.match_let_term(expression) {
-            let initializer = self.import(term.initializer().as_deref(), factory)?;
-            let body = self.import(term.body().as_deref(), factory)?;
+            let initializer =
+                self.import_with_cache(term.initializer().as_deref(), factory, cache)?;
+            let body = self.import_with_cache(term.body().as_deref(), factory, cache)?;
             Ok(self.create_let_term(initializer, body))
         } else if let Some(term) = factory.match_lambda_term(expression) {
             let num_args = term.num_args();
-            let body = self.import(term.body().as_deref(), factory)?;
+            let body = self.import_with_cache(term.body().as_deref(), factory, cache)?;
             Ok(self.create_lambda_term(num_args, body))
         } else if let Some(term) = factory.match_lazy_result_term(expression) {
-            let value = self.import(term.value().as_deref(), factory)?;
+            let value = self.import_with_cache(term.value().as_deref(), factory, cache)?;
             let conditions = term
                 .dependencies()
                 .as_deref()
                 .iter()
-                .map(|condition| self.import_condition(condition.as_deref().deref(), factory))
+                .map(|condition| {
+                    self.import_condition_with_cache(condition.as_deref().deref(), factory, cache)
+                })
                 .collect::<Result<Vec<_>, _>>()?;
             let dependencies = self.create_signal_list(conditions);
             Ok(self.create_lazy_result_term(value, dependencies))
         } else if let Some(term) = factory.match_application_term(expression) {
-            let target = self.import(term.target().as_deref(), factory)?;
+            let target = self.import_with_cache(term.target().as_deref(), factory, cache)?;
             let args = term
                 .args()
                 .as_deref()
                 .iter()
-                .map(|arg| self.import(arg.as_deref(), factory))
+                .map(|arg| self.import_with_cache(arg.as_deref(), factory, cache))
                 .collect::<Result<Vec<_>, _>>()?;
             let args = self.create_list(args);
             Ok(self.create_application_term(target, args))
         } else if let Some(term) = factory.match_partial_application_term(expression) {
-            let target = self.import(term.target().as_deref(), factory)?;
+            let target = self.import_with_cache(term.target().as_deref(), factory, cache)?;
             let args = term
                 .args()
                 .as_deref()
                 .iter()
-                .map(|arg| self.import(arg.as_deref(), factory))
+                .map(|arg| self.import_with_cache(arg.as_deref(), factory, cache))
                 .collect::<Result<Vec<_>, _>>()?;
             let args = self.create_list(args);
             Ok(self.create_partial_application_term(target, args))
         } else if let Some(term) = factory.match_recursive_term(expression) {
-            let body = self.import(term.factory().as_deref(), factory)?;
+            let body = self.import_with_cache(term.factory().as_deref(), factory, cache)?;
             Ok(self.create_recursive_term(body))
         } else if let Some(term) = factory.match_builtin_term(expression) {
             Ok(self.create_builtin_term(term.target()))
@@ -146,7 +229,7 @@ where
                 .keys()
                 .as_deref()
                 .iter()
-                .map(|item| self.import(item.as_deref(), factory))
+                .map(|item| self.import_with_cache(item.as_deref(), factory, cache))
                 .collect::<Result<Vec<_>, _>>()?;
             let keys = self.create_list(keys);
             let prototype = self.create_struct_prototype(keys);
@@ -154,7 +237,7 @@ where
                 .values()
                 .as_deref()
                 .iter()
-                .map(|item| self.import(item.as_deref(), factory))
+                .map(|item| self.import_with_cache(item.as_deref(), factory, cache))
                 .collect::<Result<Vec<_>, _>>()?;
             let values = self.create_list(values);
             Ok(self.create_record_term(prototype, values))
@@ -165,32 +248,28 @@ where
                 .keys()
                 .as_deref()
                 .iter()
-                .map(|key| self.import(key.as_deref(), factory))
+                .map(|key| self.import_with_cache(key.as_deref(), factory, cache))
                 .collect::<Result<Vec<_>, _>>()?;
             let keys = self.create_list(keys);
             let prototype = self.create_struct_prototype(keys);
+            // WASM constructors always reject mismatched arguments, so there is no validation flag to carry across
             Ok(self.create_constructor_term(prototype))
         } else if let Some(term) = factory.match_list_term(expression) {
             let items = term
                 .items()
                 .as_deref()
                 .iter()
-                .map(|key| self.import(key.as_deref(), factory))
+                .map(|key| self.import_with_cache(key.as_deref(), factory, cache))
                 .collect::<Result<Vec<_>, _>>()?;
             let items = self.create_list(items);
             Ok(self.create_list_term(items))
         } else if let Some(term) = factory.match_hashmap_term(expression) {
-            let keys = term
+            let entries = term
                 .keys()
-                .map(|term| self.import(term.as_deref(), factory));
-            let values = term
-                .values()
-                .map(|term| self.import(term.as_deref(), factory));
-            let entries = keys
-                .zip(values)
+                .zip(term.values())
                 .map(|(key, value)| {
-                    let key = key?;
-                    let value = value?;
+                    let key = self.import_with_cache(key.as_deref(), factory, cache)?;
+                    let value = self.import_with_cache(value.as_deref(), factory, cache)?;
                     Ok((key, value))
                 })
                 .collect::<Result<Vec<_>, _>>()?;
@@ -198,7 +277,7 @@ where
         } else if let Some(term) = factory.match_hashset_term(expression) {
             let values = term
                 .values()
-                .map(|term| self.import(term.as_deref(), factory))
+                .map(|term| self.import_with_cache(term.as_deref(), factory, cache))
                 .collect::<Result<Vec<_>, _>>()?;
             Ok(self.create_hashset_term(values))
         } else if let Some(term) = factory.match_signal_term(expression) {
@@ -206,7 +285,9 @@ where
                 .signals()
                 .as_deref()
                 .iter()
-                .map(|condition| self.import_condition(condition.as_deref().deref(), factory))
+                .map(|condition| {
+                    self.import_condition_with_cache(condition.as_deref().deref(), factory, cache)
+                })
                 .collect::<Result<Vec<_>, _>>()?;
             let conditions = self.create_signal_list(conditions);
             Ok(self.create_signal_term(conditions))
@@ -220,6 +301,40 @@ where
         factory: &impl ExpressionFactory<T>,
         allocator: &impl HeapAllocator<T>,
         indirect_call_arity: &HashMap<FunctionIndex, Arity>,
+    ) -> Result<T, WasmExpression<Rc<RefCell<A>>>> {
+        self.export_with_cache(
+            expression,
+            factory,
+            allocator,
+            indirect_call_arity,
+            &mut WasmTermConversionCache::default(),
+        )
+    }
+    /// Export the given arena term as a host expression, reusing any previously-exported subterms from the provided cache
+    pub fn export_with_cache<T: Expression>(
+        &self,
+        expression: &WasmExpression<Rc<RefCell<A>>>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        indirect_call_arity: &HashMap<FunctionIndex, Arity>,
+        cache: &mut WasmTermConversionCache<T>,
+    ) -> Result<T, WasmExpression<Rc<RefCell<A>>>> {
+        let pointer = expression.as_pointer();
+        if let Some(result) = cache.exported.get(&pointer) {
+            return Ok(result.clone());
+        }
+        let result =
+            self.export_term(expression, factory, allocator, indirect_call_arity, cache)?;
+        cache.exported.insert(pointer, result.clone());
+        Ok(result)
+    }
+    fn export_term<T: Expression>(
+        &self,
+        expression: &WasmExpression<Rc<RefCell<A>>>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        indirect_call_arity: &HashMap<FunctionIndex, Arity>,
+        cache: &mut WasmTermConversionCache<T>,
     ) -> Result<T, WasmExpression<Rc<RefCell<A>>>> {
         if let Some(term) = expression.as_nil_term() {
             let _term = term.as_inner();
@@ -234,77 +349,129 @@ where
             let term = term.as_inner();
             Ok(factory.create_float_term(term.value()))
         } else if let Some(term) = expression.as_string_term() {
-            let value = allocator.create_string(term.value().as_deref().as_str());
+            let value = term.read_str(|value| allocator.create_string(value));
             Ok(factory.create_string_term(value))
         } else if let Some(term) = expression.as_symbol_term() {
             Ok(factory.create_symbol_term(term.id()))
         } else if let Some(term) = expression.as_timestamp_term() {
-            Ok(factory.create_timestamp_term(term.millis()))
+            Ok(factory.create_timestamp_term(term.millis(), term.offset()))
+        } else if let Some(term) = expression.as_duration_term() {
+            Ok(factory.create_duration_term(term.millis()))
+        } else if let Some(term) = expression.as_bytes_term() {
+            Ok(factory.create_bytes_term(term.value().deref()))
+        } else if let Some(term) = expression.as_regex_term() {
+            let pattern = term
+                .as_inner()
+                .pattern()
+                .read_str(|value| allocator.create_string(value));
+            Ok(factory.create_regex_term(pattern))
+        } else if let Some(term) = expression.as_sensitive_term() {
+            let term = term.as_inner();
+            let value = self.export_with_cache(
+                term.value().as_deref(),
+                factory,
+                allocator,
+                indirect_call_arity,
+                cache,
+            )?;
+            Ok(factory.create_sensitive_term(value))
         } else if let Some(term) = expression.as_variable_term() {
             let term = term.as_inner();
             Ok(factory.create_variable_term(term.offset()))
         } else if let Some(term) = expression.as_effect_term() {
             let term = term.as_inner();
-            let condition = self.export_condition(
+            let condition = self.export_condition_with_cache(
                 term.condition().as_deref(),
                 factory,
                 allocator,
                 indirect_call_arity,
+                cache,
             )?;
             Ok(factory.create_effect_term(condition))
         } else if let Some(term) = expression.as_let_term() {
             let term = term.as_inner();
-            let initializer = self.export(
+            let initializer = self.export_with_cache(
                 term.initializer().as_deref(),
                 factory,
                 allocator,
                 indirect_call_arity,
+                cache,
             )?;
-            let body = self.export(
+            let body = self.export_with_cache(
                 term.body().as_deref(),
                 factory,
                 allocator,
                 indirect_call_arity,
+                cache,
             )?;
             Ok(factory.create_let_term(initializer, body))
+        } else if let Some(term) = expression.as_recursive_term() {
+            let term = term.as_inner();
+            let body = self.export_with_cache(
+                term.factory().as_deref(),
+                factory,
+                allocator,
+                indirect_call_arity,
+                cache,
+            )?;
+            Ok(factory.create_recursive_term(body))
         } else if let Some(term) = expression.as_lambda_term() {
             let num_args = term.num_args();
-            let body = self.export(
+            let body = self.export_with_cache(
                 term.body().as_deref(),
                 factory,
                 allocator,
                 indirect_call_arity,
+                cache,
             )?;
             Ok(factory.create_lambda_term(num_args, body))
         } else if let Some(term) = expression.as_application_term() {
             let term = term.as_inner();
-            let target = self.export(
+            let target = self.export_with_cache(
                 term.target().as_deref(),
                 factory,
                 allocator,
                 indirect_call_arity,
+                cache,
             )?;
             let args = term
                 .args()
                 .as_deref()
                 .iter()
-                .map(|arg| self.export(arg.as_deref(), factory, allocator, indirect_call_arity))
+                .map(|arg| {
+                    self.export_with_cache(
+                        arg.as_deref(),
+                        factory,
+                        allocator,
+                        indirect_call_arity,
+                        cache,
+                    )
+                })
                 .collect::<Result<Vec<_>, _>>()?;
             let args = allocator.create_list(args);
             Ok(factory.create_application_term(target, args))
         } else if let Some(term) = expression.as_partial_term() {
             let term = term.as_inner();
-            let target = self.export(
+            let target = self.export_with_cache(
                 term.target().as_deref(),
                 factory,
                 allocator,
                 indirect_call_arity,
+                cache,
             )?;
             let args = term
                 .args()
                 .as_deref()
                 .iter()
-                .map(|arg| self.export(arg.as_deref(), factory, allocator, indirect_call_arity))
+                .map(|arg| {
+                    self.export_with_cache(
+                        arg.as_deref(),
+                        factory,
+                        allocator,
+                        indirect_call_arity,
+                        cache,
+                    )
+                })
                 .collect::<Result<Vec<_>, _>>()?;
             let args = allocator.create_list(args);
             Ok(factory.create_partial_application_term(target, args))
@@ -340,7 +507,15 @@ where
                 .keys()
                 .as_deref()
                 .iter()
-                .map(|key| self.export(key.as_deref(), factory, allocator, indirect_call_arity))
+                .map(|key| {
+                    self.export_with_cache(
+                        key.as_deref(),
+                        factory,
+                        allocator,
+                        indirect_call_arity,
+                        cache,
+                    )
+                })
                 .collect::<Result<Vec<_>, _>>()?;
             let keys = allocator.create_list(keys);
             let prototype = allocator.create_struct_prototype(keys);
@@ -348,7 +523,15 @@ where
                 .values()
                 .as_deref()
                 .iter()
-                .map(|key| self.export(key.as_deref(), factory, allocator, indirect_call_arity))
+                .map(|key| {
+                    self.export_with_cache(
+                        key.as_deref(),
+                        factory,
+                        allocator,
+                        indirect_call_arity,
+                        cache,
+                    )
+                })
                 .collect::<Result<Vec<_>, _>>()?;
             let values = allocator.create_list(values);
             Ok(factory.create_record_term(prototype, values))
@@ -360,7 +543,15 @@ where
                 .keys()
                 .as_deref()
                 .iter()
-                .map(|key| self.export(key.as_deref(), factory, allocator, indirect_call_arity))
+                .map(|key| {
+                    self.export_with_cache(
+                        key.as_deref(),
+                        factory,
+                        allocator,
+                        indirect_call_arity,
+                        cache,
+                    )
+                })
                 .collect::<Result<Vec<_>, _>>()?;
             let keys = allocator.create_list(keys);
             let prototype = allocator.create_struct_prototype(keys);
@@ -370,23 +561,62 @@ where
                 .items()
                 .as_deref()
                 .iter()
-                .map(|key| self.export(key.as_deref(), factory, allocator, indirect_call_arity))
+                .map(|key| {
+                    self.export_with_cache(
+                        key.as_deref(),
+                        factory,
+                        allocator,
+                        indirect_call_arity,
+                        cache,
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let items = allocator.create_list(items);
+            Ok(factory.create_list_term(items))
+        } else if let Some(term) = expression.as_list_view_term() {
+            // List views share the storage of their source list within the arena, so the host list must be populated
+            // with the items from the relevant region of the source list
+            let items = term
+                .as_inner()
+                .iter()
+                .map(|item| {
+                    self.export_with_cache(&item, factory, allocator, indirect_call_arity, cache)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let items = allocator.create_list(items);
+            Ok(factory.create_list_term(items))
+        } else if let Some(term) = expression.as_list_rope_term() {
+            // List ropes share the storage of their underlying segments within the arena, so the host list must be
+            // populated with the combined items from all the rope segments
+            let items = term
+                .as_inner()
+                .iter()
+                .map(|item| {
+                    self.export_with_cache(&item, factory, allocator, indirect_call_arity, cache)
+                })
                 .collect::<Result<Vec<_>, _>>()?;
             let items = allocator.create_list(items);
             Ok(factory.create_list_term(items))
         } else if let Some(term) = expression.as_hashmap_term() {
             let term = term.as_inner();
-            let keys = term
+            let entries = term
                 .keys()
-                .map(|term| self.export(term.as_deref(), factory, allocator, indirect_call_arity));
-            let values = term
-                .values()
-                .map(|term| self.export(term.as_deref(), factory, allocator, indirect_call_arity));
-            let entries = keys
-                .zip(values)
+                .zip(term.values())
                 .map(|(key, value)| {
-                    let key = key?;
-                    let value = value?;
+                    let key = self.export_with_cache(
+                        key.as_deref(),
+                        factory,
+                        allocator,
+                        indirect_call_arity,
+                        cache,
+                    )?;
+                    let value = self.export_with_cache(
+                        value.as_deref(),
+                        factory,
+                        allocator,
+                        indirect_call_arity,
+                        cache,
+                    )?;
                     Ok((key, value))
                 })
                 .collect::<Result<Vec<_>, WasmExpression<Rc<RefCell<A>>>>>()?;
@@ -395,7 +625,15 @@ where
             let term = term.as_inner();
             let values = term
                 .values()
-                .map(|term| self.export(term.as_deref(), factory, allocator, indirect_call_arity))
+                .map(|term| {
+                    self.export_with_cache(
+                        term.as_deref(),
+                        factory,
+                        allocator,
+                        indirect_call_arity,
+                        cache,
+                    )
+                })
                 .collect::<Result<Vec<_>, _>>()?;
             Ok(factory.create_hashset_term(values))
         } else if let Some(term) = expression.as_signal_term() {
@@ -405,11 +643,12 @@ where
                 .as_deref()
                 .iter()
                 .map(|condition| {
-                    self.export_condition(
+                    self.export_condition_with_cache(
                         condition.as_deref(),
                         factory,
                         allocator,
                         indirect_call_arity,
+                        cache,
                     )
                 })
                 .collect::<Result<Vec<_>, WasmExpression<Rc<RefCell<A>>>>>()?;
@@ -424,6 +663,21 @@ where
         condition: &T::Signal,
         factory: &impl ExpressionFactory<T>,
     ) -> Result<ArenaRef<TypedTerm<ConditionTerm>, Self>, T>
+    where
+        T::Builtin: Into<crate::stdlib::Stdlib>,
+    {
+        self.import_condition_with_cache(
+            condition,
+            factory,
+            &mut WasmTermConversionCache::default(),
+        )
+    }
+    pub fn import_condition_with_cache<T: Expression>(
+        &self,
+        condition: &T::Signal,
+        factory: &impl ExpressionFactory<T>,
+        cache: &mut WasmTermConversionCache<T>,
+    ) -> Result<ArenaRef<TypedTerm<ConditionTerm>, Self>, T>
     where
         T::Builtin: Into<crate::stdlib::Stdlib>,
     {
@@ -433,9 +687,9 @@ where
                 payload,
                 token,
             } => {
-                let effect_type = self.import(&effect_type, factory)?;
-                let payload = self.import(&payload, factory)?;
-                let token = self.import(&token, factory)?;
+                let effect_type = self.import_with_cache(&effect_type, factory, cache)?;
+                let payload = self.import_with_cache(&payload, factory, cache)?;
+                let token = self.import_with_cache(&token, factory, cache)?;
                 Ok(SignalType::Custom {
                     effect_type,
                     payload,
@@ -444,7 +698,7 @@ where
             }
             SignalType::Pending => Ok(SignalType::Pending),
             SignalType::Error { payload } => {
-                let payload = self.import(&payload, factory)?;
+                let payload = self.import_with_cache(&payload, factory, cache)?;
                 Ok(SignalType::Error { payload })
             }
         }?;
@@ -456,6 +710,22 @@ where
         factory: &impl ExpressionFactory<T>,
         allocator: &impl HeapAllocator<T>,
         indirect_call_arity: &HashMap<FunctionIndex, Arity>,
+    ) -> Result<T::Signal, WasmExpression<Rc<RefCell<A>>>> {
+        self.export_condition_with_cache(
+            condition,
+            factory,
+            allocator,
+            indirect_call_arity,
+            &mut WasmTermConversionCache::default(),
+        )
+    }
+    pub fn export_condition_with_cache<T: Expression>(
+        &self,
+        condition: &ArenaRef<TypedTerm<ConditionTerm>, Rc<RefCell<A>>>,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+        indirect_call_arity: &HashMap<FunctionIndex, Arity>,
+        cache: &mut WasmTermConversionCache<T>,
     ) -> Result<T::Signal, WasmExpression<Rc<RefCell<A>>>> {
         let signal_type = match condition.signal_type() {
             SignalType::Custom {
@@ -463,10 +733,22 @@ where
                 payload,
                 token,
             } => {
-                let effect_type =
-                    self.export(&effect_type, factory, allocator, indirect_call_arity)?;
-                let payload = self.export(&payload, factory, allocator, indirect_call_arity)?;
-                let token = self.export(&token, factory, allocator, indirect_call_arity)?;
+                let effect_type = self.export_with_cache(
+                    &effect_type,
+                    factory,
+                    allocator,
+                    indirect_call_arity,
+                    cache,
+                )?;
+                let payload = self.export_with_cache(
+                    &payload,
+                    factory,
+                    allocator,
+                    indirect_call_arity,
+                    cache,
+                )?;
+                let token =
+                    self.export_with_cache(&token, factory, allocator, indirect_call_arity, cache)?;
                 SignalType::Custom {
                     effect_type,
                     payload,
@@ -483,7 +765,13 @@ where
                             .create_string_term(allocator.create_string(format!("{condition}"))),
                     }
                 } else {
-                    let payload = self.export(&payload, factory, allocator, indirect_call_arity)?;
+                    let payload = self.export_with_cache(
+                        &payload,
+                        factory,
+                        allocator,
+                        indirect_call_arity,
+                        cache,
+                    )?;
                     SignalType::Error { payload }
                 }
             }
@@ -504,7 +792,8 @@ impl<A: Arena> Arena for WasmTermFactory<A>
 where
     Rc<RefCell<A>>: Arena,
 {
-    type Slice<'a> = <Rc<RefCell<A>> as Arena>::Slice<'a>
+    type Slice<'a>
+        = <Rc<RefCell<A>> as Arena>::Slice<'a>
     where
         Self: 'a;
     fn read_value<T, V>(&self, offset: ArenaPointer, selector: impl FnOnce(&T) -> V) -> V {
@@ -550,6 +839,21 @@ where
     fn shrink(&mut self, offset: ArenaPointer, size: usize) {
         self.arena.borrow_mut().deref_mut().shrink(offset, size)
     }
+    fn intern_string(&mut self, pointer: ArenaPointer) -> ArenaPointer {
+        self.arena.borrow_mut().deref_mut().intern_string(pointer)
+    }
+    fn remap_interned_strings(&mut self, remapping: &ArenaRemapping) {
+        self.arena
+            .borrow_mut()
+            .deref_mut()
+            .remap_interned_strings(remapping)
+    }
+    fn allocate_fixed<T: TermSize>(&mut self, value: T) -> ArenaPointer {
+        self.arena.borrow_mut().deref_mut().allocate_fixed(value)
+    }
+    fn release(&mut self, pointer: ArenaPointer) {
+        self.arena.borrow_mut().deref_mut().release(pointer)
+    }
 }
 
 impl<A: Arena> HeapAllocator<ArenaRef<Term, Self>> for WasmTermFactory<A>
@@ -626,6 +930,57 @@ where
         expressions
     }
 
+    fn slice_list<'a>(
+        &self,
+        expressions: <ArenaRef<Term, Self> as Expression>::ExpressionListRef<'a>,
+        offset: usize,
+        length: usize,
+    ) -> <ArenaRef<Term, Self> as Expression>::ExpressionList
+    where
+        Self: 'a,
+    {
+        // Expression lists are always flat list terms, so the selected items must be copied (the runtime Slice builtin
+        // avoids this copy by allocating a list view term that shares the source list storage)
+        let num_items = expressions.as_inner().len();
+        let offset = offset.min(num_items);
+        let length = length.min(num_items - offset);
+        if offset == 0 && length == num_items {
+            return expressions;
+        }
+        // The items are collected before allocating the new list to prevent runtime borrow errors
+        let items = expressions
+            .as_inner()
+            .iter()
+            .skip(offset)
+            .take(length)
+            .collect::<Vec<_>>();
+        self.create_list(items)
+    }
+
+    fn concat_lists<'a>(
+        &self,
+        left: <ArenaRef<Term, Self> as Expression>::ExpressionListRef<'a>,
+        right: <ArenaRef<Term, Self> as Expression>::ExpressionListRef<'a>,
+    ) -> <ArenaRef<Term, Self> as Expression>::ExpressionList
+    where
+        Self: 'a,
+    {
+        // Expression lists are always flat list terms, so the items of both lists must be copied (the runtime Chain
+        // builtin avoids this copy by allocating a list rope term that shares the storage of both lists)
+        if right.as_inner().len() == 0 {
+            return left;
+        }
+        if left.as_inner().len() == 0 {
+            return right;
+        }
+        let items = left
+            .as_inner()
+            .iter()
+            .chain(right.as_inner().iter())
+            .collect::<Vec<_>>();
+        self.create_list(items)
+    }
+
     fn create_signal_list(
         &self,
         signals: impl IntoIterator<Item = <ArenaRef<Term, Self> as Expression>::Signal>,
@@ -817,9 +1172,60 @@ where
         ArenaRef::<Term, Self>::new(self.clone(), pointer)
     }
 
-    fn create_timestamp_term(&self, millis: TimestampValue) -> ArenaRef<Term, Self> {
+    fn create_timestamp_term(
+        &self,
+        millis: TimestampValue,
+        offset: TimezoneOffset,
+    ) -> ArenaRef<Term, Self> {
         let term = Term::new(
-            TermType::Timestamp(TimestampTerm::from(millis)),
+            TermType::Timestamp(TimestampTerm::new(millis, offset)),
+            &*self.arena.borrow(),
+        );
+        let pointer = self.arena.borrow_mut().deref_mut().allocate(term);
+        ArenaRef::<Term, Self>::new(self.clone(), pointer)
+    }
+
+    fn create_duration_term(&self, millis: DurationValue) -> ArenaRef<Term, Self> {
+        let term = Term::new(
+            TermType::Duration(DurationTerm::from(millis)),
+            &*self.arena.borrow(),
+        );
+        let pointer = self.arena.borrow_mut().deref_mut().allocate(term);
+        ArenaRef::<Term, Self>::new(self.clone(), pointer)
+    }
+
+    fn create_bytes_term(&self, value: &[u8]) -> ArenaRef<Term, Self> {
+        let pointer = BytesTerm::allocate(value, self.arena.deref().borrow_mut().deref_mut());
+        ArenaRef::<Term, Self>::new(self.clone(), pointer)
+    }
+
+    fn create_regex_term(
+        &self,
+        pattern: <ArenaRef<Term, Self> as Expression>::String,
+    ) -> ArenaRef<Term, Self> {
+        debug_assert!(std::ptr::eq(
+            &*pattern.arena.arena.borrow(),
+            &*self.arena.borrow()
+        ));
+        let term = Term::new(
+            TermType::Regex(RegexTerm {
+                pattern: pattern.as_pointer(),
+            }),
+            &*self.arena.borrow(),
+        );
+        let pointer = self.arena.borrow_mut().deref_mut().allocate(term);
+        ArenaRef::<Term, Self>::new(self.clone(), pointer)
+    }
+
+    fn create_sensitive_term(&self, value: ArenaRef<Term, Self>) -> ArenaRef<Term, Self> {
+        debug_assert!(std::ptr::eq(
+            &*value.arena.arena.borrow(),
+            &*self.arena.borrow()
+        ));
+        let term = Term::new(
+            TermType::Sensitive(SensitiveTerm {
+                value: value.as_pointer(),
+            }),
             &*self.arena.borrow(),
         );
         let pointer = self.arena.borrow_mut().deref_mut().allocate(term);
@@ -964,35 +1370,14 @@ where
             &*factory.arena.arena.borrow(),
             &*self.arena.borrow()
         ));
-        // TODO: support recursive WASM terms
-        let error_message = StringTerm::allocate(
-            "Recursive terms not currently supported",
-            &mut *self.arena.borrow_mut(),
-        );
-        let condition_term = Term::new(
-            TermType::Condition(ConditionTerm::Error(ErrorCondition {
-                payload: error_message,
-            })),
-            &*self.arena.borrow(),
-        );
-        let condition_pointer = self.arena.borrow_mut().deref_mut().allocate(condition_term);
-        let signal_list_term = Term::new(
-            TermType::Tree(TreeTerm {
-                left: condition_pointer,
-                right: ArenaPointer::null(),
-                length: 1,
-                depth: 1,
+        let term = Term::new(
+            TermType::Recursive(RecursiveTerm {
+                factory: factory.as_pointer(),
             }),
             &*self.arena.borrow(),
         );
-        let signal_list_pointer = self
-            .arena
-            .borrow_mut()
-            .deref_mut()
-            .allocate(signal_list_term);
-        let signal_list =
-            ArenaRef::<TypedTerm<TreeTerm>, _>::new(self.clone(), signal_list_pointer);
-        self.create_signal_term(signal_list)
+        let pointer = self.arena.borrow_mut().deref_mut().allocate(term);
+        ArenaRef::<Term, Self>::new(self.clone(), pointer)
     }
 
     fn create_builtin_term(
@@ -1227,6 +1612,46 @@ where
         }
     }
 
+    fn match_duration_term<'a>(
+        &self,
+        expression: &'a ArenaRef<Term, Self>,
+    ) -> Option<&'a <ArenaRef<Term, Self> as Expression>::DurationTerm> {
+        match expression.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::Duration => Some(expression.as_typed_term::<DurationTerm>()),
+            _ => None,
+        }
+    }
+
+    fn match_bytes_term<'a>(
+        &self,
+        expression: &'a ArenaRef<Term, Self>,
+    ) -> Option<&'a <ArenaRef<Term, Self> as Expression>::BytesTerm> {
+        match expression.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::Bytes => Some(expression.as_typed_term::<BytesTerm>()),
+            _ => None,
+        }
+    }
+
+    fn match_regex_term<'a>(
+        &self,
+        expression: &'a ArenaRef<Term, Self>,
+    ) -> Option<&'a <ArenaRef<Term, Self> as Expression>::RegexTerm> {
+        match expression.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::Regex => Some(expression.as_typed_term::<RegexTerm>()),
+            _ => None,
+        }
+    }
+
+    fn match_sensitive_term<'a>(
+        &self,
+        expression: &'a ArenaRef<Term, Self>,
+    ) -> Option<&'a <ArenaRef<Term, Self> as Expression>::SensitiveTerm> {
+        match expression.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::Sensitive => Some(expression.as_typed_term::<SensitiveTerm>()),
+            _ => None,
+        }
+    }
+
     fn match_variable_term<'a>(
         &self,
         expression: &'a ArenaRef<Term, Self>,
@@ -1304,7 +1729,7 @@ where
         expression: &'a ArenaRef<Term, Self>,
     ) -> Option<&'a <ArenaRef<Term, Self> as Expression>::RecursiveTerm> {
         match expression.read_value(|term| term.type_id()) {
-            //Discriminants TODO: Implement WASM recursive term
+            TermTypeDiscriminants::Recursive => Some(expression.as_typed_term::<RecursiveTerm>()),
             _ => None,
         }
     }
@@ -1436,9 +1861,27 @@ impl From<reflex_stdlib::stdlib::Stdlib> for reflex_wasm::stdlib::Stdlib {
             reflex_stdlib::stdlib::Stdlib::Contains => {
                 reflex_wasm::stdlib::Stdlib::Has(reflex_wasm::stdlib::Has)
             }
+            reflex_stdlib::stdlib::Stdlib::DateAdd => {
+                reflex_wasm::stdlib::Stdlib::DateAdd(reflex_wasm::stdlib::DateAdd)
+            }
+            reflex_stdlib::stdlib::Stdlib::DateDiff => {
+                reflex_wasm::stdlib::Stdlib::DateDiff(reflex_wasm::stdlib::DateDiff)
+            }
+            reflex_stdlib::stdlib::Stdlib::DateOffset => {
+                reflex_wasm::stdlib::Stdlib::DateOffset(reflex_wasm::stdlib::DateOffset)
+            }
+            reflex_stdlib::stdlib::Stdlib::DateTruncate => {
+                reflex_wasm::stdlib::Stdlib::DateTruncate(reflex_wasm::stdlib::DateTruncate)
+            }
+            reflex_stdlib::stdlib::Stdlib::DateWithOffset => {
+                reflex_wasm::stdlib::Stdlib::DateWithOffset(reflex_wasm::stdlib::DateWithOffset)
+            }
             reflex_stdlib::stdlib::Stdlib::Divide => {
                 reflex_wasm::stdlib::Stdlib::Divide(reflex_wasm::stdlib::Divide)
             }
+            reflex_stdlib::stdlib::Stdlib::Duration => {
+                reflex_wasm::stdlib::Stdlib::Duration(reflex_wasm::stdlib::Duration)
+            }
             reflex_stdlib::stdlib::Stdlib::Effect => {
                 reflex_wasm::stdlib::Stdlib::Effect(reflex_wasm::stdlib::Effect)
             }
@@ -1451,6 +1894,9 @@ impl From<reflex_stdlib::stdlib::Stdlib> for reflex_wasm::stdlib::Stdlib {
             reflex_stdlib::stdlib::Stdlib::Equal => {
                 reflex_wasm::stdlib::Stdlib::Equal(reflex_wasm::stdlib::Equal)
             }
+            reflex_stdlib::stdlib::Stdlib::Eval => {
+                reflex_wasm::stdlib::Stdlib::Eval(reflex_wasm::stdlib::Eval)
+            }
             reflex_stdlib::stdlib::Stdlib::Filter => {
                 reflex_wasm::stdlib::Stdlib::Filter(reflex_wasm::stdlib::Filter)
             }
@@ -1505,9 +1951,18 @@ impl From<reflex_stdlib::stdlib::Stdlib> for reflex_wasm::stdlib::Stdlib {
             reflex_stdlib::stdlib::Stdlib::Map => {
                 reflex_wasm::stdlib::Stdlib::Map(reflex_wasm::stdlib::Map)
             }
+            reflex_stdlib::stdlib::Stdlib::Match => {
+                reflex_wasm::stdlib::Stdlib::Match(reflex_wasm::stdlib::Match)
+            }
             reflex_stdlib::stdlib::Stdlib::Max => {
                 reflex_wasm::stdlib::Stdlib::Max(reflex_wasm::stdlib::Max)
             }
+            reflex_stdlib::stdlib::Stdlib::Memo => {
+                reflex_wasm::stdlib::Stdlib::Memo(reflex_wasm::stdlib::Memo)
+            }
+            reflex_stdlib::stdlib::Stdlib::MemoStore => {
+                reflex_wasm::stdlib::Stdlib::MemoStore(reflex_wasm::stdlib::MemoStore)
+            }
             reflex_stdlib::stdlib::Stdlib::Merge => {
                 reflex_wasm::stdlib::Stdlib::Merge(reflex_wasm::stdlib::Merge)
             }
@@ -1532,6 +1987,9 @@ impl From<reflex_stdlib::stdlib::Stdlib> for reflex_wasm::stdlib::Stdlib {
             reflex_stdlib::stdlib::Stdlib::PushFront => {
                 reflex_wasm::stdlib::Stdlib::PushFront(reflex_wasm::stdlib::PushFront)
             }
+            reflex_stdlib::stdlib::Stdlib::Quote => {
+                reflex_wasm::stdlib::Stdlib::Quote(reflex_wasm::stdlib::Quote)
+            }
             reflex_stdlib::stdlib::Stdlib::Raise => {
                 reflex_wasm::stdlib::Stdlib::Raise(reflex_wasm::stdlib::Raise)
             }
@@ -1559,6 +2017,9 @@ impl From<reflex_stdlib::stdlib::Stdlib> for reflex_wasm::stdlib::Stdlib {
             reflex_stdlib::stdlib::Stdlib::ResolveList => {
                 reflex_wasm::stdlib::Stdlib::ResolveList(reflex_wasm::stdlib::ResolveList)
             }
+            reflex_stdlib::stdlib::Stdlib::Reveal => {
+                reflex_wasm::stdlib::Stdlib::Reveal(reflex_wasm::stdlib::Reveal)
+            }
             reflex_stdlib::stdlib::Stdlib::Round => {
                 reflex_wasm::stdlib::Stdlib::Round(reflex_wasm::stdlib::Round)
             }
@@ -1568,6 +2029,15 @@ impl From<reflex_stdlib::stdlib::Stdlib> for reflex_wasm::stdlib::Stdlib {
             reflex_stdlib::stdlib::Stdlib::Slice => {
                 reflex_wasm::stdlib::Stdlib::Slice(reflex_wasm::stdlib::Slice)
             }
+            reflex_stdlib::stdlib::Stdlib::Sort => {
+                reflex_wasm::stdlib::Stdlib::Sort(reflex_wasm::stdlib::Sort)
+            }
+            reflex_stdlib::stdlib::Stdlib::SortBy => {
+                reflex_wasm::stdlib::Stdlib::SortBy(reflex_wasm::stdlib::SortBy)
+            }
+            reflex_stdlib::stdlib::Stdlib::SortMerge => {
+                reflex_wasm::stdlib::Stdlib::SortMerge(reflex_wasm::stdlib::SortMerge)
+            }
             reflex_stdlib::stdlib::Stdlib::Split => {
                 reflex_wasm::stdlib::Stdlib::Split(reflex_wasm::stdlib::Split)
             }
@@ -1577,6 +2047,9 @@ impl From<reflex_stdlib::stdlib::Stdlib> for reflex_wasm::stdlib::Stdlib {
             reflex_stdlib::stdlib::Stdlib::Subtract => {
                 reflex_wasm::stdlib::Stdlib::Subtract(reflex_wasm::stdlib::Subtract)
             }
+            reflex_stdlib::stdlib::Stdlib::Template => {
+                reflex_wasm::stdlib::Stdlib::Template(reflex_wasm::stdlib::Template)
+            }
             reflex_stdlib::stdlib::Stdlib::Unzip => {
                 reflex_wasm::stdlib::Stdlib::Unzip(reflex_wasm::stdlib::Unzip)
             }
@@ -1599,6 +2072,11 @@ impl From<reflex_json::stdlib::Stdlib> for reflex_wasm::stdlib::Stdlib {
             reflex_json::stdlib::Stdlib::JsonSerialize => {
                 reflex_wasm::stdlib::Stdlib::StringifyJson(reflex_wasm::stdlib::StringifyJson)
             }
+            reflex_json::stdlib::Stdlib::JsonSerializeCanonical => {
+                reflex_wasm::stdlib::Stdlib::StringifyJsonCanonical(
+                    reflex_wasm::stdlib::StringifyJsonCanonical,
+                )
+            }
         }
     }
 }
@@ -1635,12 +2113,21 @@ impl From<reflex_js::stdlib::Stdlib> for reflex_wasm::stdlib::Stdlib {
             reflex_js::stdlib::Stdlib::LogArgs => {
                 reflex_wasm::stdlib::Stdlib::Log(reflex_wasm::stdlib::Log)
             }
+            reflex_js::stdlib::Stdlib::LogScope => {
+                reflex_wasm::stdlib::Stdlib::LogScope(reflex_wasm::stdlib::LogScope)
+            }
             reflex_js::stdlib::Stdlib::ParseFloat => {
                 reflex_wasm::stdlib::Stdlib::ParseFloat(reflex_wasm::stdlib::ParseFloat)
             }
             reflex_js::stdlib::Stdlib::ParseInt => {
                 reflex_wasm::stdlib::Stdlib::ParseInt(reflex_wasm::stdlib::ParseInt)
             }
+            reflex_js::stdlib::Stdlib::ScopedLog => {
+                reflex_wasm::stdlib::Stdlib::ScopedLog(reflex_wasm::stdlib::ScopedLog)
+            }
+            reflex_js::stdlib::Stdlib::ScopedLogArgs => {
+                reflex_wasm::stdlib::Stdlib::ScopedLog(reflex_wasm::stdlib::ScopedLog)
+            }
             reflex_js::stdlib::Stdlib::Throw => {
                 reflex_wasm::stdlib::Stdlib::Throw(reflex_wasm::stdlib::Throw)
             }
@@ -1718,6 +2205,18 @@ impl From<reflex_handlers::stdlib::Stdlib> for reflex_wasm::stdlib::Stdlib {
                     reflex_wasm::stdlib::DecrementVariable,
                 )
             }
+            reflex_handlers::stdlib::Stdlib::Paginate => {
+                reflex_wasm::stdlib::Stdlib::Paginate(reflex_wasm::stdlib::Paginate)
+            }
+            reflex_handlers::stdlib::Stdlib::Throttle => {
+                reflex_wasm::stdlib::Stdlib::Throttle(reflex_wasm::stdlib::Throttle)
+            }
+            reflex_handlers::stdlib::Stdlib::Debounce => {
+                reflex_wasm::stdlib::Stdlib::Debounce(reflex_wasm::stdlib::Debounce)
+            }
+            reflex_handlers::stdlib::Stdlib::Sample => {
+                reflex_wasm::stdlib::Stdlib::Sample(reflex_wasm::stdlib::Sample)
+            }
         }
     }
 }
@@ -1782,11 +2281,41 @@ impl From<reflex_stdlib::stdlib::Contains> for reflex_wasm::stdlib::Stdlib {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_stdlib::stdlib::DateAdd> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_stdlib::stdlib::DateAdd) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_stdlib::stdlib::DateDiff> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_stdlib::stdlib::DateDiff) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_stdlib::stdlib::DateOffset> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_stdlib::stdlib::DateOffset) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_stdlib::stdlib::DateTruncate> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_stdlib::stdlib::DateTruncate) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_stdlib::stdlib::DateWithOffset> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_stdlib::stdlib::DateWithOffset) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_stdlib::stdlib::Divide> for reflex_wasm::stdlib::Stdlib {
     fn from(value: reflex_stdlib::stdlib::Divide) -> Self {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_stdlib::stdlib::Duration> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_stdlib::stdlib::Duration) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_stdlib::stdlib::Effect> for reflex_wasm::stdlib::Stdlib {
     fn from(value: reflex_stdlib::stdlib::Effect) -> Self {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -1807,6 +2336,11 @@ impl From<reflex_stdlib::stdlib::Equal> for reflex_wasm::stdlib::Stdlib {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_stdlib::stdlib::Eval> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_stdlib::stdlib::Eval) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_stdlib::stdlib::Filter> for reflex_wasm::stdlib::Stdlib {
     fn from(value: reflex_stdlib::stdlib::Filter) -> Self {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -1897,11 +2431,26 @@ impl From<reflex_stdlib::stdlib::Map> for reflex_wasm::stdlib::Stdlib {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_stdlib::stdlib::Match> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_stdlib::stdlib::Match) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_stdlib::stdlib::Max> for reflex_wasm::stdlib::Stdlib {
     fn from(value: reflex_stdlib::stdlib::Max) -> Self {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_stdlib::stdlib::Memo> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_stdlib::stdlib::Memo) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_stdlib::stdlib::MemoStore> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_stdlib::stdlib::MemoStore) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_stdlib::stdlib::Merge> for reflex_wasm::stdlib::Stdlib {
     fn from(value: reflex_stdlib::stdlib::Merge) -> Self {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -1942,6 +2491,11 @@ impl From<reflex_stdlib::stdlib::PushFront> for reflex_wasm::stdlib::Stdlib {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_stdlib::stdlib::Quote> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_stdlib::stdlib::Quote) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_stdlib::stdlib::Raise> for reflex_wasm::stdlib::Stdlib {
     fn from(value: reflex_stdlib::stdlib::Raise) -> Self {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -1987,6 +2541,11 @@ impl From<reflex_stdlib::stdlib::ResolveList> for reflex_wasm::stdlib::Stdlib {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_stdlib::stdlib::Reveal> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_stdlib::stdlib::Reveal) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_stdlib::stdlib::Round> for reflex_wasm::stdlib::Stdlib {
     fn from(value: reflex_stdlib::stdlib::Round) -> Self {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -2002,6 +2561,21 @@ impl From<reflex_stdlib::stdlib::Slice> for reflex_wasm::stdlib::Stdlib {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_stdlib::stdlib::Sort> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_stdlib::stdlib::Sort) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_stdlib::stdlib::SortBy> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_stdlib::stdlib::SortBy) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_stdlib::stdlib::SortMerge> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_stdlib::stdlib::SortMerge) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_stdlib::stdlib::Split> for reflex_wasm::stdlib::Stdlib {
     fn from(value: reflex_stdlib::stdlib::Split) -> Self {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -2017,6 +2591,11 @@ impl From<reflex_stdlib::stdlib::Subtract> for reflex_wasm::stdlib::Stdlib {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_stdlib::stdlib::Template> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_stdlib::stdlib::Template) -> Self {
+        Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_stdlib::stdlib::Unzip> for reflex_wasm::stdlib::Stdlib {
     fn from(value: reflex_stdlib::stdlib::Unzip) -> Self {
         Self::from(reflex_stdlib::stdlib::Stdlib::from(value))
@@ -2043,6 +2622,11 @@ impl From<reflex_json::stdlib::JsonSerialize> for reflex_wasm::stdlib::Stdlib {
         Self::from(reflex_json::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_json::stdlib::JsonSerializeCanonical> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_json::stdlib::JsonSerializeCanonical) -> Self {
+        Self::from(reflex_json::stdlib::Stdlib::from(value))
+    }
+}
 
 impl From<reflex_js::stdlib::Accessor> for reflex_wasm::stdlib::Stdlib {
     fn from(value: reflex_js::stdlib::Accessor) -> Self {
@@ -2084,6 +2668,11 @@ impl From<reflex_js::stdlib::LogArgs> for reflex_wasm::stdlib::Stdlib {
         Self::from(reflex_js::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_js::stdlib::LogScope> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_js::stdlib::LogScope) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_js::stdlib::ParseDate> for reflex_wasm::stdlib::Stdlib {
     fn from(value: reflex_js::stdlib::ParseDate) -> Self {
         Self::from(reflex_js::stdlib::Stdlib::from(value))
@@ -2099,6 +2688,16 @@ impl From<reflex_js::stdlib::ParseInt> for reflex_wasm::stdlib::Stdlib {
         Self::from(reflex_js::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_js::stdlib::ScopedLog> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_js::stdlib::ScopedLog) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_js::stdlib::ScopedLogArgs> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_js::stdlib::ScopedLogArgs) -> Self {
+        Self::from(reflex_js::stdlib::Stdlib::from(value))
+    }
+}
 impl From<reflex_js::stdlib::Throw> for reflex_wasm::stdlib::Stdlib {
     fn from(value: reflex_js::stdlib::Throw) -> Self {
         Self::from(reflex_js::stdlib::Stdlib::from(value))
@@ -2177,3 +2776,23 @@ impl From<reflex_handlers::stdlib::DecrementVariable> for reflex_wasm::stdlib::S
         Self::from(reflex_handlers::stdlib::Stdlib::from(value))
     }
 }
+impl From<reflex_handlers::stdlib::Paginate> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_handlers::stdlib::Paginate) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::Throttle> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_handlers::stdlib::Throttle) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::Debounce> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_handlers::stdlib::Debounce) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
+impl From<reflex_handlers::stdlib::Sample> for reflex_wasm::stdlib::Stdlib {
+    fn from(value: reflex_handlers::stdlib::Sample) -> Self {
+        Self::from(reflex_handlers::stdlib::Stdlib::from(value))
+    }
+}
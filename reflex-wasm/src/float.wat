@@ -0,0 +1,435 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  ;; Bundled float formatter, used in place of the host "Number" "toString" import for self-contained modules that
+  ;; cannot rely on host functions (e.g. standalone WASI command modules)
+  ;;
+  ;; The output matches the host formatter: the shortest decimal representation that parses back to the original value
+  ;; (e.g. "0.1", "100.0", "1e21", "1.5e-7"), with negative zero formatted as "0.0" and non-finite values formatted as
+  ;; "NaN", "Infinity" and "-Infinity".
+  ;;
+  ;; Digits are generated using the free-format algorithm from Burger & Dybvig's "Printing Floating-Point Numbers
+  ;; Quickly and Accurately", with intermediate values stored as fixed-size bignums that are large enough to hold the
+  ;; scaled representation of any finite 64-bit float.
+  (global $Utils::Float::format::NUM_LIMBS i32 (i32.const 40))
+  (global $Utils::Float::format::BIGNUM_SIZE i32 (i32.const 160))
+  (global $Utils::Float::format::SCRATCH_SIZE i32 (i32.const 800))
+
+  (func $Utils::Float::format (export "formatFloat") (param $value f64) (param $offset i32) (result i32)
+    (local $is_negative i32)
+    (local $digits i64)
+    (local $num_digits i32)
+    (local $exponent i32)
+    (if
+      (call $Utils::f64::is_nan (local.get $value))
+      (then
+        (return (@store-bytes $offset "NaN"))))
+    (if
+      (f64.eq (local.get $value) (f64.const inf))
+      (then
+        (return (@store-bytes $offset "Infinity"))))
+    (if
+      (f64.eq (local.get $value) (f64.const -inf))
+      (then
+        (return (@store-bytes $offset "-Infinity"))))
+    ;; Zero is always formatted without a sign (this normalizes negative zero)
+    (if
+      (f64.eq (local.get $value) (f64.const 0))
+      (then
+        (return (@store-bytes $offset "0.0"))))
+    ;; If the number is negative, write a minus sign to the output
+    (if
+      (local.tee $is_negative (f64.lt (local.get $value) (f64.const 0)))
+      (then
+        (call $Allocator::extend (local.get $offset) (i32.const 1))
+        (i32.store8 (local.get $offset) (@char "-"))
+        (local.set $offset (i32.add (i32.const 1) (local.get $offset)))))
+    ;; Generate the shortest sequence of digits that uniquely identifies the value,
+    ;; using the output location as temporary scratch space for the intermediate bignums
+    (call $Allocator::extend (local.get $offset) (global.get $Utils::Float::format::SCRATCH_SIZE))
+    (call $Utils::Float::format::get_digits
+      (i64.reinterpret_f64 (local.get $value))
+      (local.get $offset))
+    (local.set $exponent)
+    (local.set $num_digits)
+    (local.set $digits)
+    (call $Allocator::shrink
+      (i32.add (local.get $offset) (global.get $Utils::Float::format::SCRATCH_SIZE))
+      (global.get $Utils::Float::format::SCRATCH_SIZE))
+    ;; Write the digits to the output, returning the number of bytes written (taking into account any minus sign)
+    (i32.add
+      (local.get $is_negative)
+      (call $Utils::Float::format::write
+        (local.get $digits)
+        (local.get $num_digits)
+        (local.get $exponent)
+        (local.get $offset))))
+
+  (func $Utils::Float::format::get_digits (param $bits i64) (param $scratch i32) (result i64 i32 i32)
+    ;; Returns the decimal digits of the given non-zero finite value, along with the number of digits and the decimal
+    ;; exponent, where the absolute value is equal to 0.[digits] * 10^[exponent]
+    (local $remainder i32)
+    (local $scale i32)
+    (local $margin_low i32)
+    (local $margin_high i32)
+    (local $temp i32)
+    (local $biased_exponent i32)
+    (local $mantissa i64)
+    (local $binary_exponent i32)
+    (local $is_even i32)
+    (local $is_closer i32)
+    (local $exponent i32)
+    (local $digit i32)
+    (local $digits i64)
+    (local $num_digits i32)
+    (local $is_low i32)
+    (local $is_high i32)
+    (local $comparison i32)
+    ;; Allocate the intermediate bignums within the scratch space
+    (local.set $remainder (local.get $scratch))
+    (local.set $scale (i32.add (local.get $remainder) (global.get $Utils::Float::format::BIGNUM_SIZE)))
+    (local.set $margin_low (i32.add (local.get $scale) (global.get $Utils::Float::format::BIGNUM_SIZE)))
+    (local.set $margin_high (i32.add (local.get $margin_low) (global.get $Utils::Float::format::BIGNUM_SIZE)))
+    (local.set $temp (i32.add (local.get $margin_high) (global.get $Utils::Float::format::BIGNUM_SIZE)))
+    ;; Decompose the value into an integer mantissa and binary exponent
+    (local.set $biased_exponent
+      (i32.wrap_i64 (i64.and (i64.shr_u (local.get $bits) (i64.const 52)) (i64.const 0x7FF))))
+    (local.set $mantissa (i64.and (local.get $bits) (i64.const 0xFFFFFFFFFFFFF)))
+    (if
+      (local.get $biased_exponent)
+      (then
+        (local.set $mantissa (i64.or (local.get $mantissa) (i64.const 0x10000000000000)))
+        (local.set $binary_exponent (i32.sub (local.get $biased_exponent) (i32.const 1075))))
+      (else
+        ;; Subnormal values have no implicit leading bit
+        (local.set $binary_exponent (i32.const -1074))))
+    ;; Values with an even mantissa will be rounded to on input, so the rounding interval includes its boundaries
+    (local.set $is_even (i64.eqz (i64.and (local.get $mantissa) (i64.const 1))))
+    ;; If the value is an exact power of two, the next lowest float is twice as close as the next highest float
+    (local.set $is_closer
+      (i32.and
+        (i64.eq (local.get $mantissa) (i64.const 0x10000000000000))
+        (i32.gt_u (local.get $biased_exponent) (i32.const 1))))
+    ;; Initialize the value (as a fraction of the scale) and the distances to the rounding interval boundaries,
+    ;; all multiplied by a common factor to ensure they can be represented as integers
+    (call $Utils::Float::format::bignum::set
+      (local.get $remainder)
+      (i64.shl (local.get $mantissa) (i64.extend_i32_u (i32.add (i32.const 1) (local.get $is_closer)))))
+    (call $Utils::Float::format::bignum::set
+      (local.get $scale)
+      (i64.shl (i64.const 1) (i64.extend_i32_u (i32.add (i32.const 1) (local.get $is_closer)))))
+    (call $Utils::Float::format::bignum::set
+      (local.get $margin_low)
+      (i64.const 1))
+    (call $Utils::Float::format::bignum::set
+      (local.get $margin_high)
+      (i64.shl (i64.const 1) (i64.extend_i32_u (local.get $is_closer))))
+    (if
+      (i32.ge_s (local.get $binary_exponent) (i32.const 0))
+      (then
+        (call $Utils::Float::format::bignum::multiply_pow2 (local.get $remainder) (local.get $binary_exponent))
+        (call $Utils::Float::format::bignum::multiply_pow2 (local.get $margin_low) (local.get $binary_exponent))
+        (call $Utils::Float::format::bignum::multiply_pow2 (local.get $margin_high) (local.get $binary_exponent)))
+      (else
+        (call $Utils::Float::format::bignum::multiply_pow2
+          (local.get $scale)
+          (i32.sub (i32.const 0) (local.get $binary_exponent)))))
+    ;; Estimate the decimal exponent based on the position of the most significant bit
+    ;; (this is guaranteed to be either correct or one too small)
+    (local.set $exponent
+      (i32.trunc_f64_s
+        (f64.ceil
+          (f64.sub
+            (f64.mul
+              (f64.convert_i32_s
+                (i32.add
+                  (local.get $binary_exponent)
+                  (i32.sub (i32.const 63) (i32.wrap_i64 (i64.clz (local.get $mantissa))))))
+              (f64.const 0.30102999566398114))
+            (f64.const 1e-10)))))
+    ;; Scale the values by the estimated power of ten
+    (if
+      (i32.ge_s (local.get $exponent) (i32.const 0))
+      (then
+        (call $Utils::Float::format::bignum::multiply_pow10 (local.get $scale) (local.get $exponent)))
+      (else
+        (call $Utils::Float::format::bignum::multiply_pow10
+          (local.get $remainder)
+          (i32.sub (i32.const 0) (local.get $exponent)))
+        (call $Utils::Float::format::bignum::multiply_pow10
+          (local.get $margin_low)
+          (i32.sub (i32.const 0) (local.get $exponent)))
+        (call $Utils::Float::format::bignum::multiply_pow10
+          (local.get $margin_high)
+          (i32.sub (i32.const 0) (local.get $exponent)))))
+    ;; Correct the estimated exponent if the upper boundary of the rounding interval lies beyond the scale
+    (loop $LOOP
+      (if
+        (call $Utils::Float::format::is_high
+          (local.get $remainder)
+          (local.get $margin_high)
+          (local.get $scale)
+          (local.get $temp)
+          (local.get $is_even))
+        (then
+          (call $Utils::Float::format::bignum::multiply_small (local.get $scale) (i32.const 10))
+          (local.set $exponent (i32.add (local.get $exponent) (i32.const 1)))
+          (br $LOOP))))
+    ;; Generate digits until the remaining value lies within the rounding interval
+    (loop $LOOP
+      (call $Utils::Float::format::bignum::multiply_small (local.get $remainder) (i32.const 10))
+      (call $Utils::Float::format::bignum::multiply_small (local.get $margin_low) (i32.const 10))
+      (call $Utils::Float::format::bignum::multiply_small (local.get $margin_high) (i32.const 10))
+      ;; Determine the next digit by repeatedly subtracting the scale from the remainder
+      (local.set $digit (i32.const 0))
+      (loop $DIGIT
+        (if
+          (i32.ge_s
+            (call $Utils::Float::format::bignum::compare (local.get $remainder) (local.get $scale))
+            (i32.const 0))
+          (then
+            (call $Utils::Float::format::bignum::subtract (local.get $remainder) (local.get $scale))
+            (local.set $digit (i32.add (local.get $digit) (i32.const 1)))
+            (br $DIGIT))))
+      (local.set $num_digits (i32.add (local.get $num_digits) (i32.const 1)))
+      (local.set $is_low
+        (call $Utils::Float::format::is_low
+          (local.get $remainder)
+          (local.get $margin_low)
+          (local.get $is_even)))
+      (local.set $is_high
+        (call $Utils::Float::format::is_high
+          (local.get $remainder)
+          (local.get $margin_high)
+          (local.get $scale)
+          (local.get $temp)
+          (local.get $is_even)))
+      ;; If the digits generated so far do not yet uniquely identify the value, continue with the next digit
+      (if
+        (i32.eqz (i32.or (local.get $is_low) (local.get $is_high)))
+        (then
+          (local.set $digits
+            (i64.add
+              (i64.mul (local.get $digits) (i64.const 10))
+              (i64.extend_i32_u (local.get $digit))))
+          (br $LOOP))))
+    ;; Determine whether to round up the final digit
+    (if
+      (if (result i32)
+        (i32.and (local.get $is_low) (local.get $is_high))
+        (then
+          ;; If both candidate digits lie within the rounding interval, choose the closest (rounding half to even)
+          (call $Utils::Float::format::bignum::copy (local.get $temp) (local.get $remainder))
+          (call $Utils::Float::format::bignum::multiply_small (local.get $temp) (i32.const 2))
+          (i32.or
+            (i32.gt_s
+              (local.tee $comparison
+                (call $Utils::Float::format::bignum::compare (local.get $temp) (local.get $scale)))
+              (i32.const 0))
+            (i32.and
+              (i32.eqz (local.get $comparison))
+              (i32.and (local.get $digit) (i32.const 1)))))
+        (else
+          (local.get $is_high)))
+      (then
+        (local.set $digit (i32.add (local.get $digit) (i32.const 1)))))
+    (i64.add
+      (i64.mul (local.get $digits) (i64.const 10))
+      (i64.extend_i32_u (local.get $digit)))
+    (local.get $num_digits)
+    (local.get $exponent))
+
+  (func $Utils::Float::format::is_low (param $remainder i32) (param $margin_low i32) (param $is_even i32) (result i32)
+    ;; Determine whether the remainder lies within the lower rounding margin
+    (local $comparison i32)
+    (local.set $comparison
+      (call $Utils::Float::format::bignum::compare (local.get $remainder) (local.get $margin_low)))
+    (select
+      (i32.le_s (local.get $comparison) (i32.const 0))
+      (i32.lt_s (local.get $comparison) (i32.const 0))
+      (local.get $is_even)))
+
+  (func $Utils::Float::format::is_high (param $remainder i32) (param $margin_high i32) (param $scale i32) (param $temp i32) (param $is_even i32) (result i32)
+    ;; Determine whether the remainder lies within the upper rounding margin
+    (local $comparison i32)
+    (call $Utils::Float::format::bignum::copy (local.get $temp) (local.get $remainder))
+    (call $Utils::Float::format::bignum::add (local.get $temp) (local.get $margin_high))
+    (local.set $comparison
+      (call $Utils::Float::format::bignum::compare (local.get $temp) (local.get $scale)))
+    (select
+      (i32.ge_s (local.get $comparison) (i32.const 0))
+      (i32.gt_s (local.get $comparison) (i32.const 0))
+      (local.get $is_even)))
+
+  (func $Utils::Float::format::write (param $digits i64) (param $num_digits i32) (param $exponent i32) (param $offset i32) (result i32)
+    ;; Write the digits 0.[digits] * 10^[exponent] to the output, returning the number of bytes written
+    (local $length i32)
+    (if (result i32)
+      (i32.and
+        (i32.ge_s (local.get $exponent) (local.get $num_digits))
+        (i32.le_s (local.get $exponent) (i32.const 16)))
+      (then
+        ;; Integers are written with any trailing zeros, followed by a fractional zero (e.g. 1234e7 -> 12340000000.0)
+        (call $Allocator::extend
+          (local.get $offset)
+          (local.tee $length (i32.add (local.get $exponent) (i32.const 2))))
+        (call $Utils::Float::format::write_digits (local.get $digits) (local.get $num_digits) (local.get $offset))
+        (memory.fill
+          (i32.add (local.get $offset) (local.get $num_digits))
+          (@char "0")
+          (i32.sub (local.get $exponent) (local.get $num_digits)))
+        (i32.store8 (i32.add (local.get $offset) (local.get $exponent)) (@char "."))
+        (i32.store8 (i32.add (local.get $offset) (i32.add (local.get $exponent) (i32.const 1))) (@char "0"))
+        (local.get $length))
+      (else
+        (if (result i32)
+          (i32.and
+            (i32.gt_s (local.get $exponent) (i32.const 0))
+            (i32.le_s (local.get $exponent) (i32.const 16)))
+          (then
+            ;; Values with a fractional component are written with a decimal point within the digits (e.g. 1234e-2 -> 12.34)
+            (call $Allocator::extend
+              (local.get $offset)
+              (local.tee $length (i32.add (local.get $num_digits) (i32.const 1))))
+            (call $Utils::Float::format::write_digits (local.get $digits) (local.get $num_digits) (local.get $offset))
+            (call $Utils::Float::format::insert_decimal_point
+              (local.get $offset)
+              (local.get $num_digits)
+              (local.get $exponent))
+            (local.get $length))
+          (else
+            (if (result i32)
+              (i32.and
+                (i32.gt_s (local.get $exponent) (i32.const -5))
+                (i32.le_s (local.get $exponent) (i32.const 0)))
+              (then
+                ;; Small values are written with leading zeros (e.g. 1234e-6 -> 0.001234)
+                (call $Allocator::extend
+                  (local.get $offset)
+                  (local.tee $length
+                    (i32.add
+                      (i32.sub (i32.const 2) (local.get $exponent))
+                      (local.get $num_digits))))
+                (i32.store8 (local.get $offset) (@char "0"))
+                (i32.store8 (i32.add (local.get $offset) (i32.const 1)) (@char "."))
+                (memory.fill
+                  (i32.add (local.get $offset) (i32.const 2))
+                  (@char "0")
+                  (i32.sub (i32.const 0) (local.get $exponent)))
+                (call $Utils::Float::format::write_digits
+                  (local.get $digits)
+                  (local.get $num_digits)
+                  (i32.add (local.get $offset) (i32.sub (i32.const 2) (local.get $exponent))))
+                (local.get $length))
+              (else
+                ;; Otherwise the value is written in scientific notation (e.g. 1e30, 1234e30 -> 1.234e33)
+                (call $Allocator::extend
+                  (local.get $offset)
+                  (local.tee $length
+                    (i32.add
+                      (local.get $num_digits)
+                      (select
+                        (i32.const 2)
+                        (i32.const 1)
+                        (i32.gt_u (local.get $num_digits) (i32.const 1))))))
+                (call $Utils::Float::format::write_digits (local.get $digits) (local.get $num_digits) (local.get $offset))
+                (if
+                  (i32.gt_u (local.get $num_digits) (i32.const 1))
+                  (then
+                    (call $Utils::Float::format::insert_decimal_point
+                      (local.get $offset)
+                      (local.get $num_digits)
+                      (i32.const 1))))
+                (i32.store8
+                  (i32.add (local.get $offset) (i32.sub (local.get $length) (i32.const 1)))
+                  (@char "e"))
+                ;; Write the exponent to the output
+                (i32.add
+                  (local.get $length)
+                  (call $Utils::i32::write_string
+                    (i32.sub (local.get $exponent) (i32.const 1))
+                    (i32.add (local.get $offset) (local.get $length)))))))))))
+
+  (func $Utils::Float::format::write_digits (param $digits i64) (param $num_digits i32) (param $offset i32)
+    ;; Write the bytes in reverse order, starting from the least significant digit
+    (loop $LOOP
+      (if
+        (local.get $num_digits)
+        (then
+          (call $Utils::u8::write_decimal_digit
+            (i32.wrap_i64 (i64.rem_u (local.get $digits) (i64.const 10)))
+            (i32.add (local.get $offset) (local.tee $num_digits (i32.sub (local.get $num_digits) (i32.const 1)))))
+          (local.set $digits (i64.div_u (local.get $digits) (i64.const 10)))
+          (br $LOOP)))))
+
+  (func $Utils::Float::format::insert_decimal_point (param $offset i32) (param $num_digits i32) (param $index i32)
+    ;; Shift the digits following the given index one byte to the right, and write a decimal point in their place
+    ;; (the allocation must already have been extended to accommodate the additional byte)
+    (memory.copy
+      (i32.add (local.get $offset) (i32.add (local.get $index) (i32.const 1)))
+      (i32.add (local.get $offset) (local.get $index))
+      (i32.sub (local.get $num_digits) (local.get $index)))
+    (i32.store8 (i32.add (local.get $offset) (local.get $index)) (@char ".")))
+
+  (func $Utils::Float::format::bignum::set (param $self i32) (param $value i64)
+    (memory.fill (local.get $self) (i32.const 0) (global.get $Utils::Float::format::BIGNUM_SIZE))
+    (i64.store (local.get $self) (local.get $value)))
+
+  (func $Utils::Float::format::bignum::copy (param $self i32) (param $source i32)
+    (memory.copy (local.get $self) (local.get $source) (global.get $Utils::Float::format::BIGNUM_SIZE)))
+
+  (func $Utils::Float::format::bignum::compare (param $self i32) (param $other i32) (result i32)
+    (call $Term::BigInt::magnitude::compare
+      (local.get $self)
+      (call $Term::BigInt::magnitude::length (local.get $self) (global.get $Utils::Float::format::NUM_LIMBS))
+      (local.get $other)
+      (call $Term::BigInt::magnitude::length (local.get $other) (global.get $Utils::Float::format::NUM_LIMBS))))
+
+  (func $Utils::Float::format::bignum::add (param $self i32) (param $other i32)
+    (call $Term::BigInt::magnitude::add_assign
+      (local.get $self)
+      (global.get $Utils::Float::format::NUM_LIMBS)
+      (local.get $other)
+      (global.get $Utils::Float::format::NUM_LIMBS)))
+
+  (func $Utils::Float::format::bignum::subtract (param $self i32) (param $other i32)
+    (call $Term::BigInt::magnitude::subtract_assign
+      (local.get $self)
+      (global.get $Utils::Float::format::NUM_LIMBS)
+      (local.get $other)
+      (global.get $Utils::Float::format::NUM_LIMBS)))
+
+  (func $Utils::Float::format::bignum::multiply_small (param $self i32) (param $multiplier i32)
+    (call $Term::BigInt::magnitude::multiply_small_assign
+      (local.get $self)
+      (global.get $Utils::Float::format::NUM_LIMBS)
+      (local.get $multiplier)))
+
+  (func $Utils::Float::format::bignum::multiply_pow2 (param $self i32) (param $exponent i32)
+    ;; Multiply by the given power of two in chunks of at most 2^31
+    (local $chunk i32)
+    (loop $LOOP
+      (if
+        (i32.gt_s (local.get $exponent) (i32.const 0))
+        (then
+          (local.set $chunk (call $Utils::i32::min_u (local.get $exponent) (i32.const 31)))
+          (call $Utils::Float::format::bignum::multiply_small
+            (local.get $self)
+            (i32.shl (i32.const 1) (local.get $chunk)))
+          (local.set $exponent (i32.sub (local.get $exponent) (local.get $chunk)))
+          (br $LOOP)))))
+
+  (func $Utils::Float::format::bignum::multiply_pow10 (param $self i32) (param $exponent i32)
+    ;; Multiply by the given power of ten in chunks of at most 10^9
+    (local $chunk i32)
+    (loop $LOOP
+      (if
+        (i32.gt_s (local.get $exponent) (i32.const 0))
+        (then
+          (local.set $chunk (call $Utils::i32::min_u (local.get $exponent) (i32.const 9)))
+          (call $Utils::Float::format::bignum::multiply_small
+            (local.get $self)
+            (call $Utils::i32::pow (i32.const 10) (local.get $chunk)))
+          (local.set $exponent (i32.sub (local.get $exponent) (local.get $chunk)))
+          (br $LOOP))))))
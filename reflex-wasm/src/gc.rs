@@ -0,0 +1,420 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::hash::{IntMap, IntSet};
+use reflex_utils::{Visitable, WorkStack};
+
+use crate::{
+    allocator::{Arena, ArenaAllocator, ArenaIterator},
+    hash::TermSize,
+    pad_to_4_byte_offset, ArenaPointer, ArenaRef, Term,
+};
+
+/// Summary of the outcome of a garbage collection pass
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub struct GarbageCollectionStats {
+    /// Number of terms that were reachable from the provided roots
+    pub live_terms: usize,
+    /// Number of unreachable terms whose allocations were reclaimed
+    pub collected_terms: usize,
+    /// Number of bytes by which the arena was shrunk
+    pub reclaimed_bytes: usize,
+}
+
+/// Table mapping the original locations of the terms within a compacted arena region to their relocated offsets
+///
+/// Callers holding pointers into the compacted region outside the arena can use this to fix up their references.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ArenaRemapping {
+    start_offset: ArenaPointer,
+    end_offset: ArenaPointer,
+    forwarding_addresses: IntMap<ArenaPointer, ArenaPointer>,
+}
+
+impl ArenaRemapping {
+    /// Start offset of the compacted region
+    pub fn start_offset(&self) -> ArenaPointer {
+        self.start_offset
+    }
+    /// End offset of the compacted region prior to compaction
+    pub fn end_offset(&self) -> ArenaPointer {
+        self.end_offset
+    }
+    /// Determine the updated location of the given pointer
+    ///
+    /// Pointers outside the compacted region are returned unchanged. Pointers to terms within the compacted region
+    /// return the relocated offset of the term, or `None` if the term was not live and has been discarded.
+    pub fn get(&self, pointer: ArenaPointer) -> Option<ArenaPointer> {
+        if pointer < self.start_offset || pointer >= self.end_offset {
+            Some(pointer)
+        } else {
+            self.forwarding_addresses.get(&pointer).copied()
+        }
+    }
+    /// Number of live terms that were retained within the compacted region
+    pub fn len(&self) -> usize {
+        self.forwarding_addresses.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.forwarding_addresses.is_empty()
+    }
+    /// Iterate over the `(original, relocated)` offsets of all live terms within the compacted region
+    pub fn iter(&self) -> impl Iterator<Item = (ArenaPointer, ArenaPointer)> + '_ {
+        self.forwarding_addresses
+            .iter()
+            .map(|(source, target)| (*source, *target))
+    }
+}
+
+/// Relocate all terms within the compactable region of the arena that are reachable from the provided roots, discarding
+/// any unreachable terms
+///
+/// The compactable region spans from `start_offset` to the current end of the arena, and must consist of a contiguous
+/// sequence of terms. Terms located before `start_offset` are never moved.
+///
+/// Compaction proceeds in three phases:
+/// - **Mark:** traverse the term graph from the provided roots, marking every reachable term within the compactable
+///   region
+/// - **Sweep:** walk the compactable region in address order, assigning each live term a new offset immediately after
+///   the previous live term, and rewrite all pointer fields to refer to the new offsets
+/// - **Compact:** slide the live terms down into their new offsets and truncate the arena to discard the reclaimed space
+///
+/// Any pointers into the compactable region that are held outside the arena are invalidated by the compaction: the
+/// returned [`ArenaRemapping`] table can be used to determine their updated locations.
+pub fn compact_arena(
+    arena: &mut impl ArenaAllocator,
+    start_offset: ArenaPointer,
+    roots: impl IntoIterator<Item = ArenaPointer>,
+) -> (ArenaRemapping, GarbageCollectionStats) {
+    // Read the initial 4-byte allocator offset marker
+    let end_offset =
+        ArenaPointer::from(arena.read_value::<u32, _>(ArenaPointer::from(0), |value| *value));
+    let is_collectable = |pointer: ArenaPointer| pointer >= start_offset && pointer < end_offset;
+    let (forwarding_addresses, relocations, pointer_updates, stats) = {
+        let view = ArenaView(&*arena);
+        let marked = mark_live_terms(
+            view,
+            roots.into_iter().filter(|pointer| is_collectable(*pointer)),
+            is_collectable,
+        );
+        let mut stats = GarbageCollectionStats::default();
+        let mut forwarding_addresses = IntMap::<ArenaPointer, ArenaPointer>::default();
+        let mut relocations = Vec::with_capacity(marked.len());
+        let mut next_offset = start_offset;
+        for pointer in ArenaIterator::<Term, _>::new(&view, start_offset, end_offset) {
+            let size =
+                pad_to_4_byte_offset(view.read_value::<Term, _>(pointer, |term| term.size_of()));
+            if marked.contains(&pointer) {
+                forwarding_addresses.insert(pointer, next_offset);
+                relocations.push((pointer, next_offset, size));
+                next_offset = next_offset.offset(size as u32);
+                stats.live_terms += 1;
+            } else {
+                stats.collected_terms += 1;
+            }
+        }
+        stats.reclaimed_bytes = (u32::from(end_offset) - u32::from(next_offset)) as usize;
+        let pointer_updates = relocations
+            .iter()
+            .flat_map(|(pointer, _, _)| {
+                Visitable::<ArenaPointer>::children(&ArenaRef::<Term, _>::new(view, *pointer))
+                    .filter_map(|field| {
+                        let target = view.read_value::<ArenaPointer, _>(field, |target| *target);
+                        forwarding_addresses
+                            .get(&target)
+                            .filter(|updated| **updated != target)
+                            .map(|updated| (field, *updated))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        (forwarding_addresses, relocations, pointer_updates, stats)
+    };
+    // Rewrite the pointer fields in-place before any terms are moved
+    for (field, target) in pointer_updates {
+        arena.write::<ArenaPointer>(field, target);
+    }
+    // Live terms are only ever moved to lower offsets, so moving them in address order ensures that no live term is
+    // overwritten before it has been moved
+    for (source, target, size) in relocations {
+        if source == target {
+            continue;
+        }
+        for offset in (0..size as u32).step_by(std::mem::size_of::<u32>()) {
+            let word = arena.read_value::<u32, _>(source.offset(offset), |value| *value);
+            arena.write::<u32>(target.offset(offset), word);
+        }
+    }
+    let remapping = ArenaRemapping {
+        start_offset,
+        end_offset,
+        forwarding_addresses,
+    };
+    arena.remap_interned_strings(&remapping);
+    if stats.reclaimed_bytes > 0 {
+        arena.shrink(end_offset, stats.reclaimed_bytes);
+    }
+    (remapping, stats)
+}
+
+/// Reclaim the space occupied by any terms within the collectable region of the arena that are not reachable from the
+/// provided roots, updating the roots in-place to reflect the new locations of the surviving terms
+///
+/// Any other external pointers into the collectable region will be invalidated by the collection. See [`compact_arena`]
+/// for details.
+pub fn collect_garbage(
+    arena: &mut impl ArenaAllocator,
+    start_offset: ArenaPointer,
+    roots: &mut [ArenaPointer],
+) -> GarbageCollectionStats {
+    let (remapping, stats) = compact_arena(arena, start_offset, roots.iter().copied());
+    for root in roots.iter_mut() {
+        if let Some(updated) = remapping.get(*root) {
+            *root = updated;
+        }
+    }
+    stats
+}
+
+fn mark_live_terms<A: Arena + Clone>(
+    arena: A,
+    roots: impl IntoIterator<Item = ArenaPointer>,
+    is_collectable: impl Fn(ArenaPointer) -> bool,
+) -> IntSet<ArenaPointer> {
+    let mut marked = IntSet::<ArenaPointer>::default();
+    let mut stack = WorkStack::default();
+    stack.extend(roots);
+    while let Some(pointer) = stack.pop() {
+        if !marked.insert(pointer) {
+            continue;
+        }
+        let term = ArenaRef::<Term, _>::new(arena.clone(), pointer);
+        stack.extend(
+            Visitable::<ArenaPointer>::children(&term)
+                .map(|field| arena.read_value::<ArenaPointer, _>(field, |target| *target))
+                .filter(|target| is_collectable(*target) && !marked.contains(target)),
+        );
+    }
+    marked
+}
+
+/// Cloneable read-only view of an underlying arena, allowing the arena to be traversed via [`ArenaRef`] pointers
+/// without requiring the arena type itself to be cloneable
+struct ArenaView<'a, A: Arena>(&'a A);
+
+impl<'a, A: Arena> Clone for ArenaView<'a, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, A: Arena> Copy for ArenaView<'a, A> {}
+
+impl<'a, A: Arena> Arena for ArenaView<'a, A> {
+    type Slice<'b> = A::Slice<'b>
+        where
+            Self: 'b;
+    fn read_value<T, V>(&self, offset: ArenaPointer, selector: impl FnOnce(&T) -> V) -> V {
+        self.0.read_value::<T, V>(offset, selector)
+    }
+    fn inner_pointer<T, V>(
+        &self,
+        offset: ArenaPointer,
+        selector: impl FnOnce(&T) -> &V,
+    ) -> ArenaPointer {
+        self.0.inner_pointer::<T, V>(offset, selector)
+    }
+    fn as_slice<'b>(&'b self, offset: ArenaPointer, length: usize) -> Self::Slice<'b>
+    where
+        Self::Slice<'b>: 'b,
+        Self: 'b,
+    {
+        self.0.as_slice(offset, length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reflex::core::NodeId;
+
+    use crate::{
+        allocator::{FreelistAllocator, VecAllocator},
+        term_type::{IntTerm, ListTerm, StringTerm, TermType},
+    };
+
+    use super::*;
+
+    fn get_term_id(arena: &VecAllocator, pointer: ArenaPointer) -> u64 {
+        ArenaRef::<Term, _>::new(arena, pointer).id()
+    }
+
+    #[test]
+    fn collect_unreachable_terms() {
+        let mut arena = VecAllocator::default();
+        let start_offset = arena.start_offset();
+        let _unreachable = arena.allocate(Term::new(TermType::Int(IntTerm::from(1)), &arena));
+        let item = arena.allocate(Term::new(TermType::Int(IntTerm::from(2)), &arena));
+        let _unreachable = arena.allocate(Term::new(TermType::Int(IntTerm::from(3)), &arena));
+        let list = ListTerm::allocate([item, item], &mut arena);
+        let _unreachable = ListTerm::allocate([item], &mut arena);
+        let root = arena.allocate(Term::new(TermType::Int(IntTerm::from(4)), &arena));
+        let list_id = get_term_id(&arena, list);
+        let root_id = get_term_id(&arena, root);
+        let end_offset = arena.end_offset();
+
+        let mut roots = [list, root];
+        let stats = arena.collect_garbage(start_offset, &mut roots);
+
+        assert_eq!(stats.live_terms, 3);
+        assert_eq!(stats.collected_terms, 3);
+        assert_eq!(
+            u32::from(arena.end_offset()),
+            u32::from(end_offset) - stats.reclaimed_bytes as u32,
+        );
+        let [list, root] = roots;
+        assert_eq!(get_term_id(&arena, list), list_id);
+        assert_eq!(get_term_id(&arena, root), root_id);
+        let items = ArenaRef::<Term, _>::new(&arena, list)
+            .as_list_term()
+            .unwrap()
+            .as_inner()
+            .iter()
+            .map(|item| item.as_int_term().map(|term| term.as_inner().value()))
+            .collect::<Vec<_>>();
+        assert_eq!(items, vec![Some(2), Some(2)]);
+        assert_eq!(
+            ArenaIterator::<Term, _>::new(&arena, start_offset, arena.end_offset()).count(),
+            3,
+        );
+    }
+
+    #[test]
+    fn remap_interned_strings() {
+        let mut arena = VecAllocator::default();
+        let start_offset = arena.start_offset();
+        let _unreachable = StringTerm::allocate("foo", &mut arena);
+        let bar = StringTerm::allocate("bar", &mut arena);
+
+        let mut roots = [bar];
+        arena.collect_garbage(start_offset, &mut roots);
+
+        let [bar] = roots;
+        assert_eq!(StringTerm::allocate("bar", &mut arena), bar);
+        let end_offset = arena.end_offset();
+        let foo = StringTerm::allocate("foo", &mut arena);
+        assert_eq!(foo, end_offset);
+        assert_eq!(
+            ArenaRef::<Term, _>::new(&arena, foo)
+                .as_string_term()
+                .map(|term| term.read_str(str::to_owned)),
+            Some(String::from("foo")),
+        );
+    }
+
+    #[test]
+    fn preserve_terms_before_start_offset() {
+        let mut arena = VecAllocator::default();
+        let permanent = arena.allocate(Term::new(TermType::Int(IntTerm::from(1)), &arena));
+        let start_offset = arena.end_offset();
+        let _unreachable = arena.allocate(Term::new(TermType::Int(IntTerm::from(2)), &arena));
+        let list = ListTerm::allocate([permanent], &mut arena);
+
+        let mut roots = [list, permanent];
+        let stats = arena.collect_garbage(start_offset, &mut roots);
+
+        assert_eq!(stats.live_terms, 1);
+        assert_eq!(stats.collected_terms, 1);
+        let [list, root] = roots;
+        assert_eq!(list, start_offset);
+        assert_eq!(root, permanent);
+        let items = ArenaRef::<Term, _>::new(&arena, list)
+            .as_list_term()
+            .unwrap()
+            .as_inner()
+            .iter()
+            .map(|item| item.pointer)
+            .collect::<Vec<_>>();
+        assert_eq!(items, vec![permanent]);
+    }
+
+    #[test]
+    fn compact_remapping() {
+        let mut arena = VecAllocator::default();
+        let permanent = arena.allocate(Term::new(TermType::Int(IntTerm::from(1)), &arena));
+        let start_offset = arena.end_offset();
+        let unreachable = arena.allocate(Term::new(TermType::Int(IntTerm::from(2)), &arena));
+        let item = arena.allocate(Term::new(TermType::Int(IntTerm::from(3)), &arena));
+        let list = ListTerm::allocate([permanent, item], &mut arena);
+        let list_id = get_term_id(&arena, list);
+        let end_offset = arena.end_offset();
+
+        let (remapping, stats) = arena.compact(start_offset, [list]);
+
+        assert_eq!(stats.live_terms, 2);
+        assert_eq!(stats.collected_terms, 1);
+        assert_eq!(remapping.len(), 2);
+        assert_eq!(remapping.start_offset(), start_offset);
+        assert_eq!(remapping.end_offset(), end_offset);
+        assert_eq!(remapping.get(permanent), Some(permanent));
+        assert_eq!(remapping.get(unreachable), None);
+        assert_eq!(remapping.get(item), Some(start_offset));
+        let relocated_list = remapping.get(list).unwrap();
+        assert_eq!(get_term_id(&arena, relocated_list), list_id);
+        let items = ArenaRef::<Term, _>::new(&arena, relocated_list)
+            .as_list_term()
+            .unwrap()
+            .as_inner()
+            .iter()
+            .map(|item| item.pointer)
+            .collect::<Vec<_>>();
+        assert_eq!(items, vec![permanent, start_offset]);
+        let mut entries = remapping.iter().collect::<Vec<_>>();
+        entries.sort_by_key(|(source, _)| u32::from(*source));
+        assert_eq!(entries, vec![(item, start_offset), (list, relocated_list)]);
+    }
+
+    #[test]
+    fn empty_roots() {
+        let mut arena = VecAllocator::default();
+        let start_offset = arena.start_offset();
+        let _unreachable = arena.allocate(Term::new(TermType::Int(IntTerm::from(1)), &arena));
+        let stats = arena.collect_garbage(start_offset, &mut []);
+        assert_eq!(stats.live_terms, 0);
+        assert_eq!(stats.collected_terms, 1);
+        assert_eq!(arena.end_offset(), start_offset);
+    }
+
+    #[test]
+    fn reuse_released_terms() {
+        let mut arena = FreelistAllocator::new(VecAllocator::default());
+        let start_offset = arena.start_offset();
+        let permanent = arena.allocate_fixed(Term::new(TermType::Int(IntTerm::from(1)), &arena));
+        let temporary = arena.allocate_fixed(Term::new(TermType::Int(IntTerm::from(2)), &arena));
+        let end_offset = arena.end_offset();
+
+        arena.release(temporary);
+        assert!(arena.free_bytes() > 0);
+        let reused = arena.allocate_fixed(Term::new(TermType::Int(IntTerm::from(3)), &arena));
+
+        assert_eq!(reused, temporary);
+        assert_eq!(arena.end_offset(), end_offset);
+        assert_eq!(arena.free_bytes(), 0);
+        let values = ArenaIterator::<Term, _>::new(&arena, start_offset, arena.end_offset())
+            .map(|pointer| {
+                ArenaRef::<Term, _>::new(&*arena, pointer)
+                    .as_int_term()
+                    .map(|term| term.as_inner().value())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![Some(1), Some(3)]);
+
+        arena.release(reused);
+        let mut roots = [permanent];
+        let stats = arena.collect_garbage(start_offset, &mut roots);
+        assert_eq!(stats.collected_terms, 1);
+        assert_eq!(arena.free_bytes(), 0);
+        let end_offset = arena.end_offset();
+        let next = arena.allocate_fixed(Term::new(TermType::Int(IntTerm::from(4)), &arena));
+        assert_eq!(next, end_offset);
+    }
+}
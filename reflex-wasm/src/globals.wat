@@ -6,4 +6,10 @@
   (global $FALSE i32 (i32.const 0))
   (global $TRUE i32 (i32.const 1))
   ;; Sentinel value used to indicate missing values
-  (global $NULL (export "NULL") i32 (i32.const 0xFFFFFFFF)))
+  (global $NULL (export "NULL") i32 (i32.const 0xFFFFFFFF))
+  ;; Numeric semantics policy used by arithmetic builtins when handling integer overflow and non-finite float results
+  ;; (this is overridden by the compiler according to the configured runtime options)
+  (global $NUMERIC_SEMANTICS (export "NUMERIC_SEMANTICS") i32 (i32.const 0))
+  (global $NumericSemantics::WRAP i32 (i32.const 0))
+  (global $NumericSemantics::SATURATE i32 (i32.const 1))
+  (global $NumericSemantics::ERROR i32 (i32.const 2)))
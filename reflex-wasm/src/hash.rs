@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 // SPDX-FileContributor: Jordan Hall <j.hall@mwam.com> https://github.com/j-hall-mwam
+use serde::{Deserialize, Serialize};
+
 use crate::{
     utils::{u32_get_byte, u64_get_byte},
     Arena,
@@ -105,7 +107,7 @@ impl TermHash for f64 {
     }
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
 #[repr(transparent)]
 pub struct TermHashState(u64);
 
@@ -0,0 +1,558 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::hash::IntSet;
+use reflex_utils::{Visitable, WorkStack};
+use sha2::{Digest, Sha256};
+use strum::IntoEnumIterator;
+
+use crate::{
+    allocator::{Arena, ArenaMut},
+    hash::{TermHasher, TermSize},
+    pad_to_4_byte_offset,
+    stdlib::{Stdlib, StdlibDiscriminants},
+    term_type::{StringTerm, TermTypeDiscriminants, TypedTerm},
+    ArenaPointer, ArenaRef, Term,
+};
+
+/// Magic bytes identifying the start of a versioned heap snapshot file
+pub const HEAP_SNAPSHOT_MAGIC: [u8; 8] = *b"RFXHEAP\0";
+
+/// Current heap snapshot format version
+///
+/// Version `0` refers to legacy snapshots consisting of a raw linear memory dump with no header. Version `1` snapshots
+/// have the same header layout as the current version, but do not record the memory layout of the snapshot payload.
+pub const HEAP_SNAPSHOT_VERSION: u32 = 2;
+
+const HEADER_SIZE: usize = HEAP_SNAPSHOT_MAGIC.len() + 4 + 4 + 8 + 8 + 8;
+
+/// Metadata stored at the start of a heap snapshot file, used to validate the snapshot payload before loading it
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct HeapSnapshotHeader {
+    /// Snapshot format version
+    pub version: u32,
+    /// Memory layout of the runtime that produced the snapshot (unspecified for version `1` snapshots)
+    pub layout: HeapSnapshotLayout,
+    /// Fingerprint of the standard library builtins available within the runtime that produced the snapshot
+    pub stdlib_fingerprint: u64,
+    /// Length of the snapshot payload in bytes
+    pub payload_length: u64,
+    /// Checksum of the snapshot payload
+    pub checksum: u64,
+}
+
+impl HeapSnapshotHeader {
+    fn new(payload: &[u8]) -> Self {
+        Self {
+            version: HEAP_SNAPSHOT_VERSION,
+            layout: HeapSnapshotLayout::current(),
+            stdlib_fingerprint: stdlib_fingerprint(),
+            payload_length: payload.len() as u64,
+            checksum: payload_checksum(payload),
+        }
+    }
+    fn to_bytes(self) -> [u8; HEADER_SIZE] {
+        let mut bytes = [0u8; HEADER_SIZE];
+        let fields = HEAP_SNAPSHOT_MAGIC
+            .into_iter()
+            .chain(self.version.to_le_bytes())
+            .chain(self.layout.to_bytes())
+            .chain(self.stdlib_fingerprint.to_le_bytes())
+            .chain(self.payload_length.to_le_bytes())
+            .chain(self.checksum.to_le_bytes());
+        for (target, value) in bytes.iter_mut().zip(fields) {
+            *target = value;
+        }
+        bytes
+    }
+    /// Parse the header located at the start of an encoded heap snapshot, returning `None` for legacy headerless
+    /// snapshots
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let bytes = bytes.get(0..HEADER_SIZE)?;
+        let (magic, fields) = bytes.split_at(HEAP_SNAPSHOT_MAGIC.len());
+        if magic != HEAP_SNAPSHOT_MAGIC {
+            return None;
+        }
+        let read_u32 =
+            |offset: usize| u32::from_le_bytes(fields[offset..offset + 4].try_into().unwrap());
+        let read_u64 =
+            |offset: usize| u64::from_le_bytes(fields[offset..offset + 8].try_into().unwrap());
+        Some(Self {
+            version: read_u32(0),
+            layout: HeapSnapshotLayout::from_bytes(fields[4..8].try_into().unwrap()),
+            stdlib_fingerprint: read_u64(8),
+            payload_length: read_u64(16),
+            checksum: read_u64(24),
+        })
+    }
+}
+
+/// Memory layout of the terms contained within a heap snapshot payload
+///
+/// Heap snapshots are raw memory dumps, so can only be loaded by a runtime whose term representation matches that of
+/// the runtime that produced the snapshot.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct HeapSnapshotLayout {
+    /// Whether multi-byte values within the payload are stored in big-endian byte order
+    pub big_endian: bool,
+    /// Size of a heap pointer in bytes
+    pub pointer_size: u8,
+    /// Size of the term header and largest term variant in bytes
+    pub term_size: u16,
+}
+
+impl HeapSnapshotLayout {
+    /// Memory layout of the current runtime
+    pub fn current() -> Self {
+        Self {
+            big_endian: cfg!(target_endian = "big"),
+            pointer_size: std::mem::size_of::<ArenaPointer>() as u8,
+            term_size: std::mem::size_of::<Term>() as u16,
+        }
+    }
+    fn to_bytes(self) -> [u8; 4] {
+        let [term_size_low, term_size_high] = self.term_size.to_le_bytes();
+        [
+            self.big_endian as u8,
+            self.pointer_size,
+            term_size_low,
+            term_size_high,
+        ]
+    }
+    fn from_bytes(bytes: [u8; 4]) -> Self {
+        let [big_endian, pointer_size, term_size_low, term_size_high] = bytes;
+        Self {
+            big_endian: big_endian != 0,
+            pointer_size,
+            term_size: u16::from_le_bytes([term_size_low, term_size_high]),
+        }
+    }
+}
+
+impl std::fmt::Display for HeapSnapshotLayout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}-endian, {}-byte pointers, {}-byte terms",
+            if self.big_endian { "big" } else { "little" },
+            self.pointer_size,
+            self.term_size
+        )
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum HeapSnapshotError {
+    /// The snapshot file is shorter than the length recorded in its header
+    Truncated { expected: usize, actual: usize },
+    /// The snapshot was produced by a newer format version, or by an older version for which no migration exists
+    UnsupportedVersion(u32),
+    /// The snapshot was produced by a runtime with a different set of standard library builtins
+    StdlibMismatch { expected: u64, actual: u64 },
+    /// The snapshot was produced by a runtime with an incompatible memory layout
+    LayoutMismatch {
+        expected: HeapSnapshotLayout,
+        actual: HeapSnapshotLayout,
+    },
+    /// The snapshot payload does not match the checksum recorded in its header
+    ChecksumMismatch { expected: u64, actual: u64 },
+    /// Failed to migrate a snapshot from an older format version
+    Migration { version: u32, message: String },
+}
+
+impl std::error::Error for HeapSnapshotError {}
+
+impl std::fmt::Display for HeapSnapshotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Truncated { expected, actual } => write!(
+                f,
+                "Truncated heap snapshot: expected {expected} bytes, received {actual} bytes"
+            ),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "Unsupported heap snapshot version: {version}")
+            }
+            Self::StdlibMismatch { expected, actual } => write!(
+                f,
+                "Heap snapshot standard library mismatch: expected {expected:016x}, received {actual:016x}"
+            ),
+            Self::LayoutMismatch { expected, actual } => write!(
+                f,
+                "Heap snapshot memory layout mismatch: expected {expected}, received {actual}"
+            ),
+            Self::ChecksumMismatch { expected, actual } => write!(
+                f,
+                "Heap snapshot checksum mismatch: expected {expected:016x}, received {actual:016x}"
+            ),
+            Self::Migration { version, message } => write!(
+                f,
+                "Failed to migrate heap snapshot from version {version}: {message}"
+            ),
+        }
+    }
+}
+
+/// Prefix the given linear memory contents with a versioned snapshot header
+///
+/// Any values wrapped in sensitive terms are redacted from the snapshot payload (see [`redact_sensitive_terms`]).
+pub fn encode_heap_snapshot(heap: &[u8]) -> Vec<u8> {
+    let mut payload = heap.to_vec();
+    redact_sensitive_terms(&mut payload);
+    let header = HeapSnapshotHeader::new(&payload);
+    let mut bytes = Vec::with_capacity(HEADER_SIZE + payload.len());
+    bytes.extend_from_slice(&header.to_bytes());
+    bytes.extend_from_slice(&payload);
+    bytes
+}
+
+/// Placeholder byte written in place of the contents of any redacted strings
+const REDACTED_STRING_BYTE: u8 = b'*';
+
+/// Overwrite the contents of any strings reachable from sensitive terms within the given linear memory contents
+///
+/// Redacted strings retain their original length so that the memory layout is unaffected, and the hashes of the
+/// redacted terms (and of any terms that refer to them) are recomputed so that the snapshot does not retain a
+/// fingerprint of the original values. Note that any other references to the redacted strings will also observe the
+/// placeholder contents.
+///
+/// Only the leading sequence of well-formed terms is inspected, so payloads that do not consist of a valid sequence of
+/// terms are left untouched beyond the first unrecognized term.
+pub fn redact_sensitive_terms(heap: &mut [u8]) {
+    let (redacted_strings, rehashed_terms) = {
+        let arena = &*heap;
+        let terms = get_heap_terms(arena);
+        let is_term = {
+            let terms = terms.iter().copied().collect::<IntSet<_>>();
+            move |pointer: ArenaPointer| terms.contains(&pointer)
+        };
+        let get_children = |pointer: ArenaPointer| {
+            Visitable::<ArenaPointer>::children(&ArenaRef::<Term, _>::new(arena, pointer))
+                .map(|field| arena.read_value::<ArenaPointer, _>(field, |target| *target))
+                .filter(|target| is_term(*target))
+                .collect::<Vec<_>>()
+        };
+        // Mark all terms that are reachable from sensitive terms
+        let mut redacted = IntSet::<ArenaPointer>::default();
+        let mut stack = WorkStack::default();
+        stack.extend(terms.iter().copied().filter(|pointer| {
+            arena.read_value::<Term, _>(*pointer, |term| term.type_id())
+                == TermTypeDiscriminants::Sensitive
+        }));
+        while let Some(pointer) = stack.pop() {
+            if !redacted.insert(pointer) {
+                continue;
+            }
+            stack.extend(
+                get_children(pointer)
+                    .into_iter()
+                    .filter(|target| !redacted.contains(target)),
+            );
+        }
+        if redacted.is_empty() {
+            return;
+        }
+        let redacted_strings = terms
+            .iter()
+            .copied()
+            .filter(|pointer| redacted.contains(pointer))
+            .filter(|pointer| {
+                arena.read_value::<Term, _>(*pointer, |term| term.type_id())
+                    == TermTypeDiscriminants::String
+            })
+            .map(|pointer| {
+                let term = ArenaRef::<TypedTerm<StringTerm>, _>::new(arena, pointer).as_inner();
+                (term.inner_pointer(|term| &term.data.items), term.len())
+            })
+            .collect::<Vec<_>>();
+        // Terms are allocated after the terms they refer to, so a single pass in allocation order is sufficient to
+        // determine all the terms whose hashes are affected by the redaction
+        let mut rehashed = redacted;
+        let rehashed_terms = terms
+            .iter()
+            .copied()
+            .filter(|pointer| {
+                let is_affected = rehashed.contains(pointer)
+                    || get_children(*pointer)
+                        .into_iter()
+                        .any(|target| rehashed.contains(&target));
+                if is_affected {
+                    rehashed.insert(*pointer);
+                }
+                is_affected
+            })
+            .collect::<Vec<_>>();
+        (redacted_strings, rehashed_terms)
+    };
+    for (offset, length) in redacted_strings {
+        let offset = u32::from(offset) as usize;
+        heap[offset..(offset + length)].fill(REDACTED_STRING_BYTE);
+    }
+    for pointer in rehashed_terms {
+        let hash = {
+            let arena = &*heap;
+            arena.read_value::<Term, _>(pointer, |term| {
+                TermHasher::default().hash(term, &arena).finish()
+            })
+        };
+        (&mut *heap).write::<u64>(Term::get_hash_pointer(pointer), u64::from(hash));
+    }
+}
+
+/// Determine the locations of the leading sequence of well-formed terms within the given linear memory contents
+fn get_heap_terms(heap: &[u8]) -> Vec<ArenaPointer> {
+    let header_size = std::mem::size_of::<u32>();
+    if heap.len() < header_size {
+        return Vec::new();
+    }
+    // The initial 4-byte allocator offset marker can exceed the payload length if the payload has been truncated
+    let end_offset =
+        (heap.read_value::<u32, _>(ArenaPointer::from(0), |value| *value) as usize).min(heap.len());
+    let mut results = Vec::new();
+    let mut offset = header_size;
+    while offset + std::mem::size_of::<u64>() + std::mem::size_of::<u32>() <= end_offset {
+        let pointer = ArenaPointer::from(offset as u32);
+        let type_id = heap.read_value::<u32, _>(Term::get_value_pointer(pointer), |value| *value);
+        if TermTypeDiscriminants::try_from(type_id).is_err() {
+            break;
+        }
+        let size = pad_to_4_byte_offset(heap.read_value::<Term, _>(pointer, |term| term.size_of()));
+        if offset + size > end_offset {
+            break;
+        }
+        results.push(pointer);
+        offset += size;
+    }
+    results
+}
+
+/// Validate the header of an encoded heap snapshot, returning the linear memory contents
+///
+/// Snapshots produced by older format versions are rejected. See [`decode_heap_snapshot_with_migration`] for loading
+/// snapshots produced by older format versions.
+pub fn decode_heap_snapshot(bytes: &[u8]) -> Result<Vec<u8>, HeapSnapshotError> {
+    decode_heap_snapshot_with_migration(bytes, |version, _| {
+        Err(HeapSnapshotError::UnsupportedVersion(version))
+    })
+}
+
+/// Validate the header of an encoded heap snapshot, returning the linear memory contents
+///
+/// The provided migration is invoked with the format version and payload of any snapshot produced by an older format
+/// version (including legacy headerless snapshots, which are treated as version `0`), and is responsible for converting
+/// the payload to the current format.
+pub fn decode_heap_snapshot_with_migration(
+    bytes: &[u8],
+    migrate: impl FnOnce(u32, Vec<u8>) -> Result<Vec<u8>, HeapSnapshotError>,
+) -> Result<Vec<u8>, HeapSnapshotError> {
+    let header = match HeapSnapshotHeader::from_bytes(bytes) {
+        Some(header) => header,
+        None => return migrate(0, bytes.to_vec()),
+    };
+    if header.version > HEAP_SNAPSHOT_VERSION {
+        return Err(HeapSnapshotError::UnsupportedVersion(header.version));
+    }
+    let payload = &bytes[HEADER_SIZE..];
+    if (payload.len() as u64) < header.payload_length {
+        return Err(HeapSnapshotError::Truncated {
+            expected: HEADER_SIZE + header.payload_length as usize,
+            actual: bytes.len(),
+        });
+    }
+    let payload = &payload[0..(header.payload_length as usize)];
+    let checksum = payload_checksum(payload);
+    if checksum != header.checksum {
+        return Err(HeapSnapshotError::ChecksumMismatch {
+            expected: header.checksum,
+            actual: checksum,
+        });
+    }
+    let fingerprint = stdlib_fingerprint();
+    if header.stdlib_fingerprint != fingerprint {
+        return Err(HeapSnapshotError::StdlibMismatch {
+            expected: fingerprint,
+            actual: header.stdlib_fingerprint,
+        });
+    }
+    // Snapshots produced by older format versions do not record their memory layout
+    if header.version >= 2 {
+        let layout = HeapSnapshotLayout::current();
+        if header.layout != layout {
+            return Err(HeapSnapshotError::LayoutMismatch {
+                expected: layout,
+                actual: header.layout,
+            });
+        }
+    }
+    if header.version < HEAP_SNAPSHOT_VERSION {
+        migrate(header.version, payload.to_vec())
+    } else {
+        Ok(payload.to_vec())
+    }
+}
+
+/// Migration for use with [`decode_heap_snapshot_with_migration`] that accepts snapshots produced by older format
+/// versions as-is
+///
+/// Older snapshots carry no layout information (and legacy headerless snapshots carry no integrity information), so
+/// this should only be used by tooling that is able to cope with corrupt heap contents.
+pub fn accept_legacy_heap_snapshot(
+    version: u32,
+    payload: Vec<u8>,
+) -> Result<Vec<u8>, HeapSnapshotError> {
+    match version {
+        0 | 1 => Ok(payload),
+        version => Err(HeapSnapshotError::UnsupportedVersion(version)),
+    }
+}
+
+/// Compute a fingerprint identifying the set of standard library builtins compiled into the current runtime
+///
+/// Heap snapshots refer to builtins by their discriminant, so snapshots can only be loaded by a runtime whose standard
+/// library has the same fingerprint as the runtime that produced the snapshot.
+pub fn stdlib_fingerprint() -> u64 {
+    let mut hasher = Sha256::new();
+    for builtin in Stdlib::iter() {
+        hasher.update((StdlibDiscriminants::from(&builtin) as u32).to_le_bytes());
+        hasher.update(builtin.uid().as_bytes());
+    }
+    truncate_digest(hasher.finalize().as_slice())
+}
+
+fn payload_checksum(payload: &[u8]) -> u64 {
+    truncate_digest(Sha256::digest(payload).as_slice())
+}
+
+fn truncate_digest(digest: &[u8]) -> u64 {
+    u64::from_le_bytes(digest[0..8].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        allocator::{ArenaAllocator, VecAllocator},
+        compiler::CompilerState,
+        term_type::{IntTerm, ListTerm, SensitiveTerm, TermType},
+    };
+
+    use super::*;
+
+    #[test]
+    fn heap_snapshot_roundtrip() {
+        let heap = (0..64u8).collect::<Vec<_>>();
+        let bytes = encode_heap_snapshot(&heap);
+        assert_eq!(bytes.len(), HEADER_SIZE + heap.len());
+        assert_eq!(decode_heap_snapshot(&bytes), Ok(heap));
+    }
+
+    #[test]
+    fn invalid_heap_snapshots() {
+        let heap = (0..64u8).collect::<Vec<_>>();
+        let bytes = encode_heap_snapshot(&heap);
+        assert_eq!(
+            decode_heap_snapshot(&bytes[0..(bytes.len() - 4)]),
+            Err(HeapSnapshotError::Truncated {
+                expected: bytes.len(),
+                actual: bytes.len() - 4,
+            }),
+        );
+        let mut corrupted = bytes.clone();
+        corrupted[HEADER_SIZE] ^= 0xff;
+        assert!(matches!(
+            decode_heap_snapshot(&corrupted),
+            Err(HeapSnapshotError::ChecksumMismatch { .. })
+        ));
+        let mut mismatched = bytes.clone();
+        mismatched[16] ^= 0xff;
+        assert_eq!(
+            decode_heap_snapshot(&mismatched),
+            Err(HeapSnapshotError::StdlibMismatch {
+                expected: stdlib_fingerprint(),
+                actual: stdlib_fingerprint() ^ 0xff,
+            }),
+        );
+        let mut incompatible = bytes.clone();
+        incompatible[12] ^= 1;
+        assert_eq!(
+            decode_heap_snapshot(&incompatible),
+            Err(HeapSnapshotError::LayoutMismatch {
+                expected: HeapSnapshotLayout::current(),
+                actual: HeapSnapshotLayout {
+                    big_endian: !HeapSnapshotLayout::current().big_endian,
+                    ..HeapSnapshotLayout::current()
+                },
+            }),
+        );
+        let mut future = bytes.clone();
+        future[8..12].copy_from_slice(&(HEAP_SNAPSHOT_VERSION + 1).to_le_bytes());
+        assert_eq!(
+            decode_heap_snapshot(&future),
+            Err(HeapSnapshotError::UnsupportedVersion(
+                HEAP_SNAPSHOT_VERSION + 1
+            )),
+        );
+    }
+
+    #[test]
+    fn legacy_heap_snapshots() {
+        let heap = (0..64u8).collect::<Vec<_>>();
+        assert_eq!(
+            decode_heap_snapshot(&heap),
+            Err(HeapSnapshotError::UnsupportedVersion(0)),
+        );
+        assert_eq!(
+            decode_heap_snapshot_with_migration(&heap, accept_legacy_heap_snapshot),
+            Ok(heap.clone()),
+        );
+        let mut unversioned_layout = encode_heap_snapshot(&heap);
+        unversioned_layout[8..12].copy_from_slice(&1u32.to_le_bytes());
+        unversioned_layout[12..16].copy_from_slice(&[0; 4]);
+        assert_eq!(
+            decode_heap_snapshot(&unversioned_layout),
+            Err(HeapSnapshotError::UnsupportedVersion(1)),
+        );
+        assert_eq!(
+            decode_heap_snapshot_with_migration(&unversioned_layout, |version, payload| {
+                assert_eq!(version, 1);
+                Ok(payload.into_iter().rev().collect())
+            }),
+            Ok(heap.into_iter().rev().collect()),
+        );
+    }
+
+    #[test]
+    fn sensitive_heap_snapshot_values() {
+        let allocate_heap = |secret: &str| {
+            let mut arena = VecAllocator::default();
+            let public = StringTerm::allocate("public", &mut arena);
+            let secret = StringTerm::allocate(secret, &mut arena);
+            let sensitive = arena.allocate(Term::new(
+                TermType::Sensitive(SensitiveTerm { value: secret }),
+                &arena,
+            ));
+            ListTerm::allocate([public, sensitive], &mut arena);
+            arena
+        };
+        let arena = allocate_heap("secret");
+        let bytes = encode_heap_snapshot(arena.as_bytes());
+        let heap = decode_heap_snapshot(&bytes).unwrap();
+        assert!(!heap.windows(6).any(|bytes| bytes == b"secret"));
+        assert!(heap.windows(6).any(|bytes| bytes == b"public"));
+        assert_eq!(heap, allocate_heap("******").as_bytes());
+    }
+
+    #[test]
+    fn compiler_state_from_heap_snapshot() {
+        let mut arena = VecAllocator::default();
+        arena.allocate(Term::new(TermType::Int(IntTerm::from(3)), &arena));
+        let mut bytes = encode_heap_snapshot(arena.as_bytes());
+        let state =
+            CompilerState::from_encoded_heap_snapshot::<Term>(&bytes, accept_legacy_heap_snapshot)
+                .unwrap();
+        assert_eq!(state.heap.as_bytes(), arena.as_bytes());
+        bytes[13] = bytes[13].wrapping_add(1);
+        assert!(matches!(
+            CompilerState::from_encoded_heap_snapshot::<Term>(&bytes, accept_legacy_heap_snapshot),
+            Err(HeapSnapshotError::LayoutMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,170 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BinaryHeap},
+};
+
+use serde::Serialize;
+
+use crate::{
+    allocator::{Arena, ArenaIterator},
+    hash::TermSize,
+    pad_to_4_byte_offset,
+    term_type::TermTypeDiscriminants,
+    ArenaPointer, Term,
+};
+
+/// Snapshot of the memory usage of the terms allocated within an arena region
+#[derive(PartialEq, Eq, Clone, Default, Debug, Serialize)]
+pub struct HeapUsageStats {
+    /// Total number of bytes occupied by allocated terms (including alignment padding)
+    pub allocated_bytes: usize,
+    /// Total number of allocated terms
+    pub num_terms: usize,
+    /// Total number of bytes lost to padding terms to 4-byte alignment
+    pub padding_bytes: usize,
+    /// Allocation totals for each term type present within the arena region
+    pub term_types: BTreeMap<TermTypeDiscriminants, TermTypeUsageStats>,
+    /// Largest individual terms within the arena region, ordered by descending size
+    pub largest_terms: Vec<TermAllocationStats>,
+}
+
+/// Aggregate allocation statistics for a single term type
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug, Serialize)]
+pub struct TermTypeUsageStats {
+    pub num_terms: usize,
+    pub allocated_bytes: usize,
+}
+
+/// Allocation statistics for an individual term
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize)]
+pub struct TermAllocationStats {
+    #[serde(serialize_with = "serialize_pointer")]
+    pub pointer: ArenaPointer,
+    pub term_type: TermTypeDiscriminants,
+    pub allocated_bytes: usize,
+}
+
+fn serialize_pointer<S: serde::Serializer>(
+    pointer: &ArenaPointer,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u32(u32::from(*pointer))
+}
+
+/// Compute memory usage statistics for the sequence of terms located between the given offsets, retaining details of
+/// the given number of largest terms
+pub fn get_heap_usage_stats(
+    arena: &impl Arena,
+    start_offset: ArenaPointer,
+    end_offset: ArenaPointer,
+    num_largest_terms: usize,
+) -> HeapUsageStats {
+    let mut stats = HeapUsageStats::default();
+    // Min-heap of the largest terms encountered so far, ordered by size and then by reverse address (so that earlier
+    // terms take precedence over later terms of the same size)
+    let mut largest_terms = BinaryHeap::with_capacity(num_largest_terms + 1);
+    for pointer in ArenaIterator::<Term, _>::new(arena, start_offset, end_offset) {
+        let (term_type, size) =
+            arena.read_value::<Term, _>(pointer, |term| (term.type_id(), term.size_of()));
+        let allocated_bytes = pad_to_4_byte_offset(size);
+        stats.allocated_bytes += allocated_bytes;
+        stats.num_terms += 1;
+        stats.padding_bytes += allocated_bytes - size;
+        let type_stats = stats.term_types.entry(term_type).or_default();
+        type_stats.num_terms += 1;
+        type_stats.allocated_bytes += allocated_bytes;
+        if num_largest_terms > 0 {
+            largest_terms.push(Reverse((allocated_bytes, Reverse(pointer), term_type)));
+            if largest_terms.len() > num_largest_terms {
+                largest_terms.pop();
+            }
+        }
+    }
+    stats.largest_terms = largest_terms
+        .into_sorted_vec()
+        .into_iter()
+        .map(
+            |Reverse((allocated_bytes, Reverse(pointer), term_type))| TermAllocationStats {
+                pointer,
+                term_type,
+                allocated_bytes,
+            },
+        )
+        .collect();
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        allocator::{ArenaAllocator, VecAllocator},
+        term_type::{IntTerm, ListTerm, TermType},
+    };
+
+    use super::*;
+
+    #[test]
+    fn heap_usage_stats() {
+        let mut arena = VecAllocator::default();
+        let first = arena.allocate(Term::new(TermType::Int(IntTerm::from(1)), &arena));
+        let second = arena.allocate(Term::new(TermType::Int(IntTerm::from(2)), &arena));
+        let list = ListTerm::allocate([first, second], &mut arena);
+        let long_list = ListTerm::allocate([first, second, first], &mut arena);
+        let int_size = arena.read_value::<Term, _>(first, |term| term.size_of());
+        let list_size = arena.read_value::<Term, _>(list, |term| term.size_of());
+        let long_list_size = arena.read_value::<Term, _>(long_list, |term| term.size_of());
+
+        let stats = arena.heap_usage_stats(2);
+
+        assert_eq!(stats.num_terms, 4);
+        assert_eq!(
+            stats.allocated_bytes,
+            (u32::from(arena.end_offset()) - u32::from(arena.start_offset())) as usize,
+        );
+        assert_eq!(
+            stats.padding_bytes,
+            stats.allocated_bytes - (2 * int_size + list_size + long_list_size),
+        );
+        assert_eq!(
+            stats.term_types.get(&TermTypeDiscriminants::Int),
+            Some(&TermTypeUsageStats {
+                num_terms: 2,
+                allocated_bytes: 2 * pad_to_4_byte_offset(int_size),
+            }),
+        );
+        assert_eq!(
+            stats.term_types.get(&TermTypeDiscriminants::List),
+            Some(&TermTypeUsageStats {
+                num_terms: 2,
+                allocated_bytes: pad_to_4_byte_offset(list_size)
+                    + pad_to_4_byte_offset(long_list_size),
+            }),
+        );
+        assert_eq!(stats.term_types.len(), 2);
+        assert_eq!(
+            stats
+                .largest_terms
+                .iter()
+                .map(|term| term.pointer)
+                .collect::<Vec<_>>(),
+            vec![long_list, list],
+        );
+        assert_eq!(
+            stats.largest_terms[0].term_type,
+            TermTypeDiscriminants::List
+        );
+        assert_eq!(
+            stats.largest_terms[0].allocated_bytes,
+            pad_to_4_byte_offset(long_list_size),
+        );
+    }
+
+    #[test]
+    fn empty_heap_usage_stats() {
+        let arena = VecAllocator::default();
+        assert_eq!(arena.heap_usage_stats(10), HeapUsageStats::default());
+    }
+}
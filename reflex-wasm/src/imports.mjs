@@ -38,25 +38,51 @@ export default function imports(wasi, getModule) {
     Number: {
       toString: (value, offset) => {
         const instance = getModule();
-        const valueString = (() => {
-          if (isNaN(value)) {
-            return 'NaN';
-          } else if (value === Infinity) {
-            return 'Infinity';
-          } else if (value === -Infinity) {
-            return '-Infinity';
-          } else if (value === Math.floor(value)) {
-            return value.toFixed(1);
-          } else {
-            return value.toString(10);
-          }
-        })();
-        const bytes = new TextEncoder().encode(valueString);
+        const bytes = new TextEncoder().encode(formatFloat(value));
+        const length = bytes.length;
+        instance.exports.allocate(length);
+        new Uint8Array(instance.exports.memory.buffer, offset, length).set(bytes);
+        return length;
+      },
+      toCanonicalString: (value, offset) => {
+        const instance = getModule();
+        // The ECMAScript number formatting algorithm produces the canonical JSON number representation
+        const bytes = new TextEncoder().encode(String(value));
         const length = bytes.length;
         instance.exports.allocate(length);
         new Uint8Array(instance.exports.memory.buffer, offset, length).set(bytes);
         return length;
       },
+      parseInt: (offset, length, radix, strict) => {
+        const instance = getModule();
+        const value = (() => {
+          try {
+            return new TextDecoder('utf-8', { fatal: true }).decode(
+              new Uint8Array(instance.exports.memory.buffer, offset, length),
+            );
+          } catch {
+            return null;
+          }
+        })();
+        if (value === null) return NaN;
+        if (strict && !isStrictIntegerString(value, radix)) return NaN;
+        return parseInt(value, radix);
+      },
+      parseFloat: (offset, length, strict) => {
+        const instance = getModule();
+        const value = (() => {
+          try {
+            return new TextDecoder('utf-8', { fatal: true }).decode(
+              new Uint8Array(instance.exports.memory.buffer, offset, length),
+            );
+          } catch {
+            return null;
+          }
+        })();
+        if (value === null) return NaN;
+        if (strict && !isStrictFloatString(value)) return NaN;
+        return parseFloat(value);
+      },
     },
     Date: {
       parse: (offset, length) => {
@@ -76,10 +102,33 @@ export default function imports(wasi, getModule) {
         if (isNaN(timestamp)) return I64_MAX_VALUE;
         return BigInt(timestamp);
       },
-      toISOString: (timestamp, offset) => {
+      parseOffset: (offset, length) => {
+        const instance = getModule();
+        const dateString = (() => {
+          try {
+            return new TextDecoder('utf-8', { fatal: true }).decode(
+              new Uint8Array(instance.exports.memory.buffer, offset, length),
+            );
+          } catch {
+            return null;
+          }
+        })();
+        if (dateString === null) return 0;
+        const match = /T.*([+-])(\d{2}):(\d{2})$/.exec(dateString);
+        if (!match) return 0;
+        const [, sign, hours, minutes] = match;
+        return (sign === '-' ? -1 : 1) * (Number(hours) * 60 + Number(minutes));
+      },
+      toISOString: (timestamp, utcOffset, offset) => {
         const dateString = (() => {
           try {
-            return new Date(Number(timestamp)).toISOString();
+            // Format the local time at the given UTC offset, replacing the UTC suffix with the offset if non-zero
+            const isoString = new Date(Number(timestamp) + utcOffset * 60 * 1000).toISOString();
+            if (utcOffset === 0) return isoString;
+            const sign = utcOffset < 0 ? '-' : '+';
+            const hours = String(Math.floor(Math.abs(utcOffset) / 60)).padStart(2, '0');
+            const minutes = String(Math.abs(utcOffset) % 60).padStart(2, '0');
+            return `${isoString.slice(0, -1)}${sign}${hours}:${minutes}`;
           } catch {
             return null;
           }
@@ -93,6 +142,122 @@ export default function imports(wasi, getModule) {
         return length;
       },
     },
+    Regex: {
+      captureCount: (patternOffset, patternLength) => {
+        const instance = getModule();
+        const regex = getRegex(readString(instance, patternOffset, patternLength));
+        if (!regex) return -1;
+        // Count the capture groups by matching against an alternation that always succeeds
+        return new RegExp(`${regex.source}|`, regex.flags).exec('').length;
+      },
+      capture: (patternOffset, patternLength, targetOffset, targetLength, index) => {
+        const instance = getModule();
+        const regex = getRegex(readString(instance, patternOffset, patternLength));
+        const target = readString(instance, targetOffset, targetLength);
+        if (!regex || target === null) return BigInt(-1);
+        const match = regex.exec(target);
+        if (!match || !match.indices[index]) return BigInt(-1);
+        // Convert the UTF-16 match indices into UTF-8 byte offsets
+        const [startIndex, endIndex] = match.indices[index];
+        const start = getUtf8Length(target.slice(0, startIndex));
+        const end = start + getUtf8Length(target.slice(startIndex, endIndex));
+        return (BigInt(start) << BigInt(32)) | BigInt(end);
+      },
+      replace: (
+        patternOffset,
+        patternLength,
+        targetOffset,
+        targetLength,
+        replacementOffset,
+        replacementLength,
+        offset,
+      ) => {
+        const instance = getModule();
+        const regex = getRegex(readString(instance, patternOffset, patternLength));
+        const target = readString(instance, targetOffset, targetLength);
+        const replacement = readString(instance, replacementOffset, replacementLength);
+        if (!regex || target === null || replacement === null) return 0;
+        const bytes = new TextEncoder().encode(target.replace(regex, replacement));
+        const length = bytes.length;
+        instance.exports.allocate(length);
+        new Uint8Array(instance.exports.memory.buffer, offset, length).set(bytes);
+        return length;
+      },
+    },
     wasi_snapshot_preview1: wasi.wasiImport,
   };
 }
+
+const REGEX_CACHE = new Map();
+
+// Translate a pattern in the native regular expression syntax into a JavaScript RegExp (inline flags are only
+// supported as a prefix, mirroring the output of the JS parser)
+function getRegex(pattern) {
+  if (pattern === null) return null;
+  if (REGEX_CACHE.has(pattern)) return REGEX_CACHE.get(pattern);
+  const regex = (() => {
+    const [, flags = '', source] = /^(?:\(\?([ims]+)\))?([^]*)$/.exec(pattern);
+    try {
+      return new RegExp(source, `${flags}du`);
+    } catch {
+      return null;
+    }
+  })();
+  REGEX_CACHE.set(pattern, regex);
+  return regex;
+}
+
+function readString(instance, offset, length) {
+  try {
+    return new TextDecoder('utf-8', { fatal: true }).decode(
+      new Uint8Array(instance.exports.memory.buffer, offset, length),
+    );
+  } catch {
+    return null;
+  }
+}
+
+function getUtf8Length(value) {
+  return new TextEncoder().encode(value).length;
+}
+
+// Equivalent to the shortest-form float formatting used by the native backend (see reflex::core::format_float)
+function formatFloat(value) {
+  if (isNaN(value)) return 'NaN';
+  if (value === Infinity) return 'Infinity';
+  if (value === -Infinity) return '-Infinity';
+  if (value === 0) return '0.0';
+  const sign = value < 0 ? '-' : '';
+  // The exponential formatter yields the shortest digit sequence that uniquely identifies the value
+  const [mantissa, exponent] = Math.abs(value).toExponential().split('e');
+  const digits = mantissa.replace('.', '');
+  // Position of the decimal point relative to the start of the digit sequence
+  const point = Number(exponent) + 1;
+  if (digits.length <= point && point <= 16) {
+    return `${sign}${digits}${'0'.repeat(point - digits.length)}.0`;
+  } else if (0 < point && point <= 16) {
+    return `${sign}${digits.slice(0, point)}.${digits.slice(point)}`;
+  } else if (-5 < point && point <= 0) {
+    return `${sign}0.${'0'.repeat(-point)}${digits}`;
+  } else {
+    const fraction = digits.length > 1 ? `.${digits.slice(1)}` : '';
+    return `${sign}${digits.slice(0, 1)}${fraction}e${point - 1}`;
+  }
+}
+
+// Determine whether the entire input (ignoring surrounding whitespace) forms a valid integer in the given radix
+function isStrictIntegerString(value, radix) {
+  const input = value.trim().replace(/^[+-]/, '');
+  const digits = (radix === 0 || radix === 16) && /^0x/i.test(input) ? input.slice(2) : input;
+  const base = radix === 0 ? (digits === input ? 10 : 16) : radix;
+  if (!(base >= 2 && base <= 36) || digits.length === 0) return false;
+  return Array.from(digits).every((char) => {
+    const digit = parseInt(char, 36);
+    return !isNaN(digit) && digit < base;
+  });
+}
+
+// Determine whether the entire input (ignoring surrounding whitespace) forms a valid decimal number literal
+function isStrictFloatString(value) {
+  return /^[+-]?(Infinity|(\d+\.?\d*|\.\d+)([eE][+-]?\d+)?)$/.test(value.trim());
+}
@@ -4,26 +4,39 @@
 // SPDX-FileContributor: Jordan Hall <j.hall@mwam.com> https://github.com/j-hall-mwam
 use std::{
     cell::{Ref, RefCell},
+    future::Future,
     ops::{Deref, DerefMut},
-    path::Path,
+    path::{Path, PathBuf},
     rc::Rc,
 };
 
 use reflex_utils::Visitable;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use strum::IntoEnumIterator;
+use wasi_common::pipe::{ReadPipe, WritePipe};
 use wasmtime::{
-    Engine, ExternType, Instance, IntoFunc, Linker, Memory, Module, Store, Val, WasmParams,
-    WasmResults,
+    Caller, Config, Engine, ExternType, Func, FuncType, Instance, InstanceAllocationStrategy,
+    IntoFunc, Linker, Memory, Module, Mutability, PoolingAllocationConfig, ResourceLimiter, Store,
+    Trap, TypedFunc, Val, ValType, WasmParams, WasmResults,
+};
+use wasmtime_wasi::{
+    sync::{ambient_authority, file::File as WasiFile, Dir, WasiCtxBuilder},
+    WasiCtx,
 };
-use wasmtime_wasi::{sync::WasiCtxBuilder, WasiCtx};
 
 use crate::{
     allocator::{Arena, ArenaAllocator, ArenaIterator, ArenaMut},
-    compiler::runtime::builtin::RuntimeBuiltin,
-    exports::add_wasm_runtime_imports,
-    hash::TermSize,
+    compiler::{runtime::builtin::RuntimeBuiltin, CompiledFunctionId},
+    debugger::{
+        WasmDebugger, WasmDebuggerEvent, DEBUGGER_ENTER_FUNCTION_HOOK, DEBUGGER_HOOK_MODULE,
+    },
+    exports::{add_wasm_host_imports, add_wasm_runtime_imports, WasmHostImports},
+    hash::{TermHashState, TermSize},
     pad_to_4_byte_offset,
-    term_type::{TreeTerm, TypedTerm},
+    region::ArenaRegions,
+    stdlib::{Stdlib, BUILTIN_FALLBACK_MODULE},
+    term_type::{PointerTerm, TreeTerm, TypedTerm},
     ArenaPointer, ArenaRef, Term, WASM_PAGE_SIZE,
 };
 
@@ -39,6 +52,8 @@ pub enum WasmCompilerMode {
 pub struct WasmProgram {
     pub(crate) compiler_mode: WasmCompilerMode,
     bytes: Vec<u8>,
+    #[serde(default)]
+    module_cache: Option<WasmModuleCache>,
 }
 
 impl WasmProgram {
@@ -46,20 +61,407 @@ impl WasmProgram {
         Self {
             compiler_mode: WasmCompilerMode::Wasm,
             bytes,
+            module_cache: None,
         }
     }
     pub fn from_cwasm(bytes: Vec<u8>) -> Self {
         Self {
             compiler_mode: WasmCompilerMode::Cranelift,
             bytes,
+            module_cache: None,
         }
     }
+    /// Reuse the natively-compiled module from the given on-disk cache whenever the program is instantiated
+    ///
+    /// This has no effect on Cranelift-precompiled programs, which do not need to be compiled when instantiated.
+    pub fn with_module_cache(self, module_cache: WasmModuleCache) -> Self {
+        Self {
+            module_cache: Some(module_cache),
+            ..self
+        }
+    }
+    pub fn module_cache(&self) -> Option<&WasmModuleCache> {
+        self.module_cache.as_ref()
+    }
     pub fn as_bytes(&self) -> &[u8] {
         &self.bytes
     }
     pub fn into_bytes(self) -> Vec<u8> {
         self.bytes
     }
+    /// Compile the program into a module that can be instantiated multiple times within the given engine
+    pub fn compile(
+        &self,
+        engine: &Engine,
+        options: &WasmEngineOptions,
+    ) -> Result<Module, InterpreterError> {
+        match (self.compiler_mode, self.module_cache()) {
+            (WasmCompilerMode::Wasm, Some(module_cache)) => {
+                module_cache.load_module(engine, self.as_bytes(), options)
+            }
+            (WasmCompilerMode::Wasm, None) => Module::from_binary(engine, self.as_bytes())
+                .map_err(InterpreterError::ModuleLoadError),
+            (WasmCompilerMode::Cranelift, _) => {
+                unsafe { Module::deserialize(engine, self.as_bytes()) }
+                    .map_err(InterpreterError::ModuleLoadError)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, Hash, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct WasmEngineOptions {
+    /// Instrument compiled code with fuel metering, limiting the amount of fuel that can be consumed by any individual
+    /// call into the module (precompiled Cranelift modules must have been compiled with fuel metering enabled)
+    pub fuel_limit: Option<u64>,
+    /// Maximum number of pages of linear memory that can be allocated by the interpreter instance (any call that
+    /// attempts to allocate beyond this limit will be aborted with an error rather than exhausting host memory)
+    pub max_memory_pages: Option<u32>,
+    /// Enable support for asynchronous host function imports (instances created with this option enabled must be
+    /// instantiated and invoked via the async builder and call methods)
+    pub async_support: bool,
+    /// Preallocate the resources for a fixed number of instances up-front, rather than allocating fresh resources
+    /// whenever a module is instantiated (see [`WasmPoolingOptions`])
+    #[serde(default)]
+    pub pooling: Option<WasmPoolingOptions>,
+}
+
+impl WasmEngineOptions {
+    pub fn create_engine(&self) -> Result<Engine, InterpreterError> {
+        let mut config = Config::default();
+        config.consume_fuel(self.fuel_limit.is_some());
+        config.async_support(self.async_support);
+        if let Some(pooling) = self.pooling {
+            config.allocation_strategy(InstanceAllocationStrategy::Pooling(
+                pooling.allocation_config(self.max_memory_pages),
+            ));
+        }
+        Engine::new(&config).map_err(InterpreterError::EngineConfigError)
+    }
+}
+
+/// Options for the wasmtime pooling instance allocator
+///
+/// The pooling allocator reserves the memories and tables for all instances when the engine is created, making each
+/// subsequent instantiation significantly cheaper. Instantiation fails if the number of live instances would exceed
+/// the pool size, or if a module requires more resources than are reserved for each instance slot.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct WasmPoolingOptions {
+    /// Maximum number of concurrently live instances
+    pub max_instances: u32,
+    /// Maximum number of function table elements for each instance
+    pub max_table_elements: u32,
+}
+
+impl Default for WasmPoolingOptions {
+    fn default() -> Self {
+        Self {
+            max_instances: 64,
+            max_table_elements: 1_000_000,
+        }
+    }
+}
+
+impl WasmPoolingOptions {
+    fn allocation_config(&self, max_memory_pages: Option<u32>) -> PoolingAllocationConfig {
+        let mut config = PoolingAllocationConfig::default();
+        config
+            .instance_count(self.max_instances)
+            .instance_table_elements(self.max_table_elements)
+            // Each instance slot reserves (but does not commit) enough address space for the maximum memory size
+            .instance_memory_pages(
+                max_memory_pages
+                    .map(u64::from)
+                    .unwrap_or(MAX_WASM_MEMORY_PAGES),
+            );
+        config
+    }
+}
+
+/// Maximum number of pages addressable by a 32-bit linear memory
+const MAX_WASM_MEMORY_PAGES: u64 = 0x10000;
+
+/// On-disk cache of natively-compiled WASM modules, keyed by the hash of the module bytes and engine options
+///
+/// Compiling a WASM module into native code typically dominates the interpreter startup time, so caching the compiled
+/// output allows subsequent instantiations of an unchanged module to skip compilation entirely. Cached modules are
+/// loaded without further validation, so the cache directory must only be writable by trusted processes.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WasmModuleCache {
+    directory: PathBuf,
+}
+
+impl WasmModuleCache {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+    /// Load the cached compiled module for the given WASM bytes if one exists, otherwise compile the module and store
+    /// the compiled output in the cache
+    ///
+    /// Failing to write to the cache is not treated as an error, as the compiled module is still usable. Cache entries
+    /// that cannot be loaded (e.g. entries created by an incompatible wasmtime version) are recompiled and overwritten.
+    pub fn load_module(
+        &self,
+        engine: &Engine,
+        bytes: &[u8],
+        options: &WasmEngineOptions,
+    ) -> Result<Module, InterpreterError> {
+        let entry_path = self.entry_path(bytes, options);
+        if let Ok(cached_bytes) = std::fs::read(&entry_path) {
+            // Safety: cache entries are only ever written by Module::serialize (see above regarding cache trust)
+            if let Ok(module) = unsafe { Module::deserialize(engine, cached_bytes) } {
+                return Ok(module);
+            }
+        }
+        let module =
+            Module::from_binary(engine, bytes).map_err(InterpreterError::ModuleLoadError)?;
+        if let Ok(serialized) = module.serialize() {
+            self.store_entry(&entry_path, &serialized);
+        }
+        Ok(module)
+    }
+    fn entry_path(&self, bytes: &[u8], options: &WasmEngineOptions) -> PathBuf {
+        let mut hasher = Sha256::new();
+        hasher.update(bytes);
+        // Engine options that affect code generation must be incorporated into the cache key
+        hasher.update([
+            options.fuel_limit.is_some() as u8,
+            options.async_support as u8,
+        ]);
+        self.directory
+            .join(format!("{:x}.cwasm", hasher.finalize()))
+    }
+    fn store_entry(&self, entry_path: &Path, contents: &[u8]) {
+        if std::fs::create_dir_all(&self.directory).is_err() {
+            return;
+        }
+        // Write to a temporary file before renaming, to avoid leaving partially-written entries if interrupted
+        let temp_path = entry_path.with_extension(format!("{}.tmp", std::process::id()));
+        if std::fs::write(&temp_path, contents).is_ok()
+            && std::fs::rename(&temp_path, entry_path).is_err()
+        {
+            let _ = std::fs::remove_file(&temp_path);
+        }
+    }
+}
+
+/// Capabilities granted to the WASI environment of an interpreter instance
+///
+/// By default the interpreter inherits the host process stdio streams and command-line arguments, and has no access
+/// to environment variables or the host filesystem. Compiled graphs that load modules from the filesystem must be
+/// explicitly granted access to the relevant directories.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WasiOptions {
+    /// Host directories to expose to the WASI environment
+    pub preopened_dirs: Vec<WasiPreopenedDir>,
+    /// Environment variables to expose to the WASI environment
+    pub env: Vec<(String, String)>,
+    /// Whether to expose the command-line arguments of the host process to the WASI environment
+    pub inherit_args: bool,
+    /// Source of the WASI stdin stream
+    pub stdin: WasiStdio,
+    /// Destination of the WASI stdout stream
+    pub stdout: WasiStdio,
+    /// Destination of the WASI stderr stream
+    pub stderr: WasiStdio,
+}
+
+impl Default for WasiOptions {
+    fn default() -> Self {
+        Self {
+            preopened_dirs: Vec::new(),
+            env: Vec::new(),
+            inherit_args: true,
+            stdin: WasiStdio::Inherit,
+            stdout: WasiStdio::Inherit,
+            stderr: WasiStdio::Inherit,
+        }
+    }
+}
+
+impl WasiOptions {
+    /// Options for a fully sandboxed WASI environment, with no access to the host filesystem, environment, arguments
+    /// or stdio streams
+    pub fn sandboxed() -> Self {
+        Self {
+            preopened_dirs: Vec::new(),
+            env: Vec::new(),
+            inherit_args: false,
+            stdin: WasiStdio::Null,
+            stdout: WasiStdio::Null,
+            stderr: WasiStdio::Null,
+        }
+    }
+    pub fn with_preopened_dir(
+        mut self,
+        host_path: impl Into<PathBuf>,
+        guest_path: impl Into<PathBuf>,
+    ) -> Self {
+        self.preopened_dirs.push(WasiPreopenedDir {
+            host_path: host_path.into(),
+            guest_path: guest_path.into(),
+        });
+        self
+    }
+    pub fn with_env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+    pub fn with_stdin(self, stdin: WasiStdio) -> Self {
+        Self { stdin, ..self }
+    }
+    pub fn with_stdout(self, stdout: WasiStdio) -> Self {
+        Self { stdout, ..self }
+    }
+    pub fn with_stderr(self, stderr: WasiStdio) -> Self {
+        Self { stderr, ..self }
+    }
+    fn create_context(&self) -> Result<WasiCtx, InterpreterError> {
+        let builder = WasiCtxBuilder::new();
+        let builder = if self.inherit_args {
+            builder.inherit_args()?
+        } else {
+            builder
+        };
+        let builder = self
+            .env
+            .iter()
+            .try_fold(builder, |builder, (key, value)| builder.env(key, value))?;
+        let builder = match &self.stdin {
+            WasiStdio::Inherit => builder.inherit_stdin(),
+            WasiStdio::Null => builder.stdin(Box::new(ReadPipe::new(std::io::empty()))),
+            WasiStdio::File(path) => builder.stdin(Box::new(open_wasi_file(
+                path,
+                std::fs::OpenOptions::new().read(true),
+            )?)),
+        };
+        let builder = match &self.stdout {
+            WasiStdio::Inherit => builder.inherit_stdout(),
+            WasiStdio::Null => builder.stdout(Box::new(WritePipe::new(std::io::sink()))),
+            WasiStdio::File(path) => builder.stdout(Box::new(open_wasi_file(
+                path,
+                std::fs::OpenOptions::new()
+                    .write(true)
+                    .create(true)
+                    .truncate(true),
+            )?)),
+        };
+        let builder = match &self.stderr {
+            WasiStdio::Inherit => builder.inherit_stderr(),
+            WasiStdio::Null => builder.stderr(Box::new(WritePipe::new(std::io::sink()))),
+            WasiStdio::File(path) => builder.stderr(Box::new(open_wasi_file(
+                path,
+                std::fs::OpenOptions::new()
+                    .write(true)
+                    .create(true)
+                    .truncate(true),
+            )?)),
+        };
+        let mut builder = builder;
+        for WasiPreopenedDir {
+            host_path,
+            guest_path,
+        } in self.preopened_dirs.iter()
+        {
+            let dir = Dir::open_ambient_dir(host_path, ambient_authority()).map_err(|err| {
+                InterpreterError::WasiConfigError(anyhow::anyhow!(
+                    "Unable to open preopened directory {}: {}",
+                    host_path.display(),
+                    err
+                ))
+            })?;
+            builder = builder
+                .preopened_dir(dir, guest_path)
+                .map_err(InterpreterError::WasiConfigError)?;
+        }
+        Ok(builder.build())
+    }
+}
+
+fn open_wasi_file(
+    path: &Path,
+    options: &std::fs::OpenOptions,
+) -> Result<WasiFile, InterpreterError> {
+    options
+        .open(path)
+        .map(|file| WasiFile::from_cap_std(cap_std::fs::File::from_std(file)))
+        .map_err(|err| {
+            InterpreterError::WasiConfigError(anyhow::anyhow!(
+                "Unable to open stdio file {}: {}",
+                path.display(),
+                err
+            ))
+        })
+}
+
+/// Host directory exposed to the WASI environment at the given guest path
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WasiPreopenedDir {
+    pub host_path: PathBuf,
+    pub guest_path: PathBuf,
+}
+
+/// Source or destination of a WASI stdio stream
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WasiStdio {
+    /// Share the corresponding stream of the host process
+    #[default]
+    Inherit,
+    /// Discard all output (or provide empty input)
+    Null,
+    /// Redirect the stream to the given host file (output files are truncated if they already exist)
+    File(PathBuf),
+}
+
+impl std::str::FromStr for WasiStdio {
+    type Err = std::convert::Infallible;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "inherit" => WasiStdio::Inherit,
+            "null" => WasiStdio::Null,
+            path => WasiStdio::File(PathBuf::from(path)),
+        })
+    }
+}
+
+/// Host state associated with an interpreter instance
+pub struct WasmHostContext {
+    wasi: WasiCtx,
+    max_memory_pages: Option<u32>,
+    memory_limit_exceeded: bool,
+}
+
+impl WasmHostContext {
+    fn new(wasi: WasiCtx, max_memory_pages: Option<u32>) -> Self {
+        Self {
+            wasi,
+            max_memory_pages,
+            memory_limit_exceeded: false,
+        }
+    }
+}
+
+impl ResourceLimiter for WasmHostContext {
+    fn memory_growing(&mut self, _current: usize, desired: usize, _maximum: Option<usize>) -> bool {
+        match self.max_memory_pages {
+            Some(max_pages) if desired > (max_pages as usize) * WASM_PAGE_SIZE => {
+                // Denying the request causes the memory.grow instruction to fail, which the runtime allocator
+                // will retry with progressively smaller requests before giving up
+                self.memory_limit_exceeded = true;
+                false
+            }
+            _ => true,
+        }
+    }
+    fn table_growing(&mut self, _current: u32, _desired: u32, _maximum: Option<u32>) -> bool {
+        true
+    }
 }
 
 pub struct UnboundEvaluationResult {
@@ -111,13 +513,20 @@ impl<A: Arena + Clone> InterpreterEvaluationResult<A> {
 
 #[derive(Debug)]
 pub enum InterpreterError {
+    EngineConfigError(anyhow::Error),
     ModuleLoadError(anyhow::Error),
     GlobalNotFound(String),
     MemoryNotFound(String),
     InvalidFunctionDefinition(String, anyhow::Error),
     InvalidFunctionEvaluation(String, anyhow::Error),
     WasiContextError(wasi_common::StringArrayError),
+    WasiConfigError(anyhow::Error),
     WasiLinkError(anyhow::Error),
+    FuelMeteringError(anyhow::Error),
+    EvaluationBudgetExceeded(u64),
+    MemoryLimitExceeded(u32),
+    AsyncSupportError(String),
+    HeapLoadError(anyhow::Error),
 }
 
 impl std::error::Error for InterpreterError {}
@@ -125,6 +534,9 @@ impl std::error::Error for InterpreterError {}
 impl std::fmt::Display for InterpreterError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            InterpreterError::EngineConfigError(err) => {
+                write!(f, "Invalid WASM engine configuration: {err}")
+            }
             InterpreterError::ModuleLoadError(err) => {
                 write!(f, "Unable to load WASM module: {err}")
             }
@@ -141,7 +553,31 @@ impl std::fmt::Display for InterpreterError {
                 write!(f, "Failed to evaluate function \"{name}\": {err}")
             }
             InterpreterError::WasiContextError(err) => std::fmt::Display::fmt(err, f),
+            InterpreterError::WasiConfigError(err) => {
+                write!(f, "Invalid WASI configuration: {err}")
+            }
             InterpreterError::WasiLinkError(err) => std::fmt::Display::fmt(err, f),
+            InterpreterError::FuelMeteringError(err) => {
+                write!(f, "Unable to configure evaluation budget: {err}")
+            }
+            InterpreterError::EvaluationBudgetExceeded(budget) => {
+                write!(
+                    f,
+                    "Evaluation exceeded instruction budget of {budget} units"
+                )
+            }
+            InterpreterError::MemoryLimitExceeded(max_pages) => {
+                write!(
+                    f,
+                    "Evaluation exceeded linear memory limit of {max_pages} pages"
+                )
+            }
+            InterpreterError::AsyncSupportError(message) => {
+                write!(f, "Invalid async interpreter usage: {message}")
+            }
+            InterpreterError::HeapLoadError(err) => {
+                write!(f, "Unable to load heap contents: {err}")
+            }
         }
     }
 }
@@ -153,16 +589,26 @@ impl From<wasi_common::StringArrayError> for InterpreterError {
 }
 
 pub struct WasmContextBuilder {
-    store: Store<WasiCtx>,
-    linker: Linker<WasiCtx>,
+    store: Store<WasmHostContext>,
+    linker: Linker<WasmHostContext>,
     module: Module,
     memory_name: String,
+    fuel_limit: Option<u64>,
+    async_support: bool,
 }
 
 impl WasmContextBuilder {
     pub fn from_cwasm(
         program_bytes: &[u8],
         memory_name: impl Into<String>,
+    ) -> Result<Self, InterpreterError> {
+        Self::from_cwasm_with_options(program_bytes, memory_name, &WasmEngineOptions::default())
+    }
+
+    pub fn from_cwasm_with_options(
+        program_bytes: &[u8],
+        memory_name: impl Into<String>,
+        options: &WasmEngineOptions,
     ) -> Result<Self, InterpreterError> {
         Self::from_module_factory(
             |engine| {
@@ -170,16 +616,39 @@ impl WasmContextBuilder {
                     .map_err(InterpreterError::ModuleLoadError)
             },
             memory_name.into(),
+            options,
         )
     }
 
     pub fn from_wasm(
         bytes: &[u8],
         memory_name: impl Into<String>,
+    ) -> Result<Self, InterpreterError> {
+        Self::from_wasm_with_options(bytes, memory_name, &WasmEngineOptions::default())
+    }
+
+    pub fn from_wasm_with_options(
+        bytes: &[u8],
+        memory_name: impl Into<String>,
+        options: &WasmEngineOptions,
     ) -> Result<Self, InterpreterError> {
         Self::from_module_factory(
             |e| Module::from_binary(e, bytes).map_err(InterpreterError::ModuleLoadError),
             memory_name.into(),
+            options,
+        )
+    }
+
+    pub fn from_wasm_with_module_cache(
+        bytes: &[u8],
+        memory_name: impl Into<String>,
+        options: &WasmEngineOptions,
+        module_cache: &WasmModuleCache,
+    ) -> Result<Self, InterpreterError> {
+        Self::from_module_factory(
+            |engine| module_cache.load_module(engine, bytes, options),
+            memory_name.into(),
+            options,
         )
     }
 
@@ -190,24 +659,55 @@ impl WasmContextBuilder {
         Self::from_module_factory(
             |engine| Module::from_file(engine, path).map_err(InterpreterError::ModuleLoadError),
             memory_name.into(),
+            &WasmEngineOptions::default(),
+        )
+    }
+
+    /// Create a builder for an additional instance of a module that has already been compiled
+    ///
+    /// The module must have been compiled by an engine that was created with the same engine options.
+    pub fn from_module(
+        module: &Module,
+        memory_name: impl Into<String>,
+        options: &WasmEngineOptions,
+    ) -> Result<Self, InterpreterError> {
+        let engine = module.engine().clone();
+        Self::from_module_factory_with_engine(
+            engine,
+            |_| Ok(module.clone()),
+            memory_name.into(),
+            options,
         )
     }
 
     fn from_module_factory(
         builder: impl FnOnce(&Engine) -> Result<Module, InterpreterError>,
         memory_name: String,
+        options: &WasmEngineOptions,
+    ) -> Result<Self, InterpreterError> {
+        let engine = options.create_engine()?;
+        Self::from_module_factory_with_engine(engine, builder, memory_name, options)
+    }
+
+    fn from_module_factory_with_engine(
+        engine: Engine,
+        builder: impl FnOnce(&Engine) -> Result<Module, InterpreterError>,
+        memory_name: String,
+        options: &WasmEngineOptions,
     ) -> Result<Self, InterpreterError> {
-        let wasi = WasiCtxBuilder::new()
-            .inherit_stdio()
-            .inherit_args()?
-            .build();
+        let wasi = WasiOptions::default().create_context()?;
 
-        let engine = Engine::default();
-        let store = Store::new(&engine, wasi);
+        let mut store = Store::new(
+            &engine,
+            WasmHostContext::new(wasi, options.max_memory_pages),
+        );
+        if options.max_memory_pages.is_some() {
+            store.limiter(|context| context);
+        }
         let mut linker = Linker::new(store.engine());
         let module = builder(store.engine())?;
 
-        wasmtime_wasi::add_to_linker(&mut linker, |s| s)
+        wasmtime_wasi::add_to_linker(&mut linker, |s: &mut WasmHostContext| &mut s.wasi)
             .map_err(InterpreterError::WasiLinkError)?;
 
         Ok(Self {
@@ -215,9 +715,17 @@ impl WasmContextBuilder {
             linker,
             module,
             memory_name,
+            fuel_limit: options.fuel_limit,
+            async_support: options.async_support,
         })
     }
 
+    /// Replace the default WASI environment with one that has been granted the given capabilities
+    pub fn with_wasi_options(mut self, options: &WasiOptions) -> Result<Self, InterpreterError> {
+        self.store.data_mut().wasi = options.create_context()?;
+        Ok(self)
+    }
+
     pub fn add_import<F, Params, Args>(
         mut self,
         module: &str,
@@ -225,7 +733,7 @@ impl WasmContextBuilder {
         func: F,
     ) -> Result<Self, InterpreterError>
     where
-        F: IntoFunc<WasiCtx, Params, Args>,
+        F: IntoFunc<WasmHostContext, Params, Args>,
     {
         self.linker
             .func_wrap(module, name, func)
@@ -234,29 +742,257 @@ impl WasmContextBuilder {
         Ok(self)
     }
 
+    /// Register an asynchronous host function import, allowing the host to await the result without blocking the
+    /// interpreter thread (this requires the engine to have been created with async support enabled)
+    pub fn add_async_import<F>(
+        mut self,
+        module: &str,
+        name: &str,
+        ty: FuncType,
+        func: F,
+    ) -> Result<Self, InterpreterError>
+    where
+        F: for<'a> Fn(
+                Caller<'a, WasmHostContext>,
+                &'a [Val],
+                &'a mut [Val],
+            )
+                -> Box<dyn Future<Output = Result<(), anyhow::Error>> + Send + 'a>
+            + Send
+            + Sync
+            + 'static,
+    {
+        if !self.async_support {
+            return Err(InterpreterError::AsyncSupportError(format!(
+                "Unable to register async import \"{module}.{name}\" without enabling async support"
+            )));
+        }
+        self.linker
+            .func_new_async(module, name, ty, func)
+            .map_err(InterpreterError::WasiLinkError)?;
+
+        Ok(self)
+    }
+
+    /// Register a host implementation for a standard library builtin that was compiled as a host function import
+    /// (this is used to provide builtins that are not implemented by the runtime library, when compiling with builtin
+    /// fallbacks enabled)
+    ///
+    /// The host function is invoked with the builtin argument term pointers (where any variadic arguments are collected
+    /// into a single list term) and the evaluation state pointer, and returns the result term pointer and dependencies
+    /// term pointer (which can be null).
+    pub fn add_builtin_fallback<F>(
+        mut self,
+        target: Stdlib,
+        func: F,
+    ) -> Result<Self, InterpreterError>
+    where
+        F: Fn(
+                Caller<'_, WasmHostContext>,
+                &[ArenaPointer],
+                ArenaPointer,
+            ) -> Result<(ArenaPointer, ArenaPointer), anyhow::Error>
+            + Send
+            + Sync
+            + 'static,
+    {
+        let num_params = target.num_params();
+        let ty = builtin_fallback_type(target);
+        self.linker
+            .func_new(
+                BUILTIN_FALLBACK_MODULE,
+                target.name(),
+                ty,
+                move |caller, params, results| {
+                    let params = params
+                        .iter()
+                        .map(|value| value.i32().map(|value| ArenaPointer::from(value as u32)))
+                        .collect::<Option<Vec<_>>>()
+                        .ok_or_else(|| anyhow::anyhow!("Invalid builtin fallback arguments"))?;
+                    let (args, state) = params.split_at(num_params);
+                    let (result, dependencies) = func(caller, args, state[0])?;
+                    results[0] = Val::I32(u32::from(result) as i32);
+                    results[1] = Val::I32(u32::from(dependencies) as i32);
+                    Ok(())
+                },
+            )
+            .map_err(InterpreterError::WasiLinkError)?;
+
+        Ok(self)
+    }
+
+    /// Register a host function created against the builder's store (e.g. via [`Func::wrap`] or [`Func::new`])
+    pub fn add_func(
+        mut self,
+        module: &str,
+        name: &str,
+        factory: impl FnOnce(&mut Store<WasmHostContext>) -> Func,
+    ) -> Result<Self, InterpreterError> {
+        let func = factory(&mut self.store);
+        self.linker
+            .define(&self.store, module, name, func)
+            .map_err(InterpreterError::WasiLinkError)?;
+
+        Ok(self)
+    }
+
+    /// Register a host function created against the builder's store as the implementation of a standard library
+    /// builtin that was compiled as a host function import (see [`WasmContextBuilder::add_builtin_fallback`])
+    ///
+    /// The function signature must match the signature of the builtin fallback import.
+    pub fn add_builtin_func(
+        mut self,
+        target: Stdlib,
+        factory: impl FnOnce(&mut Store<WasmHostContext>) -> Func,
+    ) -> Result<Self, InterpreterError> {
+        let func = factory(&mut self.store);
+        let expected_type = builtin_fallback_type(target);
+        let actual_type = func.ty(&self.store);
+        if actual_type != expected_type {
+            return Err(InterpreterError::InvalidFunctionDefinition(
+                String::from(target.name()),
+                anyhow::anyhow!(
+                    "Expected host function signature {expected_type:?}, received {actual_type:?}"
+                ),
+            ));
+        }
+        self.linker
+            .define(&self.store, BUILTIN_FALLBACK_MODULE, target.name(), func)
+            .map_err(InterpreterError::WasiLinkError)?;
+
+        Ok(self)
+    }
+
+    /// Register the host implementations of the debugger hooks imported by modules compiled with debug hooks enabled
+    /// (see [`DEBUGGER_HOOK_MODULE`]), pausing evaluation according to the breakpoints registered with the debugger
+    pub fn add_debugger(mut self, debugger: WasmDebugger) -> Result<Self, InterpreterError> {
+        let memory_name = self.memory_name.clone();
+        self.linker
+            .func_new(
+                DEBUGGER_HOOK_MODULE,
+                DEBUGGER_ENTER_FUNCTION_HOOK,
+                FuncType::new([ValType::I64], []),
+                {
+                    let debugger = debugger.clone();
+                    let memory_name = memory_name.clone();
+                    move |mut caller, params, _results| {
+                        let function_id = params
+                            .get(0)
+                            .and_then(|value| value.i64())
+                            .map(|value| {
+                                CompiledFunctionId::from(TermHashState::from(value as u64))
+                            })
+                            .ok_or_else(|| anyhow::anyhow!("Invalid debugger hook arguments"))?;
+                        let heap = get_caller_memory(&mut caller, &memory_name)?;
+                        debugger.handle_event(
+                            WasmDebuggerEvent::EnterFunction { function_id },
+                            heap.data(&caller),
+                        )
+                    }
+                },
+            )
+            .map_err(InterpreterError::WasiLinkError)?;
+        for target in Stdlib::iter() {
+            let num_params = target.num_params();
+            let ty = FuncType::new(std::iter::repeat(ValType::I32).take(num_params + 1), []);
+            let debugger = debugger.clone();
+            let memory_name = memory_name.clone();
+            self.linker
+                .func_new(
+                    DEBUGGER_HOOK_MODULE,
+                    target.name(),
+                    ty,
+                    move |mut caller, params, _results| {
+                        let params = params
+                            .iter()
+                            .map(|value| value.i32().map(|value| ArenaPointer::from(value as u32)))
+                            .collect::<Option<Vec<_>>>()
+                            .ok_or_else(|| anyhow::anyhow!("Invalid debugger hook arguments"))?;
+                        let (args, state) = params.split_at(num_params);
+                        let heap = get_caller_memory(&mut caller, &memory_name)?;
+                        debugger.handle_event(
+                            WasmDebuggerEvent::CallBuiltin {
+                                target,
+                                args,
+                                state: state[0],
+                            },
+                            heap.data(&caller),
+                        )
+                    },
+                )
+                .map_err(InterpreterError::WasiLinkError)?;
+        }
+        Ok(self)
+    }
+
     pub fn build(mut self) -> Result<WasmContext, InterpreterError> {
+        if self.async_support {
+            return Err(InterpreterError::AsyncSupportError(String::from(
+                "Async-enabled modules must be instantiated asynchronously",
+            )));
+        }
         let instance = self
             .linker
             .instantiate(&mut self.store, &self.module)
             .map_err(InterpreterError::WasiLinkError)?;
+        self.into_context(instance)
+    }
+
+    pub async fn build_async(mut self) -> Result<WasmContext, InterpreterError> {
+        if !self.async_support {
+            return Err(InterpreterError::AsyncSupportError(String::from(
+                "Unable to instantiate module asynchronously without enabling async support",
+            )));
+        }
+        let instance = self
+            .linker
+            .instantiate_async(&mut self.store, &self.module)
+            .await
+            .map_err(InterpreterError::WasiLinkError)?;
+        self.into_context(instance)
+    }
 
+    fn into_context(mut self, instance: Instance) -> Result<WasmContext, InterpreterError> {
         let memory = instance
             .get_memory(&mut self.store, &self.memory_name)
             .ok_or(InterpreterError::MemoryNotFound(self.memory_name.clone()))?;
 
-        Ok(WasmContext::new(instance, self.store, memory))
+        let mut context = WasmContext::new(instance, self.store, memory);
+        context.fuel_limit = self.fuel_limit;
+        context.async_support = self.async_support;
+        Ok(context)
     }
 }
 
+fn builtin_fallback_type(target: Stdlib) -> FuncType {
+    FuncType::new(
+        std::iter::repeat(ValType::I32).take(target.num_params() + 1),
+        [ValType::I32, ValType::I32],
+    )
+}
+
+fn get_caller_memory(
+    caller: &mut Caller<'_, WasmHostContext>,
+    memory_name: &str,
+) -> Result<Memory, anyhow::Error> {
+    caller
+        .get_export(memory_name)
+        .and_then(|export| export.into_memory())
+        .ok_or_else(|| anyhow::anyhow!("Memory export not found: {}", memory_name))
+}
+
 pub struct WasmContext {
     instance: Instance,
-    store: Store<WasiCtx>,
+    store: Store<WasmHostContext>,
     memory: Memory,
     exports: Vec<(String, ExternType)>,
+    fuel_limit: Option<u64>,
+    async_support: bool,
+    regions: ArenaRegions,
 }
 
 impl WasmContext {
-    pub fn new(instance: Instance, store: Store<WasiCtx>, memory: Memory) -> Self {
+    pub fn new(instance: Instance, store: Store<WasmHostContext>, memory: Memory) -> Self {
         let mut store = store;
         let exports = {
             let exports = instance
@@ -268,12 +1004,19 @@ impl WasmContext {
                 .map(|(key, value)| (key, value.ty(&store)))
                 .collect()
         };
-        Self {
+        let mut context = Self {
             instance,
             store,
             memory,
             exports,
-        }
+            fuel_limit: None,
+            async_support: false,
+            regions: ArenaRegions::default(),
+        };
+        // Any terms present in linear memory prior to module initialization were loaded from the compiled module data
+        // sections, so the current end of the heap marks the boundary between the static and dynamic arena regions
+        context.regions = ArenaRegions::new(context.end_offset());
+        context
     }
 
     fn data(&self) -> &[u8] {
@@ -344,6 +1087,68 @@ impl WasmContext {
             .iter()
             .map(|(export_name, definition)| (export_name.as_str(), definition))
     }
+
+    pub fn fuel_consumed(&self) -> Option<u64> {
+        self.store.fuel_consumed()
+    }
+
+    fn load_heap(&mut self, heap: &[u8]) -> Result<(), anyhow::Error> {
+        let num_existing_pages = self.memory.size(&self.store) as usize;
+        let num_target_pages = 1 + (heap.len().saturating_sub(1) / WASM_PAGE_SIZE);
+        if num_target_pages > num_existing_pages {
+            self.memory.grow(
+                &mut self.store,
+                (num_target_pages - num_existing_pages) as u64,
+            )?;
+        }
+        let data = self.data_mut();
+        data[..heap.len()].copy_from_slice(heap);
+        // Clear any stale contents beyond the end of the loaded heap
+        data[heap.len()..].fill(0);
+        Ok(())
+    }
+
+    fn get_mutable_globals(&mut self) -> Vec<(String, Val)> {
+        let Self {
+            instance,
+            store,
+            exports,
+            ..
+        } = self;
+        exports
+            .iter()
+            .filter(|(_, export_type)| match export_type {
+                ExternType::Global(global_type) => global_type.mutability() == Mutability::Var,
+                _ => false,
+            })
+            .filter_map(|(export_name, _)| {
+                instance
+                    .get_global(&mut *store, export_name)
+                    .map(|global| (export_name.clone(), global.get(&mut *store)))
+            })
+            .collect()
+    }
+
+    fn reset(&mut self, state: &WasmInstanceState) -> Result<(), anyhow::Error> {
+        self.load_heap(&state.heap)?;
+        for (export_name, value) in state.globals.iter() {
+            if let Some(global) = self.instance.get_global(&mut self.store, export_name) {
+                global.set(&mut self.store, value.clone())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn set_remaining_fuel(&mut self, fuel: u64) -> Result<(), anyhow::Error> {
+        // Note that the store will be left with a fuel deficit if the previous call ran out of fuel, and that any
+        // excess fuel removed here will be counted as consumed (topping up to a consistent budget avoids this)
+        let remaining = self.store.consume_fuel(0).unwrap_or(0);
+        if remaining > fuel {
+            self.store.consume_fuel(remaining - fuel).map(|_| ())
+        } else {
+            self.store.add_fuel(fuel - remaining)
+        }
+    }
 }
 
 impl WasmInterpreter {
@@ -363,6 +1168,12 @@ impl WasmInterpreter {
         self.0.end_offset()
     }
 
+    /// Region table distinguishing the immutable heap snapshot loaded from the compiled module from the terms
+    /// allocated at runtime
+    pub fn regions(&self) -> ArenaRegions {
+        self.0.regions
+    }
+
     #[must_use]
     pub fn evaluate(
         &mut self,
@@ -379,6 +1190,27 @@ impl WasmInterpreter {
         })
     }
 
+    /// Evaluate the given expression, aborting the evaluation if it consumes more than the given amount of fuel
+    ///
+    /// This requires the interpreter to have been instantiated with fuel metering enabled. Note that the heap may
+    /// contain partially-constructed terms after an aborted evaluation.
+    pub fn evaluate_with_budget(
+        &mut self,
+        input: ArenaPointer,
+        state: ArenaPointer,
+        fuel: u64,
+    ) -> Result<UnboundEvaluationResult, InterpreterError> {
+        let (result, dependencies) = self.call_with_budget::<(u32, u32), (u32, u32)>(
+            RuntimeBuiltin::Evaluate.name(),
+            (input.into(), state.into()),
+            Some(fuel),
+        )?;
+        Ok(UnboundEvaluationResult {
+            result_pointer: result.into(),
+            dependencies_pointer: ArenaPointer::from(dependencies).as_non_null(),
+        })
+    }
+
     #[must_use]
     pub fn execute(
         &mut self,
@@ -398,19 +1230,111 @@ impl WasmInterpreter {
         export_name: &str,
         args: I,
     ) -> Result<O, InterpreterError> {
-        let target = self
-            .0
+        self.call_with_budget(export_name, args, self.0.fuel_limit)
+    }
+
+    fn call_with_budget<I: WasmParams, O: WasmResults>(
+        &mut self,
+        export_name: &str,
+        args: I,
+        fuel: Option<u64>,
+    ) -> Result<O, InterpreterError> {
+        if self.0.async_support {
+            return Err(InterpreterError::AsyncSupportError(format!(
+                "Async-enabled function \"{export_name}\" must be invoked asynchronously"
+            )));
+        }
+        let target = self.prepare_call::<I, O>(export_name, fuel)?;
+        target
+            .call(&mut self.0.store, args)
+            .map_err(|err| self.parse_call_error(export_name, fuel, err))
+    }
+
+    /// Evaluate the given expression, awaiting any asynchronous host function imports invoked during the evaluation
+    ///
+    /// This requires the interpreter to have been instantiated with async support enabled.
+    pub async fn evaluate_async(
+        &mut self,
+        input: ArenaPointer,
+        state: ArenaPointer,
+    ) -> Result<UnboundEvaluationResult, InterpreterError> {
+        let (result, dependencies) = self
+            .call_async::<(u32, u32), (u32, u32)>(
+                RuntimeBuiltin::Evaluate.name(),
+                (input.into(), state.into()),
+            )
+            .await?;
+        Ok(UnboundEvaluationResult {
+            result_pointer: result.into(),
+            dependencies_pointer: ArenaPointer::from(dependencies).as_non_null(),
+        })
+    }
+
+    pub async fn call_async<I: WasmParams, O: WasmResults>(
+        &mut self,
+        export_name: &str,
+        args: I,
+    ) -> Result<O, InterpreterError> {
+        if !self.0.async_support {
+            return Err(InterpreterError::AsyncSupportError(format!(
+                "Unable to invoke function \"{export_name}\" asynchronously without enabling async support"
+            )));
+        }
+        let fuel = self.0.fuel_limit;
+        let target = self.prepare_call::<I, O>(export_name, fuel)?;
+        match target.call_async(&mut self.0.store, args).await {
+            Ok(output) => Ok(output),
+            Err(err) => Err(self.parse_call_error(export_name, fuel, err)),
+        }
+    }
+
+    pub async fn initialize_async(&mut self) -> Result<(), InterpreterError> {
+        self.call_async::<(), ()>("_initialize", ()).await
+    }
+
+    fn prepare_call<I: WasmParams, O: WasmResults>(
+        &mut self,
+        export_name: &str,
+        fuel: Option<u64>,
+    ) -> Result<TypedFunc<I, O>, InterpreterError> {
+        if let Some(fuel) = fuel {
+            self.0
+                .set_remaining_fuel(fuel)
+                .map_err(InterpreterError::FuelMeteringError)?;
+        }
+        self.0.store.data_mut().memory_limit_exceeded = false;
+
+        self.0
             .instance
             .get_typed_func::<I, O>(&mut self.0.store, export_name)
             .map_err(|err| {
                 InterpreterError::InvalidFunctionDefinition(String::from(export_name), err)
-            })?;
-
-        let output = target.call(&mut self.0.store, args).map_err(|err| {
-            InterpreterError::InvalidFunctionEvaluation(String::from(export_name), err)
-        })?;
+            })
+    }
 
-        Ok(output)
+    fn parse_call_error(
+        &self,
+        export_name: &str,
+        fuel: Option<u64>,
+        err: anyhow::Error,
+    ) -> InterpreterError {
+        let host_context = self.0.store.data();
+        match (
+            fuel,
+            err.downcast_ref::<Trap>(),
+            host_context.max_memory_pages,
+        ) {
+            (Some(fuel), Some(Trap::OutOfFuel), _) => {
+                InterpreterError::EvaluationBudgetExceeded(fuel)
+            }
+            // The runtime allocator traps if it is unable to acquire enough linear memory to satisfy a request
+            (_, Some(Trap::UnreachableCodeReached), Some(max_pages))
+                if host_context.memory_limit_exceeded =>
+            {
+                InterpreterError::MemoryLimitExceeded(max_pages)
+            }
+            _ => InterpreterError::InvalidFunctionEvaluation(String::from(export_name), err),
+        }
     }
 
     #[must_use]
@@ -425,18 +1349,85 @@ impl WasmInterpreter {
     pub fn instantiate(
         module: &WasmProgram,
         memory_name: &'static str,
+    ) -> Result<Self, InterpreterError> {
+        Self::instantiate_with_options(module, memory_name, &WasmEngineOptions::default())
+    }
+
+    pub fn instantiate_with_options(
+        module: &WasmProgram,
+        memory_name: &'static str,
+        options: &WasmEngineOptions,
+    ) -> Result<Self, InterpreterError> {
+        Self::instantiate_with_host_imports(
+            module,
+            memory_name,
+            options,
+            &WasmHostImports::default(),
+        )
+    }
+
+    /// Instantiate the module, providing the given custom host functions alongside the standard runtime imports
+    pub fn instantiate_with_host_imports(
+        module: &WasmProgram,
+        memory_name: &'static str,
+        options: &WasmEngineOptions,
+        host_imports: &WasmHostImports,
+    ) -> Result<Self, InterpreterError> {
+        Self::instantiate_with_wasi_options(
+            module,
+            memory_name,
+            options,
+            host_imports,
+            &WasiOptions::default(),
+        )
+    }
+
+    /// Instantiate the module with custom host functions, granting the WASI environment the given capabilities
+    pub fn instantiate_with_wasi_options(
+        module: &WasmProgram,
+        memory_name: &'static str,
+        options: &WasmEngineOptions,
+        host_imports: &WasmHostImports,
+        wasi_options: &WasiOptions,
     ) -> Result<Self, InterpreterError> {
         match module.compiler_mode {
-            WasmCompilerMode::Wasm => WasmContextBuilder::from_wasm(module.as_bytes(), memory_name),
+            WasmCompilerMode::Wasm => match module.module_cache() {
+                Some(module_cache) => WasmContextBuilder::from_wasm_with_module_cache(
+                    module.as_bytes(),
+                    memory_name,
+                    options,
+                    module_cache,
+                ),
+                None => WasmContextBuilder::from_wasm_with_options(
+                    module.as_bytes(),
+                    memory_name,
+                    options,
+                ),
+            },
             WasmCompilerMode::Cranelift => {
-                WasmContextBuilder::from_cwasm(module.as_bytes(), memory_name)
+                WasmContextBuilder::from_cwasm_with_options(module.as_bytes(), memory_name, options)
             }
         }
+        .and_then(|builder| builder.with_wasi_options(wasi_options))
         .and_then(|builder| add_wasm_runtime_imports(builder, memory_name))
+        .and_then(|builder| add_wasm_host_imports(builder, host_imports))
         .and_then(|builder| builder.build())
         .map(Into::into)
     }
 
+    /// Create an additional interpreter instance of a module that has already been compiled (see
+    /// [`WasmProgram::compile`]), avoiding the cost of recompiling the module for each instance
+    pub fn instantiate_module(
+        module: &Module,
+        memory_name: &'static str,
+        options: &WasmEngineOptions,
+    ) -> Result<Self, InterpreterError> {
+        WasmContextBuilder::from_module(module, memory_name, options)
+            .and_then(|builder| add_wasm_runtime_imports(builder, memory_name))
+            .and_then(|builder| builder.build())
+            .map(Into::into)
+    }
+
     pub fn get_global(&mut self, export_name: &str) -> Option<Val> {
         self.0.get_global(export_name)
     }
@@ -449,8 +1440,156 @@ impl WasmInterpreter {
     pub fn get_table_size(&mut self, export_name: &str) -> Option<u32> {
         self.0.get_table_size(export_name)
     }
-    pub fn exports(&self) -> impl Iterator<Item = (&str, &ExternType)> + '_ {
-        self.0.exports()
+    pub fn exports(&self) -> impl Iterator<Item = (&str, &ExternType)> + '_ {
+        self.0.exports()
+    }
+    pub fn fuel_consumed(&self) -> Option<u64> {
+        self.0.fuel_consumed()
+    }
+    /// Overwrite the interpreter linear memory with the given heap contents (e.g. a heap dump taken from another
+    /// instance of the same module), growing the linear memory if necessary
+    pub fn load_heap(&mut self, heap: &[u8]) -> Result<(), InterpreterError> {
+        self.0
+            .load_heap(heap)
+            .map_err(InterpreterError::HeapLoadError)
+    }
+    /// Record the current heap allocation state, allowing all subsequent allocations to be discarded once they are no
+    /// longer needed (see [`WasmInterpreter::truncate_heap`])
+    pub fn heap_watermark(&mut self) -> Result<WasmHeapWatermark, InterpreterError> {
+        // The global evaluation cache is updated in-place during evaluation, so its contents must be preserved in order
+        // to avoid leaving dangling references to discarded cache entries
+        let mutable_terms = self.get_mutable_terms()?;
+        Ok(WasmHeapWatermark {
+            end_offset: self.end_offset(),
+            mutable_terms,
+        })
+    }
+    /// Record the heap allocations made since the given offset, along with the current contents of any terms that are
+    /// mutated in-place during evaluation
+    ///
+    /// This allows another instance of the same program whose heap matched this heap as of the given offset to be
+    /// brought up to date without copying the entire heap (see [`WasmInterpreter::apply_heap_delta`]).
+    pub fn heap_delta(
+        &mut self,
+        start_offset: ArenaPointer,
+    ) -> Result<WasmHeapDelta, InterpreterError> {
+        let end_offset = self.end_offset();
+        if end_offset < start_offset {
+            return Err(InterpreterError::HeapLoadError(anyhow::anyhow!(
+                "Heap delta offset {:#x} exceeds current heap size {:#x}",
+                u32::from(start_offset),
+                u32::from(end_offset)
+            )));
+        }
+        let mutable_terms = self.get_mutable_terms()?;
+        let data = Vec::from(self.as_slice(
+            start_offset,
+            (u32::from(end_offset) - u32::from(start_offset)) as usize,
+        ));
+        Ok(WasmHeapDelta {
+            start_offset,
+            data,
+            mutable_terms,
+        })
+    }
+    /// Append the heap allocations recorded by [`WasmInterpreter::heap_delta`] to the interpreter heap, and overwrite
+    /// any terms that are mutated in-place during evaluation with their recorded contents
+    ///
+    /// The interpreter heap must end at the offset from which the delta was recorded.
+    pub fn apply_heap_delta(&mut self, delta: &WasmHeapDelta) -> Result<(), InterpreterError> {
+        let WasmHeapDelta {
+            start_offset,
+            data,
+            mutable_terms,
+        } = delta;
+        let current_end_offset = self.end_offset();
+        if current_end_offset != *start_offset {
+            return Err(InterpreterError::HeapLoadError(anyhow::anyhow!(
+                "Heap delta offset {:#x} does not match current heap size {:#x}",
+                u32::from(*start_offset),
+                u32::from(current_end_offset)
+            )));
+        }
+        self.extend(*start_offset, data.len());
+        let heap = self.data_mut();
+        let offset = u32::from(*start_offset) as usize;
+        heap[offset..(offset + data.len())].copy_from_slice(data);
+        for (pointer, contents) in mutable_terms {
+            let offset = u32::from(*pointer) as usize;
+            heap[offset..(offset + contents.len())].copy_from_slice(contents);
+        }
+        Ok(())
+    }
+    fn get_mutable_terms(&mut self) -> Result<Vec<(ArenaPointer, Vec<u8>)>, InterpreterError> {
+        let cache_pointer = match self.get_global(EVALUATION_CACHE_GLOBAL) {
+            Some(Val::I32(pointer)) => Ok(ArenaPointer::from(pointer as u32)),
+            _ => Err(InterpreterError::GlobalNotFound(String::from(
+                EVALUATION_CACHE_GLOBAL,
+            ))),
+        }?;
+        let cache_cell = ArenaRef::<TypedTerm<PointerTerm>, _>::new(&*self, cache_pointer)
+            .as_inner()
+            .target();
+        Ok([cache_pointer, cache_cell]
+            .into_iter()
+            .map(|pointer| {
+                let size = pad_to_4_byte_offset(
+                    self.read_value::<Term, _>(pointer, |term| TermSize::size_of(term)),
+                );
+                (pointer, Vec::from(self.as_slice(pointer, size)))
+            })
+            .collect())
+    }
+    /// Discard all heap allocations made since the given watermark was recorded, restoring the global evaluation cache
+    /// to its state at the time the watermark was recorded
+    ///
+    /// Any pointers to terms allocated after the watermark was recorded will be invalidated, so any values that need to
+    /// outlive the truncation must be copied out of the interpreter heap beforehand.
+    pub fn truncate_heap(&mut self, watermark: WasmHeapWatermark) -> Result<(), InterpreterError> {
+        let WasmHeapWatermark {
+            end_offset,
+            mutable_terms,
+        } = watermark;
+        let current_end_offset = self.end_offset();
+        if current_end_offset < end_offset {
+            return Err(InterpreterError::HeapLoadError(anyhow::anyhow!(
+                "Heap watermark {:#x} exceeds current heap size {:#x}",
+                u32::from(end_offset),
+                u32::from(current_end_offset)
+            )));
+        }
+        let discarded_size = u32::from(current_end_offset) - u32::from(end_offset);
+        self.shrink(current_end_offset, discarded_size as usize);
+        // Clear the discarded allocations to match the contents of freshly-allocated linear memory
+        let data = self.data_mut();
+        data[(u32::from(end_offset) as usize)..(u32::from(current_end_offset) as usize)].fill(0);
+        for (pointer, contents) in mutable_terms {
+            let offset = u32::from(pointer) as usize;
+            data[offset..(offset + contents.len())].copy_from_slice(&contents);
+        }
+        Ok(())
+    }
+    /// Evaluate the given expression, discarding all heap allocations made during the evaluation once the result has
+    /// been processed by the given callback
+    ///
+    /// This allows short-lived evaluations to be performed repeatedly without accumulating garbage in the interpreter
+    /// heap. The callback must copy out any values it needs, as all result pointers are invalidated once it returns.
+    pub fn evaluate_scoped<V>(
+        &mut self,
+        input: ArenaPointer,
+        state: ArenaPointer,
+        callback: impl FnOnce(&Self, UnboundEvaluationResult) -> V,
+    ) -> Result<V, InterpreterError> {
+        let watermark = self.heap_watermark()?;
+        let result = match self.evaluate(input, state) {
+            Ok(result) => callback(self, result),
+            Err(err) => {
+                self.truncate_heap(watermark)?;
+                return Err(err);
+            }
+        };
+        self.truncate_heap(watermark)?;
+        Ok(result)
     }
     pub fn dump_heap(&self) -> Vec<u8> {
         let Self(context) = self;
@@ -469,10 +1608,150 @@ impl From<WasmContext> for WasmInterpreter {
     }
 }
 
+/// Name of the runtime global that points to the global evaluation cache instance
+const EVALUATION_CACHE_GLOBAL: &str = "__cache";
+
+/// Heap allocation state recorded by [`WasmInterpreter::heap_watermark`]
+pub struct WasmHeapWatermark {
+    end_offset: ArenaPointer,
+    /// Contents of any pre-existing terms that are mutated in-place during evaluation
+    mutable_terms: Vec<(ArenaPointer, Vec<u8>)>,
+}
+
+impl WasmHeapWatermark {
+    pub fn end_offset(&self) -> ArenaPointer {
+        self.end_offset
+    }
+}
+
+/// Heap changes recorded by [`WasmInterpreter::heap_delta`]
+pub struct WasmHeapDelta {
+    start_offset: ArenaPointer,
+    /// Contents of the heap allocations made since the start offset
+    data: Vec<u8>,
+    /// Current contents of any pre-existing terms that are mutated in-place during evaluation
+    mutable_terms: Vec<(ArenaPointer, Vec<u8>)>,
+}
+
+impl WasmHeapDelta {
+    pub fn start_offset(&self) -> ArenaPointer {
+        self.start_offset
+    }
+    pub fn end_offset(&self) -> ArenaPointer {
+        self.start_offset.offset(self.data.len() as u32)
+    }
+}
+
+/// Pool of reusable interpreter instances of a single compiled program, avoiding the cost of creating a fresh instance
+/// for each evaluation
+///
+/// Instances are returned to the pool once released, at which point their linear memory and exported mutable globals
+/// are reset to the state of a newly-created instance. Released instances are discarded once the pool is full. Combine
+/// with [`WasmEngineOptions::pooling`] to additionally reuse the underlying instance resources whenever the pool needs
+/// to create a new instance.
+pub struct WasmInterpreterPool {
+    module: Module,
+    memory_name: &'static str,
+    options: WasmEngineOptions,
+    pool_size: usize,
+    initialize_instances: bool,
+    initial_state: Option<WasmInstanceState>,
+    instances: Vec<WasmInterpreter>,
+}
+
+struct WasmInstanceState {
+    heap: Vec<u8>,
+    globals: Vec<(String, Val)>,
+}
+
+impl WasmInterpreterPool {
+    pub fn new(
+        program: &WasmProgram,
+        memory_name: &'static str,
+        options: &WasmEngineOptions,
+        pool_size: usize,
+    ) -> Result<Self, InterpreterError> {
+        if options.async_support {
+            return Err(InterpreterError::AsyncSupportError(String::from(
+                "Pooled interpreter instances must be instantiated synchronously",
+            )));
+        }
+        // Compile the module once up-front, to be shared by all pooled instances
+        let engine = options.create_engine()?;
+        let module = program.compile(&engine, options)?;
+        Ok(Self {
+            module,
+            memory_name,
+            options: *options,
+            pool_size,
+            initialize_instances: false,
+            initial_state: None,
+            instances: Vec::with_capacity(pool_size),
+        })
+    }
+    /// Invoke the module initializer on each newly-created instance (this is necessary for programs whose globals are
+    /// assigned by the module initializer, e.g. the uncompiled runtime module)
+    pub fn with_instance_initialization(self) -> Self {
+        Self {
+            initialize_instances: true,
+            ..self
+        }
+    }
+    /// Maximum number of idle instances retained for reuse
+    pub fn pool_size(&self) -> usize {
+        self.pool_size
+    }
+    /// Number of idle instances currently available for reuse
+    pub fn len(&self) -> usize {
+        self.instances.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.instances.is_empty()
+    }
+    /// Take an idle instance from the pool, creating a new instance if there are no idle instances available
+    pub fn acquire(&mut self) -> Result<WasmInterpreter, InterpreterError> {
+        match self.instances.pop() {
+            Some(interpreter) => Ok(interpreter),
+            None => self.instantiate(),
+        }
+    }
+    /// Return an instance to the pool, resetting it to its initial state
+    ///
+    /// The instance must have been acquired from this pool.
+    pub fn release(&mut self, interpreter: WasmInterpreter) -> Result<(), InterpreterError> {
+        let initial_state = match self.initial_state.as_ref() {
+            Some(initial_state) if self.instances.len() < self.pool_size => initial_state,
+            _ => return Ok(()),
+        };
+        let mut interpreter = interpreter;
+        interpreter
+            .0
+            .reset(initial_state)
+            .map_err(InterpreterError::HeapLoadError)?;
+        self.instances.push(interpreter);
+        Ok(())
+    }
+    fn instantiate(&mut self) -> Result<WasmInterpreter, InterpreterError> {
+        let mut interpreter =
+            WasmInterpreter::instantiate_module(&self.module, self.memory_name, &self.options)?;
+        if self.initialize_instances {
+            interpreter.initialize()?;
+        }
+        if self.initial_state.is_none() {
+            self.initial_state = Some(WasmInstanceState {
+                heap: interpreter.dump_heap(),
+                globals: interpreter.0.get_mutable_globals(),
+            });
+        }
+        Ok(interpreter)
+    }
+}
+
 impl Arena for WasmContext {
-    type Slice<'a> = &'a [u8]
-        where
-            Self: 'a;
+    type Slice<'a>
+        = &'a [u8]
+    where
+        Self: 'a;
 
     fn read_value<T, V>(&self, offset: ArenaPointer, selector: impl FnOnce(&T) -> V) -> V {
         selector(self.get_ref(offset))
@@ -550,9 +1829,10 @@ impl ArenaAllocator for WasmContext {
 }
 
 impl Arena for WasmInterpreter {
-    type Slice<'a> = &'a [u8]
-        where
-            Self: 'a;
+    type Slice<'a>
+        = &'a [u8]
+    where
+        Self: 'a;
     fn read_value<T, V>(&self, offset: ArenaPointer, selector: impl FnOnce(&T) -> V) -> V {
         <WasmContext as Arena>::read_value::<T, V>(&self.0, offset, selector)
     }
@@ -593,7 +1873,8 @@ impl ArenaAllocator for WasmInterpreter {
 // TODO: Abstract reference-wrapped arena types into blanket trait implementation
 
 impl<'heap> Arena for &'heap WasmInterpreter {
-    type Slice<'a> = &'a [u8]
+    type Slice<'a>
+        = &'a [u8]
     where
         Self: 'a;
     fn read_value<T, V>(&self, offset: ArenaPointer, selector: impl FnOnce(&T) -> V) -> V {
@@ -616,7 +1897,8 @@ impl<'heap> Arena for &'heap WasmInterpreter {
 }
 
 impl<'heap> Arena for &'heap mut WasmInterpreter {
-    type Slice<'a> = &'a [u8]
+    type Slice<'a>
+        = &'a [u8]
     where
         Self: 'a;
     fn read_value<T, V>(&self, offset: ArenaPointer, selector: impl FnOnce(&T) -> V) -> V {
@@ -657,9 +1939,10 @@ impl<'heap> ArenaAllocator for &'heap mut WasmInterpreter {
 }
 
 impl<'heap> Arena for Rc<RefCell<&'heap mut WasmInterpreter>> {
-    type Slice<'a> = Ref<'a, [u8]>
-        where
-            Self: 'a;
+    type Slice<'a>
+        = Ref<'a, [u8]>
+    where
+        Self: 'a;
     fn read_value<T, V>(&self, offset: ArenaPointer, selector: impl FnOnce(&T) -> V) -> V {
         self.deref().borrow().read_value::<T, V>(offset, selector)
     }
@@ -711,8 +1994,23 @@ pub mod mocks {
         builder
             .add_import("Debugger", "debug", |_: u32| {})?
             .add_import("Date", "parse", |_: u32, _: u32| 0u64)?
-            .add_import("Date", "toISOString", |_: i64, _: u32| 0u32)?
+            .add_import("Date", "parseOffset", |_: u32, _: u32| 0i32)?
+            .add_import("Date", "toISOString", |_: i64, _: i32, _: u32| 0u32)?
+            .add_import("Regex", "captureCount", |_: u32, _: u32| 0i32)?
+            .add_import(
+                "Regex",
+                "capture",
+                |_: u32, _: u32, _: u32, _: u32, _: u32| 0i64,
+            )?
+            .add_import(
+                "Regex",
+                "replace",
+                |_: u32, _: u32, _: u32, _: u32, _: u32, _: u32, _: u32| 0u32,
+            )?
             .add_import("Number", "toString", |_: f64, _: u32| 0u32)?
+            .add_import("Number", "toCanonicalString", |_: f64, _: u32| 0u32)?
+            .add_import("Number", "parseInt", |_: u32, _: u32, _: u32, _: u32| 0f64)?
+            .add_import("Number", "parseFloat", |_: u32, _: u32, _: u32| 0f64)?
             .add_import("Math", "remainder", |_: f64, _: f64| 0f64)?
             .add_import("Math", "acos", |_: f64| 0f64)?
             .add_import("Math", "acosh", |_: f64| 0f64)?
@@ -752,15 +2050,20 @@ mod tests {
             ApplicationTerm, BuiltinTerm, ConditionTerm, CustomCondition, EffectTerm, HashmapTerm,
             IntTerm, ListTerm, NilTerm, SignalTerm, SymbolTerm, TermType, TreeTerm, TypedTerm,
         },
-        ArenaPointer, ArenaRef, Term,
+        ArenaPointer, ArenaRef, Term, WASM_PAGE_SIZE,
     };
+    use futures::executor::block_on;
     use std::{
         cell::RefCell,
         ops::{Deref, DerefMut},
         rc::Rc,
     };
+    use wasmtime::{FuncType, Module, Val, ValType};
 
-    use super::{mocks::add_import_stubs, InterpreterError, WasmContextBuilder};
+    use super::{
+        mocks::add_import_stubs, InterpreterError, WasiOptions, WasiStdio, WasmContextBuilder,
+        WasmEngineOptions, WasmInterpreterPool, WasmModuleCache, WasmPoolingOptions, WasmProgram,
+    };
 
     const RUNTIME_BYTES: &'static [u8] = include_bytes!("../build/runtime.wasm");
 
@@ -773,6 +2076,20 @@ mod tests {
         Ok(interpreter)
     }
 
+    fn create_metered_mock_wasm_interpreter() -> Result<WasmInterpreter, InterpreterError> {
+        let options = WasmEngineOptions {
+            fuel_limit: Some(1_000_000_000),
+            ..Default::default()
+        };
+        let mut interpreter: WasmInterpreter = add_import_stubs(
+            WasmContextBuilder::from_wasm_with_options(RUNTIME_BYTES, "memory", &options)?,
+        )?
+        .build()?
+        .into();
+        interpreter.initialize()?;
+        Ok(interpreter)
+    }
+
     #[test]
     fn atomic_expressions() {
         let mut interpreter: WasmInterpreter = create_mock_wasm_interpreter().unwrap().into();
@@ -878,6 +2195,331 @@ mod tests {
         assert_eq!(interpreter_dependencies, DependencyList::empty());
     }
 
+    #[test]
+    fn evaluation_budget() {
+        let mut interpreter: WasmInterpreter = create_metered_mock_wasm_interpreter().unwrap();
+
+        let input = {
+            let int3 =
+                interpreter.allocate(Term::new(TermType::Int(IntTerm::from(3)), &interpreter));
+
+            let int2 =
+                interpreter.allocate(Term::new(TermType::Int(IntTerm::from(2)), &interpreter));
+
+            let add = interpreter.allocate(Term::new(
+                TermType::Builtin(BuiltinTerm::from(Stdlib::from(Add))),
+                &interpreter,
+            ));
+
+            let arg_list = ListTerm::allocate([int3, int2], &mut interpreter);
+
+            interpreter.allocate(Term::new(
+                TermType::Application(ApplicationTerm {
+                    target: add,
+                    args: arg_list,
+                }),
+                &interpreter,
+            ))
+        };
+
+        let state = HashmapTerm::allocate(std::iter::empty(), &mut interpreter);
+
+        let result = interpreter.evaluate_with_budget(input.into(), state.into(), 10);
+        assert!(matches!(
+            result,
+            Err(InterpreterError::EvaluationBudgetExceeded(10))
+        ));
+
+        let interpreter = Rc::new(RefCell::new(&mut interpreter));
+
+        let interpreter_result = interpreter
+            .deref()
+            .borrow_mut()
+            .deref_mut()
+            .evaluate_with_budget(input.into(), state.into(), 1_000_000_000)
+            .unwrap()
+            .bind(Rc::clone(&interpreter));
+
+        let expected_result = ArenaRef::<Term, _>::new(
+            Rc::clone(&interpreter),
+            interpreter
+                .deref()
+                .borrow_mut()
+                .deref_mut()
+                .allocate(Term::new(TermType::Int(IntTerm::from(5)), &interpreter)),
+        );
+
+        assert_eq!(interpreter_result.result(), expected_result);
+    }
+
+    #[test]
+    fn scoped_evaluation() {
+        let mut interpreter: WasmInterpreter = create_mock_wasm_interpreter().unwrap();
+
+        let input = {
+            let int3 =
+                interpreter.allocate(Term::new(TermType::Int(IntTerm::from(3)), &interpreter));
+
+            let int2 =
+                interpreter.allocate(Term::new(TermType::Int(IntTerm::from(2)), &interpreter));
+
+            let add = interpreter.allocate(Term::new(
+                TermType::Builtin(BuiltinTerm::from(Stdlib::from(Add))),
+                &interpreter,
+            ));
+
+            let arg_list = ListTerm::allocate([int3, int2], &mut interpreter);
+
+            interpreter.allocate(Term::new(
+                TermType::Application(ApplicationTerm {
+                    target: add,
+                    args: arg_list,
+                }),
+                &interpreter,
+            ))
+        };
+
+        let state = HashmapTerm::allocate(std::iter::empty(), &mut interpreter);
+
+        let heap = interpreter.dump_heap();
+
+        for _ in 0..2 {
+            let result = interpreter
+                .evaluate_scoped(input.into(), state.into(), |interpreter, result| {
+                    ArenaRef::<Term, _>::new(interpreter, result.result_pointer)
+                        .as_int_term()
+                        .map(|term| term.as_inner().value())
+                })
+                .unwrap();
+            assert_eq!(result, Some(5));
+            assert_eq!(interpreter.dump_heap(), heap);
+        }
+
+        let watermark = interpreter.heap_watermark().unwrap();
+        interpreter.truncate_heap(watermark).unwrap();
+        assert_eq!(interpreter.dump_heap(), heap);
+
+        let watermark = interpreter.heap_watermark().unwrap();
+        let int3 = interpreter.allocate(Term::new(TermType::Int(IntTerm::from(3)), &interpreter));
+        assert_eq!(int3, watermark.end_offset());
+        let stale_watermark = interpreter.heap_watermark().unwrap();
+        interpreter.truncate_heap(watermark).unwrap();
+        assert_eq!(interpreter.dump_heap(), heap);
+        assert!(interpreter.truncate_heap(stale_watermark).is_err());
+    }
+
+    #[test]
+    fn heap_deltas() {
+        let mut interpreter: WasmInterpreter = create_mock_wasm_interpreter().unwrap();
+
+        let input = {
+            let int3 =
+                interpreter.allocate(Term::new(TermType::Int(IntTerm::from(3)), &interpreter));
+
+            let int2 =
+                interpreter.allocate(Term::new(TermType::Int(IntTerm::from(2)), &interpreter));
+
+            let add = interpreter.allocate(Term::new(
+                TermType::Builtin(BuiltinTerm::from(Stdlib::from(Add))),
+                &interpreter,
+            ));
+
+            let arg_list = ListTerm::allocate([int3, int2], &mut interpreter);
+
+            interpreter.allocate(Term::new(
+                TermType::Application(ApplicationTerm {
+                    target: add,
+                    args: arg_list,
+                }),
+                &interpreter,
+            ))
+        };
+
+        let state = HashmapTerm::allocate(std::iter::empty(), &mut interpreter);
+
+        let mut replica: WasmInterpreter = create_mock_wasm_interpreter().unwrap();
+        replica.load_heap(&interpreter.dump_heap()).unwrap();
+        let synced_offset = interpreter.end_offset();
+
+        let result = interpreter.evaluate(input.into(), state.into()).unwrap();
+        assert!(interpreter.end_offset() > synced_offset);
+
+        let delta = interpreter.heap_delta(synced_offset).unwrap();
+        assert_eq!(delta.start_offset(), synced_offset);
+        assert_eq!(delta.end_offset(), interpreter.end_offset());
+        replica.apply_heap_delta(&delta).unwrap();
+        assert_eq!(replica.dump_heap(), interpreter.dump_heap());
+        assert_eq!(
+            ArenaRef::<Term, _>::new(&replica, result.result_pointer)
+                .as_int_term()
+                .map(|term| term.as_inner().value()),
+            Some(5)
+        );
+
+        assert!(replica.apply_heap_delta(&delta).is_err());
+        let end_offset = interpreter.end_offset();
+        assert!(interpreter.heap_delta(end_offset.offset(4)).is_err());
+    }
+
+    #[test]
+    fn pooled_instances() {
+        let program = WasmProgram::from_wasm(RUNTIME_BYTES.to_vec());
+        let mut pool =
+            WasmInterpreterPool::new(&program, "memory", &WasmEngineOptions::default(), 1)
+                .unwrap()
+                .with_instance_initialization();
+        assert!(pool.is_empty());
+
+        let mut interpreter = pool.acquire().unwrap();
+        let initial_heap = interpreter.dump_heap();
+        let value = interpreter.allocate(Term::new(TermType::Int(IntTerm::from(3)), &interpreter));
+        assert_eq!(value, ArenaPointer::from(initial_heap.len() as u32));
+        let extra_interpreter = pool.acquire().unwrap();
+        pool.release(interpreter).unwrap();
+        pool.release(extra_interpreter).unwrap();
+        assert_eq!(pool.len(), 1);
+
+        let mut interpreter = pool.acquire().unwrap();
+        assert!(pool.is_empty());
+        assert_eq!(interpreter.dump_heap(), initial_heap);
+        assert!(interpreter.data()[initial_heap.len()..]
+            .iter()
+            .all(|byte| *byte == 0));
+        let value = interpreter.allocate(Term::new(TermType::Int(IntTerm::from(4)), &interpreter));
+        assert_eq!(value, ArenaPointer::from(initial_heap.len() as u32));
+    }
+
+    #[test]
+    fn pooling_allocator() {
+        let program = WasmProgram::from_wasm(RUNTIME_BYTES.to_vec());
+        let options = WasmEngineOptions {
+            max_memory_pages: Some(256),
+            pooling: Some(WasmPoolingOptions {
+                max_instances: 2,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut pool = WasmInterpreterPool::new(&program, "memory", &options, 2)
+            .unwrap()
+            .with_instance_initialization();
+
+        let first = pool.acquire().unwrap();
+        let second = pool.acquire().unwrap();
+        assert!(pool.acquire().is_err());
+        pool.release(first).unwrap();
+        pool.release(second).unwrap();
+        assert_eq!(pool.len(), 2);
+        let mut interpreter = pool.acquire().unwrap();
+        let result = interpreter.call::<u32, u32>("allocate", 1024);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn memory_limit() {
+        let options = WasmEngineOptions {
+            max_memory_pages: Some(256),
+            ..Default::default()
+        };
+        let mut interpreter: WasmInterpreter = add_import_stubs(
+            WasmContextBuilder::from_wasm_with_options(RUNTIME_BYTES, "memory", &options).unwrap(),
+        )
+        .unwrap()
+        .build()
+        .unwrap()
+        .into();
+        interpreter.initialize().unwrap();
+
+        let result = interpreter.call::<u32, u32>("allocate", 1024);
+
+        assert!(result.is_ok());
+
+        let result = interpreter.call::<u32, u32>("allocate", 512 * WASM_PAGE_SIZE as u32);
+        assert!(matches!(
+            result,
+            Err(InterpreterError::MemoryLimitExceeded(256))
+        ));
+        assert!(interpreter.data().len() <= 256 * WASM_PAGE_SIZE);
+    }
+
+    #[test]
+    fn async_calls() {
+        let options = WasmEngineOptions {
+            async_support: true,
+            ..Default::default()
+        };
+        let builder = add_import_stubs(
+            WasmContextBuilder::from_wasm_with_options(RUNTIME_BYTES, "memory", &options).unwrap(),
+        )
+        .unwrap();
+        let mut interpreter: WasmInterpreter = block_on(builder.build_async()).unwrap().into();
+        block_on(interpreter.initialize_async()).unwrap();
+
+        let result = block_on(interpreter.call_async::<u32, u32>("allocate", 1024));
+        assert!(result.is_ok());
+
+        let result = interpreter.call::<u32, u32>("allocate", 1024);
+        assert!(matches!(
+            result,
+            Err(InterpreterError::AsyncSupportError(_))
+        ));
+    }
+
+    #[test]
+    fn async_imports() {
+        let options = WasmEngineOptions {
+            async_support: true,
+            ..Default::default()
+        };
+        let builder = WasmContextBuilder::from_module_factory(
+            |engine| {
+                Module::new(
+                    engine,
+                    r#"
+                    (module
+                        (import "Host" "double" (func $double (param i32) (result i32)))
+                        (memory (export "memory") 1)
+                        (func (export "run") (param i32) (result i32)
+                            (call $double (local.get 0))))
+                    "#,
+                )
+                .map_err(InterpreterError::ModuleLoadError)
+            },
+            String::from("memory"),
+            &options,
+        )
+        .unwrap()
+        .add_async_import(
+            "Host",
+            "double",
+            FuncType::new([ValType::I32], [ValType::I32]),
+            |_caller, params, results| {
+                Box::new(async move {
+                    results[0] = Val::I32(params[0].unwrap_i32() * 2);
+                    Ok(())
+                })
+            },
+        )
+        .unwrap();
+        let mut interpreter: WasmInterpreter = block_on(builder.build_async()).unwrap().into();
+
+        let result = block_on(interpreter.call_async::<i32, i32>("run", 3));
+        assert_eq!(result.unwrap(), 6);
+
+        let result = WasmContextBuilder::from_wasm(RUNTIME_BYTES, "memory")
+            .unwrap()
+            .add_async_import(
+                "Host",
+                "double",
+                FuncType::new([ValType::I32], [ValType::I32]),
+                |_caller, _params, _results| Box::new(async move { Ok(()) }),
+            );
+        assert!(matches!(
+            result,
+            Err(InterpreterError::AsyncSupportError(_))
+        ));
+    }
+
     #[test]
     fn stateful_expressions() {
         let mut interpreter: WasmInterpreter = create_mock_wasm_interpreter().unwrap().into();
@@ -1030,4 +2672,146 @@ mod tests {
         );
         assert_eq!(interpreter_dependencies, DependencyList::of(condition_id));
     }
+
+    #[test]
+    fn wasi_options() {
+        let directory =
+            std::env::temp_dir().join(format!("reflex-wasm-wasi-{}", reflex::core::Uuid::new_v4()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let create_interpreter = |wasi_options: &WasiOptions| {
+            WasmContextBuilder::from_wasm(RUNTIME_BYTES, "memory")
+                .and_then(|builder| builder.with_wasi_options(wasi_options))
+                .and_then(add_import_stubs)
+                .and_then(|builder| builder.build())
+                .map(WasmInterpreter::from)
+        };
+        let wasi_options = WasiOptions::sandboxed()
+            .with_preopened_dir(&directory, "/modules")
+            .with_env("NODE_ENV", "production")
+            .with_stdout(WasiStdio::File(directory.join("stdout.log")));
+        let mut interpreter = create_interpreter(&wasi_options).unwrap();
+        assert!(interpreter.initialize().is_ok());
+        assert!(directory.join("stdout.log").exists());
+        let wasi_options =
+            WasiOptions::sandboxed().with_preopened_dir(directory.join("missing"), "/modules");
+        assert!(matches!(
+            create_interpreter(&wasi_options),
+            Err(InterpreterError::WasiConfigError(_))
+        ));
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn module_cache() {
+        let directory = std::env::temp_dir().join(format!(
+            "reflex-wasm-module-cache-{}",
+            reflex::core::Uuid::new_v4()
+        ));
+        let module_cache = WasmModuleCache::new(&directory);
+        let options = WasmEngineOptions::default();
+        let engine = options.create_engine().unwrap();
+        let list_entries = || {
+            std::fs::read_dir(&directory)
+                .unwrap()
+                .map(|entry| entry.unwrap().path())
+                .collect::<Vec<_>>()
+        };
+
+        // Compiling the module populates the cache
+        module_cache
+            .load_module(&engine, RUNTIME_BYTES, &options)
+            .unwrap();
+        let entries = list_entries();
+        assert_eq!(entries.len(), 1);
+        let entry_path = entries.into_iter().next().unwrap();
+        let entry_size = std::fs::metadata(&entry_path).unwrap().len();
+        assert!(entry_size > 0);
+
+        // Subsequent loads reuse the cached module
+        let module = module_cache
+            .load_module(&engine, RUNTIME_BYTES, &options)
+            .unwrap();
+        assert!(module.get_export("memory").is_some());
+        assert_eq!(list_entries(), vec![entry_path.clone()]);
+
+        // Invalid cache entries are recompiled and overwritten
+        std::fs::write(&entry_path, b"invalid").unwrap();
+        let module = module_cache
+            .load_module(&engine, RUNTIME_BYTES, &options)
+            .unwrap();
+        assert!(module.get_export("memory").is_some());
+        assert_eq!(std::fs::metadata(&entry_path).unwrap().len(), entry_size);
+
+        // Engine options that affect code generation are cached separately
+        let metered_options = WasmEngineOptions {
+            fuel_limit: Some(1000),
+            ..Default::default()
+        };
+        let metered_engine = metered_options.create_engine().unwrap();
+        module_cache
+            .load_module(&metered_engine, RUNTIME_BYTES, &metered_options)
+            .unwrap();
+        assert_eq!(list_entries().len(), 2);
+
+        // Programs configured with a module cache are instantiated via the cache
+        let program =
+            super::WasmProgram::from_wasm(RUNTIME_BYTES.to_vec()).with_module_cache(module_cache);
+        assert!(WasmInterpreter::instantiate(&program, "memory").is_ok());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn custom_host_imports() {
+        use crate::exports::{
+            add_wasm_host_imports, WasmHostImport, WasmHostImportDeclaration, WasmHostImports,
+        };
+        use wasmtime::Func;
+
+        let target = Stdlib::Add(Add);
+        let valid_imports = WasmHostImports::default()
+            .with_import(WasmHostImport::new(
+                WasmHostImportDeclaration::Builtin(target),
+                |store| Func::wrap(store, |left: i32, _right: i32, _state: i32| (left, 0i32)),
+            ))
+            .with_import(WasmHostImport::new(
+                WasmHostImportDeclaration::Function {
+                    module: String::from("Custom"),
+                    name: String::from("now"),
+                },
+                |store| Func::wrap(store, || 0i64),
+            ));
+        let builder = WasmContextBuilder::from_wasm(RUNTIME_BYTES, "memory").unwrap();
+        assert!(add_wasm_host_imports(builder, &valid_imports).is_ok());
+
+        let invalid_imports = WasmHostImports::default().with_import(WasmHostImport::new(
+            WasmHostImportDeclaration::Builtin(target),
+            |store| Func::wrap(store, |left: i32| left),
+        ));
+        let builder = WasmContextBuilder::from_wasm(RUNTIME_BYTES, "memory").unwrap();
+        assert!(matches!(
+            add_wasm_host_imports(builder, &invalid_imports),
+            Err(InterpreterError::InvalidFunctionDefinition(_, _))
+        ));
+    }
+
+    #[test]
+    fn arena_regions() {
+        let mut interpreter = create_mock_wasm_interpreter().unwrap();
+        let regions = interpreter.regions();
+        assert!(regions.static_end() >= interpreter.start_offset());
+        assert!(regions.static_end() <= interpreter.end_offset());
+
+        let first = interpreter.allocate(Term::new(TermType::Int(IntTerm::from(3)), &interpreter));
+        let second = interpreter.allocate(Term::new(TermType::Int(IntTerm::from(3)), &interpreter));
+        assert!(regions.is_dynamic(first));
+        assert!(!regions.is_static(second));
+        assert!(regions.terms_equal(&interpreter, first, second));
+        assert_eq!(
+            regions
+                .dynamic_roots([first, ArenaPointer::null()])
+                .collect::<Vec<_>>(),
+            vec![first],
+        );
+    }
 }
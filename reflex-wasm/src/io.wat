@@ -32,12 +32,16 @@
       (call $Term::Cell::get_field_pointer (global.get $Io::IOVEC) (i32.const 2))))
 
   (func $Io::log_term (param $value i32) (result i32)
+    (call $Io::write_term (global.get $Io::STDOUT) (local.get $value)))
+
+  (func $Io::write_term (param $fd i32) (param $value i32) (result i32)
     (local $serialized_value i32)
     (local $bytes_written i32)
     ;; Serialize the term to a temporary string term
     (local.set $serialized_value (call $Term::String::from (local.get $value)))
-    ;; Write the serialized string contents to stdout
-    (call $Io::write_stdout
+    ;; Write the serialized string contents to the output file descriptor
+    (call $Io::write_bytes
+      (local.get $fd)
       (call $Term::String::get_offset (local.get $serialized_value))
       (call $Term::String::get_length (local.get $serialized_value)))
     ;; Store the number of bytes written
@@ -47,8 +51,9 @@
       (i32.ne (local.get $serialized_value) (local.get $value))
       (then
         (call $Term::String::drop (local.get $serialized_value))))
-    ;; Write a newline to stdout
-    (call $Io::write_stdout
+    ;; Write a newline to the output file descriptor
+    (call $Io::write_bytes
+      (local.get $fd)
       (call $Term::String::get_offset (global.get $Stdlib_Log::NEWLINE))
       (call $Term::String::get_length (global.get $Stdlib_Log::NEWLINE)))
     ;; Return the total number of bytes written
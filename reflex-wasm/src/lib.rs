@@ -10,6 +10,7 @@ use reflex::{
 };
 use reflex_macros::PointerIter;
 use reflex_utils::Visitable;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     allocator::{Arena, ArenaAllocator},
@@ -24,14 +25,22 @@ pub use wasmtime;
 pub use wasmtime_wasi;
 
 pub mod allocator;
+pub mod bridge;
 pub mod builtins;
 pub mod cache;
 pub mod cli;
 pub mod compiler;
+pub mod debugger;
 pub mod exports;
 pub mod factory;
+pub mod gc;
 pub mod hash;
+pub mod heap_snapshot;
+pub mod heap_stats;
 pub mod interpreter;
+pub mod parallel;
+pub mod region;
+pub mod replication;
 pub mod serialize;
 pub mod stdlib;
 pub mod term_type;
@@ -429,7 +438,7 @@ pub struct TermHeader {
     hash: TermHashState,
 }
 
-#[derive(PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash, Debug)]
+#[derive(PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash, Debug, Serialize, Deserialize)]
 #[repr(transparent)]
 pub struct ArenaPointer(u32);
 
@@ -3,4 +3,7 @@
 ;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 (module
   ;; Imported floating point functions
-  (func $Utils::Float::to_string (import "Number" "toString") (param f64 i32) (result i32)))
+  (func $Utils::Float::to_string (import "Number" "toString") (param f64 i32) (result i32))
+  (func $Utils::Float::to_canonical_string (import "Number" "toCanonicalString") (param f64 i32) (result i32))
+  (func $Utils::Float::parse_int (import "Number" "parseInt") (param i32 i32 i32 i32) (result f64))
+  (func $Utils::Float::parse_float (import "Number" "parseFloat") (param i32 i32 i32) (result f64)))
@@ -0,0 +1,506 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    num::NonZeroUsize,
+    sync::{Mutex, PoisonError},
+};
+
+use wasmtime::{Module, Val};
+
+use crate::{
+    cache::{EvaluationCache, EvaluationCacheBucket},
+    interpreter::{
+        InterpreterError, UnboundEvaluationResult, WasmEngineOptions, WasmHeapDelta,
+        WasmHeapWatermark, WasmInterpreter, WasmProgram,
+    },
+    replication::{PartialHeapSnapshot, PartialHeapSnapshotCacheEntry, PartialHeapSnapshotError},
+    term_type::{CellTerm, PointerTerm, TypedTerm},
+    ArenaPointer, ArenaPointerIterator, ArenaRef,
+};
+
+/// Evaluate independent expressions concurrently across a pool of worker threads, each of which hosts its own
+/// instance of the compiled module
+///
+/// The runtime allocator and evaluation cache assume exclusive access to the linear memory, so rather than mutating a
+/// single shared linear memory, each worker maintains a replica of the main interpreter heap. This means that all
+/// existing heap pointers remain valid within every worker. Once all workers have completed, the results (along with
+/// any evaluation cache entries they depend on) are copied directly into the main interpreter heap one worker at a
+/// time, leaving the main interpreter arena in the same consistent state as if the expressions had been evaluated
+/// serially.
+///
+/// Worker instances are retained across calls: the first time a worker is used it is loaded with a full copy of the
+/// main interpreter heap, but subsequent calls discard the worker's own allocations and then append only the main
+/// interpreter heap allocations made since the previous call, so the per-call synchronization cost is proportional to
+/// the amount of new data rather than to the size of the heap.
+///
+/// Worker instances must be able to reproduce the main interpreter globals: the main interpreter must be an instance
+/// of the same program, instantiated with the same engine options. Successive calls must be made against the same
+/// main interpreter, whose existing heap contents must not be modified between calls other than by allocating new
+/// terms or evaluating expressions; if the main interpreter heap is truncated or reloaded between calls then
+/// [`WasmParallelEvaluator::reset`] must be called to discard the existing worker replicas.
+pub struct WasmParallelEvaluator {
+    module: Module,
+    memory_name: &'static str,
+    options: WasmEngineOptions,
+    num_workers: NonZeroUsize,
+    initialize_workers: bool,
+    workers: Mutex<Vec<WasmParallelWorker>>,
+}
+
+impl WasmParallelEvaluator {
+    pub fn new(
+        program: &WasmProgram,
+        memory_name: &'static str,
+        options: &WasmEngineOptions,
+        num_workers: NonZeroUsize,
+    ) -> Result<Self, InterpreterError> {
+        if options.async_support {
+            return Err(InterpreterError::AsyncSupportError(String::from(
+                "Parallel evaluation workers must be instantiated synchronously",
+            )));
+        }
+        // Compile the module once up-front, to be shared by all worker instances
+        let engine = options.create_engine()?;
+        let module = program.compile(&engine, options)?;
+        Ok(Self {
+            module,
+            memory_name,
+            options: *options,
+            num_workers,
+            initialize_workers: false,
+            workers: Mutex::new(Vec::with_capacity(num_workers.get())),
+        })
+    }
+    /// Invoke the module initializer on each worker instance before loading the main interpreter heap (this is
+    /// necessary for programs whose globals are assigned by the module initializer, e.g. the uncompiled runtime module)
+    pub fn with_worker_initialization(self) -> Self {
+        Self {
+            initialize_workers: true,
+            ..self
+        }
+    }
+    pub fn num_workers(&self) -> usize {
+        self.num_workers.get()
+    }
+    /// Discard all existing worker instances, forcing the next evaluation to reload the full main interpreter heap
+    pub fn reset(&self) {
+        self.workers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+    /// Evaluate the given `(expression, state)` pairs, returning the results in the same order as the inputs
+    ///
+    /// All input pointers must refer to terms within the main interpreter heap. Result pointers refer to terms within
+    /// the main interpreter heap after all worker results have been merged.
+    pub fn evaluate(
+        &self,
+        interpreter: &mut WasmInterpreter,
+        inputs: &[(ArenaPointer, ArenaPointer)],
+    ) -> Result<Vec<UnboundEvaluationResult>, WasmParallelEvaluationError> {
+        let partition_size = 1 + (inputs.len().saturating_sub(1) / self.num_workers());
+        // Avoid the overhead of synchronizing worker instances when there is no opportunity for parallelism
+        if inputs.len() <= 1 || partition_size == inputs.len() {
+            return inputs
+                .iter()
+                .map(|(input, state)| interpreter.evaluate(*input, *state))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(WasmParallelEvaluationError::InterpreterError);
+        }
+        let mut workers = self.workers.lock().unwrap_or_else(PoisonError::into_inner);
+        let result = self.evaluate_partitions(&mut workers, interpreter, inputs, partition_size);
+        // If anything went wrong, the worker heaps can no longer be assumed to be consistent with the main interpreter
+        if result.is_err() {
+            workers.clear();
+        }
+        result
+    }
+    fn evaluate_partitions(
+        &self,
+        workers: &mut Vec<WasmParallelWorker>,
+        interpreter: &mut WasmInterpreter,
+        inputs: &[(ArenaPointer, ArenaPointer)],
+        partition_size: usize,
+    ) -> Result<Vec<UnboundEvaluationResult>, WasmParallelEvaluationError> {
+        let partitions = inputs.chunks(partition_size);
+        // Discard any workers that cannot be brought up to date by appending the latest main interpreter allocations
+        let end_offset = interpreter.end_offset();
+        workers.retain(|worker| {
+            worker
+                .synced_offset()
+                .map(|synced_offset| synced_offset <= end_offset)
+                .unwrap_or(true)
+        });
+        while workers.len() < partitions.len() {
+            workers.push(
+                self.create_worker()
+                    .map_err(WasmParallelEvaluationError::InterpreterError)?,
+            );
+        }
+        let workers = &mut workers[..partitions.len()];
+        // Determine the main interpreter heap changes that need to be applied to each worker before spawning the worker
+        // threads, to avoid contending for access to the main interpreter
+        let base_heap = if workers.iter().any(|worker| worker.watermark.is_none()) {
+            Some(interpreter.dump_heap())
+        } else {
+            None
+        };
+        let mut deltas = Vec::<WasmHeapDelta>::new();
+        for synced_offset in workers.iter().filter_map(|worker| worker.synced_offset()) {
+            if deltas
+                .iter()
+                .all(|delta| delta.start_offset() != synced_offset)
+            {
+                deltas.push(
+                    interpreter
+                        .heap_delta(synced_offset)
+                        .map_err(WasmParallelEvaluationError::InterpreterError)?,
+                );
+            }
+        }
+        let worker_results = std::thread::scope(|scope| {
+            let base_heap = base_heap.as_deref();
+            let deltas = deltas.as_slice();
+            let threads = workers
+                .iter_mut()
+                .zip(partitions)
+                .map(|(worker, partition)| {
+                    scope.spawn(move || {
+                        worker.sync(base_heap, deltas)?;
+                        worker.evaluate(partition)
+                    })
+                })
+                .collect::<Vec<_>>();
+            threads
+                .into_iter()
+                .map(|thread| {
+                    thread
+                        .join()
+                        .map_err(|_| WasmParallelEvaluationError::WorkerPanic)
+                        .and_then(|result| {
+                            result.map_err(WasmParallelEvaluationError::InterpreterError)
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+        // Copy the worker results directly into the main interpreter heap, relocating each worker's terms onto the
+        // main interpreter heap as extended by the previously-merged workers
+        let mut results = Vec::with_capacity(inputs.len());
+        for (worker, worker_result) in workers.iter().zip(worker_results) {
+            let WasmWorkerResult {
+                roots,
+                cache_entries,
+            } = worker_result;
+            let roots = PartialHeapSnapshot::replicate(
+                &worker.interpreter,
+                roots,
+                cache_entries,
+                interpreter,
+            )
+            .map_err(WasmParallelEvaluationError::MergeError)?;
+            for (result_pointer, dependencies_pointer) in roots {
+                results.push(UnboundEvaluationResult {
+                    result_pointer,
+                    dependencies_pointer,
+                });
+            }
+        }
+        Ok(results)
+    }
+    fn create_worker(&self) -> Result<WasmParallelWorker, InterpreterError> {
+        let mut interpreter =
+            WasmInterpreter::instantiate_module(&self.module, self.memory_name, &self.options)?;
+        if self.initialize_workers {
+            interpreter.initialize()?;
+        }
+        Ok(WasmParallelWorker {
+            interpreter,
+            watermark: None,
+        })
+    }
+}
+
+struct WasmParallelWorker {
+    interpreter: WasmInterpreter,
+    /// Heap allocation state as of the most recent synchronization with the main interpreter heap, or `None` if the
+    /// worker has not yet been loaded with the main interpreter heap
+    watermark: Option<WasmHeapWatermark>,
+}
+
+impl WasmParallelWorker {
+    fn synced_offset(&self) -> Option<ArenaPointer> {
+        self.watermark
+            .as_ref()
+            .map(|watermark| watermark.end_offset())
+    }
+    fn sync(
+        &mut self,
+        base_heap: Option<&[u8]>,
+        deltas: &[WasmHeapDelta],
+    ) -> Result<(), InterpreterError> {
+        match self.watermark.take() {
+            None => {
+                let base_heap = base_heap.ok_or_else(|| {
+                    InterpreterError::HeapLoadError(anyhow::anyhow!(
+                        "Missing main interpreter heap"
+                    ))
+                })?;
+                self.interpreter.load_heap(base_heap)?;
+            }
+            Some(watermark) => {
+                let delta = deltas
+                    .iter()
+                    .find(|delta| delta.start_offset() == watermark.end_offset())
+                    .ok_or_else(|| {
+                        InterpreterError::HeapLoadError(anyhow::anyhow!(
+                            "Missing main interpreter heap delta"
+                        ))
+                    })?;
+                // Discard any allocations made by the previous evaluation before appending the main interpreter
+                // allocations made since the previous synchronization
+                self.interpreter.truncate_heap(watermark)?;
+                self.interpreter.apply_heap_delta(delta)?;
+            }
+        }
+        self.watermark = Some(self.interpreter.heap_watermark()?);
+        Ok(())
+    }
+    fn evaluate(
+        &mut self,
+        inputs: &[(ArenaPointer, ArenaPointer)],
+    ) -> Result<WasmWorkerResult, InterpreterError> {
+        let base_offset = self.interpreter.end_offset();
+        let roots = inputs
+            .iter()
+            .map(|(input, state)| {
+                self.interpreter
+                    .evaluate(*input, *state)
+                    .map(|result| (result.result_pointer, result.dependencies_pointer))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // Cache entries that were already present in the main interpreter heap do not need to be merged
+        let cache_entries = get_evaluation_cache_entries(&mut self.interpreter)?
+            .into_iter()
+            .filter(|entry| entry.value >= base_offset)
+            .collect();
+        Ok(WasmWorkerResult {
+            roots,
+            cache_entries,
+        })
+    }
+}
+
+struct WasmWorkerResult {
+    roots: Vec<(ArenaPointer, Option<ArenaPointer>)>,
+    cache_entries: Vec<PartialHeapSnapshotCacheEntry>,
+}
+
+fn get_evaluation_cache_entries(
+    interpreter: &mut WasmInterpreter,
+) -> Result<Vec<PartialHeapSnapshotCacheEntry>, InterpreterError> {
+    let global_name = "__cache";
+    let cache_global = match interpreter.get_global(global_name) {
+        Some(Val::I32(pointer)) => Ok(ArenaPointer::from(pointer as u32)),
+        _ => Err(InterpreterError::GlobalNotFound(String::from(global_name))),
+    }?;
+    let arena = &*interpreter;
+    // The cache global points to a pointer term which references the cell term that wraps the cache instance
+    let cache_cell = ArenaRef::<TypedTerm<PointerTerm>, _>::new(arena, cache_global)
+        .as_inner()
+        .target();
+    let cache_instance = ArenaRef::<TypedTerm<CellTerm>, _>::new(arena, cache_cell)
+        .as_inner()
+        .inner_ref(|term| &term.fields)
+        .item_offset(0);
+    let buckets = ArenaRef::<EvaluationCache, _>::new(arena, cache_instance)
+        .inner_ref(|cache| &cache.buckets)
+        .item_offsets()
+        .skip_uninitialized_pointers(&arena)
+        .map(|pointer| {
+            PartialHeapSnapshotCacheEntry::from(ArenaRef::<EvaluationCacheBucket, _>::new(
+                arena, pointer,
+            ))
+        })
+        .collect();
+    Ok(buckets)
+}
+
+#[derive(Debug)]
+pub enum WasmParallelEvaluationError {
+    InterpreterError(InterpreterError),
+    MergeError(PartialHeapSnapshotError),
+    WorkerPanic,
+}
+
+impl std::error::Error for WasmParallelEvaluationError {}
+
+impl std::fmt::Display for WasmParallelEvaluationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InterpreterError(err) => std::fmt::Display::fmt(err, f),
+            Self::MergeError(err) => write!(f, "Failed to merge worker results: {err}"),
+            Self::WorkerPanic => write!(f, "Parallel evaluation worker thread panicked"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const RUNTIME_BYTES: &[u8] = include_bytes!("../build/runtime.wasm");
+
+    use crate::{
+        allocator::ArenaAllocator,
+        stdlib::{Add, Stdlib},
+        term_type::{ApplicationTerm, BuiltinTerm, HashmapTerm, IntTerm, ListTerm, TermType},
+        Term,
+    };
+
+    use super::*;
+
+    fn allocate_addition(left: i64, right: i64, interpreter: &mut WasmInterpreter) -> ArenaPointer {
+        let left =
+            interpreter.allocate(Term::new(TermType::Int(IntTerm::from(left)), &*interpreter));
+        let right = interpreter.allocate(Term::new(
+            TermType::Int(IntTerm::from(right)),
+            &*interpreter,
+        ));
+        let add = interpreter.allocate(Term::new(
+            TermType::Builtin(BuiltinTerm::from(Stdlib::from(Add))),
+            &*interpreter,
+        ));
+        let args = ListTerm::allocate([left, right], interpreter);
+        interpreter.allocate(Term::new(
+            TermType::Application(ApplicationTerm { target: add, args }),
+            &*interpreter,
+        ))
+    }
+
+    fn get_int_value(interpreter: &WasmInterpreter, pointer: ArenaPointer) -> Option<i64> {
+        ArenaRef::<Term, _>::new(interpreter, pointer)
+            .as_int_term()
+            .map(|term| term.as_inner().value())
+    }
+
+    #[test]
+    fn parallel_evaluation() {
+        let program = WasmProgram::from_wasm(RUNTIME_BYTES.to_vec());
+        let options = WasmEngineOptions::default();
+        let mut interpreter = WasmInterpreter::instantiate(&program, "memory").unwrap();
+        interpreter.initialize().unwrap();
+        let evaluator =
+            WasmParallelEvaluator::new(&program, "memory", &options, NonZeroUsize::new(2).unwrap())
+                .unwrap()
+                .with_worker_initialization();
+
+        let state = HashmapTerm::allocate(std::iter::empty(), &mut interpreter);
+        let inputs = [(3, 4), (5, 6), (7, 8), (9, 10), (11, 12)]
+            .into_iter()
+            .map(|(left, right)| (allocate_addition(left, right, &mut interpreter), state))
+            .collect::<Vec<_>>();
+        let end_offset = interpreter.end_offset();
+
+        let results = evaluator.evaluate(&mut interpreter, &inputs).unwrap();
+
+        assert_eq!(
+            results
+                .iter()
+                .map(|result| get_int_value(&interpreter, result.result_pointer))
+                .collect::<Vec<_>>(),
+            vec![Some(7), Some(11), Some(15), Some(19), Some(23)],
+        );
+        // Worker results are merged into the main interpreter heap
+        assert!(results
+            .iter()
+            .all(|result| result.result_pointer >= end_offset
+                && result.result_pointer < interpreter.end_offset()));
+        // Merged cache entries are available to subsequent evaluations
+        let result = interpreter.evaluate(inputs[0].0, state).unwrap();
+        assert_eq!(get_int_value(&interpreter, result.result_pointer), Some(7));
+    }
+
+    #[test]
+    fn repeated_evaluation() {
+        let program = WasmProgram::from_wasm(RUNTIME_BYTES.to_vec());
+        let options = WasmEngineOptions::default();
+        let mut interpreter = WasmInterpreter::instantiate(&program, "memory").unwrap();
+        interpreter.initialize().unwrap();
+        let evaluator =
+            WasmParallelEvaluator::new(&program, "memory", &options, NonZeroUsize::new(2).unwrap())
+                .unwrap()
+                .with_worker_initialization();
+
+        let state = HashmapTerm::allocate(std::iter::empty(), &mut interpreter);
+        let watermark = interpreter.heap_watermark().unwrap();
+
+        for offset in 0..3 {
+            let inputs = [(3, 4), (5, 6), (7, 8)]
+                .into_iter()
+                .map(|(left, right)| {
+                    (
+                        allocate_addition(left + offset, right, &mut interpreter),
+                        state,
+                    )
+                })
+                .collect::<Vec<_>>();
+            let results = evaluator.evaluate(&mut interpreter, &inputs).unwrap();
+            assert_eq!(
+                results
+                    .iter()
+                    .map(|result| get_int_value(&interpreter, result.result_pointer))
+                    .collect::<Vec<_>>(),
+                vec![Some(7 + offset), Some(11 + offset), Some(15 + offset)],
+            );
+            // Workers are retained across calls, having been synchronized with the main interpreter heap
+            let workers = evaluator.workers.lock().unwrap();
+            assert_eq!(workers.len(), 2);
+            assert!(workers
+                .iter()
+                .all(|worker| worker.synced_offset().is_some()));
+        }
+
+        // Workers whose heap extends beyond the main interpreter heap are discarded
+        interpreter.truncate_heap(watermark).unwrap();
+        let inputs = [(3, 4), (5, 6)]
+            .into_iter()
+            .map(|(left, right)| (allocate_addition(left, right, &mut interpreter), state))
+            .collect::<Vec<_>>();
+        let results = evaluator.evaluate(&mut interpreter, &inputs).unwrap();
+        assert_eq!(
+            results
+                .iter()
+                .map(|result| get_int_value(&interpreter, result.result_pointer))
+                .collect::<Vec<_>>(),
+            vec![Some(7), Some(11)],
+        );
+
+        evaluator.reset();
+        assert!(evaluator.workers.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn serial_fallback() {
+        let program = WasmProgram::from_wasm(RUNTIME_BYTES.to_vec());
+        let options = WasmEngineOptions::default();
+        let mut interpreter = WasmInterpreter::instantiate(&program, "memory").unwrap();
+        interpreter.initialize().unwrap();
+        let evaluator =
+            WasmParallelEvaluator::new(&program, "memory", &options, NonZeroUsize::new(4).unwrap())
+                .unwrap();
+
+        let state = HashmapTerm::allocate(std::iter::empty(), &mut interpreter);
+        let input = allocate_addition(3, 4, &mut interpreter);
+
+        let results = evaluator
+            .evaluate(&mut interpreter, &[(input, state)])
+            .unwrap();
+
+        assert_eq!(
+            results
+                .iter()
+                .map(|result| get_int_value(&interpreter, result.result_pointer))
+                .collect::<Vec<_>>(),
+            vec![Some(7)],
+        );
+    }
+}
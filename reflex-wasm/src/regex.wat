@@ -0,0 +1,8 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  ;; Imported regular expression functions
+  (func $Utils::Regex::capture_count (import "Regex" "captureCount") (param i32 i32) (result i32))
+  (func $Utils::Regex::capture (import "Regex" "capture") (param i32 i32 i32 i32 i32) (result i64))
+  (func $Utils::Regex::replace (import "Regex" "replace") (param i32 i32 i32 i32 i32 i32 i32) (result i32)))
@@ -0,0 +1,137 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use crate::{allocator::Arena, ArenaPointer, ArenaRef, Term};
+
+/// Classification of the arena region that a pointer refers to
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum ArenaRegion {
+    /// Immutable heap snapshot that was present when the module was instantiated (e.g. the interned terms embedded
+    /// in the compiled module data sections)
+    Static,
+    /// Mutable region containing all terms allocated at runtime
+    Dynamic,
+}
+
+/// Region table describing the layout of an arena
+///
+/// Compiled modules embed their interned terms as a contiguous heap snapshot at the start of linear memory, with all
+/// runtime allocations appended after the end of the snapshot. A single boundary offset is therefore enough to classify
+/// any pointer, without needing to encode tag bits within the pointer itself (compiled code performs raw arithmetic on
+/// arena pointers, so tag bits would not survive the round trip through the WebAssembly runtime).
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct ArenaRegions {
+    static_end: ArenaPointer,
+}
+
+impl ArenaRegions {
+    /// Create a region table where all terms located before `static_end` are part of the static region
+    pub fn new(static_end: ArenaPointer) -> Self {
+        Self {
+            static_end: ArenaPointer::from(u32::from(static_end).max(Self::arena_start())),
+        }
+    }
+    /// Create a region table for an arena that does not contain a static region
+    pub fn dynamic() -> Self {
+        Self::new(ArenaPointer::from(Self::arena_start()))
+    }
+    /// Offset of the first term in the dynamic region
+    pub fn static_end(&self) -> ArenaPointer {
+        self.static_end
+    }
+    /// Determine which region the given pointer targets, returning `None` for null pointers
+    pub fn region(&self, pointer: ArenaPointer) -> Option<ArenaRegion> {
+        if pointer.is_null() {
+            None
+        } else if pointer < self.static_end {
+            Some(ArenaRegion::Static)
+        } else {
+            Some(ArenaRegion::Dynamic)
+        }
+    }
+    /// Determine whether the given pointer targets an immutable term within the static region
+    pub fn is_static(&self, pointer: ArenaPointer) -> bool {
+        matches!(self.region(pointer), Some(ArenaRegion::Static))
+    }
+    /// Determine whether the given pointer targets a term allocated within the dynamic region
+    pub fn is_dynamic(&self, pointer: ArenaPointer) -> bool {
+        matches!(self.region(pointer), Some(ArenaRegion::Dynamic))
+    }
+    /// Filter the given garbage collection roots to exclude null pointers and pointers into the static region
+    ///
+    /// Terms within the static region are never moved or reclaimed, and can only refer to other static terms, so there
+    /// is no need to retain them as roots.
+    pub fn dynamic_roots(
+        &self,
+        roots: impl IntoIterator<Item = ArenaPointer>,
+    ) -> impl Iterator<Item = ArenaPointer> {
+        let regions = *self;
+        roots
+            .into_iter()
+            .filter(move |pointer| regions.is_dynamic(*pointer))
+    }
+    /// Determine whether two terms within the given arena are equal, short-circuiting the structural comparison where
+    /// both pointers refer to the same location
+    pub fn terms_equal<A: Arena + Clone>(
+        &self,
+        arena: A,
+        left: ArenaPointer,
+        right: ArenaPointer,
+    ) -> bool {
+        if left == right {
+            return true;
+        }
+        match (self.region(left), self.region(right)) {
+            (Some(_), Some(_)) => {
+                ArenaRef::<Term, _>::new(arena.clone(), left)
+                    == ArenaRef::<Term, _>::new(arena, right)
+            }
+            _ => false,
+        }
+    }
+    fn arena_start() -> u32 {
+        std::mem::size_of::<u32>() as u32
+    }
+}
+
+impl Default for ArenaRegions {
+    fn default() -> Self {
+        Self::dynamic()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        allocator::{ArenaAllocator, VecAllocator},
+        term_type::{IntTerm, TermType},
+    };
+
+    use super::*;
+
+    #[test]
+    fn region_classification() {
+        let mut arena = VecAllocator::default();
+        let static_term = arena.allocate(Term::new(TermType::Int(IntTerm::from(3)), &arena));
+        let regions = ArenaRegions::new(arena.end_offset());
+        let dynamic_term = arena.allocate(Term::new(TermType::Int(IntTerm::from(3)), &arena));
+        let other_term = arena.allocate(Term::new(TermType::Int(IntTerm::from(4)), &arena));
+        assert_eq!(regions.region(static_term), Some(ArenaRegion::Static));
+        assert_eq!(regions.region(dynamic_term), Some(ArenaRegion::Dynamic));
+        assert_eq!(regions.region(ArenaPointer::null()), None);
+        assert!(regions.is_static(static_term));
+        assert!(!regions.is_static(dynamic_term));
+        assert!(regions.is_dynamic(other_term));
+        assert_eq!(
+            regions
+                .dynamic_roots([static_term, ArenaPointer::null(), dynamic_term])
+                .collect::<Vec<_>>(),
+            vec![dynamic_term],
+        );
+        assert!(regions.terms_equal(&arena, static_term, static_term));
+        assert!(regions.terms_equal(&arena, static_term, dynamic_term));
+        assert!(!regions.terms_equal(&arena, dynamic_term, other_term));
+        assert!(!regions.terms_equal(&arena, static_term, ArenaPointer::null()));
+        assert!(ArenaRegions::dynamic().is_dynamic(static_term));
+    }
+}
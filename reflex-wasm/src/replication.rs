@@ -0,0 +1,487 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::collections::VecDeque;
+
+use reflex::hash::{IntMap, IntSet};
+
+use crate::{
+    allocator::{Arena, ArenaAllocator, VecAllocator},
+    cache::{EvaluationCacheBucket, EFFECT_TYPE_CACHE},
+    interpreter::{InterpreterError, WasmInterpreter},
+    serialize::{Serialize, SerializerState},
+    term_type::{ConditionTerm, TreeTerm, TypedTerm},
+    ArenaPointer, ArenaRef, Term,
+};
+
+/// Compact heap snapshot containing only the terms reachable from a set of graph roots, along with
+/// any cached results that those roots depend on
+///
+/// The snapshot is intended to be appended to a replica heap that was initialized from the same base
+/// heap as the source heap (e.g. the initial linear memory of the same compiled module), so all
+/// internal pointers are expressed as addresses within the replica heap.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct PartialHeapSnapshot {
+    /// Replica heap offset at which the snapshot data should be written
+    pub base_offset: ArenaPointer,
+    /// Serialized term data
+    pub data: Vec<u8>,
+    /// Graph root `(value, dependencies)` pairs, relocated into the replica heap
+    pub roots: Vec<(ArenaPointer, Option<ArenaPointer>)>,
+    /// Evaluation cache entries referenced by the graph root dependencies, relocated into the replica heap
+    pub cache_entries: Vec<PartialHeapSnapshotCacheEntry>,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct PartialHeapSnapshotCacheEntry {
+    pub key: u64,
+    pub value: ArenaPointer,
+    pub dependencies: Option<ArenaPointer>,
+}
+
+impl<A: Arena + Clone> From<ArenaRef<EvaluationCacheBucket, A>> for PartialHeapSnapshotCacheEntry {
+    fn from(value: ArenaRef<EvaluationCacheBucket, A>) -> Self {
+        Self {
+            key: value.key(),
+            value: value.read_value(|bucket| bucket.value),
+            dependencies: value.read_value(|bucket| bucket.dependencies.as_non_null()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum PartialHeapSnapshotError {
+    InvalidBaseOffset {
+        expected: ArenaPointer,
+        received: ArenaPointer,
+    },
+    InterpreterError(InterpreterError),
+}
+
+impl std::error::Error for PartialHeapSnapshotError {}
+
+impl std::fmt::Display for PartialHeapSnapshotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidBaseOffset { expected, received } => write!(
+                f,
+                "Invalid snapshot base offset: expected {}, received {}",
+                u32::from(*expected),
+                u32::from(*received)
+            ),
+            Self::InterpreterError(err) => std::fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+impl From<InterpreterError> for PartialHeapSnapshotError {
+    fn from(value: InterpreterError) -> Self {
+        Self::InterpreterError(value)
+    }
+}
+
+impl PartialHeapSnapshot {
+    /// Extract the terms reachable from the given graph roots, along with any cache entries that are
+    /// transitively referenced by the graph root dependencies
+    ///
+    /// The `base_heap` argument holds the contents of the replica heap that the snapshot will be
+    /// appended to. Cache entries that are not referenced by any of the graph roots are omitted.
+    pub fn export<A: Arena + Clone>(
+        source_arena: A,
+        roots: impl IntoIterator<Item = (ArenaPointer, Option<ArenaPointer>)>,
+        cache_entries: impl IntoIterator<Item = PartialHeapSnapshotCacheEntry>,
+        base_heap: &[u8],
+    ) -> Self {
+        let mut target_arena = VecAllocator::from_bytes(base_heap);
+        let base_offset = target_arena.end_offset();
+        let mut serializer_state = SerializerState::new([], base_offset);
+        let (roots, cache_entries) = copy_reachable_terms(
+            &source_arena,
+            roots,
+            cache_entries,
+            &mut target_arena,
+            &mut serializer_state,
+        );
+        let data = target_arena.as_bytes()
+            [u32::from(base_offset) as usize..u32::from(serializer_state.end_offset()) as usize]
+            .to_vec();
+        Self {
+            base_offset,
+            data,
+            roots,
+            cache_entries,
+        }
+    }
+    /// Append the snapshot terms to the given replica heap
+    pub fn write(&self, arena: &mut impl ArenaAllocator) -> Result<(), PartialHeapSnapshotError> {
+        // Read the initial 4-byte allocator offset marker
+        let end_offset = arena.read_value::<u32, _>(ArenaPointer::from(0), |value| *value);
+        let end_offset = ArenaPointer::from(end_offset);
+        if end_offset != self.base_offset {
+            return Err(PartialHeapSnapshotError::InvalidBaseOffset {
+                expected: self.base_offset,
+                received: end_offset,
+            });
+        }
+        arena.extend(self.base_offset, self.data.len());
+        for (index, word) in self.data.chunks_exact(4).enumerate() {
+            arena.write::<u32>(
+                self.base_offset.offset((index * 4) as u32),
+                u32::from_le_bytes([word[0], word[1], word[2], word[3]]),
+            );
+        }
+        Ok(())
+    }
+    /// Append the snapshot terms to the replica heap and populate the replica evaluation cache with
+    /// the snapshot cache entries
+    pub fn import(
+        &self,
+        interpreter: &mut WasmInterpreter,
+    ) -> Result<(), PartialHeapSnapshotError> {
+        self.write(interpreter)?;
+        set_cache_entries(&self.cache_entries, interpreter)
+    }
+    /// Copy the terms reachable from the given graph roots directly into the replica heap, along with any cache
+    /// entries that are transitively referenced by the graph root dependencies, and populate the replica evaluation
+    /// cache with the copied cache entries
+    ///
+    /// This is equivalent to exporting a snapshot against the current replica heap contents and importing it into the
+    /// replica, without needing to copy the replica heap. Returns the graph roots, relocated into the replica heap.
+    pub fn replicate<A: Arena + Clone>(
+        source_arena: A,
+        roots: impl IntoIterator<Item = (ArenaPointer, Option<ArenaPointer>)>,
+        cache_entries: impl IntoIterator<Item = PartialHeapSnapshotCacheEntry>,
+        interpreter: &mut WasmInterpreter,
+    ) -> Result<Vec<(ArenaPointer, Option<ArenaPointer>)>, PartialHeapSnapshotError> {
+        let mut serializer_state = SerializerState::new([], interpreter.end_offset());
+        let (roots, cache_entries) = copy_reachable_terms(
+            &source_arena,
+            roots,
+            cache_entries,
+            interpreter,
+            &mut serializer_state,
+        );
+        set_cache_entries(&cache_entries, interpreter)?;
+        Ok(roots)
+    }
+}
+
+fn copy_reachable_terms<A: Arena + Clone>(
+    source_arena: &A,
+    roots: impl IntoIterator<Item = (ArenaPointer, Option<ArenaPointer>)>,
+    cache_entries: impl IntoIterator<Item = PartialHeapSnapshotCacheEntry>,
+    target_arena: &mut impl ArenaAllocator,
+    serializer_state: &mut SerializerState,
+) -> (
+    Vec<(ArenaPointer, Option<ArenaPointer>)>,
+    Vec<PartialHeapSnapshotCacheEntry>,
+) {
+    let cache_entries = cache_entries
+        .into_iter()
+        .map(|entry| (entry.key, entry))
+        .collect::<IntMap<_, _>>();
+    let mut pending_dependencies = VecDeque::new();
+    let roots = roots
+        .into_iter()
+        .map(|(value, dependencies)| {
+            pending_dependencies.extend(dependencies);
+            (
+                copy_term(value, source_arena, target_arena, serializer_state),
+                dependencies.map(|dependencies| {
+                    copy_term(dependencies, source_arena, target_arena, serializer_state)
+                }),
+            )
+        })
+        .collect::<Vec<_>>();
+    // Traverse the dependency trees to locate any referenced cache entries, including the dependencies
+    // of the cached results themselves
+    let mut visited_cache_keys = IntSet::default();
+    let mut exported_cache_entries = Vec::new();
+    while let Some(dependencies) = pending_dependencies.pop_front() {
+        let dependencies =
+            ArenaRef::<TypedTerm<TreeTerm>, _>::new(source_arena.clone(), dependencies);
+        for condition in dependencies.as_inner().typed_nodes::<ConditionTerm>() {
+            let Some(cache_key) = parse_cache_dependency(&condition) else {
+                continue;
+            };
+            if !visited_cache_keys.insert(cache_key) {
+                continue;
+            }
+            let Some(entry) = cache_entries.get(&cache_key) else {
+                continue;
+            };
+            pending_dependencies.extend(entry.dependencies);
+            exported_cache_entries.push(PartialHeapSnapshotCacheEntry {
+                key: entry.key,
+                value: copy_term(entry.value, source_arena, target_arena, serializer_state),
+                dependencies: entry.dependencies.map(|dependencies| {
+                    copy_term(dependencies, source_arena, target_arena, serializer_state)
+                }),
+            });
+        }
+    }
+    (roots, exported_cache_entries)
+}
+
+fn set_cache_entries(
+    cache_entries: &[PartialHeapSnapshotCacheEntry],
+    interpreter: &mut WasmInterpreter,
+) -> Result<(), PartialHeapSnapshotError> {
+    for entry in cache_entries {
+        let _ = interpreter.call::<(u64, u32, u32), (u32, u32)>(
+            "setRuntimeEvaluationCacheEntry",
+            (
+                entry.key,
+                u32::from(entry.value),
+                u32::from(entry.dependencies.unwrap_or(ArenaPointer::null())),
+            ),
+        )?;
+    }
+    Ok(())
+}
+
+fn copy_term<A: Arena + Clone>(
+    source_pointer: ArenaPointer,
+    source_arena: &A,
+    target_arena: &mut impl ArenaAllocator,
+    serializer_state: &mut SerializerState,
+) -> ArenaPointer {
+    ArenaRef::<Term, _>::new(source_arena.clone(), source_pointer)
+        .serialize(target_arena, serializer_state)
+}
+
+fn parse_cache_dependency<A: Arena + Clone>(
+    condition: &ArenaRef<TypedTerm<ConditionTerm>, A>,
+) -> Option<u64> {
+    condition
+        .as_inner()
+        .as_custom_condition()
+        .and_then(|condition| {
+            let is_cache_dependency = condition
+                .as_inner()
+                .effect_type()
+                .as_string_term()
+                .map(|effect_type| effect_type.read_str(|value| value == EFFECT_TYPE_CACHE))
+                .unwrap_or(false);
+            if is_cache_dependency {
+                condition
+                    .as_inner()
+                    .payload()
+                    .as_int_term()
+                    .map(|term| term.as_inner().value() as u64)
+            } else {
+                None
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    const RUNTIME_BYTES: &[u8] = include_bytes!("../build/runtime.wasm");
+
+    use reflex::core::NodeId;
+
+    use crate::{
+        allocator::ArenaAllocator,
+        interpreter::{mocks::add_import_stubs, WasmContextBuilder},
+        term_type::{CustomCondition, IntTerm, NilTerm, StringTerm, TermType},
+    };
+
+    use super::*;
+
+    fn create_cache_dependency(key: u64, arena: &mut VecAllocator) -> ArenaPointer {
+        let effect_type = StringTerm::allocate(EFFECT_TYPE_CACHE, arena);
+        let payload = arena.allocate(Term::new(TermType::Int(IntTerm::from(key as i64)), &*arena));
+        let token = arena.allocate(Term::new(TermType::Nil(NilTerm), &*arena));
+        let condition = arena.allocate(Term::new(
+            TermType::Condition(ConditionTerm::Custom(CustomCondition {
+                effect_type,
+                payload,
+                token,
+            })),
+            &*arena,
+        ));
+        arena.allocate(Term::new(
+            TermType::Tree(TreeTerm {
+                left: condition,
+                right: ArenaPointer::null(),
+                length: 1,
+                depth: 1,
+            }),
+            &*arena,
+        ))
+    }
+
+    fn get_term_id(arena: impl Arena + Clone, pointer: ArenaPointer) -> u64 {
+        ArenaRef::<Term, _>::new(arena, pointer).id()
+    }
+
+    #[test]
+    fn partial_snapshot() {
+        let mut interpreter = {
+            let context = WasmContextBuilder::from_wasm(RUNTIME_BYTES, "memory").unwrap();
+            let mut interpreter: WasmInterpreter =
+                add_import_stubs(context).unwrap().build().unwrap().into();
+            interpreter.initialize().unwrap();
+            interpreter
+        };
+        let base_heap = interpreter.dump_heap();
+
+        let mut source_arena = VecAllocator::from_bytes(&base_heap);
+        let _unreachable =
+            source_arena.allocate(Term::new(TermType::Int(IntTerm::from(1)), &source_arena));
+        let root_value =
+            source_arena.allocate(Term::new(TermType::Int(IntTerm::from(2)), &source_arena));
+        let root_dependencies = create_cache_dependency(1, &mut source_arena);
+        let cached_value =
+            source_arena.allocate(Term::new(TermType::Int(IntTerm::from(3)), &source_arena));
+        let cached_dependencies = create_cache_dependency(2, &mut source_arena);
+        let nested_cached_value =
+            source_arena.allocate(Term::new(TermType::Int(IntTerm::from(4)), &source_arena));
+        let unreferenced_cached_value =
+            source_arena.allocate(Term::new(TermType::Int(IntTerm::from(5)), &source_arena));
+
+        let snapshot = PartialHeapSnapshot::export(
+            &source_arena,
+            [(root_value, Some(root_dependencies))],
+            [
+                PartialHeapSnapshotCacheEntry {
+                    key: 1,
+                    value: cached_value,
+                    dependencies: Some(cached_dependencies),
+                },
+                PartialHeapSnapshotCacheEntry {
+                    key: 2,
+                    value: nested_cached_value,
+                    dependencies: None,
+                },
+                PartialHeapSnapshotCacheEntry {
+                    key: 3,
+                    value: unreferenced_cached_value,
+                    dependencies: None,
+                },
+            ],
+            &base_heap,
+        );
+
+        assert_eq!(snapshot.base_offset, interpreter.end_offset());
+        assert!(
+            snapshot.data.len()
+                < (u32::from(source_arena.end_offset()) - u32::from(snapshot.base_offset)) as usize
+        );
+        assert_eq!(
+            snapshot
+                .cache_entries
+                .iter()
+                .map(|entry| entry.key)
+                .collect::<Vec<_>>(),
+            vec![1, 2],
+        );
+
+        snapshot.import(&mut interpreter).unwrap();
+
+        let [(value, dependencies)] = snapshot.roots.as_slice() else {
+            panic!("Invalid snapshot roots");
+        };
+        assert!(*value >= snapshot.base_offset);
+        assert!(*value < snapshot.base_offset.offset(snapshot.data.len() as u32));
+        assert_eq!(
+            get_term_id(&interpreter, *value),
+            get_term_id(&source_arena, root_value),
+        );
+        assert_eq!(
+            get_term_id(&interpreter, dependencies.unwrap()),
+            get_term_id(&source_arena, root_dependencies),
+        );
+        for (key, expected) in [(1, cached_value), (2, nested_cached_value)] {
+            let (value, _) = interpreter
+                .call::<u64, (u32, u32)>("getRuntimeEvaluationCacheEntry", key)
+                .unwrap();
+            assert_eq!(
+                get_term_id(&interpreter, ArenaPointer::from(value)),
+                get_term_id(&source_arena, expected),
+            );
+        }
+        let (value, _) = interpreter
+            .call::<u64, (u32, u32)>("getRuntimeEvaluationCacheEntry", 3)
+            .unwrap();
+        assert!(ArenaPointer::from(value).is_null());
+    }
+
+    #[test]
+    fn direct_replication() {
+        let mut interpreter = {
+            let context = WasmContextBuilder::from_wasm(RUNTIME_BYTES, "memory").unwrap();
+            let mut interpreter: WasmInterpreter =
+                add_import_stubs(context).unwrap().build().unwrap().into();
+            interpreter.initialize().unwrap();
+            interpreter
+        };
+        let base_heap = interpreter.dump_heap();
+
+        let mut source_arena = VecAllocator::from_bytes(&base_heap);
+        let _unreachable =
+            source_arena.allocate(Term::new(TermType::Int(IntTerm::from(1)), &source_arena));
+        let root_value =
+            source_arena.allocate(Term::new(TermType::Int(IntTerm::from(2)), &source_arena));
+        let root_dependencies = create_cache_dependency(1, &mut source_arena);
+        let cached_value =
+            source_arena.allocate(Term::new(TermType::Int(IntTerm::from(3)), &source_arena));
+
+        let cache_entries = [PartialHeapSnapshotCacheEntry {
+            key: 1,
+            value: cached_value,
+            dependencies: None,
+        }];
+        let snapshot = PartialHeapSnapshot::export(
+            &source_arena,
+            [(root_value, Some(root_dependencies))],
+            cache_entries,
+            &base_heap,
+        );
+        let roots = PartialHeapSnapshot::replicate(
+            &source_arena,
+            [(root_value, Some(root_dependencies))],
+            cache_entries,
+            &mut interpreter,
+        )
+        .unwrap();
+
+        // Replicating directly into the interpreter heap produces the same result as importing a snapshot
+        assert_eq!(roots, snapshot.roots);
+        let [(value, _)] = roots.as_slice() else {
+            panic!("Invalid replicated roots");
+        };
+        assert_eq!(
+            get_term_id(&interpreter, *value),
+            get_term_id(&source_arena, root_value),
+        );
+        let (value, _) = interpreter
+            .call::<u64, (u32, u32)>("getRuntimeEvaluationCacheEntry", 1)
+            .unwrap();
+        assert_eq!(
+            get_term_id(&interpreter, ArenaPointer::from(value)),
+            get_term_id(&source_arena, cached_value),
+        );
+    }
+
+    #[test]
+    fn invalid_base_offset() {
+        let mut source_arena = VecAllocator::default();
+        let value =
+            source_arena.allocate(Term::new(TermType::Int(IntTerm::from(3)), &source_arena));
+        let snapshot = PartialHeapSnapshot::export(
+            &source_arena,
+            [(value, None)],
+            [],
+            VecAllocator::default().as_bytes(),
+        );
+        let mut target_arena = VecAllocator::default();
+        let _filler =
+            target_arena.allocate(Term::new(TermType::Int(IntTerm::from(4)), &target_arena));
+        assert!(matches!(
+            snapshot.write(&mut target_arena),
+            Err(PartialHeapSnapshotError::InvalidBaseOffset { .. })
+        ));
+    }
+}
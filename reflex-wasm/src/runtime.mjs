@@ -18,14 +18,17 @@ function u64(value) {
 function createTermTypes(runtime) {
   return {
     Application: runtime.TermType_Application.value,
+    BigInt: runtime.TermType_BigInt.value,
     Boolean: runtime.TermType_Boolean.value,
     Builtin: runtime.TermType_Builtin.value,
+    Bytes: runtime.TermType_Bytes.value,
     Cell: runtime.TermType_Cell.value,
     Hashmap: runtime.TermType_Hashmap.value,
     Hashset: runtime.TermType_Hashset.value,
     List: runtime.TermType_List.value,
     Condition: runtime.TermType_Condition.value,
     Constructor: runtime.TermType_Constructor.value,
+    Duration: runtime.TermType_Duration.value,
     Effect: runtime.TermType_Effect.value,
     Float: runtime.TermType_Float.value,
     Int: runtime.TermType_Int.value,
@@ -33,6 +36,8 @@ function createTermTypes(runtime) {
     Partial: runtime.TermType_Partial.value,
     Pointer: runtime.TermType_Pointer.value,
     Record: runtime.TermType_Record.value,
+    Recursive: runtime.TermType_Recursive.value,
+    Regex: runtime.TermType_Regex.value,
     Signal: runtime.TermType_Signal.value,
     String: runtime.TermType_String.value,
     Symbol: runtime.TermType_Symbol.value,
@@ -41,6 +46,7 @@ function createTermTypes(runtime) {
     Lambda: runtime.TermType_Lambda.value,
     Variable: runtime.TermType_Variable.value,
     Let: runtime.TermType_Let.value,
+    DedupeIterator: runtime.TermType_DedupeIterator.value,
     EmptyIterator: runtime.TermType_EmptyIterator.value,
     EvaluateIterator: runtime.TermType_EvaluateIterator.value,
     FilterIterator: runtime.TermType_FilterIterator.value,
@@ -56,6 +62,9 @@ function createTermTypes(runtime) {
     SkipIterator: runtime.TermType_SkipIterator.value,
     TakeIterator: runtime.TermType_TakeIterator.value,
     ZipIterator: runtime.TermType_ZipIterator.value,
+    ListView: runtime.TermType_ListView.value,
+    ListRope: runtime.TermType_ListRope.value,
+    Sensitive: runtime.TermType_Sensitive.value,
   };
 }
 
@@ -92,13 +101,23 @@ function createStdlib(runtime) {
     CollectTree: runtime.__Stdlib_CollectTree.value,
     Cons: runtime.__Stdlib_Cons.value,
     Construct: runtime.__Stdlib_Construct.value,
+    DateAdd: runtime.__Stdlib_DateAdd.value,
+    DateDiff: runtime.__Stdlib_DateDiff.value,
+    DateOffset: runtime.__Stdlib_DateOffset.value,
+    DateTruncate: runtime.__Stdlib_DateTruncate.value,
+    DateWithOffset: runtime.__Stdlib_DateWithOffset.value,
+    Debounce: runtime.__Stdlib_Debounce.value,
     Debug: runtime.__Stdlib_Debug.value,
     DecrementVariable: runtime.__Stdlib_DecrementVariable.value,
+    Dedupe: runtime.__Stdlib_Dedupe.value,
+    Delete: runtime.__Stdlib_Delete.value,
     Divide: runtime.__Stdlib_Divide.value,
+    Duration: runtime.__Stdlib_Duration.value,
     Effect: runtime.__Stdlib_Effect.value,
     EndsWith: runtime.__Stdlib_EndsWith.value,
     Eq: runtime.__Stdlib_Eq.value,
     Equal: runtime.__Stdlib_Equal.value,
+    Eval: runtime.__Stdlib_Eval.value,
     Filter: runtime.__Stdlib_Filter.value,
     Flatten: runtime.__Stdlib_Flatten.value,
     Floor: runtime.__Stdlib_Floor.value,
@@ -111,6 +130,8 @@ function createStdlib(runtime) {
     GraphQlResolver: runtime.__Stdlib_GraphQlResolver.value,
     Has: runtime.__Stdlib_Has.value,
     Hash: runtime.__Stdlib_Hash.value,
+    HashmapFromIterator: runtime.__Stdlib_HashmapFromIterator.value,
+    HashsetFromIterator: runtime.__Stdlib_HashsetFromIterator.value,
     Identity: runtime.__Stdlib_Identity.value,
     If: runtime.__Stdlib_If.value,
     IfError: runtime.__Stdlib_IfError.value,
@@ -123,15 +144,20 @@ function createStdlib(runtime) {
     Keys: runtime.__Stdlib_Keys.value,
     Length: runtime.__Stdlib_Length.value,
     Log: runtime.__Stdlib_Log.value,
+    LogScope: runtime.__Stdlib_LogScope.value,
     Lt: runtime.__Stdlib_Lt.value,
     Lte: runtime.__Stdlib_Lte.value,
     Map: runtime.__Stdlib_Map.value,
+    Match: runtime.__Stdlib_Match.value,
     Max: runtime.__Stdlib_Max.value,
+    Memo: runtime.__Stdlib_Memo.value,
+    MemoStore: runtime.__Stdlib_MemoStore.value,
     Merge: runtime.__Stdlib_Merge.value,
     Min: runtime.__Stdlib_Min.value,
     Multiply: runtime.__Stdlib_Multiply.value,
     Not: runtime.__Stdlib_Not.value,
     Or: runtime.__Stdlib_Or.value,
+    Paginate: runtime.__Stdlib_Paginate.value,
     ParseDate: runtime.__Stdlib_ParseDate.value,
     ParseFloat: runtime.__Stdlib_ParseFloat.value,
     ParseInt: runtime.__Stdlib_ParseInt.value,
@@ -139,6 +165,7 @@ function createStdlib(runtime) {
     Pow: runtime.__Stdlib_Pow.value,
     Push: runtime.__Stdlib_Push.value,
     PushFront: runtime.__Stdlib_PushFront.value,
+    Quote: runtime.__Stdlib_Quote.value,
     Raise: runtime.__Stdlib_Raise.value,
     Remainder: runtime.__Stdlib_Remainder.value,
     Replace: runtime.__Stdlib_Replace.value,
@@ -152,18 +179,27 @@ function createStdlib(runtime) {
     ResolveQueryLeaf: runtime.__Stdlib_ResolveQueryLeaf.value,
     ResolveRecord: runtime.__Stdlib_ResolveRecord.value,
     ResolveTree: runtime.__Stdlib_ResolveTree.value,
+    Reveal: runtime.__Stdlib_Reveal.value,
     Round: runtime.__Stdlib_Round.value,
+    Sample: runtime.__Stdlib_Sample.value,
     Scan: runtime.__Stdlib_Scan.value,
+    ScopedLog: runtime.__Stdlib_ScopedLog.value,
     Sequence: runtime.__Stdlib_Sequence.value,
     Set: runtime.__Stdlib_Set.value,
     SetVariable: runtime.__Stdlib_SetVariable.value,
     Skip: runtime.__Stdlib_Skip.value,
     Slice: runtime.__Stdlib_Slice.value,
+    Sort: runtime.__Stdlib_Sort.value,
+    SortBy: runtime.__Stdlib_SortBy.value,
+    SortMerge: runtime.__Stdlib_SortMerge.value,
     Split: runtime.__Stdlib_Split.value,
     StartsWith: runtime.__Stdlib_StartsWith.value,
     StringifyJson: runtime.__Stdlib_StringifyJson.value,
+    StringifyJsonCanonical: runtime.__Stdlib_StringifyJsonCanonical.value,
     Subtract: runtime.__Stdlib_Subtract.value,
     Take: runtime.__Stdlib_Take.value,
+    Template: runtime.__Stdlib_Template.value,
+    Throttle: runtime.__Stdlib_Throttle.value,
     Throw: runtime.__Stdlib_Throw.value,
     ToRequest: runtime.__Stdlib_ToRequest.value,
     ToString: runtime.__Stdlib_ToString.value,
@@ -216,6 +252,49 @@ export function createRuntime(runtime) {
       const bigIntValue = runtime.getIntValue(value);
       return bigIntValue > Number.MAX_SAFE_INTEGER ? bigIntValue : Number(bigIntValue);
     },
+    createBigInt(value) {
+      const sign = value < BigInt(0) ? 1 : 0;
+      const limbs = [];
+      for (let remaining = sign ? -value : value; remaining > BigInt(0); remaining >>= BigInt(32)) {
+        limbs.push(Number(remaining & BigInt(0xffffffff)));
+      }
+      const instance = runtime.allocateBigInt(sign, limbs.length);
+      if (limbs.length > 0) {
+        const offset = runtime.getBigIntLimbOffset(instance, 0);
+        new Uint32Array(runtime.memory.buffer, offset, limbs.length).set(limbs);
+      }
+      return runtime.initBigInt(instance);
+    },
+    isBigInt(value) {
+      return runtime.isBigInt(value);
+    },
+    getBigIntValue(value) {
+      const length = runtime.getBigIntLength(value);
+      const offset = runtime.getBigIntLimbOffset(value, 0);
+      const limbs = new Uint32Array(runtime.memory.buffer, offset, length);
+      const magnitude = limbs.reduceRight(
+        (result, limb) => (result << BigInt(32)) | BigInt(limb),
+        BigInt(0),
+      );
+      return runtime.getBigIntSign(value) ? -magnitude : magnitude;
+    },
+    createBytes(value) {
+      const length = value.length;
+      const instance = runtime.allocateBytes(length);
+      if (length > 0) {
+        const offset = runtime.getBytesOffset(instance);
+        new Uint8Array(runtime.memory.buffer, offset, length).set(value);
+      }
+      return runtime.initBytes(instance);
+    },
+    isBytes(value) {
+      return runtime.isBytes(value);
+    },
+    getBytesValue(value) {
+      const length = runtime.getBytesLength(value);
+      const offset = runtime.getBytesOffset(value);
+      return Uint8Array.from(new Uint8Array(runtime.memory.buffer, offset, length));
+    },
     createFloat(value) {
       return runtime.createFloat(value);
     },
@@ -254,14 +333,44 @@ export function createRuntime(runtime) {
     getStringValue(value) {
       return getStringValue(value);
     },
-    createTimestamp(millis) {
-      return runtime.createTimestamp(BigInt(millis));
+    createRegex(pattern) {
+      return runtime.createRegex(pattern);
+    },
+    isRegex(value) {
+      return runtime.isRegex(value);
+    },
+    getRegexPattern(value) {
+      return runtime.getRegexPattern(value);
+    },
+    createSensitive(value) {
+      return runtime.createSensitive(value);
+    },
+    isSensitive(value) {
+      return runtime.isSensitive(value);
+    },
+    getSensitiveValue(value) {
+      return runtime.getSensitiveValue(value);
+    },
+    createTimestamp(millis, offset = 0) {
+      return runtime.createTimestamp(BigInt(millis), offset);
     },
     isTimestamp(value) {
       return runtime.isTimestamp(value);
     },
     getTimestampMillis(value) {
-      return Number(runtime.getDateTimestamp(value));
+      return Number(runtime.getTimestampMillis(value));
+    },
+    getTimestampOffset(value) {
+      return runtime.getTimestampOffset(value);
+    },
+    createDuration(millis) {
+      return runtime.createDuration(BigInt(millis));
+    },
+    isDuration(value) {
+      return runtime.isDuration(value);
+    },
+    getDurationMillis(value) {
+      return Number(runtime.getDurationMillis(value));
     },
     createSignal(condition) {
       return runtime.createSignal(condition);
@@ -477,6 +586,33 @@ export function createRuntime(runtime) {
     createTriple(first, second, third) {
       return runtime.createTriple(first, second, third);
     },
+    createListView(source, offset, length) {
+      return runtime.createListView(source, offset, length);
+    },
+    isListView(value) {
+      return runtime.isListView(value);
+    },
+    getListViewSource(value) {
+      return runtime.getListViewSource(value);
+    },
+    getListViewOffset(value) {
+      return u32(runtime.getListViewOffset(value));
+    },
+    getListViewLength(value) {
+      return u32(runtime.getListViewLength(value));
+    },
+    createListRope(segments, length) {
+      return runtime.createListRope(segments, length);
+    },
+    isListRope(value) {
+      return runtime.isListRope(value);
+    },
+    getListRopeSegments(value) {
+      return runtime.getListRopeSegments(value);
+    },
+    getListRopeLength(value) {
+      return u32(runtime.getListRopeLength(value));
+    },
     createRecord(keys, values) {
       return runtime.createRecord(keys, values);
     },
@@ -492,6 +628,15 @@ export function createRuntime(runtime) {
     getRecordValue(value, key) {
       return runtime.getRecordValue(value, key);
     },
+    createRecursive(factory) {
+      return runtime.createRecursive(factory);
+    },
+    isRecursive(value) {
+      return runtime.isRecursive(value);
+    },
+    getRecursiveFactory(value) {
+      return runtime.getRecursiveFactory(value);
+    },
     createConstructor(keys) {
       return runtime.createConstructor(keys);
     },
@@ -569,6 +714,9 @@ export function createRuntime(runtime) {
     getTreeValues(value) {
       return getTreeValues(value);
     },
+    createDedupeIterator(source) {
+      return runtime.createDedupeIterator(source);
+    },
     createEmptyIterator() {
       return runtime.createEmptyIterator();
     },
@@ -6,6 +6,8 @@
   (@include "./date.wat")
   (@include "./math.wat")
   (@include "./number.wat")
+  (@include "./float.wat")
+  (@include "./regex.wat")
   (@include "./wasi.wat")
   (@include "./io.wat")
   (@include "./json.wat")
@@ -36,6 +38,29 @@
     ;; Return a new dependency tree containing a single state dependency comprising the state token
     (call $Term::Tree::of (local.get $condition)))
 
+  (func $Runtime::print_result (export "printResult") (param $result i32) (result i32)
+    ;; Write the JSON representation of the provided evaluation result to stdout, returning a zero exit code,
+    ;; or write the formatted result to stderr and return a non-zero exit code if the result is a signal
+    ;; (or is not serializable as JSON)
+    (local $output i32)
+    (if (result i32)
+      (call $Term::Signal::is (local.get $result))
+      (then
+        (drop (call $Io::write_term (global.get $Io::STDERR) (local.get $result)))
+        (i32.const 1))
+      (else
+        ;; Serialize the result to a JSON string, discarding the (empty) dependencies
+        (call $Stdlib_StringifyJson (local.get $result) (global.get $NULL))
+        (drop)
+        (if (result i32)
+          (call $Term::Signal::is (local.tee $output))
+          (then
+            (drop (call $Io::write_term (global.get $Io::STDERR) (local.get $output)))
+            (i32.const 1))
+          (else
+            (drop (call $Io::write_term (global.get $Io::STDOUT) (local.get $output)))
+            (i32.const 0))))))
+
   (func $Dependencies::new (export "createDependencyTree") (result i32)
     (global.get $NULL))
 
@@ -1,4 +1,7 @@
-use std::marker::PhantomData;
+use std::{
+    collections::{BTreeMap, HashMap},
+    marker::PhantomData,
+};
 
 // SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
 // SPDX-License-Identifier: Apache-2.0
@@ -13,6 +16,9 @@ use reflex_utils::{
 
 use crate::{
     allocator::{Arena, ArenaAllocator},
+    cli::inspect_snapshot::{
+        inspect_heap_snapshot, HeapSnapshotInspectionError, HeapSnapshotLayout,
+    },
     hash::TermSize,
     ArenaPointer, ArenaRef,
 };
@@ -165,6 +171,175 @@ where
     }
 }
 
+#[derive(Debug)]
+pub enum HeapSnapshotDiffError {
+    InvalidBaseline(HeapSnapshotInspectionError),
+    InvalidSnapshot(HeapSnapshotInspectionError),
+}
+
+impl std::error::Error for HeapSnapshotDiffError {}
+
+impl std::fmt::Display for HeapSnapshotDiffError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidBaseline(err) => write!(f, "Failed to decode baseline snapshot: {err}"),
+            Self::InvalidSnapshot(err) => write!(f, "Failed to decode snapshot: {err}"),
+        }
+    }
+}
+
+/// Summary of the terms that were added or removed between two heap snapshots
+///
+/// Terms are matched across snapshots by hash rather than by address, so terms that have been moved to a different
+/// offset (e.g. by heap compaction) are not reported as changes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize)]
+pub struct HeapSnapshotDiff {
+    /// Total number of bytes occupied by terms within the baseline snapshot
+    pub baseline_bytes: usize,
+    /// Total number of bytes occupied by terms within the updated snapshot
+    pub snapshot_bytes: usize,
+    /// Combined totals across all term types
+    pub total: HeapSnapshotTypeDiff,
+    /// Added and removed terms for each term type that differs between the two snapshots
+    pub term_types: BTreeMap<String, HeapSnapshotTypeDiff>,
+}
+
+/// Added and removed terms for a single term type
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+pub struct HeapSnapshotTypeDiff {
+    pub added_terms: usize,
+    pub added_bytes: usize,
+    pub removed_terms: usize,
+    pub removed_bytes: usize,
+}
+
+impl HeapSnapshotTypeDiff {
+    /// Net change in the number of allocated bytes
+    pub fn byte_delta(&self) -> isize {
+        self.added_bytes as isize - self.removed_bytes as isize
+    }
+    fn add(&mut self, num_terms: usize, size: usize) {
+        self.added_terms += num_terms;
+        self.added_bytes += num_terms * size;
+    }
+    fn remove(&mut self, num_terms: usize, size: usize) {
+        self.removed_terms += num_terms;
+        self.removed_bytes += num_terms * size;
+    }
+}
+
+impl HeapSnapshotDiff {
+    /// Net change in the total heap size
+    pub fn byte_delta(&self) -> isize {
+        self.snapshot_bytes as isize - self.baseline_bytes as isize
+    }
+    pub fn is_empty(&self) -> bool {
+        self.term_types.is_empty()
+    }
+}
+
+/// Compare the terms allocated within two serialized heap snapshots, reporting the added and removed terms grouped by
+/// term type
+pub fn diff_heap_snapshots(
+    baseline: &[u8],
+    snapshot: &[u8],
+) -> Result<HeapSnapshotDiff, HeapSnapshotDiffError> {
+    let baseline =
+        inspect_heap_snapshot(baseline).map_err(HeapSnapshotDiffError::InvalidBaseline)?;
+    let snapshot =
+        inspect_heap_snapshot(snapshot).map_err(HeapSnapshotDiffError::InvalidSnapshot)?;
+    Ok(diff_heap_snapshot_layouts(&baseline, &snapshot))
+}
+
+/// Compare the terms allocated within two decoded heap snapshots
+pub fn diff_heap_snapshot_layouts(
+    baseline: &HeapSnapshotLayout,
+    snapshot: &HeapSnapshotLayout,
+) -> HeapSnapshotDiff {
+    // Count the number of occurrences of each distinct term within each snapshot (the same term can be allocated
+    // multiple times within a heap snapshot, so the comparison treats each snapshot as a multiset of term hashes)
+    let mut term_counts = HashMap::<&str, (&str, usize, usize, usize)>::new();
+    for entry in baseline.entries.iter() {
+        let (_, _, baseline_count, _) = term_counts.entry(entry.hash.as_str()).or_insert((
+            entry.term_type.as_str(),
+            entry.size,
+            0,
+            0,
+        ));
+        *baseline_count += 1;
+    }
+    for entry in snapshot.entries.iter() {
+        let (_, _, _, snapshot_count) = term_counts.entry(entry.hash.as_str()).or_insert((
+            entry.term_type.as_str(),
+            entry.size,
+            0,
+            0,
+        ));
+        *snapshot_count += 1;
+    }
+    let mut total = HeapSnapshotTypeDiff::default();
+    let mut term_types = BTreeMap::<String, HeapSnapshotTypeDiff>::new();
+    for (term_type, size, baseline_count, snapshot_count) in term_counts.into_values() {
+        if baseline_count == snapshot_count {
+            continue;
+        }
+        let type_diff = term_types.entry(String::from(term_type)).or_default();
+        if snapshot_count > baseline_count {
+            type_diff.add(snapshot_count - baseline_count, size);
+            total.add(snapshot_count - baseline_count, size);
+        } else {
+            type_diff.remove(baseline_count - snapshot_count, size);
+            total.remove(baseline_count - snapshot_count, size);
+        }
+    }
+    HeapSnapshotDiff {
+        baseline_bytes: baseline.entries.iter().map(|entry| entry.size).sum(),
+        snapshot_bytes: snapshot.entries.iter().map(|entry| entry.size).sum(),
+        total,
+        term_types,
+    }
+}
+
+impl std::fmt::Display for HeapSnapshotDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:<24}  {:>8}  {:>10}  {:>8}  {:>10}  {:>10}",
+            "type", "added", "bytes", "removed", "bytes", "delta"
+        )?;
+        let mut term_types = self.term_types.iter().collect::<Vec<_>>();
+        term_types.sort_by_key(|(_, diff)| std::cmp::Reverse(diff.byte_delta().abs()));
+        for (term_type, diff) in term_types {
+            writeln!(
+                f,
+                "{:<24}  {:>8}  {:>10}  {:>8}  {:>10}  {:>+10}",
+                term_type,
+                diff.added_terms,
+                diff.added_bytes,
+                diff.removed_terms,
+                diff.removed_bytes,
+                diff.byte_delta()
+            )?;
+        }
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{} terms added ({} bytes), {} terms removed ({} bytes)",
+            self.total.added_terms,
+            self.total.added_bytes,
+            self.total.removed_terms,
+            self.total.removed_bytes
+        )?;
+        writeln!(
+            f,
+            "Heap size: {} bytes -> {} bytes ({:+} bytes)",
+            self.baseline_bytes,
+            self.snapshot_bytes,
+            self.byte_delta()
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::cell::RefCell;
@@ -172,7 +347,8 @@ mod tests {
 
     use crate::{
         allocator::{ArenaAllocator, ArenaIterator, VecAllocator},
-        term_type::{IntTerm, TermType, TreeTerm},
+        pad_to_4_byte_offset,
+        term_type::{IntTerm, ListTerm, TermType, TreeTerm},
         ArenaPointerIterator, ArenaRef, Term,
     };
 
@@ -247,4 +423,55 @@ mod tests {
             .read_value(|term| term.right);
         assert_eq!(target_left_pointer, target_right_pointer);
     }
+
+    #[test]
+    fn diff_snapshots() {
+        let mut baseline = VecAllocator::default();
+        let first = baseline.allocate(Term::new(TermType::Int(IntTerm::from(1)), &baseline));
+        let second = baseline.allocate(Term::new(TermType::Int(IntTerm::from(2)), &baseline));
+        let _list = ListTerm::allocate([first, second], &mut baseline);
+
+        let mut snapshot = VecAllocator::default();
+        // Terms that have moved to a different address are not reported as changes
+        let second = snapshot.allocate(Term::new(TermType::Int(IntTerm::from(2)), &snapshot));
+        let third = snapshot.allocate(Term::new(TermType::Int(IntTerm::from(3)), &snapshot));
+        let _duplicate = snapshot.allocate(Term::new(TermType::Int(IntTerm::from(3)), &snapshot));
+        let list = ListTerm::allocate([second, third], &mut snapshot);
+
+        let int_size = pad_to_4_byte_offset(
+            ArenaRef::<Term, _>::new(&baseline, first).read_value(|term| term.size_of()),
+        );
+        let list_size = pad_to_4_byte_offset(
+            ArenaRef::<Term, _>::new(&snapshot, list).read_value(|term| term.size_of()),
+        );
+
+        let diff = diff_heap_snapshots(baseline.as_bytes(), snapshot.as_bytes()).unwrap();
+        assert_eq!(
+            diff.term_types.get("Int"),
+            Some(&HeapSnapshotTypeDiff {
+                added_terms: 2,
+                added_bytes: 2 * int_size,
+                removed_terms: 1,
+                removed_bytes: int_size,
+            }),
+        );
+        assert_eq!(
+            diff.term_types.get("List"),
+            Some(&HeapSnapshotTypeDiff {
+                added_terms: 1,
+                added_bytes: list_size,
+                removed_terms: 1,
+                removed_bytes: list_size,
+            }),
+        );
+        assert_eq!(diff.term_types.len(), 2);
+        assert_eq!(diff.total.added_terms, 3);
+        assert_eq!(diff.total.removed_terms, 2);
+        assert_eq!(diff.byte_delta(), int_size as isize);
+        assert_eq!(diff.byte_delta(), diff.total.byte_delta());
+
+        let diff = diff_heap_snapshots(snapshot.as_bytes(), snapshot.as_bytes()).unwrap();
+        assert!(diff.is_empty());
+        assert_eq!(diff.byte_delta(), 0);
+    }
 }
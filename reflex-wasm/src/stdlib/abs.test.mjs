@@ -145,5 +145,54 @@ export default (describe) => {
         assert.deepEqual(getStateDependencies(dependencies), []);
       })();
     });
+
+    test('(BigInt)', (assert, {
+      createApplication,
+      createBigInt,
+      createBuiltin,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Abs),
+          createUnitList(createBigInt(123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(123456789012345678901234567890)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Abs),
+          createUnitList(createBigInt(-123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(123456789012345678901234567890)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Abs),
+          createUnitList(createBigInt(0n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(0)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Abs),
+          createUnitList(createBigInt(-18446744073709551616n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(18446744073709551616)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
   });
 };
@@ -8,8 +8,19 @@
     (@impl
       (i32.eq (global.get $TermType::Int))
       (func $Stdlib_Abs::impl::Int (param $self i32) (param $state i32) (result i32 i32)
-        (call $Term::Int::new (call $Utils::i64::abs (call $Term::Int::get::value (local.get $self))))
-        (global.get $NULL)))
+        (local $value i64)
+        (local $is_valid i32)
+        (call $Utils::i64::checked_abs (call $Term::Int::get::value (local.get $self)))
+        (local.set $is_valid)
+        (local.set $value)
+        (if (result i32 i32)
+          (local.get $is_valid)
+          (then
+            (call $Term::Int::new (local.get $value))
+            (global.get $NULL))
+          (else
+            (call $Term::Signal::of (call $Term::Condition::integer_overflow))
+            (global.get $NULL)))))
 
     (@impl
       (i32.eq (global.get $TermType::Float))
@@ -17,6 +28,12 @@
         (call $Term::Float::new (f64.abs (call $Term::Float::get::value (local.get $self))))
         (global.get $NULL)))
 
+    (@impl
+      (i32.eq (global.get $TermType::BigInt))
+      (func $Stdlib_Abs::impl::BigInt (param $self i32) (param $state i32) (result i32 i32)
+        (call $Term::BigInt::abs (local.get $self))
+        (global.get $NULL)))
+
     (@default
       (func $Stdlib_Abs::impl::default (param $self i32) (param $state i32) (result i32 i32)
         (call $Term::Signal::of
@@ -231,5 +231,199 @@ export default (describe) => {
         assert.deepEqual(getStateDependencies(dependencies), []);
       })();
     });
+
+    test('(BigInt, BigInt)', (assert, {
+      createApplication,
+      createBigInt,
+      createBuiltin,
+      createPair,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Add),
+          createPair(createBigInt(123456789012345678901234567890n), createBigInt(987654321098765432109876543210n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(1111111110111111111011111111100)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Add),
+          createPair(createBigInt(987654321098765432109876543210n), createBigInt(123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(1111111110111111111011111111100)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Add),
+          createPair(createBigInt(-123456789012345678901234567890n), createBigInt(987654321098765432109876543210n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(864197532086419753208641975320)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Add),
+          createPair(createBigInt(987654321098765432109876543210n), createBigInt(-123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(864197532086419753208641975320)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Add),
+          createPair(createBigInt(123456789012345678901234567890n), createBigInt(123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(246913578024691357802469135780)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Add),
+          createPair(createBigInt(-123456789012345678901234567890n), createBigInt(-123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(-246913578024691357802469135780)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Add),
+          createPair(createBigInt(0n), createBigInt(123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(123456789012345678901234567890)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Add),
+          createPair(createBigInt(4294967295n), createBigInt(1n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(4294967296)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Add),
+          createPair(createBigInt(18446744073709551616n), createBigInt(-4294967296n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(18446744069414584320)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('(BigInt, Int)', (assert, {
+      createApplication,
+      createBigInt,
+      createBuiltin,
+      createInt,
+      createPair,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Add),
+          createPair(createBigInt(123456789012345678901234567890n), createInt(3)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(123456789012345678901234567893)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Add),
+          createPair(createBigInt(-123456789012345678901234567890n), createInt(3)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(-123456789012345678901234567887)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Add),
+          createPair(createBigInt(123456789012345678901234567890n), createInt(-7)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(123456789012345678901234567883)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Add),
+          createPair(createBigInt(9223372036854775808n), createInt(-1)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(9223372036854775807)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('(Int, BigInt)', (assert, {
+      createApplication,
+      createBigInt,
+      createBuiltin,
+      createInt,
+      createPair,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Add),
+          createPair(createInt(3), createBigInt(123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(123456789012345678901234567893)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Add),
+          createPair(createInt(-3), createBigInt(123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(123456789012345678901234567887)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Add),
+          createPair(createInt(9007199254740991), createBigInt(1n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(9007199254740992)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Add),
+          createPair(createInt(-9007199254740991), createBigInt(-18446744073709551616n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(-18455751272964292607)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
   });
 };
@@ -9,40 +9,110 @@
       (i32.eq (global.get $TermType::Int))
       (i32.eq (global.get $TermType::Int))
       (func $Stdlib_Add::impl::Int::Int (param $self i32) (param $other i32) (param $state i32) (result i32 i32)
-        (call $Term::Int::new
-          (i64.add
-            (call $Term::Int::get::value (local.get $self))
-            (call $Term::Int::get::value (local.get $other))))
-        (global.get $NULL)))
+        (local $value i64)
+        (local $is_valid i32)
+        (call $Utils::i64::checked_add
+          (call $Term::Int::get::value (local.get $self))
+          (call $Term::Int::get::value (local.get $other)))
+        (local.set $is_valid)
+        (local.set $value)
+        (if (result i32 i32)
+          (local.get $is_valid)
+          (then
+            (call $Term::Int::new (local.get $value))
+            (global.get $NULL))
+          (else
+            (call $Term::Signal::of (call $Term::Condition::integer_overflow))
+            (global.get $NULL)))))
 
     (@impl
       (i32.eq (global.get $TermType::Float))
       (i32.eq (global.get $TermType::Float))
       (func $Stdlib_Add::impl::Float::Float (param $self i32) (param $other i32) (param $state i32) (result i32 i32)
-        (call $Term::Float::new
+        (local $value f64)
+        (local $is_valid i32)
+        (call $Utils::f64::checked_result
           (f64.add
             (call $Term::Float::get::value (local.get $self))
             (call $Term::Float::get::value (local.get $other))))
-        (global.get $NULL)))
+        (local.set $is_valid)
+        (local.set $value)
+        (if (result i32 i32)
+          (local.get $is_valid)
+          (then
+            (call $Term::Float::new (local.get $value))
+            (global.get $NULL))
+          (else
+            (call $Term::Signal::of (call $Term::Condition::invalid_float_result))
+            (global.get $NULL)))))
 
     (@impl
       (i32.eq (global.get $TermType::Int))
       (i32.eq (global.get $TermType::Float))
       (func $Stdlib_Add::impl::Int::Float (param $self i32) (param $other i32) (param $state i32) (result i32 i32)
-        (call $Term::Float::new
+        (local $value f64)
+        (local $is_valid i32)
+        (call $Utils::f64::checked_result
           (f64.add
             (f64.convert_i64_s (call $Term::Int::get::value (local.get $self)))
             (call $Term::Float::get::value (local.get $other))))
-        (global.get $NULL)))
+        (local.set $is_valid)
+        (local.set $value)
+        (if (result i32 i32)
+          (local.get $is_valid)
+          (then
+            (call $Term::Float::new (local.get $value))
+            (global.get $NULL))
+          (else
+            (call $Term::Signal::of (call $Term::Condition::invalid_float_result))
+            (global.get $NULL)))))
 
     (@impl
       (i32.eq (global.get $TermType::Float))
       (i32.eq (global.get $TermType::Int))
       (func $Stdlib_Add::impl::Float::Int (param $self i32) (param $other i32) (param $state i32) (result i32 i32)
-        (call $Term::Float::new
+        (local $value f64)
+        (local $is_valid i32)
+        (call $Utils::f64::checked_result
           (f64.add
             (call $Term::Float::get::value (local.get $self))
             (f64.convert_i64_s (call $Term::Int::get::value (local.get $other)))))
+        (local.set $is_valid)
+        (local.set $value)
+        (if (result i32 i32)
+          (local.get $is_valid)
+          (then
+            (call $Term::Float::new (local.get $value))
+            (global.get $NULL))
+          (else
+            (call $Term::Signal::of (call $Term::Condition::invalid_float_result))
+            (global.get $NULL)))))
+
+    (@impl
+      (i32.eq (global.get $TermType::BigInt))
+      (i32.eq (global.get $TermType::BigInt))
+      (func $Stdlib_Add::impl::BigInt::BigInt (param $self i32) (param $other i32) (param $state i32) (result i32 i32)
+        (call $Term::BigInt::add
+          (local.get $self)
+          (local.get $other))
+        (global.get $NULL)))
+
+    (@impl
+      (i32.eq (global.get $TermType::BigInt))
+      (i32.eq (global.get $TermType::Int))
+      (func $Stdlib_Add::impl::BigInt::Int (param $self i32) (param $other i32) (param $state i32) (result i32 i32)
+        (call $Term::BigInt::add
+          (local.get $self)
+          (call $Term::BigInt::from_i64 (call $Term::Int::get::value (local.get $other))))
+        (global.get $NULL)))
+
+    (@impl
+      (i32.eq (global.get $TermType::Int))
+      (i32.eq (global.get $TermType::BigInt))
+      (func $Stdlib_Add::impl::Int::BigInt (param $self i32) (param $other i32) (param $state i32) (result i32 i32)
+        (call $Term::BigInt::add
+          (call $Term::BigInt::from_i64 (call $Term::Int::get::value (local.get $self)))
+          (local.get $other))
         (global.get $NULL)))
 
     (@default
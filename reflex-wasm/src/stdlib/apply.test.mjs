@@ -87,5 +87,48 @@ export default (describe) => {
         assert.deepEqual(getStateDependencies(dependencies), []);
       })();
     });
+
+    test('(Collector, Iterator)', (assert, {
+      createApplication,
+      createBuiltin,
+      createFlattenIterator,
+      createInt,
+      createPair,
+      createRangeIterator,
+      createZipIterator,
+      evaluate,
+      format,
+      getHashmapValue,
+      getStateDependencies,
+      hasHashsetValue,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Apply),
+          createPair(
+            createBuiltin(Stdlib.CollectHashmap),
+            createFlattenIterator(createZipIterator(createRangeIterator(3, 2), createRangeIterator(6, 2))),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'Map(2)');
+        assert.strictEqual(format(getHashmapValue(result, createInt(3))), '6');
+        assert.strictEqual(format(getHashmapValue(result, createInt(4))), '7');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Apply),
+          createPair(createBuiltin(Stdlib.CollectHashset), createRangeIterator(3, 2)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'Set(2)');
+        assert.strictEqual(hasHashsetValue(result, createInt(3)), true);
+        assert.strictEqual(hasHashsetValue(result, createInt(4)), true);
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
   });
 };
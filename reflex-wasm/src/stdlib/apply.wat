@@ -18,7 +18,22 @@
       (func $Stdlib_Apply::impl::<apply>::<iterate> (param $self i32) (param $arg_list i32) (param $state i32) (result i32 i32)
         (local $list i32)
         (local $dependencies i32)
-        ;; Collect the argument list into a temporary list instance
+        ;; If the target function is a hashmap or hashset collector, consume the argument iterator directly into the
+        ;; resulting collection rather than collecting the arguments into a temporary list instance
+        (if
+          (call $Term::Builtin::is (local.get $self))
+          (then
+            (if
+              (i32.eq (call $Term::Builtin::get::uid (local.get $self)) (global.get $Stdlib_CollectHashmap))
+              (then
+                (return
+                  (call $Stdlib_HashmapFromIterator::impl::<iterate> (local.get $arg_list) (local.get $state)))))
+            (if
+              (i32.eq (call $Term::Builtin::get::uid (local.get $self)) (global.get $Stdlib_CollectHashset))
+              (then
+                (return
+                  (call $Stdlib_HashsetFromIterator::impl::<iterate> (local.get $arg_list) (local.get $state)))))))
+        ;; Otherwise collect the argument list into a temporary list instance
         ;; TODO: Convert argument lists to iterators for more efficient dynamic application
         (call $Term::List::traits::collect (local.get $arg_list) (local.get $state))
         (local.set $dependencies)
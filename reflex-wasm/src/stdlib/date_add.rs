@@ -0,0 +1,23 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{uuid, ArgType, Arity, FunctionArity, Uid, Uuid};
+
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct DateAdd;
+impl DateAdd {
+    pub const UUID: Uuid = uuid!("7688ec20-de77-47ef-8e34-40fc02e2e5ec");
+    const ARITY: FunctionArity<2, 0> = FunctionArity {
+        required: [ArgType::Strict, ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity(&self) -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for DateAdd {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
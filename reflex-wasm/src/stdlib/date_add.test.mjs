@@ -0,0 +1,62 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_DateAdd', (test) => {
+    test('(Timestamp, Duration)', (assert, {
+      createApplication,
+      createBuiltin,
+      createDuration,
+      createPair,
+      createTimestamp,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.DateAdd),
+          createPair(createTimestamp(Date.UTC(2000, 0, 1)), createDuration(90 * 60 * 1000)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'Timestamp(2000-01-01T01:30:00.000Z)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.DateAdd),
+          createPair(createTimestamp(Date.UTC(2000, 0, 1)), createDuration(-24 * 60 * 60 * 1000)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'Timestamp(1999-12-31T00:00:00.000Z)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('invalid arguments', (assert, {
+      createApplication,
+      createBuiltin,
+      createDuration,
+      createInt,
+      createPair,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.DateAdd),
+        createPair(createInt(0), createDuration(1000)),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(
+        format(result),
+        '{<InvalidFunctionArgsCondition:DateAdd(0, Duration(1000ms))>}',
+      );
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+  });
+};
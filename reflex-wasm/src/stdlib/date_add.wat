@@ -0,0 +1,35 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@builtin $Stdlib_DateAdd "DateAdd"
+    (@args (@strict $self) (@strict $other))
+
+    (@impl
+      (i32.eq (global.get $TermType::Timestamp))
+      (i32.eq (global.get $TermType::Duration))
+      (func $Stdlib_DateAdd::impl::Timestamp::Duration (param $self i32) (param $other i32) (param $state i32) (result i32 i32)
+        (local $value i64)
+        (local $is_valid i32)
+        (call $Utils::i64::checked_add
+          (call $Term::Timestamp::get::millis (local.get $self))
+          (call $Term::Duration::get::millis (local.get $other)))
+        (local.set $is_valid)
+        (local.set $value)
+        (if (result i32 i32)
+          (local.get $is_valid)
+          (then
+            (call $Term::Timestamp::new
+              (local.get $value)
+              (call $Term::Timestamp::get::offset (local.get $self)))
+            (global.get $NULL))
+          (else
+            (call $Stdlib_DateAdd::impl::default (local.get $self) (local.get $other) (local.get $state))))))
+
+    (@default
+      (func $Stdlib_DateAdd::impl::default (param $self i32) (param $other i32) (param $state i32) (result i32 i32)
+        (call $Term::Signal::of
+          (call $Term::Condition::invalid_builtin_function_args
+            (global.get $Stdlib_DateAdd)
+            (call $Term::List::create_pair (local.get $self) (local.get $other))))
+        (global.get $NULL)))))
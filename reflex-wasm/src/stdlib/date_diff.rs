@@ -0,0 +1,23 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{uuid, ArgType, Arity, FunctionArity, Uid, Uuid};
+
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct DateDiff;
+impl DateDiff {
+    pub const UUID: Uuid = uuid!("d5e384bf-dee5-44e0-8908-61a6921494af");
+    const ARITY: FunctionArity<2, 0> = FunctionArity {
+        required: [ArgType::Strict, ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity(&self) -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for DateDiff {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
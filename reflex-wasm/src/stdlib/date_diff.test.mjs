@@ -0,0 +1,67 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_DateDiff', (test) => {
+    test('(Timestamp, Timestamp)', (assert, {
+      createApplication,
+      createBuiltin,
+      createPair,
+      createTimestamp,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.DateDiff),
+          createPair(
+            createTimestamp(Date.UTC(2000, 0, 2)),
+            createTimestamp(Date.UTC(2000, 0, 1, 12)),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'Duration(43200000ms)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.DateDiff),
+          createPair(
+            createTimestamp(Date.UTC(2000, 0, 1, 12)),
+            createTimestamp(Date.UTC(2000, 0, 2)),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'Duration(-43200000ms)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('invalid arguments', (assert, {
+      createApplication,
+      createBuiltin,
+      createDuration,
+      createPair,
+      createTimestamp,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.DateDiff),
+        createPair(createTimestamp(0), createDuration(1000)),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(
+        format(result),
+        '{<InvalidFunctionArgsCondition:DateDiff(Timestamp(1970-01-01T00:00:00.000Z), Duration(1000ms))>}',
+      );
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+  });
+};
@@ -0,0 +1,23 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{uuid, ArgType, Arity, FunctionArity, Uid, Uuid};
+
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct DateOffset;
+impl DateOffset {
+    pub const UUID: Uuid = uuid!("e13501d7-d525-43cf-80e6-1e68cb21491d");
+    const ARITY: FunctionArity<1, 0> = FunctionArity {
+        required: [ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity(&self) -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for DateOffset {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
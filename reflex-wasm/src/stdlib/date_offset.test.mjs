@@ -0,0 +1,53 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_DateOffset', (test) => {
+    test('(Timestamp)', (assert, {
+      createApplication,
+      createBuiltin,
+      createTimestamp,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      for (const [offset, expected] of [
+        [0, '"Z"'],
+        [120, '"+02:00"'],
+        [-330, '"-05:30"'],
+        [23 * 60 + 59, '"+23:59"'],
+      ]) {
+        const expression = createApplication(
+          createBuiltin(Stdlib.DateOffset),
+          createUnitList(createTimestamp(Date.UTC(2000, 0, 1), offset)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), expected);
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      }
+    });
+
+    test('invalid arguments', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.DateOffset),
+        createUnitList(createInt(3)),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(format(result), '{<InvalidFunctionArgsCondition:DateOffset(3)>}');
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+  });
+};
@@ -0,0 +1,61 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@const-string $Stdlib_DateOffset::UTC_OFFSET_Z "Z")
+
+  (@builtin $Stdlib_DateOffset "DateOffset"
+    (@args (@strict $self))
+
+    (@impl
+      (i32.eq (global.get $TermType::Timestamp))
+      (func $Stdlib_DateOffset::impl::Timestamp (param $self i32) (param $state i32) (result i32 i32)
+        (call $Stdlib_DateOffset::format_utc_offset (call $Term::Timestamp::get::offset (local.get $self)))
+        (global.get $NULL)))
+
+    (@default
+      (func $Stdlib_DateOffset::impl::default (param $self i32) (param $state i32) (result i32 i32)
+        (call $Term::Signal::of
+          (call $Term::Condition::invalid_builtin_function_args
+            (global.get $Stdlib_DateOffset)
+            (call $Term::List::of (local.get $self))))
+        (global.get $NULL))))
+
+  (func $Stdlib_DateOffset::format_utc_offset (param $offset i32) (result i32)
+    ;; Format a UTC offset (expressed in minutes east of UTC) as either "Z" for zero offsets or "±HH:MM"
+    (local $instance i32)
+    (local $minutes i32)
+    (local $char_offset i32)
+    (if
+      (i32.eqz (local.get $offset))
+      (then
+        (return (global.get $Stdlib_DateOffset::UTC_OFFSET_Z))))
+    (local.set $minutes
+      (select
+        (i32.sub (i32.const 0) (local.get $offset))
+        (local.get $offset)
+        (i32.lt_s (local.get $offset) (i32.const 0))))
+    ;; Allocate a new String term with the correct capacity
+    (local.set $instance (call $Term::String::allocate (i32.const 6)))
+    (local.set $char_offset (call $Term::String::get_char_pointer (local.get $instance) (i32.const 0)))
+    ;; Write the sign character
+    (i32.store8
+      (local.get $char_offset)
+      (select
+        (@char "-")
+        (@char "+")
+        (i32.lt_s (local.get $offset) (i32.const 0))))
+    ;; Write the hours and minutes components
+    (call $Stdlib_DateOffset::write_digit_pair
+      (i32.div_u (local.get $minutes) (i32.const 60))
+      (i32.add (local.get $char_offset) (i32.const 1)))
+    (i32.store8 offset=3 (local.get $char_offset) (@char ":"))
+    (call $Stdlib_DateOffset::write_digit_pair
+      (i32.rem_u (local.get $minutes) (i32.const 60))
+      (i32.add (local.get $char_offset) (i32.const 4)))
+    ;; Instantiate the term
+    (call $Term::String::init (local.get $instance)))
+
+  (func $Stdlib_DateOffset::write_digit_pair (param $value i32) (param $offset i32)
+    (i32.store8 (local.get $offset) (i32.add (@char "0") (i32.div_u (local.get $value) (i32.const 10))))
+    (i32.store8 offset=1 (local.get $offset) (i32.add (@char "0") (i32.rem_u (local.get $value) (i32.const 10))))))
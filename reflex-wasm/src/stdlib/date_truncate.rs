@@ -0,0 +1,23 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{uuid, ArgType, Arity, FunctionArity, Uid, Uuid};
+
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct DateTruncate;
+impl DateTruncate {
+    pub const UUID: Uuid = uuid!("7df51501-4deb-4312-adc9-d6f0f34e1340");
+    const ARITY: FunctionArity<3, 0> = FunctionArity {
+        required: [ArgType::Strict, ArgType::Strict, ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity(&self) -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for DateTruncate {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
@@ -0,0 +1,82 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_DateTruncate', (test) => {
+    test('(Timestamp, String, String)', (assert, {
+      createApplication,
+      createBuiltin,
+      createString,
+      createTimestamp,
+      createTriple,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const timestamp = Date.UTC(2023, 2, 4, 5, 6, 7, 89);
+      for (const [unit, utcOffset, expected] of [
+        ['day', 'Z', '2023-03-04T00:00:00.000Z'],
+        ['day', 'UTC', '2023-03-04T00:00:00.000Z'],
+        ['hour', 'Z', '2023-03-04T05:00:00.000Z'],
+        ['day', '+00:00', '2023-03-04T00:00:00.000Z'],
+        ['day', '-08:00', '2023-03-03T08:00:00.000Z'],
+        ['day', '+10:00', '2023-03-03T14:00:00.000Z'],
+        ['hour', '+05:30', '2023-03-04T04:30:00.000Z'],
+      ]) {
+        const expression = createApplication(
+          createBuiltin(Stdlib.DateTruncate),
+          createTriple(createTimestamp(timestamp), createString(unit), createString(utcOffset)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), `Timestamp(${expected})`);
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      }
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.DateTruncate),
+          createTriple(createTimestamp(-1), createString('day'), createString('Z')),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'Timestamp(1969-12-31T00:00:00.000Z)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('invalid arguments', (assert, {
+      createApplication,
+      createBuiltin,
+      createString,
+      createTimestamp,
+      createTriple,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      for (const [unit, utcOffset] of [
+        ['week', 'Z'],
+        ['day', ''],
+        ['day', '+5:30'],
+        ['day', '05:30'],
+        ['day', '+24:00'],
+        ['day', '+05:60'],
+        ['day', '+05-30'],
+        ['day', 'Europe/London'],
+      ]) {
+        const expression = createApplication(
+          createBuiltin(Stdlib.DateTruncate),
+          createTriple(createTimestamp(0), createString(unit), createString(utcOffset)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(
+          format(result),
+          `{<InvalidFunctionArgsCondition:DateTruncate(Timestamp(1970-01-01T00:00:00.000Z), "${unit}", "${utcOffset}")>}`,
+        );
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      }
+    });
+  });
+};
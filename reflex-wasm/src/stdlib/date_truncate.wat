@@ -0,0 +1,168 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@const-string $Stdlib_DateTruncate::UNIT_DAY "day")
+  (@const-string $Stdlib_DateTruncate::UNIT_HOUR "hour")
+  (@const-string $Stdlib_DateTruncate::UTC_OFFSET_Z "Z")
+  (@const-string $Stdlib_DateTruncate::UTC_OFFSET_UTC "UTC")
+
+  (@builtin $Stdlib_DateTruncate "DateTruncate"
+    (@args (@strict $self) (@strict $unit) (@strict $utc_offset))
+
+    (@impl
+      (i32.eq (global.get $TermType::Timestamp))
+      (i32.eq (global.get $TermType::String))
+      (i32.eq (global.get $TermType::String))
+      (func $Stdlib_DateTruncate::impl::Timestamp::String::String (param $self i32) (param $unit i32) (param $utc_offset i32) (param $state i32) (result i32 i32)
+        (local $unit_millis i64)
+        (local $offset_millis i64)
+        (local $value i64)
+        (local $is_valid i32)
+        ;; Determine the length of the truncation unit (a zero value indicates an invalid unit)
+        (local.set $unit_millis (call $Stdlib_DateTruncate::parse_unit (local.get $unit)))
+        ;; Determine the fixed UTC offset (named timezones are not supported)
+        (call $Stdlib_DateTruncate::parse_utc_offset (local.get $utc_offset))
+        (local.set $is_valid)
+        (local.set $offset_millis)
+        (if
+          (i32.or
+            (i64.eqz (local.get $unit_millis))
+            (i32.eqz (local.get $is_valid)))
+          (then
+            (return
+              (call $Stdlib_DateTruncate::impl::default
+                (local.get $self)
+                (local.get $unit)
+                (local.get $utc_offset)
+                (local.get $state)))))
+        ;; Convert the timestamp to local time
+        (call $Utils::i64::checked_add
+          (call $Term::Timestamp::get::millis (local.get $self))
+          (local.get $offset_millis))
+        (local.set $is_valid)
+        (local.set $value)
+        (if
+          (local.get $is_valid)
+          (then
+            ;; Truncate the local time to the start of the containing unit
+            (call $Utils::i64::checked_sub
+              (local.get $value)
+              (call $Stdlib_DateTruncate::rem_euclid (local.get $value) (local.get $unit_millis)))
+            (local.set $is_valid)
+            (local.set $value)))
+        (if
+          (local.get $is_valid)
+          (then
+            ;; Convert the truncated local time back to UTC
+            (call $Utils::i64::checked_sub (local.get $value) (local.get $offset_millis))
+            (local.set $is_valid)
+            (local.set $value)))
+        (if (result i32 i32)
+          (local.get $is_valid)
+          (then
+            (call $Term::Timestamp::new
+              (local.get $value)
+              (call $Term::Timestamp::get::offset (local.get $self)))
+            (global.get $NULL))
+          (else
+            (call $Stdlib_DateTruncate::impl::default
+              (local.get $self)
+              (local.get $unit)
+              (local.get $utc_offset)
+              (local.get $state))))))
+
+    (@default
+      (func $Stdlib_DateTruncate::impl::default (param $self i32) (param $unit i32) (param $utc_offset i32) (param $state i32) (result i32 i32)
+        (call $Term::Signal::of
+          (call $Term::Condition::invalid_builtin_function_args
+            (global.get $Stdlib_DateTruncate)
+            (call $Term::List::create_triple (local.get $self) (local.get $unit) (local.get $utc_offset))))
+        (global.get $NULL))))
+
+  (func $Stdlib_DateTruncate::parse_unit (param $unit i32) (result i64)
+    (if (result i64)
+      (call $Term::traits::equals (local.get $unit) (global.get $Stdlib_DateTruncate::UNIT_DAY))
+      (then
+        (i64.const 86400000))
+      (else
+        (if (result i64)
+          (call $Term::traits::equals (local.get $unit) (global.get $Stdlib_DateTruncate::UNIT_HOUR))
+          (then
+            (i64.const 3600000))
+          (else
+            (i64.const 0))))))
+
+  (func $Stdlib_DateTruncate::parse_utc_offset (param $utc_offset i32) (result i64 i32)
+    ;; Parse a fixed UTC offset of the form "Z", "UTC" or "±HH:MM", returning the offset in milliseconds followed by a
+    ;; boolean indicating whether the offset was valid
+    (local $offset i32)
+    (local $sign i64)
+    (local $hours i32)
+    (local $minutes i32)
+    (if
+      (i32.or
+        (call $Term::traits::equals (local.get $utc_offset) (global.get $Stdlib_DateTruncate::UTC_OFFSET_Z))
+        (call $Term::traits::equals (local.get $utc_offset) (global.get $Stdlib_DateTruncate::UTC_OFFSET_UTC)))
+      (then
+        (return (i64.const 0) (global.get $TRUE))))
+    (if
+      (i32.ne (call $Term::String::get_length (local.get $utc_offset)) (i32.const 6))
+      (then
+        (return (i64.const 0) (global.get $FALSE))))
+    (local.set $offset (call $Term::String::get_offset (local.get $utc_offset)))
+    ;; Parse the sign character
+    (local.set $sign
+      (if (result i64)
+        (i32.eq (i32.load8_u (local.get $offset)) (@char "+"))
+        (then
+          (i64.const 1))
+        (else
+          (if (result i64)
+            (i32.eq (i32.load8_u (local.get $offset)) (@char "-"))
+            (then
+              (i64.const -1))
+            (else
+              (i64.const 0))))))
+    ;; Parse the hours and minutes components (invalid digits are represented as -1, which is out of range when
+    ;; compared as an unsigned integer)
+    (local.set $hours (call $Stdlib_DateTruncate::parse_digit_pair (i32.add (local.get $offset) (i32.const 1))))
+    (local.set $minutes (call $Stdlib_DateTruncate::parse_digit_pair (i32.add (local.get $offset) (i32.const 4))))
+    (if
+      (i32.or
+        (i64.eqz (local.get $sign))
+        (i32.or
+          (i32.ne (i32.load8_u offset=3 (local.get $offset)) (@char ":"))
+          (i32.or
+            (i32.ge_u (local.get $hours) (i32.const 24))
+            (i32.ge_u (local.get $minutes) (i32.const 60)))))
+      (then
+        (return (i64.const 0) (global.get $FALSE))))
+    (i64.mul
+      (local.get $sign)
+      (i64.add
+        (i64.mul (i64.extend_i32_u (local.get $hours)) (i64.const 3600000))
+        (i64.mul (i64.extend_i32_u (local.get $minutes)) (i64.const 60000))))
+    (global.get $TRUE))
+
+  (func $Stdlib_DateTruncate::parse_digit_pair (param $offset i32) (result i32)
+    (local $tens i32)
+    (local $units i32)
+    (local.set $tens (i32.sub (i32.load8_u (local.get $offset)) (@char "0")))
+    (local.set $units (i32.sub (i32.load8_u offset=1 (local.get $offset)) (@char "0")))
+    (if (result i32)
+      (i32.or
+        (i32.gt_u (local.get $tens) (i32.const 9))
+        (i32.gt_u (local.get $units) (i32.const 9)))
+      (then
+        (i32.const -1))
+      (else
+        (i32.add (i32.mul (local.get $tens) (i32.const 10)) (local.get $units)))))
+
+  (func $Stdlib_DateTruncate::rem_euclid (param $self i64) (param $divisor i64) (result i64)
+    (local $remainder i64)
+    (local.set $remainder (i64.rem_s (local.get $self) (local.get $divisor)))
+    (select
+      (i64.add (local.get $remainder) (local.get $divisor))
+      (local.get $remainder)
+      (i64.lt_s (local.get $remainder) (i64.const 0)))))
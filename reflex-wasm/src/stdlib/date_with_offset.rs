@@ -0,0 +1,23 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{uuid, ArgType, Arity, FunctionArity, Uid, Uuid};
+
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct DateWithOffset;
+impl DateWithOffset {
+    pub const UUID: Uuid = uuid!("a53333e1-6077-4698-835f-b4ba32c1ed98");
+    const ARITY: FunctionArity<2, 0> = FunctionArity {
+        required: [ArgType::Strict, ArgType::Strict],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity(&self) -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for DateWithOffset {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
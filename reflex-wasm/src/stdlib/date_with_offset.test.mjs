@@ -0,0 +1,60 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_DateWithOffset', (test) => {
+    test('(Timestamp, String)', (assert, {
+      createApplication,
+      createBuiltin,
+      createPair,
+      createString,
+      createTimestamp,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      for (const [offset, expected] of [
+        ['Z', 'Timestamp(2000-01-01T12:00:00.000Z)'],
+        ['UTC', 'Timestamp(2000-01-01T12:00:00.000Z)'],
+        ['+02:00', 'Timestamp(2000-01-01T14:00:00.000+02:00)'],
+        ['-05:30', 'Timestamp(2000-01-01T06:30:00.000-05:30)'],
+      ]) {
+        const expression = createApplication(
+          createBuiltin(Stdlib.DateWithOffset),
+          createPair(createTimestamp(Date.UTC(2000, 0, 1, 12), 60), createString(offset)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), expected);
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      }
+    });
+
+    test('invalid offsets', (assert, {
+      createApplication,
+      createBuiltin,
+      createPair,
+      createString,
+      createTimestamp,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      for (const offset of ['Europe/London', '+24:00', '+0200']) {
+        const expression = createApplication(
+          createBuiltin(Stdlib.DateWithOffset),
+          createPair(createTimestamp(0), createString(offset)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(
+          format(result),
+          `{<InvalidFunctionArgsCondition:DateWithOffset(Timestamp(1970-01-01T00:00:00.000Z), "${offset}")>}`,
+        );
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      }
+    });
+  });
+};
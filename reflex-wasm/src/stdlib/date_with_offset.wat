@@ -0,0 +1,35 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@builtin $Stdlib_DateWithOffset "DateWithOffset"
+    (@args (@strict $self) (@strict $utc_offset))
+
+    (@impl
+      (i32.eq (global.get $TermType::Timestamp))
+      (i32.eq (global.get $TermType::String))
+      (func $Stdlib_DateWithOffset::impl::Timestamp::String (param $self i32) (param $utc_offset i32) (param $state i32) (result i32 i32)
+        (local $offset_millis i64)
+        (local $is_valid i32)
+        ;; Determine the fixed UTC offset (named timezones are not supported)
+        (call $Stdlib_DateTruncate::parse_utc_offset (local.get $utc_offset))
+        (local.set $is_valid)
+        (local.set $offset_millis)
+        (if (result i32 i32)
+          (local.get $is_valid)
+          (then
+            ;; The instant is left untouched: only the offset at which it is observed changes
+            (call $Term::Timestamp::new
+              (call $Term::Timestamp::get::millis (local.get $self))
+              (i32.wrap_i64 (i64.div_s (local.get $offset_millis) (i64.const 60000))))
+            (global.get $NULL))
+          (else
+            (call $Stdlib_DateWithOffset::impl::default (local.get $self) (local.get $utc_offset) (local.get $state))))))
+
+    (@default
+      (func $Stdlib_DateWithOffset::impl::default (param $self i32) (param $utc_offset i32) (param $state i32) (result i32 i32)
+        (call $Term::Signal::of
+          (call $Term::Condition::invalid_builtin_function_args
+            (global.get $Stdlib_DateWithOffset)
+            (call $Term::List::create_pair (local.get $self) (local.get $utc_offset))))
+        (global.get $NULL)))))
@@ -0,0 +1,96 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_Dedupe', (test) => {
+    test('(Iterator)', (assert, {
+      createApplication,
+      createBuiltin,
+      createEmptyIterator,
+      createInt,
+      createList,
+      createRangeIterator,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.ResolveList),
+          createUnitList(
+            createApplication(
+              createBuiltin(Stdlib.Dedupe),
+              createUnitList(createEmptyIterator()),
+            ),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '[]');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.ResolveList),
+          createUnitList(
+            createApplication(
+              createBuiltin(Stdlib.Dedupe),
+              createUnitList(createRangeIterator(3, 3)),
+            ),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '[3, 4, 5]');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.ResolveList),
+          createUnitList(
+            createApplication(
+              createBuiltin(Stdlib.Dedupe),
+              createUnitList(
+                createList([
+                  createInt(3),
+                  createInt(3),
+                  createInt(4),
+                  createInt(4),
+                  createInt(4),
+                  createInt(5),
+                  createInt(3),
+                ]),
+              ),
+            ),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '[3, 4, 5, 3]');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('invalid arguments', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Dedupe),
+          createUnitList(createInt(3)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '{<InvalidFunctionArgsCondition:Dedupe(3)>}');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+  });
+};
@@ -0,0 +1,183 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_Delete', (test) => {
+    test('(Int, Int)', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createPair,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.Delete),
+        createPair(createInt(3), createInt(4)),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(format(result), '{<InvalidFunctionArgsCondition:Delete(3, 4)>}');
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+
+    test('(Hashmap, String)', (assert, {
+      createApplication,
+      createBuiltin,
+      createHashmap,
+      createInt,
+      createPair,
+      createString,
+      evaluate,
+      format,
+      getHashmapValue,
+      getStateDependencies,
+      hasHashmapKey,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const hashmap = createHashmap([
+          [createString('foo'), createInt(3)],
+          [createString('bar'), createInt(4)],
+          [createString('baz'), createInt(5)],
+        ]);
+        const expression = createApplication(
+          createBuiltin(Stdlib.Delete),
+          createPair(hashmap, createString('bar')),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'Map(2)');
+        assert.strictEqual(format(getHashmapValue(result, createString('foo'))), '3');
+        assert.strictEqual(hasHashmapKey(result, createString('bar')), false);
+        assert.strictEqual(format(getHashmapValue(result, createString('baz'))), '5');
+        assert.strictEqual(format(getHashmapValue(hashmap, createString('bar'))), '4');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const hashmap = createHashmap([
+          [createString('foo'), createInt(3)],
+          [createString('bar'), createInt(4)],
+          [createString('baz'), createInt(5)],
+        ]);
+        const expression = createApplication(
+          createBuiltin(Stdlib.Delete),
+          createPair(hashmap, createString('qux')),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(result, hashmap);
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const hashmap = createHashmap([[createString('foo'), createInt(3)]]);
+        const expression = createApplication(
+          createBuiltin(Stdlib.Delete),
+          createPair(hashmap, createString('foo')),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'Map(0)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('(Hashmap, Int)', (assert, {
+      createApplication,
+      createBuiltin,
+      createHashmap,
+      createInt,
+      createPair,
+      createTriple,
+      evaluate,
+      format,
+      getHashmapValue,
+      getStateDependencies,
+      hasHashmapKey,
+      hash,
+      NULL,
+      Stdlib,
+    }) => {
+      const keys = Array.from({ length: 32 }, (_, index) => index);
+      const original = createHashmap(keys.map((key) => [createInt(key), createInt(key * 2)]));
+      // Delete each key in turn, ensuring all the remaining entries are still reachable
+      const result = keys.reduce((hashmap, key) => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Delete),
+          createPair(hashmap, createInt(key)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), `Map(${keys.length - key - 1})`);
+        assert.strictEqual(hasHashmapKey(result, createInt(key)), false);
+        for (const remaining of keys.slice(key + 1)) {
+          assert.strictEqual(
+            format(getHashmapValue(result, createInt(remaining))),
+            `${remaining * 2}`,
+          );
+        }
+        assert.deepEqual(getStateDependencies(dependencies), []);
+        return result;
+      }, original);
+      assert.strictEqual(format(result), 'Map(0)');
+      // Deleting and re-inserting an entry should produce a hashmap with the same hash as the original
+      const [deleted] = evaluate(
+        createApplication(createBuiltin(Stdlib.Delete), createPair(original, createInt(3))),
+        NULL,
+      );
+      const [restored] = evaluate(
+        createApplication(createBuiltin(Stdlib.Set), createTriple(deleted, createInt(3), createInt(6))),
+        NULL,
+      );
+      assert.strictEqual(format(restored), `Map(${keys.length})`);
+      assert.strictEqual(hash(restored), hash(original));
+    });
+
+    test('(Hashset, String)', (assert, {
+      createApplication,
+      createBuiltin,
+      createHashset,
+      createPair,
+      createString,
+      evaluate,
+      format,
+      getStateDependencies,
+      hasHashsetValue,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const hashset = createHashset([createString('foo'), createString('bar'), createString('baz')]);
+        const expression = createApplication(
+          createBuiltin(Stdlib.Delete),
+          createPair(hashset, createString('bar')),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'Set(2)');
+        assert.strictEqual(hasHashsetValue(result, createString('foo')), true);
+        assert.strictEqual(hasHashsetValue(result, createString('bar')), false);
+        assert.strictEqual(hasHashsetValue(result, createString('baz')), true);
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const hashset = createHashset([createString('foo'), createString('bar'), createString('baz')]);
+        const expression = createApplication(
+          createBuiltin(Stdlib.Delete),
+          createPair(hashset, createString('qux')),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(result, hashset);
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const hashset = createHashset([createString('foo')]);
+        const expression = createApplication(
+          createBuiltin(Stdlib.Delete),
+          createPair(hashset, createString('foo')),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'Set(0)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+  });
+};
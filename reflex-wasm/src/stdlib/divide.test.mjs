@@ -285,5 +285,226 @@ export default (describe) => {
         assert.deepEqual(getStateDependencies(dependencies), []);
       })();
     });
+
+    test('(BigInt, BigInt)', (assert, {
+      createApplication,
+      createBigInt,
+      createBuiltin,
+      createPair,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Divide),
+          createPair(createBigInt(123456789012345678901234567890n), createBigInt(987654321098765432109876543210n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(0)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Divide),
+          createPair(createBigInt(987654321098765432109876543210n), createBigInt(123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(8)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Divide),
+          createPair(createBigInt(-123456789012345678901234567890n), createBigInt(987654321098765432109876543210n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(0)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Divide),
+          createPair(createBigInt(987654321098765432109876543210n), createBigInt(-123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(-8)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Divide),
+          createPair(createBigInt(123456789012345678901234567890n), createBigInt(123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(1)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Divide),
+          createPair(createBigInt(-123456789012345678901234567890n), createBigInt(-123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(1)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Divide),
+          createPair(createBigInt(0n), createBigInt(123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(0)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Divide),
+          createPair(createBigInt(4294967295n), createBigInt(1n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(4294967295)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Divide),
+          createPair(createBigInt(18446744073709551616n), createBigInt(-4294967296n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(-4294967296)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Divide),
+          createPair(createBigInt(123456789012345678901234567890n), createBigInt(0n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '{<InvalidFunctionArgsCondition:Divide(BigInt(123456789012345678901234567890), BigInt(0))>}');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('(BigInt, Int)', (assert, {
+      createApplication,
+      createBigInt,
+      createBuiltin,
+      createInt,
+      createPair,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Divide),
+          createPair(createBigInt(123456789012345678901234567890n), createInt(3)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(41152263004115226300411522630)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Divide),
+          createPair(createBigInt(-123456789012345678901234567890n), createInt(3)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(-41152263004115226300411522630)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Divide),
+          createPair(createBigInt(123456789012345678901234567890n), createInt(-7)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(-17636684144620811271604938270)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Divide),
+          createPair(createBigInt(9223372036854775808n), createInt(-1)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(-9223372036854775808)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Divide),
+          createPair(createBigInt(123456789012345678901234567890n), createInt(0)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '{<InvalidFunctionArgsCondition:Divide(BigInt(123456789012345678901234567890), 0)>}');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('(Int, BigInt)', (assert, {
+      createApplication,
+      createBigInt,
+      createBuiltin,
+      createInt,
+      createPair,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Divide),
+          createPair(createInt(3), createBigInt(123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(0)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Divide),
+          createPair(createInt(-3), createBigInt(123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(0)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Divide),
+          createPair(createInt(9007199254740991), createBigInt(1n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(9007199254740991)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Divide),
+          createPair(createInt(-9007199254740991), createBigInt(-18446744073709551616n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'BigInt(0)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Divide),
+          createPair(createInt(3), createBigInt(0n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '{<InvalidFunctionArgsCondition:Divide(3, BigInt(0))>}');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
   });
 };
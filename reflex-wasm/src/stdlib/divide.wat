@@ -10,6 +10,8 @@
       (i32.eq (global.get $TermType::Int))
       (func $Stdlib_Divide::impl::Int::Int (param $self i32) (param $divisor i32) (param $state i32) (result i32 i32)
         (local $divisor_value i64)
+        (local $value i64)
+        (local $is_valid i32)
         (if (result i32 i32)
           (i64.eqz (local.tee $divisor_value (call $Term::Int::get::value (local.get $divisor))))
           (then
@@ -19,14 +21,25 @@
                 (call $Term::List::create_pair (local.get $self) (local.get $divisor))))
             (global.get $NULL))
           (else
-            (call $Term::Int::new (i64.div_s (call $Term::Int::get::value (local.get $self)) (local.get $divisor_value)))
-            (global.get $NULL)))))
+            (call $Utils::i64::checked_div_s (call $Term::Int::get::value (local.get $self)) (local.get $divisor_value))
+            (local.set $is_valid)
+            (local.set $value)
+            (if (result i32 i32)
+              (local.get $is_valid)
+              (then
+                (call $Term::Int::new (local.get $value))
+                (global.get $NULL))
+              (else
+                (call $Term::Signal::of (call $Term::Condition::integer_overflow))
+                (global.get $NULL)))))))
 
     (@impl
       (i32.eq (global.get $TermType::Float))
       (i32.eq (global.get $TermType::Float))
       (func $Stdlib_Divide::impl::Float::Float (param $self i32) (param $divisor i32) (param $state i32) (result i32 i32)
         (local $divisor_value f64)
+        (local $value f64)
+        (local $is_valid i32)
         (if (result i32 i32)
           (f64.eq (f64.const 0) (local.tee $divisor_value (call $Term::Float::get::value (local.get $divisor))))
           (then
@@ -36,14 +49,25 @@
                 (call $Term::List::create_pair (local.get $self) (local.get $divisor))))
             (global.get $NULL))
           (else
-            (call $Term::Float::new (f64.div (call $Term::Float::get::value (local.get $self)) (local.get $divisor_value)))
-            (global.get $NULL)))))
+            (call $Utils::f64::checked_result (f64.div (call $Term::Float::get::value (local.get $self)) (local.get $divisor_value)))
+            (local.set $is_valid)
+            (local.set $value)
+            (if (result i32 i32)
+              (local.get $is_valid)
+              (then
+                (call $Term::Float::new (local.get $value))
+                (global.get $NULL))
+              (else
+                (call $Term::Signal::of (call $Term::Condition::invalid_float_result))
+                (global.get $NULL)))))))
 
     (@impl
       (i32.eq (global.get $TermType::Int))
       (i32.eq (global.get $TermType::Float))
       (func $Stdlib_Divide::impl::Int::Float (param $self i32) (param $divisor i32) (param $state i32) (result i32 i32)
         (local $divisor_value f64)
+        (local $value f64)
+        (local $is_valid i32)
         (if (result i32 i32)
           (f64.eq (f64.const 0) (local.tee $divisor_value (call $Term::Float::get::value (local.get $divisor))))
           (then
@@ -53,14 +77,25 @@
                 (call $Term::List::create_pair (local.get $self) (local.get $divisor))))
             (global.get $NULL))
           (else
-            (call $Term::Float::new (f64.div (f64.convert_i64_s (call $Term::Int::get::value (local.get $self))) (local.get $divisor_value)))
-            (global.get $NULL)))))
+            (call $Utils::f64::checked_result (f64.div (f64.convert_i64_s (call $Term::Int::get::value (local.get $self))) (local.get $divisor_value)))
+            (local.set $is_valid)
+            (local.set $value)
+            (if (result i32 i32)
+              (local.get $is_valid)
+              (then
+                (call $Term::Float::new (local.get $value))
+                (global.get $NULL))
+              (else
+                (call $Term::Signal::of (call $Term::Condition::invalid_float_result))
+                (global.get $NULL)))))))
 
     (@impl
       (i32.eq (global.get $TermType::Float))
       (i32.eq (global.get $TermType::Int))
       (func $Stdlib_Divide::impl::Float::Int (param $self i32) (param $divisor i32) (param $state i32) (result i32 i32)
         (local $divisor_value i64)
+        (local $value f64)
+        (local $is_valid i32)
         (if (result i32 i32)
           (i64.eqz (local.tee $divisor_value (call $Term::Int::get::value (local.get $divisor))))
           (then
@@ -70,7 +105,76 @@
                 (call $Term::List::create_pair (local.get $self) (local.get $divisor))))
             (global.get $NULL))
           (else
-            (call $Term::Float::new (f64.div (call $Term::Float::get::value (local.get $self)) (f64.convert_i64_s (local.get $divisor_value))))
+            (call $Utils::f64::checked_result (f64.div (call $Term::Float::get::value (local.get $self)) (f64.convert_i64_s (local.get $divisor_value))))
+            (local.set $is_valid)
+            (local.set $value)
+            (if (result i32 i32)
+              (local.get $is_valid)
+              (then
+                (call $Term::Float::new (local.get $value))
+                (global.get $NULL))
+              (else
+                (call $Term::Signal::of (call $Term::Condition::invalid_float_result))
+                (global.get $NULL)))))))
+
+    (@impl
+      (i32.eq (global.get $TermType::BigInt))
+      (i32.eq (global.get $TermType::BigInt))
+      (func $Stdlib_Divide::impl::BigInt::BigInt (param $self i32) (param $divisor i32) (param $state i32) (result i32 i32)
+        (if (result i32 i32)
+          (i32.eqz (call $Term::BigInt::get::limbs::length (local.get $divisor)))
+          (then
+            (call $Term::Signal::of
+              (call $Term::Condition::invalid_builtin_function_args
+                (global.get $Stdlib_Divide)
+                (call $Term::List::create_pair (local.get $self) (local.get $divisor))))
+            (global.get $NULL))
+          (else
+            ;; Discard the remainder and return the quotient
+            (call $Term::BigInt::divide
+              (local.get $self)
+              (local.get $divisor))
+            (drop)
+            (global.get $NULL)))))
+
+    (@impl
+      (i32.eq (global.get $TermType::BigInt))
+      (i32.eq (global.get $TermType::Int))
+      (func $Stdlib_Divide::impl::BigInt::Int (param $self i32) (param $divisor i32) (param $state i32) (result i32 i32)
+        (if (result i32 i32)
+          (i64.eqz (call $Term::Int::get::value (local.get $divisor)))
+          (then
+            (call $Term::Signal::of
+              (call $Term::Condition::invalid_builtin_function_args
+                (global.get $Stdlib_Divide)
+                (call $Term::List::create_pair (local.get $self) (local.get $divisor))))
+            (global.get $NULL))
+          (else
+            ;; Discard the remainder and return the quotient
+            (call $Term::BigInt::divide
+              (local.get $self)
+              (call $Term::BigInt::from_i64 (call $Term::Int::get::value (local.get $divisor))))
+            (drop)
+            (global.get $NULL)))))
+
+    (@impl
+      (i32.eq (global.get $TermType::Int))
+      (i32.eq (global.get $TermType::BigInt))
+      (func $Stdlib_Divide::impl::Int::BigInt (param $self i32) (param $divisor i32) (param $state i32) (result i32 i32)
+        (if (result i32 i32)
+          (i32.eqz (call $Term::BigInt::get::limbs::length (local.get $divisor)))
+          (then
+            (call $Term::Signal::of
+              (call $Term::Condition::invalid_builtin_function_args
+                (global.get $Stdlib_Divide)
+                (call $Term::List::create_pair (local.get $self) (local.get $divisor))))
+            (global.get $NULL))
+          (else
+            ;; Discard the remainder and return the quotient
+            (call $Term::BigInt::divide
+              (call $Term::BigInt::from_i64 (call $Term::Int::get::value (local.get $self)))
+              (local.get $divisor))
+            (drop)
             (global.get $NULL)))))
 
     (@default
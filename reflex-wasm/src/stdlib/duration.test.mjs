@@ -0,0 +1,106 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_Duration', (test) => {
+    test('(Int)', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Duration),
+          createUnitList(createInt(0)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'Duration(0ms)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Duration),
+          createUnitList(createInt(-3600000)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'Duration(-3600000ms)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('(Float)', (assert, {
+      createApplication,
+      createBuiltin,
+      createFloat,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Duration),
+          createUnitList(createFloat(1500.9)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'Duration(1500ms)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Duration),
+          createUnitList(createFloat(-1500.9)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'Duration(-1500ms)');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Duration),
+          createUnitList(createFloat(NaN)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '{<InvalidFunctionArgsCondition:Duration(NaN)>}');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Duration),
+          createUnitList(createFloat(Infinity)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '{<InvalidFunctionArgsCondition:Duration(Infinity)>}');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('(Duration)', (assert, {
+      createApplication,
+      createBuiltin,
+      createDuration,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.Duration),
+        createUnitList(createDuration(1500)),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(format(result), 'Duration(1500ms)');
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+  });
+};
@@ -0,0 +1,42 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@builtin $Stdlib_Duration "Duration"
+    (@args (@strict $self))
+
+    (@impl
+      (i32.eq (global.get $TermType::Duration))
+      (func $Stdlib_Duration::impl::Duration (param $self i32) (param $state i32) (result i32 i32)
+        (local.get $self)
+        (global.get $NULL)))
+
+    (@impl
+      (i32.eq (global.get $TermType::Int))
+      (func $Stdlib_Duration::impl::Int (param $self i32) (param $state i32) (result i32 i32)
+        (call $Term::Duration::new (call $Term::Int::get::value (local.get $self)))
+        (global.get $NULL)))
+
+    (@impl
+      (i32.eq (global.get $TermType::Float))
+      (func $Stdlib_Duration::impl::Float (param $self i32) (param $state i32) (result i32 i32)
+        (local $value f64)
+        (local.set $value (f64.trunc (call $Term::Float::get::value (local.get $self))))
+        ;; Ensure the value is within the representable range of a 64-bit signed integer (this also excludes NaN)
+        (if (result i32 i32)
+          (i32.and
+            (f64.ge (local.get $value) (f64.const -0x1p63))
+            (f64.lt (local.get $value) (f64.const 0x1p63)))
+          (then
+            (call $Term::Duration::new (i64.trunc_f64_s (local.get $value)))
+            (global.get $NULL))
+          (else
+            (call $Stdlib_Duration::impl::default (local.get $self) (local.get $state))))))
+
+    (@default
+      (func $Stdlib_Duration::impl::default (param $self i32) (param $state i32) (result i32 i32)
+        (call $Term::Signal::of
+          (call $Term::Condition::invalid_builtin_function_args
+            (global.get $Stdlib_Duration)
+            (call $Term::List::of (local.get $self))))
+        (global.get $NULL)))))
@@ -0,0 +1,109 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_Eval', (test) => {
+    test('(Record)', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createLambda,
+      createLet,
+      createPair,
+      createUnitList,
+      createVariable,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Eval),
+          createUnitList(
+            createApplication(
+              createBuiltin(Stdlib.Quote),
+              createUnitList(
+                createApplication(createBuiltin(Stdlib.Add), createPair(createInt(3), createInt(4))),
+              ),
+            ),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '7');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createApplication(
+            createBuiltin(Stdlib.Eval),
+            createUnitList(
+              createApplication(
+                createBuiltin(Stdlib.Quote),
+                createUnitList(
+                  createLambda(
+                    1,
+                    createLet(
+                      createInt(3),
+                      createApplication(
+                        createBuiltin(Stdlib.Add),
+                        createPair(createVariable(0), createVariable(1)),
+                      ),
+                    ),
+                  ),
+                ),
+              ),
+            ),
+          ),
+          createUnitList(createInt(4)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '7');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('invalid arguments', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createPair,
+      createRecord,
+      createString,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Eval),
+          createUnitList(createInt(3)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '{<InvalidFunctionArgsCondition:Eval(3)>}');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Eval),
+          createUnitList(
+            createRecord(
+              createPair(createString('type'), createString('offset')),
+              createPair(createString('Variable'), createInt(0)),
+            ),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(
+          format(result),
+          '{<InvalidFunctionArgsCondition:Eval({ "type": "Variable", "offset": 0 })>}',
+        );
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+  });
+};
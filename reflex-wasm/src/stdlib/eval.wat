@@ -0,0 +1,231 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@builtin $Stdlib_Eval "Eval"
+    (@args (@strict $self))
+
+    (@impl
+      (i32.eq (global.get $TermType::Record))
+      (func $Stdlib_Eval::impl::Record (param $self i32) (param $state i32) (result i32 i32)
+        (local $result i32)
+        (if (result i32 i32)
+          ;; Reconstruct the expression, returning an error if the quoted expression is invalid
+          (i32.eq
+            (global.get $NULL)
+            (local.tee $result (call $Stdlib_Eval::unquote (local.get $self) (i32.const 0))))
+          (then
+            (call $Stdlib_Eval::impl::default (local.get $self) (local.get $state)))
+          (else
+            (local.get $result)
+            (global.get $NULL)))))
+
+    (@default
+      (func $Stdlib_Eval::impl::default (param $self i32) (param $state i32) (result i32 i32)
+        (call $Term::Signal::of
+          (call $Term::Condition::invalid_builtin_function_args
+            (global.get $Stdlib_Eval)
+            (call $Term::List::of (local.get $self))))
+        (global.get $NULL))))
+
+  (func $Stdlib_Eval::unquote (param $self i32) (param $scope_depth i32) (result i32)
+    ;; Returns the null sentinel value if the provided node is not a valid quoted expression
+    (local $node_type i32)
+    (local $first i32)
+    (local $second i32)
+    (if
+      (i32.eqz (call $Term::Record::is (local.get $self)))
+      (then
+        (return (global.get $NULL))))
+    (local.set $node_type (call $Term::Record::find_value (local.get $self) (global.get $Stdlib_Quote::FIELD_TYPE)))
+    (if
+      (i32.eq (global.get $NULL) (local.get $node_type))
+      (then
+        (return (global.get $NULL))))
+    (if
+      (call $Term::traits::equals (local.get $node_type) (global.get $Stdlib_Quote::TYPE_APPLICATION))
+      (then
+        (local.set $first
+          (call $Stdlib_Eval::unquote_field (local.get $self) (global.get $Stdlib_Quote::FIELD_TARGET) (local.get $scope_depth)))
+        (local.set $second
+          (call $Stdlib_Eval::unquote_list_field (local.get $self) (global.get $Stdlib_Quote::FIELD_ARGS) (local.get $scope_depth)))
+        (return
+          (if (result i32)
+            (i32.or
+              (i32.eq (global.get $NULL) (local.get $first))
+              (i32.eq (global.get $NULL) (local.get $second)))
+            (then
+              (global.get $NULL))
+            (else
+              (call $Term::Application::new (local.get $first) (local.get $second)))))))
+    (if
+      (call $Term::traits::equals (local.get $node_type) (global.get $Stdlib_Quote::TYPE_LAMBDA))
+      (then
+        (local.set $first
+          (call $Stdlib_Eval::parse_offset
+            (call $Term::Record::find_value (local.get $self) (global.get $Stdlib_Quote::FIELD_ARITY))))
+        (if
+          (i32.eq (i32.const -1) (local.get $first))
+          (then
+            (return (global.get $NULL))))
+        (local.set $second
+          (call $Stdlib_Eval::unquote_field
+            (local.get $self)
+            (global.get $Stdlib_Quote::FIELD_BODY)
+            (i32.add (local.get $scope_depth) (local.get $first))))
+        (return
+          (if (result i32)
+            (i32.eq (global.get $NULL) (local.get $second))
+            (then
+              (global.get $NULL))
+            (else
+              (call $Term::Lambda::new (local.get $first) (local.get $second)))))))
+    (if
+      (call $Term::traits::equals (local.get $node_type) (global.get $Stdlib_Quote::TYPE_LET))
+      (then
+        (local.set $first
+          (call $Stdlib_Eval::unquote_field (local.get $self) (global.get $Stdlib_Quote::FIELD_INITIALIZER) (local.get $scope_depth)))
+        (local.set $second
+          (call $Stdlib_Eval::unquote_field
+            (local.get $self)
+            (global.get $Stdlib_Quote::FIELD_BODY)
+            (i32.add (local.get $scope_depth) (i32.const 1))))
+        (return
+          (if (result i32)
+            (i32.or
+              (i32.eq (global.get $NULL) (local.get $first))
+              (i32.eq (global.get $NULL) (local.get $second)))
+            (then
+              (global.get $NULL))
+            (else
+              (call $Term::Let::new (local.get $first) (local.get $second)))))))
+    (if
+      (call $Term::traits::equals (local.get $node_type) (global.get $Stdlib_Quote::TYPE_VARIABLE))
+      (then
+        (local.set $first
+          (call $Stdlib_Eval::parse_offset
+            (call $Term::Record::find_value (local.get $self) (global.get $Stdlib_Quote::FIELD_OFFSET))))
+        (return
+          (if (result i32)
+            ;; Variables that refer outside the reconstructed expression would otherwise be left dangling
+            (i32.or
+              (i32.eq (i32.const -1) (local.get $first))
+              (i32.ge_u (local.get $first) (local.get $scope_depth)))
+            (then
+              (global.get $NULL))
+            (else
+              (call $Term::Variable::new (local.get $first)))))))
+    (if
+      (call $Term::traits::equals (local.get $node_type) (global.get $Stdlib_Quote::TYPE_LIST))
+      (then
+        (return
+          (call $Stdlib_Eval::unquote_list_field (local.get $self) (global.get $Stdlib_Quote::FIELD_ITEMS) (local.get $scope_depth)))))
+    (if
+      (call $Term::traits::equals (local.get $node_type) (global.get $Stdlib_Quote::TYPE_RECORD))
+      (then
+        (local.set $first (call $Term::Record::find_value (local.get $self) (global.get $Stdlib_Quote::FIELD_KEYS)))
+        (local.set $second
+          (call $Stdlib_Eval::unquote_list_field (local.get $self) (global.get $Stdlib_Quote::FIELD_VALUES) (local.get $scope_depth)))
+        (if
+          (i32.or
+            (i32.eq (global.get $NULL) (local.get $first))
+            (i32.eq (global.get $NULL) (local.get $second)))
+          (then
+            (return (global.get $NULL))))
+        (if
+          (i32.eqz (call $Term::is_list (local.get $first)))
+          (then
+            (return (global.get $NULL))))
+        (return
+          (if (result i32)
+            (i32.ne
+              (call $Term::List::get_length (local.get $first))
+              (call $Term::List::get_length (local.get $second)))
+            (then
+              (global.get $NULL))
+            (else
+              (call $Term::Record::new (local.get $first) (local.get $second)))))))
+    (if (result i32)
+      (call $Term::traits::equals (local.get $node_type) (global.get $Stdlib_Quote::TYPE_VALUE))
+      (then
+        (call $Term::Record::find_value (local.get $self) (global.get $Stdlib_Quote::FIELD_VALUE)))
+      (else
+        (global.get $NULL))))
+
+  (func $Stdlib_Eval::unquote_field (param $self i32) (param $field_name i32) (param $scope_depth i32) (result i32)
+    (local $value i32)
+    (if (result i32)
+      (i32.eq
+        (global.get $NULL)
+        (local.tee $value (call $Term::Record::find_value (local.get $self) (local.get $field_name))))
+      (then
+        (global.get $NULL))
+      (else
+        (call $Stdlib_Eval::unquote (local.get $value) (local.get $scope_depth)))))
+
+  (func $Stdlib_Eval::unquote_list_field (param $self i32) (param $field_name i32) (param $scope_depth i32) (result i32)
+    (local $value i32)
+    (local $results i32)
+    (local $length i32)
+    (local $index i32)
+    (local $item i32)
+    (local.set $value (call $Term::Record::find_value (local.get $self) (local.get $field_name)))
+    (if
+      (i32.eq (global.get $NULL) (local.get $value))
+      (then
+        (return (global.get $NULL))))
+    (if
+      (i32.eqz (call $Term::is_list (local.get $value)))
+      (then
+        (return (global.get $NULL))))
+    (if
+      (i32.eqz (local.tee $length (call $Term::List::get_length (local.get $value))))
+      (then
+        (return (call $Term::List::empty))))
+    ;; Reconstruct each of the list items into a new list of the same length, bailing out if any item is invalid
+    (local.set $results (call $Term::List::allocate (local.get $length)))
+    (loop $LOOP
+      (if
+        (i32.eq
+          (global.get $NULL)
+          (local.tee $item
+            (call $Stdlib_Eval::unquote
+              (call $Term::List::get_item (local.get $value) (local.get $index))
+              (local.get $scope_depth))))
+        (then
+          (return (global.get $NULL))))
+      (call $Term::List::set_item (local.get $results) (local.get $index) (local.get $item))
+      (br_if $LOOP (i32.lt_u (local.tee $index (i32.add (local.get $index) (i32.const 1))) (local.get $length))))
+    (call $Term::List::init (local.get $results) (local.get $length)))
+
+  (func $Stdlib_Eval::parse_offset (param $value i32) (result i32)
+    ;; Returns -1 if the provided value is not a non-negative integer
+    (local $float f64)
+    (if
+      (i32.eq (global.get $NULL) (local.get $value))
+      (then
+        (return (i32.const -1))))
+    (if
+      (call $Term::Int::is (local.get $value))
+      (then
+        (return
+          (select
+            (i32.wrap_i64 (call $Term::Int::get_value (local.get $value)))
+            (i32.const -1)
+            (i64.lt_u (call $Term::Int::get_value (local.get $value)) (i64.const 0x80000000))))))
+    (if (result i32)
+      (call $Term::Float::is (local.get $value))
+      (then
+        (local.set $float (call $Term::Float::get_value (local.get $value)))
+        (if (result i32)
+          (i32.and
+            (f64.eq (f64.trunc (local.get $float)) (local.get $float))
+            (i32.and
+              (f64.ge (local.get $float) (f64.const 0))
+              (f64.lt (local.get $float) (f64.const 2147483648))))
+          (then
+            (i32.trunc_f64_u (local.get $float)))
+          (else
+            (i32.const -1))))
+      (else
+        (i32.const -1)))))
@@ -240,5 +240,279 @@ export default (describe) => {
         assert.deepEqual(getStateDependencies(dependencies), []);
       })();
     });
+
+    test('(Timestamp, Timestamp)', (assert, {
+      createApplication,
+      createBuiltin,
+      createPair,
+      createTimestamp,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createTimestamp(1), createTimestamp(2)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'false');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createTimestamp(2), createTimestamp(2)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'false');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createTimestamp(2), createTimestamp(1)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'true');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('(Duration, Duration)', (assert, {
+      createApplication,
+      createBuiltin,
+      createPair,
+      createDuration,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createDuration(1), createDuration(2)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'false');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createDuration(2), createDuration(2)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'false');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createDuration(2), createDuration(1)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'true');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('(BigInt, BigInt)', (assert, {
+      createApplication,
+      createBigInt,
+      createBuiltin,
+      createPair,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createBigInt(123456789012345678901234567890n), createBigInt(987654321098765432109876543210n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'false');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createBigInt(987654321098765432109876543210n), createBigInt(123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'true');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createBigInt(-123456789012345678901234567890n), createBigInt(987654321098765432109876543210n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'false');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createBigInt(987654321098765432109876543210n), createBigInt(-123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'true');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createBigInt(123456789012345678901234567890n), createBigInt(123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'false');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createBigInt(-123456789012345678901234567890n), createBigInt(-123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'false');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createBigInt(0n), createBigInt(123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'false');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createBigInt(4294967295n), createBigInt(1n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'true');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createBigInt(18446744073709551616n), createBigInt(-4294967296n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'true');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('(BigInt, Int)', (assert, {
+      createApplication,
+      createBigInt,
+      createBuiltin,
+      createInt,
+      createPair,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createBigInt(123456789012345678901234567890n), createInt(3)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'true');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createBigInt(-123456789012345678901234567890n), createInt(3)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'false');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createBigInt(123456789012345678901234567890n), createInt(-7)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'true');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createBigInt(9223372036854775808n), createInt(-1)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'true');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('(Int, BigInt)', (assert, {
+      createApplication,
+      createBigInt,
+      createBuiltin,
+      createInt,
+      createPair,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createInt(3), createBigInt(123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'false');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createInt(-3), createBigInt(123456789012345678901234567890n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'false');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createInt(9007199254740991), createBigInt(1n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'true');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Gt),
+          createPair(createInt(-9007199254740991), createBigInt(-18446744073709551616n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'true');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
   });
 };
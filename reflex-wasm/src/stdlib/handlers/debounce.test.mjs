@@ -0,0 +1,39 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_Debounce', (test) => {
+    test('(Lambda, Int)', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createLambda,
+      createPair,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.Debounce),
+        createPair(
+          createLambda(
+            0,
+            createApplication(createBuiltin(Stdlib.Add), createPair(createInt(3), createInt(4))),
+          ),
+          createInt(1000),
+        ),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(
+        format(result),
+        '{<CustomCondition:"reflex::debounce":[(0) => Add(3, 4), 1000]:null>}',
+      );
+      assert.deepEqual(
+        getStateDependencies(dependencies).map((dependency) => format(dependency)),
+        ['<CustomCondition:"reflex::debounce":[(0) => Add(3, 4), 1000]:null>'],
+      );
+    });
+  });
+};
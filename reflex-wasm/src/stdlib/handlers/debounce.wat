@@ -0,0 +1,28 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@const-string $Stdlib_Debounce::EFFECT_NAME_DEBOUNCE "reflex::debounce")
+
+  (@builtin $Stdlib_Debounce "Debounce"
+    (@args (@strict $input) (@strict $interval))
+
+    (@impl
+      (call $TermType::implements::apply)
+      (i32.or (i32.const 0xFFFFFFFF))
+      (func $Stdlib_Debounce::impl::<apply>::any (param $input i32) (param $interval i32) (param $state i32) (result i32 i32)
+        ;; Create an effect containing the provided arguments
+        (call $Term::Effect::new
+          (call $Term::Condition::custom
+            (global.get $Stdlib_Debounce::EFFECT_NAME_DEBOUNCE)
+            (call $Term::List::create_pair (local.get $input) (local.get $interval))
+            (call $Term::Nil::new)))
+        (global.get $NULL)))
+
+    (@default
+      (func $Stdlib_Debounce::impl::default (param $input i32) (param $interval i32) (param $state i32) (result i32 i32)
+        (call $Term::Signal::of
+          (call $Term::Condition::invalid_builtin_function_args
+            (global.get $Stdlib_Debounce)
+            (call $Term::List::create_pair (local.get $input) (local.get $interval))))
+        (global.get $NULL)))))
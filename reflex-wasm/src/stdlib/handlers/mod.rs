@@ -1,12 +1,20 @@
 // SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+pub mod debounce;
+pub mod paginate;
 pub mod resolve_loader_results;
+pub mod sample;
 pub mod scan;
+pub mod throttle;
 pub mod to_request;
 pub mod variable;
 
+pub use debounce::*;
+pub use paginate::*;
 pub use resolve_loader_results::*;
+pub use sample::*;
 pub use scan::*;
+pub use throttle::*;
 pub use to_request::*;
 pub use variable::*;
@@ -0,0 +1,74 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_Paginate', (test) => {
+    test('(Lambda, Int)', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createLambda,
+      createNil,
+      createPair,
+      createRecord,
+      createString,
+      createTriple,
+      createUnitList,
+      createVariable,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      // Page fetcher returns a single-item page containing the cursor, with a final page cursor of 2
+      const fetchPage = createLambda(
+        1,
+        createRecord(
+          createPair(createString('items'), createString('cursor')),
+          createPair(
+            createUnitList(createVariable(0)),
+            createApplication(
+              createBuiltin(Stdlib.If),
+              createTriple(
+                createApplication(
+                  createBuiltin(Stdlib.Eq),
+                  createPair(createVariable(0), createInt(2)),
+                ),
+                createLambda(0, createNil()),
+                createLambda(
+                  0,
+                  createApplication(
+                    createBuiltin(Stdlib.Add),
+                    createPair(createVariable(0), createInt(1)),
+                  ),
+                ),
+              ),
+            ),
+          ),
+        ),
+      );
+      const paginate = createApplication(
+        createBuiltin(Stdlib.Paginate),
+        createPair(fetchPage, createInt(1)),
+      );
+      const get = (target, key) =>
+        createApplication(createBuiltin(Stdlib.Get), createPair(target, createString(key)));
+      (() => {
+        const [result, dependencies] = evaluate(get(paginate, 'items'), NULL);
+        assert.strictEqual(format(result), '[1]');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const [result, dependencies] = evaluate(get(get(paginate, 'next'), 'items'), NULL);
+        assert.strictEqual(format(result), '[2]');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const [result, dependencies] = evaluate(get(get(paginate, 'next'), 'next'), NULL);
+        assert.strictEqual(format(result), 'null');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+  });
+};
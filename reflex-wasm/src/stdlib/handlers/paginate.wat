@@ -0,0 +1,61 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@const-string $Stdlib_Paginate::FIELD_ITEMS "items")
+  (@const-string $Stdlib_Paginate::FIELD_CURSOR "cursor")
+  (@const-string $Stdlib_Paginate::FIELD_NEXT "next")
+
+  (@const $Stdlib_Paginate::KEYS i32
+    (@depends-on $Stdlib_Paginate::FIELD_ITEMS)
+    (@depends-on $Stdlib_Paginate::FIELD_NEXT)
+    (call $Term::List::create_pair
+      (global.get $Stdlib_Paginate::FIELD_ITEMS)
+      (global.get $Stdlib_Paginate::FIELD_NEXT)))
+
+  (@builtin $Stdlib_Paginate "Paginate"
+    (@args (@strict $self) (@strict $cursor))
+
+    (@impl
+      (call $TermType::implements::apply)
+      (i32.or (i32.const 0xFFFFFFFF))
+      (func $Stdlib_Paginate::impl::<apply>::any (param $self i32) (param $cursor i32) (param $state i32) (result i32 i32)
+        (local $page i32)
+        (local $next_cursor i32)
+        ;; Create a lazy application of the page fetcher function to the current cursor
+        (local.set $page
+          (call $Term::Application::new (local.get $self) (call $Term::List::of (local.get $cursor))))
+        ;; Create a lazy accessor for the next page cursor
+        (local.set $next_cursor
+          (call $Term::Application::new
+            (call $Term::Builtin::new (global.get $Stdlib_Get))
+            (call $Term::List::create_pair (local.get $page) (global.get $Stdlib_Paginate::FIELD_CURSOR))))
+        ;; Create a record containing the current page items, combined with a lazily-evaluated reference to the next page
+        ;; (this will only be evaluated once a consumer accesses the next field)
+        (call $Term::Record::new
+          (global.get $Stdlib_Paginate::KEYS)
+          (call $Term::List::create_pair
+            (call $Term::Application::new
+              (call $Term::Builtin::new (global.get $Stdlib_Get))
+              (call $Term::List::create_pair (local.get $page) (global.get $Stdlib_Paginate::FIELD_ITEMS)))
+            (call $Term::Application::new
+              (call $Term::Builtin::new (global.get $Stdlib_If))
+              (call $Term::List::create_triple
+                (call $Term::Application::new
+                  (call $Term::Builtin::new (global.get $Stdlib_Eq))
+                  (call $Term::List::create_pair (local.get $next_cursor) (call $Term::Nil::new)))
+                (call $Term::Lambda::new (i32.const 0) (call $Term::Nil::new))
+                (call $Term::Lambda::new
+                  (i32.const 0)
+                  (call $Term::Application::new
+                    (call $Term::Builtin::new (global.get $Stdlib_Paginate))
+                    (call $Term::List::create_pair (local.get $self) (local.get $next_cursor))))))))
+        (global.get $NULL)))
+
+    (@default
+      (func $Stdlib_Paginate::impl::default (param $self i32) (param $cursor i32) (param $state i32) (result i32 i32)
+        (call $Term::Signal::of
+          (call $Term::Condition::invalid_builtin_function_args
+            (global.get $Stdlib_Paginate)
+            (call $Term::List::create_pair (local.get $self) (local.get $cursor))))
+        (global.get $NULL)))))
@@ -0,0 +1,39 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_Sample', (test) => {
+    test('(Lambda, Lambda)', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createLambda,
+      createPair,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.Sample),
+        createPair(
+          createLambda(
+            0,
+            createApplication(createBuiltin(Stdlib.Add), createPair(createInt(3), createInt(4))),
+          ),
+          createLambda(0, createInt(5)),
+        ),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(
+        format(result),
+        '{<CustomCondition:"reflex::sample":[(0) => Add(3, 4), (0) => 5]:null>}',
+      );
+      assert.deepEqual(
+        getStateDependencies(dependencies).map((dependency) => format(dependency)),
+        ['<CustomCondition:"reflex::sample":[(0) => Add(3, 4), (0) => 5]:null>'],
+      );
+    });
+  });
+};
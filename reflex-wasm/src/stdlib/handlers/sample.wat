@@ -0,0 +1,28 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@const-string $Stdlib_Sample::EFFECT_NAME_SAMPLE "reflex::sample")
+
+  (@builtin $Stdlib_Sample "Sample"
+    (@args (@strict $input) (@strict $trigger))
+
+    (@impl
+      (call $TermType::implements::apply)
+      (call $TermType::implements::apply)
+      (func $Stdlib_Sample::impl::<apply>::<apply> (param $input i32) (param $trigger i32) (param $state i32) (result i32 i32)
+        ;; Create an effect containing the provided arguments
+        (call $Term::Effect::new
+          (call $Term::Condition::custom
+            (global.get $Stdlib_Sample::EFFECT_NAME_SAMPLE)
+            (call $Term::List::create_pair (local.get $input) (local.get $trigger))
+            (call $Term::Nil::new)))
+        (global.get $NULL)))
+
+    (@default
+      (func $Stdlib_Sample::impl::default (param $input i32) (param $trigger i32) (param $state i32) (result i32 i32)
+        (call $Term::Signal::of
+          (call $Term::Condition::invalid_builtin_function_args
+            (global.get $Stdlib_Sample)
+            (call $Term::List::create_pair (local.get $input) (local.get $trigger))))
+        (global.get $NULL)))))
@@ -0,0 +1,28 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@const-string $Stdlib_Throttle::EFFECT_NAME_THROTTLE "reflex::throttle")
+
+  (@builtin $Stdlib_Throttle "Throttle"
+    (@args (@strict $input) (@strict $interval))
+
+    (@impl
+      (call $TermType::implements::apply)
+      (i32.or (i32.const 0xFFFFFFFF))
+      (func $Stdlib_Throttle::impl::<apply>::any (param $input i32) (param $interval i32) (param $state i32) (result i32 i32)
+        ;; Create an effect containing the provided arguments
+        (call $Term::Effect::new
+          (call $Term::Condition::custom
+            (global.get $Stdlib_Throttle::EFFECT_NAME_THROTTLE)
+            (call $Term::List::create_pair (local.get $input) (local.get $interval))
+            (call $Term::Nil::new)))
+        (global.get $NULL)))
+
+    (@default
+      (func $Stdlib_Throttle::impl::default (param $input i32) (param $interval i32) (param $state i32) (result i32 i32)
+        (call $Term::Signal::of
+          (call $Term::Condition::invalid_builtin_function_args
+            (global.get $Stdlib_Throttle)
+            (call $Term::List::create_pair (local.get $input) (local.get $interval))))
+        (global.get $NULL)))))
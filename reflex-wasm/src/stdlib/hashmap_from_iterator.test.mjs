@@ -0,0 +1,162 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_HashmapFromIterator', (test) => {
+    test('(Int)', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.HashmapFromIterator),
+        createUnitList(createInt(3)),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(format(result), '{<InvalidFunctionArgsCondition:HashmapFromIterator(3)>}');
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+
+    test('(EmptyIterator)', (assert, {
+      createApplication,
+      createBuiltin,
+      createEmptyIterator,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.HashmapFromIterator),
+        createUnitList(createEmptyIterator()),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(format(result), 'Map(0)');
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+
+    test('(List)', (assert, {
+      createApplication,
+      createBuiltin,
+      createEmptyList,
+      createInt,
+      createLambda,
+      createList,
+      createString,
+      createUnitList,
+      evaluate,
+      format,
+      getHashmapValue,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.HashmapFromIterator),
+          createUnitList(
+            createList([
+              createString('foo'),
+              createInt(3),
+              createString('bar'),
+              createInt(4),
+              createString('foo'),
+              createInt(5),
+            ]),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'Map(2)');
+        assert.strictEqual(format(getHashmapValue(result, createString('foo'))), '5');
+        assert.strictEqual(format(getHashmapValue(result, createString('bar'))), '4');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.HashmapFromIterator),
+          createUnitList(
+            createList([
+              createApplication(createLambda(0, createString('foo')), createEmptyList()),
+              createApplication(createLambda(0, createInt(3)), createEmptyList()),
+              createApplication(createLambda(0, createString('bar')), createEmptyList()),
+              createApplication(createLambda(0, createInt(4)), createEmptyList()),
+            ]),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'Map(2)');
+        assert.strictEqual(format(getHashmapValue(result, createString('foo'))), '3');
+        assert.strictEqual(format(getHashmapValue(result, createString('bar'))), '4');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('(FlattenIterator)', (assert, {
+      createApplication,
+      createBuiltin,
+      createFlattenIterator,
+      createInt,
+      createRangeIterator,
+      createUnitList,
+      createZipIterator,
+      evaluate,
+      format,
+      getHashmapValue,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.HashmapFromIterator),
+        createUnitList(
+          createFlattenIterator(createZipIterator(createRangeIterator(3, 3), createRangeIterator(6, 3))),
+        ),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(format(result), 'Map(3)');
+      assert.strictEqual(format(getHashmapValue(result, createInt(3))), '6');
+      assert.strictEqual(format(getHashmapValue(result, createInt(4))), '7');
+      assert.strictEqual(format(getHashmapValue(result, createInt(5))), '8');
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+
+    test('Signals', (assert, {
+      createApplication,
+      createBuiltin,
+      createErrorCondition,
+      createInt,
+      createList,
+      createSignal,
+      createString,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.HashmapFromIterator),
+        createUnitList(
+          createList([
+            createString('foo'),
+            createSignal(createErrorCondition(createString('bar'))),
+            createSignal(createErrorCondition(createString('baz'))),
+            createInt(3),
+          ]),
+        ),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(format(result), '{<ErrorCondition:"bar">,<ErrorCondition:"baz">}');
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+  });
+};
@@ -0,0 +1,154 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_HashsetFromIterator', (test) => {
+    test('(Int)', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.HashsetFromIterator),
+        createUnitList(createInt(3)),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(format(result), '{<InvalidFunctionArgsCondition:HashsetFromIterator(3)>}');
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+
+    test('(EmptyIterator)', (assert, {
+      createApplication,
+      createBuiltin,
+      createEmptyIterator,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.HashsetFromIterator),
+        createUnitList(createEmptyIterator()),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(format(result), 'Set(0)');
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+
+    test('(List)', (assert, {
+      createApplication,
+      createBuiltin,
+      createEmptyList,
+      createLambda,
+      createList,
+      createString,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      hasHashsetValue,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.HashsetFromIterator),
+          createUnitList(
+            createList([
+              createString('foo'),
+              createString('bar'),
+              createString('foo'),
+              createString('baz'),
+            ]),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'Set(3)');
+        assert.strictEqual(hasHashsetValue(result, createString('foo')), true);
+        assert.strictEqual(hasHashsetValue(result, createString('bar')), true);
+        assert.strictEqual(hasHashsetValue(result, createString('baz')), true);
+        assert.strictEqual(hasHashsetValue(result, createString('qux')), false);
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.HashsetFromIterator),
+          createUnitList(
+            createList([
+              createApplication(createLambda(0, createString('foo')), createEmptyList()),
+              createApplication(createLambda(0, createString('bar')), createEmptyList()),
+            ]),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'Set(2)');
+        assert.strictEqual(hasHashsetValue(result, createString('foo')), true);
+        assert.strictEqual(hasHashsetValue(result, createString('bar')), true);
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('(RangeIterator)', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createRangeIterator,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      hasHashsetValue,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.HashsetFromIterator),
+        createUnitList(createRangeIterator(3, 3)),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(format(result), 'Set(3)');
+      assert.strictEqual(hasHashsetValue(result, createInt(3)), true);
+      assert.strictEqual(hasHashsetValue(result, createInt(4)), true);
+      assert.strictEqual(hasHashsetValue(result, createInt(5)), true);
+      assert.strictEqual(hasHashsetValue(result, createInt(6)), false);
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+
+    test('Signals', (assert, {
+      createApplication,
+      createBuiltin,
+      createErrorCondition,
+      createList,
+      createSignal,
+      createString,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.HashsetFromIterator),
+        createUnitList(
+          createList([
+            createString('foo'),
+            createSignal(createErrorCondition(createString('bar'))),
+            createSignal(createErrorCondition(createString('baz'))),
+          ]),
+        ),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(format(result), '{<ErrorCondition:"bar">,<ErrorCondition:"baz">}');
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+  });
+};
@@ -0,0 +1,21 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@builtin $Stdlib_HashsetFromIterator "HashsetFromIterator"
+    (@args (@strict $self))
+
+    (@impl
+      (call $TermType::implements::iterate)
+      (func $Stdlib_HashsetFromIterator::impl::<iterate> (param $self i32) (param $state i32) (result i32 i32)
+        ;; Consume the values of the source iterator directly into a new hashset, sized according to the iterator size
+        ;; hint where known (this avoids collecting the iterator items into an intermediate list)
+        (call $Term::Hashset::traits::collect_strict (local.get $self) (local.get $state))))
+
+    (@default
+      (func $Stdlib_HashsetFromIterator::impl::default (param $self i32) (param $state i32) (result i32 i32)
+        (call $Term::Signal::of
+          (call $Term::Condition::invalid_builtin_function_args
+            (global.get $Stdlib_HashsetFromIterator)
+            (call $Term::List::of (local.get $self))))
+        (global.get $NULL)))))
@@ -15,11 +15,20 @@ import collectRecord from './collect_record.test.mjs';
 import collectSignal from './collect_signal.test.mjs';
 import collectString from './collect_string.test.mjs';
 import collectTree from './collect_tree.test.mjs';
+import dateAdd from './date_add.test.mjs';
+import dateDiff from './date_diff.test.mjs';
+import dateOffset from './date_offset.test.mjs';
+import dateTruncate from './date_truncate.test.mjs';
+import dateWithOffset from './date_with_offset.test.mjs';
+import dedupe from './dedupe.test.mjs';
+import _delete from './delete.test.mjs';
 import divide from './divide.test.mjs';
+import duration from './duration.test.mjs';
 import effect from './effect.test.mjs';
 import endsWith from './ends_with.test.mjs';
 import eq from './eq.test.mjs';
 import equal from './equal.test.mjs';
+import _eval from './eval.test.mjs';
 import filter from './filter.test.mjs';
 import flatten from './flatten.test.mjs';
 import floor from './floor.test.mjs';
@@ -29,6 +38,8 @@ import gt from './gt.test.mjs';
 import gte from './gte.test.mjs';
 import has from './has.test.mjs';
 import hash from './hash.test.mjs';
+import hashmapFromIterator from './hashmap_from_iterator.test.mjs';
+import hashsetFromIterator from './hashset_from_iterator.test.mjs';
 import identity from './identity.test.mjs';
 import intersperse from './intersperse.test.mjs';
 import _if from './if.test.mjs';
@@ -40,7 +51,10 @@ import length from './length.test.mjs';
 import lt from './lt.test.mjs';
 import lte from './lte.test.mjs';
 import map from './map.test.mjs';
+import match from './match.test.mjs';
 import max from './max.test.mjs';
+import memo from './memo.test.mjs';
+import memoStore from './memo_store.test.mjs';
 import merge from './merge.test.mjs';
 import min from './min.test.mjs';
 import multiply from './multiply.test.mjs';
@@ -49,6 +63,7 @@ import or from './or.test.mjs';
 import pow from './pow.test.mjs';
 import push from './push.test.mjs';
 import pushFront from './push_front.test.mjs';
+import quote from './quote.test.mjs';
 import raise from './raise.test.mjs';
 import remainder from './remainder.test.mjs';
 import replace from './replace.test.mjs';
@@ -59,20 +74,29 @@ import resolveHashset from './resolve_hashset.test.mjs';
 import resolveList from './resolve_list.test.mjs';
 import resolveRecord from './resolve_record.test.mjs';
 import resolveTree from './resolve_tree.test.mjs';
+import reveal from './reveal.test.mjs';
 import round from './round.test.mjs';
 import set from './set.test.mjs';
 import sequence from './sequence.test.mjs';
 import skip from './skip.test.mjs';
 import slice from './slice.test.mjs';
+import sort from './sort.test.mjs';
+import sortBy from './sort_by.test.mjs';
+import sortMerge from './sort_merge.test.mjs';
 import split from './split.test.mjs';
 import startsWith from './starts_with.test.mjs';
 import subtract from './subtract.test.mjs';
 import take from './take.test.mjs';
+import template from './template.test.mjs';
 import unzip from './unzip.test.mjs';
 import values from './values.test.mjs';
 import zip from './zip.test.mjs';
+import debounce from './handlers/debounce.test.mjs';
+import paginate from './handlers/paginate.test.mjs';
 import resolveLoaderResults from './handlers/resolve_loader_results.test.mjs';
+import sample from './handlers/sample.test.mjs';
 import scan from './handlers/scan.test.mjs';
+import throttle from './handlers/throttle.test.mjs';
 import toRequest from './handlers/to_request.test.mjs';
 import variable from './handlers/variable.test.mjs';
 import accessor from './js/accessor.test.mjs';
@@ -82,14 +106,17 @@ import formatErrorMessage from './js/format_error_message.test.mjs';
 import isFinite from './js/is_finite.test.mjs';
 import isTruthy from './js/is_truthy.test.mjs';
 import log from './js/log.test.mjs';
+import logScope from './js/log_scope.test.mjs';
 import parseDate from './js/parse_date.test.mjs';
 import parseFloat from './js/parse_float.test.mjs';
 import parseInt from './js/parse_int.test.mjs';
+import scopedLog from './js/scoped_log.test.mjs';
 import toString from './js/to_string.test.mjs';
 import _throw from './js/throw.test.mjs';
 import urlencode from './js/urlencode.test.mjs';
 import parseJson from './json/parse_json.test.mjs';
 import stringifyJson from './json/stringify_json.test.mjs';
+import stringifyJsonCanonical from './json/stringify_json_canonical.test.mjs';
 import car from './lisp/car.test.mjs';
 import cdr from './lisp/cdr.test.mjs';
 import cons from './lisp/cons.test.mjs';
@@ -117,12 +144,21 @@ export default (describe) => {
   collectTree(describe);
   cons(describe);
   construct(describe);
+  dateAdd(describe);
+  dateDiff(describe);
+  dateOffset(describe);
+  dateTruncate(describe);
+  dateWithOffset(describe);
   debug(describe);
+  dedupe(describe);
+  _delete(describe);
   divide(describe);
+  duration(describe);
   effect(describe);
   endsWith(describe);
   eq(describe);
   equal(describe);
+  _eval(describe);
   filter(describe);
   flatten(describe);
   floor(describe);
@@ -134,6 +170,8 @@ export default (describe) => {
   gte(describe);
   has(describe);
   hash(describe);
+  hashmapFromIterator(describe);
+  hashsetFromIterator(describe);
   identity(describe);
   intersperse(describe);
   _if(describe);
@@ -145,10 +183,14 @@ export default (describe) => {
   keys(describe);
   length(describe);
   log(describe);
+  logScope(describe);
   lt(describe);
   lte(describe);
   map(describe);
+  match(describe);
   max(describe);
+  memo(describe);
+  memoStore(describe);
   merge(describe);
   min(describe);
   multiply(describe);
@@ -161,6 +203,7 @@ export default (describe) => {
   pow(describe);
   push(describe);
   pushFront(describe);
+  quote(describe);
   raise(describe);
   remainder(describe);
   replace(describe);
@@ -171,20 +214,31 @@ export default (describe) => {
   resolveQueryBranch(describe);
   resolveQueryLeaf(describe);
   resolveList(describe);
+  debounce(describe);
+  paginate(describe);
   resolveLoaderResults(describe);
+  sample(describe);
   resolveRecord(describe);
   resolveTree(describe);
+  reveal(describe);
   round(describe);
   scan(describe);
+  throttle(describe);
+  scopedLog(describe);
   sequence(describe);
   set(describe);
   skip(describe);
   slice(describe);
+  sort(describe);
+  sortBy(describe);
+  sortMerge(describe);
   split(describe);
   startsWith(describe);
   stringifyJson(describe);
+  stringifyJsonCanonical(describe);
   subtract(describe);
   take(describe);
+  template(describe);
   _throw(describe);
   toRequest(describe);
   toString(describe);
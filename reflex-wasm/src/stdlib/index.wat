@@ -16,11 +16,20 @@
   (@include "./collect_signal.wat")
   (@include "./collect_string.wat")
   (@include "./collect_tree.wat")
+  (@include "./date_add.wat")
+  (@include "./date_diff.wat")
+  (@include "./date_offset.wat")
+  (@include "./date_truncate.wat")
+  (@include "./date_with_offset.wat")
+  (@include "./dedupe.wat")
+  (@include "./delete.wat")
   (@include "./divide.wat")
+  (@include "./duration.wat")
   (@include "./effect.wat")
   (@include "./ends_with.wat")
   (@include "./eq.wat")
   (@include "./equal.wat")
+  (@include "./eval.wat")
   (@include "./filter.wat")
   (@include "./flatten.wat")
   (@include "./floor.wat")
@@ -33,8 +42,14 @@
   (@include "./gte.wat")
   (@include "./has.wat")
   (@include "./hash.wat")
+  (@include "./hashmap_from_iterator.wat")
+  (@include "./hashset_from_iterator.wat")
+  (@include "./handlers/debounce.wat")
+  (@include "./handlers/paginate.wat")
   (@include "./handlers/resolve_loader_results.wat")
+  (@include "./handlers/sample.wat")
   (@include "./handlers/scan.wat")
+  (@include "./handlers/throttle.wat")
   (@include "./handlers/to_request.wat")
   (@include "./handlers/variable.wat")
   (@include "./identity.wat")
@@ -50,14 +65,17 @@
   (@include "./js/is_finite.wat")
   (@include "./js/is_truthy.wat")
   (@include "./js/log.wat")
+  (@include "./js/log_scope.wat")
   (@include "./js/parse_date.wat")
   (@include "./js/parse_float.wat")
   (@include "./js/parse_int.wat")
+  (@include "./js/scoped_log.wat")
   (@include "./js/throw.wat")
   (@include "./js/to_string.wat")
   (@include "./js/urlencode.wat")
   (@include "./json/parse_json.wat")
   (@include "./json/stringify_json.wat")
+  (@include "./json/stringify_json_canonical.wat")
   (@include "./keys.wat")
   (@include "./length.wat")
   (@include "./lisp/car.wat")
@@ -66,7 +84,10 @@
   (@include "./lt.wat")
   (@include "./lte.wat")
   (@include "./map.wat")
+  (@include "./match.wat")
   (@include "./max.wat")
+  (@include "./memo.wat")
+  (@include "./memo_store.wat")
   (@include "./merge.wat")
   (@include "./min.wat")
   (@include "./multiply.wat")
@@ -75,6 +96,7 @@
   (@include "./pow.wat")
   (@include "./push.wat")
   (@include "./push_front.wat")
+  (@include "./quote.wat")
   (@include "./raise.wat")
   (@include "./remainder.wat")
   (@include "./replace.wat")
@@ -85,15 +107,20 @@
   (@include "./resolve_list.wat")
   (@include "./resolve_record.wat")
   (@include "./resolve_tree.wat")
+  (@include "./reveal.wat")
   (@include "./round.wat")
   (@include "./sequence.wat")
   (@include "./set.wat")
   (@include "./skip.wat")
   (@include "./slice.wat")
+  (@include "./sort.wat")
+  (@include "./sort_by.wat")
+  (@include "./sort_merge.wat")
   (@include "./split.wat")
   (@include "./starts_with.wat")
   (@include "./subtract.wat")
   (@include "./take.wat")
+  (@include "./template.wat")
   (@include "./unzip.wat")
   (@include "./values.wat")
   (@include "./zip.wat")
@@ -119,13 +146,23 @@
       $Stdlib_CollectTree
       $Stdlib_Cons
       $Stdlib_Construct
+      $Stdlib_DateAdd
+      $Stdlib_DateDiff
+      $Stdlib_DateOffset
+      $Stdlib_DateTruncate
+      $Stdlib_DateWithOffset
+      $Stdlib_Debounce
       $Stdlib_Debug
       $Stdlib_DecrementVariable
+      $Stdlib_Dedupe
+      $Stdlib_Delete
       $Stdlib_Divide
+      $Stdlib_Duration
       $Stdlib_Effect
       $Stdlib_EndsWith
       $Stdlib_Eq
       $Stdlib_Equal
+      $Stdlib_Eval
       $Stdlib_Filter
       $Stdlib_Flatten
       $Stdlib_Floor
@@ -138,6 +175,8 @@
       $Stdlib_Gte
       $Stdlib_Has
       $Stdlib_Hash
+      $Stdlib_HashmapFromIterator
+      $Stdlib_HashsetFromIterator
       $Stdlib_Identity
       $Stdlib_If
       $Stdlib_IfError
@@ -150,15 +189,20 @@
       $Stdlib_Keys
       $Stdlib_Length
       $Stdlib_Log
+      $Stdlib_LogScope
       $Stdlib_Lt
       $Stdlib_Lte
       $Stdlib_Map
+      $Stdlib_Match
       $Stdlib_Max
+      $Stdlib_Memo
+      $Stdlib_MemoStore
       $Stdlib_Merge
       $Stdlib_Min
       $Stdlib_Multiply
       $Stdlib_Not
       $Stdlib_Or
+      $Stdlib_Paginate
       $Stdlib_ParseDate
       $Stdlib_ParseFloat
       $Stdlib_ParseInt
@@ -166,6 +210,7 @@
       $Stdlib_Pow
       $Stdlib_Push
       $Stdlib_PushFront
+      $Stdlib_Quote
       $Stdlib_Raise
       $Stdlib_Remainder
       $Stdlib_Replace
@@ -179,18 +224,27 @@
       $Stdlib_ResolveQueryLeaf
       $Stdlib_ResolveRecord
       $Stdlib_ResolveTree
+      $Stdlib_Reveal
       $Stdlib_Round
+      $Stdlib_Sample
       $Stdlib_Scan
+      $Stdlib_ScopedLog
       $Stdlib_Sequence
       $Stdlib_Set
       $Stdlib_SetVariable
       $Stdlib_Skip
       $Stdlib_Slice
+      $Stdlib_Sort
+      $Stdlib_SortBy
+      $Stdlib_SortMerge
       $Stdlib_Split
       $Stdlib_StartsWith
       $Stdlib_StringifyJson
+      $Stdlib_StringifyJsonCanonical
       $Stdlib_Subtract
       $Stdlib_Take
+      $Stdlib_Template
+      $Stdlib_Throttle
       $Stdlib_Throw
       $Stdlib_ToRequest
       $Stdlib_ToString
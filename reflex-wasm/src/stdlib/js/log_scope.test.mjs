@@ -0,0 +1,30 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_LogScope', (test) => {
+    test('(String, Lambda)', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createLambda,
+      createPair,
+      createString,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.LogScope),
+          createPair(createString('foo'), createLambda(0, createInt(3))),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '3');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+  });
+};
@@ -0,0 +1,21 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@builtin $Stdlib_LogScope "LogScope"
+    (@args (@strict $self) (@strict $body))
+
+    (@impl
+      (i32.or (i32.const 0xFFFFFFFF))
+      (call $TermType::implements::apply)
+      (func $Stdlib_LogScope::impl::any::<apply> (param $self i32) (param $body i32) (param $state i32) (result i32 i32)
+        ;; Scope labels are applied to the function body at compile time, so at runtime the body is invoked directly
+        (call $Term::traits::apply (local.get $body) (call $Term::List::empty) (local.get $state))))
+
+    (@default
+      (func $Stdlib_LogScope::impl::default (param $self i32) (param $body i32) (param $state i32) (result i32 i32)
+        (call $Term::Signal::of
+          (call $Term::Condition::invalid_builtin_function_args
+            (global.get $Stdlib_LogScope)
+            (call $Term::List::create_pair (local.get $self) (local.get $body))))
+        (global.get $NULL)))))
@@ -8,9 +8,11 @@ pub mod format_error_message;
 pub mod is_finite;
 pub mod is_truthy;
 pub mod log;
+pub mod log_scope;
 pub mod parse_date;
 pub mod parse_float;
 pub mod parse_int;
+pub mod scoped_log;
 pub mod throw;
 pub mod to_string;
 pub mod urlencode;
@@ -22,9 +24,11 @@ pub use format_error_message::*;
 pub use is_finite::*;
 pub use is_truthy::*;
 pub use log::*;
+pub use log_scope::*;
 pub use parse_date::*;
 pub use parse_float::*;
 pub use parse_int::*;
+pub use scoped_log::*;
 pub use throw::*;
 pub use to_string::*;
 pub use urlencode::*;
@@ -95,5 +95,29 @@ export default (describe) => {
       assert.strictEqual(format(result), `Timestamp(${new Date(timestamp).toISOString()})`);
       assert.deepEqual(getStateDependencies(dependencies), []);
     });
+
+    test('(String) with UTC offset', (assert, {
+      createApplication,
+      createBuiltin,
+      createString,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      getTimestampMillis,
+      getTimestampOffset,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.ParseDate),
+        createUnitList(createString('2023-03-04T05:06:07.089-05:30')),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(format(result), 'Timestamp(2023-03-04T05:06:07.089-05:30)');
+      assert.strictEqual(getTimestampMillis(result), Date.UTC(2023, 2, 4, 10, 36, 7, 89));
+      assert.strictEqual(getTimestampOffset(result), -330);
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
   });
 };
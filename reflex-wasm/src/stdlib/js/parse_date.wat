@@ -8,13 +8,15 @@
     (@impl
       (i32.eq (global.get $TermType::Int))
       (func $Stdlib_ParseDate::impl::Int (param $self i32) (param $state i32) (result i32 i32)
-        (call $Term::Timestamp::new (call $Term::Int::get_value (local.get $self)))
+        (call $Term::Timestamp::new (call $Term::Int::get_value (local.get $self)) (i32.const 0))
         (global.get $NULL)))
 
     (@impl
       (i32.eq (global.get $TermType::Float))
       (func $Stdlib_ParseDate::impl::Float (param $self i32) (param $state i32) (result i32 i32)
-        (call $Term::Timestamp::new (i64.trunc_f64_s (call $Term::Float::get_value (local.get $self))))
+        (call $Term::Timestamp::new
+          (i64.trunc_f64_s (call $Term::Float::get_value (local.get $self)))
+          (i32.const 0))
         (global.get $NULL)))
 
     (@impl
@@ -35,7 +37,12 @@
                 (call $Term::String::get_length (local.get $self))))
             (i64.const 0xFFFFFFFFFFFFFFFF))
           (then
-            (call $Term::Timestamp::new (local.get $timestamp))
+            (call $Term::Timestamp::new
+              (local.get $timestamp)
+              ;; Retain any explicit UTC offset specified in the input string
+              (call $Utils::Date::parse_offset
+                (call $Term::String::get_offset (local.get $self))
+                (call $Term::String::get_length (local.get $self))))
             (global.get $NULL))
           (else
             (call $Stdlib_ParseDate::impl::default (local.get $self) (local.get $state))))))
@@ -7,10 +7,11 @@ use reflex::core::{uuid, ArgType, Arity, FunctionArity, Uid, Uuid};
 pub struct ParseFloat;
 impl ParseFloat {
     pub const UUID: Uuid = uuid!("55e1f68f-e20f-4fab-a6f3-3e38b3dbd6ad");
+    // Optional arguments are passed as variadic arguments
     const ARITY: FunctionArity<1, 0> = FunctionArity {
         required: [ArgType::Strict],
         optional: [],
-        variadic: None,
+        variadic: Some(ArgType::Strict),
     };
     pub fn arity(&self) -> Arity {
         Arity::from(&Self::ARITY)
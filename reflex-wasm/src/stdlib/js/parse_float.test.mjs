@@ -136,5 +136,94 @@ export default (describe) => {
         assert.deepEqual(getStateDependencies(dependencies), []);
       })();
     });
+
+    test('(String)', (assert, {
+      createApplication,
+      createBuiltin,
+      createString,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      for (const [input, expected] of [
+        ['3.142', '3.142'],
+        ['-3.142', '-3.142'],
+        ['+3.142', '3.142'],
+        ['  3.142\n', '3.142'],
+        ['3.142abc', '3.142'],
+        ['.5', '0.5'],
+        ['-.5', '-0.5'],
+        ['1.5e-3', '0.0015'],
+        ['1.2.3', '1.2'],
+        ['Infinity', 'Infinity'],
+        ['-Infinity', '-Infinity'],
+        ['Infinityx', 'Infinity'],
+        ['infinity', 'NaN'],
+        ['NaN', 'NaN'],
+        ['', 'NaN'],
+        ['.', 'NaN'],
+        ['e3', 'NaN'],
+      ]) {
+        const expression = createApplication(
+          createBuiltin(Stdlib.ParseFloat),
+          createUnitList(createString(input)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), expected, JSON.stringify(input));
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      }
+    });
+
+    test('(String, Record)', (assert, {
+      createApplication,
+      createBoolean,
+      createBuiltin,
+      createInt,
+      createPair,
+      createRecord,
+      createString,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      for (const [input, strict, expected] of [
+        ['3.142', true, '3.142'],
+        ['  -1.5e3\n', true, '-1500.0'],
+        ['Infinity', true, 'Infinity'],
+        ['3.142abc', true, 'NaN'],
+        ['3.142abc', false, '3.142'],
+        ['1e', true, 'NaN'],
+        ['Infinityx', true, 'NaN'],
+      ]) {
+        const expression = createApplication(
+          createBuiltin(Stdlib.ParseFloat),
+          createPair(
+            createString(input),
+            createRecord(createUnitList(createString('strict')), createUnitList(createBoolean(strict))),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), expected, JSON.stringify([input, strict]));
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      }
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.ParseFloat),
+          createPair(createString('3.142abc'), createInt(3)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(
+          format(result),
+          '{<InvalidFunctionArgsCondition:ParseFloat("3.142abc", 3)>}',
+        );
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
   });
 };
@@ -3,22 +3,50 @@
 ;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 (module
   (@builtin $Stdlib_ParseFloat "ParseFloat"
-    (@args (@strict $self))
+    ;; The optional options argument is passed as a variadic argument
+    (@args (@strict $self) (@variadic (@strict $optional_args)))
 
     (@impl
       (i32.eq (global.get $TermType::Int))
-      (func $Stdlib_ParseFloat::impl::Int (param $self i32) (param $state i32) (result i32 i32)
+      (i32.eq (global.get $TermType::List))
+      (func $Stdlib_ParseFloat::impl::Int::List (param $self i32) (param $optional_args i32) (param $state i32) (result i32 i32)
         (call $Term::Float::new (f64.convert_i64_s (call $Term::Int::get_value (local.get $self))))
         (global.get $NULL)))
 
     (@impl
       (i32.eq (global.get $TermType::Float))
-      (func $Stdlib_ParseFloat::impl::Float (param $self i32) (param $state i32) (result i32 i32)
+      (i32.eq (global.get $TermType::List))
+      (func $Stdlib_ParseFloat::impl::Float::List (param $self i32) (param $optional_args i32) (param $state i32) (result i32 i32)
         (local.get $self)
         (global.get $NULL)))
 
+    (@impl
+      (i32.eq (global.get $TermType::String))
+      (i32.eq (global.get $TermType::List))
+      (func $Stdlib_ParseFloat::impl::String::List (param $self i32) (param $optional_args i32) (param $state i32) (result i32 i32)
+        (local $strict i32)
+        (local $is_valid i32)
+        (call $Stdlib_ParseInt::parse_options (call $Stdlib_ParseInt::get_optional_arg (local.get $optional_args) (i32.const 0)))
+        (local.set $is_valid)
+        (local.set $strict)
+        (if
+          (i32.eqz (local.get $is_valid))
+          (then
+            (return
+              (call $Term::Signal::of
+                (call $Term::Condition::invalid_builtin_function_args
+                  (global.get $Stdlib_ParseFloat)
+                  (call $Term::List::push_front (local.get $optional_args) (local.get $self))))
+              (global.get $NULL))))
+        (call $Term::Float::new
+          (call $Utils::Float::parse_float
+            (call $Term::String::get_offset (local.get $self))
+            (call $Term::String::get_length (local.get $self))
+            (local.get $strict)))
+        (global.get $NULL)))
+
     (@default
-      (func $Stdlib_ParseFloat::impl::default (param $self i32) (param $state i32) (result i32 i32)
+      (func $Stdlib_ParseFloat::impl::default (param $self i32) (param $optional_args i32) (param $state i32) (result i32 i32)
         (call $Term::Signal::of
           (call $Term::Condition::invalid_builtin_function_args
             (global.get $Stdlib_ParseFloat)
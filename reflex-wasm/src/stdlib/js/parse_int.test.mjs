@@ -219,5 +219,163 @@ export default (describe) => {
         assert.deepEqual(getStateDependencies(dependencies), []);
       })();
     });
+
+    test('(String)', (assert, {
+      createApplication,
+      createBuiltin,
+      createString,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      for (const [input, expected] of [
+        ['0', '0'],
+        ['3', '3'],
+        ['-3', '-3'],
+        ['+3', '3'],
+        ['  42\n', '42'],
+        ['\u00a0\ufeff42', '42'],
+        ['12px', '12'],
+        ['3.142', '3'],
+        ['1e3', '1'],
+        ['007', '7'],
+        ['0x1F', '31'],
+        ['-0X1f', '-31'],
+        ['0x', 'NaN'],
+        ['0b101', '0'],
+        ['9007199254740991', '9007199254740991'],
+        ['', 'NaN'],
+        ['   ', 'NaN'],
+        ['-', 'NaN'],
+        ['abc', 'NaN'],
+        ['Infinity', 'NaN'],
+      ]) {
+        const expression = createApplication(
+          createBuiltin(Stdlib.ParseInt),
+          createUnitList(createString(input)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), expected, JSON.stringify(input));
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      }
+    });
+
+    test('(String, Int)', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createPair,
+      createString,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      for (const [input, radix, expected] of [
+        ['ff', 16, '255'],
+        ['0x1F', 16, '31'],
+        ['0x1F', 10, '0'],
+        ['101', 2, '5'],
+        ['1012', 2, '5'],
+        ['zz', 36, '1295'],
+        ['42', 0, '42'],
+        ['42', 1, 'NaN'],
+        ['42', 37, 'NaN'],
+        ['42', -16, 'NaN'],
+      ]) {
+        const expression = createApplication(
+          createBuiltin(Stdlib.ParseInt),
+          createPair(createString(input), createInt(radix)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), expected, JSON.stringify([input, radix]));
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      }
+    });
+
+    test('(String, Float)', (assert, {
+      createApplication,
+      createBuiltin,
+      createFloat,
+      createPair,
+      createString,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      for (const [input, radix, expected] of [
+        ['ff', 16.0, '255'],
+        ['777', 8.9, '511'],
+        ['42', NaN, '42'],
+        ['42', Infinity, '42'],
+        ['42', 1e100, 'NaN'],
+      ]) {
+        const expression = createApplication(
+          createBuiltin(Stdlib.ParseInt),
+          createPair(createString(input), createFloat(radix)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), expected, JSON.stringify([input, radix]));
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      }
+    });
+
+    test('(String, Nil, Record)', (assert, {
+      createApplication,
+      createBoolean,
+      createBuiltin,
+      createInt,
+      createNil,
+      createRecord,
+      createString,
+      createTriple,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      for (const [input, radix, strict, expected] of [
+        ['42', null, true, '42'],
+        ['  -42  ', null, true, '-42'],
+        ['0x1F', null, true, '31'],
+        ['12px', null, true, 'NaN'],
+        ['12px', null, false, '12'],
+        ['3.142', null, true, 'NaN'],
+        ['1012', 2, true, 'NaN'],
+        ['101', 2, true, '5'],
+      ]) {
+        const expression = createApplication(
+          createBuiltin(Stdlib.ParseInt),
+          createTriple(
+            createString(input),
+            radix === null ? createNil() : createInt(radix),
+            createRecord(createUnitList(createString('strict')), createUnitList(createBoolean(strict))),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), expected, JSON.stringify([input, radix, strict]));
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      }
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.ParseInt),
+          createTriple(createString('12px'), createNil(), createInt(3)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(
+          format(result),
+          '{<InvalidFunctionArgsCondition:ParseInt("12px", null, 3)>}',
+        );
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
   });
 };
@@ -2,18 +2,23 @@
 ;; SPDX-License-Identifier: Apache-2.0
 ;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 (module
+  (@const-string $Stdlib_ParseInt::OPTION_STRICT "strict")
+
   (@builtin $Stdlib_ParseInt "ParseInt"
-    (@args (@strict $self))
+    ;; The optional radix and options arguments are passed as variadic arguments
+    (@args (@strict $self) (@variadic (@strict $optional_args)))
 
     (@impl
       (i32.eq (global.get $TermType::Int))
-      (func $Stdlib_ParseInt::impl::Int (param $self i32) (param $state i32) (result i32 i32)
+      (i32.eq (global.get $TermType::List))
+      (func $Stdlib_ParseInt::impl::Int::List (param $self i32) (param $optional_args i32) (param $state i32) (result i32 i32)
         (local.get $self)
         (global.get $NULL)))
 
     (@impl
       (i32.eq (global.get $TermType::Float))
-      (func $Stdlib_ParseInt::impl::Float (param $self i32) (param $state i32) (result i32 i32)
+      (i32.eq (global.get $TermType::List))
+      (func $Stdlib_ParseInt::impl::Float::List (param $self i32) (param $optional_args i32) (param $state i32) (result i32 i32)
         (local $value f64)
         (if (result i32 i32)
           (i32.and
@@ -25,18 +30,166 @@
             (call $Term::Int::new (i64.trunc_f64_s (local.get $value)))
             (global.get $NULL))
           (else
-            (call $Stdlib_ParseInt::impl::default (local.get $self) (local.get $state))))))
+            (call $Stdlib_ParseInt::impl::default (local.get $self) (local.get $optional_args) (local.get $state))))))
 
     (@impl
       (i32.eq (global.get $TermType::Timestamp))
-      (func $Stdlib_ParseInt::impl::Timestamp (param $self i32) (param $state i32) (result i32 i32)
+      (i32.eq (global.get $TermType::List))
+      (func $Stdlib_ParseInt::impl::Timestamp::List (param $self i32) (param $optional_args i32) (param $state i32) (result i32 i32)
         (call $Term::Int::new (call $Term::Timestamp::get::millis (local.get $self)))
         (global.get $NULL)))
 
+    (@impl
+      (i32.eq (global.get $TermType::String))
+      (i32.eq (global.get $TermType::List))
+      (func $Stdlib_ParseInt::impl::String::List (param $self i32) (param $optional_args i32) (param $state i32) (result i32 i32)
+        (local $radix i32)
+        (local $strict i32)
+        (local $is_valid i32)
+        (call $Stdlib_ParseInt::parse_radix (call $Stdlib_ParseInt::get_optional_arg (local.get $optional_args) (i32.const 0)))
+        (local.set $is_valid)
+        (local.set $radix)
+        (if
+          (i32.eqz (local.get $is_valid))
+          (then
+            (return (call $Stdlib_ParseInt::error::invalid_args (local.get $self) (local.get $optional_args)))))
+        (call $Stdlib_ParseInt::parse_options (call $Stdlib_ParseInt::get_optional_arg (local.get $optional_args) (i32.const 1)))
+        (local.set $is_valid)
+        (local.set $strict)
+        (if
+          (i32.eqz (local.get $is_valid))
+          (then
+            (return (call $Stdlib_ParseInt::error::invalid_args (local.get $self) (local.get $optional_args)))))
+        (call $Stdlib_ParseInt::from_number
+          (call $Utils::Float::parse_int
+            (call $Term::String::get_offset (local.get $self))
+            (call $Term::String::get_length (local.get $self))
+            (local.get $radix)
+            (local.get $strict)))
+        (global.get $NULL)))
+
     (@default
-      (func $Stdlib_ParseInt::impl::default (param $self i32) (param $state i32) (result i32 i32)
+      (func $Stdlib_ParseInt::impl::default (param $self i32) (param $optional_args i32) (param $state i32) (result i32 i32)
         (call $Term::Signal::of
           (call $Term::Condition::invalid_builtin_function_args
             (global.get $Stdlib_ParseInt)
             (call $Term::List::of (local.get $self))))
-        (global.get $NULL)))))
+        (global.get $NULL))))
+
+  (func $Stdlib_ParseInt::error::invalid_args (param $self i32) (param $optional_args i32) (result i32 i32)
+    (call $Term::Signal::of
+      (call $Term::Condition::invalid_builtin_function_args
+        (global.get $Stdlib_ParseInt)
+        (call $Term::List::push_front (local.get $optional_args) (local.get $self))))
+    (global.get $NULL))
+
+  (func $Stdlib_ParseInt::get_optional_arg (param $optional_args i32) (param $index i32) (result i32)
+    ;; Omitted optional arguments are returned as the null pointer
+    (if (result i32)
+      (i32.lt_u (local.get $index) (call $Term::List::get_length (local.get $optional_args)))
+      (then
+        (call $Term::List::get_item (local.get $optional_args) (local.get $index)))
+      (else
+        (global.get $NULL))))
+
+  (func $Stdlib_ParseInt::is_omitted_arg (param $value i32) (result i32)
+    ;; Optional arguments that are either omitted or null are treated as unspecified
+    (if (result i32)
+      (i32.eq (local.get $value) (global.get $NULL))
+      (then
+        (global.get $TRUE))
+      (else
+        (call $Term::Nil::is (local.get $value)))))
+
+  (func $Stdlib_ParseInt::parse_radix (param $value i32) (result i32 i32)
+    ;; Returns the radix to pass to the host parser (where 0 selects the default radix detection and 0xFFFFFFFF is an
+    ;; invalid radix that results in NaN), followed by a boolean indicating whether the argument was valid
+    (local $radix f64)
+    (if (result i32 i32)
+      (call $Stdlib_ParseInt::is_omitted_arg (local.get $value))
+      (then
+        (i32.const 0)
+        (global.get $TRUE))
+      (else
+        (if (result i32 i32)
+          (call $Term::Int::is (local.get $value))
+          (then
+            (call $Stdlib_ParseInt::get_radix_value (call $Term::Int::get_value (local.get $value)))
+            (global.get $TRUE))
+          (else
+            (if (result i32 i32)
+              (call $Term::Float::is (local.get $value))
+              (then
+                ;; Non-finite radixes fall back to the default radix detection, as per JavaScript semantics
+                (if (result i32 i32)
+                  (call $Utils::f64::is_finite (local.tee $radix (call $Term::Float::get_value (local.get $value))))
+                  (then
+                    ;; Check the range before truncating to ensure the conversion cannot trap
+                    (if (result i32)
+                      (i32.and
+                        (f64.ge (local.tee $radix (f64.trunc (local.get $radix))) (f64.const 0))
+                        (f64.lt (local.get $radix) (f64.const 37)))
+                      (then
+                        (i32.trunc_f64_u (local.get $radix)))
+                      (else
+                        (i32.const 0xFFFFFFFF)))
+                    (global.get $TRUE))
+                  (else
+                    (i32.const 0)
+                    (global.get $TRUE))))
+              (else
+                (i32.const 0)
+                (global.get $FALSE))))))))
+
+  (func $Stdlib_ParseInt::get_radix_value (param $value i64) (result i32)
+    ;; Out-of-range values are mapped to an invalid radix rather than being wrapped into the valid range
+    (select
+      (i32.wrap_i64 (local.get $value))
+      (i32.const 0xFFFFFFFF)
+      (i64.lt_u (local.get $value) (i64.const 37))))
+
+  (func $Stdlib_ParseInt::parse_options (param $value i32) (result i32 i32)
+    ;; Returns the strict parsing flag, followed by a boolean indicating whether the options argument was valid
+    (local $strict i32)
+    (if (result i32 i32)
+      (call $Stdlib_ParseInt::is_omitted_arg (local.get $value))
+      (then
+        (global.get $FALSE)
+        (global.get $TRUE))
+      (else
+        (if (result i32 i32)
+          (call $Term::Record::is (local.get $value))
+          (then
+            (local.set $strict
+              (call $Term::Record::traits::get (local.get $value) (global.get $Stdlib_ParseInt::OPTION_STRICT)))
+            (if (result i32 i32)
+              (call $Stdlib_ParseInt::is_omitted_arg (local.get $strict))
+              (then
+                (global.get $FALSE)
+                (global.get $TRUE))
+              (else
+                (if (result i32 i32)
+                  (call $Term::Boolean::is (local.get $strict))
+                  (then
+                    (call $Term::Boolean::get::value (local.get $strict))
+                    (global.get $TRUE))
+                  (else
+                    (global.get $FALSE)
+                    (global.get $FALSE))))))
+          (else
+            (global.get $FALSE)
+            (global.get $FALSE))))))
+
+  (func $Stdlib_ParseInt::from_number (param $value f64) (result i32)
+    ;; Values that can be represented exactly as integers are returned as Int terms, all other values (including NaN)
+    ;; are returned as Float terms
+    (if (result i32)
+      (i32.and
+        (call $Utils::f64::is_finite (local.get $value))
+        (i32.and
+          (f64.eq (local.get $value) (f64.trunc (local.get $value)))
+          (f64.le (f64.abs (local.get $value)) (f64.const 9007199254740991))))
+      (then
+        (call $Term::Int::new (i64.trunc_f64_s (local.get $value))))
+      (else
+        (call $Term::Float::new (local.get $value))))))
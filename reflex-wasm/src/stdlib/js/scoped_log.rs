@@ -0,0 +1,23 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{uuid, ArgType, Arity, FunctionArity, Uid, Uuid};
+
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct ScopedLog;
+impl ScopedLog {
+    pub const UUID: Uuid = uuid!("8a4d2c6e-3b1f-4e7a-a9c5-2f6e8d0b4c73");
+    const ARITY: FunctionArity<2, 0> = FunctionArity {
+        required: [ArgType::Strict, ArgType::Eager],
+        optional: [],
+        variadic: Some(ArgType::Eager),
+    };
+    pub fn arity(&self) -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for ScopedLog {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
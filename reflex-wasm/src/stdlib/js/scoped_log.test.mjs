@@ -0,0 +1,65 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_ScopedLog', (test) => {
+    test('(List, Int)', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createPair,
+      createString,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.ScopedLog),
+          createPair(createUnitList(createString('foo')), createInt(3)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '3');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('(List, Int, Int, Signal)', (assert, {
+      createApplication,
+      createBuiltin,
+      createErrorCondition,
+      createInt,
+      createList,
+      createPair,
+      createSignal,
+      createString,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.ScopedLog),
+          createList([
+            createPair(createString('foo'), createString('bar')),
+            createInt(3),
+            createInt(4),
+            createApplication(
+              createBuiltin(Stdlib.Identity),
+              createUnitList(createSignal(createErrorCondition(createString('foo')))),
+            ),
+          ]),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '3');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+  });
+};
@@ -62,7 +62,7 @@
               (else
                 (if (result i32)
                   ;; If the AggregateError object "errors" field is not a list, bail out
-                  (i32.eqz (call $Term::List::is (local.get $errors)))
+                  (i32.eqz (call $Term::is_list (local.get $errors)))
                   (then
                     (global.get $NULL))
                   (else
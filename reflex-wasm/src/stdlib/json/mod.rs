@@ -3,6 +3,8 @@
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 pub mod parse_json;
 pub mod stringify_json;
+pub mod stringify_json_canonical;
 
 pub use parse_json::*;
 pub use stringify_json::*;
+pub use stringify_json_canonical::*;
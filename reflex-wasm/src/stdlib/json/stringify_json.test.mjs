@@ -294,6 +294,33 @@ export default (describe) => {
         assert.strictEqual(format(result), JSON.stringify('-2147483647.0'));
         assert.deepEqual(getStateDependencies(dependencies), []);
       })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.StringifyJson),
+          createUnitList(createFloat(1e21)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), JSON.stringify('1e21'));
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.StringifyJson),
+          createUnitList(createFloat(-1.5e-7)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), JSON.stringify('-1.5e-7'));
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.StringifyJson),
+          createUnitList(createFloat(0.1 + 0.2)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), JSON.stringify('0.30000000000000004'));
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
       (() => {
         const expression = createApplication(
           createBuiltin(Stdlib.StringifyJson),
@@ -616,5 +643,25 @@ export default (describe) => {
       );
       assert.deepEqual(getStateDependencies(dependencies), []);
     });
+
+    test('(Bytes)', (assert, {
+      createApplication,
+      createBuiltin,
+      createBytes,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.StringifyJson),
+        createUnitList(createBytes([102, 111, 111, 98, 97, 114])),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(format(result), JSON.stringify(JSON.stringify('Zm9vYmFy')));
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
   });
 };
@@ -0,0 +1,232 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_StringifyJsonCanonical', (test) => {
+    test('(Int)', (assert, {
+      createApplication,
+      createBuiltin,
+      createDuration,
+      createInt,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.StringifyJsonCanonical),
+          createUnitList(createInt(-3)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), JSON.stringify('-3'));
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.StringifyJsonCanonical),
+          createUnitList(createInt(9007199254740993n)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), JSON.stringify('9007199254740992'));
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.StringifyJsonCanonical),
+          createUnitList(createDuration(1500)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), JSON.stringify('1500'));
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('(Float)', (assert, {
+      createApplication,
+      createBuiltin,
+      createFloat,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      for (const [value, expected] of [
+        [0.0, '0'],
+        [-0.0, '0'],
+        [1.0, '1'],
+        [0.1, '0.1'],
+        [123.456, '123.456'],
+        [1e21, '1e+21'],
+        [0.0000001, '1e-7'],
+        [5e-324, '5e-324'],
+      ]) {
+        const expression = createApplication(
+          createBuiltin(Stdlib.StringifyJsonCanonical),
+          createUnitList(createFloat(value)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), JSON.stringify(expected));
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      }
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.StringifyJsonCanonical),
+          createUnitList(createFloat(Infinity)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(
+          format(result),
+          '{<InvalidFunctionArgsCondition:StringifyJsonCanonical(Infinity)>}',
+        );
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('(String)', (assert, {
+      createApplication,
+      createBuiltin,
+      createString,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      for (const value of ['', 'foo', 'foo "bar" \\ \b\f\n\r\t\u001f é 😀']) {
+        const expression = createApplication(
+          createBuiltin(Stdlib.StringifyJsonCanonical),
+          createUnitList(createString(value)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), JSON.stringify(JSON.stringify(value)));
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      }
+    });
+
+    test('(List)', (assert, {
+      createApplication,
+      createBuiltin,
+      createEmptyList,
+      createFloat,
+      createInt,
+      createString,
+      createTriple,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.StringifyJsonCanonical),
+          createUnitList(createEmptyList()),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), JSON.stringify('[]'));
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.StringifyJsonCanonical),
+          createUnitList(createTriple(createInt(3), createFloat(1.5), createString('foo'))),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), JSON.stringify('[3,1.5,"foo"]'));
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('(Record)', (assert, {
+      createApplication,
+      createBoolean,
+      createBuiltin,
+      createEmptyList,
+      createInt,
+      createNil,
+      createPair,
+      createRecord,
+      createString,
+      createTriple,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.StringifyJsonCanonical),
+          createUnitList(createRecord(createEmptyList(), createEmptyList())),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), JSON.stringify('{}'));
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.StringifyJsonCanonical),
+          createUnitList(
+            createRecord(
+              createTriple(createString('foo'), createString('bar'), createString('baz')),
+              createTriple(
+                createRecord(
+                  createPair(createString('b'), createString('a')),
+                  createPair(createNil(), createBoolean(true)),
+                ),
+                createEmptyList(),
+                createInt(5),
+              ),
+            ),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(
+          format(result),
+          JSON.stringify('{"bar":[],"baz":5,"foo":{"a":true,"b":null}}'),
+        );
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        // Keys are ordered by UTF-16 code units rather than by Unicode code points
+        const expression = createApplication(
+          createBuiltin(Stdlib.StringifyJsonCanonical),
+          createUnitList(
+            createRecord(
+              createTriple(createString('\ue000'), createString('😀'), createString('a')),
+              createTriple(createInt(1), createInt(2), createInt(3)),
+            ),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), JSON.stringify('{"a":3,"😀":2,"\ue000":1}'));
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.StringifyJsonCanonical),
+          createUnitList(
+            createRecord(
+              createTriple(createString('foo'), createString('bar'), createString('baz')),
+              createTriple(createInt(3), createBuiltin(Stdlib.Identity), createInt(5)),
+            ),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(
+          format(result),
+          '{<InvalidFunctionArgsCondition:StringifyJsonCanonical({ "foo": 3, "bar": Identity, "baz": 5 })>}',
+        );
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+  });
+};
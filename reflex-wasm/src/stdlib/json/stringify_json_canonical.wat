@@ -0,0 +1,356 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@builtin $Stdlib_StringifyJsonCanonical "StringifyJsonCanonical"
+    (@args (@strict $self))
+
+    (@impl
+      (call $TermType::implements::to_json)
+      (func $Stdlib_StringifyJsonCanonical::impl::<to_json> (param $self i32) (param $state i32) (result i32 i32)
+        (local $output i32)
+        (local $offset i32)
+        ;; Allocate a new dynamic string term
+        (local.set $output (call $Term::String::allocate_unsized))
+        ;; Attempt to serialize the input term into the newly-allocated string contents
+        (local.set $offset
+          (call $Stdlib_StringifyJsonCanonical::write_json
+            (local.get $self)
+            (call $Term::String::get_char_pointer (local.get $output) (i32.const 0))))
+        ;; Determine whether a non-serializable term was encountered
+        (if (result i32 i32)
+          (i32.ne (global.get $TRUE))
+          (then
+            ;; If a non-serializable term was encountered, dispose of the temporary dynamic string
+            ;; FIXME: improve dynamic string deallocation
+            ;; Reset the heap space occupied by the temporary dynamic string with zeroes
+            (memory.fill
+              (local.get $output)
+              (i32.const 0)
+              (i32.sub (call $Allocator::get_offset) (local.get $output)))
+            ;; Reset the allocator heap offset
+            (call $Allocator::set_offset (local.get $output))
+            ;; Return the default error signal
+            (call $Stdlib_StringifyJsonCanonical::impl::default (local.get $self) (local.get $state)))
+          (else
+            ;; Initialize the dynamic string term
+            (call $Term::String::init_unsized
+              (local.get $output)
+              (i32.sub
+                (local.get $offset)
+                (call $Term::String::get_char_pointer (local.get $output) (i32.const 0))))
+            (global.get $NULL)))))
+
+    (@default
+      (func $Stdlib_StringifyJsonCanonical::impl::default (param $self i32) (param $state i32) (result i32 i32)
+        (call $Term::Signal::of
+          (call $Term::Condition::invalid_builtin_function_args
+            (global.get $Stdlib_StringifyJsonCanonical)
+            (call $Term::List::of (local.get $self))))
+        (global.get $NULL))))
+
+  (func $Stdlib_StringifyJsonCanonical::write_json (param $value i32) (param $offset i32) (result i32 i32)
+    (local $term_type i32)
+    ;; Serialize the target of any pointer terms
+    (if
+      (call $Term::Pointer::is (local.get $value))
+      (then
+        (local.set $value (call $Term::Pointer::dereference (local.get $value)))))
+    (local.set $term_type (call $Term::get_type (local.get $value)))
+    ;; Container terms and numeric terms are serialized canonically, whereas all other serializable terms have a single
+    ;; possible JSON representation and can therefore defer to their standard JSON serialization
+    (@switch
+      (@list
+        (@list
+          (i32.eq (local.get $term_type) (global.get $TermType::Int))
+          (return
+            (call $Stdlib_StringifyJsonCanonical::write_number
+              (f64.convert_i64_s (call $Term::Int::get::value (local.get $value)))
+              (local.get $offset))))
+        (@list
+          (i32.eq (local.get $term_type) (global.get $TermType::Float))
+          (return
+            (call $Stdlib_StringifyJsonCanonical::write_number
+              (call $Term::Float::get::value (local.get $value))
+              (local.get $offset))))
+        (@list
+          (i32.eq (local.get $term_type) (global.get $TermType::Duration))
+          (return
+            (call $Stdlib_StringifyJsonCanonical::write_number
+              (f64.convert_i64_s (call $Term::Duration::get::millis (local.get $value)))
+              (local.get $offset))))
+        (@list
+          (i32.eq (local.get $term_type) (global.get $TermType::String))
+          (return
+            (global.get $TRUE)
+            (call $Stdlib_StringifyJsonCanonical::write_string
+              (call $Term::String::get_offset (local.get $value))
+              (call $Term::String::get_length (local.get $value))
+              (local.get $offset))))
+        (@list
+          (call $TermType::is_list (local.get $term_type))
+          (return (call $Stdlib_StringifyJsonCanonical::write_list (local.get $value) (local.get $offset))))
+        (@list
+          (i32.eq (local.get $term_type) (global.get $TermType::Record))
+          (return (call $Stdlib_StringifyJsonCanonical::write_record (local.get $value) (local.get $offset))))
+        (@list
+          (call $TermType::implements::to_json (local.get $term_type))
+          (return (call $Term::traits::to_json (local.get $value) (local.get $offset)))))
+      ;; If the term does not support JSON serialization, bail out
+      (global.get $FALSE)
+      (local.get $offset)))
+
+  (func $Stdlib_StringifyJsonCanonical::write_number (param $value f64) (param $offset i32) (result i32 i32)
+    (if (result i32 i32)
+      ;; Non-finite numbers have no JSON representation, so bail out
+      (i32.eqz (call $Utils::f64::is_finite (local.get $value)))
+      (then
+        (global.get $FALSE)
+        (local.get $offset))
+      (else
+        ;; Put the success marker on the stack
+        (global.get $TRUE)
+        ;; Write the ECMAScript representation of the number to the output string and return the updated offset
+        (i32.add
+          (local.get $offset)
+          (call $Utils::Float::to_canonical_string (local.get $value) (local.get $offset))))))
+
+  (func $Stdlib_StringifyJsonCanonical::write_string (param $source_offset i32) (param $source_length i32) (param $offset i32) (result i32)
+    (local $index i32)
+    (local $char i32)
+    (local $escape_code i32)
+    ;; Allocate one byte for the opening quote and write the character to the output
+    (call $Allocator::extend (local.get $offset) (i32.const 1))
+    (i32.store8 (local.get $offset) (@char "\""))
+    (local.set $offset (i32.add (local.get $offset) (i32.const 1)))
+    (if
+      (local.get $source_length)
+      (then
+        ;; Iterate through the source string one byte at a time
+        (loop $LOOP
+          (local.set $char (i32.load8_u (i32.add (local.get $source_offset) (local.get $index))))
+          (if
+            ;; If the character has a short escape sequence, write the backslash-escaped form to the output
+            (local.tee $escape_code (call $Utils::u8::get_json_escape_code (local.get $char)))
+            (then
+              (call $Allocator::extend (local.get $offset) (i32.const 2))
+              (i32.store8 offset=0 (local.get $offset) (@char "\\"))
+              (i32.store8 offset=1 (local.get $offset) (local.get $escape_code))
+              (local.set $offset (i32.add (local.get $offset) (i32.const 2))))
+            (else
+              (if
+                ;; If the character is any other control character, write a lowercase unicode escape sequence
+                (i32.lt_u (local.get $char) (i32.const 0x20))
+                (then
+                  (call $Allocator::extend (local.get $offset) (i32.const 6))
+                  (i32.store8 offset=0 (local.get $offset) (@char "\\"))
+                  (i32.store8 offset=1 (local.get $offset) (@char "u"))
+                  (i32.store8 offset=2 (local.get $offset) (@char "0"))
+                  (i32.store8 offset=3 (local.get $offset) (@char "0"))
+                  (i32.store8 offset=4 (local.get $offset) (i32.add (@char "0") (i32.shr_u (local.get $char) (i32.const 4))))
+                  (i32.store8 offset=5
+                    (local.get $offset)
+                    (call $Stdlib_StringifyJsonCanonical::get_hex_digit (i32.and (local.get $char) (i32.const 0x0F))))
+                  (local.set $offset (i32.add (local.get $offset) (i32.const 6))))
+                (else
+                  ;; Otherwise copy the byte to the output unmodified
+                  (call $Allocator::extend (local.get $offset) (i32.const 1))
+                  (i32.store8 (local.get $offset) (local.get $char))
+                  (local.set $offset (i32.add (local.get $offset) (i32.const 1)))))))
+          ;; Continue with the next byte
+          (br_if $LOOP
+            (i32.lt_u (local.tee $index (i32.add (local.get $index) (i32.const 1))) (local.get $source_length))))))
+    ;; Allocate one byte for the closing quote, write the character to the output and return the updated offset
+    (call $Allocator::extend (local.get $offset) (i32.const 1))
+    (i32.store8 (local.get $offset) (@char "\""))
+    (i32.add (local.get $offset) (i32.const 1)))
+
+  (func $Stdlib_StringifyJsonCanonical::get_hex_digit (param $value i32) (result i32)
+    (select
+      (i32.add (@char "0") (local.get $value))
+      (i32.add (@char "a") (i32.sub (local.get $value) (i32.const 10)))
+      (i32.lt_u (local.get $value) (i32.const 10))))
+
+  (func $Stdlib_StringifyJsonCanonical::write_list (param $self i32) (param $offset i32) (result i32 i32)
+    (local $length i32)
+    (local $index i32)
+    ;; Allocate one byte for the opening bracket and write the character to the output
+    (call $Allocator::extend (local.get $offset) (i32.const 1))
+    (i32.store8 (local.get $offset) (@char "["))
+    (local.set $offset (i32.add (local.get $offset) (i32.const 1)))
+    (if
+      (local.tee $length (call $Term::List::get_length (local.get $self)))
+      (then
+        ;; Iterate through the list items
+        (loop $LOOP
+          (if
+            ;; If this is not the first item, allocate one byte for the comma separator and write it to the output
+            (local.get $index)
+            (then
+              (call $Allocator::extend (local.get $offset) (i32.const 1))
+              (i32.store8 (local.get $offset) (@char ","))
+              (local.set $offset (i32.add (local.get $offset) (i32.const 1)))))
+          ;; Write the current item to the output and store the updated offset
+          (local.set $offset
+            (call $Stdlib_StringifyJsonCanonical::write_json
+              (call $Term::List::get_item (local.get $self) (local.get $index))
+              (local.get $offset)))
+          ;; If the item serialization failed, bail out
+          (if
+            (i32.ne (global.get $TRUE))
+            (then
+              (return (global.get $FALSE) (local.get $offset))))
+          ;; Continue with the next item
+          (br_if $LOOP
+            (i32.lt_u (local.tee $index (i32.add (local.get $index) (i32.const 1))) (local.get $length))))))
+    ;; Allocate one byte for the closing bracket and write the character to the output
+    (call $Allocator::extend (local.get $offset) (i32.const 1))
+    (i32.store8 (local.get $offset) (@char "]"))
+    ;; Put the success marker on the stack
+    (global.get $TRUE)
+    ;; Return the updated offset, taking into account the final closing bracket
+    (i32.add (local.get $offset) (i32.const 1)))
+
+  (func $Stdlib_StringifyJsonCanonical::write_record (param $self i32) (param $offset i32) (result i32 i32)
+    (local $keys i32)
+    (local $values i32)
+    (local $num_keys i32)
+    (local $index i32)
+    (local $count i32)
+    (local $previous i32)
+    (local $next i32)
+    (local $key i32)
+    (local.set $keys (call $Term::Record::get::keys (local.get $self)))
+    (local.set $values (call $Term::Record::get::values (local.get $self)))
+    (local.set $num_keys (call $Term::List::get_length (local.get $keys)))
+    ;; If any of the keys are not strings, bail out
+    (if
+      (local.get $num_keys)
+      (then
+        (loop $LOOP
+          (if
+            (i32.eqz (call $Term::Record::is_valid_json_key (call $Term::List::get_item (local.get $keys) (local.get $index))))
+            (then
+              (return (global.get $FALSE) (local.get $offset))))
+          (br_if $LOOP (i32.lt_u (local.tee $index (i32.add (local.get $index) (i32.const 1))) (local.get $num_keys))))))
+    ;; Allocate one byte for the opening brace and write the character to the output
+    (call $Allocator::extend (local.get $offset) (i32.const 1))
+    (i32.store8 (local.get $offset) (@char "{"))
+    (local.set $offset (i32.add (local.get $offset) (i32.const 1)))
+    (if
+      (local.get $num_keys)
+      (then
+        ;; Emit the fields in key order, selecting the smallest key that follows the previously-emitted key on each
+        ;; iteration (this avoids allocating a temporary sorted copy of the keys)
+        (local.set $previous (global.get $NULL))
+        (loop $LOOP
+          ;; Find the smallest remaining key
+          (local.set $next (global.get $NULL))
+          (local.set $index (i32.const 0))
+          (loop $INNER
+            (local.set $key (call $Term::List::get_item (local.get $keys) (local.get $index)))
+            (if
+              (i32.and
+                (i32.or
+                  (i32.eq (global.get $NULL) (local.get $previous))
+                  (call $Stdlib_StringifyJsonCanonical::is_key_before
+                    (call $Term::List::get_item (local.get $keys) (local.get $previous))
+                    (local.get $key)))
+                (i32.or
+                  (i32.eq (global.get $NULL) (local.get $next))
+                  (call $Stdlib_StringifyJsonCanonical::is_key_before
+                    (local.get $key)
+                    (call $Term::List::get_item (local.get $keys) (local.get $next)))))
+              (then
+                (local.set $next (local.get $index))))
+            (br_if $INNER
+              (i32.lt_u (local.tee $index (i32.add (local.get $index) (i32.const 1))) (local.get $num_keys))))
+          (if
+            ;; If this is not the first field, allocate one byte for the comma separator and write it to the output
+            (local.get $count)
+            (then
+              (call $Allocator::extend (local.get $offset) (i32.const 1))
+              (i32.store8 (local.get $offset) (@char ","))
+              (local.set $offset (i32.add (local.get $offset) (i32.const 1)))))
+          ;; Write the key to the output
+          (local.set $key (call $Term::List::get_item (local.get $keys) (local.get $next)))
+          (local.set $offset
+            (call $Stdlib_StringifyJsonCanonical::write_string
+              (call $Term::String::get_offset (local.get $key))
+              (call $Term::String::get_length (local.get $key))
+              (local.get $offset)))
+          ;; Allocate one byte for the colon separator and write the character to the output
+          (call $Allocator::extend (local.get $offset) (i32.const 1))
+          (i32.store8 (local.get $offset) (@char ":"))
+          ;; Write the value to the output and store the updated offset
+          (local.set $offset
+            (call $Stdlib_StringifyJsonCanonical::write_json
+              (call $Term::List::get_item (local.get $values) (local.get $next))
+              ;; The target offset is incremented to reflect the preceding 1-byte colon separator
+              (i32.add (local.get $offset) (i32.const 1))))
+          ;; If the value serialization failed, bail out
+          (if
+            (i32.ne (global.get $TRUE))
+            (then
+              (return (global.get $FALSE) (local.get $offset))))
+          (local.set $previous (local.get $next))
+          ;; Continue with the next field
+          (br_if $LOOP
+            (i32.lt_u (local.tee $count (i32.add (local.get $count) (i32.const 1))) (local.get $num_keys))))))
+    ;; Allocate one byte for the closing brace and write the character to the output
+    (call $Allocator::extend (local.get $offset) (i32.const 1))
+    (i32.store8 (local.get $offset) (@char "}"))
+    ;; Put the success marker on the stack
+    (global.get $TRUE)
+    ;; Return the updated offset, taking into account the final closing brace
+    (i32.add (local.get $offset) (i32.const 1)))
+
+  (func $Stdlib_StringifyJsonCanonical::is_key_before (param $left i32) (param $right i32) (result i32)
+    (local $left_offset i32)
+    (local $right_offset i32)
+    (local $left_length i32)
+    (local $right_length i32)
+    (local $length i32)
+    (local $index i32)
+    (local $left_byte i32)
+    (local $right_byte i32)
+    (local.set $left_offset (call $Term::String::get_offset (local.get $left)))
+    (local.set $right_offset (call $Term::String::get_offset (local.get $right)))
+    (local.set $left_length (call $Term::String::get_length (local.get $left)))
+    (local.set $right_length (call $Term::String::get_length (local.get $right)))
+    (local.set $length
+      (select
+        (local.get $left_length)
+        (local.get $right_length)
+        (i32.lt_u (local.get $left_length) (local.get $right_length))))
+    (if
+      (local.get $length)
+      (then
+        (loop $LOOP
+          (if
+            ;; Compare the first differing byte of the two UTF-8 strings
+            (i32.ne
+              (local.tee $left_byte (i32.load8_u (i32.add (local.get $left_offset) (local.get $index))))
+              (local.tee $right_byte (i32.load8_u (i32.add (local.get $right_offset) (local.get $index)))))
+            (then
+              (return
+                (i32.lt_u
+                  (call $Stdlib_StringifyJsonCanonical::get_utf16_byte_weight (local.get $left_byte))
+                  (call $Stdlib_StringifyJsonCanonical::get_utf16_byte_weight (local.get $right_byte))))))
+          (br_if $LOOP (i32.lt_u (local.tee $index (i32.add (local.get $index) (i32.const 1))) (local.get $length))))))
+    ;; If one key is a prefix of the other, the shorter key comes first
+    (i32.lt_u (local.get $left_length) (local.get $right_length)))
+
+  (func $Stdlib_StringifyJsonCanonical::get_utf16_byte_weight (param $value i32) (result i32)
+    ;; UTF-8 byte order matches code point order, which only differs from UTF-16 code unit order in that characters
+    ;; beyond the Basic Multilingual Plane (four-byte sequences, lead bytes 0xF0-0xF4) are encoded as surrogate pairs,
+    ;; which sort before the U+E000-U+FFFF range (three-byte sequences, lead bytes 0xEE-0xEF). Given that the first
+    ;; differing byte of two valid UTF-8 strings is either a pair of lead bytes or a pair of continuation bytes that
+    ;; share the same lead byte, it suffices to reorder these lead bytes accordingly.
+    (select
+      (select
+        (i32.sub (local.get $value) (i32.const 2))
+        (i32.add (local.get $value) (i32.const 5))
+        (i32.ge_u (local.get $value) (i32.const 0xF0)))
+      (local.get $value)
+      (i32.ge_u (local.get $value) (i32.const 0xEE)))))
@@ -45,6 +45,62 @@
             (f64.convert_i64_s (call $Term::Int::get::value (local.get $other)))))
         (global.get $NULL)))
 
+    (@impl
+      (i32.eq (global.get $TermType::Timestamp))
+      (i32.eq (global.get $TermType::Timestamp))
+      (func $Stdlib_Lt::impl::Timestamp::Timestamp (param $self i32) (param $other i32) (param $state i32) (result i32 i32)
+        (call $Term::Boolean::new
+          (i64.lt_s
+            (call $Term::Timestamp::get::millis (local.get $self))
+            (call $Term::Timestamp::get::millis (local.get $other))))
+        (global.get $NULL)))
+
+    (@impl
+      (i32.eq (global.get $TermType::Duration))
+      (i32.eq (global.get $TermType::Duration))
+      (func $Stdlib_Lt::impl::Duration::Duration (param $self i32) (param $other i32) (param $state i32) (result i32 i32)
+        (call $Term::Boolean::new
+          (i64.lt_s
+            (call $Term::Duration::get::millis (local.get $self))
+            (call $Term::Duration::get::millis (local.get $other))))
+        (global.get $NULL)))
+
+    (@impl
+      (i32.eq (global.get $TermType::BigInt))
+      (i32.eq (global.get $TermType::BigInt))
+      (func $Stdlib_Lt::impl::BigInt::BigInt (param $self i32) (param $other i32) (param $state i32) (result i32 i32)
+        (call $Term::Boolean::new
+          (i32.lt_s
+            (call $Term::BigInt::compare
+              (local.get $self)
+              (local.get $other))
+            (i32.const 0)))
+        (global.get $NULL)))
+
+    (@impl
+      (i32.eq (global.get $TermType::BigInt))
+      (i32.eq (global.get $TermType::Int))
+      (func $Stdlib_Lt::impl::BigInt::Int (param $self i32) (param $other i32) (param $state i32) (result i32 i32)
+        (call $Term::Boolean::new
+          (i32.lt_s
+            (call $Term::BigInt::compare
+              (local.get $self)
+              (call $Term::BigInt::from_i64 (call $Term::Int::get::value (local.get $other))))
+            (i32.const 0)))
+        (global.get $NULL)))
+
+    (@impl
+      (i32.eq (global.get $TermType::Int))
+      (i32.eq (global.get $TermType::BigInt))
+      (func $Stdlib_Lt::impl::Int::BigInt (param $self i32) (param $other i32) (param $state i32) (result i32 i32)
+        (call $Term::Boolean::new
+          (i32.lt_s
+            (call $Term::BigInt::compare
+              (call $Term::BigInt::from_i64 (call $Term::Int::get::value (local.get $self)))
+              (local.get $other))
+            (i32.const 0)))
+        (global.get $NULL)))
+
     (@default
       (func $Stdlib_Lt::impl::default (param $self i32) (param $other i32) (param $state i32) (result i32 i32)
         (call $Term::Signal::of
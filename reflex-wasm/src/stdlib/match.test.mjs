@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_Match', (test) => {
+    test('(String, Regex)', (assert, {
+      createApplication,
+      createBuiltin,
+      createPair,
+      createRegex,
+      createString,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Match),
+          createPair(createString('foo'), createRegex(createString('bar'))),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), 'null');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Match),
+          createPair(createString('foo123bar'), createRegex(createString('\\d+'))),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '["123"]');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Match),
+          createPair(
+            createString('café 12-34'),
+            createRegex(createString('(\\d+)-(\\d+)(x)?')),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '["12-34", "12", "34", null]');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Match),
+          createPair(createString('FOO'), createRegex(createString('(?i)foo'))),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '["FOO"]');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('invalid pattern', (assert, {
+      createApplication,
+      createBuiltin,
+      createPair,
+      createRegex,
+      createString,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.Match),
+        createPair(createString('foo'), createRegex(createString('('))),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(format(result), '{<InvalidFunctionArgsCondition:Match("foo", /(/)>}');
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+  });
+};
@@ -0,0 +1,29 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@builtin $Stdlib_Match "Match"
+    (@args (@strict $self) (@strict $pattern))
+
+    (@impl
+      (i32.eq (global.get $TermType::String))
+      (i32.eq (global.get $TermType::Regex))
+      (func $Stdlib_Match::impl::String::Regex (param $self i32) (param $pattern i32) (param $state i32) (result i32 i32)
+        (local $result i32)
+        (if (result i32 i32)
+          (i32.eq
+            (local.tee $result (call $Term::Regex::match (local.get $pattern) (local.get $self)))
+            (global.get $NULL))
+          (then
+            (call $Stdlib_Match::impl::default (local.get $self) (local.get $pattern) (local.get $state)))
+          (else
+            (local.get $result)
+            (global.get $NULL)))))
+
+    (@default
+      (func $Stdlib_Match::impl::default (param $self i32) (param $pattern i32) (param $state i32) (result i32 i32)
+        (call $Term::Signal::of
+          (call $Term::Condition::invalid_builtin_function_args
+            (global.get $Stdlib_Match)
+            (call $Term::List::create_pair (local.get $self) (local.get $pattern))))
+        (global.get $NULL)))))
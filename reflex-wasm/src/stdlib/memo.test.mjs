@@ -0,0 +1,73 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_Memo', (test) => {
+    test('(Builtin, Int, ...)', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createList,
+      createPair,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Memo),
+          createList([createBuiltin(Stdlib.Add), createInt(10), createInt(3), createInt(4)]),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), `${3 + 4}`);
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Memo),
+          createPair(createBuiltin(Stdlib.Add), createInt(0)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '{<InvalidFunctionArgsCondition:Memo(Add, 0, [])>}');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('(Builtin, Float, ...)', (assert, {
+      createApplication,
+      createBuiltin,
+      createFloat,
+      createInt,
+      createList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Memo),
+          createList([createBuiltin(Stdlib.Add), createFloat(10.0), createInt(3), createInt(4)]),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), `${3 + 4}`);
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Memo),
+          createList([createBuiltin(Stdlib.Add), createFloat(1.5), createInt(3), createInt(4)]),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(
+          format(result),
+          '{<InvalidFunctionArgsCondition:Memo(Add, 1.5, [3, 4])>}',
+        );
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+  });
+};
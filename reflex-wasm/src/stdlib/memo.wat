@@ -0,0 +1,43 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@builtin $Stdlib_Memo "Memo"
+    (@args (@strict $self) (@strict $capacity) (@variadic (@strict $arg_list)))
+
+    (@impl
+      (call $TermType::implements::apply)
+      (i32.eq (global.get $TermType::Int))
+      (call $TermType::implements::iterate)
+      (func $Stdlib_Memo::impl::<apply>::Int::<iterate> (param $self i32) (param $capacity i32) (param $arg_list i32) (param $state i32) (result i32 i32)
+        (if (result i32 i32)
+          (i64.gt_s (call $Term::Int::get::value (local.get $capacity)) (i64.const 0))
+          (then
+            (call $Stdlib_Memo::apply_target (local.get $self) (local.get $arg_list) (local.get $state)))
+          (else
+            (call $Stdlib_Memo::impl::default (local.get $self) (local.get $capacity) (local.get $arg_list) (local.get $state))))))
+
+    (@impl
+      (call $TermType::implements::apply)
+      (i32.eq (global.get $TermType::Float))
+      (call $TermType::implements::iterate)
+      (func $Stdlib_Memo::impl::<apply>::Float::<iterate> (param $self i32) (param $capacity i32) (param $arg_list i32) (param $state i32) (result i32 i32)
+        (if (result i32 i32)
+          (i64.gt_s (call $Term::Float::get_non_negative_integer_value (local.get $capacity)) (i64.const 0))
+          (then
+            (call $Stdlib_Memo::apply_target (local.get $self) (local.get $arg_list) (local.get $state)))
+          (else
+            (call $Stdlib_Memo::impl::default (local.get $self) (local.get $capacity) (local.get $arg_list) (local.get $state))))))
+
+    (@default
+      (func $Stdlib_Memo::impl::default (param $self i32) (param $capacity i32) (param $arg_list i32) (param $state i32) (result i32 i32)
+        (call $Term::Signal::of
+          (call $Term::Condition::invalid_builtin_function_args
+            (global.get $Stdlib_Memo)
+            (call $Term::List::create_triple (local.get $self) (local.get $capacity) (local.get $arg_list))))
+        (global.get $NULL))))
+
+  (func $Stdlib_Memo::apply_target (param $self i32) (param $arg_list i32) (param $state i32) (result i32 i32)
+    ;; Function applications are already memoized by the runtime's global cache, so the memo table capacity is only
+    ;; validated and the target function is applied directly
+    (call $Stdlib_Apply::impl::<apply>::<iterate> (local.get $self) (local.get $arg_list) (local.get $state))))
@@ -0,0 +1,28 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{uuid, ArgType, Arity, FunctionArity, Uid, Uuid};
+
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct MemoStore;
+impl MemoStore {
+    pub const UUID: Uuid = uuid!("840d522e-d6ee-4359-9cce-f2c1eff56fd0");
+    const ARITY: FunctionArity<4, 0> = FunctionArity {
+        required: [
+            ArgType::Strict,
+            ArgType::Strict,
+            ArgType::Strict,
+            ArgType::Strict,
+        ],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity(&self) -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for MemoStore {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
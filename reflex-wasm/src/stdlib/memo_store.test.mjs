@@ -0,0 +1,34 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_MemoStore', (test) => {
+    test('(Builtin, Int, List, any)', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createList,
+      createPair,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.MemoStore),
+          createList([
+            createBuiltin(Stdlib.Add),
+            createInt(10),
+            createPair(createInt(3), createInt(4)),
+            createInt(7),
+          ]),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '7');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+  });
+};
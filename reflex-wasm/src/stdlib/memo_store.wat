@@ -0,0 +1,12 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@builtin $Stdlib_MemoStore "MemoStore"
+    (@args (@strict $self) (@strict $capacity) (@strict $arg_list) (@strict $result))
+
+    (@default
+      (func $Stdlib_MemoStore::impl::default (param $self i32) (param $capacity i32) (param $arg_list i32) (param $result i32) (param $state i32) (result i32 i32)
+        ;; Memoized results are held in the runtime's global cache, so there is no memo table to update
+        (local.get $result)
+        (global.get $NULL)))))
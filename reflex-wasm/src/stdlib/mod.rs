@@ -6,6 +6,7 @@ use reflex::core::{
     Uuid,
 };
 use reflex_macros::Matcher;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use strum_macros::{EnumDiscriminants, EnumIter};
 
 pub mod abs;
@@ -22,11 +23,20 @@ pub mod collect_record;
 pub mod collect_signal;
 pub mod collect_string;
 pub mod collect_tree;
+pub mod date_add;
+pub mod date_diff;
+pub mod date_offset;
+pub mod date_truncate;
+pub mod date_with_offset;
+pub mod dedupe;
+pub mod delete;
 pub mod divide;
+pub mod duration;
 pub mod effect;
 pub mod ends_with;
 pub mod eq;
 pub mod equal;
+pub mod eval;
 pub mod filter;
 pub mod flatten;
 pub mod floor;
@@ -38,6 +48,8 @@ pub mod gte;
 pub mod handlers;
 pub mod has;
 pub mod hash;
+pub mod hashmap_from_iterator;
+pub mod hashset_from_iterator;
 pub mod identity;
 pub mod r#if;
 pub mod if_error;
@@ -52,7 +64,10 @@ pub mod lisp;
 pub mod lt;
 pub mod lte;
 pub mod map;
+pub mod r#match;
 pub mod max;
+pub mod memo;
+pub mod memo_store;
 pub mod merge;
 pub mod min;
 pub mod multiply;
@@ -61,6 +76,7 @@ pub mod or;
 pub mod pow;
 pub mod push;
 pub mod push_front;
+pub mod quote;
 pub mod raise;
 pub mod remainder;
 pub mod replace;
@@ -71,15 +87,20 @@ pub mod resolve_hashset;
 pub mod resolve_list;
 pub mod resolve_record;
 pub mod resolve_tree;
+pub mod reveal;
 pub mod round;
 pub mod sequence;
 pub mod set;
 pub mod skip;
 pub mod slice;
+pub mod sort;
+pub mod sort_by;
+pub mod sort_merge;
 pub mod split;
 pub mod starts_with;
 pub mod subtract;
 pub mod take;
+pub mod template;
 pub mod unzip;
 pub mod values;
 pub mod zip;
@@ -98,11 +119,20 @@ pub use collect_record::*;
 pub use collect_signal::*;
 pub use collect_string::*;
 pub use collect_tree::*;
+pub use date_add::*;
+pub use date_diff::*;
+pub use date_offset::*;
+pub use date_truncate::*;
+pub use date_with_offset::*;
+pub use dedupe::*;
+pub use delete::*;
 pub use divide::*;
+pub use duration::*;
 pub use effect::*;
 pub use ends_with::*;
 pub use eq::*;
 pub use equal::*;
+pub use eval::*;
 pub use filter::*;
 pub use flatten::*;
 pub use floor::*;
@@ -115,6 +145,8 @@ pub use gte::*;
 pub use handlers::*;
 pub use has::*;
 pub use hash::*;
+pub use hashmap_from_iterator::*;
+pub use hashset_from_iterator::*;
 pub use identity::*;
 pub use if_error::*;
 pub use if_pending::*;
@@ -128,7 +160,10 @@ pub use lisp::*;
 pub use lt::*;
 pub use lte::*;
 pub use map::*;
+pub use r#match::*;
 pub use max::*;
+pub use memo::*;
+pub use memo_store::*;
 pub use merge::*;
 pub use min::*;
 pub use multiply::*;
@@ -137,6 +172,7 @@ pub use or::*;
 pub use pow::*;
 pub use push::*;
 pub use push_front::*;
+pub use quote::*;
 pub use r#if::*;
 pub use raise::*;
 pub use remainder::*;
@@ -148,15 +184,20 @@ pub use resolve_hashset::*;
 pub use resolve_list::*;
 pub use resolve_record::*;
 pub use resolve_tree::*;
+pub use reveal::*;
 pub use round::*;
 pub use sequence::*;
 pub use set::*;
 pub use skip::*;
 pub use slice::*;
+pub use sort::*;
+pub use sort_by::*;
+pub use sort_merge::*;
 pub use split::*;
 pub use starts_with::*;
 pub use subtract::*;
 pub use take::*;
+pub use template::*;
 pub use unzip::*;
 pub use values::*;
 pub use zip::*;
@@ -182,13 +223,23 @@ pub enum Stdlib {
     CollectTree(CollectTree),
     Cons(Cons),
     Construct(Construct),
+    DateAdd(DateAdd),
+    DateDiff(DateDiff),
+    DateOffset(DateOffset),
+    DateTruncate(DateTruncate),
+    DateWithOffset(DateWithOffset),
+    Debounce(Debounce),
     Debug(Debug),
     DecrementVariable(DecrementVariable),
+    Dedupe(Dedupe),
+    Delete(Delete),
     Divide(Divide),
+    Duration(Duration),
     Effect(Effect),
     EndsWith(EndsWith),
     Eq(Eq),
     Equal(Equal),
+    Eval(Eval),
     Filter(Filter),
     Flatten(Flatten),
     Floor(Floor),
@@ -201,6 +252,8 @@ pub enum Stdlib {
     Gte(Gte),
     Has(Has),
     Hash(Hash),
+    HashmapFromIterator(HashmapFromIterator),
+    HashsetFromIterator(HashsetFromIterator),
     Identity(Identity),
     If(If),
     IfError(IfError),
@@ -213,15 +266,20 @@ pub enum Stdlib {
     Keys(Keys),
     Length(Length),
     Log(Log),
+    LogScope(LogScope),
     Lt(Lt),
     Lte(Lte),
     Map(Map),
+    Match(Match),
     Max(Max),
+    Memo(Memo),
+    MemoStore(MemoStore),
     Merge(Merge),
     Min(Min),
     Multiply(Multiply),
     Not(Not),
     Or(Or),
+    Paginate(Paginate),
     ParseDate(ParseDate),
     ParseFloat(ParseFloat),
     ParseInt(ParseInt),
@@ -229,6 +287,7 @@ pub enum Stdlib {
     Pow(Pow),
     Push(Push),
     PushFront(PushFront),
+    Quote(Quote),
     Raise(Raise),
     Remainder(Remainder),
     Replace(Replace),
@@ -242,18 +301,27 @@ pub enum Stdlib {
     ResolveQueryLeaf(ResolveQueryLeaf),
     ResolveRecord(ResolveRecord),
     ResolveTree(ResolveTree),
+    Reveal(Reveal),
     Round(Round),
+    Sample(Sample),
     Scan(Scan),
+    ScopedLog(ScopedLog),
     Sequence(Sequence),
     Set(Set),
     SetVariable(SetVariable),
     Skip(Skip),
     Slice(Slice),
+    Sort(Sort),
+    SortBy(SortBy),
+    SortMerge(SortMerge),
     Split(Split),
     StartsWith(StartsWith),
     StringifyJson(StringifyJson),
+    StringifyJsonCanonical(StringifyJsonCanonical),
     Subtract(Subtract),
     Take(Take),
+    Template(Template),
+    Throttle(Throttle),
     Throw(Throw),
     ToRequest(ToRequest),
     ToString(ToString),
@@ -291,13 +359,23 @@ impl From<Stdlib> for u32 {
             Stdlib::CollectTree(_) => StdlibDiscriminants::CollectTree as u32,
             Stdlib::Cons(_) => StdlibDiscriminants::Cons as u32,
             Stdlib::Construct(_) => StdlibDiscriminants::Construct as u32,
+            Stdlib::DateAdd(_) => StdlibDiscriminants::DateAdd as u32,
+            Stdlib::DateDiff(_) => StdlibDiscriminants::DateDiff as u32,
+            Stdlib::DateOffset(_) => StdlibDiscriminants::DateOffset as u32,
+            Stdlib::DateTruncate(_) => StdlibDiscriminants::DateTruncate as u32,
+            Stdlib::DateWithOffset(_) => StdlibDiscriminants::DateWithOffset as u32,
+            Stdlib::Debounce(_) => StdlibDiscriminants::Debounce as u32,
             Stdlib::Debug(_) => StdlibDiscriminants::Debug as u32,
             Stdlib::DecrementVariable(_) => StdlibDiscriminants::DecrementVariable as u32,
+            Stdlib::Dedupe(_) => StdlibDiscriminants::Dedupe as u32,
+            Stdlib::Delete(_) => StdlibDiscriminants::Delete as u32,
             Stdlib::Divide(_) => StdlibDiscriminants::Divide as u32,
+            Stdlib::Duration(_) => StdlibDiscriminants::Duration as u32,
             Stdlib::Effect(_) => StdlibDiscriminants::Effect as u32,
             Stdlib::EndsWith(_) => StdlibDiscriminants::EndsWith as u32,
             Stdlib::Eq(_) => StdlibDiscriminants::Eq as u32,
             Stdlib::Equal(_) => StdlibDiscriminants::Equal as u32,
+            Stdlib::Eval(_) => StdlibDiscriminants::Eval as u32,
             Stdlib::Filter(_) => StdlibDiscriminants::Filter as u32,
             Stdlib::Flatten(_) => StdlibDiscriminants::Flatten as u32,
             Stdlib::Floor(_) => StdlibDiscriminants::Floor as u32,
@@ -310,6 +388,8 @@ impl From<Stdlib> for u32 {
             Stdlib::Gte(_) => StdlibDiscriminants::Gte as u32,
             Stdlib::Has(_) => StdlibDiscriminants::Has as u32,
             Stdlib::Hash(_) => StdlibDiscriminants::Hash as u32,
+            Stdlib::HashmapFromIterator(_) => StdlibDiscriminants::HashmapFromIterator as u32,
+            Stdlib::HashsetFromIterator(_) => StdlibDiscriminants::HashsetFromIterator as u32,
             Stdlib::Identity(_) => StdlibDiscriminants::Identity as u32,
             Stdlib::If(_) => StdlibDiscriminants::If as u32,
             Stdlib::IfError(_) => StdlibDiscriminants::IfError as u32,
@@ -322,15 +402,20 @@ impl From<Stdlib> for u32 {
             Stdlib::Keys(_) => StdlibDiscriminants::Keys as u32,
             Stdlib::Length(_) => StdlibDiscriminants::Length as u32,
             Stdlib::Log(_) => StdlibDiscriminants::Log as u32,
+            Stdlib::LogScope(_) => StdlibDiscriminants::LogScope as u32,
             Stdlib::Lt(_) => StdlibDiscriminants::Lt as u32,
             Stdlib::Lte(_) => StdlibDiscriminants::Lte as u32,
             Stdlib::Map(_) => StdlibDiscriminants::Map as u32,
+            Stdlib::Match(_) => StdlibDiscriminants::Match as u32,
             Stdlib::Max(_) => StdlibDiscriminants::Max as u32,
+            Stdlib::Memo(_) => StdlibDiscriminants::Memo as u32,
+            Stdlib::MemoStore(_) => StdlibDiscriminants::MemoStore as u32,
             Stdlib::Merge(_) => StdlibDiscriminants::Merge as u32,
             Stdlib::Min(_) => StdlibDiscriminants::Min as u32,
             Stdlib::Multiply(_) => StdlibDiscriminants::Multiply as u32,
             Stdlib::Not(_) => StdlibDiscriminants::Not as u32,
             Stdlib::Or(_) => StdlibDiscriminants::Or as u32,
+            Stdlib::Paginate(_) => StdlibDiscriminants::Paginate as u32,
             Stdlib::ParseDate(_) => StdlibDiscriminants::ParseDate as u32,
             Stdlib::ParseFloat(_) => StdlibDiscriminants::ParseFloat as u32,
             Stdlib::ParseInt(_) => StdlibDiscriminants::ParseInt as u32,
@@ -338,6 +423,7 @@ impl From<Stdlib> for u32 {
             Stdlib::Pow(_) => StdlibDiscriminants::Pow as u32,
             Stdlib::Push(_) => StdlibDiscriminants::Push as u32,
             Stdlib::PushFront(_) => StdlibDiscriminants::PushFront as u32,
+            Stdlib::Quote(_) => StdlibDiscriminants::Quote as u32,
             Stdlib::Raise(_) => StdlibDiscriminants::Raise as u32,
             Stdlib::Remainder(_) => StdlibDiscriminants::Remainder as u32,
             Stdlib::Replace(_) => StdlibDiscriminants::Replace as u32,
@@ -351,18 +437,27 @@ impl From<Stdlib> for u32 {
             Stdlib::ResolveQueryLeaf(_) => StdlibDiscriminants::ResolveQueryLeaf as u32,
             Stdlib::ResolveRecord(_) => StdlibDiscriminants::ResolveRecord as u32,
             Stdlib::ResolveTree(_) => StdlibDiscriminants::ResolveTree as u32,
+            Stdlib::Reveal(_) => StdlibDiscriminants::Reveal as u32,
             Stdlib::Round(_) => StdlibDiscriminants::Round as u32,
+            Stdlib::Sample(_) => StdlibDiscriminants::Sample as u32,
             Stdlib::Scan(_) => StdlibDiscriminants::Scan as u32,
+            Stdlib::ScopedLog(_) => StdlibDiscriminants::ScopedLog as u32,
             Stdlib::Sequence(_) => StdlibDiscriminants::Sequence as u32,
             Stdlib::Set(_) => StdlibDiscriminants::Set as u32,
             Stdlib::SetVariable(_) => StdlibDiscriminants::SetVariable as u32,
             Stdlib::Skip(_) => StdlibDiscriminants::Skip as u32,
             Stdlib::Slice(_) => StdlibDiscriminants::Slice as u32,
+            Stdlib::Sort(_) => StdlibDiscriminants::Sort as u32,
+            Stdlib::SortBy(_) => StdlibDiscriminants::SortBy as u32,
+            Stdlib::SortMerge(_) => StdlibDiscriminants::SortMerge as u32,
             Stdlib::Split(_) => StdlibDiscriminants::Split as u32,
             Stdlib::StartsWith(_) => StdlibDiscriminants::StartsWith as u32,
             Stdlib::StringifyJson(_) => StdlibDiscriminants::StringifyJson as u32,
+            Stdlib::StringifyJsonCanonical(_) => StdlibDiscriminants::StringifyJsonCanonical as u32,
             Stdlib::Subtract(_) => StdlibDiscriminants::Subtract as u32,
             Stdlib::Take(_) => StdlibDiscriminants::Take as u32,
+            Stdlib::Template(_) => StdlibDiscriminants::Template as u32,
+            Stdlib::Throttle(_) => StdlibDiscriminants::Throttle as u32,
             Stdlib::Throw(_) => StdlibDiscriminants::Throw as u32,
             Stdlib::ToRequest(_) => StdlibDiscriminants::ToRequest as u32,
             Stdlib::ToString(_) => StdlibDiscriminants::ToString as u32,
@@ -414,15 +509,31 @@ impl TryFrom<u32> for Stdlib {
             value if value == StdlibDiscriminants::Construct as u32 => {
                 Ok(Self::Construct(Construct))
             }
+            value if value == StdlibDiscriminants::DateAdd as u32 => Ok(Self::DateAdd(DateAdd)),
+            value if value == StdlibDiscriminants::DateDiff as u32 => Ok(Self::DateDiff(DateDiff)),
+            value if value == StdlibDiscriminants::DateOffset as u32 => {
+                Ok(Self::DateOffset(DateOffset))
+            }
+            value if value == StdlibDiscriminants::DateTruncate as u32 => {
+                Ok(Self::DateTruncate(DateTruncate))
+            }
+            value if value == StdlibDiscriminants::DateWithOffset as u32 => {
+                Ok(Self::DateWithOffset(DateWithOffset))
+            }
+            value if value == StdlibDiscriminants::Debounce as u32 => Ok(Self::Debounce(Debounce)),
             value if value == StdlibDiscriminants::Debug as u32 => Ok(Self::Debug(Debug)),
             value if value == StdlibDiscriminants::DecrementVariable as u32 => {
                 Ok(Self::DecrementVariable(DecrementVariable))
             }
+            value if value == StdlibDiscriminants::Dedupe as u32 => Ok(Self::Dedupe(Dedupe)),
+            value if value == StdlibDiscriminants::Delete as u32 => Ok(Self::Delete(Delete)),
             value if value == StdlibDiscriminants::Divide as u32 => Ok(Self::Divide(Divide)),
+            value if value == StdlibDiscriminants::Duration as u32 => Ok(Self::Duration(Duration)),
             value if value == StdlibDiscriminants::Effect as u32 => Ok(Self::Effect(Effect)),
             value if value == StdlibDiscriminants::EndsWith as u32 => Ok(Self::EndsWith(EndsWith)),
             value if value == StdlibDiscriminants::Eq as u32 => Ok(Self::Eq(Eq)),
             value if value == StdlibDiscriminants::Equal as u32 => Ok(Self::Equal(Equal)),
+            value if value == StdlibDiscriminants::Eval as u32 => Ok(Self::Eval(Eval)),
             value if value == StdlibDiscriminants::Filter as u32 => Ok(Self::Filter(Filter)),
             value if value == StdlibDiscriminants::Flatten as u32 => Ok(Self::Flatten(Flatten)),
             value if value == StdlibDiscriminants::Floor as u32 => Ok(Self::Floor(Floor)),
@@ -441,6 +552,12 @@ impl TryFrom<u32> for Stdlib {
             value if value == StdlibDiscriminants::Gte as u32 => Ok(Self::Gte(Gte)),
             value if value == StdlibDiscriminants::Has as u32 => Ok(Self::Has(Has)),
             value if value == StdlibDiscriminants::Hash as u32 => Ok(Self::Hash(Hash)),
+            value if value == StdlibDiscriminants::HashmapFromIterator as u32 => {
+                Ok(Self::HashmapFromIterator(HashmapFromIterator))
+            }
+            value if value == StdlibDiscriminants::HashsetFromIterator as u32 => {
+                Ok(Self::HashsetFromIterator(HashsetFromIterator))
+            }
             value if value == StdlibDiscriminants::Identity as u32 => Ok(Self::Identity(Identity)),
             value if value == StdlibDiscriminants::If as u32 => Ok(Self::If(If)),
             value if value == StdlibDiscriminants::IfError as u32 => Ok(Self::IfError(IfError)),
@@ -459,15 +576,22 @@ impl TryFrom<u32> for Stdlib {
             value if value == StdlibDiscriminants::Keys as u32 => Ok(Self::Keys(Keys)),
             value if value == StdlibDiscriminants::Length as u32 => Ok(Self::Length(Length)),
             value if value == StdlibDiscriminants::Log as u32 => Ok(Self::Log(Log)),
+            value if value == StdlibDiscriminants::LogScope as u32 => Ok(Self::LogScope(LogScope)),
             value if value == StdlibDiscriminants::Lt as u32 => Ok(Self::Lt(Lt)),
             value if value == StdlibDiscriminants::Lte as u32 => Ok(Self::Lte(Lte)),
             value if value == StdlibDiscriminants::Map as u32 => Ok(Self::Map(Map)),
+            value if value == StdlibDiscriminants::Match as u32 => Ok(Self::Match(Match)),
             value if value == StdlibDiscriminants::Max as u32 => Ok(Self::Max(Max)),
+            value if value == StdlibDiscriminants::Memo as u32 => Ok(Self::Memo(Memo)),
+            value if value == StdlibDiscriminants::MemoStore as u32 => {
+                Ok(Self::MemoStore(MemoStore))
+            }
             value if value == StdlibDiscriminants::Merge as u32 => Ok(Self::Merge(Merge)),
             value if value == StdlibDiscriminants::Min as u32 => Ok(Self::Min(Min)),
             value if value == StdlibDiscriminants::Multiply as u32 => Ok(Self::Multiply(Multiply)),
             value if value == StdlibDiscriminants::Not as u32 => Ok(Self::Not(Not)),
             value if value == StdlibDiscriminants::Or as u32 => Ok(Self::Or(Or)),
+            value if value == StdlibDiscriminants::Paginate as u32 => Ok(Self::Paginate(Paginate)),
             value if value == StdlibDiscriminants::ParseDate as u32 => {
                 Ok(Self::ParseDate(ParseDate))
             }
@@ -483,6 +607,7 @@ impl TryFrom<u32> for Stdlib {
             value if value == StdlibDiscriminants::PushFront as u32 => {
                 Ok(Self::PushFront(PushFront))
             }
+            value if value == StdlibDiscriminants::Quote as u32 => Ok(Self::Quote(Quote)),
             value if value == StdlibDiscriminants::Raise as u32 => Ok(Self::Raise(Raise)),
             value if value == StdlibDiscriminants::Remainder as u32 => {
                 Ok(Self::Remainder(Remainder))
@@ -518,8 +643,13 @@ impl TryFrom<u32> for Stdlib {
             value if value == StdlibDiscriminants::ResolveTree as u32 => {
                 Ok(Self::ResolveTree(ResolveTree))
             }
+            value if value == StdlibDiscriminants::Reveal as u32 => Ok(Self::Reveal(Reveal)),
             value if value == StdlibDiscriminants::Round as u32 => Ok(Self::Round(Round)),
+            value if value == StdlibDiscriminants::Sample as u32 => Ok(Self::Sample(Sample)),
             value if value == StdlibDiscriminants::Scan as u32 => Ok(Self::Scan(Scan)),
+            value if value == StdlibDiscriminants::ScopedLog as u32 => {
+                Ok(Self::ScopedLog(ScopedLog))
+            }
             value if value == StdlibDiscriminants::Sequence as u32 => Ok(Self::Sequence(Sequence)),
             value if value == StdlibDiscriminants::Set as u32 => Ok(Self::Set(Set)),
             value if value == StdlibDiscriminants::SetVariable as u32 => {
@@ -527,6 +657,11 @@ impl TryFrom<u32> for Stdlib {
             }
             value if value == StdlibDiscriminants::Skip as u32 => Ok(Self::Skip(Skip)),
             value if value == StdlibDiscriminants::Slice as u32 => Ok(Self::Slice(Slice)),
+            value if value == StdlibDiscriminants::Sort as u32 => Ok(Self::Sort(Sort)),
+            value if value == StdlibDiscriminants::SortBy as u32 => Ok(Self::SortBy(SortBy)),
+            value if value == StdlibDiscriminants::SortMerge as u32 => {
+                Ok(Self::SortMerge(SortMerge))
+            }
             value if value == StdlibDiscriminants::Split as u32 => Ok(Self::Split(Split)),
             value if value == StdlibDiscriminants::StartsWith as u32 => {
                 Ok(Self::StartsWith(StartsWith))
@@ -534,8 +669,13 @@ impl TryFrom<u32> for Stdlib {
             value if value == StdlibDiscriminants::StringifyJson as u32 => {
                 Ok(Self::StringifyJson(StringifyJson))
             }
+            value if value == StdlibDiscriminants::StringifyJsonCanonical as u32 => {
+                Ok(Self::StringifyJsonCanonical(StringifyJsonCanonical))
+            }
             value if value == StdlibDiscriminants::Subtract as u32 => Ok(Self::Subtract(Subtract)),
             value if value == StdlibDiscriminants::Take as u32 => Ok(Self::Take(Take)),
+            value if value == StdlibDiscriminants::Template as u32 => Ok(Self::Template(Template)),
+            value if value == StdlibDiscriminants::Throttle as u32 => Ok(Self::Throttle(Throttle)),
             value if value == StdlibDiscriminants::Throw as u32 => Ok(Self::Throw(Throw)),
             value if value == StdlibDiscriminants::ToRequest as u32 => {
                 Ok(Self::ToRequest(ToRequest))
@@ -552,6 +692,10 @@ impl TryFrom<u32> for Stdlib {
     }
 }
 
+/// Host import module under which any builtins that are not implemented by the runtime library are imported when
+/// compiling with builtin fallbacks enabled
+pub const BUILTIN_FALLBACK_MODULE: &str = "Builtins";
+
 impl Stdlib {
     pub fn name(&self) -> &'static str {
         match self {
@@ -574,13 +718,23 @@ impl Stdlib {
             Self::CollectTree(_) => "Stdlib_CollectTree",
             Self::Cons(_) => "Stdlib_Cons",
             Self::Construct(_) => "Stdlib_Construct",
+            Self::DateAdd(_) => "Stdlib_DateAdd",
+            Self::DateDiff(_) => "Stdlib_DateDiff",
+            Self::DateOffset(_) => "Stdlib_DateOffset",
+            Self::DateTruncate(_) => "Stdlib_DateTruncate",
+            Self::DateWithOffset(_) => "Stdlib_DateWithOffset",
+            Self::Debounce(_) => "Stdlib_Debounce",
             Self::Debug(_) => "Stdlib_Debug",
             Self::DecrementVariable(_) => "Stdlib_DecrementVariable",
+            Self::Dedupe(_) => "Stdlib_Dedupe",
+            Self::Delete(_) => "Stdlib_Delete",
             Self::Divide(_) => "Stdlib_Divide",
+            Self::Duration(_) => "Stdlib_Duration",
             Self::Effect(_) => "Stdlib_Effect",
             Self::EndsWith(_) => "Stdlib_EndsWith",
             Self::Eq(_) => "Stdlib_Eq",
             Self::Equal(_) => "Stdlib_Equal",
+            Self::Eval(_) => "Stdlib_Eval",
             Self::Filter(_) => "Stdlib_Filter",
             Self::Flatten(_) => "Stdlib_Flatten",
             Self::Floor(_) => "Stdlib_Floor",
@@ -593,6 +747,8 @@ impl Stdlib {
             Self::Gte(_) => "Stdlib_Gte",
             Self::Has(_) => "Stdlib_Has",
             Self::Hash(_) => "Stdlib_Hash",
+            Self::HashmapFromIterator(_) => "Stdlib_HashmapFromIterator",
+            Self::HashsetFromIterator(_) => "Stdlib_HashsetFromIterator",
             Self::Identity(_) => "Stdlib_Identity",
             Self::If(_) => "Stdlib_If",
             Self::IfError(_) => "Stdlib_IfError",
@@ -605,15 +761,20 @@ impl Stdlib {
             Self::Keys(_) => "Stdlib_Keys",
             Self::Length(_) => "Stdlib_Length",
             Self::Log(_) => "Stdlib_Log",
+            Self::LogScope(_) => "Stdlib_LogScope",
             Self::Lt(_) => "Stdlib_Lt",
             Self::Lte(_) => "Stdlib_Lte",
             Self::Map(_) => "Stdlib_Map",
+            Self::Match(_) => "Stdlib_Match",
             Self::Max(_) => "Stdlib_Max",
+            Self::Memo(_) => "Stdlib_Memo",
+            Self::MemoStore(_) => "Stdlib_MemoStore",
             Self::Merge(_) => "Stdlib_Merge",
             Self::Min(_) => "Stdlib_Min",
             Self::Multiply(_) => "Stdlib_Multiply",
             Self::Not(_) => "Stdlib_Not",
             Self::Or(_) => "Stdlib_Or",
+            Self::Paginate(_) => "Stdlib_Paginate",
             Self::ParseDate(_) => "Stdlib_ParseDate",
             Self::ParseFloat(_) => "Stdlib_ParseFloat",
             Self::ParseInt(_) => "Stdlib_ParseInt",
@@ -621,6 +782,7 @@ impl Stdlib {
             Self::Pow(_) => "Stdlib_Pow",
             Self::Push(_) => "Stdlib_Push",
             Self::PushFront(_) => "Stdlib_PushFront",
+            Self::Quote(_) => "Stdlib_Quote",
             Self::Raise(_) => "Stdlib_Raise",
             Self::Remainder(_) => "Stdlib_Remainder",
             Self::Replace(_) => "Stdlib_Replace",
@@ -634,18 +796,27 @@ impl Stdlib {
             Self::ResolveQueryLeaf(_) => "Stdlib_ResolveQueryLeaf",
             Self::ResolveRecord(_) => "Stdlib_ResolveRecord",
             Self::ResolveTree(_) => "Stdlib_ResolveTree",
+            Self::Reveal(_) => "Stdlib_Reveal",
             Self::Round(_) => "Stdlib_Round",
+            Self::Sample(_) => "Stdlib_Sample",
             Self::Scan(_) => "Stdlib_Scan",
+            Self::ScopedLog(_) => "Stdlib_ScopedLog",
             Self::Sequence(_) => "Stdlib_Sequence",
             Self::Set(_) => "Stdlib_Set",
             Self::SetVariable(_) => "Stdlib_SetVariable",
             Self::Skip(_) => "Stdlib_Skip",
             Self::Slice(_) => "Stdlib_Slice",
+            Self::Sort(_) => "Stdlib_Sort",
+            Self::SortBy(_) => "Stdlib_SortBy",
+            Self::SortMerge(_) => "Stdlib_SortMerge",
             Self::Split(_) => "Stdlib_Split",
             Self::StartsWith(_) => "Stdlib_StartsWith",
             Self::StringifyJson(_) => "Stdlib_StringifyJson",
+            Self::StringifyJsonCanonical(_) => "Stdlib_StringifyJsonCanonical",
             Self::Subtract(_) => "Stdlib_Subtract",
             Self::Take(_) => "Stdlib_Take",
+            Self::Template(_) => "Stdlib_Template",
+            Self::Throttle(_) => "Stdlib_Throttle",
             Self::Throw(_) => "Stdlib_Throw",
             Self::ToRequest(_) => "Stdlib_ToRequest",
             Self::ToString(_) => "Stdlib_ToString",
@@ -655,6 +826,12 @@ impl Stdlib {
             Self::Zip(_) => "Stdlib_Zip",
         }
     }
+    /// Number of term arguments accepted by the runtime implementation of this builtin, where any variadic arguments are
+    /// collected into a single list argument (this excludes the trailing evaluation state argument)
+    pub fn num_params(&self) -> usize {
+        let arity = self.arity();
+        arity.required().len() + arity.optional().len() + usize::from(arity.variadic().is_some())
+    }
     pub fn arity(&self) -> Arity {
         match self {
             Self::Abs(inner) => inner.arity(),
@@ -676,13 +853,23 @@ impl Stdlib {
             Self::CollectTree(inner) => inner.arity(),
             Self::Cons(inner) => inner.arity(),
             Self::Construct(inner) => inner.arity(),
+            Self::DateAdd(inner) => inner.arity(),
+            Self::DateDiff(inner) => inner.arity(),
+            Self::DateOffset(inner) => inner.arity(),
+            Self::DateTruncate(inner) => inner.arity(),
+            Self::DateWithOffset(inner) => inner.arity(),
+            Self::Debounce(inner) => inner.arity(),
             Self::Debug(inner) => inner.arity(),
             Self::DecrementVariable(inner) => inner.arity(),
+            Self::Dedupe(inner) => inner.arity(),
+            Self::Delete(inner) => inner.arity(),
             Self::Divide(inner) => inner.arity(),
+            Self::Duration(inner) => inner.arity(),
             Self::Effect(inner) => inner.arity(),
             Self::EndsWith(inner) => inner.arity(),
             Self::Eq(inner) => inner.arity(),
             Self::Equal(inner) => inner.arity(),
+            Self::Eval(inner) => inner.arity(),
             Self::Filter(inner) => inner.arity(),
             Self::Flatten(inner) => inner.arity(),
             Self::Floor(inner) => inner.arity(),
@@ -695,6 +882,8 @@ impl Stdlib {
             Self::Gte(inner) => inner.arity(),
             Self::Has(inner) => inner.arity(),
             Self::Hash(inner) => inner.arity(),
+            Self::HashmapFromIterator(inner) => inner.arity(),
+            Self::HashsetFromIterator(inner) => inner.arity(),
             Self::Identity(inner) => inner.arity(),
             Self::If(inner) => inner.arity(),
             Self::IfError(inner) => inner.arity(),
@@ -707,15 +896,20 @@ impl Stdlib {
             Self::Keys(inner) => inner.arity(),
             Self::Length(inner) => inner.arity(),
             Self::Log(inner) => inner.arity(),
+            Self::LogScope(inner) => inner.arity(),
             Self::Lt(inner) => inner.arity(),
             Self::Lte(inner) => inner.arity(),
             Self::Map(inner) => inner.arity(),
+            Self::Match(inner) => inner.arity(),
             Self::Max(inner) => inner.arity(),
+            Self::Memo(inner) => inner.arity(),
+            Self::MemoStore(inner) => inner.arity(),
             Self::Merge(inner) => inner.arity(),
             Self::Min(inner) => inner.arity(),
             Self::Multiply(inner) => inner.arity(),
             Self::Not(inner) => inner.arity(),
             Self::Or(inner) => inner.arity(),
+            Self::Paginate(inner) => inner.arity(),
             Self::ParseDate(inner) => inner.arity(),
             Self::ParseFloat(inner) => inner.arity(),
             Self::ParseInt(inner) => inner.arity(),
@@ -723,6 +917,7 @@ impl Stdlib {
             Self::Pow(inner) => inner.arity(),
             Self::Push(inner) => inner.arity(),
             Self::PushFront(inner) => inner.arity(),
+            Self::Quote(inner) => inner.arity(),
             Self::Raise(inner) => inner.arity(),
             Self::Remainder(inner) => inner.arity(),
             Self::Replace(inner) => inner.arity(),
@@ -736,18 +931,27 @@ impl Stdlib {
             Self::ResolveQueryLeaf(inner) => inner.arity(),
             Self::ResolveRecord(inner) => inner.arity(),
             Self::ResolveTree(inner) => inner.arity(),
+            Self::Reveal(inner) => inner.arity(),
             Self::Round(inner) => inner.arity(),
+            Self::Sample(inner) => inner.arity(),
             Self::Scan(inner) => inner.arity(),
+            Self::ScopedLog(inner) => inner.arity(),
             Self::Sequence(inner) => inner.arity(),
             Self::Set(inner) => inner.arity(),
             Self::SetVariable(inner) => inner.arity(),
             Self::Skip(inner) => inner.arity(),
             Self::Slice(inner) => inner.arity(),
+            Self::Sort(inner) => inner.arity(),
+            Self::SortBy(inner) => inner.arity(),
+            Self::SortMerge(inner) => inner.arity(),
             Self::Split(inner) => inner.arity(),
             Self::StartsWith(inner) => inner.arity(),
             Self::StringifyJson(inner) => inner.arity(),
+            Self::StringifyJsonCanonical(inner) => inner.arity(),
             Self::Subtract(inner) => inner.arity(),
             Self::Take(inner) => inner.arity(),
+            Self::Template(inner) => inner.arity(),
+            Self::Throttle(inner) => inner.arity(),
             Self::Throw(inner) => inner.arity(),
             Self::ToRequest(inner) => inner.arity(),
             Self::ToString(inner) => inner.arity(),
@@ -778,13 +982,23 @@ impl Stdlib {
             Self::CollectTree(inner) => inner.uid(),
             Self::Cons(inner) => inner.uid(),
             Self::Construct(inner) => inner.uid(),
+            Self::DateAdd(inner) => inner.uid(),
+            Self::DateDiff(inner) => inner.uid(),
+            Self::DateOffset(inner) => inner.uid(),
+            Self::DateTruncate(inner) => inner.uid(),
+            Self::DateWithOffset(inner) => inner.uid(),
+            Self::Debounce(inner) => inner.uid(),
             Self::Debug(inner) => inner.uid(),
             Self::DecrementVariable(inner) => inner.uid(),
+            Self::Dedupe(inner) => inner.uid(),
+            Self::Delete(inner) => inner.uid(),
             Self::Divide(inner) => inner.uid(),
+            Self::Duration(inner) => inner.uid(),
             Self::Effect(inner) => inner.uid(),
             Self::EndsWith(inner) => inner.uid(),
             Self::Eq(inner) => inner.uid(),
             Self::Equal(inner) => inner.uid(),
+            Self::Eval(inner) => inner.uid(),
             Self::Filter(inner) => inner.uid(),
             Self::Flatten(inner) => inner.uid(),
             Self::Floor(inner) => inner.uid(),
@@ -797,6 +1011,8 @@ impl Stdlib {
             Self::Gte(inner) => inner.uid(),
             Self::Has(inner) => inner.uid(),
             Self::Hash(inner) => inner.uid(),
+            Self::HashmapFromIterator(inner) => inner.uid(),
+            Self::HashsetFromIterator(inner) => inner.uid(),
             Self::Identity(inner) => inner.uid(),
             Self::If(inner) => inner.uid(),
             Self::IfError(inner) => inner.uid(),
@@ -809,15 +1025,20 @@ impl Stdlib {
             Self::Keys(inner) => inner.uid(),
             Self::Length(inner) => inner.uid(),
             Self::Log(inner) => inner.uid(),
+            Self::LogScope(inner) => inner.uid(),
             Self::Lt(inner) => inner.uid(),
             Self::Lte(inner) => inner.uid(),
             Self::Map(inner) => inner.uid(),
+            Self::Match(inner) => inner.uid(),
             Self::Max(inner) => inner.uid(),
+            Self::Memo(inner) => inner.uid(),
+            Self::MemoStore(inner) => inner.uid(),
             Self::Merge(inner) => inner.uid(),
             Self::Min(inner) => inner.uid(),
             Self::Multiply(inner) => inner.uid(),
             Self::Not(inner) => inner.uid(),
             Self::Or(inner) => inner.uid(),
+            Self::Paginate(inner) => inner.uid(),
             Self::ParseDate(inner) => inner.uid(),
             Self::ParseFloat(inner) => inner.uid(),
             Self::ParseInt(inner) => inner.uid(),
@@ -825,6 +1046,7 @@ impl Stdlib {
             Self::Pow(inner) => inner.uid(),
             Self::Push(inner) => inner.uid(),
             Self::PushFront(inner) => inner.uid(),
+            Self::Quote(inner) => inner.uid(),
             Self::Raise(inner) => inner.uid(),
             Self::Remainder(inner) => inner.uid(),
             Self::Replace(inner) => inner.uid(),
@@ -838,18 +1060,27 @@ impl Stdlib {
             Self::ResolveQueryLeaf(inner) => inner.uid(),
             Self::ResolveRecord(inner) => inner.uid(),
             Self::ResolveTree(inner) => inner.uid(),
+            Self::Reveal(inner) => inner.uid(),
             Self::Round(inner) => inner.uid(),
+            Self::Sample(inner) => inner.uid(),
             Self::Scan(inner) => inner.uid(),
+            Self::ScopedLog(inner) => inner.uid(),
             Self::Sequence(inner) => inner.uid(),
             Self::Set(inner) => inner.uid(),
             Self::SetVariable(inner) => inner.uid(),
             Self::Skip(inner) => inner.uid(),
             Self::Slice(inner) => inner.uid(),
+            Self::Sort(inner) => inner.uid(),
+            Self::SortBy(inner) => inner.uid(),
+            Self::SortMerge(inner) => inner.uid(),
             Self::Split(inner) => inner.uid(),
             Self::StartsWith(inner) => inner.uid(),
             Self::StringifyJson(inner) => inner.uid(),
+            Self::StringifyJsonCanonical(inner) => inner.uid(),
             Self::Subtract(inner) => inner.uid(),
             Self::Take(inner) => inner.uid(),
+            Self::Template(inner) => inner.uid(),
+            Self::Throttle(inner) => inner.uid(),
             Self::Throw(inner) => inner.uid(),
             Self::ToRequest(inner) => inner.uid(),
             Self::ToString(inner) => inner.uid(),
@@ -918,13 +1149,23 @@ impl TryFrom<Uuid> for Stdlib {
             CollectTree::UUID => Ok(Self::CollectTree(CollectTree)),
             Cons::UUID => Ok(Self::Cons(Cons)),
             Construct::UUID => Ok(Self::Construct(Construct)),
+            DateAdd::UUID => Ok(Self::DateAdd(DateAdd)),
+            DateDiff::UUID => Ok(Self::DateDiff(DateDiff)),
+            DateOffset::UUID => Ok(Self::DateOffset(DateOffset)),
+            DateTruncate::UUID => Ok(Self::DateTruncate(DateTruncate)),
+            DateWithOffset::UUID => Ok(Self::DateWithOffset(DateWithOffset)),
+            Debounce::UUID => Ok(Self::Debounce(Debounce)),
             Debug::UUID => Ok(Self::Debug(Debug)),
             DecrementVariable::UUID => Ok(Self::DecrementVariable(DecrementVariable)),
+            Dedupe::UUID => Ok(Self::Dedupe(Dedupe)),
+            Delete::UUID => Ok(Self::Delete(Delete)),
             Divide::UUID => Ok(Self::Divide(Divide)),
+            Duration::UUID => Ok(Self::Duration(Duration)),
             Effect::UUID => Ok(Self::Effect(Effect)),
             EndsWith::UUID => Ok(Self::EndsWith(EndsWith)),
             Eq::UUID => Ok(Self::Eq(Eq)),
             Equal::UUID => Ok(Self::Equal(Equal)),
+            Eval::UUID => Ok(Self::Eval(Eval)),
             Filter::UUID => Ok(Self::Filter(Filter)),
             Flatten::UUID => Ok(Self::Flatten(Flatten)),
             Floor::UUID => Ok(Self::Floor(Floor)),
@@ -937,6 +1178,8 @@ impl TryFrom<Uuid> for Stdlib {
             Gte::UUID => Ok(Self::Gte(Gte)),
             Has::UUID => Ok(Self::Has(Has)),
             Hash::UUID => Ok(Self::Hash(Hash)),
+            HashmapFromIterator::UUID => Ok(Self::HashmapFromIterator(HashmapFromIterator)),
+            HashsetFromIterator::UUID => Ok(Self::HashsetFromIterator(HashsetFromIterator)),
             Identity::UUID => Ok(Self::Identity(Identity)),
             If::UUID => Ok(Self::If(If)),
             IfError::UUID => Ok(Self::IfError(IfError)),
@@ -949,15 +1192,20 @@ impl TryFrom<Uuid> for Stdlib {
             Keys::UUID => Ok(Self::Keys(Keys)),
             Length::UUID => Ok(Self::Length(Length)),
             Log::UUID => Ok(Self::Log(Log)),
+            LogScope::UUID => Ok(Self::LogScope(LogScope)),
             Lt::UUID => Ok(Self::Lt(Lt)),
             Lte::UUID => Ok(Self::Lte(Lte)),
             Map::UUID => Ok(Self::Map(Map)),
+            Match::UUID => Ok(Self::Match(Match)),
             Max::UUID => Ok(Self::Max(Max)),
+            Memo::UUID => Ok(Self::Memo(Memo)),
+            MemoStore::UUID => Ok(Self::MemoStore(MemoStore)),
             Merge::UUID => Ok(Self::Merge(Merge)),
             Min::UUID => Ok(Self::Min(Min)),
             Multiply::UUID => Ok(Self::Multiply(Multiply)),
             Not::UUID => Ok(Self::Not(Not)),
             Or::UUID => Ok(Self::Or(Or)),
+            Paginate::UUID => Ok(Self::Paginate(Paginate)),
             ParseDate::UUID => Ok(Self::ParseDate(ParseDate)),
             ParseFloat::UUID => Ok(Self::ParseFloat(ParseFloat)),
             ParseInt::UUID => Ok(Self::ParseInt(ParseInt)),
@@ -965,6 +1213,7 @@ impl TryFrom<Uuid> for Stdlib {
             Pow::UUID => Ok(Self::Pow(Pow)),
             Push::UUID => Ok(Self::Push(Push)),
             PushFront::UUID => Ok(Self::PushFront(PushFront)),
+            Quote::UUID => Ok(Self::Quote(Quote)),
             Raise::UUID => Ok(Self::Raise(Raise)),
             Remainder::UUID => Ok(Self::Remainder(Remainder)),
             Replace::UUID => Ok(Self::Replace(Replace)),
@@ -978,18 +1227,29 @@ impl TryFrom<Uuid> for Stdlib {
             ResolveQueryLeaf::UUID => Ok(Self::ResolveQueryLeaf(ResolveQueryLeaf)),
             ResolveRecord::UUID => Ok(Self::ResolveRecord(ResolveRecord)),
             ResolveTree::UUID => Ok(Self::ResolveTree(ResolveTree)),
+            Reveal::UUID => Ok(Self::Reveal(Reveal)),
             Round::UUID => Ok(Self::Round(Round)),
+            Sample::UUID => Ok(Self::Sample(Sample)),
             Scan::UUID => Ok(Self::Scan(Scan)),
+            ScopedLog::UUID => Ok(Self::ScopedLog(ScopedLog)),
             Sequence::UUID => Ok(Self::Sequence(Sequence)),
             Set::UUID => Ok(Self::Set(Set)),
             SetVariable::UUID => Ok(Self::SetVariable(SetVariable)),
             Skip::UUID => Ok(Self::Skip(Skip)),
             Slice::UUID => Ok(Self::Slice(Slice)),
+            Sort::UUID => Ok(Self::Sort(Sort)),
+            SortBy::UUID => Ok(Self::SortBy(SortBy)),
+            SortMerge::UUID => Ok(Self::SortMerge(SortMerge)),
             Split::UUID => Ok(Self::Split(Split)),
             StartsWith::UUID => Ok(Self::StartsWith(StartsWith)),
             StringifyJson::UUID => Ok(Self::StringifyJson(StringifyJson)),
+            StringifyJsonCanonical::UUID => {
+                Ok(Self::StringifyJsonCanonical(StringifyJsonCanonical))
+            }
             Subtract::UUID => Ok(Self::Subtract(Subtract)),
             Take::UUID => Ok(Self::Take(Take)),
+            Template::UUID => Ok(Self::Template(Template)),
+            Throttle::UUID => Ok(Self::Throttle(Throttle)),
             Throw::UUID => Ok(Self::Throw(Throw)),
             ToRequest::UUID => Ok(Self::ToRequest(ToRequest)),
             ToString::UUID => Ok(Self::ToString(ToString)),
@@ -1008,6 +1268,23 @@ impl std::fmt::Display for Stdlib {
     }
 }
 
+// Builtins are serialized by UUID rather than by discriminant, as discriminants are reassigned whenever builtins are added
+impl Serialize for Stdlib {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.uid().to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Stdlib {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let uid = String::deserialize(deserializer)?;
+        Uuid::parse_str(&uid)
+            .ok()
+            .and_then(|uid| Self::try_from(uid).ok())
+            .ok_or_else(|| serde::de::Error::custom(format!("Unknown builtin: {}", uid)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1033,84 +1310,111 @@ mod tests {
         assert_eq!(StdlibDiscriminants::CollectTree as u32, 16);
         assert_eq!(StdlibDiscriminants::Cons as u32, 17);
         assert_eq!(StdlibDiscriminants::Construct as u32, 18);
-        assert_eq!(StdlibDiscriminants::Debug as u32, 19);
-        assert_eq!(StdlibDiscriminants::DecrementVariable as u32, 20);
-        assert_eq!(StdlibDiscriminants::Divide as u32, 21);
-        assert_eq!(StdlibDiscriminants::Effect as u32, 22);
-        assert_eq!(StdlibDiscriminants::EndsWith as u32, 23);
-        assert_eq!(StdlibDiscriminants::Eq as u32, 24);
-        assert_eq!(StdlibDiscriminants::Equal as u32, 25);
-        assert_eq!(StdlibDiscriminants::Filter as u32, 26);
-        assert_eq!(StdlibDiscriminants::Flatten as u32, 27);
-        assert_eq!(StdlibDiscriminants::Floor as u32, 28);
-        assert_eq!(StdlibDiscriminants::Fold as u32, 29);
-        assert_eq!(StdlibDiscriminants::FormatErrorMessage as u32, 30);
-        assert_eq!(StdlibDiscriminants::Get as u32, 31);
-        assert_eq!(StdlibDiscriminants::GetVariable as u32, 32);
-        assert_eq!(StdlibDiscriminants::GraphQlResolver as u32, 33);
-        assert_eq!(StdlibDiscriminants::Gt as u32, 34);
-        assert_eq!(StdlibDiscriminants::Gte as u32, 35);
-        assert_eq!(StdlibDiscriminants::Has as u32, 36);
-        assert_eq!(StdlibDiscriminants::Hash as u32, 37);
-        assert_eq!(StdlibDiscriminants::Identity as u32, 38);
-        assert_eq!(StdlibDiscriminants::If as u32, 39);
-        assert_eq!(StdlibDiscriminants::IfError as u32, 40);
-        assert_eq!(StdlibDiscriminants::IfPending as u32, 41);
-        assert_eq!(StdlibDiscriminants::IncrementVariable as u32, 42);
-        assert_eq!(StdlibDiscriminants::Intersperse as u32, 43);
-        assert_eq!(StdlibDiscriminants::IsFinite as u32, 44);
-        assert_eq!(StdlibDiscriminants::IsTruthy as u32, 45);
-        assert_eq!(StdlibDiscriminants::Iterate as u32, 46);
-        assert_eq!(StdlibDiscriminants::Keys as u32, 47);
-        assert_eq!(StdlibDiscriminants::Length as u32, 48);
-        assert_eq!(StdlibDiscriminants::Log as u32, 49);
-        assert_eq!(StdlibDiscriminants::Lt as u32, 50);
-        assert_eq!(StdlibDiscriminants::Lte as u32, 51);
-        assert_eq!(StdlibDiscriminants::Map as u32, 52);
-        assert_eq!(StdlibDiscriminants::Max as u32, 53);
-        assert_eq!(StdlibDiscriminants::Merge as u32, 54);
-        assert_eq!(StdlibDiscriminants::Min as u32, 55);
-        assert_eq!(StdlibDiscriminants::Multiply as u32, 56);
-        assert_eq!(StdlibDiscriminants::Not as u32, 57);
-        assert_eq!(StdlibDiscriminants::Or as u32, 58);
-        assert_eq!(StdlibDiscriminants::ParseDate as u32, 59);
-        assert_eq!(StdlibDiscriminants::ParseFloat as u32, 60);
-        assert_eq!(StdlibDiscriminants::ParseInt as u32, 61);
-        assert_eq!(StdlibDiscriminants::ParseJson as u32, 62);
-        assert_eq!(StdlibDiscriminants::Pow as u32, 63);
-        assert_eq!(StdlibDiscriminants::Push as u32, 64);
-        assert_eq!(StdlibDiscriminants::PushFront as u32, 65);
-        assert_eq!(StdlibDiscriminants::Raise as u32, 66);
-        assert_eq!(StdlibDiscriminants::Remainder as u32, 67);
-        assert_eq!(StdlibDiscriminants::Replace as u32, 68);
-        assert_eq!(StdlibDiscriminants::ResolveArgs as u32, 69);
-        assert_eq!(StdlibDiscriminants::ResolveDeep as u32, 70);
-        assert_eq!(StdlibDiscriminants::ResolveHashmap as u32, 71);
-        assert_eq!(StdlibDiscriminants::ResolveHashset as u32, 72);
-        assert_eq!(StdlibDiscriminants::ResolveList as u32, 73);
-        assert_eq!(StdlibDiscriminants::ResolveLoaderResults as u32, 74);
-        assert_eq!(StdlibDiscriminants::ResolveQueryBranch as u32, 75);
-        assert_eq!(StdlibDiscriminants::ResolveQueryLeaf as u32, 76);
-        assert_eq!(StdlibDiscriminants::ResolveRecord as u32, 77);
-        assert_eq!(StdlibDiscriminants::ResolveTree as u32, 78);
-        assert_eq!(StdlibDiscriminants::Round as u32, 79);
-        assert_eq!(StdlibDiscriminants::Scan as u32, 80);
-        assert_eq!(StdlibDiscriminants::Sequence as u32, 81);
-        assert_eq!(StdlibDiscriminants::Set as u32, 82);
-        assert_eq!(StdlibDiscriminants::SetVariable as u32, 83);
-        assert_eq!(StdlibDiscriminants::Skip as u32, 84);
-        assert_eq!(StdlibDiscriminants::Slice as u32, 85);
-        assert_eq!(StdlibDiscriminants::Split as u32, 86);
-        assert_eq!(StdlibDiscriminants::StartsWith as u32, 87);
-        assert_eq!(StdlibDiscriminants::StringifyJson as u32, 88);
-        assert_eq!(StdlibDiscriminants::Subtract as u32, 89);
-        assert_eq!(StdlibDiscriminants::Take as u32, 90);
-        assert_eq!(StdlibDiscriminants::Throw as u32, 91);
-        assert_eq!(StdlibDiscriminants::ToRequest as u32, 92);
-        assert_eq!(StdlibDiscriminants::ToString as u32, 93);
-        assert_eq!(StdlibDiscriminants::Urlencode as u32, 94);
-        assert_eq!(StdlibDiscriminants::Unzip as u32, 95);
-        assert_eq!(StdlibDiscriminants::Values as u32, 96);
-        assert_eq!(StdlibDiscriminants::Zip as u32, 97);
+        assert_eq!(StdlibDiscriminants::DateAdd as u32, 19);
+        assert_eq!(StdlibDiscriminants::DateDiff as u32, 20);
+        assert_eq!(StdlibDiscriminants::DateOffset as u32, 21);
+        assert_eq!(StdlibDiscriminants::DateTruncate as u32, 22);
+        assert_eq!(StdlibDiscriminants::DateWithOffset as u32, 23);
+        assert_eq!(StdlibDiscriminants::Debounce as u32, 24);
+        assert_eq!(StdlibDiscriminants::Debug as u32, 25);
+        assert_eq!(StdlibDiscriminants::DecrementVariable as u32, 26);
+        assert_eq!(StdlibDiscriminants::Dedupe as u32, 27);
+        assert_eq!(StdlibDiscriminants::Delete as u32, 28);
+        assert_eq!(StdlibDiscriminants::Divide as u32, 29);
+        assert_eq!(StdlibDiscriminants::Duration as u32, 30);
+        assert_eq!(StdlibDiscriminants::Effect as u32, 31);
+        assert_eq!(StdlibDiscriminants::EndsWith as u32, 32);
+        assert_eq!(StdlibDiscriminants::Eq as u32, 33);
+        assert_eq!(StdlibDiscriminants::Equal as u32, 34);
+        assert_eq!(StdlibDiscriminants::Eval as u32, 35);
+        assert_eq!(StdlibDiscriminants::Filter as u32, 36);
+        assert_eq!(StdlibDiscriminants::Flatten as u32, 37);
+        assert_eq!(StdlibDiscriminants::Floor as u32, 38);
+        assert_eq!(StdlibDiscriminants::Fold as u32, 39);
+        assert_eq!(StdlibDiscriminants::FormatErrorMessage as u32, 40);
+        assert_eq!(StdlibDiscriminants::Get as u32, 41);
+        assert_eq!(StdlibDiscriminants::GetVariable as u32, 42);
+        assert_eq!(StdlibDiscriminants::GraphQlResolver as u32, 43);
+        assert_eq!(StdlibDiscriminants::Gt as u32, 44);
+        assert_eq!(StdlibDiscriminants::Gte as u32, 45);
+        assert_eq!(StdlibDiscriminants::Has as u32, 46);
+        assert_eq!(StdlibDiscriminants::Hash as u32, 47);
+        assert_eq!(StdlibDiscriminants::HashmapFromIterator as u32, 48);
+        assert_eq!(StdlibDiscriminants::HashsetFromIterator as u32, 49);
+        assert_eq!(StdlibDiscriminants::Identity as u32, 50);
+        assert_eq!(StdlibDiscriminants::If as u32, 51);
+        assert_eq!(StdlibDiscriminants::IfError as u32, 52);
+        assert_eq!(StdlibDiscriminants::IfPending as u32, 53);
+        assert_eq!(StdlibDiscriminants::IncrementVariable as u32, 54);
+        assert_eq!(StdlibDiscriminants::Intersperse as u32, 55);
+        assert_eq!(StdlibDiscriminants::IsFinite as u32, 56);
+        assert_eq!(StdlibDiscriminants::IsTruthy as u32, 57);
+        assert_eq!(StdlibDiscriminants::Iterate as u32, 58);
+        assert_eq!(StdlibDiscriminants::Keys as u32, 59);
+        assert_eq!(StdlibDiscriminants::Length as u32, 60);
+        assert_eq!(StdlibDiscriminants::Log as u32, 61);
+        assert_eq!(StdlibDiscriminants::LogScope as u32, 62);
+        assert_eq!(StdlibDiscriminants::Lt as u32, 63);
+        assert_eq!(StdlibDiscriminants::Lte as u32, 64);
+        assert_eq!(StdlibDiscriminants::Map as u32, 65);
+        assert_eq!(StdlibDiscriminants::Match as u32, 66);
+        assert_eq!(StdlibDiscriminants::Max as u32, 67);
+        assert_eq!(StdlibDiscriminants::Memo as u32, 68);
+        assert_eq!(StdlibDiscriminants::MemoStore as u32, 69);
+        assert_eq!(StdlibDiscriminants::Merge as u32, 70);
+        assert_eq!(StdlibDiscriminants::Min as u32, 71);
+        assert_eq!(StdlibDiscriminants::Multiply as u32, 72);
+        assert_eq!(StdlibDiscriminants::Not as u32, 73);
+        assert_eq!(StdlibDiscriminants::Or as u32, 74);
+        assert_eq!(StdlibDiscriminants::Paginate as u32, 75);
+        assert_eq!(StdlibDiscriminants::ParseDate as u32, 76);
+        assert_eq!(StdlibDiscriminants::ParseFloat as u32, 77);
+        assert_eq!(StdlibDiscriminants::ParseInt as u32, 78);
+        assert_eq!(StdlibDiscriminants::ParseJson as u32, 79);
+        assert_eq!(StdlibDiscriminants::Pow as u32, 80);
+        assert_eq!(StdlibDiscriminants::Push as u32, 81);
+        assert_eq!(StdlibDiscriminants::PushFront as u32, 82);
+        assert_eq!(StdlibDiscriminants::Quote as u32, 83);
+        assert_eq!(StdlibDiscriminants::Raise as u32, 84);
+        assert_eq!(StdlibDiscriminants::Remainder as u32, 85);
+        assert_eq!(StdlibDiscriminants::Replace as u32, 86);
+        assert_eq!(StdlibDiscriminants::ResolveArgs as u32, 87);
+        assert_eq!(StdlibDiscriminants::ResolveDeep as u32, 88);
+        assert_eq!(StdlibDiscriminants::ResolveHashmap as u32, 89);
+        assert_eq!(StdlibDiscriminants::ResolveHashset as u32, 90);
+        assert_eq!(StdlibDiscriminants::ResolveList as u32, 91);
+        assert_eq!(StdlibDiscriminants::ResolveLoaderResults as u32, 92);
+        assert_eq!(StdlibDiscriminants::ResolveQueryBranch as u32, 93);
+        assert_eq!(StdlibDiscriminants::ResolveQueryLeaf as u32, 94);
+        assert_eq!(StdlibDiscriminants::ResolveRecord as u32, 95);
+        assert_eq!(StdlibDiscriminants::ResolveTree as u32, 96);
+        assert_eq!(StdlibDiscriminants::Reveal as u32, 97);
+        assert_eq!(StdlibDiscriminants::Round as u32, 98);
+        assert_eq!(StdlibDiscriminants::Sample as u32, 99);
+        assert_eq!(StdlibDiscriminants::Scan as u32, 100);
+        assert_eq!(StdlibDiscriminants::ScopedLog as u32, 101);
+        assert_eq!(StdlibDiscriminants::Sequence as u32, 102);
+        assert_eq!(StdlibDiscriminants::Set as u32, 103);
+        assert_eq!(StdlibDiscriminants::SetVariable as u32, 104);
+        assert_eq!(StdlibDiscriminants::Skip as u32, 105);
+        assert_eq!(StdlibDiscriminants::Slice as u32, 106);
+        assert_eq!(StdlibDiscriminants::Sort as u32, 107);
+        assert_eq!(StdlibDiscriminants::SortBy as u32, 108);
+        assert_eq!(StdlibDiscriminants::SortMerge as u32, 109);
+        assert_eq!(StdlibDiscriminants::Split as u32, 110);
+        assert_eq!(StdlibDiscriminants::StartsWith as u32, 111);
+        assert_eq!(StdlibDiscriminants::StringifyJson as u32, 112);
+        assert_eq!(StdlibDiscriminants::StringifyJsonCanonical as u32, 113);
+        assert_eq!(StdlibDiscriminants::Subtract as u32, 114);
+        assert_eq!(StdlibDiscriminants::Take as u32, 115);
+        assert_eq!(StdlibDiscriminants::Template as u32, 116);
+        assert_eq!(StdlibDiscriminants::Throttle as u32, 117);
+        assert_eq!(StdlibDiscriminants::Throw as u32, 118);
+        assert_eq!(StdlibDiscriminants::ToRequest as u32, 119);
+        assert_eq!(StdlibDiscriminants::ToString as u32, 120);
+        assert_eq!(StdlibDiscriminants::Urlencode as u32, 121);
+        assert_eq!(StdlibDiscriminants::Unzip as u32, 122);
+        assert_eq!(StdlibDiscriminants::Values as u32, 123);
+        assert_eq!(StdlibDiscriminants::Zip as u32, 124);
     }
 }
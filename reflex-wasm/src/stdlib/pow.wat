@@ -10,13 +10,16 @@
       (i32.eq (global.get $TermType::Int))
       (func $Stdlib_Pow::impl::Int::Int (param $self i32) (param $exponent i32) (param $state i32) (result i32 i32)
         (local $self_value i64)
-        (local $exponent_value i32)
+        (local $exponent_value i64)
         (local $is_negative_exponent i32)
+        (local $float_value f64)
+        (local $int_value i64)
+        (local $is_valid i32)
         (if (result i32 i32)
           ;; If the base is zero and the exponent is negative, return an error
           (i32.and
             (i64.eqz (local.tee $self_value (call $Term::Int::get::value (local.get $self))))
-            (local.tee $is_negative_exponent (i32.lt_s (local.tee $exponent_value (i32.wrap_i64 (call $Term::Int::get::value (local.get $exponent)))) (i32.const 0))))
+            (local.tee $is_negative_exponent (i64.lt_s (local.tee $exponent_value (call $Term::Int::get::value (local.get $exponent))) (i64.const 0))))
           (then
             (call $Term::Signal::of
               (call $Term::Condition::invalid_builtin_function_args
@@ -29,18 +32,35 @@
               (local.get $is_negative_exponent)
               (then
                 ;; If the exponent is negative, perform a fast float exponentiation operation
-                (call $Term::Float::new
+                (call $Utils::f64::checked_result
                   (call $Utils::f64::pow_int
                     (f64.convert_i64_s (local.get $self_value))
-                    (local.get $exponent_value)))
-                (global.get $NULL))
+                    (call $Utils::i64::clamp_exponent_i32 (local.get $exponent_value))))
+                (local.set $is_valid)
+                (local.set $float_value)
+                (if (result i32 i32)
+                  (local.get $is_valid)
+                  (then
+                    (call $Term::Float::new (local.get $float_value))
+                    (global.get $NULL))
+                  (else
+                    (call $Term::Signal::of (call $Term::Condition::invalid_float_result))
+                    (global.get $NULL))))
               (else
                 ;; If the exponent is non-negative, perform a fast integer exponentiation operation
-                (call $Term::Int::new
-                  (call $Utils::i64::pow
-                    (call $Term::Int::get::value (local.get $self))
-                    (local.get $exponent_value)))
-                (global.get $NULL)))))))
+                (call $Utils::i64::checked_pow
+                  (local.get $self_value)
+                  (call $Utils::i64::clamp_exponent_u32 (local.get $exponent_value)))
+                (local.set $is_valid)
+                (local.set $int_value)
+                (if (result i32 i32)
+                  (local.get $is_valid)
+                  (then
+                    (call $Term::Int::new (local.get $int_value))
+                    (global.get $NULL))
+                  (else
+                    (call $Term::Signal::of (call $Term::Condition::integer_overflow))
+                    (global.get $NULL)))))))))
 
     (@impl
       (i32.eq (global.get $TermType::Float))
@@ -49,13 +69,14 @@
         (local $self_value f64)
         (local $exponent_value f64)
         (local $is_integer_exponent i32)
-        (local $is_negative_exponent i32)
+        (local $value f64)
+        (local $is_valid i32)
         (if (result i32 i32)
           ;; If the base is zero and the exponent is negative, or if the base is negative and the exponent is not an integer, return an error
           (i32.or
             (i32.and
               (f64.eq (local.tee $self_value (call $Term::Float::get::value (local.get $self))) (f64.const 0))
-              (local.tee $is_negative_exponent (f64.lt (local.tee $exponent_value (call $Term::Float::get::value (local.get $exponent))) (f64.const 0))))
+              (f64.lt (local.tee $exponent_value (call $Term::Float::get::value (local.get $exponent))) (f64.const 0)))
             (i32.and
               (f64.lt (local.get $self_value) (f64.const 0))
               (i32.eqz (local.tee $is_integer_exponent (call $Utils::f64::is_integer (local.get $exponent_value))))))
@@ -67,32 +88,30 @@
             (global.get $NULL))
           (else
             ;; Otherwise perform the exponentiation operation
-            (call $Term::Float::new
+            (call $Utils::f64::checked_result
               (if (result f64)
-                ;; If the exponent is an integer, perform a fast exponentiation operation
+                ;; If the exponent is an integer, perform a fast float exponentiation operation (integer exponentiation is
+                ;; avoided here as it would silently wrap results that overflow the integer range)
                 (local.get $is_integer_exponent)
                 (then
-                  (if (result f64)
-                    (i32.and
-                      (call $Utils::f64::is_integer (local.get $self_value))
-                      (i32.eqz (local.get $is_negative_exponent)))
-                    (then
-                      ;; If base is an integer and the exponent is a non-negative integer, perform a fast integer exponentiation operation
-                      (f64.convert_i64_s
-                        (call $Utils::i64::pow
-                          (i64.trunc_f64_s (local.get $self_value))
-                          (i32.trunc_f64_s (local.get $exponent_value)))))
-                    (else
-                      ;; If the exponent is an integer, perform a fast float exponentiation operation
-                      (call $Utils::f64::pow_int
-                        (local.get $self_value)
-                        (i32.trunc_f64_s (local.get $exponent_value))))))
+                  (call $Utils::f64::pow_int
+                    (local.get $self_value)
+                    (i32.trunc_f64_s (local.get $exponent_value))))
                 (else
                   ;; Otherwise if the exponent is not an integer, perform the default float exponentiation operation
                   (call $Utils::f64::pow
                     (local.get $self_value)
                     (local.get $exponent_value)))))
-            (global.get $NULL)))))
+            (local.set $is_valid)
+            (local.set $value)
+            (if (result i32 i32)
+              (local.get $is_valid)
+              (then
+                (call $Term::Float::new (local.get $value))
+                (global.get $NULL))
+              (else
+                (call $Term::Signal::of (call $Term::Condition::invalid_float_result))
+                (global.get $NULL)))))))
 
     (@impl
       (i32.eq (global.get $TermType::Int))
@@ -101,13 +120,14 @@
         (local $self_value i64)
         (local $exponent_value f64)
         (local $is_integer_exponent i32)
-        (local $is_negative_exponent i32)
+        (local $value f64)
+        (local $is_valid i32)
         (if (result i32 i32)
           ;; If the base is zero and the exponent is negative, or if the base is negative and the exponent is not an integer, return an error
           (i32.or
             (i32.and
               (i64.eqz (local.tee $self_value (call $Term::Int::get::value (local.get $self))))
-              (local.tee $is_negative_exponent (f64.lt (local.tee $exponent_value (call $Term::Float::get::value (local.get $exponent))) (f64.const 0))))
+              (f64.lt (local.tee $exponent_value (call $Term::Float::get::value (local.get $exponent))) (f64.const 0)))
             (i32.and
               (i64.lt_s (local.get $self_value) (i64.const 0))
               (i32.eqz (local.tee $is_integer_exponent (call $Utils::f64::is_integer (local.get $exponent_value))))))
@@ -119,43 +139,44 @@
             (global.get $NULL))
           (else
             ;; Otherwise perform the exponentiation operation
-            (call $Term::Float::new
+            (call $Utils::f64::checked_result
               (if (result f64)
-                ;; If the exponent is an integer, perform a fast exponentiation operation
+                ;; If the exponent is an integer, perform a fast float exponentiation operation (integer exponentiation is
+                ;; avoided here as it would silently wrap results that overflow the integer range)
                 (local.get $is_integer_exponent)
                 (then
-                  (if (result f64)
-                    (local.get $is_negative_exponent)
-                    (then
-                      ;; If the exponent is negative, perform a fast float exponentiation operation
-                      (call $Utils::f64::pow_int
-                        (f64.convert_i64_s (local.get $self_value))
-                        (i32.trunc_f64_s (local.get $exponent_value))))
-                    (else
-                      ;; If the exponent is non-negative, perform a fast integer exponentiation operation
-                      (f64.convert_i64_s
-                        (call $Utils::i64::pow
-                          (local.get $self_value)
-                          (i32.trunc_f64_s (local.get $exponent_value)))))))
+                  (call $Utils::f64::pow_int
+                    (f64.convert_i64_s (local.get $self_value))
+                    (i32.trunc_f64_s (local.get $exponent_value))))
                 (else
                   ;; Otherwise if the exponent is not an integer, perform the default float exponentiation operation
                   (call $Utils::f64::pow
                     (f64.convert_i64_s (local.get $self_value))
                     (local.get $exponent_value)))))
-            (global.get $NULL)))))
+            (local.set $is_valid)
+            (local.set $value)
+            (if (result i32 i32)
+              (local.get $is_valid)
+              (then
+                (call $Term::Float::new (local.get $value))
+                (global.get $NULL))
+              (else
+                (call $Term::Signal::of (call $Term::Condition::invalid_float_result))
+                (global.get $NULL)))))))
 
     (@impl
       (i32.eq (global.get $TermType::Float))
       (i32.eq (global.get $TermType::Int))
       (func $Stdlib_Pow::impl::Float::Int (param $self i32) (param $exponent i32) (param $state i32) (result i32 i32)
         (local $self_value f64)
-        (local $exponent_value i32)
-        (local $is_negative_exponent i32)
+        (local $exponent_value i64)
+        (local $value f64)
+        (local $is_valid i32)
         (if (result i32 i32)
           ;; If the base is zero and the exponent is negative, return an error
           (i32.and
             (f64.eq (local.tee $self_value (call $Term::Float::get::value (local.get $self))) (f64.const 0))
-            (local.tee $is_negative_exponent (i32.lt_s (local.tee $exponent_value (i32.wrap_i64 (call $Term::Int::get::value (local.get $exponent)))) (i32.const 0))))
+            (i64.lt_s (local.tee $exponent_value (call $Term::Int::get::value (local.get $exponent))) (i64.const 0)))
           (then
             (call $Term::Signal::of
               (call $Term::Condition::invalid_builtin_function_args
@@ -163,24 +184,22 @@
                 (call $Term::List::create_pair (local.get $self) (local.get $exponent))))
             (global.get $NULL))
           (else
-            ;; Otherwise perform the exponentiation operation
-            (call $Term::Float::new
-              (if (result f64)
-                (i32.or
-                  (i32.eqz (call $Utils::f64::is_integer (local.tee $self_value (call $Term::Float::get::value (local.get $self)))))
-                  (local.get $is_negative_exponent))
-                (then
-                  ;; If the base is not an integer, or if the exponent is negative, perform a fast float exponentiation operation
-                  (call $Utils::f64::pow_int
-                    (local.get $self_value)
-                    (local.get $exponent_value)))
-                (else
-                  ;; Otherwise if the base is an integer and the exponent is non-negative, perform a fast integer exponentiation operation
-                  (f64.convert_i64_s
-                    (call $Utils::i64::pow
-                      (i64.trunc_f64_s (local.get $self_value))
-                      (local.get $exponent_value))))))
-            (global.get $NULL)))))
+            ;; Otherwise perform a fast float exponentiation operation (integer exponentiation is avoided here as it would
+            ;; silently wrap results that overflow the integer range)
+            (call $Utils::f64::checked_result
+              (call $Utils::f64::pow_int
+                (local.get $self_value)
+                (call $Utils::i64::clamp_exponent_i32 (local.get $exponent_value))))
+            (local.set $is_valid)
+            (local.set $value)
+            (if (result i32 i32)
+              (local.get $is_valid)
+              (then
+                (call $Term::Float::new (local.get $value))
+                (global.get $NULL))
+              (else
+                (call $Term::Signal::of (call $Term::Condition::invalid_float_result))
+                (global.get $NULL)))))))
 
     (@default
       (func $Stdlib_Pow::impl::default (param $self i32) (param $exponent i32) (param $state i32) (result i32 i32)
@@ -0,0 +1,23 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{uuid, ArgType, Arity, FunctionArity, Uid, Uuid};
+
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Quote;
+impl Quote {
+    pub const UUID: Uuid = uuid!("1fe9f134-1280-491f-bc9a-33f827f1d421");
+    const ARITY: FunctionArity<1, 0> = FunctionArity {
+        required: [ArgType::Lazy],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity(&self) -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for Quote {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
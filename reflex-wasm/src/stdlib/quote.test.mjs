@@ -0,0 +1,110 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_Quote', (test) => {
+    test('(Value)', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.Quote),
+        createUnitList(createInt(3)),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(format(result), '{ "type": "Value", "value": 3 }');
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+
+    test('(Application)', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createPair,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.Quote),
+        createUnitList(
+          createApplication(createBuiltin(Stdlib.Add), createPair(createInt(3), createInt(4))),
+        ),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(
+        format(result),
+        '{ "type": "Application", "target": { "type": "Value", "value": Add }, "args": [{ "type": "Value", "value": 3 }, { "type": "Value", "value": 4 }] }',
+      );
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+
+    test('(Lambda)', (assert, {
+      createApplication,
+      createBuiltin,
+      createLambda,
+      createLet,
+      createInt,
+      createUnitList,
+      createVariable,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.Quote),
+        createUnitList(createLambda(1, createLet(createInt(3), createVariable(1)))),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(
+        format(result),
+        '{ "type": "Lambda", "arity": 1, "body": { "type": "Let", "initializer": { "type": "Value", "value": 3 }, "body": { "type": "Variable", "offset": 1 } } }',
+      );
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+
+    test('(List)', (assert, {
+      createApplication,
+      createBuiltin,
+      createEmptyList,
+      createInt,
+      createPair,
+      createRecord,
+      createString,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.Quote),
+        createUnitList(
+          createPair(
+            createEmptyList(),
+            createRecord(createUnitList(createString('foo')), createUnitList(createInt(3))),
+          ),
+        ),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(
+        format(result),
+        '{ "type": "List", "items": [{ "type": "List", "items": [] }, { "type": "Record", "keys": ["foo"], "values": [{ "type": "Value", "value": 3 }] }] }',
+      );
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+  });
+};
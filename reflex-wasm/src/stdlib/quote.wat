@@ -0,0 +1,177 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@const-string $Stdlib_Quote::FIELD_TYPE "type")
+  (@const-string $Stdlib_Quote::FIELD_TARGET "target")
+  (@const-string $Stdlib_Quote::FIELD_ARGS "args")
+  (@const-string $Stdlib_Quote::FIELD_ARITY "arity")
+  (@const-string $Stdlib_Quote::FIELD_BODY "body")
+  (@const-string $Stdlib_Quote::FIELD_INITIALIZER "initializer")
+  (@const-string $Stdlib_Quote::FIELD_OFFSET "offset")
+  (@const-string $Stdlib_Quote::FIELD_ITEMS "items")
+  (@const-string $Stdlib_Quote::FIELD_KEYS "keys")
+  (@const-string $Stdlib_Quote::FIELD_VALUES "values")
+  (@const-string $Stdlib_Quote::FIELD_VALUE "value")
+  (@const-string $Stdlib_Quote::FIELD_NAME "name")
+
+  (@const-string $Stdlib_Quote::TYPE_APPLICATION "Application")
+  (@const-string $Stdlib_Quote::TYPE_LAMBDA "Lambda")
+  (@const-string $Stdlib_Quote::TYPE_LET "Let")
+  (@const-string $Stdlib_Quote::TYPE_VARIABLE "Variable")
+  (@const-string $Stdlib_Quote::TYPE_LIST "List")
+  (@const-string $Stdlib_Quote::TYPE_RECORD "Record")
+  (@const-string $Stdlib_Quote::TYPE_VALUE "Value")
+  (@const-string $Stdlib_Quote::TYPE_SPLICE "Splice")
+
+  (@const $Stdlib_Quote::APPLICATION_KEYS i32
+    (@depends-on $Stdlib_Quote::FIELD_TYPE)
+    (@depends-on $Stdlib_Quote::FIELD_TARGET)
+    (@depends-on $Stdlib_Quote::FIELD_ARGS)
+    (call $Term::List::create_triple
+      (global.get $Stdlib_Quote::FIELD_TYPE)
+      (global.get $Stdlib_Quote::FIELD_TARGET)
+      (global.get $Stdlib_Quote::FIELD_ARGS)))
+
+  (@const $Stdlib_Quote::LAMBDA_KEYS i32
+    (@depends-on $Stdlib_Quote::FIELD_TYPE)
+    (@depends-on $Stdlib_Quote::FIELD_ARITY)
+    (@depends-on $Stdlib_Quote::FIELD_BODY)
+    (call $Term::List::create_triple
+      (global.get $Stdlib_Quote::FIELD_TYPE)
+      (global.get $Stdlib_Quote::FIELD_ARITY)
+      (global.get $Stdlib_Quote::FIELD_BODY)))
+
+  (@const $Stdlib_Quote::LET_KEYS i32
+    (@depends-on $Stdlib_Quote::FIELD_TYPE)
+    (@depends-on $Stdlib_Quote::FIELD_INITIALIZER)
+    (@depends-on $Stdlib_Quote::FIELD_BODY)
+    (call $Term::List::create_triple
+      (global.get $Stdlib_Quote::FIELD_TYPE)
+      (global.get $Stdlib_Quote::FIELD_INITIALIZER)
+      (global.get $Stdlib_Quote::FIELD_BODY)))
+
+  (@const $Stdlib_Quote::VARIABLE_KEYS i32
+    (@depends-on $Stdlib_Quote::FIELD_TYPE)
+    (@depends-on $Stdlib_Quote::FIELD_OFFSET)
+    (call $Term::List::create_pair
+      (global.get $Stdlib_Quote::FIELD_TYPE)
+      (global.get $Stdlib_Quote::FIELD_OFFSET)))
+
+  (@const $Stdlib_Quote::LIST_KEYS i32
+    (@depends-on $Stdlib_Quote::FIELD_TYPE)
+    (@depends-on $Stdlib_Quote::FIELD_ITEMS)
+    (call $Term::List::create_pair
+      (global.get $Stdlib_Quote::FIELD_TYPE)
+      (global.get $Stdlib_Quote::FIELD_ITEMS)))
+
+  (@const $Stdlib_Quote::RECORD_KEYS i32
+    (@depends-on $Stdlib_Quote::FIELD_TYPE)
+    (@depends-on $Stdlib_Quote::FIELD_KEYS)
+    (@depends-on $Stdlib_Quote::FIELD_VALUES)
+    (call $Term::List::create_triple
+      (global.get $Stdlib_Quote::FIELD_TYPE)
+      (global.get $Stdlib_Quote::FIELD_KEYS)
+      (global.get $Stdlib_Quote::FIELD_VALUES)))
+
+  (@const $Stdlib_Quote::VALUE_KEYS i32
+    (@depends-on $Stdlib_Quote::FIELD_TYPE)
+    (@depends-on $Stdlib_Quote::FIELD_VALUE)
+    (call $Term::List::create_pair
+      (global.get $Stdlib_Quote::FIELD_TYPE)
+      (global.get $Stdlib_Quote::FIELD_VALUE)))
+
+  (@builtin $Stdlib_Quote "Quote"
+    (@args (@lazy $self))
+
+    (@default
+      (func $Stdlib_Quote::impl::default (param $self i32) (param $state i32) (result i32 i32)
+        (call $Stdlib_Quote::quote (local.get $self))
+        (global.get $NULL))))
+
+  (func $Stdlib_Quote::quote (param $self i32) (result i32)
+    ;; Any term that is not one of the structural term types is wrapped as-is in a Value node
+    ;; (note that compiled closures are opaque, and will therefore be quoted as Value nodes)
+    (if (result i32)
+      (call $Term::Application::is (local.get $self))
+      (then
+        (call $Term::Record::new
+          (global.get $Stdlib_Quote::APPLICATION_KEYS)
+          (call $Term::List::create_triple
+            (global.get $Stdlib_Quote::TYPE_APPLICATION)
+            (call $Stdlib_Quote::quote (call $Term::Application::get::target (local.get $self)))
+            (call $Stdlib_Quote::quote_list (call $Term::Application::get::args (local.get $self))))))
+      (else
+        (if (result i32)
+          (call $Term::Lambda::is (local.get $self))
+          (then
+            (call $Term::Record::new
+              (global.get $Stdlib_Quote::LAMBDA_KEYS)
+              (call $Term::List::create_triple
+                (global.get $Stdlib_Quote::TYPE_LAMBDA)
+                (call $Term::Int::new (i64.extend_i32_u (call $Term::Lambda::get_num_args (local.get $self))))
+                (call $Stdlib_Quote::quote (call $Term::Lambda::get_body (local.get $self))))))
+          (else
+            (if (result i32)
+              (call $Term::Let::is (local.get $self))
+              (then
+                (call $Term::Record::new
+                  (global.get $Stdlib_Quote::LET_KEYS)
+                  (call $Term::List::create_triple
+                    (global.get $Stdlib_Quote::TYPE_LET)
+                    (call $Stdlib_Quote::quote (call $Term::Let::get::initializer (local.get $self)))
+                    (call $Stdlib_Quote::quote (call $Term::Let::get::body (local.get $self))))))
+              (else
+                (if (result i32)
+                  (call $Term::Variable::is (local.get $self))
+                  (then
+                    (call $Term::Record::new
+                      (global.get $Stdlib_Quote::VARIABLE_KEYS)
+                      (call $Term::List::create_pair
+                        (global.get $Stdlib_Quote::TYPE_VARIABLE)
+                        (call $Term::Int::new
+                          (i64.extend_i32_u (call $Term::Variable::get::stack_offset (local.get $self)))))))
+                  (else
+                    (if (result i32)
+                      (call $Term::is_list (local.get $self))
+                      (then
+                        (call $Term::Record::new
+                          (global.get $Stdlib_Quote::LIST_KEYS)
+                          (call $Term::List::create_pair
+                            (global.get $Stdlib_Quote::TYPE_LIST)
+                            (call $Stdlib_Quote::quote_list (local.get $self)))))
+                      (else
+                        (if (result i32)
+                          (call $Term::Record::is (local.get $self))
+                          (then
+                            (call $Term::Record::new
+                              (global.get $Stdlib_Quote::RECORD_KEYS)
+                              (call $Term::List::create_triple
+                                (global.get $Stdlib_Quote::TYPE_RECORD)
+                                (call $Term::Record::get::keys (local.get $self))
+                                (call $Stdlib_Quote::quote_list (call $Term::Record::get::values (local.get $self))))))
+                          (else
+                            (call $Term::Record::new
+                              (global.get $Stdlib_Quote::VALUE_KEYS)
+                              (call $Term::List::create_pair
+                                (global.get $Stdlib_Quote::TYPE_VALUE)
+                                (local.get $self))))))))))))))))
+
+  (func $Stdlib_Quote::quote_list (param $self i32) (result i32)
+    (local $results i32)
+    (local $length i32)
+    (local $index i32)
+    (if (result i32)
+      (i32.eqz (local.tee $length (call $Term::List::get_length (local.get $self))))
+      (then
+        (call $Term::List::empty))
+      (else
+        ;; Quote each of the list items into a new list of the same length
+        (local.set $results (call $Term::List::allocate (local.get $length)))
+        (loop $LOOP
+          (call $Term::List::set_item
+            (local.get $results)
+            (local.get $index)
+            (call $Stdlib_Quote::quote (call $Term::List::get_item (local.get $self) (local.get $index))))
+          (br_if $LOOP (i32.lt_u (local.tee $index (i32.add (local.get $index) (i32.const 1))) (local.get $length))))
+        (call $Term::List::init (local.get $results) (local.get $length))))))
@@ -27,6 +27,8 @@
       (i32.eq (global.get $TermType::Float))
       (func $Stdlib_Remainder::impl::Float::Float (param $self i32) (param $divisor i32) (param $state i32) (result i32 i32)
         (local $divisor_value f64)
+        (local $value f64)
+        (local $is_valid i32)
         (if (result i32 i32)
           (f64.eq (f64.const 0) (local.tee $divisor_value (call $Term::Float::get::value (local.get $divisor))))
           (then
@@ -36,9 +38,13 @@
                 (call $Term::List::create_pair (local.get $self) (local.get $divisor))))
             (global.get $NULL))
           (else
-            (call $Term::Float::new
+            (call $Utils::f64::checked_result
               (if (result f64)
-                (call $Utils::f64::is_integer (local.get $divisor_value))
+                ;; Dividends outside the 64-bit integer range (including NaN and infinite values) cannot be truncated to
+                ;; integers, so must use the default remainder operation
+                (i32.and
+                  (call $Utils::f64::is_integer (local.get $divisor_value))
+                  (f64.lt (f64.abs (call $Term::Float::get::value (local.get $self))) (f64.const 0x1p63)))
                 (then
                   ;; If the divisor is an integer, perform a fast remainder operation
                   (call $Utils::f64::remainder_int
@@ -49,13 +55,24 @@
                   (call $Utils::f64::remainder
                     (call $Term::Float::get::value (local.get $self))
                     (local.get $divisor_value)))))
-            (global.get $NULL)))))
+            (local.set $is_valid)
+            (local.set $value)
+            (if (result i32 i32)
+              (local.get $is_valid)
+              (then
+                (call $Term::Float::new (local.get $value))
+                (global.get $NULL))
+              (else
+                (call $Term::Signal::of (call $Term::Condition::invalid_float_result))
+                (global.get $NULL)))))))
 
     (@impl
       (i32.eq (global.get $TermType::Int))
       (i32.eq (global.get $TermType::Float))
       (func $Stdlib_Remainder::impl::Int::Float (param $self i32) (param $divisor i32) (param $state i32) (result i32 i32)
         (local $divisor_value f64)
+        (local $value f64)
+        (local $is_valid i32)
         (if (result i32 i32)
           (f64.eq (f64.const 0) (local.tee $divisor_value (call $Term::Float::get::value (local.get $divisor))))
           (then
@@ -65,7 +82,7 @@
                 (call $Term::List::create_pair (local.get $self) (local.get $divisor))))
             (global.get $NULL))
           (else
-            (call $Term::Float::new
+            (call $Utils::f64::checked_result
               (if (result f64)
                 (call $Utils::f64::is_integer (local.get $divisor_value))
                 (then
@@ -79,7 +96,16 @@
                   (call $Utils::f64::remainder
                     (f64.convert_i64_s (call $Term::Int::get::value (local.get $self)))
                     (local.get $divisor_value)))))
-            (global.get $NULL)))))
+            (local.set $is_valid)
+            (local.set $value)
+            (if (result i32 i32)
+              (local.get $is_valid)
+              (then
+                (call $Term::Float::new (local.get $value))
+                (global.get $NULL))
+              (else
+                (call $Term::Signal::of (call $Term::Condition::invalid_float_result))
+                (global.get $NULL)))))))
 
     (@impl
       (i32.eq (global.get $TermType::Float))
@@ -87,6 +113,8 @@
       (func $Stdlib_Remainder::impl::Float::Int (param $self i32) (param $divisor i32) (param $state i32) (result i32 i32)
         (local $self_value f64)
         (local $divisor_value i64)
+        (local $value f64)
+        (local $is_valid i32)
         (if (result i32 i32)
           (i64.eqz (local.tee $divisor_value (call $Term::Int::get::value (local.get $divisor))))
           (then
@@ -96,20 +124,107 @@
                 (call $Term::List::create_pair (local.get $self) (local.get $divisor))))
             (global.get $NULL))
           (else
-            (call $Term::Float::new
+            (call $Utils::f64::checked_result
               (if (result f64)
-                (call $Utils::f64::is_integer (local.tee $self_value (call $Term::Float::get::value (local.get $self))))
+                (f64.lt (f64.abs (local.tee $self_value (call $Term::Float::get::value (local.get $self)))) (f64.const 0x1p63))
                 (then
-                  ;; If the base is an integer, perform a fast integer remainder operation
-                  (f64.convert_i64_s
-                    (i64.rem_s
-                      (i64.trunc_f64_s (local.get $self_value))
-                      (local.get $divisor_value))))
+                  (if (result f64)
+                    (call $Utils::f64::is_integer (local.get $self_value))
+                    (then
+                      ;; If the base is an integer, perform a fast integer remainder operation
+                      (f64.convert_i64_s
+                        (i64.rem_s
+                          (i64.trunc_f64_s (local.get $self_value))
+                          (local.get $divisor_value))))
+                    (else
+                      ;; Otherwise fall perform a fast float remainder operation
+                      (call $Utils::f64::remainder_int
+                        (local.get $self_value)
+                        (local.get $divisor_value)))))
                 (else
-                  ;; Otherwise fall perform a fast float remainder operation
-                  (call $Utils::f64::remainder_int
+                  ;; Values outside the 64-bit integer range (including NaN and infinite values) cannot be truncated to
+                  ;; integers, so fall back to the default remainder operation
+                  (call $Utils::f64::remainder
                     (local.get $self_value)
-                    (local.get $divisor_value)))))
+                    (f64.convert_i64_s (local.get $divisor_value))))))
+            (local.set $is_valid)
+            (local.set $value)
+            (if (result i32 i32)
+              (local.get $is_valid)
+              (then
+                (call $Term::Float::new (local.get $value))
+                (global.get $NULL))
+              (else
+                (call $Term::Signal::of (call $Term::Condition::invalid_float_result))
+                (global.get $NULL)))))))
+
+    (@impl
+      (i32.eq (global.get $TermType::BigInt))
+      (i32.eq (global.get $TermType::BigInt))
+      (func $Stdlib_Remainder::impl::BigInt::BigInt (param $self i32) (param $divisor i32) (param $state i32) (result i32 i32)
+        (local $remainder i32)
+        (if (result i32 i32)
+          (i32.eqz (call $Term::BigInt::get::limbs::length (local.get $divisor)))
+          (then
+            (call $Term::Signal::of
+              (call $Term::Condition::invalid_builtin_function_args
+                (global.get $Stdlib_Remainder)
+                (call $Term::List::create_pair (local.get $self) (local.get $divisor))))
+            (global.get $NULL))
+          (else
+            ;; Discard the quotient and return the remainder
+            (call $Term::BigInt::divide
+              (local.get $self)
+              (local.get $divisor))
+            (local.set $remainder)
+            (drop)
+            (local.get $remainder)
+            (global.get $NULL)))))
+
+    (@impl
+      (i32.eq (global.get $TermType::BigInt))
+      (i32.eq (global.get $TermType::Int))
+      (func $Stdlib_Remainder::impl::BigInt::Int (param $self i32) (param $divisor i32) (param $state i32) (result i32 i32)
+        (local $remainder i32)
+        (if (result i32 i32)
+          (i64.eqz (call $Term::Int::get::value (local.get $divisor)))
+          (then
+            (call $Term::Signal::of
+              (call $Term::Condition::invalid_builtin_function_args
+                (global.get $Stdlib_Remainder)
+                (call $Term::List::create_pair (local.get $self) (local.get $divisor))))
+            (global.get $NULL))
+          (else
+            ;; Discard the quotient and return the remainder
+            (call $Term::BigInt::divide
+              (local.get $self)
+              (call $Term::BigInt::from_i64 (call $Term::Int::get::value (local.get $divisor))))
+            (local.set $remainder)
+            (drop)
+            (local.get $remainder)
+            (global.get $NULL)))))
+
+    (@impl
+      (i32.eq (global.get $TermType::Int))
+      (i32.eq (global.get $TermType::BigInt))
+      (func $Stdlib_Remainder::impl::Int::BigInt (param $self i32) (param $divisor i32) (param $state i32) (result i32 i32)
+        (local $remainder i32)
+        (if (result i32 i32)
+          (i32.eqz (call $Term::BigInt::get::limbs::length (local.get $divisor)))
+          (then
+            (call $Term::Signal::of
+              (call $Term::Condition::invalid_builtin_function_args
+                (global.get $Stdlib_Remainder)
+                (call $Term::List::create_pair (local.get $self) (local.get $divisor))))
+            (global.get $NULL))
+          (else
+            ;; Discard the quotient and return the remainder
+            (call $Term::BigInt::divide
+              (call $Term::BigInt::from_i64 (call $Term::Int::get::value (local.get $self)))
+              (local.get $divisor))
+            (local.set $remainder)
+            (drop)
+            (local.get $remainder)
             (global.get $NULL)))))
 
     (@default
@@ -109,5 +109,57 @@ export default (describe) => {
         assert.deepEqual(getStateDependencies(dependencies), []);
       })();
     });
+
+    test('(String, Regex, String)', (assert, {
+      createApplication,
+      createBuiltin,
+      createRegex,
+      createString,
+      createTriple,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Replace),
+          createTriple(
+            createString('foo123bar456'),
+            createRegex(createString('\\d+')),
+            createString('-'),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '"foo-bar456"');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Replace),
+          createTriple(
+            createString('foo12-34bar'),
+            createRegex(createString('(\\d+)-(\\d+)')),
+            createString('$2$1'),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '"foo3412bar"');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Replace),
+          createTriple(createString('foo'), createRegex(createString('(')), createString('')),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(
+          format(result),
+          '{<InvalidFunctionArgsCondition:Replace("foo", /(/, "")>}',
+        );
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
   });
 };
@@ -16,6 +16,19 @@
           (call $Term::String::get_offset (local.get $replacement)) (call $Term::String::get_length (local.get $replacement)))
         (global.get $NULL)))
 
+    (@impl
+      (i32.eq (global.get $TermType::String))
+      (i32.eq (global.get $TermType::Regex))
+      (i32.eq (global.get $TermType::String))
+      (func $Stdlib_Replace::impl::String::Regex::String (param $self i32) (param $pattern i32) (param $replacement i32) (param $state i32) (result i32 i32)
+        (if (result i32 i32)
+          (call $Term::Regex::is_valid (local.get $pattern))
+          (then
+            (call $Term::Regex::replace (local.get $pattern) (local.get $self) (local.get $replacement))
+            (global.get $NULL))
+          (else
+            (call $Stdlib_Replace::impl::default (local.get $self) (local.get $pattern) (local.get $replacement) (local.get $state))))))
+
     (@default
       (func $Stdlib_Replace::impl::default (param $self i32) (param $pattern i32) (param $replacement i32) (param $state i32) (result i32 i32)
         (call $Term::Signal::of
@@ -196,7 +196,7 @@
     (@switch
       (@list
         (@list
-          (i32.eq (local.get $term_type) (global.get $TermType::List))
+          (call $TermType::is_list (local.get $term_type))
           (return (call $Stdlib_ResolveDeep::is_fully_resolved::List (local.get $target))))
         (@list
           (i32.eq (local.get $term_type) (global.get $TermType::Record))
@@ -221,7 +221,7 @@
     (local $index i32)
     (if (result i32)
       ;; If the list is empty, return true
-      (i32.eqz (local.tee $length (call $Term::List::get::items::length (local.get $self))))
+      (i32.eqz (local.tee $length (call $Term::List::get_length (local.get $self))))
       (then
         (global.get $TRUE))
       (else
@@ -229,7 +229,7 @@
         (loop $LOOP
           (if
             ;; If the current item is not fully resolved, return false
-            (i32.eqz (call $Stdlib_ResolveDeep::is_fully_resolved (call $Term::List::get::items::value (local.get $self) (local.get $index))))
+            (i32.eqz (call $Stdlib_ResolveDeep::is_fully_resolved (call $Term::List::get_item (local.get $self) (local.get $index))))
             (then
               (return (global.get $FALSE)))
             (else
@@ -0,0 +1,18 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@builtin $Stdlib_Reveal "Reveal"
+    (@args (@strict $self))
+
+    (@impl
+      (i32.eq (global.get $TermType::Sensitive))
+      (func $Stdlib_Reveal::impl::Sensitive (param $self i32) (param $state i32) (result i32 i32)
+        (call $Term::Sensitive::get::value (local.get $self))
+        (global.get $NULL)))
+
+    (@default
+      (func $Stdlib_Reveal::impl::default (param $self i32) (param $state i32) (result i32 i32)
+        ;; Values that are not sensitive are returned unchanged
+        (local.get $self)
+        (global.get $NULL)))))
@@ -0,0 +1,293 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@builtin $Stdlib_Sort "Sort"
+    (@args (@strict $self))
+
+    (@impl
+      (i32.eq (global.get $TermType::List))
+      (func $Stdlib_Sort::impl::List (param $self i32) (param $state i32) (result i32 i32)
+        (local $result i32)
+        (local $dependencies i32)
+        ;; Resolve the list items before attempting to compare them
+        (call $Term::List::traits::collect_strict (local.get $self) (local.get $state))
+        (local.set $dependencies)
+        (if (result i32 i32)
+          (call $Term::Signal::is (local.tee $result))
+          (then
+            (local.get $result)
+            (local.get $dependencies))
+          (else
+            (call $Stdlib_Sort::merge_sort (local.get $result) (global.get $NULL) (local.get $state))
+            (local.set $dependencies (call $Dependencies::traits::union (local.get $dependencies)))
+            (if (result i32 i32)
+              ;; If the list contained items that cannot be compared with one another, return an error signal
+              (i32.eq (local.tee $result) (global.get $NULL))
+              (then
+                (call $Term::Signal::of
+                  (call $Term::Condition::invalid_builtin_function_args
+                    (global.get $Stdlib_Sort)
+                    (call $Term::List::of (local.get $self))))
+                (local.get $dependencies))
+              (else
+                (local.get $result)
+                (local.get $dependencies)))))))
+
+    (@default
+      (func $Stdlib_Sort::impl::default (param $self i32) (param $state i32) (result i32 i32)
+        (call $Term::Signal::of
+          (call $Term::Condition::invalid_builtin_function_args
+            (global.get $Stdlib_Sort)
+            (call $Term::List::of (local.get $self))))
+        (global.get $NULL))))
+
+  (func $Stdlib_Sort::merge_sort (param $self i32) (param $comparator i32) (param $state i32) (result i32 i32)
+    ;; Performs a stable bottom-up merge sort of the list items, using the given comparator function if one is provided,
+    ;; or the natural ordering of the list items if the comparator is null. Returns a signal if the comparator returned a
+    ;; signal, or null if the list items could not be compared.
+    (local $length i32)
+    (local $source i32)
+    (local $target i32)
+    (local $width i32)
+    (local $offset i32)
+    (local $midpoint i32)
+    (local $end i32)
+    (local $left_index i32)
+    (local $right_index i32)
+    (local $target_index i32)
+    (local $left i32)
+    (local $right i32)
+    (local $ordering i32)
+    (local $is_valid i32)
+    (local $is_greater i32)
+    (local $signal i32)
+    (local $dependencies i32)
+    ;; If the list contains fewer than two items, there is nothing to sort
+    (if
+      (i32.lt_u (local.tee $length (call $Term::List::get_length (local.get $self))) (i32.const 2))
+      (then
+        (return (local.get $self) (global.get $NULL))))
+    (local.set $dependencies (global.get $NULL))
+    ;; Copy the list items into a working buffer, and allocate a second buffer of the same size to merge into
+    (local.set $source (call $Term::List::allocate (local.get $length)))
+    (call $Term::List::copy_items
+      (local.get $self)
+      (call $Term::List::get::items::pointer (local.get $source) (i32.const 0)))
+    (local.set $target (call $Term::List::allocate (local.get $length)))
+    ;; Merge successively larger runs of sorted items, starting with runs of a single item
+    (local.set $width (i32.const 1))
+    (loop $PASS
+      (local.set $offset (i32.const 0))
+      (loop $RUN
+        (local.set $midpoint (call $Utils::i32::min_u (i32.add (local.get $offset) (local.get $width)) (local.get $length)))
+        (local.set $end
+          (call $Utils::i32::min_u
+            (i32.add (local.get $offset) (i32.mul (local.get $width) (i32.const 2)))
+            (local.get $length)))
+        (local.set $left_index (local.get $offset))
+        (local.set $right_index (local.get $midpoint))
+        (local.set $target_index (local.get $offset))
+        ;; Merge the left-hand and right-hand runs into the target buffer
+        (loop $MERGE
+          (if
+            (i32.lt_u (local.get $target_index) (local.get $end))
+            (then
+              (if
+                (i32.ge_u (local.get $left_index) (local.get $midpoint))
+                (then
+                  ;; If the left-hand run has been exhausted, take the next item from the right-hand run
+                  (local.set $is_greater (global.get $TRUE)))
+                (else
+                  (if
+                    (i32.ge_u (local.get $right_index) (local.get $end))
+                    (then
+                      ;; If the right-hand run has been exhausted, take the next item from the left-hand run
+                      (local.set $is_greater (global.get $FALSE)))
+                    (else
+                      ;; Otherwise compare the next item from each run
+                      (local.set $left (call $Term::List::get::items::value (local.get $source) (local.get $left_index)))
+                      (local.set $right (call $Term::List::get::items::value (local.get $source) (local.get $right_index)))
+                      (if
+                        (i32.eq (global.get $NULL) (local.get $comparator))
+                        (then
+                          (call $Stdlib_Sort::compare_values (local.get $left) (local.get $right))
+                          (local.set $is_valid)
+                          (local.set $ordering)
+                          (if
+                            ;; If the items cannot be compared, bail out
+                            (i32.eqz (local.get $is_valid))
+                            (then
+                              (return (global.get $NULL) (local.get $dependencies))))
+                          (local.set $is_greater (i32.gt_s (local.get $ordering) (i32.const 0))))
+                        (else
+                          (call $Stdlib_Sort::apply_comparator (local.get $comparator) (local.get $left) (local.get $right) (local.get $state))
+                          (local.set $dependencies (call $Dependencies::traits::union (local.get $dependencies)))
+                          (local.set $signal)
+                          (local.set $is_greater)
+                          (if
+                            ;; If the comparator returned a signal, or a non-numeric result, bail out
+                            (i32.ne (global.get $NULL) (local.get $signal))
+                            (then
+                              (return (local.get $signal) (local.get $dependencies))))))))))
+              ;; Items are only taken from the right-hand run if they are strictly less than the left-hand item,
+              ;; ensuring that the sort is stable
+              (if
+                (local.get $is_greater)
+                (then
+                  (call $Term::List::set::items::value
+                    (local.get $target)
+                    (local.get $target_index)
+                    (call $Term::List::get::items::value (local.get $source) (local.get $right_index)))
+                  (local.set $right_index (i32.add (local.get $right_index) (i32.const 1))))
+                (else
+                  (call $Term::List::set::items::value
+                    (local.get $target)
+                    (local.get $target_index)
+                    (call $Term::List::get::items::value (local.get $source) (local.get $left_index)))
+                  (local.set $left_index (i32.add (local.get $left_index) (i32.const 1)))))
+              (local.set $target_index (i32.add (local.get $target_index) (i32.const 1)))
+              (br $MERGE))))
+        ;; Continue with the next pair of runs
+        (br_if $RUN (i32.lt_u (local.tee $offset (local.get $end)) (local.get $length))))
+      ;; Swap the source and target buffers, and continue with the next pass
+      (local.get $source)
+      (local.set $source (local.get $target))
+      (local.set $target)
+      (br_if $PASS
+        (i32.lt_u
+          (local.tee $width (i32.mul (local.get $width) (i32.const 2)))
+          (local.get $length))))
+    ;; Instantiate the sorted list
+    (call $Term::List::init (local.get $source) (local.get $length))
+    (local.get $dependencies))
+
+  (func $Stdlib_Sort::apply_comparator (param $comparator i32) (param $left i32) (param $right i32) (param $state i32) (result i32 i32 i32)
+    ;; Returns a boolean indicating whether the comparator determined that the left item should be sorted after the
+    ;; right item, followed by a signal if the comparator did not return a number (or null if the comparison succeeded)
+    (local $result i32)
+    (local $dependencies i32)
+    (call $Term::traits::apply
+      (local.get $comparator)
+      (call $Term::List::create_pair (local.get $left) (local.get $right))
+      (local.get $state))
+    (local.set $dependencies)
+    (call $Term::traits::evaluate (local.get $state))
+    (local.set $dependencies (call $Dependencies::traits::union (local.get $dependencies)))
+    (local.set $result)
+    (if (result i32 i32 i32)
+      (call $Term::Signal::is (local.get $result))
+      (then
+        (global.get $FALSE)
+        (local.get $result)
+        (local.get $dependencies))
+      (else
+        (if (result i32 i32 i32)
+          (call $Term::Int::is (local.get $result))
+          (then
+            (i64.gt_s (call $Term::Int::get::value (local.get $result)) (i64.const 0))
+            (global.get $NULL)
+            (local.get $dependencies))
+          (else
+            (if (result i32 i32 i32)
+              (call $Term::Float::is (local.get $result))
+              (then
+                (f64.gt (call $Term::Float::get::value (local.get $result)) (f64.const 0))
+                (global.get $NULL)
+                (local.get $dependencies))
+              (else
+                (global.get $FALSE)
+                (call $Term::Signal::of
+                  (call $Term::Condition::type_error (global.get $TermType::Float) (local.get $result)))
+                (local.get $dependencies))))))))
+
+  (func $Stdlib_Sort::compare_values (param $left i32) (param $right i32) (result i32 i32)
+    ;; Returns the relative ordering of the two items (-1, 0 or 1), followed by a boolean indicating whether the items
+    ;; were able to be compared
+    (local $left_type i32)
+    (local $right_type i32)
+    (local $left_float f64)
+    (local $right_float f64)
+    (local.set $left_type (call $Term::get_type (local.get $left)))
+    (local.set $right_type (call $Term::get_type (local.get $right)))
+    (if (result i32 i32)
+      (i32.and
+        (i32.eq (global.get $TermType::Int) (local.get $left_type))
+        (i32.eq (global.get $TermType::Int) (local.get $right_type)))
+      (then
+        (call $Stdlib_Sort::compare_i64
+          (call $Term::Int::get::value (local.get $left))
+          (call $Term::Int::get::value (local.get $right)))
+        (global.get $TRUE))
+      (else
+        (if (result i32 i32)
+          (i32.and
+            (i32.or
+              (i32.eq (global.get $TermType::Int) (local.get $left_type))
+              (i32.eq (global.get $TermType::Float) (local.get $left_type)))
+            (i32.or
+              (i32.eq (global.get $TermType::Int) (local.get $right_type))
+              (i32.eq (global.get $TermType::Float) (local.get $right_type))))
+          (then
+            ;; Mixed numeric types are compared as floats
+            (local.set $left_float
+              (if (result f64)
+                (i32.eq (global.get $TermType::Int) (local.get $left_type))
+                (then
+                  (f64.convert_i64_s (call $Term::Int::get::value (local.get $left))))
+                (else
+                  (call $Term::Float::get::value (local.get $left)))))
+            (local.set $right_float
+              (if (result f64)
+                (i32.eq (global.get $TermType::Int) (local.get $right_type))
+                (then
+                  (f64.convert_i64_s (call $Term::Int::get::value (local.get $right))))
+                (else
+                  (call $Term::Float::get::value (local.get $right)))))
+            (i32.sub
+              (f64.gt (local.get $left_float) (local.get $right_float))
+              (f64.lt (local.get $left_float) (local.get $right_float)))
+            ;; NaN values cannot be compared
+            (i32.and
+              (f64.eq (local.get $left_float) (local.get $left_float))
+              (f64.eq (local.get $right_float) (local.get $right_float))))
+          (else
+            (if (result i32 i32)
+              (i32.ne (local.get $left_type) (local.get $right_type))
+              (then
+                (i32.const 0)
+                (global.get $FALSE))
+              (else
+                (if (result i32 i32)
+                  (i32.eq (global.get $TermType::String) (local.get $left_type))
+                  (then
+                    (call $Utils::bytes::compare
+                      (call $Term::String::get_offset (local.get $left))
+                      (call $Term::String::get_length (local.get $left))
+                      (call $Term::String::get_offset (local.get $right))
+                      (call $Term::String::get_length (local.get $right)))
+                    (global.get $TRUE))
+                  (else
+                    (if (result i32 i32)
+                      (i32.eq (global.get $TermType::Timestamp) (local.get $left_type))
+                      (then
+                        (call $Stdlib_Sort::compare_i64
+                          (call $Term::Timestamp::get::millis (local.get $left))
+                          (call $Term::Timestamp::get::millis (local.get $right)))
+                        (global.get $TRUE))
+                      (else
+                        (if (result i32 i32)
+                          (i32.eq (global.get $TermType::Duration) (local.get $left_type))
+                          (then
+                            (call $Stdlib_Sort::compare_i64
+                              (call $Term::Duration::get::millis (local.get $left))
+                              (call $Term::Duration::get::millis (local.get $right)))
+                            (global.get $TRUE))
+                          (else
+                            (i32.const 0)
+                            (global.get $FALSE))))))))))))))
+
+  (func $Stdlib_Sort::compare_i64 (param $left i64) (param $right i64) (result i32)
+    (i32.sub
+      (i64.gt_s (local.get $left) (local.get $right))
+      (i64.lt_s (local.get $left) (local.get $right)))))
@@ -0,0 +1,136 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_SortBy', (test) => {
+    test('(List, Lambda)', (assert, {
+      createApplication,
+      createBuiltin,
+      createEmptyList,
+      createInt,
+      createLambda,
+      createList,
+      createPair,
+      createVariable,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.SortBy),
+          createPair(createEmptyList(), createBuiltin(Stdlib.Subtract)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '[]');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.SortBy),
+          createPair(
+            createList([createInt(3), createInt(1), createInt(4), createInt(1), createInt(5)]),
+            createBuiltin(Stdlib.Subtract),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '[1, 1, 3, 4, 5]');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.SortBy),
+          createPair(
+            createList([createInt(3), createInt(1), createInt(4), createInt(1), createInt(5)]),
+            createLambda(
+              2,
+              createApplication(
+                createBuiltin(Stdlib.Subtract),
+                createPair(createVariable(0), createVariable(1)),
+              ),
+            ),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '[5, 4, 3, 1, 1]');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.SortBy),
+          createPair(
+            createList([
+              createInt(21),
+              createInt(13),
+              createInt(11),
+              createInt(22),
+              createInt(12),
+              createInt(31),
+            ]),
+            createLambda(
+              2,
+              createApplication(
+                createBuiltin(Stdlib.Subtract),
+                createPair(
+                  createApplication(
+                    createBuiltin(Stdlib.Remainder),
+                    createPair(createVariable(1), createInt(10)),
+                  ),
+                  createApplication(
+                    createBuiltin(Stdlib.Remainder),
+                    createPair(createVariable(0), createInt(10)),
+                  ),
+                ),
+              ),
+            ),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '[21, 11, 31, 22, 12, 13]');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('invalid arguments', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createLambda,
+      createList,
+      createPair,
+      createString,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.SortBy),
+          createPair(createInt(3), createBuiltin(Stdlib.Subtract)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(
+          format(result),
+          '{<InvalidFunctionArgsCondition:SortBy(3, Subtract)>}',
+        );
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.SortBy),
+          createPair(
+            createList([createInt(3), createInt(4)]),
+            createLambda(2, createString('foo')),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '{<TypeErrorCondition:Float:"foo">}');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+  });
+};
@@ -0,0 +1,20 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@builtin $Stdlib_SortBy "SortBy"
+    (@args (@strict $self) (@strict $comparator))
+
+    (@impl
+      (i32.eq (global.get $TermType::List))
+      (call $TermType::implements::apply)
+      (func $Stdlib_SortBy::impl::List::<apply> (param $self i32) (param $comparator i32) (param $state i32) (result i32 i32)
+        (call $Stdlib_Sort::merge_sort (local.get $self) (local.get $comparator) (local.get $state))))
+
+    (@default
+      (func $Stdlib_SortBy::impl::default (param $self i32) (param $comparator i32) (param $state i32) (result i32 i32)
+        (call $Term::Signal::of
+          (call $Term::Condition::invalid_builtin_function_args
+            (global.get $Stdlib_SortBy)
+            (call $Term::List::create_pair (local.get $self) (local.get $comparator))))
+        (global.get $NULL)))))
@@ -0,0 +1,29 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{uuid, ArgType, Arity, FunctionArity, Uid, Uuid};
+
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct SortMerge;
+impl SortMerge {
+    pub const UUID: Uuid = uuid!("682e7eff-895c-4a74-815d-8f8dbc01ae50");
+    const ARITY: FunctionArity<5, 0> = FunctionArity {
+        required: [
+            ArgType::Strict,
+            ArgType::Strict,
+            ArgType::Strict,
+            ArgType::Strict,
+            ArgType::Strict,
+        ],
+        optional: [],
+        variadic: None,
+    };
+    pub fn arity(&self) -> Arity {
+        Arity::from(&Self::ARITY)
+    }
+}
+impl Uid for SortMerge {
+    fn uid(&self) -> Uuid {
+        Self::UUID
+    }
+}
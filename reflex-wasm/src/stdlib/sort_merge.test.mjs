@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_SortMerge', (test) => {
+    test('(List, List, Lambda, Any, Any)', (assert, {
+      createApplication,
+      createBuiltin,
+      createEmptyList,
+      createInt,
+      createList,
+      createNil,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.SortMerge),
+          createList([
+            createEmptyList(),
+            createEmptyList(),
+            createBuiltin(Stdlib.Subtract),
+            createNil(),
+            createNil(),
+          ]),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '[]');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.SortMerge),
+          createList([
+            createList([createInt(1), createInt(3), createInt(5)]),
+            createList([createInt(2), createInt(3), createInt(4), createInt(6)]),
+            createBuiltin(Stdlib.Subtract),
+            createNil(),
+            createNil(),
+          ]),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '[1, 2, 3, 3, 4, 5, 6]');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('invalid arguments', (assert, {
+      createApplication,
+      createBuiltin,
+      createEmptyList,
+      createInt,
+      createList,
+      createNil,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.SortMerge),
+          createList([
+            createInt(3),
+            createEmptyList(),
+            createBuiltin(Stdlib.Subtract),
+            createNil(),
+            createNil(),
+          ]),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(
+          format(result),
+          '{<InvalidFunctionArgsCondition:SortMerge(3, [], Subtract)>}',
+        );
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+  });
+};
@@ -0,0 +1,28 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@builtin $Stdlib_SortMerge "SortMerge"
+    (@args (@strict $left) (@strict $right) (@strict $comparator) (@strict $merged) (@strict $comparison))
+
+    (@impl
+      (i32.eq (global.get $TermType::List))
+      (i32.eq (global.get $TermType::List))
+      (call $TermType::implements::apply)
+      (i32.or (i32.const 0xFFFFFFFF))
+      (i32.or (i32.const 0xFFFFFFFF))
+      (func $Stdlib_SortMerge::impl::List::List::<apply>::any::any (param $left i32) (param $right i32) (param $comparator i32) (param $merged i32) (param $comparison i32) (param $state i32) (result i32 i32)
+        ;; The merge progress arguments are only used by the interpreter, which merges the lists incrementally.
+        ;; Stable-sorting the concatenation of two sorted lists yields the same ordering as merging them directly.
+        (call $Stdlib_Sort::merge_sort
+          (call $Term::List::traits::union (local.get $left) (local.get $right))
+          (local.get $comparator)
+          (local.get $state))))
+
+    (@default
+      (func $Stdlib_SortMerge::impl::default (param $left i32) (param $right i32) (param $comparator i32) (param $merged i32) (param $comparison i32) (param $state i32) (result i32 i32)
+        (call $Term::Signal::of
+          (call $Term::Condition::invalid_builtin_function_args
+            (global.get $Stdlib_SortMerge)
+            (call $Term::List::create_triple (local.get $left) (local.get $right) (local.get $comparator))))
+        (global.get $NULL)))))
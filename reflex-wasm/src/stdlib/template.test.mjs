@@ -0,0 +1,125 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Stdlib_Template', (test) => {
+    test('(any, Record)', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createPair,
+      createRecord,
+      createString,
+      createTriple,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const createValueNode = (value) =>
+        createRecord(
+          createPair(createString('type'), createString('value')),
+          createPair(createString('Value'), value),
+        );
+      const createSpliceNode = (name) =>
+        createRecord(
+          createPair(createString('type'), createString('name')),
+          createPair(createString('Splice'), createString(name)),
+        );
+      const template = createRecord(
+        createTriple(createString('type'), createString('target'), createString('args')),
+        createTriple(
+          createString('Application'),
+          createSpliceNode('target'),
+          createUnitList(createSpliceNode('args')),
+        ),
+      );
+      const bindings = createRecord(
+        createPair(createString('target'), createString('args')),
+        createPair(
+          createValueNode(createBuiltin(Stdlib.Add)),
+          createPair(createValueNode(createInt(3)), createValueNode(createInt(4))),
+        ),
+      );
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Template),
+          createPair(template, bindings),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(
+          format(result),
+          '{ "type": "Application", "target": { "type": "Value", "value": Add }, "args": [{ "type": "Value", "value": 3 }, { "type": "Value", "value": 4 }] }',
+        );
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Eval),
+          createUnitList(
+            createApplication(createBuiltin(Stdlib.Template), createPair(template, bindings)),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '7');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Template),
+          createPair(createValueNode(createSpliceNode('foo')), bindings),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(
+          format(result),
+          '{ "type": "Value", "value": { "type": "Splice", "name": "foo" } }',
+        );
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+
+    test('invalid arguments', (assert, {
+      createApplication,
+      createBuiltin,
+      createEmptyList,
+      createInt,
+      createPair,
+      createRecord,
+      createString,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Template),
+          createPair(createInt(3), createInt(4)),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '{<InvalidFunctionArgsCondition:Template(3, 4)>}');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const template = createRecord(
+          createPair(createString('type'), createString('name')),
+          createPair(createString('Splice'), createString('foo')),
+        );
+        const bindings = createRecord(createEmptyList(), createEmptyList());
+        const expression = createApplication(
+          createBuiltin(Stdlib.Template),
+          createPair(template, bindings),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(
+          format(result),
+          '{<InvalidFunctionArgsCondition:Template({ "type": "Splice", "name": "foo" }, {})>}',
+        );
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+  });
+};
@@ -0,0 +1,166 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@builtin $Stdlib_Template "Template"
+    (@args (@strict $self) (@strict $bindings))
+
+    (@impl
+      (i32.or (i32.const 0xFFFFFFFF))
+      (i32.eq (global.get $TermType::Record))
+      (func $Stdlib_Template::impl::any::Record (param $self i32) (param $bindings i32) (param $state i32) (result i32 i32)
+        (local $result i32)
+        (if (result i32 i32)
+          ;; Fill the template placeholders, returning an error if any of the placeholders are missing a binding
+          (i32.eq
+            (global.get $NULL)
+            (local.tee $result (call $Stdlib_Template::fill (local.get $self) (local.get $bindings))))
+          (then
+            (call $Stdlib_Template::impl::default (local.get $self) (local.get $bindings) (local.get $state)))
+          (else
+            (local.get $result)
+            (global.get $NULL)))))
+
+    (@default
+      (func $Stdlib_Template::impl::default (param $self i32) (param $bindings i32) (param $state i32) (result i32 i32)
+        (call $Term::Signal::of
+          (call $Term::Condition::invalid_builtin_function_args
+            (global.get $Stdlib_Template)
+            (call $Term::List::create_pair (local.get $self) (local.get $bindings))))
+        (global.get $NULL))))
+
+  (func $Stdlib_Template::fill (param $self i32) (param $bindings i32) (result i32)
+    ;; Returns the null sentinel value if the template contains a placeholder with no corresponding binding
+    (local $placeholder i32)
+    (local $node_type i32)
+    (local $values i32)
+    (if
+      (i32.ne (global.get $NULL) (local.tee $placeholder (call $Stdlib_Template::get_placeholder_name (local.get $self))))
+      (then
+        (return (call $Term::Record::find_value (local.get $bindings) (local.get $placeholder)))))
+    (if
+      (call $Term::is_list (local.get $self))
+      (then
+        (return (call $Stdlib_Template::fill_list (local.get $self) (local.get $bindings)))))
+    (if
+      (i32.eqz (call $Term::Record::is (local.get $self)))
+      (then
+        (return (local.get $self))))
+    ;; The contents of quoted values are opaque, so cannot contain placeholders
+    (local.set $node_type (call $Term::Record::find_value (local.get $self) (global.get $Stdlib_Quote::FIELD_TYPE)))
+    (if
+      (i32.ne (global.get $NULL) (local.get $node_type))
+      (then
+        (if
+          (call $Term::traits::equals (local.get $node_type) (global.get $Stdlib_Quote::TYPE_VALUE))
+          (then
+            (return (local.get $self))))))
+    (if (result i32)
+      (i32.eq
+        (global.get $NULL)
+        (local.tee $values
+          (call $Stdlib_Template::fill_values (call $Term::Record::get::values (local.get $self)) (local.get $bindings))))
+      (then
+        (global.get $NULL))
+      (else
+        (call $Term::Record::new (call $Term::Record::get::keys (local.get $self)) (local.get $values)))))
+
+  (func $Stdlib_Template::fill_values (param $self i32) (param $bindings i32) (result i32)
+    (local $results i32)
+    (local $length i32)
+    (local $index i32)
+    (local $item i32)
+    (if
+      (i32.eqz (local.tee $length (call $Term::List::get_length (local.get $self))))
+      (then
+        (return (local.get $self))))
+    ;; Fill each of the record values into a new list of the same length, bailing out if any value is invalid
+    (local.set $results (call $Term::List::allocate (local.get $length)))
+    (loop $LOOP
+      (if
+        (i32.eq
+          (global.get $NULL)
+          (local.tee $item
+            (call $Stdlib_Template::fill
+              (call $Term::List::get_item (local.get $self) (local.get $index))
+              (local.get $bindings))))
+        (then
+          (return (global.get $NULL))))
+      (call $Term::List::set_item (local.get $results) (local.get $index) (local.get $item))
+      (br_if $LOOP (i32.lt_u (local.tee $index (i32.add (local.get $index) (i32.const 1))) (local.get $length))))
+    (call $Term::List::init (local.get $results) (local.get $length)))
+
+  (func $Stdlib_Template::fill_list (param $self i32) (param $bindings i32) (result i32)
+    (local $results i32)
+    (local $length i32)
+    (local $index i32)
+    (local $item i32)
+    (local $placeholder i32)
+    (local $binding_length i32)
+    (local $binding_index i32)
+    (if
+      (i32.eqz (local.tee $length (call $Term::List::get_length (local.get $self))))
+      (then
+        (return (local.get $self))))
+    ;; The number of results is not known in advance due to list bindings being spliced into the enclosing list
+    (local.set $results (call $Term::List::allocate_unsized))
+    (loop $LOOP
+      (local.set $item (call $Term::List::get_item (local.get $self) (local.get $index)))
+      (if
+        (i32.eq (global.get $NULL) (local.tee $placeholder (call $Stdlib_Template::get_placeholder_name (local.get $item))))
+        (then
+          ;; Fill any placeholders within the current item
+          (if
+            (i32.eq (global.get $NULL) (local.tee $item (call $Stdlib_Template::fill (local.get $item) (local.get $bindings))))
+            (then
+              (return (global.get $NULL))))
+          (local.set $results (call $Term::List::append_unsized (local.get $results) (local.get $item))))
+        (else
+          ;; Replace the placeholder with its corresponding binding
+          (if
+            (i32.eq (global.get $NULL) (local.tee $item (call $Term::Record::find_value (local.get $bindings) (local.get $placeholder))))
+            (then
+              (return (global.get $NULL))))
+          (if
+            (call $Term::is_list (local.get $item))
+            (then
+              ;; List bindings are spliced into the enclosing list
+              (local.set $binding_length (call $Term::List::get_length (local.get $item)))
+              (local.set $binding_index (i32.const 0))
+              (block $BREAK
+                (loop $SPLICE
+                  (br_if $BREAK (i32.ge_u (local.get $binding_index) (local.get $binding_length)))
+                  (local.set $results
+                    (call $Term::List::append_unsized
+                      (local.get $results)
+                      (call $Term::List::get_item (local.get $item) (local.get $binding_index))))
+                  (local.set $binding_index (i32.add (local.get $binding_index) (i32.const 1)))
+                  (br $SPLICE))))
+            (else
+              (local.set $results (call $Term::List::append_unsized (local.get $results) (local.get $item)))))))
+      (br_if $LOOP (i32.lt_u (local.tee $index (i32.add (local.get $index) (i32.const 1))) (local.get $length))))
+    (call $Term::List::init_unsized (local.get $results)))
+
+  (func $Stdlib_Template::get_placeholder_name (param $self i32) (result i32)
+    ;; Returns the placeholder name if the provided node is a Splice node, or the null sentinel value otherwise
+    (local $value i32)
+    (if
+      (i32.eqz (call $Term::Record::is (local.get $self)))
+      (then
+        (return (global.get $NULL))))
+    (if
+      (i32.eq (global.get $NULL) (local.tee $value (call $Term::Record::find_value (local.get $self) (global.get $Stdlib_Quote::FIELD_TYPE))))
+      (then
+        (return (global.get $NULL))))
+    (if
+      (i32.eqz (call $Term::traits::equals (local.get $value) (global.get $Stdlib_Quote::TYPE_SPLICE)))
+      (then
+        (return (global.get $NULL))))
+    (if
+      (i32.eq (global.get $NULL) (local.tee $value (call $Term::Record::find_value (local.get $self) (global.get $Stdlib_Quote::FIELD_NAME))))
+      (then
+        (return (global.get $NULL))))
+    (select
+      (local.get $value)
+      (global.get $NULL)
+      (call $Term::String::is (local.get $value)))))
@@ -85,14 +85,33 @@
           (then
             (global.get $FALSE))
           (else
-            ;; Confirm equality according to the underlying term type implementation
-            (call $TermType::traits::equals
-              (call $Term::pointer::value (local.get $self))
-              (call $Term::pointer::value (local.get $other))))))))
+            (if (result i32)
+              ;; Lists are compared by contents, regardless of whether they are flat lists, list views or list ropes
+              (i32.and
+                (call $Term::is_list (local.get $self))
+                (call $Term::is_list (local.get $other)))
+              (then
+                ;; This assumes that lists with the same length and hash are almost certainly identical
+                (i32.eq
+                  (call $Term::List::get_length (local.get $self))
+                  (call $Term::List::get_length (local.get $other))))
+              (else
+                ;; Confirm equality according to the underlying term type implementation
+                (call $TermType::traits::equals
+                  (call $Term::pointer::value (local.get $self))
+                  (call $Term::pointer::value (local.get $other))))))))))
 
   (func $Term::traits::hash (param $self i32) (param $state i64) (result i64)
-    ;; Compute the hash according to the term type implementation
-    (call $TermType::traits::hash (call $Term::pointer::value (local.get $self)) (local.get $state)))
+    (if (result i64)
+      ;; List views and list ropes are hashed identically to the equivalent flat list
+      (i32.or
+        (call $Term::ListView::is (local.get $self))
+        (call $Term::ListRope::is (local.get $self)))
+      (then
+        (call $Term::List::hash (local.get $self) (local.get $state)))
+      (else
+        ;; Compute the hash according to the term type implementation
+        (call $TermType::traits::hash (call $Term::pointer::value (local.get $self)) (local.get $state)))))
 
   (func $Term::traits::size (param $self i32) (result i32)
     (i32.add
@@ -118,6 +137,15 @@
   (func $Term::get_type (export "getTermType") (param $self i32) (result i32)
     (call $Term::TermType::get::type (local.get $self)))
 
+  (func $Term::get_dispatch_type (param $self i32) (result i32)
+    ;; List views and list ropes are dispatched to the same method implementations as flat lists
+    (local $type i32)
+    (local.set $type (call $Term::get_type (local.get $self)))
+    (select
+      (global.get $TermType::List)
+      (local.get $type)
+      (call $TermType::is_list (local.get $type))))
+
   (func $Term::get_value (param $self i32) (result i32)
     (call $Term::TermType::get::value (local.get $self)))
 
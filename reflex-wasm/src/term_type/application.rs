@@ -12,7 +12,7 @@ use reflex_macros::PointerIter;
 use serde_json::Value as JsonValue;
 
 use crate::{
-    allocator::Arena,
+    allocator::{Arena, ArenaAllocator},
     compiler::{
         error::CompilerError, instruction, runtime::builtin::RuntimeBuiltin,
         BlockWrappedExpression, CompileWasm, CompiledBlockBuilder, CompiledFunctionCall,
@@ -23,8 +23,8 @@ use crate::{
     hash::{TermHash, TermHasher, TermSize},
     stdlib::Stdlib,
     term_type::{
-        list::compile_list, BuiltinTerm, ConstructorTerm, LambdaTerm, ListTerm, TypedTerm,
-        WasmExpression,
+        list::compile_list, BooleanTerm, BuiltinTerm, ConstructorTerm, FloatTerm, IntTerm,
+        LambdaTerm, ListTerm, TermType, TypedTerm, WasmExpression,
     },
     ArenaPointer, ArenaRef, Term,
 };
@@ -408,6 +408,11 @@ impl<A: Arena + Clone> CompileWasm<A> for LambdaCompiledFunctionCall<A> {
         if num_args == 0 {
             return target.body().compile(stack, state, options);
         }
+        // If this is a small lambda whose semantics are unaffected by inlining, splice the function body directly
+        // into the call site to avoid the overhead of an indirect function call
+        if args.len() == num_args && is_inlinable_lambda(&target_term, options) {
+            return compile_inlined_lambda(&target_term, args, stack, state, options);
+        }
         // Ensure that the target lambda is compiled
         // (note that all lambda functions are extracted out and linked in a later compiler phase)
         let _ = target_term.compile(stack.clone(), state, options)?;
@@ -419,32 +424,10 @@ impl<A: Arena + Clone> CompileWasm<A> for LambdaCompiledFunctionCall<A> {
             .iter()
             .fold(Result::<_, CompilerError<_>>::Ok(block), |block, arg| {
                 let block = block?;
-                // Determine the argument eagerness based on the compiler options
-                let eagerness = options.lazy_lambda_args;
                 // Push the argument onto the stack
-                // => [Term...]
-                let block = match eagerness {
-                    // If lambdas are compiled with strict arguments enabled, the lambda body implementation will
-                    // handle short-circuiting a combined signal argument, so we evaluate the argument strictly here
-                    // at the call site but wrap it in a signal-catching boundary to ensure that multiple signals
-                    // can be combined within the lambda body (this will happen immediately after the indirect
-                    // lambda call)
-                    ArgType::Strict => block.append_inner(|stack| {
-                        BlockWrappedExpression::new(arg).compile(stack, state, options)
-                    }),
-                    // If lambdas are defined as having lazy arguments, however the compiler options define function
-                    // arguments as non-lazy, 'upgrade' the argument to be evaluated eagerly
-                    // (note that we cannot evaluate the argument strictly, as this would change the behavior of lambdas
-                    // that contain no references to their signal arguments, so we evaluate the argument eagerly and
-                    // defer any signal short-circuiting to the underlying lambda implementation)
-                    ArgType::Lazy if !options.lazy_function_args => block.append_inner(|stack| {
-                        EagerExpression::new(arg).compile(stack, state, options)
-                    }),
-                    // Otherwise compile the argument as determined by the compiler eagerness options
-                    eagerness => block.append_inner(|stack| {
-                        MaybeLazyExpression::new(arg, eagerness).compile(stack, state, options)
-                    }),
-                }?;
+                // => [Term]
+                let block =
+                    block.append_inner(|stack| compile_lambda_arg(arg, stack, state, options))?;
                 Ok(block)
             })?;
         // Call the compiled lambda function
@@ -463,6 +446,135 @@ impl<A: Arena + Clone> CompileWasm<A> for LambdaCompiledFunctionCall<A> {
     }
 }
 
+fn compile_lambda_arg<A: Arena + Clone>(
+    arg: WasmExpression<A>,
+    stack: CompilerStack,
+    state: &mut CompilerState,
+    options: &CompilerOptions,
+) -> CompilerResult<A> {
+    // Determine the argument eagerness based on the compiler options
+    let eagerness = options.lazy_lambda_args;
+    // Push the argument onto the stack
+    // => [Term]
+    match eagerness {
+        // If lambdas are compiled with strict arguments enabled, the lambda body implementation will
+        // handle short-circuiting a combined signal argument, so we evaluate the argument strictly here
+        // at the call site but wrap it in a signal-catching boundary to ensure that multiple signals
+        // can be combined within the lambda body (this will happen immediately after the indirect
+        // lambda call)
+        ArgType::Strict => BlockWrappedExpression::new(arg).compile(stack, state, options),
+        // If lambdas are defined as having lazy arguments, however the compiler options define function
+        // arguments as non-lazy, 'upgrade' the argument to be evaluated eagerly
+        // (note that we cannot evaluate the argument strictly, as this would change the behavior of lambdas
+        // that contain no references to their signal arguments, so we evaluate the argument eagerly and
+        // defer any signal short-circuiting to the underlying lambda implementation)
+        ArgType::Lazy if !options.lazy_function_args => {
+            EagerExpression::new(arg).compile(stack, state, options)
+        }
+        // Otherwise compile the argument as determined by the compiler eagerness options
+        eagerness => MaybeLazyExpression::new(arg, eagerness).compile(stack, state, options),
+    }
+}
+
+/// Determine whether the given lambda is small enough to be inlined, and whether inlining it would preserve the
+/// semantics of the compiled lambda function.
+///
+/// Only lambdas whose body is a single builtin application are eligible for inlining, where each of the application
+/// arguments is either a static atomic value or a reference to one of the lambda arguments. Every lambda argument must
+/// be referenced by the body, and if lambdas are compiled with strict arguments then every lambda argument must be
+/// passed as a strict builtin argument (this ensures that the builtin will short-circuit any signal arguments in the
+/// same way as the compiled lambda function would have done).
+fn is_inlinable_lambda<A: Arena + Clone>(
+    target: &ArenaRef<LambdaTerm, A>,
+    options: &CompilerOptions,
+) -> bool {
+    let num_args = target.num_args() as StackOffset;
+    let body = target.body();
+    if options.inline_lambda_threshold == 0 || body.size() > options.inline_lambda_threshold {
+        return false;
+    }
+    let Some(application) = body.as_application_term() else {
+        return false;
+    };
+    let application = application.as_inner();
+    let Some(builtin) = application
+        .target()
+        .as_builtin_term()
+        .and_then(|term| Stdlib::try_from(term.as_inner().target()).ok())
+    else {
+        return false;
+    };
+    let arity = builtin.arity();
+    let application_args = application.args();
+    let application_args = application_args.as_inner();
+    let mut referenced_args = HashSet::new();
+    for (arg, arg_type) in application_args.iter().zip(
+        arity
+            .iter()
+            .chain(std::iter::repeat_n(ArgType::Lazy, application_args.len())),
+    ) {
+        if let Some(variable) = arg.as_variable_term() {
+            let offset = variable.as_inner().stack_offset();
+            if offset >= num_args {
+                return false;
+            }
+            // Strict lambda arguments must be short-circuited by the builtin itself
+            let is_signal_safe = matches!(arg_type, ArgType::Strict)
+                || !matches!(options.lazy_lambda_args, ArgType::Strict);
+            if is_signal_safe {
+                referenced_args.insert(offset);
+            }
+        } else if !(arg.is_static() && arg.is_atomic()) {
+            return false;
+        }
+    }
+    referenced_args.len() == num_args
+}
+
+fn compile_inlined_lambda<A: Arena + Clone>(
+    target: &ArenaRef<LambdaTerm, A>,
+    args: &CompiledFunctionCallArgs<A>,
+    stack: CompilerStack,
+    state: &mut CompilerState,
+    options: &CompilerOptions,
+) -> CompilerResult<A> {
+    let num_args = target.num_args() as usize;
+    let block = CompiledBlockBuilder::new(stack);
+    // Push each argument onto the stack in reverse order, so that the first argument is at the top of the stack
+    // (all arguments are compiled before any variables are declared, to avoid shifting the caller's variable offsets)
+    // => [Term...]
+    let block = args
+        .iter()
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .try_fold(block, |block, arg| {
+            block.append_inner(|stack| compile_lambda_arg(arg, stack, state, options))
+        })?;
+    // Pop each argument from the top of the stack and assign it to a lexical scope, leaving the last argument in the
+    // innermost scope as per the variable offsets used within the lambda body
+    // => []
+    let block = (0..num_args).fold(block, |block, _| {
+        block.push(instruction::runtime::DeclareVariable {
+            value_type: ValueType::HeapPointer,
+        })
+    });
+    // Yield the lambda body onto the stack, wrapped in a block to ensure that any signals do not break out of the
+    // caller's control flow block (matching the behavior of a function call)
+    // => [Term]
+    let block = block.append_inner(|stack| {
+        BlockWrappedExpression::new(target.body()).compile(stack, state, options)
+    })?;
+    // Drop the lexical scopes, leaving the result on the stack
+    // => [Term]
+    let block = (0..num_args).fold(block, |block, _| {
+        block.push(instruction::core::ScopeEnd {
+            value_type: ValueType::HeapPointer,
+        })
+    });
+    block.finish()
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct ConstructorCompiledFunctionCall<A: Arena + Clone> {
     target: ArenaRef<TypedTerm<ConstructorTerm>, A>,
@@ -538,6 +650,12 @@ impl<A: Arena + Clone> CompileWasm<A> for BuiltinCompiledFunctionCall<A> {
         // Retrieve the corresponding target function
         let builtin = Stdlib::try_from(builtin_target)
             .map_err(|_| CompilerError::InvalidFunctionTarget(builtin_target))?;
+        // Applications of pure builtins to literal arguments can be evaluated at compile time
+        if options.constant_folding {
+            if let Some(value) = fold_constant_application(&builtin, args, state) {
+                return compile_folded_constant(value, stack, state);
+            }
+        }
         let target = target.as_term().clone();
         match builtin {
             // Certain builtin stdlib functions have special-case compilation strategies
@@ -558,6 +676,172 @@ impl<A: Arena + Clone> CompileWasm<A> for BuiltinCompiledFunctionCall<A> {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+enum ConstantValue {
+    Int(i64),
+    Float(f64),
+    Boolean(bool),
+}
+
+impl From<ConstantValue> for TermType {
+    fn from(value: ConstantValue) -> Self {
+        match value {
+            ConstantValue::Int(value) => TermType::Int(IntTerm::from(value)),
+            ConstantValue::Float(value) => TermType::Float(FloatTerm::from(value)),
+            ConstantValue::Boolean(value) => TermType::Boolean(BooleanTerm::from(value)),
+        }
+    }
+}
+
+/// Attempt to evaluate an application of a pure builtin to constant arguments at compile time.
+///
+/// Results whose runtime behavior depends on the configured numeric semantics (integer overflow and non-finite float
+/// results) are left unfolded, so that the runtime implementation can determine the appropriate result.
+fn fold_constant_application<A: Arena + Clone>(
+    builtin: &Stdlib,
+    args: &CompiledFunctionCallArgs<A>,
+    state: &CompilerState,
+) -> Option<ConstantValue> {
+    let args = args
+        .iter()
+        .map(|arg| fold_constant_expression(&arg, state))
+        .collect::<Option<Vec<_>>>()?;
+    match (builtin, args.as_slice()) {
+        (Stdlib::Add(_), [left, right]) => {
+            fold_arithmetic(*left, *right, i64::checked_add, |left, right| left + right)
+        }
+        (Stdlib::Subtract(_), [left, right]) => {
+            fold_arithmetic(*left, *right, i64::checked_sub, |left, right| left - right)
+        }
+        (Stdlib::Multiply(_), [left, right]) => {
+            fold_arithmetic(*left, *right, i64::checked_mul, |left, right| left * right)
+        }
+        (Stdlib::Gt(_), [left, right]) => {
+            fold_comparison(*left, *right, |ordering| ordering.is_gt())
+        }
+        (Stdlib::Gte(_), [left, right]) => {
+            fold_comparison(*left, *right, |ordering| ordering.is_ge())
+        }
+        (Stdlib::Lt(_), [left, right]) => {
+            fold_comparison(*left, *right, |ordering| ordering.is_lt())
+        }
+        (Stdlib::Lte(_), [left, right]) => {
+            fold_comparison(*left, *right, |ordering| ordering.is_le())
+        }
+        (Stdlib::Not(_), [ConstantValue::Boolean(value)]) => Some(ConstantValue::Boolean(!*value)),
+        _ => None,
+    }
+}
+
+fn fold_constant_expression<A: Arena + Clone>(
+    value: &WasmExpression<A>,
+    state: &CompilerState,
+) -> Option<ConstantValue> {
+    if let Some(term) = value.as_int_term() {
+        Some(ConstantValue::Int(term.as_inner().value()))
+    } else if let Some(term) = value.as_float_term() {
+        Some(ConstantValue::Float(term.as_inner().value()))
+    } else if let Some(term) = value.as_boolean_term() {
+        Some(ConstantValue::Boolean(term.as_inner().value()))
+    } else if let Some(term) = value.as_application_term() {
+        // Nested applications are folded recursively, allowing entire constant subtrees to be folded into a single term
+        // (unless constant folding has been disabled for the nested subtree via a compiler options override)
+        let hash = value.read_value(|term| term.header.hash);
+        let is_folding_disabled = state
+            .options_overrides
+            .get(&hash)
+            .map(|options| !options.constant_folding)
+            .unwrap_or(false);
+        if is_folding_disabled {
+            return None;
+        }
+        let term = term.as_inner();
+        let target = term.target();
+        let builtin = Stdlib::try_from(target.as_builtin_term()?.as_inner().target()).ok()?;
+        let args = CompiledFunctionCallArgs {
+            args: vec![term.args()],
+        };
+        fold_constant_application(&builtin, &args, state)
+    } else {
+        None
+    }
+}
+
+fn fold_arithmetic(
+    left: ConstantValue,
+    right: ConstantValue,
+    int_op: impl Fn(i64, i64) -> Option<i64>,
+    float_op: impl Fn(f64, f64) -> f64,
+) -> Option<ConstantValue> {
+    match (left, right) {
+        (ConstantValue::Int(left), ConstantValue::Int(right)) => {
+            int_op(left, right).map(ConstantValue::Int)
+        }
+        (left, right) => {
+            let value = float_op(as_float_operand(left)?, as_float_operand(right)?);
+            if value.is_finite() {
+                Some(ConstantValue::Float(value))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+fn fold_comparison(
+    left: ConstantValue,
+    right: ConstantValue,
+    predicate: impl Fn(std::cmp::Ordering) -> bool,
+) -> Option<ConstantValue> {
+    let ordering = match (left, right) {
+        (ConstantValue::Int(left), ConstantValue::Int(right)) => Some(left.cmp(&right)),
+        (left, right) => as_float_operand(left)?.partial_cmp(&as_float_operand(right)?),
+    }?;
+    Some(ConstantValue::Boolean(predicate(ordering)))
+}
+
+fn as_float_operand(value: ConstantValue) -> Option<f64> {
+    match value {
+        ConstantValue::Int(value) => Some(value as f64),
+        ConstantValue::Float(value) => Some(value),
+        ConstantValue::Boolean(_) => None,
+    }
+}
+
+fn compile_folded_constant<A: Arena + Clone>(
+    value: ConstantValue,
+    stack: CompilerStack,
+    state: &mut CompilerState,
+) -> CompilerResult<A> {
+    // Allocate the folded result into the heap snapshot, reusing any existing identical term
+    let term = Term::new(TermType::from(value), &state.heap);
+    let existing = state
+        .serializer_state
+        .allocated_terms
+        .get(&term.id())
+        .copied();
+    let heap_pointer = match existing {
+        Some(heap_pointer) => heap_pointer,
+        None => {
+            let term_id = term.id();
+            let heap_pointer = state.heap.allocate(term);
+            state
+                .serializer_state
+                .allocated_terms
+                .insert(term_id, heap_pointer);
+            state.serializer_state.next_offset = state.heap.end_offset();
+            heap_pointer
+        }
+    };
+    let block = CompiledBlockBuilder::new(stack);
+    // Push the folded result term onto the stack
+    // => [Term]
+    let block = block.push(instruction::core::Const {
+        value: ConstValue::HeapPointer(heap_pointer),
+    });
+    block.finish()
+}
+
 impl<'a, A: Arena + Clone> CompileWasm<A> for CompiledFunctionCall<'a, A, Stdlib> {
     fn compile(
         &self,
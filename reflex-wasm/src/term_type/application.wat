@@ -18,7 +18,8 @@
   (export "getApplicationArgs" (func $Term::Application::get::args))
 
   (func $Term::Application::new (export "createApplication") (param $target i32) (param $args i32) (result i32)
-    (call $Term::TermType::Application::new (local.get $target) (local.get $args)))
+    ;; Any list views or list ropes are flattened, to ensure that the argument list is stored as a contiguous list
+    (call $Term::TermType::Application::new (local.get $target) (call $Term::List::flatten (local.get $args))))
 
   (func $Term::Application::traits::is_atomic (param $self i32) (result i32)
     (global.get $FALSE))
@@ -0,0 +1,265 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::collections::HashSet;
+
+use num_bigint::{BigInt, Sign};
+use reflex::core::{ArgType, DependencyList, GraphNode, SerializeJson, StackOffset};
+use reflex_macros::PointerIter;
+use serde_json::Value as JsonValue;
+
+use crate::{
+    allocator::{Arena, ArenaAllocator},
+    compiler::{
+        instruction, runtime::builtin::RuntimeBuiltin, CompileWasm, CompiledBlockBuilder,
+        CompilerOptions, CompilerResult, CompilerStack, CompilerState, ConstValue, Internable,
+        ValueType,
+    },
+    hash::{TermHash, TermHasher, TermSize},
+    term_type::TermType,
+    ArenaPointer, ArenaRef, Array, Term,
+};
+
+#[derive(Clone, Copy, Debug, PointerIter)]
+#[repr(C)]
+pub struct BigIntTerm {
+    pub sign: u32,
+    pub limbs: Array<u32>,
+}
+impl TermSize for BigIntTerm {
+    fn size_of(&self) -> usize {
+        std::mem::size_of::<u32>() + self.limbs.size_of()
+    }
+}
+impl TermHash for BigIntTerm {
+    fn hash(&self, hasher: TermHasher, arena: &impl Arena) -> TermHasher {
+        hasher.hash(&self.sign, arena).hash(&self.limbs, arena)
+    }
+}
+impl BigIntTerm {
+    pub fn allocate(value: &BigInt, arena: &mut impl ArenaAllocator) -> ArenaPointer {
+        let (sign, limbs) = get_bigint_limbs(value);
+        let term = Term::new(
+            TermType::BigInt(BigIntTerm {
+                sign,
+                limbs: Default::default(),
+            }),
+            arena,
+        );
+        let term_size = term.size_of();
+        let instance = arena.allocate(term);
+        let list = instance.offset((term_size - std::mem::size_of::<Array<u32>>()) as u32);
+        Array::<u32>::extend(list, limbs, arena);
+        let hash = arena.read_value::<Term, _>(instance, |term| {
+            TermHasher::default().hash(term, arena).finish()
+        });
+        arena.write::<u64>(Term::get_hash_pointer(instance), u64::from(hash));
+        instance
+    }
+}
+
+impl<A: Arena + Clone> ArenaRef<BigIntTerm, A> {
+    pub fn is_negative(&self) -> bool {
+        self.read_value(|term| term.sign != 0)
+    }
+    pub fn limbs(&self) -> ArenaRef<Array<u32>, A> {
+        self.inner_ref(|term| &term.limbs)
+    }
+    pub fn value(&self) -> BigInt {
+        let sign = if self.is_negative() {
+            Sign::Minus
+        } else {
+            Sign::Plus
+        };
+        BigInt::from_slice(sign, &self.limbs().iter().collect::<Vec<_>>())
+    }
+}
+
+fn get_bigint_limbs(value: &BigInt) -> (u32, Vec<u32>) {
+    let (sign, limbs) = value.to_u32_digits();
+    let sign = match sign {
+        Sign::Minus => 1,
+        Sign::NoSign | Sign::Plus => 0,
+    };
+    (sign, limbs)
+}
+
+impl<A: Arena + Clone> GraphNode for ArenaRef<BigIntTerm, A> {
+    fn size(&self) -> usize {
+        1
+    }
+    fn capture_depth(&self) -> StackOffset {
+        0
+    }
+    fn free_variables(&self) -> HashSet<StackOffset> {
+        HashSet::new()
+    }
+    fn count_variable_usages(&self, _offset: StackOffset) -> usize {
+        0
+    }
+    fn dynamic_dependencies(&self, _deep: bool) -> DependencyList {
+        DependencyList::empty()
+    }
+    fn has_dynamic_dependencies(&self, _deep: bool) -> bool {
+        false
+    }
+    fn is_static(&self) -> bool {
+        true
+    }
+    fn is_atomic(&self) -> bool {
+        true
+    }
+    fn is_complex(&self) -> bool {
+        false
+    }
+}
+
+impl<A: Arena + Clone> SerializeJson for ArenaRef<BigIntTerm, A> {
+    fn to_json(&self) -> Result<JsonValue, String> {
+        // Arbitrary-precision integers cannot be safely represented as JSON numbers
+        Ok(JsonValue::String(self.value().to_string()))
+    }
+    fn patch(&self, target: &Self) -> Result<Option<JsonValue>, String> {
+        if self.value() == target.value() {
+            Ok(None)
+        } else {
+            target.to_json().map(Some)
+        }
+    }
+}
+
+impl<A: Arena + Clone> PartialEq for ArenaRef<BigIntTerm, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value() == other.value()
+    }
+}
+impl<A: Arena + Clone> Eq for ArenaRef<BigIntTerm, A> {}
+
+impl<A: Arena + Clone> std::fmt::Debug for ArenaRef<BigIntTerm, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.read_value(|term| std::fmt::Debug::fmt(term, f))
+    }
+}
+
+impl<A: Arena + Clone> std::fmt::Display for ArenaRef<BigIntTerm, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+impl<A: Arena + Clone> Internable for ArenaRef<BigIntTerm, A> {
+    fn should_intern(&self, _eager: ArgType) -> bool {
+        true
+    }
+}
+
+impl<A: Arena + Clone> CompileWasm<A> for ArenaRef<BigIntTerm, A> {
+    fn compile(
+        &self,
+        stack: CompilerStack,
+        _state: &mut CompilerState,
+        _options: &CompilerOptions,
+    ) -> CompilerResult<A> {
+        let sign = self.read_value(|term| term.sign);
+        let limbs = self.limbs();
+        let block = CompiledBlockBuilder::new(stack);
+        // Push the sign onto the stack
+        // => [sign]
+        let block = block.push(instruction::core::Const {
+            value: ConstValue::U32(sign),
+        });
+        // Push the number of limbs onto the stack
+        // => [sign, length]
+        let block = block.push(instruction::core::Const {
+            value: ConstValue::U32(limbs.len() as u32),
+        });
+        // Allocate the BigInt term
+        // => [BigIntTerm]
+        let block = block.push(instruction::runtime::CallRuntimeBuiltin {
+            target: RuntimeBuiltin::AllocateBigInt,
+        });
+        // Assign the limb values
+        let block = limbs
+            .iter()
+            .enumerate()
+            .fold(block, |block, (index, limb)| {
+                // Duplicate the BigInt term pointer onto the stack
+                // => [BigIntTerm, BigIntTerm]
+                let block = block.push(instruction::core::Duplicate {
+                    value_type: ValueType::HeapPointer,
+                });
+                // Push the limb index onto the stack
+                // => [BigIntTerm, BigIntTerm, index]
+                let block = block.push(instruction::core::Const {
+                    value: ConstValue::U32(index as u32),
+                });
+                // Get the memory offset for the limb at the given index
+                // => [BigIntTerm, offset]
+                let block = block.push(instruction::runtime::CallRuntimeBuiltin {
+                    target: RuntimeBuiltin::GetBigIntLimbOffset,
+                });
+                // Push the limb value onto the stack
+                // => [BigIntTerm, offset, limb]
+                let block = block.push(instruction::core::Const {
+                    value: ConstValue::U32(limb),
+                });
+                // Write the limb value to the term contents
+                // => [BigIntTerm]
+                block.push(instruction::core::WriteHeapValue {
+                    value_type: ValueType::U32,
+                })
+            });
+        // Initialize the BigInt term
+        // => [BigIntTerm]
+        let block = block.push(instruction::runtime::CallRuntimeBuiltin {
+            target: RuntimeBuiltin::InitBigInt,
+        });
+        block.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        allocator::VecAllocator,
+        term_type::{TermType, TermTypeDiscriminants},
+        utils::chunks_to_u64,
+    };
+
+    use super::*;
+
+    #[test]
+    fn big_int() {
+        assert_eq!(
+            TermType::BigInt(BigIntTerm {
+                sign: 1,
+                limbs: Default::default(),
+            })
+            .as_bytes(),
+            [TermTypeDiscriminants::BigInt as u32, 1, 0, 0],
+        );
+        let mut allocator = VecAllocator::default();
+        {
+            let value = -BigInt::from(0x123456789abcdef0u64);
+            let instance = BigIntTerm::allocate(&value, &mut allocator);
+            let result = allocator.get_ref::<Term>(instance).as_bytes();
+            let _hash = chunks_to_u64([result[0], result[1]]);
+            let discriminant = result[2];
+            let sign = result[3];
+            let limbs_capacity = result[4];
+            let limbs_length = result[5];
+            let limbs = &result[6..];
+            assert_eq!(discriminant, TermTypeDiscriminants::BigInt as u32);
+            assert_eq!(sign, 1);
+            assert_eq!(limbs_capacity, 2);
+            assert_eq!(limbs_length, 2);
+            assert_eq!(limbs, [0x9abcdef0, 0x12345678]);
+            assert_eq!(
+                ArenaRef::<Term, _>::new(&allocator, instance)
+                    .as_big_int_term()
+                    .map(|term| term.as_inner().value()),
+                Some(value),
+            );
+        }
+    }
+}
@@ -0,0 +1,75 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Term::BigInt', (test) => {
+    test('value', (assert, { createBigInt, getBigIntValue }) => {
+      assert.strictEqual(getBigIntValue(createBigInt(0n)), 0n);
+      assert.strictEqual(getBigIntValue(createBigInt(3n)), 3n);
+      assert.strictEqual(getBigIntValue(createBigInt(-3n)), -3n);
+      assert.strictEqual(
+        getBigIntValue(createBigInt(123456789012345678901234567890n)),
+        123456789012345678901234567890n,
+      );
+      assert.strictEqual(
+        getBigIntValue(createBigInt(-123456789012345678901234567890n)),
+        -123456789012345678901234567890n,
+      );
+    });
+
+    test('display', (assert, { createBigInt, display }) => {
+      assert.strictEqual(display(createBigInt(0n)), '0');
+      assert.strictEqual(display(createBigInt(3n)), '3');
+      assert.strictEqual(display(createBigInt(-3n)), '-3');
+      assert.strictEqual(display(createBigInt(4294967296n)), '4294967296');
+      assert.strictEqual(display(createBigInt(1000000000n)), '1000000000');
+      assert.strictEqual(display(createBigInt(1000000000000000001n)), '1000000000000000001');
+      assert.strictEqual(
+        display(createBigInt(123456789012345678901234567890n)),
+        '123456789012345678901234567890',
+      );
+      assert.strictEqual(
+        display(createBigInt(-340282366920938463463374607431768211455n)),
+        '-340282366920938463463374607431768211455',
+      );
+    });
+
+    test('format', (assert, { createBigInt, format }) => {
+      assert.strictEqual(format(createBigInt(-3n)), 'BigInt(-3)');
+      assert.strictEqual(
+        format(createBigInt(123456789012345678901234567890n)),
+        'BigInt(123456789012345678901234567890)',
+      );
+    });
+
+    test('hash', (assert, { createBigInt, hash }) => {
+      assert.strictEqual(hash(createBigInt(0n)), hash(createBigInt(0n)));
+      assert.strictEqual(
+        hash(createBigInt(123456789012345678901234567890n)),
+        hash(createBigInt(123456789012345678901234567890n)),
+      );
+      assert.notStrictEqual(hash(createBigInt(-3n)), hash(createBigInt(3n)));
+      assert.notStrictEqual(
+        hash(createBigInt(123456789012345678901234567890n)),
+        hash(createBigInt(123456789012345678901234567891n)),
+      );
+    });
+
+    test('equals', (assert, { createBigInt, equals }) => {
+      assert.strictEqual(equals(createBigInt(0n), createBigInt(0n)), true);
+      assert.strictEqual(equals(createBigInt(-3n), createBigInt(-3n)), true);
+      assert.strictEqual(equals(createBigInt(-3n), createBigInt(3n)), false);
+      assert.strictEqual(
+        equals(
+          createBigInt(123456789012345678901234567890n),
+          createBigInt(123456789012345678901234567890n),
+        ),
+        true,
+      );
+      assert.strictEqual(
+        equals(createBigInt(4294967296n), createBigInt(4294967297n)),
+        false,
+      );
+    });
+  });
+};
@@ -0,0 +1,638 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@let $BigInt
+    (@struct $BigInt
+      (@field $sign i32)
+      (@field $limbs (@repeated i32)))
+
+    (@derive $size (@get $BigInt))
+    (@derive $equals (@get $BigInt))
+    (@derive $hash (@get $BigInt))
+
+    (@export $BigInt (@get $BigInt)))
+
+  (export "isBigInt" (func $Term::BigInt::is))
+  (export "getBigIntSign" (func $Term::BigInt::get::sign))
+  (export "getBigIntLength" (func $Term::BigInt::get::limbs::length))
+
+  (@const $Term::BigInt::ZERO i32 (call $Term::TermType::BigInt::new (i32.const 0)))
+
+  (func $Term::BigInt::allocate (export "allocateBigInt") (param $sign i32) (param $length i32) (result i32)
+    (local $self i32)
+    ;; Allocates a new BigInt term with the given number of 32-bit limbs, allowing the magnitude to be written directly
+    ;; into the allocated array (least significant limb first).
+    ;; The term must be instantiated before it can be used.
+    (if (result i32)
+      (i32.eqz (local.get $length))
+      (then
+        ;; Return the pre-allocated singleton instance
+        (global.get $Term::BigInt::ZERO))
+      (else
+        ;; The standard constructor wrappers do not allocate space for the array elements, so we have to manually
+        ;; allocate a larger amount of space than usual, then fill in the term contents into the newly-allocated space
+        (local.tee $self
+          (call $Allocator::allocate
+            (i32.add
+              (call $Term::BigInt::empty::sizeof)
+              (i32.mul (local.get $length) (i32.const 4)))))
+        ;; Manually write the struct contents into the term wrapper
+        (call $TermType::BigInt::construct (call $Term::pointer::value (local.get $self)) (local.get $sign))
+        ;; Set the capacity and length of the limbs array
+        (call $Term::BigInt::set::limbs::capacity (local.get $self) (local.get $length))
+        (call $Term::BigInt::set::limbs::length (local.get $self) (local.get $length)))))
+
+  (func $Term::BigInt::allocate_zeroed (param $sign i32) (param $length i32) (result i32)
+    (local $self i32)
+    ;; Allocate a new term with all limbs set to zero, ready to be used as an accumulator
+    (local.set $self (call $Term::BigInt::allocate (local.get $sign) (local.get $length)))
+    (memory.fill
+      (call $Term::BigInt::get::limbs::pointer (local.get $self) (i32.const 0))
+      (i32.const 0)
+      (i32.mul (local.get $length) (i32.const 4)))
+    (local.get $self))
+
+  (func $Term::BigInt::init (export "initBigInt") (param $self i32) (result i32)
+    (local $length i32)
+    ;; Discard any leading zero limbs to ensure that each value has a single canonical representation
+    (local.set $length
+      (call $Term::BigInt::magnitude::length
+        (call $Term::BigInt::get::limbs::pointer (local.get $self) (i32.const 0))
+        (call $Term::BigInt::get::limbs::length (local.get $self))))
+    (if (result i32)
+      (i32.eqz (local.get $length))
+      (then
+        ;; If the value is zero, dispose of the temporary term and return the pre-allocated singleton instance
+        (call $Term::BigInt::drop (local.get $self))
+        (global.get $Term::BigInt::ZERO))
+      (else
+        (call $Term::BigInt::set::limbs::length (local.get $self) (local.get $length))
+        ;; Instantiate the term
+        (call $Term::init (local.get $self)))))
+
+  (func $Term::BigInt::drop (param $self i32)
+    ;; Avoid dropping the global zero instance
+    (if (i32.ne (local.get $self) (global.get $Term::BigInt::ZERO))
+      (then
+        (call $Term::drop (local.get $self)))))
+
+  (func $Term::BigInt::empty::sizeof (result i32)
+    ;; Determine the size of the term wrapper by inspecting the limbs pointer for an imaginary term located at memory
+    ;; address 0. The pointer offset tells us how many bytes are taken up by the preceding term wrapper.
+    (call $Term::BigInt::get::limbs::pointer (i32.const 0) (i32.const 0)))
+
+  (func $Term::BigInt::get_limb_offset (export "getBigIntLimbOffset") (param $self i32) (param $index i32) (result i32)
+    (call $Term::BigInt::get::limbs::pointer (local.get $self) (local.get $index)))
+
+  (func $Term::BigInt::from_i64 (param $value i64) (result i32)
+    (local $self i32)
+    (local $magnitude i64)
+    ;; Note that the absolute value of the minimum signed integer wraps around to itself, which is still the correct
+    ;; magnitude when interpreted as an unsigned integer
+    (local.set $magnitude (call $Utils::i64::abs (local.get $value)))
+    (local.set $self (call $Term::BigInt::allocate (i64.lt_s (local.get $value) (i64.const 0)) (i32.const 2)))
+    (call $Term::BigInt::set::limbs::value (local.get $self) (i32.const 0) (i32.wrap_i64 (local.get $magnitude)))
+    (call $Term::BigInt::set::limbs::value
+      (local.get $self)
+      (i32.const 1)
+      (i32.wrap_i64 (i64.shr_u (local.get $magnitude) (i64.const 32))))
+    (call $Term::BigInt::init (local.get $self)))
+
+  (func $Term::BigInt::traits::is_atomic (param $self i32) (result i32)
+    (global.get $TRUE))
+
+  (func $Term::BigInt::traits::display (param $self i32) (param $offset i32) (result i32)
+    (local $length i32)
+    (local $digits_offset i32)
+    (local $scratch_offset i32)
+    (local $end_offset i32)
+    (local $remainder i32)
+    (local $num_digits i32)
+    ;; Zero is a special case as it has no limbs
+    (if
+      (i32.eqz (local.tee $length (call $Term::BigInt::get::limbs::length (local.get $self))))
+      (then
+        (@store-bytes $offset "0")
+        (return (i32.add (local.get $offset)))))
+    ;; If the number is negative, write a minus sign to the output
+    (if
+      (call $Term::BigInt::get::sign (local.get $self))
+      (then
+        (@store-bytes $offset "-")
+        (local.set $offset (i32.add (local.get $offset)))))
+    ;; Converting the magnitude to decimal requires a mutable working copy of the limbs, so reserve enough space for
+    ;; the maximum possible number of output digits (each 32-bit limb contributes fewer than 10 decimal digits),
+    ;; followed by the working copy of the limbs (the excess space will be released once the digits have been written)
+    (local.set $digits_offset (local.get $offset))
+    (local.set $scratch_offset
+      (call $Allocator::pad_to_4_byte_offset
+        (i32.add (local.get $offset) (i32.mul (local.get $length) (i32.const 10)))))
+    (local.set $end_offset (i32.add (local.get $scratch_offset) (i32.mul (local.get $length) (i32.const 4))))
+    (call $Allocator::extend (local.get $offset) (i32.sub (local.get $end_offset) (local.get $offset)))
+    (memory.copy
+      (local.get $scratch_offset)
+      (call $Term::BigInt::get::limbs::pointer (local.get $self) (i32.const 0))
+      (i32.mul (local.get $length) (i32.const 4)))
+    ;; Repeatedly divide the working copy by 10^9, writing the digits of each remainder in reverse order
+    (loop $LOOP
+      (local.set $remainder
+        (call $Term::BigInt::magnitude::divide_small_assign
+          (local.get $scratch_offset)
+          (local.get $length)
+          (i32.const 1000000000)))
+      (local.set $length (call $Term::BigInt::magnitude::length (local.get $scratch_offset) (local.get $length)))
+      ;; Each remainder is padded to 9 digits, except for the most significant remainder
+      (local.set $num_digits (i32.const 0))
+      (loop $DIGITS
+        (call $Utils::u8::write_decimal_digit (i32.rem_u (local.get $remainder) (i32.const 10)) (local.get $offset))
+        (local.set $offset (i32.add (local.get $offset) (i32.const 1)))
+        (local.set $remainder (i32.div_u (local.get $remainder) (i32.const 10)))
+        (local.set $num_digits (i32.add (local.get $num_digits) (i32.const 1)))
+        (br_if $DIGITS
+          (if (result i32)
+            (local.get $length)
+            (then
+              (i32.lt_u (local.get $num_digits) (i32.const 9)))
+            (else
+              (i32.ne (local.get $remainder) (i32.const 0))))))
+      (br_if $LOOP (local.get $length)))
+    ;; Reverse the digits into most-significant-first order
+    (call $Utils::bytes::reverse (local.get $digits_offset) (i32.sub (local.get $offset) (local.get $digits_offset)))
+    ;; Release the unused space and return the updated offset
+    (call $Allocator::shrink (local.get $end_offset) (i32.sub (local.get $end_offset) (local.get $offset)))
+    (local.get $offset))
+
+  (func $Term::BigInt::traits::debug (param $self i32) (param $offset i32) (result i32)
+    (@store-bytes $offset "BigInt(")
+    (local.set $offset (i32.add (local.get $offset)))
+    (local.set $offset (call $Term::BigInt::traits::display (local.get $self) (local.get $offset)))
+    (@store-bytes $offset ")")
+    (i32.add (local.get $offset)))
+
+  (func $Term::BigInt::traits::substitute (param $self i32) (param $variables i32) (param $scope_offset i32) (result i32)
+    (global.get $NULL))
+
+  (func $Term::BigInt::traits::to_json (param $self i32) (param $offset i32) (result i32 i32)
+    ;; Put the success marker on the stack
+    (global.get $TRUE)
+    ;; Arbitrary-precision integers cannot be safely represented as JSON numbers, so serialize as a decimal string
+    (@store-bytes $offset "\"")
+    (local.set $offset (i32.add (local.get $offset)))
+    (local.set $offset (call $Term::BigInt::traits::display (local.get $self) (local.get $offset)))
+    (@store-bytes $offset "\"")
+    (i32.add (local.get $offset)))
+
+  (func $Term::BigInt::compare (param $self i32) (param $other i32) (result i32)
+    (local $sign i32)
+    (local $ordering i32)
+    ;; Values with differing signs can be ordered by sign alone
+    (if
+      (i32.ne
+        (local.tee $sign (call $Term::BigInt::get::sign (local.get $self)))
+        (call $Term::BigInt::get::sign (local.get $other)))
+      (then
+        (return (select (i32.const -1) (i32.const 1) (local.get $sign)))))
+    ;; Otherwise compare the magnitudes, inverting the ordering for negative values
+    (local.set $ordering
+      (call $Term::BigInt::magnitude::compare
+        (call $Term::BigInt::get::limbs::pointer (local.get $self) (i32.const 0))
+        (call $Term::BigInt::get::limbs::length (local.get $self))
+        (call $Term::BigInt::get::limbs::pointer (local.get $other) (i32.const 0))
+        (call $Term::BigInt::get::limbs::length (local.get $other))))
+    (select
+      (i32.sub (i32.const 0) (local.get $ordering))
+      (local.get $ordering)
+      (local.get $sign)))
+
+  (func $Term::BigInt::add (param $self i32) (param $other i32) (result i32)
+    (call $Term::BigInt::add_signed
+      (local.get $self)
+      (local.get $other)
+      (call $Term::BigInt::get::sign (local.get $other))))
+
+  (func $Term::BigInt::subtract (param $self i32) (param $other i32) (result i32)
+    (call $Term::BigInt::add_signed
+      (local.get $self)
+      (local.get $other)
+      (i32.eqz (call $Term::BigInt::get::sign (local.get $other)))))
+
+  (func $Term::BigInt::add_signed (param $self i32) (param $other i32) (param $other_sign i32) (result i32)
+    (local $self_sign i32)
+    (local $left i32)
+    (local $left_length i32)
+    (local $right i32)
+    (local $right_length i32)
+    (local.set $self_sign (call $Term::BigInt::get::sign (local.get $self)))
+    (local.set $left (call $Term::BigInt::get::limbs::pointer (local.get $self) (i32.const 0)))
+    (local.set $left_length (call $Term::BigInt::get::limbs::length (local.get $self)))
+    (local.set $right (call $Term::BigInt::get::limbs::pointer (local.get $other) (i32.const 0)))
+    (local.set $right_length (call $Term::BigInt::get::limbs::length (local.get $other)))
+    (if (result i32)
+      (i32.eq (local.get $self_sign) (local.get $other_sign))
+      (then
+        ;; If both operands have the same sign, add the magnitudes and retain the sign
+        (call $Term::BigInt::magnitude::add
+          (local.get $left)
+          (local.get $left_length)
+          (local.get $right)
+          (local.get $right_length)
+          (local.get $self_sign)))
+      (else
+        ;; Otherwise subtract the smaller magnitude from the larger magnitude, taking the sign of the larger operand
+        (if (result i32)
+          (i32.lt_s
+            (call $Term::BigInt::magnitude::compare
+              (local.get $left)
+              (local.get $left_length)
+              (local.get $right)
+              (local.get $right_length))
+            (i32.const 0))
+          (then
+            (call $Term::BigInt::magnitude::subtract
+              (local.get $right)
+              (local.get $right_length)
+              (local.get $left)
+              (local.get $left_length)
+              (local.get $other_sign)))
+          (else
+            (call $Term::BigInt::magnitude::subtract
+              (local.get $left)
+              (local.get $left_length)
+              (local.get $right)
+              (local.get $right_length)
+              (local.get $self_sign)))))))
+
+  (func $Term::BigInt::multiply (param $self i32) (param $other i32) (result i32)
+    (local $instance i32)
+    (local $target i32)
+    (local $left i32)
+    (local $left_length i32)
+    (local $right i32)
+    (local $right_length i32)
+    (local $left_index i32)
+    (local $right_index i32)
+    (local $left_limb i64)
+    (local $carry i64)
+    (local.set $left (call $Term::BigInt::get::limbs::pointer (local.get $self) (i32.const 0)))
+    (local.set $left_length (call $Term::BigInt::get::limbs::length (local.get $self)))
+    (local.set $right (call $Term::BigInt::get::limbs::pointer (local.get $other) (i32.const 0)))
+    (local.set $right_length (call $Term::BigInt::get::limbs::length (local.get $other)))
+    ;; Allocate a zero-filled accumulator with enough limbs to hold the full product
+    (local.set $instance
+      (call $Term::BigInt::allocate_zeroed
+        (i32.xor
+          (call $Term::BigInt::get::sign (local.get $self))
+          (call $Term::BigInt::get::sign (local.get $other)))
+        (i32.add (local.get $left_length) (local.get $right_length))))
+    (local.set $target (call $Term::BigInt::get::limbs::pointer (local.get $instance) (i32.const 0)))
+    ;; Perform long multiplication, accumulating one row of partial products per limb of the left operand
+    ;; (the intermediate values cannot overflow, as (2^32 - 1)^2 + 2 * (2^32 - 1) = 2^64 - 1)
+    (loop $LEFT
+      (if
+        (i32.lt_u (local.get $left_index) (local.get $left_length))
+        (then
+          (local.set $left_limb (call $Term::BigInt::magnitude::get (local.get $left) (local.get $left_index)))
+          (local.set $carry (i64.const 0))
+          (local.set $right_index (i32.const 0))
+          (loop $RIGHT
+            (if
+              (i32.lt_u (local.get $right_index) (local.get $right_length))
+              (then
+                (local.set $carry
+                  (i64.add
+                    (i64.add
+                      (call $Term::BigInt::magnitude::get
+                        (local.get $target)
+                        (i32.add (local.get $left_index) (local.get $right_index)))
+                      (i64.mul
+                        (local.get $left_limb)
+                        (call $Term::BigInt::magnitude::get (local.get $right) (local.get $right_index))))
+                    (local.get $carry)))
+                (call $Term::BigInt::magnitude::set
+                  (local.get $target)
+                  (i32.add (local.get $left_index) (local.get $right_index))
+                  (i32.wrap_i64 (local.get $carry)))
+                (local.set $carry (i64.shr_u (local.get $carry) (i64.const 32)))
+                (local.set $right_index (i32.add (local.get $right_index) (i32.const 1)))
+                (br $RIGHT))))
+          ;; Store the final carry for this row in the next most significant limb
+          (call $Term::BigInt::magnitude::set
+            (local.get $target)
+            (i32.add (local.get $left_index) (local.get $right_length))
+            (i32.wrap_i64 (local.get $carry)))
+          (local.set $left_index (i32.add (local.get $left_index) (i32.const 1)))
+          (br $LEFT))))
+    (call $Term::BigInt::init (local.get $instance)))
+
+  (func $Term::BigInt::divide (param $self i32) (param $other i32) (result i32 i32)
+    ;; Perform truncating division, returning the quotient and the remainder
+    ;; (the quotient is rounded towards zero, and the remainder takes the sign of the dividend)
+    ;; The divisor must be non-zero.
+    (local $quotient i32)
+    (local $remainder i32)
+    (local $left i32)
+    (local $left_length i32)
+    (local $right i32)
+    (local $right_length i32)
+    (local $quotient_limbs i32)
+    (local $remainder_limbs i32)
+    (local $remainder_length i32)
+    (local $bit_index i32)
+    (local.set $left (call $Term::BigInt::get::limbs::pointer (local.get $self) (i32.const 0)))
+    (local.set $left_length (call $Term::BigInt::get::limbs::length (local.get $self)))
+    (local.set $right (call $Term::BigInt::get::limbs::pointer (local.get $other) (i32.const 0)))
+    (local.set $right_length (call $Term::BigInt::get::limbs::length (local.get $other)))
+    ;; If the magnitude of the dividend is smaller than the divisor, the dividend is the remainder
+    (if
+      (i32.lt_s
+        (call $Term::BigInt::magnitude::compare
+          (local.get $left)
+          (local.get $left_length)
+          (local.get $right)
+          (local.get $right_length))
+        (i32.const 0))
+      (then
+        (return (global.get $Term::BigInt::ZERO) (local.get $self))))
+    ;; Allocate zero-filled accumulators for the quotient and remainder
+    ;; (the remainder requires an extra limb to accommodate the intermediate shifted value)
+    (local.set $quotient
+      (call $Term::BigInt::allocate_zeroed
+        (i32.xor
+          (call $Term::BigInt::get::sign (local.get $self))
+          (call $Term::BigInt::get::sign (local.get $other)))
+        (local.get $left_length)))
+    (local.set $remainder
+      (call $Term::BigInt::allocate_zeroed
+        (call $Term::BigInt::get::sign (local.get $self))
+        (local.tee $remainder_length (i32.add (local.get $right_length) (i32.const 1)))))
+    (local.set $quotient_limbs (call $Term::BigInt::get::limbs::pointer (local.get $quotient) (i32.const 0)))
+    (local.set $remainder_limbs (call $Term::BigInt::get::limbs::pointer (local.get $remainder) (i32.const 0)))
+    ;; Perform binary long division, starting from the most significant bit of the dividend
+    (local.set $bit_index (i32.mul (local.get $left_length) (i32.const 32)))
+    (loop $LOOP
+      (local.set $bit_index (i32.sub (local.get $bit_index) (i32.const 1)))
+      ;; Shift the next bit of the dividend into the remainder
+      (call $Term::BigInt::magnitude::shift_left_assign
+        (local.get $remainder_limbs)
+        (local.get $remainder_length)
+        (i32.and
+          (i32.shr_u
+            (call $Term::BigInt::magnitude::get_u32 (local.get $left) (i32.shr_u (local.get $bit_index) (i32.const 5)))
+            (i32.and (local.get $bit_index) (i32.const 31)))
+          (i32.const 1)))
+      ;; If the remainder is at least as large as the divisor, subtract the divisor and set the quotient bit
+      (if
+        (i32.ge_s
+          (call $Term::BigInt::magnitude::compare
+            (local.get $remainder_limbs)
+            (call $Term::BigInt::magnitude::length (local.get $remainder_limbs) (local.get $remainder_length))
+            (local.get $right)
+            (local.get $right_length))
+          (i32.const 0))
+        (then
+          (call $Term::BigInt::magnitude::subtract_assign
+            (local.get $remainder_limbs)
+            (local.get $remainder_length)
+            (local.get $right)
+            (local.get $right_length))
+          (call $Term::BigInt::magnitude::set
+            (local.get $quotient_limbs)
+            (i32.shr_u (local.get $bit_index) (i32.const 5))
+            (i32.or
+              (call $Term::BigInt::magnitude::get_u32
+                (local.get $quotient_limbs)
+                (i32.shr_u (local.get $bit_index) (i32.const 5)))
+              (i32.shl (i32.const 1) (i32.and (local.get $bit_index) (i32.const 31)))))))
+      (br_if $LOOP (local.get $bit_index)))
+    (call $Term::BigInt::init (local.get $quotient))
+    (call $Term::BigInt::init (local.get $remainder)))
+
+  (func $Term::BigInt::abs (param $self i32) (result i32)
+    (local $instance i32)
+    (local $length i32)
+    ;; If the value is already non-negative, return it as-is
+    (if
+      (i32.eqz (call $Term::BigInt::get::sign (local.get $self)))
+      (then
+        (return (local.get $self))))
+    ;; Otherwise create a copy of the value with a positive sign
+    (local.set $instance
+      (call $Term::BigInt::allocate
+        (i32.const 0)
+        (local.tee $length (call $Term::BigInt::get::limbs::length (local.get $self)))))
+    (memory.copy
+      (call $Term::BigInt::get::limbs::pointer (local.get $instance) (i32.const 0))
+      (call $Term::BigInt::get::limbs::pointer (local.get $self) (i32.const 0))
+      (i32.mul (local.get $length) (i32.const 4)))
+    (call $Term::BigInt::init (local.get $instance)))
+
+  ;; Low-level helpers for operating on unsigned magnitudes, represented as arrays of 32-bit limbs
+  ;; (least significant limb first)
+
+  (func $Term::BigInt::magnitude::get (param $limbs i32) (param $index i32) (result i64)
+    (i64.extend_i32_u (call $Term::BigInt::magnitude::get_u32 (local.get $limbs) (local.get $index))))
+
+  (func $Term::BigInt::magnitude::get_u32 (param $limbs i32) (param $index i32) (result i32)
+    (i32.load (i32.add (local.get $limbs) (i32.shl (local.get $index) (i32.const 2)))))
+
+  (func $Term::BigInt::magnitude::get_or_zero (param $limbs i32) (param $length i32) (param $index i32) (result i64)
+    (if (result i64)
+      (i32.lt_u (local.get $index) (local.get $length))
+      (then
+        (call $Term::BigInt::magnitude::get (local.get $limbs) (local.get $index)))
+      (else
+        (i64.const 0))))
+
+  (func $Term::BigInt::magnitude::set (param $limbs i32) (param $index i32) (param $value i32)
+    (i32.store (i32.add (local.get $limbs) (i32.shl (local.get $index) (i32.const 2))) (local.get $value)))
+
+  (func $Term::BigInt::magnitude::length (param $limbs i32) (param $length i32) (result i32)
+    ;; Determine the number of significant limbs, ignoring any leading zero limbs
+    (loop $LOOP
+      (if
+        (local.get $length)
+        (then
+          (if
+            (i32.eqz
+              (call $Term::BigInt::magnitude::get_u32
+                (local.get $limbs)
+                (i32.sub (local.get $length) (i32.const 1))))
+            (then
+              (local.set $length (i32.sub (local.get $length) (i32.const 1)))
+              (br $LOOP))))))
+    (local.get $length))
+
+  (func $Term::BigInt::magnitude::compare (param $left i32) (param $left_length i32) (param $right i32) (param $right_length i32) (result i32)
+    ;; Both magnitudes are assumed to have had any leading zero limbs removed
+    (local $left_limb i32)
+    (local $right_limb i32)
+    ;; Magnitudes with differing numbers of significant limbs can be ordered by length alone
+    (if
+      (i32.ne (local.get $left_length) (local.get $right_length))
+      (then
+        (return (select (i32.const -1) (i32.const 1) (i32.lt_u (local.get $left_length) (local.get $right_length))))))
+    ;; Otherwise compare each pair of limbs in turn, starting from the most significant limb
+    (loop $LOOP
+      (if
+        (local.get $left_length)
+        (then
+          (local.set $left_length (i32.sub (local.get $left_length) (i32.const 1)))
+          (local.set $left_limb (call $Term::BigInt::magnitude::get_u32 (local.get $left) (local.get $left_length)))
+          (local.set $right_limb (call $Term::BigInt::magnitude::get_u32 (local.get $right) (local.get $left_length)))
+          (if
+            (i32.ne (local.get $left_limb) (local.get $right_limb))
+            (then
+              (return (select (i32.const -1) (i32.const 1) (i32.lt_u (local.get $left_limb) (local.get $right_limb))))))
+          (br $LOOP))))
+    (i32.const 0))
+
+  (func $Term::BigInt::magnitude::add (param $left i32) (param $left_length i32) (param $right i32) (param $right_length i32) (param $sign i32) (result i32)
+    (local $instance i32)
+    (local $target i32)
+    (local $index i32)
+    (local $carry i64)
+    ;; Ensure that the left operand is the longer of the two operands
+    (if
+      (i32.lt_u (local.get $left_length) (local.get $right_length))
+      (then
+        (return
+          (call $Term::BigInt::magnitude::add
+            (local.get $right)
+            (local.get $right_length)
+            (local.get $left)
+            (local.get $left_length)
+            (local.get $sign)))))
+    ;; Allocate a new term with enough limbs to hold the final carry
+    (local.set $instance (call $Term::BigInt::allocate (local.get $sign) (i32.add (local.get $left_length) (i32.const 1))))
+    (local.set $target (call $Term::BigInt::get::limbs::pointer (local.get $instance) (i32.const 0)))
+    (loop $LOOP
+      (if
+        (i32.lt_u (local.get $index) (local.get $left_length))
+        (then
+          (local.set $carry
+            (i64.add
+              (local.get $carry)
+              (i64.add
+                (call $Term::BigInt::magnitude::get (local.get $left) (local.get $index))
+                (call $Term::BigInt::magnitude::get_or_zero (local.get $right) (local.get $right_length) (local.get $index)))))
+          (call $Term::BigInt::magnitude::set (local.get $target) (local.get $index) (i32.wrap_i64 (local.get $carry)))
+          (local.set $carry (i64.shr_u (local.get $carry) (i64.const 32)))
+          (local.set $index (i32.add (local.get $index) (i32.const 1)))
+          (br $LOOP))))
+    (call $Term::BigInt::magnitude::set (local.get $target) (local.get $left_length) (i32.wrap_i64 (local.get $carry)))
+    (call $Term::BigInt::init (local.get $instance)))
+
+  (func $Term::BigInt::magnitude::subtract (param $left i32) (param $left_length i32) (param $right i32) (param $right_length i32) (param $sign i32) (result i32)
+    ;; The left magnitude must be greater than or equal to the right magnitude
+    (local $instance i32)
+    (local.set $instance (call $Term::BigInt::allocate (local.get $sign) (local.get $left_length)))
+    (memory.copy
+      (call $Term::BigInt::get::limbs::pointer (local.get $instance) (i32.const 0))
+      (local.get $left)
+      (i32.mul (local.get $left_length) (i32.const 4)))
+    (call $Term::BigInt::magnitude::subtract_assign
+      (call $Term::BigInt::get::limbs::pointer (local.get $instance) (i32.const 0))
+      (local.get $left_length)
+      (local.get $right)
+      (local.get $right_length))
+    (call $Term::BigInt::init (local.get $instance)))
+
+  (func $Term::BigInt::magnitude::subtract_assign (param $target i32) (param $target_length i32) (param $source i32) (param $source_length i32)
+    ;; Subtract the source magnitude from the target magnitude in place
+    ;; (the target magnitude must be greater than or equal to the source magnitude)
+    (local $index i32)
+    (local $difference i64)
+    (local $borrow i64)
+    (loop $LOOP
+      (if
+        (i32.lt_u (local.get $index) (local.get $target_length))
+        (then
+          (local.set $difference
+            (i64.sub
+              (i64.sub
+                (call $Term::BigInt::magnitude::get (local.get $target) (local.get $index))
+                (call $Term::BigInt::magnitude::get_or_zero (local.get $source) (local.get $source_length) (local.get $index)))
+              (local.get $borrow)))
+          (call $Term::BigInt::magnitude::set (local.get $target) (local.get $index) (i32.wrap_i64 (local.get $difference)))
+          (local.set $borrow (i64.extend_i32_u (i64.lt_s (local.get $difference) (i64.const 0))))
+          (local.set $index (i32.add (local.get $index) (i32.const 1)))
+          (br $LOOP)))))
+
+  (func $Term::BigInt::magnitude::add_assign (param $target i32) (param $target_length i32) (param $source i32) (param $source_length i32)
+    ;; Add the source magnitude to the target magnitude in place
+    ;; (the target must have enough limbs to hold the result, including any final carry)
+    (local $index i32)
+    (local $carry i64)
+    (loop $LOOP
+      (if
+        (i32.lt_u (local.get $index) (local.get $target_length))
+        (then
+          (local.set $carry
+            (i64.add
+              (local.get $carry)
+              (i64.add
+                (call $Term::BigInt::magnitude::get (local.get $target) (local.get $index))
+                (call $Term::BigInt::magnitude::get_or_zero (local.get $source) (local.get $source_length) (local.get $index)))))
+          (call $Term::BigInt::magnitude::set (local.get $target) (local.get $index) (i32.wrap_i64 (local.get $carry)))
+          (local.set $carry (i64.shr_u (local.get $carry) (i64.const 32)))
+          (local.set $index (i32.add (local.get $index) (i32.const 1)))
+          (br $LOOP)))))
+
+  (func $Term::BigInt::magnitude::multiply_small_assign (param $limbs i32) (param $length i32) (param $multiplier i32)
+    ;; Multiply the magnitude in place by the given 32-bit multiplier
+    ;; (the magnitude must have enough limbs to hold the result, including any final carry)
+    (local $index i32)
+    (local $carry i64)
+    (loop $LOOP
+      (if
+        (i32.lt_u (local.get $index) (local.get $length))
+        (then
+          (local.set $carry
+            (i64.add
+              (local.get $carry)
+              (i64.mul
+                (call $Term::BigInt::magnitude::get (local.get $limbs) (local.get $index))
+                (i64.extend_i32_u (local.get $multiplier)))))
+          (call $Term::BigInt::magnitude::set (local.get $limbs) (local.get $index) (i32.wrap_i64 (local.get $carry)))
+          (local.set $carry (i64.shr_u (local.get $carry) (i64.const 32)))
+          (local.set $index (i32.add (local.get $index) (i32.const 1)))
+          (br $LOOP)))))
+
+  (func $Term::BigInt::magnitude::shift_left_assign (param $limbs i32) (param $length i32) (param $carry i32)
+    ;; Shift the magnitude left by a single bit in place, shifting the given carry bit into the least significant bit
+    (local $index i32)
+    (local $limb i32)
+    (loop $LOOP
+      (if
+        (i32.lt_u (local.get $index) (local.get $length))
+        (then
+          (local.set $limb (call $Term::BigInt::magnitude::get_u32 (local.get $limbs) (local.get $index)))
+          (call $Term::BigInt::magnitude::set
+            (local.get $limbs)
+            (local.get $index)
+            (i32.or (i32.shl (local.get $limb) (i32.const 1)) (local.get $carry)))
+          (local.set $carry (i32.shr_u (local.get $limb) (i32.const 31)))
+          (local.set $index (i32.add (local.get $index) (i32.const 1)))
+          (br $LOOP)))))
+
+  (func $Term::BigInt::magnitude::divide_small_assign (param $limbs i32) (param $length i32) (param $divisor i32) (result i32)
+    ;; Divide the magnitude in place by the given 32-bit divisor, returning the remainder
+    (local $remainder i64)
+    (local $value i64)
+    (loop $LOOP
+      (if
+        (local.get $length)
+        (then
+          (local.set $length (i32.sub (local.get $length) (i32.const 1)))
+          (local.set $value
+            (i64.or
+              (i64.shl (local.get $remainder) (i64.const 32))
+              (call $Term::BigInt::magnitude::get (local.get $limbs) (local.get $length))))
+          (call $Term::BigInt::magnitude::set
+            (local.get $limbs)
+            (local.get $length)
+            (i32.wrap_i64 (i64.div_u (local.get $value) (i64.extend_i32_u (local.get $divisor)))))
+          (local.set $remainder (i64.rem_u (local.get $value) (i64.extend_i32_u (local.get $divisor))))
+          (br $LOOP))))
+    (i32.wrap_i64 (local.get $remainder))))
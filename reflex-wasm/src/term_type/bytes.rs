@@ -0,0 +1,276 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{collections::HashSet, ops::Deref};
+
+use base64::Engine;
+use reflex::core::{ArgType, BytesTermType, DependencyList, GraphNode, SerializeJson, StackOffset};
+use reflex_macros::PointerIter;
+use serde_json::Value as JsonValue;
+
+use crate::{
+    allocator::{Arena, ArenaAllocator},
+    compiler::{
+        instruction, runtime::builtin::RuntimeBuiltin, CompileWasm, CompiledBlockBuilder,
+        CompilerOptions, CompilerResult, CompilerStack, CompilerState, ConstValue, Internable,
+        ValueType,
+    },
+    hash::{TermHash, TermHasher, TermSize},
+    term_type::{TermType, TypedTerm},
+    ArenaPointer, ArenaRef, Array, Term,
+};
+
+#[derive(Clone, Copy, Debug, PointerIter)]
+#[repr(C)]
+pub struct BytesTerm {
+    pub length: u32,
+    pub data: Array<u32>,
+}
+impl TermSize for BytesTerm {
+    fn size_of(&self) -> usize {
+        std::mem::size_of::<u32>() + self.data.size_of()
+    }
+}
+impl TermHash for BytesTerm {
+    fn hash(&self, hasher: TermHasher, arena: &impl Arena) -> TermHasher {
+        hasher.hash(&self.length, arena).hash(&self.data, arena)
+    }
+}
+impl BytesTerm {
+    pub fn allocate(value: &[u8], arena: &mut impl ArenaAllocator) -> ArenaPointer {
+        let term = Term::new(
+            TermType::Bytes(BytesTerm {
+                length: value.len() as u32,
+                data: Default::default(),
+            }),
+            arena,
+        );
+        let term_size = term.size_of();
+        let instance = arena.allocate(term);
+        let list = instance.offset((term_size - std::mem::size_of::<Array<u32>>()) as u32);
+        Array::<u32>::extend(list, get_byte_chunks(value), arena);
+        let hash = arena.read_value::<Term, _>(instance, |term| {
+            TermHasher::default().hash(term, arena).finish()
+        });
+        arena.write::<u64>(Term::get_hash_pointer(instance), u64::from(hash));
+        instance
+    }
+}
+
+impl<A: Arena + Clone> ArenaRef<BytesTerm, A> {
+    pub fn len(&self) -> usize {
+        self.read_value(|term| term.length as usize)
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn data(&self) -> ArenaRef<Array<u32>, A> {
+        self.inner_ref(|term| &term.data)
+    }
+    pub fn as_slice<'a>(&'a self) -> A::Slice<'a> {
+        self.arena
+            .as_slice(self.inner_pointer(|term| &term.data.items), self.len())
+    }
+    fn to_base64(&self) -> String {
+        base64::engine::general_purpose::STANDARD.encode(self.as_slice().deref())
+    }
+}
+
+impl<A: Arena + Clone> BytesTermType for ArenaRef<TypedTerm<BytesTerm>, A> {
+    type BytesRef<'a> = A::Slice<'a>
+    where
+        Self: 'a;
+    fn value<'a>(&'a self) -> Self::BytesRef<'a> {
+        let inner = self.as_inner();
+        self.arena
+            .as_slice(inner.inner_pointer(|term| &term.data.items), inner.len())
+    }
+}
+
+fn get_byte_chunks(value: &[u8]) -> Vec<u32> {
+    value
+        .chunks(4)
+        .map(|chunk| {
+            (chunk.first().copied().unwrap_or(0) as u32)
+                | (chunk.get(1).copied().unwrap_or(0) as u32) << 8
+                | (chunk.get(2).copied().unwrap_or(0) as u32) << 16
+                | (chunk.get(3).copied().unwrap_or(0) as u32) << 24
+        })
+        .collect::<Vec<_>>()
+}
+
+impl<A: Arena + Clone> GraphNode for ArenaRef<BytesTerm, A> {
+    fn size(&self) -> usize {
+        1
+    }
+    fn capture_depth(&self) -> StackOffset {
+        0
+    }
+    fn free_variables(&self) -> HashSet<StackOffset> {
+        HashSet::new()
+    }
+    fn count_variable_usages(&self, _offset: StackOffset) -> usize {
+        0
+    }
+    fn dynamic_dependencies(&self, _deep: bool) -> DependencyList {
+        DependencyList::empty()
+    }
+    fn has_dynamic_dependencies(&self, _deep: bool) -> bool {
+        false
+    }
+    fn is_static(&self) -> bool {
+        true
+    }
+    fn is_atomic(&self) -> bool {
+        true
+    }
+    fn is_complex(&self) -> bool {
+        false
+    }
+}
+
+impl<A: Arena + Clone> SerializeJson for ArenaRef<BytesTerm, A> {
+    fn to_json(&self) -> Result<JsonValue, String> {
+        Ok(JsonValue::String(self.to_base64()))
+    }
+    fn patch(&self, target: &Self) -> Result<Option<JsonValue>, String> {
+        if self.as_slice().deref() == target.as_slice().deref() {
+            Ok(None)
+        } else {
+            target.to_json().map(Some)
+        }
+    }
+}
+
+impl<A: Arena + Clone> PartialEq for ArenaRef<BytesTerm, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice().deref() == other.as_slice().deref()
+    }
+}
+impl<A: Arena + Clone> Eq for ArenaRef<BytesTerm, A> {}
+
+impl<A: Arena + Clone> std::fmt::Debug for ArenaRef<BytesTerm, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.read_value(|term| std::fmt::Debug::fmt(term, f))
+    }
+}
+
+impl<A: Arena + Clone> std::fmt::Display for ArenaRef<BytesTerm, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_base64())
+    }
+}
+
+impl<A: Arena + Clone> Internable for ArenaRef<BytesTerm, A> {
+    fn should_intern(&self, _eager: ArgType) -> bool {
+        true
+    }
+}
+
+impl<A: Arena + Clone> CompileWasm<A> for ArenaRef<BytesTerm, A> {
+    fn compile(
+        &self,
+        stack: CompilerStack,
+        _state: &mut CompilerState,
+        _options: &CompilerOptions,
+    ) -> CompilerResult<A> {
+        let num_bytes = self.len();
+        let data = self.data();
+        let block = CompiledBlockBuilder::new(stack);
+        // Push the byte length onto the stack
+        // => [length]
+        let block = block.push(instruction::core::Const {
+            value: ConstValue::U32(num_bytes as u32),
+        });
+        // Allocate the Bytes term
+        // => [BytesTerm]
+        let block = block.push(instruction::runtime::CallRuntimeBuiltin {
+            target: RuntimeBuiltin::AllocateBytes,
+        });
+        // Assign the term contents
+        let block = data
+            .iter()
+            .enumerate()
+            .fold(block, |block, (chunk_index, chunk)| {
+                let byte_index = chunk_index * std::mem::size_of::<u32>();
+                // Duplicate the Bytes term pointer onto the stack
+                // => [BytesTerm, BytesTerm]
+                let block = block.push(instruction::core::Duplicate {
+                    value_type: ValueType::HeapPointer,
+                });
+                // Push the chunk index onto the stack
+                // => [BytesTerm, BytesTerm, index]
+                let block = block.push(instruction::core::Const {
+                    value: ConstValue::U32(byte_index as u32),
+                });
+                // Get the memory offset for the chunk at the given index
+                // => [BytesTerm, offset]
+                let block = block.push(instruction::runtime::CallRuntimeBuiltin {
+                    target: RuntimeBuiltin::GetBytesByteOffset,
+                });
+                // Push the chunk onto the stack
+                // => [BytesTerm, offset, chunk]
+                let block = block.push(instruction::core::Const {
+                    value: ConstValue::U32(chunk),
+                });
+                // Write the chunk value to the term contents
+                // => [BytesTerm]
+                block.push(instruction::core::WriteHeapValue {
+                    value_type: ValueType::U32,
+                })
+            });
+        // Initialize the Bytes term
+        // => [BytesTerm]
+        let block = block.push(instruction::runtime::CallRuntimeBuiltin {
+            target: RuntimeBuiltin::InitBytes,
+        });
+        block.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        allocator::VecAllocator,
+        term_type::{TermType, TermTypeDiscriminants},
+        utils::chunks_to_u64,
+    };
+
+    use super::*;
+
+    #[test]
+    fn bytes() {
+        assert_eq!(
+            TermType::Bytes(BytesTerm {
+                length: 0x54321,
+                data: Default::default(),
+            })
+            .as_bytes(),
+            [TermTypeDiscriminants::Bytes as u32, 0x54321, 0, 0],
+        );
+        let mut allocator = VecAllocator::default();
+        {
+            let value = [0x01, 0x02, 0x03, 0x04, 0xff];
+            let instance = BytesTerm::allocate(&value, &mut allocator);
+            let result = allocator.get_ref::<Term>(instance).as_bytes();
+            let _hash = chunks_to_u64([result[0], result[1]]);
+            let discriminant = result[2];
+            let length = result[3];
+            let data_capacity = result[4];
+            let data_length = result[5];
+            let data = &result[6..];
+            assert_eq!(discriminant, TermTypeDiscriminants::Bytes as u32);
+            assert_eq!(length, 5);
+            assert_eq!(data_capacity, 2);
+            assert_eq!(data_length, 2);
+            assert_eq!(data, [0x04030201, 0x000000ff]);
+            let term = ArenaRef::<Term, _>::new(&allocator, instance);
+            assert_eq!(
+                term.as_bytes_term()
+                    .map(|term| Vec::from(term.value().deref())),
+                Some(Vec::from(value)),
+            );
+            assert_eq!(format!("{}", term), "AQIDBP8=");
+        }
+    }
+}
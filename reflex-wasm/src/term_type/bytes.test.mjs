@@ -0,0 +1,44 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Term::Bytes', (test) => {
+    test('value', (assert, { createBytes, getBytesValue }) => {
+      assert.deepEqual(Array.from(getBytesValue(createBytes([]))), []);
+      assert.deepEqual(Array.from(getBytesValue(createBytes([0]))), [0]);
+      assert.deepEqual(
+        Array.from(getBytesValue(createBytes([0, 1, 2, 3, 4, 255]))),
+        [0, 1, 2, 3, 4, 255],
+      );
+    });
+
+    test('display', (assert, { createBytes, display }) => {
+      assert.strictEqual(display(createBytes([])), '');
+      assert.strictEqual(display(createBytes([102])), 'Zg==');
+      assert.strictEqual(display(createBytes([102, 111])), 'Zm8=');
+      assert.strictEqual(display(createBytes([102, 111, 111])), 'Zm9v');
+      assert.strictEqual(display(createBytes([102, 111, 111, 98, 97, 114])), 'Zm9vYmFy');
+      assert.strictEqual(display(createBytes([0, 239, 255, 62, 63])), 'AO//Pj8=');
+    });
+
+    test('format', (assert, { createBytes, format }) => {
+      assert.strictEqual(format(createBytes([])), 'Bytes()');
+      assert.strictEqual(format(createBytes([102, 111, 111])), 'Bytes(Zm9v)');
+    });
+
+    test('hash', (assert, { createBytes, hash }) => {
+      assert.strictEqual(hash(createBytes([])), hash(createBytes([])));
+      assert.strictEqual(hash(createBytes([1, 2, 3])), hash(createBytes([1, 2, 3])));
+      assert.notStrictEqual(hash(createBytes([])), hash(createBytes([0])));
+      assert.notStrictEqual(hash(createBytes([1, 2, 3])), hash(createBytes([1, 2, 4])));
+      assert.notStrictEqual(hash(createBytes([1, 2, 3])), hash(createBytes([1, 2, 3, 0])));
+    });
+
+    test('equals', (assert, { createBytes, equals }) => {
+      assert.strictEqual(equals(createBytes([]), createBytes([])), true);
+      assert.strictEqual(equals(createBytes([1, 2, 3]), createBytes([1, 2, 3])), true);
+      assert.strictEqual(equals(createBytes([1, 2, 3]), createBytes([1, 2, 4])), false);
+      assert.strictEqual(equals(createBytes([1, 2, 3]), createBytes([1, 2, 3, 0])), false);
+    });
+  });
+};
@@ -0,0 +1,128 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@let $Bytes
+    (@struct $Bytes
+      (@field $length i32)
+      (@field $data (@repeated i32)))
+
+    (@derive $size (@get $Bytes))
+    (@derive $equals (@get $Bytes))
+    (@derive $hash (@get $Bytes))
+
+    (@export $Bytes (@get $Bytes)))
+
+  (export "isBytes" (func $Term::Bytes::is))
+  (export "getBytesLength" (func $Term::Bytes::get::length))
+
+  (@const $Term::Bytes::EMPTY i32 (call $Term::TermType::Bytes::new (i32.const 0)))
+
+  (func $Term::Bytes::allocate (export "allocateBytes") (param $length i32) (result i32)
+    (if (result i32)
+      (i32.eqz (local.get $length))
+      (then
+        ;; Return the pre-allocated singleton instance
+        (global.get $Term::Bytes::EMPTY))
+      (else
+        (call $Term::Bytes::allocate_sized (local.get $length)))))
+
+  (func $Term::Bytes::drop (param $self i32)
+    ;; Avoid dropping the global empty instance
+    (if (i32.ne (local.get $self) (global.get $Term::Bytes::EMPTY))
+      (then
+        (call $Term::drop (local.get $self)))))
+
+  (func $Term::Bytes::empty::sizeof (result i32)
+    ;; Determine the size of the term wrapper by inspecting the data pointer for an imaginary bytes term located
+    ;; at memory address 0. The pointer offset tells us how many bytes are taken up by the preceding term wrapper.
+    (call $Term::Bytes::get::data::pointer (i32.const 0) (i32.const 0)))
+
+  (func $Term::Bytes::allocate_sized (param $length i32) (result i32)
+    (local $self i32)
+    ;; Allocates a new Bytes term with the given capacity, allowing data to be copied directly into the allocated array.
+    ;; The term must be instantiated before it can be used.
+    (local.tee $self
+      (call $Allocator::allocate
+        (i32.add
+          (call $Term::Bytes::empty::sizeof)
+          (call $Allocator::pad_to_4_byte_offset (local.get $length)))))
+    ;; Manually write the struct contents into the term wrapper
+    (call $TermType::Bytes::construct (call $Term::pointer::value (local.get $self)) (i32.const 0))
+    ;; Set the byte length
+    (call $Term::Bytes::set::length (local.get $self) (local.get $length))
+    ;; Set the capacity and length of the data array, padded to 4-byte cell size
+    (call $Term::Bytes::set::data::capacity
+      (local.get $self)
+      (i32.div_u (call $Allocator::pad_to_4_byte_offset (local.get $length)) (i32.const 4)))
+    (call $Term::Bytes::set::data::length
+      (local.get $self)
+      (i32.div_u (call $Allocator::pad_to_4_byte_offset (local.get $length)) (i32.const 4))))
+
+  (func $Term::Bytes::init (export "initBytes") (param $self i32) (result i32)
+    ;; Instantiate the term
+    (call $Term::init (local.get $self)))
+
+  (func $Term::Bytes::from_slice (param $offset i32) (param $length i32) (result i32)
+    (local $self i32)
+    ;; Allocates a new Bytes term whose contents is copied from the given slice of linear memory
+    (if (result i32)
+      (i32.eqz (local.get $length))
+      (then
+        ;; Return the pre-allocated singleton instance
+        (global.get $Term::Bytes::EMPTY))
+      (else
+        ;; Allocate a new Bytes term with the correct capacity
+        (local.tee $self (call $Term::Bytes::allocate (local.get $length)))
+        ;; Copy the slice into the data array
+        (memory.copy
+          (call $Term::Bytes::get::data::pointer (local.get $self) (i32.const 0))
+          (local.get $offset)
+          (local.get $length))
+        ;; Instantiate the term
+        (call $Term::Bytes::init))))
+
+  (func $Term::Bytes::get_offset (export "getBytesOffset") (param $self i32) (result i32)
+    (call $Term::Bytes::get::data::pointer (local.get $self) (i32.const 0)))
+
+  (func $Term::Bytes::get_length (param $self i32) (result i32)
+    (call $Term::Bytes::get::length (local.get $self)))
+
+  (func $Term::Bytes::get_byte_offset (export "getBytesByteOffset") (param $self i32) (param $index i32) (result i32)
+    (i32.add
+      (call $Term::Bytes::get_offset (local.get $self))
+      (local.get $index)))
+
+  (func $Term::Bytes::traits::is_atomic (param $self i32) (result i32)
+    (global.get $TRUE))
+
+  (func $Term::Bytes::traits::display (param $self i32) (param $offset i32) (result i32)
+    ;; Write the base64-encoded contents to the output and return the updated offset
+    (i32.add
+      (local.get $offset)
+      (call $Utils::bytes::write_base64
+        (call $Term::Bytes::get_offset (local.get $self))
+        (call $Term::Bytes::get_length (local.get $self))
+        (local.get $offset))))
+
+  (func $Term::Bytes::traits::debug (param $self i32) (param $offset i32) (result i32)
+    (@store-bytes $offset "Bytes(")
+    (local.set $offset (i32.add (local.get $offset)))
+    (local.set $offset (call $Term::Bytes::traits::display (local.get $self) (local.get $offset)))
+    (@store-bytes $offset ")")
+    (i32.add (local.get $offset)))
+
+  (func $Term::Bytes::traits::substitute (param $self i32) (param $variables i32) (param $scope_offset i32) (result i32)
+    (global.get $NULL))
+
+  (func $Term::Bytes::traits::to_json (param $self i32) (param $offset i32) (result i32 i32)
+    ;; Put the success marker on the stack
+    (global.get $TRUE)
+    ;; Write the opening quote to the output
+    (@store-bytes $offset "\"")
+    (local.set $offset (i32.add (local.get $offset)))
+    ;; Write the base64-encoded contents to the output
+    (local.set $offset (call $Term::Bytes::traits::display (local.get $self) (local.get $offset)))
+    ;; Write the closing quote to the output and return the updated offset
+    (@store-bytes $offset "\"")
+    (i32.add (local.get $offset))))
@@ -75,6 +75,10 @@
   (@const $Term::Condition::PENDING i32 (call $Term::TermType::Condition::PendingCondition::new))
   (@const $Term::Condition::INVALID_POINTER i32 (call $Term::TermType::Condition::InvalidPointerCondition::new))
 
+  ;; Error messages emitted by arithmetic builtins (these must match the corresponding messages in the reflex crate)
+  (@const-string $Term::Condition::ERROR_MESSAGE_INTEGER_OVERFLOW "Integer overflow")
+  (@const-string $Term::Condition::ERROR_MESSAGE_INVALID_FLOAT_RESULT "Invalid float result")
+
   ;; TODO: Codegen union variant constructors/accessors via macro
   (func $Term::Condition::custom (export "createCustomCondition") (param $type i32) (param $payload i32) (param $token i32) (result i32)
     (call $Term::TermType::Condition::CustomCondition::new (local.get $type) (local.get $payload) (local.get $token)))
@@ -92,7 +96,8 @@
     (call $Term::TermType::Condition::InvalidFunctionTargetCondition::new (local.get $target)))
 
   (func $Term::Condition::invalid_function_args (export "createInvalidFunctionArgsCondition") (param $target i32) (param $args i32) (result i32)
-    (call $Term::TermType::Condition::InvalidFunctionArgsCondition::new (local.get $target) (local.get $args)))
+    ;; Any list views or list ropes are flattened, to ensure that the argument list is stored as a contiguous list
+    (call $Term::TermType::Condition::InvalidFunctionArgsCondition::new (local.get $target) (call $Term::List::flatten (local.get $args))))
 
   (func $Term::Condition::invalid_builtin_function_target (export "createInvalidBuiltinFunctionTarget") (param $target i32) (result i32)
     (call $Term::Condition::invalid_function_target (call $Term::Builtin::new (local.get $target))))
@@ -100,6 +105,12 @@
   (func $Term::Condition::invalid_builtin_function_args (export "createInvalidBuiltinFunctionArgs") (param $target i32) (param $args i32) (result i32)
     (call $Term::Condition::invalid_function_args (call $Term::Builtin::new (local.get $target)) (local.get $args)))
 
+  (func $Term::Condition::integer_overflow (result i32)
+    (call $Term::Condition::error (global.get $Term::Condition::ERROR_MESSAGE_INTEGER_OVERFLOW)))
+
+  (func $Term::Condition::invalid_float_result (result i32)
+    (call $Term::Condition::error (global.get $Term::Condition::ERROR_MESSAGE_INVALID_FLOAT_RESULT)))
+
   (func $Term::Condition::invalid_pointer (export "createInvalidPointerCondition") (result i32)
     (global.get $Term::Condition::INVALID_POINTER))
 
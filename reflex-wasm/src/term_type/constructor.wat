@@ -26,8 +26,9 @@
         ;; Return the pre-allocated singleton instance
         (global.get $Term::Constructor::EMPTY))
       (else
+        ;; Any list views or list ropes are flattened, to ensure that the keys are stored as a contiguous list
         (call $Term::TermType::Constructor::new
-          (local.get $keys)))))
+          (call $Term::List::flatten (local.get $keys))))))
 
   (func $Term::Constructor::empty (result i32)
     (global.get $Term::Constructor::EMPTY))
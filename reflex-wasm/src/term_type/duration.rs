@@ -0,0 +1,184 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::collections::HashSet;
+
+use reflex::core::{
+    ArgType, DependencyList, DurationTermType, DurationValue, GraphNode, SerializeJson,
+    StackOffset,
+};
+use reflex_macros::PointerIter;
+use serde_json::Value as JsonValue;
+
+use crate::{
+    allocator::Arena,
+    compiler::{
+        instruction, runtime::builtin::RuntimeBuiltin, CompileWasm, CompiledBlockBuilder,
+        CompilerOptions, CompilerResult, CompilerStack, CompilerState, ConstValue, Internable,
+    },
+    hash::{TermHash, TermHasher, TermSize},
+    term_type::TypedTerm,
+    utils::{chunks_to_i64, i64_to_chunks},
+    ArenaRef,
+};
+
+#[derive(Clone, Copy, Debug, PointerIter)]
+#[repr(C)]
+pub struct DurationTerm {
+    pub millis: [u32; 2],
+}
+impl TermSize for DurationTerm {
+    fn size_of(&self) -> usize {
+        std::mem::size_of::<Self>()
+    }
+}
+impl TermHash for DurationTerm {
+    fn hash(&self, hasher: TermHasher, arena: &impl Arena) -> TermHasher {
+        hasher.hash(&self.millis, arena)
+    }
+}
+impl From<i64> for DurationTerm {
+    fn from(value: i64) -> Self {
+        Self {
+            millis: i64_to_chunks(value),
+        }
+    }
+}
+impl From<DurationTerm> for i64 {
+    fn from(value: DurationTerm) -> Self {
+        let DurationTerm { millis, .. } = value;
+        chunks_to_i64(millis)
+    }
+}
+
+impl<A: Arena + Clone> ArenaRef<DurationTerm, A> {
+    pub fn millis(&self) -> i64 {
+        self.read_value(|term| i64::from(*term))
+    }
+}
+
+impl<A: Arena + Clone> DurationTermType for ArenaRef<DurationTerm, A> {
+    fn millis(&self) -> DurationValue {
+        self.millis() as DurationValue
+    }
+}
+
+impl<A: Arena + Clone> DurationTermType for ArenaRef<TypedTerm<DurationTerm>, A> {
+    fn millis(&self) -> DurationValue {
+        <ArenaRef<DurationTerm, A> as DurationTermType>::millis(&self.as_inner())
+    }
+}
+
+impl<A: Arena + Clone> GraphNode for ArenaRef<DurationTerm, A> {
+    fn size(&self) -> usize {
+        1
+    }
+    fn capture_depth(&self) -> StackOffset {
+        0
+    }
+    fn free_variables(&self) -> HashSet<StackOffset> {
+        HashSet::new()
+    }
+    fn count_variable_usages(&self, _offset: StackOffset) -> usize {
+        0
+    }
+    fn dynamic_dependencies(&self, _deep: bool) -> DependencyList {
+        DependencyList::empty()
+    }
+    fn has_dynamic_dependencies(&self, _deep: bool) -> bool {
+        false
+    }
+    fn is_static(&self) -> bool {
+        true
+    }
+    fn is_atomic(&self) -> bool {
+        true
+    }
+    fn is_complex(&self) -> bool {
+        false
+    }
+}
+
+impl<A: Arena + Clone> SerializeJson for ArenaRef<DurationTerm, A> {
+    fn to_json(&self) -> Result<JsonValue, String> {
+        Ok(JsonValue::Number(self.millis().into()))
+    }
+    fn patch(&self, target: &Self) -> Result<Option<JsonValue>, String> {
+        if self.millis() == target.millis() {
+            Ok(None)
+        } else {
+            target.to_json().map(Some)
+        }
+    }
+}
+
+impl<A: Arena + Clone> PartialEq for ArenaRef<DurationTerm, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.millis() == other.millis()
+    }
+}
+impl<A: Arena + Clone> Eq for ArenaRef<DurationTerm, A> {}
+
+impl<A: Arena + Clone> std::fmt::Debug for ArenaRef<DurationTerm, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.read_value(|term| std::fmt::Debug::fmt(term, f))
+    }
+}
+
+impl<A: Arena + Clone> std::fmt::Display for ArenaRef<DurationTerm, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}ms", self.millis())
+    }
+}
+
+impl<A: Arena + Clone> Internable for ArenaRef<DurationTerm, A> {
+    fn should_intern(&self, _eager: ArgType) -> bool {
+        true
+    }
+}
+
+impl<A: Arena + Clone> CompileWasm<A> for ArenaRef<DurationTerm, A> {
+    fn compile(
+        &self,
+        stack: CompilerStack,
+        _state: &mut CompilerState,
+        _options: &CompilerOptions,
+    ) -> CompilerResult<A> {
+        let millis = self.millis();
+        let block = CompiledBlockBuilder::new(stack);
+        // Push the value argument onto the stack
+        // => [value]
+        let block = block.push(instruction::core::Const {
+            value: ConstValue::I64(millis),
+        });
+        // Invoke the term constructor
+        // => [DurationTerm]
+        let block = block.push(instruction::runtime::CallRuntimeBuiltin {
+            target: RuntimeBuiltin::CreateDuration,
+        });
+        block.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        term_type::{TermType, TermTypeDiscriminants},
+        utils::i64_to_chunks,
+    };
+
+    use super::*;
+
+    #[test]
+    fn duration() {
+        let value = -(24 * 60 * 60 * 1000);
+        assert_eq!(
+            TermType::Duration(DurationTerm::from(value)).as_bytes(),
+            [
+                TermTypeDiscriminants::Duration as u32,
+                i64_to_chunks(value)[0],
+                i64_to_chunks(value)[1]
+            ],
+        );
+    }
+}
@@ -0,0 +1,26 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Term::Duration', (test) => {
+    test('display', (assert, { createDuration, display }) => {
+      assert.strictEqual(display(createDuration(0)), '0ms');
+      assert.strictEqual(display(createDuration(1500)), '1500ms');
+      assert.strictEqual(display(createDuration(-86400000)), '-86400000ms');
+    });
+
+    test('format', (assert, { createDuration, format }) => {
+      assert.strictEqual(format(createDuration(1500)), 'Duration(1500ms)');
+    });
+
+    test('hash', (assert, { createDuration, hash }) => {
+      assert.strictEqual(hash(createDuration(1500)), hash(createDuration(1500)));
+      assert.notStrictEqual(hash(createDuration(1500)), hash(createDuration(1499)));
+    });
+
+    test('equals', (assert, { createDuration, equals }) => {
+      assert.strictEqual(equals(createDuration(1500), createDuration(1500)), true);
+      assert.strictEqual(equals(createDuration(1500), createDuration(1499)), false);
+    });
+  });
+};
@@ -0,0 +1,52 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@let $Duration
+    (@struct $Duration
+      (@field $millis i64))
+
+    (@derive $size (@get $Duration))
+    (@derive $equals (@get $Duration))
+    (@derive $hash (@get $Duration))
+
+    (@export $Duration (@get $Duration)))
+
+  (export "isDuration" (func $Term::Duration::is))
+  (export "getDurationMillis" (func $Term::Duration::get::millis))
+
+  (func $Term::Duration::new (export "createDuration") (param $millis i64) (result i32)
+    (call $Term::TermType::Duration::new (local.get $millis)))
+
+  (func $Term::Duration::traits::is_atomic (param $self i32) (result i32)
+    (global.get $TRUE))
+
+  (func $Term::Duration::traits::display (param $self i32) (param $offset i32) (result i32)
+    ;; Write the number of milliseconds to the output
+    (call $Utils::i64::write_string
+      (call $Term::Duration::get::millis (local.get $self))
+      (local.get $offset))
+    (local.set $offset (i32.add (local.get $offset)))
+    ;; Write the unit suffix to the output and return the updated offset
+    (@store-bytes $offset "ms")
+    (i32.add (local.get $offset)))
+
+  (func $Term::Duration::traits::debug (param $self i32) (param $offset i32) (result i32)
+    (@store-bytes $offset "Duration(")
+    (local.set $offset (i32.add (local.get $offset)))
+    (local.set $offset (call $Term::Duration::traits::display (local.get $self) (local.get $offset)))
+    (@store-bytes $offset ")")
+    (i32.add (local.get $offset)))
+
+  (func $Term::Duration::traits::substitute (param $self i32) (param $variables i32) (param $scope_offset i32) (result i32)
+    (global.get $NULL))
+
+  (func $Term::Duration::traits::to_json (param $self i32) (param $offset i32) (result i32 i32)
+    ;; Put the success marker on the stack
+    (global.get $TRUE)
+    ;; Write the number of milliseconds to the output string and return the updated offset
+    (i32.add
+      (local.get $offset)
+      (call $Utils::i64::write_string
+        (call $Term::Duration::get::millis (local.get $self))
+        (local.get $offset)))))
@@ -5,7 +5,8 @@
 use std::collections::HashSet;
 
 use reflex::core::{
-    ArgType, DependencyList, FloatTermType, FloatValue, GraphNode, SerializeJson, StackOffset,
+    format_float, ArgType, DependencyList, FloatFormat, FloatTermType, FloatValue, GraphNode,
+    SerializeJson, StackOffset,
 };
 use reflex_macros::PointerIter;
 use serde_json::Value as JsonValue;
@@ -155,7 +156,12 @@ impl<A: Arena + Clone> std::fmt::Debug for ArenaRef<FloatTerm, A> {
 
 impl<A: Arena + Clone> std::fmt::Display for ArenaRef<FloatTerm, A> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.value())
+        let value = self.value();
+        if value.is_finite() {
+            write!(f, "{}", format_float(value, FloatFormat::default()))
+        } else {
+            write!(f, "{}", value)
+        }
     }
 }
 
@@ -10,6 +10,13 @@ export default (describe) => {
       assert.strictEqual(display(createFloat(-3.0)), '-3.0');
       assert.strictEqual(display(createFloat(3.142)), '3.142');
       assert.strictEqual(display(createFloat(-3.142)), '-3.142');
+      assert.strictEqual(display(createFloat(0.1 + 0.2)), '0.30000000000000004');
+      assert.strictEqual(display(createFloat(0.00001)), '0.00001');
+      assert.strictEqual(display(createFloat(0.000001)), '1e-6');
+      assert.strictEqual(display(createFloat(-1.5e-7)), '-1.5e-7');
+      assert.strictEqual(display(createFloat(1e15)), '1000000000000000.0');
+      assert.strictEqual(display(createFloat(1e16)), '1e16');
+      assert.strictEqual(display(createFloat(1.25e21)), '1.25e21');
     });
 
     test('format', (assert, { createFloat, format }) => {
@@ -19,6 +26,13 @@ export default (describe) => {
       assert.strictEqual(format(createFloat(-3.0)), '-3.0');
       assert.strictEqual(format(createFloat(3.142)), '3.142');
       assert.strictEqual(format(createFloat(-3.142)), '-3.142');
+      assert.strictEqual(format(createFloat(0.1 + 0.2)), '0.30000000000000004');
+      assert.strictEqual(format(createFloat(0.00001)), '0.00001');
+      assert.strictEqual(format(createFloat(0.000001)), '1e-6');
+      assert.strictEqual(format(createFloat(-1.5e-7)), '-1.5e-7');
+      assert.strictEqual(format(createFloat(1e15)), '1000000000000000.0');
+      assert.strictEqual(format(createFloat(1e16)), '1e16');
+      assert.strictEqual(format(createFloat(1.25e21)), '1.25e21');
     });
 
     test('hash', (assert, { createFloat, hash }) => {
@@ -73,11 +73,17 @@ impl TermSize for HashmapTerm {
 impl TermHash for HashmapTerm {
     fn hash(&self, hasher: TermHasher, arena: &impl Arena) -> TermHasher {
         let hasher = hasher.hash(&self.num_entries, arena);
-        self.buckets
+        // Combine the entry hashes via a commutative operation to ensure that hashmaps with the same entries have the
+        // same hash regardless of bucket layout (this must match the runtime hashmap hashing implementation)
+        let entries_hash = self
+            .buckets
             .items()
             .filter(|bucket| !bucket.key.is_uninitialized())
             .take(self.num_entries as usize)
-            .fold(hasher, |hasher, item| item.hash(hasher, arena))
+            .fold(0u64, |result, item| {
+                result.wrapping_add(u64::from(item.hash(TermHasher::default(), arena).finish()))
+            });
+        hasher.hash(&entries_hash, arena)
     }
 }
 impl HashmapTerm {
@@ -362,9 +368,11 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<HashmapTerm, A> {
     }
     fn free_variables(&self) -> HashSet<StackOffset> {
         self.keys()
-            .flat_map(|item| item.free_variables())
-            .chain(self.values().flat_map(|item| item.free_variables()))
-            .collect()
+            .chain(self.values())
+            .fold(HashSet::new(), |mut results, item| {
+                results.extend(item.shared_free_variables().iter().copied());
+                results
+            })
     }
     fn count_variable_usages(&self, offset: StackOffset) -> usize {
         self.keys()
@@ -703,4 +711,24 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn hashmap_hash() {
+        let mut allocator = VecAllocator::default();
+        let entries = (3..6)
+            .map(|index| {
+                (
+                    allocator.allocate(Term::new(TermType::Int(IntTerm::from(index)), &allocator)),
+                    allocator.allocate(Term::new(TermType::Int(IntTerm::from(-index)), &allocator)),
+                )
+            })
+            .collect::<Vec<_>>();
+        let instance = HashmapTerm::allocate(entries.clone(), &mut allocator);
+        let reversed = HashmapTerm::allocate(entries.iter().copied().rev(), &mut allocator);
+        let subset = HashmapTerm::allocate(entries.iter().copied().skip(1), &mut allocator);
+        let hash =
+            |pointer: ArenaPointer| allocator.read_value::<Term, _>(pointer, |term| term.id());
+        assert_eq!(hash(instance), hash(reversed));
+        assert_ne!(hash(instance), hash(subset));
+    }
 }
@@ -7,8 +7,70 @@ export default (describe) => {
       throw new Error('Not yet implemented');
     });
 
-    test.skip('hash', (assert) => {
-      throw new Error('Not yet implemented');
+    test('hash', (assert, { createHashmap, createInt, createString, hash }) => {
+      assert.strictEqual(hash(createHashmap([])), hash(createHashmap([])));
+      assert.strictEqual(
+        hash(
+          createHashmap([
+            [createString('foo'), createInt(3)],
+            [createString('bar'), createInt(4)],
+            [createString('baz'), createInt(5)],
+          ]),
+        ),
+        hash(
+          createHashmap([
+            [createString('foo'), createInt(3)],
+            [createString('bar'), createInt(4)],
+            [createString('baz'), createInt(5)],
+          ]),
+        ),
+      );
+      assert.strictEqual(
+        hash(
+          createHashmap([
+            [createString('foo'), createInt(3)],
+            [createString('bar'), createInt(4)],
+            [createString('baz'), createInt(5)],
+          ]),
+        ),
+        hash(
+          createHashmap([
+            [createString('baz'), createInt(5)],
+            [createString('foo'), createInt(3)],
+            [createString('bar'), createInt(4)],
+          ]),
+        ),
+      );
+      assert.notStrictEqual(
+        hash(
+          createHashmap([
+            [createString('foo'), createInt(3)],
+            [createString('bar'), createInt(4)],
+            [createString('baz'), createInt(5)],
+          ]),
+        ),
+        hash(
+          createHashmap([
+            [createString('foo'), createInt(3)],
+            [createString('bar'), createInt(4)],
+            [createString('baz'), createInt(6)],
+          ]),
+        ),
+      );
+      assert.notStrictEqual(
+        hash(
+          createHashmap([
+            [createString('foo'), createInt(3)],
+            [createString('bar'), createInt(4)],
+          ]),
+        ),
+        hash(
+          createHashmap([
+            [createString('foo'), createInt(4)],
+            [createString('bar'), createInt(3)],
+          ]),
+        ),
+      );
     });
 
     test.skip('equals', (assert) => {
@@ -173,6 +235,44 @@ export default (describe) => {
       })();
     });
 
+    test('incremental insertion', (assert, {
+      createApplication,
+      createBuiltin,
+      createHashmap,
+      createInt,
+      createTriple,
+      evaluate,
+      format,
+      getHashmapCapacity,
+      getHashmapNumEntries,
+      getHashmapValue,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const numEntries = 32;
+        const capacities = new Set();
+        let hashmap = createHashmap([]);
+        for (let index = 0; index < numEntries; index++) {
+          const expression = createApplication(
+            createBuiltin(Stdlib.Set),
+            createTriple(hashmap, createInt(index), createInt(index * 2)),
+          );
+          const [result, dependencies] = evaluate(expression, NULL);
+          assert.deepEqual(getStateDependencies(dependencies), []);
+          hashmap = result;
+          capacities.add(getHashmapCapacity(hashmap));
+        }
+        assert.strictEqual(getHashmapNumEntries(hashmap), numEntries);
+        assert.ok(capacities.size <= Math.ceil(Math.log2(numEntries)) + 2);
+        assert.ok(getHashmapCapacity(hashmap) >= Math.floor((numEntries * 4) / 3));
+        for (let index = 0; index < numEntries; index++) {
+          assert.strictEqual(format(getHashmapValue(hashmap, createInt(index))), `${index * 2}`);
+        }
+      })();
+    });
+
     test('iteration', (assert, {
       createApplication,
       createBuiltin,
@@ -48,26 +48,27 @@
       (call $Hashmap::get::num_entries (local.get $other))))
 
   (func $Hashmap::traits::hash (param $self i32) (param $state i64) (result i64)
-    ;; FIXME: Two instances of an equivalent hashmap might return different hashes if keys were added in a different order
-    ;; Consider e.g. insertion sort when initializing the buckets to ensure consistent hash
+    ;; Combine the entry hashes via a commutative operation to ensure that hashmaps with the same entries have the same
+    ;; hash regardless of the order in which the entries were inserted (or the capacity of the underlying bucket array)
     (local $num_entries i32)
     (local $capacity i32)
     (local $index i32)
     (local $key i32)
-    (local.get $state)
+    (local $entries_hash i64)
     ;; Hash the number of hashmap entries
-    (local.tee $num_entries (call $Hashmap::get::num_entries (local.get $self)))
-    (local.set $state (call $Hash::write_i32))
-    ;; Hash the hashmap items
-    (if (result i64)
+    (local.set $state
+      (call $Hash::write_i32
+        (local.get $state)
+        (local.tee $num_entries (call $Hashmap::get::num_entries (local.get $self)))))
+    ;; Combine the hashes of the hashmap entries
+    (if
       (i32.eqz (local.get $num_entries))
       ;; If the hashmap is empty, nothing more to do
-      (then
-        (local.get $state))
+      (then)
       (else
         ;; Hash each of the hashmap buckets
         (local.set $capacity (call $Hashmap::get::buckets::capacity (local.get $self)))
-        (loop $LOOP (result i64)
+        (loop $LOOP
           (if
             (i32.eqz
               (local.tee $key
@@ -76,20 +77,22 @@
             ;; If this is an empty bucket then skip the hashing
             (then)
             (else
-              ;; Otherwise hash the key and value
-              (call $Hash::write_i64 (local.get $state) (call $Term::get_hash (local.get $key)))
-              (call $HashmapBucket::get::value
-                (call $Hashmap::get::buckets::pointer (local.get $self) (local.get $index)))
-              (call $Term::get_hash)
-              (call $Hash::write_i64)
-              (local.set $state)))
-          ;; If this was the final bucket return the hash, otherwise continue with the next bucket
-          (if (result i64)
-            (i32.eq (local.tee $index (i32.add (local.get $index) (i32.const 1))) (local.get $capacity))
-            (then
-              (local.get $state))
-            (else
-              (br $LOOP)))))))
+              ;; Otherwise hash the key and value and add the entry hash to the combined hash
+              (local.set $entries_hash
+                (i64.add
+                  (local.get $entries_hash)
+                  (call $Hash::write_i64
+                    (call $Hash::write_i64 (call $Hash::new) (call $Term::get_hash (local.get $key)))
+                    (call $Term::get_hash
+                      (call $HashmapBucket::get::value
+                        (call $Hashmap::get::buckets::pointer (local.get $self) (local.get $index)))))))))
+          ;; If this was not the final bucket, continue with the next bucket
+          (br_if $LOOP
+            (i32.lt_u
+              (local.tee $index (i32.add (local.get $index) (i32.const 1)))
+              (local.get $capacity))))))
+    ;; Hash the combined entry hashes
+    (call $Hash::write_i64 (local.get $state) (local.get $entries_hash)))
 
   (func $Term::Hashmap::empty (export "createEmptyHashmap") (result i32)
     (global.get $Term::Hashmap::EMPTY))
@@ -378,52 +381,35 @@
 
   (func $Term::Hashmap::traits::set (export "setHashmapValue") (param $self i32) (param $key i32) (param $value i32) (result i32)
     (local $existing_bucket_index i32)
-    (local $existing_capacity i32)
-    (local $existing_key i32)
     (local $num_entries i32)
     (local $instance i32)
-    (local $bucket_index i32)
     (if (result i32)
       ;; If the key does not already exist, return a new hashmap with an additional entry
       (i32.eq (global.get $NULL) (local.tee $existing_bucket_index (call $Term::Hashmap::find_bucket_index (local.get $self) (local.get $key))))
       (then
-        ;; Allocate a new hashmap instance
-        (local.tee $instance
-          (call $Term::Hashmap::allocate
+        (if (result i32)
+          ;; If the existing hashmap has enough spare capacity to hold the additional entry, copy the existing buckets
+          ;; as-is and insert the new entry into the copy (this avoids re-hashing all the existing entries)
+          (i32.ge_u
+            (call $Term::Hashmap::get::buckets::capacity (local.get $self))
             (call $Term::Hashmap::default_capacity
               (i32.add
                 (local.tee $num_entries (call $Term::Hashmap::get::num_entries (local.get $self)))
-                (i32.const 1)))))
-        ;; Copy all the existing entries across to the new hashmap
-        (if
-          ;; If the existing hashmap was empty, nothing to do
-          (i32.eqz (local.tee $existing_capacity (call $Term::Hashmap::get::buckets::capacity (local.get $self))))
-          (then)
+                (i32.const 1))))
+          (then
+            ;; Create a clone of the current hashmap
+            (local.tee $instance (call $Term::traits::clone (local.get $self)))
+            ;; Insert the provided key and value into the cloned hashmap
+            ;; (this function returns the number of new entries added to the hashmap)
+            (call $Term::Hashmap::insert_entry (local.get $instance) (local.get $key) (local.get $value))
+            ;; Keep track of how many unique entries have been added to the hashmap
+            (local.set $num_entries (i32.add (local.get $num_entries)))
+            ;; Update the hashmap size
+            (call $Term::Hashmap::set::num_entries (local.get $instance) (local.get $num_entries))
+            ;; Instantiate the cloned hashmap
+            (call $Term::init))
           (else
-            ;; Otherwise iterate through all the buckets of the existing hashmap
-            (loop $LOOP
-              ;; If the current bucket is not empty, insert the existing key and value into the new hashmap
-              (if
-                (local.tee $existing_key (call $Term::Hashmap::get_bucket_key (local.get $self) (local.get $bucket_index)))
-                (then
-                  (call $Term::Hashmap::insert_entry
-                    (local.get $instance)
-                    (local.get $existing_key)
-                    (call $Term::Hashmap::get_bucket_value (local.get $self) (local.get $bucket_index)))
-                  ;; Discard the resulting number of items added to the hashmap
-                  (drop))
-                (else))
-              ;; If this was not the final bucket, continue with the next bucket
-              (br_if $LOOP (i32.lt_u (local.tee $bucket_index (i32.add (local.get $bucket_index) (i32.const 1))) (local.get $existing_capacity))))))
-        ;; Insert the provided key and value into the new hashmap
-        ;; (this function returns the number of new entries added to the hashmap)
-        (call $Term::Hashmap::insert_entry (local.get $instance) (local.get $key) (local.get $value))
-        ;; Keep track of how many unique entries have been added to the hashmap
-        (local.set $num_entries (i32.add (local.get $num_entries)))
-        ;; Set the hashmap size
-        (call $Term::Hashmap::set::num_entries (local.get $instance) (local.get $num_entries))
-        ;; Instantiate the hashmap term
-        (call $Term::init))
+            (call $Term::Hashmap::set_with_capacity (local.get $self) (local.get $key) (local.get $value)))))
       (else
         ;; Otherwise if the key already exists, return an updated hashmap with the corresponding value overridden
         (if (result i32)
@@ -441,6 +427,137 @@
             ;; Instantiate the cloned hashmap
             (call $Term::init))))))
 
+  (func $Term::Hashmap::set_with_capacity (param $self i32) (param $key i32) (param $value i32) (result i32)
+    ;; Return a new hashmap with enough capacity to hold all the existing entries along with the provided additional entry
+    ;; (the provided key MUST NOT already exist within the existing hashmap)
+    (local $existing_capacity i32)
+    (local $existing_key i32)
+    (local $num_entries i32)
+    (local $capacity i32)
+    (local $instance i32)
+    (local $bucket_index i32)
+    ;; Determine the minimum capacity required to hold the additional entry within the load factor threshold
+    (local.set $capacity
+      (call $Term::Hashmap::default_capacity
+        (i32.add
+          (local.tee $num_entries (call $Term::Hashmap::get::num_entries (local.get $self)))
+          (i32.const 1))))
+    ;; Allocate a new hashmap instance, at least doubling the existing capacity
+    ;; (this ensures that hashmaps built up incrementally via repeated insertions are only rehashed a logarithmic number
+    ;; of times, rather than on every insertion)
+    (local.tee $instance
+      (call $Term::Hashmap::allocate
+        (select
+          (local.get $capacity)
+          (local.tee $existing_capacity
+            (i32.mul
+              (call $Term::Hashmap::get::buckets::capacity (local.get $self))
+              (i32.const 2)))
+          (i32.gt_u (local.get $capacity) (local.get $existing_capacity)))))
+    ;; Copy all the existing entries across to the new hashmap
+    (if
+      ;; If the existing hashmap was empty, nothing to do
+      (i32.eqz (local.tee $existing_capacity (call $Term::Hashmap::get::buckets::capacity (local.get $self))))
+      (then)
+      (else
+        ;; Otherwise iterate through all the buckets of the existing hashmap
+        (loop $LOOP
+          ;; If the current bucket is not empty, insert the existing key and value into the new hashmap
+          (if
+            (local.tee $existing_key (call $Term::Hashmap::get_bucket_key (local.get $self) (local.get $bucket_index)))
+            (then
+              (call $Term::Hashmap::insert_entry
+                (local.get $instance)
+                (local.get $existing_key)
+                (call $Term::Hashmap::get_bucket_value (local.get $self) (local.get $bucket_index)))
+              ;; Discard the resulting number of items added to the hashmap
+              (drop))
+            (else))
+          ;; If this was not the final bucket, continue with the next bucket
+          (br_if $LOOP (i32.lt_u (local.tee $bucket_index (i32.add (local.get $bucket_index) (i32.const 1))) (local.get $existing_capacity))))))
+    ;; Insert the provided key and value into the new hashmap
+    ;; (this function returns the number of new entries added to the hashmap)
+    (call $Term::Hashmap::insert_entry (local.get $instance) (local.get $key) (local.get $value))
+    ;; Keep track of how many unique entries have been added to the hashmap
+    (local.set $num_entries (i32.add (local.get $num_entries)))
+    ;; Set the hashmap size
+    (call $Term::Hashmap::set::num_entries (local.get $instance) (local.get $num_entries))
+    ;; Instantiate the hashmap term
+    (call $Term::init))
+
+  (func $Term::Hashmap::traits::delete (export "deleteHashmapValue") (param $self i32) (param $key i32) (result i32)
+    (local $bucket_index i32)
+    (local $num_entries i32)
+    (local $instance i32)
+    (if (result i32)
+      ;; If the key does not exist, return the current instance
+      (i32.eq (global.get $NULL) (local.tee $bucket_index (call $Term::Hashmap::find_bucket_index (local.get $self) (local.get $key))))
+      (then
+        (local.get $self))
+      (else
+        (if (result i32)
+          ;; If this was the only entry in the hashmap, return the empty hashmap
+          (i32.eqz
+            (local.tee $num_entries
+              (i32.sub (call $Term::Hashmap::get::num_entries (local.get $self)) (i32.const 1))))
+          (then
+            (call $Term::Hashmap::empty))
+          (else
+            ;; Otherwise create a clone of the current hashmap
+            (local.tee $instance (call $Term::traits::clone (local.get $self)))
+            ;; Remove the entry from the cloned hashmap
+            (call $Term::Hashmap::remove_bucket (local.get $instance) (local.get $bucket_index))
+            ;; Update the hashmap size
+            (call $Term::Hashmap::set::num_entries (local.get $instance) (local.get $num_entries))
+            ;; Instantiate the cloned hashmap
+            (call $Term::init))))))
+
+  (func $Term::Hashmap::remove_bucket (param $self i32) (param $bucket_index i32)
+    ;; Clear the given bucket, then move any subsequent entries from the same probe sequence back into the vacated bucket
+    ;; (entries are located by probing consecutive buckets until an empty bucket is reached, so removing an entry must
+    ;; not leave a gap between any subsequent entry and its ideal bucket)
+    (local $capacity i32)
+    (local $next_index i32)
+    (local $key i32)
+    (local.set $capacity (call $Term::Hashmap::get::buckets::capacity (local.get $self)))
+    (call $Term::Hashmap::update_bucket (local.get $self) (local.get $bucket_index) (i32.const 0) (i32.const 0))
+    (local.set $next_index (local.get $bucket_index))
+    (loop $LOOP
+      (if
+        ;; If the next bucket is empty, there are no more entries in the probe sequence
+        (i32.eqz
+          (local.tee $key
+            (call $Term::Hashmap::get_bucket_key
+              (local.get $self)
+              (local.tee $next_index
+                (i32.rem_u (i32.add (local.get $next_index) (i32.const 1)) (local.get $capacity))))))
+        (then)
+        (else
+          (if
+            ;; If the vacated bucket lies between the entry's ideal bucket and its current bucket (taking into account
+            ;; wrapping around to the beginning), move the entry into the vacated bucket
+            (i32.ge_u
+              (i32.rem_u
+                (i32.add
+                  (i32.sub
+                    (local.get $next_index)
+                    (call $Term::Hashmap::get_hash_bucket (local.get $capacity) (local.get $key)))
+                  (local.get $capacity))
+                (local.get $capacity))
+              (i32.rem_u
+                (i32.add (i32.sub (local.get $next_index) (local.get $bucket_index)) (local.get $capacity))
+                (local.get $capacity)))
+            (then
+              (call $Term::Hashmap::update_bucket
+                (local.get $self)
+                (local.get $bucket_index)
+                (local.get $key)
+                (call $Term::Hashmap::get_bucket_value (local.get $self) (local.get $next_index)))
+              (call $Term::Hashmap::update_bucket (local.get $self) (local.get $next_index) (i32.const 0) (i32.const 0))
+              (local.set $bucket_index (local.get $next_index))))
+          ;; Continue with the next bucket
+          (br $LOOP)))))
+
   (func $Term::Hashmap::traits::keys (param $self i32) (result i32)
     (call $Term::HashmapKeysIterator::new (local.get $self)))
 
@@ -90,21 +90,106 @@
     (local.get $dependencies))
 
   (func $Term::Hashset::traits::collect_strict (param $iterator i32) (param $state i32) (result i32 i32)
+    ;; Consume the source iterator items directly into the underlying hashmap, without allocating intermediate iterators
+    (local $length i32)
+    (local $instance i32)
+    (local $num_entries i32)
+    (local $iterator_state i32)
     (local $dependencies i32)
-    (call $Term::Hashmap::traits::collect_strict
-      ;; TODO: Avoid unnecessary heap allocations for intermediate values
-      (call $Term::FlattenIterator::new
-        (call $Term::ZipIterator::new
-          (local.get $iterator)
-          (call $Term::RepeatIterator::new (call $Term::Nil::new))))
-      (local.get $state))
-    (local.set $dependencies)
-    (call $Term::Hashset::new)
-    (local.get $dependencies))
+    (local $signal i32)
+    (local $value i32)
+    ;; If the iterator size is known in advance, preallocate a hashmap of the correct capacity, otherwise start off with
+    ;; the empty hashmap and allocate hashmaps of increasing capacity as more items are consumed from the source iterator
+    (local.set $instance
+      (if (result i32)
+        (i32.or
+          (i32.eq (local.tee $length (call $Term::traits::size_hint (local.get $iterator))) (global.get $NULL))
+          (i32.eqz (local.get $length)))
+        (then
+          (call $Term::Hashmap::empty))
+        (else
+          (call $Term::Hashmap::allocate (call $Term::Hashmap::default_capacity (local.get $length))))))
+    (local.set $iterator_state (global.get $NULL))
+    (local.set $dependencies (global.get $NULL))
+    (local.set $signal (global.get $NULL))
+    (loop $LOOP
+      ;; Consume the next iterator item
+      (call $Term::traits::next (local.get $iterator) (local.get $iterator_state) (local.get $state))
+      ;; Update the accumulated dependencies and iterator state
+      (local.set $dependencies (call $Dependencies::traits::union (local.get $dependencies)))
+      (local.set $iterator_state)
+      (if
+        ;; If the iterator has been fully consumed, nothing more to do
+        (i32.eq (local.tee $value) (global.get $NULL))
+        (then)
+        (else
+          ;; Otherwise resolve the value
+          (call $Term::traits::evaluate (local.get $value) (local.get $state))
+          (local.set $dependencies (call $Dependencies::traits::union (local.get $dependencies)))
+          (local.set $value)
+          ;; If the value resolves to a signal, or a signal has already been encountered,
+          ;; update the combined signal and continue with the next item
+          (br_if $LOOP
+            (i32.ne
+              (global.get $NULL)
+              (local.tee $signal
+                (call $Term::Signal::traits::union
+                  (local.get $signal)
+                  (select
+                    (local.get $value)
+                    (global.get $NULL)
+                    (call $Term::Signal::is (local.get $value)))))))
+          ;; Ensure enough capacity exists in the hashmap to store an additional entry
+          (local.set $instance
+            (call $Term::Hashmap::ensure_capacity
+              (local.get $instance)
+              (call $Term::Hashmap::default_capacity (i32.add (local.get $num_entries) (i32.const 1)))))
+          ;; Store the value as a key in the underlying hashmap
+          ;; (this function returns the number of new entries added to the hashmap)
+          (call $Term::Hashmap::insert_entry (local.get $instance) (local.get $value) (call $Term::Nil::new))
+          ;; Keep track of how many unique entries have been added to the hashmap
+          (local.set $num_entries (i32.add (local.get $num_entries)))
+          ;; Update the hashmap length to ensure that any reallocations copy all the items collected so far
+          (call $Term::Hashmap::set::num_entries (local.get $instance) (local.get $num_entries))
+          ;; Continue with the next entry
+          (br $LOOP))))
+    (if (result i32 i32)
+      ;; If a signal was encountered during the iteration, return the combined signal
+      (i32.ne (global.get $NULL) (local.get $signal))
+      (then
+        (local.get $signal)
+        (local.get $dependencies))
+      (else
+        ;; Otherwise if the source iterator did not produce any valid entries, return the empty hashset
+        (if (result i32 i32)
+          (i32.eqz (local.get $num_entries))
+          (then
+            (call $Term::Hashset::empty)
+            (local.get $dependencies))
+          (else
+            ;; Otherwise initialize the underlying hashmap and wrap it in a hashset
+            (call $Term::init (local.get $instance))
+            (call $Term::Hashset::new)
+            (local.get $dependencies))))))
 
   (func $Term::Hashset::get::num_entries (export "getHashsetNumEntries") (param $self i32) (result i32)
     (call $Term::Hashmap::get::num_entries (call $Term::Hashset::get::entries (local.get $self))))
 
+  (func $Term::Hashset::delete (export "deleteHashsetValue") (param $self i32) (param $value i32) (result i32)
+    (local $existing_entries i32)
+    (local $updated_entries i32)
+    (if (result i32)
+      (i32.eq
+        (local.tee $updated_entries
+          (call $Term::Hashmap::traits::delete
+            (local.tee $existing_entries (call $Term::Hashset::get::entries (local.get $self)))
+            (local.get $value)))
+        (local.get $existing_entries))
+      (then
+        (local.get $self))
+      (else
+        (call $Term::Hashset::new (local.get $updated_entries)))))
+
   (func $Term::Hashset::push (export "pushHashsetValue") (param $self i32) (param $value i32) (result i32)
     (local $existing_entries i32)
     (local $updated_entries i32)
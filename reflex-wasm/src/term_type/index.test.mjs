@@ -2,11 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 import application from './application.test.mjs';
+import bigInt from './big_int.test.mjs';
 import boolean from './boolean.test.mjs';
 import builtin from './builtin.test.mjs';
+import bytes from './bytes.test.mjs';
 import cell from './cell.test.mjs';
 import condition from './condition.test.mjs';
 import constructor from './constructor.test.mjs';
+import duration from './duration.test.mjs';
 import effect from './effect.test.mjs';
 import float from './float.test.mjs';
 import hashmap from './hashmap.test.mjs';
@@ -17,10 +20,15 @@ import lambda from './lambda.test.mjs';
 import lazyResult from './lazy_result.test.mjs';
 import _let from './let.test.mjs';
 import list from './list.test.mjs';
+import listRope from './list_rope.test.mjs';
+import listView from './list_view.test.mjs';
 import nil from './nil.test.mjs';
 import partial from './partial.test.mjs';
 import pointer from './pointer.test.mjs';
 import record from './record.test.mjs';
+import recursive from './recursive.test.mjs';
+import regex from './regex.test.mjs';
+import sensitive from './sensitive.test.mjs';
 import signal from './signal.test.mjs';
 import string from './string.test.mjs';
 import symbol from './symbol.test.mjs';
@@ -30,11 +38,14 @@ import variable from './variable.test.mjs';
 
 export default (describe) => {
   application(describe);
+  bigInt(describe);
   boolean(describe);
   builtin(describe);
+  bytes(describe);
   cell(describe);
   condition(describe);
   constructor(describe);
+  duration(describe);
   effect(describe);
   float(describe);
   hashmap(describe);
@@ -44,10 +55,15 @@ export default (describe) => {
   lazyResult(describe);
   _let(describe);
   list(describe);
+  listRope(describe);
+  listView(describe);
   nil(describe);
   partial(describe);
   pointer(describe);
   record(describe);
+  recursive(describe);
+  regex(describe);
+  sensitive(describe);
   signal(describe);
   string(describe);
   symbol(describe);
@@ -3,16 +3,20 @@
 ;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 (module
   (@include "./application.wat")
+  (@include "./big_int.wat")
   (@include "./boolean.wat")
   (@include "./builtin.wat")
+  (@include "./bytes.wat")
   (@include "./cell.wat")
   (@include "./condition.wat")
   (@include "./constructor.wat")
+  (@include "./duration.wat")
   (@include "./effect.wat")
   (@include "./float.wat")
   (@include "./hashmap.wat")
   (@include "./hashset.wat")
   (@include "./int.wat")
+  (@include "./iterator/dedupe.wat")
   (@include "./iterator/empty.wat")
   (@include "./iterator/evaluate.wat")
   (@include "./iterator/filter.wat")
@@ -33,10 +37,15 @@
   (@include "./lazy_result.wat")
   (@include "./let.wat")
   (@include "./list.wat")
+  (@include "./list_rope.wat")
+  (@include "./list_view.wat")
   (@include "./nil.wat")
   (@include "./partial.wat")
   (@include "./pointer.wat")
   (@include "./record.wat")
+  (@include "./recursive.wat")
+  (@include "./regex.wat")
+  (@include "./sensitive.wat")
   (@include "./signal.wat")
   (@include "./string.wat")
   (@include "./symbol.wat")
@@ -47,11 +56,14 @@
   (@let $TermType
     (@union $TermType
       (@import $Application "./application.wat")
+      (@import $BigInt "./big_int.wat")
       (@import $Boolean "./boolean.wat")
       (@import $Builtin "./builtin.wat")
+      (@import $Bytes "./bytes.wat")
       (@import $Cell "./cell.wat")
       (@import $Condition "./condition.wat")
       (@import $Constructor "./constructor.wat")
+      (@import $Duration "./duration.wat")
       (@import $Effect "./effect.wat")
       (@import $Float "./float.wat")
       (@import $Hashmap "./hashmap.wat")
@@ -65,12 +77,15 @@
       (@import $Partial "./partial.wat")
       (@import $Pointer "./pointer.wat")
       (@import $Record "./record.wat")
+      (@import $Recursive "./recursive.wat")
+      (@import $Regex "./regex.wat")
       (@import $Signal "./signal.wat")
       (@import $String "./string.wat")
       (@import $Symbol "./symbol.wat")
       (@import $Timestamp "./timestamp.wat")
       (@import $Tree "./tree.wat")
       (@import $Variable "./variable.wat")
+      (@import $DedupeIterator "./iterator/dedupe.wat")
       (@import $EmptyIterator "./iterator/empty.wat")
       (@import $EvaluateIterator "./iterator/evaluate.wat")
       (@import $FilterIterator "./iterator/filter.wat")
@@ -86,7 +101,10 @@
       (@import $RepeatIterator "./iterator/repeat.wat")
       (@import $SkipIterator "./iterator/skip.wat")
       (@import $TakeIterator "./iterator/take.wat")
-      (@import $ZipIterator "./iterator/zip.wat"))
+      (@import $ZipIterator "./iterator/zip.wat")
+      (@import $ListView "./list_view.wat")
+      (@import $ListRope "./list_rope.wat")
+      (@import $Sensitive "./sensitive.wat"))
 
     (@derive $equals (@get $TermType))
     (@derive $hash (@get $TermType))
@@ -173,7 +191,8 @@
         $Application
         $Effect
         $LazyResult
-        $Let)
+        $Let
+        $Recursive)
 
       (func $TermType::implements::evaluate (param $type i32) (result i32)
         (@fold $result $typename
@@ -257,6 +276,7 @@
         $Hashmap
         $Hashset
         $Tree
+        $DedupeIterator
         $EmptyIterator
         $EvaluateIterator
         $FilterIterator
@@ -272,7 +292,9 @@
         $RepeatIterator
         $SkipIterator
         $TakeIterator
-        $ZipIterator)
+        $ZipIterator
+        $ListView
+        $ListRope)
 
       (func $TermType::implements::iterate (param $type i32) (result i32)
         (@fold $result $typename
@@ -359,8 +381,14 @@
         $Float
         $String
         $List
+        $ListView
+        $ListRope
         $Record
-        $Timestamp)
+        $Timestamp
+        $Duration
+        $BigInt
+        $Bytes
+        $Sensitive)
 
       (func $TermType::implements::to_json (param $type i32) (result i32)
         (@fold $result $typename
@@ -0,0 +1,74 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Term::DedupeIterator', (test) => {
+    test('iteration', (assert, {
+      createApplication,
+      createBuiltin,
+      createDedupeIterator,
+      createEmptyIterator,
+      createInt,
+      createList,
+      createRangeIterator,
+      createString,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.ResolveList),
+          createUnitList(createDedupeIterator(createEmptyIterator())),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '[]');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.ResolveList),
+          createUnitList(createDedupeIterator(createRangeIterator(3, 3))),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '[3, 4, 5]');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.ResolveList),
+          createUnitList(
+            createDedupeIterator(
+              createList([createInt(3), createInt(3), createInt(3), createInt(4), createInt(3)]),
+            ),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '[3, 4, 3]');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.ResolveList),
+          createUnitList(
+            createDedupeIterator(
+              createList([
+                createString('foo'),
+                createString('foo'),
+                createString('bar'),
+                createString('bar'),
+                createString('baz'),
+              ]),
+            ),
+          ),
+        );
+        const [result, dependencies] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '["foo", "bar", "baz"]');
+        assert.deepEqual(getStateDependencies(dependencies), []);
+      })();
+    });
+  });
+};
@@ -0,0 +1,124 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@let $DedupeIterator
+    (@struct $DedupeIterator
+      (@field $source (@ref $Term)))
+
+    (@derive $size (@get $DedupeIterator))
+    (@derive $equals (@get $DedupeIterator))
+    (@derive $hash (@get $DedupeIterator))
+
+    (@export $DedupeIterator (@get $DedupeIterator)))
+
+  (export "isDedupeIterator" (func $Term::DedupeIterator::is))
+  (export "getDedupeIteratorSource" (func $Term::DedupeIterator::get::source))
+
+  (func $Term::DedupeIterator::new (export "createDedupeIterator") (param $source i32) (result i32)
+    (call $Term::TermType::DedupeIterator::new (local.get $source)))
+
+  (func $Term::DedupeIterator::traits::is_atomic (param $self i32) (result i32)
+    (call $Term::traits::is_atomic (call $Term::DedupeIterator::get::source (local.get $self))))
+
+  (func $Term::DedupeIterator::traits::display (param $self i32) (param $offset i32) (result i32)
+    (call $TermType::traits::display (global.get $TermType::DedupeIterator) (local.get $offset)))
+
+  (func $Term::DedupeIterator::traits::debug (param $self i32) (param $offset i32) (result i32)
+    (call $Term::DedupeIterator::traits::display (local.get $self) (local.get $offset)))
+
+  (func $Term::DedupeIterator::traits::substitute (param $self i32) (param $variables i32) (param $scope_offset i32) (result i32)
+    (local $substituted_source i32)
+    (local.set $substituted_source
+      (call $Term::traits::substitute
+        (call $Term::DedupeIterator::get::source (local.get $self))
+        (local.get $variables)
+        (local.get $scope_offset)))
+    (if (result i32)
+      (i32.eq (global.get $NULL) (local.get $substituted_source))
+      (then
+        (global.get $NULL))
+      (else
+        (call $Term::DedupeIterator::new (local.get $substituted_source)))))
+
+  (func $Term::DedupeIterator::traits::iterate (param $self i32) (result i32)
+    (local.get $self))
+
+  (func $Term::DedupeIterator::traits::size_hint (param $self i32) (result i32)
+    (global.get $NULL))
+
+  (func $Term::DedupeIterator::traits::next (param $self i32) (param $iterator_state i32) (param $state i32) (result i32 i32 i32)
+    (local $source i32)
+    (local $source_state i32)
+    (local $previous i32)
+    (local $item i32)
+    (local $dependencies i32)
+    (local.set $source (call $Term::DedupeIterator::get::source (local.get $self)))
+    (local.set $dependencies (global.get $NULL))
+    ;; Retrieve the previously-emitted item and the source iterator state from the iterator state cell
+    (if
+      (i32.eq (global.get $NULL) (local.get $iterator_state))
+      (then
+        (local.set $previous (global.get $NULL))
+        (local.set $source_state (global.get $NULL)))
+      (else
+        (local.set $previous (call $Term::DedupeIterator::get_iterator_state_item (local.get $iterator_state)))
+        (local.set $source_state (call $Term::DedupeIterator::get_iterator_state_state (local.get $iterator_state)))))
+    (loop $LOOP
+      ;; Consume the next item from the source iterator, accumulating the iteration dependencies
+      (call $Term::traits::next (local.get $source) (local.get $source_state) (local.get $state))
+      (local.set $dependencies (call $Dependencies::traits::union (local.get $dependencies)))
+      (local.set $source_state)
+      (local.set $item)
+      (if
+        ;; If the source iterator has been fully consumed, clean up the iterator state and return the complete marker
+        (i32.eq (global.get $NULL) (local.get $item))
+        (then
+          (if
+            (i32.ne (global.get $NULL) (local.get $iterator_state))
+            (then
+              (call $Term::drop (local.get $iterator_state))))
+          (global.get $NULL)
+          (global.get $NULL)
+          (local.get $dependencies)
+          (return)))
+      ;; Skip any items whose hash is identical to the previously-emitted item
+      (br_if $LOOP
+        (if (result i32)
+          (i32.eq (global.get $NULL) (local.get $previous))
+          (then
+            (global.get $FALSE))
+          (else
+            (i64.eq
+              (call $Term::get_hash (local.get $item))
+              (call $Term::get_hash (local.get $previous)))))))
+    ;; Store the emitted item for comparison with subsequent items
+    (if
+      (i32.eq (global.get $NULL) (local.get $iterator_state))
+      (then
+        (local.set $iterator_state
+          (call $Term::DedupeIterator::allocate_iterator_state (local.get $item) (local.get $source_state))))
+      (else
+        (call $Term::DedupeIterator::set_iterator_state_item (local.get $iterator_state) (local.get $item))
+        (call $Term::DedupeIterator::set_iterator_state_state (local.get $iterator_state) (local.get $source_state))))
+    (local.get $item)
+    (local.get $iterator_state)
+    (local.get $dependencies))
+
+  (func $Term::DedupeIterator::allocate_iterator_state (param $item i32) (param $state i32) (result i32)
+    (local $iterator_state i32)
+    (local.tee $iterator_state (call $Term::Cell::allocate (i32.const 2)))
+    (call $Term::Cell::set_field (local.get $iterator_state) (i32.const 0) (local.get $item))
+    (call $Term::Cell::set_field (local.get $iterator_state) (i32.const 1) (local.get $state)))
+
+  (func $Term::DedupeIterator::get_iterator_state_item (param $iterator_state i32) (result i32)
+    (call $Term::Cell::get_field (local.get $iterator_state) (i32.const 0)))
+
+  (func $Term::DedupeIterator::set_iterator_state_item (param $iterator_state i32) (param $value i32)
+    (call $Term::Cell::set_field (local.get $iterator_state) (i32.const 0) (local.get $value)))
+
+  (func $Term::DedupeIterator::get_iterator_state_state (param $iterator_state i32) (result i32)
+    (call $Term::Cell::get_field (local.get $iterator_state) (i32.const 1)))
+
+  (func $Term::DedupeIterator::set_iterator_state_state (param $iterator_state i32) (param $value i32)
+    (call $Term::Cell::set_field (local.get $iterator_state) (i32.const 1) (local.get $value))))
@@ -97,7 +97,7 @@
       (@list
         ;; If the target is a list term, retrieve the field value directly
         (@list
-          (i32.eq (local.get $term_type) (global.get $TermType::List))
+          (call $TermType::is_list (local.get $term_type))
           (return
             (call $Term::List::get_item (local.get $target) (local.get $index))
             (global.get $NULL)))
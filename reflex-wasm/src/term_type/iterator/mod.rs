@@ -1,6 +1,7 @@
 // SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+mod dedupe;
 mod empty;
 mod evaluate;
 mod filter;
@@ -18,6 +19,7 @@ mod skip;
 mod take;
 mod zip;
 
+pub use dedupe::*;
 pub use empty::*;
 pub use evaluate::*;
 pub use filter::*;
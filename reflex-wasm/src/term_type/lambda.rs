@@ -180,8 +180,10 @@ impl<A: Arena + Clone> CompileWasm<A> for ArenaRef<LambdaTerm, A> {
         // which means they can be invoked similarly to the standard library builtins,
         // using the function lookup table to perform an indirect call to the compiled function wrapper.
         let compiled_function_id = CompiledFunctionId::from(self);
-        // Compile the lambda body if it has not yet already been compiled
-        if !state.compiled_lambdas.contains_key(&compiled_function_id) {
+        // Compile the lambda body if it has not yet already been compiled (or restored from the compiler cache)
+        if !state.compiled_lambdas.contains_key(&compiled_function_id)
+            && !state.restore_cached_lambda(compiled_function_id)
+        {
             let num_args = self.num_args() as StackOffset;
             let body = self.body();
             let params = ParamsSignature::from_iter((0..num_args).map(|_| ValueType::HeapPointer));
@@ -193,9 +195,10 @@ impl<A: Arena + Clone> CompileWasm<A> for ArenaRef<LambdaTerm, A> {
                     // and a block wrapper to catch short-circuiting signals
                     let inner_stack = params
                         .iter()
-                        .fold(CompilerStack::default(), |stack, value_type| {
-                            stack.declare_variable(value_type)
-                        })
+                        .fold(
+                            CompilerStack::default().with_trace(&stack),
+                            |stack, value_type| stack.declare_variable(value_type),
+                        )
                         .enter_block(&TypeSignature {
                             params: ParamsSignature::Void,
                             results: ParamsSignature::Single(ValueType::HeapPointer),
@@ -218,9 +221,10 @@ impl<A: Arena + Clone> CompileWasm<A> for ArenaRef<LambdaTerm, A> {
                     // and a block wrapper to catch short-circuiting signals
                     let inner_stack = params
                         .iter()
-                        .fold(CompilerStack::default(), |stack, value_type| {
-                            stack.enter_scope(value_type)
-                        })
+                        .fold(
+                            CompilerStack::default().with_trace(&stack),
+                            |stack, value_type| stack.enter_scope(value_type),
+                        )
                         .enter_block(&TypeSignature {
                             params: ParamsSignature::Void,
                             results: ParamsSignature::Single(ValueType::HeapPointer),
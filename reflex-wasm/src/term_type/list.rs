@@ -129,7 +129,10 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<ListTerm, A> {
             .unwrap_or(0)
     }
     fn free_variables(&self) -> HashSet<StackOffset> {
-        self.iter().flat_map(|term| term.free_variables()).collect()
+        self.iter().fold(HashSet::new(), |mut results, term| {
+            results.extend(term.shared_free_variables().iter().copied());
+            results
+        })
     }
     fn count_variable_usages(&self, offset: StackOffset) -> usize {
         self.iter()
@@ -229,33 +232,46 @@ impl<A: Arena + Clone> SerializeJson for ArenaRef<ListTerm, A> {
             .map(|values| JsonValue::Array(values))
     }
     fn patch(&self, target: &Self) -> Result<Option<JsonValue>, String> {
-        let updates = target
-            .iter()
-            .zip(self.iter())
-            .map(|(current, previous)| previous.patch(&current))
-            .chain(
-                target
-                    .iter()
-                    .skip(self.len())
-                    .map(|item| item.to_json().map(Some)),
-            )
-            .collect::<Result<Vec<_>, _>>()?;
-        let updates = reflex_utils::json::json_object(
-            updates
-                .into_iter()
-                .enumerate()
-                .filter_map(|(index, item)| item.map(|value| (index.to_string(), value)))
-                .chain(if target.len() != self.len() {
-                    Some((String::from("length"), JsonValue::from(target.len())))
-                } else {
-                    None
-                }),
-        );
-        if is_empty_json_object(&updates) {
-            Ok(None)
-        } else {
-            Ok(Some(updates))
-        }
+        patch_list_items(
+            &self.iter().collect::<Vec<_>>(),
+            &target.iter().collect::<Vec<_>>(),
+        )
+    }
+}
+
+/// Create a JSON patch describing the changes between two sequences of list items
+///
+/// This is shared by all list term representations (flat lists, list views and list ropes).
+pub(crate) fn patch_list_items<A: Arena + Clone>(
+    previous: &[ArenaRef<Term, A>],
+    target: &[ArenaRef<Term, A>],
+) -> Result<Option<JsonValue>, String> {
+    let updates = target
+        .iter()
+        .zip(previous.iter())
+        .map(|(current, previous)| previous.patch(current))
+        .chain(
+            target
+                .iter()
+                .skip(previous.len())
+                .map(|item| item.to_json().map(Some)),
+        )
+        .collect::<Result<Vec<_>, _>>()?;
+    let updates = reflex_utils::json::json_object(
+        updates
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, item)| item.map(|value| (index.to_string(), value)))
+            .chain(if target.len() != previous.len() {
+                Some((String::from("length"), JsonValue::from(target.len())))
+            } else {
+                None
+            }),
+    );
+    if is_empty_json_object(&updates) {
+        Ok(None)
+    } else {
+        Ok(Some(updates))
     }
 }
 
@@ -276,32 +292,41 @@ impl<A: Arena + Clone> std::fmt::Debug for ArenaRef<ListTerm, A> {
 
 impl<A: Arena + Clone> std::fmt::Display for ArenaRef<ListTerm, A> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let max_displayed_items = 100;
-        let items = self.iter();
-        let num_items = items.len();
-        write!(
-            f,
-            "[{}]",
-            if num_items <= max_displayed_items {
-                items
-                    .map(|item| format!("{}", item))
-                    .collect::<Vec<_>>()
-                    .join(", ")
-            } else {
-                items
-                    .take(max_displayed_items - 1)
-                    .map(|item| format!("{}", item))
-                    .chain(once(format!(
-                        "...{} more items",
-                        num_items - (max_displayed_items - 1)
-                    )))
-                    .collect::<Vec<_>>()
-                    .join(", ")
-            }
-        )
+        fmt_list_items(self.iter(), f)
     }
 }
 
+/// Format a sequence of list items, truncating the output for long lists
+///
+/// This is shared by all list term representations (flat lists, list views and list ropes).
+pub(crate) fn fmt_list_items<A: Arena + Clone>(
+    items: impl ExactSizeIterator<Item = ArenaRef<Term, A>>,
+    f: &mut std::fmt::Formatter<'_>,
+) -> std::fmt::Result {
+    let max_displayed_items = 100;
+    let num_items = items.len();
+    write!(
+        f,
+        "[{}]",
+        if num_items <= max_displayed_items {
+            items
+                .map(|item| format!("{}", item))
+                .collect::<Vec<_>>()
+                .join(", ")
+        } else {
+            items
+                .take(max_displayed_items - 1)
+                .map(|item| format!("{}", item))
+                .chain(once(format!(
+                    "...{} more items",
+                    num_items - (max_displayed_items - 1)
+                )))
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+    )
+}
+
 impl<A: Arena + Clone> Internable for ArenaRef<ListTerm, A> {
     fn should_intern(&self, eager: ArgType) -> bool {
         self.iter().all(|item| item.should_intern(eager))
@@ -195,7 +195,7 @@
     (local $index i32)
     (if (result i32)
       ;; If the list is empty, return true
-      (i32.eqz (local.tee $length (call $Term::List::get::items::length (local.get $self))))
+      (i32.eqz (local.tee $length (call $Term::List::get_length (local.get $self))))
       (then
         (global.get $TRUE))
       (else
@@ -203,7 +203,7 @@
         (loop $LOOP
           (if
             ;; If the current item is not atomic, return false
-            (i32.eqz (call $Term::traits::is_atomic (call $Term::List::get::items::value (local.get $self) (local.get $index))))
+            (i32.eqz (call $Term::traits::is_atomic (call $Term::List::get_item (local.get $self) (local.get $index))))
             (then
               (return (global.get $FALSE)))
             (else
@@ -324,7 +324,7 @@
     (local $item i32)
     (if (result i32 i32)
       ;; If the list is empty, write an empty JSON array literal
-      (i32.eqz (local.tee $length (call $Term::List::get::items::length (local.get $self))))
+      (i32.eqz (local.tee $length (call $Term::List::get_length (local.get $self))))
       (then
         ;; Put the success marker on the stack
         (global.get $TRUE)
@@ -344,7 +344,7 @@
           (if
             (i32.eqz
               (call $Term::implements::to_json
-                (local.tee $item (call $Term::List::get::items::value (local.get $self) (local.get $index)))))
+                (local.tee $item (call $Term::List::get_item (local.get $self) (local.get $index)))))
             (then
               (return (global.get $FALSE) (local.get $offset)))
             (else))
@@ -378,8 +378,7 @@
         (i32.add (local.get $offset) (i32.const 1)))))
 
   (func $Term::List::traits::length (param $self i32) (result i32)
-    ;; Retrieve the struct field value from the correct offset
-    (call $Term::List::get::items::length (local.get $self)))
+    (call $Term::List::get_length (local.get $self)))
 
   (func $Term::List::traits::iterate (param $self i32) (result i32)
     (local.get $self))
@@ -397,14 +396,14 @@
             (i32.const 0)
             (local.get $iterator_state)
             (i32.eq (global.get $NULL) (local.get $iterator_state))))
-        (call $Term::List::get::items::length (local.get $self)))
+        (call $Term::List::get_length (local.get $self)))
       (then
         (global.get $NULL)
         (global.get $NULL)
         (global.get $NULL))
       (else
         ;; Otherwise emit the current item and the incremented iterator state
-        (call $Term::List::get::items::value (local.get $self) (local.get $iterator_state))
+        (call $Term::List::get_item (local.get $self) (local.get $iterator_state))
         (i32.add (local.get $iterator_state) (i32.const 1))
         (global.get $NULL))))
 
@@ -420,10 +419,10 @@
           ;; Determine whether the index is within the list bounds
           (i32.and
             (i32.ge_s (local.tee $index (i32.wrap_i64 (call $Term::Int::get::value (local.get $key)))) (i32.const 0))
-            (i32.lt_u (local.get $index) (call $Term::List::get::items::length (local.get $self))))
+            (i32.lt_u (local.get $index) (call $Term::List::get_length (local.get $self))))
           (then
             ;; If the index is within the list bounds, etrieve the corresponding list item
-            (call $Term::List::get::items::value (local.get $self) (local.get $index)))
+            (call $Term::List::get_item (local.get $self) (local.get $index)))
           (else
             ;; Otherwise return the null sentinel value
             (global.get $NULL))))))
@@ -439,10 +438,10 @@
         ;; Determine whether the index is within the list bounds
         (i32.and
           (i32.ge_s (local.tee $index (i32.wrap_i64 (call $Term::Int::get::value (local.get $key)))) (i32.const 0))
-          (i32.lt_u (local.get $index) (call $Term::List::get::items::length (local.get $self)))))))
+          (i32.lt_u (local.get $index) (call $Term::List::get_length (local.get $self)))))))
 
   (func $Term::List::traits::keys (param $self i32) (result i32)
-    (call $Term::RangeIterator::new (i64.const 0) (call $Term::List::get::items::length (local.get $self))))
+    (call $Term::RangeIterator::new (i64.const 0) (call $Term::List::get_length (local.get $self))))
 
   (func $Term::List::traits::values (param $self i32) (result i32)
     (call $Term::List::traits::iterate (local.get $self)))
@@ -462,25 +461,133 @@
             (local.get $self))
           (else
             (local.tee $result (call $Term::List::allocate (i32.add (local.get $length_self) (local.get $length_other))))
-            (memory.copy
-              (call $Term::List::get::items::pointer (local.get $result) (i32.const 0))
-              (call $Term::List::get::items::pointer (local.get $self) (i32.const 0))
-              (i32.mul (local.get $length_self) (i32.const 4)))
-            (memory.copy
-              (call $Term::List::get::items::pointer (local.get $result) (local.get $length_self))
-              (call $Term::List::get::items::pointer (local.get $other) (i32.const 0))
-              (i32.mul (local.get $length_other) (i32.const 4)))
+            (call $Term::List::copy_items
+              (local.get $self)
+              (call $Term::List::get::items::pointer (local.get $result) (i32.const 0)))
+            (call $Term::List::copy_items
+              (local.get $other)
+              (call $Term::List::get::items::pointer (local.get $result) (local.get $length_self)))
             ;; Instantiate the list term
             (call $Term::List::init (i32.add (local.get $length_self) (local.get $length_other))))))))
 
+  (func $TermType::is_list (param $type i32) (result i32)
+    ;; List views and list ropes share the storage of existing lists, and are treated as lists wherever a list is expected
+    (i32.or
+      (i32.eq (local.get $type) (global.get $TermType::List))
+      (i32.or
+        (i32.eq (local.get $type) (global.get $TermType::ListView))
+        (i32.eq (local.get $type) (global.get $TermType::ListRope)))))
+
+  (func $Term::is_list (param $self i32) (result i32)
+    (call $TermType::is_list (call $Term::get_type (local.get $self))))
+
   (func $Term::List::get_length (export "getListLength") (param $self i32) (result i32)
-    (call $Term::List::get::items::length (local.get $self)))
+    (if (result i32)
+      (call $Term::List::is (local.get $self))
+      (then
+        (call $Term::List::get::items::length (local.get $self)))
+      (else
+        (if (result i32)
+          (call $Term::ListView::is (local.get $self))
+          (then
+            (call $Term::ListView::get::length (local.get $self)))
+          (else
+            (call $Term::ListRope::get::length (local.get $self)))))))
 
   (func $Term::List::get_items (export "getListItems") (param $self i32) (result i32)
-    (call $Term::List::get::items::pointer (local.get $self) (i32.const 0)))
+    ;; Returns a pointer to the contiguous list items (rope segments are not stored contiguously, so ropes will be
+    ;; flattened into a newly-allocated list)
+    (if (result i32)
+      (call $Term::List::is (local.get $self))
+      (then
+        (call $Term::List::get::items::pointer (local.get $self) (i32.const 0)))
+      (else
+        (if (result i32)
+          (call $Term::ListView::is (local.get $self))
+          (then
+            (call $Term::ListView::get_items (local.get $self)))
+          (else
+            (call $Term::List::get::items::pointer (call $Term::List::flatten (local.get $self)) (i32.const 0)))))))
 
   (func $Term::List::get_item (export "getListItem") (param $self i32) (param $index i32) (result i32)
-    (call $Term::List::get::items::value (local.get $self) (local.get $index)))
+    (if (result i32)
+      (call $Term::List::is (local.get $self))
+      (then
+        (call $Term::List::get::items::value (local.get $self) (local.get $index)))
+      (else
+        (if (result i32)
+          (call $Term::ListView::is (local.get $self))
+          (then
+            (call $Term::ListView::get_item (local.get $self) (local.get $index)))
+          (else
+            (call $Term::ListRope::get_item (local.get $self) (local.get $index)))))))
+
+  (func $Term::List::copy_items (param $self i32) (param $target i32)
+    ;; Copy the list items into the given memory location, which MUST have enough space for the entire list contents
+    (if
+      (call $Term::ListRope::is (local.get $self))
+      (then
+        (call $Term::ListRope::copy_items (local.get $self) (local.get $target)))
+      (else
+        (memory.copy
+          (local.get $target)
+          (call $Term::List::get_items (local.get $self))
+          (i32.mul (call $Term::List::get_length (local.get $self)) (i32.const 4))))))
+
+  (func $Term::List::flatten (param $self i32) (result i32)
+    ;; Return a flat list containing the same items as the given list, view or rope
+    (local $instance i32)
+    (local $length i32)
+    (if (result i32)
+      (call $Term::List::is (local.get $self))
+      (then
+        (local.get $self))
+      (else
+        ;; Allocate a new list of the correct capacity
+        (local.tee $instance
+          (call $Term::List::allocate (local.tee $length (call $Term::List::get_length (local.get $self)))))
+        ;; Copy the existing items into the new list
+        (call $Term::List::copy_items
+          (local.get $self)
+          (call $Term::List::get::items::pointer (local.get $instance) (i32.const 0)))
+        ;; Instantiate the new list
+        (call $Term::List::init (local.get $length)))))
+
+  (func $Term::List::concat (param $self i32) (param $other i32) (result i32)
+    ;; Concatenate the two lists without copying their items, sharing the existing list storage via a list rope
+    (if (result i32)
+      (i32.eqz (call $Term::List::get_length (local.get $self)))
+      (then
+        (local.get $other))
+      (else
+        (if (result i32)
+          (i32.eqz (call $Term::List::get_length (local.get $other)))
+          (then
+            (local.get $self))
+          (else
+            (call $Term::ListRope::concat (local.get $self) (local.get $other)))))))
+
+  (func $Term::List::hash (param $self i32) (param $state i64) (result i64)
+    ;; Compute a hash that matches the hash of a flat list containing the same items
+    (local $length i32)
+    (local $index i32)
+    (local.set $state (call $Hash::write_byte (local.get $state) (global.get $TermType::List)))
+    (local.set $state
+      (call $Hash::write_i32
+        (local.get $state)
+        (local.tee $length (call $Term::List::get_length (local.get $self)))))
+    (if (result i64)
+      (i32.eqz (local.get $length))
+      (then
+        (local.get $state))
+      (else
+        (loop $LOOP (result i64)
+          (local.set $state
+            (call $Hash::write_term
+              (local.get $state)
+              (call $Term::List::get_item (local.get $self) (local.get $index))))
+          (br_if $LOOP (i32.lt_u (local.tee $index (i32.add (local.get $index) (i32.const 1))) (local.get $length)))
+          (local.get $state)))))
 
   (func $Term::List::set_item (export "setListItem") (param $self i32) (param $index i32) (param $value i32)
     (call $Term::List::set::items::value (local.get $self) (local.get $index) (local.get $value)))
@@ -488,8 +595,14 @@
   (func $Term::List::update_index (param $self i32) (param $index i32) (param $value i32) (result i32)
     ;; Return a clone of the given list, with the specified index set to the given value
     ;; This assumes the given index is within the list bounds
-    ;; Create a clone of the original list
-    (local.tee $self (call $Term::traits::clone (local.get $self)))
+    ;; Create a clone of the original list (list views and ropes are copied into a newly-allocated flat list)
+    (local.tee $self
+      (if (result i32)
+        (call $Term::List::is (local.get $self))
+        (then
+          (call $Term::traits::clone (local.get $self)))
+        (else
+          (call $Term::List::flatten (local.get $self)))))
     ;; Update the specified index with the specified value
     (call $Term::List::set::items::value (local.get $self) (local.get $index) (local.get $value))
     ;; Instantiate the term
@@ -499,9 +612,10 @@
     (local $length i32)
     (if (result i32 i32)
       ;; If the source iterator is already a list, return the existing instance
-      (call $Term::List::is (local.get $iterator))
+      ;; (list views and ropes are flattened into a newly-allocated list)
+      (call $Term::is_list (local.get $iterator))
       (then
-        (local.get $iterator)
+        (call $Term::List::flatten (local.get $iterator))
         (global.get $NULL))
       (else
         ;; Otherwise collect the list items according to whether the iterator size is known
@@ -612,14 +726,15 @@
     (local $length i32)
     (if (result i32 i32)
       ;; If the source iterator is already a list composed solely of static items, return the existing instance
+      ;; (list views and ropes are flattened into a newly-allocated list)
       (if (result i32)
-        (call $Term::List::is (local.get $iterator))
+        (call $Term::is_list (local.get $iterator))
         (then
           (i32.eqz (call $Term::List::has_dynamic_items (local.get $iterator))))
         (else
           (global.get $FALSE)))
       (then
-        (local.get $iterator)
+        (call $Term::List::flatten (local.get $iterator))
         (global.get $NULL))
       (else
         ;; Otherwise collect the list items according to whether the iterator size is known
@@ -806,7 +921,7 @@
     (local $index i32)
     (if (result i32)
       ;; If the list is empty, return false
-      (i32.eqz (local.tee $length (call $Term::List::get::items::length (local.get $self))))
+      (i32.eqz (local.tee $length (call $Term::List::get_length (local.get $self))))
       (then
         (global.get $FALSE))
       (else
@@ -814,7 +929,7 @@
         (loop $LOOP
           (if
             ;; If the current item is dynamic, return true
-            (i32.eqz (call $Term::is_static (call $Term::List::get::items::value (local.get $self) (local.get $index))))
+            (i32.eqz (call $Term::is_static (call $Term::List::get_item (local.get $self) (local.get $index))))
             (then
               (return (global.get $TRUE)))
             (else
@@ -826,7 +941,7 @@
   (func $Term::List::find_index (param $self i32) (param $value i32) (result i32)
     (local $num_items i32)
     (local $item_index i32)
-    (local.set $num_items (call $Term::List::get::items::length (local.get $self)))
+    (local.set $num_items (call $Term::List::get_length (local.get $self)))
     ;; Iterate through the items in order until a match is located
     (local.set $item_index (i32.const 0))
     (loop $LOOP (result i32)
@@ -838,7 +953,7 @@
         (else
           (if (result i32)
             ;; Check whether the current field matches the provided value
-            (call $Term::traits::equals (call $Term::List::get::items::value (local.get $self) (local.get $item_index)) (local.get $value))
+            (call $Term::traits::equals (call $Term::List::get_item (local.get $self) (local.get $item_index)) (local.get $value))
             (then
               ;; Item matches; return current index
               (local.get $item_index))
@@ -851,12 +966,11 @@
     (local $instance i32)
     (local $existing_length i32)
     ;; Allocate a new list with the correct capacity
-    (local.tee $instance (call $Term::List::allocate (i32.add (local.tee $existing_length (call $Term::List::get::items::length (local.get $self))) (i32.const 1))))
+    (local.tee $instance (call $Term::List::allocate (i32.add (local.tee $existing_length (call $Term::List::get_length (local.get $self))) (i32.const 1))))
     ;; Copy the existing values into the new list
-    (memory.copy
-      (call $Term::List::get::items::pointer (local.get $instance) (i32.const 0))
-      (call $Term::List::get::items::pointer (local.get $self) (i32.const 0))
-      (i32.mul (local.get $existing_length) (i32.const 4)))
+    (call $Term::List::copy_items
+      (local.get $self)
+      (call $Term::List::get::items::pointer (local.get $instance) (i32.const 0)))
     ;; Add the provided value to the new list
     (call $Term::List::set::items::value (local.get $instance) (local.get $existing_length) (local.get $value))
     ;; Instantiate the new list
@@ -866,25 +980,23 @@
     (local $instance i32)
     (local $existing_length i32)
     ;; Allocate a new list with the correct capacity
-    (local.tee $instance (call $Term::List::allocate (i32.add (local.tee $existing_length (call $Term::List::get::items::length (local.get $self))) (i32.const 1))))
+    (local.tee $instance (call $Term::List::allocate (i32.add (local.tee $existing_length (call $Term::List::get_length (local.get $self))) (i32.const 1))))
     ;; Copy the existing values into the new list
-    (memory.copy
-      (call $Term::List::get::items::pointer (local.get $instance) (i32.const 1))
-      (call $Term::List::get::items::pointer (local.get $self) (i32.const 0))
-      (i32.mul (local.get $existing_length) (i32.const 4)))
+    (call $Term::List::copy_items
+      (local.get $self)
+      (call $Term::List::get::items::pointer (local.get $instance) (i32.const 1)))
     ;; Add the provided value to the new list
     (call $Term::List::set::items::value (local.get $instance) (i32.const 0) (local.get $value))
     ;; Instantiate the new list
     (call $Term::List::init (i32.add (local.get $existing_length) (i32.const 1))))
 
   (func $Term::List::slice (param $self i32) (param $offset i32) (param $length i32) (result i32)
-    (local $instance i32)
     (local $source_length i32)
     (if (result i32)
       ;; If the specified region encompasses the whole list, return the unmodified list
       (i32.and
         (i32.eqz (local.get $offset))
-        (i32.ge_u (local.get $length) (local.tee $source_length (call $Term::List::get::items::length (local.get $self)))))
+        (i32.ge_u (local.get $length) (local.tee $source_length (call $Term::List::get_length (local.get $self)))))
       (then
         (local.get $self))
       (else
@@ -896,27 +1008,25 @@
           (then
             (call $Term::List::empty))
           (else
-            ;; Otherwise allocate a new list of the correct capacity
-            (local.tee $instance
-              (call $Term::List::allocate
-                (local.tee $length
-                  (call $Utils::i32::min_u
-                    (local.get $length)
-                    (i32.sub (local.get $source_length) (local.get $offset))))))
-            ;; Copy the existing items into the new list
-            (memory.copy
-              (call $Term::List::get::items::pointer (local.get $instance) (i32.const 0))
-              (call $Term::List::get::items::pointer (local.get $self) (local.get $offset))
-              (i32.mul (local.get $length) (i32.const 4)))
-            ;; Instantiate the new list
-            (call $Term::List::init (local.get $length)))))))
+            ;; Otherwise clamp the length to the end of the list
+            (local.set $length
+              (call $Utils::i32::min_u
+                (local.get $length)
+                (i32.sub (local.get $source_length) (local.get $offset))))
+            ;; Return a view of the specified region that shares the existing list storage
+            (if (result i32)
+              (call $Term::ListRope::is (local.get $self))
+              (then
+                (call $Term::ListRope::slice (local.get $self) (local.get $offset) (local.get $length)))
+              (else
+                (call $Term::ListView::new (local.get $self) (local.get $offset) (local.get $length)))))))))
 
   (func $Term::List::is_typed_list (param $self i32) (param $type i32) (result i32)
     (local $length i32)
     (local $index i32)
     (if (result i32)
       ;; If the list is empty, return true
-      (i32.eqz (local.tee $length (call $Term::List::get::items::length (local.get $self))))
+      (i32.eqz (local.tee $length (call $Term::List::get_length (local.get $self))))
       (then
         (global.get $TRUE))
       (else
@@ -924,7 +1034,7 @@
         (loop $LOOP
           (if
             ;; If the current item is not of the given type, return false
-            (i32.ne (local.get $type) (call $Term::get_type (call $Term::List::get::items::value (local.get $self) (local.get $index))))
+            (i32.ne (local.get $type) (call $Term::get_type (call $Term::List::get_item (local.get $self) (local.get $index))))
             (then
               (return (global.get $FALSE)))
             (else
@@ -0,0 +1,247 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::collections::HashSet;
+
+use reflex::core::{ArgType, DependencyList, GraphNode, SerializeJson, StackOffset};
+use reflex_macros::PointerIter;
+use serde_json::Value as JsonValue;
+
+use crate::{
+    allocator::Arena,
+    compiler::{
+        CompileWasm, CompilerOptions, CompilerResult, CompilerStack, CompilerState, Internable,
+    },
+    hash::{TermHash, TermHasher, TermSize},
+    term_type::{
+        list::{compile_list, fmt_list_items, patch_list_items},
+        ListTerm, TermType, TypedTerm,
+    },
+    ArenaPointer, ArenaRef, Term,
+};
+
+/// Concatenation of several list segments, sharing the item storage of the underlying lists
+///
+/// List ropes are created by the runtime when concatenating lists, where each segment is either a flat list term or a
+/// list view term. List ropes are treated as equivalent to the flat list containing the same items.
+#[derive(Clone, Copy, Debug, PointerIter)]
+#[repr(C)]
+pub struct ListRopeTerm {
+    pub segments: ArenaPointer,
+    pub length: u32,
+}
+impl TermSize for ListRopeTerm {
+    fn size_of(&self) -> usize {
+        std::mem::size_of::<Self>()
+    }
+}
+impl TermHash for ListRopeTerm {
+    fn hash(&self, hasher: TermHasher, arena: &impl Arena) -> TermHasher {
+        // List ropes are hashed identically to the equivalent flat list
+        let hasher = hasher.write_u32(self.length);
+        self.item_pointers(arena)
+            .into_iter()
+            .fold(hasher, |hasher, item| {
+                let item_hash = arena.read_value::<Term, _>(item, |term| term.id());
+                hasher.hash(&item_hash, arena)
+            })
+    }
+}
+impl ListRopeTerm {
+    pub(crate) fn item_pointers(&self, arena: &impl Arena) -> Vec<ArenaPointer> {
+        let segments = arena.read_value::<Term, _>(self.segments, |term| match term.as_value() {
+            TermType::List(segments) => segments.items.items().copied().collect::<Vec<_>>(),
+            _ => Vec::new(),
+        });
+        segments
+            .into_iter()
+            .flat_map(|segment| {
+                arena.read_value::<Term, _>(segment, |term| match term.as_value() {
+                    TermType::List(segment) => segment.items.items().copied().collect::<Vec<_>>(),
+                    TermType::ListView(segment) => segment.item_pointers(arena),
+                    _ => Vec::new(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl<A: Arena + Clone> ArenaRef<ListRopeTerm, A> {
+    pub fn segments(&self) -> ArenaRef<TypedTerm<ListTerm>, A> {
+        ArenaRef::<TypedTerm<ListTerm>, _>::new(
+            self.arena.clone(),
+            self.read_value(|term| term.segments),
+        )
+    }
+    pub fn length(&self) -> u32 {
+        self.read_value(|term| term.length)
+    }
+    pub fn len(&self) -> usize {
+        self.length() as usize
+    }
+    pub fn get(&self, index: usize) -> Option<ArenaRef<Term, A>> {
+        self.read_value(|term| term.item_pointers(&self.arena).get(index).copied())
+            .map(|pointer| ArenaRef::<Term, _>::new(self.arena.clone(), pointer))
+    }
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = ArenaRef<Term, A>> + '_ {
+        self.read_value(|term| term.item_pointers(&self.arena))
+            .into_iter()
+            .map(|pointer| ArenaRef::<Term, _>::new(self.arena.clone(), pointer))
+    }
+}
+
+impl<A: Arena + Clone> SerializeJson for ArenaRef<ListRopeTerm, A> {
+    fn to_json(&self) -> Result<JsonValue, String> {
+        self.iter()
+            .map(|item| item.to_json())
+            .collect::<Result<Vec<_>, String>>()
+            .map(|values| JsonValue::Array(values))
+    }
+    fn patch(&self, target: &Self) -> Result<Option<JsonValue>, String> {
+        patch_list_items(
+            &self.iter().collect::<Vec<_>>(),
+            &target.iter().collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl<A: Arena + Clone> PartialEq for ArenaRef<ListRopeTerm, A> {
+    fn eq(&self, other: &Self) -> bool {
+        // This assumes that lists with the same length and hash are almost certainly identical
+        self.len() == other.len()
+    }
+}
+impl<A: Arena + Clone> Eq for ArenaRef<ListRopeTerm, A> {}
+
+impl<A: Arena + Clone> std::fmt::Debug for ArenaRef<ListRopeTerm, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.read_value(|term| std::fmt::Debug::fmt(term, f))
+    }
+}
+
+impl<A: Arena + Clone> std::fmt::Display for ArenaRef<ListRopeTerm, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_list_items(self.iter(), f)
+    }
+}
+
+impl<A: Arena + Clone> GraphNode for ArenaRef<ListRopeTerm, A> {
+    fn size(&self) -> usize {
+        1 + self.iter().map(|term| term.size()).sum::<usize>()
+    }
+    fn capture_depth(&self) -> StackOffset {
+        self.iter()
+            .map(|term| term.capture_depth())
+            .max()
+            .unwrap_or(0)
+    }
+    fn free_variables(&self) -> HashSet<StackOffset> {
+        self.iter().fold(HashSet::new(), |mut results, term| {
+            results.extend(term.shared_free_variables().iter().copied());
+            results
+        })
+    }
+    fn count_variable_usages(&self, offset: StackOffset) -> usize {
+        self.iter()
+            .map(|term| term.count_variable_usages(offset))
+            .sum()
+    }
+    fn dynamic_dependencies(&self, deep: bool) -> DependencyList {
+        if deep {
+            self.iter()
+                .flat_map(|term| term.dynamic_dependencies(deep))
+                .collect()
+        } else {
+            DependencyList::empty()
+        }
+    }
+    fn has_dynamic_dependencies(&self, deep: bool) -> bool {
+        if deep {
+            self.iter().any(|term| term.has_dynamic_dependencies(deep))
+        } else {
+            false
+        }
+    }
+    fn is_static(&self) -> bool {
+        true
+    }
+    fn is_atomic(&self) -> bool {
+        self.iter().all(|term| term.is_atomic())
+    }
+    fn is_complex(&self) -> bool {
+        true
+    }
+}
+
+impl<A: Arena + Clone> Internable for ArenaRef<ListRopeTerm, A> {
+    fn should_intern(&self, eager: ArgType) -> bool {
+        self.iter().all(|item| item.should_intern(eager))
+    }
+}
+
+impl<A: Arena + Clone> CompileWasm<A> for ArenaRef<ListRopeTerm, A> {
+    fn compile(
+        &self,
+        stack: CompilerStack,
+        state: &mut CompilerState,
+        options: &CompilerOptions,
+    ) -> CompilerResult<A> {
+        // List ropes are compiled into the equivalent flat list, seeing as the compiled list items will be allocated
+        // afresh at runtime
+        let items = self.iter();
+        let eagerness = options.lazy_list_items;
+        compile_list(items.map(|item| (item, eagerness)), stack, state, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        allocator::{ArenaAllocator, VecAllocator},
+        term_type::{IntTerm, ListViewTerm, TermType, TermTypeDiscriminants},
+    };
+
+    use super::*;
+
+    #[test]
+    fn list_rope() {
+        assert_eq!(
+            TermType::ListRope(ListRopeTerm {
+                segments: ArenaPointer(0x54321),
+                length: 0x98765,
+            })
+            .as_bytes(),
+            [TermTypeDiscriminants::ListRope as u32, 0x54321, 0x98765],
+        );
+        let mut allocator = VecAllocator::default();
+        {
+            let items = (0..4)
+                .map(|value| {
+                    allocator.allocate(Term::new(TermType::Int(IntTerm::from(value)), &allocator))
+                })
+                .collect::<Vec<_>>();
+            let left = ListTerm::allocate(items[0..2].iter().copied(), &mut allocator);
+            let source = ListTerm::allocate(items.iter().copied(), &mut allocator);
+            let right = allocator.allocate(Term::new(
+                TermType::ListView(ListViewTerm {
+                    source,
+                    offset: 2,
+                    length: 2,
+                }),
+                &allocator,
+            ));
+            let segments = ListTerm::allocate([left, right], &mut allocator);
+            let rope = allocator.allocate(Term::new(
+                TermType::ListRope(ListRopeTerm {
+                    segments,
+                    length: 4,
+                }),
+                &allocator,
+            ));
+            assert_eq!(
+                allocator.get_ref::<Term>(rope).id(),
+                allocator.get_ref::<Term>(source).id(),
+            );
+        }
+    }
+}
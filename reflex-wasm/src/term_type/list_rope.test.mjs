@@ -0,0 +1,231 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Term::ListRope', (test) => {
+    test('format', (assert, { createInt, createListRope, createPair, createTriple, format }) => {
+      const rope = createListRope(
+        createPair(
+          createTriple(createInt(1), createInt(2), createInt(3)),
+          createPair(createInt(4), createInt(5)),
+        ),
+        5,
+      );
+      assert.strictEqual(format(rope), '[1, 2, 3, 4, 5]');
+    });
+
+    test('hash', (assert, { createInt, createList, createListRope, createPair, createTriple, hash }) => {
+      const rope = createListRope(
+        createPair(
+          createTriple(createInt(1), createInt(2), createInt(3)),
+          createPair(createInt(4), createInt(5)),
+        ),
+        5,
+      );
+      assert.strictEqual(
+        hash(rope),
+        hash(createList([createInt(1), createInt(2), createInt(3), createInt(4), createInt(5)])),
+      );
+      assert.strictEqual(
+        hash(rope),
+        hash(
+          createListRope(
+            createPair(
+              createPair(createInt(1), createInt(2)),
+              createTriple(createInt(3), createInt(4), createInt(5)),
+            ),
+            5,
+          ),
+        ),
+      );
+      assert.notStrictEqual(
+        hash(rope),
+        hash(createList([createInt(1), createInt(2), createInt(3), createInt(4), createInt(6)])),
+      );
+    });
+
+    test('equals', (assert, { createInt, createList, createListRope, createPair, createTriple, equals }) => {
+      const rope = createListRope(
+        createPair(
+          createTriple(createInt(1), createInt(2), createInt(3)),
+          createPair(createInt(4), createInt(5)),
+        ),
+        5,
+      );
+      assert.strictEqual(
+        equals(rope, createList([createInt(1), createInt(2), createInt(3), createInt(4), createInt(5)])),
+        true,
+      );
+      assert.strictEqual(
+        equals(createList([createInt(1), createInt(2), createInt(3), createInt(4), createInt(5)]), rope),
+        true,
+      );
+      assert.strictEqual(
+        equals(rope, createList([createInt(1), createInt(2), createInt(3), createInt(4), createInt(6)])),
+        false,
+      );
+    });
+
+    test('shared storage', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createPair,
+      createTriple,
+      evaluate,
+      format,
+      getListItems,
+      getListRopeLength,
+      getListRopeSegments,
+      isList,
+      isListRope,
+      isListView,
+      NULL,
+      Stdlib,
+    }) => {
+      const left = createTriple(createInt(1), createInt(2), createInt(3));
+      const right = createPair(createInt(4), createInt(5));
+      const [chained] = evaluate(
+        createApplication(createBuiltin(Stdlib.Chain), createPair(left, right)),
+        NULL,
+      );
+      assert.strictEqual(isListRope(chained), true);
+      assert.strictEqual(getListRopeLength(chained), 5);
+      assert.deepEqual(getListItems(getListRopeSegments(chained)), [left, right]);
+      assert.strictEqual(format(chained), '[1, 2, 3, 4, 5]');
+      (() => {
+        const [result] = evaluate(
+          createApplication(createBuiltin(Stdlib.Chain), createPair(chained, chained)),
+          NULL,
+        );
+        assert.strictEqual(isListRope(result), true);
+        assert.deepEqual(getListItems(getListRopeSegments(result)), [left, right, left, right]);
+        assert.strictEqual(format(result), '[1, 2, 3, 4, 5, 1, 2, 3, 4, 5]');
+      })();
+      (() => {
+        const [result] = evaluate(
+          createApplication(
+            createBuiltin(Stdlib.Slice),
+            createTriple(chained, createInt(0), createInt(2)),
+          ),
+          NULL,
+        );
+        assert.strictEqual(isListView(result), true);
+        assert.strictEqual(format(result), '[1, 2]');
+      })();
+      (() => {
+        const [result] = evaluate(
+          createApplication(
+            createBuiltin(Stdlib.Slice),
+            createTriple(chained, createInt(2), createInt(2)),
+          ),
+          NULL,
+        );
+        assert.strictEqual(isListRope(result), true);
+        assert.strictEqual(format(result), '[3, 4]');
+      })();
+      (() => {
+        const [result] = evaluate(
+          createApplication(
+            createBuiltin(Stdlib.Slice),
+            createTriple(chained, createInt(3), createInt(2)),
+          ),
+          NULL,
+        );
+        assert.strictEqual(result, right);
+      })();
+      (() => {
+        const [result] = evaluate(
+          createApplication(
+            createBuiltin(Stdlib.Chain),
+            createPair(
+              createApplication(createBuiltin(Stdlib.Chain), createPair(chained, chained)),
+              createApplication(
+                createBuiltin(Stdlib.Chain),
+                createPair(
+                  createApplication(createBuiltin(Stdlib.Chain), createPair(chained, chained)),
+                  chained,
+                ),
+              ),
+            ),
+          ),
+          NULL,
+        );
+        assert.strictEqual(isList(result), true);
+        assert.strictEqual(
+          format(result),
+          '[1, 2, 3, 4, 5, 1, 2, 3, 4, 5, 1, 2, 3, 4, 5, 1, 2, 3, 4, 5, 1, 2, 3, 4, 5]',
+        );
+      })();
+    });
+
+    test('item access', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createListRope,
+      createPair,
+      createTriple,
+      createUnitList,
+      evaluate,
+      format,
+      NULL,
+      Stdlib,
+    }) => {
+      const rope = createListRope(
+        createPair(
+          createTriple(createInt(1), createInt(2), createInt(3)),
+          createPair(createInt(4), createInt(5)),
+        ),
+        5,
+      );
+      (() => {
+        const expression = createApplication(createBuiltin(Stdlib.Get), createPair(rope, createInt(2)));
+        const [result] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '3');
+      })();
+      (() => {
+        const expression = createApplication(createBuiltin(Stdlib.Get), createPair(rope, createInt(3)));
+        const [result] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '4');
+      })();
+      (() => {
+        const expression = createApplication(createBuiltin(Stdlib.Length), createUnitList(rope));
+        const [result] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '5');
+      })();
+      (() => {
+        const expression = createApplication(createBuiltin(Stdlib.Push), createPair(rope, createInt(6)));
+        const [result] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '[1, 2, 3, 4, 5, 6]');
+      })();
+    });
+
+    test('iteration', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createListRope,
+      createPair,
+      createTriple,
+      createUnitList,
+      evaluate,
+      format,
+      isList,
+      NULL,
+      Stdlib,
+    }) => {
+      const rope = createListRope(
+        createPair(
+          createTriple(createInt(1), createInt(2), createInt(3)),
+          createPair(createInt(4), createInt(5)),
+        ),
+        5,
+      );
+      const expression = createApplication(createBuiltin(Stdlib.ResolveList), createUnitList(rope));
+      const [result] = evaluate(expression, NULL);
+      assert.strictEqual(isList(result), true);
+      assert.strictEqual(format(result), '[1, 2, 3, 4, 5]');
+    });
+  });
+};
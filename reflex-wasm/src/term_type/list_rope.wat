@@ -0,0 +1,214 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@let $ListRope
+    (@struct $ListRope
+      (@field $segments (@ref $Term))
+      (@field $length i32))
+
+    (@derive $size (@get $ListRope))
+    (@derive $equals (@get $ListRope))
+    (@derive $hash (@get $ListRope))
+
+    (@export $ListRope (@get $ListRope)))
+
+  (export "isListRope" (func $Term::ListRope::is))
+  (export "getListRopeSegments" (func $Term::ListRope::get::segments))
+  (export "getListRopeLength" (func $Term::ListRope::get::length))
+
+  ;; Maximum number of segments before a concatenated rope is flattened into a single contiguous list
+  (global $Term::ListRope::MAX_SEGMENTS i32 (i32.const 8))
+
+  (func $Term::ListRope::new (export "createListRope") (param $segments i32) (param $length i32) (result i32)
+    ;; This assumes the segments list contains at least two non-empty lists or list views
+    (call $Term::TermType::ListRope::new (local.get $segments) (local.get $length)))
+
+  (func $Term::ListRope::concat (param $self i32) (param $other i32) (result i32)
+    ;; This assumes that both lists are non-empty
+    (local $num_segments i32)
+    (local $length_self i32)
+    (local $length_other i32)
+    (local $instance i32)
+    (local.set $length_self (call $Term::List::get_length (local.get $self)))
+    (local.set $length_other (call $Term::List::get_length (local.get $other)))
+    (if (result i32)
+      ;; If the combined rope would exceed the maximum number of segments, flatten into a single contiguous list
+      (i32.gt_u
+        (local.tee $num_segments
+          (i32.add
+            (call $Term::ListRope::num_segments (local.get $self))
+            (call $Term::ListRope::num_segments (local.get $other))))
+        (global.get $Term::ListRope::MAX_SEGMENTS))
+      (then
+        (local.tee $instance (call $Term::List::allocate (i32.add (local.get $length_self) (local.get $length_other))))
+        (call $Term::List::copy_items
+          (local.get $self)
+          (call $Term::List::get::items::pointer (local.get $instance) (i32.const 0)))
+        (call $Term::List::copy_items
+          (local.get $other)
+          (call $Term::List::get::items::pointer (local.get $instance) (local.get $length_self)))
+        (call $Term::List::init (i32.add (local.get $length_self) (local.get $length_other))))
+      (else
+        ;; Otherwise create a new rope containing the segments of both lists
+        (local.set $instance (call $Term::List::allocate (local.get $num_segments)))
+        (call $Term::ListRope::append_segments
+          (local.get $other)
+          (local.get $instance)
+          (call $Term::ListRope::append_segments (local.get $self) (local.get $instance) (i32.const 0)))
+        (drop)
+        (call $Term::ListRope::new
+          (call $Term::List::init (local.get $instance) (local.get $num_segments))
+          (i32.add (local.get $length_self) (local.get $length_other))))))
+
+  (func $Term::ListRope::num_segments (param $list i32) (result i32)
+    (if (result i32)
+      (call $Term::ListRope::is (local.get $list))
+      (then
+        (call $Term::List::get_length (call $Term::ListRope::get::segments (local.get $list))))
+      (else
+        (i32.const 1))))
+
+  (func $Term::ListRope::append_segments (param $list i32) (param $target i32) (param $index i32) (result i32)
+    ;; Write the segments of the given list into the target segments list at the given index, returning the next index
+    (local $segments i32)
+    (if (result i32)
+      (call $Term::ListRope::is (local.get $list))
+      (then
+        (call $Term::List::copy_items
+          (local.tee $segments (call $Term::ListRope::get::segments (local.get $list)))
+          (call $Term::List::get::items::pointer (local.get $target) (local.get $index)))
+        (i32.add (local.get $index) (call $Term::List::get_length (local.get $segments))))
+      (else
+        (call $Term::List::set_item (local.get $target) (local.get $index) (local.get $list))
+        (i32.add (local.get $index) (i32.const 1)))))
+
+  (func $Term::ListRope::get_item (param $self i32) (param $index i32) (result i32)
+    ;; This assumes the given index is within the rope bounds
+    (local $segments i32)
+    (local $segment i32)
+    (local $segment_index i32)
+    (local $segment_length i32)
+    (local.set $segments (call $Term::ListRope::get::segments (local.get $self)))
+    ;; Skip over any segments that lie entirely before the given index
+    (loop $LOOP
+      (if
+        (i32.ge_u
+          (local.get $index)
+          (local.tee $segment_length
+            (call $Term::List::get_length
+              (local.tee $segment (call $Term::List::get_item (local.get $segments) (local.get $segment_index))))))
+        (then
+          (local.set $index (i32.sub (local.get $index) (local.get $segment_length)))
+          (local.set $segment_index (i32.add (local.get $segment_index) (i32.const 1)))
+          (br $LOOP))))
+    ;; Retrieve the item from the segment that contains the given index
+    (call $Term::List::get_item (local.get $segment) (local.get $index)))
+
+  (func $Term::ListRope::copy_items (param $self i32) (param $target i32)
+    (local $segments i32)
+    (local $segment i32)
+    (local $num_segments i32)
+    (local $index i32)
+    (local.set $num_segments
+      (call $Term::List::get_length (local.tee $segments (call $Term::ListRope::get::segments (local.get $self)))))
+    ;; Copy the contents of each segment in turn into the target location
+    (loop $LOOP
+      (call $Term::List::copy_items
+        (local.tee $segment (call $Term::List::get_item (local.get $segments) (local.get $index)))
+        (local.get $target))
+      (local.set $target
+        (i32.add (local.get $target) (i32.mul (call $Term::List::get_length (local.get $segment)) (i32.const 4))))
+      (br_if $LOOP (i32.lt_u (local.tee $index (i32.add (local.get $index) (i32.const 1))) (local.get $num_segments)))))
+
+  (func $Term::ListRope::slice (param $self i32) (param $offset i32) (param $length i32) (result i32)
+    ;; This assumes the specified region is non-empty and lies within the rope bounds
+    (local $segments i32)
+    (local $segment i32)
+    (local $segment_index i32)
+    (local $segment_length i32)
+    (local $remaining i32)
+    (local $results i32)
+    (local $num_results i32)
+    (local.set $segments (call $Term::ListRope::get::segments (local.get $self)))
+    ;; Skip over any segments that lie entirely before the start of the specified region
+    (loop $LOOP
+      (if
+        (i32.ge_u
+          (local.get $offset)
+          (local.tee $segment_length
+            (call $Term::List::get_length
+              (local.tee $segment (call $Term::List::get_item (local.get $segments) (local.get $segment_index))))))
+        (then
+          (local.set $offset (i32.sub (local.get $offset) (local.get $segment_length)))
+          (local.set $segment_index (i32.add (local.get $segment_index) (i32.const 1)))
+          (br $LOOP))))
+    ;; If the specified region lies entirely within a single segment, return a slice of that segment
+    (if
+      (i32.le_u (i32.add (local.get $offset) (local.get $length)) (local.get $segment_length))
+      (then
+        (return (call $Term::List::slice (local.get $segment) (local.get $offset) (local.get $length)))))
+    ;; Otherwise create a new rope containing slices of all the segments that overlap the specified region
+    (local.set $results
+      (call $Term::List::allocate
+        (i32.sub (call $Term::List::get_length (local.get $segments)) (local.get $segment_index))))
+    (local.set $remaining (local.get $length))
+    (loop $LOOP
+      (local.set $segment_length
+        (i32.sub
+          (call $Term::List::get_length
+            (local.tee $segment (call $Term::List::get_item (local.get $segments) (local.get $segment_index))))
+          (local.get $offset)))
+      (call $Term::List::set_item
+        (local.get $results)
+        (local.get $num_results)
+        (call $Term::List::slice (local.get $segment) (local.get $offset) (local.get $remaining)))
+      (local.set $num_results (i32.add (local.get $num_results) (i32.const 1)))
+      ;; If the region extends beyond the current segment, continue with the start of the next segment
+      (if
+        (i32.gt_u (local.get $remaining) (local.get $segment_length))
+        (then
+          (local.set $remaining (i32.sub (local.get $remaining) (local.get $segment_length)))
+          (local.set $offset (i32.const 0))
+          (local.set $segment_index (i32.add (local.get $segment_index) (i32.const 1)))
+          (br $LOOP))))
+    (call $Term::ListRope::new
+      (call $Term::List::init (local.get $results) (local.get $num_results))
+      (local.get $length)))
+
+  (func $Term::ListRope::traits::is_atomic (param $self i32) (result i32)
+    (call $Term::List::traits::is_atomic (local.get $self)))
+
+  (func $Term::ListRope::traits::display (param $self i32) (param $offset i32) (result i32)
+    (call $Term::List::traits::display (local.get $self) (local.get $offset)))
+
+  (func $Term::ListRope::traits::debug (param $self i32) (param $offset i32) (result i32)
+    (call $Term::ListRope::traits::display (local.get $self) (local.get $offset)))
+
+  (func $Term::ListRope::traits::substitute (param $self i32) (param $variables i32) (param $scope_offset i32) (result i32)
+    (local $substituted_segments i32)
+    (local.set $substituted_segments
+      (call $Term::traits::substitute
+        (call $Term::ListRope::get::segments (local.get $self))
+        (local.get $variables)
+        (local.get $scope_offset)))
+    (if (result i32)
+      (i32.eq (global.get $NULL) (local.get $substituted_segments))
+      (then
+        (global.get $NULL))
+      (else
+        (call $Term::ListRope::new
+          (local.get $substituted_segments)
+          (call $Term::ListRope::get::length (local.get $self))))))
+
+  (func $Term::ListRope::traits::to_json (param $self i32) (param $offset i32) (result i32 i32)
+    (call $Term::List::traits::to_json (local.get $self) (local.get $offset)))
+
+  (func $Term::ListRope::traits::iterate (param $self i32) (result i32)
+    (local.get $self))
+
+  (func $Term::ListRope::traits::size_hint (param $self i32) (result i32)
+    (call $Term::ListRope::get::length (local.get $self)))
+
+  (func $Term::ListRope::traits::next (param $self i32) (param $iterator_state i32) (param $state i32) (result i32 i32 i32)
+    (call $Term::List::traits::next (local.get $self) (local.get $iterator_state) (local.get $state))))
@@ -0,0 +1,159 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Term::ListView', (test) => {
+    test('format', (assert, { createInt, createList, createListView, format }) => {
+      const source = createList([createInt(1), createInt(2), createInt(3), createInt(4), createInt(5)]);
+      assert.strictEqual(format(createListView(source, 0, 1)), '[1]');
+      assert.strictEqual(format(createListView(source, 1, 3)), '[2, 3, 4]');
+      assert.strictEqual(format(createListView(source, 3, 2)), '[4, 5]');
+    });
+
+    test('hash', (assert, { createInt, createList, createListView, createPair, hash }) => {
+      const source = createList([createInt(1), createInt(2), createInt(3), createInt(4), createInt(5)]);
+      assert.strictEqual(
+        hash(createListView(source, 1, 2)),
+        hash(createListView(source, 1, 2)),
+      );
+      assert.strictEqual(
+        hash(createListView(source, 1, 2)),
+        hash(createPair(createInt(2), createInt(3))),
+      );
+      assert.notStrictEqual(
+        hash(createListView(source, 1, 2)),
+        hash(createListView(source, 2, 2)),
+      );
+    });
+
+    test('equals', (assert, { createInt, createList, createListView, createPair, equals }) => {
+      const source = createList([createInt(1), createInt(2), createInt(3), createInt(4), createInt(5)]);
+      assert.strictEqual(
+        equals(createListView(source, 1, 2), createPair(createInt(2), createInt(3))),
+        true,
+      );
+      assert.strictEqual(
+        equals(createPair(createInt(2), createInt(3)), createListView(source, 1, 2)),
+        true,
+      );
+      assert.strictEqual(
+        equals(createListView(source, 1, 2), createListView(source, 1, 3)),
+        false,
+      );
+    });
+
+    test('shared storage', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createList,
+      createTriple,
+      evaluate,
+      format,
+      getListViewLength,
+      getListViewOffset,
+      getListViewSource,
+      isListView,
+      NULL,
+      Stdlib,
+    }) => {
+      const source = createList([createInt(1), createInt(2), createInt(3), createInt(4), createInt(5)]);
+      const [slice] = evaluate(
+        createApplication(
+          createBuiltin(Stdlib.Slice),
+          createTriple(source, createInt(1), createInt(3)),
+        ),
+        NULL,
+      );
+      assert.strictEqual(isListView(slice), true);
+      assert.strictEqual(getListViewSource(slice), source);
+      assert.strictEqual(getListViewOffset(slice), 1);
+      assert.strictEqual(getListViewLength(slice), 3);
+      assert.strictEqual(format(slice), '[2, 3, 4]');
+      const [nested] = evaluate(
+        createApplication(
+          createBuiltin(Stdlib.Slice),
+          createTriple(slice, createInt(1), createInt(5)),
+        ),
+        NULL,
+      );
+      assert.strictEqual(isListView(nested), true);
+      assert.strictEqual(getListViewSource(nested), source);
+      assert.strictEqual(getListViewOffset(nested), 2);
+      assert.strictEqual(getListViewLength(nested), 2);
+      assert.strictEqual(format(nested), '[3, 4]');
+    });
+
+    test('item access', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createList,
+      createListView,
+      createPair,
+      createUnitList,
+      evaluate,
+      format,
+      NULL,
+      Stdlib,
+    }) => {
+      const source = createList([createInt(1), createInt(2), createInt(3), createInt(4), createInt(5)]);
+      const view = createListView(source, 1, 3);
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Get),
+          createPair(view, createInt(0)),
+        );
+        const [result] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '2');
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Get),
+          createPair(view, createInt(2)),
+        );
+        const [result] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '4');
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Length),
+          createUnitList(view),
+        );
+        const [result] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '3');
+      })();
+      (() => {
+        const expression = createApplication(
+          createBuiltin(Stdlib.Push),
+          createPair(view, createInt(6)),
+        );
+        const [result] = evaluate(expression, NULL);
+        assert.strictEqual(format(result), '[2, 3, 4, 6]');
+      })();
+    });
+
+    test('iteration', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createList,
+      createListView,
+      createUnitList,
+      evaluate,
+      format,
+      isList,
+      NULL,
+      Stdlib,
+    }) => {
+      const source = createList([createInt(1), createInt(2), createInt(3), createInt(4), createInt(5)]);
+      const expression = createApplication(
+        createBuiltin(Stdlib.ResolveList),
+        createUnitList(createListView(source, 2, 3)),
+      );
+      const [result] = evaluate(expression, NULL);
+      assert.strictEqual(isList(result), true);
+      assert.strictEqual(format(result), '[3, 4, 5]');
+    });
+  });
+};
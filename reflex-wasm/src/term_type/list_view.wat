@@ -0,0 +1,81 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@let $ListView
+    (@struct $ListView
+      (@field $source (@ref $Term))
+      (@field $offset i32)
+      (@field $length i32))
+
+    (@derive $size (@get $ListView))
+    (@derive $equals (@get $ListView))
+    (@derive $hash (@get $ListView))
+
+    (@export $ListView (@get $ListView)))
+
+  (export "isListView" (func $Term::ListView::is))
+  (export "getListViewSource" (func $Term::ListView::get::source))
+  (export "getListViewOffset" (func $Term::ListView::get::offset))
+  (export "getListViewLength" (func $Term::ListView::get::length))
+
+  (func $Term::ListView::new (export "createListView") (param $source i32) (param $offset i32) (param $length i32) (result i32)
+    ;; This assumes the specified region is non-empty and lies within the bounds of the source list
+    (if (result i32)
+      (call $Term::ListView::is (local.get $source))
+      (then
+        ;; If the source is itself a view, create a view of the underlying list to avoid chains of indirection
+        (call $Term::TermType::ListView::new
+          (call $Term::ListView::get::source (local.get $source))
+          (i32.add (call $Term::ListView::get::offset (local.get $source)) (local.get $offset))
+          (local.get $length)))
+      (else
+        (call $Term::TermType::ListView::new (local.get $source) (local.get $offset) (local.get $length)))))
+
+  (func $Term::ListView::get_items (param $self i32) (result i32)
+    (call $Term::List::get::items::pointer
+      (call $Term::ListView::get::source (local.get $self))
+      (call $Term::ListView::get::offset (local.get $self))))
+
+  (func $Term::ListView::get_item (param $self i32) (param $index i32) (result i32)
+    (call $Term::List::get::items::value
+      (call $Term::ListView::get::source (local.get $self))
+      (i32.add (call $Term::ListView::get::offset (local.get $self)) (local.get $index))))
+
+  (func $Term::ListView::traits::is_atomic (param $self i32) (result i32)
+    (call $Term::List::traits::is_atomic (local.get $self)))
+
+  (func $Term::ListView::traits::display (param $self i32) (param $offset i32) (result i32)
+    (call $Term::List::traits::display (local.get $self) (local.get $offset)))
+
+  (func $Term::ListView::traits::debug (param $self i32) (param $offset i32) (result i32)
+    (call $Term::ListView::traits::display (local.get $self) (local.get $offset)))
+
+  (func $Term::ListView::traits::substitute (param $self i32) (param $variables i32) (param $scope_offset i32) (result i32)
+    (local $substituted_source i32)
+    (local.set $substituted_source
+      (call $Term::traits::substitute
+        (call $Term::ListView::get::source (local.get $self))
+        (local.get $variables)
+        (local.get $scope_offset)))
+    (if (result i32)
+      (i32.eq (global.get $NULL) (local.get $substituted_source))
+      (then
+        (global.get $NULL))
+      (else
+        (call $Term::ListView::new
+          (local.get $substituted_source)
+          (call $Term::ListView::get::offset (local.get $self))
+          (call $Term::ListView::get::length (local.get $self))))))
+
+  (func $Term::ListView::traits::to_json (param $self i32) (param $offset i32) (result i32 i32)
+    (call $Term::List::traits::to_json (local.get $self) (local.get $offset)))
+
+  (func $Term::ListView::traits::iterate (param $self i32) (result i32)
+    (local.get $self))
+
+  (func $Term::ListView::traits::size_hint (param $self i32) (result i32)
+    (call $Term::ListView::get::length (local.get $self)))
+
+  (func $Term::ListView::traits::next (param $self i32) (param $iterator_state i32) (param $state i32) (result i32 i32 i32)
+    (call $Term::List::traits::next (local.get $self) (local.get $iterator_state) (local.get $state))))
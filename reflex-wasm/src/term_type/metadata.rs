@@ -0,0 +1,140 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    cell::RefCell,
+    collections::{HashSet, VecDeque},
+    rc::Rc,
+};
+
+use reflex::{
+    core::StackOffset,
+    hash::{HashId, IntMap},
+};
+
+/// Maximum number of terms whose graph metadata is retained before the least recently added entries are evicted
+const GRAPH_NODE_METADATA_CACHE_CAPACITY: usize = 65536;
+
+/// Graph metadata computed for a term, where each field is populated on first access
+///
+/// Metadata is keyed by term hash, which is derived from the term's structure, so the cached values remain valid for
+/// any term with the same hash regardless of which arena the term is allocated in.
+#[derive(Default, Clone, Debug)]
+struct GraphNodeMetadata {
+    size: Option<usize>,
+    capture_depth: Option<StackOffset>,
+    free_variables: Option<Rc<HashSet<StackOffset>>>,
+}
+
+#[derive(Default)]
+struct GraphNodeMetadataCache {
+    entries: IntMap<HashId, GraphNodeMetadata>,
+    /// Cached term hashes in the order they were added, used to determine which entry to evict when at capacity
+    insertion_order: VecDeque<HashId>,
+}
+
+impl GraphNodeMetadataCache {
+    fn get(&self, hash: HashId) -> Option<&GraphNodeMetadata> {
+        self.entries.get(&hash)
+    }
+    fn entry(&mut self, hash: HashId) -> &mut GraphNodeMetadata {
+        if !self.entries.contains_key(&hash) {
+            while self.entries.len() >= GRAPH_NODE_METADATA_CACHE_CAPACITY {
+                match self.insertion_order.pop_front() {
+                    Some(evicted_hash) => {
+                        self.entries.remove(&evicted_hash);
+                    }
+                    None => break,
+                }
+            }
+            self.insertion_order.push_back(hash);
+        }
+        self.entries.entry(hash).or_default()
+    }
+}
+
+thread_local! {
+    static GRAPH_NODE_METADATA_CACHE: RefCell<GraphNodeMetadataCache> =
+        RefCell::new(GraphNodeMetadataCache::default());
+}
+
+pub(crate) fn cached_size(hash: HashId, compute: impl FnOnce() -> usize) -> usize {
+    if let Some(value) = get_metadata(hash, |metadata| metadata.size) {
+        return value;
+    }
+    // The cache must not be borrowed while computing the value, as the computation will typically query the metadata of
+    // the term's children
+    let value = compute();
+    set_metadata(hash, |metadata| metadata.size = Some(value));
+    value
+}
+
+pub(crate) fn get_capture_depth(hash: HashId) -> Option<StackOffset> {
+    get_metadata(hash, |metadata| metadata.capture_depth)
+}
+
+pub(crate) fn set_capture_depth(hash: HashId, value: StackOffset) {
+    set_metadata(hash, |metadata| metadata.capture_depth = Some(value))
+}
+
+pub(crate) fn get_free_variables(hash: HashId) -> Option<Rc<HashSet<StackOffset>>> {
+    get_metadata(hash, |metadata| metadata.free_variables.clone())
+}
+
+pub(crate) fn set_free_variables(hash: HashId, value: Rc<HashSet<StackOffset>>) {
+    set_metadata(hash, |metadata| metadata.free_variables = Some(value))
+}
+
+fn get_metadata<V>(hash: HashId, get: impl FnOnce(&GraphNodeMetadata) -> Option<V>) -> Option<V> {
+    GRAPH_NODE_METADATA_CACHE.with(|cache| cache.borrow().get(hash).and_then(get))
+}
+
+fn set_metadata(hash: HashId, set: impl FnOnce(&mut GraphNodeMetadata)) {
+    GRAPH_NODE_METADATA_CACHE.with(|cache| set(cache.borrow_mut().entry(hash)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn graph_node_metadata_cache() {
+        let hash = 0x1234_5678_9abc_def0;
+        assert_eq!(cached_size(hash, || 3), 3);
+        assert_eq!(cached_size(hash, || unreachable!()), 3);
+        assert_eq!(get_capture_depth(hash), None);
+        set_capture_depth(hash, 2);
+        assert_eq!(get_capture_depth(hash), Some(2));
+        assert_eq!(get_free_variables(hash), None);
+        set_free_variables(hash, Rc::new(HashSet::from([0, 1])));
+        let free_variables = get_free_variables(hash).unwrap();
+        assert_eq!(*free_variables, HashSet::from([0, 1]));
+        assert!(Rc::ptr_eq(
+            &free_variables,
+            &get_free_variables(hash).unwrap()
+        ));
+        assert_eq!(cached_size(hash + 1, || 5), 5);
+    }
+
+    #[test]
+    fn graph_node_metadata_cache_eviction() {
+        let mut cache = GraphNodeMetadataCache::default();
+        for hash in 0..(GRAPH_NODE_METADATA_CACHE_CAPACITY as HashId) {
+            cache.entry(hash).size = Some(hash as usize);
+        }
+        cache.entry(0).capture_depth = Some(1);
+        assert_eq!(cache.entries.len(), GRAPH_NODE_METADATA_CACHE_CAPACITY);
+        cache
+            .entry(GRAPH_NODE_METADATA_CACHE_CAPACITY as HashId)
+            .size = Some(0);
+        assert_eq!(cache.entries.len(), GRAPH_NODE_METADATA_CACHE_CAPACITY);
+        assert!(cache.get(0).is_none());
+        assert_eq!(cache.get(1).and_then(|metadata| metadata.size), Some(1));
+        assert_eq!(
+            cache
+                .get(GRAPH_NODE_METADATA_CACHE_CAPACITY as HashId)
+                .and_then(|metadata| metadata.size),
+            Some(0)
+        );
+    }
+}
@@ -24,11 +24,14 @@ use crate::{
 };
 
 pub mod application;
+pub mod big_int;
 pub mod boolean;
 pub mod builtin;
+pub mod bytes;
 pub mod cell;
 pub mod condition;
 pub mod constructor;
+pub mod duration;
 pub mod effect;
 pub mod float;
 pub mod hashmap;
@@ -39,10 +42,15 @@ pub mod lambda;
 pub mod lazy_result;
 pub mod r#let;
 pub mod list;
+pub mod list_rope;
+pub mod list_view;
 pub mod nil;
 pub mod partial;
 pub mod pointer;
 pub mod record;
+pub mod recursive;
+pub mod regex;
+pub mod sensitive;
 pub mod signal;
 pub mod string;
 pub mod symbol;
@@ -50,12 +58,18 @@ pub mod timestamp;
 pub mod tree;
 pub mod variable;
 
+mod metadata;
+mod traversal;
+
 pub use application::*;
+pub use big_int::*;
 pub use boolean::*;
 pub use builtin::*;
+pub use bytes::*;
 pub use cell::*;
 pub use condition::*;
 pub use constructor::*;
+pub use duration::*;
 pub use effect::*;
 pub use float::*;
 pub use hashmap::*;
@@ -65,11 +79,16 @@ pub use iterator::*;
 pub use lambda::*;
 pub use lazy_result::*;
 pub use list::*;
+pub use list_rope::*;
+pub use list_view::*;
 pub use nil::*;
 pub use partial::*;
 pub use pointer::*;
 pub use r#let::*;
 pub use record::*;
+pub use recursive::*;
+pub use regex::*;
+pub use sensitive::*;
 pub use signal::*;
 pub use string::*;
 pub use symbol::*;
@@ -80,14 +99,18 @@ pub use variable::*;
 const TERM_TYPE_DISCRIMINANT_SIZE: usize = std::mem::size_of::<u32>();
 
 #[derive(Clone, Copy, Debug, EnumDiscriminants)]
+#[strum_discriminants(derive(Hash, PartialOrd, Ord, serde::Serialize))]
 #[repr(C)]
 pub enum TermType {
     Application(ApplicationTerm),
+    BigInt(BigIntTerm),
     Boolean(BooleanTerm),
     Builtin(BuiltinTerm),
+    Bytes(BytesTerm),
     Cell(CellTerm),
     Condition(ConditionTerm),
     Constructor(ConstructorTerm),
+    Duration(DurationTerm),
     Effect(EffectTerm),
     Float(FloatTerm),
     Hashmap(HashmapTerm),
@@ -101,12 +124,15 @@ pub enum TermType {
     Partial(PartialTerm),
     Pointer(PointerTerm),
     Record(RecordTerm),
+    Recursive(RecursiveTerm),
+    Regex(RegexTerm),
     Signal(SignalTerm),
     String(StringTerm),
     Symbol(SymbolTerm),
     Timestamp(TimestampTerm),
     Tree(TreeTerm),
     Variable(VariableTerm),
+    DedupeIterator(DedupeIteratorTerm),
     EmptyIterator(EmptyIteratorTerm),
     EvaluateIterator(EvaluateIteratorTerm),
     FilterIterator(FilterIteratorTerm),
@@ -123,6 +149,9 @@ pub enum TermType {
     SkipIterator(SkipIteratorTerm),
     TakeIterator(TakeIteratorTerm),
     ZipIterator(ZipIteratorTerm),
+    ListView(ListViewTerm),
+    ListRope(ListRopeTerm),
+    Sensitive(SensitiveTerm),
 }
 
 impl TryFrom<u32> for TermTypeDiscriminants {
@@ -130,11 +159,14 @@ impl TryFrom<u32> for TermTypeDiscriminants {
     fn try_from(value: u32) -> Result<Self, Self::Error> {
         match value {
             value if value == Self::Application as u32 => Ok(Self::Application),
+            value if value == Self::BigInt as u32 => Ok(Self::BigInt),
             value if value == Self::Boolean as u32 => Ok(Self::Boolean),
             value if value == Self::Builtin as u32 => Ok(Self::Builtin),
+            value if value == Self::Bytes as u32 => Ok(Self::Bytes),
             value if value == Self::Cell as u32 => Ok(Self::Cell),
             value if value == Self::Condition as u32 => Ok(Self::Condition),
             value if value == Self::Constructor as u32 => Ok(Self::Constructor),
+            value if value == Self::Duration as u32 => Ok(Self::Duration),
             value if value == Self::Effect as u32 => Ok(Self::Effect),
             value if value == Self::Float as u32 => Ok(Self::Float),
             value if value == Self::Hashmap as u32 => Ok(Self::Hashmap),
@@ -148,12 +180,15 @@ impl TryFrom<u32> for TermTypeDiscriminants {
             value if value == Self::Partial as u32 => Ok(Self::Partial),
             value if value == Self::Pointer as u32 => Ok(Self::Pointer),
             value if value == Self::Record as u32 => Ok(Self::Record),
+            value if value == Self::Recursive as u32 => Ok(Self::Recursive),
+            value if value == Self::Regex as u32 => Ok(Self::Regex),
             value if value == Self::Signal as u32 => Ok(Self::Signal),
             value if value == Self::String as u32 => Ok(Self::String),
             value if value == Self::Symbol as u32 => Ok(Self::Symbol),
             value if value == Self::Timestamp as u32 => Ok(Self::Timestamp),
             value if value == Self::Tree as u32 => Ok(Self::Tree),
             value if value == Self::Variable as u32 => Ok(Self::Variable),
+            value if value == Self::DedupeIterator as u32 => Ok(Self::DedupeIterator),
             value if value == Self::EmptyIterator as u32 => Ok(Self::EmptyIterator),
             value if value == Self::EvaluateIterator as u32 => Ok(Self::EvaluateIterator),
             value if value == Self::FilterIterator as u32 => Ok(Self::FilterIterator),
@@ -172,6 +207,9 @@ impl TryFrom<u32> for TermTypeDiscriminants {
             value if value == Self::SkipIterator as u32 => Ok(Self::SkipIterator),
             value if value == Self::TakeIterator as u32 => Ok(Self::TakeIterator),
             value if value == Self::ZipIterator as u32 => Ok(Self::ZipIterator),
+            value if value == Self::ListView as u32 => Ok(Self::ListView),
+            value if value == Self::ListRope as u32 => Ok(Self::ListRope),
+            value if value == Self::Sensitive as u32 => Ok(Self::Sensitive),
             _ => Err(()),
         }
     }
@@ -182,11 +220,14 @@ impl TermSize for TermType {
         let discriminant_size = TERM_TYPE_DISCRIMINANT_SIZE;
         let value_size = match self {
             Self::Application(term) => term.size_of(),
+            Self::BigInt(term) => term.size_of(),
             Self::Boolean(term) => term.size_of(),
             Self::Builtin(term) => term.size_of(),
+            Self::Bytes(term) => term.size_of(),
             Self::Cell(term) => term.size_of(),
             Self::Condition(term) => term.size_of(),
             Self::Constructor(term) => term.size_of(),
+            Self::Duration(term) => term.size_of(),
             Self::Effect(term) => term.size_of(),
             Self::Float(term) => term.size_of(),
             Self::Hashmap(term) => term.size_of(),
@@ -200,12 +241,15 @@ impl TermSize for TermType {
             Self::Partial(term) => term.size_of(),
             Self::Pointer(term) => term.size_of(),
             Self::Record(term) => term.size_of(),
+            Self::Recursive(term) => term.size_of(),
+            Self::Regex(term) => term.size_of(),
             Self::Signal(term) => term.size_of(),
             Self::String(term) => term.size_of(),
             Self::Symbol(term) => term.size_of(),
             Self::Timestamp(term) => term.size_of(),
             Self::Tree(term) => term.size_of(),
             Self::Variable(term) => term.size_of(),
+            Self::DedupeIterator(term) => term.size_of(),
             Self::EmptyIterator(term) => term.size_of(),
             Self::EvaluateIterator(term) => term.size_of(),
             Self::FilterIterator(term) => term.size_of(),
@@ -222,6 +266,9 @@ impl TermSize for TermType {
             Self::SkipIterator(term) => term.size_of(),
             Self::TakeIterator(term) => term.size_of(),
             Self::ZipIterator(term) => term.size_of(),
+            Self::ListView(term) => term.size_of(),
+            Self::ListRope(term) => term.size_of(),
+            Self::Sensitive(term) => term.size_of(),
         };
         discriminant_size + value_size
     }
@@ -233,12 +280,18 @@ impl TermHash for TermType {
             Self::Application(term) => hasher
                 .write_u8(TermTypeDiscriminants::Application as u8)
                 .hash(term, arena),
+            Self::BigInt(term) => hasher
+                .write_u8(TermTypeDiscriminants::BigInt as u8)
+                .hash(term, arena),
             Self::Boolean(term) => hasher
                 .write_u8(TermTypeDiscriminants::Boolean as u8)
                 .hash(term, arena),
             Self::Builtin(term) => hasher
                 .write_u8(TermTypeDiscriminants::Builtin as u8)
                 .hash(term, arena),
+            Self::Bytes(term) => hasher
+                .write_u8(TermTypeDiscriminants::Bytes as u8)
+                .hash(term, arena),
             Self::Cell(term) => hasher
                 .write_u8(TermTypeDiscriminants::Cell as u8)
                 .hash(term, arena),
@@ -248,6 +301,9 @@ impl TermHash for TermType {
             Self::Constructor(term) => hasher
                 .write_u8(TermTypeDiscriminants::Constructor as u8)
                 .hash(term, arena),
+            Self::Duration(term) => hasher
+                .write_u8(TermTypeDiscriminants::Duration as u8)
+                .hash(term, arena),
             Self::Effect(term) => hasher
                 .write_u8(TermTypeDiscriminants::Effect as u8)
                 .hash(term, arena),
@@ -287,6 +343,12 @@ impl TermHash for TermType {
             Self::Record(term) => hasher
                 .write_u8(TermTypeDiscriminants::Record as u8)
                 .hash(term, arena),
+            Self::Recursive(term) => hasher
+                .write_u8(TermTypeDiscriminants::Recursive as u8)
+                .hash(term, arena),
+            Self::Regex(term) => hasher
+                .write_u8(TermTypeDiscriminants::Regex as u8)
+                .hash(term, arena),
             Self::Signal(term) => hasher
                 .write_u8(TermTypeDiscriminants::Signal as u8)
                 .hash(term, arena),
@@ -305,6 +367,9 @@ impl TermHash for TermType {
             Self::Variable(term) => hasher
                 .write_u8(TermTypeDiscriminants::Variable as u8)
                 .hash(term, arena),
+            Self::DedupeIterator(term) => hasher
+                .write_u8(TermTypeDiscriminants::DedupeIterator as u8)
+                .hash(term, arena),
             Self::EmptyIterator(term) => hasher
                 .write_u8(TermTypeDiscriminants::EmptyIterator as u8)
                 .hash(term, arena),
@@ -353,6 +418,16 @@ impl TermHash for TermType {
             Self::ZipIterator(term) => hasher
                 .write_u8(TermTypeDiscriminants::ZipIterator as u8)
                 .hash(term, arena),
+            // List views and list ropes are hashed identically to the equivalent flat list
+            Self::ListView(term) => hasher
+                .write_u8(TermTypeDiscriminants::List as u8)
+                .hash(term, arena),
+            Self::ListRope(term) => hasher
+                .write_u8(TermTypeDiscriminants::List as u8)
+                .hash(term, arena),
+            Self::Sensitive(term) => hasher
+                .write_u8(TermTypeDiscriminants::Sensitive as u8)
+                .hash(term, arena),
         }
     }
 }
@@ -360,11 +435,14 @@ impl TermHash for TermType {
 #[derive(Debug, Clone)]
 pub enum TermPointerIterator {
     Application(ApplicationTermPointerIter),
+    BigInt(BigIntTermPointerIter),
     Boolean(BooleanTermPointerIter),
     Builtin(BuiltinTermPointerIter),
+    Bytes(BytesTermPointerIter),
     Cell(CellTermPointerIter),
     Condition(ConditionTermPointerIter),
     Constructor(ConstructorTermPointerIter),
+    Duration(DurationTermPointerIter),
     Effect(EffectTermPointerIter),
     Float(FloatTermPointerIter),
     Hashmap(HashmapTermPointerIter),
@@ -378,12 +456,15 @@ pub enum TermPointerIterator {
     Partial(PartialTermPointerIter),
     Pointer(PointerTermPointerIter),
     Record(RecordTermPointerIter),
+    Recursive(RecursiveTermPointerIter),
+    Regex(RegexTermPointerIter),
     Signal(SignalTermPointerIter),
     String(StringTermPointerIter),
     Symbol(SymbolTermPointerIter),
     Timestamp(TimestampTermPointerIter),
     Tree(TreeTermPointerIter),
     Variable(VariableTermPointerIter),
+    DedupeIterator(DedupeIteratorTermPointerIter),
     EmptyIterator(EmptyIteratorTermPointerIter),
     EvaluateIterator(EvaluateIteratorTermPointerIter),
     FilterIterator(FilterIteratorTermPointerIter),
@@ -400,6 +481,9 @@ pub enum TermPointerIterator {
     SkipIterator(SkipIteratorTermPointerIter),
     TakeIterator(TakeIteratorTermPointerIter),
     ZipIterator(ZipIteratorTermPointerIter),
+    ListView(ListViewTermPointerIter),
+    ListRope(ListRopeTermPointerIter),
+    Sensitive(SensitiveTermPointerIter),
 }
 
 impl Iterator for TermPointerIterator {
@@ -407,11 +491,14 @@ impl Iterator for TermPointerIterator {
     fn next(&mut self) -> Option<Self::Item> {
         match self {
             Self::Application(inner) => inner.next(),
+            Self::BigInt(inner) => inner.next(),
             Self::Boolean(inner) => inner.next(),
             Self::Builtin(inner) => inner.next(),
+            Self::Bytes(inner) => inner.next(),
             Self::Cell(inner) => inner.next(),
             Self::Condition(inner) => inner.next(),
             Self::Constructor(inner) => inner.next(),
+            Self::Duration(inner) => inner.next(),
             Self::Effect(inner) => inner.next(),
             Self::Float(inner) => inner.next(),
             Self::Hashmap(inner) => inner.next(),
@@ -425,12 +512,15 @@ impl Iterator for TermPointerIterator {
             Self::Partial(inner) => inner.next(),
             Self::Pointer(inner) => inner.next(),
             Self::Record(inner) => inner.next(),
+            Self::Recursive(inner) => inner.next(),
+            Self::Regex(inner) => inner.next(),
             Self::Signal(inner) => inner.next(),
             Self::String(inner) => inner.next(),
             Self::Symbol(inner) => inner.next(),
             Self::Timestamp(inner) => inner.next(),
             Self::Tree(inner) => inner.next(),
             Self::Variable(inner) => inner.next(),
+            Self::DedupeIterator(inner) => inner.next(),
             Self::EmptyIterator(inner) => inner.next(),
             Self::EvaluateIterator(inner) => inner.next(),
             Self::FilterIterator(inner) => inner.next(),
@@ -447,6 +537,9 @@ impl Iterator for TermPointerIterator {
             Self::SkipIterator(inner) => inner.next(),
             Self::TakeIterator(inner) => inner.next(),
             Self::ZipIterator(inner) => inner.next(),
+            Self::ListView(inner) => inner.next(),
+            Self::ListRope(inner) => inner.next(),
+            Self::Sensitive(inner) => inner.next(),
         }
     }
 }
@@ -461,6 +554,9 @@ impl<A: Arena + Clone> Visitable<ArenaPointer> for ArenaRef<Term, A> {
                     .as_inner()
                     .children(),
             ),
+            TermTypeDiscriminants::BigInt => TermPointerIterator::BigInt(
+                Visitable::<ArenaPointer>::children(&self.as_typed_term::<BigIntTerm>().as_inner()),
+            ),
             TermTypeDiscriminants::Boolean => {
                 TermPointerIterator::Boolean(Visitable::<ArenaPointer>::children(
                     &self.as_typed_term::<BooleanTerm>().as_inner(),
@@ -471,6 +567,9 @@ impl<A: Arena + Clone> Visitable<ArenaPointer> for ArenaRef<Term, A> {
                     &self.as_typed_term::<BuiltinTerm>().as_inner(),
                 ))
             }
+            TermTypeDiscriminants::Bytes => TermPointerIterator::Bytes(
+                Visitable::<ArenaPointer>::children(&self.as_typed_term::<BytesTerm>().as_inner()),
+            ),
             TermTypeDiscriminants::Cell => TermPointerIterator::Cell(
                 Visitable::<ArenaPointer>::children(&self.as_typed_term::<CellTerm>().as_inner()),
             ),
@@ -484,6 +583,11 @@ impl<A: Arena + Clone> Visitable<ArenaPointer> for ArenaRef<Term, A> {
                     &self.as_typed_term::<ConstructorTerm>().as_inner(),
                 ))
             }
+            TermTypeDiscriminants::Duration => {
+                TermPointerIterator::Duration(Visitable::<ArenaPointer>::children(
+                    &self.as_typed_term::<DurationTerm>().as_inner(),
+                ))
+            }
             TermTypeDiscriminants::Effect => TermPointerIterator::Effect(
                 Visitable::<ArenaPointer>::children(&self.as_typed_term::<EffectTerm>().as_inner()),
             ),
@@ -533,6 +637,16 @@ impl<A: Arena + Clone> Visitable<ArenaPointer> for ArenaRef<Term, A> {
             TermTypeDiscriminants::Record => TermPointerIterator::Record(
                 Visitable::<ArenaPointer>::children(&self.as_typed_term::<RecordTerm>().as_inner()),
             ),
+            TermTypeDiscriminants::Recursive => {
+                TermPointerIterator::Recursive(Visitable::<ArenaPointer>::children(
+                    &self.as_typed_term::<RecursiveTerm>().as_inner(),
+                ))
+            }
+            TermTypeDiscriminants::Regex => {
+                TermPointerIterator::Regex(Visitable::<ArenaPointer>::children(
+                    &self.as_typed_term::<RegexTerm>().as_inner(),
+                ))
+            }
             TermTypeDiscriminants::Signal => TermPointerIterator::Signal(
                 Visitable::<ArenaPointer>::children(&self.as_typed_term::<SignalTerm>().as_inner()),
             ),
@@ -555,6 +669,11 @@ impl<A: Arena + Clone> Visitable<ArenaPointer> for ArenaRef<Term, A> {
                     &self.as_typed_term::<VariableTerm>().as_inner(),
                 ))
             }
+            TermTypeDiscriminants::DedupeIterator => {
+                TermPointerIterator::DedupeIterator(Visitable::<ArenaPointer>::children(
+                    &self.as_typed_term::<DedupeIteratorTerm>().as_inner(),
+                ))
+            }
             TermTypeDiscriminants::EmptyIterator => {
                 TermPointerIterator::EmptyIterator(Visitable::<ArenaPointer>::children(
                     &self.as_typed_term::<EmptyIteratorTerm>().as_inner(),
@@ -637,6 +756,21 @@ impl<A: Arena + Clone> Visitable<ArenaPointer> for ArenaRef<Term, A> {
                     &self.as_typed_term::<ZipIteratorTerm>().as_inner(),
                 ))
             }
+            TermTypeDiscriminants::ListView => {
+                TermPointerIterator::ListView(Visitable::<ArenaPointer>::children(
+                    &self.as_typed_term::<ListViewTerm>().as_inner(),
+                ))
+            }
+            TermTypeDiscriminants::ListRope => {
+                TermPointerIterator::ListRope(Visitable::<ArenaPointer>::children(
+                    &self.as_typed_term::<ListRopeTerm>().as_inner(),
+                ))
+            }
+            TermTypeDiscriminants::Sensitive => {
+                TermPointerIterator::Sensitive(Visitable::<ArenaPointer>::children(
+                    &self.as_typed_term::<SensitiveTerm>().as_inner(),
+                ))
+            }
         }
     }
 }
@@ -648,6 +782,10 @@ impl<A: Arena + Clone> Internable for ArenaRef<Term, A> {
                 .as_typed_term::<ApplicationTerm>()
                 .as_inner()
                 .should_intern(eager),
+            TermTypeDiscriminants::BigInt => self
+                .as_typed_term::<BigIntTerm>()
+                .as_inner()
+                .should_intern(eager),
             TermTypeDiscriminants::Boolean => self
                 .as_typed_term::<BooleanTerm>()
                 .as_inner()
@@ -656,6 +794,10 @@ impl<A: Arena + Clone> Internable for ArenaRef<Term, A> {
                 .as_typed_term::<BuiltinTerm>()
                 .as_inner()
                 .should_intern(eager),
+            TermTypeDiscriminants::Bytes => self
+                .as_typed_term::<BytesTerm>()
+                .as_inner()
+                .should_intern(eager),
             TermTypeDiscriminants::Cell => self
                 .as_typed_term::<CellTerm>()
                 .as_inner()
@@ -668,6 +810,10 @@ impl<A: Arena + Clone> Internable for ArenaRef<Term, A> {
                 .as_typed_term::<ConstructorTerm>()
                 .as_inner()
                 .should_intern(eager),
+            TermTypeDiscriminants::Duration => self
+                .as_typed_term::<DurationTerm>()
+                .as_inner()
+                .should_intern(eager),
             TermTypeDiscriminants::Effect => self
                 .as_typed_term::<EffectTerm>()
                 .as_inner()
@@ -720,6 +866,14 @@ impl<A: Arena + Clone> Internable for ArenaRef<Term, A> {
                 .as_typed_term::<RecordTerm>()
                 .as_inner()
                 .should_intern(eager),
+            TermTypeDiscriminants::Recursive => self
+                .as_typed_term::<RecursiveTerm>()
+                .as_inner()
+                .should_intern(eager),
+            TermTypeDiscriminants::Regex => self
+                .as_typed_term::<RegexTerm>()
+                .as_inner()
+                .should_intern(eager),
             TermTypeDiscriminants::Signal => self
                 .as_typed_term::<SignalTerm>()
                 .as_inner()
@@ -744,6 +898,10 @@ impl<A: Arena + Clone> Internable for ArenaRef<Term, A> {
                 .as_typed_term::<VariableTerm>()
                 .as_inner()
                 .should_intern(eager),
+            TermTypeDiscriminants::DedupeIterator => self
+                .as_typed_term::<DedupeIteratorTerm>()
+                .as_inner()
+                .should_intern(eager),
             TermTypeDiscriminants::EmptyIterator => self
                 .as_typed_term::<EmptyIteratorTerm>()
                 .as_inner()
@@ -808,6 +966,18 @@ impl<A: Arena + Clone> Internable for ArenaRef<Term, A> {
                 .as_typed_term::<ZipIteratorTerm>()
                 .as_inner()
                 .should_intern(eager),
+            TermTypeDiscriminants::ListView => self
+                .as_typed_term::<ListViewTerm>()
+                .as_inner()
+                .should_intern(eager),
+            TermTypeDiscriminants::ListRope => self
+                .as_typed_term::<ListRopeTerm>()
+                .as_inner()
+                .should_intern(eager),
+            TermTypeDiscriminants::Sensitive => self
+                .as_typed_term::<SensitiveTerm>()
+                .as_inner()
+                .should_intern(eager),
         }
     }
 }
@@ -828,6 +998,7 @@ impl<A: Arena + Clone> IteratorExpression for WasmExpression<A> {
 
 #[derive(Clone, Copy, Debug)]
 pub enum WasmIteratorTerm<A: Arena + Clone> {
+    DedupeIterator(ArenaRef<DedupeIteratorTerm, A>),
     EmptyIterator(ArenaRef<EmptyIteratorTerm, A>),
     EvaluateIterator(ArenaRef<EvaluateIteratorTerm, A>),
     FilterIterator(ArenaRef<FilterIteratorTerm, A>),
@@ -858,6 +1029,10 @@ where
         expression: &'a ArenaRef<Term, Self>,
     ) -> Option<<ArenaRef<Term, Self> as IteratorExpression>::IteratorTerm<'a>> {
         match expression.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::DedupeIterator => expression
+                .as_dedupe_iterator_term()
+                .map(|term| term.as_inner())
+                .map(WasmIteratorTerm::DedupeIterator),
             TermTypeDiscriminants::EmptyIterator => expression
                 .as_empty_iterator_term()
                 .map(|term| term.as_inner())
@@ -935,6 +1110,14 @@ impl<'a> Into<Option<&'a ApplicationTerm>> for &'a TermType {
         }
     }
 }
+impl<'a> Into<Option<&'a BigIntTerm>> for &'a TermType {
+    fn into(self) -> Option<&'a BigIntTerm> {
+        match self {
+            TermType::BigInt(term) => Some(term),
+            _ => None,
+        }
+    }
+}
 impl<'a> Into<Option<&'a BooleanTerm>> for &'a TermType {
     fn into(self) -> Option<&'a BooleanTerm> {
         match self {
@@ -951,6 +1134,14 @@ impl<'a> Into<Option<&'a BuiltinTerm>> for &'a TermType {
         }
     }
 }
+impl<'a> Into<Option<&'a BytesTerm>> for &'a TermType {
+    fn into(self) -> Option<&'a BytesTerm> {
+        match self {
+            TermType::Bytes(term) => Some(term),
+            _ => None,
+        }
+    }
+}
 impl<'a> Into<Option<&'a CellTerm>> for &'a TermType {
     fn into(self) -> Option<&'a CellTerm> {
         match self {
@@ -975,6 +1166,14 @@ impl<'a> Into<Option<&'a ConstructorTerm>> for &'a TermType {
         }
     }
 }
+impl<'a> Into<Option<&'a DurationTerm>> for &'a TermType {
+    fn into(self) -> Option<&'a DurationTerm> {
+        match self {
+            TermType::Duration(term) => Some(term),
+            _ => None,
+        }
+    }
+}
 impl<'a> Into<Option<&'a EffectTerm>> for &'a TermType {
     fn into(self) -> Option<&'a EffectTerm> {
         match self {
@@ -1079,6 +1278,22 @@ impl<'a> Into<Option<&'a RecordTerm>> for &'a TermType {
         }
     }
 }
+impl<'a> Into<Option<&'a RecursiveTerm>> for &'a TermType {
+    fn into(self) -> Option<&'a RecursiveTerm> {
+        match self {
+            TermType::Recursive(term) => Some(term),
+            _ => None,
+        }
+    }
+}
+impl<'a> Into<Option<&'a RegexTerm>> for &'a TermType {
+    fn into(self) -> Option<&'a RegexTerm> {
+        match self {
+            TermType::Regex(term) => Some(term),
+            _ => None,
+        }
+    }
+}
 impl<'a> Into<Option<&'a SignalTerm>> for &'a TermType {
     fn into(self) -> Option<&'a SignalTerm> {
         match self {
@@ -1127,6 +1342,14 @@ impl<'a> Into<Option<&'a VariableTerm>> for &'a TermType {
         }
     }
 }
+impl<'a> Into<Option<&'a DedupeIteratorTerm>> for &'a TermType {
+    fn into(self) -> Option<&'a DedupeIteratorTerm> {
+        match self {
+            TermType::DedupeIterator(term) => Some(term),
+            _ => None,
+        }
+    }
+}
 impl<'a> Into<Option<&'a EmptyIteratorTerm>> for &'a TermType {
     fn into(self) -> Option<&'a EmptyIteratorTerm> {
         match self {
@@ -1255,6 +1478,30 @@ impl<'a> Into<Option<&'a ZipIteratorTerm>> for &'a TermType {
         }
     }
 }
+impl<'a> Into<Option<&'a ListViewTerm>> for &'a TermType {
+    fn into(self) -> Option<&'a ListViewTerm> {
+        match self {
+            TermType::ListView(term) => Some(term),
+            _ => None,
+        }
+    }
+}
+impl<'a> Into<Option<&'a ListRopeTerm>> for &'a TermType {
+    fn into(self) -> Option<&'a ListRopeTerm> {
+        match self {
+            TermType::ListRope(term) => Some(term),
+            _ => None,
+        }
+    }
+}
+impl<'a> Into<Option<&'a SensitiveTerm>> for &'a TermType {
+    fn into(self) -> Option<&'a SensitiveTerm> {
+        match self {
+            TermType::Sensitive(term) => Some(term),
+            _ => None,
+        }
+    }
+}
 
 impl<A: Arena + Clone> ArenaRef<Term, A> {
     pub fn arity(&self) -> Option<Arity> {
@@ -1289,6 +1536,10 @@ impl<A: Arena + Clone> PartialEq for ArenaRef<Term, A> {
                 self.as_typed_term::<ApplicationTerm>().as_inner()
                     == other.as_typed_term::<ApplicationTerm>().as_inner()
             }
+            (TermTypeDiscriminants::BigInt, TermTypeDiscriminants::BigInt) => {
+                self.as_typed_term::<BigIntTerm>().as_inner()
+                    == other.as_typed_term::<BigIntTerm>().as_inner()
+            }
             (TermTypeDiscriminants::Boolean, TermTypeDiscriminants::Boolean) => {
                 self.as_typed_term::<BooleanTerm>().as_inner()
                     == other.as_typed_term::<BooleanTerm>().as_inner()
@@ -1297,6 +1548,10 @@ impl<A: Arena + Clone> PartialEq for ArenaRef<Term, A> {
                 self.as_typed_term::<BuiltinTerm>().as_inner()
                     == other.as_typed_term::<BuiltinTerm>().as_inner()
             }
+            (TermTypeDiscriminants::Bytes, TermTypeDiscriminants::Bytes) => {
+                self.as_typed_term::<BytesTerm>().as_inner()
+                    == other.as_typed_term::<BytesTerm>().as_inner()
+            }
             (TermTypeDiscriminants::Cell, TermTypeDiscriminants::Cell) => {
                 self.as_typed_term::<CellTerm>().as_inner()
                     == other.as_typed_term::<CellTerm>().as_inner()
@@ -1309,6 +1564,10 @@ impl<A: Arena + Clone> PartialEq for ArenaRef<Term, A> {
                 self.as_typed_term::<ConstructorTerm>().as_inner()
                     == other.as_typed_term::<ConstructorTerm>().as_inner()
             }
+            (TermTypeDiscriminants::Duration, TermTypeDiscriminants::Duration) => {
+                self.as_typed_term::<DurationTerm>().as_inner()
+                    == other.as_typed_term::<DurationTerm>().as_inner()
+            }
             (TermTypeDiscriminants::Effect, TermTypeDiscriminants::Effect) => {
                 self.as_typed_term::<EffectTerm>().as_inner()
                     == other.as_typed_term::<EffectTerm>().as_inner()
@@ -1361,6 +1620,14 @@ impl<A: Arena + Clone> PartialEq for ArenaRef<Term, A> {
                 self.as_typed_term::<RecordTerm>().as_inner()
                     == other.as_typed_term::<RecordTerm>().as_inner()
             }
+            (TermTypeDiscriminants::Recursive, TermTypeDiscriminants::Recursive) => {
+                self.as_typed_term::<RecursiveTerm>().as_inner()
+                    == other.as_typed_term::<RecursiveTerm>().as_inner()
+            }
+            (TermTypeDiscriminants::Regex, TermTypeDiscriminants::Regex) => {
+                self.as_typed_term::<RegexTerm>().as_inner()
+                    == other.as_typed_term::<RegexTerm>().as_inner()
+            }
             (TermTypeDiscriminants::Signal, TermTypeDiscriminants::Signal) => {
                 self.as_typed_term::<SignalTerm>().as_inner()
                     == other.as_typed_term::<SignalTerm>().as_inner()
@@ -1385,6 +1652,10 @@ impl<A: Arena + Clone> PartialEq for ArenaRef<Term, A> {
                 self.as_typed_term::<VariableTerm>().as_inner()
                     == other.as_typed_term::<VariableTerm>().as_inner()
             }
+            (TermTypeDiscriminants::DedupeIterator, TermTypeDiscriminants::DedupeIterator) => {
+                self.as_typed_term::<DedupeIteratorTerm>().as_inner()
+                    == other.as_typed_term::<DedupeIteratorTerm>().as_inner()
+            }
             (TermTypeDiscriminants::EmptyIterator, TermTypeDiscriminants::EmptyIterator) => {
                 self.as_typed_term::<EmptyIteratorTerm>().as_inner()
                     == other.as_typed_term::<EmptyIteratorTerm>().as_inner()
@@ -1466,6 +1737,18 @@ impl<A: Arena + Clone> PartialEq for ArenaRef<Term, A> {
                 self.as_typed_term::<ZipIteratorTerm>().as_inner()
                     == other.as_typed_term::<ZipIteratorTerm>().as_inner()
             }
+            (TermTypeDiscriminants::ListView, TermTypeDiscriminants::ListView) => {
+                self.as_typed_term::<ListViewTerm>().as_inner()
+                    == other.as_typed_term::<ListViewTerm>().as_inner()
+            }
+            (TermTypeDiscriminants::ListRope, TermTypeDiscriminants::ListRope) => {
+                self.as_typed_term::<ListRopeTerm>().as_inner()
+                    == other.as_typed_term::<ListRopeTerm>().as_inner()
+            }
+            (TermTypeDiscriminants::Sensitive, TermTypeDiscriminants::Sensitive) => {
+                self.as_typed_term::<SensitiveTerm>().as_inner()
+                    == other.as_typed_term::<SensitiveTerm>().as_inner()
+            }
             _ => false,
         }
     }
@@ -1489,6 +1772,8 @@ impl<A: Arena + Clone> Expression for ArenaRef<Term, A> {
     type StringTerm = ArenaRef<TypedTerm<StringTerm>, A>;
     type SymbolTerm = ArenaRef<TypedTerm<SymbolTerm>, A>;
     type TimestampTerm = ArenaRef<TypedTerm<TimestampTerm>, A>;
+    type DurationTerm = ArenaRef<TypedTerm<DurationTerm>, A>;
+    type BytesTerm = ArenaRef<TypedTerm<BytesTerm>, A>;
     type VariableTerm = ArenaRef<TypedTerm<VariableTerm>, A>;
     type EffectTerm = ArenaRef<TypedTerm<EffectTerm>, A>;
     type LetTerm = ArenaRef<TypedTerm<LetTerm>, A>;
@@ -1496,8 +1781,7 @@ impl<A: Arena + Clone> Expression for ArenaRef<Term, A> {
     type LazyResultTerm = ArenaRef<TypedTerm<LazyResultTerm>, A>;
     type ApplicationTerm = ArenaRef<TypedTerm<ApplicationTerm>, A>;
     type PartialApplicationTerm = ArenaRef<TypedTerm<PartialTerm>, A>;
-    // FIXME: implement recursive term type
-    type RecursiveTerm = ArenaRef<TypedTerm<NilTerm>, A>;
+    type RecursiveTerm = ArenaRef<TypedTerm<RecursiveTerm>, A>;
     type BuiltinTerm = ArenaRef<TypedTerm<BuiltinTerm>, A>;
     // FIXME: remove compiled function term
     type CompiledFunctionTerm = ArenaRef<TypedTerm<BuiltinTerm>, A>;
@@ -1506,6 +1790,8 @@ impl<A: Arena + Clone> Expression for ArenaRef<Term, A> {
     type ListTerm = ArenaRef<TypedTerm<ListTerm>, A>;
     type HashmapTerm = ArenaRef<TypedTerm<HashmapTerm>, A>;
     type HashsetTerm = ArenaRef<TypedTerm<HashsetTerm>, A>;
+    type RegexTerm = ArenaRef<TypedTerm<RegexTerm>, A>;
+    type SensitiveTerm = ArenaRef<TypedTerm<SensitiveTerm>, A>;
     type SignalTerm = ArenaRef<TypedTerm<SignalTerm>, A>;
 
     type StringRef<'a> = ArenaRef<TypedTerm<StringTerm>, A> where Self: 'a;
@@ -1518,16 +1804,22 @@ impl<A: Arena + Clone> Expression for ArenaRef<Term, A> {
 
 impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
     fn size(&self) -> usize {
-        match self.read_value(|term| term.type_id()) {
+        metadata::cached_size(self.id(), || match self.read_value(|term| term.type_id()) {
             TermTypeDiscriminants::Application => {
                 GraphNode::size(&self.as_typed_term::<ApplicationTerm>().as_inner())
             }
+            TermTypeDiscriminants::BigInt => {
+                GraphNode::size(&self.as_typed_term::<BigIntTerm>().as_inner())
+            }
             TermTypeDiscriminants::Boolean => {
                 GraphNode::size(&self.as_typed_term::<BooleanTerm>().as_inner())
             }
             TermTypeDiscriminants::Builtin => {
                 GraphNode::size(&self.as_typed_term::<BuiltinTerm>().as_inner())
             }
+            TermTypeDiscriminants::Bytes => {
+                GraphNode::size(&self.as_typed_term::<BytesTerm>().as_inner())
+            }
             TermTypeDiscriminants::Cell => {
                 GraphNode::size(&self.as_typed_term::<CellTerm>().as_inner())
             }
@@ -1537,6 +1829,9 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
             TermTypeDiscriminants::Constructor => {
                 GraphNode::size(&self.as_typed_term::<ConstructorTerm>().as_inner())
             }
+            TermTypeDiscriminants::Duration => {
+                GraphNode::size(&self.as_typed_term::<DurationTerm>().as_inner())
+            }
             TermTypeDiscriminants::Effect => {
                 GraphNode::size(&self.as_typed_term::<EffectTerm>().as_inner())
             }
@@ -1576,6 +1871,12 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
             TermTypeDiscriminants::Record => {
                 GraphNode::size(&self.as_typed_term::<RecordTerm>().as_inner())
             }
+            TermTypeDiscriminants::Recursive => {
+                GraphNode::size(&self.as_typed_term::<RecursiveTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Regex => {
+                GraphNode::size(&self.as_typed_term::<RegexTerm>().as_inner())
+            }
             TermTypeDiscriminants::Signal => {
                 GraphNode::size(&self.as_typed_term::<SignalTerm>().as_inner())
             }
@@ -1594,6 +1895,9 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
             TermTypeDiscriminants::Variable => {
                 GraphNode::size(&self.as_typed_term::<VariableTerm>().as_inner())
             }
+            TermTypeDiscriminants::DedupeIterator => {
+                GraphNode::size(&self.as_typed_term::<DedupeIteratorTerm>().as_inner())
+            }
             TermTypeDiscriminants::EmptyIterator => {
                 GraphNode::size(&self.as_typed_term::<EmptyIteratorTerm>().as_inner())
             }
@@ -1644,278 +1948,45 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
             TermTypeDiscriminants::ZipIterator => {
                 GraphNode::size(&self.as_typed_term::<ZipIteratorTerm>().as_inner())
             }
-        }
-    }
-    fn capture_depth(&self) -> StackOffset {
-        match self.read_value(|term| term.type_id()) {
-            TermTypeDiscriminants::Application => {
-                GraphNode::capture_depth(&self.as_typed_term::<ApplicationTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Boolean => {
-                GraphNode::capture_depth(&self.as_typed_term::<BooleanTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Builtin => {
-                GraphNode::capture_depth(&self.as_typed_term::<BuiltinTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Cell => {
-                GraphNode::capture_depth(&self.as_typed_term::<CellTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Condition => {
-                GraphNode::capture_depth(&self.as_typed_term::<ConditionTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Constructor => {
-                GraphNode::capture_depth(&self.as_typed_term::<ConstructorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Effect => {
-                GraphNode::capture_depth(&self.as_typed_term::<EffectTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Float => {
-                GraphNode::capture_depth(&self.as_typed_term::<FloatTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Hashmap => {
-                GraphNode::capture_depth(&self.as_typed_term::<HashmapTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Hashset => {
-                GraphNode::capture_depth(&self.as_typed_term::<HashsetTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Int => {
-                GraphNode::capture_depth(&self.as_typed_term::<IntTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Lambda => {
-                GraphNode::capture_depth(&self.as_typed_term::<LambdaTerm>().as_inner())
-            }
-            TermTypeDiscriminants::LazyResult => {
-                GraphNode::capture_depth(&self.as_typed_term::<LazyResultTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Let => {
-                GraphNode::capture_depth(&self.as_typed_term::<LetTerm>().as_inner())
-            }
-            TermTypeDiscriminants::List => {
-                GraphNode::capture_depth(&self.as_typed_term::<ListTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Nil => {
-                GraphNode::capture_depth(&self.as_typed_term::<NilTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Partial => {
-                GraphNode::capture_depth(&self.as_typed_term::<PartialTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Pointer => {
-                GraphNode::capture_depth(&self.as_typed_term::<PointerTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Record => {
-                GraphNode::capture_depth(&self.as_typed_term::<RecordTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Signal => {
-                GraphNode::capture_depth(&self.as_typed_term::<SignalTerm>().as_inner())
-            }
-            TermTypeDiscriminants::String => {
-                GraphNode::capture_depth(&self.as_typed_term::<StringTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Symbol => {
-                GraphNode::capture_depth(&self.as_typed_term::<SymbolTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Timestamp => {
-                GraphNode::capture_depth(&self.as_typed_term::<TimestampTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Tree => {
-                GraphNode::capture_depth(&self.as_typed_term::<TreeTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Variable => {
-                GraphNode::capture_depth(&self.as_typed_term::<VariableTerm>().as_inner())
-            }
-            TermTypeDiscriminants::EmptyIterator => {
-                GraphNode::capture_depth(&self.as_typed_term::<EmptyIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::EvaluateIterator => {
-                GraphNode::capture_depth(&self.as_typed_term::<EvaluateIteratorTerm>().as_inner())
+            TermTypeDiscriminants::ListView => {
+                GraphNode::size(&self.as_typed_term::<ListViewTerm>().as_inner())
             }
-            TermTypeDiscriminants::FilterIterator => {
-                GraphNode::capture_depth(&self.as_typed_term::<FilterIteratorTerm>().as_inner())
+            TermTypeDiscriminants::ListRope => {
+                GraphNode::size(&self.as_typed_term::<ListRopeTerm>().as_inner())
             }
-            TermTypeDiscriminants::FlattenIterator => {
-                GraphNode::capture_depth(&self.as_typed_term::<FlattenIteratorTerm>().as_inner())
+            TermTypeDiscriminants::Sensitive => {
+                GraphNode::size(&self.as_typed_term::<SensitiveTerm>().as_inner())
             }
-            TermTypeDiscriminants::HashmapKeysIterator => GraphNode::capture_depth(
-                &self.as_typed_term::<HashmapKeysIteratorTerm>().as_inner(),
+        })
+    }
+    fn capture_depth(&self) -> StackOffset {
+        traversal::capture_depth(self)
+    }
+    fn free_variables(&self) -> HashSet<StackOffset> {
+        // The trait requires an owned set, so the cached set must be cloned here
+        // (callers within this crate can avoid the copy by using the shared set directly)
+        HashSet::clone(&self.shared_free_variables())
+    }
+    fn count_variable_usages(&self, offset: StackOffset) -> usize {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::Application => GraphNode::count_variable_usages(
+                &self.as_typed_term::<ApplicationTerm>().as_inner(),
+                offset,
             ),
-            TermTypeDiscriminants::HashmapValuesIterator => GraphNode::capture_depth(
-                &self.as_typed_term::<HashmapValuesIteratorTerm>().as_inner(),
+            TermTypeDiscriminants::BigInt => GraphNode::count_variable_usages(
+                &self.as_typed_term::<BigIntTerm>().as_inner(),
+                offset,
             ),
-            TermTypeDiscriminants::IndexedAccessorIterator => GraphNode::capture_depth(
-                &self
-                    .as_typed_term::<IndexedAccessorIteratorTerm>()
-                    .as_inner(),
+            TermTypeDiscriminants::Boolean => GraphNode::count_variable_usages(
+                &self.as_typed_term::<BooleanTerm>().as_inner(),
+                offset,
             ),
-            TermTypeDiscriminants::IntegersIterator => {
-                GraphNode::capture_depth(&self.as_typed_term::<IntegersIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::IntersperseIterator => GraphNode::capture_depth(
-                &self.as_typed_term::<IntersperseIteratorTerm>().as_inner(),
+            TermTypeDiscriminants::Builtin => GraphNode::count_variable_usages(
+                &self.as_typed_term::<BuiltinTerm>().as_inner(),
+                offset,
             ),
-            TermTypeDiscriminants::MapIterator => {
-                GraphNode::capture_depth(&self.as_typed_term::<MapIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::OnceIterator => {
-                GraphNode::capture_depth(&self.as_typed_term::<OnceIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::RangeIterator => {
-                GraphNode::capture_depth(&self.as_typed_term::<RangeIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::RepeatIterator => {
-                GraphNode::capture_depth(&self.as_typed_term::<RepeatIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::SkipIterator => {
-                GraphNode::capture_depth(&self.as_typed_term::<SkipIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::TakeIterator => {
-                GraphNode::capture_depth(&self.as_typed_term::<TakeIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::ZipIterator => {
-                GraphNode::capture_depth(&self.as_typed_term::<ZipIteratorTerm>().as_inner())
-            }
-        }
-    }
-    fn free_variables(&self) -> HashSet<StackOffset> {
-        match self.read_value(|term| term.type_id()) {
-            TermTypeDiscriminants::Application => {
-                GraphNode::free_variables(&self.as_typed_term::<ApplicationTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Boolean => {
-                GraphNode::free_variables(&self.as_typed_term::<BooleanTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Builtin => {
-                GraphNode::free_variables(&self.as_typed_term::<BuiltinTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Cell => {
-                GraphNode::free_variables(&self.as_typed_term::<CellTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Condition => {
-                GraphNode::free_variables(&self.as_typed_term::<ConditionTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Constructor => {
-                GraphNode::free_variables(&self.as_typed_term::<ConstructorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Effect => {
-                GraphNode::free_variables(&self.as_typed_term::<EffectTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Float => {
-                GraphNode::free_variables(&self.as_typed_term::<FloatTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Hashmap => {
-                GraphNode::free_variables(&self.as_typed_term::<HashmapTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Hashset => {
-                GraphNode::free_variables(&self.as_typed_term::<HashsetTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Int => {
-                GraphNode::free_variables(&self.as_typed_term::<IntTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Lambda => {
-                GraphNode::free_variables(&self.as_typed_term::<LambdaTerm>().as_inner())
-            }
-            TermTypeDiscriminants::LazyResult => {
-                GraphNode::free_variables(&self.as_typed_term::<LazyResultTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Let => {
-                GraphNode::free_variables(&self.as_typed_term::<LetTerm>().as_inner())
-            }
-            TermTypeDiscriminants::List => {
-                GraphNode::free_variables(&self.as_typed_term::<ListTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Nil => {
-                GraphNode::free_variables(&self.as_typed_term::<NilTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Partial => {
-                GraphNode::free_variables(&self.as_typed_term::<PartialTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Pointer => {
-                GraphNode::free_variables(&self.as_typed_term::<PointerTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Record => {
-                GraphNode::free_variables(&self.as_typed_term::<RecordTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Signal => {
-                GraphNode::free_variables(&self.as_typed_term::<SignalTerm>().as_inner())
-            }
-            TermTypeDiscriminants::String => {
-                GraphNode::free_variables(&self.as_typed_term::<StringTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Symbol => {
-                GraphNode::free_variables(&self.as_typed_term::<SymbolTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Timestamp => {
-                GraphNode::free_variables(&self.as_typed_term::<TimestampTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Tree => {
-                GraphNode::free_variables(&self.as_typed_term::<TreeTerm>().as_inner())
-            }
-            TermTypeDiscriminants::Variable => {
-                GraphNode::free_variables(&self.as_typed_term::<VariableTerm>().as_inner())
-            }
-            TermTypeDiscriminants::EmptyIterator => {
-                GraphNode::free_variables(&self.as_typed_term::<EmptyIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::EvaluateIterator => {
-                GraphNode::free_variables(&self.as_typed_term::<EvaluateIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::FilterIterator => {
-                GraphNode::free_variables(&self.as_typed_term::<FilterIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::FlattenIterator => {
-                GraphNode::free_variables(&self.as_typed_term::<FlattenIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::HashmapKeysIterator => GraphNode::free_variables(
-                &self.as_typed_term::<HashmapKeysIteratorTerm>().as_inner(),
-            ),
-            TermTypeDiscriminants::HashmapValuesIterator => GraphNode::free_variables(
-                &self.as_typed_term::<HashmapValuesIteratorTerm>().as_inner(),
-            ),
-            TermTypeDiscriminants::IndexedAccessorIterator => GraphNode::free_variables(
-                &self
-                    .as_typed_term::<IndexedAccessorIteratorTerm>()
-                    .as_inner(),
-            ),
-            TermTypeDiscriminants::IntegersIterator => {
-                GraphNode::free_variables(&self.as_typed_term::<IntegersIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::IntersperseIterator => GraphNode::free_variables(
-                &self.as_typed_term::<IntersperseIteratorTerm>().as_inner(),
-            ),
-            TermTypeDiscriminants::MapIterator => {
-                GraphNode::free_variables(&self.as_typed_term::<MapIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::OnceIterator => {
-                GraphNode::free_variables(&self.as_typed_term::<OnceIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::RangeIterator => {
-                GraphNode::free_variables(&self.as_typed_term::<RangeIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::RepeatIterator => {
-                GraphNode::free_variables(&self.as_typed_term::<RepeatIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::SkipIterator => {
-                GraphNode::free_variables(&self.as_typed_term::<SkipIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::TakeIterator => {
-                GraphNode::free_variables(&self.as_typed_term::<TakeIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::ZipIterator => {
-                GraphNode::free_variables(&self.as_typed_term::<ZipIteratorTerm>().as_inner())
-            }
-        }
-    }
-    fn count_variable_usages(&self, offset: StackOffset) -> usize {
-        match self.read_value(|term| term.type_id()) {
-            TermTypeDiscriminants::Application => GraphNode::count_variable_usages(
-                &self.as_typed_term::<ApplicationTerm>().as_inner(),
-                offset,
-            ),
-            TermTypeDiscriminants::Boolean => GraphNode::count_variable_usages(
-                &self.as_typed_term::<BooleanTerm>().as_inner(),
-                offset,
-            ),
-            TermTypeDiscriminants::Builtin => GraphNode::count_variable_usages(
-                &self.as_typed_term::<BuiltinTerm>().as_inner(),
+            TermTypeDiscriminants::Bytes => GraphNode::count_variable_usages(
+                &self.as_typed_term::<BytesTerm>().as_inner(),
                 offset,
             ),
             TermTypeDiscriminants::Cell => GraphNode::count_variable_usages(
@@ -1930,6 +2001,10 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
                 &self.as_typed_term::<ConstructorTerm>().as_inner(),
                 offset,
             ),
+            TermTypeDiscriminants::Duration => GraphNode::count_variable_usages(
+                &self.as_typed_term::<DurationTerm>().as_inner(),
+                offset,
+            ),
             TermTypeDiscriminants::Effect => GraphNode::count_variable_usages(
                 &self.as_typed_term::<EffectTerm>().as_inner(),
                 offset,
@@ -1982,6 +2057,14 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
                 &self.as_typed_term::<RecordTerm>().as_inner(),
                 offset,
             ),
+            TermTypeDiscriminants::Recursive => GraphNode::count_variable_usages(
+                &self.as_typed_term::<RecursiveTerm>().as_inner(),
+                offset,
+            ),
+            TermTypeDiscriminants::Regex => GraphNode::count_variable_usages(
+                &self.as_typed_term::<RegexTerm>().as_inner(),
+                offset,
+            ),
             TermTypeDiscriminants::Signal => GraphNode::count_variable_usages(
                 &self.as_typed_term::<SignalTerm>().as_inner(),
                 offset,
@@ -2006,6 +2089,10 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
                 &self.as_typed_term::<VariableTerm>().as_inner(),
                 offset,
             ),
+            TermTypeDiscriminants::DedupeIterator => GraphNode::count_variable_usages(
+                &self.as_typed_term::<DedupeIteratorTerm>().as_inner(),
+                offset,
+            ),
             TermTypeDiscriminants::EmptyIterator => GraphNode::count_variable_usages(
                 &self.as_typed_term::<EmptyIteratorTerm>().as_inner(),
                 offset,
@@ -2072,6 +2159,18 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
                 &self.as_typed_term::<ZipIteratorTerm>().as_inner(),
                 offset,
             ),
+            TermTypeDiscriminants::ListView => GraphNode::count_variable_usages(
+                &self.as_typed_term::<ListViewTerm>().as_inner(),
+                offset,
+            ),
+            TermTypeDiscriminants::ListRope => GraphNode::count_variable_usages(
+                &self.as_typed_term::<ListRopeTerm>().as_inner(),
+                offset,
+            ),
+            TermTypeDiscriminants::Sensitive => GraphNode::count_variable_usages(
+                &self.as_typed_term::<SensitiveTerm>().as_inner(),
+                offset,
+            ),
         }
     }
     fn dynamic_dependencies(&self, deep: bool) -> DependencyList {
@@ -2080,6 +2179,10 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
                 &self.as_typed_term::<ApplicationTerm>().as_inner(),
                 deep,
             ),
+            TermTypeDiscriminants::BigInt => GraphNode::dynamic_dependencies(
+                &self.as_typed_term::<BigIntTerm>().as_inner(),
+                deep,
+            ),
             TermTypeDiscriminants::Boolean => GraphNode::dynamic_dependencies(
                 &self.as_typed_term::<BooleanTerm>().as_inner(),
                 deep,
@@ -2088,6 +2191,10 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
                 &self.as_typed_term::<BuiltinTerm>().as_inner(),
                 deep,
             ),
+            TermTypeDiscriminants::Bytes => GraphNode::dynamic_dependencies(
+                &self.as_typed_term::<BytesTerm>().as_inner(),
+                deep,
+            ),
             TermTypeDiscriminants::Cell => {
                 GraphNode::dynamic_dependencies(&self.as_typed_term::<CellTerm>().as_inner(), deep)
             }
@@ -2099,6 +2206,10 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
                 &self.as_typed_term::<ConstructorTerm>().as_inner(),
                 deep,
             ),
+            TermTypeDiscriminants::Duration => GraphNode::dynamic_dependencies(
+                &self.as_typed_term::<DurationTerm>().as_inner(),
+                deep,
+            ),
             TermTypeDiscriminants::Effect => GraphNode::dynamic_dependencies(
                 &self.as_typed_term::<EffectTerm>().as_inner(),
                 deep,
@@ -2146,6 +2257,14 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
                 &self.as_typed_term::<RecordTerm>().as_inner(),
                 deep,
             ),
+            TermTypeDiscriminants::Recursive => GraphNode::dynamic_dependencies(
+                &self.as_typed_term::<RecursiveTerm>().as_inner(),
+                deep,
+            ),
+            TermTypeDiscriminants::Regex => GraphNode::dynamic_dependencies(
+                &self.as_typed_term::<RegexTerm>().as_inner(),
+                deep,
+            ),
             TermTypeDiscriminants::Signal => GraphNode::dynamic_dependencies(
                 &self.as_typed_term::<SignalTerm>().as_inner(),
                 deep,
@@ -2169,6 +2288,10 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
                 &self.as_typed_term::<VariableTerm>().as_inner(),
                 deep,
             ),
+            TermTypeDiscriminants::DedupeIterator => GraphNode::dynamic_dependencies(
+                &self.as_typed_term::<DedupeIteratorTerm>().as_inner(),
+                deep,
+            ),
             TermTypeDiscriminants::EmptyIterator => GraphNode::dynamic_dependencies(
                 &self.as_typed_term::<EmptyIteratorTerm>().as_inner(),
                 deep,
@@ -2235,6 +2358,18 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
                 &self.as_typed_term::<ZipIteratorTerm>().as_inner(),
                 deep,
             ),
+            TermTypeDiscriminants::ListView => GraphNode::dynamic_dependencies(
+                &self.as_typed_term::<ListViewTerm>().as_inner(),
+                deep,
+            ),
+            TermTypeDiscriminants::ListRope => GraphNode::dynamic_dependencies(
+                &self.as_typed_term::<ListRopeTerm>().as_inner(),
+                deep,
+            ),
+            TermTypeDiscriminants::Sensitive => GraphNode::dynamic_dependencies(
+                &self.as_typed_term::<SensitiveTerm>().as_inner(),
+                deep,
+            ),
         }
     }
     fn has_dynamic_dependencies(&self, deep: bool) -> bool {
@@ -2243,6 +2378,10 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
                 &self.as_typed_term::<ApplicationTerm>().as_inner(),
                 deep,
             ),
+            TermTypeDiscriminants::BigInt => GraphNode::has_dynamic_dependencies(
+                &self.as_typed_term::<BigIntTerm>().as_inner(),
+                deep,
+            ),
             TermTypeDiscriminants::Boolean => GraphNode::has_dynamic_dependencies(
                 &self.as_typed_term::<BooleanTerm>().as_inner(),
                 deep,
@@ -2251,6 +2390,10 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
                 &self.as_typed_term::<BuiltinTerm>().as_inner(),
                 deep,
             ),
+            TermTypeDiscriminants::Bytes => GraphNode::has_dynamic_dependencies(
+                &self.as_typed_term::<BytesTerm>().as_inner(),
+                deep,
+            ),
             TermTypeDiscriminants::Cell => GraphNode::has_dynamic_dependencies(
                 &self.as_typed_term::<CellTerm>().as_inner(),
                 deep,
@@ -2263,6 +2406,10 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
                 &self.as_typed_term::<ConstructorTerm>().as_inner(),
                 deep,
             ),
+            TermTypeDiscriminants::Duration => GraphNode::has_dynamic_dependencies(
+                &self.as_typed_term::<DurationTerm>().as_inner(),
+                deep,
+            ),
             TermTypeDiscriminants::Effect => GraphNode::has_dynamic_dependencies(
                 &self.as_typed_term::<EffectTerm>().as_inner(),
                 deep,
@@ -2315,6 +2462,14 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
                 &self.as_typed_term::<RecordTerm>().as_inner(),
                 deep,
             ),
+            TermTypeDiscriminants::Recursive => GraphNode::has_dynamic_dependencies(
+                &self.as_typed_term::<RecursiveTerm>().as_inner(),
+                deep,
+            ),
+            TermTypeDiscriminants::Regex => GraphNode::has_dynamic_dependencies(
+                &self.as_typed_term::<RegexTerm>().as_inner(),
+                deep,
+            ),
             TermTypeDiscriminants::Signal => GraphNode::has_dynamic_dependencies(
                 &self.as_typed_term::<SignalTerm>().as_inner(),
                 deep,
@@ -2339,6 +2494,10 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
                 &self.as_typed_term::<VariableTerm>().as_inner(),
                 deep,
             ),
+            TermTypeDiscriminants::DedupeIterator => GraphNode::has_dynamic_dependencies(
+                &self.as_typed_term::<DedupeIteratorTerm>().as_inner(),
+                deep,
+            ),
             TermTypeDiscriminants::EmptyIterator => GraphNode::has_dynamic_dependencies(
                 &self.as_typed_term::<EmptyIteratorTerm>().as_inner(),
                 deep,
@@ -2405,6 +2564,18 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
                 &self.as_typed_term::<ZipIteratorTerm>().as_inner(),
                 deep,
             ),
+            TermTypeDiscriminants::ListView => GraphNode::has_dynamic_dependencies(
+                &self.as_typed_term::<ListViewTerm>().as_inner(),
+                deep,
+            ),
+            TermTypeDiscriminants::ListRope => GraphNode::has_dynamic_dependencies(
+                &self.as_typed_term::<ListRopeTerm>().as_inner(),
+                deep,
+            ),
+            TermTypeDiscriminants::Sensitive => GraphNode::has_dynamic_dependencies(
+                &self.as_typed_term::<SensitiveTerm>().as_inner(),
+                deep,
+            ),
         }
     }
     fn is_static(&self) -> bool {
@@ -2412,12 +2583,18 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
             TermTypeDiscriminants::Application => {
                 GraphNode::is_static(&self.as_typed_term::<ApplicationTerm>().as_inner())
             }
+            TermTypeDiscriminants::BigInt => {
+                GraphNode::is_static(&self.as_typed_term::<BigIntTerm>().as_inner())
+            }
             TermTypeDiscriminants::Boolean => {
                 GraphNode::is_static(&self.as_typed_term::<BooleanTerm>().as_inner())
             }
             TermTypeDiscriminants::Builtin => {
                 GraphNode::is_static(&self.as_typed_term::<BuiltinTerm>().as_inner())
             }
+            TermTypeDiscriminants::Bytes => {
+                GraphNode::is_static(&self.as_typed_term::<BytesTerm>().as_inner())
+            }
             TermTypeDiscriminants::Cell => {
                 GraphNode::is_static(&self.as_typed_term::<CellTerm>().as_inner())
             }
@@ -2427,6 +2604,9 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
             TermTypeDiscriminants::Constructor => {
                 GraphNode::is_static(&self.as_typed_term::<ConstructorTerm>().as_inner())
             }
+            TermTypeDiscriminants::Duration => {
+                GraphNode::is_static(&self.as_typed_term::<DurationTerm>().as_inner())
+            }
             TermTypeDiscriminants::Effect => {
                 GraphNode::is_static(&self.as_typed_term::<EffectTerm>().as_inner())
             }
@@ -2466,6 +2646,12 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
             TermTypeDiscriminants::Record => {
                 GraphNode::is_static(&self.as_typed_term::<RecordTerm>().as_inner())
             }
+            TermTypeDiscriminants::Recursive => {
+                GraphNode::is_static(&self.as_typed_term::<RecursiveTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Regex => {
+                GraphNode::is_static(&self.as_typed_term::<RegexTerm>().as_inner())
+            }
             TermTypeDiscriminants::Signal => {
                 GraphNode::is_static(&self.as_typed_term::<SignalTerm>().as_inner())
             }
@@ -2484,6 +2670,9 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
             TermTypeDiscriminants::Variable => {
                 GraphNode::is_static(&self.as_typed_term::<VariableTerm>().as_inner())
             }
+            TermTypeDiscriminants::DedupeIterator => {
+                GraphNode::is_static(&self.as_typed_term::<DedupeIteratorTerm>().as_inner())
+            }
             TermTypeDiscriminants::EmptyIterator => {
                 GraphNode::is_static(&self.as_typed_term::<EmptyIteratorTerm>().as_inner())
             }
@@ -2534,6 +2723,15 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
             TermTypeDiscriminants::ZipIterator => {
                 GraphNode::is_static(&self.as_typed_term::<ZipIteratorTerm>().as_inner())
             }
+            TermTypeDiscriminants::ListView => {
+                GraphNode::is_static(&self.as_typed_term::<ListViewTerm>().as_inner())
+            }
+            TermTypeDiscriminants::ListRope => {
+                GraphNode::is_static(&self.as_typed_term::<ListRopeTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Sensitive => {
+                GraphNode::is_static(&self.as_typed_term::<SensitiveTerm>().as_inner())
+            }
         }
     }
     fn is_atomic(&self) -> bool {
@@ -2541,12 +2739,18 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
             TermTypeDiscriminants::Application => {
                 GraphNode::is_atomic(&self.as_typed_term::<ApplicationTerm>().as_inner())
             }
+            TermTypeDiscriminants::BigInt => {
+                GraphNode::is_atomic(&self.as_typed_term::<BigIntTerm>().as_inner())
+            }
             TermTypeDiscriminants::Boolean => {
                 GraphNode::is_atomic(&self.as_typed_term::<BooleanTerm>().as_inner())
             }
             TermTypeDiscriminants::Builtin => {
                 GraphNode::is_atomic(&self.as_typed_term::<BuiltinTerm>().as_inner())
             }
+            TermTypeDiscriminants::Bytes => {
+                GraphNode::is_atomic(&self.as_typed_term::<BytesTerm>().as_inner())
+            }
             TermTypeDiscriminants::Cell => {
                 GraphNode::is_atomic(&self.as_typed_term::<CellTerm>().as_inner())
             }
@@ -2556,6 +2760,9 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
             TermTypeDiscriminants::Constructor => {
                 GraphNode::is_atomic(&self.as_typed_term::<ConstructorTerm>().as_inner())
             }
+            TermTypeDiscriminants::Duration => {
+                GraphNode::is_atomic(&self.as_typed_term::<DurationTerm>().as_inner())
+            }
             TermTypeDiscriminants::Effect => {
                 GraphNode::is_atomic(&self.as_typed_term::<EffectTerm>().as_inner())
             }
@@ -2595,6 +2802,12 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
             TermTypeDiscriminants::Record => {
                 GraphNode::is_atomic(&self.as_typed_term::<RecordTerm>().as_inner())
             }
+            TermTypeDiscriminants::Recursive => {
+                GraphNode::is_atomic(&self.as_typed_term::<RecursiveTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Regex => {
+                GraphNode::is_atomic(&self.as_typed_term::<RegexTerm>().as_inner())
+            }
             TermTypeDiscriminants::Signal => {
                 GraphNode::is_atomic(&self.as_typed_term::<SignalTerm>().as_inner())
             }
@@ -2613,6 +2826,9 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
             TermTypeDiscriminants::Variable => {
                 GraphNode::is_atomic(&self.as_typed_term::<VariableTerm>().as_inner())
             }
+            TermTypeDiscriminants::DedupeIterator => {
+                GraphNode::is_atomic(&self.as_typed_term::<DedupeIteratorTerm>().as_inner())
+            }
             TermTypeDiscriminants::EmptyIterator => {
                 GraphNode::is_atomic(&self.as_typed_term::<EmptyIteratorTerm>().as_inner())
             }
@@ -2661,136 +2877,494 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<Term, A> {
                 GraphNode::is_atomic(&self.as_typed_term::<TakeIteratorTerm>().as_inner())
             }
             TermTypeDiscriminants::ZipIterator => {
-                GraphNode::is_atomic(&self.as_typed_term::<ZipIteratorTerm>().as_inner())
+                GraphNode::is_atomic(&self.as_typed_term::<ZipIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::ListView => {
+                GraphNode::is_atomic(&self.as_typed_term::<ListViewTerm>().as_inner())
+            }
+            TermTypeDiscriminants::ListRope => {
+                GraphNode::is_atomic(&self.as_typed_term::<ListRopeTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Sensitive => {
+                GraphNode::is_atomic(&self.as_typed_term::<SensitiveTerm>().as_inner())
+            }
+        }
+    }
+    fn is_complex(&self) -> bool {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::Application => {
+                GraphNode::is_complex(&self.as_typed_term::<ApplicationTerm>().as_inner())
+            }
+            TermTypeDiscriminants::BigInt => {
+                GraphNode::is_complex(&self.as_typed_term::<BigIntTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Boolean => {
+                GraphNode::is_complex(&self.as_typed_term::<BooleanTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Builtin => {
+                GraphNode::is_complex(&self.as_typed_term::<BuiltinTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Bytes => {
+                GraphNode::is_complex(&self.as_typed_term::<BytesTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Cell => {
+                GraphNode::is_complex(&self.as_typed_term::<CellTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Condition => {
+                GraphNode::is_complex(&self.as_typed_term::<ConditionTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Constructor => {
+                GraphNode::is_complex(&self.as_typed_term::<ConstructorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Duration => {
+                GraphNode::is_complex(&self.as_typed_term::<DurationTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Effect => {
+                GraphNode::is_complex(&self.as_typed_term::<EffectTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Float => {
+                GraphNode::is_complex(&self.as_typed_term::<FloatTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Hashmap => {
+                GraphNode::is_complex(&self.as_typed_term::<HashmapTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Hashset => {
+                GraphNode::is_complex(&self.as_typed_term::<HashsetTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Int => {
+                GraphNode::is_complex(&self.as_typed_term::<IntTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Lambda => {
+                GraphNode::is_complex(&self.as_typed_term::<LambdaTerm>().as_inner())
+            }
+            TermTypeDiscriminants::LazyResult => {
+                GraphNode::is_complex(&self.as_typed_term::<LazyResultTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Let => {
+                GraphNode::is_complex(&self.as_typed_term::<LetTerm>().as_inner())
+            }
+            TermTypeDiscriminants::List => {
+                GraphNode::is_complex(&self.as_typed_term::<ListTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Nil => {
+                GraphNode::is_complex(&self.as_typed_term::<NilTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Partial => {
+                GraphNode::is_complex(&self.as_typed_term::<PartialTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Pointer => {
+                GraphNode::is_complex(&self.as_typed_term::<PointerTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Record => {
+                GraphNode::is_complex(&self.as_typed_term::<RecordTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Recursive => {
+                GraphNode::is_complex(&self.as_typed_term::<RecursiveTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Regex => {
+                GraphNode::is_complex(&self.as_typed_term::<RegexTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Signal => {
+                GraphNode::is_complex(&self.as_typed_term::<SignalTerm>().as_inner())
+            }
+            TermTypeDiscriminants::String => {
+                GraphNode::is_complex(&self.as_typed_term::<StringTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Symbol => {
+                GraphNode::is_complex(&self.as_typed_term::<SymbolTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Timestamp => {
+                GraphNode::is_complex(&self.as_typed_term::<TimestampTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Tree => {
+                GraphNode::is_complex(&self.as_typed_term::<TreeTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Variable => {
+                GraphNode::is_complex(&self.as_typed_term::<VariableTerm>().as_inner())
+            }
+            TermTypeDiscriminants::DedupeIterator => {
+                GraphNode::is_complex(&self.as_typed_term::<DedupeIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::EmptyIterator => {
+                GraphNode::is_complex(&self.as_typed_term::<EmptyIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::EvaluateIterator => {
+                GraphNode::is_complex(&self.as_typed_term::<EvaluateIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::FilterIterator => {
+                GraphNode::is_complex(&self.as_typed_term::<FilterIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::FlattenIterator => {
+                GraphNode::is_complex(&self.as_typed_term::<FlattenIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::HashmapKeysIterator => {
+                GraphNode::is_complex(&self.as_typed_term::<HashmapKeysIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::HashmapValuesIterator => {
+                GraphNode::is_complex(&self.as_typed_term::<HashmapValuesIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::IndexedAccessorIterator => GraphNode::is_complex(
+                &self
+                    .as_typed_term::<IndexedAccessorIteratorTerm>()
+                    .as_inner(),
+            ),
+            TermTypeDiscriminants::IntegersIterator => {
+                GraphNode::is_complex(&self.as_typed_term::<IntegersIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::IntersperseIterator => {
+                GraphNode::is_complex(&self.as_typed_term::<IntersperseIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::MapIterator => {
+                GraphNode::is_complex(&self.as_typed_term::<MapIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::OnceIterator => {
+                GraphNode::is_complex(&self.as_typed_term::<OnceIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::RangeIterator => {
+                GraphNode::is_complex(&self.as_typed_term::<RangeIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::RepeatIterator => {
+                GraphNode::is_complex(&self.as_typed_term::<RepeatIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::SkipIterator => {
+                GraphNode::is_complex(&self.as_typed_term::<SkipIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::TakeIterator => {
+                GraphNode::is_complex(&self.as_typed_term::<TakeIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::ZipIterator => {
+                GraphNode::is_complex(&self.as_typed_term::<ZipIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::ListView => {
+                GraphNode::is_complex(&self.as_typed_term::<ListViewTerm>().as_inner())
+            }
+            TermTypeDiscriminants::ListRope => {
+                GraphNode::is_complex(&self.as_typed_term::<ListRopeTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Sensitive => {
+                GraphNode::is_complex(&self.as_typed_term::<SensitiveTerm>().as_inner())
+            }
+        }
+    }
+}
+
+impl<A: Arena + Clone> ArenaRef<Term, A> {
+    /// Retrieve the set of variables referenced by this term that are not bound within the term itself, shared with the
+    /// graph metadata cache
+    pub(crate) fn shared_free_variables(&self) -> Rc<HashSet<StackOffset>> {
+        traversal::free_variables(self)
+    }
+    /// Compute the capture depth by delegating to the underlying typed term implementation
+    pub(crate) fn typed_capture_depth(&self) -> StackOffset {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::Application => {
+                GraphNode::capture_depth(&self.as_typed_term::<ApplicationTerm>().as_inner())
+            }
+            TermTypeDiscriminants::BigInt => {
+                GraphNode::capture_depth(&self.as_typed_term::<BigIntTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Boolean => {
+                GraphNode::capture_depth(&self.as_typed_term::<BooleanTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Builtin => {
+                GraphNode::capture_depth(&self.as_typed_term::<BuiltinTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Bytes => {
+                GraphNode::capture_depth(&self.as_typed_term::<BytesTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Cell => {
+                GraphNode::capture_depth(&self.as_typed_term::<CellTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Condition => {
+                GraphNode::capture_depth(&self.as_typed_term::<ConditionTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Constructor => {
+                GraphNode::capture_depth(&self.as_typed_term::<ConstructorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Duration => {
+                GraphNode::capture_depth(&self.as_typed_term::<DurationTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Effect => {
+                GraphNode::capture_depth(&self.as_typed_term::<EffectTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Float => {
+                GraphNode::capture_depth(&self.as_typed_term::<FloatTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Hashmap => {
+                GraphNode::capture_depth(&self.as_typed_term::<HashmapTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Hashset => {
+                GraphNode::capture_depth(&self.as_typed_term::<HashsetTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Int => {
+                GraphNode::capture_depth(&self.as_typed_term::<IntTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Lambda => {
+                GraphNode::capture_depth(&self.as_typed_term::<LambdaTerm>().as_inner())
+            }
+            TermTypeDiscriminants::LazyResult => {
+                GraphNode::capture_depth(&self.as_typed_term::<LazyResultTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Let => {
+                GraphNode::capture_depth(&self.as_typed_term::<LetTerm>().as_inner())
+            }
+            TermTypeDiscriminants::List => {
+                GraphNode::capture_depth(&self.as_typed_term::<ListTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Nil => {
+                GraphNode::capture_depth(&self.as_typed_term::<NilTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Partial => {
+                GraphNode::capture_depth(&self.as_typed_term::<PartialTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Pointer => {
+                GraphNode::capture_depth(&self.as_typed_term::<PointerTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Record => {
+                GraphNode::capture_depth(&self.as_typed_term::<RecordTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Recursive => {
+                GraphNode::capture_depth(&self.as_typed_term::<RecursiveTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Regex => {
+                GraphNode::capture_depth(&self.as_typed_term::<RegexTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Signal => {
+                GraphNode::capture_depth(&self.as_typed_term::<SignalTerm>().as_inner())
+            }
+            TermTypeDiscriminants::String => {
+                GraphNode::capture_depth(&self.as_typed_term::<StringTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Symbol => {
+                GraphNode::capture_depth(&self.as_typed_term::<SymbolTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Timestamp => {
+                GraphNode::capture_depth(&self.as_typed_term::<TimestampTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Tree => {
+                GraphNode::capture_depth(&self.as_typed_term::<TreeTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Variable => {
+                GraphNode::capture_depth(&self.as_typed_term::<VariableTerm>().as_inner())
+            }
+            TermTypeDiscriminants::DedupeIterator => {
+                GraphNode::capture_depth(&self.as_typed_term::<DedupeIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::EmptyIterator => {
+                GraphNode::capture_depth(&self.as_typed_term::<EmptyIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::EvaluateIterator => {
+                GraphNode::capture_depth(&self.as_typed_term::<EvaluateIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::FilterIterator => {
+                GraphNode::capture_depth(&self.as_typed_term::<FilterIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::FlattenIterator => {
+                GraphNode::capture_depth(&self.as_typed_term::<FlattenIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::HashmapKeysIterator => GraphNode::capture_depth(
+                &self.as_typed_term::<HashmapKeysIteratorTerm>().as_inner(),
+            ),
+            TermTypeDiscriminants::HashmapValuesIterator => GraphNode::capture_depth(
+                &self.as_typed_term::<HashmapValuesIteratorTerm>().as_inner(),
+            ),
+            TermTypeDiscriminants::IndexedAccessorIterator => GraphNode::capture_depth(
+                &self
+                    .as_typed_term::<IndexedAccessorIteratorTerm>()
+                    .as_inner(),
+            ),
+            TermTypeDiscriminants::IntegersIterator => {
+                GraphNode::capture_depth(&self.as_typed_term::<IntegersIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::IntersperseIterator => GraphNode::capture_depth(
+                &self.as_typed_term::<IntersperseIteratorTerm>().as_inner(),
+            ),
+            TermTypeDiscriminants::MapIterator => {
+                GraphNode::capture_depth(&self.as_typed_term::<MapIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::OnceIterator => {
+                GraphNode::capture_depth(&self.as_typed_term::<OnceIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::RangeIterator => {
+                GraphNode::capture_depth(&self.as_typed_term::<RangeIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::RepeatIterator => {
+                GraphNode::capture_depth(&self.as_typed_term::<RepeatIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::SkipIterator => {
+                GraphNode::capture_depth(&self.as_typed_term::<SkipIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::TakeIterator => {
+                GraphNode::capture_depth(&self.as_typed_term::<TakeIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::ZipIterator => {
+                GraphNode::capture_depth(&self.as_typed_term::<ZipIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::ListView => {
+                GraphNode::capture_depth(&self.as_typed_term::<ListViewTerm>().as_inner())
+            }
+            TermTypeDiscriminants::ListRope => {
+                GraphNode::capture_depth(&self.as_typed_term::<ListRopeTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Sensitive => {
+                GraphNode::capture_depth(&self.as_typed_term::<SensitiveTerm>().as_inner())
             }
         }
     }
-    fn is_complex(&self) -> bool {
+    /// Compute the set of free variables by delegating to the underlying typed term implementation
+    pub(crate) fn typed_free_variables(&self) -> HashSet<StackOffset> {
         match self.read_value(|term| term.type_id()) {
             TermTypeDiscriminants::Application => {
-                GraphNode::is_complex(&self.as_typed_term::<ApplicationTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<ApplicationTerm>().as_inner())
+            }
+            TermTypeDiscriminants::BigInt => {
+                GraphNode::free_variables(&self.as_typed_term::<BigIntTerm>().as_inner())
             }
             TermTypeDiscriminants::Boolean => {
-                GraphNode::is_complex(&self.as_typed_term::<BooleanTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<BooleanTerm>().as_inner())
             }
             TermTypeDiscriminants::Builtin => {
-                GraphNode::is_complex(&self.as_typed_term::<BuiltinTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<BuiltinTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Bytes => {
+                GraphNode::free_variables(&self.as_typed_term::<BytesTerm>().as_inner())
             }
             TermTypeDiscriminants::Cell => {
-                GraphNode::is_complex(&self.as_typed_term::<CellTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<CellTerm>().as_inner())
             }
             TermTypeDiscriminants::Condition => {
-                GraphNode::is_complex(&self.as_typed_term::<ConditionTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<ConditionTerm>().as_inner())
             }
             TermTypeDiscriminants::Constructor => {
-                GraphNode::is_complex(&self.as_typed_term::<ConstructorTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<ConstructorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Duration => {
+                GraphNode::free_variables(&self.as_typed_term::<DurationTerm>().as_inner())
             }
             TermTypeDiscriminants::Effect => {
-                GraphNode::is_complex(&self.as_typed_term::<EffectTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<EffectTerm>().as_inner())
             }
             TermTypeDiscriminants::Float => {
-                GraphNode::is_complex(&self.as_typed_term::<FloatTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<FloatTerm>().as_inner())
             }
             TermTypeDiscriminants::Hashmap => {
-                GraphNode::is_complex(&self.as_typed_term::<HashmapTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<HashmapTerm>().as_inner())
             }
             TermTypeDiscriminants::Hashset => {
-                GraphNode::is_complex(&self.as_typed_term::<HashsetTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<HashsetTerm>().as_inner())
             }
             TermTypeDiscriminants::Int => {
-                GraphNode::is_complex(&self.as_typed_term::<IntTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<IntTerm>().as_inner())
             }
             TermTypeDiscriminants::Lambda => {
-                GraphNode::is_complex(&self.as_typed_term::<LambdaTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<LambdaTerm>().as_inner())
             }
             TermTypeDiscriminants::LazyResult => {
-                GraphNode::is_complex(&self.as_typed_term::<LazyResultTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<LazyResultTerm>().as_inner())
             }
             TermTypeDiscriminants::Let => {
-                GraphNode::is_complex(&self.as_typed_term::<LetTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<LetTerm>().as_inner())
             }
             TermTypeDiscriminants::List => {
-                GraphNode::is_complex(&self.as_typed_term::<ListTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<ListTerm>().as_inner())
             }
             TermTypeDiscriminants::Nil => {
-                GraphNode::is_complex(&self.as_typed_term::<NilTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<NilTerm>().as_inner())
             }
             TermTypeDiscriminants::Partial => {
-                GraphNode::is_complex(&self.as_typed_term::<PartialTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<PartialTerm>().as_inner())
             }
             TermTypeDiscriminants::Pointer => {
-                GraphNode::is_complex(&self.as_typed_term::<PointerTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<PointerTerm>().as_inner())
             }
             TermTypeDiscriminants::Record => {
-                GraphNode::is_complex(&self.as_typed_term::<RecordTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<RecordTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Recursive => {
+                GraphNode::free_variables(&self.as_typed_term::<RecursiveTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Regex => {
+                GraphNode::free_variables(&self.as_typed_term::<RegexTerm>().as_inner())
             }
             TermTypeDiscriminants::Signal => {
-                GraphNode::is_complex(&self.as_typed_term::<SignalTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<SignalTerm>().as_inner())
             }
             TermTypeDiscriminants::String => {
-                GraphNode::is_complex(&self.as_typed_term::<StringTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<StringTerm>().as_inner())
             }
             TermTypeDiscriminants::Symbol => {
-                GraphNode::is_complex(&self.as_typed_term::<SymbolTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<SymbolTerm>().as_inner())
             }
             TermTypeDiscriminants::Timestamp => {
-                GraphNode::is_complex(&self.as_typed_term::<TimestampTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<TimestampTerm>().as_inner())
             }
             TermTypeDiscriminants::Tree => {
-                GraphNode::is_complex(&self.as_typed_term::<TreeTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<TreeTerm>().as_inner())
             }
             TermTypeDiscriminants::Variable => {
-                GraphNode::is_complex(&self.as_typed_term::<VariableTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<VariableTerm>().as_inner())
+            }
+            TermTypeDiscriminants::DedupeIterator => {
+                GraphNode::free_variables(&self.as_typed_term::<DedupeIteratorTerm>().as_inner())
             }
             TermTypeDiscriminants::EmptyIterator => {
-                GraphNode::is_complex(&self.as_typed_term::<EmptyIteratorTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<EmptyIteratorTerm>().as_inner())
             }
             TermTypeDiscriminants::EvaluateIterator => {
-                GraphNode::is_complex(&self.as_typed_term::<EvaluateIteratorTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<EvaluateIteratorTerm>().as_inner())
             }
             TermTypeDiscriminants::FilterIterator => {
-                GraphNode::is_complex(&self.as_typed_term::<FilterIteratorTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<FilterIteratorTerm>().as_inner())
             }
             TermTypeDiscriminants::FlattenIterator => {
-                GraphNode::is_complex(&self.as_typed_term::<FlattenIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::HashmapKeysIterator => {
-                GraphNode::is_complex(&self.as_typed_term::<HashmapKeysIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::HashmapValuesIterator => {
-                GraphNode::is_complex(&self.as_typed_term::<HashmapValuesIteratorTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<FlattenIteratorTerm>().as_inner())
             }
-            TermTypeDiscriminants::IndexedAccessorIterator => GraphNode::is_complex(
+            TermTypeDiscriminants::HashmapKeysIterator => GraphNode::free_variables(
+                &self.as_typed_term::<HashmapKeysIteratorTerm>().as_inner(),
+            ),
+            TermTypeDiscriminants::HashmapValuesIterator => GraphNode::free_variables(
+                &self.as_typed_term::<HashmapValuesIteratorTerm>().as_inner(),
+            ),
+            TermTypeDiscriminants::IndexedAccessorIterator => GraphNode::free_variables(
                 &self
                     .as_typed_term::<IndexedAccessorIteratorTerm>()
                     .as_inner(),
             ),
             TermTypeDiscriminants::IntegersIterator => {
-                GraphNode::is_complex(&self.as_typed_term::<IntegersIteratorTerm>().as_inner())
-            }
-            TermTypeDiscriminants::IntersperseIterator => {
-                GraphNode::is_complex(&self.as_typed_term::<IntersperseIteratorTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<IntegersIteratorTerm>().as_inner())
             }
+            TermTypeDiscriminants::IntersperseIterator => GraphNode::free_variables(
+                &self.as_typed_term::<IntersperseIteratorTerm>().as_inner(),
+            ),
             TermTypeDiscriminants::MapIterator => {
-                GraphNode::is_complex(&self.as_typed_term::<MapIteratorTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<MapIteratorTerm>().as_inner())
             }
             TermTypeDiscriminants::OnceIterator => {
-                GraphNode::is_complex(&self.as_typed_term::<OnceIteratorTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<OnceIteratorTerm>().as_inner())
             }
             TermTypeDiscriminants::RangeIterator => {
-                GraphNode::is_complex(&self.as_typed_term::<RangeIteratorTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<RangeIteratorTerm>().as_inner())
             }
             TermTypeDiscriminants::RepeatIterator => {
-                GraphNode::is_complex(&self.as_typed_term::<RepeatIteratorTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<RepeatIteratorTerm>().as_inner())
             }
             TermTypeDiscriminants::SkipIterator => {
-                GraphNode::is_complex(&self.as_typed_term::<SkipIteratorTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<SkipIteratorTerm>().as_inner())
             }
             TermTypeDiscriminants::TakeIterator => {
-                GraphNode::is_complex(&self.as_typed_term::<TakeIteratorTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<TakeIteratorTerm>().as_inner())
             }
             TermTypeDiscriminants::ZipIterator => {
-                GraphNode::is_complex(&self.as_typed_term::<ZipIteratorTerm>().as_inner())
+                GraphNode::free_variables(&self.as_typed_term::<ZipIteratorTerm>().as_inner())
+            }
+            TermTypeDiscriminants::ListView => {
+                GraphNode::free_variables(&self.as_typed_term::<ListViewTerm>().as_inner())
+            }
+            TermTypeDiscriminants::ListRope => {
+                GraphNode::free_variables(&self.as_typed_term::<ListRopeTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Sensitive => {
+                GraphNode::free_variables(&self.as_typed_term::<SensitiveTerm>().as_inner())
             }
         }
     }
@@ -2802,12 +3376,18 @@ impl<A: Arena + Clone> SerializeJson for ArenaRef<Term, A> {
             TermTypeDiscriminants::Application => {
                 SerializeJson::to_json(&self.as_typed_term::<ApplicationTerm>().as_inner())
             }
+            TermTypeDiscriminants::BigInt => {
+                SerializeJson::to_json(&self.as_typed_term::<BigIntTerm>().as_inner())
+            }
             TermTypeDiscriminants::Boolean => {
                 SerializeJson::to_json(&self.as_typed_term::<BooleanTerm>().as_inner())
             }
             TermTypeDiscriminants::Builtin => {
                 SerializeJson::to_json(&self.as_typed_term::<BuiltinTerm>().as_inner())
             }
+            TermTypeDiscriminants::Bytes => {
+                SerializeJson::to_json(&self.as_typed_term::<BytesTerm>().as_inner())
+            }
             TermTypeDiscriminants::Cell => {
                 SerializeJson::to_json(&self.as_typed_term::<CellTerm>().as_inner())
             }
@@ -2817,6 +3397,9 @@ impl<A: Arena + Clone> SerializeJson for ArenaRef<Term, A> {
             TermTypeDiscriminants::Constructor => {
                 SerializeJson::to_json(&self.as_typed_term::<ConstructorTerm>().as_inner())
             }
+            TermTypeDiscriminants::Duration => {
+                SerializeJson::to_json(&self.as_typed_term::<DurationTerm>().as_inner())
+            }
             TermTypeDiscriminants::Effect => {
                 SerializeJson::to_json(&self.as_typed_term::<EffectTerm>().as_inner())
             }
@@ -2856,6 +3439,12 @@ impl<A: Arena + Clone> SerializeJson for ArenaRef<Term, A> {
             TermTypeDiscriminants::Record => {
                 SerializeJson::to_json(&self.as_typed_term::<RecordTerm>().as_inner())
             }
+            TermTypeDiscriminants::Recursive => {
+                SerializeJson::to_json(&self.as_typed_term::<RecursiveTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Regex => {
+                SerializeJson::to_json(&self.as_typed_term::<RegexTerm>().as_inner())
+            }
             TermTypeDiscriminants::Signal => {
                 SerializeJson::to_json(&self.as_typed_term::<SignalTerm>().as_inner())
             }
@@ -2874,6 +3463,9 @@ impl<A: Arena + Clone> SerializeJson for ArenaRef<Term, A> {
             TermTypeDiscriminants::Variable => {
                 SerializeJson::to_json(&self.as_typed_term::<VariableTerm>().as_inner())
             }
+            TermTypeDiscriminants::DedupeIterator => {
+                SerializeJson::to_json(&self.as_typed_term::<DedupeIteratorTerm>().as_inner())
+            }
             TermTypeDiscriminants::EmptyIterator => {
                 SerializeJson::to_json(&self.as_typed_term::<EmptyIteratorTerm>().as_inner())
             }
@@ -2924,6 +3516,15 @@ impl<A: Arena + Clone> SerializeJson for ArenaRef<Term, A> {
             TermTypeDiscriminants::ZipIterator => {
                 SerializeJson::to_json(&self.as_typed_term::<ZipIteratorTerm>().as_inner())
             }
+            TermTypeDiscriminants::ListView => {
+                SerializeJson::to_json(&self.as_typed_term::<ListViewTerm>().as_inner())
+            }
+            TermTypeDiscriminants::ListRope => {
+                SerializeJson::to_json(&self.as_typed_term::<ListRopeTerm>().as_inner())
+            }
+            TermTypeDiscriminants::Sensitive => {
+                SerializeJson::to_json(&self.as_typed_term::<SensitiveTerm>().as_inner())
+            }
         }
     }
     fn patch(&self, target: &Self) -> Result<Option<JsonValue>, String> {
@@ -2940,6 +3541,10 @@ impl<A: Arena + Clone> SerializeJson for ArenaRef<Term, A> {
                     &target.as_typed_term::<ApplicationTerm>().as_inner(),
                 )
             }
+            (TermTypeDiscriminants::BigInt, TermTypeDiscriminants::BigInt) => SerializeJson::patch(
+                &self.as_typed_term::<BigIntTerm>().as_inner(),
+                &target.as_typed_term::<BigIntTerm>().as_inner(),
+            ),
             (TermTypeDiscriminants::Boolean, TermTypeDiscriminants::Boolean) => {
                 SerializeJson::patch(
                     &self.as_typed_term::<BooleanTerm>().as_inner(),
@@ -2952,6 +3557,10 @@ impl<A: Arena + Clone> SerializeJson for ArenaRef<Term, A> {
                     &target.as_typed_term::<BuiltinTerm>().as_inner(),
                 )
             }
+            (TermTypeDiscriminants::Bytes, TermTypeDiscriminants::Bytes) => SerializeJson::patch(
+                &self.as_typed_term::<BytesTerm>().as_inner(),
+                &target.as_typed_term::<BytesTerm>().as_inner(),
+            ),
             (TermTypeDiscriminants::Cell, TermTypeDiscriminants::Cell) => SerializeJson::patch(
                 &self.as_typed_term::<CellTerm>().as_inner(),
                 &target.as_typed_term::<CellTerm>().as_inner(),
@@ -2968,6 +3577,12 @@ impl<A: Arena + Clone> SerializeJson for ArenaRef<Term, A> {
                     &target.as_typed_term::<ConstructorTerm>().as_inner(),
                 )
             }
+            (TermTypeDiscriminants::Duration, TermTypeDiscriminants::Duration) => {
+                SerializeJson::patch(
+                    &self.as_typed_term::<DurationTerm>().as_inner(),
+                    &target.as_typed_term::<DurationTerm>().as_inner(),
+                )
+            }
             (TermTypeDiscriminants::Effect, TermTypeDiscriminants::Effect) => SerializeJson::patch(
                 &self.as_typed_term::<EffectTerm>().as_inner(),
                 &target.as_typed_term::<EffectTerm>().as_inner(),
@@ -3030,6 +3645,18 @@ impl<A: Arena + Clone> SerializeJson for ArenaRef<Term, A> {
                 &self.as_typed_term::<RecordTerm>().as_inner(),
                 &target.as_typed_term::<RecordTerm>().as_inner(),
             ),
+            (TermTypeDiscriminants::Recursive, TermTypeDiscriminants::Recursive) => {
+                SerializeJson::patch(
+                    &self.as_typed_term::<RecursiveTerm>().as_inner(),
+                    &target.as_typed_term::<RecursiveTerm>().as_inner(),
+                )
+            }
+            (TermTypeDiscriminants::Regex, TermTypeDiscriminants::Regex) => {
+                SerializeJson::patch(
+                    &self.as_typed_term::<RegexTerm>().as_inner(),
+                    &target.as_typed_term::<RegexTerm>().as_inner(),
+                )
+            }
             (TermTypeDiscriminants::Signal, TermTypeDiscriminants::Signal) => SerializeJson::patch(
                 &self.as_typed_term::<SignalTerm>().as_inner(),
                 &target.as_typed_term::<SignalTerm>().as_inner(),
@@ -3058,6 +3685,12 @@ impl<A: Arena + Clone> SerializeJson for ArenaRef<Term, A> {
                     &target.as_typed_term::<VariableTerm>().as_inner(),
                 )
             }
+            (TermTypeDiscriminants::DedupeIterator, TermTypeDiscriminants::DedupeIterator) => {
+                SerializeJson::patch(
+                    &self.as_typed_term::<DedupeIteratorTerm>().as_inner(),
+                    &target.as_typed_term::<DedupeIteratorTerm>().as_inner(),
+                )
+            }
             (TermTypeDiscriminants::EmptyIterator, TermTypeDiscriminants::EmptyIterator) => {
                 SerializeJson::patch(
                     &self.as_typed_term::<EmptyIteratorTerm>().as_inner(),
@@ -3161,6 +3794,24 @@ impl<A: Arena + Clone> SerializeJson for ArenaRef<Term, A> {
                     &target.as_typed_term::<ZipIteratorTerm>().as_inner(),
                 )
             }
+            (TermTypeDiscriminants::ListView, TermTypeDiscriminants::ListView) => {
+                SerializeJson::patch(
+                    &self.as_typed_term::<ListViewTerm>().as_inner(),
+                    &target.as_typed_term::<ListViewTerm>().as_inner(),
+                )
+            }
+            (TermTypeDiscriminants::ListRope, TermTypeDiscriminants::ListRope) => {
+                SerializeJson::patch(
+                    &self.as_typed_term::<ListRopeTerm>().as_inner(),
+                    &target.as_typed_term::<ListRopeTerm>().as_inner(),
+                )
+            }
+            (TermTypeDiscriminants::Sensitive, TermTypeDiscriminants::Sensitive) => {
+                SerializeJson::patch(
+                    &self.as_typed_term::<SensitiveTerm>().as_inner(),
+                    &target.as_typed_term::<SensitiveTerm>().as_inner(),
+                )
+            }
             _ => target.to_json().map(Some),
         }
     }
@@ -3172,12 +3823,18 @@ impl<A: Arena + Clone> std::fmt::Debug for ArenaRef<Term, A> {
             TermTypeDiscriminants::Application => {
                 std::fmt::Debug::fmt(&self.as_typed_term::<ApplicationTerm>().as_inner(), f)
             }
+            TermTypeDiscriminants::BigInt => {
+                std::fmt::Debug::fmt(&self.as_typed_term::<BigIntTerm>().as_inner(), f)
+            }
             TermTypeDiscriminants::Boolean => {
                 std::fmt::Debug::fmt(&self.as_typed_term::<BooleanTerm>().as_inner(), f)
             }
             TermTypeDiscriminants::Builtin => {
                 std::fmt::Debug::fmt(&self.as_typed_term::<BuiltinTerm>().as_inner(), f)
             }
+            TermTypeDiscriminants::Bytes => {
+                std::fmt::Debug::fmt(&self.as_typed_term::<BytesTerm>().as_inner(), f)
+            }
             TermTypeDiscriminants::Cell => {
                 std::fmt::Debug::fmt(&self.as_typed_term::<CellTerm>().as_inner(), f)
             }
@@ -3187,6 +3844,9 @@ impl<A: Arena + Clone> std::fmt::Debug for ArenaRef<Term, A> {
             TermTypeDiscriminants::Constructor => {
                 std::fmt::Debug::fmt(&self.as_typed_term::<ConstructorTerm>().as_inner(), f)
             }
+            TermTypeDiscriminants::Duration => {
+                std::fmt::Debug::fmt(&self.as_typed_term::<DurationTerm>().as_inner(), f)
+            }
             TermTypeDiscriminants::Effect => {
                 std::fmt::Debug::fmt(&self.as_typed_term::<EffectTerm>().as_inner(), f)
             }
@@ -3226,6 +3886,12 @@ impl<A: Arena + Clone> std::fmt::Debug for ArenaRef<Term, A> {
             TermTypeDiscriminants::Record => {
                 std::fmt::Debug::fmt(&self.as_typed_term::<RecordTerm>().as_inner(), f)
             }
+            TermTypeDiscriminants::Recursive => {
+                std::fmt::Debug::fmt(&self.as_typed_term::<RecursiveTerm>().as_inner(), f)
+            }
+            TermTypeDiscriminants::Regex => {
+                std::fmt::Debug::fmt(&self.as_typed_term::<RegexTerm>().as_inner(), f)
+            }
             TermTypeDiscriminants::Signal => {
                 std::fmt::Debug::fmt(&self.as_typed_term::<SignalTerm>().as_inner(), f)
             }
@@ -3244,6 +3910,9 @@ impl<A: Arena + Clone> std::fmt::Debug for ArenaRef<Term, A> {
             TermTypeDiscriminants::Variable => {
                 std::fmt::Debug::fmt(&self.as_typed_term::<VariableTerm>().as_inner(), f)
             }
+            TermTypeDiscriminants::DedupeIterator => {
+                std::fmt::Debug::fmt(&self.as_typed_term::<DedupeIteratorTerm>().as_inner(), f)
+            }
             TermTypeDiscriminants::EmptyIterator => {
                 std::fmt::Debug::fmt(&self.as_typed_term::<EmptyIteratorTerm>().as_inner(), f)
             }
@@ -3298,22 +3967,44 @@ impl<A: Arena + Clone> std::fmt::Debug for ArenaRef<Term, A> {
             TermTypeDiscriminants::ZipIterator => {
                 std::fmt::Debug::fmt(&self.as_typed_term::<ZipIteratorTerm>().as_inner(), f)
             }
+            TermTypeDiscriminants::ListView => {
+                std::fmt::Debug::fmt(&self.as_typed_term::<ListViewTerm>().as_inner(), f)
+            }
+            TermTypeDiscriminants::ListRope => {
+                std::fmt::Debug::fmt(&self.as_typed_term::<ListRopeTerm>().as_inner(), f)
+            }
+            TermTypeDiscriminants::Sensitive => {
+                std::fmt::Debug::fmt(&self.as_typed_term::<SensitiveTerm>().as_inner(), f)
+            }
         }
     }
 }
 
 impl<A: Arena + Clone> std::fmt::Display for ArenaRef<Term, A> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        traversal::fmt_term(self, f)
+    }
+}
+
+impl<A: Arena + Clone> ArenaRef<Term, A> {
+    /// Format the term by delegating to the underlying typed term implementation
+    pub(crate) fn fmt_typed(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.read_value(|term| term.type_id()) {
             TermTypeDiscriminants::Application => {
                 std::fmt::Display::fmt(&self.as_typed_term::<ApplicationTerm>().as_inner(), f)
             }
+            TermTypeDiscriminants::BigInt => {
+                std::fmt::Display::fmt(&self.as_typed_term::<BigIntTerm>().as_inner(), f)
+            }
             TermTypeDiscriminants::Boolean => {
                 std::fmt::Display::fmt(&self.as_typed_term::<BooleanTerm>().as_inner(), f)
             }
             TermTypeDiscriminants::Builtin => {
                 std::fmt::Display::fmt(&self.as_typed_term::<BuiltinTerm>().as_inner(), f)
             }
+            TermTypeDiscriminants::Bytes => {
+                std::fmt::Display::fmt(&self.as_typed_term::<BytesTerm>().as_inner(), f)
+            }
             TermTypeDiscriminants::Cell => {
                 std::fmt::Display::fmt(&self.as_typed_term::<CellTerm>().as_inner(), f)
             }
@@ -3323,6 +4014,9 @@ impl<A: Arena + Clone> std::fmt::Display for ArenaRef<Term, A> {
             TermTypeDiscriminants::Constructor => {
                 std::fmt::Display::fmt(&self.as_typed_term::<ConstructorTerm>().as_inner(), f)
             }
+            TermTypeDiscriminants::Duration => {
+                std::fmt::Display::fmt(&self.as_typed_term::<DurationTerm>().as_inner(), f)
+            }
             TermTypeDiscriminants::Effect => {
                 std::fmt::Display::fmt(&self.as_typed_term::<EffectTerm>().as_inner(), f)
             }
@@ -3362,6 +4056,12 @@ impl<A: Arena + Clone> std::fmt::Display for ArenaRef<Term, A> {
             TermTypeDiscriminants::Record => {
                 std::fmt::Display::fmt(&self.as_typed_term::<RecordTerm>().as_inner(), f)
             }
+            TermTypeDiscriminants::Recursive => {
+                std::fmt::Display::fmt(&self.as_typed_term::<RecursiveTerm>().as_inner(), f)
+            }
+            TermTypeDiscriminants::Regex => {
+                std::fmt::Display::fmt(&self.as_typed_term::<RegexTerm>().as_inner(), f)
+            }
             TermTypeDiscriminants::Signal => {
                 std::fmt::Display::fmt(&self.as_typed_term::<SignalTerm>().as_inner(), f)
             }
@@ -3380,6 +4080,9 @@ impl<A: Arena + Clone> std::fmt::Display for ArenaRef<Term, A> {
             TermTypeDiscriminants::Variable => {
                 std::fmt::Display::fmt(&self.as_typed_term::<VariableTerm>().as_inner(), f)
             }
+            TermTypeDiscriminants::DedupeIterator => {
+                std::fmt::Display::fmt(&self.as_typed_term::<DedupeIteratorTerm>().as_inner(), f)
+            }
             TermTypeDiscriminants::EmptyIterator => {
                 std::fmt::Display::fmt(&self.as_typed_term::<EmptyIteratorTerm>().as_inner(), f)
             }
@@ -3434,6 +4137,15 @@ impl<A: Arena + Clone> std::fmt::Display for ArenaRef<Term, A> {
             TermTypeDiscriminants::ZipIterator => {
                 std::fmt::Display::fmt(&self.as_typed_term::<ZipIteratorTerm>().as_inner(), f)
             }
+            TermTypeDiscriminants::ListView => {
+                std::fmt::Display::fmt(&self.as_typed_term::<ListViewTerm>().as_inner(), f)
+            }
+            TermTypeDiscriminants::ListRope => {
+                std::fmt::Display::fmt(&self.as_typed_term::<ListRopeTerm>().as_inner(), f)
+            }
+            TermTypeDiscriminants::Sensitive => {
+                std::fmt::Display::fmt(&self.as_typed_term::<SensitiveTerm>().as_inner(), f)
+            }
         }
     }
 }
@@ -3443,12 +4155,18 @@ impl<A: Arena + Clone> std::fmt::Debug for ArenaRef<TermType, A> {
             TermTypeDiscriminants::Application => {
                 std::fmt::Debug::fmt(&self.read_value(|value| *value), f)
             }
+            TermTypeDiscriminants::BigInt => {
+                std::fmt::Debug::fmt(&self.read_value(|value| *value), f)
+            }
             TermTypeDiscriminants::Boolean => {
                 std::fmt::Debug::fmt(&self.read_value(|value| *value), f)
             }
             TermTypeDiscriminants::Builtin => {
                 std::fmt::Debug::fmt(&self.read_value(|value| *value), f)
             }
+            TermTypeDiscriminants::Bytes => {
+                std::fmt::Debug::fmt(&self.read_value(|value| *value), f)
+            }
             TermTypeDiscriminants::Cell => {
                 std::fmt::Debug::fmt(&self.read_value(|value| *value), f)
             }
@@ -3458,6 +4176,9 @@ impl<A: Arena + Clone> std::fmt::Debug for ArenaRef<TermType, A> {
             TermTypeDiscriminants::Constructor => {
                 std::fmt::Debug::fmt(&self.read_value(|value| *value), f)
             }
+            TermTypeDiscriminants::Duration => {
+                std::fmt::Debug::fmt(&self.read_value(|value| *value), f)
+            }
             TermTypeDiscriminants::Effect => {
                 std::fmt::Debug::fmt(&self.read_value(|value| *value), f)
             }
@@ -3491,6 +4212,12 @@ impl<A: Arena + Clone> std::fmt::Debug for ArenaRef<TermType, A> {
             TermTypeDiscriminants::Record => {
                 std::fmt::Debug::fmt(&self.read_value(|value| *value), f)
             }
+            TermTypeDiscriminants::Recursive => {
+                std::fmt::Debug::fmt(&self.read_value(|value| *value), f)
+            }
+            TermTypeDiscriminants::Regex => {
+                std::fmt::Debug::fmt(&self.read_value(|value| *value), f)
+            }
             TermTypeDiscriminants::Signal => {
                 std::fmt::Debug::fmt(&self.read_value(|value| *value), f)
             }
@@ -3509,6 +4236,9 @@ impl<A: Arena + Clone> std::fmt::Debug for ArenaRef<TermType, A> {
             TermTypeDiscriminants::Variable => {
                 std::fmt::Debug::fmt(&self.read_value(|value| *value), f)
             }
+            TermTypeDiscriminants::DedupeIterator => {
+                std::fmt::Debug::fmt(&self.read_value(|value| *value), f)
+            }
             TermTypeDiscriminants::EmptyIterator => {
                 std::fmt::Debug::fmt(&self.read_value(|value| *value), f)
             }
@@ -3557,6 +4287,15 @@ impl<A: Arena + Clone> std::fmt::Debug for ArenaRef<TermType, A> {
             TermTypeDiscriminants::ZipIterator => {
                 std::fmt::Debug::fmt(&self.read_value(|value| *value), f)
             }
+            TermTypeDiscriminants::ListView => {
+                std::fmt::Debug::fmt(&self.read_value(|value| *value), f)
+            }
+            TermTypeDiscriminants::ListRope => {
+                std::fmt::Debug::fmt(&self.read_value(|value| *value), f)
+            }
+            TermTypeDiscriminants::Sensitive => {
+                std::fmt::Debug::fmt(&self.read_value(|value| *value), f)
+            }
         }
     }
 }
@@ -3582,11 +4321,14 @@ impl<V> TypedTerm<V> {
         unsafe {
             match &self.term.as_value() {
                 TermType::Application(inner) => std::mem::transmute::<&ApplicationTerm, &V>(inner),
+                TermType::BigInt(inner) => std::mem::transmute::<&BigIntTerm, &V>(inner),
                 TermType::Boolean(inner) => std::mem::transmute::<&BooleanTerm, &V>(inner),
                 TermType::Builtin(inner) => std::mem::transmute::<&BuiltinTerm, &V>(inner),
+                TermType::Bytes(inner) => std::mem::transmute::<&BytesTerm, &V>(inner),
                 TermType::Cell(inner) => std::mem::transmute::<&CellTerm, &V>(inner),
                 TermType::Condition(inner) => std::mem::transmute::<&ConditionTerm, &V>(inner),
                 TermType::Constructor(inner) => std::mem::transmute::<&ConstructorTerm, &V>(inner),
+                TermType::Duration(inner) => std::mem::transmute::<&DurationTerm, &V>(inner),
                 TermType::Effect(inner) => std::mem::transmute::<&EffectTerm, &V>(inner),
                 TermType::Float(inner) => std::mem::transmute::<&FloatTerm, &V>(inner),
                 TermType::Hashmap(inner) => std::mem::transmute::<&HashmapTerm, &V>(inner),
@@ -3600,12 +4342,17 @@ impl<V> TypedTerm<V> {
                 TermType::Partial(inner) => std::mem::transmute::<&PartialTerm, &V>(inner),
                 TermType::Pointer(inner) => std::mem::transmute::<&PointerTerm, &V>(inner),
                 TermType::Record(inner) => std::mem::transmute::<&RecordTerm, &V>(inner),
+                TermType::Recursive(inner) => std::mem::transmute::<&RecursiveTerm, &V>(inner),
+                TermType::Regex(inner) => std::mem::transmute::<&RegexTerm, &V>(inner),
                 TermType::Signal(inner) => std::mem::transmute::<&SignalTerm, &V>(inner),
                 TermType::String(inner) => std::mem::transmute::<&StringTerm, &V>(inner),
                 TermType::Symbol(inner) => std::mem::transmute::<&SymbolTerm, &V>(inner),
                 TermType::Timestamp(inner) => std::mem::transmute::<&TimestampTerm, &V>(inner),
                 TermType::Tree(inner) => std::mem::transmute::<&TreeTerm, &V>(inner),
                 TermType::Variable(inner) => std::mem::transmute::<&VariableTerm, &V>(inner),
+                TermType::DedupeIterator(inner) => {
+                    std::mem::transmute::<&DedupeIteratorTerm, &V>(inner)
+                }
                 TermType::EmptyIterator(inner) => {
                     std::mem::transmute::<&EmptyIteratorTerm, &V>(inner)
                 }
@@ -3650,6 +4397,9 @@ impl<V> TypedTerm<V> {
                     std::mem::transmute::<&TakeIteratorTerm, &V>(inner)
                 }
                 TermType::ZipIterator(inner) => std::mem::transmute::<&ZipIteratorTerm, &V>(inner),
+                TermType::ListView(inner) => std::mem::transmute::<&ListViewTerm, &V>(inner),
+                TermType::ListRope(inner) => std::mem::transmute::<&ListRopeTerm, &V>(inner),
+                TermType::Sensitive(inner) => std::mem::transmute::<&SensitiveTerm, &V>(inner),
             }
         }
     }
@@ -3703,6 +4453,18 @@ impl<A: Arena + Clone> ArenaRef<Term, A> {
             _ => None,
         }
     }
+    pub fn as_big_int_term(&self) -> Option<&ArenaRef<TypedTerm<BigIntTerm>, A>> {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::BigInt => Some(self.as_typed_term::<BigIntTerm>()),
+            _ => None,
+        }
+    }
+    pub fn into_big_int_term(self) -> Option<ArenaRef<TypedTerm<BigIntTerm>, A>> {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::BigInt => Some(self.into_typed_term::<BigIntTerm>()),
+            _ => None,
+        }
+    }
     pub fn into_boolean_term(self) -> Option<ArenaRef<TypedTerm<BooleanTerm>, A>> {
         match self.read_value(|term| term.type_id()) {
             TermTypeDiscriminants::Boolean => Some(self.into_typed_term::<BooleanTerm>()),
@@ -3721,6 +4483,18 @@ impl<A: Arena + Clone> ArenaRef<Term, A> {
             _ => None,
         }
     }
+    pub fn as_bytes_term(&self) -> Option<&ArenaRef<TypedTerm<BytesTerm>, A>> {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::Bytes => Some(self.as_typed_term::<BytesTerm>()),
+            _ => None,
+        }
+    }
+    pub fn into_bytes_term(self) -> Option<ArenaRef<TypedTerm<BytesTerm>, A>> {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::Bytes => Some(self.into_typed_term::<BytesTerm>()),
+            _ => None,
+        }
+    }
     pub fn as_cell_term(&self) -> Option<&ArenaRef<TypedTerm<CellTerm>, A>> {
         match self.read_value(|term| term.type_id()) {
             TermTypeDiscriminants::Cell => Some(self.as_typed_term::<CellTerm>()),
@@ -3757,6 +4531,18 @@ impl<A: Arena + Clone> ArenaRef<Term, A> {
             _ => None,
         }
     }
+    pub fn as_duration_term(&self) -> Option<&ArenaRef<TypedTerm<DurationTerm>, A>> {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::Duration => Some(self.as_typed_term::<DurationTerm>()),
+            _ => None,
+        }
+    }
+    pub fn into_duration_term(self) -> Option<ArenaRef<TypedTerm<DurationTerm>, A>> {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::Duration => Some(self.into_typed_term::<DurationTerm>()),
+            _ => None,
+        }
+    }
     pub fn as_effect_term(&self) -> Option<&ArenaRef<TypedTerm<EffectTerm>, A>> {
         match self.read_value(|term| term.type_id()) {
             TermTypeDiscriminants::Effect => Some(self.as_typed_term::<EffectTerm>()),
@@ -3913,6 +4699,30 @@ impl<A: Arena + Clone> ArenaRef<Term, A> {
             _ => None,
         }
     }
+    pub fn as_recursive_term(&self) -> Option<&ArenaRef<TypedTerm<RecursiveTerm>, A>> {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::Recursive => Some(self.as_typed_term::<RecursiveTerm>()),
+            _ => None,
+        }
+    }
+    pub fn into_recursive_term(self) -> Option<ArenaRef<TypedTerm<RecursiveTerm>, A>> {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::Recursive => Some(self.into_typed_term::<RecursiveTerm>()),
+            _ => None,
+        }
+    }
+    pub fn as_regex_term(&self) -> Option<&ArenaRef<TypedTerm<RegexTerm>, A>> {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::Regex => Some(self.as_typed_term::<RegexTerm>()),
+            _ => None,
+        }
+    }
+    pub fn into_regex_term(self) -> Option<ArenaRef<TypedTerm<RegexTerm>, A>> {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::Regex => Some(self.into_typed_term::<RegexTerm>()),
+            _ => None,
+        }
+    }
     pub fn as_signal_term(&self) -> Option<&ArenaRef<TypedTerm<SignalTerm>, A>> {
         match self.read_value(|term| term.type_id()) {
             TermTypeDiscriminants::Signal => Some(self.as_typed_term::<SignalTerm>()),
@@ -3985,6 +4795,22 @@ impl<A: Arena + Clone> ArenaRef<Term, A> {
             _ => None,
         }
     }
+    pub fn as_dedupe_iterator_term(&self) -> Option<&ArenaRef<TypedTerm<DedupeIteratorTerm>, A>> {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::DedupeIterator => {
+                Some(self.as_typed_term::<DedupeIteratorTerm>())
+            }
+            _ => None,
+        }
+    }
+    pub fn into_dedupe_iterator_term(self) -> Option<ArenaRef<TypedTerm<DedupeIteratorTerm>, A>> {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::DedupeIterator => {
+                Some(self.into_typed_term::<DedupeIteratorTerm>())
+            }
+            _ => None,
+        }
+    }
     pub fn as_empty_iterator_term(&self) -> Option<&ArenaRef<TypedTerm<EmptyIteratorTerm>, A>> {
         match self.read_value(|term| term.type_id()) {
             TermTypeDiscriminants::EmptyIterator => Some(self.as_typed_term::<EmptyIteratorTerm>()),
@@ -4241,6 +5067,42 @@ impl<A: Arena + Clone> ArenaRef<Term, A> {
             _ => None,
         }
     }
+    pub fn as_list_view_term(&self) -> Option<&ArenaRef<TypedTerm<ListViewTerm>, A>> {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::ListView => Some(self.as_typed_term::<ListViewTerm>()),
+            _ => None,
+        }
+    }
+    pub fn into_list_view_term(self) -> Option<ArenaRef<TypedTerm<ListViewTerm>, A>> {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::ListView => Some(self.into_typed_term::<ListViewTerm>()),
+            _ => None,
+        }
+    }
+    pub fn as_list_rope_term(&self) -> Option<&ArenaRef<TypedTerm<ListRopeTerm>, A>> {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::ListRope => Some(self.as_typed_term::<ListRopeTerm>()),
+            _ => None,
+        }
+    }
+    pub fn as_sensitive_term(&self) -> Option<&ArenaRef<TypedTerm<SensitiveTerm>, A>> {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::Sensitive => Some(self.as_typed_term::<SensitiveTerm>()),
+            _ => None,
+        }
+    }
+    pub fn into_list_rope_term(self) -> Option<ArenaRef<TypedTerm<ListRopeTerm>, A>> {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::ListRope => Some(self.into_typed_term::<ListRopeTerm>()),
+            _ => None,
+        }
+    }
+    pub fn into_sensitive_term(self) -> Option<ArenaRef<TypedTerm<SensitiveTerm>, A>> {
+        match self.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::Sensitive => Some(self.into_typed_term::<SensitiveTerm>()),
+            _ => None,
+        }
+    }
 }
 
 impl<A: Arena + Clone, V> NodeId for ArenaRef<TypedTerm<V>, A> {
@@ -4321,45 +5183,54 @@ mod tests {
     fn term_type() {
         assert_eq!(std::mem::size_of::<TermType>(), 20);
         assert_eq!(TermTypeDiscriminants::Application as u32, 0);
-        assert_eq!(TermTypeDiscriminants::Boolean as u32, 1);
-        assert_eq!(TermTypeDiscriminants::Builtin as u32, 2);
-        assert_eq!(TermTypeDiscriminants::Cell as u32, 3);
-        assert_eq!(TermTypeDiscriminants::Condition as u32, 4);
-        assert_eq!(TermTypeDiscriminants::Constructor as u32, 5);
-        assert_eq!(TermTypeDiscriminants::Effect as u32, 6);
-        assert_eq!(TermTypeDiscriminants::Float as u32, 7);
-        assert_eq!(TermTypeDiscriminants::Hashmap as u32, 8);
-        assert_eq!(TermTypeDiscriminants::Hashset as u32, 9);
-        assert_eq!(TermTypeDiscriminants::Int as u32, 10);
-        assert_eq!(TermTypeDiscriminants::Lambda as u32, 11);
-        assert_eq!(TermTypeDiscriminants::LazyResult as u32, 12);
-        assert_eq!(TermTypeDiscriminants::Let as u32, 13);
-        assert_eq!(TermTypeDiscriminants::List as u32, 14);
-        assert_eq!(TermTypeDiscriminants::Nil as u32, 15);
-        assert_eq!(TermTypeDiscriminants::Partial as u32, 16);
-        assert_eq!(TermTypeDiscriminants::Pointer as u32, 17);
-        assert_eq!(TermTypeDiscriminants::Record as u32, 18);
-        assert_eq!(TermTypeDiscriminants::Signal as u32, 19);
-        assert_eq!(TermTypeDiscriminants::String as u32, 20);
-        assert_eq!(TermTypeDiscriminants::Symbol as u32, 21);
-        assert_eq!(TermTypeDiscriminants::Timestamp as u32, 22);
-        assert_eq!(TermTypeDiscriminants::Tree as u32, 23);
-        assert_eq!(TermTypeDiscriminants::Variable as u32, 24);
-        assert_eq!(TermTypeDiscriminants::EmptyIterator as u32, 25);
-        assert_eq!(TermTypeDiscriminants::EvaluateIterator as u32, 26);
-        assert_eq!(TermTypeDiscriminants::FilterIterator as u32, 27);
-        assert_eq!(TermTypeDiscriminants::FlattenIterator as u32, 28);
-        assert_eq!(TermTypeDiscriminants::HashmapKeysIterator as u32, 29);
-        assert_eq!(TermTypeDiscriminants::HashmapValuesIterator as u32, 30);
-        assert_eq!(TermTypeDiscriminants::IndexedAccessorIterator as u32, 31);
-        assert_eq!(TermTypeDiscriminants::IntegersIterator as u32, 32);
-        assert_eq!(TermTypeDiscriminants::IntersperseIterator as u32, 33);
-        assert_eq!(TermTypeDiscriminants::MapIterator as u32, 34);
-        assert_eq!(TermTypeDiscriminants::OnceIterator as u32, 35);
-        assert_eq!(TermTypeDiscriminants::RangeIterator as u32, 36);
-        assert_eq!(TermTypeDiscriminants::RepeatIterator as u32, 37);
-        assert_eq!(TermTypeDiscriminants::SkipIterator as u32, 38);
-        assert_eq!(TermTypeDiscriminants::TakeIterator as u32, 39);
-        assert_eq!(TermTypeDiscriminants::ZipIterator as u32, 40);
+        assert_eq!(TermTypeDiscriminants::BigInt as u32, 1);
+        assert_eq!(TermTypeDiscriminants::Boolean as u32, 2);
+        assert_eq!(TermTypeDiscriminants::Builtin as u32, 3);
+        assert_eq!(TermTypeDiscriminants::Bytes as u32, 4);
+        assert_eq!(TermTypeDiscriminants::Cell as u32, 5);
+        assert_eq!(TermTypeDiscriminants::Condition as u32, 6);
+        assert_eq!(TermTypeDiscriminants::Constructor as u32, 7);
+        assert_eq!(TermTypeDiscriminants::Duration as u32, 8);
+        assert_eq!(TermTypeDiscriminants::Effect as u32, 9);
+        assert_eq!(TermTypeDiscriminants::Float as u32, 10);
+        assert_eq!(TermTypeDiscriminants::Hashmap as u32, 11);
+        assert_eq!(TermTypeDiscriminants::Hashset as u32, 12);
+        assert_eq!(TermTypeDiscriminants::Int as u32, 13);
+        assert_eq!(TermTypeDiscriminants::Lambda as u32, 14);
+        assert_eq!(TermTypeDiscriminants::LazyResult as u32, 15);
+        assert_eq!(TermTypeDiscriminants::Let as u32, 16);
+        assert_eq!(TermTypeDiscriminants::List as u32, 17);
+        assert_eq!(TermTypeDiscriminants::Nil as u32, 18);
+        assert_eq!(TermTypeDiscriminants::Partial as u32, 19);
+        assert_eq!(TermTypeDiscriminants::Pointer as u32, 20);
+        assert_eq!(TermTypeDiscriminants::Record as u32, 21);
+        assert_eq!(TermTypeDiscriminants::Recursive as u32, 22);
+        assert_eq!(TermTypeDiscriminants::Regex as u32, 23);
+        assert_eq!(TermTypeDiscriminants::Signal as u32, 24);
+        assert_eq!(TermTypeDiscriminants::String as u32, 25);
+        assert_eq!(TermTypeDiscriminants::Symbol as u32, 26);
+        assert_eq!(TermTypeDiscriminants::Timestamp as u32, 27);
+        assert_eq!(TermTypeDiscriminants::Tree as u32, 28);
+        assert_eq!(TermTypeDiscriminants::Variable as u32, 29);
+        assert_eq!(TermTypeDiscriminants::DedupeIterator as u32, 30);
+        assert_eq!(TermTypeDiscriminants::EmptyIterator as u32, 31);
+        assert_eq!(TermTypeDiscriminants::EvaluateIterator as u32, 32);
+        assert_eq!(TermTypeDiscriminants::FilterIterator as u32, 33);
+        assert_eq!(TermTypeDiscriminants::FlattenIterator as u32, 34);
+        assert_eq!(TermTypeDiscriminants::HashmapKeysIterator as u32, 35);
+        assert_eq!(TermTypeDiscriminants::HashmapValuesIterator as u32, 36);
+        assert_eq!(TermTypeDiscriminants::IndexedAccessorIterator as u32, 37);
+        assert_eq!(TermTypeDiscriminants::IntegersIterator as u32, 38);
+        assert_eq!(TermTypeDiscriminants::IntersperseIterator as u32, 39);
+        assert_eq!(TermTypeDiscriminants::MapIterator as u32, 40);
+        assert_eq!(TermTypeDiscriminants::OnceIterator as u32, 41);
+        assert_eq!(TermTypeDiscriminants::RangeIterator as u32, 42);
+        assert_eq!(TermTypeDiscriminants::RepeatIterator as u32, 43);
+        assert_eq!(TermTypeDiscriminants::SkipIterator as u32, 44);
+        assert_eq!(TermTypeDiscriminants::TakeIterator as u32, 45);
+        assert_eq!(TermTypeDiscriminants::ZipIterator as u32, 46);
+        assert_eq!(TermTypeDiscriminants::ListView as u32, 47);
+        assert_eq!(TermTypeDiscriminants::ListRope as u32, 48);
+        assert_eq!(TermTypeDiscriminants::Sensitive as u32, 49);
     }
 }
@@ -4,10 +4,7 @@
 // SPDX-FileContributor: Jordan Hall <j.hall@mwam.com> https://github.com/j-hall-mwam
 use std::collections::HashSet;
 
-use reflex::core::{
-    ArgType, DependencyList, Expression, GraphNode, NilTermType, RecursiveTermType, SerializeJson,
-    StackOffset,
-};
+use reflex::core::{ArgType, DependencyList, GraphNode, NilTermType, SerializeJson, StackOffset};
 use reflex_macros::PointerIter;
 use serde_json::Value as JsonValue;
 
@@ -18,7 +15,7 @@ use crate::{
         CompilerOptions, CompilerResult, CompilerStack, CompilerState, Internable,
     },
     hash::{TermHash, TermHasher, TermSize},
-    term_type::{TypedTerm, WasmExpression},
+    term_type::TypedTerm,
     ArenaRef,
 };
 
@@ -40,16 +37,6 @@ impl<A: Arena + Clone> NilTermType for ArenaRef<NilTerm, A> {}
 
 impl<A: Arena + Clone> NilTermType for ArenaRef<TypedTerm<NilTerm>, A> {}
 
-// FIXME: implement RecursiveTerm
-impl<A: Arena + Clone> RecursiveTermType<WasmExpression<A>> for ArenaRef<TypedTerm<NilTerm>, A> {
-    fn factory<'a>(&'a self) -> <WasmExpression<A> as Expression>::ExpressionRef<'a>
-    where
-        WasmExpression<A>: 'a,
-    {
-        panic!("Recursive terms not currently supported")
-    }
-}
-
 impl<A: Arena + Clone> GraphNode for ArenaRef<NilTerm, A> {
     fn size(&self) -> usize {
         1
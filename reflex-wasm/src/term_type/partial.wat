@@ -18,7 +18,8 @@
   (export "getPartialArgs" (func $Term::Partial::get::args))
 
   (func $Term::Partial::new (export "createPartial") (param $target i32) (param $args i32) (result i32)
-    (call $Term::TermType::Partial::new (local.get $target) (local.get $args)))
+    ;; Any list views or list ropes are flattened, to ensure that the argument list is stored as a contiguous list
+    (call $Term::TermType::Partial::new (local.get $target) (call $Term::List::flatten (local.get $args))))
 
   (func $Term::Partial::traits::is_atomic (param $self i32) (result i32)
     (i32.and
@@ -196,12 +196,12 @@ impl<A: Arena + Clone> SerializeJson for ArenaRef<RecordTerm, A> {
         let entries = keys.zip(values);
         let fields = entries
             .map(|(key, value)| {
-                let key = key.to_json()?;
+                let key = match key.as_string_term() {
+                    Some(key) => key.read_str(str::to_owned),
+                    None => return Err(format!("Invalid JSON object key: {}", key)),
+                };
                 let value = value.to_json()?;
-                match key {
-                    JsonValue::String(key) => Ok((key, value)),
-                    _ => Err(format!("Invalid JSON object key: {}", key.to_string())),
-                }
+                Ok((key, value))
             })
             .collect::<Result<JsonMap<_, _>, String>>()?;
         Ok(JsonValue::Object(fields))
@@ -234,9 +234,9 @@ impl<A: Arena + Clone> SerializeJson for ArenaRef<RecordTerm, A> {
                 })
                 .filter_map(|entry| entry.transpose()) // Filter out unchanged fields
                 .map(|entry| {
-                    entry.and_then(|(key, value)| match key.to_json()? {
-                        JsonValue::String(key) => Ok((key, value)),
-                        _ => Err(format!("Invalid JSON object key: {}", key.to_string())),
+                    entry.and_then(|(key, value)| match key.as_string_term() {
+                        Some(key) => Ok((key.read_str(str::to_owned), value)),
+                        None => Err(format!("Invalid JSON object key: {}", key)),
                     })
                 })
                 .collect::<Result<JsonMap<_, _>, _>>()?,
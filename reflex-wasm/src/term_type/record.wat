@@ -55,9 +55,10 @@
         ;; Return the pre-allocated singleton instance
         (global.get $Term::Record::EMPTY))
       (else
+        ;; Any list views or list ropes are flattened, to ensure that the keys and values are stored as contiguous lists
         (call $Term::TermType::Record::new
-          (local.get $keys)
-          (local.get $values)
+          (local.tee $keys (call $Term::List::flatten (local.get $keys)))
+          (local.tee $values (call $Term::List::flatten (local.get $values)))
           ;; Determine whether to allocate a lookup hashmap depending on the number of record fields
           (if (result i32)
             (i32.ge_u (call $Term::List::traits::length (local.get $keys)) (global.get $Term::Record::LOOKUP_TABLE_MIN_SIZE))
@@ -0,0 +1,178 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::collections::HashSet;
+
+use reflex::core::{
+    ArgType, DependencyList, Expression, GraphNode, RecursiveTermType, SerializeJson, StackOffset,
+};
+use reflex_macros::PointerIter;
+use serde_json::Value as JsonValue;
+
+use crate::{
+    allocator::Arena,
+    compiler::{
+        instruction, runtime::builtin::RuntimeBuiltin, CompileWasm, CompiledBlockBuilder,
+        CompilerOptions, CompilerResult, CompilerStack, CompilerState, Internable,
+    },
+    hash::{TermHash, TermHasher, TermSize},
+    term_type::{TypedTerm, WasmExpression},
+    ArenaPointer, ArenaRef, Term,
+};
+
+#[derive(Clone, Copy, Debug, PointerIter)]
+#[repr(C)]
+pub struct RecursiveTerm {
+    pub factory: ArenaPointer,
+}
+impl TermSize for RecursiveTerm {
+    fn size_of(&self) -> usize {
+        std::mem::size_of::<Self>()
+    }
+}
+impl TermHash for RecursiveTerm {
+    fn hash(&self, hasher: TermHasher, arena: &impl Arena) -> TermHasher {
+        let factory_hash = arena.read_value::<Term, _>(self.factory, |term| term.id());
+        hasher.hash(&factory_hash, arena)
+    }
+}
+
+impl<A: Arena + Clone> ArenaRef<RecursiveTerm, A> {
+    pub fn factory(&self) -> ArenaRef<Term, A> {
+        ArenaRef::<Term, _>::new(self.arena.clone(), self.read_value(|term| term.factory))
+    }
+}
+
+impl<A: Arena + Clone> RecursiveTermType<WasmExpression<A>> for ArenaRef<RecursiveTerm, A> {
+    fn factory<'a>(&'a self) -> <WasmExpression<A> as Expression>::ExpressionRef<'a>
+    where
+        WasmExpression<A>: 'a,
+    {
+        self.factory()
+    }
+}
+
+impl<A: Arena + Clone> RecursiveTermType<WasmExpression<A>>
+    for ArenaRef<TypedTerm<RecursiveTerm>, A>
+{
+    fn factory<'a>(&'a self) -> <WasmExpression<A> as Expression>::ExpressionRef<'a>
+    where
+        WasmExpression<A>: 'a,
+    {
+        <ArenaRef<RecursiveTerm, A> as RecursiveTermType<WasmExpression<A>>>::factory(
+            &self.as_inner(),
+        )
+    }
+}
+
+impl<A: Arena + Clone> GraphNode for ArenaRef<RecursiveTerm, A> {
+    fn size(&self) -> usize {
+        1 + self.factory().size()
+    }
+    fn capture_depth(&self) -> StackOffset {
+        self.factory().capture_depth()
+    }
+    fn free_variables(&self) -> HashSet<StackOffset> {
+        self.factory().free_variables()
+    }
+    fn count_variable_usages(&self, offset: StackOffset) -> usize {
+        self.factory().count_variable_usages(offset)
+    }
+    fn dynamic_dependencies(&self, deep: bool) -> DependencyList {
+        self.factory().dynamic_dependencies(deep)
+    }
+    fn has_dynamic_dependencies(&self, deep: bool) -> bool {
+        self.factory().has_dynamic_dependencies(deep)
+    }
+    fn is_static(&self) -> bool {
+        false
+    }
+    fn is_atomic(&self) -> bool {
+        self.factory().is_atomic()
+    }
+    fn is_complex(&self) -> bool {
+        true
+    }
+}
+
+impl<A: Arena + Clone> SerializeJson for ArenaRef<RecursiveTerm, A> {
+    fn to_json(&self) -> Result<JsonValue, String> {
+        Err(format!("Unable to serialize term: {}", self))
+    }
+    fn patch(&self, target: &Self) -> Result<Option<JsonValue>, String> {
+        Err(format!(
+            "Unable to create patch for terms: {}, {}",
+            self, target
+        ))
+    }
+}
+
+impl<A: Arena + Clone> PartialEq for ArenaRef<RecursiveTerm, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.factory() == other.factory()
+    }
+}
+impl<A: Arena + Clone> Eq for ArenaRef<RecursiveTerm, A> {}
+
+impl<A: Arena + Clone> std::fmt::Debug for ArenaRef<RecursiveTerm, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.read_value(|term| std::fmt::Debug::fmt(term, f))
+    }
+}
+
+impl<A: Arena + Clone> std::fmt::Display for ArenaRef<RecursiveTerm, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<recursive:{}>", self.factory())
+    }
+}
+
+impl<A: Arena + Clone> Internable for ArenaRef<RecursiveTerm, A> {
+    fn should_intern(&self, eager: ArgType) -> bool {
+        match eager {
+            ArgType::Strict | ArgType::Eager => false,
+            ArgType::Lazy => self.factory().should_intern(eager),
+        }
+    }
+}
+
+impl<A: Arena + Clone> CompileWasm<A> for ArenaRef<RecursiveTerm, A> {
+    fn compile(
+        &self,
+        stack: CompilerStack,
+        state: &mut CompilerState,
+        options: &CompilerOptions,
+    ) -> CompilerResult<A> {
+        let factory = self.factory();
+        let block = CompiledBlockBuilder::new(stack);
+        // Yield the factory function onto the stack
+        // => [Term]
+        let block = block.append_inner(|stack| factory.compile(stack, state, options))?;
+        // Invoke the term constructor
+        // => [RecursiveTerm]
+        let block = block.push(instruction::runtime::CallRuntimeBuiltin {
+            target: RuntimeBuiltin::CreateRecursive,
+        });
+        // Evaluate the result
+        // => [Term]
+        let block = block.push(instruction::runtime::Evaluate);
+        block.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::term_type::{TermType, TermTypeDiscriminants};
+
+    use super::*;
+
+    #[test]
+    fn recursive() {
+        assert_eq!(
+            TermType::Recursive(RecursiveTerm {
+                factory: ArenaPointer(0x54321),
+            })
+            .as_bytes(),
+            [TermTypeDiscriminants::Recursive as u32, 0x54321],
+        );
+    }
+}
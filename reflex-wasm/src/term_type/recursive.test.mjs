@@ -0,0 +1,95 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Term::Recursive', (test) => {
+    test('format', (assert, { createLambda, createRecursive, createVariable, format }) => {
+      assert.strictEqual(
+        format(createRecursive(createLambda(1, createVariable(0)))),
+        '<recursive:(1) => Variable(0)>',
+      );
+    });
+
+    test('hash', (assert, { createInt, createLambda, createRecursive, createVariable, hash }) => {
+      assert.strictEqual(
+        hash(createRecursive(createLambda(1, createVariable(0)))),
+        hash(createRecursive(createLambda(1, createVariable(0)))),
+      );
+      assert.notStrictEqual(
+        hash(createRecursive(createLambda(1, createVariable(0)))),
+        hash(createRecursive(createLambda(1, createInt(3)))),
+      );
+      assert.notStrictEqual(
+        hash(createRecursive(createLambda(1, createVariable(0)))),
+        hash(createLambda(1, createVariable(0))),
+      );
+    });
+
+    test('equals', (assert, { createInt, createLambda, createRecursive, createVariable, equals }) => {
+      assert.strictEqual(
+        equals(
+          createRecursive(createLambda(1, createVariable(0))),
+          createRecursive(createLambda(1, createVariable(0))),
+        ),
+        true,
+      );
+      assert.strictEqual(
+        equals(
+          createRecursive(createLambda(1, createVariable(0))),
+          createRecursive(createLambda(1, createInt(3))),
+        ),
+        false,
+      );
+    });
+
+    test('self-referential structures', (assert, {
+      createApplication,
+      createBuiltin,
+      createInt,
+      createLambda,
+      createPair,
+      createRecursive,
+      createVariable,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const cycle = createRecursive(createLambda(1, createPair(createInt(3), createVariable(0))));
+      const expression = createApplication(
+        createBuiltin(Stdlib.Get),
+        createPair(
+          createApplication(
+            createBuiltin(Stdlib.Get),
+            createPair(
+              createApplication(createBuiltin(Stdlib.Get), createPair(cycle, createInt(1))),
+              createInt(1),
+            ),
+          ),
+          createInt(0),
+        ),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(format(result), '3');
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+
+    test('lazy self-references', (assert, {
+      createInt,
+      createLambda,
+      createPair,
+      createRecursive,
+      createVariable,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+    }) => {
+      const cycle = createRecursive(createLambda(1, createPair(createInt(3), createVariable(0))));
+      const [result, dependencies] = evaluate(cycle, NULL);
+      assert.strictEqual(format(result), '[3, <recursive:(1) => [3, Variable(0)]>]');
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+  });
+};
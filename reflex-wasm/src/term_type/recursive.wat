@@ -0,0 +1,96 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@let $Recursive
+    (@struct $Recursive
+      (@field $factory (@ref $Term)))
+
+    (@derive $size (@get $Recursive))
+    (@derive $equals (@get $Recursive))
+    (@derive $hash (@get $Recursive))
+
+    (@export $Recursive (@get $Recursive)))
+
+  (export "isRecursive" (func $Term::Recursive::is))
+  (export "getRecursiveFactory" (func $Term::Recursive::get::factory))
+
+  ;; Linked list of recursive terms whose factories are currently being evaluated (or NULL if there are none),
+  ;; stored as a chain of tree terms where the left branch holds the term and the right branch holds the remainder
+  (global $Term::Recursive::ACTIVE (mut i32) (i32.const 0xFFFFFFFF))
+
+  (func $Term::Recursive::new (export "createRecursive") (param $factory i32) (result i32)
+    (call $Term::TermType::Recursive::new (local.get $factory)))
+
+  (func $Term::Recursive::traits::is_atomic (param $self i32) (result i32)
+    (call $Term::traits::is_atomic (call $Term::Recursive::get::factory (local.get $self))))
+
+  (func $Term::Recursive::traits::display (param $self i32) (param $offset i32) (result i32)
+    (@store-bytes $offset "<recursive:")
+    (local.set $offset (i32.add (local.get $offset)))
+    (local.set $offset
+      (call $Term::traits::debug
+        (call $Term::Recursive::get::factory (local.get $self))
+        (local.get $offset)))
+    (@store-bytes $offset ">")
+    (i32.add (local.get $offset)))
+
+  (func $Term::Recursive::traits::debug (param $self i32) (param $offset i32) (result i32)
+    (call $Term::Recursive::traits::display (local.get $self) (local.get $offset)))
+
+  (func $Term::Recursive::traits::substitute (param $self i32) (param $variables i32) (param $scope_offset i32) (result i32)
+    (local $substituted_factory i32)
+    (if (result i32)
+      (i32.eq
+        (global.get $NULL)
+        (local.tee $substituted_factory
+          (call $Term::traits::substitute
+            (call $Term::Recursive::get::factory (local.get $self))
+            (local.get $variables)
+            (local.get $scope_offset))))
+      (then
+        (global.get $NULL))
+      (else
+        (call $Term::Recursive::new (local.get $substituted_factory)))))
+
+  (func $Term::Recursive::traits::evaluate (param $self i32) (param $state i32) (result i32 i32)
+    (local $result i32)
+    (local $dependencies i32)
+    (if
+      ;; If this term is already being unrolled further up the call stack, return the term itself as a lazy
+      ;; self-reference (this prevents the factory from recursing infinitely when its arguments are evaluated eagerly,
+      ;; as any further unrolling will be deferred until the self-reference is evaluated outside the factory)
+      (call $Term::Recursive::is_active (local.get $self))
+      (then
+        (return (local.get $self) (global.get $NULL))))
+    ;; Mark the term as active for the duration of the factory evaluation
+    (global.set $Term::Recursive::ACTIVE
+      (call $Term::Tree::new (local.get $self) (global.get $Term::Recursive::ACTIVE)))
+    ;; Apply the factory to the recursive term itself and evaluate the result
+    (call $Term::traits::evaluate
+      (call $Term::Application::new
+        (call $Term::Recursive::get::factory (local.get $self))
+        (call $Term::List::of (local.get $self)))
+      (local.get $state))
+    (local.set $dependencies)
+    (local.set $result)
+    ;; Restore the previous set of active terms
+    (global.set $Term::Recursive::ACTIVE
+      (call $Term::Tree::get::right (global.get $Term::Recursive::ACTIVE)))
+    (local.get $result)
+    (local.get $dependencies))
+
+  (func $Term::Recursive::is_active (param $self i32) (result i32)
+    (local $active i32)
+    (local.set $active (global.get $Term::Recursive::ACTIVE))
+    (loop $LOOP
+      (if
+        (i32.ne (global.get $NULL) (local.get $active))
+        (then
+          (if
+            (call $Term::traits::equals (call $Term::Tree::get::left (local.get $active)) (local.get $self))
+            (then
+              (return (global.get $TRUE))))
+          (local.set $active (call $Term::Tree::get::right (local.get $active)))
+          (br $LOOP))))
+    (global.get $FALSE)))
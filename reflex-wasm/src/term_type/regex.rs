@@ -0,0 +1,186 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{collections::HashSet, sync::Arc};
+
+use reflex::{
+    core::{
+        ArgType, DependencyList, Expression, GraphNode, RegexTermType, SerializeJson, StackOffset,
+    },
+    regex::{compile_regex, Regex},
+};
+use reflex_macros::PointerIter;
+use serde_json::Value as JsonValue;
+
+use crate::{
+    allocator::Arena,
+    compiler::{
+        instruction, runtime::builtin::RuntimeBuiltin, CompileWasm, CompiledBlockBuilder,
+        CompilerOptions, CompilerResult, CompilerStack, CompilerState, Internable,
+    },
+    hash::{TermHash, TermHasher, TermSize},
+    term_type::{StringTerm, TypedTerm, WasmExpression},
+    ArenaPointer, ArenaRef, Term,
+};
+
+#[derive(Clone, Copy, Debug, PointerIter)]
+#[repr(C)]
+pub struct RegexTerm {
+    pub pattern: ArenaPointer,
+}
+impl TermSize for RegexTerm {
+    fn size_of(&self) -> usize {
+        std::mem::size_of::<Self>()
+    }
+}
+impl TermHash for RegexTerm {
+    fn hash(&self, hasher: TermHasher, arena: &impl Arena) -> TermHasher {
+        let pattern_hash = arena.read_value::<Term, _>(self.pattern, |term| term.id());
+        hasher.hash(&pattern_hash, arena)
+    }
+}
+
+impl<A: Arena + Clone> ArenaRef<RegexTerm, A> {
+    pub fn pattern(&self) -> ArenaRef<TypedTerm<StringTerm>, A> {
+        ArenaRef::<TypedTerm<StringTerm>, _>::new(
+            self.arena.clone(),
+            self.read_value(|term| term.pattern),
+        )
+    }
+    pub fn compiled(&self) -> Result<Arc<Regex>, String> {
+        self.pattern().read_str(compile_regex)
+    }
+}
+
+impl<A: Arena + Clone> RegexTermType<WasmExpression<A>> for ArenaRef<RegexTerm, A> {
+    fn pattern<'a>(&'a self) -> <WasmExpression<A> as Expression>::StringRef<'a>
+    where
+        <WasmExpression<A> as Expression>::String: 'a,
+        WasmExpression<A>: 'a,
+    {
+        self.pattern()
+    }
+    fn compiled(&self) -> Result<Arc<Regex>, String> {
+        self.compiled()
+    }
+}
+
+impl<A: Arena + Clone> RegexTermType<WasmExpression<A>> for ArenaRef<TypedTerm<RegexTerm>, A> {
+    fn pattern<'a>(&'a self) -> <WasmExpression<A> as Expression>::StringRef<'a>
+    where
+        <WasmExpression<A> as Expression>::String: 'a,
+        WasmExpression<A>: 'a,
+    {
+        <ArenaRef<RegexTerm, A> as RegexTermType<WasmExpression<A>>>::pattern(&self.as_inner())
+    }
+    fn compiled(&self) -> Result<Arc<Regex>, String> {
+        self.as_inner().compiled()
+    }
+}
+
+impl<A: Arena + Clone> GraphNode for ArenaRef<RegexTerm, A> {
+    fn size(&self) -> usize {
+        1
+    }
+    fn capture_depth(&self) -> StackOffset {
+        0
+    }
+    fn free_variables(&self) -> HashSet<StackOffset> {
+        HashSet::new()
+    }
+    fn count_variable_usages(&self, _offset: StackOffset) -> usize {
+        0
+    }
+    fn dynamic_dependencies(&self, _deep: bool) -> DependencyList {
+        DependencyList::empty()
+    }
+    fn has_dynamic_dependencies(&self, _deep: bool) -> bool {
+        false
+    }
+    fn is_static(&self) -> bool {
+        true
+    }
+    fn is_atomic(&self) -> bool {
+        true
+    }
+    fn is_complex(&self) -> bool {
+        false
+    }
+}
+
+impl<A: Arena + Clone> SerializeJson for ArenaRef<RegexTerm, A> {
+    fn to_json(&self) -> Result<JsonValue, String> {
+        Err(format!("Unable to serialize term: {}", self))
+    }
+    fn patch(&self, target: &Self) -> Result<Option<JsonValue>, String> {
+        Err(format!(
+            "Unable to create patch for terms: {}, {}",
+            self, target
+        ))
+    }
+}
+
+impl<A: Arena + Clone> PartialEq for ArenaRef<RegexTerm, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern() == other.pattern()
+    }
+}
+impl<A: Arena + Clone> Eq for ArenaRef<RegexTerm, A> {}
+
+impl<A: Arena + Clone> std::fmt::Debug for ArenaRef<RegexTerm, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.read_value(|term| std::fmt::Debug::fmt(term, f))
+    }
+}
+
+impl<A: Arena + Clone> std::fmt::Display for ArenaRef<RegexTerm, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.pattern()
+            .read_str(|pattern| write!(f, "/{}/", pattern))
+    }
+}
+
+impl<A: Arena + Clone> Internable for ArenaRef<RegexTerm, A> {
+    fn should_intern(&self, _eager: ArgType) -> bool {
+        true
+    }
+}
+
+impl<A: Arena + Clone> CompileWasm<A> for ArenaRef<RegexTerm, A> {
+    fn compile(
+        &self,
+        stack: CompilerStack,
+        state: &mut CompilerState,
+        options: &CompilerOptions,
+    ) -> CompilerResult<A> {
+        let pattern = self.pattern();
+        let block = CompiledBlockBuilder::new(stack);
+        // Yield the pattern string onto the stack
+        // => [StringTerm]
+        let block = block.append_inner(|stack| pattern.as_term().compile(stack, state, options))?;
+        // Invoke the term constructor
+        // => [RegexTerm]
+        let block = block.push(instruction::runtime::CallRuntimeBuiltin {
+            target: RuntimeBuiltin::CreateRegex,
+        });
+        block.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::term_type::{TermType, TermTypeDiscriminants};
+
+    use super::*;
+
+    #[test]
+    fn regex() {
+        assert_eq!(
+            TermType::Regex(RegexTerm {
+                pattern: ArenaPointer(0x54321),
+            })
+            .as_bytes(),
+            [TermTypeDiscriminants::Regex as u32, 0x54321],
+        );
+    }
+}
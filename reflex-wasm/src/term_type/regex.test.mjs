@@ -0,0 +1,38 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Term::Regex', (test) => {
+    test('display', (assert, { createRegex, createString, display }) => {
+      assert.strictEqual(display(createRegex(createString('foo'))), '/foo/');
+      assert.strictEqual(display(createRegex(createString('(\\d+)-(\\d+)'))), '/(\\d+)-(\\d+)/');
+    });
+
+    test('format', (assert, { createRegex, createString, format }) => {
+      assert.strictEqual(format(createRegex(createString('foo'))), '/foo/');
+    });
+
+    test('hash', (assert, { createRegex, createString, hash }) => {
+      assert.strictEqual(
+        hash(createRegex(createString('foo'))),
+        hash(createRegex(createString('foo'))),
+      );
+      assert.notStrictEqual(
+        hash(createRegex(createString('foo'))),
+        hash(createRegex(createString('bar'))),
+      );
+      assert.notStrictEqual(hash(createRegex(createString('foo'))), hash(createString('foo')));
+    });
+
+    test('equals', (assert, { createRegex, createString, equals }) => {
+      assert.strictEqual(
+        equals(createRegex(createString('foo')), createRegex(createString('foo'))),
+        true,
+      );
+      assert.strictEqual(
+        equals(createRegex(createString('foo')), createRegex(createString('bar'))),
+        false,
+      );
+    });
+  });
+};
@@ -0,0 +1,129 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@let $Regex
+    (@struct $Regex
+      (@field $pattern (@ref $Term)))
+
+    (@derive $size (@get $Regex))
+    (@derive $equals (@get $Regex))
+    (@derive $hash (@get $Regex))
+
+    (@export $Regex (@get $Regex)))
+
+  (export "isRegex" (func $Term::Regex::is))
+  (export "getRegexPattern" (func $Term::Regex::get::pattern))
+
+  (func $Term::Regex::new (export "createRegex") (param $pattern i32) (result i32)
+    (call $Term::TermType::Regex::new (local.get $pattern)))
+
+  (func $Term::Regex::traits::is_atomic (param $self i32) (result i32)
+    (global.get $TRUE))
+
+  (func $Term::Regex::traits::display (param $self i32) (param $offset i32) (result i32)
+    (@store-bytes $offset "/")
+    (local.set $offset (i32.add (local.get $offset)))
+    (local.set $offset
+      (call $Term::String::traits::display
+        (call $Term::Regex::get::pattern (local.get $self))
+        (local.get $offset)))
+    (@store-bytes $offset "/")
+    (i32.add (local.get $offset)))
+
+  (func $Term::Regex::traits::debug (param $self i32) (param $offset i32) (result i32)
+    (call $Term::Regex::traits::display (local.get $self) (local.get $offset)))
+
+  (func $Term::Regex::traits::substitute (param $self i32) (param $variables i32) (param $scope_offset i32) (result i32)
+    (global.get $NULL))
+
+  (func $Term::Regex::is_valid (param $self i32) (result i32)
+    (local $pattern i32)
+    ;; The host compiles (and caches) the pattern, reporting a negative capture count for invalid patterns
+    (i32.ge_s
+      (call $Utils::Regex::capture_count
+        (call $Term::String::get_offset (local.tee $pattern (call $Term::Regex::get::pattern (local.get $self))))
+        (call $Term::String::get_length (local.get $pattern)))
+      (i32.const 0)))
+
+  (func $Term::Regex::match (param $self i32) (param $target i32) (result i32)
+    ;; Returns a list containing the full match followed by each of the capture groups (or Nil for unmatched groups),
+    ;; Nil if the pattern does not match, or the null pointer if the pattern is invalid
+    (local $pattern i32)
+    (local $num_captures i32)
+    (local $results i32)
+    (local $index i32)
+    (local $span i64)
+    (local.set $pattern (call $Term::Regex::get::pattern (local.get $self)))
+    (if (result i32)
+      (i32.lt_s
+        (local.tee $num_captures
+          (call $Utils::Regex::capture_count
+            (call $Term::String::get_offset (local.get $pattern))
+            (call $Term::String::get_length (local.get $pattern))))
+        (i32.const 0))
+      (then
+        (global.get $NULL))
+      (else
+        (if (result i32)
+          ;; Determine whether the pattern matches the target string
+          (i64.eq
+            (call $Term::Regex::get_capture (local.get $pattern) (local.get $target) (i32.const 0))
+            (i64.const -1))
+          (then
+            (call $Term::Nil::new))
+          (else
+            ;; Allocate a new list with enough capacity to hold the full match and all the capture groups
+            (local.set $results (call $Term::List::allocate (local.get $num_captures)))
+            (loop $LOOP
+              (call $Term::List::set_item
+                (local.get $results)
+                (local.get $index)
+                (if (result i32)
+                  (i64.eq
+                    (local.tee $span
+                      (call $Term::Regex::get_capture (local.get $pattern) (local.get $target) (local.get $index)))
+                    (i64.const -1))
+                  (then
+                    (call $Term::Nil::new))
+                  (else
+                    ;; Capture spans are encoded as the start offset in the high 32 bits and the end offset in the low 32 bits
+                    (call $Term::String::slice
+                      (local.get $target)
+                      (i32.wrap_i64 (i64.shr_u (local.get $span) (i64.const 32)))
+                      (i32.sub
+                        (i32.wrap_i64 (local.get $span))
+                        (i32.wrap_i64 (i64.shr_u (local.get $span) (i64.const 32))))))))
+              (br_if $LOOP
+                (i32.lt_u
+                  (local.tee $index (i32.add (local.get $index) (i32.const 1)))
+                  (local.get $num_captures))))
+            (call $Term::List::init (local.get $results) (local.get $num_captures)))))))
+
+  (func $Term::Regex::get_capture (param $pattern i32) (param $target i32) (param $index i32) (result i64)
+    (call $Utils::Regex::capture
+      (call $Term::String::get_offset (local.get $pattern))
+      (call $Term::String::get_length (local.get $pattern))
+      (call $Term::String::get_offset (local.get $target))
+      (call $Term::String::get_length (local.get $target))
+      (local.get $index)))
+
+  (func $Term::Regex::replace (param $self i32) (param $target i32) (param $replacement i32) (result i32)
+    ;; Replaces the first match within the target string, expanding `$1`-style capture group references
+    ;; (the pattern must have already been validated)
+    (local $pattern i32)
+    (local $instance i32)
+    (local.set $pattern (call $Term::Regex::get::pattern (local.get $self)))
+    ;; Allocate a new dynamic string term
+    (local.set $instance (call $Term::String::allocate_unsized))
+    ;; Write the replaced contents into the newly-allocated string and initialize the dynamic string term
+    (call $Term::String::init_unsized
+      (local.get $instance)
+      (call $Utils::Regex::replace
+        (call $Term::String::get_offset (local.get $pattern))
+        (call $Term::String::get_length (local.get $pattern))
+        (call $Term::String::get_offset (local.get $target))
+        (call $Term::String::get_length (local.get $target))
+        (call $Term::String::get_offset (local.get $replacement))
+        (call $Term::String::get_length (local.get $replacement))
+        (call $Term::String::get_char_pointer (local.get $instance) (i32.const 0))))))
@@ -0,0 +1,198 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::collections::HashSet;
+
+use reflex::core::{
+    ArgType, DependencyList, Expression, GraphNode, SensitiveTermType, SerializeJson, StackOffset,
+};
+use reflex_macros::PointerIter;
+use serde_json::Value as JsonValue;
+
+use crate::{
+    allocator::Arena,
+    compiler::{
+        instruction, runtime::builtin::RuntimeBuiltin, CompileWasm, CompiledBlockBuilder,
+        CompilerOptions, CompilerResult, CompilerStack, CompilerState, Internable,
+    },
+    hash::{TermHash, TermHasher, TermSize},
+    term_type::{TypedTerm, WasmExpression},
+    ArenaPointer, ArenaRef, Term,
+};
+
+/// Placeholder emitted in place of the underlying value whenever a sensitive term is formatted or serialized
+pub const SENSITIVE_TERM_PLACEHOLDER: &str = "***";
+
+#[derive(Clone, Copy, Debug, PointerIter)]
+#[repr(C)]
+pub struct SensitiveTerm {
+    pub value: ArenaPointer,
+}
+impl TermSize for SensitiveTerm {
+    fn size_of(&self) -> usize {
+        std::mem::size_of::<Self>()
+    }
+}
+impl TermHash for SensitiveTerm {
+    fn hash(&self, hasher: TermHasher, arena: &impl Arena) -> TermHasher {
+        let value_hash = arena.read_value::<Term, _>(self.value, |term| term.id());
+        hasher.hash(&value_hash, arena)
+    }
+}
+
+impl<A: Arena + Clone> ArenaRef<SensitiveTerm, A> {
+    pub fn value(&self) -> ArenaRef<Term, A> {
+        ArenaRef::<Term, _>::new(self.arena.clone(), self.read_value(|term| term.value))
+    }
+}
+
+impl<A: Arena + Clone> SensitiveTermType<WasmExpression<A>> for ArenaRef<SensitiveTerm, A> {
+    fn value<'a>(&'a self) -> <WasmExpression<A> as Expression>::ExpressionRef<'a>
+    where
+        WasmExpression<A>: 'a,
+    {
+        self.value()
+    }
+}
+
+impl<A: Arena + Clone> SensitiveTermType<WasmExpression<A>>
+    for ArenaRef<TypedTerm<SensitiveTerm>, A>
+{
+    fn value<'a>(&'a self) -> <WasmExpression<A> as Expression>::ExpressionRef<'a>
+    where
+        WasmExpression<A>: 'a,
+    {
+        <ArenaRef<SensitiveTerm, A> as SensitiveTermType<WasmExpression<A>>>::value(
+            &self.as_inner(),
+        )
+    }
+}
+
+impl<A: Arena + Clone> GraphNode for ArenaRef<SensitiveTerm, A> {
+    // The wrapped value is opaque to the rest of the graph, so the sensitive term behaves as a static leaf node
+    fn size(&self) -> usize {
+        1
+    }
+    fn capture_depth(&self) -> StackOffset {
+        0
+    }
+    fn free_variables(&self) -> HashSet<StackOffset> {
+        HashSet::new()
+    }
+    fn count_variable_usages(&self, _offset: StackOffset) -> usize {
+        0
+    }
+    fn dynamic_dependencies(&self, _deep: bool) -> DependencyList {
+        DependencyList::empty()
+    }
+    fn has_dynamic_dependencies(&self, _deep: bool) -> bool {
+        false
+    }
+    fn is_static(&self) -> bool {
+        true
+    }
+    fn is_atomic(&self) -> bool {
+        true
+    }
+    fn is_complex(&self) -> bool {
+        false
+    }
+}
+
+impl<A: Arena + Clone> SerializeJson for ArenaRef<SensitiveTerm, A> {
+    fn to_json(&self) -> Result<JsonValue, String> {
+        Ok(JsonValue::String(String::from(SENSITIVE_TERM_PLACEHOLDER)))
+    }
+    fn patch(&self, target: &Self) -> Result<Option<JsonValue>, String> {
+        if self.value() == target.value() {
+            Ok(None)
+        } else {
+            target.to_json().map(Some)
+        }
+    }
+}
+
+impl<A: Arena + Clone> PartialEq for ArenaRef<SensitiveTerm, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value() == other.value()
+    }
+}
+impl<A: Arena + Clone> Eq for ArenaRef<SensitiveTerm, A> {}
+
+impl<A: Arena + Clone> std::fmt::Debug for ArenaRef<SensitiveTerm, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl<A: Arena + Clone> std::fmt::Display for ArenaRef<SensitiveTerm, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SENSITIVE_TERM_PLACEHOLDER)
+    }
+}
+
+impl<A: Arena + Clone> Internable for ArenaRef<SensitiveTerm, A> {
+    fn should_intern(&self, eager: ArgType) -> bool {
+        self.value().should_intern(eager)
+    }
+}
+
+impl<A: Arena + Clone> CompileWasm<A> for ArenaRef<SensitiveTerm, A> {
+    fn compile(
+        &self,
+        stack: CompilerStack,
+        state: &mut CompilerState,
+        options: &CompilerOptions,
+    ) -> CompilerResult<A> {
+        let value = self.value();
+        let block = CompiledBlockBuilder::new(stack);
+        // Yield the wrapped value onto the stack
+        // => [Term]
+        let block = block.append_inner(|stack| value.compile(stack, state, options))?;
+        // Invoke the term constructor
+        // => [SensitiveTerm]
+        let block = block.push(instruction::runtime::CallRuntimeBuiltin {
+            target: RuntimeBuiltin::CreateSensitive,
+        });
+        block.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        allocator::{ArenaAllocator, VecAllocator},
+        term_type::{StringTerm, TermType, TermTypeDiscriminants},
+    };
+
+    use super::*;
+
+    #[test]
+    fn sensitive() {
+        assert_eq!(
+            TermType::Sensitive(SensitiveTerm {
+                value: ArenaPointer(0x54321),
+            })
+            .as_bytes(),
+            [TermTypeDiscriminants::Sensitive as u32, 0x54321],
+        );
+        let mut allocator = VecAllocator::default();
+        {
+            let value = StringTerm::allocate("foo", &mut allocator);
+            let sensitive = allocator.allocate(Term::new(
+                TermType::Sensitive(SensitiveTerm { value }),
+                &allocator,
+            ));
+            assert_ne!(
+                allocator.get_ref::<Term>(sensitive).id(),
+                allocator.get_ref::<Term>(value).id(),
+            );
+            let sensitive = ArenaRef::<Term, _>::new(&allocator, sensitive);
+            assert_eq!(format!("{}", sensitive), SENSITIVE_TERM_PLACEHOLDER);
+            assert_eq!(
+                sensitive.to_json(),
+                Ok(JsonValue::String(String::from(SENSITIVE_TERM_PLACEHOLDER))),
+            );
+        }
+    }
+}
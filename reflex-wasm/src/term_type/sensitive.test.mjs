@@ -0,0 +1,74 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+export default (describe) => {
+  describe('Term::Sensitive', (test) => {
+    test('format', (assert, { createSensitive, createString, format }) => {
+      assert.strictEqual(format(createSensitive(createString('foo'))), '***');
+    });
+
+    test('hash', (assert, { createSensitive, createString, hash }) => {
+      assert.strictEqual(
+        hash(createSensitive(createString('foo'))),
+        hash(createSensitive(createString('foo'))),
+      );
+      assert.notStrictEqual(
+        hash(createSensitive(createString('foo'))),
+        hash(createSensitive(createString('bar'))),
+      );
+      assert.notStrictEqual(
+        hash(createSensitive(createString('foo'))),
+        hash(createString('foo')),
+      );
+    });
+
+    test('equals', (assert, { createSensitive, createString, equals }) => {
+      assert.strictEqual(
+        equals(createSensitive(createString('foo')), createSensitive(createString('foo'))),
+        true,
+      );
+      assert.strictEqual(
+        equals(createSensitive(createString('foo')), createSensitive(createString('bar'))),
+        false,
+      );
+    });
+
+    test('value', (assert, { createSensitive, createString, format, getSensitiveValue, isSensitive }) => {
+      const value = createSensitive(createString('foo'));
+      assert.strictEqual(isSensitive(value), true);
+      assert.strictEqual(isSensitive(createString('foo')), false);
+      assert.strictEqual(format(getSensitiveValue(value)), '"foo"');
+    });
+
+    test('toJson', (assert, {
+      createApplication,
+      createBuiltin,
+      createRecord,
+      createSensitive,
+      createString,
+      createTriple,
+      createUnitList,
+      evaluate,
+      format,
+      getStateDependencies,
+      NULL,
+      Stdlib,
+    }) => {
+      const expression = createApplication(
+        createBuiltin(Stdlib.StringifyJson),
+        createUnitList(
+          createRecord(
+            createTriple(createString('foo'), createString('bar'), createString('baz')),
+            createTriple(createString('one'), createSensitive(createString('two')), createString('three')),
+          ),
+        ),
+      );
+      const [result, dependencies] = evaluate(expression, NULL);
+      assert.strictEqual(
+        format(result),
+        JSON.stringify(JSON.stringify({ foo: 'one', bar: '***', baz: 'three' })),
+      );
+      assert.deepEqual(getStateDependencies(dependencies), []);
+    });
+  });
+};
@@ -0,0 +1,41 @@
+;; SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+;; SPDX-License-Identifier: Apache-2.0
+;; SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+(module
+  (@let $Sensitive
+    (@struct $Sensitive
+      (@field $value (@ref $Term)))
+
+    (@derive $size (@get $Sensitive))
+    (@derive $equals (@get $Sensitive))
+    (@derive $hash (@get $Sensitive))
+
+    (@export $Sensitive (@get $Sensitive)))
+
+  (export "isSensitive" (func $Term::Sensitive::is))
+  (export "getSensitiveValue" (func $Term::Sensitive::get::value))
+
+  (func $Term::Sensitive::new (export "createSensitive") (param $value i32) (result i32)
+    (call $Term::TermType::Sensitive::new (local.get $value)))
+
+  (func $Term::Sensitive::traits::is_atomic (param $self i32) (result i32)
+    ;; The wrapped value is opaque, so sensitive terms are always treated as atomic values
+    (global.get $TRUE))
+
+  (func $Term::Sensitive::traits::substitute (param $self i32) (param $variables i32) (param $scope_offset i32) (result i32)
+    (global.get $NULL))
+
+  (func $Term::Sensitive::traits::display (param $self i32) (param $offset i32) (result i32)
+    ;; Redact the wrapped value to prevent it from leaking via logging
+    (@store-bytes $offset "***")
+    (i32.add (local.get $offset)))
+
+  (func $Term::Sensitive::traits::debug (param $self i32) (param $offset i32) (result i32)
+    (call $Term::Sensitive::traits::display (local.get $self) (local.get $offset)))
+
+  (func $Term::Sensitive::traits::to_json (param $self i32) (param $offset i32) (result i32 i32)
+    ;; Put the success marker on the stack
+    (global.get $TRUE)
+    ;; Write the redacted placeholder to the output string and return the updated offset
+    (@store-bytes $offset "\"***\"")
+    (i32.add (local.get $offset))))
@@ -2,14 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 // SPDX-FileContributor: Jordan Hall <j.hall@mwam.com> https://github.com/j-hall-mwam
-use std::{collections::HashSet, ops::Deref, slice, str::from_utf8_unchecked};
+use std::{cell::RefCell, collections::HashSet, ops::Deref, slice, str::from_utf8_unchecked};
 
 use reflex::{
     core::{
         ArgType, DependencyList, Expression, GraphNode, SerializeJson, StackOffset, StringTermType,
         StringValue,
     },
-    hash::HashId,
+    hash::{HashId, IntSet},
 };
 use reflex_macros::PointerIter;
 use serde_json::Value as JsonValue;
@@ -60,7 +60,7 @@ impl StringTerm {
             TermHasher::default().hash(term, arena).finish()
         });
         arena.write::<u64>(Term::get_hash_pointer(instance), u64::from(hash));
-        instance
+        arena.intern_string(instance)
     }
     pub fn as_str(&self) -> &str {
         let start_pointer = self.data.items.as_ptr() as *const u8;
@@ -120,6 +120,59 @@ impl<A: Arena + Clone> ArenaRef<StringTerm, A> {
     fn as_utf8<'a>(&'a self) -> Utf8Bytes<A::Slice<'a>> {
         Utf8Bytes(self.arena.as_slice(self.offset(), self.len()))
     }
+    /// Invoke the provided callback with a reference to the string contents, borrowed directly from the arena
+    ///
+    /// Any invalid UTF-8 byte sequences will be replaced with the Unicode replacement character (this is the only case
+    /// in which the string contents are copied out of the arena).
+    pub fn read_str<R>(&self, f: impl FnOnce(&str) -> R) -> R {
+        read_utf8_bytes(&self.arena.as_slice(self.offset(), self.len()), None, f)
+    }
+}
+
+impl<A: Arena + Clone> ArenaRef<TypedTerm<StringTerm>, A> {
+    /// Invoke the provided callback with a reference to the string contents, borrowed directly from the arena
+    ///
+    /// The result of the UTF-8 validation is cached according to the term hash, so repeated reads of the same string
+    /// will skip the validation step.
+    pub fn read_str<R>(&self, f: impl FnOnce(&str) -> R) -> R {
+        let inner = self.as_inner();
+        let hash = self.read_value(|term| term.id());
+        read_utf8_bytes(
+            &self.arena.as_slice(inner.offset(), inner.len()),
+            Some(hash),
+            f,
+        )
+    }
+}
+
+/// Maximum number of validated string hashes to retain before the UTF-8 validation cache is reset
+const UTF8_VALIDATION_CACHE_CAPACITY: usize = 4096;
+
+thread_local! {
+    static UTF8_VALIDATION_CACHE: RefCell<IntSet<HashId>> = RefCell::new(IntSet::default());
+}
+
+fn read_utf8_bytes<R>(bytes: &[u8], cache_key: Option<HashId>, f: impl FnOnce(&str) -> R) -> R {
+    let is_cached = cache_key
+        .is_some_and(|hash| UTF8_VALIDATION_CACHE.with(|cache| cache.borrow().contains(&hash)));
+    if is_cached {
+        return f(unsafe { from_utf8_unchecked(bytes) });
+    }
+    match std::str::from_utf8(bytes) {
+        Ok(value) => {
+            if let Some(hash) = cache_key {
+                UTF8_VALIDATION_CACHE.with(|cache| {
+                    let mut cache = cache.borrow_mut();
+                    if cache.len() >= UTF8_VALIDATION_CACHE_CAPACITY {
+                        cache.clear();
+                    }
+                    cache.insert(hash);
+                });
+            }
+            f(value)
+        }
+        Err(_) => f(&String::from_utf8_lossy(bytes)),
+    }
 }
 
 pub struct Utf8Bytes<T: Deref<Target = [u8]>>(T);
@@ -202,10 +255,10 @@ impl<A: Arena + Clone> GraphNode for ArenaRef<StringTerm, A> {
 
 impl<A: Arena + Clone> SerializeJson for ArenaRef<StringTerm, A> {
     fn to_json(&self) -> Result<JsonValue, String> {
-        Ok(JsonValue::String(String::from(self.as_utf8())))
+        Ok(JsonValue::String(self.read_str(str::to_owned)))
     }
     fn patch(&self, target: &Self) -> Result<Option<JsonValue>, String> {
-        if self.as_str().deref() == target.as_str().deref() {
+        if self.read_str(|value| target.read_str(|target| value == target)) {
             Ok(None)
         } else {
             target.to_json().map(Some)
@@ -380,4 +433,33 @@ mod tests {
             assert_eq!(data, &get_string_chunks(value));
         }
     }
+
+    #[test]
+    fn interned_strings() {
+        let mut allocator = VecAllocator::default();
+        let foo = StringTerm::allocate("foo", &mut allocator);
+        let end_offset = allocator.end_offset();
+        assert_eq!(StringTerm::allocate("foo", &mut allocator), foo);
+        assert_eq!(allocator.end_offset(), end_offset);
+        let bar = StringTerm::allocate("bar", &mut allocator);
+        assert_ne!(bar, foo);
+        assert_ne!(StringTerm::allocate("foobar", &mut allocator), foo);
+        assert_eq!(StringTerm::allocate("bar", &mut allocator), bar);
+    }
+
+    #[test]
+    fn read_str() {
+        let mut allocator = VecAllocator::default();
+        let value = "foobarbaz";
+        let instance = StringTerm::allocate(value, &mut allocator);
+        let term = ArenaRef::<Term, _>::new(&allocator, instance);
+        let string_term = term.as_string_term().unwrap();
+        assert_eq!(string_term.read_str(str::to_owned), value);
+        assert_eq!(string_term.read_str(str::to_owned), value);
+        assert_eq!(string_term.as_inner().read_str(|value| value.len()), 9);
+        assert_eq!(
+            read_utf8_bytes(&[0x66, 0x6f, 0xff, 0x6f], None, str::to_owned),
+            "fo\u{FFFD}o",
+        );
+    }
 }
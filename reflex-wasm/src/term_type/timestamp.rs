@@ -4,10 +4,10 @@
 // SPDX-FileContributor: Jordan Hall <j.hall@mwam.com> https://github.com/j-hall-mwam
 use std::collections::HashSet;
 
-use chrono::{DateTime, NaiveDateTime, SecondsFormat, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Utc};
 use reflex::core::{
     ArgType, DependencyList, GraphNode, SerializeJson, StackOffset, TimestampTermType,
-    TimestampValue,
+    TimestampValue, TimezoneOffset,
 };
 use reflex_macros::PointerIter;
 use serde_json::Value as JsonValue;
@@ -28,6 +28,15 @@ use crate::{
 #[repr(C)]
 pub struct TimestampTerm {
     pub millis: [u32; 2],
+    pub offset: i32,
+}
+impl TimestampTerm {
+    pub fn new(millis: i64, offset: i32) -> Self {
+        Self {
+            millis: i64_to_chunks(millis),
+            offset,
+        }
+    }
 }
 impl TermSize for TimestampTerm {
     fn size_of(&self) -> usize {
@@ -36,26 +45,16 @@ impl TermSize for TimestampTerm {
 }
 impl TermHash for TimestampTerm {
     fn hash(&self, hasher: TermHasher, arena: &impl Arena) -> TermHasher {
-        hasher.hash(&self.millis, arena)
-    }
-}
-impl From<i64> for TimestampTerm {
-    fn from(value: i64) -> Self {
-        Self {
-            millis: i64_to_chunks(value),
-        }
-    }
-}
-impl From<TimestampTerm> for i64 {
-    fn from(value: TimestampTerm) -> Self {
-        let TimestampTerm { millis, .. } = value;
-        chunks_to_i64(millis)
+        hasher.hash(&self.millis, arena).write_i32(self.offset)
     }
 }
 
 impl<A: Arena + Clone> ArenaRef<TimestampTerm, A> {
     pub fn millis(&self) -> i64 {
-        self.read_value(|term| i64::from(*term))
+        self.read_value(|term| chunks_to_i64(term.millis))
+    }
+    pub fn offset(&self) -> i32 {
+        self.read_value(|term| term.offset)
     }
 }
 
@@ -63,12 +62,18 @@ impl<A: Arena + Clone> TimestampTermType for ArenaRef<TimestampTerm, A> {
     fn millis(&self) -> TimestampValue {
         self.millis() as TimestampValue
     }
+    fn offset(&self) -> TimezoneOffset {
+        self.offset() as TimezoneOffset
+    }
 }
 
 impl<A: Arena + Clone> TimestampTermType for ArenaRef<TypedTerm<TimestampTerm>, A> {
     fn millis(&self) -> TimestampValue {
         <ArenaRef<TimestampTerm, A> as TimestampTermType>::millis(&self.as_inner())
     }
+    fn offset(&self) -> TimezoneOffset {
+        <ArenaRef<TimestampTerm, A> as TimestampTermType>::offset(&self.as_inner())
+    }
 }
 
 impl<A: Arena + Clone> GraphNode for ArenaRef<TimestampTerm, A> {
@@ -106,7 +111,7 @@ impl<A: Arena + Clone> SerializeJson for ArenaRef<TimestampTerm, A> {
         Ok(JsonValue::String(format!("{}", self)))
     }
     fn patch(&self, target: &Self) -> Result<Option<JsonValue>, String> {
-        if self.millis() == target.millis() {
+        if self == target {
             Ok(None)
         } else {
             target.to_json().map(Some)
@@ -116,7 +121,7 @@ impl<A: Arena + Clone> SerializeJson for ArenaRef<TimestampTerm, A> {
 
 impl<A: Arena + Clone> PartialEq for ArenaRef<TimestampTerm, A> {
     fn eq(&self, other: &Self) -> bool {
-        self.millis() == other.millis()
+        self.millis() == other.millis() && self.offset() == other.offset()
     }
 }
 impl<A: Arena + Clone> Eq for ArenaRef<TimestampTerm, A> {}
@@ -129,7 +134,7 @@ impl<A: Arena + Clone> std::fmt::Debug for ArenaRef<TimestampTerm, A> {
 
 impl<A: Arena + Clone> std::fmt::Display for ArenaRef<TimestampTerm, A> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(&UtcTimestamp(self.millis()), f)
+        std::fmt::Display::fmt(&ZonedTimestamp(self.millis(), self.offset()), f)
     }
 }
 
@@ -139,24 +144,29 @@ impl<A: Arena + Clone> Internable for ArenaRef<TimestampTerm, A> {
     }
 }
 
+/// ISO-8601 formatter for a timestamp observed at the given UTC offset (expressed in minutes east of UTC)
+///
+/// Zero offsets are written with the `Z` suffix, all other offsets are written as `±HH:MM`
 #[derive(Hash, PartialEq, Eq, Clone, Copy, Debug)]
-pub struct UtcTimestamp(pub i64);
+pub struct ZonedTimestamp(pub i64, pub i32);
 
-impl std::fmt::Display for UtcTimestamp {
+impl std::fmt::Display for ZonedTimestamp {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let Self(timestamp) = *self;
-        let seconds = timestamp / 1000;
-        let millis = timestamp % 10;
-        let nanos = millis * 1000;
-        write!(
-            f,
-            "{}",
-            DateTime::<Utc>::from_utc(
-                NaiveDateTime::from_timestamp_opt(seconds, nanos as u32).unwrap_or_default(),
-                Utc,
-            )
-            .to_rfc3339_opts(SecondsFormat::AutoSi, true)
-        )
+        let Self(timestamp, offset) = *self;
+        let datetime = DateTime::<Utc>::from_utc(
+            NaiveDateTime::from_timestamp_millis(timestamp).unwrap_or_default(),
+            Utc,
+        );
+        match FixedOffset::east_opt(offset * 60).filter(|_| offset != 0) {
+            Some(timezone) => write!(
+                f,
+                "{}",
+                datetime
+                    .with_timezone(&timezone)
+                    .format("%Y-%m-%dT%H:%M:%S%.3f%:z")
+            ),
+            None => write!(f, "{}", datetime.format("%Y-%m-%dT%H:%M:%S%.3fZ")),
+        }
     }
 }
 
@@ -168,12 +178,18 @@ impl<A: Arena + Clone> CompileWasm<A> for ArenaRef<TimestampTerm, A> {
         _options: &CompilerOptions,
     ) -> CompilerResult<A> {
         let millis = self.millis();
+        let offset = self.offset();
         let block = CompiledBlockBuilder::new(stack);
-        // Push the value argument onto the stack
-        // => [value]
+        // Push the timestamp argument onto the stack
+        // => [i64]
         let block = block.push(instruction::core::Const {
             value: ConstValue::I64(millis),
         });
+        // Push the offset argument onto the stack
+        // => [i64, i32]
+        let block = block.push(instruction::core::Const {
+            value: ConstValue::I32(offset),
+        });
         // Invoke the term constructor
         // => [TimestampTerm]
         let block = block.push(instruction::runtime::CallRuntimeBuiltin {
@@ -199,12 +215,29 @@ mod tests {
             .unwrap()
             .as_millis() as i64;
         assert_eq!(
-            TermType::Timestamp(TimestampTerm::from(timestamp)).as_bytes(),
+            TermType::Timestamp(TimestampTerm::new(timestamp, -330)).as_bytes(),
             [
                 TermTypeDiscriminants::Timestamp as u32,
                 i64_to_chunks(timestamp)[0],
-                i64_to_chunks(timestamp)[1]
+                i64_to_chunks(timestamp)[1],
+                -330i32 as u32,
             ],
         );
     }
+
+    #[test]
+    fn zoned_timestamp() {
+        assert_eq!(
+            format!("{}", ZonedTimestamp(1677906367089, 0)),
+            "2023-03-04T05:06:07.089Z",
+        );
+        assert_eq!(
+            format!("{}", ZonedTimestamp(1677899167089, 120)),
+            "2023-03-04T05:06:07.089+02:00",
+        );
+        assert_eq!(
+            format!("{}", ZonedTimestamp(1677906367089, -330)),
+            "2023-03-03T23:36:07.089-05:30",
+        );
+    }
 }
@@ -16,16 +16,33 @@ export default (describe) => {
       );
     });
 
+    test('UTC offsets', (assert, { createTimestamp, display, format, getTimestampMillis, getTimestampOffset }) => {
+      const timestamp = Date.UTC(2023, 2, 4, 5, 6, 7, 89);
+      assert.strictEqual(display(createTimestamp(timestamp, 0)), '2023-03-04T05:06:07.089Z');
+      assert.strictEqual(display(createTimestamp(timestamp, 120)), '2023-03-04T07:06:07.089+02:00');
+      assert.strictEqual(display(createTimestamp(timestamp, -330)), '2023-03-03T23:36:07.089-05:30');
+      assert.strictEqual(
+        format(createTimestamp(timestamp, 120)),
+        'Timestamp(2023-03-04T07:06:07.089+02:00)',
+      );
+      assert.strictEqual(getTimestampMillis(createTimestamp(timestamp, 120)), timestamp);
+      assert.strictEqual(getTimestampOffset(createTimestamp(timestamp, 120)), 120);
+      assert.strictEqual(getTimestampOffset(createTimestamp(timestamp, -330)), -330);
+    });
+
     test('hash', (assert, { createTimestamp, hash }) => {
       const timestamp = Date.now();
       assert.strictEqual(hash(createTimestamp(timestamp)), hash(createTimestamp(timestamp)));
       assert.notStrictEqual(hash(createTimestamp(timestamp)), hash(createTimestamp(timestamp - 1)));
+      assert.notStrictEqual(hash(createTimestamp(timestamp, 0)), hash(createTimestamp(timestamp, 60)));
     });
 
     test('equals', (assert, { createTimestamp, equals }) => {
       const timestamp = Date.now();
       assert.strictEqual(equals(createTimestamp(timestamp), createTimestamp(timestamp)), true);
       assert.strictEqual(equals(createTimestamp(timestamp), createTimestamp(timestamp - 1)), false);
+      assert.strictEqual(equals(createTimestamp(timestamp, 60), createTimestamp(timestamp, 60)), true);
+      assert.strictEqual(equals(createTimestamp(timestamp, 0), createTimestamp(timestamp, 60)), false);
     });
   });
 };
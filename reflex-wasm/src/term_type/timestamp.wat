@@ -4,7 +4,8 @@
 (module
   (@let $Timestamp
     (@struct $Timestamp
-      (@field $millis i64))
+      (@field $millis i64)
+      (@field $offset i32))
 
     (@derive $size (@get $Timestamp))
     (@derive $equals (@get $Timestamp))
@@ -14,9 +15,12 @@
 
   (export "isTimestamp" (func $Term::Timestamp::is))
   (export "getTimestampMillis" (func $Term::Timestamp::get::millis))
+  (export "getTimestampOffset" (func $Term::Timestamp::get::offset))
 
-  (func $Term::Timestamp::new (export "createTimestamp") (param $millis i64) (result i32)
-    (call $Term::TermType::Timestamp::new (local.get $millis)))
+  ;; The UTC offset is expressed in minutes east of UTC, and determines how the timestamp is serialized (without
+  ;; affecting the instant the timestamp represents)
+  (func $Term::Timestamp::new (export "createTimestamp") (param $millis i64) (param $offset i32) (result i32)
+    (call $Term::TermType::Timestamp::new (local.get $millis) (local.get $offset)))
 
   (func $Term::Timestamp::traits::is_atomic (param $self i32) (result i32)
     (global.get $TRUE))
@@ -24,6 +28,7 @@
   (func $Term::Timestamp::traits::display (param $self i32) (param $offset i32) (result i32)
     (call $Utils::Date::to_iso_string
       (call $Term::Timestamp::get::millis (local.get $self))
+      (call $Term::Timestamp::get::offset (local.get $self))
       (local.get $offset))
     (i32.add (local.get $offset)))
 
@@ -46,6 +51,7 @@
     (local.tee $bytes_written
       (call $Utils::Date::to_iso_string
         (call $Term::Timestamp::get::millis (local.get $self))
+        (call $Term::Timestamp::get::offset (local.get $self))
         (local.get $offset)))
     (local.set $offset (i32.add (local.get $offset)))
     (if (result i32 i32)
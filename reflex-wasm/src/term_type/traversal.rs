@@ -0,0 +1,465 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{collections::HashSet, iter::once, rc::Rc};
+
+use reflex::{
+    core::{NodeId, StackOffset},
+    hash::{HashId, IntMap},
+};
+use reflex_utils::WorkStack;
+
+use crate::{
+    allocator::Arena,
+    term_type::{
+        metadata, ApplicationTerm, LambdaTerm, LazyResultTerm, LetTerm, ListTerm, PartialTerm,
+        RecordTerm, TermTypeDiscriminants, VariableTerm,
+    },
+    ArenaRef, Term,
+};
+
+/// Determine the set of variables referenced by the provided term that are not bound within the term itself
+///
+/// See [`visit_cached_subterms`] for details of how intermediate results are cached.
+pub(crate) fn free_variables<A: Arena + Clone>(
+    term: &ArenaRef<Term, A>,
+) -> Rc<HashSet<StackOffset>> {
+    visit_cached_subterms(
+        term,
+        metadata::get_free_variables,
+        metadata::set_free_variables,
+        |term| match term.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::Variable => Rc::new(HashSet::from([term
+                .as_typed_term::<VariableTerm>()
+                .as_inner()
+                .stack_offset()])),
+            _ => Rc::new(term.typed_free_variables()),
+        },
+        |children| match children {
+            [(child, 0)] => Rc::clone(child),
+            children => Rc::new(
+                children
+                    .iter()
+                    .flat_map(|(child, depth)| {
+                        child
+                            .iter()
+                            .filter_map(move |offset| offset.checked_sub(*depth))
+                    })
+                    .collect(),
+            ),
+        },
+    )
+}
+
+/// Determine the number of stack entries captured by the provided term
+///
+/// See [`visit_cached_subterms`] for details of how intermediate results are cached.
+pub(crate) fn capture_depth<A: Arena + Clone>(term: &ArenaRef<Term, A>) -> StackOffset {
+    visit_cached_subterms(
+        term,
+        metadata::get_capture_depth,
+        metadata::set_capture_depth,
+        |term| match term.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::Variable => {
+                term.as_typed_term::<VariableTerm>()
+                    .as_inner()
+                    .stack_offset()
+                    + 1
+            }
+            _ => term.typed_capture_depth(),
+        },
+        |children| {
+            children
+                .iter()
+                .map(|(child, depth)| child.saturating_sub(*depth))
+                .max()
+                .unwrap_or(0)
+        },
+    )
+}
+
+enum CachedSubtermVisit<A: Arena + Clone> {
+    Enter(ArenaRef<Term, A>),
+    Exit(ArenaRef<Term, A>, Vec<(ArenaRef<Term, A>, StackOffset)>),
+}
+
+/// Compute a variable scope metric for the provided term via a post-order traversal of its structural subterms, using an
+/// explicit work stack rather than recursing through the term graph
+///
+/// The result for each structural subterm is combined from the results of its children (alongside the number of
+/// variables bound by the subterm for each child), with variable references and any remaining terms handled by the
+/// `leaf` callback. The result for every visited subterm is stored in the graph metadata cache, and any subterm whose
+/// result is already cached is not traversed further, so repeated queries for a term or any of its subterms do not
+/// re-traverse the subtree.
+fn visit_cached_subterms<A: Arena + Clone, V: Clone>(
+    term: &ArenaRef<Term, A>,
+    get_cached: impl Fn(HashId) -> Option<V>,
+    set_cached: impl Fn(HashId, V),
+    leaf: impl Fn(&ArenaRef<Term, A>) -> V,
+    combine: impl Fn(&[(V, StackOffset)]) -> V,
+) -> V {
+    if let Some(value) = get_cached(term.id()) {
+        return value;
+    }
+    // Results are additionally tracked locally for the duration of the traversal, in case any of the cached entries
+    // are evicted before the parent term's result has been computed
+    let mut results = IntMap::<HashId, V>::default();
+    let mut stack = WorkStack::new(CachedSubtermVisit::Enter(term.clone()));
+    while let Some(visit) = stack.pop() {
+        let (term, value) = match visit {
+            CachedSubtermVisit::Enter(term) => {
+                let id = term.id();
+                if results.contains_key(&id) {
+                    continue;
+                }
+                if let Some(value) = get_cached(id) {
+                    results.insert(id, value);
+                    continue;
+                }
+                match structural_children(&term) {
+                    Some(children) => {
+                        // Visit all the children before combining their results
+                        let child_visits = children
+                            .iter()
+                            .map(|(child, _)| CachedSubtermVisit::Enter(child.clone()))
+                            .collect::<Vec<_>>();
+                        stack.push(CachedSubtermVisit::Exit(term, children));
+                        stack.extend(child_visits);
+                        continue;
+                    }
+                    None => {
+                        let value = leaf(&term);
+                        (term, value)
+                    }
+                }
+            }
+            CachedSubtermVisit::Exit(term, children) => {
+                let child_values = children
+                    .iter()
+                    .filter_map(|(child, depth)| {
+                        results
+                            .get(&child.id())
+                            .map(|value| (value.clone(), *depth))
+                    })
+                    .collect::<Vec<_>>();
+                let value = combine(&child_values);
+                (term, value)
+            }
+        };
+        set_cached(term.id(), value.clone());
+        results.insert(term.id(), value);
+    }
+    results.remove(&term.id()).unwrap_or_else(|| leaf(term))
+}
+
+/// Retrieve the child terms of a structural term, alongside the number of variables bound by the term for each child
+/// (or `None` if the term is not a structural term)
+fn structural_children<A: Arena + Clone>(
+    term: &ArenaRef<Term, A>,
+) -> Option<Vec<(ArenaRef<Term, A>, StackOffset)>> {
+    match term.read_value(|term| term.type_id()) {
+        TermTypeDiscriminants::Let => {
+            let term = term.as_typed_term::<LetTerm>().as_inner();
+            Some(vec![(term.initializer(), 0), (term.body(), 1)])
+        }
+        TermTypeDiscriminants::Lambda => {
+            let term = term.as_typed_term::<LambdaTerm>().as_inner();
+            Some(vec![(term.body(), term.num_args() as StackOffset)])
+        }
+        TermTypeDiscriminants::Application => {
+            let term = term.as_typed_term::<ApplicationTerm>().as_inner();
+            Some(
+                once((term.target(), 0))
+                    .chain(term.args().as_inner().iter().map(|arg| (arg, 0)))
+                    .collect(),
+            )
+        }
+        TermTypeDiscriminants::Partial => {
+            let term = term.as_typed_term::<PartialTerm>().as_inner();
+            Some(
+                once((term.target(), 0))
+                    .chain(term.args().as_inner().iter().map(|arg| (arg, 0)))
+                    .collect(),
+            )
+        }
+        TermTypeDiscriminants::LazyResult => {
+            let term = term.as_typed_term::<LazyResultTerm>().as_inner();
+            Some(vec![(term.value(), 0)])
+        }
+        TermTypeDiscriminants::List => {
+            let term = term.as_typed_term::<ListTerm>().as_inner();
+            Some(term.iter().map(|item| (item, 0)).collect())
+        }
+        TermTypeDiscriminants::Record => {
+            let term = term.as_typed_term::<RecordTerm>().as_inner();
+            Some(
+                term.values()
+                    .as_inner()
+                    .iter()
+                    .map(|value| (value, 0))
+                    .collect(),
+            )
+        }
+        _ => None,
+    }
+}
+
+enum DisplayFragment<A: Arena + Clone> {
+    Literal(&'static str),
+    Text(String),
+    Term(ArenaRef<Term, A>),
+}
+
+/// Format the provided term using an explicit work stack rather than recursing through the term graph
+///
+/// Structural terms are expanded into a sequence of output fragments which are pushed onto the work stack, with any
+/// remaining terms delegating to the underlying typed term `Display` implementation.
+pub(crate) fn fmt_term<A: Arena + Clone>(
+    term: &ArenaRef<Term, A>,
+    f: &mut std::fmt::Formatter<'_>,
+) -> std::fmt::Result {
+    let max_displayed_items = 100;
+    let mut stack = WorkStack::new(DisplayFragment::Term(term.clone()));
+    while let Some(fragment) = stack.pop() {
+        let term = match fragment {
+            DisplayFragment::Literal(value) => {
+                f.write_str(value)?;
+                continue;
+            }
+            DisplayFragment::Text(value) => {
+                f.write_str(&value)?;
+                continue;
+            }
+            DisplayFragment::Term(term) => term,
+        };
+        match term.read_value(|term| term.type_id()) {
+            TermTypeDiscriminants::Let => {
+                let term = term.as_typed_term::<LetTerm>().as_inner();
+                stack.extend([
+                    DisplayFragment::Literal("<let:"),
+                    DisplayFragment::Term(term.initializer()),
+                    DisplayFragment::Literal(":"),
+                    DisplayFragment::Term(term.body()),
+                    DisplayFragment::Literal(">"),
+                ])
+            }
+            TermTypeDiscriminants::LazyResult => {
+                let term = term.as_typed_term::<LazyResultTerm>().as_inner();
+                stack.extend([
+                    DisplayFragment::Literal("<lazy:"),
+                    DisplayFragment::Term(term.value()),
+                    DisplayFragment::Text(format!(":{}>", term.dependencies())),
+                ])
+            }
+            TermTypeDiscriminants::Partial => {
+                let term = term.as_typed_term::<PartialTerm>().as_inner();
+                stack.extend([
+                    DisplayFragment::Text(format!("<partial:{}:", term.args().as_inner().len())),
+                    DisplayFragment::Term(term.target()),
+                    DisplayFragment::Literal(">"),
+                ])
+            }
+            TermTypeDiscriminants::Application => {
+                let term = term.as_typed_term::<ApplicationTerm>().as_inner();
+                let args = term.args();
+                stack.extend(
+                    [
+                        DisplayFragment::Literal("<apply:"),
+                        DisplayFragment::Term(term.target()),
+                        DisplayFragment::Literal(":("),
+                    ]
+                    .into_iter()
+                    .chain(join_fragments(
+                        args.as_inner()
+                            .iter()
+                            .map(|arg| vec![DisplayFragment::Term(arg)]),
+                        ", ",
+                    ))
+                    .chain(once(DisplayFragment::Literal(")>"))),
+                )
+            }
+            TermTypeDiscriminants::List => {
+                let term = term.as_typed_term::<ListTerm>().as_inner();
+                let num_items = term.len();
+                let displayed_items = if num_items <= max_displayed_items {
+                    term.iter().map(DisplayFragment::Term).collect::<Vec<_>>()
+                } else {
+                    term.iter()
+                        .take(max_displayed_items - 1)
+                        .map(DisplayFragment::Term)
+                        .chain(once(DisplayFragment::Text(format!(
+                            "...{} more items",
+                            num_items - (max_displayed_items - 1)
+                        ))))
+                        .collect::<Vec<_>>()
+                };
+                stack.extend(
+                    once(DisplayFragment::Literal("["))
+                        .chain(join_fragments(
+                            displayed_items.into_iter().map(|item| vec![item]),
+                            ", ",
+                        ))
+                        .chain(once(DisplayFragment::Literal("]"))),
+                )
+            }
+            TermTypeDiscriminants::Record => {
+                let term = term.as_typed_term::<RecordTerm>().as_inner();
+                let keys = term.keys();
+                let values = term.values();
+                if keys.as_inner().len() == 0 {
+                    f.write_str("{}")?;
+                } else {
+                    stack.extend(
+                        once(DisplayFragment::Literal("{ "))
+                            .chain(join_fragments(
+                                keys.as_inner().iter().zip(values.as_inner().iter()).map(
+                                    |(key, value)| {
+                                        vec![
+                                            DisplayFragment::Term(key),
+                                            DisplayFragment::Literal(": "),
+                                            DisplayFragment::Term(value),
+                                        ]
+                                    },
+                                ),
+                                ", ",
+                            ))
+                            .chain(once(DisplayFragment::Literal(" }"))),
+                    )
+                }
+            }
+            _ => term.fmt_typed(f)?,
+        }
+    }
+    Ok(())
+}
+
+fn join_fragments<A: Arena + Clone>(
+    items: impl IntoIterator<Item = Vec<DisplayFragment<A>>>,
+    separator: &'static str,
+) -> impl Iterator<Item = DisplayFragment<A>> {
+    items
+        .into_iter()
+        .enumerate()
+        .flat_map(move |(index, item)| {
+            (if index == 0 {
+                None
+            } else {
+                Some(DisplayFragment::Literal(separator))
+            })
+            .into_iter()
+            .chain(item)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use reflex::core::GraphNode;
+
+    use crate::{
+        allocator::{ArenaAllocator, VecAllocator},
+        term_type::{IntTerm, TermType},
+    };
+
+    use super::*;
+
+    #[test]
+    fn free_variables() {
+        let mut allocator = VecAllocator::default();
+        let target = allocator.allocate(Term::new(
+            TermType::Variable(VariableTerm { stack_offset: 0 }),
+            &allocator,
+        ));
+        let first_arg = allocator.allocate(Term::new(
+            TermType::Variable(VariableTerm { stack_offset: 2 }),
+            &allocator,
+        ));
+        let second_arg = allocator.allocate(Term::new(
+            TermType::Variable(VariableTerm { stack_offset: 5 }),
+            &allocator,
+        ));
+        let args = ListTerm::allocate([first_arg, second_arg], &mut allocator);
+        let body = allocator.allocate(Term::new(
+            TermType::Application(ApplicationTerm { target, args }),
+            &allocator,
+        ));
+        let expression = allocator.allocate(Term::new(
+            TermType::Lambda(LambdaTerm { num_args: 2, body }),
+            &allocator,
+        ));
+        let expression = ArenaRef::<Term, _>::new(&allocator, expression);
+        assert_eq!(expression.free_variables(), [0, 3].into_iter().collect());
+        assert_eq!(expression.capture_depth(), 4);
+        assert_eq!(format!("{}", expression), "<function:2>",);
+        let body = ArenaRef::<Term, _>::new(&allocator, body);
+        assert_eq!(
+            format!("{}", body),
+            "<apply:<variable:0>:(<variable:2>, <variable:5>)>",
+        );
+    }
+
+    #[test]
+    fn cached_subterm_metadata() {
+        let mut allocator = VecAllocator::default();
+        let target = allocator.allocate(Term::new(
+            TermType::Variable(VariableTerm { stack_offset: 7 }),
+            &allocator,
+        ));
+        let arg = allocator.allocate(Term::new(
+            TermType::Variable(VariableTerm { stack_offset: 8 }),
+            &allocator,
+        ));
+        let args = ListTerm::allocate([arg], &mut allocator);
+        let body = allocator.allocate(Term::new(
+            TermType::Application(ApplicationTerm { target, args }),
+            &allocator,
+        ));
+        let expression = allocator.allocate(Term::new(
+            TermType::Lambda(LambdaTerm { num_args: 3, body }),
+            &allocator,
+        ));
+        let expression = ArenaRef::<Term, _>::new(&allocator, expression);
+        let body = ArenaRef::<Term, _>::new(&allocator, body);
+        let free_variables = expression.shared_free_variables();
+        assert_eq!(*free_variables, [4, 5].into_iter().collect());
+        assert!(Rc::ptr_eq(
+            &free_variables,
+            &expression.shared_free_variables()
+        ));
+        assert_eq!(
+            metadata::get_free_variables(body.id()).as_deref(),
+            Some(&[7, 8].into_iter().collect())
+        );
+        assert_eq!(expression.capture_depth(), 6);
+        assert_eq!(metadata::get_capture_depth(body.id()), Some(9));
+    }
+
+    #[test]
+    fn deeply_nested_terms() {
+        let mut allocator = VecAllocator::default();
+        let depth = 100_000;
+        let body = allocator.allocate(Term::new(
+            TermType::Variable(VariableTerm {
+                stack_offset: depth + 3,
+            }),
+            &allocator,
+        ));
+        let expression = (0..depth).fold(body, |body, _| {
+            let initializer =
+                allocator.allocate(Term::new(TermType::Int(IntTerm::from(3)), &allocator));
+            allocator.allocate(Term::new(
+                TermType::Let(LetTerm { initializer, body }),
+                &allocator,
+            ))
+        });
+        let expression = ArenaRef::<Term, _>::new(&allocator, expression);
+        assert_eq!(expression.free_variables(), [3].into_iter().collect());
+        assert_eq!(expression.capture_depth(), 4);
+        let formatted = format!("{}", expression);
+        assert!(formatted.starts_with("<let:3:<let:3:"));
+        assert!(formatted.ends_with(&format!(
+            "<variable:{}>{}",
+            depth + 3,
+            ">".repeat(depth as usize)
+        )));
+    }
+}
@@ -103,6 +103,37 @@
       (br_if $LOOP (local.tee $exponent (i32.shr_u (local.get $exponent) (i32.const 1)))))
     (local.get $result))
 
+  ;; Exponents too large to represent will overflow (or underflow) for any base other than 0, 1 or -1, so clamp to the
+  ;; nearest representable exponent while preserving the parity that determines the sign of the result
+
+  (func $Utils::i64::clamp_exponent_u32 (param $self i64) (result i32)
+    ;; Assumes the exponent is non-negative
+    (if (result i32)
+      (i64.gt_u (local.get $self) (i64.const 0xFFFFFFFF))
+      (then
+        (i32.or
+          (i32.const 0xFFFFFFFE)
+          (i32.and (i32.wrap_i64 (local.get $self)) (i32.const 0x00000001))))
+      (else
+        (i32.wrap_i64 (local.get $self)))))
+
+  (func $Utils::i64::clamp_exponent_i32 (param $self i64) (result i32)
+    (if (result i32)
+      (i64.gt_s (local.get $self) (i64.const 0x7FFFFFFF))
+      (then
+        (i32.or
+          (i32.const 0x7FFFFFFE)
+          (i32.and (i32.wrap_i64 (local.get $self)) (i32.const 0x00000001))))
+      (else
+        (if (result i32)
+          (i64.lt_s (local.get $self) (i64.const -0x80000000))
+          (then
+            (i32.or
+              (i32.const 0x80000000)
+              (i32.and (i32.wrap_i64 (local.get $self)) (i32.const 0x00000001))))
+          (else
+            (i32.wrap_i64 (local.get $self)))))))
+
   (func $Utils::i32::neg (param $self i32) (result i32)
     (i32.sub (i32.const 0) (local.get $self)))
 
@@ -135,7 +166,14 @@
     (i64.mul (i64.div_u (i64.add (local.get $self) (i64.sub (local.get $step) (i64.const 1))) (local.get $step)) (local.get $step)))
 
   (func $Utils::f64::is_integer (param $self f64) (result i32)
-    (f64.eq (local.get $self) (f64.convert_i32_s (i32.trunc_f64_s (local.get $self)))))
+    ;; Values outside the 32-bit integer range (including NaN and infinite values) would trap when truncated, so are
+    ;; treated as non-integers
+    (if (result i32)
+      (f64.lt (f64.abs (local.get $self)) (f64.const 0x1p31))
+      (then
+        (f64.eq (local.get $self) (f64.convert_i32_s (i32.trunc_f64_s (local.get $self)))))
+      (else
+        (global.get $FALSE))))
 
   (func $Utils::f64::is_nan (param $self f64) (result i32)
     (f64.ne (local.get $self) (local.get $self)))
@@ -186,6 +224,181 @@
       (br_if $LOOP (local.tee $exponent (i32.shr_u (local.get $exponent) (i32.const 1)))))
     (local.get $result))
 
+  ;; Checked arithmetic helpers return the result of the operation, followed by a boolean indicating whether the result
+  ;; is valid according to the configured numeric semantics policy
+
+  (func $Utils::i64::overflow (param $wrapped i64) (param $saturated i64) (result i64 i32)
+    (if (result i64 i32)
+      (i32.eq (global.get $NUMERIC_SEMANTICS) (global.get $NumericSemantics::SATURATE))
+      (then
+        (local.get $saturated)
+        (global.get $TRUE))
+      (else
+        (local.get $wrapped)
+        (i32.ne (global.get $NUMERIC_SEMANTICS) (global.get $NumericSemantics::ERROR)))))
+
+  (func $Utils::i64::checked_add (param $self i64) (param $other i64) (result i64 i32)
+    (local $result i64)
+    (local.set $result (i64.add (local.get $self) (local.get $other)))
+    ;; Overflow has occurred if both operands have a different sign from the result
+    (if (result i64 i32)
+      (i64.lt_s
+        (i64.and
+          (i64.xor (local.get $self) (local.get $result))
+          (i64.xor (local.get $other) (local.get $result)))
+        (i64.const 0))
+      (then
+        (call $Utils::i64::overflow
+          (local.get $result)
+          (select
+            (i64.const 0x7FFFFFFFFFFFFFFF)
+            (i64.const 0x8000000000000000)
+            (i64.ge_s (local.get $self) (i64.const 0)))))
+      (else
+        (local.get $result)
+        (global.get $TRUE))))
+
+  (func $Utils::i64::checked_sub (param $self i64) (param $other i64) (result i64 i32)
+    (local $result i64)
+    (local.set $result (i64.sub (local.get $self) (local.get $other)))
+    ;; Overflow has occurred if the operands have different signs and the result sign differs from the left operand
+    (if (result i64 i32)
+      (i64.lt_s
+        (i64.and
+          (i64.xor (local.get $self) (local.get $other))
+          (i64.xor (local.get $self) (local.get $result)))
+        (i64.const 0))
+      (then
+        (call $Utils::i64::overflow
+          (local.get $result)
+          (select
+            (i64.const 0x7FFFFFFFFFFFFFFF)
+            (i64.const 0x8000000000000000)
+            (i64.ge_s (local.get $self) (i64.const 0)))))
+      (else
+        (local.get $result)
+        (global.get $TRUE))))
+
+  (func $Utils::i64::is_multiply_overflow (param $self i64) (param $other i64) (result i32)
+    (if (result i32)
+      (i64.eqz (local.get $self))
+      (then
+        (global.get $FALSE))
+      (else
+        ;; The division check would trap when dividing the minimum value by -1, so handle this case separately
+        (if (result i32)
+          (i64.eq (local.get $self) (i64.const -1))
+          (then
+            (i64.eq (local.get $other) (i64.const 0x8000000000000000)))
+          (else
+            (i64.ne
+              (i64.div_s (i64.mul (local.get $self) (local.get $other)) (local.get $self))
+              (local.get $other)))))))
+
+  (func $Utils::i64::checked_mul (param $self i64) (param $other i64) (result i64 i32)
+    (local $result i64)
+    (local.set $result (i64.mul (local.get $self) (local.get $other)))
+    (if (result i64 i32)
+      (call $Utils::i64::is_multiply_overflow (local.get $self) (local.get $other))
+      (then
+        (call $Utils::i64::overflow
+          (local.get $result)
+          (select
+            (i64.const 0x8000000000000000)
+            (i64.const 0x7FFFFFFFFFFFFFFF)
+            (i64.lt_s (i64.xor (local.get $self) (local.get $other)) (i64.const 0)))))
+      (else
+        (local.get $result)
+        (global.get $TRUE))))
+
+  (func $Utils::i64::checked_div_s (param $self i64) (param $other i64) (result i64 i32)
+    ;; The divisor is assumed to be non-zero; overflow only occurs when dividing the minimum value by -1
+    (if (result i64 i32)
+      (i32.and
+        (i64.eq (local.get $self) (i64.const 0x8000000000000000))
+        (i64.eq (local.get $other) (i64.const -1)))
+      (then
+        (call $Utils::i64::overflow
+          (i64.const 0x8000000000000000)
+          (i64.const 0x7FFFFFFFFFFFFFFF)))
+      (else
+        (i64.div_s (local.get $self) (local.get $other))
+        (global.get $TRUE))))
+
+  (func $Utils::i64::checked_abs (param $self i64) (result i64 i32)
+    ;; Overflow only occurs when negating the minimum value
+    (if (result i64 i32)
+      (i64.eq (local.get $self) (i64.const 0x8000000000000000))
+      (then
+        (call $Utils::i64::overflow
+          (i64.const 0x8000000000000000)
+          (i64.const 0x7FFFFFFFFFFFFFFF)))
+      (else
+        (call $Utils::i64::abs (local.get $self))
+        (global.get $TRUE))))
+
+  (func $Utils::i64::checked_pow (param $self i64) (param $exponent i32) (result i64 i32)
+    ;; See https://rosettacode.org/wiki/Exponentiation_operator#C
+    (local $base i64)
+    (local $result i64)
+    (local $remaining i32)
+    (local $is_overflow i32)
+    (local.set $base (local.get $self))
+    (local.set $result (i64.const 1))
+    (local.set $remaining (local.get $exponent))
+    (loop $LOOP
+      (if
+        (i32.and (local.get $remaining) (i32.const 0x00000001))
+        (then
+          (local.set $is_overflow
+            (i32.or
+              (local.get $is_overflow)
+              (call $Utils::i64::is_multiply_overflow (local.get $result) (local.get $base))))
+          (local.set $result (i64.mul (local.get $result) (local.get $base)))))
+      (if
+        (local.tee $remaining (i32.shr_u (local.get $remaining) (i32.const 1)))
+        (then
+          ;; The squared base will only contribute to the result if there are remaining exponent bits, so only check
+          ;; for overflow in that case (the result magnitude is at least 1, so an overflowing base overflows the result)
+          (local.set $is_overflow
+            (i32.or
+              (local.get $is_overflow)
+              (call $Utils::i64::is_multiply_overflow (local.get $base) (local.get $base))))
+          (local.set $base (i64.mul (local.get $base) (local.get $base)))
+          (br $LOOP))))
+    (if (result i64 i32)
+      (local.get $is_overflow)
+      (then
+        (call $Utils::i64::overflow
+          (local.get $result)
+          ;; Negative bases raised to odd exponents saturate to the minimum value
+          (select
+            (i64.const 0x8000000000000000)
+            (i64.const 0x7FFFFFFFFFFFFFFF)
+            (i32.and
+              (i64.lt_s (local.get $self) (i64.const 0))
+              (i32.and (local.get $exponent) (i32.const 0x00000001))))))
+      (else
+        (local.get $result)
+        (global.get $TRUE))))
+
+
+  (func $Utils::f64::checked_result (param $value f64) (result f64 i32)
+    (if (result f64 i32)
+      (i32.eq (global.get $NUMERIC_SEMANTICS) (global.get $NumericSemantics::SATURATE))
+      (then
+        ;; Clamp infinite results to the nearest finite value (NaN results are invalid)
+        (f64.max (f64.const -0x1.fffffffffffffp+1023) (f64.min (f64.const 0x1.fffffffffffffp+1023) (local.get $value)))
+        (i32.eqz (call $Utils::f64::is_nan (local.get $value))))
+      (else
+        (local.get $value)
+        (if (result i32)
+          (i32.eq (global.get $NUMERIC_SEMANTICS) (global.get $NumericSemantics::ERROR))
+          (then
+            (call $Utils::f64::is_finite (local.get $value)))
+          (else
+            (global.get $TRUE))))))
+
   (func $Utils::u8_array::equals (param $left_offset i32) (param $left_length i32) (param $right_offset i32) (param $right_length i32) (result i32)
     (local $index i32)
     (if (result i32)
@@ -285,6 +498,137 @@
       (else
         (global.get $FALSE))))
 
+  (func $Utils::bytes::compare (param $left_offset i32) (param $left_length i32) (param $right_offset i32) (param $right_length i32) (result i32)
+    ;; Returns -1, 0 or 1 according to the lexicographic ordering of the two byte arrays
+    (local $index i32)
+    (local $length i32)
+    (local $left_value i32)
+    (local $right_value i32)
+    (local.set $length (call $Utils::i32::min_u (local.get $left_length) (local.get $right_length)))
+    (block $BLOCK
+      (loop $LOOP
+        (br_if $BLOCK (i32.ge_u (local.get $index) (local.get $length)))
+        (local.set $left_value (i32.load8_u (i32.add (local.get $left_offset) (local.get $index))))
+        (local.set $right_value (i32.load8_u (i32.add (local.get $right_offset) (local.get $index))))
+        ;; If the bytes differ, the first differing byte determines the ordering
+        (if
+          (i32.ne (local.get $left_value) (local.get $right_value))
+          (then
+            (return
+              (select
+                (i32.const -1)
+                (i32.const 1)
+                (i32.lt_u (local.get $left_value) (local.get $right_value))))))
+        (local.set $index (i32.add (local.get $index) (i32.const 1)))
+        (br $LOOP)))
+    ;; If one array is a prefix of the other, the shorter array is ordered first
+    (i32.sub
+      (i32.gt_u (local.get $left_length) (local.get $right_length))
+      (i32.lt_u (local.get $left_length) (local.get $right_length))))
+
+  (func $Utils::bytes::reverse (param $offset i32) (param $length i32)
+    (local $end_offset i32)
+    (local $value i32)
+    ;; Swap bytes in place, working inwards from both ends of the range
+    (local.set $end_offset (i32.sub (i32.add (local.get $offset) (local.get $length)) (i32.const 1)))
+    (loop $LOOP
+      (if
+        (i32.lt_u (local.get $offset) (local.get $end_offset))
+        (then
+          (local.set $value (i32.load8_u (local.get $offset)))
+          (i32.store8 (local.get $offset) (i32.load8_u (local.get $end_offset)))
+          (i32.store8 (local.get $end_offset) (local.get $value))
+          (local.set $offset (i32.add (local.get $offset) (i32.const 1)))
+          (local.set $end_offset (i32.sub (local.get $end_offset) (i32.const 1)))
+          (br $LOOP)))))
+
+  (func $Utils::bytes::write_base64 (param $source_offset i32) (param $source_length i32) (param $target_offset i32) (result i32)
+    (local $encoded_length i32)
+    (local $remaining i32)
+    (local $chunk i32)
+    ;; Allocate four output characters for every (possibly partial) three-byte input chunk
+    (local.set $encoded_length
+      (i32.mul
+        (i32.div_u (i32.add (local.get $source_length) (i32.const 2)) (i32.const 3))
+        (i32.const 4)))
+    (call $Allocator::extend (local.get $target_offset) (local.get $encoded_length))
+    (local.set $remaining (local.get $source_length))
+    (loop $LOOP
+      (if
+        (i32.gt_u (local.get $remaining) (i32.const 0))
+        (then
+          ;; Combine up to three input bytes into a single 24-bit chunk, zero-padding any missing trailing bytes
+          (local.set $chunk (i32.shl (i32.load8_u (local.get $source_offset)) (i32.const 16)))
+          (if
+            (i32.gt_u (local.get $remaining) (i32.const 1))
+            (then
+              (local.set $chunk
+                (i32.or
+                  (local.get $chunk)
+                  (i32.shl (i32.load8_u offset=1 (local.get $source_offset)) (i32.const 8))))))
+          (if
+            (i32.gt_u (local.get $remaining) (i32.const 2))
+            (then
+              (local.set $chunk
+                (i32.or (local.get $chunk) (i32.load8_u offset=2 (local.get $source_offset))))))
+          ;; Write the four 6-bit groups as base64 characters, replacing any missing trailing bytes with padding
+          (i32.store8 offset=0
+            (local.get $target_offset)
+            (call $Utils::base64::encode_char (i32.shr_u (local.get $chunk) (i32.const 18))))
+          (i32.store8 offset=1
+            (local.get $target_offset)
+            (call $Utils::base64::encode_char
+              (i32.and (i32.shr_u (local.get $chunk) (i32.const 12)) (i32.const 0x3F))))
+          (i32.store8 offset=2
+            (local.get $target_offset)
+            (if (result i32)
+              (i32.gt_u (local.get $remaining) (i32.const 1))
+              (then
+                (call $Utils::base64::encode_char
+                  (i32.and (i32.shr_u (local.get $chunk) (i32.const 6)) (i32.const 0x3F))))
+              (else
+                (@char "="))))
+          (i32.store8 offset=3
+            (local.get $target_offset)
+            (if (result i32)
+              (i32.gt_u (local.get $remaining) (i32.const 2))
+              (then
+                (call $Utils::base64::encode_char (i32.and (local.get $chunk) (i32.const 0x3F))))
+              (else
+                (@char "="))))
+          (local.set $source_offset (i32.add (local.get $source_offset) (i32.const 3)))
+          (local.set $target_offset (i32.add (local.get $target_offset) (i32.const 4)))
+          (local.set $remaining
+            (select
+              (i32.sub (local.get $remaining) (i32.const 3))
+              (i32.const 0)
+              (i32.gt_u (local.get $remaining) (i32.const 3))))
+          (br $LOOP))))
+    ;; Return the number of bytes written
+    (local.get $encoded_length))
+
+  (func $Utils::base64::encode_char (param $value i32) (result i32)
+    ;; Map a 6-bit value onto the standard base64 alphabet
+    (if (result i32)
+      (i32.lt_u (local.get $value) (i32.const 26))
+      (then
+        (i32.add (@char "A") (local.get $value)))
+      (else
+        (if (result i32)
+          (i32.lt_u (local.get $value) (i32.const 52))
+          (then
+            (i32.add (@char "a") (i32.sub (local.get $value) (i32.const 26))))
+          (else
+            (if (result i32)
+              (i32.lt_u (local.get $value) (i32.const 62))
+              (then
+                (i32.add (@char "0") (i32.sub (local.get $value) (i32.const 52))))
+              (else
+                (select
+                  (@char "+")
+                  (@char "/")
+                  (i32.eq (local.get $value) (i32.const 62))))))))))
+
   (func $Utils::bytes::write_json (param $source_offset i32) (param $source_length i32) (param $target_offset i32) (result i32)
     (local $original_offset i32)
     (local $index i32)
@@ -508,71 +852,11 @@
       (br_if $LOOP (i32.wrap_i64 (local.tee $value (i64.div_u (local.get $value) (i64.const 10)))))))
 
   (func $Utils::f64::write_string (param $value f64) (param $offset i32) (result i32)
-    (local $original_offset i32)
-    (local $integers f64)
-    (local $multiplier f64)
-    (local $num_decimals i32)
-    (local $remaining_decimals f64)
-    (local $next_decimal f64)
     (if (result i32)
       ;; If the value is NaN or infinite, return the null sentinel value
       (i32.eqz (call $Utils::f64::is_finite (local.get $value)))
       (then
         (global.get $NULL))
       (else
-        ;; Keep track of the original offset to determine how many bytes were written
-        (local.set $original_offset (local.get $offset))
-        ;; Determine how many digits are needed to represent the decimal portion of the number
-        (local.set $multiplier (f64.const 1))
-        (loop $LOOP
-          ;; Increment the number of decimals
-          (local.set $num_decimals (i32.add (local.get $num_decimals) (i32.const 1)))
-          ;; Loop until round(value * multiplier) / multiplier == value
-          (br_if $LOOP
-            (i32.and
-              (f64.ne
-                (f64.div
-                  (f64.nearest
-                    (f64.mul
-                      (local.get $value)
-                      ;; Increase the multiplier by a factor of 10 on each iteration
-                      (local.tee $multiplier (f64.mul (local.get $multiplier) (f64.const 10)))))
-                  (local.get $multiplier))
-                (local.get $value))
-              ;; Enforce a maximum number of decimal places to avoid potential infinite loops
-              (i32.lt_u (local.get $num_decimals) (i32.const 32)))))
-        ;; Write the integer portion of the number
-        (call $Utils::i32::write_string
-          (i32.trunc_f64_s (local.tee $integers (f64.trunc (local.get $value))))
-          (local.get $offset))
-        ;; Allocate the required number of bytes to store the decimal point character followed by the decimal digits
-        (call $Allocator::extend
-          (local.tee $offset (i32.add (local.get $offset)))
-          (i32.add (i32.const 1) (local.get $num_decimals)))
-        ;; Write the decimal point character and increment the current offset
-        (i32.store8 (local.get $offset) (@char "."))
-        (local.set $offset (i32.add (local.get $offset) (i32.const 1)))
-        ;; Write the decimal digits
-        (local.set $remaining_decimals (f64.abs (f64.sub (local.get $value) (local.get $integers))))
-        (if
-          (i32.gt_u (local.get $num_decimals) (i32.const 1))
-          (then
-            ;; Write all but the final decimals by multiplying by 10 and truncating
-            (loop $LOOP
-              (call $Utils::u8::write_decimal_digit
-                (i32.trunc_f64_u
-                  (local.tee $next_decimal
-                    (f64.trunc (local.tee $remaining_decimals (f64.mul (local.get $remaining_decimals) (f64.const 10))))))
-                (local.get $offset))
-              (local.set $offset (i32.add (local.get $offset) (i32.const 1)))
-              (local.set $remaining_decimals (f64.sub (local.get $remaining_decimals) (local.get $next_decimal)))
-              (br_if $LOOP (i32.gt_u (local.tee $num_decimals (i32.sub (local.get $num_decimals) (i32.const 1))) (i32.const 1)))))
-          (else))
-        ;; Write the final decimal by mutiplying by 10 and rounding
-        (call $Utils::u8::write_decimal_digit
-          (i32.trunc_f64_u (f64.nearest (f64.mul (local.get $remaining_decimals) (f64.const 10))))
-          (local.get $offset))
-        ;; Increment the current offset to reflect the final digit
-        (i32.add (local.get $offset) (i32.const 1))
-        ;; Subtract the original offset to calculate the number of bytes written
-        (i32.sub (local.get $original_offset))))))
+        ;; Delegate to the host float formatter to ensure output is consistent with the other backends
+        (call $Utils::Float::to_string (local.get $value) (local.get $offset))))))
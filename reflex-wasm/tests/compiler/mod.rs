@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 mod lazy;
+mod numeric_semantics;
 mod runner;
 mod stdlib;
 mod term_type;
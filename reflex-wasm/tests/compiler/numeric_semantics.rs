@@ -0,0 +1,211 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{
+    create_checked_float_term, create_checked_int_term, Expression, ExpressionFactory, FloatValue,
+    HeapAllocator, IntValue, NumericSemantics,
+};
+use reflex_wasm::stdlib;
+
+use crate::{compiler::runner::run_scenario, WasmTestScenario};
+
+#[test]
+fn numeric_semantics() {
+    use NumericSemantics::{Error, Saturate, Wrap};
+    use NumericValue::{Float, FloatError, Int, IntError};
+    let add = stdlib::Stdlib::Add(stdlib::Add);
+    let subtract = stdlib::Stdlib::Subtract(stdlib::Subtract);
+    let multiply = stdlib::Stdlib::Multiply(stdlib::Multiply);
+    let divide = stdlib::Stdlib::Divide(stdlib::Divide);
+    let remainder = stdlib::Stdlib::Remainder(stdlib::Remainder);
+    let pow = stdlib::Stdlib::Pow(stdlib::Pow);
+    for (numeric_semantics, builtin, args, expected) in [
+        (Wrap, add, (Int(IntValue::MAX), Int(1)), Int(IntValue::MIN)),
+        (
+            Saturate,
+            add,
+            (Int(IntValue::MAX), Int(1)),
+            Int(IntValue::MAX),
+        ),
+        (Error, add, (Int(IntValue::MAX), Int(1)), IntError),
+        (Error, add, (Int(3), Int(4)), Int(7)),
+        (
+            Wrap,
+            subtract,
+            (Int(IntValue::MIN), Int(1)),
+            Int(IntValue::MAX),
+        ),
+        (
+            Saturate,
+            subtract,
+            (Int(IntValue::MIN), Int(1)),
+            Int(IntValue::MIN),
+        ),
+        (Error, subtract, (Int(IntValue::MIN), Int(1)), IntError),
+        (Wrap, multiply, (Int(IntValue::MAX), Int(2)), Int(-2)),
+        (
+            Saturate,
+            multiply,
+            (Int(IntValue::MIN), Int(2)),
+            Int(IntValue::MIN),
+        ),
+        (
+            Saturate,
+            multiply,
+            (Int(-1), Int(IntValue::MIN)),
+            Int(IntValue::MAX),
+        ),
+        (Error, multiply, (Int(IntValue::MAX), Int(2)), IntError),
+        (Error, multiply, (Int(-3), Int(4)), Int(-12)),
+        (
+            Wrap,
+            divide,
+            (Int(IntValue::MIN), Int(-1)),
+            Int(IntValue::MIN),
+        ),
+        (
+            Saturate,
+            divide,
+            (Int(IntValue::MIN), Int(-1)),
+            Int(IntValue::MAX),
+        ),
+        (Error, divide, (Int(IntValue::MIN), Int(-1)), IntError),
+        (
+            Wrap,
+            add,
+            (Float(FloatValue::MAX), Float(FloatValue::MAX)),
+            Float(FloatValue::INFINITY),
+        ),
+        (
+            Saturate,
+            add,
+            (Float(FloatValue::MAX), Float(FloatValue::MAX)),
+            Float(FloatValue::MAX),
+        ),
+        (
+            Error,
+            add,
+            (Float(FloatValue::MAX), Float(FloatValue::MAX)),
+            FloatError,
+        ),
+        (Error, add, (Float(1.5), Float(2.0)), Float(3.5)),
+        (Wrap, remainder, (Int(IntValue::MIN), Int(-1)), Int(0)),
+        (Saturate, remainder, (Int(IntValue::MIN), Int(-1)), Int(0)),
+        (Error, remainder, (Int(IntValue::MIN), Int(-1)), Int(0)),
+        (
+            Saturate,
+            remainder,
+            (Float(FloatValue::INFINITY), Float(2.0)),
+            FloatError,
+        ),
+        (
+            Error,
+            remainder,
+            (Float(FloatValue::INFINITY), Float(2.0)),
+            FloatError,
+        ),
+        (Error, remainder, (Float(5.5), Int(2)), Float(1.5)),
+        (Wrap, pow, (Int(2), Int(63)), Int(IntValue::MIN)),
+        (Saturate, pow, (Int(2), Int(63)), Int(IntValue::MAX)),
+        (Saturate, pow, (Int(-2), Int(63)), Int(IntValue::MIN)),
+        (Error, pow, (Int(2), Int(63)), IntError),
+        (Error, pow, (Int(3), Int(4)), Int(81)),
+        (Error, pow, (Int(2), Int(4294967296)), IntError),
+        (
+            Saturate,
+            pow,
+            (Int(-2), Int(4294967297)),
+            Int(IntValue::MIN),
+        ),
+        (Error, pow, (Int(-1), Int(4294967297)), Int(-1)),
+        (Error, pow, (Int(2), Int(-2)), Float(0.25)),
+        (Error, pow, (Int(2), Int(-4294967295)), Float(0.0)),
+        (Error, pow, (Int(-1), Int(-4294967295)), Float(-1.0)),
+        (Error, pow, (Float(2.0), Int(4294967297)), FloatError),
+        (
+            Wrap,
+            pow,
+            (Float(10.0), Float(400.0)),
+            Float(FloatValue::INFINITY),
+        ),
+        (
+            Saturate,
+            pow,
+            (Float(10.0), Float(400.0)),
+            Float(FloatValue::MAX),
+        ),
+        (Error, pow, (Float(10.0), Float(400.0)), FloatError),
+    ] {
+        let scenario = NumericSemanticsScenario {
+            numeric_semantics,
+            builtin,
+            args,
+            expected,
+        };
+        let (actual, expected) = run_scenario(&scenario).unwrap();
+        assert_eq!(actual, expected);
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum NumericValue {
+    Int(IntValue),
+    Float(FloatValue),
+    /// Error signal emitted when an integer result cannot be represented
+    IntError,
+    /// Error signal emitted when a float result is rejected by the numeric semantics policy
+    FloatError,
+}
+
+impl NumericValue {
+    fn create_term<T: Expression>(
+        self,
+        factory: &impl ExpressionFactory<T>,
+        allocator: &impl HeapAllocator<T>,
+    ) -> T {
+        match self {
+            Self::Int(value) => factory.create_int_term(value),
+            Self::Float(value) => factory.create_float_term(value),
+            Self::IntError => create_checked_int_term(None, factory, allocator),
+            Self::FloatError => create_checked_float_term(None, factory, allocator),
+        }
+    }
+}
+
+struct NumericSemanticsScenario {
+    numeric_semantics: NumericSemantics,
+    builtin: stdlib::Stdlib,
+    args: (NumericValue, NumericValue),
+    expected: NumericValue,
+}
+
+impl<T, TFactory> WasmTestScenario<T, TFactory> for NumericSemanticsScenario
+where
+    T: Expression<Builtin = stdlib::Stdlib>,
+    TFactory: ExpressionFactory<T>,
+{
+    fn numeric_semantics(&self) -> NumericSemantics {
+        self.numeric_semantics
+    }
+
+    fn input(&self, factory: &TFactory, allocator: &impl HeapAllocator<T>) -> T {
+        let (left, right) = self.args;
+        factory.create_application_term(
+            factory.create_builtin_term(self.builtin),
+            allocator.create_pair(
+                left.create_term(factory, allocator),
+                right.create_term(factory, allocator),
+            ),
+        )
+    }
+
+    fn expected(
+        &self,
+        factory: &TFactory,
+        allocator: &impl HeapAllocator<T>,
+    ) -> (T, Vec<T::Signal>) {
+        let result = self.expected.create_term(factory, allocator);
+        let dependencies = Default::default();
+        (result, dependencies)
+    }
+}
@@ -51,12 +51,13 @@ pub(crate) fn run_scenario(
     ),
     CompilerTestError<CachedSharedTerm<reflex_wasm::stdlib::Stdlib>>,
 > {
-    let factory = SharedTermFactory::<reflex_wasm::stdlib::Stdlib>::default();
+    let factory = SharedTermFactory::<reflex_wasm::stdlib::Stdlib>::default()
+        .with_numeric_semantics(scenario.numeric_semantics());
     let allocator = DefaultAllocator::<CachedSharedTerm<reflex_wasm::stdlib::Stdlib>>::default();
     let compiler_options = WasmCompilerOptions {
         compiler: scenario.options(),
-        runtime: Default::default(),
-        generator: Default::default(),
+        debug_info: false,
+        ..Default::default()
     };
     let expression = scenario.input(&factory, &allocator);
     let state = scenario.state(&factory, &allocator);
@@ -362,6 +363,7 @@ where
         [(&entry_point_name, entry_point)],
         &RUNTIME_BYTES,
         Some(&linear_memory),
+        factory.numeric_semantics(),
         compiler_options,
         true,
     )
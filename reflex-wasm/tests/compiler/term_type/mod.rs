@@ -5,6 +5,7 @@ mod application;
 mod boolean;
 mod builtin;
 mod constructor;
+mod duration;
 mod effect;
 mod float;
 mod hashmap;
@@ -16,7 +17,9 @@ mod list;
 mod nil;
 mod partial;
 mod record;
+mod recursive;
 mod signal;
 mod string;
 mod symbol;
+mod timestamp;
 mod variable;
@@ -0,0 +1,194 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{Expression, ExpressionFactory, HeapAllocator};
+use reflex_wasm::stdlib;
+
+use crate::{compiler::runner::run_scenario, WasmTestScenario};
+
+#[test]
+fn recursive_term() {
+    let scenario = RecursiveTermFunctionScenario;
+    let (actual, expected) = run_scenario(&scenario).unwrap();
+    assert_eq!(actual, expected);
+
+    let scenario = RecursiveTermMutuallyRecursiveFunctionsScenario;
+    let (actual, expected) = run_scenario(&scenario).unwrap();
+    assert_eq!(actual, expected);
+}
+
+struct RecursiveTermFunctionScenario;
+
+impl<T, TFactory> WasmTestScenario<T, TFactory> for RecursiveTermFunctionScenario
+where
+    T: Expression<Builtin = stdlib::Stdlib>,
+    TFactory: ExpressionFactory<T>,
+{
+    fn input(&self, factory: &TFactory, allocator: &impl HeapAllocator<T>) -> T {
+        // Note that the inner closures are declared in their lambda-lifted form,
+        // with any captured variables passed in as partially-applied arguments
+        let factorial =
+            factory.create_recursive_term(factory.create_lambda_term(
+                1,
+                factory.create_partial_application_term(
+                    factory.create_lambda_term(
+                        2,
+                        factory.create_application_term(
+                            factory.create_builtin_term(stdlib::If),
+                            allocator.create_triple(
+                                factory.create_application_term(
+                                    factory.create_builtin_term(stdlib::Eq),
+                                    allocator.create_pair(
+                                        factory.create_variable_term(0),
+                                        factory.create_int_term(0),
+                                    ),
+                                ),
+                                factory.create_lambda_term(0, factory.create_int_term(1)),
+                                factory.create_partial_application_term(
+                                    factory.create_lambda_term(
+                                        2,
+                                        factory.create_application_term(
+                                            factory.create_builtin_term(stdlib::Multiply),
+                                            allocator.create_pair(
+                                                factory.create_variable_term(0),
+                                                factory.create_application_term(
+                                                    factory.create_variable_term(1),
+                                                    allocator.create_unit_list(
+                                                        factory.create_application_term(
+                                                            factory.create_builtin_term(
+                                                                stdlib::Subtract,
+                                                            ),
+                                                            allocator.create_pair(
+                                                                factory.create_variable_term(0),
+                                                                factory.create_int_term(1),
+                                                            ),
+                                                        ),
+                                                    ),
+                                                ),
+                                            ),
+                                        ),
+                                    ),
+                                    allocator.create_pair(
+                                        factory.create_variable_term(1),
+                                        factory.create_variable_term(0),
+                                    ),
+                                ),
+                            ),
+                        ),
+                    ),
+                    allocator.create_unit_list(factory.create_variable_term(0)),
+                ),
+            ));
+        factory.create_application_term(
+            factorial,
+            allocator.create_unit_list(factory.create_int_term(5)),
+        )
+    }
+
+    fn expected(
+        &self,
+        factory: &TFactory,
+        _allocator: &impl HeapAllocator<T>,
+    ) -> (T, Vec<T::Signal>) {
+        let result = factory.create_int_term(120);
+        let dependencies = Default::default();
+        (result, dependencies)
+    }
+}
+
+struct RecursiveTermMutuallyRecursiveFunctionsScenario;
+
+impl<T, TFactory> WasmTestScenario<T, TFactory> for RecursiveTermMutuallyRecursiveFunctionsScenario
+where
+    T: Expression<Builtin = stdlib::Stdlib>,
+    TFactory: ExpressionFactory<T>,
+{
+    fn input(&self, factory: &TFactory, allocator: &impl HeapAllocator<T>) -> T {
+        // Note that the inner closures are declared in their lambda-lifted form,
+        // with any captured variables passed in as partially-applied arguments
+        let create_parity_check = |base_case: bool, other: i64| {
+            factory.create_partial_application_term(
+                factory.create_lambda_term(
+                    2,
+                    factory.create_application_term(
+                        factory.create_builtin_term(stdlib::If),
+                        allocator.create_triple(
+                            factory.create_application_term(
+                                factory.create_builtin_term(stdlib::Eq),
+                                allocator.create_pair(
+                                    factory.create_variable_term(0),
+                                    factory.create_int_term(0),
+                                ),
+                            ),
+                            factory.create_lambda_term(0, factory.create_boolean_term(base_case)),
+                            factory.create_partial_application_term(
+                                factory.create_lambda_term(
+                                    2,
+                                    factory.create_application_term(
+                                        factory.create_application_term(
+                                            factory.create_builtin_term(stdlib::Get),
+                                            allocator.create_pair(
+                                                factory.create_variable_term(1),
+                                                factory.create_int_term(other),
+                                            ),
+                                        ),
+                                        allocator.create_unit_list(
+                                            factory.create_application_term(
+                                                factory.create_builtin_term(stdlib::Subtract),
+                                                allocator.create_pair(
+                                                    factory.create_variable_term(0),
+                                                    factory.create_int_term(1),
+                                                ),
+                                            ),
+                                        ),
+                                    ),
+                                ),
+                                allocator.create_pair(
+                                    factory.create_variable_term(1),
+                                    factory.create_variable_term(0),
+                                ),
+                            ),
+                        ),
+                    ),
+                ),
+                allocator.create_unit_list(factory.create_variable_term(0)),
+            )
+        };
+        let definitions = factory.create_recursive_term(factory.create_lambda_term(
+            1,
+            factory.create_list_term(
+                allocator.create_pair(create_parity_check(true, 1), create_parity_check(false, 0)),
+            ),
+        ));
+        let is_even = factory.create_application_term(
+            factory.create_builtin_term(stdlib::Get),
+            allocator.create_pair(definitions, factory.create_int_term(0)),
+        );
+        factory.create_application_term(
+            factory.create_builtin_term(stdlib::ResolveList),
+            allocator.create_unit_list(factory.create_list_term(allocator.create_pair(
+                factory.create_application_term(
+                    is_even.clone(),
+                    allocator.create_unit_list(factory.create_int_term(4)),
+                ),
+                factory.create_application_term(
+                    is_even,
+                    allocator.create_unit_list(factory.create_int_term(7)),
+                ),
+            ))),
+        )
+    }
+
+    fn expected(
+        &self,
+        factory: &TFactory,
+        allocator: &impl HeapAllocator<T>,
+    ) -> (T, Vec<T::Signal>) {
+        let result = factory.create_list_term(allocator.create_pair(
+            factory.create_boolean_term(true),
+            factory.create_boolean_term(false),
+        ));
+        let dependencies = Default::default();
+        (result, dependencies)
+    }
+}
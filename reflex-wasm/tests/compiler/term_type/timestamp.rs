@@ -0,0 +1,36 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::core::{Expression, ExpressionFactory, HeapAllocator};
+use reflex_wasm::stdlib;
+
+use crate::{compiler::runner::run_scenario, WasmTestScenario};
+
+#[test]
+fn timestamp_term() {
+    let scenario = TimestampTermScenario;
+    let (actual, expected) = run_scenario(&scenario).unwrap();
+    assert_eq!(actual, expected);
+}
+
+struct TimestampTermScenario;
+
+impl<T, TFactory> WasmTestScenario<T, TFactory> for TimestampTermScenario
+where
+    T: Expression<Builtin = stdlib::Stdlib>,
+    TFactory: ExpressionFactory<T>,
+{
+    fn input(&self, factory: &TFactory, _allocator: &impl HeapAllocator<T>) -> T {
+        factory.create_timestamp_term(1677899167089, -330)
+    }
+
+    fn expected(
+        &self,
+        factory: &TFactory,
+        _allocator: &impl HeapAllocator<T>,
+    ) -> (T, Vec<T::Signal>) {
+        let result = factory.create_timestamp_term(1677899167089, -330);
+        let dependencies = Default::default();
+        (result, dependencies)
+    }
+}
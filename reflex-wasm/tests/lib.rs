@@ -1,7 +1,7 @@
 // SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
-use reflex::core::{Expression, ExpressionFactory, HeapAllocator};
+use reflex::core::{Expression, ExpressionFactory, HeapAllocator, NumericSemantics};
 use reflex_wasm::compiler::CompilerOptions;
 
 mod compiler;
@@ -10,6 +10,9 @@ pub trait WasmTestScenario<T: Expression, TFactory: ExpressionFactory<T>> {
     fn options(&self) -> CompilerOptions {
         Default::default()
     }
+    fn numeric_semantics(&self) -> NumericSemantics {
+        Default::default()
+    }
     fn input(&self, factory: &TFactory, allocator: &impl HeapAllocator<T>) -> T;
     fn state(&self, factory: &TFactory, allocator: &impl HeapAllocator<T>) -> Vec<(T::Signal, T)> {
         let _ = factory;
@@ -0,0 +1,316 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    collections::{BTreeMap, HashSet},
+    ops::Deref,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{
+        ApplicationTermType, BytesTermType, Expression, ExpressionFactory, RefType, StringTermType,
+        StringValue,
+    },
+    hash::{HashId, IntMap, IntSet},
+    lint::{get_child_expressions, get_matching_builtin},
+};
+
+/// Approximate size of the hash and type discriminant that precede each term within a heap snapshot
+const TERM_HEADER_SIZE: usize = 12;
+/// Size of a reference to another term within a heap snapshot
+const POINTER_SIZE: usize = 4;
+
+/// Configuration for the graph metrics analysis
+///
+/// Builtins can be identified by UUID, by qualified name (e.g. `stdlib:Effect`) or by unqualified name (e.g. `Effect`).
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GraphMetricsConfig {
+    /// Builtins that construct effects
+    pub effect_builtins: HashSet<String>,
+}
+
+impl Default for GraphMetricsConfig {
+    fn default() -> Self {
+        Self {
+            effect_builtins: ["Effect"].into_iter().map(String::from).collect(),
+        }
+    }
+}
+
+impl GraphMetricsConfig {
+    /// Compute structural metrics for the provided expression
+    ///
+    /// Subexpressions that are shared between multiple parents are only counted once.
+    pub fn analyze_expression<T: Expression>(
+        &self,
+        expression: &T,
+        factory: &impl ExpressionFactory<T>,
+    ) -> GraphMetrics {
+        let mut state = GraphMetricsState::default();
+        let depth = analyze_expression(expression, self, factory, &mut state);
+        GraphMetrics {
+            num_nodes: state.depths.len(),
+            term_counts: state.term_counts,
+            max_depth: depth,
+            num_effects: state.effects.len(),
+            estimated_snapshot_size: state.estimated_snapshot_size,
+        }
+    }
+}
+
+/// Structural metrics of an expression graph
+#[derive(PartialEq, Eq, Clone, Default, Debug, Serialize, Deserialize)]
+pub struct GraphMetrics {
+    /// Number of distinct terms within the graph
+    pub num_nodes: usize,
+    /// Number of distinct terms within the graph, grouped by term type
+    pub term_counts: BTreeMap<String, usize>,
+    /// Length of the longest path from the root expression to a leaf term
+    pub max_depth: usize,
+    /// Number of distinct effects within the graph
+    pub num_effects: usize,
+    /// Estimated size of the serialized graph within a heap snapshot, in bytes
+    pub estimated_snapshot_size: usize,
+}
+
+impl std::fmt::Display for GraphMetrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Nodes: {}", self.num_nodes)?;
+        writeln!(f, "Max depth: {}", self.max_depth)?;
+        writeln!(f, "Effects: {}", self.num_effects)?;
+        writeln!(
+            f,
+            "Estimated snapshot size: {} bytes",
+            self.estimated_snapshot_size
+        )?;
+        write!(f, "Terms:")?;
+        for (term_type, count) in self.term_counts.iter() {
+            write!(f, "\n  {}: {}", term_type, count)?;
+        }
+        Ok(())
+    }
+}
+
+/// Upper limits for graph metrics, used to reject oversized graphs before deployment
+#[derive(PartialEq, Eq, Clone, Default, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GraphMetricsBudget {
+    pub max_nodes: Option<usize>,
+    pub max_depth: Option<usize>,
+    pub max_effects: Option<usize>,
+    pub max_snapshot_size: Option<usize>,
+}
+
+impl GraphMetricsBudget {
+    /// Report any budgets exceeded by the provided metrics
+    pub fn validate(&self, metrics: &GraphMetrics) -> Vec<GraphMetricsBudgetViolation> {
+        [
+            (GraphMetric::Nodes, metrics.num_nodes, self.max_nodes),
+            (GraphMetric::Depth, metrics.max_depth, self.max_depth),
+            (GraphMetric::Effects, metrics.num_effects, self.max_effects),
+            (
+                GraphMetric::SnapshotSize,
+                metrics.estimated_snapshot_size,
+                self.max_snapshot_size,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(metric, value, limit)| {
+            limit
+                .filter(|limit| value > *limit)
+                .map(|limit| GraphMetricsBudgetViolation {
+                    metric,
+                    value,
+                    limit,
+                })
+        })
+        .collect()
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GraphMetric {
+    Nodes,
+    Depth,
+    Effects,
+    SnapshotSize,
+}
+
+impl GraphMetric {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Nodes => "node count",
+            Self::Depth => "max depth",
+            Self::Effects => "effect count",
+            Self::SnapshotSize => "estimated snapshot size",
+        }
+    }
+}
+
+impl std::fmt::Display for GraphMetric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct GraphMetricsBudgetViolation {
+    pub metric: GraphMetric,
+    pub value: usize,
+    pub limit: usize,
+}
+
+impl std::fmt::Display for GraphMetricsBudgetViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Graph {} ({}) exceeds budget of {}",
+            self.metric, self.value, self.limit
+        )
+    }
+}
+
+#[derive(Default)]
+struct GraphMetricsState {
+    depths: IntMap<HashId, usize>,
+    term_counts: BTreeMap<String, usize>,
+    effects: IntSet<HashId>,
+    estimated_snapshot_size: usize,
+}
+
+fn analyze_expression<T: Expression>(
+    expression: &T,
+    config: &GraphMetricsConfig,
+    factory: &impl ExpressionFactory<T>,
+    state: &mut GraphMetricsState,
+) -> usize {
+    let id = expression.id();
+    if let Some(depth) = state.depths.get(&id) {
+        return *depth;
+    }
+    // Guard against revisiting the current term while its children are being analyzed
+    state.depths.insert(id, 0);
+    let children = get_child_expressions(expression, factory);
+    *state
+        .term_counts
+        .entry(String::from(get_term_type_name(expression, factory)))
+        .or_insert(0) += 1;
+    if is_effect(expression, config, factory) {
+        state.effects.insert(id);
+    }
+    state.estimated_snapshot_size += TERM_HEADER_SIZE
+        + get_term_payload_size(expression, factory)
+        + children.len() * POINTER_SIZE;
+    let depth = 1 + children
+        .iter()
+        .map(|(_, child)| analyze_expression(child, config, factory, state))
+        .max()
+        .unwrap_or(0);
+    state.depths.insert(id, depth);
+    depth
+}
+
+fn is_effect<T: Expression>(
+    expression: &T,
+    config: &GraphMetricsConfig,
+    factory: &impl ExpressionFactory<T>,
+) -> bool {
+    if factory.match_effect_term(expression).is_some() {
+        true
+    } else if let Some(term) = factory.match_application_term(expression) {
+        get_matching_builtin(term.target().as_deref(), &config.effect_builtins, factory).is_some()
+    } else {
+        false
+    }
+}
+
+fn get_term_payload_size<T: Expression>(
+    expression: &T,
+    factory: &impl ExpressionFactory<T>,
+) -> usize {
+    if let Some(term) = factory.match_string_term(expression) {
+        get_byte_array_size(term.value().as_deref().as_str().deref().len())
+    } else if let Some(term) = factory.match_bytes_term(expression) {
+        get_byte_array_size(term.value().len())
+    } else if factory.match_int_term(expression).is_some()
+        || factory.match_float_term(expression).is_some()
+        || factory.match_timestamp_term(expression).is_some()
+        || factory.match_duration_term(expression).is_some()
+    {
+        8
+    } else if factory.match_nil_term(expression).is_some() {
+        0
+    } else {
+        POINTER_SIZE
+    }
+}
+
+fn get_byte_array_size(length: usize) -> usize {
+    // Byte arrays are prefixed with their length and padded to a word boundary
+    POINTER_SIZE + length.div_ceil(POINTER_SIZE) * POINTER_SIZE
+}
+
+fn get_term_type_name<T: Expression>(
+    expression: &T,
+    factory: &impl ExpressionFactory<T>,
+) -> &'static str {
+    if factory.match_nil_term(expression).is_some() {
+        "Nil"
+    } else if factory.match_boolean_term(expression).is_some() {
+        "Boolean"
+    } else if factory.match_int_term(expression).is_some() {
+        "Int"
+    } else if factory.match_float_term(expression).is_some() {
+        "Float"
+    } else if factory.match_string_term(expression).is_some() {
+        "String"
+    } else if factory.match_symbol_term(expression).is_some() {
+        "Symbol"
+    } else if factory.match_timestamp_term(expression).is_some() {
+        "Timestamp"
+    } else if factory.match_duration_term(expression).is_some() {
+        "Duration"
+    } else if factory.match_bytes_term(expression).is_some() {
+        "Bytes"
+    } else if factory.match_regex_term(expression).is_some() {
+        "Regex"
+    } else if factory.match_variable_term(expression).is_some() {
+        "Variable"
+    } else if factory.match_effect_term(expression).is_some() {
+        "Effect"
+    } else if factory.match_let_term(expression).is_some() {
+        "Let"
+    } else if factory.match_lambda_term(expression).is_some() {
+        "Lambda"
+    } else if factory.match_lazy_result_term(expression).is_some() {
+        "LazyResult"
+    } else if factory.match_application_term(expression).is_some() {
+        "Application"
+    } else if factory.match_partial_application_term(expression).is_some() {
+        "PartialApplication"
+    } else if factory.match_recursive_term(expression).is_some() {
+        "Recursive"
+    } else if factory.match_builtin_term(expression).is_some() {
+        "Builtin"
+    } else if factory.match_compiled_function_term(expression).is_some() {
+        "CompiledFunction"
+    } else if factory.match_record_term(expression).is_some() {
+        "Record"
+    } else if factory.match_constructor_term(expression).is_some() {
+        "Constructor"
+    } else if factory.match_list_term(expression).is_some() {
+        "List"
+    } else if factory.match_hashmap_term(expression).is_some() {
+        "Hashmap"
+    } else if factory.match_hashset_term(expression).is_some() {
+        "Hashset"
+    } else if factory.match_signal_term(expression).is_some() {
+        "Signal"
+    } else {
+        "Unknown"
+    }
+}
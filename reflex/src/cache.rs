@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
 // SPDX-FileContributor: Chris Campbell <c.campbell@mwam.com> https://github.com/c-campbell-mwam
-use std::collections::hash_map::Entry;
+use std::collections::{hash_map::Entry, VecDeque};
 
 use crate::{
     core::{
@@ -55,6 +55,13 @@ pub trait EvaluationCache<T: Expression> {
         state: &impl DynamicState<T>,
         result: Option<EvaluationResult<T>>,
     );
+    /// Retrieve a previously-memoized result from the bounded memo table associated with the given function
+    fn retrieve_memoized(&mut self, _function: HashId, _key: HashId) -> Option<T> {
+        None
+    }
+    /// Store a result in the memo table associated with the given function, evicting the least recently used entries
+    /// once the table exceeds the given capacity (caches that do not maintain memo tables will ignore this)
+    fn store_memoized(&mut self, _function: HashId, _capacity: usize, _key: HashId, _result: T) {}
     fn metrics(&self) -> Option<&EvaluationCacheMetrics> {
         None
     }
@@ -69,15 +76,17 @@ pub struct EvaluationCacheMetrics {
     pub(crate) static_substitutions: CacheMetrics,
     pub(crate) dynamic_substitutions: CacheMetrics,
     pub(crate) evaluations: CacheMetrics,
+    pub(crate) memoizations: CacheMetrics,
 }
 impl std::fmt::Display for EvaluationCacheMetrics {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f,
-            "Evaluations:           {}\nReductions:            {}\nStatic substitutions:  {}\nDynamic substitutions: {}",
+            "Evaluations:           {}\nReductions:            {}\nStatic substitutions:  {}\nDynamic substitutions: {}\nMemoizations:          {}",
             self.evaluations,
             self.reductions,
             self.static_substitutions,
             self.dynamic_substitutions,
+            self.memoizations,
         )
     }
 }
@@ -167,12 +176,14 @@ impl<T: Expression> EvaluationCache<T> for NoopCache {
 
 pub struct SubstitutionCache<T: Expression> {
     entries: IntMap<HashId, SubstitutionCacheEntry<T>>,
+    memo_tables: MemoTables<T>,
     metrics: EvaluationCacheMetrics,
 }
 impl<T: Expression> SubstitutionCache<T> {
     pub fn new() -> Self {
         Self {
             entries: IntMap::default(),
+            memo_tables: MemoTables::default(),
             metrics: EvaluationCacheMetrics::default(),
         }
     }
@@ -339,6 +350,22 @@ impl<T: Expression> EvaluationCache<T> for SubstitutionCache<T> {
             }
         }
     }
+    fn retrieve_memoized(&mut self, function: HashId, key: HashId) -> Option<T> {
+        let result = self.memo_tables.retrieve(function, key);
+        match result {
+            Some(result) => {
+                self.metrics.memoizations.cache_hit();
+                Some(result)
+            }
+            None => {
+                self.metrics.memoizations.cache_miss();
+                None
+            }
+        }
+    }
+    fn store_memoized(&mut self, function: HashId, capacity: usize, key: HashId, result: T) {
+        self.memo_tables.store(function, capacity, key, result);
+    }
     fn metrics(&self) -> Option<&EvaluationCacheMetrics> {
         Some(&self.metrics)
     }
@@ -474,9 +501,14 @@ impl<T: Expression> GenerationalSubstitutionCache<T> {
             previous: None,
         }
     }
-    pub fn advance(self) -> Self {
+    pub fn advance(mut self) -> Self {
+        // Memo tables are explicitly bounded, so they are carried over into the next generation rather than expiring
+        let memo_tables = std::mem::take(&mut self.current.memo_tables);
         Self {
-            current: SubstitutionCache::new(),
+            current: SubstitutionCache {
+                memo_tables,
+                ..SubstitutionCache::new()
+            },
             previous: Some(self.current),
         }
     }
@@ -643,4 +675,94 @@ impl<T: Expression> EvaluationCache<T> for GenerationalSubstitutionCache<T> {
         self.current
             .store_dynamic_substitution(expression, deep, state, result);
     }
+    fn retrieve_memoized(&mut self, function: HashId, key: HashId) -> Option<T> {
+        self.current.retrieve_memoized(function, key)
+    }
+    fn store_memoized(&mut self, function: HashId, capacity: usize, key: HashId, result: T) {
+        self.current.store_memoized(function, capacity, key, result);
+    }
+}
+
+struct MemoTables<T: Expression> {
+    tables: IntMap<HashId, MemoTable<T>>,
+}
+impl<T: Expression> Default for MemoTables<T> {
+    fn default() -> Self {
+        Self {
+            tables: IntMap::default(),
+        }
+    }
+}
+impl<T: Expression> MemoTables<T> {
+    fn retrieve(&mut self, function: HashId, key: HashId) -> Option<T> {
+        self.tables
+            .get_mut(&function)
+            .and_then(|table| table.retrieve(key))
+    }
+    fn store(&mut self, function: HashId, capacity: usize, key: HashId, value: T) {
+        self.tables
+            .entry(function)
+            .or_default()
+            .store(capacity, key, value);
+    }
+}
+
+struct MemoTable<T: Expression> {
+    entries: IntMap<HashId, (T, usize)>,
+    /// Log of entry accesses in chronological order, where each access is tagged with a timestamp so that stale log
+    /// entries (superseded by more recent accesses to the same key) can be skipped when evicting entries
+    access_log: VecDeque<(HashId, usize)>,
+    timestamp: usize,
+}
+impl<T: Expression> Default for MemoTable<T> {
+    fn default() -> Self {
+        Self {
+            entries: IntMap::default(),
+            access_log: VecDeque::default(),
+            timestamp: 0,
+        }
+    }
+}
+impl<T: Expression> MemoTable<T> {
+    fn retrieve(&mut self, key: HashId) -> Option<T> {
+        let timestamp = self.timestamp + 1;
+        let (value, last_accessed) = self.entries.get_mut(&key)?;
+        *last_accessed = timestamp;
+        let value = value.clone();
+        self.timestamp = timestamp;
+        self.access_log.push_back((key, timestamp));
+        self.compact_access_log();
+        Some(value)
+    }
+    fn store(&mut self, capacity: usize, key: HashId, value: T) {
+        self.timestamp += 1;
+        self.entries.insert(key, (value, self.timestamp));
+        self.access_log.push_back((key, self.timestamp));
+        // Evict the least recently used entries until the table is within the capacity limit
+        while self.entries.len() > capacity.max(1) {
+            match self.access_log.pop_front() {
+                Some((key, timestamp)) => {
+                    if let Entry::Occupied(entry) = self.entries.entry(key) {
+                        if entry.get().1 == timestamp {
+                            entry.remove();
+                        }
+                    }
+                }
+                None => break,
+            }
+        }
+        self.compact_access_log();
+    }
+    fn compact_access_log(&mut self) {
+        // Prevent the access log from growing unboundedly when the same entries are repeatedly accessed
+        if self.access_log.len() > self.entries.len() * 2 {
+            let entries = &self.entries;
+            self.access_log.retain(|(key, timestamp)| {
+                entries
+                    .get(key)
+                    .map(|(_, last_accessed)| last_accessed == timestamp)
+                    .unwrap_or(false)
+            });
+        }
+    }
 }
@@ -7,7 +7,7 @@ use std::{
     collections::HashSet,
     convert::TryFrom,
     hash::{Hash, Hasher},
-    iter::{once, repeat, FromIterator},
+    iter::{once, repeat, repeat_n, FromIterator},
     marker::PhantomData,
     ops::Deref,
     path::Path,
@@ -20,12 +20,17 @@ pub use uuid::{uuid, Uuid};
 
 pub use crate::cache::EvaluationCache;
 use crate::hash::{hash_object, FnvHasher, HashId, IntMap, IntSet};
+pub use crate::regex::Regex;
 
 pub type IntValue = i64;
 pub type FloatValue = f64;
 pub type SymbolId = u32;
 /// Timestamp expressed as milliseconds since UNIX epoch
 pub type TimestampValue = i64;
+/// UTC offset expressed as a signed number of minutes east of UTC
+pub type TimezoneOffset = i32;
+/// Duration expressed as a signed number of milliseconds
+pub type DurationValue = i64;
 
 pub fn is_integer(value: FloatValue) -> bool {
     as_integer(value).is_some()
@@ -40,6 +45,177 @@ pub fn as_integer(value: FloatValue) -> Option<IntValue> {
     }
 }
 
+/// Policy determining how arithmetic builtins handle integer overflow and non-finite float results
+#[derive(Hash, Eq, PartialEq, Default, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum NumericSemantics {
+    /// Wrap integer overflow using two's complement arithmetic, and allow NaN/Infinity float results
+    #[default]
+    Wrap,
+    /// Clamp integer overflow to the nearest representable value, and clamp infinite float results
+    /// to the nearest finite value (NaN results are treated as errors)
+    Saturate,
+    /// Emit an error signal on integer overflow or non-finite float results
+    Error,
+}
+impl NumericSemantics {
+    pub fn add_int(&self, left: IntValue, right: IntValue) -> Option<IntValue> {
+        match self {
+            Self::Wrap => Some(left.wrapping_add(right)),
+            Self::Saturate => Some(left.saturating_add(right)),
+            Self::Error => left.checked_add(right),
+        }
+    }
+    pub fn subtract_int(&self, left: IntValue, right: IntValue) -> Option<IntValue> {
+        match self {
+            Self::Wrap => Some(left.wrapping_sub(right)),
+            Self::Saturate => Some(left.saturating_sub(right)),
+            Self::Error => left.checked_sub(right),
+        }
+    }
+    pub fn multiply_int(&self, left: IntValue, right: IntValue) -> Option<IntValue> {
+        match self {
+            Self::Wrap => Some(left.wrapping_mul(right)),
+            Self::Saturate => Some(left.saturating_mul(right)),
+            Self::Error => left.checked_mul(right),
+        }
+    }
+    /// Divide the integer operands (the divisor must be non-zero)
+    pub fn divide_int(&self, left: IntValue, right: IntValue) -> Option<IntValue> {
+        match self {
+            Self::Wrap => Some(left.wrapping_div(right)),
+            Self::Saturate => Some(left.saturating_div(right)),
+            Self::Error => left.checked_div(right),
+        }
+    }
+    /// Compute the remainder of the integer operands (the divisor must be non-zero)
+    ///
+    /// The remainder is always smaller in magnitude than the divisor, so the result is representable under all policies
+    /// (including the minimum value divided by -1, whose intermediate quotient overflows but whose remainder is zero)
+    pub fn remainder_int(&self, left: IntValue, right: IntValue) -> Option<IntValue> {
+        Some(left.wrapping_rem(right))
+    }
+    pub fn pow_int(&self, base: IntValue, exponent: u32) -> Option<IntValue> {
+        match self {
+            Self::Wrap => Some(base.wrapping_pow(exponent)),
+            Self::Saturate => Some(base.saturating_pow(exponent)),
+            Self::Error => base.checked_pow(exponent),
+        }
+    }
+    pub fn abs_int(&self, value: IntValue) -> Option<IntValue> {
+        match self {
+            Self::Wrap => Some(value.wrapping_abs()),
+            Self::Saturate => Some(value.saturating_abs()),
+            Self::Error => value.checked_abs(),
+        }
+    }
+    /// Apply the policy to the result of a floating-point operation
+    pub fn float(&self, value: FloatValue) -> Option<FloatValue> {
+        match self {
+            Self::Wrap => Some(value),
+            Self::Saturate => {
+                if value.is_nan() {
+                    None
+                } else {
+                    Some(value.clamp(FloatValue::MIN, FloatValue::MAX))
+                }
+            }
+            Self::Error => {
+                if value.is_finite() {
+                    Some(value)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+impl std::fmt::Display for NumericSemantics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Wrap => write!(f, "wrap"),
+            Self::Saturate => write!(f, "saturate"),
+            Self::Error => write!(f, "error"),
+        }
+    }
+}
+impl std::str::FromStr for NumericSemantics {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "wrap" => Ok(Self::Wrap),
+            "saturate" => Ok(Self::Saturate),
+            "error" => Ok(Self::Error),
+            _ => Err(format!(
+                "Invalid numeric semantics: {} (expected one of: wrap, saturate, error)",
+                s
+            )),
+        }
+    }
+}
+
+/// Error signal payload emitted by arithmetic builtins when an integer result cannot be represented
+pub const INTEGER_OVERFLOW_ERROR_MESSAGE: &str = "Integer overflow";
+/// Error signal payload emitted by arithmetic builtins when a float result is rejected by the numeric semantics policy
+pub const INVALID_FLOAT_RESULT_ERROR_MESSAGE: &str = "Invalid float result";
+
+/// Strategy determining how float values are converted into their string representation
+#[derive(Hash, Eq, PartialEq, Default, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum FloatFormat {
+    /// Shortest representation that parses back to the original value (e.g. `1.0`, `0.1`, `1e21`)
+    #[default]
+    Shortest,
+    /// Fixed number of digits after the decimal point, rounding to the nearest representable digit
+    Fixed(u8),
+}
+impl std::fmt::Display for FloatFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Shortest => write!(f, "shortest"),
+            Self::Fixed(precision) => write!(f, "fixed:{}", precision),
+        }
+    }
+}
+impl std::str::FromStr for FloatFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            None if s == "shortest" => Ok(Self::Shortest),
+            Some(("fixed", precision)) => precision.parse().map(Self::Fixed).map_err(|_| {
+                format!(
+                    "Invalid float precision: {} (expected integer 0-255)",
+                    precision
+                )
+            }),
+            _ => Err(format!(
+                "Invalid float format: {} (expected one of: shortest, fixed:<precision>)",
+                s
+            )),
+        }
+    }
+}
+
+/// Format a float value according to the provided formatting strategy
+///
+/// All backends delegate to this function to ensure float output is identical regardless of where a value was
+/// computed. Shortest-form output is generated with the Ryū algorithm: integral values retain a trailing `.0`, and
+/// exponent notation is used for magnitudes smaller than `1e-5` or greater than or equal to `1e16`. Negative zero is
+/// formatted as zero, and non-finite values are formatted as `NaN`, `Infinity` and `-Infinity` regardless of the
+/// formatting strategy.
+pub fn format_float(value: FloatValue, format: FloatFormat) -> String {
+    if value.is_nan() {
+        String::from("NaN")
+    } else if value.is_infinite() {
+        String::from(if value > 0.0 { "Infinity" } else { "-Infinity" })
+    } else {
+        // Normalize negative zero to avoid emitting a spurious sign
+        let value = if value == 0.0 { 0.0 } else { value };
+        match format {
+            FloatFormat::Shortest => String::from(ryu::Buffer::new().format_finite(value)),
+            FloatFormat::Fixed(precision) => format!("{:.*}", precision as usize, value),
+        }
+    }
+}
+
 #[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Default, Clone, Copy, Serialize, Deserialize)]
 pub struct InstructionPointer(pub usize);
 impl InstructionPointer {
@@ -99,6 +275,39 @@ pub trait SymbolTermType: Clone {
 
 pub trait TimestampTermType: Clone {
     fn millis(&self) -> TimestampValue;
+    /// UTC offset at which the instant was observed (this does not affect the instant itself)
+    fn offset(&self) -> TimezoneOffset;
+}
+
+pub trait DurationTermType: Clone {
+    fn millis(&self) -> DurationValue;
+}
+
+pub trait BytesTermType: Clone {
+    type BytesRef<'a>: Deref<Target = [u8]>
+    where
+        Self: 'a;
+    fn value<'a>(&'a self) -> Self::BytesRef<'a>;
+}
+
+pub trait RegexTermType<T: Expression>: Clone {
+    fn pattern<'a>(&'a self) -> T::StringRef<'a>
+    where
+        T::String: 'a,
+        T: 'a;
+    /// Compiled regular expression, or an error message if the pattern is invalid
+    fn compiled(&self) -> Result<Arc<Regex>, String>;
+}
+
+/// Opaque wrapper around a secret value (credentials, PII etc) that redacts the underlying value when formatted or
+/// serialized
+///
+/// Sensitive terms are intended to be constructed by effect handlers: the wrapped value can only be accessed via the
+/// `Reveal` builtin or by handler code that explicitly matches the sensitive term.
+pub trait SensitiveTermType<T: Expression>: Clone {
+    fn value<'a>(&'a self) -> T::ExpressionRef<'a>
+    where
+        T: 'a;
 }
 
 pub trait VariableTermType: Clone {
@@ -332,6 +541,84 @@ pub fn parse_record_values<'a, T: Expression>(
     }
 }
 
+/// Validate that the keys of the given record term exactly match the keys of the provided prototype,
+/// returning a descriptive error message listing any missing or unexpected keys
+pub fn validate_record_keys<T: Expression>(
+    prototype: &T::StructPrototype,
+    values: &T::RecordTerm,
+) -> Result<(), String> {
+    let expected_keys = prototype.keys();
+    let expected_keys = expected_keys.as_deref();
+    let input_prototype = values.prototype();
+    let input_keys = input_prototype.as_deref().keys();
+    let input_keys = input_keys.as_deref();
+    if input_keys.id() == expected_keys.id() {
+        return Ok(());
+    }
+    let expected_key_ids = expected_keys
+        .iter()
+        .map(|key| key.as_deref().id())
+        .collect::<IntSet<_>>();
+    let input_key_ids = input_keys
+        .iter()
+        .map(|key| key.as_deref().id())
+        .collect::<IntSet<_>>();
+    let missing_keys = expected_keys
+        .iter()
+        .filter(|key| !input_key_ids.contains(&key.as_deref().id()))
+        .map(|key| format!("{}", key.as_deref()))
+        .collect::<Vec<_>>();
+    let unexpected_keys = input_keys
+        .iter()
+        .filter(|key| !expected_key_ids.contains(&key.as_deref().id()))
+        .map(|key| format!("{}", key.as_deref()))
+        .collect::<Vec<_>>();
+    match (missing_keys.is_empty(), unexpected_keys.is_empty()) {
+        (true, true) => Ok(()),
+        (false, true) => Err(format!("Missing keys: {}", missing_keys.join(", "))),
+        (true, false) => Err(format!("Unexpected keys: {}", unexpected_keys.join(", "))),
+        (false, false) => Err(format!(
+            "Missing keys: {}; Unexpected keys: {}",
+            missing_keys.join(", "),
+            unexpected_keys.join(", ")
+        )),
+    }
+}
+
+/// Validate that the given number of positional values exactly matches the number of keys in the provided prototype,
+/// returning a descriptive error message listing any missing keys or the expected number of values
+pub fn validate_record_length<T: Expression>(
+    prototype: &T::StructPrototype,
+    num_values: usize,
+) -> Result<(), String> {
+    let keys = prototype.keys();
+    let keys = keys.as_deref();
+    let num_keys = keys.len();
+    if num_values < num_keys {
+        Err(format!(
+            "Missing keys: {}",
+            keys.iter()
+                .skip(num_values)
+                .map(|key| format!("{}", key.as_deref()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))
+    } else if num_values > num_keys {
+        Err(format!(
+            "Expected {} {}, received {}",
+            num_keys,
+            if num_keys == 1 {
+                "argument"
+            } else {
+                "arguments"
+            },
+            num_values
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 pub trait RefType<T> {
     fn as_deref(&self) -> &T;
 }
@@ -462,6 +749,10 @@ where
     type StringTerm: StringTermType<Self>;
     type SymbolTerm: SymbolTermType;
     type TimestampTerm: TimestampTermType;
+    type DurationTerm: DurationTermType;
+    type BytesTerm: BytesTermType;
+    type RegexTerm: RegexTermType<Self>;
+    type SensitiveTerm: SensitiveTermType<Self>;
     type VariableTerm: VariableTermType;
     type EffectTerm: EffectTermType<Self>;
     type LetTerm: LetTermType<Self>;
@@ -537,6 +828,19 @@ pub trait Builtin:
     ) -> Result<T, String>;
     fn should_parallelize<T: Expression<Builtin = Self> + Applicable<T>>(&self, args: &[T])
         -> bool;
+    /// Determine the result of applying this builtin to the given arguments without evaluating the arguments, for use
+    /// in static analyses (returns `None` if the builtin cannot be applied statically to the given arguments)
+    ///
+    /// Arguments whose values are not statically known are passed as unevaluated expressions, so implementations must
+    /// not return a result that depends on the value of any argument that is not an atomic value.
+    fn apply_static<T: Expression<Builtin = Self>>(
+        &self,
+        _args: &[T],
+        _factory: &impl ExpressionFactory<T>,
+        _allocator: &impl HeapAllocator<T>,
+    ) -> Option<T> {
+        None
+    }
 }
 
 pub trait Uid {
@@ -664,6 +968,15 @@ impl ArgType {
         }
     }
 }
+impl std::fmt::Display for ArgType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Strict => write!(f, "strict"),
+            Self::Eager => write!(f, "eager"),
+            Self::Lazy => write!(f, "lazy"),
+        }
+    }
+}
 
 #[derive(Hash, Eq, PartialEq, Clone, Copy, Debug)]
 pub struct FunctionArity<const REQUIRED: usize, const OPTIONAL: usize> {
@@ -1130,7 +1443,11 @@ pub trait ExpressionFactory<T: Expression> {
     fn create_float_term(&self, value: FloatValue) -> T;
     fn create_string_term(&self, value: T::String) -> T;
     fn create_symbol_term(&self, value: SymbolId) -> T;
-    fn create_timestamp_term(&self, millis: TimestampValue) -> T;
+    fn create_timestamp_term(&self, millis: TimestampValue, offset: TimezoneOffset) -> T;
+    fn create_duration_term(&self, millis: DurationValue) -> T;
+    fn create_bytes_term(&self, value: &[u8]) -> T;
+    fn create_regex_term(&self, pattern: T::String) -> T;
+    fn create_sensitive_term(&self, value: T) -> T;
     fn create_variable_term(&self, offset: StackOffset) -> T;
     fn create_effect_term(&self, condition: T::Signal) -> T;
     fn create_let_term(&self, initializer: T, body: T) -> T;
@@ -1168,6 +1485,10 @@ pub trait ExpressionFactory<T: Expression> {
     fn match_string_term<'a>(&self, expression: &'a T) -> Option<&'a T::StringTerm>;
     fn match_symbol_term<'a>(&self, expression: &'a T) -> Option<&'a T::SymbolTerm>;
     fn match_timestamp_term<'a>(&self, expression: &'a T) -> Option<&'a T::TimestampTerm>;
+    fn match_duration_term<'a>(&self, expression: &'a T) -> Option<&'a T::DurationTerm>;
+    fn match_bytes_term<'a>(&self, expression: &'a T) -> Option<&'a T::BytesTerm>;
+    fn match_regex_term<'a>(&self, expression: &'a T) -> Option<&'a T::RegexTerm>;
+    fn match_sensitive_term<'a>(&self, expression: &'a T) -> Option<&'a T::SensitiveTerm>;
     fn match_variable_term<'a>(&self, expression: &'a T) -> Option<&'a T::VariableTerm>;
     fn match_effect_term<'a>(&self, expression: &'a T) -> Option<&'a T::EffectTerm>;
     fn match_let_term<'a>(&self, expression: &'a T) -> Option<&'a T::LetTerm>;
@@ -1190,6 +1511,15 @@ pub trait ExpressionFactory<T: Expression> {
     fn match_hashmap_term<'a>(&self, expression: &'a T) -> Option<&'a T::HashmapTerm>;
     fn match_hashset_term<'a>(&self, expression: &'a T) -> Option<&'a T::HashsetTerm>;
     fn match_signal_term<'a>(&self, expression: &'a T) -> Option<&'a T::SignalTerm>;
+
+    /// Policy used by arithmetic builtins when handling integer overflow and non-finite float results
+    fn numeric_semantics(&self) -> NumericSemantics {
+        NumericSemantics::default()
+    }
+    /// Whether constructor applications should emit error signals for arguments that do not match the constructor keys
+    fn validate_constructors(&self) -> bool {
+        false
+    }
 }
 
 pub trait HeapAllocator<T: Expression> {
@@ -1208,6 +1538,25 @@ pub trait HeapAllocator<T: Expression> {
     fn create_pair(&self, left: T, right: T) -> T::ExpressionList;
     fn create_triple(&self, first: T, second: T, third: T) -> T::ExpressionList;
     fn clone_list<'a>(&self, expressions: T::ExpressionListRef<'a>) -> T::ExpressionList
+    where
+        Self: 'a;
+    /// Create a list containing a window of an existing list's items, clamped to the bounds of the existing list
+    /// (implementations may share the existing list's storage rather than copying its items)
+    fn slice_list<'a>(
+        &self,
+        expressions: T::ExpressionListRef<'a>,
+        offset: usize,
+        length: usize,
+    ) -> T::ExpressionList
+    where
+        Self: 'a;
+    /// Create a list containing the items of the left list followed by the items of the right list (implementations
+    /// may share the existing lists' storage rather than copying their items)
+    fn concat_lists<'a>(
+        &self,
+        left: T::ExpressionListRef<'a>,
+        right: T::ExpressionListRef<'a>,
+    ) -> T::ExpressionList
     where
         Self: 'a;
     fn create_signal_list(&self, signals: impl IntoIterator<Item = T::Signal>) -> T::SignalList;
@@ -1643,6 +1992,61 @@ pub fn validate_function_application_arity<T: Expression>(
     }
 }
 
+/// Validate the number of arguments passed to a builtin at graph construction time, reporting the builtin name and
+/// expected call signature for applications that are guaranteed to fail when evaluated
+pub fn validate_builtin_application_arity<TBuiltin: Builtin>(
+    builtin: &TBuiltin,
+    num_args: usize,
+) -> Result<(), String> {
+    let arity = builtin.arity();
+    let num_required_args = arity.required().len();
+    if num_args < num_required_args {
+        Err(format!(
+            "{}: Expected {} {}, received {} (signature: {})",
+            builtin,
+            num_required_args,
+            if arity.optional().len() > 0 || arity.variadic().is_some() {
+                "or more arguments"
+            } else if num_required_args != 1 {
+                "arguments"
+            } else {
+                "argument"
+            },
+            num_args,
+            format_function_signature(builtin, &arity),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Format a human-readable call signature for a function with the given arity, where optional arguments are enclosed
+/// in square brackets and variadic arguments are prefixed with an ellipsis (e.g. `Foo(strict, [lazy], ...eager)`)
+pub fn format_function_signature(name: impl std::fmt::Display, arity: &Arity) -> String {
+    // Homogeneous arity iterators do not expose the underlying argument type, so retrieve it directly
+    let (required, optional) = match arity {
+        Arity::Homogeneous(arity) => (
+            repeat_n(arity.arity_type, arity.required).collect::<Vec<_>>(),
+            repeat_n(arity.arity_type, arity.optional).collect::<Vec<_>>(),
+        ),
+        Arity::Heterogeneous(_) => (
+            arity.required().collect::<Vec<_>>(),
+            arity.optional().collect::<Vec<_>>(),
+        ),
+    };
+    let args = required
+        .into_iter()
+        .map(|arg_type| format!("{}", arg_type))
+        .chain(
+            optional
+                .into_iter()
+                .map(|arg_type| format!("[{}]", arg_type)),
+        )
+        .chain(arity.variadic().map(|arg_type| format!("...{}", arg_type)))
+        .collect::<Vec<_>>();
+    format!("{}({})", name, args.join(", "))
+}
+
 pub fn create_record<T: Expression>(
     properties: impl IntoIterator<Item = (T, T)>,
     factory: &impl ExpressionFactory<T>,
@@ -1692,6 +2096,43 @@ pub fn create_error_expression<T: Expression>(
     )
 }
 
+/// Create an integer term from the result of a checked arithmetic operation, emitting an error signal if the result
+/// could not be represented according to the active [`NumericSemantics`] policy
+pub fn create_checked_int_term<T: Expression>(
+    value: Option<IntValue>,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T {
+    match value {
+        Some(value) => factory.create_int_term(value),
+        None => create_error_expression(
+            factory
+                .create_string_term(allocator.create_static_string(INTEGER_OVERFLOW_ERROR_MESSAGE)),
+            factory,
+            allocator,
+        ),
+    }
+}
+
+/// Create a float term from the result of a checked arithmetic operation, emitting an error signal if the result was
+/// rejected by the active [`NumericSemantics`] policy
+pub fn create_checked_float_term<T: Expression>(
+    value: Option<FloatValue>,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> T {
+    match value {
+        Some(value) => factory.create_float_term(value),
+        None => create_error_expression(
+            factory.create_string_term(
+                allocator.create_static_string(INVALID_FLOAT_RESULT_ERROR_MESSAGE),
+            ),
+            factory,
+            allocator,
+        ),
+    }
+}
+
 pub fn get_short_circuit_signal<T: Expression>(
     args: &[T],
     arity: &Arity,
@@ -1876,6 +2317,97 @@ pub fn build_hashset_lookup_table<T: Expression>(
 mod tests {
     use super::*;
 
+    #[test]
+    fn function_signatures() {
+        assert_eq!(
+            format_function_signature("Foo", &Arity::strict(2, 0, false)),
+            "Foo(strict, strict)"
+        );
+        assert_eq!(
+            format_function_signature("Foo", &Arity::lazy(1, 1, true)),
+            "Foo(lazy, [lazy], ...lazy)"
+        );
+        assert_eq!(
+            format_function_signature("Foo", &Arity::eager(0, 0, false)),
+            "Foo()"
+        );
+        const ARITY: FunctionArity<1, 1> = FunctionArity {
+            required: [ArgType::Strict],
+            optional: [ArgType::Lazy],
+            variadic: Some(ArgType::Eager),
+        };
+        assert_eq!(
+            format_function_signature("Foo", &Arity::from(&ARITY)),
+            "Foo(strict, [lazy], ...eager)"
+        );
+    }
+
+    #[test]
+    fn format_floats() {
+        assert_eq!(format_float(0.0, FloatFormat::Shortest), "0.0");
+        assert_eq!(format_float(-0.0, FloatFormat::Shortest), "0.0");
+        assert_eq!(format_float(-0.0, FloatFormat::Fixed(2)), "0.00");
+        assert_eq!(format_float(1.0, FloatFormat::Shortest), "1.0");
+        assert_eq!(format_float(-1.875, FloatFormat::Shortest), "-1.875");
+        assert_eq!(
+            format_float(0.1 + 0.2, FloatFormat::Shortest),
+            "0.30000000000000004"
+        );
+        assert_eq!(format_float(0.0001, FloatFormat::Shortest), "0.0001");
+        assert_eq!(format_float(0.00001, FloatFormat::Shortest), "0.00001");
+        assert_eq!(format_float(0.000001, FloatFormat::Shortest), "1e-6");
+        assert_eq!(format_float(1.5e-7, FloatFormat::Shortest), "1.5e-7");
+        assert_eq!(
+            format_float(1e15, FloatFormat::Shortest),
+            "1000000000000000.0"
+        );
+        assert_eq!(format_float(1e16, FloatFormat::Shortest), "1e16");
+        assert_eq!(format_float(1.25e21, FloatFormat::Shortest), "1.25e21");
+        assert_eq!(
+            format_float(FloatValue::MAX, FloatFormat::Shortest),
+            "1.7976931348623157e308"
+        );
+        assert_eq!(format_float(FloatValue::NAN, FloatFormat::Shortest), "NaN");
+        assert_eq!(
+            format_float(FloatValue::INFINITY, FloatFormat::Shortest),
+            "Infinity"
+        );
+        assert_eq!(
+            format_float(FloatValue::NEG_INFINITY, FloatFormat::Fixed(2)),
+            "-Infinity"
+        );
+        assert_eq!(format_float(1.0, FloatFormat::Fixed(0)), "1");
+        assert_eq!(format_float(1.0, FloatFormat::Fixed(2)), "1.00");
+        assert_eq!(format_float(-1.23456, FloatFormat::Fixed(3)), "-1.235");
+        assert_eq!(format_float(1e-7, FloatFormat::Fixed(3)), "0.000");
+        for value in [
+            0.1,
+            -2.5,
+            1e-5,
+            1.5e-7,
+            1e16,
+            1.25e21,
+            FloatValue::MIN_POSITIVE,
+        ] {
+            assert_eq!(
+                format_float(value, FloatFormat::Shortest).parse::<FloatValue>(),
+                Ok(value)
+            );
+        }
+    }
+
+    #[test]
+    fn parse_float_format() {
+        assert_eq!("shortest".parse(), Ok(FloatFormat::Shortest));
+        assert_eq!("fixed:2".parse(), Ok(FloatFormat::Fixed(2)));
+        assert!("fixed".parse::<FloatFormat>().is_err());
+        assert!("fixed:-1".parse::<FloatFormat>().is_err());
+        assert!("exponential".parse::<FloatFormat>().is_err());
+        for format in [FloatFormat::Shortest, FloatFormat::Fixed(3)] {
+            assert_eq!(format.to_string().parse(), Ok(format));
+        }
+    }
+
     #[test]
     fn arity() {
         trait MyApplicable {
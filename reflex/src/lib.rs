@@ -1,9 +1,14 @@
 // SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+pub mod analysis;
 pub mod cache;
 pub mod core;
 pub mod env;
 pub mod hash;
+pub mod lint;
 pub mod loader;
+pub mod regex;
+pub mod sandbox;
+pub mod source_map;
 pub mod utils;
@@ -0,0 +1,611 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{collections::HashSet, ops::Deref};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{
+        ApplicationTermType, BuiltinTermType, ConditionListType, ConditionType, EffectTermType,
+        Expression, ExpressionFactory, ExpressionListType, HashmapTermType, HashsetTermType,
+        LambdaTermType, LazyResultTermType, LetTermType, ListTermType, PartialApplicationTermType,
+        RecordTermType, RecursiveTermType, RefType, SignalTermType, SignalType, StackOffset,
+        StringTermType, StringValue, StructPrototypeType, VariableTermType,
+    },
+    hash::{HashId, IntSet},
+    sandbox::get_builtin_aliases,
+};
+
+/// Configuration for the graph lint rules
+///
+/// Builtins can be identified by UUID, by qualified name (e.g. `stdlib:Map`) or by unqualified name (e.g. `Map`).
+#[derive(PartialEq, Eq, Clone, Default, Debug, Serialize, Deserialize)]
+pub struct LintConfig {
+    #[serde(default)]
+    pub effect_in_iteration: EffectInIterationLintConfig,
+    #[serde(default)]
+    pub unguarded_recursion: UnguardedRecursionLintConfig,
+    #[serde(default)]
+    pub large_literal: LargeLiteralLintConfig,
+}
+
+impl LintConfig {
+    /// Report any lint rule violations within the provided expression
+    pub fn lint_expression<T: Expression>(
+        &self,
+        expression: &T,
+        factory: &impl ExpressionFactory<T>,
+    ) -> Vec<LintDiagnostic> {
+        let mut results = Vec::new();
+        lint_expression(
+            expression,
+            self,
+            factory,
+            &mut LintPath::default(),
+            &mut IntSet::default(),
+            &mut results,
+        );
+        results
+    }
+}
+
+/// Effects constructed within a function that is invoked for each item of a collection
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EffectInIterationLintConfig {
+    pub level: LintLevel,
+    /// Builtins that invoke a function argument once for each collection item
+    pub iteration_builtins: HashSet<String>,
+    /// Builtins that construct effects
+    pub effect_builtins: HashSet<String>,
+}
+
+impl Default for EffectInIterationLintConfig {
+    fn default() -> Self {
+        Self {
+            level: LintLevel::Warn,
+            iteration_builtins: ["Map", "Filter", "Fold"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            effect_builtins: ["Effect"].into_iter().map(String::from).collect(),
+        }
+    }
+}
+
+/// Recursive functions that reference themselves outside any conditional branch, and therefore never terminate
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UnguardedRecursionLintConfig {
+    pub level: LintLevel,
+    /// Builtins whose arguments (other than the first) are only evaluated conditionally
+    pub conditional_builtins: HashSet<String>,
+}
+
+impl Default for UnguardedRecursionLintConfig {
+    fn default() -> Self {
+        Self {
+            level: LintLevel::Warn,
+            conditional_builtins: ["If", "IfError", "IfPending"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+        }
+    }
+}
+
+/// Literal strings and collections that exceed the configured size limits
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LargeLiteralLintConfig {
+    pub level: LintLevel,
+    /// Maximum length of a string literal, in bytes
+    pub max_string_length: usize,
+    /// Maximum number of items within a list, record, hashmap or hashset literal
+    pub max_collection_size: usize,
+}
+
+impl Default for LargeLiteralLintConfig {
+    fn default() -> Self {
+        Self {
+            level: LintLevel::Warn,
+            max_string_length: 64 * 1024,
+            max_collection_size: 10000,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
+impl std::fmt::Display for LintLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Allow => write!(f, "allow"),
+            Self::Warn => write!(f, "warning"),
+            Self::Deny => write!(f, "error"),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LintRule {
+    EffectInIteration,
+    UnguardedRecursion,
+    LargeLiteral,
+}
+
+impl LintRule {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::EffectInIteration => "effect_in_iteration",
+            Self::UnguardedRecursion => "unguarded_recursion",
+            Self::LargeLiteral => "large_literal",
+        }
+    }
+}
+
+impl std::fmt::Display for LintRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Lint rule violation, located by its path from the root of the linted expression
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct LintDiagnostic {
+    pub rule: LintRule,
+    pub level: LintLevel,
+    pub path: LintPath,
+    pub message: String,
+}
+
+impl LintDiagnostic {
+    pub fn is_error(&self) -> bool {
+        self.level == LintLevel::Deny
+    }
+}
+
+impl std::fmt::Display for LintDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}[{}] {}: {}",
+            self.level, self.rule, self.path, self.message
+        )
+    }
+}
+
+/// Location of a subexpression, expressed as a list of child accessors starting from the root expression
+#[derive(PartialEq, Eq, Clone, Default, Debug, Serialize, Deserialize)]
+pub struct LintPath(Vec<LintPathSegment>);
+
+impl LintPath {
+    pub fn segments(&self) -> &[LintPathSegment] {
+        &self.0
+    }
+    fn push(&mut self, segment: LintPathSegment) {
+        self.0.push(segment);
+    }
+    fn pop(&mut self) {
+        self.0.pop();
+    }
+}
+
+impl std::fmt::Display for LintPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "$")?;
+        for segment in self.0.iter() {
+            write!(f, "{}", segment)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub enum LintPathSegment {
+    Target,
+    Arg(usize),
+    Body,
+    Initializer,
+    Factory,
+    Value,
+    Field(String),
+    Item(usize),
+    Key(usize),
+    Payload,
+    Token,
+}
+
+impl std::fmt::Display for LintPathSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Target => write!(f, ".target"),
+            Self::Arg(index) => write!(f, ".args[{}]", index),
+            Self::Body => write!(f, ".body"),
+            Self::Initializer => write!(f, ".initializer"),
+            Self::Factory => write!(f, ".factory"),
+            Self::Value => write!(f, ".value"),
+            Self::Field(key) => write!(f, "[{}]", key),
+            Self::Item(index) => write!(f, "[{}]", index),
+            Self::Key(index) => write!(f, ".keys[{}]", index),
+            Self::Payload => write!(f, ".payload"),
+            Self::Token => write!(f, ".token"),
+        }
+    }
+}
+
+fn lint_expression<T: Expression>(
+    expression: &T,
+    config: &LintConfig,
+    factory: &impl ExpressionFactory<T>,
+    path: &mut LintPath,
+    visited: &mut IntSet<HashId>,
+    results: &mut Vec<LintDiagnostic>,
+) {
+    if !visited.insert(expression.id()) {
+        return;
+    }
+    if config.effect_in_iteration.level != LintLevel::Allow {
+        lint_effect_in_iteration(
+            expression,
+            &config.effect_in_iteration,
+            factory,
+            path,
+            results,
+        );
+    }
+    if config.unguarded_recursion.level != LintLevel::Allow {
+        lint_unguarded_recursion(
+            expression,
+            &config.unguarded_recursion,
+            factory,
+            path,
+            results,
+        );
+    }
+    if config.large_literal.level != LintLevel::Allow {
+        lint_large_literal(expression, &config.large_literal, factory, path, results);
+    }
+    for (segment, child) in get_child_expressions(expression, factory) {
+        path.push(segment);
+        lint_expression(&child, config, factory, path, visited, results);
+        path.pop();
+    }
+}
+
+fn lint_effect_in_iteration<T: Expression>(
+    expression: &T,
+    config: &EffectInIterationLintConfig,
+    factory: &impl ExpressionFactory<T>,
+    path: &mut LintPath,
+    results: &mut Vec<LintDiagnostic>,
+) {
+    let term = match factory.match_application_term(expression) {
+        Some(term) => term,
+        None => return,
+    };
+    let iteration_builtin = match get_matching_builtin(
+        term.target().as_deref(),
+        &config.iteration_builtins,
+        factory,
+    ) {
+        Some(builtin) => builtin,
+        None => return,
+    };
+    for (index, arg) in term.args().as_deref().iter().enumerate() {
+        let arg = arg.as_deref();
+        if let Some(lambda) = factory.match_lambda_term(arg) {
+            path.push(LintPathSegment::Arg(index));
+            path.push(LintPathSegment::Body);
+            let mut effect_paths = Vec::new();
+            find_effect_constructors(
+                lambda.body().as_deref(),
+                config,
+                factory,
+                path,
+                &mut IntSet::default(),
+                &mut effect_paths,
+            );
+            path.pop();
+            path.pop();
+            results.extend(effect_paths.into_iter().map(|path| LintDiagnostic {
+                rule: LintRule::EffectInIteration,
+                level: config.level,
+                path,
+                message: format!(
+                    "Effect constructed within function invoked for each item of {}",
+                    iteration_builtin
+                ),
+            }));
+        }
+    }
+}
+
+fn find_effect_constructors<T: Expression>(
+    expression: &T,
+    config: &EffectInIterationLintConfig,
+    factory: &impl ExpressionFactory<T>,
+    path: &mut LintPath,
+    visited: &mut IntSet<HashId>,
+    results: &mut Vec<LintPath>,
+) {
+    if !visited.insert(expression.id()) {
+        return;
+    }
+    let is_effect_constructor = if factory.match_effect_term(expression).is_some() {
+        true
+    } else if let Some(term) = factory.match_application_term(expression) {
+        get_matching_builtin(term.target().as_deref(), &config.effect_builtins, factory).is_some()
+    } else {
+        false
+    };
+    if is_effect_constructor {
+        results.push(path.clone());
+        return;
+    }
+    for (segment, child) in get_child_expressions(expression, factory) {
+        path.push(segment);
+        find_effect_constructors(&child, config, factory, path, visited, results);
+        path.pop();
+    }
+}
+
+fn lint_unguarded_recursion<T: Expression>(
+    expression: &T,
+    config: &UnguardedRecursionLintConfig,
+    factory: &impl ExpressionFactory<T>,
+    path: &mut LintPath,
+    results: &mut Vec<LintDiagnostic>,
+) {
+    let term = match factory.match_recursive_term(expression) {
+        Some(term) => term,
+        None => return,
+    };
+    let recursive_factory = term.factory();
+    let lambda = match factory.match_lambda_term(recursive_factory.as_deref()) {
+        Some(lambda) if lambda.num_args() == 1 => lambda,
+        _ => return,
+    };
+    path.push(LintPathSegment::Factory);
+    path.push(LintPathSegment::Body);
+    let mut reference_paths = Vec::new();
+    find_unguarded_references(
+        lambda.body().as_deref(),
+        0,
+        config,
+        factory,
+        path,
+        &mut reference_paths,
+    );
+    path.pop();
+    path.pop();
+    results.extend(reference_paths.into_iter().map(|path| LintDiagnostic {
+        rule: LintRule::UnguardedRecursion,
+        level: config.level,
+        path,
+        message: String::from("Recursive function references itself outside a conditional branch"),
+    }));
+}
+
+/// Find any references to the variable at the given stack offset that will be evaluated whenever the expression is
+/// evaluated (references within function bodies, conditional branches and collection items are deferred, and are
+/// therefore not considered to be unguarded)
+fn find_unguarded_references<T: Expression>(
+    expression: &T,
+    offset: StackOffset,
+    config: &UnguardedRecursionLintConfig,
+    factory: &impl ExpressionFactory<T>,
+    path: &mut LintPath,
+    results: &mut Vec<LintPath>,
+) {
+    if let Some(term) = factory.match_variable_term(expression) {
+        if term.offset() == offset {
+            results.push(path.clone());
+        }
+    } else if let Some(term) = factory.match_let_term(expression) {
+        path.push(LintPathSegment::Initializer);
+        find_unguarded_references(
+            term.initializer().as_deref(),
+            offset,
+            config,
+            factory,
+            path,
+            results,
+        );
+        path.pop();
+        path.push(LintPathSegment::Body);
+        find_unguarded_references(
+            term.body().as_deref(),
+            offset + 1,
+            config,
+            factory,
+            path,
+            results,
+        );
+        path.pop();
+    } else if let Some(term) = factory.match_application_term(expression) {
+        let target = term.target();
+        let target = target.as_deref();
+        let is_conditional =
+            get_matching_builtin(target, &config.conditional_builtins, factory).is_some();
+        path.push(LintPathSegment::Target);
+        find_unguarded_references(target, offset, config, factory, path, results);
+        path.pop();
+        let args = term.args();
+        let num_eager_args = if is_conditional {
+            1
+        } else {
+            args.as_deref().len()
+        };
+        for (index, arg) in args.as_deref().iter().take(num_eager_args).enumerate() {
+            path.push(LintPathSegment::Arg(index));
+            find_unguarded_references(arg.as_deref(), offset, config, factory, path, results);
+            path.pop();
+        }
+    }
+}
+
+fn lint_large_literal<T: Expression>(
+    expression: &T,
+    config: &LargeLiteralLintConfig,
+    factory: &impl ExpressionFactory<T>,
+    path: &mut LintPath,
+    results: &mut Vec<LintDiagnostic>,
+) {
+    let message = if let Some(term) = factory.match_string_term(expression) {
+        let length = term.value().as_deref().as_str().deref().len();
+        (length > config.max_string_length).then(|| {
+            format!(
+                "String literal length ({} bytes) exceeds maximum length of {} bytes",
+                length, config.max_string_length
+            )
+        })
+    } else {
+        let size = if let Some(term) = factory.match_list_term(expression) {
+            Some(term.items().as_deref().len())
+        } else if let Some(term) = factory.match_record_term(expression) {
+            Some(term.values().as_deref().len())
+        } else if let Some(term) = factory.match_hashmap_term(expression) {
+            Some(term.keys().len())
+        } else {
+            factory
+                .match_hashset_term(expression)
+                .map(|term| term.values().len())
+        };
+        size.filter(|size| *size > config.max_collection_size)
+            .map(|size| {
+                format!(
+                    "Collection literal size ({} items) exceeds maximum size of {} items",
+                    size, config.max_collection_size
+                )
+            })
+    };
+    if let Some(message) = message {
+        results.push(LintDiagnostic {
+            rule: LintRule::LargeLiteral,
+            level: config.level,
+            path: path.clone(),
+            message,
+        });
+    }
+}
+
+pub(crate) fn get_matching_builtin<T: Expression>(
+    expression: &T,
+    builtins: &HashSet<String>,
+    factory: &impl ExpressionFactory<T>,
+) -> Option<String> {
+    let target = factory.match_builtin_term(expression)?.target();
+    let [uid, qualified_name, unqualified_name] = get_builtin_aliases(&target);
+    let is_match = builtins.contains(&uid)
+        || builtins.contains(&qualified_name)
+        || builtins.contains(&unqualified_name);
+    is_match.then_some(qualified_name)
+}
+
+pub(crate) fn get_child_expressions<T: Expression>(
+    expression: &T,
+    factory: &impl ExpressionFactory<T>,
+) -> Vec<(LintPathSegment, T)> {
+    if let Some(term) = factory.match_effect_term(expression) {
+        get_condition_child_expressions(term.condition().as_deref())
+    } else if let Some(term) = factory.match_signal_term(expression) {
+        term.signals()
+            .as_deref()
+            .iter()
+            .flat_map(|condition| get_condition_child_expressions(condition.as_deref()))
+            .collect()
+    } else if let Some(term) = factory.match_lazy_result_term(expression) {
+        vec![(LintPathSegment::Value, term.value().as_deref().clone())]
+    } else if let Some(term) = factory.match_let_term(expression) {
+        vec![
+            (
+                LintPathSegment::Initializer,
+                term.initializer().as_deref().clone(),
+            ),
+            (LintPathSegment::Body, term.body().as_deref().clone()),
+        ]
+    } else if let Some(term) = factory.match_lambda_term(expression) {
+        vec![(LintPathSegment::Body, term.body().as_deref().clone())]
+    } else if let Some(term) = factory.match_application_term(expression) {
+        get_call_child_expressions(term.target().as_deref(), term.args().as_deref())
+    } else if let Some(term) = factory.match_partial_application_term(expression) {
+        get_call_child_expressions(term.target().as_deref(), term.args().as_deref())
+    } else if let Some(term) = factory.match_recursive_term(expression) {
+        vec![(LintPathSegment::Factory, term.factory().as_deref().clone())]
+    } else if let Some(term) = factory.match_record_term(expression) {
+        let prototype = term.prototype();
+        let keys = prototype.as_deref().keys();
+        keys.as_deref()
+            .iter()
+            .zip(term.values().as_deref().iter())
+            .map(|(key, value)| {
+                let key = key.as_deref();
+                let key = match factory.match_string_term(key) {
+                    Some(key) => String::from(key.value().as_deref().as_str().deref()),
+                    None => format!("{}", key),
+                };
+                (LintPathSegment::Field(key), value.as_deref().clone())
+            })
+            .collect()
+    } else if let Some(term) = factory.match_list_term(expression) {
+        term.items()
+            .as_deref()
+            .iter()
+            .enumerate()
+            .map(|(index, item)| (LintPathSegment::Item(index), item.as_deref().clone()))
+            .collect()
+    } else if let Some(term) = factory.match_hashmap_term(expression) {
+        term.keys()
+            .enumerate()
+            .map(|(index, key)| (LintPathSegment::Key(index), key.as_deref().clone()))
+            .chain(
+                term.values()
+                    .enumerate()
+                    .map(|(index, value)| (LintPathSegment::Item(index), value.as_deref().clone())),
+            )
+            .collect()
+    } else if let Some(term) = factory.match_hashset_term(expression) {
+        term.values()
+            .enumerate()
+            .map(|(index, value)| (LintPathSegment::Item(index), value.as_deref().clone()))
+            .collect()
+    } else {
+        Vec::new()
+    }
+}
+
+fn get_call_child_expressions<T: Expression>(
+    target: &T,
+    args: &T::ExpressionList,
+) -> Vec<(LintPathSegment, T)> {
+    [(LintPathSegment::Target, target.clone())]
+        .into_iter()
+        .chain(
+            args.iter()
+                .enumerate()
+                .map(|(index, arg)| (LintPathSegment::Arg(index), arg.as_deref().clone())),
+        )
+        .collect()
+}
+
+fn get_condition_child_expressions<T: Expression>(
+    condition: &T::Signal,
+) -> Vec<(LintPathSegment, T)> {
+    match condition.signal_type() {
+        SignalType::Custom { payload, token, .. } => vec![
+            (LintPathSegment::Payload, payload),
+            (LintPathSegment::Token, token),
+        ],
+        SignalType::Error { payload } => vec![(LintPathSegment::Payload, payload)],
+        SignalType::Pending => Vec::new(),
+    }
+}
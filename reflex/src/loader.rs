@@ -6,7 +6,7 @@ use std::{
     collections::HashMap,
     marker::PhantomData,
     ops::{Deref, DerefMut},
-    path::{Path, PathBuf},
+    path::{Component, Path, PathBuf},
     rc::Rc,
 };
 
@@ -22,6 +22,14 @@ impl<'a, TLoader: ModuleLoader> ModuleLoader for &'a TLoader {
     }
 }
 
+impl<TLoader: ModuleLoader> ModuleLoader for Rc<TLoader> {
+    type Output = TLoader::Output;
+    fn load(&self, import_path: &str, current_path: &Path) -> Option<Result<Self::Output, String>> {
+        let loader = self.deref();
+        loader.load(import_path, current_path)
+    }
+}
+
 #[derive(Derivative)]
 #[derivative(Default(bound = ""), Clone(bound = ""), Debug(bound = ""))]
 pub struct NoopModuleLoader<T: Expression> {
@@ -180,6 +188,50 @@ impl<T: Expression> ModuleLoader for RecursiveModuleLoader<T> {
     }
 }
 
+/// Module loader that memoizes the results of an inner loader, keyed by the resolved module path
+///
+/// This ensures that each module is instantiated at most once per loader session: all importers of a given module share
+/// the same module instance, matching ES module semantics. Clones of the loader share the same module cache.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "TLoader: Clone"))]
+pub struct CachedModuleLoader<TLoader: ModuleLoader> {
+    inner: TLoader,
+    cache: Rc<RefCell<ModuleCacheEntries<TLoader::Output>>>,
+}
+
+type ModuleCacheEntries<T> = HashMap<PathBuf, Option<Result<T, String>>>;
+
+impl<TLoader: ModuleLoader> CachedModuleLoader<TLoader> {
+    pub fn new(inner: TLoader) -> Self {
+        Self {
+            inner,
+            cache: Default::default(),
+        }
+    }
+    /// Number of distinct modules that have been loaded so far
+    pub fn len(&self) -> usize {
+        self.cache.borrow().len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.cache.borrow().is_empty()
+    }
+}
+
+impl<TLoader: ModuleLoader> ModuleLoader for CachedModuleLoader<TLoader> {
+    type Output = TLoader::Output;
+    fn load(&self, import_path: &str, current_path: &Path) -> Option<Result<Self::Output, String>> {
+        let module_id =
+            normalize_module_path(&get_module_filesystem_path(import_path, current_path));
+        if let Some(cached) = self.cache.borrow().get(&module_id) {
+            return cached.clone();
+        }
+        // The cache must not be borrowed while loading the module, as the inner loader can recursively load imports
+        let result = self.inner.load(import_path, current_path);
+        self.cache.borrow_mut().insert(module_id, result.clone());
+        result
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct StaticModuleLoader<T: Expression> {
     modules: HashMap<String, T>,
@@ -250,3 +302,22 @@ pub fn get_module_filesystem_path(import_path: &str, module_path: &Path) -> Path
         .map(|parent| parent.join(import_path))
         .unwrap_or_else(|| Path::new(import_path).to_path_buf())
 }
+
+/// Lexically resolve any `.` and `..` components of the given module path, without accessing the filesystem
+pub fn normalize_module_path(path: &Path) -> PathBuf {
+    path.components()
+        .fold(PathBuf::new(), |mut result, component| {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => match result.components().next_back() {
+                    Some(Component::Normal(_)) => {
+                        result.pop();
+                    }
+                    Some(Component::RootDir) | Some(Component::Prefix(_)) => {}
+                    _ => result.push(component),
+                },
+                component => result.push(component),
+            }
+            result
+        })
+}
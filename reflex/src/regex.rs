@@ -0,0 +1,54 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+pub use ::regex::Regex;
+
+/// Maximum number of compiled patterns to retain before the cache is flushed
+const MAX_CACHED_PATTERNS: usize = 1024;
+
+type RegexCache = HashMap<String, Result<Arc<Regex>, String>>;
+
+static REGEX_CACHE: Mutex<Option<RegexCache>> = Mutex::new(None);
+
+/// Compile the given pattern, reusing any previously-compiled instance of the same pattern
+pub fn compile_regex(pattern: &str) -> Result<Arc<Regex>, String> {
+    let mut cache = match REGEX_CACHE.lock() {
+        Ok(cache) => cache,
+        Err(_) => return parse_regex(pattern),
+    };
+    let cache = cache.get_or_insert_with(Default::default);
+    if let Some(existing) = cache.get(pattern) {
+        return existing.clone();
+    }
+    if cache.len() >= MAX_CACHED_PATTERNS {
+        cache.clear();
+    }
+    let result = parse_regex(pattern);
+    cache.insert(String::from(pattern), result.clone());
+    result
+}
+
+fn parse_regex(pattern: &str) -> Result<Arc<Regex>, String> {
+    Regex::new(pattern)
+        .map(Arc::new)
+        .map_err(|err| format!("Invalid regular expression /{}/: {}", pattern, err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn caches_compiled_patterns() {
+        let first = compile_regex("^foo(\\d+)$").unwrap();
+        let second = compile_regex("^foo(\\d+)$").unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(first.is_match("foo123"));
+        assert!(compile_regex("(foo").is_err());
+    }
+}
@@ -0,0 +1,438 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    ops::Deref,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{
+        ApplicationTermType, BuiltinTermType, ConditionListType, ConditionType, EffectTermType,
+        Expression, ExpressionFactory, ExpressionListType, HashmapTermType, HashsetTermType,
+        LambdaTermType, LazyResultTermType, LetTermType, ListTermType, PartialApplicationTermType,
+        RecordTermType, RecursiveTermType, RefType, SignalTermType, SignalType, StringTermType,
+        StringValue, Uid,
+    },
+    hash::{HashId, IntSet},
+};
+
+/// Set of sandbox policies for individual graph roots, falling back to a default policy for any unlisted roots
+#[derive(PartialEq, Eq, Clone, Default, Debug, Serialize, Deserialize)]
+pub struct SandboxPolicies {
+    #[serde(flatten)]
+    pub default: SandboxPolicy,
+    /// Per-root policy overrides, keyed by graph root name (compiler entry point name or query label)
+    #[serde(default)]
+    pub roots: HashMap<String, SandboxPolicy>,
+}
+
+impl SandboxPolicies {
+    pub fn with_root(mut self, root: impl Into<String>, policy: SandboxPolicy) -> Self {
+        self.roots.insert(root.into(), policy);
+        self
+    }
+    pub fn get(&self, root: &str) -> &SandboxPolicy {
+        self.roots.get(root).unwrap_or(&self.default)
+    }
+}
+
+impl From<SandboxPolicy> for SandboxPolicies {
+    fn from(value: SandboxPolicy) -> Self {
+        Self {
+            default: value,
+            roots: Default::default(),
+        }
+    }
+}
+
+/// Restrictions on the builtins and effect types that may be used by a graph root
+///
+/// Builtins can be identified by UUID, by qualified name (e.g. `stdlib:Log`) or by unqualified name (e.g. `Log`).
+#[derive(PartialEq, Eq, Clone, Default, Debug, Serialize, Deserialize)]
+pub struct SandboxPolicy {
+    #[serde(default)]
+    pub builtins: SandboxRule<String>,
+    #[serde(default)]
+    pub effect_types: SandboxRule<String>,
+}
+
+impl SandboxPolicy {
+    pub fn allow_builtin(mut self, builtin: impl Uid) -> Self {
+        self.builtins = self.builtins.allow(format!("{}", builtin.uid()));
+        self
+    }
+    pub fn deny_builtin(mut self, builtin: impl Uid) -> Self {
+        self.builtins = self.builtins.deny(format!("{}", builtin.uid()));
+        self
+    }
+    pub fn allow_effect_type(mut self, effect_type: impl Into<String>) -> Self {
+        self.effect_types = self.effect_types.allow(effect_type.into());
+        self
+    }
+    pub fn deny_effect_type(mut self, effect_type: impl Into<String>) -> Self {
+        self.effect_types = self.effect_types.deny(effect_type.into());
+        self
+    }
+    pub fn is_unrestricted(&self) -> bool {
+        self.builtins.is_unrestricted() && self.effect_types.is_unrestricted()
+    }
+    pub fn permits_builtin(&self, builtin: &(impl Uid + std::fmt::Display)) -> bool {
+        if self.builtins.is_unrestricted() {
+            return true;
+        }
+        let [uid, qualified_name, unqualified_name] = get_builtin_aliases(builtin);
+        self.builtins.permits_any(&[
+            uid.as_str(),
+            qualified_name.as_str(),
+            unqualified_name.as_str(),
+        ])
+    }
+    pub fn permits_effect_type(&self, effect_type: &str) -> bool {
+        self.effect_types.permits(effect_type)
+    }
+    pub fn validate_builtin(
+        &self,
+        builtin: &(impl Uid + std::fmt::Display),
+    ) -> Result<(), SandboxViolation> {
+        if self.permits_builtin(builtin) {
+            Ok(())
+        } else {
+            Err(SandboxViolation::Builtin(format!("{}", builtin)))
+        }
+    }
+    pub fn validate_effect_type<T: Expression>(
+        &self,
+        effect_type: &T,
+        factory: &impl ExpressionFactory<T>,
+    ) -> Result<(), SandboxViolation> {
+        if self.effect_types.is_unrestricted() {
+            return Ok(());
+        }
+        let effect_type = get_effect_type_name(effect_type, factory);
+        if self.permits_effect_type(&effect_type) {
+            Ok(())
+        } else {
+            Err(SandboxViolation::EffectType(effect_type))
+        }
+    }
+    pub fn validate_effect<T: Expression>(
+        &self,
+        effect: &T::Signal,
+        factory: &impl ExpressionFactory<T>,
+    ) -> Result<(), SandboxViolation> {
+        match effect.signal_type() {
+            SignalType::Custom { effect_type, .. } => {
+                self.validate_effect_type(&effect_type, factory)
+            }
+            SignalType::Error { .. } | SignalType::Pending => Ok(()),
+        }
+    }
+    /// Ensure that the provided expression does not reference any forbidden builtins or contain any statically-known forbidden effects
+    pub fn validate_expression<T: Expression>(
+        &self,
+        expression: &T,
+        factory: &impl ExpressionFactory<T>,
+    ) -> Result<(), SandboxViolation> {
+        if self.is_unrestricted() {
+            return Ok(());
+        }
+        validate_sandbox_expression(expression, self, factory, &mut IntSet::default())
+    }
+}
+
+/// Combined allow/deny list, where values must be present in the allow list (if specified) and absent from the deny list
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct SandboxRule<V: Eq + Hash> {
+    #[serde(default)]
+    pub allow: Option<HashSet<V>>,
+    #[serde(default)]
+    pub deny: HashSet<V>,
+}
+
+impl<V: Eq + Hash> Default for SandboxRule<V> {
+    fn default() -> Self {
+        Self {
+            allow: None,
+            deny: HashSet::new(),
+        }
+    }
+}
+
+impl<V: Eq + Hash> SandboxRule<V> {
+    pub fn allow(mut self, value: V) -> Self {
+        self.allow.get_or_insert_with(HashSet::new).insert(value);
+        self
+    }
+    pub fn deny(mut self, value: V) -> Self {
+        self.deny.insert(value);
+        self
+    }
+    pub fn is_unrestricted(&self) -> bool {
+        self.allow.is_none() && self.deny.is_empty()
+    }
+    pub fn permits<Q: Eq + Hash + ?Sized>(&self, value: &Q) -> bool
+    where
+        V: Borrow<Q>,
+    {
+        let is_allowed = match &self.allow {
+            Some(allowed) => allowed.contains(value),
+            None => true,
+        };
+        is_allowed && !self.deny.contains(value)
+    }
+    /// Determine whether a value known by several aliases is permitted, where any alias may appear in the allow list
+    /// but none may appear in the deny list
+    pub fn permits_any<Q: Eq + Hash + ?Sized>(&self, aliases: &[&Q]) -> bool
+    where
+        V: Borrow<Q>,
+    {
+        let is_allowed = match &self.allow {
+            Some(allowed) => aliases.iter().any(|alias| allowed.contains(*alias)),
+            None => true,
+        };
+        is_allowed && !aliases.iter().any(|alias| self.deny.contains(*alias))
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum SandboxViolation {
+    Builtin(String),
+    EffectType(String),
+}
+
+impl std::error::Error for SandboxViolation {}
+
+impl std::fmt::Display for SandboxViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Builtin(name) => write!(f, "Builtin not permitted by sandbox policy: {name}"),
+            Self::EffectType(effect_type) => write!(
+                f,
+                "Effect type not permitted by sandbox policy: {effect_type}"
+            ),
+        }
+    }
+}
+
+fn validate_sandbox_expression<T: Expression>(
+    expression: &T,
+    policy: &SandboxPolicy,
+    factory: &impl ExpressionFactory<T>,
+    visited: &mut IntSet<HashId>,
+) -> Result<(), SandboxViolation> {
+    if !visited.insert(expression.id()) {
+        return Ok(());
+    }
+    if let Some(term) = factory.match_builtin_term(expression) {
+        policy.validate_builtin(&term.target())
+    } else if let Some(term) = factory.match_effect_term(expression) {
+        validate_sandbox_condition(term.condition().as_deref(), policy, factory, visited)
+    } else if let Some(term) = factory.match_signal_term(expression) {
+        term.signals().as_deref().iter().try_for_each(|condition| {
+            validate_sandbox_condition(condition.as_deref(), policy, factory, visited)
+        })
+    } else if let Some(term) = factory.match_lazy_result_term(expression) {
+        validate_sandbox_expression(term.value().as_deref(), policy, factory, visited)?;
+        term.dependencies()
+            .as_deref()
+            .iter()
+            .try_for_each(|condition| {
+                validate_sandbox_condition(condition.as_deref(), policy, factory, visited)
+            })
+    } else if let Some(term) = factory.match_let_term(expression) {
+        validate_sandbox_expression(term.initializer().as_deref(), policy, factory, visited)?;
+        validate_sandbox_expression(term.body().as_deref(), policy, factory, visited)
+    } else if let Some(term) = factory.match_lambda_term(expression) {
+        validate_sandbox_expression(term.body().as_deref(), policy, factory, visited)
+    } else if let Some(term) = factory.match_application_term(expression) {
+        validate_sandbox_expression(term.target().as_deref(), policy, factory, visited)?;
+        term.args().as_deref().iter().try_for_each(|arg| {
+            validate_sandbox_expression(arg.as_deref(), policy, factory, visited)
+        })
+    } else if let Some(term) = factory.match_partial_application_term(expression) {
+        validate_sandbox_expression(term.target().as_deref(), policy, factory, visited)?;
+        term.args().as_deref().iter().try_for_each(|arg| {
+            validate_sandbox_expression(arg.as_deref(), policy, factory, visited)
+        })
+    } else if let Some(term) = factory.match_recursive_term(expression) {
+        validate_sandbox_expression(term.factory().as_deref(), policy, factory, visited)
+    } else if let Some(term) = factory.match_record_term(expression) {
+        term.values().as_deref().iter().try_for_each(|value| {
+            validate_sandbox_expression(value.as_deref(), policy, factory, visited)
+        })
+    } else if let Some(term) = factory.match_list_term(expression) {
+        term.items().as_deref().iter().try_for_each(|item| {
+            validate_sandbox_expression(item.as_deref(), policy, factory, visited)
+        })
+    } else if let Some(term) = factory.match_hashmap_term(expression) {
+        term.keys().chain(term.values()).try_for_each(|item| {
+            validate_sandbox_expression(item.as_deref(), policy, factory, visited)
+        })
+    } else if let Some(term) = factory.match_hashset_term(expression) {
+        term.values().try_for_each(|item| {
+            validate_sandbox_expression(item.as_deref(), policy, factory, visited)
+        })
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_sandbox_condition<T: Expression>(
+    condition: &T::Signal,
+    policy: &SandboxPolicy,
+    factory: &impl ExpressionFactory<T>,
+    visited: &mut IntSet<HashId>,
+) -> Result<(), SandboxViolation> {
+    match condition.signal_type() {
+        SignalType::Custom {
+            effect_type,
+            payload,
+            token,
+        } => {
+            policy.validate_effect_type(&effect_type, factory)?;
+            validate_sandbox_expression(&payload, policy, factory, visited)?;
+            validate_sandbox_expression(&token, policy, factory, visited)
+        }
+        SignalType::Error { payload } => {
+            validate_sandbox_expression(&payload, policy, factory, visited)
+        }
+        SignalType::Pending => Ok(()),
+    }
+}
+
+/// Get the UUID, qualified name (e.g. `stdlib:Log`) and unqualified name (e.g. `Log`) of the provided builtin
+pub(crate) fn get_builtin_aliases(builtin: &(impl Uid + std::fmt::Display)) -> [String; 3] {
+    let uid = format!("{}", builtin.uid());
+    let name = format!("{}", builtin);
+    let qualified_name = name.trim_start_matches('<').trim_end_matches('>');
+    let unqualified_name = match qualified_name.rsplit_once(':') {
+        Some((_, name)) => name,
+        None => qualified_name,
+    };
+    [
+        uid,
+        String::from(qualified_name),
+        String::from(unqualified_name),
+    ]
+}
+
+fn get_effect_type_name<T: Expression>(
+    effect_type: &T,
+    factory: &impl ExpressionFactory<T>,
+) -> String {
+    match factory.match_string_term(effect_type) {
+        Some(term) => String::from(term.value().as_deref().as_str().deref()),
+        None => format!("{}", effect_type),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sandbox_rules() {
+        let rule = SandboxRule::<String>::default();
+        assert!(rule.is_unrestricted());
+        assert!(rule.permits("foo"));
+        let rule = SandboxRule::default().deny(String::from("foo"));
+        assert!(!rule.is_unrestricted());
+        assert!(!rule.permits("foo"));
+        assert!(rule.permits("bar"));
+        let rule = SandboxRule::default()
+            .allow(String::from("foo"))
+            .allow(String::from("bar"))
+            .deny(String::from("bar"));
+        assert!(rule.permits("foo"));
+        assert!(!rule.permits("bar"));
+        assert!(!rule.permits("baz"));
+        assert!(rule.permits_any(&["baz", "foo"]));
+        assert!(!rule.permits_any(&["foo", "bar"]));
+        assert!(!rule.permits_any(&["baz", "qux"]));
+    }
+
+    #[test]
+    fn sandbox_builtin_names() {
+        struct Log;
+        impl Uid for Log {
+            fn uid(&self) -> crate::core::Uuid {
+                crate::core::uuid!("5c1f3b0e-7d1e-4c2f-9a63-3b1e6a2f4d10")
+            }
+        }
+        impl std::fmt::Display for Log {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "<stdlib:Log>")
+            }
+        }
+        for denied in ["5c1f3b0e-7d1e-4c2f-9a63-3b1e6a2f4d10", "stdlib:Log", "Log"] {
+            let policy: SandboxPolicy = serde_json::from_value(serde_json::json!({
+                "builtins": { "deny": [denied] },
+            }))
+            .unwrap();
+            assert!(!policy.permits_builtin(&Log));
+            assert_eq!(
+                policy.validate_builtin(&Log),
+                Err(SandboxViolation::Builtin(String::from("<stdlib:Log>")))
+            );
+        }
+        let policy: SandboxPolicy = serde_json::from_value(serde_json::json!({
+            "builtins": { "deny": ["js:Log"] },
+        }))
+        .unwrap();
+        assert!(policy.permits_builtin(&Log));
+        let policy = SandboxPolicy::default().allow_builtin(Log);
+        assert!(policy.permits_builtin(&Log));
+        let policy = SandboxPolicy::default().deny_builtin(Log);
+        assert!(!policy.permits_builtin(&Log));
+    }
+
+    #[test]
+    fn sandbox_policy_serialization() {
+        let policy = SandboxPolicy::default().deny_effect_type("reflex::fetch");
+        let serialized = serde_json::to_value(&policy).unwrap();
+        assert_eq!(
+            serde_json::from_value::<SandboxPolicy>(serialized).unwrap(),
+            policy
+        );
+        let policy: SandboxPolicy =
+            serde_json::from_str(r#"{ "effect_types": { "allow": ["reflex::timestamp"] } }"#)
+                .unwrap();
+        assert!(policy.builtins.is_unrestricted());
+        assert!(policy.permits_effect_type("reflex::timestamp"));
+        assert!(!policy.permits_effect_type("reflex::fetch"));
+    }
+
+    #[test]
+    fn sandbox_policy_roots() {
+        let policies: SandboxPolicies = serde_json::from_str(
+            r#"{
+                "effect_types": { "deny": ["reflex::fetch"] },
+                "roots": {
+                    "foo": { "effect_types": { "deny": ["reflex::timestamp"] } }
+                }
+            }"#,
+        )
+        .unwrap();
+        assert!(!policies.get("bar").permits_effect_type("reflex::fetch"));
+        assert!(policies.get("bar").permits_effect_type("reflex::timestamp"));
+        assert!(policies.get("foo").permits_effect_type("reflex::fetch"));
+        assert!(!policies.get("foo").permits_effect_type("reflex::timestamp"));
+        let policies: SandboxPolicies =
+            serde_json::from_str(r#"{ "effect_types": { "deny": ["reflex::fetch"] } }"#).unwrap();
+        assert_eq!(
+            policies,
+            SandboxPolicies::from(SandboxPolicy::default().deny_effect_type("reflex::fetch"))
+        );
+        let policies = SandboxPolicies::default().with_root(
+            "foo",
+            SandboxPolicy::default().deny_effect_type("reflex::fetch"),
+        );
+        assert!(policies.get("bar").is_unrestricted());
+        assert!(!policies.get("foo").is_unrestricted());
+    }
+}
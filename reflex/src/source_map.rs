@@ -0,0 +1,172 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use std::{
+    collections::BTreeMap,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{core::Expression, hash::HashId};
+
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct SourceLocation {
+    pub path: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl std::fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "{}:{}:{}", path.display(), self.line, self.column),
+            None => write!(f, "<anonymous>:{}:{}", self.line, self.column),
+        }
+    }
+}
+
+/// User-level source information for a term produced by a parser
+#[derive(PartialEq, Eq, Clone, Default, Debug, Serialize, Deserialize)]
+pub struct SourceMetadata {
+    /// Symbol name under which the term was declared, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Location of the term within the source file from which it was parsed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<SourceLocation>,
+}
+
+impl std::fmt::Display for SourceMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = self.name.as_deref().unwrap_or("<anonymous>");
+        match &self.location {
+            Some(location) => write!(f, "{} at {}", name, location),
+            None => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Side-table mapping term hashes to the source metadata of the user-level terms from which they were parsed
+///
+/// Entries are keyed by term hash, so metadata only applies to terms that survive any subsequent transformations
+/// unchanged (rewritten terms will have a different hash and will therefore not be annotated).
+#[derive(PartialEq, Eq, Clone, Default, Debug, Serialize, Deserialize)]
+pub struct SourceMetadataTable {
+    entries: BTreeMap<HashId, SourceMetadata>,
+}
+
+impl SourceMetadataTable {
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    pub fn get(&self, hash: HashId) -> Option<&SourceMetadata> {
+        self.entries.get(&hash)
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (HashId, &SourceMetadata)> + '_ {
+        self.entries
+            .iter()
+            .map(|(hash, metadata)| (*hash, metadata))
+    }
+    /// Add metadata for the given term hash, filling in any fields that are missing from an existing entry
+    pub fn insert(&mut self, hash: HashId, metadata: SourceMetadata) {
+        let existing = self.entries.entry(hash).or_default();
+        if existing.name.is_none() {
+            existing.name = metadata.name;
+        }
+        if existing.location.is_none() {
+            existing.location = metadata.location;
+        }
+    }
+    /// Create a new table whose entries are rekeyed according to the provided mapping, discarding any unmapped entries
+    pub fn remap(&self, mut mapping: impl FnMut(HashId) -> Option<HashId>) -> Self {
+        self.entries
+            .iter()
+            .filter_map(|(hash, metadata)| mapping(*hash).map(|hash| (hash, metadata.clone())))
+            .collect()
+    }
+    /// Human-readable label for the given term hash, falling back to the raw hash if no metadata is present
+    pub fn describe(&self, hash: HashId) -> String {
+        match self.get(hash) {
+            Some(metadata) => format!("{}", metadata),
+            None => format!("{:016x}", hash),
+        }
+    }
+}
+
+impl FromIterator<(HashId, SourceMetadata)> for SourceMetadataTable {
+    fn from_iter<I: IntoIterator<Item = (HashId, SourceMetadata)>>(iter: I) -> Self {
+        let mut table = Self::default();
+        table.extend(iter);
+        table
+    }
+}
+
+impl Extend<(HashId, SourceMetadata)> for SourceMetadataTable {
+    fn extend<I: IntoIterator<Item = (HashId, SourceMetadata)>>(&mut self, iter: I) {
+        for (hash, metadata) in iter {
+            self.insert(hash, metadata);
+        }
+    }
+}
+
+/// Shared handle to a source metadata table, allowing parsers to record metadata on behalf of their callers
+#[derive(Clone, Default, Debug)]
+pub struct SourceMetadataRecorder {
+    table: Arc<Mutex<SourceMetadataTable>>,
+}
+
+impl SourceMetadataRecorder {
+    pub fn record<T: Expression>(&self, expression: &T, metadata: SourceMetadata) {
+        self.table.lock().unwrap().insert(expression.id(), metadata);
+    }
+    /// Retrieve a copy of all the metadata that has been recorded so far
+    pub fn table(&self) -> SourceMetadataTable {
+        self.table.lock().unwrap().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_entries() {
+        let location = SourceLocation {
+            path: Some(PathBuf::from("index.js")),
+            line: 3,
+            column: 7,
+        };
+        let mut table = SourceMetadataTable::default();
+        table.insert(
+            1,
+            SourceMetadata {
+                name: None,
+                location: Some(location.clone()),
+            },
+        );
+        table.insert(
+            1,
+            SourceMetadata {
+                name: Some(String::from("foo")),
+                location: None,
+            },
+        );
+        assert_eq!(
+            table.get(1),
+            Some(&SourceMetadata {
+                name: Some(String::from("foo")),
+                location: Some(location),
+            }),
+        );
+        assert_eq!(table.describe(1), "foo at index.js:3:7");
+        assert_eq!(table.describe(2), "0000000000000002");
+        let remapped = table.remap(|hash| if hash == 1 { Some(3) } else { None });
+        assert_eq!(remapped.get(1), None);
+        assert_eq!(remapped.describe(3), "foo at index.js:3:7");
+    }
+}
@@ -0,0 +1,130 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::{
+    analysis::{
+        GraphMetric, GraphMetrics, GraphMetricsBudget, GraphMetricsBudgetViolation,
+        GraphMetricsConfig,
+    },
+    core::{ExpressionFactory, HeapAllocator},
+};
+use reflex_lang::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
+use reflex_stdlib::{Effect, Map, Stdlib};
+
+type T = CachedSharedTerm<Stdlib>;
+
+fn create_effect_iteration(
+    factory: &SharedTermFactory<Stdlib>,
+    allocator: &DefaultAllocator<T>,
+) -> T {
+    factory.create_application_term(
+        factory.create_builtin_term(Map),
+        allocator.create_pair(
+            factory.create_list_term(
+                allocator.create_pair(factory.create_int_term(3), factory.create_int_term(4)),
+            ),
+            factory.create_lambda_term(
+                1,
+                factory.create_application_term(
+                    factory.create_builtin_term(Effect),
+                    allocator.create_triple(
+                        factory.create_string_term(allocator.create_static_string("reflex::fetch")),
+                        factory.create_variable_term(0),
+                        factory.create_nil_term(),
+                    ),
+                ),
+            ),
+        ),
+    )
+}
+
+#[test]
+fn graph_metrics() {
+    let factory = SharedTermFactory::<Stdlib>::default();
+    let allocator = DefaultAllocator::default();
+    let config = GraphMetricsConfig::default();
+    let expression = create_effect_iteration(&factory, &allocator);
+    assert_eq!(
+        config.analyze_expression(&expression, &factory),
+        GraphMetrics {
+            num_nodes: 11,
+            term_counts: [
+                ("Application", 2),
+                ("Builtin", 2),
+                ("Int", 2),
+                ("Lambda", 1),
+                ("List", 1),
+                ("Nil", 1),
+                ("String", 1),
+                ("Variable", 1),
+            ]
+            .into_iter()
+            .map(|(key, value)| (String::from(key), value))
+            .collect(),
+            max_depth: 4,
+            num_effects: 1,
+            estimated_snapshot_size: 236,
+        }
+    );
+    let config = GraphMetricsConfig {
+        effect_builtins: Default::default(),
+    };
+    assert_eq!(
+        config.analyze_expression(&expression, &factory).num_effects,
+        0
+    );
+}
+
+#[test]
+fn shared_subexpressions() {
+    let factory = SharedTermFactory::<Stdlib>::default();
+    let allocator = DefaultAllocator::default();
+    let config = GraphMetricsConfig::default();
+    let item = factory.create_string_term(allocator.create_static_string("foo"));
+    let expression = factory.create_list_term(allocator.create_triple(
+        item.clone(),
+        item.clone(),
+        factory.create_list_term(allocator.create_unit_list(item.clone())),
+    ));
+    let metrics = config.analyze_expression(&expression, &factory);
+    assert_eq!(metrics.num_nodes, 3);
+    assert_eq!(metrics.term_counts.get("String"), Some(&1));
+    assert_eq!(metrics.term_counts.get("List"), Some(&2));
+    assert_eq!(metrics.max_depth, 3);
+}
+
+#[test]
+fn graph_metrics_budget() {
+    let factory = SharedTermFactory::<Stdlib>::default();
+    let allocator = DefaultAllocator::default();
+    let config = GraphMetricsConfig::default();
+    let expression = create_effect_iteration(&factory, &allocator);
+    let metrics = config.analyze_expression(&expression, &factory);
+    assert_eq!(GraphMetricsBudget::default().validate(&metrics), Vec::new());
+    let budget = GraphMetricsBudget {
+        max_nodes: Some(11),
+        max_depth: Some(3),
+        max_effects: Some(1),
+        max_snapshot_size: Some(200),
+    };
+    let violations = budget.validate(&metrics);
+    assert_eq!(
+        violations,
+        vec![
+            GraphMetricsBudgetViolation {
+                metric: GraphMetric::Depth,
+                value: 4,
+                limit: 3,
+            },
+            GraphMetricsBudgetViolation {
+                metric: GraphMetric::SnapshotSize,
+                value: 236,
+                limit: 200,
+            },
+        ]
+    );
+    assert_eq!(
+        format!("{}", violations[0]),
+        "Graph max depth (4) exceeds budget of 3"
+    );
+}
@@ -0,0 +1,143 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::{
+    core::{ExpressionFactory, HeapAllocator},
+    lint::{LintConfig, LintLevel, LintPathSegment, LintRule},
+};
+use reflex_lang::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
+use reflex_stdlib::{Add, Effect, If, Map, Stdlib};
+
+type T = CachedSharedTerm<Stdlib>;
+
+#[test]
+fn effect_in_iteration() {
+    let factory = SharedTermFactory::<Stdlib>::default();
+    let allocator = DefaultAllocator::default();
+    let config = LintConfig::default();
+    let create_effect = |payload: T| {
+        factory.create_application_term(
+            factory.create_builtin_term(Effect),
+            allocator.create_triple(
+                factory.create_string_term(allocator.create_static_string("reflex::fetch")),
+                payload,
+                factory.create_nil_term(),
+            ),
+        )
+    };
+    let items = factory.create_list_term(
+        allocator.create_pair(factory.create_int_term(3), factory.create_int_term(4)),
+    );
+    let expression = factory.create_application_term(
+        factory.create_builtin_term(Map),
+        allocator.create_pair(
+            items.clone(),
+            factory.create_lambda_term(1, create_effect(factory.create_variable_term(0))),
+        ),
+    );
+    let diagnostics = config.lint_expression(&expression, &factory);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].rule, LintRule::EffectInIteration);
+    assert_eq!(diagnostics[0].level, LintLevel::Warn);
+    assert_eq!(
+        diagnostics[0].path.segments(),
+        &[LintPathSegment::Arg(1), LintPathSegment::Body]
+    );
+    assert_eq!(
+        format!("{}", diagnostics[0]),
+        "warning[effect_in_iteration] $.args[1].body: Effect constructed within function invoked for each item of stdlib:Map",
+    );
+    let expression = factory.create_application_term(
+        factory.create_builtin_term(Map),
+        allocator.create_pair(
+            items.clone(),
+            factory.create_lambda_term(
+                1,
+                factory.create_application_term(
+                    factory.create_builtin_term(Add),
+                    allocator
+                        .create_pair(factory.create_variable_term(0), factory.create_int_term(1)),
+                ),
+            ),
+        ),
+    );
+    assert_eq!(config.lint_expression(&expression, &factory), Vec::new());
+    let expression = factory
+        .create_list_term(allocator.create_unit_list(create_effect(factory.create_int_term(3))));
+    assert_eq!(config.lint_expression(&expression, &factory), Vec::new());
+}
+
+#[test]
+fn unguarded_recursion() {
+    let factory = SharedTermFactory::<Stdlib>::default();
+    let allocator = DefaultAllocator::default();
+    let config = LintConfig::default();
+    let recursive_call = factory.create_application_term(
+        factory.create_variable_term(0),
+        allocator.create_empty_list(),
+    );
+    let expression = factory.create_recursive_term(factory.create_lambda_term(
+        1,
+        factory.create_application_term(
+            factory.create_builtin_term(Add),
+            allocator.create_pair(factory.create_int_term(1), recursive_call.clone()),
+        ),
+    ));
+    let diagnostics = config.lint_expression(&expression, &factory);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].rule, LintRule::UnguardedRecursion);
+    assert_eq!(
+        format!("{}", diagnostics[0].path),
+        "$.factory.body.args[1].target"
+    );
+    let expression = factory.create_recursive_term(factory.create_lambda_term(
+        1,
+        factory.create_application_term(
+            factory.create_builtin_term(If),
+            allocator.create_triple(
+                factory.create_boolean_term(false),
+                recursive_call.clone(),
+                factory.create_int_term(3),
+            ),
+        ),
+    ));
+    assert_eq!(config.lint_expression(&expression, &factory), Vec::new());
+    let expression = factory.create_recursive_term(
+        factory.create_lambda_term(1, factory.create_lambda_term(0, recursive_call.clone())),
+    );
+    assert_eq!(config.lint_expression(&expression, &factory), Vec::new());
+    let config = LintConfig {
+        unguarded_recursion: serde_json::from_str(r#"{ "level": "allow" }"#).unwrap(),
+        ..Default::default()
+    };
+    let expression = factory.create_recursive_term(factory.create_lambda_term(1, recursive_call));
+    assert_eq!(config.lint_expression(&expression, &factory), Vec::new());
+}
+
+#[test]
+fn large_literals() {
+    let factory = SharedTermFactory::<Stdlib>::default();
+    let allocator = DefaultAllocator::default();
+    let config: LintConfig = serde_json::from_str(
+        r#"{ "large_literal": { "level": "deny", "max_string_length": 3, "max_collection_size": 2 } }"#,
+    )
+    .unwrap();
+    assert_eq!(config.effect_in_iteration, Default::default());
+    let expression = factory.create_list_term(allocator.create_triple(
+        factory.create_string_term(allocator.create_static_string("foo")),
+        factory.create_string_term(allocator.create_static_string("quux")),
+        factory.create_int_term(3),
+    ));
+    let diagnostics = config.lint_expression(&expression, &factory);
+    assert_eq!(
+        diagnostics
+            .iter()
+            .map(|diagnostic| (diagnostic.rule, format!("{}", diagnostic.path)))
+            .collect::<Vec<_>>(),
+        vec![
+            (LintRule::LargeLiteral, String::from("$")),
+            (LintRule::LargeLiteral, String::from("$[1]")),
+        ]
+    );
+    assert!(diagnostics.iter().all(|diagnostic| diagnostic.is_error()));
+}
@@ -0,0 +1,48 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::{
+    cache::GenerationalSubstitutionCache,
+    core::{EvaluationCache, ExpressionFactory},
+};
+use reflex_lang::{CachedSharedTerm, SharedTermFactory};
+use reflex_stdlib::Stdlib;
+
+type T = CachedSharedTerm<Stdlib>;
+
+#[test]
+fn memo_table_eviction() {
+    let factory = SharedTermFactory::<Stdlib>::default();
+    let mut cache = GenerationalSubstitutionCache::<T>::new();
+    let function = 0;
+    let capacity = 2;
+    cache.store_memoized(function, capacity, 1, factory.create_int_term(1));
+    cache.store_memoized(function, capacity, 2, factory.create_int_term(2));
+    assert_eq!(
+        cache.retrieve_memoized(function, 1),
+        Some(factory.create_int_term(1))
+    );
+    cache.store_memoized(function, capacity, 3, factory.create_int_term(3));
+    assert_eq!(cache.retrieve_memoized(function, 2), None);
+    assert_eq!(
+        cache.retrieve_memoized(function, 1),
+        Some(factory.create_int_term(1))
+    );
+    assert_eq!(
+        cache.retrieve_memoized(function, 3),
+        Some(factory.create_int_term(3))
+    );
+    assert_eq!(cache.retrieve_memoized(1, 1), None);
+}
+
+#[test]
+fn memo_tables_retained_across_generations() {
+    let factory = SharedTermFactory::<Stdlib>::default();
+    let mut cache = GenerationalSubstitutionCache::<T>::new();
+    cache.store_memoized(0, 2, 1, factory.create_int_term(1));
+    let mut cache = cache.advance();
+    assert_eq!(
+        cache.retrieve_memoized(0, 1),
+        Some(factory.create_int_term(1))
+    );
+}
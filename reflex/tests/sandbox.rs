@@ -0,0 +1,143 @@
+// SPDX-FileCopyrightText: 2023 Marshall Wace <opensource@mwam.com>
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileContributor: Tim Kendrick <t.kendrick@mwam.com> https://github.com/timkendrickmw
+use reflex::{
+    core::{Expression, ExpressionFactory, HeapAllocator, SignalType},
+    sandbox::{SandboxPolicy, SandboxViolation},
+};
+use reflex_lang::{allocator::DefaultAllocator, CachedSharedTerm, SharedTermFactory};
+use reflex_stdlib::{Add, Raise, Stdlib};
+
+type T = CachedSharedTerm<Stdlib>;
+
+fn create_effect(
+    effect_type: &'static str,
+    payload: T,
+    factory: &impl ExpressionFactory<T>,
+    allocator: &impl HeapAllocator<T>,
+) -> <T as Expression>::Signal {
+    allocator.create_signal(SignalType::Custom {
+        effect_type: factory.create_string_term(allocator.create_static_string(effect_type)),
+        payload,
+        token: factory.create_nil_term(),
+    })
+}
+
+#[test]
+fn nested_builtins() {
+    let factory = SharedTermFactory::<Stdlib>::default();
+    let allocator = DefaultAllocator::default();
+    let policy = SandboxPolicy::default().deny_builtin(Raise);
+    let raise = factory.create_builtin_term(Raise);
+    let expressions = [
+        factory.create_let_term(factory.create_int_term(3), raise.clone()),
+        factory.create_let_term(raise.clone(), factory.create_int_term(3)),
+        factory.create_lambda_term(
+            1,
+            factory.create_let_term(factory.create_variable_term(0), raise.clone()),
+        ),
+        factory.create_record_term(
+            allocator.create_struct_prototype(allocator.create_pair(
+                factory.create_string_term(allocator.create_static_string("foo")),
+                factory.create_string_term(allocator.create_static_string("bar")),
+            )),
+            allocator.create_pair(
+                factory.create_int_term(3),
+                factory.create_lambda_term(1, raise.clone()),
+            ),
+        ),
+        factory.create_hashmap_term([(factory.create_int_term(3), raise.clone())]),
+        factory.create_hashmap_term([(raise.clone(), factory.create_int_term(3))]),
+        factory.create_application_term(
+            factory.create_builtin_term(Add),
+            allocator.create_pair(
+                factory.create_int_term(3),
+                factory.create_application_term(
+                    raise.clone(),
+                    allocator.create_unit_list(factory.create_int_term(4)),
+                ),
+            ),
+        ),
+    ];
+    for expression in expressions {
+        assert_eq!(
+            policy.validate_expression(&expression, &factory),
+            Err(SandboxViolation::Builtin(format!("{}", Stdlib::Raise))),
+            "{}",
+            expression
+        );
+        assert_eq!(
+            SandboxPolicy::default()
+                .deny_builtin(Add)
+                .validate_expression(&expression, &factory)
+                .is_ok(),
+            factory.match_application_term(&expression).is_none(),
+        );
+    }
+}
+
+#[test]
+fn nested_effects() {
+    let factory = SharedTermFactory::<Stdlib>::default();
+    let allocator = DefaultAllocator::default();
+    let policy = SandboxPolicy::default().deny_effect_type("foo");
+    let forbidden_effect = create_effect("foo", factory.create_nil_term(), &factory, &allocator);
+    let expressions = [
+        factory.create_effect_term(forbidden_effect.clone()),
+        factory.create_lambda_term(
+            1,
+            factory.create_let_term(
+                factory.create_variable_term(0),
+                factory.create_effect_term(forbidden_effect.clone()),
+            ),
+        ),
+        factory.create_list_term(allocator.create_pair(
+            factory.create_int_term(3),
+            factory.create_effect_term(forbidden_effect.clone()),
+        )),
+        factory.create_hashmap_term([(
+            factory.create_int_term(3),
+            factory.create_effect_term(forbidden_effect.clone()),
+        )]),
+        // Forbidden effects nested within the payload of a permitted effect
+        factory.create_effect_term(create_effect(
+            "bar",
+            factory.create_effect_term(forbidden_effect.clone()),
+            &factory,
+            &allocator,
+        )),
+        // Forbidden effects nested within the payload of a signal
+        factory.create_signal_term(allocator.create_signal_list([allocator.create_signal(
+            SignalType::Error {
+                payload: factory.create_effect_term(forbidden_effect.clone()),
+            },
+        )])),
+        factory.create_signal_term(allocator.create_signal_list([create_effect(
+            "bar",
+            factory.create_record_term(
+                allocator.create_struct_prototype(allocator.create_unit_list(
+                    factory.create_string_term(allocator.create_static_string("foo")),
+                )),
+                allocator.create_unit_list(factory.create_effect_term(forbidden_effect.clone())),
+            ),
+            &factory,
+            &allocator,
+        )])),
+    ];
+    for expression in expressions {
+        assert_eq!(
+            policy.validate_expression(&expression, &factory),
+            Err(SandboxViolation::EffectType(String::from("foo"))),
+            "{}",
+            expression
+        );
+        assert_eq!(
+            SandboxPolicy::default()
+                .deny_effect_type("baz")
+                .validate_expression(&expression, &factory),
+            Ok(()),
+            "{}",
+            expression
+        );
+    }
+}